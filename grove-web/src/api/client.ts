@@ -33,6 +33,41 @@ export function clearSecretKey() {
   sessionStorage.removeItem(SK_KEY);
 }
 
+// ─── Session token (server `[auth]` session policy) ──────────────────────────
+
+const SESSION_KEY = 'grove_auth_session';
+
+/** Fired on `window` when the server rejects the session (idle / expired). */
+export const SESSION_EXPIRED_EVENT = 'grove:session-expired';
+
+export function getSessionToken(): string | null {
+  return sessionStorage.getItem(SESSION_KEY);
+}
+
+export function setSessionToken(token: string) {
+  sessionStorage.setItem(SESSION_KEY, token);
+}
+
+export function clearSessionToken() {
+  sessionStorage.removeItem(SESSION_KEY);
+}
+
+// ─── Device id (server-side failed sign-in tracking) ──────────────────────────
+
+const DEVICE_KEY = 'grove_device_id';
+
+/** Stable per-browser id sent as `X-Grove-Device` on `/auth/verify`. */
+export function getDeviceId(): string {
+  let id = localStorage.getItem(DEVICE_KEY);
+  if (!id) {
+    const bytes = new Uint8Array(16);
+    crypto.getRandomValues(bytes);
+    id = Array.from(bytes, (b) => b.toString(16).padStart(2, '0')).join('');
+    localStorage.setItem(DEVICE_KEY, id);
+  }
+  return id;
+}
+
 /** Extract secret key from URL hash fragment: /#sk=xxx */
 export function extractSkFromUrl(): string | null {
   const hash = window.location.hash;
@@ -178,6 +213,8 @@ async function getSignedHeaders(
     headers['X-Timestamp'] = sig.timestamp;
     headers['X-Nonce'] = sig.nonce;
     headers['X-Signature'] = sig.signature;
+    const session = getSessionToken();
+    if (session) headers['X-Grove-Session'] = session;
   }
   return headers;
 }
@@ -201,6 +238,8 @@ async function getAuthOnlyHeaders(
     headers['X-Timestamp'] = sig.timestamp;
     headers['X-Nonce'] = sig.nonce;
     headers['X-Signature'] = sig.signature;
+    const session = getSessionToken();
+    if (session) headers['X-Grove-Session'] = session;
   }
   return headers;
 }
@@ -218,6 +257,13 @@ export async function appendHmacToUrl(url: string): Promise<string> {
     return `${url}${sep}token=${encodeURIComponent(radioToken)}`;
   }
 
+  // WebSockets can't send headers: the session token rides as a (signed)
+  // query param instead.
+  const session = getSecretKey() ? getSessionToken() : null;
+  if (session) {
+    url = `${url}${url.includes('?') ? '&' : '?'}grove_session=${session}`;
+  }
+
   // Extract pathname + query for signing. The signature must cover existing
   // query params (e.g. `?session_id=…`) so a MITM can't swap them.
   let pathWithQuery: string;
@@ -249,6 +295,9 @@ export async function appendHmacToUrl(url: string): Promise<string> {
 
 // Try to extract error message (and JSON body) from response
 async function extractErrorPayload(response: Response): Promise<ErrorPayload> {
+  if (response.status === 401 && response.headers.get('x-grove-session') === 'expired') {
+    window.dispatchEvent(new Event(SESSION_EXPIRED_EVENT));
+  }
   try {
     const text = await response.text();
     if (text) {
@@ -504,10 +553,10 @@ class ApiClient {
     }
   }
 
-  async put<T, R>(path: string, data: T): Promise<R> {
+  async put<T, R>(path: string, data: T, extraHeaders?: Record<string, string>): Promise<R> {
     const response = await fetch(`${this.baseUrl}${path}`, {
       method: 'PUT',
-      headers: await getSignedHeaders('PUT', path),
+      headers: { ...(await getSignedHeaders('PUT', path)), ...extraHeaders },
       body: JSON.stringify(data),
     });
 
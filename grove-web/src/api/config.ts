@@ -30,8 +30,30 @@ export interface ThemeConfig {
   light_theme: string;
   dark_theme: string;
   custom_themes: CustomThemeConfig[];
+  /** Color-blind safe status colors; overrides success / warning / error */
+  status_palette?: StatusPalette;
+  /** Use the TUI theme's full colors (see getTuiTheme) instead of the web palette */
+  sync_tui_colors?: boolean;
 }
 
+/** Full colors of the theme selected in the TUI (GET /api/v1/config/theme) */
+export interface TuiTheme {
+  id: string;
+  name: string;
+  is_light: boolean;
+  is_custom: boolean;
+  /** Every TUI color as `#rrggbb`, keyed by field name (bg, status_live, …) */
+  colors: Record<string, string>;
+  card_backgrounds: string[];
+  accent_palette: string[];
+  /** The colors mapped onto the web theme slots */
+  web_colors: CustomThemeConfig["colors"];
+  /** CSS custom properties (`--color-*`, `--tui-*`) to set on the root */
+  css_variables: Record<string, string>;
+}
+
+export type StatusPalette = "default" | "deuteranopia" | "protanopia";
+
 export interface LayoutConfig {
   default: string;
   agent_command?: string;
@@ -52,8 +74,16 @@ export interface WebConfig {
   show_hide_window_shortcut?: string;
 }
 
+export type AutoLinkMode = "symlink" | "copy" | "hardlink";
+
 export interface AutoLinkConfig {
   patterns: string[];
+  /** Per-pattern link mode; patterns not listed use "symlink". */
+  modes: Record<string, AutoLinkMode>;
+  /** Size limit for a single copied path, in MB. */
+  copy_max_mb: number;
+  /** Commands run in each new worktree; {branch}, {task_id}, {target} are expanded. */
+  post_create: string[];
 }
 
 export interface CustomAgentServer {
@@ -80,6 +110,16 @@ export interface HooksConfig {
   response_sound: string;
   permission_sound_enabled: boolean;
   permission_sound: string;
+  /** Shell command per notification level, run with GROVE_HOOK_* env vars */
+  commands?: HookCommands;
+  /** Write Grove hooks into agent settings (e.g. .claude/settings.local.json) on task creation */
+  install_agent_hooks?: boolean;
+}
+
+export interface HookCommands {
+  notice?: string;
+  warn?: string;
+  critical?: string;
 }
 
 export interface SupportedLanguage {
@@ -113,6 +153,21 @@ export interface NotificationsConfig {
   /** Retention policy for tray "Done" chats. Wire shape matches the Rust
    *  externally-tagged enum (see `RetentionPolicy` in storage/config.rs). */
   tray_done_retention?: RetentionPolicyWire;
+  /** Do Not Disturb: silence sounds and banners (notifications still reach the inbox). */
+  do_not_disturb?: boolean;
+  /** Daily quiet hours (server local time). */
+  quiet_hours?: QuietHours;
+  /** Read-only: sounds/banners are suppressed right now (DND or quiet hours). */
+  muted?: boolean;
+}
+
+/** Daily quiet hours; `start` later than `end` wraps past midnight. */
+export interface QuietHours {
+  enabled: boolean;
+  /** "HH:MM" */
+  start: string;
+  /** "HH:MM" */
+  end: string;
 }
 
 /** Wire shape of `notifications.tray_done_retention` (externally tagged). */
@@ -127,6 +182,86 @@ export interface BrowserControlConfig {
   auto_groups?: boolean;
 }
 
+/** Per-user review diff preferences (defaults for the diff endpoints) */
+export interface ReviewConfig {
+  /** `-w`: ignore all whitespace changes */
+  ignore_whitespace: boolean;
+  /** `--ignore-blank-lines` */
+  ignore_blank_lines: boolean;
+  /** `-M` rename detection */
+  detect_renames: boolean;
+  /** `-C` copy detection */
+  detect_copies: boolean;
+}
+
+/** Sync of task metadata between machines through a git remote */
+export interface SyncConfig {
+  enabled: boolean;
+  /** Git remote URL of the sync repo */
+  remote?: string | null;
+  /** Automatic sync interval while Grove is running */
+  interval_minutes: number;
+}
+
+/** Copy-to-clipboard behaviour of the TUI */
+export interface ClipboardConfig {
+  /** Also emit OSC 52 so copies reach the local clipboard over SSH / tmux */
+  osc52: boolean;
+}
+
+/** Signing of commits and merges Grove creates */
+export interface SigningConfig {
+  enabled: boolean;
+  format: 'openpgp' | 'ssh' | 'x509';
+  /** Key id, or public key path for SSH; git's user.signingkey when unset */
+  key?: string | null;
+}
+
+/** Task risk scoring rules (`[risk]`) */
+export interface RiskConfig {
+  enabled: boolean;
+  /** Globs of critical paths (migrations, CI, auth, …) */
+  critical_paths: string[];
+  /** Globs that identify test files */
+  test_paths: string[];
+  /** File count at which the "files touched" factor maxes out */
+  max_files: number;
+  /** Maximum points of each factor */
+  weights: { files: number; critical_paths: number; deletions: number; no_tests: number };
+  /** Score thresholds (0-100) */
+  medium: number;
+  high: number;
+}
+
+/** Timestamp display (`[display]`) */
+export interface DisplayConfig {
+  time_format: 'relative' | 'absolute';
+  /** BCP 47 tag for date formatting; empty follows the browser / system */
+  locale?: string;
+}
+
+/** User-defined "Open With" application */
+export interface OpenWithApp {
+  name: string;
+  /** Shell command run in the worktree; `{path}` / `{branch}` are substituted */
+  command: string;
+}
+
+/** Organization mode: tasks shared with teammates through a team server */
+export interface OrganizationConfig {
+  server_url?: string | null;
+  /** The token itself is never sent back */
+  has_token: boolean;
+  /** This machine serves `/org` as a team server (config file only) */
+  serving: boolean;
+}
+
+/** An empty string clears the field */
+interface OrganizationConfigPatch {
+  server_url?: string;
+  token?: string;
+}
+
 export interface Config {
   theme: ThemeConfig;
   layout: LayoutConfig;
@@ -138,6 +273,15 @@ export interface Config {
   notifications: NotificationsConfig;
   indexing: IndexingConfig;
   browser_control: BrowserControlConfig;
+  review: ReviewConfig;
+  sync: SyncConfig;
+  organization: OrganizationConfig;
+  clipboard: ClipboardConfig;
+  signing: SigningConfig;
+  risk: RiskConfig;
+  display: DisplayConfig;
+  /** In display order */
+  open_with: OpenWithApp[];
   platform: string; // "macos" | "windows" | "linux"
 }
 
@@ -157,6 +301,19 @@ interface ConfigPatch {
   notifications?: Partial<NotificationsConfig>;
   indexing?: IndexingConfigPatch;
   browser_control?: Partial<BrowserControlConfig>;
+  review?: Partial<ReviewConfig>;
+  /** An empty `remote` clears it */
+  sync?: Partial<SyncConfig>;
+  organization?: OrganizationConfigPatch;
+  clipboard?: Partial<ClipboardConfig>;
+  /** An empty `key` clears it */
+  signing?: Partial<SigningConfig>;
+  /** Replaces the whole section */
+  risk?: RiskConfig;
+  /** Replaces the whole section */
+  display?: DisplayConfig;
+  /** Replaces the whole list (order included) */
+  open_with?: OpenWithApp[];
 }
 
 // Application info for picker
@@ -180,6 +337,10 @@ export async function patchConfig(patch: ConfigPatch): Promise<Config> {
   return apiClient.patch<ConfigPatch, Config>('/api/v1/config', patch);
 }
 
+export async function getTuiTheme(appearance: "light" | "dark"): Promise<TuiTheme> {
+  return apiClient.get<TuiTheme>(`/api/v1/config/theme?appearance=${appearance}`);
+}
+
 export async function listApplications(): Promise<{ apps: AppInfo[]; platform: string }> {
   return apiClient.get<ApplicationsResponse>('/api/v1/config/applications');
 }
@@ -28,11 +28,28 @@ interface BranchesDetailResponse {
   current: string;
 }
 
+/** `git log %G?` verdict; omitted for commits that were not checked */
+export type SignatureStatus = 'good' | 'unknown' | 'bad' | 'expired' | 'revoked' | 'unverified';
+
+/** Task / chat resolved from a commit's `Grove-Task` / `Grove-Chat` trailers */
+export interface CommitLinks {
+  task_id?: string;
+  /** Absent when the task no longer exists */
+  task_name?: string;
+  task_archived: boolean;
+  chat_id?: string;
+  chat_title?: string;
+}
+
 export interface RepoCommitEntry {
   hash: string;
   message: string;
   author: string;
   time_ago: string;
+  /** Commit time (RFC 3339) */
+  time: string;
+  signature?: SignatureStatus;
+  links?: CommitLinks;
 }
 
 interface RepoCommitsResponse {
@@ -62,6 +79,33 @@ interface CommitRequest {
   message: string;
 }
 
+export interface StashEntry {
+  index: number;
+  reference: string;
+  hash: string;
+  branch: string | null;
+  message: string;
+  created_at: number;
+  /** Task whose branch the stash was created on */
+  task_id?: string;
+  task_name?: string;
+}
+
+interface StashListResponse {
+  stashes: StashEntry[];
+}
+
+interface StashShowResponse {
+  index: number;
+  patch: string;
+}
+
+interface StashActionRequest {
+  pop?: boolean;
+  task_id?: string;
+  hash?: string;
+}
+
 // ============================================================================
 // API Functions
 // ============================================================================
@@ -175,3 +219,43 @@ export async function gitCommit(projectId: string, message: string): Promise<Git
     { message }
   );
 }
+
+/**
+ * List stashes of the repository and all task worktrees
+ */
+export async function listStashes(projectId: string): Promise<StashEntry[]> {
+  const res = await apiClient.get<StashListResponse>(`/api/v1/projects/${projectId}/git/stash/list`);
+  return res.stashes;
+}
+
+/**
+ * Get a stash's stat summary and patch
+ */
+export async function showStash(projectId: string, index: number): Promise<string> {
+  const res = await apiClient.get<StashShowResponse>(`/api/v1/projects/${projectId}/git/stash/${index}`);
+  return res.patch;
+}
+
+/**
+ * Apply (or pop) a stash in the main repository, or in a task worktree when `taskId` is set
+ */
+export async function applyStash(
+  projectId: string,
+  stash: StashEntry,
+  options?: { pop?: boolean; taskId?: string }
+): Promise<GitOpResponse> {
+  return apiClient.post<StashActionRequest, GitOpResponse>(
+    `/api/v1/projects/${projectId}/git/stash/${stash.index}/apply`,
+    { pop: options?.pop, task_id: options?.taskId, hash: stash.hash }
+  );
+}
+
+/**
+ * Drop a stash
+ */
+export async function dropStash(projectId: string, stash: StashEntry): Promise<GitOpResponse> {
+  return apiClient.post<StashActionRequest, GitOpResponse>(
+    `/api/v1/projects/${projectId}/git/stash/${stash.index}/drop`,
+    { hash: stash.hash }
+  );
+}
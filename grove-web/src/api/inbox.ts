@@ -0,0 +1,38 @@
+// Inbox API client
+
+import { apiClient } from './client';
+
+// ============================================================================
+// Types
+// ============================================================================
+
+interface InboxItemBase {
+  project_id: string;
+  project_name: string;
+  project_path: string;
+  task_id: string;
+  task_name: string;
+  /** RFC 3339, absent for conflicts and live permission requests. */
+  timestamp?: string;
+}
+
+export type InboxItem = InboxItemBase & (
+  | { kind: 'permission'; chat_id: string; chat_title: string | null; description: string }
+  | { kind: 'review_reply'; comment_id: number; from: string; excerpt: string }
+  | { kind: 'conflict' }
+  | { kind: 'merge_failed'; error: string }
+  | { kind: 'handback' }
+);
+
+interface InboxResponse {
+  items: InboxItem[];
+}
+
+// ============================================================================
+// API Functions
+// ============================================================================
+
+export async function getInbox(): Promise<InboxItem[]> {
+  const res = await apiClient.get<InboxResponse>('/api/v1/inbox');
+  return res.items;
+}
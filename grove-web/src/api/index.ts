@@ -2,8 +2,12 @@
 
 export type { ApiError } from './client';
 
-export { getConfig, patchConfig, listApplications, getAppIconUrl, previewHookSound } from './config';
-export type { AppInfo, CustomAgentServer, CustomThemeConfig } from './config';
+export { getConfig, patchConfig, getTuiTheme, listApplications, getAppIconUrl, previewHookSound } from './config';
+export type { AppInfo, AutoLinkMode, CustomAgentServer, CustomThemeConfig, ReviewConfig, SyncConfig, OrganizationConfig, ClipboardConfig, SigningConfig, RiskConfig, DisplayConfig, StatusPalette, OpenWithApp, QuietHours, TuiTheme } from './config';
+export { getOrgStatus, publishToTeam, listTeamTasks, getTeamComments, addTeamComment } from './org';
+export type { OrgStatus, SharedTask, TeamTasks } from './org';
+export { getSyncStatus, runSync } from './sync';
+export type { SyncStatus } from './sync';
 
 export {
   listCustomAgents,
@@ -19,37 +23,108 @@ export type {
 
 export { checkAllDependencies, checkCommands } from './env';
 
-export { listProjects, getProject, addProject, deleteProject, renameProject, getProjectStats, getBranches, getRemotes, openIDE, openTerminal, initGitRepo, createNewProject, cloneProject, listResources, uploadResource, deleteResource, previewResource, resourceDownloadUrl, openResourceFile, getInstructions, updateInstructions, getMemory, updateMemory, listResourceWorkdirs, addResourceWorkdir, deleteResourceWorkdir, openResourceWorkdir, createResourceFolder, moveResource, createResourceLink, updateResourceLink } from './projects';
+export { getLogs } from './logs';
+export type { LogEntry, LogLevel, LogsResponse } from './logs';
+
+export { listProjects, getProject, addProject, deleteProject, renameProject, setDefaultTarget, setProjectPinned, setCommitSigning, setCommitTrailers, getGitIdentity, updateGitIdentity, getProjectStats, getProjectUsage, getProjectHealth, getBranches, getRemotes, openIDE, openTerminal, initGitRepo, createNewProject, cloneProject, listResources, uploadResource, deleteResource, previewResource, resourceDownloadUrl, openResourceFile, getInstructions, updateInstructions, getDiffFilters, updateDiffFilters, getMergeGate, updateMergeGate, getGlobalPreamble, updateGlobalPreamble, getProjectPreamble, updateProjectPreamble, getChatDefaults, updateChatDefaults, getBenchSettings, updateBenchSettings, getSecurityScanSettings, updateSecurityScanSettings, getTaskSummarySettings, updateTaskSummarySettings, getForgeSettings, updateForgeSettings, getTaskTemplates, updateTaskTemplates, getLifecycleHooks, updateLifecycleHooks, getFileGuard, updateFileGuard, getMemory, updateMemory, listProjectNotes, getProjectNote, createProjectNote, updateProjectNote, deleteProjectNote, listResourceWorkdirs, addResourceWorkdir, deleteResourceWorkdir, openResourceWorkdir, createResourceFolder, moveResource, createResourceLink, updateResourceLink } from './projects';
 export type {
   ProjectListItem,
   ProjectResponse,
   ProjectStatsResponse,
+  ProjectUsageResponse,
+  ProjectHealthResponse,
   ResourceFile,
   WorkDirectoryEntry,
+  ProjectNote,
+  ProjectNoteDetail,
+  BranchInfo,
+  GitIdentity,
+  GitIdentityResponse,
+  MergeGate,
+  InstructionPreamble,
+  AgentChatDefaults,
+  ChatDefaults,
+  DiffFilters,
+  BenchSettings,
+  ScanSeverity,
+  SecurityScanner,
+  SecurityScanSettings,
+  TaskSummarySettings,
+  ForgeKind,
+  ForgeSettings,
+  ForgeSettingsResponse,
+  TaskTemplate,
+  HookEvent,
+  LifecycleHook,
+  LifecycleHookSettings,
+  FileGuardSettings,
 } from './projects';
 
 export {
   listTasks,
+  listAllTasks,
+  listTaskViews,
+  saveTaskView,
+  deleteTaskView,
   getTask,
   createTask,
   renameTask,
+  handbackTask,
+  setTaskPinned,
+  getTaskCopyTargets,
+  openTaskWith,
   activateTask,
   lookupSymbol,
+  getFileOutline,
   reindexSymbols,
   archiveTask,
   recoverTask,
   deleteTask,
   getNotes,
   updateNotes,
+  listNoteRevisions,
+  getNoteRevision,
+  diffNoteRevision,
+  restoreNoteRevision,
   syncTask,
   commitTask,
+  getCheckpoints,
+  updateCheckpoints,
+  squashCheckpoints,
+  dropCheckpoints,
+  getTaskGitIdentity,
+  updateTaskGitIdentity,
+  getTaskScope,
+  getTaskOwners,
+  getTaskMergeGate,
+  getTaskRisk,
+  getTaskRisks,
+  getTaskTestChanges,
+  getTaskBench,
+  runTaskBench,
+  getTaskSecurityScan,
+  runTaskSecurityScan,
+  getTaskFileGuard,
+  updateTaskScope,
+  getTaskSparse,
+  updateTaskSparse,
   mergeTask,
   resetTask,
   rebaseToTask,
+  getTargetHistory,
+  listShareLinks,
+  createShareLink,
+  revokeShareLink,
+  listUnmanagedWorktrees,
+  adoptWorktree,
   getDiff,
   getCommits,
   getReviewComments,
   getTaskStats,
+  getTaskUsage,
+  getChatUsage,
+  getTerminalScreen,
+  sendSessionKeys,
   getTaskFiles,
   getTaskDirEntries,
   getFileContent,
@@ -58,6 +133,10 @@ export {
   createDirectory,
   deleteFileOrDir,
   moveFileOrDir,
+  uploadTaskFiles,
+  taskFileDownloadUrl,
+  getFileHistory,
+  getFileHistoryPatch,
   listChats,
   createChat,
   updateChatTitle,
@@ -74,6 +153,16 @@ export {
   forkChat,
   uploadChatAttachment,
   getChatHistory,
+  exportChatTranscript,
+  getChatTimeline,
+  listChatPins,
+  addChatPin,
+  deleteChatPin,
+  listNativeSessions,
+  importNativeSession,
+  listTurnSnapshots,
+  getTurnDiff,
+  revertTurn,
   takeControl,
   readFile,
   listArtifacts,
@@ -93,16 +182,59 @@ export {
 } from './tasks';
 export type {
   TaskResponse,
+  TaskSparse,
+  AgentState,
+  TaskAssignee,
+  TaskSort,
+  TaskView,
+  TaskViewFilter,
+  ProjectTaskResponse,
+  AllTasksFilter,
+  HandbackResponse,
+  TaskCopyTargets,
+  CheckpointCommit,
+  CheckpointsResponse,
+  TurnSnapshot,
+  TurnDiff,
+  TurnManifest,
+  TranscriptExport,
+  ToolSpan,
+  TurnTimeline,
+  ChatPin,
+  ChatPinKind,
+  NativeSession,
+  NativeSessionSource,
+  ImportChatResponse,
+  UpdateCheckpointsRequest,
   SymbolCandidate,
+  FileOutline,
+  NoteRevision,
+  NoteSource,
   DiffResponse,
   CommitsResponse,
   ReviewCommentEntry,
+  ReviewSubmissionEntry,
+  ReviewVerdict,
+  ReviewReaction,
   TaskStatsResponse,
+  TaskTime,
+  UsageTotals,
+  TurnUsageRow,
+  ChatUsageResponse,
+  TaskUsageResponse,
+  TerminalScreenResponse,
+  SessionKeysRequest,
   ChatSessionResponse,
   ArtifactFile,
   ArtifactsResponse,
   ArtifactWorkDirectoryEntry,
   DirEntry,
+  FileVersion,
+  FileConflict,
+  FsUploadedFile,
+  FileHistoryEntry,
+  FileHistoryResponse,
+  FilePatchResponse,
   MentionAgent,
   MentionOutgoing,
   MentionPendingReply,
@@ -111,6 +243,21 @@ export type {
   GraphNodeResponse,
   GraphEdgeResponse,
   GraphPendingMessageInfo,
+  TargetChange,
+  ShareLink,
+  UnmanagedWorktree,
+  OwnersSummary,
+  OwnershipWarning,
+  MergeBlocker,
+  RiskAssessment,
+  TestChanges,
+  BenchRun,
+  BenchComparison,
+  ScanFinding,
+  ScanReport,
+  SecurityScanStatus,
+  FileGuardStatus,
+  GuardViolation,
 } from './tasks';
 
 export {
@@ -125,11 +272,17 @@ export {
   createBranch,
   deleteBranch,
   renameBranch,
+  listStashes,
+  showStash,
+  applyStash,
+  dropStash,
 } from './git';
 export type {
   RepoStatusResponse,
   BranchDetailInfo,
   RepoCommitEntry,
+  CommitLinks,
+  StashEntry,
 } from './git';
 
 
@@ -181,7 +334,7 @@ export type {
   InstalledSkill,
 } from './skills';
 
-export { renderD2 } from './render';
+export { renderD2, renderMarkdown } from './render';
 export { fetchUrlMetadata } from './url';
 export type { UrlMetadata } from './url';
 export type { RenderD2Error } from './render';
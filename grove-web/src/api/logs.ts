@@ -0,0 +1,25 @@
+// Logs API
+
+import { apiClient } from './client';
+
+export type LogLevel = 'error' | 'warn' | 'info' | 'debug' | 'trace';
+
+export interface LogEntry {
+  timestamp: string;
+  level: 'ERROR' | 'WARN' | 'INFO' | 'DEBUG' | 'TRACE';
+  target: string;
+  message: string;
+}
+
+export interface LogsResponse {
+  entries: LogEntry[];
+  log_dir: string;
+}
+
+export async function getLogs(options: { limit?: number; level?: LogLevel } = {}): Promise<LogsResponse> {
+  const params = new URLSearchParams();
+  if (options.limit !== undefined) params.set('limit', String(options.limit));
+  if (options.level) params.set('level', options.level);
+  const query = params.toString();
+  return apiClient.get<LogsResponse>(`/api/v1/logs${query ? `?${query}` : ''}`);
+}
@@ -0,0 +1,74 @@
+// Organization mode API client
+
+import { apiClient } from './client';
+import type { ReviewCommentEntry } from './tasks';
+
+/** Organization mode state and the outcome of the last publish */
+export interface OrgStatus {
+  /** Whether a team server is configured */
+  configured: boolean;
+  /** Name the team server knows this machine's user by (after the first publish) */
+  member: string | null;
+  /** RFC 3339 */
+  last_run_at: string | null;
+  last_error: string | null;
+  published: number;
+}
+
+/** A teammate's task as published to the team server */
+export interface SharedTask {
+  owner: string;
+  task: {
+    id: string;
+    name: string;
+    branch: string;
+    target: string;
+    created_at: string;
+    updated_at: string;
+    status: string;
+    code_additions: number;
+    code_deletions: number;
+    files_changed: number;
+  };
+  notes: string;
+}
+
+export interface TeamTasks {
+  member: string;
+  tasks: SharedTask[];
+}
+
+export async function getOrgStatus(): Promise<OrgStatus> {
+  return apiClient.get<OrgStatus>('/api/v1/org/status');
+}
+
+/** Publish local tasks to the team server now */
+export async function publishToTeam(): Promise<OrgStatus> {
+  return apiClient.post<Record<string, never>, OrgStatus>('/api/v1/org/publish', {});
+}
+
+export async function listTeamTasks(projectId: string): Promise<TeamTasks> {
+  return apiClient.get<TeamTasks>(`/api/v1/projects/${projectId}/team-tasks`);
+}
+
+function teamCommentsPath(projectId: string, owner: string, taskId: string): string {
+  return `/api/v1/projects/${projectId}/team-tasks/${encodeURIComponent(owner)}/${encodeURIComponent(taskId)}/comments`;
+}
+
+export async function getTeamComments(projectId: string, owner: string, taskId: string): Promise<ReviewCommentEntry[]> {
+  return apiClient.get<ReviewCommentEntry[]>(teamCommentsPath(projectId, owner, taskId));
+}
+
+/** Comment on a teammate's task, or reply to `replyTo` */
+export async function addTeamComment(
+  projectId: string,
+  owner: string,
+  taskId: string,
+  content: string,
+  replyTo?: number,
+): Promise<ReviewCommentEntry[]> {
+  return apiClient.post<{ content: string; reply_to?: number }, ReviewCommentEntry[]>(
+    teamCommentsPath(projectId, owner, taskId),
+    { content, reply_to: replyTo },
+  );
+}
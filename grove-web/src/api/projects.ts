@@ -3,7 +3,7 @@
 import { apiClient } from './client';
 import { createStudioFileApi } from './studio-factory';
 import type { StudioFileEntry, StudioWorkDirEntry } from './studio-types';
-import type { TaskResponse } from './tasks';
+import type { TaskResponse, UsageTotals } from './tasks';
 
 // ============================================================================
 // Types
@@ -21,6 +21,11 @@ export interface ProjectListItem {
   exists: boolean;
   /** Project type: "repo" or "studio" */
   project_type: string;
+  pinned?: boolean;
+  /** Per-project signing override; absent = follow the global setting */
+  commit_signing?: boolean;
+  /** Add `Grove-Task` / `Grove-Chat` trailers to commits Grove makes */
+  commit_trailers: boolean;
 }
 
 interface ProjectListResponse {
@@ -44,6 +49,9 @@ export interface ProjectResponse {
   exists: boolean;
   /** Project type: "repo" or "studio" */
   project_type: string;
+  /** Default target branch for new tasks, when set */
+  default_target?: string;
+  pinned?: boolean;
 }
 
 interface AddProjectRequest {
@@ -58,6 +66,18 @@ interface NewProjectRequest {
   project_type?: string;
 }
 
+export interface ProjectHealthResponse {
+  target_branch: string;
+  /** null when there is no origin/<target> ref */
+  target_behind_origin: number | null;
+  uncommitted_changes: number;
+  stash_count: number;
+  stale_worktrees: string[];
+  broken_links: string[];
+  /** Human-readable summary; empty = healthy */
+  warnings: string[];
+}
+
 export interface ProjectStatsResponse {
   total_tasks: number;
   live_tasks: number;
@@ -67,9 +87,27 @@ export interface ProjectStatsResponse {
   weekly_activity: number[];
 }
 
-interface BranchInfo {
+export interface ProjectUsageResponse {
+  totals: UsageTotals;
+  tasks: {
+    task_id: string;
+    /** Null when the task was deleted */
+    task_name: string | null;
+    totals: UsageTotals;
+  }[];
+}
+
+export interface BranchInfo {
   name: string;
   is_current: boolean;
+  /** Commits ahead of the base branch */
+  ahead?: number;
+  /** Commits behind the base branch */
+  behind?: number;
+  /** Last commit time (unix seconds) */
+  last_commit_at?: number;
+  /** Worktree path if the branch is checked out somewhere */
+  worktree?: string;
 }
 
 interface BranchesResponse {
@@ -149,6 +187,34 @@ export async function renameProject(id: string, name: string): Promise<ProjectRe
   return apiClient.patch<{ name: string }, ProjectResponse>(`/api/v1/projects/${id}`, { name });
 }
 
+/**
+ * Set (or clear with null) the project's default target branch for new tasks
+ */
+export async function setDefaultTarget(id: string, target: string | null): Promise<void> {
+  return apiClient.put<{ target: string | null }, void>(`/api/v1/projects/${id}/default-target`, { target });
+}
+
+/**
+ * Pin or unpin a project (pinned projects sort first)
+ */
+export async function setProjectPinned(id: string, pinned: boolean): Promise<ProjectResponse> {
+  return apiClient.put<{ pinned: boolean }, ProjectResponse>(`/api/v1/projects/${id}/pin`, { pinned });
+}
+
+/**
+ * Override commit signing for this project (null = follow the global setting)
+ */
+export async function setCommitSigning(id: string, enabled: boolean | null): Promise<ProjectResponse> {
+  return apiClient.put<{ enabled: boolean | null }, ProjectResponse>(`/api/v1/projects/${id}/commit-signing`, { enabled });
+}
+
+/**
+ * Turn `Grove-Task` / `Grove-Chat` commit trailers on or off for this project
+ */
+export async function setCommitTrailers(id: string, enabled: boolean): Promise<ProjectResponse> {
+  return apiClient.put<{ enabled: boolean }, ProjectResponse>(`/api/v1/projects/${id}/commit-trailers`, { enabled });
+}
+
 /**
  * Get project statistics
  */
@@ -156,13 +222,30 @@ export async function getProjectStats(id: string): Promise<ProjectStatsResponse>
   return apiClient.get<ProjectStatsResponse>(`/api/v1/projects/${id}/stats`);
 }
 
+/**
+ * Get cumulative agent usage (tokens, cost) of a project, per task
+ */
+export async function getProjectUsage(id: string): Promise<ProjectUsageResponse> {
+  return apiClient.get<ProjectUsageResponse>(`/api/v1/projects/${id}/usage`);
+}
+
+/**
+ * Get repo health checks (target behind origin, dirty main repo, stashes,
+ * stale worktrees, broken AutoLink symlinks) for the project header
+ */
+export async function getProjectHealth(id: string): Promise<ProjectHealthResponse> {
+  return apiClient.get<ProjectHealthResponse>(`/api/v1/projects/${id}/health`);
+}
+
 /**
  * Get branches for a project
  * @param id - Project ID
  * @param remote - Remote name ("local", "origin", "upstream", etc.). Default: "local"
+ * @param base - Base branch for ahead/behind counts. Default: the current branch
  */
-export async function getBranches(id: string, remote: string = 'local'): Promise<BranchesResponse> {
-  return apiClient.get<BranchesResponse>(`/api/v1/projects/${id}/branches?remote=${encodeURIComponent(remote)}`);
+export async function getBranches(id: string, remote: string = 'local', base?: string): Promise<BranchesResponse> {
+  const baseParam = base ? `&base=${encodeURIComponent(base)}` : '';
+  return apiClient.get<BranchesResponse>(`/api/v1/projects/${id}/branches?remote=${encodeURIComponent(remote)}${baseParam}`);
 }
 
 interface RemotesResponse {
@@ -291,6 +374,300 @@ export async function updateInstructions(id: string, content: string): Promise<{
   return apiClient.put<{ content: string }, { content: string }>(`/api/v1/projects/${id}/instructions`, { content });
 }
 
+/** Author / committer / trailer override for commits Grove makes */
+export interface GitIdentity {
+  author_name?: string | null;
+  author_email?: string | null;
+  committer_name?: string | null;
+  committer_email?: string | null;
+  /** e.g. `Co-authored-by: Me <me@example.com>` */
+  trailer?: string | null;
+}
+
+export interface GitIdentityResponse {
+  /** Override stored at this level; unset fields fall back */
+  identity: GitIdentity;
+  /** What commits actually use (task fields over project fields) */
+  effective: GitIdentity;
+}
+
+export async function getGitIdentity(id: string): Promise<GitIdentityResponse> {
+  return apiClient.get<GitIdentityResponse>(`/api/v1/projects/${id}/git-identity`);
+}
+
+/** Replaces the whole project override; empty fields clear it */
+export async function updateGitIdentity(id: string, identity: GitIdentity): Promise<GitIdentityResponse> {
+  return apiClient.put<GitIdentity, GitIdentityResponse>(`/api/v1/projects/${id}/git-identity`, identity);
+}
+
+/** Project-level globs collapsed by default in review diffs */
+/** Project diff settings: collapse globs and test-file globs */
+export interface DiffFilters {
+  collapsed_patterns: string[];
+  /** Globs that identify test files (empty = the `[risk] test_paths` defaults) */
+  test_patterns?: string[];
+}
+
+export async function getDiffFilters(id: string): Promise<DiffFilters> {
+  return apiClient.get<DiffFilters>(`/api/v1/projects/${id}/diff-filters`);
+}
+
+/** Save the collapse globs; test globs are kept unless given */
+export async function updateDiffFilters(id: string, collapsedPatterns: string[], testPatterns?: string[]): Promise<DiffFilters> {
+  return apiClient.put<DiffFilters, DiffFilters>(
+    `/api/v1/projects/${id}/diff-filters`,
+    { collapsed_patterns: collapsedPatterns, test_patterns: testPatterns },
+  );
+}
+
+/** Benchmark comparison settings: command run on target and task HEAD before merge */
+export interface BenchSettings {
+  command: string;
+  format: 'auto' | 'criterion' | 'hyperfine';
+  threshold_percent: number;
+  timeout_secs: number;
+}
+
+export async function getBenchSettings(id: string): Promise<BenchSettings> {
+  return apiClient.get<BenchSettings>(`/api/v1/projects/${id}/bench`);
+}
+
+export async function updateBenchSettings(id: string, settings: BenchSettings): Promise<BenchSettings> {
+  return apiClient.put<BenchSettings, BenchSettings>(`/api/v1/projects/${id}/bench`, settings);
+}
+
+/** Guard on files added by a task: size limit, blocked paths / extensions, disallowed licenses */
+export interface FileGuardSettings {
+  enabled: boolean;
+  /** Block the merge on violations (otherwise warn) */
+  block: boolean;
+  /** Per-file size limit in KB (0 = no limit) */
+  max_file_kb: number;
+  blocked_paths: string[];
+  blocked_extensions: string[];
+  /** SPDX prefixes, e.g. "GPL" matches GPL-3.0-only but not LGPL-2.1 */
+  disallowed_licenses: string[];
+}
+
+export async function getFileGuard(id: string): Promise<FileGuardSettings> {
+  return apiClient.get<FileGuardSettings>(`/api/v1/projects/${id}/file-guard`);
+}
+
+export async function updateFileGuard(id: string, settings: FileGuardSettings): Promise<FileGuardSettings> {
+  return apiClient.put<FileGuardSettings, FileGuardSettings>(`/api/v1/projects/${id}/file-guard`, settings);
+}
+
+export type ScanSeverity = 'info' | 'low' | 'moderate' | 'high' | 'critical';
+
+/** A security audit command, e.g. `cargo audit --json` or `npm audit --json` */
+export interface SecurityScanner {
+  name: string;
+  command: string;
+  format: 'auto' | 'cargo_audit' | 'npm_audit';
+}
+
+/** Security scan settings: scanners run in the task worktree before merge */
+export interface SecurityScanSettings {
+  scanners: SecurityScanner[];
+  run_before_merge: boolean;
+  /** Block the merge on findings at or above block_severity */
+  block: boolean;
+  block_severity: ScanSeverity;
+  warn_severity: ScanSeverity;
+  timeout_secs: number;
+}
+
+export async function getSecurityScanSettings(id: string): Promise<SecurityScanSettings> {
+  return apiClient.get<SecurityScanSettings>(`/api/v1/projects/${id}/security-scan`);
+}
+
+export async function updateSecurityScanSettings(
+  id: string,
+  settings: SecurityScanSettings,
+): Promise<SecurityScanSettings> {
+  return apiClient.put<SecurityScanSettings, SecurityScanSettings>(
+    `/api/v1/projects/${id}/security-scan`,
+    settings,
+  );
+}
+
+/** Automatic "What was done" summaries appended to task notes */
+export interface TaskSummarySettings {
+  enabled: boolean;
+  /** Turn stop reasons (snake_case, e.g. end_turn) that trigger a summary */
+  stop_reasons?: string[];
+  /** Summarize the whole task right before it is merged */
+  on_merge: boolean;
+  /** AI provider id or name; empty uses the built-in template */
+  provider?: string;
+  /** Keep summary blocks in the merge commit body */
+  include_in_merge_commit: boolean;
+}
+
+export async function getTaskSummarySettings(id: string): Promise<TaskSummarySettings> {
+  return apiClient.get<TaskSummarySettings>(`/api/v1/projects/${id}/task-summary`);
+}
+
+export async function updateTaskSummarySettings(
+  id: string,
+  settings: TaskSummarySettings,
+): Promise<TaskSummarySettings> {
+  return apiClient.put<TaskSummarySettings, TaskSummarySettings>(
+    `/api/v1/projects/${id}/task-summary`,
+    settings,
+  );
+}
+
+export type ForgeKind = "github" | "gitlab" | "gitea";
+
+/** Where the project's pull / merge requests are opened */
+export interface ForgeSettings {
+  /** Forge type; unset detects it from the origin host */
+  kind?: ForgeKind | null;
+  /** REST API root for self-hosted instances; unset derives it from the host */
+  api_url?: string | null;
+}
+
+export interface ForgeSettingsResponse extends ForgeSettings {
+  /** Forge guessed from the origin host */
+  detected: ForgeKind | null;
+  /** A token is configured in config.toml for the effective forge */
+  token_configured: boolean;
+}
+
+export async function getForgeSettings(id: string): Promise<ForgeSettingsResponse> {
+  return apiClient.get<ForgeSettingsResponse>(`/api/v1/projects/${id}/forge`);
+}
+
+export async function updateForgeSettings(
+  id: string,
+  settings: ForgeSettings,
+): Promise<ForgeSettingsResponse> {
+  return apiClient.put<ForgeSettings, ForgeSettingsResponse>(
+    `/api/v1/projects/${id}/forge`,
+    settings,
+  );
+}
+
+/** Named preset for new tasks: base branch, notes skeleton, agent, env and autolink patterns */
+export interface TaskTemplate {
+  name: string;
+  /** Target branch; unset uses the project default */
+  target?: string | null;
+  /** Notes skeleton; `{branch}`, `{task_id}` and `{target}` are expanded */
+  notes?: string;
+  /** Default agent for the task's terminal layout and new chats */
+  agent?: string | null;
+  /** Environment variables for the task's sessions and chats */
+  env?: Record<string, string>;
+  /** Extra AutoLink patterns on top of the global ones */
+  autolink_patterns?: string[];
+}
+
+export async function getTaskTemplates(id: string): Promise<TaskTemplate[]> {
+  const res = await apiClient.get<{ templates: TaskTemplate[] }>(`/api/v1/projects/${id}/templates`);
+  return res.templates;
+}
+
+export async function updateTaskTemplates(id: string, templates: TaskTemplate[]): Promise<TaskTemplate[]> {
+  const res = await apiClient.put<{ templates: TaskTemplate[] }, { templates: TaskTemplate[] }>(
+    `/api/v1/projects/${id}/templates`,
+    { templates },
+  );
+  return res.templates;
+}
+
+export type HookEvent = "post_create" | "pre_merge" | "post_merge";
+
+/** A configured lifecycle hook command, run in the task worktree */
+export interface LifecycleHook {
+  event: HookEvent;
+  command: string;
+  /** Abort task creation / the merge on failure; unset: only pre-merge blocks */
+  blocking?: boolean | null;
+}
+
+/** Per-project task lifecycle hooks (plus the repo's `.grove/hooks/` scripts) */
+export interface LifecycleHookSettings {
+  hooks: LifecycleHook[];
+  /** Also run `.grove/hooks/<event>[.sh]` from the worktree */
+  run_repo_scripts: boolean;
+  /** Per-hook timeout in seconds */
+  timeout_secs: number;
+}
+
+export async function getLifecycleHooks(id: string): Promise<LifecycleHookSettings> {
+  return apiClient.get<LifecycleHookSettings>(`/api/v1/projects/${id}/lifecycle-hooks`);
+}
+
+export async function updateLifecycleHooks(
+  id: string,
+  settings: LifecycleHookSettings,
+): Promise<LifecycleHookSettings> {
+  return apiClient.put<LifecycleHookSettings, LifecycleHookSettings>(
+    `/api/v1/projects/${id}/lifecycle-hooks`,
+    settings,
+  );
+}
+
+/** Project merge gate: block merges while review comments are open or required reviewers haven't approved */
+export interface MergeGate {
+  require_resolved: boolean;
+  required_reviewers?: string[];
+}
+
+export async function getMergeGate(id: string): Promise<MergeGate> {
+  return apiClient.get<MergeGate>(`/api/v1/projects/${id}/merge-gate`);
+}
+
+export async function updateMergeGate(id: string, gate: MergeGate): Promise<MergeGate> {
+  return apiClient.put<MergeGate, MergeGate>(`/api/v1/projects/${id}/merge-gate`, gate);
+}
+
+/** Instruction preamble prepended to the first prompt of each new chat session */
+export interface InstructionPreamble {
+  /** Applies to every agent */
+  preamble: string;
+  /** Agent id → preamble for that agent only */
+  agents?: Record<string, string>;
+}
+
+export async function getGlobalPreamble(): Promise<InstructionPreamble> {
+  return apiClient.get<InstructionPreamble>('/api/v1/preamble');
+}
+
+export async function updateGlobalPreamble(preamble: InstructionPreamble): Promise<InstructionPreamble> {
+  return apiClient.put<InstructionPreamble, InstructionPreamble>('/api/v1/preamble', preamble);
+}
+
+export async function getProjectPreamble(id: string): Promise<InstructionPreamble> {
+  return apiClient.get<InstructionPreamble>(`/api/v1/projects/${id}/preamble`);
+}
+
+export async function updateProjectPreamble(id: string, preamble: InstructionPreamble): Promise<InstructionPreamble> {
+  return apiClient.put<InstructionPreamble, InstructionPreamble>(`/api/v1/projects/${id}/preamble`, preamble);
+}
+
+/** Per-agent mode/model applied to new chats in a project (pinned wins over last used) */
+export interface AgentChatDefaults {
+  last_mode?: string;
+  last_model?: string;
+  pinned_mode?: string;
+  pinned_model?: string;
+}
+
+export interface ChatDefaults {
+  agents: Record<string, AgentChatDefaults>;
+}
+
+export async function getChatDefaults(id: string): Promise<ChatDefaults> {
+  return apiClient.get<ChatDefaults>(`/api/v1/projects/${id}/chat-defaults`);
+}
+
+export async function updateChatDefaults(id: string, defaults: ChatDefaults): Promise<ChatDefaults> {
+  return apiClient.put<ChatDefaults, ChatDefaults>(`/api/v1/projects/${id}/chat-defaults`, defaults);
+}
+
 export async function getMemory(id: string): Promise<{ content: string }> {
   return apiClient.get<{ content: string }>(`/api/v1/projects/${id}/memory`);
 }
@@ -299,6 +676,50 @@ export async function updateMemory(id: string, content: string): Promise<{ conte
   return apiClient.put<{ content: string }, { content: string }>(`/api/v1/projects/${id}/memory`, { content });
 }
 
+/** Project-level note shared by every task (architecture decisions, conventions, ...) */
+export interface ProjectNote {
+  slug: string;
+  title: string;
+  /** Attached as context to newly started chats */
+  include_in_chat: boolean;
+  updated_at: string;
+}
+
+export interface ProjectNoteDetail extends ProjectNote {
+  content: string;
+}
+
+export async function listProjectNotes(id: string): Promise<ProjectNote[]> {
+  const res = await apiClient.get<{ notes: ProjectNote[] }>(`/api/v1/projects/${id}/notes`);
+  return res.notes;
+}
+
+export async function getProjectNote(id: string, slug: string): Promise<ProjectNoteDetail> {
+  return apiClient.get<ProjectNoteDetail>(`/api/v1/projects/${id}/notes/${encodeURIComponent(slug)}`);
+}
+
+export async function createProjectNote(
+  id: string,
+  note: { title: string; content?: string; include_in_chat?: boolean },
+): Promise<ProjectNoteDetail> {
+  return apiClient.post<typeof note, ProjectNoteDetail>(`/api/v1/projects/${id}/notes`, note);
+}
+
+export async function updateProjectNote(
+  id: string,
+  slug: string,
+  patch: { title?: string; content?: string; include_in_chat?: boolean },
+): Promise<ProjectNoteDetail> {
+  return apiClient.put<typeof patch, ProjectNoteDetail>(
+    `/api/v1/projects/${id}/notes/${encodeURIComponent(slug)}`,
+    patch,
+  );
+}
+
+export async function deleteProjectNote(id: string, slug: string): Promise<void> {
+  return apiClient.delete(`/api/v1/projects/${id}/notes/${encodeURIComponent(slug)}`);
+}
+
 // ============================================================================
 // Folder picker (web fallback for native browse_folder)
 // ============================================================================
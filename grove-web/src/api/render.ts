@@ -27,3 +27,15 @@ export async function renderD2(source: string): Promise<string> {
     } as RenderD2Error;
   }
 }
+
+/**
+ * Render Markdown to sanitized HTML with the shared backend renderer
+ * (same output as the TUI notes view). Safe to inject as-is.
+ */
+export async function renderMarkdown(source: string): Promise<string> {
+  const result = await apiClient.post<{ source: string }, { html: string }>(
+    '/api/v1/render/markdown',
+    { source }
+  );
+  return result.html;
+}
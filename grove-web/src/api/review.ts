@@ -1,7 +1,7 @@
 // Review API client — Full diff data for diff review UI
 
 import { apiClient } from './client';
-import type { ReviewCommentsResponse } from './tasks';
+import type { ReviewCommentsResponse, ReviewVerdict, TestChanges } from './tasks';
 
 // ============================================================================
 // Types
@@ -12,6 +12,19 @@ export interface DiffLine {
   old_line: number | null;
   new_line: number | null;
   content: string;
+  /** Coverage of an inserted line from the task's uploaded report */
+  covered?: boolean;
+}
+
+/** Chat turn that most likely produced a hunk (working tree diffs only) */
+export interface HunkAttribution {
+  chat_id: string;
+  chat_title: string;
+  agent: string;
+  /** Turn start (unix seconds) */
+  turn_ts: number;
+  model?: string;
+  mode?: string;
 }
 
 export interface DiffHunk {
@@ -21,6 +34,7 @@ export interface DiffHunk {
   new_lines: number;
   header: string;
   lines: DiffLine[];
+  attribution?: HunkAttribution;
 }
 
 export interface DiffFile {
@@ -35,6 +49,27 @@ export interface DiffFile {
   is_unsupported?: boolean; // Diff not supported for this file type
   load_error?: boolean; // Diff failed to load
   is_untracked?: boolean; // File is untracked (never git-added)
+  is_generated?: boolean; // Generated/vendored file — collapsed by default
+  owners?: string[]; // CODEOWNERS owners of the file
+  dependencies?: DependencySummary; // Package-level summary of a lockfile change
+}
+
+export type DependencyChangeKind = 'added' | 'removed' | 'upgraded' | 'downgraded' | 'changed';
+
+export interface DependencyChange {
+  name: string;
+  kind: DependencyChangeKind;
+  old_versions: string[];
+  new_versions: string[];
+}
+
+/** Added / removed / updated packages of a changed lockfile */
+export interface DependencySummary {
+  ecosystem: string;
+  added: number;
+  removed: number;
+  updated: number;
+  changes: DependencyChange[];
 }
 
 export interface DiffStatFile {
@@ -43,12 +78,47 @@ export interface DiffStatFile {
   additions: number;
   deletions: number;
   is_binary: boolean;
+  /** Generated / vendored (gitattributes or project globs); still counted in totals */
+  collapsed: boolean;
+  /** CODEOWNERS owners of the file */
+  owners?: string[];
+}
+
+/** A ref the diff can be compared against */
+export interface DiffRefEntry {
+  /** Ref name to pass as from_ref / to_ref */
+  name: string;
+  kind: 'task' | 'branch' | 'tag' | 'remote';
+  /** Task name for task branches, otherwise the ref name */
+  label: string;
+}
+
+/** Full diff parse result (all files with hunks) */
+export interface DiffResult {
+  files: DiffFile[];
+  total_additions: number;
+  total_deletions: number;
+}
+
+/** Staged / unstaged sides of a task worktree's index */
+export interface IndexDiff {
+  /** index ↔ HEAD */
+  staged: DiffResult;
+  /** worktree ↔ index */
+  unstaged: DiffResult;
+  untracked: string[];
 }
 
 export interface DiffStatsResult {
   files: DiffStatFile[];
   total_additions: number;
   total_deletions: number;
+  /** Task path scope the list was limited to */
+  scope?: string;
+  /** Changed files hidden because they are outside the scope */
+  outside_scope?: number;
+  /** Source vs test files among the changes */
+  tests?: TestChanges;
 }
 
 // ============================================================================
@@ -61,15 +131,54 @@ export async function getDiffStats(
   taskId: string,
   fromRef?: string,
   toRef?: string,
+  /** Ignore the task's path scope */
+  full?: boolean,
 ): Promise<DiffStatsResult> {
   let url = `/api/v1/projects/${projectId}/tasks/${taskId}/diff`;
   const params: string[] = [];
   if (fromRef) params.push(`from_ref=${encodeURIComponent(fromRef)}`);
   if (toRef) params.push(`to_ref=${encodeURIComponent(toRef)}`);
+  if (full) params.push('full=true');
   if (params.length > 0) url += `?${params.join('&')}`;
   return apiClient.get<DiffStatsResult>(url);
 }
 
+/** Executable lines and how many are covered */
+export interface CoverageTotals {
+  lines: number;
+  covered: number;
+}
+
+/** Overall and changed-line coverage of a task's latest uploaded report */
+export interface CoverageSummary {
+  format: 'lcov' | 'cobertura';
+  uploaded_at: string;
+  totals: CoverageTotals;
+  percent: number | null;
+  previous_percent: number | null;
+  /** Change against the previous report, in percentage points */
+  delta: number | null;
+  patch: CoverageTotals;
+  patch_percent: number | null;
+  /** Changed files, least covered first */
+  files: { path: string; lines: number; covered: number; uncovered_lines: number[] }[];
+}
+
+/** Coverage summary of the task's latest report (null when none uploaded) */
+export async function getTaskCoverage(projectId: string, taskId: string): Promise<CoverageSummary | null> {
+  return apiClient.get<CoverageSummary | null>(`/api/v1/projects/${projectId}/tasks/${taskId}/coverage`);
+}
+
+/** Upload an lcov / Cobertura report produced by the task's test run */
+export async function uploadTaskCoverage(projectId: string, taskId: string, report: Blob): Promise<void> {
+  await apiClient.postBinary(`/api/v1/projects/${projectId}/tasks/${taskId}/coverage`, report, 'text/plain');
+}
+
+/** List refs (other task branches, branches, tags, remotes) the diff can be compared against */
+export async function getDiffRefs(projectId: string, taskId: string): Promise<{ refs: DiffRefEntry[] }> {
+  return apiClient.get<{ refs: DiffRefEntry[] }>(`/api/v1/projects/${projectId}/tasks/${taskId}/diff/refs`);
+}
+
 export async function getSingleFileDiff(
   projectId: string,
   taskId: string,
@@ -83,6 +192,105 @@ export async function getSingleFileDiff(
   return apiClient.get<DiffFile>(url);
 }
 
+/** Staged and unstaged diffs of the task worktree; hunk headers identify hunks to (un)stage */
+export async function getIndexDiff(projectId: string, taskId: string): Promise<IndexDiff> {
+  return apiClient.get<IndexDiff>(`/api/v1/projects/${projectId}/tasks/${taskId}/index`);
+}
+
+/** Stage a whole file, or only the given hunks (`DiffHunk.header` from `unstaged`) */
+export async function stageHunks(
+  projectId: string,
+  taskId: string,
+  path: string,
+  hunks?: string[],
+): Promise<IndexDiff> {
+  return apiClient.post<{ path: string; hunks?: string[] }, IndexDiff>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/index/stage`,
+    { path, hunks },
+  );
+}
+
+/** Unstage a whole file, or only the given hunks (`DiffHunk.header` from `staged`) */
+export async function unstageHunks(
+  projectId: string,
+  taskId: string,
+  path: string,
+  hunks?: string[],
+): Promise<IndexDiff> {
+  return apiClient.post<{ path: string; hunks?: string[] }, IndexDiff>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/index/unstage`,
+    { path, hunks },
+  );
+}
+
+/** One conflicted region: `ours` is the target branch, `theirs` the task commit */
+export interface ConflictHunk {
+  start_line: number;
+  end_line: number;
+  ours_label: string;
+  theirs_label: string;
+  ours: string;
+  /** Common ancestor (diff3 markers only) */
+  base?: string;
+  theirs: string;
+}
+
+export interface ConflictFile {
+  path: string;
+  /** Empty for binary files and delete / modify conflicts */
+  hunks: ConflictHunk[];
+  /** Worktree content with markers, for manual editing */
+  content?: string;
+}
+
+/** State of a task's stopped rebase */
+export interface RebaseConflicts {
+  in_progress: boolean;
+  step?: number;
+  total?: number;
+  /** `<short hash> <subject>` of the commit being applied */
+  commit?: string;
+  files: ConflictFile[];
+}
+
+export type ConflictResolution =
+  | { resolution: 'ours' }
+  | { resolution: 'theirs' }
+  | { resolution: 'manual'; content: string };
+
+/** Window event fired when a sync stops on conflicts, so open conflict views reload */
+export const REBASE_CONFLICTS_EVENT = 'grove:rebase-conflicts';
+
+export async function getRebaseConflicts(projectId: string, taskId: string): Promise<RebaseConflicts> {
+  return apiClient.get<RebaseConflicts>(`/api/v1/projects/${projectId}/tasks/${taskId}/conflicts`);
+}
+
+/** Take one side in every hunk of a conflicted file, or write manual content, and stage it */
+export async function resolveConflict(
+  projectId: string,
+  taskId: string,
+  path: string,
+  resolution: ConflictResolution,
+): Promise<RebaseConflicts> {
+  return apiClient.post<{ path: string } & ConflictResolution, RebaseConflicts>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/conflicts/resolve`,
+    { path, ...resolution },
+  );
+}
+
+/** Continue the rebase; returns the next stop's conflicts or `in_progress: false` when done */
+export async function continueRebase(projectId: string, taskId: string): Promise<RebaseConflicts> {
+  return apiClient.post<undefined, RebaseConflicts>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/rebase/continue`,
+  );
+}
+
+export async function abortRebase(projectId: string, taskId: string): Promise<RebaseConflicts> {
+  return apiClient.post<undefined, RebaseConflicts>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/rebase/abort`,
+  );
+}
+
 /** Create a new review comment (inline, file, or project level) */
 async function createComment(
   projectId: string,
@@ -95,6 +303,7 @@ async function createComment(
     endLine?: number;
     content: string;
     author?: string;
+    draft?: boolean;
   },
 ): Promise<ReviewCommentsResponse> {
   return apiClient.post<Record<string, unknown>, ReviewCommentsResponse>(
@@ -107,17 +316,19 @@ async function createComment(
       end_line: params.endLine,
       content: params.content,
       author: params.author,
+      draft: params.draft,
     },
   );
 }
 
-/** Create an inline comment */
+/** Create an inline comment (`draft` adds it to the pending review) */
 export async function createInlineComment(
   projectId: string,
   taskId: string,
   anchor: { filePath: string; side: string; startLine: number; endLine: number },
   content: string,
   author?: string,
+  draft?: boolean,
 ): Promise<ReviewCommentsResponse> {
   return createComment(projectId, taskId, {
     comment_type: 'inline',
@@ -127,6 +338,7 @@ export async function createInlineComment(
     endLine: anchor.endLine,
     content,
     author,
+    draft,
   });
 }
 
@@ -137,12 +349,14 @@ export async function createFileComment(
   filePath: string,
   content: string,
   author?: string,
+  draft?: boolean,
 ): Promise<ReviewCommentsResponse> {
   return createComment(projectId, taskId, {
     comment_type: 'file',
     filePath,
     content,
     author,
+    draft,
   });
 }
 
@@ -152,14 +366,63 @@ export async function createProjectComment(
   taskId: string,
   content: string,
   author?: string,
+  draft?: boolean,
 ): Promise<ReviewCommentsResponse> {
   return createComment(projectId, taskId, {
     comment_type: 'project',
     content,
     author,
+    draft,
   });
 }
 
+export interface CrossReviewStarted {
+  chat_id: string;
+  title: string;
+  agent: string;
+  /** Agents of the chats that worked on the task */
+  author_agents: string[];
+}
+
+/** Start a reviewer chat with a different agent that posts its findings as review comments */
+export async function startCrossReview(
+  projectId: string,
+  taskId: string,
+  agent?: string,
+): Promise<CrossReviewStarted> {
+  return apiClient.post<Record<string, unknown>, CrossReviewStarted>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/cross-review`,
+    { agent },
+  );
+}
+
+/** Publish the pending review drafts with an overall verdict and notify the agent */
+export async function submitReview(
+  projectId: string,
+  taskId: string,
+  params: { verdict: ReviewVerdict; summary?: string; author?: string; chatId?: string },
+): Promise<ReviewCommentsResponse & { delivered_chat_id?: string }> {
+  return apiClient.post<Record<string, unknown>, ReviewCommentsResponse & { delivered_chat_id?: string }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/review/submit`,
+    {
+      verdict: params.verdict,
+      summary: params.summary,
+      author: params.author,
+      chat_id: params.chatId,
+    },
+  );
+}
+
+/** Discard all pending review drafts */
+export async function discardReviewDrafts(
+  projectId: string,
+  taskId: string,
+): Promise<ReviewCommentsResponse> {
+  return apiClient.delete<ReviewCommentsResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/review/drafts`,
+  );
+}
+
 /** Reply to a review comment (no status change) */
 export async function replyReviewComment(
   projectId: string,
@@ -210,6 +473,21 @@ export async function deleteComment(
   );
 }
 
+/** Toggle the current user's reaction (👍 👀 🚀) on a comment or one of its replies */
+export async function toggleReaction(
+  projectId: string,
+  taskId: string,
+  commentId: number,
+  emoji: string,
+  replyId?: number,
+  author?: string,
+): Promise<ReviewCommentsResponse> {
+  return apiClient.post<Record<string, unknown>, ReviewCommentsResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/review/comments/${commentId}/reactions`,
+    { emoji, reply_id: replyId, author },
+  );
+}
+
 /** Edit a review comment's content */
 export async function editComment(
   projectId: string,
@@ -0,0 +1,23 @@
+// Workspace sync API client
+
+import { apiClient } from './client';
+
+/** Outcome of the last sync run by the server */
+export interface SyncStatus {
+  /** RFC 3339 */
+  last_run_at: string | null;
+  last_error: string | null;
+  /** Records changed locally by the last merge */
+  imported: number;
+  /** Same-file conflicts resolved by the last merge */
+  conflicts: number;
+}
+
+export async function getSyncStatus(): Promise<SyncStatus> {
+  return apiClient.get<SyncStatus>('/api/v1/sync/status');
+}
+
+/** Sync with the configured remote now */
+export async function runSync(): Promise<SyncStatus> {
+  return apiClient.post<Record<string, never>, SyncStatus>('/api/v1/sync/run', {});
+}
@@ -3,6 +3,8 @@
 import { apiClient } from './client';
 import { createStudioFileApi } from './studio-factory';
 import type { StudioFileEntry, StudioWorkDirEntry } from './studio-types';
+import type { CommitLinks, SignatureStatus } from './git';
+import type { GitIdentity, GitIdentityResponse, ScanSeverity } from './projects';
 
 // ============================================================================
 // Types
@@ -12,6 +14,8 @@ export interface CommitResponse {
   hash: string;
   message: string;
   time_ago: string;
+  /** Commit time (RFC 3339) */
+  time: string;
 }
 
 export interface TaskResponse {
@@ -32,6 +36,23 @@ export interface TaskResponse {
   enableChat: boolean;
   created_by: string;
   is_local: boolean;
+  /** Inferred terminal agent state, live tmux tasks only */
+  agent_state?: AgentState;
+  /** Who currently owns the task; omitted when unassigned */
+  assignee?: TaskAssignee;
+  /** Pinned tasks sort ahead of the rest */
+  pinned?: boolean;
+  /** Non-blocking post-create hook failures (create responses only) */
+  warning?: string;
+}
+
+export type AgentState = "busy" | "waiting" | "idle";
+
+export type TaskAssignee = { kind: "human" } | { kind: "agent"; chat_id: string };
+
+export interface HandbackResponse extends TaskResponse {
+  /** Whether the handback prompt reached a live agent chat */
+  delivered: boolean;
 }
 
 interface TaskListResponse {
@@ -42,10 +63,40 @@ interface CreateTaskRequest {
   name: string;
   target?: string;
   notes?: string;
+  /** Monorepo path scope, e.g. `services/api` */
+  scope?: string;
+  /** Only check these paths out (sparse checkout); omitted = whole repo */
+  sparse_paths?: string[];
+  /** Project task template to create from */
+  template?: string;
 }
 
 type TaskFilter = 'active' | 'archived';
 
+/** Task list ordering (pinned tasks always stay on top) */
+export type TaskSort = 'activity' | 'diff_size' | 'status';
+
+export interface TaskViewFilter {
+  statuses?: string[];
+  agent_state?: 'busy' | 'waiting' | 'idle';
+  assignee?: 'human' | 'agent';
+  query?: string;
+  pinned_only?: boolean;
+}
+
+/** A saved, named filter + sort preset (per project) */
+export interface TaskView {
+  name: string;
+  sort: TaskSort;
+  filter: TaskViewFilter;
+}
+
+export interface TaskListOptions {
+  sort?: TaskSort;
+  /** Name of a saved view */
+  view?: string;
+}
+
 interface NotesResponse {
   content: string;
 }
@@ -64,6 +115,32 @@ interface GitOperationResponse {
   warning?: string;
 }
 
+/** Why the project's merge gate refuses a merge */
+export type MergeBlocker =
+  | { kind: 'open_comments'; count: number; comment_ids: number[] }
+  | { kind: 'reviewer_not_approved'; reviewer: string; verdict: 'approve' | 'request_changes' | null }
+  | { kind: 'security_findings'; count: number; severity: ScanSeverity }
+  | { kind: 'guarded_files'; violations: GuardViolation[] };
+
+/** An added file that violates the project's file guard */
+export type GuardViolation =
+  | { kind: 'blocked_path'; path: string; pattern: string }
+  | { kind: 'blocked_extension'; path: string; extension: string }
+  | { kind: 'too_large'; path: string; size: number; limit: number }
+  | { kind: 'license'; path: string; license: string };
+
+interface SyncResponse {
+  success: boolean;
+  message: string;
+  /** Files the rebase stopped on; resolve them in the conflict view */
+  conflicts?: string[];
+}
+
+interface MergeResponse extends GitOperationResponse {
+  /** Present when the merge gate refused the merge */
+  blockers?: MergeBlocker[];
+}
+
 interface DiffFileEntry {
   path: string;
   status: string; // "A" | "M" | "D" | "R"
@@ -81,6 +158,10 @@ interface CommitEntry {
   hash: string;
   message: string;
   time_ago: string;
+  /** Commit time (RFC 3339) */
+  time: string;
+  signature?: SignatureStatus;
+  links?: CommitLinks;
 }
 
 export interface CommitsResponse {
@@ -90,6 +171,12 @@ export interface CommitsResponse {
   skip_versions: number;
 }
 
+/** Emoji reaction and who left it (omitted when nobody reacted) */
+export interface ReviewReaction {
+  emoji: string;
+  authors: string[];
+}
+
 export interface CommentReply {
   id: number;
   content: string;
@@ -97,6 +184,7 @@ export interface CommentReply {
   model: string;
   role: string;
   timestamp: string;
+  reactions?: ReviewReaction[];
 }
 
 export type CommentType = 'inline' | 'file' | 'project';
@@ -115,6 +203,19 @@ export interface ReviewCommentEntry {
   timestamp: string;
   status: string; // "open" | "resolved" | "outdated"
   replies: CommentReply[];
+  draft?: boolean; // pending in the current user's unsubmitted review
+  reactions?: ReviewReaction[];
+}
+
+export type ReviewVerdict = 'approve' | 'request_changes' | 'comment';
+
+export interface ReviewSubmissionEntry {
+  id: number;
+  reviewer: string;
+  verdict: ReviewVerdict;
+  summary: string;
+  comment_ids: number[];
+  timestamp: string;
 }
 
 export interface ReviewCommentsResponse {
@@ -122,6 +223,8 @@ export interface ReviewCommentsResponse {
   open_count: number;
   resolved_count: number;
   outdated_count: number;
+  draft_count: number;
+  last_review?: ReviewSubmissionEntry;
   git_user_name?: string;
 }
 
@@ -144,6 +247,77 @@ export interface TaskStatsResponse {
   last_activity: string | null;
   file_edits: FileEditEntry[];
   hourly_activity: ActivityEntry[];
+  /** Time spent, from edit bursts and session attaches */
+  time_spent: TaskTime;
+}
+
+/** Time spent on a task (seconds; today / week in the server's local time) */
+export interface TaskTime {
+  total_secs: number;
+  today_secs: number;
+  week_secs: number;
+  attached_secs: number;
+  last_active_at?: string;
+}
+
+/** Accumulated agent usage of a chat, task or project */
+export interface UsageTotals {
+  turns: number;
+  input_tokens: number;
+  output_tokens: number;
+  cached_read_tokens: number;
+  cached_write_tokens: number;
+  thought_tokens: number;
+  total_tokens: number;
+  /** Absent when no agent in scope reported cost */
+  cost_amount?: number;
+  cost_currency?: string;
+  /** End of the latest turn (Unix seconds) */
+  last_turn_at?: number;
+}
+
+export interface TurnUsageRow {
+  agent: string;
+  model?: string;
+  input_tokens: number;
+  output_tokens: number;
+  cached_read_tokens?: number;
+  cached_write_tokens?: number;
+  thought_tokens?: number;
+  total_tokens: number;
+  start_ts: number;
+  end_ts: number;
+  /** Cumulative session cost as of this turn */
+  cost_amount?: number;
+  cost_currency?: string;
+}
+
+export interface ChatUsageResponse {
+  chat_id: string;
+  agent: string | null;
+  model: string | null;
+  totals: UsageTotals;
+  /** Latest turns, newest first */
+  turns: TurnUsageRow[];
+}
+
+export interface TaskUsageResponse {
+  totals: UsageTotals;
+  chats: {
+    chat_id: string;
+    title: string | null;
+    agent: string;
+    model: string | null;
+    totals: UsageTotals;
+  }[];
+}
+
+export interface TerminalScreenResponse {
+  session_name: string;
+  running: boolean;        // false when the tmux session isn't running
+  pane_id: string | null;
+  pane_count: number;
+  content: string;         // captured buffer, ANSI escapes preserved
 }
 
 // ============================================================================
@@ -157,14 +331,79 @@ export async function listTasks(
   projectId: string,
   filter: TaskFilter = 'active',
   signal?: AbortSignal,
+  options: TaskListOptions = {},
 ): Promise<TaskResponse[]> {
+  const params = new URLSearchParams({ filter });
+  if (options.sort) params.set('sort', options.sort);
+  if (options.view) params.set('view', options.view);
   const response = await apiClient.get<TaskListResponse>(
-    `/api/v1/projects/${projectId}/tasks?filter=${filter}`,
+    `/api/v1/projects/${projectId}/tasks?${params.toString()}`,
     signal,
   );
   return response.tasks;
 }
 
+/** Task joined with its project, from the cross-project task list */
+export interface ProjectTaskResponse extends TaskResponse {
+  project_id: string;
+  project_name: string;
+  project_type: 'repo' | 'studio';
+}
+
+export interface AllTasksFilter {
+  /** Only these statuses (e.g. ['live', 'idle']) */
+  status?: string[];
+  agentBusy?: boolean;
+  hasConflicts?: boolean;
+}
+
+/**
+ * List active tasks across all projects (pinned first, then most recent)
+ */
+export async function listAllTasks(
+  filter: AllTasksFilter = {},
+  signal?: AbortSignal,
+): Promise<ProjectTaskResponse[]> {
+  const params = new URLSearchParams({ all: 'true' });
+  if (filter.status?.length) params.set('status', filter.status.join(','));
+  if (filter.agentBusy) params.set('agent_busy', 'true');
+  if (filter.hasConflicts) params.set('has_conflicts', 'true');
+  const response = await apiClient.get<{ tasks: ProjectTaskResponse[] }>(
+    `/api/v1/tasks?${params.toString()}`,
+    signal,
+  );
+  return response.tasks;
+}
+
+/**
+ * List saved task views for a project
+ */
+export async function listTaskViews(projectId: string): Promise<TaskView[]> {
+  const response = await apiClient.get<{ views: TaskView[] }>(
+    `/api/v1/projects/${projectId}/task-views`
+  );
+  return response.views;
+}
+
+/**
+ * Create or replace a saved task view
+ */
+export async function saveTaskView(projectId: string, view: TaskView): Promise<TaskView> {
+  return apiClient.put<Omit<TaskView, 'name'>, TaskView>(
+    `/api/v1/projects/${projectId}/task-views/${encodeURIComponent(view.name)}`,
+    { sort: view.sort, filter: view.filter }
+  );
+}
+
+/**
+ * Delete a saved task view
+ */
+export async function deleteTaskView(projectId: string, name: string): Promise<void> {
+  await apiClient.delete<void>(
+    `/api/v1/projects/${projectId}/task-views/${encodeURIComponent(name)}`
+  );
+}
+
 /**
  * Get a single task by id.
  *
@@ -191,11 +430,35 @@ export async function createTask(
   projectId: string,
   name: string,
   target?: string,
-  notes?: string
+  notes?: string,
+  scope?: string,
+  sparsePaths?: string[],
+  template?: string
 ): Promise<TaskResponse> {
   return apiClient.post<CreateTaskRequest, TaskResponse>(
     `/api/v1/projects/${projectId}/tasks`,
-    { name, target, notes }
+    { name, target, notes, scope, sparse_paths: sparsePaths, template }
+  );
+}
+
+/** Sparse checkout paths of a task worktree; empty = whole repo checked out */
+export interface TaskSparse {
+  paths: string[];
+}
+
+export async function getTaskSparse(projectId: string, taskId: string): Promise<TaskSparse> {
+  return apiClient.get<TaskSparse>(`/api/v1/projects/${projectId}/tasks/${taskId}/sparse`);
+}
+
+/** Replace the checked-out paths; an empty list restores the full checkout */
+export async function updateTaskSparse(
+  projectId: string,
+  taskId: string,
+  paths: string[],
+): Promise<TaskSparse> {
+  return apiClient.put<TaskSparse, TaskSparse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/sparse`,
+    { paths },
   );
 }
 
@@ -213,6 +476,59 @@ export async function renameTask(
   );
 }
 
+/**
+ * Hand a task to the human or an agent chat (null clears the assignee).
+ * Assigning an agent sends it the unresolved review comments and notes.
+ */
+export async function handbackTask(
+  projectId: string,
+  taskId: string,
+  assignee: TaskAssignee | null,
+  message?: string
+): Promise<HandbackResponse> {
+  return apiClient.put<{ assignee: TaskAssignee | null; message?: string }, HandbackResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/assignee`,
+    { assignee, message }
+  );
+}
+
+/**
+ * Pin or unpin a task (pinned tasks sort first)
+ */
+export async function setTaskPinned(projectId: string, taskId: string, pinned: boolean): Promise<TaskResponse> {
+  return apiClient.put<{ pinned: boolean }, TaskResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/pin`,
+    { pinned }
+  );
+}
+
+/** Values offered by the task "Copy" menu */
+export interface TaskCopyTargets {
+  branch: string;
+  path: string;
+  /** Missing for ACP-only tasks, which have no terminal session */
+  attach_command?: string;
+}
+
+/**
+ * Get the branch, worktree path and session attach command of a task
+ */
+export async function getTaskCopyTargets(projectId: string, taskId: string): Promise<TaskCopyTargets> {
+  return apiClient.get<TaskCopyTargets>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/copy-targets`
+  );
+}
+
+/**
+ * Open the task worktree with a user-defined "Open With" application
+ */
+export async function openTaskWith(projectId: string, taskId: string, name: string): Promise<{ success: boolean; message: string }> {
+  return apiClient.post<{ name: string }, { success: boolean; message: string }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/open-with`,
+    { name }
+  );
+}
+
 /**
  * Archive a task
  */
@@ -238,6 +554,27 @@ export async function activateTask(projectId: string, taskId: string): Promise<v
   );
 }
 
+export interface SessionKeysRequest {
+  text?: string;     // typed literally
+  keys?: string[];   // tmux key names pressed after text: "Enter", "Escape", "C-c", ...
+  enter?: boolean;   // press Enter at the end
+  pane?: number;     // tmux pane index (default 0 = agent pane)
+}
+
+/**
+ * Type into the task's running tmux / zellij session
+ */
+export async function sendSessionKeys(
+  projectId: string,
+  taskId: string,
+  request: SessionKeysRequest
+): Promise<void> {
+  await apiClient.post<SessionKeysRequest, void>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/session/keys`,
+    request
+  );
+}
+
 // ============================================================================
 // Symbol indexing (cmd+click navigation)
 // ============================================================================
@@ -280,6 +617,29 @@ export async function lookupSymbol(
   return res.candidates;
 }
 
+export interface FileOutline {
+  path: string;
+  /** Language of the file, or null when no grammar handles it. */
+  language: string | null;
+  /** Declarations in source order; methods and fields carry `container`. */
+  symbols: SymbolCandidate[];
+}
+
+/**
+ * Functions, types and fields declared in one file. Parsed on demand,
+ * so it reflects the file as saved on disk even before the index catches up.
+ */
+export async function getFileOutline(
+  projectId: string,
+  taskId: string,
+  path: string,
+): Promise<FileOutline> {
+  const params = new URLSearchParams({ path });
+  return apiClient.get<FileOutline>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/symbols/outline?${params}`,
+  );
+}
+
 /**
  * Force a fresh full reindex. Idempotent on success.
  */
@@ -326,11 +686,72 @@ export async function updateNotes(
   );
 }
 
+export type NoteSource = 'web' | 'mcp' | 'tui' | 'sync' | 'automation' | 'restore' | 'summary' | 'external';
+
+export interface NoteRevision {
+  id: string;
+  /** RFC 3339 */
+  ts: string;
+  source: NoteSource;
+  size: number;
+  hash: string;
+}
+
+/**
+ * List previous versions of a task's notes, newest first
+ */
+export async function listNoteRevisions(projectId: string, taskId: string): Promise<NoteRevision[]> {
+  const res = await apiClient.get<{ revisions: NoteRevision[] }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/notes/revisions`
+  );
+  return res.revisions;
+}
+
+/**
+ * Get one notes revision with its content
+ */
+export async function getNoteRevision(
+  projectId: string,
+  taskId: string,
+  revId: string
+): Promise<{ revision: NoteRevision; content: string }> {
+  return apiClient.get(`/api/v1/projects/${projectId}/tasks/${taskId}/notes/revisions/${revId}`);
+}
+
+/**
+ * Unified diff from a revision to another revision (or the current notes)
+ */
+export async function diffNoteRevision(
+  projectId: string,
+  taskId: string,
+  revId: string,
+  against?: string
+): Promise<string> {
+  const query = against ? `?against=${encodeURIComponent(against)}` : '';
+  const res = await apiClient.get<{ diff: string }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/notes/revisions/${revId}/diff${query}`
+  );
+  return res.diff;
+}
+
+/**
+ * Restore notes to a revision (recorded as a new revision)
+ */
+export async function restoreNoteRevision(
+  projectId: string,
+  taskId: string,
+  revId: string
+): Promise<NotesResponse> {
+  return apiClient.post<undefined, NotesResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/notes/revisions/${revId}/restore`
+  );
+}
+
 /**
  * Sync task: fetch and rebase onto target
  */
-export async function syncTask(projectId: string, taskId: string): Promise<GitOperationResponse> {
-  return apiClient.post<undefined, GitOperationResponse>(
+export async function syncTask(projectId: string, taskId: string): Promise<SyncResponse> {
+  return apiClient.post<undefined, SyncResponse>(
     `/api/v1/projects/${projectId}/tasks/${taskId}/sync`
   );
 }
@@ -351,6 +772,7 @@ export async function commitTask(
 
 interface MergeRequest {
   method?: "squash" | "merge-commit";
+  include_review?: boolean;
 }
 
 /**
@@ -359,15 +781,234 @@ interface MergeRequest {
 export async function mergeTask(
   projectId: string,
   taskId: string,
-  method?: "squash" | "merge-commit"
-): Promise<GitOperationResponse> {
-  const body = method ? { method } : undefined;
-  return apiClient.post<MergeRequest | undefined, GitOperationResponse>(
+  method?: "squash" | "merge-commit",
+  includeReview?: boolean
+): Promise<MergeResponse> {
+  const body = method || includeReview ? { method, include_review: includeReview } : undefined;
+  return apiClient.post<MergeRequest | undefined, MergeResponse>(
     `/api/v1/projects/${projectId}/tasks/${taskId}/merge`,
     body
   );
 }
 
+/** Heuristic risk score of a task's changes (`[risk]` config) */
+export interface RiskAssessment {
+  /** 0-100 */
+  score: number;
+  level: 'low' | 'medium' | 'high';
+  /** Factors that added points, highest first */
+  factors: { kind: 'files' | 'critical_paths' | 'deletions' | 'no_tests'; points: number; detail: string }[];
+  critical_files: string[];
+}
+
+/** Risk of one task (null when scoring is disabled) */
+export async function getTaskRisk(projectId: string, taskId: string): Promise<RiskAssessment | null> {
+  return apiClient.get<RiskAssessment | null>(`/api/v1/projects/${projectId}/tasks/${taskId}/risk`);
+}
+
+/** Risk of every active task in a project, keyed by task id */
+export async function getTaskRisks(projectId: string): Promise<Record<string, RiskAssessment>> {
+  return apiClient.get<Record<string, RiskAssessment>>(`/api/v1/projects/${projectId}/task-risks`);
+}
+
+/** Source vs test files a task changed ("tests missing" warning) */
+export interface TestChanges {
+  source_files: string[];
+  test_files: string[];
+  tests_missing: boolean;
+}
+
+/** Test coverage of a task's changes against its target */
+export async function getTaskTestChanges(projectId: string, taskId: string): Promise<TestChanges | null> {
+  return apiClient.get<TestChanges | null>(`/api/v1/projects/${projectId}/tasks/${taskId}/test-changes`);
+}
+
+export type BenchVerdict = 'regression' | 'improvement' | 'unchanged' | 'added' | 'removed';
+
+export interface BenchComparison {
+  name: string;
+  /** Mean time in nanoseconds on the target / task HEAD */
+  base_ns: number | null;
+  head_ns: number | null;
+  /** Positive = slower on the task HEAD */
+  change_percent: number | null;
+  verdict: BenchVerdict;
+}
+
+export interface BenchRun {
+  status: 'running' | 'done' | 'failed';
+  started_at: string;
+  finished_at?: string;
+  command: string;
+  threshold_percent: number;
+  base_ref: string;
+  base_commit: string;
+  head_commit: string;
+  results: BenchComparison[];
+  error?: string;
+}
+
+/** Latest benchmark comparison of the task against its target */
+export async function getTaskBench(projectId: string, taskId: string): Promise<BenchRun | null> {
+  return apiClient.get<BenchRun | null>(`/api/v1/projects/${projectId}/tasks/${taskId}/bench`);
+}
+
+/** Start a benchmark comparison in the background */
+export async function runTaskBench(projectId: string, taskId: string): Promise<BenchRun> {
+  return apiClient.post<undefined, BenchRun>(`/api/v1/projects/${projectId}/tasks/${taskId}/bench`);
+}
+
+export interface ScanFinding {
+  scanner: string;
+  /** Advisory ID, e.g. RUSTSEC-2023-0001 or GHSA-xxxx */
+  id: string;
+  package: string;
+  version?: string;
+  severity: ScanSeverity;
+  title: string;
+  url?: string;
+}
+
+export interface ScanReport {
+  scanned_at: string;
+  head_commit: string;
+  /** Highest severity first */
+  findings: ScanFinding[];
+  /** Scanners that failed or printed no report */
+  errors?: string[];
+}
+
+export interface SecurityScanStatus {
+  configured: boolean;
+  /** Findings at or above this block the merge (null = warn only) */
+  block_severity: ScanSeverity | null;
+  warn_severity: ScanSeverity;
+  report: ScanReport | null;
+  warning: string | null;
+}
+
+/** Latest security scan of the task */
+export async function getTaskSecurityScan(projectId: string, taskId: string): Promise<SecurityScanStatus> {
+  return apiClient.get<SecurityScanStatus>(`/api/v1/projects/${projectId}/tasks/${taskId}/security-scan`);
+}
+
+/** Run the project's security scanners in the task worktree now */
+export async function runTaskSecurityScan(projectId: string, taskId: string): Promise<SecurityScanStatus> {
+  return apiClient.post<undefined, SecurityScanStatus>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/security-scan`,
+  );
+}
+
+export interface FileGuardStatus {
+  enabled: boolean;
+  /** Violations block the merge (otherwise they only warn) */
+  block: boolean;
+  violations: GuardViolation[];
+}
+
+/** Added files of the task that violate the project's file guard */
+export async function getTaskFileGuard(projectId: string, taskId: string): Promise<FileGuardStatus> {
+  return apiClient.get<FileGuardStatus>(`/api/v1/projects/${projectId}/tasks/${taskId}/file-guard`);
+}
+
+/** What currently blocks merging the task under the project's merge gate */
+export async function getTaskMergeGate(
+  projectId: string,
+  taskId: string
+): Promise<{ enabled: boolean; blockers: MergeBlocker[] }> {
+  return apiClient.get<{ enabled: boolean; blockers: MergeBlocker[] }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/merge-gate`
+  );
+}
+
+export interface CheckpointCommit {
+  hash: string;
+  message: string;
+  /** Unix seconds */
+  timestamp: number;
+}
+
+export interface CheckpointsResponse {
+  enabled: boolean;
+  interval_mins: number;
+  /** Trailing `grove-checkpoint:` commits on the task branch, newest first */
+  checkpoints: CheckpointCommit[];
+}
+
+export interface UpdateCheckpointsRequest {
+  enabled?: boolean;
+  interval_mins?: number;
+}
+
+/**
+ * Get auto-commit checkpoint settings and trailing checkpoint commits
+ */
+export async function getCheckpoints(projectId: string, taskId: string): Promise<CheckpointsResponse> {
+  return apiClient.get<CheckpointsResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/checkpoints`
+  );
+}
+
+/**
+ * Update auto-commit checkpoint settings
+ */
+export async function updateCheckpoints(
+  projectId: string,
+  taskId: string,
+  req: UpdateCheckpointsRequest
+): Promise<CheckpointsResponse> {
+  return apiClient.put<UpdateCheckpointsRequest, CheckpointsResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/checkpoints`,
+    req
+  );
+}
+
+/**
+ * Squash trailing checkpoint commits into one commit
+ */
+export async function squashCheckpoints(
+  projectId: string,
+  taskId: string,
+  message: string
+): Promise<GitOperationResponse> {
+  return apiClient.post<CommitRequest, GitOperationResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/checkpoints/squash`,
+    { message }
+  );
+}
+
+/**
+ * Drop trailing checkpoint commits (changes stay staged)
+ */
+export async function dropCheckpoints(projectId: string, taskId: string): Promise<GitOperationResponse> {
+  return apiClient.post<undefined, GitOperationResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/checkpoints/drop`
+  );
+}
+
+/**
+ * Task git identity override and the effective identity
+ */
+export async function getTaskGitIdentity(projectId: string, taskId: string): Promise<GitIdentityResponse> {
+  return apiClient.get<GitIdentityResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/git-identity`
+  );
+}
+
+/**
+ * Replace the task git identity override (empty fields follow the project)
+ */
+export async function updateTaskGitIdentity(
+  projectId: string,
+  taskId: string,
+  identity: GitIdentity
+): Promise<GitIdentityResponse> {
+  return apiClient.put<GitIdentity, GitIdentityResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/git-identity`,
+    identity
+  );
+}
+
 /**
  * Get diff (changed files) for a task
  */
@@ -406,6 +1047,48 @@ export async function getTaskStats(
   );
 }
 
+/**
+ * Get cumulative agent usage (tokens, cost) of a task, per chat
+ */
+export async function getTaskUsage(
+  projectId: string,
+  taskId: string
+): Promise<TaskUsageResponse> {
+  return apiClient.get<TaskUsageResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/usage`
+  );
+}
+
+/**
+ * Get agent usage of one chat, with its latest turns
+ */
+export async function getChatUsage(
+  projectId: string,
+  taskId: string,
+  chatId: string
+): Promise<ChatUsageResponse> {
+  return apiClient.get<ChatUsageResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/usage`
+  );
+}
+
+/**
+ * Snapshot of the task's tmux pane (what's on screen), without attaching
+ */
+export async function getTerminalScreen(
+  projectId: string,
+  taskId: string,
+  options: { lines?: number; pane?: number } = {}
+): Promise<TerminalScreenResponse> {
+  const params = new URLSearchParams();
+  if (options.lines !== undefined) params.set('lines', String(options.lines));
+  if (options.pane !== undefined) params.set('pane', String(options.pane));
+  const query = params.toString();
+  return apiClient.get<TerminalScreenResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/terminal/screen${query ? `?${query}` : ''}`
+  );
+}
+
 /**
  * Reset task: remove worktree and branch, recreate from target
  */
@@ -426,6 +1109,8 @@ export interface FileMetadata {
 export interface FilesResponse {
   files: string[];
   metadata?: FileMetadata[];
+  /** Task path scope the listing was limited to */
+  scope?: string;
 }
 
 export interface DirEntry {
@@ -444,8 +1129,46 @@ interface RebaseToRequest {
 /**
  * Get all git-tracked files in a task's worktree
  */
-export async function getTaskFiles(projectId: string, taskId: string): Promise<FilesResponse> {
-  return apiClient.get<FilesResponse>(`/api/v1/projects/${projectId}/tasks/${taskId}/files`);
+/** `full` lists the whole tree for path-scoped tasks */
+export async function getTaskFiles(projectId: string, taskId: string, full?: boolean): Promise<FilesResponse> {
+  const query = full ? '?full=true' : '';
+  return apiClient.get<FilesResponse>(`/api/v1/projects/${projectId}/tasks/${taskId}/files${query}`);
+}
+
+/** A changed file flagged by the CODEOWNERS check */
+export interface OwnershipWarning {
+  path: string;
+  kind: 'outside_scope' | 'other_team';
+  owners: string[];
+}
+
+/** CODEOWNERS overview of a task's changes, for PR preparation */
+export interface OwnersSummary {
+  codeowners_file: string | null;
+  scope: string | null;
+  /** Owners of the scope directory, treated as the task's team */
+  scope_owners: string[];
+  /** Owners to request review from, with the files each one owns */
+  owners: { owner: string; files: string[] }[];
+  unowned: string[];
+  warnings: OwnershipWarning[];
+}
+
+export async function getTaskOwners(projectId: string, taskId: string): Promise<OwnersSummary> {
+  return apiClient.get<OwnersSummary>(`/api/v1/projects/${projectId}/tasks/${taskId}/owners`);
+}
+
+/** Path scope of a monorepo task (null when unscoped) */
+export async function getTaskScope(projectId: string, taskId: string): Promise<{ scope: string | null }> {
+  return apiClient.get<{ scope: string | null }>(`/api/v1/projects/${projectId}/tasks/${taskId}/scope`);
+}
+
+/** Set or clear (null / "") the task's path scope */
+export async function updateTaskScope(projectId: string, taskId: string, scope: string | null): Promise<{ scope: string | null }> {
+  return apiClient.put<{ scope: string | null }, { scope: string | null }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/scope`,
+    { scope }
+  );
 }
 
 export async function getTaskDirEntries(projectId: string, taskId: string, dirPath: string): Promise<DirEntriesResponse> {
@@ -468,6 +1191,91 @@ export async function rebaseToTask(
   );
 }
 
+export interface UnmanagedWorktree {
+  path: string;
+  branch: string;
+  suggested_name: string;
+  suggested_target: string;
+}
+
+/**
+ * List git worktrees of the project that are not tracked as tasks
+ */
+export async function listUnmanagedWorktrees(projectId: string): Promise<UnmanagedWorktree[]> {
+  const res = await apiClient.get<{ worktrees: UnmanagedWorktree[] }>(
+    `/api/v1/projects/${projectId}/worktrees/unmanaged`
+  );
+  return res.worktrees;
+}
+
+/**
+ * Import an existing worktree as a task (name/target default to the suggestions)
+ */
+export async function adoptWorktree(
+  projectId: string,
+  path: string,
+  name?: string,
+  target?: string
+): Promise<TaskResponse> {
+  return apiClient.post<{ path: string; name?: string; target?: string }, TaskResponse>(
+    `/api/v1/projects/${projectId}/worktrees/adopt`,
+    { path, name, target }
+  );
+}
+
+export interface TargetChange {
+  from_target: string;
+  to_target: string;
+  changed_at: string;
+}
+
+/**
+ * Get the history of target branch changes for a task
+ */
+export async function getTargetHistory(projectId: string, taskId: string): Promise<TargetChange[]> {
+  const res = await apiClient.get<{ changes: TargetChange[] }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/target-history`
+  );
+  return res.changes;
+}
+
+/** Public read-only link to a task's diff, commits and review */
+export interface ShareLink {
+  token: string;
+  /** Path of the public page, relative to the server origin */
+  url_path: string;
+  has_password: boolean;
+  created_at: string;
+  expires_at?: string | null;
+  expired: boolean;
+}
+
+export async function listShareLinks(projectId: string, taskId: string): Promise<ShareLink[]> {
+  const res = await apiClient.get<{ shares: ShareLink[] }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/shares`
+  );
+  return res.shares;
+}
+
+/**
+ * Create a share link; `expiresInHours` omitted means it never expires
+ */
+export async function createShareLink(
+  projectId: string,
+  taskId: string,
+  password?: string,
+  expiresInHours?: number,
+): Promise<ShareLink> {
+  return apiClient.post<{ password?: string; expires_in_hours?: number }, ShareLink>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/shares`,
+    { password: password || undefined, expires_in_hours: expiresInHours },
+  );
+}
+
+export async function revokeShareLink(projectId: string, taskId: string, token: string): Promise<void> {
+  await apiClient.delete(`/api/v1/projects/${projectId}/tasks/${taskId}/shares/${token}`);
+}
+
 // ============================================================================
 // Chat Session API (Multi-Chat support)
 // ============================================================================
@@ -481,6 +1289,8 @@ export interface ChatSessionResponse {
   history_path: string;
   /** "acp" (default) or "terminal". Snapshotted at chat creation, immutable. */
   launch_mode: string;
+  /** Agent working directory relative to the worktree; absent = worktree root */
+  work_subdir?: string;
 }
 
 interface ChatListResponse {
@@ -490,6 +1300,7 @@ interface ChatListResponse {
 interface CreateChatRequest {
   title?: string;
   agent?: string;
+  work_subdir?: string;
 }
 
 interface UpdateChatTitleRequest {
@@ -531,10 +1342,11 @@ export async function createChat(
   taskId: string,
   title?: string,
   agent?: string,
+  workSubdir?: string,
 ): Promise<ChatSessionResponse> {
   return apiClient.post<CreateChatRequest, ChatSessionResponse>(
     `/api/v1/projects/${projectId}/tasks/${taskId}/chats`,
-    { title, agent }
+    { title, agent, work_subdir: workSubdir }
   );
 }
 
@@ -790,10 +1602,36 @@ export async function uploadChatAttachment(
 interface FileContentResponse {
   content: string;
   path: string;
+  /** Content hash; send back as If-Match when saving */
+  hash: string;
 }
 
 interface WriteFileRequest {
   content: string;
+  /** Content originally loaded, so the server can three-way merge on conflict */
+  base?: string;
+}
+
+/** Version of a file as loaded into the editor */
+export interface FileVersion {
+  hash: string;
+  content: string;
+}
+
+/** Body of the 409 returned when the file changed on disk since it was loaded */
+export interface FileConflict {
+  error: string;
+  path: string;
+  /** Hash of the on-disk content (If-Match for an overwrite) */
+  current_hash: string;
+  base: string | null;
+  /** Content we tried to save */
+  ours: string;
+  /** Content currently on disk */
+  theirs: string;
+  /** Three-way merge result; contains conflict markers when has_conflicts */
+  merged: string | null;
+  has_conflicts: boolean;
 }
 
 /**
@@ -810,17 +1648,79 @@ export async function getFileContent(
 }
 
 /**
- * Write content to a file in a task's worktree
+ * Write content to a file in a task's worktree.
+ *
+ * `version` is what the editor loaded; the save is rejected with a 409
+ * (`ApiError.data` is a `FileConflict`) if the file changed since. Pass
+ * `null` to overwrite unconditionally.
  */
 export async function writeFileContent(
   projectId: string,
   taskId: string,
   filePath: string,
-  content: string
+  content: string,
+  version: FileVersion | null
 ): Promise<FileContentResponse> {
   return apiClient.put<WriteFileRequest, FileContentResponse>(
     `/api/v1/projects/${projectId}/tasks/${taskId}/file?path=${encodeURIComponent(filePath)}`,
-    { content }
+    { content, base: version?.content },
+    { 'If-Match': version ? `"${version.hash}"` : '*' }
+  );
+}
+
+export interface FileHistoryEntry {
+  hash: string;
+  author: string;
+  /** Commit time (Unix seconds) */
+  timestamp: number;
+  time_ago: string;
+  /** Commit time (RFC 3339) */
+  time: string;
+  message: string;
+  /** Path of the file in this commit (differs after a rename) */
+  path: string;
+  /** Commit belongs to the task branch (target..HEAD) */
+  on_branch: boolean;
+}
+
+export interface FileHistoryResponse {
+  path: string;
+  commits: FileHistoryEntry[];
+}
+
+export interface FilePatchResponse {
+  commit: string;
+  path: string;
+  patch: string;
+}
+
+/**
+ * Commits touching a file (following renames), newest first.
+ * Only the task branch unless `includeTarget` is set.
+ */
+export async function getFileHistory(
+  projectId: string,
+  taskId: string,
+  path: string,
+  includeTarget = false
+): Promise<FileHistoryResponse> {
+  const params = new URLSearchParams({ path, include_target: String(includeTarget) });
+  return apiClient.get<FileHistoryResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/file/history?${params}`
+  );
+}
+
+/**
+ * Patch of one file in one commit (`path` as of that commit)
+ */
+export async function getFileHistoryPatch(
+  projectId: string,
+  taskId: string,
+  commit: string,
+  path: string
+): Promise<FilePatchResponse> {
+  return apiClient.get<FilePatchResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/file/history/${encodeURIComponent(commit)}?path=${encodeURIComponent(path)}`
   );
 }
 
@@ -885,6 +1785,42 @@ export async function deleteFileOrDir(
   );
 }
 
+export interface FsUploadedFile {
+  /** Worktree-relative path */
+  path: string;
+  size: number;
+  /** MIME type detected from the extension */
+  mime: string;
+}
+
+/**
+ * Upload files (binary-safe) into a directory of a task's worktree.
+ * Existing files are only replaced when `overwrite` is set.
+ */
+export async function uploadTaskFiles(
+  projectId: string,
+  taskId: string,
+  dir: string,
+  files: File[],
+  overwrite = false
+): Promise<FsUploadedFile[]> {
+  const formData = new FormData();
+  for (const file of files) formData.append('file', file);
+  const params = new URLSearchParams({ dir, overwrite: String(overwrite) });
+  const res = await apiClient.postFormData<{ files: FsUploadedFile[] }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/fs/upload?${params}`,
+    formData
+  );
+  return res.files;
+}
+
+/**
+ * URL that downloads a worktree file as an attachment (any file type)
+ */
+export function taskFileDownloadUrl(projectId: string, taskId: string, path: string): string {
+  return `/api/v1/projects/${projectId}/tasks/${taskId}/fs/download?path=${encodeURIComponent(path)}`;
+}
+
 /**
  * Open a worktree file (or directory) with the OS default application.
  * Runs on the machine hosting the Grove server.
@@ -919,6 +1855,68 @@ export async function moveFileOrDir(
   );
 }
 
+interface CopyFileRequest {
+  source: string;
+  destination: string;
+}
+
+export interface FsBatchItemResult {
+  /** Path the item was addressed by (the source for move/copy) */
+  path: string;
+  success: boolean;
+  error?: string;
+}
+
+export interface FsBatchResponse {
+  /** True when every item succeeded */
+  success: boolean;
+  results: FsBatchItemResult[];
+}
+
+/**
+ * Delete several files/directories in a task's worktree.
+ * Paths nested under another listed path are skipped.
+ */
+export async function batchDeletePaths(
+  projectId: string,
+  taskId: string,
+  paths: string[]
+): Promise<FsBatchResponse> {
+  return apiClient.post<{ paths: string[] }, FsBatchResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/fs/batch-delete`,
+    { paths }
+  );
+}
+
+/**
+ * Move/rename several paths in a task's worktree (applied in order).
+ * Tracked paths are moved with `git mv`.
+ */
+export async function batchMovePaths(
+  projectId: string,
+  taskId: string,
+  moves: MoveFileRequest[]
+): Promise<FsBatchResponse> {
+  return apiClient.post<{ moves: MoveFileRequest[] }, FsBatchResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/fs/batch-move`,
+    { moves }
+  );
+}
+
+/**
+ * Copy several files in a task's worktree
+ */
+export async function batchCopyPaths(
+  projectId: string,
+  taskId: string,
+  copies: CopyFileRequest[]
+): Promise<FsBatchResponse> {
+  return apiClient.post<{ copies: CopyFileRequest[] }, FsBatchResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/fs/batch-copy`,
+    { copies }
+  );
+}
+
 
 // ============================================================================
 // Chat History & Take Control API (read-only observation mode)
@@ -956,6 +1954,8 @@ interface ChatHistoryResponse {
   // eslint-disable-next-line @typescript-eslint/no-explicit-any
   events: any[];
   total: number;
+  /** Seq of the newest persisted event — pass as `since_seq` when reconnecting the chat WS */
+  last_seq: number;
   session: SessionMetadata | null;
 }
 
@@ -977,6 +1977,250 @@ export async function getChatHistory(
   );
 }
 
+export interface TurnManifest {
+  /** Turn start (unix seconds), matches TurnSnapshot.turn_ts */
+  turn_ts: number;
+  agent_name: string;
+  agent_version: string;
+  model?: string;
+  mode?: string;
+  /** SHA-256 of the user prompt */
+  prompt_sha256: string;
+  skills: string[];
+  /** Instruction preambles injected into this turn (first turn of a session only) */
+  instructions?: { scope: string; text: string }[];
+  head_before?: string;
+  head_after?: string;
+  finished_ts?: number;
+}
+
+export interface TranscriptExport {
+  chat_id: string;
+  title: string;
+  agent: string;
+  exported_at: string;
+  session: SessionMetadata | null;
+  manifests: TurnManifest[];
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  events: any[];
+}
+
+/**
+ * Export the full chat transcript with per-turn reproducibility manifests
+ */
+export async function exportChatTranscript(
+  projectId: string,
+  taskId: string,
+  chatId: string
+): Promise<TranscriptExport> {
+  return apiClient.get<TranscriptExport>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/export`
+  );
+}
+
+/** One tool call on the per-turn timeline */
+export interface ToolSpan {
+  id: string;
+  title: string;
+  status: string;
+  /** Unix milliseconds */
+  start_ms: number;
+  end_ms?: number;
+  duration_ms?: number;
+  /** Gantt row; calls in the same lane never overlap */
+  lane: number;
+}
+
+export interface TurnTimeline {
+  index: number;
+  /** Beginning of the user prompt */
+  prompt: string;
+  start_ts?: number;
+  end_ts?: number;
+  tools: ToolSpan[];
+  /** Most tool calls running at once */
+  max_parallel: number;
+  /** Sum of finished tool durations */
+  tool_time_ms: number;
+}
+
+/**
+ * Per-turn tool-call timeline of a chat (start/end/duration and lane per call)
+ */
+export async function getChatTimeline(
+  projectId: string,
+  taskId: string,
+  chatId: string
+): Promise<TurnTimeline[]> {
+  const response = await apiClient.get<{ turns: TurnTimeline[] }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/timeline`
+  );
+  return response.turns;
+}
+
+export type ChatPinKind = "user" | "assistant" | "thinking" | "tool";
+
+/** A pinned message or tool result of a chat */
+export interface ChatPin {
+  id: string;
+  kind: ChatPinKind;
+  /** Content snapshot (truncated server-side, ends with "…" when cut) */
+  text: string;
+  tool_call_id?: string;
+  note?: string;
+  created_at: string;
+}
+
+/**
+ * Pinned messages / tool results of a chat, oldest first
+ */
+export async function listChatPins(
+  projectId: string,
+  taskId: string,
+  chatId: string
+): Promise<ChatPin[]> {
+  const response = await apiClient.get<{ pins: ChatPin[] }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/pins`
+  );
+  return response.pins;
+}
+
+/**
+ * Pin a message or tool result (returns the existing pin if already pinned)
+ */
+export async function addChatPin(
+  projectId: string,
+  taskId: string,
+  chatId: string,
+  pin: { kind: ChatPinKind; text: string; tool_call_id?: string; note?: string }
+): Promise<ChatPin> {
+  return apiClient.post<typeof pin, ChatPin>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/pins`,
+    pin
+  );
+}
+
+/**
+ * Unpin
+ */
+export async function deleteChatPin(
+  projectId: string,
+  taskId: string,
+  chatId: string,
+  pinId: string
+): Promise<void> {
+  await apiClient.delete(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/pins/${pinId}`
+  );
+}
+
+export type NativeSessionSource = "claude" | "codex";
+
+/** A Claude Code / Codex session log that can be imported as a chat */
+export interface NativeSession {
+  source: NativeSessionSource;
+  /** Absolute path of the session log */
+  path: string;
+  session_id: string;
+  cwd?: string;
+  /** Beginning of the first prompt */
+  title: string;
+  prompts: number;
+  started_at?: string;
+  updated_at?: string;
+}
+
+export interface ImportChatResponse {
+  chat_id: string;
+  title: string;
+  agent: string;
+  events: number;
+  /** Whether the chat resumes the original agent session */
+  resumable: boolean;
+}
+
+/**
+ * Claude Code / Codex sessions that ran in the task worktree or project root
+ */
+export async function listNativeSessions(
+  projectId: string,
+  taskId: string
+): Promise<NativeSession[]> {
+  const response = await apiClient.get<{ sessions: NativeSession[] }>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chat-import`
+  );
+  return response.sessions;
+}
+
+/**
+ * Import a native session log as a new chat on the task
+ */
+export async function importNativeSession(
+  projectId: string,
+  taskId: string,
+  source: NativeSessionSource,
+  path: string
+): Promise<ImportChatResponse> {
+  return apiClient.post<{ source: NativeSessionSource; path: string }, ImportChatResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chat-import`,
+    { source, path }
+  );
+}
+
+export interface TurnSnapshot {
+  /** Turn start (unix seconds), matches the Complete event's start_ts */
+  turn_ts: number;
+  before: string;
+  after?: string;
+}
+
+export interface TurnDiff {
+  files: { path: string; additions: number; deletions: number }[];
+  patch: string;
+  truncated: boolean;
+}
+
+/**
+ * List per-turn worktree snapshots of a chat
+ */
+export async function listTurnSnapshots(
+  projectId: string,
+  taskId: string,
+  chatId: string
+): Promise<TurnSnapshot[]> {
+  return apiClient.get<TurnSnapshot[]>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/snapshots`
+  );
+}
+
+/**
+ * Get the filesystem changes made during one turn
+ */
+export async function getTurnDiff(
+  projectId: string,
+  taskId: string,
+  chatId: string,
+  turnTs: number
+): Promise<TurnDiff> {
+  return apiClient.get<TurnDiff>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/snapshots/${turnTs}/diff`
+  );
+}
+
+/**
+ * Revert one turn's filesystem changes in the worktree
+ */
+export async function revertTurn(
+  projectId: string,
+  taskId: string,
+  chatId: string,
+  turnTs: number
+): Promise<GitOperationResponse> {
+  return apiClient.post<undefined, GitOperationResponse>(
+    `/api/v1/projects/${projectId}/tasks/${taskId}/chats/${chatId}/snapshots/${turnTs}/revert`
+  );
+}
+
 /**
  * Read a file by absolute path (for Plan File rendering)
  */
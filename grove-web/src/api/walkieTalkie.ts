@@ -82,4 +82,4 @@ export type RadioEvent =
   | { type: "client_disconnected" }
   | { type: "client_count"; count: number }
   | { type: "group_changed" }
-  | { type: "theme_changed" };
+  | { type: "theme_changed"; name?: string };
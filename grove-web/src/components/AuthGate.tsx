@@ -1,4 +1,4 @@
-import { useState, useEffect, useCallback, type ReactNode } from "react";
+import { useState, useEffect, useCallback, useRef, type ReactNode } from "react";
 import {
   extractSkFromUrl,
   extractPageFromUrl,
@@ -9,6 +9,12 @@ import {
   setPageIntent,
   setRadioToken,
   computeHmac,
+  apiClient,
+  getSessionToken,
+  setSessionToken,
+  clearSessionToken,
+  SESSION_EXPIRED_EVENT,
+  getDeviceId,
 } from "../api/client";
 
 interface AuthGateProps {
@@ -17,6 +23,58 @@ interface AuthGateProps {
 
 type AuthState = "loading" | "authenticated" | "needs_auth";
 
+/** Server session policy from `/auth/info` (`[auth]` in config.toml) */
+interface SessionPolicy {
+  idle_timeout_secs: number;
+  max_lifetime_secs?: number;
+  pin_ip: boolean;
+}
+
+const ACTIVITY_EVENTS = ["pointerdown", "keydown", "touchstart", "wheel"] as const;
+
+/**
+ * Auto-lock while sessions are enforced: user input is reported to the server
+ * as a throttled heartbeat (reads alone don't keep a session alive), and the
+ * page locks itself once the idle timeout passes without input — even if the
+ * server hasn't rejected a request yet.
+ */
+function useIdleLock(policy: SessionPolicy | null, active: boolean, onLock: () => void) {
+  const lastActivity = useRef(0);
+  const lastHeartbeat = useRef(0);
+
+  useEffect(() => {
+    if (!policy || !active) return;
+    const idleMs = policy.idle_timeout_secs * 1000;
+    const heartbeatMs = Math.min(60_000, idleMs / 3);
+    lastActivity.current = Date.now();
+    lastHeartbeat.current = Date.now();
+
+    const onActivity = () => {
+      const now = Date.now();
+      lastActivity.current = now;
+      if (now - lastHeartbeat.current >= heartbeatMs) {
+        lastHeartbeat.current = now;
+        apiClient.post("/api/v1/auth/session/heartbeat").catch(() => {});
+      }
+    };
+    const timer = setInterval(() => {
+      if (Date.now() - lastActivity.current > idleMs) {
+        // Revoke server-side too (while the session token is still around), then lock
+        clearInterval(timer);
+        apiClient.postNoContent("/api/v1/auth/session/lock").catch(() => {}).finally(onLock);
+      }
+    }, 10_000);
+
+    for (const event of ACTIVITY_EVENTS) {
+      window.addEventListener(event, onActivity, { passive: true });
+    }
+    return () => {
+      clearInterval(timer);
+      for (const event of ACTIVITY_EVENTS) window.removeEventListener(event, onActivity);
+    };
+  }, [policy, active, onLock]);
+}
+
 /** Base URL for raw auth probes (pre-auth, so we can't use apiClient).
  *
  * Priority:
@@ -28,29 +86,98 @@ function getAuthBase(): string {
   return (typeof g.__GROVE_API_BASE__ === "string" && g.__GROVE_API_BASE__) || "";
 }
 
+/** `/auth/verify` refused: too many failed attempts from this client. */
+class LockedOutError extends Error {
+  readonly retryAfterSecs: number;
+
+  constructor(retryAfterSecs: number) {
+    super("Too many failed attempts");
+    this.retryAfterSecs = retryAfterSecs;
+  }
+}
+
+function lockedOutMessage(secs: number): string {
+  const wait = secs >= 120 ? `${Math.ceil(secs / 60)} min` : `${secs}s`;
+  return `Too many failed attempts — try again in ${wait}`;
+}
+
+/** POST `/auth/verify`; throws `LockedOutError` on 429. */
+async function postVerify(body: { proof: string; challenge?: string }): Promise<Response> {
+  // Intentional raw fetch: this endpoint is the pre-auth handshake and
+  // accepts an unsigned request body containing the HMAC proof. The
+  // global apiClient would attach signed headers, which the server's
+  // /auth/verify path is allowed to ignore but which require us to
+  // already know the SK is valid — chicken-and-egg.
+  const resp = await fetch(`${getAuthBase()}/api/v1/auth/verify`, {
+    method: "POST",
+    headers: { "Content-Type": "application/json", "X-Grove-Device": getDeviceId() },
+    body: JSON.stringify(body),
+  });
+  if (resp.status === 429) {
+    throw new LockedOutError(Number(resp.headers.get("Retry-After")) || 60);
+  }
+  return resp;
+}
+
 export function AuthGate({ children }: AuthGateProps) {
   const [authState, setAuthState] = useState<AuthState>("loading");
   const [skInput, setSkInput] = useState("");
   const [error, setError] = useState<string | null>(null);
   const [verifying, setVerifying] = useState(false);
+  const [sessionPolicy, setSessionPolicy] = useState<SessionPolicy | null>(null);
+  const [locked, setLocked] = useState(false);
+  const [readOnly, setReadOnly] = useState(false);
+
+  /** Drop the key and session and show the lock screen. */
+  const lock = useCallback(() => {
+    clearSecretKey();
+    clearSessionToken();
+    setSkInput("");
+    setError(null);
+    setLocked(true);
+    setAuthState("needs_auth");
+  }, []);
+
+  useEffect(() => {
+    window.addEventListener(SESSION_EXPIRED_EVENT, lock);
+    return () => window.removeEventListener(SESSION_EXPIRED_EVENT, lock);
+  }, [lock]);
 
-  /** Verify the current SK by sending HMAC("grove-verify") to the server. */
+  useIdleLock(sessionPolicy, authState === "authenticated", lock);
+
+  /**
+   * Verify the current SK by answering a one-time challenge with
+   * HMAC("grove-verify|{challenge}"); the server returns a session token when
+   * sessions are enforced. Older servers without `/auth/challenge` get the
+   * static HMAC("grove-verify") proof. Throws `LockedOutError` while the
+   * server refuses this client.
+   */
   const verifySk = useCallback(async (): Promise<boolean> => {
+    try {
+      // Raw fetch for the same pre-auth reason as postVerify
+      const resp = await fetch(`${getAuthBase()}/api/v1/auth/challenge`, { method: "POST" });
+      if (resp.ok) {
+        const { challenge } = (await resp.json()) as { challenge: string };
+        const proof = await computeHmac(`grove-verify|${challenge}`);
+        if (!proof) return false;
+        const verify = await postVerify({ proof, challenge });
+        if (!verify.ok) return false;
+        const { session } = (await verify.json()) as { session?: string };
+        if (session) setSessionToken(session);
+        else clearSessionToken();
+        return true;
+      }
+    } catch (err) {
+      if (err instanceof LockedOutError) throw err;
+      return false;
+    }
+
     const proof = await computeHmac("grove-verify");
     if (!proof) return false;
     try {
-      // Intentional raw fetch: this endpoint is the pre-auth handshake and
-      // accepts an unsigned request body containing the HMAC proof. The
-      // global apiClient would attach signed headers, which the server's
-      // /auth/verify path is allowed to ignore but which require us to
-      // already know the SK is valid — chicken-and-egg.
-      const resp = await fetch(`${getAuthBase()}/api/v1/auth/verify`, {
-        method: "POST",
-        headers: { "Content-Type": "application/json" },
-        body: JSON.stringify({ proof }),
-      });
-      return resp.ok;
-    } catch {
+      return (await postVerify({ proof })).ok;
+    } catch (err) {
+      if (err instanceof LockedOutError) throw err;
       return false;
     }
   }, []);
@@ -105,10 +232,23 @@ export function AuthGate({ children }: AuthGateProps) {
         if (info && (info.remote || info.required)) {
           (window as unknown as Record<string, unknown>).__GROVE_REMOTE__ = true;
         }
+        setReadOnly(Boolean(info?.read_only));
         if (!info.required) {
           setAuthState("authenticated");
           return;
         }
+        setSessionPolicy(info.session ?? null);
+        // A stored session must still be alive: reloading must not bypass
+        // the lock by silently re-verifying the stored key.
+        if (info.session && getSecretKey() && getSessionToken()) {
+          try {
+            await apiClient.post("/api/v1/auth/session/heartbeat");
+            setAuthState("authenticated");
+          } catch {
+            lock();
+          }
+          return;
+        }
       } catch {
         // Network error — assume no auth needed
         setAuthState("authenticated");
@@ -119,9 +259,13 @@ export function AuthGate({ children }: AuthGateProps) {
       const storedSk = getSecretKey();
       if (storedSk) {
         // Verify the stored SK
-        if (await verifySk()) {
-          setAuthState("authenticated");
-          return;
+        try {
+          if (await verifySk()) {
+            setAuthState("authenticated");
+            return;
+          }
+        } catch (err) {
+          if (err instanceof LockedOutError) setError(lockedOutMessage(err.retryAfterSecs));
         }
         // SK invalid, clear it
         clearSecretKey();
@@ -131,7 +275,7 @@ export function AuthGate({ children }: AuthGateProps) {
     };
 
     init();
-  }, [verifySk]);
+  }, [verifySk, lock]);
 
   const handleSubmit = useCallback(
     async (e: React.FormEvent) => {
@@ -143,14 +287,15 @@ export function AuthGate({ children }: AuthGateProps) {
         // Temporarily store the SK so computeHmac can use it
         setSecretKey(skInput);
         if (await verifySk()) {
+          setLocked(false);
           setAuthState("authenticated");
         } else {
           clearSecretKey();
           setError("Invalid secret key");
         }
-      } catch {
+      } catch (err) {
         clearSecretKey();
-        setError("Connection failed");
+        setError(err instanceof LockedOutError ? lockedOutMessage(err.retryAfterSecs) : "Connection failed");
       }
       setVerifying(false);
     },
@@ -166,7 +311,19 @@ export function AuthGate({ children }: AuthGateProps) {
   }
 
   if (authState === "authenticated") {
-    return <>{children}</>;
+    return (
+      <>
+        {children}
+        {readOnly && (
+          <div
+            className="fixed bottom-2 left-1/2 -translate-x-1/2 z-[9999] px-3 py-1 rounded-full text-xs font-medium bg-[var(--color-warning)]/15 text-[var(--color-warning)] border border-[var(--color-warning)]/40 pointer-events-none"
+            title="This server was started with --read-only; changes are rejected"
+          >
+            Read-only
+          </div>
+        )}
+      </>
+    );
   }
 
   // SK input page
@@ -175,7 +332,9 @@ export function AuthGate({ children }: AuthGateProps) {
       <div className="w-full max-w-sm">
         <div className="text-center mb-8">
           <h1 className="text-2xl font-bold text-white mb-2">Grove</h1>
-          <p className="text-[#888] text-sm">Enter the secret key to continue</p>
+          <p className="text-[#888] text-sm">
+            {locked ? "Locked after inactivity — enter the secret key to unlock" : "Enter the secret key to continue"}
+          </p>
         </div>
 
         <form onSubmit={handleSubmit} className="space-y-4">
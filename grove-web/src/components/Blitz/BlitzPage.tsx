@@ -1700,6 +1700,11 @@ export function BlitzPage({
         targetBranch={selectedTask?.target || ""}
         isLoading={opsState.isMerging}
         error={opsState.mergeError}
+        risk={opsState.mergeRisk}
+        testChanges={opsState.mergeTestChanges}
+        bench={opsState.mergeBench}
+        securityWarning={opsState.mergeSecurityWarning}
+        fileGuard={opsState.mergeFileGuard}
         onMerge={opsHandlers.handleMergeSubmit}
         onCancel={opsHandlers.handleMergeCancel}
       />
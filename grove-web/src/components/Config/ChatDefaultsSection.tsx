@@ -0,0 +1,88 @@
+import { useEffect, useState } from "react";
+import { Pin } from "lucide-react";
+import { Input } from "../ui";
+import { getChatDefaults, updateChatDefaults, type ChatDefaults } from "../../api";
+import { useProject } from "../../context";
+
+/**
+ * Per-agent mode/model defaults of the selected project inside Settings →
+ * Agent. Grove remembers the mode and model last used with each agent and
+ * applies them to new chats; a pinned value takes precedence.
+ */
+export function ChatDefaultsSection() {
+  const { selectedProject } = useProject();
+  const projectId = selectedProject?.id;
+  const [defaults, setDefaults] = useState<ChatDefaults | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!projectId) return;
+    let cancelled = false;
+    setDefaults(null);
+    getChatDefaults(projectId)
+      .then((data) => { if (!cancelled) setDefaults(data); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId]);
+
+  if (!projectId || !defaults) {
+    return null;
+  }
+
+  const agents = Object.entries(defaults.agents);
+
+  const update = (agent: string, field: "pinned_mode" | "pinned_model", value: string) => {
+    setDefaults((prev) => prev && {
+      agents: { ...prev.agents, [agent]: { ...prev.agents[agent], [field]: value || undefined } },
+    });
+  };
+
+  const save = async () => {
+    setError(null);
+    try {
+      setDefaults(await updateChatDefaults(projectId, defaults));
+    } catch {
+      setError("Failed to save chat defaults");
+    }
+  };
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center gap-2 select-none">
+        <Pin className="w-4 h-4 text-[var(--color-info)]" />
+        <span className="text-xs font-medium text-[var(--color-text-muted)] uppercase tracking-wider">
+          Chat Defaults · {selectedProject?.name}
+        </span>
+      </div>
+      {agents.length === 0 ? (
+        <p className="text-xs text-[var(--color-text-muted)]">
+          No chats in this project yet — the mode and model you use will be remembered per agent.
+        </p>
+      ) : (
+        agents.map(([agent, d]) => (
+          <div key={agent} className="grid grid-cols-[120px_1fr_1fr] gap-2 items-center">
+            <span className="text-sm font-mono text-[var(--color-text)] truncate" title={agent}>{agent}</span>
+            <Input
+              placeholder={d.last_mode ? `Mode (last: ${d.last_mode})` : "Pinned mode"}
+              value={d.pinned_mode ?? ""}
+              onChange={(e) => update(agent, "pinned_mode", e.target.value)}
+              onBlur={save}
+            />
+            <Input
+              placeholder={d.last_model ? `Model (last: ${d.last_model})` : "Pinned model"}
+              value={d.pinned_model ?? ""}
+              onChange={(e) => update(agent, "pinned_model", e.target.value)}
+              onBlur={save}
+            />
+          </div>
+        ))
+      )}
+      {error && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+      <p className="text-xs leading-relaxed text-[var(--color-text-muted)]">
+        New chats start with the pinned mode/model, else the ones last used with that agent in this project.
+      </p>
+    </div>
+  );
+}
@@ -0,0 +1,106 @@
+import { useEffect, useState } from "react";
+import { BellOff } from "lucide-react";
+import { getConfig, patchConfig, type QuietHours } from "../../api";
+
+const DEFAULT_QUIET_HOURS: QuietHours = { enabled: false, start: "22:00", end: "08:00" };
+
+/**
+ * Do Not Disturb card inside Settings → Notification. Silences sounds and
+ * system banners manually or during daily quiet hours; notifications are
+ * still recorded and show up in the inbox.
+ */
+export function DoNotDisturbSection() {
+  const [dnd, setDnd] = useState(false);
+  const [quiet, setQuiet] = useState<QuietHours>(DEFAULT_QUIET_HOURS);
+  const [muted, setMuted] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    getConfig()
+      .then((cfg) => {
+        if (cancelled) return;
+        setDnd(cfg.notifications.do_not_disturb ?? false);
+        setQuiet(cfg.notifications.quiet_hours ?? DEFAULT_QUIET_HOURS);
+        setMuted(cfg.notifications.muted ?? false);
+      })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, []);
+
+  const save = async (patch: { do_not_disturb?: boolean; quiet_hours?: QuietHours }) => {
+    setError(null);
+    try {
+      const cfg = await patchConfig({ notifications: patch });
+      setDnd(cfg.notifications.do_not_disturb ?? false);
+      setQuiet(cfg.notifications.quiet_hours ?? DEFAULT_QUIET_HOURS);
+      setMuted(cfg.notifications.muted ?? false);
+    } catch {
+      setError("Failed to save Do Not Disturb settings");
+    }
+  };
+
+  const updateQuiet = (next: QuietHours) => {
+    setQuiet(next);
+    if (next.start && next.end) void save({ quiet_hours: next });
+  };
+
+  const checkbox = "accent-[var(--color-highlight)]";
+
+  return (
+    <div className="rounded-xl border border-[var(--color-border)] bg-[var(--color-bg-secondary)] p-4 space-y-3">
+      <div className="flex items-start justify-between gap-3">
+        <div>
+          <div className="flex items-center gap-1.5 text-sm font-semibold text-[var(--color-text)]">
+            <BellOff className="w-3.5 h-3.5" />
+            Do Not Disturb
+            {muted && (
+              <span className="rounded px-1.5 py-0.5 text-[10px] font-medium text-[var(--color-warning)] bg-[var(--color-warning)]/10">
+                Muted now
+              </span>
+            )}
+          </div>
+          <div className="text-xs text-[var(--color-text-muted)] mt-0.5">
+            Silence all sounds and banners — notifications still reach the inbox (TUI: <kbd>M</kbd>)
+          </div>
+        </div>
+        <input
+          type="checkbox"
+          className={checkbox}
+          checked={dnd}
+          onChange={(e) => void save({ do_not_disturb: e.target.checked })}
+          aria-label="Do Not Disturb"
+        />
+      </div>
+      <div className="flex flex-wrap items-center gap-2 text-xs text-[var(--color-text)]">
+        <label className="flex items-center gap-2">
+          <input
+            type="checkbox"
+            className={checkbox}
+            checked={quiet.enabled}
+            onChange={(e) => updateQuiet({ ...quiet, enabled: e.target.checked })}
+          />
+          Quiet hours from
+        </label>
+        <input
+          type="time"
+          value={quiet.start}
+          disabled={!quiet.enabled}
+          onChange={(e) => updateQuiet({ ...quiet, start: e.target.value })}
+          className="rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs disabled:opacity-50"
+        />
+        <span>to</span>
+        <input
+          type="time"
+          value={quiet.end}
+          disabled={!quiet.enabled}
+          onChange={(e) => updateQuiet({ ...quiet, end: e.target.value })}
+          className="rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs disabled:opacity-50"
+        />
+      </div>
+      {error && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,105 @@
+import { useEffect, useState } from "react";
+import { GitPullRequest } from "lucide-react";
+import {
+  getForgeSettings,
+  updateForgeSettings,
+  type ForgeKind,
+  type ForgeSettingsResponse,
+} from "../../api";
+import { useProject } from "../../context";
+
+const FORGES: { value: ForgeKind; label: string }[] = [
+  { value: "github", label: "GitHub" },
+  { value: "gitlab", label: "GitLab" },
+  { value: "gitea", label: "Gitea / Forgejo" },
+];
+
+const forgeLabel = (kind: ForgeKind | null | undefined) =>
+  FORGES.find((f) => f.value === kind)?.label ?? "unknown";
+
+/**
+ * Forge of the selected project inside Settings → Agent: where pull / merge
+ * requests are opened. Detected from the origin host unless overridden;
+ * self-hosted instances can set their REST API root.
+ */
+export function ForgeSection() {
+  const { selectedProject } = useProject();
+  const projectId = selectedProject?.id;
+  const [settings, setSettings] = useState<ForgeSettingsResponse | null>(null);
+  const [apiUrl, setApiUrl] = useState("");
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!projectId) return;
+    let cancelled = false;
+    setSettings(null);
+    getForgeSettings(projectId)
+      .then((data) => {
+        if (cancelled) return;
+        setSettings(data);
+        setApiUrl(data.api_url ?? "");
+      })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId]);
+
+  if (!projectId || !settings) {
+    return null;
+  }
+
+  const save = async (kind: ForgeKind | null, api_url: string) => {
+    setError(null);
+    try {
+      const next = await updateForgeSettings(projectId, { kind, api_url: api_url.trim() || null });
+      setSettings(next);
+      setApiUrl(next.api_url ?? "");
+    } catch {
+      setError("Failed to save forge settings");
+    }
+  };
+
+  const effective = settings.kind ?? settings.detected;
+  const input =
+    "rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs text-[var(--color-text)]";
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center gap-2 select-none">
+        <GitPullRequest className="w-4 h-4 text-[var(--color-info)]" />
+        <span className="text-xs font-medium text-[var(--color-text-muted)] uppercase tracking-wider">
+          Forge · {selectedProject?.name}
+        </span>
+      </div>
+      <div className="flex flex-wrap items-center gap-2 text-xs text-[var(--color-text-muted)]">
+        <span>Pull requests go to</span>
+        <select
+          value={settings.kind ?? ""}
+          onChange={(e) => void save((e.target.value || null) as ForgeKind | null, apiUrl)}
+          className={input}
+        >
+          <option value="">Auto-detect ({forgeLabel(settings.detected)})</option>
+          {FORGES.map(({ value, label }) => (
+            <option key={value} value={value}>{label}</option>
+          ))}
+        </select>
+        <input
+          value={apiUrl}
+          onChange={(e) => setApiUrl(e.target.value)}
+          onBlur={() => {
+            if (apiUrl.trim() !== (settings.api_url ?? "")) void save(settings.kind ?? null, apiUrl);
+          }}
+          placeholder="API root (self-hosted, optional)"
+          className={`${input} min-w-[16rem] flex-1`}
+        />
+      </div>
+      {effective && !settings.token_configured && (
+        <div className="text-xs text-[var(--color-warning)]">
+          No token — add <code>[{effective}] token = "…"</code> to config.toml
+        </div>
+      )}
+      {error && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+    </div>
+  );
+}
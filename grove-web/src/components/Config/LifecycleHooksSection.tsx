@@ -0,0 +1,160 @@
+import { useEffect, useState } from "react";
+import { Plus, Trash2, Workflow } from "lucide-react";
+import {
+  getLifecycleHooks,
+  updateLifecycleHooks,
+  type HookEvent,
+  type LifecycleHookSettings,
+} from "../../api";
+import { useProject } from "../../context";
+
+const EVENTS: { value: HookEvent; label: string }[] = [
+  { value: "post_create", label: "Post-create" },
+  { value: "pre_merge", label: "Pre-merge" },
+  { value: "post_merge", label: "Post-merge" },
+];
+
+/**
+ * Task lifecycle hooks of the selected project inside Settings → Agent:
+ * commands run in the task worktree after creation, before and after a merge.
+ * Blocking failures abort task creation / the merge, the others only warn.
+ */
+export function LifecycleHooksSection() {
+  const { selectedProject } = useProject();
+  const projectId = selectedProject?.id;
+  const [settings, setSettings] = useState<LifecycleHookSettings | null>(null);
+  const [dirty, setDirty] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!projectId) return;
+    let cancelled = false;
+    setSettings(null);
+    setDirty(false);
+    getLifecycleHooks(projectId)
+      .then((s) => { if (!cancelled) setSettings(s); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId]);
+
+  if (!projectId || !settings) {
+    return null;
+  }
+
+  const patch = (next: Partial<LifecycleHookSettings>) => {
+    setSettings({ ...settings, ...next });
+    setDirty(true);
+  };
+
+  const save = async () => {
+    setError(null);
+    try {
+      setSettings(await updateLifecycleHooks(projectId, settings));
+      setDirty(false);
+    } catch (err: unknown) {
+      const msg = err && typeof err === "object" && "message" in err ? String((err as { message: string }).message) : "";
+      setError(msg || "Failed to save lifecycle hooks");
+    }
+  };
+
+  const input =
+    "rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs text-[var(--color-text)]";
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center justify-between gap-2">
+        <div className="flex items-center gap-2 select-none">
+          <Workflow className="w-4 h-4 text-[var(--color-info)]" />
+          <span className="text-xs font-medium text-[var(--color-text-muted)] uppercase tracking-wider">
+            Lifecycle Hooks · {selectedProject?.name}
+          </span>
+        </div>
+        <div className="flex items-center gap-2">
+          <button
+            onClick={() => patch({ hooks: [...settings.hooks, { event: "post_create", command: "" }] })}
+            className="flex items-center gap-1 text-xs text-[var(--color-text-muted)] hover:text-[var(--color-highlight)] transition-colors"
+          >
+            <Plus className="w-3.5 h-3.5" />
+            Add
+          </button>
+          {dirty && (
+            <button
+              onClick={() => void save()}
+              className="px-2 py-1 text-xs rounded-md bg-[var(--color-highlight)] text-white hover:opacity-90 transition-opacity"
+            >
+              Save
+            </button>
+          )}
+        </div>
+      </div>
+      {settings.hooks.map((hook, index) => (
+        <div key={index} className="flex flex-wrap items-center gap-2">
+          <select
+            value={hook.event}
+            onChange={(e) =>
+              patch({
+                hooks: settings.hooks.map((h, i) => (i === index ? { ...h, event: e.target.value as HookEvent } : h)),
+              })
+            }
+            className={input}
+            aria-label="Hook event"
+          >
+            {EVENTS.map(({ value, label }) => (
+              <option key={value} value={value}>{label}</option>
+            ))}
+          </select>
+          <input
+            value={hook.command}
+            onChange={(e) =>
+              patch({ hooks: settings.hooks.map((h, i) => (i === index ? { ...h, command: e.target.value } : h)) })
+            }
+            placeholder="npm install, cargo test, …"
+            className={`${input} min-w-[12rem] flex-1 font-mono`}
+          />
+          <label className="flex items-center gap-1 text-xs text-[var(--color-text-muted)]">
+            <input
+              type="checkbox"
+              checked={hook.event !== "post_merge" && (hook.blocking ?? hook.event === "pre_merge")}
+              disabled={hook.event === "post_merge"}
+              onChange={(e) =>
+                patch({ hooks: settings.hooks.map((h, i) => (i === index ? { ...h, blocking: e.target.checked } : h)) })
+              }
+            />
+            Blocking
+          </label>
+          <button
+            onClick={() => patch({ hooks: settings.hooks.filter((_, i) => i !== index) })}
+            className="p-1 text-[var(--color-text-muted)] hover:text-[var(--color-error)] transition-colors"
+            title="Remove hook"
+          >
+            <Trash2 className="w-3.5 h-3.5" />
+          </button>
+        </div>
+      ))}
+      <div className="flex flex-wrap items-center gap-4 text-xs text-[var(--color-text-muted)]">
+        <label className="flex items-center gap-1.5">
+          <input
+            type="checkbox"
+            checked={settings.run_repo_scripts}
+            onChange={(e) => patch({ run_repo_scripts: e.target.checked })}
+          />
+          Run <code className="font-mono">.grove/hooks/</code> scripts from the repo (only for repos you trust)
+        </label>
+        <label className="flex items-center gap-1.5">
+          Timeout
+          <input
+            type="number"
+            min={1}
+            value={settings.timeout_secs}
+            onChange={(e) => patch({ timeout_secs: Number(e.target.value) })}
+            className={`${input} w-20`}
+          />
+          s
+        </label>
+      </div>
+      {error && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+    </div>
+  );
+}
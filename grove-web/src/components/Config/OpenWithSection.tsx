@@ -0,0 +1,121 @@
+import { useEffect, useState } from "react";
+import { AppWindow, ArrowDown, ArrowUp, Plus, Trash2 } from "lucide-react";
+import { Button, Input } from "../ui";
+import { getConfig, patchConfig, type OpenWithApp } from "../../api";
+
+/**
+ * User-defined "Open With" applications inside Settings → General. Each entry
+ * is a shell command template run in the task worktree; the list order is the
+ * order shown in the task "Open With" menu and the TUI picker.
+ */
+export function OpenWithSection() {
+  const [apps, setApps] = useState<OpenWithApp[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    getConfig()
+      .then((cfg) => { if (!cancelled) setApps(cfg.open_with); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, []);
+
+  const save = async (next: OpenWithApp[]) => {
+    setError(null);
+    try {
+      const cfg = await patchConfig({ open_with: next });
+      setApps(cfg.open_with);
+    } catch {
+      setError("Names and commands must be non-empty and names unique");
+    }
+  };
+
+  const update = (index: number, field: keyof OpenWithApp, value: string) => {
+    setApps((prev) => prev && prev.map((app, i) => (i === index ? { ...app, [field]: value } : app)));
+  };
+
+  const move = (index: number, delta: number) => {
+    if (!apps) return;
+    const target = index + delta;
+    if (target < 0 || target >= apps.length) return;
+    const next = [...apps];
+    [next[index], next[target]] = [next[target], next[index]];
+    save(next);
+  };
+
+  if (!apps) {
+    return null;
+  }
+
+  const complete = apps.every((a) => a.name.trim() && a.command.trim());
+
+  return (
+    <div>
+      <div className="flex items-center gap-2 mb-3 select-none">
+        <AppWindow className="w-4 h-4 text-[var(--color-info)]" />
+        <span className="text-sm font-medium text-[var(--color-text)]">Open With</span>
+      </div>
+      <div className="space-y-2">
+        {apps.map((app, i) => (
+          <div key={i} className="grid grid-cols-[140px_1fr_auto] gap-2 items-center">
+            <Input
+              placeholder="Name"
+              value={app.name}
+              onChange={(e) => update(i, "name", e.target.value)}
+              onBlur={() => { if (complete) save(apps); }}
+            />
+            <Input
+              placeholder="fork {path}"
+              value={app.command}
+              onChange={(e) => update(i, "command", e.target.value)}
+              onBlur={() => { if (complete) save(apps); }}
+              className="font-mono"
+            />
+            <div className="flex items-center">
+              <button
+                onClick={() => move(i, -1)}
+                disabled={i === 0}
+                className="p-1.5 rounded text-[var(--color-text-muted)] hover:text-[var(--color-text)] disabled:opacity-30"
+                title="Move up"
+              >
+                <ArrowUp className="w-3.5 h-3.5" />
+              </button>
+              <button
+                onClick={() => move(i, 1)}
+                disabled={i === apps.length - 1}
+                className="p-1.5 rounded text-[var(--color-text-muted)] hover:text-[var(--color-text)] disabled:opacity-30"
+                title="Move down"
+              >
+                <ArrowDown className="w-3.5 h-3.5" />
+              </button>
+              <button
+                onClick={() => save(apps.filter((_, j) => j !== i))}
+                className="p-1.5 rounded text-[var(--color-text-muted)] hover:text-[var(--color-error)]"
+                title="Remove"
+              >
+                <Trash2 className="w-3.5 h-3.5" />
+              </button>
+            </div>
+          </div>
+        ))}
+      </div>
+      <div className="flex items-center gap-3 mt-3">
+        <Button
+          size="sm"
+          variant="secondary"
+          disabled={!complete}
+          onClick={() => setApps([...apps, { name: "", command: "" }])}
+        >
+          <Plus className="w-4 h-4" />
+          Add application
+        </Button>
+        <span className="text-xs text-[var(--color-text-muted)]">
+          Runs in the task worktree; <code>{"{path}"}</code> and <code>{"{branch}"}</code> are replaced (quoted).
+        </span>
+      </div>
+      {error && (
+        <div className="mt-2 px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,104 @@
+import { useEffect, useState } from "react";
+import { Loader2, Upload } from "lucide-react";
+import { Button, Input } from "../ui";
+import { getConfig, patchConfig, getOrgStatus, publishToTeam, type OrganizationConfig, type OrgStatus } from "../../api";
+import { formatTimeAgo } from "../../utils/notificationFormat";
+
+/**
+ * Organization mode inside Settings: connects to a team Grove server that
+ * holds task metadata, notes and review comments so teammates can review each
+ * other's agent tasks. Worktrees stay on this machine.
+ */
+export function OrganizationSection() {
+  const [org, setOrg] = useState<OrganizationConfig | null>(null);
+  const [serverUrl, setServerUrl] = useState("");
+  const [token, setToken] = useState("");
+  const [status, setStatus] = useState<OrgStatus | null>(null);
+  const [publishing, setPublishing] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    getConfig()
+      .then((cfg) => {
+        if (cancelled) return;
+        setOrg(cfg.organization);
+        setServerUrl(cfg.organization.server_url ?? "");
+      })
+      .catch(() => {});
+    getOrgStatus()
+      .then((s) => { if (!cancelled) setStatus(s); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, []);
+
+  const save = async (patch: { server_url?: string; token?: string }) => {
+    setError(null);
+    try {
+      const cfg = await patchConfig({ organization: patch });
+      setOrg(cfg.organization);
+      setStatus(await getOrgStatus());
+    } catch {
+      setError("Failed to save organization settings");
+    }
+  };
+
+  const handlePublish = async () => {
+    setPublishing(true);
+    setError(null);
+    try {
+      setStatus(await publishToTeam());
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Publish failed");
+      getOrgStatus().then(setStatus).catch(() => {});
+    } finally {
+      setPublishing(false);
+    }
+  };
+
+  if (!org) {
+    return <div className="text-sm text-[var(--color-text-muted)]">Loading…</div>;
+  }
+
+  const lastError = error ?? status?.last_error;
+
+  return (
+    <div className="space-y-4">
+      <div className="grid grid-cols-2 gap-3">
+        <Input
+          label="Team server"
+          placeholder="https://grove.example.com"
+          value={serverUrl}
+          onChange={(e) => setServerUrl(e.target.value)}
+          onBlur={() => { if (serverUrl.trim() !== (org.server_url ?? "")) save({ server_url: serverUrl.trim() }); }}
+        />
+        <Input
+          label="Token"
+          type="password"
+          placeholder={org.has_token ? "••••••••  (saved)" : "Your token from the server admin"}
+          value={token}
+          onChange={(e) => setToken(e.target.value)}
+          onBlur={() => { if (token.trim()) { save({ token: token.trim() }); setToken(""); } }}
+        />
+      </div>
+      <p className="text-xs text-[var(--color-text-muted)]">
+        Active tasks, notes and review comments are published every minute under the name your token was issued to; worktrees stay local. Teammates see tasks of projects with the same origin remote.
+        {org.serving && " This machine is also serving as a team server."}
+      </p>
+      <div className="flex items-center gap-3">
+        <Button size="sm" variant="secondary" onClick={handlePublish} disabled={publishing || !status?.configured}>
+          {publishing ? <Loader2 className="w-4 h-4 animate-spin" /> : <Upload className="w-4 h-4" />}
+          Publish now
+        </Button>
+        <span className="text-xs text-[var(--color-text-muted)]">
+          {status?.last_run_at
+            ? `Last run ${formatTimeAgo(status.last_run_at)} · ${status.published} tasks published${status.member ? ` as ${status.member}` : ""}`
+            : "Not published yet"}
+        </span>
+      </div>
+      {lastError && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{lastError}</div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,148 @@
+import { useEffect, useState } from "react";
+import { Plus, ScrollText, Trash2 } from "lucide-react";
+import { Button, Input } from "../ui";
+import {
+  getGlobalPreamble,
+  getProjectPreamble,
+  updateGlobalPreamble,
+  updateProjectPreamble,
+  type InstructionPreamble,
+} from "../../api";
+import { useProject } from "../../context";
+
+interface AgentEntry {
+  agent: string;
+  text: string;
+}
+
+const textareaClass =
+  "w-full min-h-[72px] rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-3 py-2 text-sm text-[var(--color-text)] font-mono outline-none focus:border-[var(--color-highlight)] resize-y";
+
+/**
+ * Instruction preambles inside Settings → Agent. The global preamble and the
+ * selected project's preamble are prepended (as a hidden context block) to
+ * the first prompt of every new chat session, each optionally narrowed to a
+ * single agent. Changes apply to new sessions only.
+ */
+export function PreambleSection() {
+  const { selectedProject } = useProject();
+  const [scope, setScope] = useState<"global" | "project">("global");
+  const [preamble, setPreamble] = useState("");
+  const [agents, setAgents] = useState<AgentEntry[]>([]);
+  const [loaded, setLoaded] = useState(false);
+  const [status, setStatus] = useState<string | null>(null);
+
+  const projectId = scope === "project" ? selectedProject?.id : undefined;
+
+  useEffect(() => {
+    let cancelled = false;
+    setLoaded(false);
+    setStatus(null);
+    const load = projectId ? getProjectPreamble(projectId) : getGlobalPreamble();
+    load
+      .then((data) => {
+        if (cancelled) return;
+        setPreamble(data.preamble);
+        setAgents(Object.entries(data.agents ?? {}).map(([agent, text]) => ({ agent, text })));
+        setLoaded(true);
+      })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId]);
+
+  const save = async () => {
+    const body: InstructionPreamble = {
+      preamble,
+      agents: Object.fromEntries(agents.map((e) => [e.agent, e.text])),
+    };
+    try {
+      const saved = projectId
+        ? await updateProjectPreamble(projectId, body)
+        : await updateGlobalPreamble(body);
+      setPreamble(saved.preamble);
+      setAgents(Object.entries(saved.agents ?? {}).map(([agent, text]) => ({ agent, text })));
+      setStatus("Saved — applies to new chat sessions");
+    } catch {
+      setStatus("Failed to save preamble");
+    }
+  };
+
+  const update = (index: number, field: keyof AgentEntry, value: string) => {
+    setAgents((prev) => prev.map((e, i) => (i === index ? { ...e, [field]: value } : e)));
+  };
+
+  return (
+    <div className="space-y-2">
+      <div className="flex flex-wrap items-center justify-between gap-3">
+        <div className="flex items-center gap-2 select-none">
+          <ScrollText className="w-4 h-4 text-[var(--color-info)]" />
+          <span className="text-xs font-medium text-[var(--color-text-muted)] uppercase tracking-wider">Instruction Preamble</span>
+        </div>
+        <div className="inline-flex rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] p-0.5">
+          {(["global", "project"] as const).map((s) => (
+            <button
+              key={s}
+              type="button"
+              disabled={s === "project" && !selectedProject}
+              onClick={() => setScope(s)}
+              className={`rounded px-3 py-1.5 text-xs font-medium transition-colors disabled:opacity-40 ${
+                scope === s
+                  ? "bg-[var(--color-highlight)] text-white"
+                  : "text-[var(--color-text-muted)] hover:text-[var(--color-text)]"
+              }`}
+            >
+              {s === "global" ? "Global" : selectedProject?.name ?? "Project"}
+            </button>
+          ))}
+        </div>
+      </div>
+      {loaded && (
+        <>
+          <textarea
+            value={preamble}
+            onChange={(e) => setPreamble(e.target.value)}
+            placeholder="Instructions for every agent…"
+            className={textareaClass}
+            aria-label="Preamble for all agents"
+          />
+          {agents.map((entry, i) => (
+            <div key={i} className="grid grid-cols-[140px_1fr_auto] gap-2 items-start">
+              <Input
+                placeholder="Agent id"
+                value={entry.agent}
+                onChange={(e) => update(i, "agent", e.target.value)}
+                className="font-mono"
+              />
+              <textarea
+                value={entry.text}
+                onChange={(e) => update(i, "text", e.target.value)}
+                placeholder="Instructions for this agent only…"
+                className={textareaClass}
+              />
+              <button
+                onClick={() => setAgents(agents.filter((_, j) => j !== i))}
+                className="p-1.5 rounded text-[var(--color-text-muted)] hover:text-[var(--color-error)]"
+                title="Remove"
+              >
+                <Trash2 className="w-3.5 h-3.5" />
+              </button>
+            </div>
+          ))}
+          <div className="flex items-center gap-3">
+            <Button size="sm" variant="secondary" onClick={() => setAgents([...agents, { agent: "", text: "" }])}>
+              <Plus className="w-4 h-4" />
+              Add agent preamble
+            </Button>
+            <Button size="sm" onClick={save}>
+              Save
+            </Button>
+            {status && <span className="text-xs text-[var(--color-text-muted)]">{status}</span>}
+          </div>
+        </>
+      )}
+      <p className="text-xs leading-relaxed text-[var(--color-text-muted)]">
+        Prepended to the first prompt of each new chat session (global, then project) and recorded in the turn manifest.
+      </p>
+    </div>
+  );
+}
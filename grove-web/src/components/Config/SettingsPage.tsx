@@ -44,6 +44,7 @@ import {
   listApplications,
   listCustomAgents,
   type AppInfo,
+  type AutoLinkMode,
   type CustomAgentServer,
   type CustomAgentPersona,
 } from "../../api";
@@ -62,6 +63,19 @@ import {
 } from "../../utils/agentIcon";
 import { getExtensionStatus } from "../../api/extension";
 import { PluginsSection } from "./PluginsSection";
+import { SyncSection } from "./SyncSection";
+import { OpenWithSection } from "./OpenWithSection";
+import { PreambleSection } from "./PreambleSection";
+import { ChatDefaultsSection } from "./ChatDefaultsSection";
+import { TaskSummarySection } from "./TaskSummarySection";
+import { ForgeSection } from "./ForgeSection";
+import { TemplatesSection } from "./TemplatesSection";
+import { LifecycleHooksSection } from "./LifecycleHooksSection";
+import { DoNotDisturbSection } from "./DoNotDisturbSection";
+import { TimeDisplaySection } from "./TimeDisplaySection";
+import { StatusPaletteSection } from "./StatusPaletteSection";
+import { TuiThemeSyncSection } from "./TuiThemeSyncSection";
+import { OrganizationSection } from "./OrganizationSection";
 import { formatShortcut } from "../AI/utils";
 import { useKeyboardScope } from "../../keyboard";
 
@@ -229,6 +243,8 @@ export function SettingsPage({ config }: SettingsPageProps) {
     browserControl: false,
     shortcuts: false,
     plugins: false,
+    sync: false,
+    organization: false,
   });
 
   // Environment state
@@ -317,6 +333,9 @@ export function SettingsPage({ config }: SettingsPageProps) {
 
   // AutoLink state
   const [autoLinkPatterns, setAutoLinkPatterns] = useState<string[]>([]);
+  const [autoLinkModes, setAutoLinkModes] = useState<Record<string, AutoLinkMode>>({});
+  const [autoLinkCopyMaxMb, setAutoLinkCopyMaxMb] = useState(100);
+  const [postCreateCommands, setPostCreateCommands] = useState("");
 
   // Symbol indexing state (cmd+click navigation)
   const [indexingEnabled, setIndexingEnabled] = useState(true);
@@ -451,6 +470,9 @@ export function SettingsPage({ config }: SettingsPageProps) {
 
     // Load AutoLink config
     setAutoLinkPatterns(cfg.auto_link.patterns);
+    setAutoLinkModes(cfg.auto_link.modes ?? {});
+    setAutoLinkCopyMaxMb(cfg.auto_link.copy_max_mb ?? 100);
+    setPostCreateCommands((cfg.auto_link.post_create ?? []).join("\n"));
 
     // Load ACP config
     const acp = cfg.acp;
@@ -682,6 +704,9 @@ export function SettingsPage({ config }: SettingsPageProps) {
       },
       auto_link: {
         patterns: autoLinkPatterns,
+        modes: autoLinkModes,
+        copy_max_mb: autoLinkCopyMaxMb,
+        post_create: postCreateCommands.split("\n").map((c) => c.trim()).filter(Boolean),
       },
       hooks: {
         response_sound_enabled: hooksResponseSoundEnabled,
@@ -720,7 +745,7 @@ export function SettingsPage({ config }: SettingsPageProps) {
     } catch {
       console.error("Failed to save config");
     }
-  }, [isLoaded, selectedLayout, agentCommand, acpAgent, chatRenderWindowLimit, chatRenderWindowTrigger, customLayouts, selectedCustomLayoutId, customLayoutsLoaded, ideCommand, terminalCommand, terminalMultiplexer, webTerminalMode, workspaceLayout, showHideWindowShortcut, autoLinkPatterns, hooksResponseSoundEnabled, hooksResponseSound, hooksPermissionSoundEnabled, hooksPermissionSound, trayEnabled, trayShowPermission, trayShowDone, trayShowRunning, menubarShortcut, systemNotifEnabled, systemNotifShowPermission, systemNotifShowDone, systemNotifShowRunning, trayDoneRetentionMode, trayDoneRetentionUnit, trayDoneRetentionValue, indexingEnabled, indexingDisabledLangs, browserControlEnabled, browserControlAutoGroups, refreshGlobalConfig]);
+  }, [isLoaded, selectedLayout, agentCommand, acpAgent, chatRenderWindowLimit, chatRenderWindowTrigger, customLayouts, selectedCustomLayoutId, customLayoutsLoaded, ideCommand, terminalCommand, terminalMultiplexer, webTerminalMode, workspaceLayout, showHideWindowShortcut, autoLinkPatterns, autoLinkModes, autoLinkCopyMaxMb, postCreateCommands, hooksResponseSoundEnabled, hooksResponseSound, hooksPermissionSoundEnabled, hooksPermissionSound, trayEnabled, trayShowPermission, trayShowDone, trayShowRunning, menubarShortcut, systemNotifEnabled, systemNotifShowPermission, systemNotifShowDone, systemNotifShowRunning, trayDoneRetentionMode, trayDoneRetentionUnit, trayDoneRetentionValue, indexingEnabled, indexingDisabledLangs, browserControlEnabled, browserControlAutoGroups, refreshGlobalConfig]);
 
   // Handle theme change with immediate save
   const handleModeChange = useCallback((newMode: "auto" | "light" | "dark") => {
@@ -856,7 +881,7 @@ export function SettingsPage({ config }: SettingsPageProps) {
     }, 500); // 500ms debounce
 
     return () => clearTimeout(timer);
-  }, [selectedLayout, agentCommand, acpAgent, chatRenderWindowLimit, chatRenderWindowTrigger, customLayouts, selectedCustomLayoutId, customLayoutsLoaded, ideCommand, terminalCommand, terminalMultiplexer, webTerminalMode, workspaceLayout, showHideWindowShortcut, autoLinkPatterns, hooksResponseSoundEnabled, hooksResponseSound, hooksPermissionSoundEnabled, hooksPermissionSound, trayEnabled, trayShowPermission, trayShowDone, trayShowRunning, menubarShortcut, systemNotifEnabled, systemNotifShowPermission, systemNotifShowDone, systemNotifShowRunning, indexingEnabled, indexingDisabledLangs, browserControlEnabled, browserControlAutoGroups, isLoaded, saveConfig]);
+  }, [selectedLayout, agentCommand, acpAgent, chatRenderWindowLimit, chatRenderWindowTrigger, customLayouts, selectedCustomLayoutId, customLayoutsLoaded, ideCommand, terminalCommand, terminalMultiplexer, webTerminalMode, workspaceLayout, showHideWindowShortcut, autoLinkPatterns, autoLinkModes, autoLinkCopyMaxMb, postCreateCommands, hooksResponseSoundEnabled, hooksResponseSound, hooksPermissionSoundEnabled, hooksPermissionSound, trayEnabled, trayShowPermission, trayShowDone, trayShowRunning, menubarShortcut, systemNotifEnabled, systemNotifShowPermission, systemNotifShowDone, systemNotifShowRunning, indexingEnabled, indexingDisabledLangs, browserControlEnabled, browserControlAutoGroups, isLoaded, saveConfig]);
 
   useEffect(() => {
     if (!isRecordingWindowShortcut) return;
@@ -1456,6 +1481,24 @@ env_vars = [
               />
             </div>
 
+            {/* Instruction preamble (global / project, optionally per agent) */}
+            <PreambleSection />
+
+            {/* Per-project mode/model defaults for new chats */}
+            <ChatDefaultsSection />
+
+            {/* Automatic "What was done" notes on turn end / merge */}
+            <TaskSummarySection />
+
+            {/* GitHub / GitLab / Gitea for pull / merge requests */}
+            <ForgeSection />
+
+            {/* Named presets for new tasks */}
+            <TemplatesSection />
+
+            {/* post-create / pre-merge / post-merge commands */}
+            <LifecycleHooksSection />
+
             {/* Chat render window */}
             <div className="space-y-2">
               <div className="flex flex-wrap items-center justify-between gap-3">
@@ -2013,6 +2056,12 @@ env_vars = [
                 </div>
               </div>
             )}
+
+            <TimeDisplaySection />
+
+            <StatusPaletteSection />
+
+            <TuiThemeSyncSection />
           </div>
         </Section>
 
@@ -2020,7 +2069,7 @@ env_vars = [
         <Section
           id="devtools"
           title="General"
-          description="Default IDE and terminal application"
+          description="Default IDE, terminal and Open With applications"
           icon={Wrench}
           iconColor="#64748b"
           isOpen={openSections.devtools}
@@ -2121,6 +2170,8 @@ env_vars = [
                 </button>
               </div>
             </div>
+
+            <OpenWithSection />
           </div>
         </Section>
 
@@ -2186,10 +2237,29 @@ env_vars = [
                         const newPatterns = [...autoLinkPatterns];
                         newPatterns[index] = e.target.value;
                         setAutoLinkPatterns(newPatterns);
+                        // Keep the link mode attached to the renamed pattern
+                        const mode = autoLinkModes[pattern];
+                        if (mode) {
+                          const nextModes = { ...autoLinkModes };
+                          delete nextModes[pattern];
+                          setAutoLinkModes({ ...nextModes, [e.target.value]: mode });
+                        }
                       }}
                       placeholder="e.g., node_modules or **/dist"
                       className="flex-1 px-3 py-2 text-sm bg-[var(--color-bg)] border border-[var(--color-border)] rounded-lg focus:outline-none focus:ring-2 focus:ring-[var(--color-accent)] font-mono"
                     />
+                    <select
+                      value={autoLinkModes[pattern] ?? "symlink"}
+                      onChange={(e) =>
+                        setAutoLinkModes({ ...autoLinkModes, [pattern]: e.target.value as AutoLinkMode })
+                      }
+                      title="How matching paths are brought into new worktrees"
+                      className="px-2 py-2 text-sm bg-[var(--color-bg)] border border-[var(--color-border)] rounded-lg focus:outline-none focus:ring-2 focus:ring-[var(--color-accent)]"
+                    >
+                      <option value="symlink">Symlink</option>
+                      <option value="copy">Copy</option>
+                      <option value="hardlink">Hardlink</option>
+                    </select>
                     <button
                       onClick={() => {
                         setAutoLinkPatterns(autoLinkPatterns.filter((_, i) => i !== index));
@@ -2208,6 +2278,44 @@ env_vars = [
                 )}
               </div>
 
+              {/* copy 模式大小上限 */}
+              <div className="flex items-center justify-between mb-4">
+                <div className="select-none">
+                  <h4 className="text-sm font-medium text-[var(--color-text)]">Copy Size Limit</h4>
+                  <p className="text-xs text-[var(--color-text-muted)] mt-1">
+                    Paths larger than this are skipped in Copy mode (MB). Use Copy for tools that
+                    can't follow symlinks, such as Docker build contexts.
+                  </p>
+                </div>
+                <input
+                  type="number"
+                  min={1}
+                  value={autoLinkCopyMaxMb}
+                  onChange={(e) => setAutoLinkCopyMaxMb(Math.max(1, Number(e.target.value) || 1))}
+                  className="w-24 px-3 py-2 text-sm bg-[var(--color-bg)] border border-[var(--color-border)] rounded-lg focus:outline-none focus:ring-2 focus:ring-[var(--color-accent)]"
+                />
+              </div>
+
+              {/* worktree 创建后执行的命令 */}
+              <div className="mb-4">
+                <div className="select-none mb-2">
+                  <h4 className="text-sm font-medium text-[var(--color-text)]">Post-create Commands</h4>
+                  <p className="text-xs text-[var(--color-text-muted)] mt-1">
+                    One per line, run in each new worktree. <code className="font-mono">{"{branch}"}</code>,{" "}
+                    <code className="font-mono">{"{task_id}"}</code> and{" "}
+                    <code className="font-mono">{"{target}"}</code> are expanded here and in patterns above
+                    (e.g. <code className="font-mono">{".env.{branch}"}</code>).
+                  </p>
+                </div>
+                <textarea
+                  value={postCreateCommands}
+                  onChange={(e) => setPostCreateCommands(e.target.value)}
+                  rows={3}
+                  placeholder="e.g., cp .env.example .env.{branch}"
+                  className="w-full px-3 py-2 text-sm bg-[var(--color-bg)] border border-[var(--color-border)] rounded-lg focus:outline-none focus:ring-2 focus:ring-[var(--color-accent)] font-mono"
+                />
+              </div>
+
               {/* 预设模板 */}
               <div className="p-3 bg-[var(--color-bg-secondary)] rounded-lg mb-3">
                 <h5 className="text-xs font-medium text-[var(--color-text)] mb-2 select-none">
@@ -2450,6 +2558,9 @@ env_vars = [
           onToggle={() => toggleSection("hooks")}
         >
           <div className="space-y-4">
+            {/* Do Not Disturb — manual toggle + daily quiet hours */}
+            <DoNotDisturbSection />
+
             {/* System Notifications card — sound + banner per event type */}
             <div className="rounded-xl border border-[var(--color-border)] bg-[var(--color-bg-secondary)] p-4">
               <div className="flex items-start justify-between gap-3 mb-3">
@@ -2791,6 +2902,32 @@ env_vars = [
           <PluginsSection />
         </Section>
 
+        {/* Sync Section */}
+        <Section
+          id="sync"
+          title="Sync"
+          description="Keep tasks, review comments and notes in sync between machines via a git remote"
+          icon={RefreshCw}
+          iconColor="#0ea5e9"
+          isOpen={openSections.sync}
+          onToggle={() => toggleSection("sync")}
+        >
+          <SyncSection />
+        </Section>
+
+        {/* Organization Section */}
+        <Section
+          id="organization"
+          title="Organization"
+          description="Share task metadata, notes and review comments with teammates through a team server"
+          icon={UserCog}
+          iconColor="#f59e0b"
+          isOpen={openSections.organization}
+          onToggle={() => toggleSection("organization")}
+        >
+          <OrganizationSection />
+        </Section>
+
       </div>
 
       {/* Custom Agent Servers Modal (existing) */}
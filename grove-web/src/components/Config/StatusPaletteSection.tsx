@@ -0,0 +1,56 @@
+import { Eye } from "lucide-react";
+import type { StatusPalette } from "../../api";
+import { useTheme } from "../../context";
+
+const PALETTES: { value: StatusPalette; label: string }[] = [
+  { value: "default", label: "Theme default" },
+  { value: "deuteranopia", label: "Deuteranopia safe" },
+  { value: "protanopia", label: "Protanopia safe" },
+];
+
+const SWATCHES: { key: "success" | "warning" | "error"; label: string }[] = [
+  { key: "success", label: "Live" },
+  { key: "warning", label: "Conflict" },
+  { key: "error", label: "Error" },
+];
+
+/**
+ * Status color card inside Settings → Appearance. Swaps the red / green
+ * status colors for a color-blind safe palette in the web UI, plugins and the
+ * TUI (Config panel → Status Colors).
+ */
+export function StatusPaletteSection() {
+  const { theme, statusPalette, setAppearance } = useTheme();
+
+  return (
+    <div className="rounded-xl border border-[var(--color-border)] bg-[var(--color-bg-secondary)] p-4 space-y-3">
+      <div>
+        <div className="flex items-center gap-1.5 text-sm font-semibold text-[var(--color-text)]">
+          <Eye className="w-3.5 h-3.5" />
+          Status Colors
+        </div>
+        <div className="text-xs text-[var(--color-text-muted)] mt-0.5">
+          Replace red / green status colors with a palette that stays distinguishable with color vision deficiency
+        </div>
+      </div>
+      <div className="flex flex-wrap items-center gap-3 text-xs text-[var(--color-text)]">
+        <select
+          value={statusPalette}
+          onChange={(e) => void setAppearance({ statusPalette: e.target.value as StatusPalette })}
+          className="rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs"
+          aria-label="Status color palette"
+        >
+          {PALETTES.map(({ value, label }) => (
+            <option key={value} value={value}>{label}</option>
+          ))}
+        </select>
+        {SWATCHES.map(({ key, label }) => (
+          <span key={key} className="flex items-center gap-1.5 text-[var(--color-text-muted)]">
+            <span className="w-2.5 h-2.5 rounded-full" style={{ backgroundColor: theme.colors[key] }} />
+            {label}
+          </span>
+        ))}
+      </div>
+    </div>
+  );
+}
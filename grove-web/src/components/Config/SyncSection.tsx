@@ -0,0 +1,112 @@
+import { useEffect, useState } from "react";
+import { Loader2, RefreshCw } from "lucide-react";
+import { Button, Input } from "../ui";
+import { Checkbox } from "../ui/Checkbox";
+import { getConfig, patchConfig, getSyncStatus, runSync, type SyncConfig, type SyncStatus } from "../../api";
+import { formatTimeAgo } from "../../utils/notificationFormat";
+
+/**
+ * Workspace sync inside Settings: pushes task list, review comments and notes
+ * to a user-provided git remote so they follow you between machines.
+ * Worktrees and chats stay local.
+ */
+export function SyncSection() {
+  const [sync, setSync] = useState<SyncConfig | null>(null);
+  const [remote, setRemote] = useState("");
+  const [status, setStatus] = useState<SyncStatus | null>(null);
+  const [running, setRunning] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    getConfig()
+      .then((cfg) => {
+        if (cancelled) return;
+        setSync(cfg.sync);
+        setRemote(cfg.sync.remote ?? "");
+      })
+      .catch(() => {});
+    getSyncStatus()
+      .then((s) => { if (!cancelled) setStatus(s); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, []);
+
+  const save = async (patch: Partial<SyncConfig>) => {
+    setError(null);
+    try {
+      const cfg = await patchConfig({ sync: patch });
+      setSync(cfg.sync);
+      setRemote(cfg.sync.remote ?? "");
+    } catch {
+      setError("Failed to save sync settings");
+    }
+  };
+
+  const handleSyncNow = async () => {
+    setRunning(true);
+    setError(null);
+    try {
+      setStatus(await runSync());
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Sync failed");
+      getSyncStatus().then(setStatus).catch(() => {});
+    } finally {
+      setRunning(false);
+    }
+  };
+
+  if (!sync) {
+    return <div className="text-sm text-[var(--color-text-muted)]">Loading…</div>;
+  }
+
+  const lastError = error ?? status?.last_error;
+
+  return (
+    <div className="space-y-4">
+      <Checkbox
+        checked={sync.enabled}
+        onChange={(enabled) => save({ enabled })}
+        label={<span className="text-sm text-[var(--color-text)]">Sync automatically while Grove is running</span>}
+      />
+      <div className="grid grid-cols-[1fr_auto] gap-3 items-end">
+        <Input
+          label="Remote"
+          placeholder="git@github.com:you/grove-sync.git"
+          value={remote}
+          onChange={(e) => setRemote(e.target.value)}
+          onBlur={() => { if (remote.trim() !== (sync.remote ?? "")) save({ remote: remote.trim() }); }}
+        />
+        <div className="w-28">
+          <Input
+            label="Every (min)"
+            type="number"
+            min={1}
+            value={sync.interval_minutes}
+            onChange={(e) => {
+              const v = parseInt(e.target.value, 10);
+              if (v > 0) save({ interval_minutes: v });
+            }}
+          />
+        </div>
+      </div>
+      <p className="text-xs text-[var(--color-text-muted)]">
+        Use a private repository. Projects are matched across machines by their origin remote, and worktrees and chats are not synced.
+      </p>
+      <div className="flex items-center gap-3">
+        <Button size="sm" variant="secondary" onClick={handleSyncNow} disabled={running || !sync.remote}>
+          {running ? <Loader2 className="w-4 h-4 animate-spin" /> : <RefreshCw className="w-4 h-4" />}
+          Sync now
+        </Button>
+        <span className="text-xs text-[var(--color-text-muted)]">
+          {status?.last_run_at
+            ? `Last run ${formatTimeAgo(status.last_run_at)} · ${status.imported} updated · ${status.conflicts} conflicts resolved`
+            : "Not synced yet"}
+        </span>
+      </div>
+      {lastError && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{lastError}</div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,146 @@
+import { useEffect, useState } from "react";
+import { FileText } from "lucide-react";
+import {
+  getTaskSummarySettings,
+  listProviders,
+  updateTaskSummarySettings,
+  type TaskSummarySettings,
+} from "../../api";
+import type { ProviderProfile } from "../AI/types";
+import { useProject } from "../../context";
+
+const STOP_REASONS: { value: string; label: string }[] = [
+  { value: "end_turn", label: "Completed" },
+  { value: "max_tokens", label: "Max tokens" },
+  { value: "max_turn_requests", label: "Max turn requests" },
+  { value: "refusal", label: "Refusal" },
+  { value: "cancelled", label: "Cancelled" },
+];
+
+/**
+ * Automatic "What was done" summaries of the selected project inside
+ * Settings → Agent. Summaries are appended to the task notes when a turn
+ * ends with a chosen stop reason and/or right before the task is merged.
+ */
+export function TaskSummarySection() {
+  const { selectedProject } = useProject();
+  const projectId = selectedProject?.id;
+  const [settings, setSettings] = useState<TaskSummarySettings | null>(null);
+  const [providers, setProviders] = useState<ProviderProfile[]>([]);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!projectId) return;
+    let cancelled = false;
+    setSettings(null);
+    getTaskSummarySettings(projectId)
+      .then((data) => { if (!cancelled) setSettings(data); })
+      .catch(() => {});
+    listProviders()
+      .then((data) => { if (!cancelled) setProviders(data); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId]);
+
+  if (!projectId || !settings) {
+    return null;
+  }
+
+  const save = async (next: TaskSummarySettings) => {
+    setSettings(next);
+    setError(null);
+    try {
+      setSettings(await updateTaskSummarySettings(projectId, next));
+    } catch {
+      setError("Failed to save summary settings");
+    }
+  };
+
+  const reasons = settings.stop_reasons ?? [];
+  const toggleReason = (reason: string) => {
+    const next = reasons.includes(reason)
+      ? reasons.filter((r) => r !== reason)
+      : [...reasons, reason];
+    void save({ ...settings, stop_reasons: next });
+  };
+
+  const checkbox = "accent-[var(--color-highlight)]";
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center gap-2 select-none">
+        <FileText className="w-4 h-4 text-[var(--color-info)]" />
+        <span className="text-xs font-medium text-[var(--color-text-muted)] uppercase tracking-wider">
+          Task Summaries · {selectedProject?.name}
+        </span>
+      </div>
+      <label className="flex items-center gap-2 text-sm text-[var(--color-text)]">
+        <input
+          type="checkbox"
+          className={checkbox}
+          checked={settings.enabled}
+          onChange={(e) => void save({ ...settings, enabled: e.target.checked })}
+        />
+        Append a "What was done" summary to task notes
+      </label>
+      {settings.enabled && (
+        <div className="space-y-2 pl-6">
+          <div className="flex flex-wrap items-center gap-x-3 gap-y-1 text-xs text-[var(--color-text-muted)]">
+            <span>After turns ending with:</span>
+            {STOP_REASONS.map(({ value, label }) => (
+              <label key={value} className="flex items-center gap-1 text-[var(--color-text)]">
+                <input
+                  type="checkbox"
+                  className={checkbox}
+                  checked={reasons.includes(value)}
+                  onChange={() => toggleReason(value)}
+                />
+                {label}
+              </label>
+            ))}
+          </div>
+          <label className="flex items-center gap-2 text-xs text-[var(--color-text)]">
+            <input
+              type="checkbox"
+              className={checkbox}
+              checked={settings.on_merge}
+              onChange={(e) => void save({ ...settings, on_merge: e.target.checked })}
+            />
+            Summarize the whole task before merging
+          </label>
+          <label className="flex items-center gap-2 text-xs text-[var(--color-text)]">
+            <input
+              type="checkbox"
+              className={checkbox}
+              checked={settings.include_in_merge_commit}
+              onChange={(e) => void save({ ...settings, include_in_merge_commit: e.target.checked })}
+            />
+            Include summaries in the merge commit message
+          </label>
+          <div className="flex items-center gap-2 text-xs text-[var(--color-text-muted)]">
+            <span>Written by</span>
+            <select
+              value={settings.provider ?? ""}
+              onChange={(e) => void save({ ...settings, provider: e.target.value || undefined })}
+              className="rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs text-[var(--color-text)]"
+            >
+              <option value="">Template (prompts, final reply, changed files)</option>
+              {providers.map((p) => (
+                <option key={p.id} value={p.id}>{p.name} · {p.model}</option>
+              ))}
+              {settings.provider && !providers.some((p) => p.id === settings.provider || p.name === settings.provider) && (
+                <option value={settings.provider}>{settings.provider} (not found)</option>
+              )}
+            </select>
+          </div>
+        </div>
+      )}
+      {error && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+      <p className="text-xs leading-relaxed text-[var(--color-text-muted)]">
+        Task notes are used as the merge commit body, so the record of what was done outlives the chat history.
+      </p>
+    </div>
+  );
+}
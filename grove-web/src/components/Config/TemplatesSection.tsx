@@ -0,0 +1,195 @@
+import { useEffect, useState } from "react";
+import { LayoutTemplate, Plus, Trash2 } from "lucide-react";
+import { getTaskTemplates, updateTaskTemplates, type TaskTemplate } from "../../api";
+import { useProject } from "../../context";
+
+/** Editable form of a template: env and autolink patterns as plain text */
+interface TemplateDraft {
+  name: string;
+  target: string;
+  agent: string;
+  notes: string;
+  /** One `KEY=value` per line */
+  env: string;
+  /** Comma or newline separated */
+  autolink: string;
+}
+
+const toDraft = (t: TaskTemplate): TemplateDraft => ({
+  name: t.name,
+  target: t.target ?? "",
+  agent: t.agent ?? "",
+  notes: t.notes ?? "",
+  env: Object.entries(t.env ?? {}).map(([k, v]) => `${k}=${v}`).join("\n"),
+  autolink: (t.autolink_patterns ?? []).join(", "),
+});
+
+const fromDraft = (d: TemplateDraft): TaskTemplate => ({
+  name: d.name,
+  target: d.target.trim() || null,
+  agent: d.agent.trim() || null,
+  notes: d.notes,
+  env: Object.fromEntries(
+    d.env
+      .split("\n")
+      .map((line) => line.trim())
+      .filter(Boolean)
+      .map((line) => {
+        const eq = line.indexOf("=");
+        return eq < 0 ? [line, ""] : [line.slice(0, eq), line.slice(eq + 1)];
+      }),
+  ),
+  autolink_patterns: d.autolink.split(/[\n,]/).map((p) => p.trim()).filter(Boolean),
+});
+
+/**
+ * Task templates of the selected project inside Settings → Agent: named
+ * presets (target branch, notes skeleton, agent, env vars, autolink
+ * patterns) offered by the New Task dialog, the TUI and `grove_create_task`.
+ */
+export function TemplatesSection() {
+  const { selectedProject } = useProject();
+  const projectId = selectedProject?.id;
+  const [drafts, setDrafts] = useState<TemplateDraft[] | null>(null);
+  const [dirty, setDirty] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!projectId) return;
+    let cancelled = false;
+    setDrafts(null);
+    setDirty(false);
+    getTaskTemplates(projectId)
+      .then((list) => { if (!cancelled) setDrafts(list.map(toDraft)); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId]);
+
+  if (!projectId || !drafts) {
+    return null;
+  }
+
+  const update = (index: number, patch: Partial<TemplateDraft>) => {
+    setDrafts(drafts.map((d, i) => (i === index ? { ...d, ...patch } : d)));
+    setDirty(true);
+  };
+
+  const add = () => {
+    setDrafts([
+      ...drafts,
+      { name: `template-${drafts.length + 1}`, target: "", agent: "", notes: "", env: "", autolink: "" },
+    ]);
+    setDirty(true);
+  };
+
+  const remove = (index: number) => {
+    setDrafts(drafts.filter((_, i) => i !== index));
+    setDirty(true);
+  };
+
+  const save = async () => {
+    setError(null);
+    try {
+      const saved = await updateTaskTemplates(projectId, drafts.map(fromDraft));
+      setDrafts(saved.map(toDraft));
+      setDirty(false);
+    } catch (err: unknown) {
+      const msg = err && typeof err === "object" && "message" in err ? String((err as { message: string }).message) : "";
+      setError(msg || "Failed to save task templates");
+    }
+  };
+
+  const input =
+    "rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs text-[var(--color-text)]";
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center justify-between gap-2">
+        <div className="flex items-center gap-2 select-none">
+          <LayoutTemplate className="w-4 h-4 text-[var(--color-info)]" />
+          <span className="text-xs font-medium text-[var(--color-text-muted)] uppercase tracking-wider">
+            Task Templates · {selectedProject?.name}
+          </span>
+        </div>
+        <div className="flex items-center gap-2">
+          <button
+            onClick={add}
+            className="flex items-center gap-1 text-xs text-[var(--color-text-muted)] hover:text-[var(--color-highlight)] transition-colors"
+          >
+            <Plus className="w-3.5 h-3.5" />
+            Add
+          </button>
+          {dirty && (
+            <button
+              onClick={() => void save()}
+              className="px-2 py-1 text-xs rounded-md bg-[var(--color-highlight)] text-white hover:opacity-90 transition-opacity"
+            >
+              Save
+            </button>
+          )}
+        </div>
+      </div>
+      {drafts.length === 0 && (
+        <div className="text-xs text-[var(--color-text-muted)]">
+          No templates — add one to preset the branch, notes, agent and env of new tasks
+        </div>
+      )}
+      {drafts.map((draft, index) => (
+        <div key={index} className="space-y-1.5 rounded-lg border border-[var(--color-border)] p-2">
+          <div className="flex flex-wrap items-center gap-2">
+            <input
+              value={draft.name}
+              onChange={(e) => update(index, { name: e.target.value })}
+              placeholder="Name"
+              className={`${input} w-36 font-medium`}
+            />
+            <input
+              value={draft.target}
+              onChange={(e) => update(index, { target: e.target.value })}
+              placeholder="Target branch (default)"
+              className={`${input} w-44`}
+            />
+            <input
+              value={draft.agent}
+              onChange={(e) => update(index, { agent: e.target.value })}
+              placeholder="Agent (default)"
+              className={`${input} w-36`}
+            />
+            <button
+              onClick={() => remove(index)}
+              className="ml-auto p-1 text-[var(--color-text-muted)] hover:text-[var(--color-error)] transition-colors"
+              title="Remove template"
+            >
+              <Trash2 className="w-3.5 h-3.5" />
+            </button>
+          </div>
+          <textarea
+            value={draft.notes}
+            onChange={(e) => update(index, { notes: e.target.value })}
+            placeholder="Notes skeleton ({branch}, {task_id}, {target})"
+            rows={3}
+            className={`${input} w-full font-mono resize-y`}
+          />
+          <div className="flex flex-wrap gap-2">
+            <textarea
+              value={draft.env}
+              onChange={(e) => update(index, { env: e.target.value })}
+              placeholder="KEY=value per line"
+              rows={2}
+              className={`${input} min-w-[12rem] flex-1 font-mono resize-y`}
+            />
+            <input
+              value={draft.autolink}
+              onChange={(e) => update(index, { autolink: e.target.value })}
+              placeholder="Extra autolink patterns (.env.local, …)"
+              className={`${input} min-w-[12rem] flex-1 font-mono self-start`}
+            />
+          </div>
+        </div>
+      ))}
+      {error && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+    </div>
+  );
+}
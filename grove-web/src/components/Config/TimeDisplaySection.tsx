@@ -0,0 +1,82 @@
+import { useEffect, useState } from "react";
+import { Clock } from "lucide-react";
+import { getConfig, patchConfig, type DisplayConfig } from "../../api";
+import { formatAbsoluteTime, setTimeDisplay } from "../../utils/time";
+
+const DEFAULT_DISPLAY: DisplayConfig = { time_format: "relative" };
+
+/**
+ * Timestamp display card inside Settings → Appearance. Switches commit and
+ * task times between "2 hours ago" and locale-formatted dates, for both the
+ * web UI and the TUI (Config panel → Time Display).
+ */
+export function TimeDisplaySection() {
+  const [display, setDisplay] = useState<DisplayConfig>(DEFAULT_DISPLAY);
+  const [locale, setLocale] = useState("");
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    getConfig()
+      .then((cfg) => {
+        if (cancelled) return;
+        setDisplay(cfg.display ?? DEFAULT_DISPLAY);
+        setLocale(cfg.display?.locale ?? "");
+      })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, []);
+
+  const save = async (next: DisplayConfig) => {
+    setError(null);
+    try {
+      const cfg = await patchConfig({ display: next });
+      setDisplay(cfg.display);
+      setLocale(cfg.display.locale ?? "");
+      setTimeDisplay(cfg.display);
+    } catch {
+      setError("Failed to save time display settings");
+    }
+  };
+
+  const preview = formatAbsoluteTime(new Date().toISOString(), locale.trim());
+
+  return (
+    <div className="rounded-xl border border-[var(--color-border)] bg-[var(--color-bg-secondary)] p-4 space-y-3">
+      <div>
+        <div className="flex items-center gap-1.5 text-sm font-semibold text-[var(--color-text)]">
+          <Clock className="w-3.5 h-3.5" />
+          Timestamps
+        </div>
+        <div className="text-xs text-[var(--color-text-muted)] mt-0.5">
+          Show times as relative ("2 hours ago") or absolute dates — hover a time to see the other form
+        </div>
+      </div>
+      <div className="flex flex-wrap items-center gap-2 text-xs text-[var(--color-text)]">
+        <select
+          value={display.time_format}
+          onChange={(e) => void save({ ...display, time_format: e.target.value as DisplayConfig["time_format"] })}
+          className="rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs"
+          aria-label="Time format"
+        >
+          <option value="relative">Relative</option>
+          <option value="absolute">Absolute</option>
+        </select>
+        <input
+          value={locale}
+          placeholder="Locale (system default)"
+          onChange={(e) => setLocale(e.target.value)}
+          onBlur={() => {
+            if (locale.trim() !== (display.locale ?? "")) void save({ ...display, locale: locale.trim() });
+          }}
+          className="w-44 rounded-md border border-[var(--color-border)] bg-[var(--color-bg)] px-2 py-1 text-xs"
+          aria-label="Date locale"
+        />
+        <span className="text-[var(--color-text-muted)]">{preview}</span>
+      </div>
+      {error && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+    </div>
+  );
+}
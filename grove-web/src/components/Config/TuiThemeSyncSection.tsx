@@ -0,0 +1,36 @@
+import { TerminalSquare } from "lucide-react";
+import { useTheme } from "../../context";
+
+/**
+ * Terminal color sync card inside Settings → Appearance. When enabled the web
+ * UI takes the exact colors of the theme selected in the TUI (custom themes
+ * included) and follows it live when the theme changes on either side.
+ */
+export function TuiThemeSyncSection() {
+  const { theme, syncTuiColors, setAppearance } = useTheme();
+
+  return (
+    <div className="rounded-xl border border-[var(--color-border)] bg-[var(--color-bg-secondary)] p-4 space-y-3">
+      <div>
+        <div className="flex items-center gap-1.5 text-sm font-semibold text-[var(--color-text)]">
+          <TerminalSquare className="w-3.5 h-3.5" />
+          Terminal Colors
+        </div>
+        <div className="text-xs text-[var(--color-text-muted)] mt-0.5">
+          Use the full color set of the TUI theme so the web UI matches the terminal exactly
+        </div>
+      </div>
+      <label className="flex items-center gap-2 text-xs text-[var(--color-text)]">
+        <input
+          type="checkbox"
+          checked={syncTuiColors}
+          onChange={(e) => void setAppearance({ syncTuiColors: e.target.checked })}
+        />
+        Match terminal colors
+        {syncTuiColors && (
+          <span className="text-[var(--color-text-muted)]">· {theme.name}</span>
+        )}
+      </label>
+    </div>
+  );
+}
@@ -0,0 +1,108 @@
+import { useCallback, useEffect, useState } from "react";
+import { ChevronRight, Layers, Loader2, Pin } from "lucide-react";
+import { listAllTasks, type AllTasksFilter, type ProjectTaskResponse } from "../../api";
+
+interface AllTasksCardProps {
+  onNavigate: (page: string, data?: Record<string, unknown>) => void;
+}
+
+type FilterKey = "all" | "busy" | "conflicts";
+
+const FILTERS: { key: FilterKey; label: string; filter: AllTasksFilter }[] = [
+  { key: "all", label: "All", filter: {} },
+  { key: "busy", label: "Agent busy", filter: { agentBusy: true } },
+  { key: "conflicts", label: "Conflicts", filter: { hasConflicts: true } },
+];
+
+const STATUS_COLORS: Record<string, string> = {
+  live: "var(--color-success)",
+  conflict: "var(--color-error)",
+  broken: "var(--color-error)",
+  merged: "var(--color-info)",
+};
+
+/** Flat "all my tasks" list across projects (mobile home screen). */
+export function AllTasksCard({ onNavigate }: AllTasksCardProps) {
+  const [filterKey, setFilterKey] = useState<FilterKey>("all");
+  const [tasks, setTasks] = useState<ProjectTaskResponse[] | null>(null);
+
+  const load = useCallback((key: FilterKey, signal?: AbortSignal) => {
+    const filter = FILTERS.find((f) => f.key === key)?.filter ?? {};
+    return listAllTasks(filter, signal)
+      .then(setTasks)
+      .catch((err) => {
+        if (signal?.aborted) return;
+        console.error("Failed to load tasks across projects:", err);
+        setTasks([]);
+      });
+  }, []);
+
+  useEffect(() => {
+    const controller = new AbortController();
+    Promise.resolve().then(() => load(filterKey, controller.signal));
+    return () => controller.abort();
+  }, [filterKey, load]);
+
+  return (
+    <section className="rounded-2xl border border-[var(--color-border)] bg-[var(--color-bg-secondary)] overflow-hidden">
+      <div className="flex items-center justify-between gap-2 px-4 py-3 border-b border-[var(--color-border)]">
+        <div className="flex items-center gap-2">
+          <Layers className="w-4 h-4 text-[var(--color-highlight)]" />
+          <h2 className="text-sm font-semibold text-[var(--color-text)]">All Tasks</h2>
+          {tasks && (
+            <span className="text-xs text-[var(--color-text-muted)]">{tasks.length}</span>
+          )}
+        </div>
+        <div className="flex gap-1">
+          {FILTERS.map(({ key, label }) => (
+            <button
+              key={key}
+              onClick={() => setFilterKey(key)}
+              className={`px-2 py-1 text-xs rounded-md transition-colors ${
+                filterKey === key
+                  ? "bg-[var(--color-highlight)] text-white"
+                  : "bg-[var(--color-bg)] text-[var(--color-text-muted)]"
+              }`}
+            >
+              {label}
+            </button>
+          ))}
+        </div>
+      </div>
+
+      {tasks === null ? (
+        <div className="flex justify-center py-6">
+          <Loader2 className="w-5 h-5 text-[var(--color-text-muted)] animate-spin" />
+        </div>
+      ) : tasks.length === 0 ? (
+        <p className="px-4 py-6 text-center text-sm text-[var(--color-text-muted)]">No tasks</p>
+      ) : (
+        <ul className="max-h-80 overflow-y-auto divide-y divide-[var(--color-border)]">
+          {tasks.map((task) => (
+            <li key={`${task.project_id}/${task.id}`}>
+              <button
+                onClick={() => onNavigate("tasks", { projectId: task.project_id, taskId: task.id })}
+                className="w-full flex items-center gap-3 px-4 py-2.5 text-left hover:bg-[var(--color-bg-tertiary)] transition-colors"
+              >
+                <span
+                  className="w-2 h-2 shrink-0 rounded-full"
+                  style={{ backgroundColor: STATUS_COLORS[task.status] ?? "var(--color-text-muted)" }}
+                />
+                <div className="flex-1 min-w-0">
+                  <div className="flex items-center gap-1.5 min-w-0">
+                    {task.pinned && <Pin className="w-3 h-3 shrink-0 text-[var(--color-warning)]" />}
+                    <span className="truncate text-sm text-[var(--color-text)]">{task.name}</span>
+                  </div>
+                  <div className="truncate text-xs text-[var(--color-text-muted)]">
+                    {task.project_name} · {task.agent_state ?? task.status}
+                  </div>
+                </div>
+                <ChevronRight className="w-4 h-4 shrink-0 text-[var(--color-text-muted)]" />
+              </button>
+            </li>
+          ))}
+        </ul>
+      )}
+    </section>
+  );
+}
@@ -2,6 +2,7 @@ import { useState } from "react";
 import { motion, AnimatePresence } from "framer-motion";
 import { ChevronRight, ChevronDown, GitCommit, FileText, Plus, Minus, ArrowRight, Loader2 } from "lucide-react";
 import type { Commit, CommitFileChange } from "../../data/types";
+import { TimeLabel } from "../ui";
 
 interface CommitHistoryProps {
   commits: Commit[];
@@ -116,7 +117,10 @@ export function CommitHistory({ commits, getFileChanges, isLoading = false }: Co
                         <div className="flex items-center gap-2 text-xs text-[var(--color-text-muted)]">
                           <span>{commit.author}</span>
                           <span>•</span>
-                          <span>{formatTimeAgo(commit.date, commit.timeAgo)}</span>
+                          <TimeLabel
+                            time={commit.time ?? commit.date?.toISOString()}
+                            timeAgo={formatTimeAgo(commit.date, commit.timeAgo)}
+                          />
                         </div>
                       </div>
                     </div>
@@ -6,6 +6,7 @@ import {
   ArrowRight,
   ArrowUp,
   ArrowUpDown,
+  AlertTriangle,
   Code2,
   FolderOpen,
   GitBranch,
@@ -20,11 +21,14 @@ import {
   Sparkles,
 } from "lucide-react";
 import { BranchDrawer } from "./BranchDrawer";
+import { AllTasksCard } from "./AllTasksCard";
 import { ConfirmDialog, NewBranchDialog, RenameBranchDialog, CommitDialog } from "../Dialogs";
 import { RebaseDialog } from "../Tasks/dialogs";
 import { useProject, useTheme } from "../../context";
+import { useIsMobile } from "../../hooks";
 import {
   getGitStatus,
+  getProjectHealth,
   getGitBranches,
   getGitCommits,
   gitCheckout,
@@ -57,6 +61,7 @@ import { formatTokens, computeDelta } from "../Stats/formatters";
 import type { Branch, Commit, RepoStatus, Task } from "../../data/types";
 import { getProjectStyle } from "../../utils/projectStyle";
 import { shortenPath } from "../../utils/path";
+import { TimeLabel } from "../ui";
 
 interface DashboardPageProps {
   onNavigate: (page: string, data?: Record<string, unknown>) => void;
@@ -90,6 +95,8 @@ function convertCommit(commit: RepoCommitEntry): Commit {
     message: commit.message,
     author: commit.author,
     timeAgo: commit.time_ago,
+    time: commit.time,
+    links: commit.links,
   };
 }
 
@@ -105,6 +112,7 @@ interface GuidanceTip {
 export function DashboardPage({ onNavigate }: DashboardPageProps) {
   const { selectedProject, refreshSelectedProject, applySelectedProject } = useProject();
   const { theme } = useTheme();
+  const { isMobile } = useIsMobile();
 
   const [showBranchDrawer, setShowBranchDrawer] = useState(false);
   const [showNewBranchDialog, setShowNewBranchDialog] = useState(false);
@@ -165,6 +173,19 @@ export function DashboardPage({ onNavigate }: DashboardPageProps) {
     }
   }, [selectedProject]);
 
+  // Repo health warnings shown in the header (behind origin, dirty main, ...)
+  const [healthWarnings, setHealthWarnings] = useState<string[]>([]);
+  const loadHealth = useCallback(async () => {
+    if (!selectedProject) return;
+    try {
+      const health = await getProjectHealth(selectedProject.id);
+      setHealthWarnings(health.warnings);
+    } catch (err) {
+      console.error("Failed to load project health:", err);
+      setHealthWarnings([]);
+    }
+  }, [selectedProject]);
+
   const loadBranches = useCallback(async () => {
     if (!selectedProject) return;
     setIsBranchesLoading(true);
@@ -217,10 +238,11 @@ export function DashboardPage({ onNavigate }: DashboardPageProps) {
       setBranches([]);
       setRepoCommits([]);
       setIsBranchesLoading(false);
+      setHealthWarnings([]);
       return;
     }
-    await Promise.all([loadGitStatus(), loadBranches(), loadCommits()]);
-  }, [selectedProject, loadGitStatus, loadBranches, loadCommits]);
+    await Promise.all([loadGitStatus(), loadBranches(), loadCommits(), loadHealth()]);
+  }, [selectedProject, loadGitStatus, loadBranches, loadCommits, loadHealth]);
 
   // Token stats run independently of git, so non-git projects still get the
   // Pulse + Activity charts when they've had agent activity. Fetch is an
@@ -730,6 +752,9 @@ export function DashboardPage({ onNavigate }: DashboardPageProps) {
         )}
       </AnimatePresence>
 
+      {/* Mobile home: tasks across every project */}
+      {isMobile && <AllTasksCard onNavigate={onNavigate} />}
+
       {/* ── Two-column layout ── */}
       <div className={`lg:flex-1 lg:min-h-0 grid grid-cols-1 gap-4 sm:gap-5 items-stretch ${isGitRepo ? "lg:grid-cols-[minmax(0,1fr)_minmax(260px,340px)]" : ""}`}>
 
@@ -781,6 +806,15 @@ export function DashboardPage({ onNavigate }: DashboardPageProps) {
                     Not a Git repo
                   </span>
                 )}
+                {!isStudio && healthWarnings.map((warning) => (
+                  <span
+                    key={warning}
+                    className="inline-flex items-center gap-1 rounded-md border border-[var(--color-warning)]/30 bg-[var(--color-warning)]/10 px-2 py-0.5 text-xs font-medium text-[var(--color-warning)] shrink-0"
+                  >
+                    <AlertTriangle className="h-3 w-3" />
+                    {warning}
+                  </span>
+                ))}
               </div>
               <div className="mt-0.5 text-sm text-[var(--color-text-muted)] truncate">
                 {shortenPath(selectedProject.path)}
@@ -1156,12 +1190,32 @@ export function DashboardPage({ onNavigate }: DashboardPageProps) {
                   <code className="shrink-0 font-mono text-xs text-[var(--color-highlight)]">
                     {commit.hash.slice(0, 7)}
                   </code>
-                  <span className="flex-1 min-w-0 text-sm text-[var(--color-text)] leading-snug line-clamp-2">
-                    {commit.message}
-                  </span>
-                  <span className="shrink-0 text-xs text-[var(--color-text-muted)] whitespace-nowrap">
-                    {commit.timeAgo ?? ""}
-                  </span>
+                  <div className="flex-1 min-w-0">
+                    <span className="text-sm text-[var(--color-text)] leading-snug line-clamp-2">
+                      {commit.message}
+                    </span>
+                    {commit.links?.task_id && (
+                      <button
+                        type="button"
+                        disabled={!commit.links.task_name || commit.links.task_archived}
+                        onClick={() => onNavigate("tasks", {
+                          taskId: commit.links?.task_id,
+                          ...(commit.links?.chat_id ? { chatId: commit.links.chat_id, viewMode: "terminal" } : {}),
+                        })}
+                        className="mt-0.5 max-w-full truncate text-left text-xs text-[var(--color-info)] enabled:hover:underline disabled:text-[var(--color-text-muted)]"
+                        title={commit.links.chat_title ? `Chat: ${commit.links.chat_title}` : undefined}
+                      >
+                        {commit.links.task_name ?? commit.links.task_id}
+                        {commit.links.task_archived && " (archived)"}
+                        {commit.links.chat_title && ` · ${commit.links.chat_title}`}
+                      </button>
+                    )}
+                  </div>
+                  <TimeLabel
+                    className="shrink-0 text-xs text-[var(--color-text-muted)] whitespace-nowrap"
+                    time={commit.time}
+                    timeAgo={commit.timeAgo}
+                  />
                 </div>
               )) : (
                 <p className="py-4 text-center text-sm text-[var(--color-text-muted)]">No commits</p>
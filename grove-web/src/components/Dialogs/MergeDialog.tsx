@@ -1,11 +1,26 @@
 import { useCallback, useState } from "react";
-import { GitMerge, X, Loader2, GitBranch } from "lucide-react";
+import { GitMerge, X, Loader2, GitBranch, ShieldAlert, AlertTriangle } from "lucide-react";
 import { Button } from "../ui";
 import { DialogShell } from "../ui/DialogShell";
 import { useCommand, useContextKey, useKeyboardScope } from "../../keyboard";
+import type { BenchRun, FileGuardStatus, GuardViolation, RiskAssessment, TestChanges } from "../../api";
 
 type MergeMethod = "squash" | "merge-commit";
 
+/** Why an added file was flagged by the file guard */
+function violationReason(v: GuardViolation): string {
+  switch (v.kind) {
+    case "blocked_path":
+      return `matches ${v.pattern}`;
+    case "blocked_extension":
+      return `.${v.extension} not allowed`;
+    case "too_large":
+      return `${Math.ceil(v.size / 1024)} KB > ${Math.floor(v.limit / 1024)} KB`;
+    case "license":
+      return v.license;
+  }
+}
+
 interface MergeDialogProps {
   isOpen: boolean;
   taskName: string;
@@ -13,7 +28,17 @@ interface MergeDialogProps {
   targetBranch: string;
   isLoading?: boolean;
   error?: string | null;
-  onMerge: (method: MergeMethod) => void;
+  /** Heuristic risk of the task's changes */
+  risk?: RiskAssessment | null;
+  /** Source vs test changes; warns when tests are missing */
+  testChanges?: TestChanges | null;
+  /** Latest benchmark comparison; warns on regressions */
+  bench?: BenchRun | null;
+  /** Security findings below the block threshold */
+  securityWarning?: string | null;
+  /** Added files violating the file guard */
+  fileGuard?: FileGuardStatus | null;
+  onMerge: (method: MergeMethod, includeReview: boolean) => void;
   onCancel: () => void;
 }
 
@@ -24,10 +49,25 @@ export function MergeDialog({
   targetBranch,
   isLoading = false,
   error = null,
+  risk = null,
+  testChanges = null,
+  bench = null,
+  securityWarning = null,
+  fileGuard = null,
   onMerge,
   onCancel,
 }: MergeDialogProps) {
   const [selectedMethod, setSelectedMethod] = useState<MergeMethod>("squash");
+  const [includeReview, setIncludeReview] = useState<boolean>(
+    () => localStorage.getItem("grove:merge.includeReview") === "true",
+  );
+
+  const handleToggleIncludeReview = (checked: boolean) => {
+    setIncludeReview(checked);
+    try {
+      localStorage.setItem("grove:merge.includeReview", String(checked));
+    } catch { /* ignore localStorage issues */ }
+  };
 
   // Catalog handlers register inside <MergeDialogBindings> only while
   // isOpen=true. Multiple MergeDialog wrappers can coexist (one per task
@@ -36,14 +76,17 @@ export function MergeDialog({
   // last-mounted dialog's binding would be live.
   useKeyboardScope("dialog.merge", isOpen);
 
+  const benchRegressions =
+    bench?.status === "done" ? bench.results.filter((r) => r.verdict === "regression") : [];
+
   const handleSubmit = useCallback(
     (e?: React.FormEvent) => {
       e?.preventDefault();
       if (!isLoading) {
-        onMerge(selectedMethod);
+        onMerge(selectedMethod, includeReview);
       }
     },
-    [isLoading, onMerge, selectedMethod],
+    [isLoading, onMerge, selectedMethod, includeReview],
   );
 
   return (
@@ -85,6 +128,89 @@ export function MergeDialog({
             </div>
           </div>
 
+          {/* Risk score */}
+          {risk && (
+            <div
+              className="flex items-start gap-2 p-3 rounded-lg border text-xs"
+              style={{
+                color: risk.level === "high" ? "var(--color-error)" : risk.level === "medium" ? "var(--color-warning)" : "var(--color-text-muted)",
+                borderColor: "var(--color-border)",
+              }}
+            >
+              <ShieldAlert className="w-4 h-4 flex-shrink-0" />
+              <div>
+                <p className="font-medium">
+                  {risk.level.charAt(0).toUpperCase() + risk.level.slice(1)} risk · {risk.score}/100
+                </p>
+                {risk.factors.length > 0 && (
+                  <p className="mt-0.5 text-[var(--color-text-muted)]">
+                    {risk.factors.map((f) => f.detail).join(" · ")}
+                  </p>
+                )}
+              </div>
+            </div>
+          )}
+
+          {/* Tests missing */}
+          {testChanges?.tests_missing && (
+            <div
+              className="flex items-center gap-2 p-3 rounded-lg border text-xs text-[var(--color-warning)] border-[var(--color-border)]"
+              title={testChanges.source_files.join("\n")}
+            >
+              <AlertTriangle className="w-4 h-4 flex-shrink-0" />
+              <span>
+                {testChanges.source_files.length} source file{testChanges.source_files.length !== 1 ? "s" : ""} changed without test changes
+              </span>
+            </div>
+          )}
+
+          {benchRegressions.length > 0 && (
+            <div
+              className="flex items-center gap-2 p-3 rounded-lg border text-xs text-[var(--color-error)] border-[var(--color-border)]"
+              title={benchRegressions
+                .map((r) => `${r.name} +${(r.change_percent ?? 0).toFixed(1)}%`)
+                .join("\n")}
+            >
+              <AlertTriangle className="w-4 h-4 flex-shrink-0" />
+              <span>
+                {benchRegressions.length} benchmark regression{benchRegressions.length !== 1 ? "s" : ""} vs{" "}
+                {bench?.base_ref}: {benchRegressions.slice(0, 3).map((r) => r.name).join(", ")}
+                {benchRegressions.length > 3 ? ", …" : ""}
+              </span>
+            </div>
+          )}
+
+          {securityWarning && (
+            <div className="flex items-center gap-2 p-3 rounded-lg border text-xs text-[var(--color-warning)] border-[var(--color-border)]">
+              <ShieldAlert className="w-4 h-4 flex-shrink-0" />
+              <span>{securityWarning}</span>
+            </div>
+          )}
+
+          {fileGuard && fileGuard.violations.length > 0 && (
+            <div
+              className={`p-3 rounded-lg border text-xs border-[var(--color-border)] ${
+                fileGuard.block ? "text-[var(--color-error)]" : "text-[var(--color-warning)]"
+              }`}
+            >
+              <div className="flex items-center gap-2">
+                <AlertTriangle className="w-4 h-4 flex-shrink-0" />
+                <span>
+                  {fileGuard.violations.length} added file{fileGuard.violations.length !== 1 ? "s" : ""}{" "}
+                  {fileGuard.block ? "block the merge" : "flagged by the file guard"}
+                </span>
+              </div>
+              <ul className="mt-1.5 ml-6 space-y-0.5 max-h-24 overflow-y-auto">
+                {fileGuard.violations.map((v) => (
+                  <li key={v.path} className="truncate" title={v.path}>
+                    <span className="font-mono">{v.path}</span>{" "}
+                    <span className="text-[var(--color-text-muted)]">({violationReason(v)})</span>
+                  </li>
+                ))}
+              </ul>
+            </div>
+          )}
+
           {/* Merge method selection */}
           <div className="space-y-2">
             <label className="block text-sm font-medium text-[var(--color-text)]">
@@ -142,6 +268,21 @@ export function MergeDialog({
             </label>
           </div>
 
+          <label className="flex items-start gap-3 cursor-pointer">
+            <input
+              type="checkbox"
+              checked={includeReview}
+              onChange={(e) => handleToggleIncludeReview(e.target.checked)}
+              className="mt-1"
+            />
+            <div>
+              <p className="text-sm font-medium text-[var(--color-text)]">Include review summary</p>
+              <p className="text-xs text-[var(--color-text-muted)] mt-0.5">
+                Append comment counts, reviewers and verdicts to the commit message
+              </p>
+            </div>
+          </label>
+
           {error && (
             <p className="text-sm text-[var(--color-error)]">{error}</p>
           )}
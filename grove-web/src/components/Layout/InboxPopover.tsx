@@ -0,0 +1,178 @@
+import { useEffect, useRef } from "react";
+import { motion, AnimatePresence } from "framer-motion";
+import { ShieldQuestion, MessageSquare, GitMerge, XCircle, Hand, RefreshCw } from "lucide-react";
+import { useIsMobile } from "../../hooks";
+import type { InboxItem } from "../../api/inbox";
+import { formatTimeAgo } from "../../utils/notificationFormat";
+
+interface InboxPopoverProps {
+  isOpen: boolean;
+  onClose: () => void;
+  items: InboxItem[];
+  loading: boolean;
+  onRefresh: () => void;
+  onNavigate?: (page: string, data?: Record<string, unknown>) => void;
+}
+
+function kindIcon(item: InboxItem) {
+  const className = "w-4 h-4 flex-shrink-0";
+  switch (item.kind) {
+    case "permission":
+      return <ShieldQuestion className={className} style={{ color: "var(--color-warning)" }} />;
+    case "review_reply":
+      return <MessageSquare className={className} style={{ color: "var(--color-info)" }} />;
+    case "conflict":
+      return <GitMerge className={className} style={{ color: "var(--color-error)" }} />;
+    case "merge_failed":
+      return <XCircle className={className} style={{ color: "var(--color-error)" }} />;
+    case "handback":
+      return <Hand className={className} style={{ color: "var(--color-warning)" }} />;
+  }
+}
+
+function kindSummary(item: InboxItem): string {
+  switch (item.kind) {
+    case "permission":
+      return item.chat_title ? `${item.chat_title}: ${item.description}` : item.description;
+    case "review_reply":
+      return `${item.from}: ${item.excerpt}`;
+    case "conflict":
+      return "Merge conflict needs resolving";
+    case "merge_failed":
+      return item.error;
+    case "handback":
+      return "Handed back to you";
+  }
+}
+
+function InboxRow({ item, onClick }: { item: InboxItem; onClick: () => void }) {
+  return (
+    <div
+      className="flex items-start gap-3 px-4 py-3 hover:bg-[var(--color-bg-secondary)] transition-colors cursor-pointer"
+      onClick={onClick}
+    >
+      {kindIcon(item)}
+      <div className="flex-1 min-w-0">
+        <div className="flex items-center gap-2">
+          <span className="text-sm font-medium text-[var(--color-text)] truncate">
+            {item.task_name}
+          </span>
+          {item.timestamp && (
+            <span className="text-xs text-[var(--color-text-muted)] whitespace-nowrap">
+              {formatTimeAgo(item.timestamp)}
+            </span>
+          )}
+        </div>
+        <div className="text-xs text-[var(--color-text-muted)] truncate">
+          {item.project_name}
+        </div>
+        <div className="text-xs text-[var(--color-text)] mt-1 line-clamp-2">
+          {kindSummary(item)}
+        </div>
+      </div>
+    </div>
+  );
+}
+
+/** Sidebar popover listing everything across projects that awaits the user */
+export function InboxPopover({ isOpen, onClose, items, loading, onRefresh, onNavigate }: InboxPopoverProps) {
+  const popoverRef = useRef<HTMLDivElement>(null);
+  const { isMobile } = useIsMobile();
+
+  // Close on click outside
+  useEffect(() => {
+    if (!isOpen) return;
+
+    function handleMouseDown(e: MouseEvent) {
+      if (popoverRef.current && !popoverRef.current.contains(e.target as Node)) {
+        onClose();
+      }
+    }
+
+    document.addEventListener("mousedown", handleMouseDown);
+    return () => document.removeEventListener("mousedown", handleMouseDown);
+  }, [isOpen, onClose]);
+
+  const jump = (item: InboxItem) => {
+    // Permission prompts open the blocked chat; everything else selects the task
+    onNavigate?.("tasks", {
+      taskId: item.task_id,
+      projectId: item.project_id,
+      ...(item.kind === "permission" ? { viewMode: "terminal", chatId: item.chat_id } : {}),
+    });
+    onClose();
+  };
+
+  return (
+    <AnimatePresence>
+      {isOpen && (
+        <>
+          {isMobile && (
+            <motion.div
+              initial={{ opacity: 0 }}
+              animate={{ opacity: 1 }}
+              exit={{ opacity: 0 }}
+              onClick={onClose}
+              className="fixed inset-0 z-40 bg-black/50"
+            />
+          )}
+          <motion.div
+            ref={popoverRef}
+            initial={isMobile ? { y: "100%" } : { opacity: 0, x: -8 }}
+            animate={isMobile ? { y: 0 } : { opacity: 1, x: 0 }}
+            exit={isMobile ? { y: "100%" } : { opacity: 0, x: -8 }}
+            transition={isMobile ? { type: "spring", damping: 30, stiffness: 300 } : { duration: 0.15 }}
+            className={isMobile
+              ? "glass-overlay fixed inset-x-0 bottom-0 z-50 rounded-t-2xl"
+              : "glass-popover fixed z-50 rounded-xl"
+            }
+            style={isMobile ? { maxHeight: "70vh" } : {
+              left: 72,
+              bottom: 128,
+              width: 380,
+              maxHeight: 480,
+            }}
+          >
+            {isMobile && (
+              <div className="flex justify-center pt-3 pb-1">
+                <div className="w-10 h-1 rounded-full bg-[var(--color-border)]" />
+              </div>
+            )}
+
+            <div className="flex items-center justify-between px-4 py-3 border-b border-[var(--color-border)]">
+              <span className="text-sm font-semibold text-[var(--color-text)]">Inbox</span>
+              <div className="flex items-center gap-3">
+                <span className="text-xs text-[var(--color-text-muted)]">
+                  {items.length > 0 ? `${items.length} waiting` : ""}
+                </span>
+                <button
+                  type="button"
+                  onClick={onRefresh}
+                  disabled={loading}
+                  className="text-[var(--color-text-muted)] hover:text-[var(--color-text)] transition-colors disabled:opacity-50"
+                  aria-label="Refresh inbox"
+                >
+                  <RefreshCw className={`w-3.5 h-3.5 ${loading ? "animate-spin" : ""}`} />
+                </button>
+              </div>
+            </div>
+
+            <div className="overflow-y-auto" style={{ maxHeight: 400 }}>
+              {items.length === 0 ? (
+                <div className="flex items-center justify-center py-12 text-sm text-[var(--color-text-muted)]">
+                  {loading ? "Loading…" : "Nothing is waiting on you"}
+                </div>
+              ) : (
+                <div className="divide-y divide-[var(--color-border)]">
+                  {items.map((item, i) => (
+                    <InboxRow key={`${item.project_id}-${item.task_id}-${item.kind}-${i}`} item={item} onClick={() => jump(item)} />
+                  ))}
+                </div>
+              )}
+            </div>
+          </motion.div>
+        </>
+      )}
+    </AnimatePresence>
+  );
+}
@@ -1,6 +1,7 @@
-import { useEffect, useRef, type CSSProperties } from "react";
+import { useEffect, useRef, useState, type CSSProperties } from "react";
 import { motion, AnimatePresence } from "framer-motion";
-import { X } from "lucide-react";
+import { Bell, BellOff, X } from "lucide-react";
+import { getConfig, patchConfig } from "../../api";
 import { useNotifications } from "../../context";
 import { useIsMobile } from "../../hooks";
 import type { HookEntryResponse } from "../../api/hooks";
@@ -64,6 +65,33 @@ export function NotificationPopover({ isOpen, onClose, onNavigate, anchorStyle }
   const { notifications, dismissNotification, clearAllNotifications } = useNotifications();
   const popoverRef = useRef<HTMLDivElement>(null);
   const { isMobile } = useIsMobile();
+  const [dnd, setDnd] = useState(false);
+  const [muted, setMuted] = useState(false);
+
+  // Refresh Do Not Disturb state each time the popover opens (quiet hours
+  // and the TUI toggle change it behind our back)
+  useEffect(() => {
+    if (!isOpen) return;
+    let cancelled = false;
+    getConfig()
+      .then((cfg) => {
+        if (cancelled) return;
+        setDnd(cfg.notifications.do_not_disturb ?? false);
+        setMuted(cfg.notifications.muted ?? false);
+      })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [isOpen]);
+
+  const toggleDnd = async () => {
+    try {
+      const cfg = await patchConfig({ notifications: { do_not_disturb: !dnd } });
+      setDnd(cfg.notifications.do_not_disturb ?? false);
+      setMuted(cfg.notifications.muted ?? false);
+    } catch {
+      // keep the previous state
+    }
+  };
 
   // Close on click outside
   useEffect(() => {
@@ -127,6 +155,24 @@ export function NotificationPopover({ isOpen, onClose, onNavigate, anchorStyle }
               <span className="text-xs text-[var(--color-text-muted)]">
                 {notifications.length > 0 ? `${notifications.length} active` : ""}
               </span>
+              <button
+                type="button"
+                onClick={() => void toggleDnd()}
+                className={`p-1 rounded transition-colors hover:bg-[var(--color-bg-tertiary)] ${
+                  muted ? "text-[var(--color-warning)]" : "text-[var(--color-text-muted)] hover:text-[var(--color-text)]"
+                }`}
+                title={
+                  dnd
+                    ? "Do Not Disturb is on — click to turn off"
+                    : muted
+                      ? "Quiet hours — sounds and banners are muted"
+                      : "Turn on Do Not Disturb"
+                }
+                aria-label="Toggle Do Not Disturb"
+                aria-pressed={dnd}
+              >
+                {muted ? <BellOff className="w-3.5 h-3.5" /> : <Bell className="w-3.5 h-3.5" />}
+              </button>
               {notifications.length > 0 && (
                 <button
                   type="button"
@@ -12,6 +12,7 @@ import {
   ChevronLeft,
   ChevronRight,
   Bell,
+  Inbox,
   Search,
   Layers,
   Repeat,
@@ -26,6 +27,8 @@ import type { Plugin } from "../../api/plugins";
 import { PluginIcon } from "../Plugins/PluginIcon";
 import { ProjectSelector } from "./ProjectSelector";
 import { NotificationPopover } from "./NotificationPopover";
+import { InboxPopover } from "./InboxPopover";
+import { getInbox, type InboxItem } from "../../api/inbox";
 import { formatTimeAgo, getLevelIcon } from "../../utils/notificationFormat";
 import { LogoBrand } from "./LogoBrand";
 import { GroveIcon } from "./GroveIcon";
@@ -159,6 +162,24 @@ export function Sidebar({
 }: SidebarProps) {
   const [notifOpen, setNotifOpen] = useState(false);
   const { unreadCount } = useNotifications();
+  const [inboxOpen, setInboxOpen] = useState(false);
+  const [inboxItems, setInboxItems] = useState<InboxItem[]>([]);
+  const [inboxLoading, setInboxLoading] = useState(false);
+
+  const refreshInbox = useCallback(() => {
+    setInboxLoading(true);
+    getInbox()
+      .then(setInboxItems)
+      .catch(() => {})
+      .finally(() => setInboxLoading(false));
+  }, []);
+
+  // Keep the badge roughly current; opening the popover refreshes immediately
+  useEffect(() => {
+    refreshInbox();
+    const timer = setInterval(refreshInbox, 60_000);
+    return () => clearInterval(timer);
+  }, [refreshInbox]);
   const { selectedProject } = useProject();
   const { theme } = useTheme();
   const navItems = useMemo(
@@ -525,6 +546,44 @@ export function Sidebar({
           </motion.button>
         )}
 
+        {/* Inbox */}
+        <div className="relative">
+          <motion.button
+            whileHover={{ x: isCollapsed ? 0 : 2 }}
+            whileTap={{ scale: 0.98 }}
+            onClick={() => {
+              if (!inboxOpen) refreshInbox();
+              setInboxOpen(!inboxOpen);
+            }}
+            title={isCollapsed ? "Inbox" : undefined}
+            className={`w-full flex items-center gap-3 px-3 py-2.5 rounded-xl text-sm transition-colors duration-150
+              ${isCollapsed ? "justify-center" : ""}
+              ${inboxOpen
+                ? "font-semibold text-[var(--color-highlight)]"
+                : "font-medium text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-border)]"
+              }`}
+            style={
+              inboxOpen
+                ? {
+                    backgroundColor: "color-mix(in oklab, var(--color-highlight) 18%, transparent)",
+                    boxShadow:
+                      "0 1px 2px rgba(0, 0, 0, 0.05), inset 0 0 0 1px color-mix(in oklab, var(--color-highlight) 28%, transparent)",
+                  }
+                : undefined
+            }
+          >
+            <div className="relative flex-shrink-0">
+              <Inbox className="w-5 h-5" />
+              {inboxItems.length > 0 && (
+                <span className="absolute -top-1.5 -right-1.5 min-w-[18px] h-[18px] flex items-center justify-center px-1 text-[10px] font-bold text-white bg-[var(--color-warning)] rounded-full leading-none">
+                  {inboxItems.length > 9 ? "9+" : inboxItems.length}
+                </span>
+              )}
+            </div>
+            {!isCollapsed && <span className="flex-1 text-left">Inbox</span>}
+          </motion.button>
+        </div>
+
         {/* Notification Bell */}
         <div className="relative">
           <motion.button
@@ -609,11 +668,21 @@ export function Sidebar({
   // Island mode never sets `notifOpen` — it morphs the pill itself into
   // an in-place notification view instead (see `islandView` above).
   const notificationPopover = (
-    <NotificationPopover
-      isOpen={notifOpen}
-      onClose={() => setNotifOpen(false)}
-      onNavigate={onNavigate}
-    />
+    <>
+      <NotificationPopover
+        isOpen={notifOpen}
+        onClose={() => setNotifOpen(false)}
+        onNavigate={onNavigate}
+      />
+      <InboxPopover
+        isOpen={inboxOpen}
+        onClose={() => setInboxOpen(false)}
+        items={inboxItems}
+        loading={inboxLoading}
+        onRefresh={refreshInbox}
+        onNavigate={onNavigate}
+      />
+    </>
   );
 
   // In drawer mode, content is rendered inside MobileDrawer — no wrapper needed
@@ -1,6 +1,6 @@
 import { useState, useRef, useEffect } from "react";
 import { motion } from "framer-motion";
-import { Trash2, AlertCircle, FolderX, Sparkles, Pencil } from "lucide-react";
+import { Trash2, AlertCircle, FolderX, Sparkles, Pencil, Pin, PinOff } from "lucide-react";
 import type { Project } from "../../data/types";
 import { getProjectStyle } from "../../utils/projectStyle";
 import { compactPath } from "../../utils/pathUtils";
@@ -14,6 +14,7 @@ interface ProjectCardProps {
   onDoubleClick?: () => void;
   onDelete: () => void;
   onRename: (newName: string) => Promise<void>;
+  onTogglePin?: () => void;
   compact?: boolean;
 }
 
@@ -25,7 +26,7 @@ export function ProjectCard(props: ProjectCardProps) {
   );
 }
 
-function ProjectCardInner({ project, isSelected, onSelect, onDoubleClick, onDelete, onRename, compact }: ProjectCardProps) {
+function ProjectCardInner({ project, isSelected, onSelect, onDoubleClick, onDelete, onRename, onTogglePin, compact }: ProjectCardProps) {
   const { theme } = useTheme();
   const taskCount = project.taskCount ?? project.tasks.length;
   const { color, Icon } = getProjectStyle(project.id, theme.accentPalette);
@@ -188,8 +189,11 @@ function ProjectCardInner({ project, isSelected, onSelect, onDoubleClick, onDele
               )}
             </>
           ) : (
-            <h3 className="text-sm font-semibold text-[var(--color-text)] truncate">
-              {project.name}
+            <h3 className="flex items-center gap-1.5 text-sm font-semibold text-[var(--color-text)] min-w-0">
+              <span className="truncate">{project.name}</span>
+              {project.pinned && (
+                <Pin className="w-3 h-3 flex-shrink-0 text-[var(--color-warning)]" aria-label="Pinned" />
+              )}
             </h3>
           )}
           <p
@@ -239,6 +243,18 @@ function ProjectCardInner({ project, isSelected, onSelect, onDoubleClick, onDele
       </div>
 
       <div className="absolute bottom-3 right-3 flex items-center gap-0.5">
+        {onTogglePin && (
+          <button
+            onClick={(e) => {
+              e.stopPropagation();
+              onTogglePin();
+            }}
+            className="p-1.5 rounded-md text-[var(--color-text-muted)] hover:text-[var(--color-warning)] hover:bg-[var(--color-warning)]/10 transition-colors"
+            title={project.pinned ? "Unpin project" : "Pin project to top"}
+          >
+            {project.pinned ? <PinOff className="w-3.5 h-3.5" /> : <Pin className="w-3.5 h-3.5" />}
+          </button>
+        )}
         <button
           onClick={startEditing}
           className="p-1.5 rounded-md text-[var(--color-text-muted)] hover:text-[var(--color-highlight)] hover:bg-[var(--color-highlight)]/10 transition-colors"
@@ -8,6 +8,7 @@ import { DeleteProjectDialog } from "./DeleteProjectDialog";
 import { useProject } from "../../context";
 import { useIsMobile } from "../../hooks";
 import { filterProjectsByType } from "../../utils/projectFilter";
+import { setProjectPinned } from "../../api";
 import type { Project } from "../../data/types";
 import { OptionalPerfProfiler } from "../../perf/profilerShim";
 
@@ -141,6 +142,15 @@ export function ProjectsPage({ onNavigate, initialTab }: ProjectsPageProps) {
     await renameProject(project.id, newName);
   };
 
+  const handleTogglePin = async (project: Project) => {
+    try {
+      await setProjectPinned(project.id, !project.pinned);
+      await refreshProjects();
+    } catch (err) {
+      console.error("Failed to pin project:", err);
+    }
+  };
+
   const renderProjectGrid = (items: Project[], sectionKey: string, includeAddCard = false) => (
     <div className={`grid grid-cols-1 md:grid-cols-2 xl:grid-cols-3 2xl:grid-cols-4 ${isMobile ? "gap-2" : "gap-4"}`}>
       {items.map((project, index) => (
@@ -157,6 +167,7 @@ export function ProjectsPage({ onNavigate, initialTab }: ProjectsPageProps) {
             onDoubleClick={() => handleDoubleClick(project)}
             onDelete={() => setProjectToDelete(project)}
             onRename={(newName) => handleRenameProject(project, newName)}
+            onTogglePin={() => handleTogglePin(project)}
             compact={isMobile}
           />
         </motion.div>
@@ -0,0 +1,216 @@
+import { useCallback, useEffect, useState } from 'react';
+import { AlertTriangle, Check, GitMerge, Pencil, X } from 'lucide-react';
+import {
+  abortRebase,
+  continueRebase,
+  getRebaseConflicts,
+  resolveConflict,
+  REBASE_CONFLICTS_EVENT,
+} from '../../api/review';
+import type { ConflictFile, ConflictResolution, RebaseConflicts } from '../../api/review';
+
+interface ConflictResolverProps {
+  projectId: string;
+  taskId: string;
+  /** Called once the rebase finished or was aborted */
+  onFinished: () => void;
+}
+
+const errorMessage = (err: unknown, fallback: string) =>
+  err && typeof err === 'object' && 'message' in err && (err as { message: string }).message
+    ? String((err as { message: string }).message)
+    : fallback;
+
+/** One side of a hunk */
+function HunkSide({ title, text, tone }: { title: string; text: string; tone: string }) {
+  return (
+    <div className="min-w-0 flex-1 rounded-md border border-[var(--color-border)] overflow-hidden">
+      <div className="px-2 py-1 text-[11px] font-medium truncate" style={{ color: tone, backgroundColor: `${tone}14` }}>
+        {title}
+      </div>
+      <pre className="m-0 px-2 py-1.5 text-xs font-mono whitespace-pre-wrap break-all text-[var(--color-text)] max-h-64 overflow-auto">
+        {text || <span className="italic text-[var(--color-text-muted)]">(empty)</span>}
+      </pre>
+    </div>
+  );
+}
+
+function ConflictFileCard({
+  file,
+  busy,
+  onResolve,
+}: {
+  file: ConflictFile;
+  busy: boolean;
+  onResolve: (path: string, resolution: ConflictResolution) => void;
+}) {
+  const [editing, setEditing] = useState<string | null>(null);
+  const button =
+    'flex items-center gap-1 px-2 py-1 text-xs rounded-md border border-[var(--color-border)] text-[var(--color-text)] hover:border-[var(--color-highlight)] disabled:opacity-50 transition-colors';
+
+  return (
+    <div className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-2 space-y-2">
+      <div className="flex flex-wrap items-center gap-2">
+        <span className="font-mono text-xs text-[var(--color-text)] truncate">{file.path}</span>
+        <span className="text-[11px] text-[var(--color-text-muted)]">
+          {file.hunks.length > 0
+            ? `${file.hunks.length} conflict${file.hunks.length !== 1 ? 's' : ''}`
+            : 'binary or deleted on one side'}
+        </span>
+        <div className="ml-auto flex items-center gap-1.5">
+          <button className={button} disabled={busy} onClick={() => onResolve(file.path, { resolution: 'ours' })}>
+            Accept target
+          </button>
+          <button className={button} disabled={busy} onClick={() => onResolve(file.path, { resolution: 'theirs' })}>
+            Accept task
+          </button>
+          {file.content !== undefined && editing === null && (
+            <button className={button} disabled={busy} onClick={() => setEditing(file.content ?? '')}>
+              <Pencil size={12} />
+              Edit
+            </button>
+          )}
+        </div>
+      </div>
+
+      {editing !== null ? (
+        <div className="space-y-1.5">
+          <textarea
+            value={editing}
+            onChange={(e) => setEditing(e.target.value)}
+            rows={Math.min(24, editing.split('\n').length + 1)}
+            spellCheck={false}
+            className="w-full rounded-md border border-[var(--color-border)] bg-[var(--color-bg-secondary)] px-2 py-1.5 text-xs font-mono text-[var(--color-text)] resize-y"
+            aria-label={`Resolved content of ${file.path}`}
+          />
+          <div className="flex items-center gap-1.5">
+            <button
+              className={button}
+              disabled={busy}
+              onClick={() => onResolve(file.path, { resolution: 'manual', content: editing })}
+            >
+              <Check size={12} />
+              Mark resolved
+            </button>
+            <button className={button} onClick={() => setEditing(null)}>
+              Cancel
+            </button>
+          </div>
+        </div>
+      ) : (
+        file.hunks.map((hunk) => (
+          <div key={hunk.start_line} className="space-y-1">
+            <div className="text-[11px] text-[var(--color-text-muted)]">
+              Lines {hunk.start_line}–{hunk.end_line}
+            </div>
+            <div className="flex flex-wrap gap-1.5">
+              <HunkSide title={`Target · ${hunk.ours_label || 'ours'}`} text={hunk.ours} tone="var(--color-info)" />
+              {hunk.base !== undefined && (
+                <HunkSide title="Base" text={hunk.base} tone="var(--color-text-muted)" />
+              )}
+              <HunkSide title={`Task · ${hunk.theirs_label || 'theirs'}`} text={hunk.theirs} tone="var(--color-highlight)" />
+            </div>
+          </div>
+        ))
+      )}
+    </div>
+  );
+}
+
+/**
+ * Conflict view of the Review panel: shown while the task's sync (rebase)
+ * is stopped on conflicts. Resolve each file by accepting the target or task
+ * side, or by editing it, then continue (or abort) the rebase.
+ */
+export function ConflictResolver({ projectId, taskId, onFinished }: ConflictResolverProps) {
+  const [state, setState] = useState<RebaseConflicts | null>(null);
+  const [busy, setBusy] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const load = useCallback(() => {
+    getRebaseConflicts(projectId, taskId)
+      .then(setState)
+      .catch(() => setState(null));
+  }, [projectId, taskId]);
+
+  useEffect(() => {
+    load();
+    const onConflicts = (e: Event) => {
+      const detail = (e as CustomEvent<{ taskId?: string }>).detail;
+      if (!detail?.taskId || detail.taskId === taskId) load();
+    };
+    window.addEventListener(REBASE_CONFLICTS_EVENT, onConflicts);
+    return () => window.removeEventListener(REBASE_CONFLICTS_EVENT, onConflicts);
+  }, [load, taskId]);
+
+  if (!state?.in_progress) {
+    return null;
+  }
+
+  const run = async (action: () => Promise<RebaseConflicts>, fallback: string) => {
+    setBusy(true);
+    setError(null);
+    try {
+      const next = await action();
+      setState(next);
+      if (!next.in_progress) onFinished();
+    } catch (err: unknown) {
+      setError(errorMessage(err, fallback));
+    }
+    setBusy(false);
+  };
+
+  const unresolved = state.files.length;
+
+  return (
+    <div className="mx-3 my-2 rounded-xl border border-[var(--color-warning)]/40 bg-[var(--color-bg-secondary)] p-3 space-y-2">
+      <div className="flex flex-wrap items-center gap-2">
+        <AlertTriangle size={14} className="text-[var(--color-warning)]" />
+        <span className="text-sm font-semibold text-[var(--color-text)]">Rebase conflicts</span>
+        {state.step !== undefined && state.total !== undefined && (
+          <span className="text-xs text-[var(--color-text-muted)]">
+            commit {state.step} of {state.total}
+          </span>
+        )}
+        {state.commit && (
+          <span className="font-mono text-xs text-[var(--color-text-muted)] truncate max-w-[24rem]">{state.commit}</span>
+        )}
+        <div className="ml-auto flex items-center gap-1.5">
+          <button
+            disabled={busy}
+            onClick={() => void run(() => abortRebase(projectId, taskId), 'Failed to abort rebase')}
+            className="flex items-center gap-1 px-2 py-1 text-xs rounded-md text-[var(--color-text-muted)] hover:text-[var(--color-error)] disabled:opacity-50 transition-colors"
+          >
+            <X size={12} />
+            Abort
+          </button>
+          <button
+            disabled={busy || unresolved > 0}
+            onClick={() => void run(() => continueRebase(projectId, taskId), 'Failed to continue rebase')}
+            title={unresolved > 0 ? `${unresolved} file(s) still unresolved` : 'Continue the rebase'}
+            className="flex items-center gap-1 px-2 py-1 text-xs rounded-md bg-[var(--color-highlight)] text-white hover:opacity-90 disabled:opacity-50 transition-opacity"
+          >
+            <GitMerge size={12} />
+            Continue
+          </button>
+        </div>
+      </div>
+      {unresolved === 0 && (
+        <div className="text-xs text-[var(--color-text-muted)]">All conflicts resolved — continue the rebase.</div>
+      )}
+      {state.files.map((file) => (
+        <ConflictFileCard
+          key={file.path}
+          file={file}
+          busy={busy}
+          onResolve={(path, resolution) =>
+            void run(() => resolveConflict(projectId, taskId, path, resolution), `Failed to resolve ${path}`)
+          }
+        />
+      ))}
+      {error && (
+        <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,49 @@
+import type { DependencyChangeKind, DependencySummary } from '../../api/review';
+
+const KIND_LABELS: Record<DependencyChangeKind, string> = {
+  added: 'Added',
+  removed: 'Removed',
+  upgraded: 'Upgraded',
+  downgraded: 'Downgraded',
+  changed: 'Changed',
+};
+
+interface DependencySummaryViewProps {
+  summary: DependencySummary;
+  onShowRaw: () => void;
+}
+
+/** Package-level view of a lockfile diff, shown instead of the raw lines */
+export function DependencySummaryView({ summary, onShowRaw }: DependencySummaryViewProps) {
+  return (
+    <div className="diff-deps">
+      <div className="diff-deps-header">
+        <span>
+          {summary.ecosystem} lockfile: {summary.added} added, {summary.removed} removed, {summary.updated} updated
+        </span>
+        <button type="button" className="diff-deps-raw" onClick={onShowRaw}>
+          Show raw diff
+        </button>
+      </div>
+      {summary.changes.length === 0 ? (
+        <div className="diff-binary">No package changes</div>
+      ) : (
+        <table className="diff-deps-table">
+          <tbody>
+            {summary.changes.map((change) => (
+              <tr key={change.name} className={`diff-deps-${change.kind}`}>
+                <td className="diff-deps-kind">{KIND_LABELS[change.kind]}</td>
+                <td className="diff-deps-name">{change.name}</td>
+                <td className="diff-deps-versions">
+                  {change.old_versions.join(', ')}
+                  {change.old_versions.length > 0 && change.new_versions.length > 0 && ' → '}
+                  {change.new_versions.join(', ')}
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      )}
+    </div>
+  );
+}
@@ -1,5 +1,5 @@
 import { useRef, useEffect, Fragment, useState, useMemo, useCallback, useId } from 'react';
-import type { DiffFile, DiffHunk } from '../../api/review';
+import type { DiffFile, DiffHunk, HunkAttribution } from '../../api/review';
 import { getFileContent } from '../../api/review';
 import type { ReviewCommentEntry } from '../../api/tasks';
 import type { CommentAnchor } from './DiffReviewPage';
@@ -15,6 +15,7 @@ import { extractToc } from '../ui/extractToc';
 import { ImagePreview, type PreviewRenderer, type PreviewCommentMarker } from './previewRenderers';
 import { PreviewCommentHost } from './PreviewCommentHost';
 import { PreviewSearchBar } from './PreviewSearchBar';
+import { DependencySummaryView } from './DependencySummaryView';
 import { useDomSearch } from './useDomSearch';
 import { ImageLightbox } from '../ui/ImageLightbox';
 import { usePreviewComments, type PreviewCommentLocator } from '../../context';
@@ -340,6 +341,8 @@ export function DiffFileView({
   const previewCommentId = useId().replace(/:/g, '');
   const { drafts: previewCommentDrafts, addDraft, updateDraft, removeDraft } = usePreviewComments();
   const [copied, setCopied] = useState(false);
+  // Lockfiles show a package summary until the raw lines are asked for
+  const [showRawLockfile, setShowRawLockfile] = useState(false);
   const [fileCommentText, setFileCommentText] = useState('');
   const fileCommentTextareaRef = useRef<HTMLTextAreaElement>(null);
   const fileCommentMention = useFileMention({ mentionItems: mentionItems ?? null, textareaRef: fileCommentTextareaRef });
@@ -1284,6 +1287,16 @@ export function DiffFileView({
             {file.change_type === 'added' ? 'A' : file.change_type === 'deleted' ? 'D' : file.change_type === 'renamed' ? 'R' : 'M'}
           </span>
           <span className="diff-file-path">{file.new_path}</span>
+          {file.is_generated && (
+            <span className="diff-file-generated" title="Generated or vendored file — collapsed by default">
+              Generated
+            </span>
+          )}
+          {file.owners && file.owners.length > 0 && (
+            <span className="diff-file-owners" title={`CODEOWNERS: ${file.owners.join(', ')}`}>
+              {file.owners.join(' ')}
+            </span>
+          )}
           {onTogglePreview && (
             <button
               className={`diff-file-preview-btn${isPreviewOpen ? ' active' : ''}`}
@@ -1537,6 +1550,8 @@ export function DiffFileView({
                 ) : (
                   <div className="diff-error">Failed to load file content</div>
                 )
+              ) : file.dependencies && !showRawLockfile ? (
+                <DependencySummaryView summary={file.dependencies} onShowRaw={() => setShowRawLockfile(true)} />
               ) : file.hunks.length === 0 && (file.additions > 0 || file.deletions > 0) ? (
                 <div className="diff-binary" style={{ display: 'flex', alignItems: 'center', gap: 8 }}>
                   <div className="spinner" style={{ width: 14, height: 14 }} />
@@ -2085,6 +2100,26 @@ function ExpandButtons({
   );
 }
 
+/** Small badge naming the chat turn that produced a hunk */
+/** Gutter marker for an inserted line's test coverage */
+function coverageClass(line?: { covered?: boolean } | null): string {
+  if (line?.covered == null) return '';
+  return line.covered ? 'diff-gutter-covered' : 'diff-gutter-uncovered';
+}
+
+function HunkAttributionBadge({ attribution }: { attribution: HunkAttribution }) {
+  const date = new Date(attribution.turn_ts * 1000);
+  const detail = [attribution.agent, attribution.model, attribution.mode].filter(Boolean).join(' · ');
+  return (
+    <span
+      className="diff-hunk-attribution"
+      title={`${attribution.chat_title} — turn at ${date.toLocaleString()}\n${detail}`}
+    >
+      {attribution.chat_title} · {date.toLocaleTimeString([], { hour: '2-digit', minute: '2-digit' })}
+    </span>
+  );
+}
+
 // ============================================================================
 // Expanded context lines
 // ============================================================================
@@ -2669,6 +2704,7 @@ function HunkRows({
                 />
               )}
               <span className="diff-hunk-header-text">{hunk.header}</span>
+              {hunk.attribution && <HunkAttributionBadge attribution={hunk.attribution} />}
             </div>
           </td>
         </tr>
@@ -2751,7 +2787,7 @@ function HunkRows({
                 {line.old_line ?? ''}
               </td>
               <td
-                className={`diff-gutter ${collapsedCount > 0 ? 'has-collapsed-comment' : ''}`}
+                className={`diff-gutter ${coverageClass(line)} ${collapsedCount > 0 ? 'has-collapsed-comment' : ''}`}
                 onClick={(e) => {
                   if (collapsedCount > 0 && onExpandComment) {
                     lineComments.filter((c) => collapsedCommentIds?.has(c.id)).forEach((c) => onExpandComment(c.id));
@@ -3035,6 +3071,7 @@ function SplitHunkRows({
                 />
               )}
               <span className="diff-hunk-header-text">{hunk.header}</span>
+              {hunk.attribution && <HunkAttributionBadge attribution={hunk.attribution} />}
             </div>
           </td>
         </tr>
@@ -3147,7 +3184,7 @@ function SplitHunkRows({
                 ) : ''}
               </td>
               <td
-                className={`diff-gutter diff-gutter-split-middle ${pair.right?.line_type === 'insert' ? 'diff-line-insert' : ''} ${rightHighlighted ? 'diff-line-highlighted' : ''} ${collapsedRightCount > 0 ? 'has-collapsed-comment' : ''}`}
+                className={`diff-gutter diff-gutter-split-middle ${pair.right?.line_type === 'insert' ? 'diff-line-insert' : ''} ${coverageClass(pair.right)} ${rightHighlighted ? 'diff-line-highlighted' : ''} ${collapsedRightCount > 0 ? 'has-collapsed-comment' : ''}`}
                 data-side="ADD"
                 onClick={(e) => {
                   if (collapsedRightCount > 0 && onExpandComment) {
@@ -3290,6 +3327,7 @@ interface SplitPairSide {
   content: string;
   html?: string;
   line_type: string;
+  covered?: boolean;
 }
 
 interface SplitPair {
@@ -3312,7 +3350,7 @@ function buildSplitPairs(lines: DiffFile['hunks'][0]['lines'], highlightedCode?:
           ? { old_line: deletes[i].line.old_line, content: deletes[i].line.content, html: getHtml(deletes[i].idx), line_type: 'delete' }
           : null,
         right: inserts[i]
-          ? { new_line: inserts[i].line.new_line, content: inserts[i].line.content, html: getHtml(inserts[i].idx), line_type: 'insert' }
+          ? { new_line: inserts[i].line.new_line, content: inserts[i].line.content, html: getHtml(inserts[i].idx), line_type: 'insert', covered: inserts[i].line.covered }
           : null,
       });
     }
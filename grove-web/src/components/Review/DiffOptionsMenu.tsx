@@ -0,0 +1,70 @@
+import { useState, useRef, useEffect } from 'react';
+import { Check, SlidersHorizontal } from 'lucide-react';
+import type { ReviewConfig } from '../../api/config';
+
+interface DiffOptionsMenuProps {
+  options: ReviewConfig;
+  onChange: (patch: Partial<ReviewConfig>) => void;
+}
+
+const ITEMS: { key: keyof ReviewConfig; label: string; hint: string }[] = [
+  { key: 'ignore_whitespace', label: 'Ignore whitespace', hint: '-w' },
+  { key: 'ignore_blank_lines', label: 'Ignore blank lines', hint: '--ignore-blank-lines' },
+  { key: 'detect_renames', label: 'Detect renames', hint: '-M' },
+  { key: 'detect_copies', label: 'Detect copies', hint: '-C' },
+];
+
+/** Toolbar dropdown for whitespace / rename diff options (saved as user preferences) */
+export function DiffOptionsMenu({ options, onChange }: DiffOptionsMenuProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const containerRef = useRef<HTMLDivElement>(null);
+  const activeCount = (options.ignore_whitespace ? 1 : 0) + (options.ignore_blank_lines ? 1 : 0);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    const onMouseDown = (e: MouseEvent) => {
+      if (containerRef.current && !containerRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') setIsOpen(false);
+    };
+    document.addEventListener('mousedown', onMouseDown);
+    document.addEventListener('keydown', onKeyDown);
+    return () => {
+      document.removeEventListener('mousedown', onMouseDown);
+      document.removeEventListener('keydown', onKeyDown);
+    };
+  }, [isOpen]);
+
+  return (
+    <div className="diff-version-selector" ref={containerRef}>
+      <button
+        className={`diff-version-trigger ${isOpen ? 'open' : ''}`}
+        onClick={() => setIsOpen((v) => !v)}
+        title="Diff options"
+      >
+        <SlidersHorizontal style={{ width: 12, height: 12 }} />
+        {activeCount > 0 && <span>{activeCount}</span>}
+      </button>
+
+      {isOpen && (
+        <div className="diff-version-dropdown">
+          {ITEMS.map((item) => (
+            <button
+              key={item.key}
+              className={`diff-version-option ${options[item.key] ? 'selected' : ''}`}
+              onClick={() => onChange({ [item.key]: !options[item.key] })}
+              style={{ display: 'flex', alignItems: 'center', gap: 6 }}
+            >
+              <Check style={{ width: 12, height: 12, visibility: options[item.key] ? 'visible' : 'hidden' }} />
+              <span style={{ flex: 1 }}>{item.label}</span>
+              <code style={{ opacity: 0.5, fontSize: 10 }}>{item.hint}</code>
+            </button>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
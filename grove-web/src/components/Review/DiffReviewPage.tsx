@@ -1,16 +1,29 @@
 import { useState, useEffect, useCallback, useRef, useMemo } from 'react';
-import { getDiffStats, getSingleFileDiff, createInlineComment, createFileComment, createProjectComment, deleteComment as apiDeleteComment, replyReviewComment as apiReplyComment, updateCommentStatus as apiUpdateCommentStatus, getFileContent, editComment as apiEditComment, editReply as apiEditReply, deleteReply as apiDeleteReply, bulkDeleteComments as apiBulkDeleteComments } from '../../api/review';
-import type { DiffFile, DiffStatsResult } from '../../api/review';
-import { getReviewComments, getCommits, getTaskFiles, getTaskDirEntries, getTask, openTaskFile } from '../../api/tasks';
-import type { ReviewCommentEntry, ReviewCommentsResponse, DirEntry, CommitsResponse } from '../../api/tasks';
+import { getDiffStats, getDiffRefs, getSingleFileDiff, createInlineComment, createFileComment, createProjectComment, deleteComment as apiDeleteComment, replyReviewComment as apiReplyComment, updateCommentStatus as apiUpdateCommentStatus, getFileContent, editComment as apiEditComment, editReply as apiEditReply, deleteReply as apiDeleteReply, bulkDeleteComments as apiBulkDeleteComments, submitReview as apiSubmitReview, discardReviewDrafts as apiDiscardReviewDrafts, startCrossReview as apiStartCrossReview, toggleReaction as apiToggleReaction, getTaskCoverage, uploadTaskCoverage } from '../../api/review';
+import type { DiffFile, DiffStatsResult, DiffRefEntry, CoverageSummary } from '../../api/review';
+import { getReviewComments, getCommits, getTaskFiles, getTaskDirEntries, getTask, openTaskFile, getTaskOwners } from '../../api/tasks';
+import type { ReviewCommentEntry, ReviewCommentsResponse, ReviewVerdict, DirEntry, CommitsResponse, OwnersSummary } from '../../api/tasks';
+import { getDiffFilters, updateDiffFilters } from '../../api/projects';
+import { getConfig, patchConfig } from '../../api/config';
+import type { ReviewConfig } from '../../api/config';
 import { buildMentionItems } from '../../utils/fileMention';
 
 export interface VersionOption {
   id: string;
   label: string;
-  ref?: string;  // git ref (commit hash); undefined for 'latest' and 'target'
+  ref?: string;  // git ref (commit hash or ref name); undefined for 'latest' and 'target'
 }
 
+/** Prefix of version ids that compare against an arbitrary ref (FROM side only) */
+const REF_VERSION_PREFIX = 'ref:';
+
+const REF_KIND_LABEL: Record<DiffRefEntry['kind'], string> = {
+  task: 'Task',
+  branch: 'Branch',
+  tag: 'Tag',
+  remote: 'Remote',
+};
+
 export interface CommentAnchor {
   filePath: string;
   side: 'ADD' | 'DELETE';
@@ -18,8 +31,12 @@ export interface CommentAnchor {
   endLine: number;
 }
 
-/** Build VersionOption[] from a commits response: Latest + Version N..1 + Base. */
-function buildVersionOpts(commitsData: CommitsResponse | null | undefined): VersionOption[] {
+/** Build VersionOption[] from a commits response: Latest + Version N..1 + Base,
+ *  followed by any extra compare refs (other tasks, branches, tags, remotes). */
+function buildVersionOpts(
+  commitsData: CommitsResponse | null | undefined,
+  compareRefs: DiffRefEntry[] = [],
+): VersionOption[] {
   const opts: VersionOption[] = [{ id: 'latest', label: 'Latest' }];
   // Every commit between Base..HEAD gets its own Version entry. When working
   // tree is clean the newest commit (commits[0]) ends up identical to Latest;
@@ -39,6 +56,13 @@ function buildVersionOpts(commitsData: CommitsResponse | null | undefined): Vers
     }
   }
   opts.push({ id: 'target', label: 'Base' });
+  for (const r of compareRefs) {
+    opts.push({
+      id: `${REF_VERSION_PREFIX}${r.name}`,
+      label: `${REF_KIND_LABEL[r.kind]}: ${r.label}`,
+      ref: r.name,
+    });
+  }
   return opts;
 }
 
@@ -65,8 +89,14 @@ import { FileTreeSidebar } from './FileTreeSidebar';
 import { DiffFileView, resetGlobalMatchIndex } from './DiffFileView';
 import { ConversationSidebar } from './ConversationSidebar';
 import { CodeSearchBar } from './CodeSearchBar';
-import { MessageSquare, ChevronUp, ChevronDown, PanelLeftClose, PanelLeftOpen, Crosshair, GitCompare, FileText, RefreshCw, Code, Columns2, Eye, ZoomIn, ZoomOut } from 'lucide-react';
+import { ConflictResolver } from './ConflictResolver';
+import { MessageSquare, ChevronUp, ChevronDown, PanelLeftClose, PanelLeftOpen, Crosshair, GitCompare, FileText, RefreshCw, Code, Columns2, Eye, ZoomIn, ZoomOut, Users } from 'lucide-react';
 import { VersionSelector } from './VersionSelector';
+import { DiffOptionsMenu } from './DiffOptionsMenu';
+import { ReviewSubmitMenu } from './ReviewSubmitMenu';
+import { ReviewReactionContext } from './reviewReactions';
+import { useGlobalActiveChatId } from '../Tasks/TaskView/useActiveChatId';
+import { useBanner } from '../../context';
 import { useIsMobile } from '../../hooks';
 import { useKeyboardScope, useCommand, useContextKey } from '../../keyboard';
 import './diffTheme.css';
@@ -105,6 +135,30 @@ import { getPreviewRenderer } from './previewRenderers';
 export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, isGitRepo, isChatBusy }: DiffReviewPageProps) {
   const { isMobile } = useIsMobile();
   const [diffData, setDiffData] = useState<DiffStatsResult | null>(null);
+  // Path-scoped tasks: show the whole tree instead of just the scope
+  const [fullTree, setFullTree] = useState(false);
+  const fullTreeRef = useRef(false);
+  fullTreeRef.current = fullTree;
+  // CODEOWNERS overview: files outside the scope / owned by other teams
+  const [ownersSummary, setOwnersSummary] = useState<OwnersSummary | null>(null);
+  useEffect(() => {
+    let cancelled = false;
+    getTaskOwners(projectId, taskId)
+      .then((summary) => { if (!cancelled) setOwnersSummary(summary); })
+      .catch(() => { if (!cancelled) setOwnersSummary(null); });
+    return () => { cancelled = true; };
+  }, [projectId, taskId]);
+  // Latest uploaded coverage report, summarized against the current changes
+  const [coverage, setCoverage] = useState<CoverageSummary | null>(null);
+  const [coverageError, setCoverageError] = useState<string | null>(null);
+  const coverageInputRef = useRef<HTMLInputElement>(null);
+  useEffect(() => {
+    let cancelled = false;
+    getTaskCoverage(projectId, taskId)
+      .then((summary) => { if (!cancelled) setCoverage(summary); })
+      .catch(() => { if (!cancelled) setCoverage(null); });
+    return () => { cancelled = true; };
+  }, [projectId, taskId, diffData]);
   const [taskPath, setTaskPath] = useState<string | null>(null);
   useEffect(() => {
     const ac = new AbortController();
@@ -203,6 +257,11 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
   });
   const [sidebarSearch, setSidebarSearch] = useState('');
   const [collapsedFiles, setCollapsedFiles] = useState<Set<string>>(new Set());
+  // Generated files the user expanded — not re-collapsed or skipped on refetch
+  const expandedGeneratedRef = useRef<Set<string>>(new Set());
+  const [collapsedPatterns, setCollapsedPatterns] = useState<string[]>([]);
+  // Whitespace / rename preferences — the diff endpoints read the saved values
+  const [diffOptions, setDiffOptions] = useState<ReviewConfig | null>(null);
   const [replyFormCommentId, setReplyFormCommentId] = useState<number | null>(null);
   // Sidebar state is split into desktop/mobile so we can derive the effective value
   // without setState-in-effect. Each track its own open/closed user intent; switching
@@ -412,6 +471,23 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
 
   const [collapsedCommentIds, setCollapsedCommentIds] = useState<Set<number>>(new Set());
   const [versions, setVersions] = useState<VersionOption[]>([]);
+  // Extra compare targets for the FROM selector; loaded once per task
+  const compareRefsRef = useRef<DiffRefEntry[]>([]);
+  useEffect(() => {
+    let cancelled = false;
+    getDiffRefs(projectId, taskId)
+      .then(({ refs }) => {
+        if (cancelled) return;
+        compareRefsRef.current = refs;
+        setVersions((prev) => {
+          if (prev.length === 0) return prev;
+          const base = prev.filter((v) => !v.id.startsWith(REF_VERSION_PREFIX));
+          return [...base, ...buildVersionOpts(null, refs).filter((v) => v.id.startsWith(REF_VERSION_PREFIX))];
+        });
+      })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId, taskId]);
   const currentDiffRefs = useMemo(() => {
     const fromOpt = versions.find((v) => v.id === fromVersion);
     const toOpt = versions.find((v) => v.id === toVersion);
@@ -614,6 +690,8 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
       additions: e.additions,
       deletions: e.deletions,
       is_untracked: e.status === 'U',
+      is_generated: e.collapsed,
+      owners: e.owners,
     }));
     return sortTreeOrder(statFiles);
   }, [viewMode, allFiles, diffData, temporaryVirtualPaths, comments, sortTreeOrder]);
@@ -777,7 +855,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
     let count = allFiles.length;
     if (count === 0) {
       try {
-        const result = await getTaskFiles(projectId, taskId);
+        const result = await getTaskFiles(projectId, taskId, fullTreeRef.current);
         count = result.files.length;
       } catch {
         setFocusMode(false);
@@ -920,6 +998,24 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
     });
   }, []);
 
+  // Draft review mode — new comments stay pending (hidden from the agent)
+  // until the review is submitted with a verdict.
+  const [reviewDraftMode, setReviewDraftMode] = useState<boolean>(
+    () => localStorage.getItem('grove:review.draftMode') === 'true'
+  );
+  const handleToggleDraftMode = useCallback(() => {
+    setReviewDraftMode((prev) => {
+      const next = !prev;
+      try {
+        localStorage.setItem('grove:review.draftMode', String(next));
+      } catch { /* ignore localStorage issues */ }
+      return next;
+    });
+  }, []);
+  const pendingDraftCount = useMemo(() => comments.filter((c) => c.draft).length, [comments]);
+  const activeChatId = useGlobalActiveChatId();
+  const { showBanner } = useBanner();
+
   const activeFilePath = useMemo(() => {
     const found = displayFiles.find((f) => f.new_path === selectedFile && !f.new_path.endsWith('/'));
     if (found) return found.new_path;
@@ -968,9 +1064,9 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
     () => versionList.filter((v) => v.id !== 'latest'),
     [versionList],
   );
-  // TO: everything except Base (newest first: Latest, Version N..1)
+  // TO: everything except Base and compare refs (newest first: Latest, Version N..1)
   const toOptions = useMemo(
-    () => versionList.filter((v) => v.id !== 'target'),
+    () => versionList.filter((v) => v.id !== 'target' && !v.id.startsWith(REF_VERSION_PREFIX)),
     [versionList],
   );
 
@@ -991,7 +1087,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
     let data: Awaited<ReturnType<typeof getDiffStats>> | null = null;
     let caught: unknown = null;
     try {
-      data = await getDiffStats(projectId, taskId, fromRef, toRef);
+      data = await getDiffStats(projectId, taskId, fromRef, toRef, fullTreeRef.current);
     } catch (e) {
       caught = e;
     }
@@ -1036,12 +1132,12 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
       }).catch(console.error).finally(() => {
         if (fetchGenRef.current === gen) setLoading(false);
       });
-      getTaskFiles(projectId, taskId).then((result) => {
+      getTaskFiles(projectId, taskId, fullTreeRef.current).then((result) => {
         if (fetchGenRef.current !== gen) return;
         setAllFiles(result.files);
       }).catch(() => null);
     } else if (viewMode === 'full' && !focusMode) {
-      getTaskFiles(projectId, taskId).then((result) => {
+      getTaskFiles(projectId, taskId, fullTreeRef.current).then((result) => {
         if (fetchGenRef.current !== gen) return;
         setAllFiles(result.files);
       }).catch(() => null).finally(() => {
@@ -1052,7 +1148,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
       // Parallel-fetch task files so allFiles is populated for file autocomplete (@) support!
       Promise.all([
         getCommits(projectId, taskId),
-        getTaskFiles(projectId, taskId).catch(() => ({ files: [] as string[] }))
+        getTaskFiles(projectId, taskId, fullTreeRef.current).catch(() => ({ files: [] as string[] }))
       ])
         .then(([commitsData, filesData]) => {
           if (fetchGenRef.current !== gen) return;
@@ -1061,7 +1157,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
             setAllFiles(filesData.files);
           }
 
-          const opts = buildVersionOpts(commitsData);
+          const opts = buildVersionOpts(commitsData, compareRefsRef.current);
           setVersions(opts);
 
           // Reconcile cached selection against the fresh opts (a commit could
@@ -1137,7 +1233,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
       if (viewMode === 'diff') {
         const commitsData = await getCommits(projectId, taskId).catch(() => null);
         if (fetchGenRef.current !== gen) return;
-        const opts = buildVersionOpts(commitsData);
+        const opts = buildVersionOpts(commitsData, compareRefsRef.current);
         setVersions(opts);
 
         // Reconcile cached selection against the fresh opts so the selector
@@ -1157,7 +1253,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
         const fromOpt = opts.find((v) => v.id === from);
         const toOpt = opts.find((v) => v.id === to);
 
-        const filesPromise = getTaskFiles(projectId, taskId)
+        const filesPromise = getTaskFiles(projectId, taskId, fullTreeRef.current)
           .then((result) => {
             if (fetchGenRef.current !== gen) return;
             setAllFiles(result.files);
@@ -1185,7 +1281,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
         await Promise.all([
           commentsPromise,
           fullContentPromise,
-          getTaskFiles(projectId, taskId).then((result) => {
+          getTaskFiles(projectId, taskId, fullTreeRef.current).then((result) => {
             if (fetchGenRef.current !== gen) return;
             setAllFiles(result.files);
           }).catch(() => null),
@@ -1212,6 +1308,52 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
     void doRefresh(true).catch(() => {});
   }, [doRefresh]);
 
+  // Upload a coverage report, then reload so changed lines get annotated
+  const handleUploadCoverage = useCallback((e: React.ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0];
+    e.target.value = '';
+    if (!file) return;
+    setCoverageError(null);
+    uploadTaskCoverage(projectId, taskId, file)
+      .then(() => handleSilentRefresh())
+      .catch((err: { message?: string }) => setCoverageError(err?.message || 'Failed to upload coverage'));
+  }, [projectId, taskId, handleSilentRefresh]);
+
+  useEffect(() => {
+    let cancelled = false;
+    getConfig()
+      .then((cfg) => { if (!cancelled && cfg.review) setDiffOptions(cfg.review); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, []);
+
+  // Save diff options as user preferences, then reload every file's diff
+  const handleDiffOptionsChange = useCallback((patch: Partial<ReviewConfig>) => {
+    setDiffOptions((prev) => (prev ? { ...prev, ...patch } : prev));
+    patchConfig({ review: patch })
+      .then((cfg) => {
+        setDiffOptions(cfg.review);
+        handleRefresh();
+      })
+      .catch(() => {});
+  }, [handleRefresh]);
+
+  // Save project collapse globs, then refetch so `collapsed` flags follow
+  const handleUpdateCollapsedPatterns = useCallback((patterns: string[]) => {
+    setCollapsedPatterns(patterns);
+    updateDiffFilters(projectId, patterns)
+      .then((res) => {
+        setCollapsedPatterns(res.collapsed_patterns);
+        handleSilentRefresh();
+      })
+      .catch(() => {
+        // Invalid glob — fall back to what the server has
+        getDiffFilters(projectId)
+          .then((res) => setCollapsedPatterns(res.collapsed_patterns))
+          .catch(() => {});
+      });
+  }, [projectId, handleSilentRefresh]);
+
   const previousChatBusyRef = useRef(!!isChatBusy);
 
   // Auto-refresh silently when Agent finishes a turn (isChatBusy transitions from true to false).
@@ -1257,14 +1399,14 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
       if (viewMode === 'diff') {
         const isDefaultVersion = fromVersion === 'target' && toVersion === 'latest';
         if (isDefaultVersion) {
-          diffPromise = getDiffStats(projectId, taskId);
+          diffPromise = getDiffStats(projectId, taskId, undefined, undefined, fullTreeRef.current);
         } else {
           // Resolve custom version hashes from commits list
           diffPromise = commitsPromise.then(async (commitsData) => {
             let resolvedFromRef: string | undefined = undefined;
             let resolvedToRef: string | undefined = undefined;
             if (commitsData && commitsData.commits.length > 0) {
-              const opts = buildVersionOpts(commitsData);
+              const opts = buildVersionOpts(commitsData, compareRefsRef.current);
 
               const fromOpt = opts.find(v => v.id === fromVersion);
               const toOpt = opts.find(v => v.id === toVersion);
@@ -1291,9 +1433,9 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
                 // header options" effect — no need to mirror writes here.
               }
             }
-            return getDiffStats(projectId, taskId, resolvedFromRef, resolvedToRef);
+            return getDiffStats(projectId, taskId, resolvedFromRef, resolvedToRef, fullTreeRef.current);
           }).catch(() => {
-            return getDiffStats(projectId, taskId);
+            return getDiffStats(projectId, taskId, undefined, undefined, fullTreeRef.current);
           });
         }
       } else {
@@ -1301,7 +1443,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
       }
       let filesPromise: Promise<Awaited<ReturnType<typeof getTaskFiles>> | null>;
       if (viewMode === 'full' && !focusMode) {
-        filesPromise = getTaskFiles(projectId, taskId).catch(() => null);
+        filesPromise = getTaskFiles(projectId, taskId, fullTreeRef.current).catch(() => null);
       } else {
         filesPromise = Promise.resolve(null);
       }
@@ -1352,7 +1494,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
         // Build version options: Latest, Version N..1, Base (newest first)
         // skip_versions = number of leading commits equivalent to Latest
         {
-          const opts = buildVersionOpts(commitsData);
+          const opts = buildVersionOpts(commitsData, compareRefsRef.current);
           if (!cancelled) {
             setVersions(opts);
             const { from: finalFrom, to: finalTo, changed } = reconcileVersionSelection(
@@ -1404,7 +1546,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
           // Now that we're ready, trigger the All Files fetch manually.
           if (viewMode === 'diff' && viewModeRef.current === 'full') {
             if (!focusModeRef.current) {
-              getTaskFiles(projectId, taskId)
+              getTaskFiles(projectId, taskId, fullTreeRef.current)
                 .then((result) => { if (!cancelled) setAllFiles(result.files); })
                 .catch(() => null);
             } else {
@@ -1426,7 +1568,32 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
 
     load();
     return () => { cancelled = true; };
-  }, [projectId, taskId]); // eslint-disable-line react-hooks/exhaustive-deps
+  }, [projectId, taskId, fullTree]); // eslint-disable-line react-hooks/exhaustive-deps
+
+  // Generated / vendored files start collapsed (their counts stay in the totals)
+  useEffect(() => {
+    if (!diffData) return;
+    const generated = diffData.files
+      .filter((f) => f.collapsed && !expandedGeneratedRef.current.has(f.path))
+      .map((f) => f.path);
+    if (generated.length === 0) return;
+    // eslint-disable-next-line react-hooks/set-state-in-effect
+    setCollapsedFiles((prev) => {
+      if (generated.every((p) => prev.has(p))) return prev;
+      const next = new Set(prev);
+      generated.forEach((p) => next.add(p));
+      return next;
+    });
+  }, [diffData]);
+
+  // Project-level collapse globs (edited from the rules modal)
+  useEffect(() => {
+    let cancelled = false;
+    getDiffFilters(projectId)
+      .then((res) => { if (!cancelled) setCollapsedPatterns(res.collapsed_patterns); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId]);
 
   // Unfocus mode: batch load diffs for all files after initial load
   useEffect(() => {
@@ -1442,6 +1609,8 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
       idx += batchSize;
       Promise.all(batch.map(f => {
         if (fileDiffCacheRef.current.has(f.path) || loadingDiffsRef.current.has(f.path)) return Promise.resolve();
+        // Generated files stay collapsed; their diff is fetched when expanded
+        if (f.collapsed && !expandedGeneratedRef.current.has(f.path)) return Promise.resolve();
         loadingDiffsRef.current = new Set(loadingDiffsRef.current).add(f.path);
         return getSingleFileDiff(projectId, taskId, f.path, fromOpt?.ref, toOpt?.ref)
           .then(result => {
@@ -1763,6 +1932,7 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
       const next = new Set(prev);
       if (wasCollapsed) {
         next.delete(path);
+        expandedGeneratedRef.current.add(path);
         // Trigger diff load when expanding in diff mode (lazy / ≥1000 case)
         if (viewMode === 'diff') {
           const fromOpt = versions.find(v => v.id === fromVersion);
@@ -1812,12 +1982,56 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
   // Add comment
   const handleAddComment = useCallback(async (anchor: CommentAnchor, content: string) => {
     try {
-      const result = await createInlineComment(projectId, taskId, anchor, content, gitUserNameRef.current);
+      const result = await createInlineComment(projectId, taskId, anchor, content, gitUserNameRef.current, reviewDraftMode);
       applyReviewResponse(result);
       setCommentFormAnchor(null);
     } catch {
       // Could add toast here
     }
+  }, [projectId, taskId, applyReviewResponse, reviewDraftMode]);
+
+  // Submit pending review: publish drafts with a verdict, notify the agent once
+  const handleSubmitReview = useCallback(async (verdict: ReviewVerdict, summary: string) => {
+    try {
+      const result = await apiSubmitReview(projectId, taskId, {
+        verdict,
+        summary,
+        author: gitUserNameRef.current,
+        chatId: activeChatId ?? undefined,
+      });
+      applyReviewResponse(result);
+      showBanner(
+        result.delivered_chat_id ? 'Review submitted and sent to the agent' : 'Review submitted (no live chat to notify)',
+        'success',
+      );
+      return true;
+    } catch {
+      showBanner('Failed to submit review', 'error');
+      return false;
+    }
+  }, [projectId, taskId, activeChatId, applyReviewResponse, showBanner]);
+
+  // Cross review: a different agent reviews the task and posts comments
+  const [crossReviewStarting, setCrossReviewStarting] = useState(false);
+  const handleCrossReview = useCallback(async () => {
+    setCrossReviewStarting(true);
+    try {
+      const started = await apiStartCrossReview(projectId, taskId);
+      showBanner(`Cross-review started with ${started.agent} — its comments will appear here`, 'success');
+    } catch (err) {
+      showBanner(err instanceof Error ? err.message : 'Failed to start cross-review', 'error');
+    } finally {
+      setCrossReviewStarting(false);
+    }
+  }, [projectId, taskId, showBanner]);
+
+  const handleDiscardReview = useCallback(async () => {
+    try {
+      const result = await apiDiscardReviewDrafts(projectId, taskId);
+      applyReviewResponse(result);
+    } catch {
+      // Could add toast here
+    }
   }, [projectId, taskId, applyReviewResponse]);
 
   // Delete comment
@@ -1846,23 +2060,23 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
 
   const handleSubmitFileComment = useCallback(async (filePath: string, content: string) => {
     try {
-      const result = await createFileComment(projectId, taskId, filePath, content, gitUserNameRef.current);
+      const result = await createFileComment(projectId, taskId, filePath, content, gitUserNameRef.current, reviewDraftMode);
       applyReviewResponse(result);
       setFileCommentFormPath(null);
     } catch {
       // Could add toast here
     }
-  }, [projectId, taskId, applyReviewResponse]);
+  }, [projectId, taskId, applyReviewResponse, reviewDraftMode]);
 
   // Add project comment
   const handleAddProjectComment = useCallback(async (content: string) => {
     try {
-      const result = await createProjectComment(projectId, taskId, content, gitUserNameRef.current);
+      const result = await createProjectComment(projectId, taskId, content, gitUserNameRef.current, reviewDraftMode);
       applyReviewResponse(result);
     } catch {
       // Could add toast here
     }
-  }, [projectId, taskId, applyReviewResponse]);
+  }, [projectId, taskId, applyReviewResponse, reviewDraftMode]);
 
   // Open reply form
   const handleOpenReplyForm = useCallback((commentId: number) => {
@@ -1942,6 +2156,23 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
     }
   }, [projectId, taskId, applyReviewResponse]);
 
+  // Toggle an emoji reaction on a comment or reply
+  const handleReact = useCallback(async (commentId: number, emoji: string, replyId?: number) => {
+    try {
+      const result = await apiToggleReaction(projectId, taskId, commentId, emoji, replyId, gitUserNameRef.current);
+      applyReviewResponse(result);
+    } catch {
+      // Could add toast here
+    }
+  }, [projectId, taskId, applyReviewResponse]);
+
+  const reactionContext = useMemo(
+    () => ({ onReact: handleReact, currentUser: gitUserNameRef.current }),
+    // gitUserNameRef is refreshed together with comments
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+    [handleReact, comments],
+  );
+
   // Bulk delete comments
   const handleBulkDelete = useCallback(async (statuses?: string[], authors?: string[]) => {
     try {
@@ -2041,383 +2272,477 @@ export function DiffReviewPage({ projectId, taskId, embedded, navigateToFile, is
   const validSelectedFile = activeFilePath;
 
   return (
-    <div
-      className={`diff-review-page ${isEmbedded ? 'embedded' : ''}`}
-      style={{
-        '--diff-font-size': `${fontSize}px`,
-        '--diff-line-height': `${fontSize + 8}px`,
-      } as React.CSSProperties}
-    >
-      {/* Page Header with Mode Selector */}
-      <div className="diff-page-header">
-        <div className="diff-page-title">Code Review</div>
-        <div className="diff-mode-selector">
-          {isGitRepo !== false && (
+    <ReviewReactionContext.Provider value={reactionContext}>
+      <div
+        className={`diff-review-page ${isEmbedded ? 'embedded' : ''}`}
+        style={{
+          '--diff-font-size': `${fontSize}px`,
+          '--diff-line-height': `${fontSize + 8}px`,
+        } as React.CSSProperties}
+      >
+        {/* Page Header with Mode Selector */}
+        <div className="diff-page-header">
+          <div className="diff-page-title">Code Review</div>
+          <div className="diff-mode-selector">
+            {isGitRepo !== false && (
+              <button
+                className={viewMode === 'diff' ? 'active' : ''}
+                onClick={() => void handleSetViewMode('diff')}
+              >
+                <GitCompare size={14} />
+                <span>Changes</span>
+              </button>
+            )}
             <button
-              className={viewMode === 'diff' ? 'active' : ''}
-              onClick={() => void handleSetViewMode('diff')}
+              className={viewMode === 'full' ? 'active' : ''}
+              onClick={() => void handleSetViewMode('full')}
             >
-              <GitCompare size={14} />
-              <span>Changes</span>
+              <FileText size={14} />
+              <span>All Files</span>
             </button>
-          )}
+          </div>
           <button
-            className={viewMode === 'full' ? 'active' : ''}
-            onClick={() => void handleSetViewMode('full')}
+            className="diff-refresh-btn"
+            onClick={handleRefresh}
+            disabled={refreshing}
+            title="Refresh diff"
           >
-            <FileText size={14} />
-            <span>All Files</span>
+            <RefreshCw size={14} className={refreshing ? 'spin' : ''} />
           </button>
         </div>
-        <button
-          className="diff-refresh-btn"
-          onClick={handleRefresh}
-          disabled={refreshing}
-          title="Refresh diff"
-        >
-          <RefreshCw size={14} className={refreshing ? 'spin' : ''} />
-        </button>
-      </div>
 
-      {/* Toolbar */}
-      <div className="diff-toolbar">
-        <div className="diff-toolbar-left">
-          <button
-            className="diff-toolbar-btn"
-            onClick={() => setSidebarVisible((v) => !v)}
-            title={sidebarVisible ? 'Hide file tree' : 'Show file tree'}
-          >
-            {sidebarVisible ? (
-              <PanelLeftClose style={{ width: 14, height: 14 }} />
-            ) : (
-              <PanelLeftOpen style={{ width: 14, height: 14 }} />
-            )}
-          </button>
-          <button
-            className={`diff-toggle-pill ${focusMode ? 'active' : ''}`}
-            onClick={() => void handleToggleFocusMode()}
-            title="Focus mode — show one file at a time"
-          >
-            <Crosshair style={{ width: 12, height: 12 }} />
-            <span className="toolbar-label">Focus</span>
-          </button>
-          {focusModeWarn && (
-            <span
-              style={{ fontSize: 11, color: 'var(--color-warning)', whiteSpace: 'nowrap', cursor: 'pointer' }}
-              onClick={() => setFocusModeWarn(null)}
-              title="Click to dismiss"
-            >
-              {focusModeWarn}
-            </span>
-          )}
-          <button
-            className="diff-toggle-pill"
-            onClick={() => setDisplayMode((v) => v === 'code' ? 'split' : v === 'split' ? 'preview' : 'code')}
-            title={`Display: ${displayMode === 'code' ? 'Code' : displayMode === 'split' ? 'Split' : 'Preview'} — click to cycle`}
-          >
-            {displayMode === 'code' ? (
-              <Code style={{ width: 12, height: 12 }} />
-            ) : displayMode === 'split' ? (
-              <Columns2 style={{ width: 12, height: 12 }} />
-            ) : (
-              <Eye style={{ width: 12, height: 12 }} />
-            )}
-            <span className="toolbar-label">
-              {displayMode === 'code' ? 'Code' : displayMode === 'split' ? 'Split' : 'Preview'}
-            </span>
-          </button>
-          {viewMode === 'diff' && (
-            <div className="diff-view-toggle">
-              <button
-                className={viewType === 'unified' ? 'active' : ''}
-                onClick={() => setViewType('unified')}
-              >
-                <span className="toolbar-label">Unified</span>
-                <span className="toolbar-label-short">Uni</span>
-              </button>
-              {!isMobile && (
-                <button
-                  className={viewType === 'split' ? 'active' : ''}
-                  onClick={() => setViewType('split')}
-                >
-                  <span className="toolbar-label">Split</span>
-                  <span className="toolbar-label-short">Spl</span>
-                </button>
-              )}
-            </div>
-          )}
-          {viewMode === 'diff' && fromOptions.length > 0 && toOptions.length > 0 && (
-            <div className="diff-version-range">
-              <VersionSelector options={fromOptions} selected={fromVersion} onChange={handleFromVersionChange} />
-              <span className="diff-version-arrow">&rarr;</span>
-              <VersionSelector options={toOptions} selected={toVersion} onChange={handleToVersionChange} />
-            </div>
-          )}
-          <span style={{ fontWeight: 600, color: 'var(--color-text)' }}>
-            {totalFiles}
-            <span className="toolbar-label"> file{totalFiles !== 1 ? 's' : ''}</span>
-          </span>
-          {viewMode === 'diff' && (
-            <span className="diff-stats flex items-center gap-1.5">
-              <span className="stat-add">+{diffData?.total_additions ?? 0}</span>
-              <span className="stat-del">-{diffData?.total_deletions ?? 0}</span>
-            </span>
-          )}
-        </div>
-        <div className="diff-toolbar-right">
-          <ViewedProgress viewed={viewedCount} total={totalFiles} />
-          <div className="flex items-center border border-[var(--color-border)] rounded-md bg-[var(--color-bg)] h-7 p-0.5 ml-1.5 mr-1">
+        {/* Sync stopped on rebase conflicts */}
+        {isGitRepo !== false && (
+          <ConflictResolver projectId={projectId} taskId={taskId} onFinished={handleRefresh} />
+        )}
+
+        {/* Toolbar */}
+        <div className="diff-toolbar">
+          <div className="diff-toolbar-left">
             <button
-              onClick={handleZoomOut}
-              className="px-2 h-full flex items-center justify-center text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] rounded-[4px] cursor-pointer transition-colors"
-              title="Zoom out"
+              className="diff-toolbar-btn"
+              onClick={() => setSidebarVisible((v) => !v)}
+              title={sidebarVisible ? 'Hide file tree' : 'Show file tree'}
             >
-              <ZoomOut className="w-3.5 h-3.5" />
+              {sidebarVisible ? (
+                <PanelLeftClose style={{ width: 14, height: 14 }} />
+              ) : (
+                <PanelLeftOpen style={{ width: 14, height: 14 }} />
+              )}
             </button>
             <button
-              onClick={handleZoomReset}
-              className="px-2.5 h-full flex items-center justify-center text-xs font-medium text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] rounded-[4px] cursor-pointer select-none transition-colors"
-              title="Reset zoom (12px)"
+              className={`diff-toggle-pill ${focusMode ? 'active' : ''}`}
+              onClick={() => void handleToggleFocusMode()}
+              title="Focus mode — show one file at a time"
             >
-              {fontSize}px
+              <Crosshair style={{ width: 12, height: 12 }} />
+              <span className="toolbar-label">Focus</span>
             </button>
+            {focusModeWarn && (
+              <span
+                style={{ fontSize: 11, color: 'var(--color-warning)', whiteSpace: 'nowrap', cursor: 'pointer' }}
+                onClick={() => setFocusModeWarn(null)}
+                title="Click to dismiss"
+              >
+                {focusModeWarn}
+              </span>
+            )}
             <button
-              onClick={handleZoomIn}
-              className="px-2 h-full flex items-center justify-center text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] rounded-[4px] cursor-pointer transition-colors"
-              title="Zoom in"
+              className="diff-toggle-pill"
+              onClick={() => setDisplayMode((v) => v === 'code' ? 'split' : v === 'split' ? 'preview' : 'code')}
+              title={`Display: ${displayMode === 'code' ? 'Code' : displayMode === 'split' ? 'Split' : 'Preview'} — click to cycle`}
             >
-              <ZoomIn className="w-3.5 h-3.5" />
+              {displayMode === 'code' ? (
+                <Code style={{ width: 12, height: 12 }} />
+              ) : displayMode === 'split' ? (
+                <Columns2 style={{ width: 12, height: 12 }} />
+              ) : (
+                <Eye style={{ width: 12, height: 12 }} />
+              )}
+              <span className="toolbar-label">
+                {displayMode === 'code' ? 'Code' : displayMode === 'split' ? 'Split' : 'Preview'}
+              </span>
             </button>
-          </div>
-          <div className="flex items-center border border-[var(--color-border)] rounded-md bg-[var(--color-bg)] h-7 p-0.5 mr-1">
+            {viewMode === 'diff' && (
+              <div className="diff-view-toggle">
+                <button
+                  className={viewType === 'unified' ? 'active' : ''}
+                  onClick={() => setViewType('unified')}
+                >
+                  <span className="toolbar-label">Unified</span>
+                  <span className="toolbar-label-short">Uni</span>
+                </button>
+                {!isMobile && (
+                  <button
+                    className={viewType === 'split' ? 'active' : ''}
+                    onClick={() => setViewType('split')}
+                  >
+                    <span className="toolbar-label">Split</span>
+                    <span className="toolbar-label-short">Spl</span>
+                  </button>
+                )}
+              </div>
+            )}
+            {viewMode === 'diff' && fromOptions.length > 0 && toOptions.length > 0 && (
+              <div className="diff-version-range">
+                <VersionSelector options={fromOptions} selected={fromVersion} onChange={handleFromVersionChange} />
+                <span className="diff-version-arrow">&rarr;</span>
+                <VersionSelector options={toOptions} selected={toVersion} onChange={handleToVersionChange} />
+              </div>
+            )}
+            {viewMode === 'diff' && diffOptions && (
+              <DiffOptionsMenu options={diffOptions} onChange={handleDiffOptionsChange} />
+            )}
+            {viewMode === 'diff' && (
+              <ReviewSubmitMenu
+                draftMode={reviewDraftMode}
+                onToggleDraftMode={handleToggleDraftMode}
+                pendingCount={pendingDraftCount}
+                onSubmit={handleSubmitReview}
+                onDiscard={handleDiscardReview}
+              />
+            )}
+            {viewMode === 'diff' && (
+              <button
+                className="diff-version-trigger"
+                onClick={handleCrossReview}
+                disabled={crossReviewStarting}
+                title="Have a different agent review this task and post its findings as comments"
+              >
+                <Users style={{ width: 12, height: 12 }} />
+                <span className="toolbar-label">{crossReviewStarting ? 'Starting…' : 'Cross-review'}</span>
+              </button>
+            )}
+            <span style={{ fontWeight: 600, color: 'var(--color-text)' }}>
+              {totalFiles}
+              <span className="toolbar-label"> file{totalFiles !== 1 ? 's' : ''}</span>
+            </span>
+            {viewMode === 'diff' && (
+              <span className="diff-stats flex items-center gap-1.5">
+                <span className="stat-add">+{diffData?.total_additions ?? 0}</span>
+                <span className="stat-del">-{diffData?.total_deletions ?? 0}</span>
+              </span>
+            )}
+            {ownersSummary && ownersSummary.warnings.length > 0 && (
+              <span
+                className="text-xs text-[var(--color-warning)]"
+                title={ownersSummary.warnings
+                  .map((w) => `${w.path} — ${w.kind === 'outside_scope' ? 'outside scope' : 'owned by'} ${w.owners.join(' ')}`)
+                  .join('\n')}
+              >
+                {ownersSummary.warnings.length} ownership warning{ownersSummary.warnings.length !== 1 ? 's' : ''}
+              </span>
+            )}
+            {diffData?.tests?.tests_missing && (
+              <span
+                className="text-xs text-[var(--color-warning)]"
+                title={`Source files changed without test changes:\n${diffData.tests.source_files.join('\n')}`}
+              >
+                Tests missing
+              </span>
+            )}
+            {coverage && coverage.percent != null && (
+              <span
+                className="text-xs text-[var(--color-text-muted)]"
+                title={[
+                  `${coverage.totals.covered}/${coverage.totals.lines} lines covered (${coverage.format}, ${new Date(coverage.uploaded_at).toLocaleString()})`,
+                  ...coverage.files
+                    .filter((f) => f.uncovered_lines.length > 0)
+                    .map((f) => `${f.path}: ${f.covered}/${f.lines} changed lines covered`),
+                ].join('\n')}
+              >
+                Coverage {coverage.percent.toFixed(1)}%
+                {coverage.delta != null && (
+                  <span className={coverage.delta < 0 ? 'text-[var(--color-error)]' : 'text-[var(--color-success)]'}>
+                    {' '}({coverage.delta >= 0 ? '+' : ''}{coverage.delta.toFixed(1)})
+                  </span>
+                )}
+                {coverage.patch_percent != null && ` · changed lines ${coverage.patch_percent.toFixed(0)}%`}
+              </span>
+            )}
             <button
-              onClick={goToPrevFile}
-              className="w-7 h-full flex items-center justify-center rounded-[4px] text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] disabled:opacity-40 disabled:hover:bg-transparent disabled:cursor-not-allowed cursor-pointer transition-colors"
-              title="Previous file"
-              disabled={currentFileIndex === 0}
+              onClick={() => coverageInputRef.current?.click()}
+              className={`text-xs cursor-pointer ${coverageError ? 'text-[var(--color-error)]' : 'text-[var(--color-text-muted)] hover:text-[var(--color-text)]'}`}
+              title={coverageError ?? "Upload an lcov / Cobertura report from this task's test run"}
             >
-              <ChevronUp className="w-3.5 h-3.5" />
+              {coverageError ? 'Coverage upload failed' : coverage ? 'Update coverage' : 'Upload coverage'}
             </button>
+            <input
+              ref={coverageInputRef}
+              type="file"
+              accept=".info,.lcov,.xml,.txt"
+              className="hidden"
+              onChange={handleUploadCoverage}
+            />
+            {(diffData?.scope || fullTree) && (
+              <button
+                onClick={() => setFullTree(v => !v)}
+                className="text-xs text-[var(--color-text-muted)] hover:text-[var(--color-text)] cursor-pointer"
+                title={fullTree ? 'Only show the task scope' : 'Show changes outside the task scope too'}
+              >
+                {fullTree
+                  ? 'Whole tree · back to scope'
+                  : `Scope ${diffData?.scope}/${diffData?.outside_scope ? ` · ${diffData.outside_scope} outside` : ''} · show all`}
+              </button>
+            )}
+          </div>
+          <div className="diff-toolbar-right">
+            <ViewedProgress viewed={viewedCount} total={totalFiles} />
+            <div className="flex items-center border border-[var(--color-border)] rounded-md bg-[var(--color-bg)] h-7 p-0.5 ml-1.5 mr-1">
+              <button
+                onClick={handleZoomOut}
+                className="px-2 h-full flex items-center justify-center text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] rounded-[4px] cursor-pointer transition-colors"
+                title="Zoom out"
+              >
+                <ZoomOut className="w-3.5 h-3.5" />
+              </button>
+              <button
+                onClick={handleZoomReset}
+                className="px-2.5 h-full flex items-center justify-center text-xs font-medium text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] rounded-[4px] cursor-pointer select-none transition-colors"
+                title="Reset zoom (12px)"
+              >
+                {fontSize}px
+              </button>
+              <button
+                onClick={handleZoomIn}
+                className="px-2 h-full flex items-center justify-center text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] rounded-[4px] cursor-pointer transition-colors"
+                title="Zoom in"
+              >
+                <ZoomIn className="w-3.5 h-3.5" />
+              </button>
+            </div>
+            <div className="flex items-center border border-[var(--color-border)] rounded-md bg-[var(--color-bg)] h-7 p-0.5 mr-1">
+              <button
+                onClick={goToPrevFile}
+                className="w-7 h-full flex items-center justify-center rounded-[4px] text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] disabled:opacity-40 disabled:hover:bg-transparent disabled:cursor-not-allowed cursor-pointer transition-colors"
+                title="Previous file"
+                disabled={currentFileIndex === 0}
+              >
+                <ChevronUp className="w-3.5 h-3.5" />
+              </button>
+              <button
+                onClick={goToNextFile}
+                className="w-7 h-full flex items-center justify-center rounded-[4px] text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] disabled:opacity-40 disabled:hover:bg-transparent disabled:cursor-not-allowed cursor-pointer transition-colors"
+                title="Next file"
+                disabled={currentFileIndex === totalFiles - 1}
+              >
+                <ChevronDown className="w-3.5 h-3.5" />
+              </button>
+            </div>
             <button
-              onClick={goToNextFile}
-              className="w-7 h-full flex items-center justify-center rounded-[4px] text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] disabled:opacity-40 disabled:hover:bg-transparent disabled:cursor-not-allowed cursor-pointer transition-colors"
-              title="Next file"
-              disabled={currentFileIndex === totalFiles - 1}
+              className={`diff-toolbar-btn ${convSidebarVisible ? 'active' : ''}`}
+              onClick={() => setConvSidebarVisible((v) => !v)}
+              title={convSidebarVisible ? 'Hide conversation' : 'Show conversation'}
             >
-              <ChevronDown className="w-3.5 h-3.5" />
+              <MessageSquare className="w-3.5 h-3.5" />
             </button>
           </div>
-          <button
-            className={`diff-toolbar-btn ${convSidebarVisible ? 'active' : ''}`}
-            onClick={() => setConvSidebarVisible((v) => !v)}
-            title={convSidebarVisible ? 'Hide conversation' : 'Show conversation'}
-          >
-            <MessageSquare className="w-3.5 h-3.5" />
-          </button>
         </div>
-      </div>
 
-      {/* Layout */}
-      <div
-        className="diff-layout"
-        style={{
-          '--diff-sidebar-width': `${sidebarWidth}px`,
-          '--diff-conv-sidebar-width': `${convSidebarWidth}px`,
-        } as React.CSSProperties}
-      >
-        {loading ? (
-          <div className="diff-content" style={{ display: 'flex', flexDirection: 'column', alignItems: 'center', justifyContent: 'center', flex: 1, gap: 8 }}>
-            <div className="spinner" />
-            <span style={{ color: 'var(--color-text-muted)', fontSize: 13 }}>Loading...</span>
-          </div>
-        ) : isEmpty ? (
-          /* Empty diff — keep toolbar visible for version switching */
-          <div className="diff-content" style={{ display: 'flex', alignItems: 'center', justifyContent: 'center', flex: 1 }}>
-            <span style={{ color: 'var(--color-text-muted)', fontSize: 14 }}>No changes found</span>
-          </div>
-        ) : (
-          <>
-            {/* Mobile overlay backdrop */}
-            {isMobile && (sidebarVisible || convSidebarVisible) && (
-              <div
-                style={{
-                  position: 'fixed',
-                  inset: 0,
-                  background: 'rgba(0,0,0,0.4)',
-                  zIndex: 15,
-                }}
-                onClick={() => {
-                  setSidebarVisible(false);
-                  setConvSidebarVisible(false);
-                }}
-              />
-            )}
-
-            {/* Sidebar */}
-            <FileTreeSidebar
-              files={displayFiles}
-              selectedFile={validSelectedFile}
-              onSelectFile={handleSelectFile}
-              searchQuery={sidebarSearch}
-              onSearchChange={setSidebarSearch}
-              fileCommentCounts={fileCommentCounts}
-              collapsed={!sidebarVisible}
-              getFileViewedStatus={getFileViewedStatus}
-              hideViewed={hideViewed}
-              onToggleHideViewed={handleToggleHideViewed}
-              onCreateVirtualPath={handleCreateVirtualPath}
-              viewMode={viewMode}
-              onExpandDir={viewMode === 'full' && focusMode ? handleExpandDir : undefined}
-              onLoadFileDiff={viewMode === 'full' && focusMode ? loadFileDiff : undefined}
-              taskPath={taskPath}
-              projectId={projectId}
-              onOpenInApp={(path) => { void openTaskFile(projectId, taskId, path); }}
-              autoViewedRules={autoViewedRules}
-              onUpdateAutoViewedRules={setAutoViewedRules}
-            />
+        {/* Layout */}
+        <div
+          className="diff-layout"
+          style={{
+            '--diff-sidebar-width': `${sidebarWidth}px`,
+            '--diff-conv-sidebar-width': `${convSidebarWidth}px`,
+          } as React.CSSProperties}
+        >
+          {loading ? (
+            <div className="diff-content" style={{ display: 'flex', flexDirection: 'column', alignItems: 'center', justifyContent: 'center', flex: 1, gap: 8 }}>
+              <div className="spinner" />
+              <span style={{ color: 'var(--color-text-muted)', fontSize: 13 }}>Loading...</span>
+            </div>
+          ) : isEmpty ? (
+            /* Empty diff — keep toolbar visible for version switching */
+            <div className="diff-content" style={{ display: 'flex', alignItems: 'center', justifyContent: 'center', flex: 1 }}>
+              <span style={{ color: 'var(--color-text-muted)', fontSize: 14 }}>No changes found</span>
+            </div>
+          ) : (
+            <>
+              {/* Mobile overlay backdrop */}
+              {isMobile && (sidebarVisible || convSidebarVisible) && (
+                <div
+                  style={{
+                    position: 'fixed',
+                    inset: 0,
+                    background: 'rgba(0,0,0,0.4)',
+                    zIndex: 15,
+                  }}
+                  onClick={() => {
+                    setSidebarVisible(false);
+                    setConvSidebarVisible(false);
+                  }}
+                />
+              )}
 
-            {/* Resizer between file tree sidebar and diff content (desktop only) */}
-            {!isMobile && sidebarVisible && (
-              <div
-                className="diff-resizer"
-                onPointerDown={startSidebarResize}
-                onDoubleClick={() => setSidebarWidth(DEFAULT_SIDEBAR_WIDTH)}
-                role="separator"
-                aria-orientation="vertical"
-                aria-label="Resize file tree sidebar"
-                title="Drag to resize · Double-click to reset"
+              {/* Sidebar */}
+              <FileTreeSidebar
+                files={displayFiles}
+                selectedFile={validSelectedFile}
+                onSelectFile={handleSelectFile}
+                searchQuery={sidebarSearch}
+                onSearchChange={setSidebarSearch}
+                fileCommentCounts={fileCommentCounts}
+                collapsed={!sidebarVisible}
+                getFileViewedStatus={getFileViewedStatus}
+                hideViewed={hideViewed}
+                onToggleHideViewed={handleToggleHideViewed}
+                onCreateVirtualPath={handleCreateVirtualPath}
+                viewMode={viewMode}
+                onExpandDir={viewMode === 'full' && focusMode ? handleExpandDir : undefined}
+                onLoadFileDiff={viewMode === 'full' && focusMode ? loadFileDiff : undefined}
+                taskPath={taskPath}
+                projectId={projectId}
+                onOpenInApp={(path) => { void openTaskFile(projectId, taskId, path); }}
+                autoViewedRules={autoViewedRules}
+                onUpdateAutoViewedRules={setAutoViewedRules}
+                collapsedPatterns={collapsedPatterns}
+                onUpdateCollapsedPatterns={handleUpdateCollapsedPatterns}
               />
-            )}
 
-            {/* Diff content */}
-            <div className="diff-content" ref={contentRef} tabIndex={-1} style={{ outline: 'none' }}>
-              {(() => {
-                // Reset global match index before rendering
-                resetGlobalMatchIndex();
-                return (focusMode
-                  ? displayFiles.filter((f) => f.new_path === validSelectedFile)
-                  : displayFiles
-                ).map((file) => {
-                  const renderer = getPreviewRenderer(file.new_path, viewMode);
-                  const defaultOpen = displayMode !== 'code' && !!renderer;
-                  // Image files render the picture directly inside the code
-                  // view (see DiffFileView), so the preview pane is redundant —
-                  // force it closed and hide the toggle. Other renderers keep
-                  // the override behavior.
-                  const isImage = renderer?.id === 'image';
-                  const isPreviewOpen = isImage
-                    ? false
-                    : previewOverrides.has(file.new_path)
-                      ? previewOverrides.get(file.new_path)!
-                      : defaultOpen;
-                  return (
-                    <DiffFileView
-                      key={file.new_path}
-                      file={file}
-                      viewType={viewType}
-                      isActive={validSelectedFile === file.new_path}
-                      isPreviewOpen={isPreviewOpen}
-                      onTogglePreview={renderer && !isImage ? handleTogglePreview : undefined}
-                      previewRenderer={renderer}
-                      defaultExpanded={displayMode === 'preview'}
-                      projectId={projectId}
-                      taskId={taskId}
-                      comments={getFileComments(file.new_path)}
-                      commentFormAnchor={commentFormAnchor}
-                      onGutterClick={handleGutterClick}
-                      onAddComment={handleAddComment}
-                      onDeleteComment={handleDeleteComment}
-                      onCancelComment={handleCancelComment}
-                      isCollapsed={collapsedFiles.has(file.new_path)}
-                      onToggleCollapse={handleToggleCollapse}
-                      viewedStatus={getFileViewedStatus(file.new_path)}
-                      onToggleViewed={handleToggleViewed}
-                      commentCount={fileCommentCounts.get(file.new_path)}
-                      replyFormCommentId={replyFormCommentId}
-                      onOpenReplyForm={handleOpenReplyForm}
-                      onReplyComment={handleReplyComment}
-                      onCancelReply={handleCancelReply}
-                      onResolveComment={handleResolveComment}
-                      onReopenComment={handleReopenComment}
-                      collapsedCommentIds={collapsedCommentIds}
-                      onCollapseComment={handleCollapseComment}
-                      onExpandComment={handleExpandComment}
-                      viewMode={viewMode}
-                      fullFileContent={fullFileContents.get(file.new_path)}
-                      isLoadingFullFile={loadingFiles.has(file.new_path)}
-                      onRequestFullFile={loadFullFileContent}
-                      onAddFileComment={handleAddFileComment}
-                      fileCommentFormPath={fileCommentFormPath}
-                      onCancelFileComment={handleCancelFileComment}
-                      onSubmitFileComment={handleSubmitFileComment}
-                      onEditComment={handleEditComment}
-                      onEditReply={handleEditReply}
-                      onDeleteReply={handleDeleteReply}
-                      codeSearchQuery={codeSearchQuery}
-                      codeSearchCaseSensitive={codeSearchCaseSensitive}
-                      scrollToLine={scrollToLine?.file === file.new_path ? { line: scrollToLine.line, seq: scrollToLine.seq } : undefined}
-                      mentionItems={mentionItems}
-                    />
-                  );
-                });
-              })()}
-            </div>
+              {/* Resizer between file tree sidebar and diff content (desktop only) */}
+              {!isMobile && sidebarVisible && (
+                <div
+                  className="diff-resizer"
+                  onPointerDown={startSidebarResize}
+                  onDoubleClick={() => setSidebarWidth(DEFAULT_SIDEBAR_WIDTH)}
+                  role="separator"
+                  aria-orientation="vertical"
+                  aria-label="Resize file tree sidebar"
+                  title="Drag to resize · Double-click to reset"
+                />
+              )}
+
+              {/* Diff content */}
+              <div className="diff-content" ref={contentRef} tabIndex={-1} style={{ outline: 'none' }}>
+                {(() => {
+                  // Reset global match index before rendering
+                  resetGlobalMatchIndex();
+                  return (focusMode
+                    ? displayFiles.filter((f) => f.new_path === validSelectedFile)
+                    : displayFiles
+                  ).map((file) => {
+                    const renderer = getPreviewRenderer(file.new_path, viewMode);
+                    const defaultOpen = displayMode !== 'code' && !!renderer;
+                    // Image files render the picture directly inside the code
+                    // view (see DiffFileView), so the preview pane is redundant —
+                    // force it closed and hide the toggle. Other renderers keep
+                    // the override behavior.
+                    const isImage = renderer?.id === 'image';
+                    const isPreviewOpen = isImage
+                      ? false
+                      : previewOverrides.has(file.new_path)
+                        ? previewOverrides.get(file.new_path)!
+                        : defaultOpen;
+                    return (
+                      <DiffFileView
+                        key={file.new_path}
+                        file={file}
+                        viewType={viewType}
+                        isActive={validSelectedFile === file.new_path}
+                        isPreviewOpen={isPreviewOpen}
+                        onTogglePreview={renderer && !isImage ? handleTogglePreview : undefined}
+                        previewRenderer={renderer}
+                        defaultExpanded={displayMode === 'preview'}
+                        projectId={projectId}
+                        taskId={taskId}
+                        comments={getFileComments(file.new_path)}
+                        commentFormAnchor={commentFormAnchor}
+                        onGutterClick={handleGutterClick}
+                        onAddComment={handleAddComment}
+                        onDeleteComment={handleDeleteComment}
+                        onCancelComment={handleCancelComment}
+                        isCollapsed={collapsedFiles.has(file.new_path)}
+                        onToggleCollapse={handleToggleCollapse}
+                        viewedStatus={getFileViewedStatus(file.new_path)}
+                        onToggleViewed={handleToggleViewed}
+                        commentCount={fileCommentCounts.get(file.new_path)}
+                        replyFormCommentId={replyFormCommentId}
+                        onOpenReplyForm={handleOpenReplyForm}
+                        onReplyComment={handleReplyComment}
+                        onCancelReply={handleCancelReply}
+                        onResolveComment={handleResolveComment}
+                        onReopenComment={handleReopenComment}
+                        collapsedCommentIds={collapsedCommentIds}
+                        onCollapseComment={handleCollapseComment}
+                        onExpandComment={handleExpandComment}
+                        viewMode={viewMode}
+                        fullFileContent={fullFileContents.get(file.new_path)}
+                        isLoadingFullFile={loadingFiles.has(file.new_path)}
+                        onRequestFullFile={loadFullFileContent}
+                        onAddFileComment={handleAddFileComment}
+                        fileCommentFormPath={fileCommentFormPath}
+                        onCancelFileComment={handleCancelFileComment}
+                        onSubmitFileComment={handleSubmitFileComment}
+                        onEditComment={handleEditComment}
+                        onEditReply={handleEditReply}
+                        onDeleteReply={handleDeleteReply}
+                        codeSearchQuery={codeSearchQuery}
+                        codeSearchCaseSensitive={codeSearchCaseSensitive}
+                        scrollToLine={scrollToLine?.file === file.new_path ? { line: scrollToLine.line, seq: scrollToLine.seq } : undefined}
+                        mentionItems={mentionItems}
+                      />
+                    );
+                  });
+                })()}
+              </div>
+
+              {/* Resizer between diff content and conversation sidebar (desktop only) */}
+              {!isMobile && convSidebarVisible && (
+                <div
+                  className="diff-resizer"
+                  onPointerDown={startConvSidebarResize}
+                  onDoubleClick={() => setConvSidebarWidth(DEFAULT_CONV_SIDEBAR_WIDTH)}
+                  role="separator"
+                  aria-orientation="vertical"
+                  aria-label="Resize conversation sidebar"
+                  title="Drag to resize · Double-click to reset"
+                />
+              )}
 
-            {/* Resizer between diff content and conversation sidebar (desktop only) */}
-            {!isMobile && convSidebarVisible && (
-              <div
-                className="diff-resizer"
-                onPointerDown={startConvSidebarResize}
-                onDoubleClick={() => setConvSidebarWidth(DEFAULT_CONV_SIDEBAR_WIDTH)}
-                role="separator"
-                aria-orientation="vertical"
-                aria-label="Resize conversation sidebar"
-                title="Drag to resize · Double-click to reset"
+              {/* Conversation sidebar */}
+              <ConversationSidebar
+                comments={viewMode === 'diff'
+                  ? comments.filter(c => !c.file_path || displayFiles.some(f => f.new_path === c.file_path))
+                  : comments
+                }
+                visible={convSidebarVisible}
+                onAddProjectComment={handleAddProjectComment}
+                onNavigateToComment={handleNavigateToComment}
+                onResolveComment={handleResolveComment}
+                onReopenComment={handleReopenComment}
+                onReplyComment={handleReplyComment}
+                onDeleteComment={handleDeleteComment}
+                onEditComment={handleEditComment}
+                onEditReply={handleEditReply}
+                onDeleteReply={handleDeleteReply}
+                onBulkDelete={handleBulkDelete}
+                mentionItems={mentionItems}
               />
-            )}
+            </>
+          )}
+        </div>
 
-            {/* Conversation sidebar */}
-            <ConversationSidebar
-              comments={viewMode === 'diff'
-                ? comments.filter(c => !c.file_path || displayFiles.some(f => f.new_path === c.file_path))
-                : comments
-              }
-              visible={convSidebarVisible}
-              onAddProjectComment={handleAddProjectComment}
-              onNavigateToComment={handleNavigateToComment}
-              onResolveComment={handleResolveComment}
-              onReopenComment={handleReopenComment}
-              onReplyComment={handleReplyComment}
-              onDeleteComment={handleDeleteComment}
-              onEditComment={handleEditComment}
-              onEditReply={handleEditReply}
-              onDeleteReply={handleDeleteReply}
-              onBulkDelete={handleBulkDelete}
-              mentionItems={mentionItems}
-            />
-          </>
-        )}
+        {/* Code Search Bar (Ctrl+F) */}
+        <CodeSearchBar
+          visible={codeSearchVisible}
+          focusTrigger={codeSearchFocusTrigger}
+          query={codeSearchQuery}
+          caseSensitive={codeSearchCaseSensitive}
+          currentIndex={codeSearchQuery ? codeSearchCurrentIndex : 0}
+          totalMatches={codeSearchQuery ? codeSearchTotalMatches : 0}
+          onQueryChange={handleSearchQueryChange}
+          onCaseSensitiveToggle={handleSearchCaseSensitiveToggle}
+          onPrevious={handleSearchPrevious}
+          onNext={handleSearchNext}
+          onClose={() => {
+            setCodeSearchVisible(false);
+            setCodeSearchQuery('');
+            setCodeSearchCurrentIndex(0);
+          }}
+        />
       </div>
-
-      {/* Code Search Bar (Ctrl+F) */}
-      <CodeSearchBar
-        visible={codeSearchVisible}
-        focusTrigger={codeSearchFocusTrigger}
-        query={codeSearchQuery}
-        caseSensitive={codeSearchCaseSensitive}
-        currentIndex={codeSearchQuery ? codeSearchCurrentIndex : 0}
-        totalMatches={codeSearchQuery ? codeSearchTotalMatches : 0}
-        onQueryChange={handleSearchQueryChange}
-        onCaseSensitiveToggle={handleSearchCaseSensitiveToggle}
-        onPrevious={handleSearchPrevious}
-        onNext={handleSearchNext}
-        onClose={() => {
-          setCodeSearchVisible(false);
-          setCodeSearchQuery('');
-          setCodeSearchCurrentIndex(0);
-        }}
-      />
-    </div>
+    </ReviewReactionContext.Provider>
   );
 }
 
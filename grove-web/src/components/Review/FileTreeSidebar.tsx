@@ -30,6 +30,9 @@ interface FileTreeSidebarProps {
   onOpenInApp?: (path: string) => void;
   autoViewedRules?: string[];
   onUpdateAutoViewedRules?: (rules: string[]) => void;
+  /** Project globs collapsed by default in review (stored server-side) */
+  collapsedPatterns?: string[];
+  onUpdateCollapsedPatterns?: (patterns: string[]) => void;
   /** "Hide viewed files" toggle — controlled by the parent (DiffReviewPage) so
    *  its jump-to-first-unviewed logic stays in sync with this filter. */
   hideViewed?: boolean;
@@ -70,6 +73,8 @@ export function FileTreeSidebar({
   onOpenInApp,
   autoViewedRules,
   onUpdateAutoViewedRules,
+  collapsedPatterns,
+  onUpdateCollapsedPatterns,
   hideViewed = false,
   onToggleHideViewed,
 }: FileTreeSidebarProps) {
@@ -228,6 +233,8 @@ export function FileTreeSidebar({
               localStorage.setItem(`grove:project:${projectId}:autoViewedRules`, JSON.stringify(nextRules));
             }
           }}
+          collapsedPatterns={collapsedPatterns}
+          onChangeCollapsedPatterns={onUpdateCollapsedPatterns}
           onClose={() => setShowRulesModal(false)}
         />,
         document.body
@@ -650,13 +657,26 @@ function buildTree(files: DiffFile[]): (DirNode | FileNode)[] {
 function AutoViewedRulesModal({
   rules,
   onChange,
+  collapsedPatterns,
+  onChangeCollapsedPatterns,
   onClose,
 }: {
   rules: string[];
   onChange: (rules: string[]) => void;
+  collapsedPatterns?: string[];
+  onChangeCollapsedPatterns?: (patterns: string[]) => void;
   onClose: () => void;
 }) {
   const [newRule, setNewRule] = useState('');
+  const [newCollapsed, setNewCollapsed] = useState('');
+
+  const handleAddCollapsed = () => {
+    const trimmed = newCollapsed.trim();
+    const current = collapsedPatterns || [];
+    if (!trimmed || current.includes(trimmed) || !onChangeCollapsedPatterns) return;
+    onChangeCollapsedPatterns([...current, trimmed]);
+    setNewCollapsed('');
+  };
 
   const handleAddRule = (rule: string) => {
     const trimmed = rule.trim();
@@ -873,6 +893,85 @@ function AutoViewedRulesModal({
               Add
             </button>
           </div>
+
+          {/* Collapsed by default (generated files) */}
+          {onChangeCollapsedPatterns && (
+            <div style={{ display: 'flex', flexDirection: 'column', gap: 8 }}>
+              <span style={{ fontSize: 11, fontWeight: 600, color: 'var(--color-text-muted)', textTransform: 'uppercase', letterSpacing: '0.05em' }}>
+                Collapsed by Default
+              </span>
+              <span style={{ fontSize: 11.5, color: 'var(--color-text-secondary)', lineHeight: '1.4' }}>
+                Files marked <code>linguist-generated</code> / <code>linguist-vendored</code> in <code>.gitattributes</code> are collapsed automatically. Add globs for other generated files; they still count toward totals.
+              </span>
+              <div style={{ display: 'flex', flexWrap: 'wrap', gap: 6 }}>
+                {(collapsedPatterns || []).map((pattern) => (
+                  <div
+                    key={pattern}
+                    style={{
+                      display: 'flex',
+                      alignItems: 'center',
+                      gap: 4,
+                      background: 'var(--color-bg-secondary)',
+                      color: 'var(--color-text-muted)',
+                      border: '1px solid var(--color-border)',
+                      borderRadius: 6,
+                      padding: '2px 8px',
+                      fontSize: 12,
+                      fontFamily: 'monospace',
+                    }}
+                  >
+                    <span>{pattern}</span>
+                    <button
+                      onClick={() => onChangeCollapsedPatterns((collapsedPatterns || []).filter((p) => p !== pattern))}
+                      style={{ background: 'transparent', border: 'none', cursor: 'pointer', color: 'inherit', padding: 0, display: 'flex', alignItems: 'center' }}
+                    >
+                      <X style={{ width: 12, height: 12 }} />
+                    </button>
+                  </div>
+                ))}
+              </div>
+              <div style={{ display: 'flex', gap: 8 }}>
+                <input
+                  type="text"
+                  placeholder="e.g. **/*.pb.go or dist/**"
+                  value={newCollapsed}
+                  onChange={(e) => setNewCollapsed(e.target.value)}
+                  onKeyDown={(e) => {
+                    if (e.key === 'Enter') handleAddCollapsed();
+                  }}
+                  style={{
+                    flex: 1,
+                    background: 'var(--color-bg)',
+                    border: '1px solid var(--color-border)',
+                    borderRadius: 6,
+                    padding: '6px 12px',
+                    fontSize: 13,
+                    color: 'var(--color-text)',
+                    outline: 'none',
+                  }}
+                />
+                <button
+                  onClick={handleAddCollapsed}
+                  style={{
+                    background: 'var(--color-highlight)',
+                    color: '#fff',
+                    border: 'none',
+                    borderRadius: 6,
+                    padding: '6px 14px',
+                    fontSize: 13,
+                    fontWeight: 500,
+                    cursor: 'pointer',
+                    display: 'flex',
+                    alignItems: 'center',
+                    gap: 4,
+                  }}
+                >
+                  <Plus style={{ width: 14, height: 14 }} />
+                  Add
+                </button>
+              </div>
+            </div>
+          )}
         </div>
 
         {/* Footer */}
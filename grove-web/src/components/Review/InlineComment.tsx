@@ -1,7 +1,8 @@
 import { useState, useRef } from 'react';
 import { X, Send, MessageSquare, Trash2, Reply, CheckCircle, RotateCcw, Minus, Pencil, Plus } from 'lucide-react';
-import type { ReviewCommentEntry } from '../../api/tasks';
+import type { ReviewCommentEntry, ReviewReaction } from '../../api/tasks';
 import type { CommentAnchor } from './DiffReviewPage';
+import { REACTION_EMOJIS, useReviewReactions } from './reviewReactions';
 import { AgentAvatar } from './AgentAvatar';
 import { AgentDisplay } from './agentDisplay';
 import { MarkdownRenderer, FileMentionDropdown } from '../ui';
@@ -23,6 +24,47 @@ function formatTime(ts: string): string {
   }
 }
 
+// ============================================================================
+// Reaction Bar — 👍 👀 🚀 toggles under a comment or reply
+// ============================================================================
+
+function ReactionBar({ commentId, replyId, reactions }: { commentId: number; replyId?: number; reactions?: ReviewReaction[] }) {
+  const ctx = useReviewReactions();
+  if (!ctx) return null;
+
+  return (
+    <div style={{ display: 'flex', gap: 4, padding: '0 12px 8px 12px' }}>
+      {REACTION_EMOJIS.map((emoji) => {
+        const authors = reactions?.find((r) => r.emoji === emoji)?.authors ?? [];
+        const mine = authors.includes(ctx.currentUser);
+        return (
+          <button
+            key={emoji}
+            onClick={() => ctx.onReact(commentId, emoji, replyId)}
+            title={authors.length > 0 ? authors.join(', ') : 'React'}
+            style={{
+              display: 'flex',
+              alignItems: 'center',
+              gap: 3,
+              padding: '1px 6px',
+              fontSize: 11,
+              borderRadius: 10,
+              cursor: 'pointer',
+              color: 'var(--color-text-muted)',
+              border: `1px solid ${mine ? 'var(--color-highlight)' : 'var(--color-border)'}`,
+              background: mine ? 'color-mix(in srgb, var(--color-highlight) 15%, var(--color-bg))' : 'transparent',
+              opacity: authors.length > 0 ? 1 : 0.5,
+            }}
+          >
+            <span>{emoji}</span>
+            {authors.length > 0 && <span>{authors.length}</span>}
+          </button>
+        );
+      })}
+    </div>
+  );
+}
+
 // ============================================================================
 // Comment Card — GitHub/GitLab style with avatar, author, timestamp, replies
 // ============================================================================
@@ -55,15 +97,17 @@ export function CommentCard({ comment, onDelete, onReply, onResolve, onReopen, o
   const editCommentMention = useFileMention({ mentionItems: mentionItems ?? null, textareaRef: editCommentTextareaRef });
   const editReplyMention = useFileMention({ mentionItems: mentionItems ?? null, textareaRef: editReplyTextareaRef });
 
-  const statusColor =
-    comment.status === 'resolved'
+  const statusColor = comment.draft
+    ? 'var(--color-highlight)'
+    : comment.status === 'resolved'
       ? 'var(--color-success)'
       : comment.status === 'outdated'
         ? 'var(--color-text-muted)'
         : 'var(--color-warning)';
 
-  const statusLabel =
-    comment.status === 'resolved'
+  const statusLabel = comment.draft
+    ? 'Pending'
+    : comment.status === 'resolved'
       ? 'Resolved'
       : comment.status === 'outdated'
         ? 'Outdated'
@@ -127,7 +171,7 @@ export function CommentCard({ comment, onDelete, onReply, onResolve, onReopen, o
               <CheckCircle style={{ width: 13, height: 13 }} />
             </button>
           )}
-          {activeChatId && (
+          {activeChatId && !comment.draft && (
             <button
               className="diff-comment-action-btn"
               onClick={() => {
@@ -255,6 +299,7 @@ export function CommentCard({ comment, onDelete, onReply, onResolve, onReopen, o
               <MarkdownRenderer content={comment.content} />
             </div>
           )}
+          {!comment.draft && !editingComment && <ReactionBar commentId={comment.id} reactions={comment.reactions} />}
 
           {/* Replies */}
           {comment.replies.map((reply) => (
@@ -346,6 +391,7 @@ export function CommentCard({ comment, onDelete, onReply, onResolve, onReopen, o
                   <MarkdownRenderer content={reply.content} />
                 </div>
               )}
+              {editingReplyId !== reply.id && <ReactionBar commentId={comment.id} replyId={reply.id} reactions={reply.reactions} />}
             </div>
           ))}
         </>
@@ -0,0 +1,136 @@
+import { useState, useRef, useEffect } from 'react';
+import { Check, ClipboardCheck } from 'lucide-react';
+import type { ReviewVerdict } from '../../api/tasks';
+
+interface ReviewSubmitMenuProps {
+  /** Whether new comments are added to the pending review instead of posting immediately */
+  draftMode: boolean;
+  onToggleDraftMode: () => void;
+  pendingCount: number;
+  onSubmit: (verdict: ReviewVerdict, summary: string) => Promise<boolean>;
+  onDiscard: () => void;
+}
+
+const VERDICTS: { key: ReviewVerdict; label: string }[] = [
+  { key: 'comment', label: 'Comment' },
+  { key: 'approve', label: 'Approve' },
+  { key: 'request_changes', label: 'Request changes' },
+];
+
+/** Toolbar dropdown for batching review comments and submitting them with a verdict */
+export function ReviewSubmitMenu({ draftMode, onToggleDraftMode, pendingCount, onSubmit, onDiscard }: ReviewSubmitMenuProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const [verdict, setVerdict] = useState<ReviewVerdict>('comment');
+  const [summary, setSummary] = useState('');
+  const [submitting, setSubmitting] = useState(false);
+  const containerRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    const onMouseDown = (e: MouseEvent) => {
+      if (containerRef.current && !containerRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') setIsOpen(false);
+    };
+    document.addEventListener('mousedown', onMouseDown);
+    document.addEventListener('keydown', onKeyDown);
+    return () => {
+      document.removeEventListener('mousedown', onMouseDown);
+      document.removeEventListener('keydown', onKeyDown);
+    };
+  }, [isOpen]);
+
+  const canSubmit = !submitting && (pendingCount > 0 || summary.trim() !== '' || verdict !== 'comment');
+
+  const handleSubmit = async () => {
+    setSubmitting(true);
+    const ok = await onSubmit(verdict, summary.trim());
+    setSubmitting(false);
+    if (ok) {
+      setSummary('');
+      setVerdict('comment');
+      setIsOpen(false);
+    }
+  };
+
+  return (
+    <div className="diff-version-selector" ref={containerRef}>
+      <button
+        className={`diff-version-trigger ${isOpen ? 'open' : ''}`}
+        onClick={() => setIsOpen((v) => !v)}
+        title={draftMode ? 'Review in progress — comments are pending until submitted' : 'Start a review'}
+        style={draftMode ? { color: 'var(--color-highlight)' } : undefined}
+      >
+        <ClipboardCheck style={{ width: 12, height: 12 }} />
+        <span className="toolbar-label">Review</span>
+        {pendingCount > 0 && <span>{pendingCount}</span>}
+      </button>
+
+      {isOpen && (
+        <div className="diff-version-dropdown" style={{ width: 260, padding: 6 }}>
+          <button
+            className={`diff-version-option ${draftMode ? 'selected' : ''}`}
+            onClick={onToggleDraftMode}
+            style={{ display: 'flex', alignItems: 'center', gap: 6 }}
+          >
+            <Check style={{ width: 12, height: 12, visibility: draftMode ? 'visible' : 'hidden' }} />
+            <span style={{ flex: 1 }}>Add comments as drafts</span>
+          </button>
+          <textarea
+            value={summary}
+            onChange={(e) => setSummary(e.target.value)}
+            placeholder="Overall summary (optional)"
+            rows={3}
+            style={{
+              width: '100%',
+              marginTop: 6,
+              padding: 6,
+              fontSize: 12,
+              resize: 'vertical',
+              color: 'var(--color-text)',
+              background: 'var(--color-bg)',
+              border: '1px solid var(--color-border)',
+              borderRadius: 4,
+            }}
+          />
+          {VERDICTS.map((v) => (
+            <button
+              key={v.key}
+              className={`diff-version-option ${verdict === v.key ? 'selected' : ''}`}
+              onClick={() => setVerdict(v.key)}
+              style={{ display: 'flex', alignItems: 'center', gap: 6 }}
+            >
+              <Check style={{ width: 12, height: 12, visibility: verdict === v.key ? 'visible' : 'hidden' }} />
+              <span style={{ flex: 1 }}>{v.label}</span>
+            </button>
+          ))}
+          <div style={{ display: 'flex', gap: 6, marginTop: 6 }}>
+            <button
+              className="diff-version-trigger"
+              style={{ flex: 1, justifyContent: 'center', opacity: canSubmit ? 1 : 0.5 }}
+              disabled={!canSubmit}
+              onClick={handleSubmit}
+            >
+              Submit review{pendingCount > 0 ? ` (${pendingCount})` : ''}
+            </button>
+            {pendingCount > 0 && (
+              <button
+                className="diff-version-trigger"
+                onClick={() => {
+                  onDiscard();
+                  setIsOpen(false);
+                }}
+                title="Delete all pending comments"
+              >
+                Discard
+              </button>
+            )}
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,17 @@
+import { createContext, useContext } from 'react';
+
+/** Reactions offered on review comments and replies (must match the server's list) */
+export const REACTION_EMOJIS = ['👍', '👀', '🚀'] as const;
+
+interface ReviewReactionContextValue {
+  /** Toggle the current user's reaction; `replyId` targets a reply instead of the comment */
+  onReact: (commentId: number, emoji: string, replyId?: number) => void;
+  currentUser: string;
+}
+
+/** Provided by DiffReviewPage so comment cards can react without prop threading */
+export const ReviewReactionContext = createContext<ReviewReactionContextValue | null>(null);
+
+export function useReviewReactions() {
+  return useContext(ReviewReactionContext);
+}
@@ -1,28 +1,41 @@
 import { useState, useEffect, useRef, useCallback } from "react";
-import { X, GitBranch, Plus, FileText, ChevronDown, Loader2 } from "lucide-react";
+import { X, GitBranch, Plus, FileText, ChevronDown, Loader2, FolderTree, LayoutTemplate } from "lucide-react";
 import { Button, Input } from "../ui";
 import { DialogShell } from "../ui/DialogShell";
 import { useProject } from "../../context";
 import { previewBranchName } from "../../utils/branch";
-import { getBranches } from "../../api";
+import { getBranches, getTaskTemplates, setDefaultTarget } from "../../api";
+import type { BranchInfo, TaskTemplate } from "../../api";
 import { useCommand, useContextKey, useKeyboardScope } from "../../keyboard";
 
 interface NewTaskDialogProps {
   isOpen: boolean;
   onClose: () => void;
-  onCreate: (name: string, targetBranch: string, notes: string) => void | Promise<void>;
+  /** `sparsePaths` empty = full checkout; `template` = task template name */
+  onCreate: (
+    name: string,
+    targetBranch: string,
+    notes: string,
+    sparsePaths: string[],
+    template?: string,
+  ) => void | Promise<void>;
   isLoading?: boolean;
   externalError?: string | null;
 }
 
 export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalError }: NewTaskDialogProps) {
-  const { selectedProject } = useProject();
+  const { selectedProject, refreshSelectedProject } = useProject();
   const isStudio = selectedProject?.projectType === "studio";
+  const initialTarget = selectedProject?.defaultTarget || selectedProject?.currentBranch || "main";
   const [taskName, setTaskName] = useState("");
-  const [targetBranch, setTargetBranch] = useState(selectedProject?.currentBranch || "main");
+  const [targetBranch, setTargetBranch] = useState(initialTarget);
   const [notes, setNotes] = useState("");
+  const [sparsePaths, setSparsePaths] = useState("");
+  const [templates, setTemplates] = useState<TaskTemplate[]>([]);
+  const [templateName, setTemplateName] = useState("");
   const [error, setError] = useState("");
   const [branches, setBranches] = useState<string[]>([]);
+  const [branchInfo, setBranchInfo] = useState<Record<string, BranchInfo>>({});
   const [showBranchDropdown, setShowBranchDropdown] = useState(false);
   const [isLoadingBranches, setIsLoadingBranches] = useState(false);
   const dropdownRef = useRef<HTMLDivElement>(null);
@@ -102,14 +115,16 @@ export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalEr
     if (!isOpen || !selectedProject || isStudio) return;
     /* eslint-disable react-hooks/set-state-in-effect --
      * setting branch and async-loading branch list on open. */
-    setTargetBranch(selectedProject.currentBranch || "main");
+    setTargetBranch(selectedProject.defaultTarget || selectedProject.currentBranch || "main");
     setIsLoadingBranches(true);
     getBranches(selectedProject.id, "local")
       .then((res) => {
         setBranches(res.branches.map((b) => b.name));
+        setBranchInfo(Object.fromEntries(res.branches.map((b) => [b.name, b])));
       })
       .catch(() => {
         setBranches([]);
+        setBranchInfo({});
       })
       .finally(() => {
         setIsLoadingBranches(false);
@@ -117,6 +132,26 @@ export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalEr
     /* eslint-enable react-hooks/set-state-in-effect */
   }, [isOpen, selectedProject, isStudio]);
 
+  // Task templates of the project (optional preset for the new task)
+  useEffect(() => {
+    if (!isOpen || !selectedProject) return;
+    let cancelled = false;
+    getTaskTemplates(selectedProject.id)
+      .then((list) => { if (!cancelled) setTemplates(list); })
+      .catch(() => { if (!cancelled) setTemplates([]); });
+    return () => { cancelled = true; };
+  }, [isOpen, selectedProject]);
+
+  const selectedTemplate = templates.find((t) => t.name === templateName);
+
+  const selectTemplate = (name: string) => {
+    setTemplateName(name);
+    const template = templates.find((t) => t.name === name);
+    setTargetBranch(
+      template?.target || selectedProject?.defaultTarget || selectedProject?.currentBranch || "main",
+    );
+  };
+
   // Click outside to close dropdown
   const handleClickOutside = useCallback((e: MouseEvent) => {
     if (dropdownRef.current && !dropdownRef.current.contains(e.target as Node)) {
@@ -141,13 +176,36 @@ export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalEr
     }
 
     setError("");
-    await onCreate(taskName.trim(), isStudio ? "" : targetBranch, notes.trim());
+    const paths = isStudio
+      ? []
+      : sparsePaths.split(/[\n,]/).map((p) => p.trim()).filter(Boolean);
+    await onCreate(
+      taskName.trim(),
+      isStudio ? "" : targetBranch,
+      notes.trim(),
+      paths,
+      templateName || undefined,
+    );
+  };
+
+  const isDefaultTarget = targetBranch === selectedProject?.defaultTarget;
+
+  const toggleDefaultTarget = async () => {
+    if (!selectedProject) return;
+    try {
+      await setDefaultTarget(selectedProject.id, isDefaultTarget ? null : targetBranch);
+      await refreshSelectedProject();
+    } catch {
+      setError("Failed to update default target branch");
+    }
   };
 
   const handleClose = () => {
     setTaskName("");
-    setTargetBranch(selectedProject?.currentBranch || "main");
+    setTargetBranch(initialTarget);
     setNotes("");
+    setSparsePaths("");
+    setTemplateName("");
     setError("");
     setShowBranchDropdown(false);
     setIsDragging(false);
@@ -222,6 +280,41 @@ export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalEr
                   className="!bg-[var(--color-bg)]"
                 />
 
+                {/* Template */}
+                {templates.length > 0 && (
+                  <div>
+                    <label className="block text-sm font-medium text-[var(--color-text-muted)] mb-2">
+                      <div className="flex items-center gap-1.5">
+                        <LayoutTemplate className="w-4 h-4" />
+                        <span>Template</span>
+                        <span className="text-xs font-normal">(optional)</span>
+                      </div>
+                    </label>
+                    <select
+                      value={templateName}
+                      onChange={(e) => selectTemplate(e.target.value)}
+                      className="w-full px-3 py-2 bg-[var(--color-bg)] border border-[var(--color-border)] rounded-lg text-sm text-[var(--color-text)]
+                        focus:outline-none focus:border-[var(--color-highlight)]"
+                    >
+                      <option value="">None</option>
+                      {templates.map((t) => (
+                        <option key={t.name} value={t.name}>{t.name}</option>
+                      ))}
+                    </select>
+                    {selectedTemplate && (
+                      <p className="mt-1.5 text-xs text-[var(--color-text-muted)]">
+                        {[
+                          selectedTemplate.agent ? `Agent: ${selectedTemplate.agent}` : null,
+                          Object.keys(selectedTemplate.env ?? {}).length > 0
+                            ? `Env: ${Object.keys(selectedTemplate.env ?? {}).join(", ")}`
+                            : null,
+                          selectedTemplate.notes ? "Empty notes use the template's skeleton" : null,
+                        ].filter(Boolean).join(" · ") || "Uses the template's target branch and autolink patterns"}
+                      </p>
+                    )}
+                  </div>
+                )}
+
                 {/* Notes */}
                 <div>
                   <label className="block text-sm font-medium text-[var(--color-text-muted)] mb-2">
@@ -251,7 +344,11 @@ export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalEr
                     <textarea
                       value={notes}
                       onChange={(e) => setNotes(e.target.value)}
-                      placeholder="Describe the task, requirements, or any relevant context...  (or drop a .md / .txt file)"
+                      placeholder={
+                        selectedTemplate?.notes
+                          ? selectedTemplate.notes
+                          : "Describe the task, requirements, or any relevant context...  (or drop a .md / .txt file)"
+                      }
                       rows={4}
                       className={`w-full px-3 py-2 bg-[var(--color-bg)] border rounded-lg
                         text-sm text-[var(--color-text)] placeholder:text-[var(--color-text-muted)] resize-none
@@ -334,7 +431,10 @@ export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalEr
                                 >
                                   <GitBranch className="w-3.5 h-3.5 flex-shrink-0" />
                                   <span className="truncate">{branch}</span>
-                                  {branch === selectedProject?.currentBranch && (
+                                  <BranchMeta info={branchInfo[branch]} />
+                                  {branch === selectedProject?.defaultTarget ? (
+                                    <span className="ml-auto text-xs text-[var(--color-text-muted)] flex-shrink-0">default</span>
+                                  ) : branch === selectedProject?.currentBranch && (
                                     <span className="ml-auto text-xs text-[var(--color-text-muted)] flex-shrink-0">current</span>
                                   )}
                                 </button>
@@ -346,9 +446,18 @@ export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalEr
                             )}
                           </div>
                         )}
-                        <p className="text-xs text-[var(--color-text-muted)] mt-1.5">
-                          New branch will be created from this branch
-                        </p>
+                        <div className="flex items-center justify-between gap-2 mt-1.5">
+                          <p className="text-xs text-[var(--color-text-muted)]">
+                            New branch will be created from this branch
+                          </p>
+                          <button
+                            type="button"
+                            onClick={toggleDefaultTarget}
+                            className="text-xs text-[var(--color-text-muted)] hover:text-[var(--color-highlight)] transition-colors flex-shrink-0"
+                          >
+                            {isDefaultTarget ? "Clear project default" : "Set as project default"}
+                          </button>
+                        </div>
                       </>
                     ) : (
                       <div className="flex items-center gap-2 px-3 py-2 bg-red-500/5 border border-red-500/30 rounded-lg">
@@ -359,6 +468,28 @@ export function NewTaskDialog({ isOpen, onClose, onCreate, isLoading, externalEr
                   </div>
                 )}
 
+                {/* Sparse checkout paths — large monorepos */}
+                {!isStudio && hasValidBranch && (
+                  <div>
+                    <label className="block text-sm font-medium text-[var(--color-text-muted)] mb-2">
+                      <div className="flex items-center gap-1.5">
+                        <FolderTree className="w-4 h-4" />
+                        <span>Check out only</span>
+                        <span className="text-xs font-normal">(optional)</span>
+                      </div>
+                    </label>
+                    <Input
+                      value={sparsePaths}
+                      onChange={(e) => setSparsePaths(e.target.value)}
+                      placeholder="services/api, libs/shared"
+                      className="!bg-[var(--color-bg)] font-mono"
+                    />
+                    <p className="mt-1.5 text-xs text-[var(--color-text-muted)]">
+                      Sparse checkout: only these paths (plus root files) land in the worktree. Leave empty for the whole repo.
+                    </p>
+                  </div>
+                )}
+
                 {/* Info */}
                 {isStudio ? (
                   <div className="p-3 rounded-lg bg-[var(--color-bg-secondary)] border border-[var(--color-border)]">
@@ -427,3 +558,27 @@ function NewTaskDialogBindings({
   );
   return null;
 }
+
+function relativeTime(unixSecs: number): string {
+  const diff = Date.now() / 1000 - unixSecs;
+  if (diff < 60) return "just now";
+  if (diff < 3600) return `${Math.floor(diff / 60)}m ago`;
+  if (diff < 86400) return `${Math.floor(diff / 3600)}h ago`;
+  return `${Math.floor(diff / 86400)}d ago`;
+}
+
+/** Ahead/behind the current branch, last commit age and worktree marker */
+function BranchMeta({ info }: { info?: BranchInfo }) {
+  if (!info || info.is_current) return null;
+  const title = [
+    info.ahead !== undefined ? `${info.ahead} ahead, ${info.behind ?? 0} behind current branch` : null,
+    info.worktree ? `Checked out in ${info.worktree}` : null,
+  ].filter(Boolean).join("\n");
+  return (
+    <span className="ml-auto flex items-center gap-2 text-xs text-[var(--color-text-muted)] flex-shrink-0 font-mono" title={title || undefined}>
+      {info.ahead !== undefined && <span>↑{info.ahead} ↓{info.behind ?? 0}</span>}
+      {info.last_commit_at !== undefined && <span>{relativeTime(info.last_commit_at)}</span>}
+      {info.worktree && <span>⎇</span>}
+    </span>
+  );
+}
@@ -0,0 +1,138 @@
+import { useEffect, useState } from "react";
+import { Check, Copy, Link2, Loader2, Lock, Trash2 } from "lucide-react";
+import { Button, Input } from "../../../ui";
+import { createShareLink, listShareLinks, revokeShareLink, type ShareLink } from "../../../../api";
+
+interface ShareLinksCardProps {
+  projectId: string;
+  taskId: string;
+}
+
+const EXPIRY_OPTIONS: { label: string; hours?: number }[] = [
+  { label: "Never expires" },
+  { label: "1 day", hours: 24 },
+  { label: "7 days", hours: 24 * 7 },
+  { label: "30 days", hours: 24 * 30 },
+];
+
+const shareUrl = (link: ShareLink) => `${window.location.origin}${link.url_path}`;
+
+/** Public read-only links to this task's diff, commits and review thread */
+export function ShareLinksCard({ projectId, taskId }: ShareLinksCardProps) {
+  const [links, setLinks] = useState<ShareLink[]>([]);
+  const [password, setPassword] = useState("");
+  const [expiry, setExpiry] = useState(0);
+  const [isCreating, setIsCreating] = useState(false);
+  const [copied, setCopied] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    listShareLinks(projectId, taskId)
+      .then((list) => { if (!cancelled) setLinks(list); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId, taskId]);
+
+  const copy = (link: ShareLink) => {
+    navigator.clipboard.writeText(shareUrl(link));
+    setCopied(link.token);
+    setTimeout(() => setCopied((c) => (c === link.token ? null : c)), 1500);
+  };
+
+  const handleCreate = async () => {
+    setIsCreating(true);
+    setError(null);
+    try {
+      const link = await createShareLink(projectId, taskId, password.trim(), EXPIRY_OPTIONS[expiry].hours);
+      setLinks((prev) => [link, ...prev]);
+      setPassword("");
+      copy(link);
+    } catch {
+      setError("Failed to create share link");
+    } finally {
+      setIsCreating(false);
+    }
+  };
+
+  const handleRevoke = async (link: ShareLink) => {
+    try {
+      await revokeShareLink(projectId, taskId, link.token);
+      setLinks((prev) => prev.filter((l) => l.token !== link.token));
+    } catch {
+      setError("Failed to revoke share link");
+    }
+  };
+
+  return (
+    <div className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-4 space-y-3">
+      <div>
+        <h3 className="text-sm font-medium text-[var(--color-text)]">Share Links</h3>
+        <p className="text-xs text-[var(--color-text-muted)]">
+          Anyone with a link can view this task's diff, commits and review — no Grove needed. The server must be reachable from their network.
+        </p>
+      </div>
+
+      <div className="flex gap-2 items-center">
+        <div className="flex-1">
+          <Input
+            type="password"
+            placeholder="Password (optional)"
+            value={password}
+            onChange={(e) => setPassword(e.target.value)}
+          />
+        </div>
+        <select
+          value={expiry}
+          onChange={(e) => setExpiry(Number(e.target.value))}
+          className="px-2 py-2 text-sm bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded-lg text-[var(--color-text)]"
+        >
+          {EXPIRY_OPTIONS.map((opt, i) => (
+            <option key={opt.label} value={i}>{opt.label}</option>
+          ))}
+        </select>
+        <Button size="sm" onClick={handleCreate} disabled={isCreating}>
+          {isCreating ? <Loader2 className="w-4 h-4 animate-spin" /> : <Link2 className="w-4 h-4" />}
+          Create
+        </Button>
+      </div>
+
+      {error && <div className="text-xs text-[var(--color-error)]">{error}</div>}
+
+      {links.length > 0 && (
+        <div className="space-y-1">
+          {links.map((link) => (
+            <div key={link.token} className={`flex items-center gap-2 text-xs ${link.expired ? "opacity-50" : ""}`}>
+              {link.has_password && <Lock className="w-3.5 h-3.5 text-[var(--color-text-muted)] flex-shrink-0" />}
+              <code className="truncate text-[var(--color-text)]" title={shareUrl(link)}>{link.url_path.slice(0, 22)}…</code>
+              <span className="text-[var(--color-text-muted)] whitespace-nowrap">
+                {link.expired
+                  ? "expired"
+                  : link.expires_at
+                    ? `expires ${new Date(link.expires_at).toLocaleDateString()}`
+                    : "no expiry"}
+              </span>
+              <div className="ml-auto flex items-center gap-1">
+                <button
+                  onClick={() => copy(link)}
+                  disabled={link.expired}
+                  className="p-1 rounded hover:bg-[var(--color-bg-tertiary)] text-[var(--color-text-muted)] hover:text-[var(--color-text)] transition-colors"
+                  title="Copy link"
+                >
+                  {copied === link.token ? <Check className="w-3.5 h-3.5" /> : <Copy className="w-3.5 h-3.5" />}
+                </button>
+                <button
+                  onClick={() => handleRevoke(link)}
+                  className="p-1 rounded hover:bg-[var(--color-bg-tertiary)] text-[var(--color-text-muted)] hover:text-[var(--color-error)] transition-colors"
+                  title="Revoke link"
+                >
+                  <Trash2 className="w-3.5 h-3.5" />
+                </button>
+              </div>
+            </div>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
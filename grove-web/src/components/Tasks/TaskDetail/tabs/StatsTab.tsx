@@ -1,9 +1,12 @@
 import { useState, useEffect } from "react";
 import { motion } from "framer-motion";
-import { Calendar, GitCommit, FileCode, Clock, Activity, Loader2, GitBranch, Info } from "lucide-react";
-import { getTaskStats, getDiff, getCommits, type TaskStatsResponse, type DiffResponse, type CommitsResponse } from "../../../../api";
+import { Calendar, GitCommit, FileCode, Clock, Activity, Loader2, GitBranch, Info, Coins } from "lucide-react";
+import { getTaskStats, getTaskUsage, getDiff, getCommits, getTargetHistory, type TaskStatsResponse, type TaskUsageResponse, type DiffResponse, type CommitsResponse, type TargetChange } from "../../../../api";
 import type { Task } from "../../../../data/types";
 import { compactPath } from "../../../../utils/pathUtils";
+import { ShareLinksCard } from "./ShareLinksCard";
+import { TimeLabel } from "../../../ui";
+import { formatCost, formatTokens } from "../../../Stats/formatters";
 
 interface StatsTabProps {
   projectId: string;
@@ -23,27 +26,11 @@ function formatDate(date: Date | undefined, timeAgo?: string): string {
   });
 }
 
-// Calculate active time from activity timeline
-function calculateActiveTime(hourlyActivity: Array<{ buckets: number[] }>): string {
-  if (!hourlyActivity || hourlyActivity.length === 0) {
-    return "—";
-  }
-
-  // Count all minutes with activity (buckets > 0)
-  let activeMinutes = 0;
-  for (const hourEntry of hourlyActivity) {
-    if (Array.isArray(hourEntry.buckets)) {
-      activeMinutes += hourEntry.buckets.filter(count => count > 0).length;
-    }
-  }
-
-  if (activeMinutes === 0) {
-    return "—";
-  }
-
-  const hours = Math.floor(activeMinutes / 60);
-  const minutes = activeMinutes % 60;
-
+// Format seconds as "3h 20m" / "45m"
+function formatDuration(secs: number): string {
+  const totalMinutes = Math.floor(secs / 60);
+  const hours = Math.floor(totalMinutes / 60);
+  const minutes = totalMinutes % 60;
   if (hours > 0) {
     return `${hours}h ${minutes}m`;
   }
@@ -117,6 +104,8 @@ export function StatsTab({ projectId, task }: StatsTabProps) {
   const [stats, setStats] = useState<TaskStatsResponse | null>(null);
   const [diffData, setDiffData] = useState<DiffResponse | null>(null);
   const [commitsData, setCommitsData] = useState<CommitsResponse | null>(null);
+  const [targetHistory, setTargetHistory] = useState<TargetChange[]>([]);
+  const [usage, setUsage] = useState<TaskUsageResponse | null>(null);
   const [isLoading, setIsLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
 
@@ -135,18 +124,22 @@ export function StatsTab({ projectId, task }: StatsTabProps) {
       getTaskStats(projectId, task.id),
       getDiff(projectId, task.id).catch(() => null),
       getCommits(projectId, task.id).catch(() => null),
+      getTargetHistory(projectId, task.id).catch(() => []),
+      getTaskUsage(projectId, task.id).catch(() => null),
     ])
-      .then(([statsRes, diffRes, commitsRes]) => {
+      .then(([statsRes, diffRes, commitsRes, historyRes, usageRes]) => {
         setStats(statsRes);
         setDiffData(diffRes);
         setCommitsData(commitsRes);
+        setTargetHistory(historyRes);
+        setUsage(usageRes);
       })
       .catch((err) => {
         console.error("Failed to load task stats:", err);
         setError("Failed to load stats");
       })
       .finally(() => setIsLoading(false));
-  }, [projectId, task.id]);
+  }, [projectId, task.id, task.target]);
 
   const maxEditCount = stats?.file_edits.length
     ? Math.max(...stats.file_edits.map((f) => f.edit_count))
@@ -187,6 +180,22 @@ export function StatsTab({ projectId, task }: StatsTabProps) {
             </div>
           </div>
 
+          {targetHistory.length > 0 && (
+            <div>
+              <div className="text-xs font-medium uppercase tracking-wide text-[var(--color-text-muted)] mb-1">Retarget History</div>
+              <div className="space-y-1">
+                {[...targetHistory].reverse().map((change, i) => (
+                  <div key={i} className="flex items-center gap-2 text-xs text-[var(--color-text-muted)]">
+                    <code className="truncate">{change.from_target}</code>
+                    <span>→</span>
+                    <code className="truncate text-[var(--color-text)]">{change.to_target}</code>
+                    <span className="ml-auto whitespace-nowrap">{formatRelativeTime(change.changed_at)}</span>
+                  </div>
+                ))}
+              </div>
+            </div>
+          )}
+
         </div>
       </motion.div>
 
@@ -231,10 +240,12 @@ export function StatsTab({ projectId, task }: StatsTabProps) {
         <div className="flex items-center gap-4">
           <div className="flex-1">
             <div className="text-2xl font-semibold text-[var(--color-text)]">
-              {stats?.hourly_activity ? calculateActiveTime(stats.hourly_activity) : isLoading ? "—" : "—"}
+              {stats?.time_spent?.total_secs ? formatDuration(stats.time_spent.total_secs) : "—"}
             </div>
             <div className="text-xs text-[var(--color-text-muted)] mt-1 select-none">
-              Active time
+              {stats?.time_spent?.total_secs
+                ? `Time spent · today ${formatDuration(stats.time_spent.today_secs)} · this week ${formatDuration(stats.time_spent.week_secs)}`
+                : "Time spent"}
             </div>
           </div>
           <div className="h-12 w-px bg-[var(--color-border)]" />
@@ -249,6 +260,59 @@ export function StatsTab({ projectId, task }: StatsTabProps) {
         </div>
       </motion.div>
 
+      {/* Agent Usage — tokens and cost reported by the chat agents */}
+      {usage && usage.totals.turns > 0 && (
+        <motion.div
+          initial={{ opacity: 0, y: 10 }}
+          animate={{ opacity: 1, y: 0 }}
+          transition={{ delay: 0.22 }}
+          className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-4"
+        >
+          <div className="flex items-center gap-2 mb-3 select-none">
+            <Coins className="w-4 h-4 text-[var(--color-text-muted)]" />
+            <h3 className="text-sm font-medium text-[var(--color-text)]">Agent Usage</h3>
+            <span className="text-xs text-[var(--color-text-muted)]">
+              ({usage.totals.turns} turn{usage.totals.turns !== 1 ? "s" : ""})
+            </span>
+          </div>
+
+          <div className="grid grid-cols-2 md:grid-cols-4 gap-3 mb-3">
+            {[
+              { label: "Input", value: formatTokens(usage.totals.input_tokens) },
+              { label: "Output", value: formatTokens(usage.totals.output_tokens) },
+              { label: "Cache read", value: formatTokens(usage.totals.cached_read_tokens) },
+              {
+                label: "Cost",
+                value: usage.totals.cost_amount != null ? formatCost(usage.totals.cost_amount) : "—",
+              },
+            ].map((item) => (
+              <div key={item.label}>
+                <div className="text-lg font-semibold text-[var(--color-text)] tabular-nums">{item.value}</div>
+                <div className="text-xs text-[var(--color-text-muted)] select-none">{item.label}</div>
+              </div>
+            ))}
+          </div>
+
+          <div className="space-y-1.5">
+            {usage.chats.map((chat) => (
+              <div key={chat.chat_id} className="flex items-center gap-2 text-xs">
+                <span className="truncate text-[var(--color-text)]" title={chat.title ?? chat.chat_id}>
+                  {chat.title ?? chat.chat_id}
+                </span>
+                <span className="text-[var(--color-text-muted)] whitespace-nowrap">
+                  {chat.agent}
+                  {chat.model ? ` · ${chat.model}` : ""}
+                </span>
+                <span className="ml-auto whitespace-nowrap tabular-nums text-[var(--color-text-muted)]">
+                  {formatTokens(chat.totals.total_tokens)} tokens
+                  {chat.totals.cost_amount != null && ` · ${formatCost(chat.totals.cost_amount)}`}
+                </span>
+              </div>
+            ))}
+          </div>
+        </motion.div>
+      )}
+
       {/* File Edits Heatmap (from API) */}
       {isLoading ? (
         <motion.div
@@ -490,7 +554,7 @@ export function StatsTab({ projectId, task }: StatsTabProps) {
                     <div className="flex items-center gap-2 text-xs text-[var(--color-text-muted)] mt-0.5">
                       <code className="font-mono">{commit.hash.slice(0, 7)}</code>
                       <span>•</span>
-                      <span>{commit.time_ago}</span>
+                      <TimeLabel time={commit.time} timeAgo={commit.time_ago} />
                     </div>
                   </div>
                 </div>
@@ -499,6 +563,8 @@ export function StatsTab({ projectId, task }: StatsTabProps) {
           </div>
         </motion.div>
       )}
+
+      {!task.isLocal && <ShareLinksCard projectId={projectId} taskId={task.id} />}
     </div>
   );
 }
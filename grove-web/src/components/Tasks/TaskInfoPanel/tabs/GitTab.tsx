@@ -1,9 +1,48 @@
 import { useState, useEffect, useCallback } from "react";
 import { motion } from "framer-motion";
-import { GitBranch, GitCommit, FileCode, Loader2 } from "lucide-react";
+import { GitBranch, GitCommit, FileCode, Loader2, History, ShieldCheck, ShieldAlert, Gauge, Shield } from "lucide-react";
 import type { Task } from "../../../../data/types";
 import { useProject } from "../../../../context/ProjectContext";
-import { getDiff, getCommits, type DiffResponse, type CommitsResponse } from "../../../../api";
+import {
+  getDiff,
+  getCommits,
+  getCheckpoints,
+  updateCheckpoints,
+  squashCheckpoints,
+  dropCheckpoints,
+  getTaskBench,
+  runTaskBench,
+  type BenchRun,
+  getTaskSecurityScan,
+  runTaskSecurityScan,
+  type ScanSeverity,
+  type SecurityScanStatus,
+  type DiffResponse,
+  type CommitsResponse,
+  type CheckpointsResponse,
+} from "../../../../api";
+import type { SignatureStatus } from "../../../../api/git";
+import { TimeLabel } from "../../../ui";
+
+/** Signature badge for signed commits; nothing for unsigned ones */
+function SignatureBadge({ status }: { status?: SignatureStatus }) {
+  if (!status || status === "unverified") return null;
+  if (status === "good") {
+    return (
+      <span className="flex items-center gap-0.5 text-[var(--color-success)]" title="Good signature">
+        <ShieldCheck className="w-3 h-3" />
+        Verified
+      </span>
+    );
+  }
+  const label = status === "unknown" ? "Unknown key" : status.charAt(0).toUpperCase() + status.slice(1);
+  return (
+    <span className="flex items-center gap-0.5 text-[var(--color-warning)]" title={`Signature: ${status}`}>
+      <ShieldAlert className="w-3 h-3" />
+      {label}
+    </span>
+  );
+}
 
 interface GitTabProps {
   projectId?: string;
@@ -15,6 +54,7 @@ export function GitTab({ projectId, task }: GitTabProps) {
   const resolvedProjectId = projectId || selectedProject?.id;
   const [diffData, setDiffData] = useState<DiffResponse | null>(null);
   const [commitsData, setCommitsData] = useState<CommitsResponse | null>(null);
+  const [checkpoints, setCheckpoints] = useState<CheckpointsResponse | null>(null);
   const [isLoading, setIsLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
 
@@ -24,18 +64,20 @@ export function GitTab({ projectId, task }: GitTabProps) {
     try {
       setIsLoading(true);
       setError(null);
-      const [diff, commits] = await Promise.all([
+      const [diff, commits, checkpointData] = await Promise.all([
         getDiff(resolvedProjectId, task.id),
         getCommits(resolvedProjectId, task.id),
+        task.isLocal ? Promise.resolve(null) : getCheckpoints(resolvedProjectId, task.id).catch(() => null),
       ]);
       setDiffData(diff);
       setCommitsData(commits);
+      setCheckpoints(checkpointData);
     } catch (err) {
       console.error("Failed to load git data:", err);
       setError("Failed to load git data. The task may have been deleted or archived.");
     }
     setIsLoading(false);
-  }, [resolvedProjectId, task.id]);
+  }, [resolvedProjectId, task.id, task.isLocal]);
 
   useEffect(() => {
     Promise.resolve().then(loadGitData);
@@ -108,6 +150,27 @@ export function GitTab({ projectId, task }: GitTabProps) {
         </div>
       </div>
 
+      {/* Auto-commit checkpoints */}
+      {resolvedProjectId && checkpoints && (
+        <CheckpointsCard
+          projectId={resolvedProjectId}
+          taskId={task.id}
+          data={checkpoints}
+          onChange={setCheckpoints}
+          onRewritten={loadGitData}
+        />
+      )}
+
+      {/* Benchmark comparison against the target */}
+      {resolvedProjectId && !task.isLocal && (
+        <BenchCard projectId={resolvedProjectId} taskId={task.id} target={task.target} />
+      )}
+
+      {/* Security audit findings */}
+      {resolvedProjectId && !task.isLocal && (
+        <SecurityScanCard projectId={resolvedProjectId} taskId={task.id} />
+      )}
+
       {/* Recent Commits */}
       <div className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-4">
         <h3 className="text-sm font-medium text-[var(--color-text)] mb-3 flex items-center gap-2 select-none">
@@ -137,7 +200,13 @@ export function GitTab({ projectId, task }: GitTabProps) {
                       {commit.message}
                     </p>
                     <div className="flex items-center gap-2 mt-0.5 text-xs text-[var(--color-text-muted)]">
-                      <span>{commit.time_ago}</span>
+                      <TimeLabel time={commit.time} timeAgo={commit.time_ago} />
+                      <SignatureBadge status={commit.signature} />
+                      {commit.links?.chat_id && (
+                        <span className="truncate" title={commit.links.chat_id}>
+                          Chat: {commit.links.chat_title ?? commit.links.chat_id}
+                        </span>
+                      )}
                     </div>
                   </div>
                 </div>
@@ -149,3 +218,358 @@ export function GitTab({ projectId, task }: GitTabProps) {
     </div>
   );
 }
+
+const CHECKPOINT_INTERVALS = [5, 10, 15, 30, 60];
+
+interface CheckpointsCardProps {
+  projectId: string;
+  taskId: string;
+  data: CheckpointsResponse;
+  onChange: (data: CheckpointsResponse) => void;
+  onRewritten: () => void;
+}
+
+function CheckpointsCard({ projectId, taskId, data, onChange, onRewritten }: CheckpointsCardProps) {
+  const [message, setMessage] = useState("");
+  const [busy, setBusy] = useState(false);
+  const [status, setStatus] = useState<string | null>(null);
+
+  const update = async (req: { enabled?: boolean; interval_mins?: number }) => {
+    try {
+      onChange(await updateCheckpoints(projectId, taskId, req));
+    } catch {
+      setStatus("Failed to update checkpoint settings");
+    }
+  };
+
+  const rewrite = async (action: "squash" | "drop") => {
+    setBusy(true);
+    try {
+      const res =
+        action === "squash"
+          ? await squashCheckpoints(projectId, taskId, message)
+          : await dropCheckpoints(projectId, taskId);
+      setStatus(res.message);
+      if (res.success) {
+        setMessage("");
+        onRewritten();
+      }
+    } catch {
+      setStatus(`Failed to ${action} checkpoints`);
+    }
+    setBusy(false);
+  };
+
+  const count = data.checkpoints.length;
+
+  return (
+    <div className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-4">
+      <div className="flex items-center justify-between mb-3">
+        <h3 className="text-sm font-medium text-[var(--color-text)] flex items-center gap-2 select-none">
+          <History className="w-4 h-4" />
+          Checkpoints
+        </h3>
+        <label className="flex items-center gap-2 text-xs text-[var(--color-text-muted)] select-none">
+          <input
+            type="checkbox"
+            checked={data.enabled}
+            onChange={(e) => update({ enabled: e.target.checked })}
+          />
+          Auto-commit
+        </label>
+      </div>
+      {data.enabled && (
+        <div className="flex items-center justify-between text-sm mb-3">
+          <span className="text-[var(--color-text-muted)] select-none">While active, every</span>
+          <select
+            value={data.interval_mins}
+            onChange={(e) => update({ interval_mins: Number(e.target.value) })}
+            className="text-xs bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded px-1.5 py-0.5 text-[var(--color-text)]"
+          >
+            {CHECKPOINT_INTERVALS.map((m) => (
+              <option key={m} value={m}>
+                {m} min
+              </option>
+            ))}
+          </select>
+        </div>
+      )}
+      <p className="text-xs text-[var(--color-text-muted)] select-none">
+        {count === 0
+          ? "No checkpoint commits at the branch tip"
+          : `${count} checkpoint commit${count === 1 ? "" : "s"} at the branch tip`}
+      </p>
+      {count > 0 && (
+        <div className="mt-3 space-y-2">
+          <input
+            value={message}
+            onChange={(e) => setMessage(e.target.value)}
+            placeholder="Commit message for squash"
+            className="w-full text-sm bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded px-2 py-1 text-[var(--color-text)]"
+          />
+          <div className="flex gap-2">
+            <button
+              type="button"
+              disabled={busy || !message.trim()}
+              onClick={() => rewrite("squash")}
+              className="flex-1 text-xs px-2 py-1 rounded bg-[var(--color-highlight)]/10 text-[var(--color-highlight)] hover:bg-[var(--color-highlight)]/20 disabled:opacity-50"
+            >
+              Squash
+            </button>
+            <button
+              type="button"
+              disabled={busy}
+              onClick={() => rewrite("drop")}
+              title="Undo the checkpoint commits; changes stay staged"
+              className="flex-1 text-xs px-2 py-1 rounded bg-[var(--color-bg-secondary)] text-[var(--color-text-muted)] hover:text-[var(--color-text)] disabled:opacity-50"
+            >
+              Drop
+            </button>
+          </div>
+        </div>
+      )}
+      {status && <p className="mt-2 text-xs text-[var(--color-text-muted)]">{status}</p>}
+    </div>
+  );
+}
+
+/** Poll interval while a benchmark comparison is running */
+const BENCH_POLL_MS = 3000;
+
+/** Format a duration in nanoseconds with a readable unit */
+function formatNs(ns: number | null): string {
+  if (ns === null) return "—";
+  if (ns >= 1e9) return `${(ns / 1e9).toFixed(2)} s`;
+  if (ns >= 1e6) return `${(ns / 1e6).toFixed(2)} ms`;
+  if (ns >= 1e3) return `${(ns / 1e3).toFixed(2)} µs`;
+  return `${ns.toFixed(0)} ns`;
+}
+
+const VERDICT_COLORS: Record<string, string> = {
+  regression: "text-[var(--color-error)]",
+  improvement: "text-[var(--color-success)]",
+};
+
+interface BenchCardProps {
+  projectId: string;
+  taskId: string;
+  target: string;
+}
+
+function BenchCard({ projectId, taskId, target }: BenchCardProps) {
+  const [run, setRun] = useState<BenchRun | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    getTaskBench(projectId, taskId).then(setRun).catch(() => setRun(null));
+  }, [projectId, taskId]);
+
+  const running = run?.status === "running";
+
+  useEffect(() => {
+    if (!running) return;
+    const timer = setInterval(() => {
+      getTaskBench(projectId, taskId).then(setRun).catch(() => {});
+    }, BENCH_POLL_MS);
+    return () => clearInterval(timer);
+  }, [projectId, taskId, running]);
+
+  const start = async () => {
+    setError(null);
+    try {
+      setRun(await runTaskBench(projectId, taskId));
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to start benchmarks");
+    }
+  };
+
+  const regressions = run?.results.filter((r) => r.verdict === "regression").length ?? 0;
+
+  return (
+    <div className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-4">
+      <div className="flex items-center justify-between mb-3">
+        <h3 className="text-sm font-medium text-[var(--color-text)] flex items-center gap-2 select-none">
+          <Gauge className="w-4 h-4" />
+          Benchmarks
+        </h3>
+        <button
+          type="button"
+          disabled={running}
+          onClick={start}
+          className="flex items-center gap-1 text-xs px-2 py-1 rounded bg-[var(--color-highlight)]/10 text-[var(--color-highlight)] hover:bg-[var(--color-highlight)]/20 disabled:opacity-50"
+        >
+          {running && <Loader2 className="w-3 h-3 animate-spin" />}
+          {running ? "Running..." : run ? "Re-run" : `Compare with ${target}`}
+        </button>
+      </div>
+      {!run && !error && (
+        <p className="text-xs text-[var(--color-text-muted)] select-none">
+          Runs the project's bench command on {target} and the task HEAD
+        </p>
+      )}
+      {run?.status === "failed" && (
+        <p className="text-xs text-[var(--color-error)] break-words">{run.error ?? "Benchmark run failed"}</p>
+      )}
+      {run?.status === "done" && (
+        <>
+          <p className="text-xs text-[var(--color-text-muted)] mb-2 select-none">
+            {run.base_ref} <code className="font-mono">{run.base_commit.slice(0, 7)}</code> vs HEAD{" "}
+            <code className="font-mono">{run.head_commit.slice(0, 7)}</code> · threshold {run.threshold_percent}%
+            {regressions > 0 && (
+              <span className="text-[var(--color-error)]">
+                {" "}· {regressions} regression{regressions === 1 ? "" : "s"}
+              </span>
+            )}
+          </p>
+          {run.results.length === 0 ? (
+            <p className="text-xs text-[var(--color-text-muted)] select-none">No benchmarks found in the output</p>
+          ) : (
+            <table className="w-full text-xs">
+              <thead>
+                <tr className="text-[var(--color-text-muted)] select-none">
+                  <th className="text-left font-normal pb-1">Benchmark</th>
+                  <th className="text-right font-normal pb-1">{run.base_ref}</th>
+                  <th className="text-right font-normal pb-1">HEAD</th>
+                  <th className="text-right font-normal pb-1">Change</th>
+                </tr>
+              </thead>
+              <tbody>
+                {run.results.map((r) => (
+                  <tr key={r.name} className={VERDICT_COLORS[r.verdict] ?? "text-[var(--color-text)]"}>
+                    <td className="py-0.5 pr-2 font-mono truncate max-w-0 w-1/2" title={r.name}>
+                      {r.name}
+                    </td>
+                    <td className="py-0.5 text-right font-mono">{formatNs(r.base_ns)}</td>
+                    <td className="py-0.5 text-right font-mono">{formatNs(r.head_ns)}</td>
+                    <td className="py-0.5 text-right font-mono">
+                      {r.change_percent === null
+                        ? r.verdict
+                        : `${r.change_percent > 0 ? "+" : ""}${r.change_percent.toFixed(1)}%`}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          )}
+        </>
+      )}
+      {error && <p className="mt-2 text-xs text-[var(--color-error)]">{error}</p>}
+    </div>
+  );
+}
+
+const SEVERITY_ORDER: ScanSeverity[] = ["info", "low", "moderate", "high", "critical"];
+
+const SEVERITY_COLORS: Record<ScanSeverity, string> = {
+  info: "text-[var(--color-text-muted)]",
+  low: "text-[var(--color-text-muted)]",
+  moderate: "text-[var(--color-warning)]",
+  high: "text-[var(--color-error)]",
+  critical: "text-[var(--color-error)]",
+};
+
+interface SecurityScanCardProps {
+  projectId: string;
+  taskId: string;
+}
+
+function SecurityScanCard({ projectId, taskId }: SecurityScanCardProps) {
+  const [status, setStatus] = useState<SecurityScanStatus | null>(null);
+  const [scanning, setScanning] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    getTaskSecurityScan(projectId, taskId).then(setStatus).catch(() => setStatus(null));
+  }, [projectId, taskId]);
+
+  // Nothing to show until the project configures a scanner
+  if (!status?.configured) return null;
+
+  const scan = async () => {
+    setScanning(true);
+    setError(null);
+    try {
+      setStatus(await runTaskSecurityScan(projectId, taskId));
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Security scan failed");
+    }
+    setScanning(false);
+  };
+
+  const report = status.report;
+  const blockRank = status.block_severity ? SEVERITY_ORDER.indexOf(status.block_severity) : -1;
+  const blocking = blockRank < 0
+    ? 0
+    : report?.findings.filter((f) => SEVERITY_ORDER.indexOf(f.severity) >= blockRank).length ?? 0;
+
+  return (
+    <div className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-4">
+      <div className="flex items-center justify-between mb-3">
+        <h3 className="text-sm font-medium text-[var(--color-text)] flex items-center gap-2 select-none">
+          <Shield className="w-4 h-4" />
+          Security
+        </h3>
+        <button
+          type="button"
+          disabled={scanning}
+          onClick={scan}
+          className="flex items-center gap-1 text-xs px-2 py-1 rounded bg-[var(--color-highlight)]/10 text-[var(--color-highlight)] hover:bg-[var(--color-highlight)]/20 disabled:opacity-50"
+        >
+          {scanning && <Loader2 className="w-3 h-3 animate-spin" />}
+          {scanning ? "Scanning..." : report ? "Re-scan" : "Scan"}
+        </button>
+      </div>
+      {!report ? (
+        <p className="text-xs text-[var(--color-text-muted)] select-none">
+          Not scanned yet; scanners also run before merge
+        </p>
+      ) : (
+        <>
+          <p className="text-xs text-[var(--color-text-muted)] mb-2 select-none">
+            {report.findings.length === 0
+              ? "No findings"
+              : `${report.findings.length} finding${report.findings.length === 1 ? "" : "s"}`}
+            {" "}at <code className="font-mono">{report.head_commit.slice(0, 7)}</code>
+            {blocking > 0 && (
+              <span className="text-[var(--color-error)]"> · {blocking} blocking merge</span>
+            )}
+          </p>
+          {report.findings.length > 0 && (
+            <div className="space-y-1 max-h-64 overflow-y-auto">
+              {report.findings.map((f) => (
+                <div key={`${f.scanner}-${f.id}-${f.package}`} className="text-xs flex items-start gap-2">
+                  <span className={`w-16 flex-shrink-0 font-medium uppercase ${SEVERITY_COLORS[f.severity]}`}>
+                    {f.severity}
+                  </span>
+                  <div className="min-w-0">
+                    <div className="text-[var(--color-text)]">
+                      <span className="font-mono">{f.package}</span>
+                      {f.version && <span className="text-[var(--color-text-muted)]"> {f.version}</span>}
+                      {" "}
+                      {f.url ? (
+                        <a href={f.url} target="_blank" rel="noreferrer" className="text-[var(--color-highlight)] hover:underline">
+                          {f.id}
+                        </a>
+                      ) : (
+                        <span className="text-[var(--color-text-muted)]">{f.id}</span>
+                      )}
+                    </div>
+                    <div className="text-[var(--color-text-muted)] truncate" title={f.title}>
+                      {f.title}
+                    </div>
+                  </div>
+                </div>
+              ))}
+            </div>
+          )}
+          {report.errors?.map((e) => (
+            <p key={e} className="mt-1 text-xs text-[var(--color-warning)] break-words">
+              {e}
+            </p>
+          ))}
+        </>
+      )}
+      {error && <p className="mt-2 text-xs text-[var(--color-error)]">{error}</p>}
+    </div>
+  );
+}
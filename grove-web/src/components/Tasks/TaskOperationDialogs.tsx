@@ -82,6 +82,11 @@ export function TaskOperationDialogs({
         targetBranch={taskTarget}
         isLoading={opsState.isMerging}
         error={opsState.mergeError}
+        risk={opsState.mergeRisk}
+        testChanges={opsState.mergeTestChanges}
+        bench={opsState.mergeBench}
+        securityWarning={opsState.mergeSecurityWarning}
+        fileGuard={opsState.mergeFileGuard}
         onMerge={opsHandlers.handleMergeSubmit}
         onCancel={opsHandlers.handleMergeCancel}
       />
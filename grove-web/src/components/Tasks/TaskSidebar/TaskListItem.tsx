@@ -1,6 +1,7 @@
-import { Archive, MoreVertical, Laptop, Zap, Code } from "lucide-react";
+import { Archive, MoreVertical, Laptop, Zap, Code, Pin } from "lucide-react";
 import { useIsMobile } from "../../../hooks";
 import type { Task } from "../../../data/types";
+import type { RiskAssessment } from "../../../api";
 
 interface TaskListItemProps {
   task: Task;
@@ -9,8 +10,22 @@ interface TaskListItemProps {
   onDoubleClick: () => void;
   onContextMenu?: (e: React.MouseEvent) => void;
   notification?: { level: string };
+  /** Heuristic risk of the task's changes (medium / high get a badge) */
+  risk?: RiskAssessment;
 }
 
+const RISK_COLOR: Record<RiskAssessment["level"], string> = {
+  low: "var(--color-text-muted)",
+  medium: "var(--color-warning)",
+  high: "var(--color-error)",
+};
+
+const AGENT_STATE_STYLE: Record<string, { label: string; color: string }> = {
+  busy: { label: "Busy", color: "var(--color-info)" },
+  waiting: { label: "Waiting", color: "var(--color-warning)" },
+  idle: { label: "Idle", color: "var(--color-text-muted)" },
+};
+
 function formatTimeAgo(date: Date): string {
   const seconds = Math.floor((Date.now() - date.getTime()) / 1000);
   if (seconds < 60) return "just now";
@@ -35,7 +50,7 @@ function getNotificationColor(level: string): string {
   }
 }
 
-export function TaskListItem({ task, isSelected, onClick, onDoubleClick, onContextMenu, notification }: TaskListItemProps) {
+export function TaskListItem({ task, isSelected, onClick, onDoubleClick, onContextMenu, notification, risk }: TaskListItemProps) {
   const { isMobile, isTouchDevice } = useIsMobile();
 
   return (
@@ -84,6 +99,9 @@ export function TaskListItem({ task, isSelected, onClick, onDoubleClick, onConte
               <span className="text-sm font-medium text-[var(--color-text)] truncate">
                 {task.name}
               </span>
+              {task.pinned && (
+                <Pin className="w-3 h-3 flex-shrink-0 text-[var(--color-warning)]" aria-label="Pinned" />
+              )}
               {task.isLocal && (
                 <span className="flex-shrink-0 text-[10px] font-medium px-1.5 py-0.5 rounded bg-[var(--color-accent)]/15 text-[var(--color-accent)]">
                   Local
@@ -129,6 +147,46 @@ export function TaskListItem({ task, isSelected, onClick, onDoubleClick, onConte
               </span>
             )}
 
+            {/* Inferred terminal agent state */}
+            {task.agentState && (
+              <span
+                className="text-[10px] font-medium px-1.5 py-0.5 rounded"
+                style={{
+                  color: AGENT_STATE_STYLE[task.agentState].color,
+                  backgroundColor: `color-mix(in srgb, ${AGENT_STATE_STYLE[task.agentState].color} 12%, transparent)`,
+                }}
+              >
+                {AGENT_STATE_STYLE[task.agentState].label}
+              </span>
+            )}
+
+            {/* Risk score (low risk stays quiet) */}
+            {risk && risk.level !== "low" && task.status !== "archived" && (
+              <span
+                className="text-[10px] font-medium px-1.5 py-0.5 rounded"
+                style={{
+                  color: RISK_COLOR[risk.level],
+                  backgroundColor: `color-mix(in srgb, ${RISK_COLOR[risk.level]} 12%, transparent)`,
+                }}
+                title={`Risk ${risk.score}/100: ${risk.factors.map((f) => f.detail).join(", ")}`}
+              >
+                {risk.level === "high" ? "High risk" : "Medium risk"}
+              </span>
+            )}
+
+            {/* Handback: waiting on the human */}
+            {task.assignee?.kind === "human" && task.status !== "archived" && (
+              <span
+                className="text-[10px] font-medium px-1.5 py-0.5 rounded"
+                style={{
+                  color: "var(--color-warning)",
+                  backgroundColor: "color-mix(in srgb, var(--color-warning) 12%, transparent)",
+                }}
+              >
+                Your turn
+              </span>
+            )}
+
             {/* Archived badge */}
             {task.status === "archived" && (
               <span
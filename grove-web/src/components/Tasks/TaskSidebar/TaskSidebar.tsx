@@ -2,8 +2,10 @@ import { Loader2 } from "lucide-react";
 import { TaskSearch } from "./TaskSearch";
 import { TaskFilters } from "./TaskFilters";
 import { TaskListItem } from "./TaskListItem";
+import { TaskViewBar, type TaskViewBarProps } from "./TaskViewBar";
 import { useNotifications } from "../../../context";
 import type { Task, TaskFilter } from "../../../data/types";
+import type { RiskAssessment } from "../../../api";
 
 interface TaskSidebarProps {
   tasks: Task[];
@@ -17,8 +19,12 @@ interface TaskSidebarProps {
   onContextMenuTask?: (task: Task, e: React.MouseEvent) => void;
   onFilterChange: (filter: TaskFilter) => void;
   onSearchChange: (query: string) => void;
+  /** Sort / saved view controls (omitted = hidden) */
+  viewBar?: TaskViewBarProps;
   /** When true, take full width (mobile list view) */
   fullWidth?: boolean;
+  /** Risk scores by task id */
+  risks?: Record<string, RiskAssessment>;
 }
 
 export function TaskSidebar({
@@ -33,7 +39,9 @@ export function TaskSidebar({
   onContextMenuTask,
   onFilterChange,
   onSearchChange,
+  viewBar,
   fullWidth,
+  risks,
 }: TaskSidebarProps) {
   const { getTaskNotification, dismissNotification } = useNotifications();
 
@@ -47,6 +55,11 @@ export function TaskSidebar({
       {/* Filters */}
       <div className="px-3 py-2 border-b border-[var(--color-border)]">
         <TaskFilters filter={filter} onChange={onFilterChange} />
+        {viewBar && (
+          <div className="mt-2">
+            <TaskViewBar {...viewBar} />
+          </div>
+        )}
       </div>
 
       {/* Task List */}
@@ -77,6 +90,7 @@ export function TaskSidebar({
                   onDoubleClick={() => onDoubleClickTask(task)}
                   onContextMenu={onContextMenuTask ? (e) => onContextMenuTask(task, e) : undefined}
                   notification={notif ? { level: notif.level } : undefined}
+                  risk={risks?.[task.id]}
                 />
               );
             })}
@@ -0,0 +1,70 @@
+import { Plus, Trash2 } from "lucide-react";
+import type { TaskSort, TaskView } from "../../../api";
+
+export const TASK_SORT_OPTIONS: { value: TaskSort; label: string }[] = [
+  { value: "activity", label: "Last activity" },
+  { value: "diff_size", label: "Diff size" },
+  { value: "status", label: "Status" },
+];
+
+export interface TaskViewBarProps {
+  sort: TaskSort;
+  views: TaskView[];
+  /** Name of the active saved view, null = all tasks */
+  activeView: string | null;
+  onSortChange: (sort: TaskSort) => void;
+  onViewChange: (view: string | null) => void;
+  onSaveView: () => void;
+  onDeleteView: (name: string) => void;
+}
+
+const selectClass =
+  "min-w-0 flex-1 text-xs bg-[var(--color-bg)] border border-[var(--color-border)] rounded px-1.5 py-1 text-[var(--color-text)]";
+const iconButtonClass =
+  "p-1 rounded text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] transition-colors";
+
+/** Sort selector + saved view selector for the task list */
+export function TaskViewBar({
+  sort,
+  views,
+  activeView,
+  onSortChange,
+  onViewChange,
+  onSaveView,
+  onDeleteView,
+}: TaskViewBarProps) {
+  return (
+    <div className="flex items-center gap-1.5">
+      <select
+        value={activeView ?? ""}
+        onChange={(e) => onViewChange(e.target.value || null)}
+        className={selectClass}
+        title="Saved view"
+      >
+        <option value="">All tasks</option>
+        {views.map((view) => (
+          <option key={view.name} value={view.name}>{view.name}</option>
+        ))}
+      </select>
+      <select
+        value={sort}
+        onChange={(e) => onSortChange(e.target.value as TaskSort)}
+        className={selectClass}
+        title="Sort by"
+      >
+        {TASK_SORT_OPTIONS.map(({ value, label }) => (
+          <option key={value} value={value}>{label}</option>
+        ))}
+      </select>
+      {activeView ? (
+        <button onClick={() => onDeleteView(activeView)} className={iconButtonClass} title="Delete view">
+          <Trash2 className="w-3.5 h-3.5" />
+        </button>
+      ) : (
+        <button onClick={onSaveView} className={iconButtonClass} title="Save view">
+          <Plus className="w-3.5 h-3.5" />
+        </button>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,114 @@
+import { useState, useRef, useEffect } from "react";
+import { createPortal } from "react-dom";
+import { Copy, GitBranch, FolderOpen, Terminal, Link } from "lucide-react";
+import { getTaskCopyTargets } from "../../../api";
+import type { TaskCopyTargets } from "../../../api";
+import type { Task } from "../../../data/types";
+import { useBanner } from "../../../context";
+
+interface CopyMenuProps {
+  projectId: string;
+  task: Task;
+}
+
+/** Workspace bar dropdown copying the task's branch, path, attach command or URL */
+export function CopyMenu({ projectId, task }: CopyMenuProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const [targets, setTargets] = useState<TaskCopyTargets | null>(null);
+  const triggerRef = useRef<HTMLButtonElement>(null);
+  const menuRef = useRef<HTMLDivElement>(null);
+  const [menuPos, setMenuPos] = useState({ top: 0, left: 0 });
+  const { showBanner } = useBanner();
+
+  useEffect(() => {
+    if (!isOpen) return;
+    let cancelled = false;
+    getTaskCopyTargets(projectId, task.id)
+      .then((t) => { if (!cancelled) setTargets(t); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId, task.id, isOpen]);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    const handler = (e: MouseEvent) => {
+      if (menuRef.current && !menuRef.current.contains(e.target as Node) &&
+          triggerRef.current && !triggerRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    document.addEventListener("mousedown", handler);
+    return () => document.removeEventListener("mousedown", handler);
+  }, [isOpen]);
+
+  const handleToggle = (e: React.MouseEvent) => {
+    e.stopPropagation();
+    if (!isOpen && triggerRef.current) {
+      const rect = triggerRef.current.getBoundingClientRect();
+      setMenuPos({ top: rect.bottom + 6, left: rect.right });
+    }
+    setIsOpen(!isOpen);
+  };
+
+  const copy = async (label: string, value: string) => {
+    try {
+      await navigator.clipboard.writeText(value);
+      showBanner(`Copied ${label}`, "success");
+    } catch {
+      showBanner("Clipboard unavailable", "error");
+    }
+    setIsOpen(false);
+  };
+
+  const taskUrl = `${window.location.origin}/review/${projectId}/${task.id}`;
+  const branch = targets?.branch ?? task.branch;
+  const path = targets?.path ?? "";
+
+  const itemClass = "w-full flex items-center gap-2.5 px-3 py-2 text-[12.5px] font-medium rounded-lg transition-colors text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] disabled:opacity-35 disabled:cursor-not-allowed";
+
+  return (
+    <>
+      <button
+        ref={triggerRef}
+        onClick={handleToggle}
+        className="flex items-center gap-1.5 h-7 px-2 rounded-md text-xs font-medium text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] transition-colors"
+        title="Copy branch, path, attach command or task URL"
+      >
+        <Copy size={13} />
+      </button>
+      {isOpen && createPortal(
+        <div
+          ref={menuRef}
+          style={{
+            position: "fixed",
+            top: menuPos.top,
+            left: menuPos.left,
+            transform: "translateX(-100%)",
+            zIndex: 10000,
+          }}
+          className="w-[220px] p-1.5 rounded-xl border border-[var(--color-border)] bg-[var(--color-bg)] shadow-[0_12px_40px_rgba(0,0,0,0.18),0_4px_12px_rgba(0,0,0,0.08)]"
+        >
+          <button className={itemClass} disabled={!branch} onClick={() => copy("branch", branch)}>
+            <GitBranch size={14} className="opacity-80 shrink-0" />
+            <span className="flex-1 text-left">Copy branch</span>
+          </button>
+          <button className={itemClass} disabled={!path} onClick={() => copy("worktree path", path)}>
+            <FolderOpen size={14} className="opacity-80 shrink-0" />
+            <span className="flex-1 text-left">Copy worktree path</span>
+          </button>
+          {targets?.attach_command && (
+            <button className={itemClass} onClick={() => copy("attach command", targets.attach_command!)}>
+              <Terminal size={14} className="opacity-80 shrink-0" />
+              <span className="flex-1 text-left">Copy attach command</span>
+            </button>
+          )}
+          <button className={itemClass} onClick={() => copy("task URL", taskUrl)}>
+            <Link size={14} className="opacity-80 shrink-0" />
+            <span className="flex-1 text-left">Copy task URL</span>
+          </button>
+        </div>,
+        document.body
+      )}
+    </>
+  );
+}
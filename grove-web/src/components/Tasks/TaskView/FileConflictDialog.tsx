@@ -0,0 +1,81 @@
+import { GitMerge, X } from "lucide-react";
+import { Button } from "../../ui";
+import { DialogShell } from "../../ui/DialogShell";
+import type { FileConflict } from "../../../api";
+
+interface FileConflictDialogProps {
+  conflict: FileConflict | null;
+  onClose: () => void;
+  /** Load the merge result into the editor (still unsaved) */
+  onUseMerged: (merged: string) => void;
+  /** Discard editor changes and load what is on disk */
+  onUseDisk: () => void;
+  /** Overwrite the on-disk changes with the editor content */
+  onKeepMine: () => void;
+}
+
+export function FileConflictDialog({
+  conflict,
+  onClose,
+  onUseMerged,
+  onUseDisk,
+  onKeepMine,
+}: FileConflictDialogProps) {
+  const merged = conflict?.merged ?? null;
+
+  return (
+    <DialogShell isOpen={conflict !== null} onClose={onClose} maxWidth="max-w-2xl">
+      <div className="bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded-xl shadow-xl overflow-hidden">
+        {/* Header */}
+        <div className="flex items-center justify-between px-5 py-4 border-b border-[var(--color-border)]">
+          <div className="flex items-center gap-3">
+            <div className="w-9 h-9 rounded-lg flex items-center justify-center bg-[var(--color-warning)]/10">
+              <GitMerge className="w-5 h-5 text-[var(--color-warning)]" />
+            </div>
+            <div>
+              <h2 className="text-lg font-semibold text-[var(--color-text)]">File changed on disk</h2>
+              <p className="text-xs font-mono text-[var(--color-text-muted)] break-all">{conflict?.path}</p>
+            </div>
+          </div>
+          <button
+            onClick={onClose}
+            className="p-1.5 rounded-lg hover:bg-[var(--color-bg-tertiary)] text-[var(--color-text-muted)] transition-colors"
+          >
+            <X className="w-5 h-5" />
+          </button>
+        </div>
+
+        {/* Content */}
+        <div className="px-5 py-4 space-y-3">
+          <p className="text-sm text-[var(--color-text-muted)]">
+            {merged === null
+              ? "The file was modified after you opened it and the changes could not be merged automatically."
+              : conflict?.has_conflicts
+                ? "The file was modified after you opened it. Both sides changed the same lines; the merge below contains conflict markers to resolve before saving."
+                : "The file was modified after you opened it. Your edits merge cleanly with the changes on disk."}
+          </p>
+          {merged !== null && (
+            <pre className="max-h-72 overflow-auto px-3 py-2 bg-[var(--color-bg)] border border-[var(--color-border)] rounded-lg text-xs font-mono text-[var(--color-text)] whitespace-pre">
+              {merged}
+            </pre>
+          )}
+        </div>
+
+        {/* Actions */}
+        <div className="flex justify-end gap-3 px-5 py-4 bg-[var(--color-bg)] border-t border-[var(--color-border)]">
+          <Button variant="secondary" onClick={onUseDisk}>
+            Use Disk Version
+          </Button>
+          <Button variant="secondary" onClick={onKeepMine}>
+            Overwrite
+          </Button>
+          {merged !== null && (
+            <Button variant="primary" onClick={() => onUseMerged(merged)}>
+              {conflict?.has_conflicts ? "Resolve in Editor" : "Use Merged"}
+            </Button>
+          )}
+        </div>
+      </div>
+    </DialogShell>
+  );
+}
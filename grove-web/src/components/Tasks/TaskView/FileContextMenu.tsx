@@ -1,6 +1,6 @@
 import { useEffect, useRef, useState, useLayoutEffect, useMemo } from "react";
 import { createPortal } from "react-dom";
-import { FileText, FolderPlus, Trash2, Copy, SquareArrowOutUpRight } from "lucide-react";
+import { FileText, FolderPlus, Trash2, Copy, SquareArrowOutUpRight, Pencil, Download } from "lucide-react";
 
 export interface ContextMenuPosition {
   x: number;
@@ -20,10 +20,12 @@ interface FileContextMenuProps {
   onClose: () => void;
   onNewFile: (parentPath?: string) => void;
   onNewDirectory: (parentPath?: string) => void;
+  onRename: (path: string) => void;
   onDelete: (path: string) => void;
   onCopyRelativePath: (path: string) => void;
   onCopyFullPath: (path: string) => void;
   onOpenInApp: (path: string) => void;
+  onDownload: (path: string) => void;
 }
 
 export function FileContextMenu({
@@ -34,10 +36,12 @@ export function FileContextMenu({
   onClose,
   onNewFile,
   onNewDirectory,
+  onRename,
   onDelete,
   onCopyRelativePath,
   onCopyFullPath,
   onOpenInApp,
+  onDownload,
 }: FileContextMenuProps) {
   const menuRef = useRef<HTMLDivElement>(null);
   const [dimensions, setDimensions] = useState({ width: 0, height: 0 });
@@ -119,6 +123,17 @@ export function FileContextMenu({
             <span className="text-[13px] text-[var(--color-text)]">Open</span>
           </button>
 
+          {/* Download (files only) */}
+          {!isDirectory && (
+            <button
+              onClick={() => handleAction(() => onDownload(targetPath))}
+              className="w-full flex items-center gap-2.5 px-3 py-1.5 hover:bg-[var(--color-bg-tertiary)] text-left transition-colors"
+            >
+              <Download className="w-3.5 h-3.5 text-[var(--color-text-muted)]" />
+              <span className="text-[13px] text-[var(--color-text)]">Download</span>
+            </button>
+          )}
+
           {/* Divider */}
           <div className="my-0.5 h-px bg-[var(--color-border)]" />
 
@@ -165,6 +180,15 @@ export function FileContextMenu({
           {/* Divider */}
           <div className="my-0.5 h-px bg-[var(--color-border)]" />
 
+          {/* Rename / move */}
+          <button
+            onClick={() => handleAction(() => onRename(targetPath))}
+            className="w-full flex items-center gap-2.5 px-3 py-1.5 hover:bg-[var(--color-bg-tertiary)] text-left transition-colors"
+          >
+            <Pencil className="w-3.5 h-3.5 text-[var(--color-text-muted)]" />
+            <span className="text-[13px] text-[var(--color-text)]">Rename</span>
+          </button>
+
           {/* Delete */}
           <button
             onClick={() => handleAction(() => onDelete(targetPath))}
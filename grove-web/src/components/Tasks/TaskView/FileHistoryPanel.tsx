@@ -0,0 +1,126 @@
+import { useEffect, useState } from "react";
+import { GitCommit, Loader2 } from "lucide-react";
+import { getFileHistory, getFileHistoryPatch } from "../../../api";
+import type { FileHistoryEntry } from "../../../api";
+import { TimeLabel } from "../../ui";
+
+interface FileHistoryPanelProps {
+  projectId: string;
+  taskId: string;
+  path: string;
+}
+
+function patchLineClass(line: string): string {
+  if (line.startsWith("+++") || line.startsWith("---")) return "text-[var(--color-text-muted)]";
+  if (line.startsWith("+")) return "text-[var(--color-success)] bg-[var(--color-success)]/10";
+  if (line.startsWith("-")) return "text-[var(--color-error)] bg-[var(--color-error)]/10";
+  if (line.startsWith("@@")) return "text-[var(--color-info)]";
+  return "text-[var(--color-text)]";
+}
+
+/** "History" view of a file: commits touching it and the patch of the selected one. */
+export function FileHistoryPanel({ projectId, taskId, path }: FileHistoryPanelProps) {
+  const [includeTarget, setIncludeTarget] = useState(false);
+  const [commits, setCommits] = useState<FileHistoryEntry[] | null>(null);
+  const [selected, setSelected] = useState<FileHistoryEntry | null>(null);
+  const [patch, setPatch] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    setCommits(null);
+    setSelected(null);
+    setError(null);
+    getFileHistory(projectId, taskId, path, includeTarget)
+      .then((res) => {
+        if (cancelled) return;
+        setCommits(res.commits);
+        setSelected(res.commits[0] ?? null);
+      })
+      .catch((err) => {
+        if (!cancelled) setError(err?.message || "Failed to load history");
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [projectId, taskId, path, includeTarget]);
+
+  useEffect(() => {
+    if (!selected) return;
+    let cancelled = false;
+    setPatch(null);
+    getFileHistoryPatch(projectId, taskId, selected.hash, selected.path)
+      .then((res) => {
+        if (!cancelled) setPatch(res.patch);
+      })
+      .catch((err) => {
+        if (!cancelled) setPatch(`Failed to load patch: ${err?.message || err}`);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [projectId, taskId, selected]);
+
+  return (
+    <div className="flex-1 flex min-h-0 min-w-0 divide-x divide-[var(--color-border)] overflow-hidden">
+      {/* Commit list */}
+      <div className="w-72 shrink-0 flex flex-col min-h-0">
+        <label className="flex items-center gap-2 px-3 py-2 text-xs text-[var(--color-text-muted)] border-b border-[var(--color-border)] cursor-pointer select-none">
+          <input
+            type="checkbox"
+            checked={includeTarget}
+            onChange={(e) => setIncludeTarget(e.target.checked)}
+          />
+          Include target branch history
+        </label>
+        <div className="flex-1 overflow-auto">
+          {error ? (
+            <p className="p-3 text-xs text-[var(--color-error)]">{error}</p>
+          ) : commits === null ? (
+            <div className="p-4 flex justify-center">
+              <Loader2 className="w-5 h-5 text-[var(--color-text-muted)] animate-spin" />
+            </div>
+          ) : commits.length === 0 ? (
+            <p className="p-3 text-xs text-[var(--color-text-muted)]">
+              {includeTarget ? "No commits touch this file." : "No commits on this task branch touch this file."}
+            </p>
+          ) : (
+            commits.map((commit) => (
+              <button
+                key={commit.hash}
+                onClick={() => setSelected(commit)}
+                className={`w-full text-left px-3 py-2 border-b border-[var(--color-border)] transition-colors ${selected?.hash === commit.hash ? "bg-[var(--color-bg-tertiary)]" : "hover:bg-[var(--color-bg-secondary)]"}`}
+              >
+                <div className="flex items-center gap-1.5 text-[13px] text-[var(--color-text)]">
+                  <GitCommit className={`w-3.5 h-3.5 shrink-0 ${commit.on_branch ? "text-[var(--color-highlight)]" : "text-[var(--color-text-muted)]"}`} />
+                  <span className="truncate">{commit.message}</span>
+                </div>
+                <div className="mt-0.5 text-[11px] text-[var(--color-text-muted)] truncate">
+                  <span className="font-mono">{commit.hash.slice(0, 7)}</span> · {commit.author} · <TimeLabel time={commit.time} timeAgo={commit.time_ago} />
+                  {commit.path !== path && <> · <span className="font-mono">{commit.path}</span></>}
+                </div>
+              </button>
+            ))
+          )}
+        </div>
+      </div>
+
+      {/* Patch of the selected commit */}
+      <div className="flex-1 min-w-0 overflow-auto bg-[var(--color-bg)] editor-scroll-container">
+        {selected && patch === null ? (
+          <div className="p-4 flex justify-center">
+            <Loader2 className="w-5 h-5 text-[var(--color-text-muted)] animate-spin" />
+          </div>
+        ) : patch !== null ? (
+          <pre className="p-3 text-xs font-mono leading-5">
+            {patch.split("\n").map((line, i) => (
+              <div key={i} className={`px-1 whitespace-pre ${patchLineClass(line)}`}>
+                {line || " "}
+              </div>
+            ))}
+          </pre>
+        ) : null}
+      </div>
+    </div>
+  );
+}
@@ -0,0 +1,146 @@
+import { useEffect, useMemo, useRef, useState } from "react";
+import { ChevronDown, ListTree, Loader2 } from "lucide-react";
+import { getFileOutline } from "../../../api";
+import type { FileOutline, SymbolCandidate } from "../../../api";
+
+interface FileOutlineMenuProps {
+  projectId: string;
+  taskId: string;
+  path: string;
+  /** Jump to a 0-indexed position in the open file. */
+  onJump: (line: number, col: number) => void;
+}
+
+const KIND_BADGE: Record<string, string> = {
+  function: "fn",
+  method: "m",
+  struct: "S",
+  interface: "I",
+  type: "T",
+  const: "c",
+  var: "v",
+  field: "f",
+};
+
+/**
+ * "Outline" dropdown for the code viewer: declarations of the open file,
+ * click to jump. Renders nothing for files no grammar handles.
+ */
+export function FileOutlineMenu({ projectId, taskId, path, onJump }: FileOutlineMenuProps) {
+  const [outline, setOutline] = useState<FileOutline | null>(null);
+  const [open, setOpen] = useState(false);
+  const [loading, setLoading] = useState(false);
+  const [filter, setFilter] = useState("");
+  const rootRef = useRef<HTMLDivElement>(null);
+
+  // Fetch on file change to learn whether the file has an outline at all,
+  // and again on every open so it reflects the latest save.
+  useEffect(() => {
+    let cancelled = false;
+    setOutline(null);
+    setOpen(false);
+    getFileOutline(projectId, taskId, path)
+      .then((res) => {
+        if (!cancelled) setOutline(res);
+      })
+      .catch(() => {
+        if (!cancelled) setOutline(null);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [projectId, taskId, path]);
+
+  const handleToggle = () => {
+    if (open) {
+      setOpen(false);
+      return;
+    }
+    setOpen(true);
+    setFilter("");
+    setLoading(true);
+    getFileOutline(projectId, taskId, path)
+      .then(setOutline)
+      .catch(() => {})
+      .finally(() => setLoading(false));
+  };
+
+  useEffect(() => {
+    if (!open) return;
+    const onMouseDown = (e: MouseEvent) => {
+      if (rootRef.current && !rootRef.current.contains(e.target as Node)) setOpen(false);
+    };
+    document.addEventListener("mousedown", onMouseDown);
+    return () => document.removeEventListener("mousedown", onMouseDown);
+  }, [open]);
+
+  const symbols = useMemo(() => {
+    const all = outline?.symbols ?? [];
+    const q = filter.trim().toLowerCase();
+    return q ? all.filter((s) => s.name.toLowerCase().includes(q)) : all;
+  }, [outline, filter]);
+
+  if (!outline?.language) return null;
+
+  const handlePick = (symbol: SymbolCandidate) => {
+    setOpen(false);
+    onJump(symbol.line, symbol.col);
+  };
+
+  return (
+    <div ref={rootRef} className="relative">
+      <button
+        onClick={handleToggle}
+        className={`px-2 h-7 rounded-md border border-[var(--color-border)] transition-colors flex items-center gap-1 text-xs font-medium cursor-pointer ${open ? 'bg-[var(--color-bg-tertiary)] text-[var(--color-text)]' : 'bg-[var(--color-bg)] text-[var(--color-text-muted)] hover:text-[var(--color-text)]'}`}
+        title="Symbols declared in this file"
+      >
+        <ListTree className="w-3.5 h-3.5" />
+        Outline
+        <ChevronDown className="w-3 h-3" />
+      </button>
+      {open && (
+        <div className="absolute right-0 top-8 z-20 w-72 max-h-96 flex flex-col bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded-lg shadow-xl overflow-hidden">
+          <input
+            autoFocus
+            value={filter}
+            onChange={(e) => setFilter(e.target.value)}
+            onKeyDown={(e) => {
+              if (e.key === "Escape") setOpen(false);
+              if (e.key === "Enter" && symbols[0]) handlePick(symbols[0]);
+            }}
+            placeholder="Filter symbols…"
+            className="px-3 py-2 text-xs bg-[var(--color-bg)] border-b border-[var(--color-border)] text-[var(--color-text)] outline-none"
+          />
+          <div className="flex-1 overflow-auto py-1">
+            {loading && symbols.length === 0 ? (
+              <div className="p-3 flex justify-center">
+                <Loader2 className="w-4 h-4 text-[var(--color-text-muted)] animate-spin" />
+              </div>
+            ) : symbols.length === 0 ? (
+              <p className="px-3 py-2 text-xs text-[var(--color-text-muted)]">No symbols</p>
+            ) : (
+              symbols.map((s) => (
+                <button
+                  key={`${s.kind}:${s.container ?? ""}:${s.name}:${s.line}`}
+                  onClick={() => handlePick(s)}
+                  className={`w-full text-left py-1 pr-3 flex items-center gap-2 text-xs hover:bg-[var(--color-bg-tertiary)] ${s.container ? 'pl-7' : 'pl-3'}`}
+                >
+                  <span className="w-5 shrink-0 text-center font-mono text-[10px] text-[var(--color-highlight)]">
+                    {KIND_BADGE[s.kind] ?? s.kind.slice(0, 1)}
+                  </span>
+                  <span className="truncate text-[var(--color-text)]">{s.name}</span>
+                  {s.container && (
+                    <span className="truncate text-[var(--color-text-muted)]">{s.container}</span>
+                  )}
+                  <span className="ml-auto shrink-0 font-mono text-[10px] text-[var(--color-text-muted)]">
+                    {s.line + 1}
+                  </span>
+                </button>
+              ))
+            )}
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,149 @@
+import { useState, useRef, useEffect } from "react";
+import { createPortal } from "react-dom";
+import { Bot, User, UserX, Hand } from "lucide-react";
+import { handbackTask, listChats } from "../../../api";
+import type { ChatSessionResponse, TaskAssignee } from "../../../api";
+import type { Task } from "../../../data/types";
+import { useBanner, useProject } from "../../../context";
+
+interface HandbackMenuProps {
+  projectId: string;
+  task: Task;
+}
+
+/** Workspace bar dropdown showing who owns the task, with handback actions */
+export function HandbackMenu({ projectId, task }: HandbackMenuProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const [chats, setChats] = useState<ChatSessionResponse[]>([]);
+  const [message, setMessage] = useState("");
+  const [busy, setBusy] = useState(false);
+  const triggerRef = useRef<HTMLButtonElement>(null);
+  const menuRef = useRef<HTMLDivElement>(null);
+  const [menuPos, setMenuPos] = useState({ top: 0, left: 0 });
+  const { refreshSelectedProject } = useProject();
+  const { showBanner } = useBanner();
+
+  // Chat titles are needed for the trigger label too, so load them up front
+  useEffect(() => {
+    let cancelled = false;
+    listChats(projectId, task.id)
+      .then((list) => { if (!cancelled) setChats(list); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [projectId, task.id, isOpen]);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    const handler = (e: MouseEvent) => {
+      if (menuRef.current && !menuRef.current.contains(e.target as Node) &&
+          triggerRef.current && !triggerRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    document.addEventListener("mousedown", handler);
+    return () => document.removeEventListener("mousedown", handler);
+  }, [isOpen]);
+
+  const assignee = task.assignee;
+  const assignedChat = assignee?.kind === "agent" ? chats.find((c) => c.id === assignee.chat_id) : undefined;
+  const label = !assignee
+    ? "Unassigned"
+    : assignee.kind === "human"
+      ? "You"
+      : assignedChat?.title ?? "Agent";
+
+  const handleToggle = (e: React.MouseEvent) => {
+    e.stopPropagation();
+    if (!isOpen && triggerRef.current) {
+      const rect = triggerRef.current.getBoundingClientRect();
+      setMenuPos({ top: rect.bottom + 6, left: rect.right });
+    }
+    setIsOpen(!isOpen);
+  };
+
+  const assign = async (next: TaskAssignee | null, targetLabel: string) => {
+    setBusy(true);
+    try {
+      const result = await handbackTask(projectId, task.id, next, message.trim() || undefined);
+      if (next?.kind === "agent" && !result.delivered) {
+        showBanner(`Assigned to ${targetLabel}; the chat isn't running, so no prompt was sent`, "info");
+      } else {
+        showBanner(next ? `Handed to ${targetLabel}` : "Assignee cleared", "success");
+      }
+      setMessage("");
+      setIsOpen(false);
+      await refreshSelectedProject();
+    } catch {
+      showBanner("Failed to hand back task", "error");
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const itemClass = "w-full flex items-center gap-2.5 px-3 py-2 text-[12.5px] font-medium rounded-lg transition-colors text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] disabled:opacity-35 disabled:cursor-not-allowed";
+
+  return (
+    <>
+      <button
+        ref={triggerRef}
+        onClick={handleToggle}
+        className="flex items-center gap-1.5 h-7 px-2.5 rounded-md text-xs font-medium text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] transition-colors"
+        style={assignee?.kind === "human" ? { color: "var(--color-warning)" } : undefined}
+        title="Assignee — hand the task to yourself or an agent chat"
+      >
+        {assignee?.kind === "agent" ? <Bot size={13} /> : <Hand size={13} />}
+        <span className="max-w-[120px] truncate">{label}</span>
+      </button>
+      {isOpen && createPortal(
+        <div
+          ref={menuRef}
+          style={{
+            position: "fixed",
+            top: menuPos.top,
+            left: menuPos.left,
+            transform: "translateX(-100%)",
+            zIndex: 10000,
+          }}
+          className="w-[240px] p-1.5 rounded-xl border border-[var(--color-border)] bg-[var(--color-bg)] shadow-[0_12px_40px_rgba(0,0,0,0.18),0_4px_12px_rgba(0,0,0,0.08)]"
+        >
+          <textarea
+            value={message}
+            onChange={(e) => setMessage(e.target.value)}
+            placeholder="Message (optional)"
+            rows={2}
+            className="w-full mb-1 px-2 py-1.5 text-xs rounded-lg resize-none bg-[var(--color-bg-secondary)] border border-[var(--color-border)] text-[var(--color-text)] focus:outline-none"
+          />
+          <button
+            className={itemClass}
+            disabled={busy || assignee?.kind === "human"}
+            onClick={() => assign({ kind: "human" }, "you")}
+          >
+            <User size={14} className="opacity-80 shrink-0" />
+            <span className="flex-1 text-left">Assign to me</span>
+          </button>
+          {chats.map((chat) => (
+            <button
+              key={chat.id}
+              className={itemClass}
+              disabled={busy || (assignee?.kind === "agent" && assignee.chat_id === chat.id)}
+              onClick={() => assign({ kind: "agent", chat_id: chat.id }, chat.title)}
+            >
+              <Bot size={14} className="opacity-80 shrink-0" />
+              <span className="flex-1 text-left truncate">Hand to {chat.title}</span>
+            </button>
+          ))}
+          {assignee && (
+            <>
+              <div className="h-px bg-[var(--color-border)] mx-2 my-1" />
+              <button className={itemClass} disabled={busy} onClick={() => assign(null, "")}>
+                <UserX size={14} className="opacity-80 shrink-0" />
+                <span className="flex-1 text-left">Clear assignee</span>
+              </button>
+            </>
+          )}
+        </div>,
+        document.body
+      )}
+    </>
+  );
+}
@@ -0,0 +1,141 @@
+import { useEffect, useState } from "react";
+import { History, Loader2, X } from "lucide-react";
+import { DialogShell } from "../../ui/DialogShell";
+import {
+  importNativeSession,
+  listNativeSessions,
+  type ImportChatResponse,
+  type NativeSession,
+} from "../../../api";
+
+interface ImportSessionDialogProps {
+  isOpen: boolean;
+  projectId: string;
+  taskId: string;
+  onClose: () => void;
+  onImported: (result: ImportChatResponse) => void;
+}
+
+const SOURCE_LABELS: Record<NativeSession["source"], string> = {
+  claude: "Claude Code",
+  codex: "Codex",
+};
+
+function formatTime(iso?: string): string {
+  if (!iso) return "";
+  return new Date(iso).toLocaleString(undefined, {
+    month: "short",
+    day: "numeric",
+    hour: "2-digit",
+    minute: "2-digit",
+  });
+}
+
+/**
+ * Lists Claude Code / Codex sessions that ran in the task's worktree or
+ * project root, and imports the picked one as a new chat.
+ */
+export function ImportSessionDialog({
+  isOpen,
+  projectId,
+  taskId,
+  onClose,
+  onImported,
+}: ImportSessionDialogProps) {
+  const [sessions, setSessions] = useState<NativeSession[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [importing, setImporting] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    let cancelled = false;
+    setSessions(null);
+    setError(null);
+    listNativeSessions(projectId, taskId)
+      .then((res) => {
+        if (!cancelled) setSessions(res);
+      })
+      .catch((err) => {
+        if (!cancelled) setError(err instanceof Error ? err.message : "Failed to list sessions");
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [isOpen, projectId, taskId]);
+
+  const handleImport = async (session: NativeSession) => {
+    setImporting(session.path);
+    setError(null);
+    try {
+      const result = await importNativeSession(projectId, taskId, session.source, session.path);
+      onImported(result);
+      onClose();
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Import failed");
+    } finally {
+      setImporting(null);
+    }
+  };
+
+  return (
+    <DialogShell isOpen={isOpen} onClose={onClose} maxWidth="max-w-2xl">
+      <div className="bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded-xl shadow-xl overflow-hidden">
+        <div className="flex items-center justify-between px-5 py-4 border-b border-[var(--color-border)]">
+          <div className="flex items-center gap-3 min-w-0">
+            <div className="w-9 h-9 rounded-lg flex items-center justify-center bg-[var(--color-highlight)]/10">
+              <History className="w-5 h-5 text-[var(--color-highlight)]" />
+            </div>
+            <div className="min-w-0">
+              <h2 className="text-lg font-semibold text-[var(--color-text)]">Import session</h2>
+              <p className="truncate text-xs text-[var(--color-text-muted)]">
+                Claude Code / Codex sessions run in this task's worktree or project
+              </p>
+            </div>
+          </div>
+          <button
+            type="button"
+            onClick={onClose}
+            aria-label="Close"
+            className="p-1.5 rounded-lg hover:bg-[var(--color-bg-tertiary)] text-[var(--color-text-muted)] transition-colors"
+          >
+            <X className="w-5 h-5" />
+          </button>
+        </div>
+        <div className="max-h-[65vh] overflow-y-auto px-5 py-4 space-y-2">
+          {error && <p className="text-sm text-[var(--color-error)]">{error}</p>}
+          {!error && sessions === null && (
+            <p className="text-sm text-[var(--color-text-muted)]">Loading…</p>
+          )}
+          {sessions?.length === 0 && (
+            <p className="text-sm text-[var(--color-text-muted)]">No sessions found for this task.</p>
+          )}
+          {sessions?.map((session) => (
+            <button
+              key={session.path}
+              type="button"
+              disabled={importing !== null}
+              onClick={() => void handleImport(session)}
+              className="w-full text-left rounded-lg border border-[var(--color-border)] px-3 py-2 hover:bg-[var(--color-bg-tertiary)] disabled:opacity-60 transition-colors"
+            >
+              <div className="flex items-center justify-between gap-3">
+                <span className="truncate text-sm text-[var(--color-text)]">{session.title}</span>
+                {importing === session.path ? (
+                  <Loader2 className="w-3.5 h-3.5 shrink-0 animate-spin text-[var(--color-text-muted)]" />
+                ) : (
+                  <span className="shrink-0 text-[10px] text-[var(--color-text-muted)]">
+                    {SOURCE_LABELS[session.source]}
+                  </span>
+                )}
+              </div>
+              <div className="mt-0.5 flex items-center gap-2 text-[10px] tabular-nums text-[var(--color-text-muted)]">
+                <span>{formatTime(session.updated_at)}</span>
+                <span>· {session.prompts} prompts</span>
+                {session.cwd && <span className="truncate font-mono">· {session.cwd}</span>}
+              </div>
+            </button>
+          ))}
+        </div>
+      </div>
+    </DialogShell>
+  );
+}
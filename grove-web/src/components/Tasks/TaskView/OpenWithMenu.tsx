@@ -0,0 +1,102 @@
+import { useState, useRef, useEffect } from "react";
+import { createPortal } from "react-dom";
+import { AppWindow } from "lucide-react";
+import { getConfig, openTaskWith } from "../../../api";
+import type { OpenWithApp } from "../../../api";
+import type { Task } from "../../../data/types";
+import { useBanner } from "../../../context";
+
+interface OpenWithMenuProps {
+  projectId: string;
+  task: Task;
+}
+
+/** Workspace bar dropdown launching the user-defined "Open With" applications */
+export function OpenWithMenu({ projectId, task }: OpenWithMenuProps) {
+  const [isOpen, setIsOpen] = useState(false);
+  const [apps, setApps] = useState<OpenWithApp[]>([]);
+  const triggerRef = useRef<HTMLButtonElement>(null);
+  const menuRef = useRef<HTMLDivElement>(null);
+  const [menuPos, setMenuPos] = useState({ top: 0, left: 0 });
+  const { showBanner } = useBanner();
+
+  // Reload on open so edits in Settings show up without a refresh
+  useEffect(() => {
+    let cancelled = false;
+    getConfig()
+      .then((cfg) => { if (!cancelled) setApps(cfg.open_with ?? []); })
+      .catch(() => {});
+    return () => { cancelled = true; };
+  }, [isOpen]);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    const handler = (e: MouseEvent) => {
+      if (menuRef.current && !menuRef.current.contains(e.target as Node) &&
+          triggerRef.current && !triggerRef.current.contains(e.target as Node)) {
+        setIsOpen(false);
+      }
+    };
+    document.addEventListener("mousedown", handler);
+    return () => document.removeEventListener("mousedown", handler);
+  }, [isOpen]);
+
+  if (apps.length === 0) {
+    return null;
+  }
+
+  const handleToggle = (e: React.MouseEvent) => {
+    e.stopPropagation();
+    if (!isOpen && triggerRef.current) {
+      const rect = triggerRef.current.getBoundingClientRect();
+      setMenuPos({ top: rect.bottom + 6, left: rect.right });
+    }
+    setIsOpen(!isOpen);
+  };
+
+  const launch = async (app: OpenWithApp) => {
+    setIsOpen(false);
+    try {
+      const result = await openTaskWith(projectId, task.id, app.name);
+      showBanner(result.message, result.success ? "success" : "error");
+    } catch {
+      showBanner(`Failed to open with ${app.name}`, "error");
+    }
+  };
+
+  const itemClass = "w-full flex items-center gap-2.5 px-3 py-2 text-[12.5px] font-medium rounded-lg transition-colors text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)]";
+
+  return (
+    <>
+      <button
+        ref={triggerRef}
+        onClick={handleToggle}
+        className="flex items-center gap-1.5 h-7 px-2 rounded-md text-xs font-medium text-[var(--color-text-muted)] hover:text-[var(--color-text)] hover:bg-[var(--color-bg-tertiary)] transition-colors"
+        title="Open worktree with…"
+      >
+        <AppWindow size={13} />
+      </button>
+      {isOpen && createPortal(
+        <div
+          ref={menuRef}
+          style={{
+            position: "fixed",
+            top: menuPos.top,
+            left: menuPos.left,
+            transform: "translateX(-100%)",
+            zIndex: 10000,
+          }}
+          className="w-[220px] p-1.5 rounded-xl border border-[var(--color-border)] bg-[var(--color-bg)] shadow-[0_12px_40px_rgba(0,0,0,0.18),0_4px_12px_rgba(0,0,0,0.08)]"
+        >
+          {apps.map((app) => (
+            <button key={app.name} className={itemClass} onClick={() => launch(app)} title={app.command}>
+              <AppWindow size={14} className="opacity-80 shrink-0" />
+              <span className="flex-1 text-left truncate">{app.name}</span>
+            </button>
+          ))}
+        </div>,
+        document.body
+      )}
+    </>
+  );
+}
@@ -0,0 +1,121 @@
+import { useState } from "react";
+import { Bot, Brain, ChevronDown, ChevronRight, Pin, Terminal, User, X } from "lucide-react";
+import type { ChatPin, ChatPinKind } from "../../../api";
+
+interface PinnedMessagesPanelProps {
+  pins: ChatPin[];
+  /** Scroll to the pinned message; false when it is no longer in view */
+  onJump: (pin: ChatPin) => boolean;
+  onUnpin: (pin: ChatPin) => void;
+}
+
+const KIND_ICONS: Record<ChatPinKind, typeof Bot> = {
+  user: User,
+  assistant: Bot,
+  thinking: Brain,
+  tool: Terminal,
+};
+
+/**
+ * Whether `pin` refers to a message of `kind` with `text` (or to the tool
+ * call `toolCallId`). Pins keep a server-truncated snapshot ending in "…",
+ * so a prefix match is enough for long messages.
+ */
+export function pinMatches(
+  pin: ChatPin,
+  kind: ChatPinKind,
+  text: string,
+  toolCallId?: string,
+): boolean {
+  if (pin.tool_call_id || toolCallId) return pin.tool_call_id === toolCallId;
+  if (pin.kind !== kind) return false;
+  const trimmed = text.trim();
+  if (pin.text === trimmed) return true;
+  return pin.text.endsWith("…") && trimmed.startsWith(pin.text.slice(0, -1));
+}
+
+function firstLine(text: string): string {
+  return text.split("\n").find((line) => line.trim())?.trim() ?? "";
+}
+
+/**
+ * Collapsible strip at the top of the chat listing pinned messages and tool
+ * results; clicking an entry scrolls the conversation to it.
+ */
+export function PinnedMessagesPanel({ pins, onJump, onUnpin }: PinnedMessagesPanelProps) {
+  const [expanded, setExpanded] = useState(false);
+  const [missing, setMissing] = useState<string | null>(null);
+  if (pins.length === 0) return null;
+
+  return (
+    <div className="pointer-events-none absolute inset-x-0 top-0 z-10 px-4 pt-2 sm:px-6">
+      <div className="pointer-events-auto mx-auto max-w-[920px] rounded-lg border border-[var(--color-border)] bg-[var(--color-bg-secondary)]/95 shadow-sm backdrop-blur-sm">
+        <button
+          type="button"
+          onClick={() => setExpanded((v) => !v)}
+          className="flex w-full items-center gap-1.5 px-3 py-1.5 text-xs text-[var(--color-text-muted)] hover:text-[var(--color-text)]"
+        >
+          <Pin className="h-3 w-3 text-[var(--color-highlight)]" />
+          <span>{pins.length} pinned</span>
+          {!expanded && (
+            <span className="min-w-0 flex-1 truncate text-left text-[var(--color-text)]">
+              · {pins[pins.length - 1].note || firstLine(pins[pins.length - 1].text)}
+            </span>
+          )}
+          {expanded ? (
+            <ChevronDown className="ml-auto h-3 w-3" />
+          ) : (
+            <ChevronRight className="h-3 w-3" />
+          )}
+        </button>
+        {expanded && (
+          <div className="max-h-56 overflow-y-auto border-t border-[var(--color-border)] py-1">
+            {pins.map((pin) => {
+              const Icon = KIND_ICONS[pin.kind];
+              return (
+                <div
+                  key={pin.id}
+                  className="group flex items-center gap-2 px-3 py-1 hover:bg-[var(--color-bg-tertiary)]"
+                >
+                  <Icon className="h-3 w-3 shrink-0 text-[var(--color-text-muted)]" />
+                  <button
+                    type="button"
+                    onClick={() => setMissing(onJump(pin) ? null : pin.id)}
+                    className="min-w-0 flex-1 text-left"
+                    title={pin.text}
+                  >
+                    {pin.note && (
+                      <div className="truncate text-xs font-medium text-[var(--color-text)]">
+                        {pin.note}
+                      </div>
+                    )}
+                    <div
+                      className={`truncate text-xs ${
+                        pin.note ? "text-[var(--color-text-muted)]" : "text-[var(--color-text)]"
+                      }`}
+                    >
+                      {firstLine(pin.text)}
+                    </div>
+                    {missing === pin.id && (
+                      <div className="text-[10px] text-[var(--color-warning)]">
+                        Not in the loaded conversation
+                      </div>
+                    )}
+                  </button>
+                  <button
+                    type="button"
+                    onClick={() => onUnpin(pin)}
+                    className="shrink-0 rounded p-0.5 text-[var(--color-text-muted)] opacity-0 transition-opacity hover:text-[var(--color-error)] group-hover:opacity-100"
+                    title="Unpin"
+                  >
+                    <X className="h-3 w-3" />
+                  </button>
+                </div>
+              );
+            })}
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
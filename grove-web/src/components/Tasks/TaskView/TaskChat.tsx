@@ -32,6 +32,7 @@ import {
   Plus,
   ListPlus,
   Trash2,
+  Download,
   GitFork,
   Pencil,
   Square,
@@ -52,6 +53,10 @@ import {
   Search,
   User,
   ListChecks,
+  FolderOpen,
+  Activity,
+  History,
+  Pin,
 } from "lucide-react";
 import { iconUrlForFile } from "../../ui/iconUrl";
 import {
@@ -109,6 +114,10 @@ import { useAgentQuota, useRadioEvents } from "../../../hooks";
 import { AgentQuotaPopover } from "./AgentQuotaPopover";
 import { ContextUsagePill } from "./ContextUsagePill";
 import { TurnUsageMeta } from "./TurnUsageMeta";
+import { ToolTimelineDialog } from "./ToolTimelineDialog";
+import { ImportSessionDialog } from "./ImportSessionDialog";
+import { PinnedMessagesPanel, pinMatches } from "./PinnedMessagesPanel";
+import { TurnChangesMeta } from "./TurnChangesMeta";
 import {
   quotaBadgePercent,
   quotaBatteryIcon,
@@ -121,6 +130,10 @@ import {
   updateChatTitle,
   deleteChat,
   forkChat,
+  exportChatTranscript,
+  listChatPins,
+  addChatPin,
+  deleteChatPin,
   uploadChatAttachment,
   getTaskFiles,
   getChatHistory,
@@ -128,7 +141,7 @@ import {
   readFile,
   updateNotes,
 } from "../../../api";
-import type { ChatSessionResponse, CustomAgentServer } from "../../../api";
+import type { ChatPin, ChatPinKind, ChatSessionResponse, CustomAgentServer } from "../../../api";
 import { listProjects, getProject, listResources, type ProjectListItem } from "../../../api/projects";
 import { openExternalUrl } from "../../../utils/openExternal";
 import { ansiToHtml, stripAnsi } from "../../../utils/ansi";
@@ -1977,6 +1990,9 @@ export function TaskChat({
   const [editTitleValue, setEditTitleValue] = useState("");
   const chatMenuRef = useRef<HTMLDivElement>(null);
   const [showAgentPicker, setShowAgentPicker] = useState(false);
+  /** Optional worktree subdirectory for the next chat created from the picker */
+  const [newChatSubdir, setNewChatSubdir] = useState("");
+  const [newChatError, setNewChatError] = useState<string | null>(null);
   const {
     baseAgents,
     customAgents,
@@ -3529,7 +3545,7 @@ export function TaskChat({
   // Forward-declared ref for connectChatWs so handlers defined before its
   // useCallback (e.g. the ChatListChanged refetch handler) can call it
   // without creating a TDZ reference that React Compiler refuses to compile.
-  const connectChatWsRef = useRef<(chatId: string) => Promise<void>>(
+  const connectChatWsRef = useRef<(chatId: string, sinceSeq?: number) => Promise<void>>(
     async () => {},
   );
 
@@ -3644,9 +3660,22 @@ export function TaskChat({
     onDisconnectedPropRef.current = onDisconnectedProp;
   }, [onConnectedProp, onDisconnectedProp]);
 
-  /** Connect a WebSocket for a given chat ID (idempotent) */
+  // Newest persisted history seq seen per chat (from GET /history and WS
+  // messages) — sent back as `since_seq` on reconnect so only missed events
+  // are replayed.
+  const historySeqRef = useRef<Map<string, number>>(new Map());
+  const bumpHistorySeq = useCallback((chatId: string, seq: number) => {
+    if (seq > (historySeqRef.current.get(chatId) ?? 0)) {
+      historySeqRef.current.set(chatId, seq);
+    }
+  }, []);
+  // Bumped on `history_reset` to re-run the history load for the active chat.
+  const [historyReloadNonce, setHistoryReloadNonce] = useState(0);
+
+  /** Connect a WebSocket for a given chat ID (idempotent). `sinceSeq` asks
+   *  the server to replay history persisted after that seq (reconnects). */
   const connectChatWs = useCallback(
-    async (chatId: string) => {
+    async (chatId: string, sinceSeq?: number) => {
       if (wsMapRef.current.has(chatId)) return; // Already connected
       if (connectingRef.current.has(chatId)) return; // Connection already in-flight
       // Terminal-mode chats have no ACP WebSocket — they speak PTY only.
@@ -3669,7 +3698,9 @@ export function TaskChat({
       const host = getApiHost();
       const protocol = window.location.protocol === "https:" ? "wss:" : "ws:";
       const url = await appendHmacToUrl(
-        `${protocol}//${host}/api/v1/projects/${projectId}/tasks/${task.id}/chats/${chatId}/ws`,
+        `${protocol}//${host}/api/v1/projects/${projectId}/tasks/${task.id}/chats/${chatId}/ws${
+          sinceSeq != null ? `?since_seq=${sinceSeq}` : ""
+        }`,
       );
 
       connectingRef.current.delete(chatId);
@@ -3697,6 +3728,18 @@ export function TaskChat({
           // "Resume session failed: ..." OR as the wrapped
           // "ACP session error: Internal error: \"Resume session failed: ...\"".
           // Substring match survives either format.
+          if (typeof data?.seq === "number") {
+            bumpHistorySeq(chatId, data.seq);
+          }
+          if (data?.type === "history_reset") {
+            // Missed too much (or history was compacted mid-message) to
+            // replay incrementally — reload the active chat from scratch.
+            // Inactive chats reload on their next switch anyway.
+            if (chatId === getActiveChatId()) {
+              setHistoryReloadNonce((n) => n + 1);
+            }
+            return;
+          }
           if (
             data?.type === "error" &&
             typeof data.message === "string" &&
@@ -3766,7 +3809,7 @@ export function TaskChat({
           const timer = setTimeout(() => {
             reconnectTimerRef.current.delete(chatId);
             if (!wsMapRef.current.has(chatId)) {
-              connectChatWsRef.current(chatId).then(() => {
+              connectChatWsRef.current(chatId, historySeqRef.current.get(chatId)).then(() => {
                 if (chatId === getActiveChatId()) {
                   wsRef.current = wsMapRef.current.get(chatId) ?? null;
                 }
@@ -3784,7 +3827,7 @@ export function TaskChat({
         }
       };
     },
-    [projectId, task.id, getActiveChatId],
+    [projectId, task.id, getActiveChatId, bumpHistorySeq],
   );
 
 
@@ -3824,6 +3867,12 @@ export function TaskChat({
         const buffered = wsEventBufferRef.current;
         wsEventBufferRef.current = [];
         historyLoadingRef.current = false;
+        // Reset (not bump) the replay cursor: a reload may follow a server
+        // restart where seqs started over.
+        historySeqRef.current.set(chatId, res.last_seq ?? 0);
+        for (const evt of buffered) {
+          if (typeof evt.seq === "number") bumpHistorySeq(chatId, evt.seq);
+        }
         // Reduce buffered message events into msgs locally (avoids React batching concerns)
         for (const evt of buffered) {
           msgs = reduceHistoryMessages(msgs, evt);
@@ -4035,7 +4084,7 @@ export function TaskChat({
     // loading after this effect's first run (when launch_mode was still
     // undefined → connectChatWs bailed), the effect re-fires with the
     // resolved mode and routes the chat correctly (ACP WS vs PTY-only).
-  }, [activeChatId, activeChat?.launch_mode, connectChatWs, projectId, task.id, updateBusy, chatRenderWindowSettings, updateHiddenMessageCount, getActiveChatId]);
+  }, [activeChatId, activeChat?.launch_mode, connectChatWs, projectId, task.id, updateBusy, chatRenderWindowSettings, updateHiddenMessageCount, getActiveChatId, historyReloadNonce, bumpHistorySeq]);
 
   // Cleanup all WebSockets on unmount, plus any pending reconnect timers —
   // otherwise an in-flight backoff timer fires after unmount and creates a
@@ -4729,21 +4778,29 @@ export function TaskChat({
 
   const handleNewChatWithAgent = useCallback(
     async (agent: string) => {
-      setShowAgentPicker(false);
+      setNewChatError(null);
       try {
         const newChat = await createChat(
           projectId,
           task.id,
           buildDefaultSessionTitle(),
           agent,
+          newChatSubdir.trim() || undefined,
         );
+        setShowAgentPicker(false);
+        setNewChatSubdir("");
         setChats((prev) => [...prev, newChat]);
         switchChat(newChat.id);
       } catch (err) {
         console.error("Failed to create chat:", err);
+        if (newChatSubdir.trim()) {
+          setNewChatError(err instanceof Error ? err.message : "Invalid working directory");
+        } else {
+          setShowAgentPicker(false);
+        }
       }
     },
-    [projectId, task.id, switchChat],
+    [projectId, task.id, switchChat, newChatSubdir],
   );
 
   // Listen for the two agent-related catalog dispatches:
@@ -4873,6 +4930,90 @@ export function TaskChat({
     [projectId, task.id, restoreChatState, setActiveChatId],
   );
 
+  // ─── Chat export ───────────────────────────────────────────────────────
+
+  /** 正在查看工具时间线的 chat */
+  const [timelineChat, setTimelineChat] = useState<{ id: string; title: string } | null>(null);
+  /** 导入 Claude Code / Codex session 的对话框 */
+  const [showImportSession, setShowImportSession] = useState(false);
+
+  // ─── Pinned messages ───────────────────────────────────────────────────
+
+  /** 当前 chat 的置顶消息 */
+  const [chatPins, setChatPins] = useState<ChatPin[]>([]);
+
+  useEffect(() => {
+    setChatPins([]);
+    if (!activeChatId) return;
+    let cancelled = false;
+    listChatPins(projectId, task.id, activeChatId)
+      .then((pins) => {
+        if (!cancelled) setChatPins(pins);
+      })
+      .catch((err) => console.error("Failed to load chat pins:", err));
+    return () => {
+      cancelled = true;
+    };
+  }, [projectId, task.id, activeChatId]);
+
+  /** Pin / 取消 pin 一条消息或工具结果 */
+  const togglePin = useCallback(
+    async (kind: ChatPinKind, text: string, toolCallId?: string) => {
+      if (!activeChatId) return;
+      const existing = chatPins.find((p) => pinMatches(p, kind, text, toolCallId));
+      try {
+        if (existing) {
+          await deleteChatPin(projectId, task.id, activeChatId, existing.id);
+          setChatPins((prev) => prev.filter((p) => p.id !== existing.id));
+        } else {
+          const pin = await addChatPin(projectId, task.id, activeChatId, {
+            kind,
+            text,
+            tool_call_id: toolCallId,
+          });
+          setChatPins((prev) => [...prev.filter((p) => p.id !== pin.id), pin]);
+        }
+      } catch (err) {
+        console.error("Failed to update chat pin:", err);
+      }
+    },
+    [projectId, task.id, activeChatId, chatPins],
+  );
+
+  const handleUnpin = useCallback(
+    (pin: ChatPin) => {
+      if (!activeChatId) return;
+      setChatPins((prev) => prev.filter((p) => p.id !== pin.id));
+      deleteChatPin(projectId, task.id, activeChatId, pin.id).catch((err) =>
+        console.error("Failed to unpin:", err),
+      );
+    },
+    [projectId, task.id, activeChatId],
+  );
+
+  /** 下载 chat transcript（含每个 turn 的复现清单）为 JSON 文件 */
+  const handleExportChat = useCallback(
+    async (chatId: string, title: string) => {
+      try {
+        const transcript = await exportChatTranscript(projectId, task.id, chatId);
+        const blob = new Blob([JSON.stringify(transcript, null, 2)], {
+          type: "application/json",
+        });
+        const url = URL.createObjectURL(blob);
+        const a = document.createElement("a");
+        a.href = url;
+        a.download = `${title.replace(/[^\w.-]+/g, "_").toLowerCase() || chatId}.transcript.json`;
+        document.body.appendChild(a);
+        a.click();
+        document.body.removeChild(a);
+        URL.revokeObjectURL(url);
+      } catch (err) {
+        console.error("Failed to export chat:", err);
+      }
+    },
+    [projectId, task.id],
+  );
+
   // ─── User actions ────────────────────────────────────────────────────────
 
   /** Check if the editable has any content (text, chips, or attachments) */
@@ -6941,6 +7082,28 @@ export function TaskChat({
       behavior: "smooth",
     });
   }, []);
+  /** 滚动到 pin 对应的消息；不在当前加载的对话中时返回 false */
+  const jumpToPin = useCallback(
+    (pin: ChatPin): boolean => {
+      const matchesMessage = (m: ChatMessage) =>
+        m.type === "tool"
+          ? pinMatches(pin, "tool", m.title, m.id)
+          : (m.type === "user" || m.type === "assistant" || m.type === "thinking") &&
+            pinMatches(pin, m.type, m.content);
+      const index = renderItems.findIndex((item) =>
+        item.kind === "single"
+          ? matchesMessage(item.message)
+          : item.kind === "work-summary"
+            ? item.items.some((i) => matchesMessage(i.message))
+            : item.tools.some((t) => matchesMessage(t.message)),
+      );
+      if (index < 0) return false;
+      autoStickToBottomRef.current = false;
+      virtuosoRef.current?.scrollToIndex({ index, align: "start", behavior: "smooth" });
+      return true;
+    },
+    [renderItems],
+  );
   const lastMessageType = messages[messages.length - 1]?.type;
 
   // Data-layer chat search — works across the full conversation, not just
@@ -6964,6 +7127,7 @@ export function TaskChat({
   // memoize Header/Footer/components on the only inputs that actually
   // affect their rendered output.
 
+  const hasPins = chatPins.length > 0;
   const VirtuosoHeader = useMemo(() => {
     const Header = () =>
       hiddenMessageCount > 0 ? (
@@ -6974,10 +7138,10 @@ export function TaskChat({
           </div>
         </div>
       ) : (
-        <div className="h-4" />
+        <div className={hasPins ? "h-12" : "h-4"} />
       );
     return Header;
-  }, [hiddenMessageCount]);
+  }, [hiddenMessageCount, hasPins]);
 
   const VirtuosoFooter = useMemo(() => {
     const showThinking =
@@ -7240,6 +7404,15 @@ export function TaskChat({
                         text={activeChat.title}
                         className="text-[13px] font-medium text-[var(--color-text)]"
                       />
+                      {activeChat.work_subdir && (
+                        <span
+                          className="flex shrink-0 items-center gap-1 rounded bg-[var(--color-bg-tertiary)] px-1.5 py-0.5 font-mono text-[10px] text-[var(--color-text-muted)]"
+                          title={`Agent runs in ${activeChat.work_subdir}/`}
+                        >
+                          <FolderOpen className="h-3 w-3" />
+                          {activeChat.work_subdir}/
+                        </span>
+                      )}
                       <ChevronDown className="h-3 w-3 shrink-0 text-[var(--color-text-muted)]" />
                     </button>
                   )}
@@ -7530,6 +7703,30 @@ export function TaskChat({
                           )}
                         </div>
                       </button>
+                      {!(
+                        editingTitle?.chatId === chat.id &&
+                        editingTitle.surface === "sidebar-list"
+                      ) && (
+                        <button
+                          onClick={() => void handleExportChat(chat.id, chat.title)}
+                          className="shrink-0 rounded p-0.5 text-[var(--color-text-muted)] opacity-0 transition-all hover:text-[var(--color-highlight)] group-hover:opacity-100"
+                          title="Export transcript (with per-turn manifests)"
+                        >
+                          <Download className="h-3 w-3" />
+                        </button>
+                      )}
+                      {!(
+                        editingTitle?.chatId === chat.id &&
+                        editingTitle.surface === "sidebar-list"
+                      ) && (
+                        <button
+                          onClick={() => setTimelineChat({ id: chat.id, title: chat.title })}
+                          className="shrink-0 rounded p-0.5 text-[var(--color-text-muted)] opacity-0 transition-all hover:text-[var(--color-highlight)] group-hover:opacity-100"
+                          title="Tool timeline"
+                        >
+                          <Activity className="h-3 w-3" />
+                        </button>
+                      )}
                       {chats.length > 1 &&
                         !(
                           editingTitle?.chatId === chat.id &&
@@ -7573,6 +7770,9 @@ export function TaskChat({
             activeMessageIndex={activeConversationTurnMessageIndex}
             onNavigate={navigateToConversationTurn}
           />
+          {!isTerminalLaunchMode && (
+            <PinnedMessagesPanel pins={chatPins} onJump={jumpToPin} onUnpin={handleUnpin} />
+          )}
           {/* Terminal launch mode: agent CLI runs in xterm.js (PTY).
               Chatbox input still writes lines to PTY stdin via handleSend. */}
           {isTerminalLaunchMode && agentPtyWsUrl ? (
@@ -7633,7 +7833,17 @@ export function TaskChat({
             totalListHeightChanged={handleTotalListHeightChanged}
             itemContent={(idx, item) =>
               item.kind === "single" ? (
-                <div className="mx-auto w-full max-w-[920px] px-4 pt-3 sm:px-6">
+                <div className="group/pin relative mx-auto w-full max-w-[920px] px-4 pt-3 sm:px-6">
+                  {(() => {
+                    const pinnable = pinnableMessage(item.message);
+                    if (!pinnable) return null;
+                    return (
+                      <PinButton
+                        pinned={chatPins.some((p) => pinMatches(p, pinnable.kind, pinnable.text))}
+                        onToggle={() => void togglePin(pinnable.kind, pinnable.text)}
+                      />
+                    );
+                  })()}
                   <MessageItem
                     message={item.message}
                     index={item.index}
@@ -7641,6 +7851,7 @@ export function TaskChat({
                     agentLabel={agentLabel}
                     projectId={projectId}
                     taskId={task.id}
+                    chatId={activeChatId ?? undefined}
                     isStudio={isStudioProject}
                     resolveSender={resolveSender}
                     onToggleThinkingCollapse={toggleThinkingCollapse}
@@ -7653,7 +7864,20 @@ export function TaskChat({
                   />
                 </div>
               ) : item.kind === "tool-section" ? (
-                <div className="mx-auto w-full max-w-[920px] px-4 pt-3 sm:px-6">
+                <div className="group/pin relative mx-auto w-full max-w-[920px] px-4 pt-3 sm:px-6">
+                  {(() => {
+                    // Pin the section's latest finished tool result
+                    const tool = [...item.tools].reverse().find((t) => t.message.content)?.message;
+                    if (!tool) return null;
+                    return (
+                      <PinButton
+                        pinned={chatPins.some((p) => p.tool_call_id === tool.id)}
+                        onToggle={() =>
+                          void togglePin("tool", `${tool.title}\n\n${tool.content ?? ""}`, tool.id)
+                        }
+                      />
+                    );
+                  })()}
                   <ToolSectionView
                     sectionId={item.sectionId}
                     tools={item.tools}
@@ -8685,6 +8909,27 @@ export function TaskChat({
         svgContent={lightboxSvg}
         onClose={() => { setLightboxUrl(null); setLightboxSvg(null); }}
       />
+      <ToolTimelineDialog
+        projectId={projectId}
+        taskId={task.id}
+        chatId={timelineChat?.id ?? null}
+        title={timelineChat?.title}
+        onClose={() => setTimelineChat(null)}
+      />
+      <ImportSessionDialog
+        isOpen={showImportSession}
+        projectId={projectId}
+        taskId={task.id}
+        onClose={() => setShowImportSession(false)}
+        onImported={(result) => {
+          // The chat list refetch (ChatListChanged) picks up the pending switch
+          window.dispatchEvent(
+            new CustomEvent("grove:switch-chat", {
+              detail: { projectId, taskId: task.id, chatId: result.chat_id },
+            }),
+          );
+        }}
+      />
       {showAgentPicker &&
         agentPickerAnchor &&
         typeof document !== "undefined" &&
@@ -8699,6 +8944,23 @@ export function TaskChat({
             }}
             className="min-w-48 max-h-64 overflow-y-auto rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] shadow-lg py-1"
           >
+            {!isStudioProject && (
+              <div className="border-b border-[var(--color-border)] px-2 pb-1.5 pt-0.5">
+                <label className="flex items-center gap-1.5 text-[11px] text-[var(--color-text-muted)]">
+                  <FolderOpen className="h-3 w-3 shrink-0" />
+                  <input
+                    value={newChatSubdir}
+                    onChange={(e) => { setNewChatSubdir(e.target.value); setNewChatError(null); }}
+                    placeholder="Working dir (e.g. frontend/)"
+                    className="min-w-0 flex-1 bg-transparent py-1 font-mono text-[11px] text-[var(--color-text)] outline-none"
+                    aria-label="Chat working directory"
+                  />
+                </label>
+                {newChatError && (
+                  <div className="px-0.5 text-[11px] text-[var(--color-error)]">{newChatError}</div>
+                )}
+              </div>
+            )}
             {!acpAvailabilityLoaded ? (
               <div className="flex items-center gap-2 px-3 py-2 text-sm text-[var(--color-text-muted)]">
                 <Loader2 className="w-3.5 h-3.5 animate-spin" /> Checking...
@@ -8713,6 +8975,15 @@ export function TaskChat({
                 onSelectId={(id) => handleNewChatWithAgent(id)}
               />
             )}
+            {!isStudioProject && (
+              <button
+                type="button"
+                onClick={() => { setShowAgentPicker(false); setShowImportSession(true); }}
+                className="mt-1 flex w-full items-center gap-2 border-t border-[var(--color-border)] px-3 pb-1 pt-2 text-left text-xs text-[var(--color-text-muted)] hover:text-[var(--color-text)]"
+              >
+                <History className="h-3.5 w-3.5" /> Import Claude Code / Codex session…
+              </button>
+            )}
           </div>,
           document.body,
         )}
@@ -8722,6 +8993,35 @@ export function TaskChat({
 
 // ─── Sub-components ──────────────────────────────────────────────────────────
 
+/** Kind and text to pin for a message row; null when it can't be pinned */
+function pinnableMessage(message: ChatMessage): { kind: ChatPinKind; text: string } | null {
+  if (message.type === "user" && !message.terminal && message.content.trim()) {
+    return { kind: "user", text: message.content };
+  }
+  if (message.type === "assistant" && message.complete && message.content.trim()) {
+    return { kind: "assistant", text: message.content };
+  }
+  return null;
+}
+
+/** Hover pin toggle shown at the top-right of a message row */
+function PinButton({ pinned, onToggle }: { pinned: boolean; onToggle: () => void }) {
+  return (
+    <button
+      type="button"
+      onClick={onToggle}
+      className={`absolute right-4 top-3 z-[1] rounded p-1 transition-opacity sm:right-6 ${
+        pinned
+          ? "text-[var(--color-highlight)] opacity-100"
+          : "text-[var(--color-text-muted)] opacity-0 hover:text-[var(--color-highlight)] group-hover/pin:opacity-100"
+      }`}
+      title={pinned ? "Unpin" : "Pin message"}
+    >
+      <Pin className={`h-3 w-3 ${pinned ? "fill-current" : ""}`} />
+    </button>
+  );
+}
+
 /** Reusable dropdown selector for bottom toolbar */
 const DropdownSelect = ({
   ref,
@@ -9123,6 +9423,7 @@ const MessageItem = memo(function MessageItem({
   agentLabel,
   projectId,
   taskId,
+  chatId,
   isStudio,
   onToggleThinkingCollapse,
   onPermissionResponse,
@@ -9139,6 +9440,7 @@ const MessageItem = memo(function MessageItem({
   agentLabel?: string;
   projectId: string;
   taskId: string;
+  chatId?: string;
   isStudio: boolean;
   onToggleThinkingCollapse: (index: number) => void;
   onPermissionResponse?: (optionId: string, requestId: string) => void;
@@ -9341,6 +9643,14 @@ const MessageItem = memo(function MessageItem({
                 endTs={message.endTs}
               />
             )}
+            {message.complete && message.startTs != null && chatId && !isStudio && (
+              <TurnChangesMeta
+                projectId={projectId}
+                taskId={taskId}
+                chatId={chatId}
+                turnTs={message.startTs}
+              />
+            )}
           </div>
         </div>
       );
@@ -1,7 +1,7 @@
 import { useState, useEffect, useCallback, useRef } from "react";
 import Editor, { type Monaco } from "@monaco-editor/react";
-import { X, FileCode, Eye, Columns2, Loader2, Save, Maximize2, Minimize2, PanelLeftOpen, PanelLeftClose, RefreshCw, AlertCircle, ZoomIn, ZoomOut } from "lucide-react";
-import { Button, getPreviewType, ImageLightbox } from "../../ui";
+import { X, FileCode, Eye, Columns2, Loader2, Save, Maximize2, Minimize2, PanelLeftOpen, PanelLeftClose, RefreshCw, AlertCircle, ZoomIn, ZoomOut, History } from "lucide-react";
+import { Button, downloadViaIframe, getPreviewType, ImageLightbox } from "../../ui";
 import { getPreviewRenderer } from "../../Review/previewRenderers";
 
 function rewriteHtmlUrls(html: string, projectId: string, taskId: string, parentDir: string): string {
@@ -34,13 +34,19 @@ import {
   createDirectory,
   deleteFileOrDir,
   moveFileOrDir,
+  uploadTaskFiles,
+  taskFileDownloadUrl,
   openTaskFile,
   lookupSymbol,
   getTask,
   getConfig,
 } from "../../../api";
-import type { DirEntry } from "../../../api";
+import type { DirEntry, FileConflict, FileVersion } from "../../../api";
 import { FileContextMenu, type ContextMenuPosition, type ContextMenuTarget } from "./FileContextMenu";
+import { RenamePathDialog } from "../dialogs";
+import { FileConflictDialog } from "./FileConflictDialog";
+import { FileHistoryPanel } from "./FileHistoryPanel";
+import { FileOutlineMenu } from "./FileOutlineMenu";
 import { ConfirmDialog } from "../../Dialogs/ConfirmDialog";
 import { useCommand, useDefineCommand, useContextKey } from "../../../keyboard";
 import "./task-editor.css";
@@ -460,6 +466,12 @@ const isReadOnlyPreview = (path: string) => {
   return t === 'image' || t === 'binary';
 };
 
+// A save rejected because the file changed on disk since it was loaded.
+const isSaveConflict = (err: unknown): err is { status: 409; data: FileConflict } => {
+  const e = err as { status?: number; data?: unknown } | null;
+  return e?.status === 409 && typeof e.data === 'object' && e.data !== null;
+};
+
 export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onToggleFullscreen, hideHeader = false }: TaskEditorProps) {
   const { isMobile } = useIsMobile();
   const { theme } = useTheme();
@@ -623,6 +635,8 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
   const [modified, setModified] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [viewMode, setViewMode] = useState<'code' | 'preview' | 'split'>('code');
+  // Commit history of the open file replaces the editor while shown
+  const [historyOpen, setHistoryOpen] = useState(false);
   const [contentVersion, setContentVersion] = useState(0);
   const [lightboxUrl, setLightboxUrl] = useState<string | null>(null);
   const [lightboxSvg, setLightboxSvg] = useState<string | null>(null);
@@ -696,7 +710,13 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
     return () => ac.abort();
   }, [projectId, taskId]);
 
+  // Version of the open file as loaded (hash + content), sent with saves so
+  // edits made on disk meanwhile (e.g. by the agent) are not clobbered.
+  const fileVersionRef = useRef<FileVersion | null>(null);
+  const [conflict, setConflict] = useState<FileConflict | null>(null);
+
   // Inline creation state
+  const [renameTarget, setRenameTarget] = useState<string | null>(null);
   const [creatingPath, setCreatingPath] = useState<{ type: 'file' | 'directory'; parentPath: string; depth: number } | null>(null);
 
   // Delete confirmation
@@ -726,8 +746,12 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
     // and the unmount auto-save would never save the old file's edits.
     if (modified && selectedFile && !isReadOnlyPreview(selectedFile)) {
       try {
-        await writeFileContent(projectId, taskId, selectedFile, editorContentRef.current);
+        await writeFileContent(projectId, taskId, selectedFile, editorContentRef.current, fileVersionRef.current);
       } catch (err) {
+        if (isSaveConflict(err)) {
+          setConflict(err.data);
+          return; // abort the switch; the conflict dialog takes over
+        }
         const msg = err instanceof Error ? err.message :
           (err as { message?: string })?.message || 'Failed to save file before switch';
         setError(msg);
@@ -755,6 +779,7 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
       const res = await getFileContent(projectId, taskId, path);
       setFileContent(res.content);
       editorContentRef.current = res.content;
+      fileVersionRef.current = { hash: res.hash, content: res.content };
     } catch (err) {
       const msg = err instanceof Error ? err.message :
         (err as { message?: string })?.message || 'Failed to load file';
@@ -782,6 +807,15 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
     editor.focus();
   }, [selectedFile, fileContent]);
 
+  // Outline positions are 0-indexed like the lookup endpoint.
+  const handleOutlineJump = useCallback((line: number, col: number) => {
+    const editor = editorRef.current;
+    if (!editor) return;
+    editor.revealLineInCenter(line + 1);
+    editor.setPosition({ lineNumber: line + 1, column: col + 1 });
+    editor.focus();
+  }, []);
+
   // handleSelectFile is closed over by the DefinitionProvider registered
   // on Monaco mount. The provider is registered once but needs to see
   // the latest handleSelectFile, so we mirror it through a ref.
@@ -815,10 +849,16 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
 
     setSaving(true);
     try {
-      await writeFileContent(projectId, taskId, selectedFile, editorContentRef.current);
+      const res = await writeFileContent(projectId, taskId, selectedFile, editorContentRef.current, fileVersionRef.current);
+      fileVersionRef.current = { hash: res.hash, content: res.content };
       setFileContent(editorContentRef.current);
       setModified(false);
     } catch (err) {
+      if (isSaveConflict(err)) {
+        setConflict(err.data);
+        setSaving(false);
+        return;
+      }
       const msg = err instanceof Error ? err.message :
         (err as { message?: string })?.message || 'Failed to save file';
       setError(msg);
@@ -853,12 +893,13 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
   // its child layouts on task switch (TaskView.tsx key={projectId-taskId}),
   // which would otherwise silently discard typed content. Fire-and-forget
   // because unmount can't await; failures are surfaced only in the console.
-  const unmountSaveRef = useRef<{ projectId: string; taskId: string; selectedFile: string | null; modified: boolean; content: string }>({
+  const unmountSaveRef = useRef<{ projectId: string; taskId: string; selectedFile: string | null; modified: boolean; content: string; version: FileVersion | null }>({
     projectId,
     taskId,
     selectedFile,
     modified: false,
     content: '',
+    version: null,
   });
   useEffect(() => {
     unmountSaveRef.current = {
@@ -867,6 +908,7 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
       selectedFile,
       modified,
       content: editorContentRef.current,
+      version: fileVersionRef.current,
     };
   });
   useEffect(() => {
@@ -874,12 +916,52 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
       const snap = unmountSaveRef.current;
       if (!snap.modified || !snap.selectedFile) return;
       if (isReadOnlyPreview(snap.selectedFile)) return;
-      writeFileContent(snap.projectId, snap.taskId, snap.selectedFile, snap.content).catch((err) => {
+      // A conflicting on-disk change wins here; the 409 is only logged.
+      writeFileContent(snap.projectId, snap.taskId, snap.selectedFile, snap.content, snap.version).catch((err) => {
         console.warn('TaskEditor: failed to auto-save on unmount', snap.selectedFile, err);
       });
     };
   }, []);
 
+  // Conflict resolution: the disk content becomes the new base either way
+  const handleConflictUseMerged = useCallback((merged: string) => {
+    if (!conflict) return;
+    fileVersionRef.current = { hash: conflict.current_hash, content: conflict.theirs };
+    setFileContent(merged);
+    editorContentRef.current = merged;
+    setModified(true);
+    setConflict(null);
+  }, [conflict]);
+
+  const handleConflictUseDisk = useCallback(() => {
+    if (!conflict) return;
+    fileVersionRef.current = { hash: conflict.current_hash, content: conflict.theirs };
+    setFileContent(conflict.theirs);
+    editorContentRef.current = conflict.theirs;
+    setModified(false);
+    setConflict(null);
+  }, [conflict]);
+
+  const handleConflictKeepMine = useCallback(async () => {
+    if (!conflict) return;
+    const version = { hash: conflict.current_hash, content: conflict.theirs };
+    setConflict(null);
+    try {
+      const res = await writeFileContent(projectId, taskId, conflict.path, editorContentRef.current, version);
+      fileVersionRef.current = { hash: res.hash, content: res.content };
+      setFileContent(editorContentRef.current);
+      setModified(false);
+    } catch (err) {
+      if (isSaveConflict(err)) {
+        setConflict(err.data);
+        return;
+      }
+      const msg = err instanceof Error ? err.message :
+        (err as { message?: string })?.message || 'Failed to save file';
+      setError(msg);
+    }
+  }, [conflict, projectId, taskId]);
+
   // Internal: used by create/delete handlers
   const reloadFiles = useCallback(async () => {
     try {
@@ -912,6 +994,7 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
           const fileRes = await getFileContent(projectId, taskId, selectedFile);
           setFileContent(fileRes.content);
           editorContentRef.current = fileRes.content;
+          fileVersionRef.current = { hash: fileRes.hash, content: fileRes.content };
           setModified(false);
         }
       }
@@ -955,6 +1038,11 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
     setConfirmDialogOpen(true);
   }, []);
 
+  // Rename handler (opens the rename dialog)
+  const handleRename = useCallback((path: string) => {
+    setRenameTarget(path);
+  }, []);
+
   // Copy path handlers
   const handleCopyRelativePath = useCallback((path: string) => {
     navigator.clipboard.writeText(path).catch((err) => {
@@ -977,6 +1065,11 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
     });
   }, [projectId, taskId]);
 
+  // Download through the browser (any file type)
+  const handleDownload = useCallback((path: string) => {
+    downloadViaIframe(taskFileDownloadUrl(projectId, taskId, path), path.split('/').pop());
+  }, [projectId, taskId]);
+
   // Create file submit handler
   const handleCreateFile = useCallback(async (path: string) => {
     try {
@@ -996,6 +1089,7 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
       const res = await getFileContent(projectId, taskId, path);
       setFileContent(res.content);
       editorContentRef.current = res.content;
+      fileVersionRef.current = { hash: res.hash, content: res.content };
     } catch (err) {
       const msg = err instanceof Error ? err.message : String(err);
       setError(msg);
@@ -1031,27 +1125,40 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
     }
   }, [projectId, taskId, reloadFiles, selectedFile]);
 
-  // Upload/dropped OS file callback
+  // Submit rename dialog (a rename is a move within the worktree)
+  const handleConfirmRename = useCallback(async (destination: string) => {
+    const source = renameTarget;
+    setRenameTarget(null);
+    if (!source) return;
+    await handleMoveFile(source, destination);
+  }, [renameTarget, handleMoveFile]);
+
+  // Upload/dropped OS file callback (binary-safe multipart upload)
   const handleUploadFile = useCallback(async (parentPath: string, file: File) => {
     try {
-      // Read the file as text
-      const content = await file.text();
-      const fileName = file.name;
-      const fullPath = parentPath ? `${parentPath}/${fileName}` : fileName;
-
-      await createFile(projectId, taskId, fullPath, content);
+      const [uploaded] = await uploadTaskFiles(projectId, taskId, parentPath, [file]);
+      const fullPath = uploaded?.path ?? (parentPath ? `${parentPath}/${file.name}` : file.name);
       await reloadFiles();
-      
+
       // Select newly uploaded file
       setSelectedFile(fullPath);
       setLoading(true);
       setModified(false);
       setError(null);
       setViewMode('code');
-      
+
+      // Images and binary formats are previewed from the raw URL
+      if (isReadOnlyPreview(fullPath)) {
+        setFileContent('');
+        editorContentRef.current = '';
+        fileVersionRef.current = null;
+        return;
+      }
+
       const res = await getFileContent(projectId, taskId, fullPath);
       setFileContent(res.content);
       editorContentRef.current = res.content;
+      fileVersionRef.current = { hash: res.hash, content: res.content };
     } catch (err) {
       const msg = err instanceof Error ? err.message : String(err);
       setError(`Failed to upload file: ${msg}`);
@@ -1330,6 +1437,22 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
                     </button>
                   </div>
                 )}
+                {selectedFile && !historyOpen && !(viewMode === 'preview' && isPreviewable) && (
+                  <FileOutlineMenu
+                    projectId={projectId}
+                    taskId={taskId}
+                    path={selectedFile}
+                    onJump={handleOutlineJump}
+                  />
+                )}
+                <button
+                  onClick={() => setHistoryOpen((v) => !v)}
+                  className={`px-2 h-7 rounded-md border border-[var(--color-border)] transition-colors flex items-center gap-1 text-xs font-medium cursor-pointer ${historyOpen ? 'bg-[var(--color-bg-tertiary)] text-[var(--color-text)]' : 'bg-[var(--color-bg)] text-[var(--color-text-muted)] hover:text-[var(--color-text)]'}`}
+                  title="Commits touching this file"
+                >
+                  <History className="w-3.5 h-3.5" />
+                  History
+                </button>
                 <div className="flex items-center border border-[var(--color-border)] rounded-md bg-[var(--color-bg)] h-7 p-0.5 ml-1">
                   <button
                     onClick={handleZoomOut}
@@ -1375,6 +1498,8 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
                 </p>
               </div>
             </div>
+          ) : selectedFile && historyOpen ? (
+            <FileHistoryPanel projectId={projectId} taskId={taskId} path={selectedFile} />
           ) : selectedFile ? (
             isReadOnlyPreview(selectedFile) ? (
               <div 
@@ -1598,10 +1723,30 @@ export function TaskEditor({ projectId, taskId, onClose, fullscreen = false, onT
         onClose={() => setContextMenuOpen(false)}
         onNewFile={handleNewFile}
         onNewDirectory={handleNewDirectory}
+        onRename={handleRename}
         onDelete={handleDelete}
         onCopyRelativePath={handleCopyRelativePath}
         onCopyFullPath={handleCopyFullPath}
         onOpenInApp={handleOpenInApp}
+        onDownload={handleDownload}
+      />
+
+      {/* Rename / move dialog */}
+      <RenamePathDialog
+        key={renameTarget ?? ''}
+        isOpen={renameTarget !== null}
+        path={renameTarget ?? ''}
+        onClose={() => setRenameTarget(null)}
+        onRename={handleConfirmRename}
+      />
+
+      {/* Save conflict (file changed on disk since it was loaded) */}
+      <FileConflictDialog
+        conflict={conflict}
+        onClose={() => setConflict(null)}
+        onUseMerged={handleConflictUseMerged}
+        onUseDisk={handleConflictUseDisk}
+        onKeepMine={handleConflictKeepMine}
       />
 
       {/* Confirm Dialog for deletion */}
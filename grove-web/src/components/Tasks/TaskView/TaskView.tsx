@@ -25,6 +25,9 @@ import { patchConfig } from "../../../api/config";
 import { useConfig } from "../../../context";
 import { useCommand, useKeyboardScope, useContextKey, useVoiceControlContext } from "../../../keyboard";
 import { usePluginPanelCommands } from "../../Plugins/pluginPanelCommands";
+import { HandbackMenu } from "./HandbackMenu";
+import { CopyMenu } from "./CopyMenu";
+import { OpenWithMenu } from "./OpenWithMenu";
 
 // --- Workspace Bar Dropdown (for overflow actions) ---
 function OverflowDropdown({ items }: { items: OverflowItem[] }) {
@@ -429,6 +432,9 @@ export const TaskView = forwardRef<TaskViewHandle, TaskViewProps>((props, ref) =
 
   const workspaceActions = useMemo(() => (
     <div className="flex items-center gap-1 shrink-0">
+      {!isArchived && <HandbackMenu projectId={projectId} task={task} />}
+      <CopyMenu projectId={projectId} task={task} />
+      {!isArchived && <OpenWithMenu projectId={projectId} task={task} />}
       {onCommit && (
         <button
           onClick={onCommit}
@@ -466,7 +472,7 @@ export const TaskView = forwardRef<TaskViewHandle, TaskViewProps>((props, ref) =
       <div className="w-px h-4 bg-[var(--color-border)] mx-1" />
       {overflowItems.length > 0 && <OverflowDropdown items={overflowItems} />}
     </div>
-  ), [onCommit, onMerge, onSync, canOperate, isLocal, isArchived, overflowItems]);
+  ), [projectId, task, onCommit, onMerge, onSync, canOperate, isLocal, isArchived, overflowItems]);
 
   return (
     <div className={`flex-1 flex flex-col h-full overflow-hidden ${fullscreen ? 'fixed inset-0 z-50 bg-[var(--color-bg)]' : ''}`}>
@@ -516,6 +522,10 @@ export const TaskView = forwardRef<TaskViewHandle, TaskViewProps>((props, ref) =
 
         {/* Right: Git Actions + Overflow + CmdK + Fullscreen */}
         <div className="flex items-center gap-1 shrink-0">
+          {!isArchived && <HandbackMenu projectId={projectId} task={task} />}
+          <CopyMenu projectId={projectId} task={task} />
+          {!isArchived && <OpenWithMenu projectId={projectId} task={task} />}
+
           {/* Git Actions — direct buttons (omitted on non-git projects) */}
           {onCommit && (
             <button
@@ -0,0 +1,155 @@
+import { useEffect, useState } from "react";
+import { Activity, X } from "lucide-react";
+import { DialogShell } from "../../ui/DialogShell";
+import { getChatTimeline, type TurnTimeline, type ToolSpan } from "../../../api";
+
+interface ToolTimelineDialogProps {
+  projectId: string;
+  taskId: string;
+  /** Chat to show; null closes the dialog */
+  chatId: string | null;
+  title?: string;
+  onClose: () => void;
+}
+
+/** Tool calls at or above this duration are highlighted as slow. */
+const SLOW_TOOL_MS = 10_000;
+
+function formatMs(ms: number): string {
+  if (ms < 1000) return `${ms}ms`;
+  if (ms < 60_000) return `${(ms / 1000).toFixed(1)}s`;
+  return `${Math.floor(ms / 60_000)}m ${Math.round((ms % 60_000) / 1000)}s`;
+}
+
+function barColor(span: ToolSpan): string {
+  if (span.status === "failed" || span.status === "error") return "var(--color-error)";
+  if (span.duration_ms == null) return "var(--color-text-muted)";
+  if (span.duration_ms >= SLOW_TOOL_MS) return "var(--color-warning)";
+  return "var(--color-highlight)";
+}
+
+function TurnRow({ turn }: { turn: TurnTimeline }) {
+  const start = Math.min(...turn.tools.map((t) => t.start_ms));
+  const end = Math.max(
+    ...turn.tools.map((t) => t.end_ms ?? t.start_ms),
+    turn.end_ts != null ? turn.end_ts * 1000 : 0,
+  );
+  const span = Math.max(end - start, 1);
+  const wall = turn.start_ts != null && turn.end_ts != null ? (turn.end_ts - turn.start_ts) * 1000 : null;
+  const slow = turn.tools.filter((t) => (t.duration_ms ?? 0) >= SLOW_TOOL_MS);
+
+  return (
+    <div className="space-y-1.5">
+      <div className="flex items-baseline justify-between gap-3">
+        <span className="truncate text-xs text-[var(--color-text)]" title={turn.prompt}>
+          #{turn.index + 1} {turn.prompt || "(no prompt)"}
+        </span>
+        <span className="shrink-0 text-[10px] tabular-nums text-[var(--color-text-muted)]">
+          {turn.tools.length} tools · max {turn.max_parallel} parallel · tool time {formatMs(turn.tool_time_ms)}
+          {wall != null && ` · wall ${formatMs(wall)}`}
+        </span>
+      </div>
+      <div
+        className="relative rounded bg-[var(--color-bg-tertiary)]"
+        style={{ height: `${Math.max(turn.max_parallel, 1) * 14 + 4}px` }}
+      >
+        {turn.tools.map((tool) => {
+          const left = ((tool.start_ms - start) / span) * 100;
+          const width = (((tool.end_ms ?? end) - tool.start_ms) / span) * 100;
+          return (
+            <div
+              key={tool.id}
+              className="absolute h-[10px] rounded-sm"
+              style={{
+                left: `${left}%`,
+                width: `max(${width}%, 2px)`,
+                top: `${tool.lane * 14 + 2}px`,
+                background: barColor(tool),
+                opacity: tool.end_ms == null ? 0.5 : 0.85,
+              }}
+              title={`${tool.title || tool.id} — ${
+                tool.duration_ms != null ? formatMs(tool.duration_ms) : "unfinished"
+              }${tool.status ? ` (${tool.status})` : ""}`}
+            />
+          );
+        })}
+      </div>
+      {slow.length > 0 && (
+        <div className="text-[10px] text-[var(--color-warning)]">
+          Slow:{" "}
+          {slow
+            .map((t) => `${t.title || t.id} (${formatMs(t.duration_ms ?? 0)})`)
+            .join(", ")}
+        </div>
+      )}
+    </div>
+  );
+}
+
+/**
+ * Gantt-like view of the tool calls in each turn of a chat — overlapping
+ * calls are stacked in lanes so parallelism and slow tools are visible.
+ */
+export function ToolTimelineDialog({
+  projectId,
+  taskId,
+  chatId,
+  title,
+  onClose,
+}: ToolTimelineDialogProps) {
+  const [turns, setTurns] = useState<TurnTimeline[] | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!chatId) return;
+    let cancelled = false;
+    setTurns(null);
+    setError(null);
+    getChatTimeline(projectId, taskId, chatId)
+      .then((res) => {
+        if (!cancelled) setTurns(res.filter((t) => t.tools.length > 0));
+      })
+      .catch((err) => {
+        if (!cancelled) setError(err instanceof Error ? err.message : "Failed to load timeline");
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [projectId, taskId, chatId]);
+
+  return (
+    <DialogShell isOpen={chatId !== null} onClose={onClose} maxWidth="max-w-3xl">
+      <div className="bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded-xl shadow-xl overflow-hidden">
+        <div className="flex items-center justify-between px-5 py-4 border-b border-[var(--color-border)]">
+          <div className="flex items-center gap-3 min-w-0">
+            <div className="w-9 h-9 rounded-lg flex items-center justify-center bg-[var(--color-highlight)]/10">
+              <Activity className="w-5 h-5 text-[var(--color-highlight)]" />
+            </div>
+            <div className="min-w-0">
+              <h2 className="text-lg font-semibold text-[var(--color-text)]">Tool timeline</h2>
+              {title && <p className="truncate text-xs text-[var(--color-text-muted)]">{title}</p>}
+            </div>
+          </div>
+          <button
+            type="button"
+            onClick={onClose}
+            aria-label="Close"
+            className="p-1.5 rounded-lg hover:bg-[var(--color-bg-tertiary)] text-[var(--color-text-muted)] transition-colors"
+          >
+            <X className="w-5 h-5" />
+          </button>
+        </div>
+        <div className="max-h-[65vh] overflow-y-auto px-5 py-4 space-y-4">
+          {error && <p className="text-sm text-[var(--color-error)]">{error}</p>}
+          {!error && turns === null && (
+            <p className="text-sm text-[var(--color-text-muted)]">Loading…</p>
+          )}
+          {turns?.length === 0 && (
+            <p className="text-sm text-[var(--color-text-muted)]">No timed tool calls in this chat yet.</p>
+          )}
+          {turns?.map((turn) => <TurnRow key={turn.index} turn={turn} />)}
+        </div>
+      </div>
+    </DialogShell>
+  );
+}
@@ -0,0 +1,95 @@
+import { useState } from "react";
+import { FileDiff, Undo2, Loader2 } from "lucide-react";
+import { getTurnDiff, revertTurn, type TurnDiff } from "../../../api";
+
+interface TurnChangesMetaProps {
+  projectId: string;
+  taskId: string;
+  chatId: string;
+  /** Turn start (unix seconds) — keys the snapshot taken before the turn. */
+  turnTs: number;
+}
+
+/**
+ * Low-contrast row beneath a finished turn: expands the files that turn
+ * changed (from the before/after worktree snapshots) and lets the user
+ * revert just that turn. Revert needs a second click to confirm.
+ */
+export function TurnChangesMeta({ projectId, taskId, chatId, turnTs }: TurnChangesMetaProps) {
+  const [diff, setDiff] = useState<TurnDiff | null>(null);
+  const [open, setOpen] = useState(false);
+  const [loading, setLoading] = useState(false);
+  const [confirming, setConfirming] = useState(false);
+  const [status, setStatus] = useState<string | null>(null);
+
+  const toggle = async () => {
+    if (open) {
+      setOpen(false);
+      return;
+    }
+    setLoading(true);
+    try {
+      setDiff(await getTurnDiff(projectId, taskId, chatId, turnTs));
+      setOpen(true);
+    } catch {
+      setStatus("No snapshot for this turn");
+    }
+    setLoading(false);
+  };
+
+  const revert = async () => {
+    if (!confirming) {
+      setConfirming(true);
+      return;
+    }
+    setConfirming(false);
+    setLoading(true);
+    try {
+      const res = await revertTurn(projectId, taskId, chatId, turnTs);
+      setStatus(res.message);
+    } catch {
+      setStatus("Revert failed");
+    }
+    setLoading(false);
+  };
+
+  return (
+    <div className="mt-1 text-[10px] text-[var(--color-text-muted)] select-none">
+      <div className="flex items-center gap-3">
+        <button
+          type="button"
+          onClick={toggle}
+          className="flex items-center gap-1 hover:text-[var(--color-text)] transition-colors"
+        >
+          {loading ? <Loader2 className="w-3 h-3 animate-spin" /> : <FileDiff className="w-3 h-3" />}
+          {open && diff ? `${diff.files.length} file${diff.files.length === 1 ? "" : "s"} changed` : "Changes"}
+        </button>
+        {open && diff && diff.files.length > 0 && (
+          <button
+            type="button"
+            onClick={revert}
+            onBlur={() => setConfirming(false)}
+            className={`flex items-center gap-1 transition-colors ${
+              confirming ? "text-[var(--color-error)]" : "hover:text-[var(--color-text)]"
+            }`}
+          >
+            <Undo2 className="w-3 h-3" />
+            {confirming ? "Confirm revert?" : "Revert turn"}
+          </button>
+        )}
+        {status && <span>{status}</span>}
+      </div>
+      {open && diff && diff.files.length > 0 && (
+        <ul className="mt-1 space-y-0.5 font-mono tabular-nums">
+          {diff.files.map((f) => (
+            <li key={f.path} className="flex gap-2">
+              <span className="text-[var(--color-success)]">+{f.additions}</span>
+              <span className="text-[var(--color-error)]">-{f.deletions}</span>
+              <span className="truncate">{f.path}</span>
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+}
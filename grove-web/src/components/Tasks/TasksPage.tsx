@@ -1,11 +1,12 @@
 import { useState, useMemo, useCallback, useEffect, useLayoutEffect, useRef } from "react";
 import { motion, AnimatePresence } from "framer-motion";
-import { Plus, ArrowLeft, GitBranch } from "lucide-react";
+import { Plus, ArrowLeft, GitBranch, FolderGit2, Users } from "lucide-react";
 import { TaskSidebar } from "./TaskSidebar/TaskSidebar";
 import { TaskInfoPanel } from "./TaskInfoPanel";
 import { TaskView, type TaskViewHandle } from "./TaskView";
 import { NewTaskDialog } from "./NewTaskDialog";
 import { TaskOperationDialogs } from "./TaskOperationDialogs";
+import { AdoptWorktreesDialog, SaveTaskViewDialog, TeamTasksDialog } from "./dialogs";
 import { Button } from "../ui";
 import { ContextMenu } from "../ui/ContextMenu";
 import { useProject, useCommandPalette } from "../../context";
@@ -23,9 +24,16 @@ import { useCommand, useDefineCommand, useKeyboardScope, useHelpKeyDisplay, cont
 import {
   createTask as apiCreateTask,
   recoverTask as apiRecoverTask,
+  setTaskPinned,
   listTasks as apiListTasks,
+  listTaskViews,
+  saveTaskView,
+  deleteTaskView,
   initGitRepo,
+  getOrgStatus,
+  getTaskRisks,
 } from "../../api";
+import type { TaskSort, TaskView as SavedTaskView, RiskAssessment } from "../../api";
 import type { Task, TaskFilter } from "../../data/types";
 import { convertTaskResponse } from "../../utils/taskConvert";
 import { fuzzyFindByName } from "../../utils/fuzzySearch";
@@ -71,6 +79,13 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
   // Mobile: whether the detail view is showing (stacked navigation)
   const [mobileShowDetail, setMobileShowDetail] = useState(false);
   const [showNewTaskDialog, setShowNewTaskDialog] = useState(initialOpenNewTask ?? false);
+  const [showAdoptDialog, setShowAdoptDialog] = useState(false);
+  const [showTeamDialog, setShowTeamDialog] = useState(false);
+  // Organization mode: only offer the Team view when a team server is configured
+  const [orgConfigured, setOrgConfigured] = useState(false);
+  useEffect(() => {
+    getOrgStatus().then((s) => setOrgConfigured(s.configured)).catch(() => {});
+  }, []);
   useEffect(() => {
     if (initialOpenNewTask) {
       Promise.resolve().then(() => {
@@ -83,6 +98,13 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
   const [createError, setCreateError] = useState<string | null>(null);
   const [archivedTasks, setArchivedTasks] = useState<Task[]>([]);
   const [isLoadingArchived, setIsLoadingArchived] = useState(false);
+  // Custom sort / saved views: when either is active the list comes from the
+  // server (`?sort=&view=`) instead of the project snapshot.
+  const [taskSort, setTaskSort] = useState<TaskSort>("activity");
+  const [savedViews, setSavedViews] = useState<SavedTaskView[]>([]);
+  const [activeView, setActiveView] = useState<string | null>(null);
+  const [viewTasks, setViewTasks] = useState<Task[] | null>(null);
+  const [showSaveView, setShowSaveView] = useState(false);
   const [isFullscreen, setIsFullscreen] = useState(false);
   // Mirror fullscreen state onto <body> so the sidebar (lives in App.tsx,
   // out of TasksPage's tree) can be hidden via CSS. Otherwise toggling
@@ -160,13 +182,107 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
     return () => { cancelled = true; };
   }, [filter, selectedProject]);
 
+  // Risk badges: scored server-side per task (cached), refreshed with the project
+  const [taskRisks, setTaskRisks] = useState<Record<string, RiskAssessment>>({});
+  useEffect(() => {
+    let cancelled = false;
+    if (!selectedProject || isStudio) return;
+    getTaskRisks(selectedProject.id)
+      .then((risks) => { if (!cancelled) setTaskRisks(risks); })
+      .catch(() => { if (!cancelled) setTaskRisks({}); });
+    return () => { cancelled = true; };
+  }, [selectedProject, isStudio]);
+
+  // Load saved views when the project changes
+  const projectIdForViews = selectedProject?.id;
+  useEffect(() => {
+    let cancelled = false;
+    Promise.resolve().then(() => {
+      if (cancelled) return;
+      setActiveView(null);
+      setTaskSort("activity");
+      setSavedViews([]);
+    });
+    if (!projectIdForViews || isStudio) return () => { cancelled = true; };
+    listTaskViews(projectIdForViews)
+      .then((views) => { if (!cancelled) setSavedViews(views); })
+      .catch((err) => console.error("Failed to load task views:", err));
+    return () => { cancelled = true; };
+  }, [projectIdForViews, isStudio]);
+
+  // Fetch the sorted / view-filtered list; refetches whenever the project
+  // snapshot refreshes so status changes show up.
+  useEffect(() => {
+    let cancelled = false;
+    if (!selectedProject || (!activeView && taskSort === "activity")) {
+      Promise.resolve().then(() => { if (!cancelled) setViewTasks(null); });
+      return () => { cancelled = true; };
+    }
+    apiListTasks(selectedProject.id, filter, undefined, {
+      sort: taskSort,
+      view: activeView ?? undefined,
+    })
+      .then((tasks) => { if (!cancelled) setViewTasks(tasks.map(convertTaskResponse)); })
+      .catch((err) => {
+        if (cancelled) return;
+        console.error("Failed to load task view:", err);
+        setViewTasks(null);
+      });
+    return () => { cancelled = true; };
+  }, [selectedProject, filter, taskSort, activeView]);
+
+  const showMessage = pageHandlers.showMessage;
+  const handleViewChange = useCallback((name: string | null) => {
+    setActiveView(name);
+    const view = savedViews.find((v) => v.name === name);
+    if (view) setTaskSort(view.sort);
+  }, [savedViews]);
+
+  const handleSaveView = useCallback(async (view: SavedTaskView) => {
+    if (!selectedProject) return;
+    try {
+      const saved = await saveTaskView(selectedProject.id, view);
+      setSavedViews((prev) => [...prev.filter((v) => v.name !== saved.name), saved]);
+      setShowSaveView(false);
+      setActiveView(saved.name);
+      setTaskSort(saved.sort);
+    } catch (err) {
+      console.error("Failed to save task view:", err);
+      showMessage("Failed to save view");
+    }
+  }, [selectedProject, showMessage]);
+
+  const handleDeleteView = useCallback(async (name: string) => {
+    if (!selectedProject) return;
+    try {
+      await deleteTaskView(selectedProject.id, name);
+      setSavedViews((prev) => prev.filter((v) => v.name !== name));
+      setActiveView(null);
+    } catch (err) {
+      console.error("Failed to delete task view:", err);
+      showMessage("Failed to delete view");
+    }
+  }, [selectedProject, showMessage]);
+
+  const viewBar = isStudio
+    ? undefined
+    : {
+        sort: taskSort,
+        views: savedViews,
+        activeView,
+        onSortChange: setTaskSort,
+        onViewChange: handleViewChange,
+        onSaveView: () => setShowSaveView(true),
+        onDeleteView: handleDeleteView,
+      };
+
   // Get tasks for current project (combine active and archived).
   // Backend already excludes Local Task from the tasks array; Local Task has
   // its own dedicated WorkPage route.
   const activeTasks = (selectedProject?.tasks || []).filter(
     (t) => t.status !== "archived"
   );
-  const tasks = filter === "archived" ? archivedTasks : activeTasks;
+  const tasks = viewTasks ?? (filter === "archived" ? archivedTasks : activeTasks);
 
   // Handle initial task selection from navigation
   useEffect(() => {
@@ -355,6 +471,18 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
     setFilter("active");
   }, [selectedProject, pageState.selectedTask, refreshSelectedProject, pageHandlers]);
 
+  // Pin / unpin a task (pinned tasks sort first)
+  const handleTogglePin = useCallback(async (task: Task) => {
+    if (!selectedProject) return;
+    try {
+      await setTaskPinned(selectedProject.id, task.id, !task.pinned);
+      await refreshSelectedProject();
+    } catch (err) {
+      console.error("Failed to pin task:", err);
+      pageHandlers.showMessage(err instanceof Error ? err.message : "Failed to pin task");
+    }
+  }, [selectedProject, refreshSelectedProject, pageHandlers]);
+
   // Unified panel add handler (Terminal/Chat/Review/Editor/Stats/Git/Notes/Comments)
   const handleAddPanel = useCallback((type: PanelType) => {
     // Call TaskView's addPanel method
@@ -379,7 +507,7 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
 
   // Handle new task creation (Zen-only)
   const handleCreateTask = useCallback(
-    async (name: string, targetBranch: string, notes: string) => {
+    async (name: string, targetBranch: string, notes: string, sparsePaths: string[], template?: string) => {
       if (!selectedProject) return;
       setIsCreating(true);
       setCreateError(null);
@@ -388,7 +516,15 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
       let createErr: unknown = null;
       try {
         // Create task and get the response
-        taskResponse = await apiCreateTask(selectedProject.id, name, targetBranch, notesArg);
+        taskResponse = await apiCreateTask(
+          selectedProject.id,
+          name,
+          targetBranch,
+          notesArg,
+          undefined,
+          sparsePaths.length > 0 ? sparsePaths : undefined,
+          template,
+        );
       } catch (err: unknown) {
         createErr = err;
       }
@@ -410,6 +546,10 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
         const newTask = convertTaskResponse(taskResponse);
         pageHandlers.setSelectedTask(newTask);
         pageHandlers.setInWorkspace(true);
+        // Non-blocking post-create hook failures
+        if (taskResponse.warning) {
+          pageHandlers.showMessage(`Created: ${taskResponse.name} — ${taskResponse.warning}`);
+        }
         // Async refresh, don't block UI
         refreshSelectedProject();
       }
@@ -882,6 +1022,7 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
     ? buildContextMenuItems(pageState.contextMenu.task, {
         onEnterTerminal: () => handleDoubleClickTask(pageState.contextMenu!.task),
         onRename: opsHandlers.handleRename,
+        onTogglePin: () => handleTogglePin(pageState.contextMenu!.task),
         onCommit: isStudio ? undefined : opsHandlers.handleCommit,
         onRebase: isStudio ? undefined : opsHandlers.handleRebase,
         onSync: isStudio ? undefined : opsHandlers.handleSync,
@@ -924,6 +1065,28 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
                 <kbd className="px-1 py-0.5 text-[10px] font-mono rounded border bg-[var(--color-bg)] border-[var(--color-border)]">{helpKey}</kbd>
               </button>
             )}
+            {!isMobile && selectedProject.isGitRepo && selectedProject.projectType !== "studio" && (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => setShowAdoptDialog(true)}
+                title="Import worktrees created outside Grove as tasks"
+              >
+                <FolderGit2 className="w-4 h-4 mr-1.5" />
+                Adopt
+              </Button>
+            )}
+            {!isMobile && orgConfigured && selectedProject.projectType !== "studio" && (
+              <Button
+                variant="ghost"
+                size="sm"
+                onClick={() => setShowTeamDialog(true)}
+                title="Tasks your teammates published to the team server"
+              >
+                <Users className="w-4 h-4 mr-1.5" />
+                Team
+              </Button>
+            )}
             {!(isMobile && mobileShowDetail) && (
               <Button onClick={() => setShowNewTaskDialog(true)} size="sm">
                 <Plus className="w-4 h-4 mr-1.5" />
@@ -1009,6 +1172,8 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
                   onContextMenuTask={pageHandlers.handleContextMenu}
                   onFilterChange={(f) => { setFilter(f); pageHandlers.setSelectedTask(null); pageHandlers.setInWorkspace(false); }}
                   onSearchChange={pageHandlers.setSearchQuery}
+                  viewBar={viewBar}
+                  risks={taskRisks}
                   fullWidth
                 />
               </motion.div>
@@ -1040,6 +1205,8 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
                   onContextMenuTask={pageHandlers.handleContextMenu}
                   onFilterChange={(f) => { setFilter(f); pageHandlers.setSelectedTask(null); pageHandlers.setInWorkspace(false); }}
                   onSearchChange={pageHandlers.setSearchQuery}
+                  viewBar={viewBar}
+                  risks={taskRisks}
                 />
               </div>
 
@@ -1158,6 +1325,30 @@ export function TasksPage({ initialTaskId, initialChatId, initialViewMode, onNav
         externalError={createError}
       />
 
+      {/* Adopt externally-created worktrees */}
+      <AdoptWorktreesDialog
+        key={showAdoptDialog ? "adopt-open" : "adopt-closed"}
+        isOpen={showAdoptDialog}
+        projectId={selectedProject.id}
+        onClose={() => setShowAdoptDialog(false)}
+        onAdopted={refreshSelectedProject}
+      />
+
+      <SaveTaskViewDialog
+        key={showSaveView ? `save-view-${taskSort}` : "save-view-closed"}
+        isOpen={showSaveView}
+        initialSort={taskSort}
+        onClose={() => setShowSaveView(false)}
+        onSave={handleSaveView}
+      />
+
+      <TeamTasksDialog
+        key={showTeamDialog ? `team-${selectedProject.id}` : "team-closed"}
+        isOpen={showTeamDialog}
+        projectId={selectedProject.id}
+        onClose={() => setShowTeamDialog(false)}
+      />
+
       {/* Shared operation dialogs (Commit / Merge / Clean / Reset / Rebase / Archive / PostMerge / DirtyBranch) */}
       <TaskOperationDialogs
         task={pageState.selectedTask}
@@ -0,0 +1,146 @@
+import { useEffect, useState } from "react";
+import { FolderGit2, GitBranch, Loader2, X } from "lucide-react";
+import { Button, Input } from "../../ui";
+import { DialogShell } from "../../ui/DialogShell";
+import { adoptWorktree, listUnmanagedWorktrees, type UnmanagedWorktree } from "../../../api";
+import { compactPath } from "../../../utils/pathUtils";
+
+interface AdoptWorktreesDialogProps {
+  isOpen: boolean;
+  projectId: string;
+  onClose: () => void;
+  onAdopted: () => void | Promise<void>;
+}
+
+interface Draft {
+  name: string;
+  target: string;
+}
+
+/** Lists worktrees created outside Grove (`git worktree add`) and imports them as tasks */
+export function AdoptWorktreesDialog({ isOpen, projectId, onClose, onAdopted }: AdoptWorktreesDialogProps) {
+  const [worktrees, setWorktrees] = useState<UnmanagedWorktree[]>([]);
+  const [drafts, setDrafts] = useState<Record<string, Draft>>({});
+  const [isLoading, setIsLoading] = useState(true);
+  const [adopting, setAdopting] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    let cancelled = false;
+    listUnmanagedWorktrees(projectId)
+      .then((list) => {
+        if (cancelled) return;
+        setWorktrees(list);
+        setDrafts(Object.fromEntries(
+          list.map((w) => [w.path, { name: w.suggested_name, target: w.suggested_target }])
+        ));
+      })
+      .catch(() => { if (!cancelled) setError("Failed to scan worktrees"); })
+      .finally(() => { if (!cancelled) setIsLoading(false); });
+    return () => { cancelled = true; };
+  }, [isOpen, projectId]);
+
+  const updateDraft = (path: string, patch: Partial<Draft>) => {
+    setDrafts((prev) => ({ ...prev, [path]: { ...prev[path], ...patch } }));
+  };
+
+  const handleAdopt = async (wt: UnmanagedWorktree) => {
+    const draft = drafts[wt.path];
+    setAdopting(wt.path);
+    setError(null);
+    try {
+      await adoptWorktree(projectId, wt.path, draft?.name.trim(), draft?.target.trim());
+      setWorktrees((prev) => prev.filter((w) => w.path !== wt.path));
+      await onAdopted();
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to adopt worktree");
+    } finally {
+      setAdopting(null);
+    }
+  };
+
+  return (
+    <DialogShell isOpen={isOpen} onClose={onClose} maxWidth="max-w-2xl">
+      <div className="bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded-xl shadow-xl overflow-hidden">
+        {/* Header */}
+        <div className="flex items-center justify-between px-5 py-4 border-b border-[var(--color-border)]">
+          <div className="flex items-center gap-3">
+            <div className="w-9 h-9 rounded-lg flex items-center justify-center bg-[var(--color-highlight)]/10">
+              <FolderGit2 className="w-5 h-5 text-[var(--color-highlight)]" />
+            </div>
+            <div>
+              <h2 className="text-lg font-semibold text-[var(--color-text)]">Adopt Worktrees</h2>
+              <p className="text-xs text-[var(--color-text-muted)]">Worktrees created outside Grove, not yet tracked as tasks</p>
+            </div>
+          </div>
+          <button
+            onClick={onClose}
+            className="p-1.5 rounded-lg hover:bg-[var(--color-bg-tertiary)] text-[var(--color-text-muted)] transition-colors"
+          >
+            <X className="w-5 h-5" />
+          </button>
+        </div>
+
+        {/* Content */}
+        <div className="px-5 py-4 space-y-3 max-h-[60vh] overflow-y-auto">
+          {error && (
+            <div className="px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+          )}
+          {isLoading ? (
+            <div className="flex items-center justify-center gap-2 py-8 text-sm text-[var(--color-text-muted)]">
+              <Loader2 className="w-4 h-4 animate-spin" />
+              <span>Scanning worktrees...</span>
+            </div>
+          ) : worktrees.length === 0 ? (
+            <div className="py-8 text-center text-sm text-[var(--color-text-muted)]">
+              No unmanaged worktrees found
+            </div>
+          ) : (
+            worktrees.map((wt) => (
+              <div key={wt.path} className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-3 space-y-2">
+                <div className="flex items-center gap-2 min-w-0">
+                  <GitBranch className="w-4 h-4 text-[var(--color-text-muted)] flex-shrink-0" />
+                  <code className="text-sm text-[var(--color-accent)] truncate">{wt.branch}</code>
+                  <span className="ml-auto text-xs text-[var(--color-text-muted)] truncate" title={wt.path}>
+                    {compactPath(wt.path, 48)}
+                  </span>
+                </div>
+                <div className="grid grid-cols-[1fr_1fr_auto] gap-2 items-end">
+                  <div>
+                    <label className="block text-xs text-[var(--color-text-muted)] mb-1">Task name</label>
+                    <Input
+                      value={drafts[wt.path]?.name ?? ""}
+                      onChange={(e) => updateDraft(wt.path, { name: e.target.value })}
+                    />
+                  </div>
+                  <div>
+                    <label className="block text-xs text-[var(--color-text-muted)] mb-1">Target branch</label>
+                    <Input
+                      value={drafts[wt.path]?.target ?? ""}
+                      onChange={(e) => updateDraft(wt.path, { target: e.target.value })}
+                    />
+                  </div>
+                  <Button
+                    size="sm"
+                    onClick={() => handleAdopt(wt)}
+                    disabled={adopting !== null || !drafts[wt.path]?.name.trim()}
+                  >
+                    {adopting === wt.path ? <Loader2 className="w-4 h-4 animate-spin" /> : "Adopt"}
+                  </Button>
+                </div>
+              </div>
+            ))
+          )}
+        </div>
+
+        {/* Actions */}
+        <div className="flex justify-end gap-3 px-5 py-4 bg-[var(--color-bg)] border-t border-[var(--color-border)]">
+          <Button variant="secondary" onClick={onClose}>
+            Close
+          </Button>
+        </div>
+      </div>
+    </DialogShell>
+  );
+}
@@ -0,0 +1,163 @@
+import { useState } from "react";
+import { Filter, X } from "lucide-react";
+import { Button, Input } from "../../ui";
+import { DialogShell } from "../../ui/DialogShell";
+import type { TaskSort, TaskView, TaskViewFilter } from "../../../api";
+import { TASK_SORT_OPTIONS } from "../TaskSidebar/TaskViewBar";
+
+interface SaveTaskViewDialogProps {
+  isOpen: boolean;
+  /** Pre-filled sort (the list's current sort) */
+  initialSort: TaskSort;
+  onClose: () => void;
+  onSave: (view: TaskView) => void;
+}
+
+const STATUS_OPTIONS = ["live", "idle", "merged", "conflict", "broken"];
+
+const selectClass =
+  "w-full px-3 py-2 text-sm bg-[var(--color-bg)] border border-[var(--color-border)] rounded-lg text-[var(--color-text)]";
+const labelClass = "block text-sm font-medium text-[var(--color-text-muted)] mb-2";
+
+export function SaveTaskViewDialog({ isOpen, initialSort, onClose, onSave }: SaveTaskViewDialogProps) {
+  const [name, setName] = useState("");
+  const [sort, setSort] = useState<TaskSort>(initialSort);
+  const [statuses, setStatuses] = useState<string[]>([]);
+  const [agentState, setAgentState] = useState<TaskViewFilter["agent_state"] | "">("");
+  const [assignee, setAssignee] = useState<TaskViewFilter["assignee"] | "">("");
+  const [query, setQuery] = useState("");
+  const [pinnedOnly, setPinnedOnly] = useState(false);
+
+  const toggleStatus = (status: string) => {
+    setStatuses((prev) => (prev.includes(status) ? prev.filter((s) => s !== status) : [...prev, status]));
+  };
+
+  const handleSave = () => {
+    if (!name.trim()) return;
+    onSave({
+      name: name.trim(),
+      sort,
+      filter: {
+        statuses,
+        agent_state: agentState || undefined,
+        assignee: assignee || undefined,
+        query: query.trim() || undefined,
+        pinned_only: pinnedOnly,
+      },
+    });
+  };
+
+  return (
+    <DialogShell isOpen={isOpen} onClose={onClose}>
+      <div className="bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded-xl shadow-xl overflow-hidden">
+        {/* Header */}
+        <div className="flex items-center justify-between px-5 py-4 border-b border-[var(--color-border)]">
+          <div className="flex items-center gap-3">
+            <div className="w-9 h-9 rounded-lg flex items-center justify-center bg-[var(--color-highlight)]/10">
+              <Filter className="w-5 h-5 text-[var(--color-highlight)]" />
+            </div>
+            <h2 className="text-lg font-semibold text-[var(--color-text)]">Save View</h2>
+          </div>
+          <button
+            onClick={onClose}
+            className="p-1.5 rounded-lg hover:bg-[var(--color-bg-tertiary)] text-[var(--color-text-muted)] transition-colors"
+          >
+            <X className="w-5 h-5" />
+          </button>
+        </div>
+
+        {/* Content */}
+        <div className="px-5 py-4 space-y-4">
+          <div>
+            <label className={labelClass}>Name</label>
+            <Input
+              value={name}
+              onChange={(e) => setName(e.target.value)}
+              placeholder="e.g. Needs review"
+              autoFocus
+              onKeyDown={(e) => {
+                if (e.key === "Enter") handleSave();
+              }}
+            />
+          </div>
+
+          <div>
+            <label className={labelClass}>Sort by</label>
+            <select value={sort} onChange={(e) => setSort(e.target.value as TaskSort)} className={selectClass}>
+              {TASK_SORT_OPTIONS.map(({ value, label }) => (
+                <option key={value} value={value}>{label}</option>
+              ))}
+            </select>
+          </div>
+
+          <div>
+            <label className={labelClass}>Status (any of)</label>
+            <div className="flex flex-wrap gap-1.5">
+              {STATUS_OPTIONS.map((status) => (
+                <button
+                  key={status}
+                  onClick={() => toggleStatus(status)}
+                  className={`px-2.5 py-1 text-xs rounded-md transition-colors ${
+                    statuses.includes(status)
+                      ? "bg-[var(--color-highlight)] text-white"
+                      : "bg-[var(--color-bg)] text-[var(--color-text-muted)] hover:text-[var(--color-text)]"
+                  }`}
+                >
+                  {status}
+                </button>
+              ))}
+            </div>
+          </div>
+
+          <div className="grid grid-cols-2 gap-3">
+            <div>
+              <label className={labelClass}>Agent state</label>
+              <select
+                value={agentState}
+                onChange={(e) => setAgentState(e.target.value as TaskViewFilter["agent_state"] | "")}
+                className={selectClass}
+              >
+                <option value="">Any</option>
+                <option value="busy">Busy</option>
+                <option value="waiting">Waiting</option>
+                <option value="idle">Idle</option>
+              </select>
+            </div>
+            <div>
+              <label className={labelClass}>Assignee</label>
+              <select
+                value={assignee}
+                onChange={(e) => setAssignee(e.target.value as TaskViewFilter["assignee"] | "")}
+                className={selectClass}
+              >
+                <option value="">Any</option>
+                <option value="human">Human</option>
+                <option value="agent">Agent</option>
+              </select>
+            </div>
+          </div>
+
+          <div>
+            <label className={labelClass}>Name or branch contains</label>
+            <Input value={query} onChange={(e) => setQuery(e.target.value)} placeholder="Optional" />
+          </div>
+
+          <label className="flex items-center gap-2 text-sm text-[var(--color-text)]">
+            <input type="checkbox" checked={pinnedOnly} onChange={(e) => setPinnedOnly(e.target.checked)} />
+            Pinned tasks only
+          </label>
+        </div>
+
+        {/* Actions */}
+        <div className="flex justify-end gap-3 px-5 py-4 bg-[var(--color-bg)] border-t border-[var(--color-border)]">
+          <Button variant="secondary" onClick={onClose}>
+            Cancel
+          </Button>
+          <Button variant="primary" onClick={handleSave} disabled={!name.trim()}>
+            Save
+          </Button>
+        </div>
+      </div>
+    </DialogShell>
+  );
+}
@@ -0,0 +1,219 @@
+import { useEffect, useState } from "react";
+import { GitBranch, Loader2, MessageSquare, Users, X } from "lucide-react";
+import { Button, MarkdownRenderer } from "../../ui";
+import { DialogShell } from "../../ui/DialogShell";
+import {
+  addTeamComment,
+  getTeamComments,
+  listTeamTasks,
+  type ReviewCommentEntry,
+  type SharedTask,
+} from "../../../api";
+import { formatTimeAgo } from "../../../utils/notificationFormat";
+
+interface TeamTasksDialogProps {
+  isOpen: boolean;
+  projectId: string;
+  onClose: () => void;
+}
+
+const taskKey = (t: SharedTask) => `${t.owner}/${t.task.id}`;
+
+/** Teammates' tasks published to the team server, with their notes and review threads */
+export function TeamTasksDialog({ isOpen, projectId, onClose }: TeamTasksDialogProps) {
+  const [tasks, setTasks] = useState<SharedTask[]>([]);
+  const [selected, setSelected] = useState<SharedTask | null>(null);
+  const [comments, setComments] = useState<ReviewCommentEntry[]>([]);
+  const [isLoading, setIsLoading] = useState(true);
+  const [draft, setDraft] = useState("");
+  const [replyTo, setReplyTo] = useState<number | null>(null);
+  const [isSending, setIsSending] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!isOpen) return;
+    let cancelled = false;
+    listTeamTasks(projectId)
+      .then((res) => {
+        if (cancelled) return;
+        setTasks(res.tasks);
+        setSelected(res.tasks[0] ?? null);
+      })
+      .catch((err) => { if (!cancelled) setError(err instanceof Error ? err.message : "Failed to load team tasks"); })
+      .finally(() => { if (!cancelled) setIsLoading(false); });
+    return () => { cancelled = true; };
+  }, [isOpen, projectId]);
+
+  useEffect(() => {
+    if (!selected) return;
+    let cancelled = false;
+    getTeamComments(projectId, selected.owner, selected.task.id)
+      .then((list) => { if (!cancelled) setComments(list); })
+      .catch(() => { if (!cancelled) setComments([]); });
+    return () => { cancelled = true; };
+  }, [projectId, selected]);
+
+  const selectTask = (task: SharedTask) => {
+    setSelected(task);
+    setComments([]);
+    setReplyTo(null);
+  };
+
+  const handleSend = async () => {
+    if (!selected || !draft.trim()) return;
+    setIsSending(true);
+    setError(null);
+    try {
+      setComments(await addTeamComment(projectId, selected.owner, selected.task.id, draft.trim(), replyTo ?? undefined));
+      setDraft("");
+      setReplyTo(null);
+    } catch (err) {
+      setError(err instanceof Error ? err.message : "Failed to send comment");
+    } finally {
+      setIsSending(false);
+    }
+  };
+
+  return (
+    <DialogShell isOpen={isOpen} onClose={onClose} maxWidth="max-w-4xl">
+      <div className="bg-[var(--color-bg-secondary)] border border-[var(--color-border)] rounded-xl shadow-xl overflow-hidden">
+        {/* Header */}
+        <div className="flex items-center justify-between px-5 py-4 border-b border-[var(--color-border)]">
+          <div className="flex items-center gap-3">
+            <div className="w-9 h-9 rounded-lg flex items-center justify-center bg-[var(--color-highlight)]/10">
+              <Users className="w-5 h-5 text-[var(--color-highlight)]" />
+            </div>
+            <div>
+              <h2 className="text-lg font-semibold text-[var(--color-text)]">Team Tasks</h2>
+              <p className="text-xs text-[var(--color-text-muted)]">Tasks your teammates published for this project</p>
+            </div>
+          </div>
+          <button
+            onClick={onClose}
+            className="p-1.5 rounded-lg hover:bg-[var(--color-bg-tertiary)] text-[var(--color-text-muted)] transition-colors"
+          >
+            <X className="w-5 h-5" />
+          </button>
+        </div>
+
+        {error && (
+          <div className="mx-5 mt-4 px-3 py-2 text-sm text-[var(--color-error)] bg-[var(--color-error)]/10 rounded-lg">{error}</div>
+        )}
+
+        {isLoading ? (
+          <div className="flex items-center justify-center gap-2 py-12 text-sm text-[var(--color-text-muted)]">
+            <Loader2 className="w-4 h-4 animate-spin" />
+            <span>Loading team tasks...</span>
+          </div>
+        ) : tasks.length === 0 ? (
+          <div className="py-12 text-center text-sm text-[var(--color-text-muted)]">
+            No teammate has published tasks for this project
+          </div>
+        ) : (
+          <div className="grid grid-cols-[240px_1fr] h-[60vh]">
+            {/* Task list */}
+            <div className="border-r border-[var(--color-border)] overflow-y-auto">
+              {tasks.map((t) => (
+                <button
+                  key={taskKey(t)}
+                  onClick={() => selectTask(t)}
+                  className={`w-full text-left px-4 py-3 border-b border-[var(--color-border)] transition-colors ${
+                    selected && taskKey(selected) === taskKey(t)
+                      ? "bg-[var(--color-bg-tertiary)]"
+                      : "hover:bg-[var(--color-bg-tertiary)]/50"
+                  }`}
+                >
+                  <div className="text-sm font-medium text-[var(--color-text)] truncate">{t.task.name}</div>
+                  <div className="text-xs text-[var(--color-text-muted)] truncate">
+                    {t.owner} · {formatTimeAgo(t.task.updated_at)}
+                  </div>
+                </button>
+              ))}
+            </div>
+
+            {/* Detail */}
+            {selected && (
+              <div className="flex flex-col min-h-0">
+                <div className="flex-1 overflow-y-auto px-5 py-4 space-y-4">
+                  <div className="flex items-center gap-2 text-xs text-[var(--color-text-muted)]">
+                    <GitBranch className="w-3.5 h-3.5" />
+                    <code className="text-[var(--color-accent)]">{selected.task.branch}</code>
+                    <span>→ {selected.task.target}</span>
+                    <span className="ml-auto">
+                      <span className="text-[var(--color-success)]">+{selected.task.code_additions}</span>{" "}
+                      <span className="text-[var(--color-error)]">-{selected.task.code_deletions}</span>
+                      {" · "}{selected.task.files_changed} files
+                    </span>
+                  </div>
+
+                  {selected.notes.trim() && (
+                    <div className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-3 text-sm">
+                      <MarkdownRenderer content={selected.notes} />
+                    </div>
+                  )}
+
+                  <div className="space-y-3">
+                    {comments.length === 0 && (
+                      <div className="text-sm text-[var(--color-text-muted)]">No review comments yet</div>
+                    )}
+                    {comments.map((c) => (
+                      <div key={c.id} className="rounded-lg border border-[var(--color-border)] bg-[var(--color-bg)] p-3 space-y-2">
+                        <div className="flex items-center gap-2 text-xs text-[var(--color-text-muted)]">
+                          <span className="font-medium text-[var(--color-text)]">{c.agent}</span>
+                          {c.file_path && <code className="truncate">{c.file_path}{c.start_line ? `:${c.start_line}` : ""}</code>}
+                          <span>{formatTimeAgo(c.timestamp)}</span>
+                          {c.status !== "open" && <span className="capitalize">· {c.status}</span>}
+                          <button
+                            onClick={() => setReplyTo(c.id)}
+                            className="ml-auto hover:text-[var(--color-text)] transition-colors"
+                          >
+                            Reply
+                          </button>
+                        </div>
+                        <div className="text-sm text-[var(--color-text)] whitespace-pre-wrap">{c.content}</div>
+                        {c.replies.map((r) => (
+                          <div key={r.id} className="ml-4 pl-3 border-l-2 border-[var(--color-border)]">
+                            <div className="text-xs text-[var(--color-text-muted)]">
+                              <span className="font-medium text-[var(--color-text)]">{r.agent}</span> · {formatTimeAgo(r.timestamp)}
+                            </div>
+                            <div className="text-sm text-[var(--color-text)] whitespace-pre-wrap">{r.content}</div>
+                          </div>
+                        ))}
+                      </div>
+                    ))}
+                  </div>
+                </div>
+
+                {/* Composer */}
+                <div className="border-t border-[var(--color-border)] px-5 py-3 space-y-2">
+                  {replyTo !== null && (
+                    <div className="flex items-center gap-2 text-xs text-[var(--color-text-muted)]">
+                      <MessageSquare className="w-3.5 h-3.5" />
+                      <span>Replying to #{replyTo}</span>
+                      <button onClick={() => setReplyTo(null)} className="hover:text-[var(--color-text)]">Cancel</button>
+                    </div>
+                  )}
+                  <div className="flex gap-2 items-end">
+                    <textarea
+                      value={draft}
+                      onChange={(e) => setDraft(e.target.value)}
+                      onKeyDown={(e) => {
+                        if (e.key === "Enter" && (e.metaKey || e.ctrlKey)) handleSend();
+                      }}
+                      rows={2}
+                      placeholder={replyTo !== null ? "Write a reply..." : `Comment on ${selected.owner}'s task...`}
+                      className="flex-1 px-3 py-2 bg-[var(--color-bg)] border border-[var(--color-border)] rounded-lg text-sm text-[var(--color-text)] placeholder-[var(--color-text-muted)] resize-none focus:outline-none focus:border-[var(--color-highlight)]"
+                    />
+                    <Button size="sm" onClick={handleSend} disabled={isSending || !draft.trim()}>
+                      {isSending ? <Loader2 className="w-4 h-4 animate-spin" /> : "Send"}
+                    </Button>
+                  </div>
+                </div>
+              </div>
+            )}
+          </div>
+        )}
+      </div>
+    </DialogShell>
+  );
+}
@@ -1,2 +1,6 @@
+export { AdoptWorktreesDialog } from "./AdoptWorktreesDialog";
 export { RebaseDialog } from "./RebaseDialog";
 export { RenameTaskDialog } from "./RenameTaskDialog";
+export { RenamePathDialog } from "./RenamePathDialog";
+export { TeamTasksDialog } from "./TeamTasksDialog";
+export { SaveTaskViewDialog } from "./SaveTaskViewDialog";
@@ -0,0 +1,20 @@
+import { useTimeFormat } from "../../utils/time";
+
+interface TimeLabelProps {
+  /** RFC 3339 timestamp from the API */
+  time?: string;
+  /** Humanized string from the API ("2 hours ago") */
+  timeAgo?: string;
+  className?: string;
+}
+
+/** Relative or absolute time per the `[display]` config; hover shows the other form. */
+export function TimeLabel({ time, timeAgo, className }: TimeLabelProps) {
+  const formatTime = useTimeFormat();
+  const { label, title } = formatTime(time, timeAgo);
+  return (
+    <span className={className} title={title}>
+      {label}
+    </span>
+  );
+}
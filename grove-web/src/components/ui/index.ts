@@ -16,6 +16,7 @@ export { SketchChip } from "./SketchChip";
 export { OPEN_SKETCH_EVENT, invalidateSketchNameCache, setSketchNames } from "./sketchChipCache";
 export type { OpenSketchDetail } from "./sketchChipCache";
 export { KeyBadge } from "./KeyBadge";
+export { TimeLabel } from "./TimeLabel";
 export { VSCodeIcon } from "./VSCodeIcon";
 export { FileMentionDropdown } from "./FileMentionDropdown";
 export { FilePreviewDrawer, getExtBadge, downloadViaIframe, saveBlobAsFile, getPreviewType, canPreviewFile } from "./filePreview";
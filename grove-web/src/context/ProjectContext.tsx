@@ -59,6 +59,7 @@ function convertTask(task: TaskResponse): Task {
     multiplexer: task.multiplexer || "tmux",
     createdBy: task.created_by || "",
     isLocal: task.is_local || false,
+    pinned: task.pinned,
   };
 }
 
@@ -75,6 +76,8 @@ function convertProject(project: ProjectResponse): Project {
     isGitRepo: project.is_git_repo,
     exists: project.exists,
     projectType: normalizeProjectType(project.project_type),
+    defaultTarget: project.default_target,
+    pinned: project.pinned,
   };
 }
 
@@ -92,6 +95,7 @@ function convertProjectListItem(item: ProjectListItem): Project {
     isGitRepo: item.is_git_repo,
     exists: item.exists,
     projectType: normalizeProjectType(item.project_type),
+    pinned: item.pinned,
   };
 }
 
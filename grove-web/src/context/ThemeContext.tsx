@@ -1,7 +1,9 @@
 import { createContext, useContext, useState, useEffect, useMemo, useCallback, useRef } from "react";
 import type { ReactNode } from "react";
 import { apiClient } from "../api/client";
-import type { CustomThemeConfig } from "../api/config";
+import { getTuiTheme } from "../api/config";
+import type { CustomThemeConfig, StatusPalette, TuiTheme } from "../api/config";
+import { useRadioEvents } from "../hooks/useRadioEvents";
 
 const isTauri = typeof window !== "undefined" && "__TAURI_INTERNALS__" in window;
 
@@ -355,10 +357,94 @@ export const builtInThemes: Theme[] = [
     },
     accentPalette: ["#ef5350", "#ecc48d", "#addb67", "#82aaff", "#c792ea", "#7fdbca"],
   },
+  {
+    id: "high-contrast",
+    name: "High Contrast",
+    isLight: false,
+    colors: {
+      bg: "#000000",
+      bgSecondary: "#00376e",
+      bgTertiary: "#1a1a1a",
+      border: "#ffffff",
+      text: "#ffffff",
+      textMuted: "#d2d2d2",
+      highlight: "#ffff00",
+      accent: "#00ffff",
+      success: "#00ff00",
+      warning: "#ffaf00",
+      error: "#ff6e6e",
+      info: "#78c8ff",
+    },
+    accentPalette: ["#ff6e6e", "#ffaf00", "#ffff00", "#00ff00", "#00ffff", "#78c8ff"],
+  },
 ];
 
 export type ThemeMode = "auto" | "light" | "dark";
 
+/**
+ * Color-blind safe status colors (Okabe–Ito based): states are told apart on
+ * the blue / yellow axis and by lightness instead of red vs green. Mirrors
+ * `status_palette_colors` in the TUI.
+ */
+const statusPalettes: Record<Exclude<StatusPalette, "default">, { dark: StatusColors; light: StatusColors }> = {
+  deuteranopia: {
+    dark: { success: "#56b4e9", warning: "#f0e442", error: "#d55e00" },
+    light: { success: "#0072b2", warning: "#e69f00", error: "#aa3c00" },
+  },
+  // Reds look dim to protanopes, so errors use a brighter orange-red
+  protanopia: {
+    dark: { success: "#56b4e9", warning: "#f0e442", error: "#ff8c42" },
+    light: { success: "#0072b2", warning: "#b49600", error: "#dc5a14" },
+  },
+};
+
+interface StatusColors {
+  success: string;
+  warning: string;
+  error: string;
+}
+
+function applyStatusPalette(theme: Theme, palette: StatusPalette): Theme {
+  if (palette === "default") return theme;
+  const status = statusPalettes[palette][theme.isLight ? "light" : "dark"];
+  return { ...theme, colors: { ...theme.colors, ...status } };
+}
+
+/** The TUI's theme as a web theme (its status palette is already applied) */
+function tuiThemeToTheme(t: TuiTheme): Theme {
+  const c = t.web_colors;
+  return {
+    id: t.id,
+    name: t.name,
+    colors: {
+      bg: c.bg,
+      bgSecondary: c.bg_secondary,
+      bgTertiary: c.bg_tertiary,
+      border: c.border,
+      text: c.text,
+      textMuted: c.text_muted,
+      highlight: c.highlight,
+      accent: c.accent,
+      success: c.success,
+      warning: c.warning,
+      error: c.error,
+      info: c.info,
+    },
+    accentPalette: t.accent_palette,
+    isLight: t.is_light,
+    isCustom: t.is_custom,
+  };
+}
+
+type AppearanceParams = {
+  mode?: ThemeMode;
+  lightThemeId?: string;
+  darkThemeId?: string;
+  customThemes?: Theme[];
+  statusPalette?: StatusPalette;
+  syncTuiColors?: boolean;
+};
+
 interface ThemeContextType {
   theme: Theme; // Current effective theme
   mode: ThemeMode;
@@ -366,7 +452,10 @@ interface ThemeContextType {
   darkThemeId: string;
   customThemes: Theme[];
   themes: Theme[]; // All available themes (built-in + custom)
-  setAppearance: (params: { mode?: ThemeMode; lightThemeId?: string; darkThemeId?: string; customThemes?: Theme[] }) => Promise<void>;
+  statusPalette: StatusPalette;
+  /** Use the exact colors of the TUI theme (all `--tui-*` variables included) */
+  syncTuiColors: boolean;
+  setAppearance: (params: AppearanceParams) => Promise<void>;
 }
 
 const ThemeContext = createContext<ThemeContextType | undefined>(undefined);
@@ -381,54 +470,83 @@ export function ThemeProvider({ children }: { children: ReactNode }) {
   const [lightThemeId, setLightThemeId] = useState("light");
   const [darkThemeId, setDarkThemeId] = useState("dark");
   const [customThemes, setCustomThemes] = useState<Theme[]>([]);
+  const [statusPalette, setStatusPalette] = useState<StatusPalette>("default");
+  const [syncTuiColors, setSyncTuiColors] = useState(false);
+  const [tuiTheme, setTuiTheme] = useState<TuiTheme | null>(null);
+  // Bumped on every theme_changed event so the TUI colors are refetched even
+  // when only the terminal side changed
+  const [themeRevision, setThemeRevision] = useState(0);
   const [systemIsDark, setSystemIsDark] = useState<boolean>(getSystemIsDark);
 
   const allThemes = useMemo(() => [...builtInThemes, ...customThemes], [customThemes]);
 
-  // Load theme from backend config on mount and on focus regain.
-  useEffect(() => {
-    const loadTheme = async () => {
-      try {
-        const config = await apiClient.get<{ theme?: { mode: string; light_theme: string; dark_theme: string; custom_themes?: CustomThemeConfig[] } }>("/api/v1/config");
-        const t = config.theme;
-        if (t) {
-          if (t.mode) setMode(t.mode as ThemeMode);
-          if (t.light_theme) setLightThemeId(t.light_theme);
-          if (t.dark_theme) setDarkThemeId(t.dark_theme);
-          if (t.custom_themes) {
-            const parsed: Theme[] = t.custom_themes.map(ct => ({
-              id: ct.id,
-              name: ct.name,
-              colors: {
-                bg: ct.colors.bg,
-                bgSecondary: ct.colors.bg_secondary,
-                bgTertiary: ct.colors.bg_tertiary,
-                border: ct.colors.border,
-                text: ct.colors.text,
-                textMuted: ct.colors.text_muted,
-                highlight: ct.colors.highlight,
-                accent: ct.colors.accent,
-                success: ct.colors.success,
-                warning: ct.colors.warning,
-                error: ct.colors.error,
-                info: ct.colors.info,
-              },
-              accentPalette: ct.accent_palette,
-              isLight: ct.is_light,
-              isCustom: true,
-            }));
-            setCustomThemes(parsed);
-          }
+  // Load theme from backend config on mount, on focus regain and whenever
+  // the theme changes elsewhere (TUI or another window).
+  const loadTheme = useCallback(async () => {
+    try {
+      const config = await apiClient.get<{ theme?: { mode: string; light_theme: string; dark_theme: string; custom_themes?: CustomThemeConfig[]; status_palette?: StatusPalette; sync_tui_colors?: boolean } }>("/api/v1/config");
+      const t = config.theme;
+      if (t) {
+        setStatusPalette(t.status_palette ?? "default");
+        setSyncTuiColors(t.sync_tui_colors ?? false);
+        if (t.mode) setMode(t.mode as ThemeMode);
+        if (t.light_theme) setLightThemeId(t.light_theme);
+        if (t.dark_theme) setDarkThemeId(t.dark_theme);
+        if (t.custom_themes) {
+          const parsed: Theme[] = t.custom_themes.map(ct => ({
+            id: ct.id,
+            name: ct.name,
+            colors: {
+              bg: ct.colors.bg,
+              bgSecondary: ct.colors.bg_secondary,
+              bgTertiary: ct.colors.bg_tertiary,
+              border: ct.colors.border,
+              text: ct.colors.text,
+              textMuted: ct.colors.text_muted,
+              highlight: ct.colors.highlight,
+              accent: ct.colors.accent,
+              success: ct.colors.success,
+              warning: ct.colors.warning,
+              error: ct.colors.error,
+              info: ct.colors.info,
+            },
+            accentPalette: ct.accent_palette,
+            isLight: ct.is_light,
+            isCustom: true,
+          }));
+          setCustomThemes(parsed);
         }
-      } catch (error) {
-        console.error("Failed to load theme from config:", error);
       }
-    };
+    } catch (error) {
+      console.error("Failed to load theme from config:", error);
+    }
+  }, []);
 
+  useEffect(() => {
     loadTheme();
     window.addEventListener("focus", loadTheme);
     return () => window.removeEventListener("focus", loadTheme);
-  }, []);
+  }, [loadTheme]);
+
+  useRadioEvents({
+    onThemeChanged: () => {
+      void loadTheme();
+      setThemeRevision((r) => r + 1);
+    },
+  });
+
+  // Fetch the TUI theme's full colors while syncing with the terminal
+  useEffect(() => {
+    if (!syncTuiColors) {
+      setTuiTheme(null);
+      return;
+    }
+    let cancelled = false;
+    getTuiTheme(systemIsDark ? "dark" : "light")
+      .then((t) => { if (!cancelled) setTuiTheme(t); })
+      .catch((error) => console.error("Failed to load TUI theme:", error));
+    return () => { cancelled = true; };
+  }, [syncTuiColors, mode, lightThemeId, darkThemeId, customThemes, statusPalette, systemIsDark, themeRevision]);
 
   // Listen for system theme changes
   useEffect(() => {
@@ -440,14 +558,17 @@ export function ThemeProvider({ children }: { children: ReactNode }) {
 
   // Resolve the actual theme to use
   const theme = useMemo<Theme>(() => {
+    if (syncTuiColors && tuiTheme) return tuiThemeToTheme(tuiTheme);
     const targetId = mode === "auto" 
       ? (systemIsDark ? darkThemeId : lightThemeId)
       : (mode === "dark" ? darkThemeId : lightThemeId);
     
-    return allThemes.find(t => t.id === targetId) || (systemIsDark ? builtInThemes.find(t => t.id === "dark")! : builtInThemes.find(t => t.id === "light")!);
-  }, [mode, lightThemeId, darkThemeId, systemIsDark, allThemes]);
+    const resolved = allThemes.find(t => t.id === targetId) || (systemIsDark ? builtInThemes.find(t => t.id === "dark")! : builtInThemes.find(t => t.id === "light")!);
+    return applyStatusPalette(resolved, statusPalette);
+  }, [mode, lightThemeId, darkThemeId, systemIsDark, allThemes, statusPalette, syncTuiColors, tuiTheme]);
 
   // Apply CSS variables when theme changes
+  const appliedTuiVariables = useRef<string[]>([]);
   useEffect(() => {
     const root = document.documentElement;
     const colors = theme.colors;
@@ -466,6 +587,16 @@ export function ThemeProvider({ children }: { children: ReactNode }) {
     root.style.setProperty("--color-error", colors.error);
     root.style.setProperty("--color-info", colors.info);
 
+    // The full TUI palette (`--tui-status-live`, `--tui-accent-0`, …)
+    const tuiVariables = syncTuiColors && tuiTheme ? tuiTheme.css_variables : {};
+    for (const name of appliedTuiVariables.current) {
+      if (!(name in tuiVariables)) root.style.removeProperty(name);
+    }
+    for (const [name, value] of Object.entries(tuiVariables)) {
+      root.style.setProperty(name, value);
+    }
+    appliedTuiVariables.current = Object.keys(tuiVariables).filter((name) => name.startsWith("--tui-"));
+
     const faviconEl = document.querySelector<HTMLLinkElement>("link[rel~='icon'][type='image/svg+xml']");
     if (faviconEl) {
       faviconEl.href = theme.isLight ? "/favicon-light.svg" : "/favicon.svg";
@@ -476,17 +607,19 @@ export function ThemeProvider({ children }: { children: ReactNode }) {
         invoke("tray_update_theme_icons", { isLight: theme.isLight }).catch(() => {});
       });
     }
-  }, [theme]);
+  }, [theme, syncTuiColors, tuiTheme]);
 
   // Abort in-flight setAppearance PATCH before sending a new one. Rapid mode
   // toggles (A→B→A within 100ms) would otherwise produce overlapping requests
   // whose backend completion order may not match send order.
   const appearancePatchAbortRef = useRef<AbortController | null>(null);
-  const setAppearance = useCallback(async (params: { mode?: ThemeMode; lightThemeId?: string; darkThemeId?: string; customThemes?: Theme[] }) => {
+  const setAppearance = useCallback(async (params: AppearanceParams) => {
     if (params.mode !== undefined) setMode(params.mode);
     if (params.lightThemeId !== undefined) setLightThemeId(params.lightThemeId);
     if (params.darkThemeId !== undefined) setDarkThemeId(params.darkThemeId);
     if (params.customThemes !== undefined) setCustomThemes(params.customThemes);
+    if (params.statusPalette !== undefined) setStatusPalette(params.statusPalette);
+    if (params.syncTuiColors !== undefined) setSyncTuiColors(params.syncTuiColors);
 
     if (appearancePatchAbortRef.current) {
       appearancePatchAbortRef.current.abort();
@@ -501,6 +634,8 @@ export function ThemeProvider({ children }: { children: ReactNode }) {
           mode: params.mode,
           light_theme: params.lightThemeId,
           dark_theme: params.darkThemeId,
+          status_palette: params.statusPalette,
+          sync_tui_colors: params.syncTuiColors,
           custom_themes: params.customThemes?.map(ct => ({
             id: ct.id,
             name: ct.name,
@@ -537,8 +672,8 @@ export function ThemeProvider({ children }: { children: ReactNode }) {
   // render. Without this, the 5 internal useState + system theme listener +
   // focus event listener churn the value identity multiple times per second.
   const contextValue = useMemo(
-    () => ({ theme, mode, lightThemeId, darkThemeId, customThemes, themes: allThemes, setAppearance }),
-    [theme, mode, lightThemeId, darkThemeId, customThemes, allThemes, setAppearance],
+    () => ({ theme, mode, lightThemeId, darkThemeId, customThemes, themes: allThemes, statusPalette, syncTuiColors, setAppearance }),
+    [theme, mode, lightThemeId, darkThemeId, customThemes, allThemes, statusPalette, syncTuiColors, setAppearance],
   );
 
   return (
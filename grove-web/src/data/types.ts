@@ -1,3 +1,5 @@
+import type { CommitLinks } from '../api/git';
+
 // Project type
 export type ProjectType = 'repo' | 'studio';
 
@@ -11,7 +13,9 @@ export interface Commit {
   author: string;
   date?: Date;
   timeAgo?: string;  // pre-formatted time string from API (e.g., "2 hours ago")
+  time?: string;  // commit time from API (RFC 3339)
   files?: CommitFileChange[];  // expanded view shows file changes
+  links?: CommitLinks;  // task / chat from Grove-Task / Grove-Chat trailers
 }
 
 export interface Task {
@@ -25,6 +29,12 @@ export interface Task {
   multiplexer: string;
   createdBy?: string;
   isLocal?: boolean;
+  /** Inferred terminal agent state (live tmux tasks only) */
+  agentState?: "busy" | "waiting" | "idle";
+  /** Who currently owns the task (handback workflow) */
+  assignee?: { kind: "human" } | { kind: "agent"; chat_id: string };
+  /** Pinned tasks sort ahead of the rest */
+  pinned?: boolean;
 }
 
 export interface Project {
@@ -49,6 +59,10 @@ export interface Project {
   exists: boolean;
   /** Project type: 'repo' for code repositories, 'studio' for AI agent workspaces */
   projectType: ProjectType;
+  /** Default target branch for new tasks, when configured */
+  defaultTarget?: string;
+  /** Pinned projects sort ahead of the rest */
+  pinned?: boolean;
 }
 
 export type ActivityType = 'create' | 'merge' | 'sync' | 'archive' | 'recover';
@@ -43,6 +43,8 @@ export interface RadioEventCallbacks {
       body_excerpt?: string;
     },
   ) => void;
+  /** Theme settings changed — from the web Settings page or the TUI. */
+  onThemeChanged?: () => void;
   /** Fired when the shared WS opens or reopens after a disconnect. Useful for
    *  consumers who need to re-sync state after a missed-events window. */
   onConnected?: () => void;
@@ -117,6 +119,9 @@ function dispatch(event: RadioEvent) {
           body_excerpt: event.body_excerpt,
         });
       break;
+    case "theme_changed":
+      for (const s of subscribers) s.current.onThemeChanged?.();
+      break;
     case "client_connected":
       radioClientCount += 1;
       notifyClientCount();
@@ -10,8 +10,15 @@ import {
   deleteTask as apiDeleteTask,
   getCommits as apiGetCommits,
   getBranches as apiGetBranches,
+  getTaskRisk as apiGetTaskRisk,
+  getTaskTestChanges as apiGetTaskTestChanges,
+  getTaskBench as apiGetTaskBench,
+  getTaskSecurityScan as apiGetTaskSecurityScan,
+  getTaskFileGuard as apiGetTaskFileGuard,
 } from "../api";
+import type { BenchRun, FileGuardStatus, RiskAssessment, TestChanges } from "../api";
 import type { ApiError } from "../api/client";
+import { REBASE_CONFLICTS_EVENT } from "../api/review";
 import type { Task } from "../data/types";
 import type { PendingArchiveConfirm } from "../utils/archiveHelpers";
 import { handleArchiveError, buildArchiveConfirmMessage } from "../utils/archiveHelpers";
@@ -104,6 +111,16 @@ export interface TaskOperationsState {
   showMergeDialog: boolean;
   isMerging: boolean;
   mergeError: string | null;
+  /** Risk of the task being merged, shown in the merge dialog */
+  mergeRisk: RiskAssessment | null;
+  /** Source vs test changes of the task being merged ("tests missing" warning) */
+  mergeTestChanges: TestChanges | null;
+  /** Latest benchmark comparison of the task being merged (regressions) */
+  mergeBench: BenchRun | null;
+  /** Non-blocking security findings from the task's latest scan */
+  mergeSecurityWarning: string | null;
+  /** Added files violating the project's file guard */
+  mergeFileGuard: FileGuardStatus | null;
 
   // Rename
   showRenameDialog: boolean;
@@ -142,7 +159,7 @@ export interface TaskOperationsHandlers {
 
   // Merge
   handleMerge: () => Promise<void>;
-  handleMergeSubmit: (method: "squash" | "merge-commit") => Promise<void>;
+  handleMergeSubmit: (method: "squash" | "merge-commit", includeReview: boolean) => Promise<void>;
   handleMergeCancel: () => void;
 
   // Rename
@@ -205,6 +222,11 @@ export function useTaskOperations(
   const [showMergeDialog, setShowMergeDialog] = useState(false);
   const [isMerging, setIsMerging] = useState(false);
   const [mergeError, setMergeError] = useState<string | null>(null);
+  const [mergeRisk, setMergeRisk] = useState<RiskAssessment | null>(null);
+  const [mergeTestChanges, setMergeTestChanges] = useState<TestChanges | null>(null);
+  const [mergeBench, setMergeBench] = useState<BenchRun | null>(null);
+  const [mergeSecurityWarning, setMergeSecurityWarning] = useState<string | null>(null);
+  const [mergeFileGuard, setMergeFileGuard] = useState<FileGuardStatus | null>(null);
 
   // Rename state
   const [showRenameDialog, setShowRenameDialog] = useState(false);
@@ -276,11 +298,29 @@ export function useTaskOperations(
 
     let commitsRes: Awaited<ReturnType<typeof apiGetCommits>> | null = null;
     let commitsErr: unknown = null;
+    // Risk is advisory: a failed lookup never blocks the merge
+    const riskPromise = apiGetTaskRisk(projectId, selectedTask.id).catch(() => null);
+    const testsPromise = apiGetTaskTestChanges(projectId, selectedTask.id).catch(() => null);
+    const benchPromise = apiGetTaskBench(projectId, selectedTask.id).catch(() => null);
+    const securityPromise = apiGetTaskSecurityScan(projectId, selectedTask.id).catch(() => null);
+    const guardPromise = apiGetTaskFileGuard(projectId, selectedTask.id).catch(() => null);
     try {
       commitsRes = await apiGetCommits(projectId, selectedTask.id);
     } catch (err) {
       commitsErr = err;
     }
+    const risk = await riskPromise;
+    setMergeRisk(risk);
+    setMergeTestChanges(await testsPromise);
+    const bench = await benchPromise;
+    setMergeBench(bench);
+    const securityWarning = (await securityPromise)?.warning ?? null;
+    setMergeSecurityWarning(securityWarning);
+    const fileGuard = await guardPromise;
+    setMergeFileGuard(fileGuard);
+    const guardedFiles = (fileGuard?.violations.length ?? 0) > 0;
+    const benchRegressed =
+      bench?.status === "done" && bench.results.some((r) => r.verdict === "regression");
 
     if (commitsErr || !commitsRes) {
       console.error("Failed to get commits:", commitsErr);
@@ -290,8 +330,8 @@ export function useTaskOperations(
     }
 
     const commitCount = commitsRes.total;
-    if (commitCount > 1) {
-      // Multiple commits, show dialog to choose method
+    if (commitCount > 1 || risk?.level === "high" || benchRegressed || securityWarning || guardedFiles) {
+      // Multiple commits (or a high-risk / regressed task), show dialog to choose method
       setMergeError(null);
       setShowMergeDialog(true);
       return;
@@ -334,14 +374,14 @@ export function useTaskOperations(
   }, [projectId, selectedTask, isMerging, onRefresh, onShowMessage, onTaskMerged]);
 
   const handleMergeSubmit = useCallback(
-    async (method: "squash" | "merge-commit") => {
+    async (method: "squash" | "merge-commit", includeReview: boolean) => {
       if (!projectId || !selectedTask || isMerging) return;
       setIsMerging(true);
       setMergeError(null);
       let result: Awaited<ReturnType<typeof apiMergeTask>> | null = null;
       let err: unknown = null;
       try {
-        result = await apiMergeTask(projectId, selectedTask.id, method);
+        result = await apiMergeTask(projectId, selectedTask.id, method, includeReview);
       } catch (e) {
         err = e;
       }
@@ -504,6 +544,10 @@ export function useTaskOperations(
       if (result.success) {
         onShowMessage(msg || "Synced successfully");
         await onRefresh();
+      } else if (result.conflicts?.length) {
+        // Left mid-rebase: the Review panel's conflict view takes over
+        onShowMessage(msg);
+        window.dispatchEvent(new CustomEvent(REBASE_CONFLICTS_EVENT, { detail: { taskId: selectedTask.id } }));
       } else {
         const dirty = parseDirtyBranchError(msg, "Sync", selectedTask.branch);
         if (dirty) {
@@ -659,6 +703,11 @@ export function useTaskOperations(
     showMergeDialog,
     isMerging,
     mergeError,
+    mergeRisk,
+    mergeTestChanges,
+    mergeBench,
+    mergeSecurityWarning,
+    mergeFileGuard,
     showRenameDialog,
     isRenaming,
     isSyncing,
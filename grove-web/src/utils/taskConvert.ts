@@ -14,5 +14,8 @@ export function convertTaskResponse(task: TaskResponse): Task {
     multiplexer: task.multiplexer || "tmux",
     createdBy: task.created_by || "",
     isLocal: task.is_local || false,
+    agentState: task.agent_state,
+    assignee: task.assignee,
+    pinned: task.pinned,
   };
 }
@@ -1,4 +1,4 @@
-import { Terminal, GitCommit, GitBranchPlus, RefreshCw, GitMerge, Archive, RotateCcw, Trash2, Edit3 } from "lucide-react";
+import { Terminal, GitCommit, GitBranchPlus, RefreshCw, GitMerge, Archive, RotateCcw, Trash2, Edit3, Pin, PinOff } from "lucide-react";
 import type { Task } from "../data/types";
 import type { ContextMenuItem } from "../components/ui/ContextMenu";
 
@@ -9,6 +9,7 @@ import type { ContextMenuItem } from "../components/ui/ContextMenu";
 export interface TaskOperationHandlers {
   onEnterTerminal?: () => void;
   onRename?: () => void;
+  onTogglePin?: () => void;
   onCommit?: () => void;
   onRebase?: () => void;
   onSync?: () => void;
@@ -110,6 +111,17 @@ export function buildContextMenuItems(
     });
   }
 
+  // Pin / unpin
+  if (handlers.onTogglePin) {
+    items.push({
+      id: "pin",
+      label: task.pinned ? "Unpin" : "Pin to Top",
+      icon: task.pinned ? PinOff : Pin,
+      variant: "default",
+      onClick: handlers.onTogglePin,
+    });
+  }
+
   // Git operations group
   const gitItems: ContextMenuItem[] = [];
   if (handlers.onCommit) {
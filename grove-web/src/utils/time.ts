@@ -0,0 +1,63 @@
+import { useEffect, useState } from "react";
+import { getConfig, type DisplayConfig } from "../api";
+
+const DEFAULT_DISPLAY: DisplayConfig = { time_format: "relative" };
+
+let current: DisplayConfig = DEFAULT_DISPLAY;
+let loaded = false;
+const listeners = new Set<(display: DisplayConfig) => void>();
+
+/** Update the shared `[display]` settings (e.g. after saving them). */
+export function setTimeDisplay(display: DisplayConfig): void {
+  current = display;
+  loaded = true;
+  listeners.forEach((listener) => listener(display));
+}
+
+/** Locale-aware absolute time ("Jan 31, 2026, 09:30"); empty for invalid input. */
+export function formatAbsoluteTime(time: string | undefined, locale?: string): string {
+  if (!time) return "";
+  const date = new Date(time);
+  if (Number.isNaN(date.getTime())) return "";
+  try {
+    return date.toLocaleString(locale || undefined, { dateStyle: "medium", timeStyle: "short" });
+  } catch {
+    // Invalid locale tag in config
+    return date.toLocaleString(undefined, { dateStyle: "medium", timeStyle: "short" });
+  }
+}
+
+export interface FormattedTime {
+  /** Text to render, per the relative / absolute setting */
+  label: string;
+  /** Hover text: the absolute time when the label is relative */
+  title?: string;
+}
+
+/**
+ * Formatter honouring the `[display]` config. APIs send an RFC 3339 `time`
+ * next to the humanized `time_ago`; the relative string is kept as the label
+ * in relative mode and the absolute one becomes its tooltip.
+ */
+export function useTimeFormat(): (time?: string, timeAgo?: string) => FormattedTime {
+  const [display, setDisplay] = useState(current);
+
+  useEffect(() => {
+    listeners.add(setDisplay);
+    if (!loaded) {
+      loaded = true;
+      getConfig()
+        .then((cfg) => setTimeDisplay(cfg.display ?? DEFAULT_DISPLAY))
+        .catch(() => { loaded = false; });
+    }
+    return () => { listeners.delete(setDisplay); };
+  }, []);
+
+  return (time, timeAgo) => {
+    const absolute = formatAbsoluteTime(time, display.locale);
+    if (display.time_format === "absolute" && absolute) {
+      return { label: absolute, title: timeAgo };
+    }
+    return { label: timeAgo || absolute, title: absolute || undefined };
+  };
+}
@@ -0,0 +1,355 @@
+//! 前端无关的 chat 客户端
+//!
+//! Web 通过 WebSocket 订阅 `AcpSessionHandle`，但 TUI / MCP 不一定和 session
+//! 在同一个进程：session 可能由 `grove web` / `grove notifyd` 持有（Remote），
+//! 也可能在本进程内（Local），或者根本没在跑（Detached）。`ChatClient` 把这三种
+//! 情况收拢成一个接口：
+//!
+//! - 写：`send(SocketCommand)` —— Local 直接调 handle，Remote 走 Unix socket
+//! - 读：`history()` / `HistoryTail` —— 所有 session owner 都会实时 append
+//!   `history.jsonl`，所以无论谁持有 session，读磁盘都能看到同一份对话
+
+use std::fs;
+use std::io::{BufRead, Seek, SeekFrom};
+use std::path::PathBuf;
+
+use super::{AcpUpdate, QueuedMessage, SessionAccess, SocketCommand, SocketResponse};
+use crate::error::{GroveError, Result};
+use crate::storage::{chat_history, tasks};
+
+/// `HistoryTail` 首次读取时最多回看的字节数（更早的事件由 `history()` 提供）
+const TAIL_INITIAL_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Session 当前由谁持有
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatHost {
+    /// 本进程
+    Local,
+    /// 另一个 grove 进程（web / notifyd / 另一个 TUI）
+    Remote,
+    /// 没有运行中的 session，只能读历史
+    Detached,
+}
+
+/// 绑定到单个 chat 的客户端
+pub struct ChatClient {
+    pub project_key: String,
+    pub task_id: String,
+    pub chat_id: String,
+    access: Option<SessionAccess>,
+}
+
+impl ChatClient {
+    /// 只做发现，不启动 session
+    pub fn discover(project_key: &str, task_id: &str, chat_id: &str) -> Self {
+        let session_key = format!("{}:{}:{}", project_key, task_id, chat_id);
+        Self {
+            project_key: project_key.to_string(),
+            task_id: task_id.to_string(),
+            chat_id: chat_id.to_string(),
+            access: super::discover_session(project_key, task_id, chat_id, &session_key),
+        }
+    }
+
+    /// 发现已有 session；没有就按 chat 记录的 agent 在本进程启动一个
+    pub async fn connect(
+        project_key: &str,
+        project_path: &str,
+        project_name: &str,
+        task: &tasks::Task,
+        chat_id: &str,
+    ) -> Result<Self> {
+        let mut client = Self::discover(project_key, &task.id, chat_id);
+        if client.access.is_some() {
+            return Ok(client);
+        }
+
+        let chat = tasks::get_chat_session(project_key, &task.id, chat_id)?
+            .ok_or_else(|| GroveError::session(format!("Chat not found: {}", chat_id)))?;
+        let resolved = super::resolve_agent(&chat.agent)
+            .ok_or_else(|| GroveError::session(format!("Unknown agent: {}", chat.agent)))?;
+
+        let share_process = super::shares_agent_process(&resolved.agent_name);
+        let config = super::AcpStartConfig {
+            agent_command: resolved.command,
+            agent_name: resolved.agent_name,
+            agent_args: resolved.args,
+            working_dir: chat.working_dir(&task.worktree_path),
+            env_vars: crate::api::handlers::acp::build_grove_env(
+                project_key,
+                project_path,
+                project_name,
+                task,
+                Some(chat_id),
+            ),
+            project_key: project_key.to_string(),
+            task_id: task.id.clone(),
+            chat_id: Some(chat_id.to_string()),
+            agent_type: resolved.agent_type,
+            remote_url: resolved.url,
+            remote_auth: resolved.auth_header,
+            suppress_initial_connecting: false,
+            persona_injection: None,
+            initial_mode: None,
+            initial_model: None,
+            share_process,
+        };
+
+        // 可能和另一个进程竞争启动；socket bind 失败时 listener 只记日志，
+        // 下次 discover 会找到对方持有的 Remote session。
+        let session_key = format!("{}:{}:{}", project_key, task.id, chat_id);
+        let (handle, _rx) = super::get_or_start_session(session_key, config).await?;
+        client.access = Some(SessionAccess::Local(handle));
+        Ok(client)
+    }
+
+    pub fn access(&self) -> Option<&SessionAccess> {
+        self.access.as_ref()
+    }
+
+    pub fn host(&self) -> ChatHost {
+        match self.access {
+            Some(SessionAccess::Local(_)) => ChatHost::Local,
+            Some(SessionAccess::Remote { .. }) => ChatHost::Remote,
+            None => ChatHost::Detached,
+        }
+    }
+
+    async fn request(&self, cmd: SocketCommand) -> Result<SocketResponse> {
+        let response = match &self.access {
+            Some(SessionAccess::Local(handle)) => super::dispatch_socket_command(handle, cmd).await,
+            Some(SessionAccess::Remote { sock_path, .. }) => {
+                super::send_socket_command(sock_path, &cmd).await?
+            }
+            None => return Err(GroveError::session("Chat is not running")),
+        };
+        match response {
+            SocketResponse::Error { message } => Err(GroveError::session(message)),
+            other => Ok(other),
+        }
+    }
+
+    /// 发送命令到 session owner
+    pub async fn send(&self, cmd: SocketCommand) -> Result<()> {
+        self.request(cmd).await.map(|_| ())
+    }
+
+    /// 查询 owner 的 busy 状态和待发送队列；Detached 视为空闲、空队列
+    pub async fn status(&self) -> Result<ChatStatus> {
+        if self.access.is_none() {
+            return Ok(ChatStatus::default());
+        }
+        match self.request(SocketCommand::Status).await? {
+            SocketResponse::Status { busy, queue } => Ok(ChatStatus { busy, queue }),
+            _ => Err(GroveError::session("Unexpected status response")),
+        }
+    }
+
+    /// session.json 中的 agent 元数据（可用 mode / model 等）
+    pub fn metadata(&self) -> Option<super::SessionMetadata> {
+        super::read_session_metadata(&self.project_key, &self.task_id, &self.chat_id)
+    }
+
+    /// 磁盘上的完整历史（已合并 chunk / tool 事件）
+    pub fn history(&self) -> Vec<AcpUpdate> {
+        chat_history::compact_events(chat_history::load_history(
+            &self.project_key,
+            &self.task_id,
+            &self.chat_id,
+        ))
+    }
+
+    /// 跟随 history.jsonl 的增量读取器
+    pub fn tail(&self) -> HistoryTail {
+        HistoryTail::new(chat_history::history_file_path(
+            &self.project_key,
+            &self.task_id,
+            &self.chat_id,
+        ))
+    }
+}
+
+/// Owner 侧的瞬时状态（不落盘）
+#[derive(Debug, Clone, Default)]
+pub struct ChatStatus {
+    pub busy: bool,
+    pub queue: Vec<QueuedMessage>,
+}
+
+/// 在任务下新建一个 ACP chat 记录（session 在第一次 `connect` 时才启动）
+///
+/// `work_subdir` 需已经过 `tasks::normalize_work_subdir` 校验。
+pub fn create_chat(
+    project_key: &str,
+    task_id: &str,
+    agent: &str,
+    title: Option<String>,
+    work_subdir: Option<String>,
+) -> Result<tasks::ChatSession> {
+    let now = chrono::Utc::now();
+    let chat = tasks::ChatSession {
+        id: tasks::generate_chat_id(),
+        title: title.unwrap_or_else(|| format!("New Chat {}", now.format("%Y-%m-%d %H:%M"))),
+        agent: agent.to_string(),
+        acp_session_id: None,
+        created_at: now,
+        duty: None,
+        launch_mode: "acp".to_string(),
+        work_subdir,
+    };
+    tasks::add_chat_session(project_key, task_id, chat.clone())?;
+    Ok(chat)
+}
+
+/// 默认 agent：config 里的 `acp.agent_command`，否则第一个已安装的 ACP agent
+pub fn default_agent() -> Option<String> {
+    crate::storage::config::load_config()
+        .acp
+        .agent_command
+        .or_else(super::pick_first_available_acp_agent)
+}
+
+/// 一次 `HistoryTail::poll` 的结果
+#[derive(Debug, Default)]
+pub struct TailBatch {
+    /// 文件被截断 / 重写过，调用方应丢弃之前的 transcript
+    pub reset: bool,
+    pub events: Vec<AcpUpdate>,
+}
+
+/// 增量读取 history.jsonl：只解析上次之后新 append 的完整行
+#[derive(Debug)]
+pub struct HistoryTail {
+    path: PathBuf,
+    offset: u64,
+    started: bool,
+}
+
+impl HistoryTail {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            offset: 0,
+            started: false,
+        }
+    }
+
+    pub fn poll(&mut self) -> TailBatch {
+        let mut batch = TailBatch::default();
+        let Ok(mut file) = fs::File::open(&self.path) else {
+            return batch;
+        };
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        let mut skip_partial = false;
+        if !self.started {
+            self.started = true;
+            if len > TAIL_INITIAL_BYTES {
+                self.offset = len - TAIL_INITIAL_BYTES;
+                skip_partial = true;
+            }
+        } else if len < self.offset {
+            self.offset = 0;
+            batch.reset = true;
+        }
+        if len == self.offset || file.seek(SeekFrom::Start(self.offset)).is_err() {
+            return batch;
+        }
+
+        let mut reader = std::io::BufReader::new(file);
+        if skip_partial {
+            let mut discard = Vec::new();
+            self.offset += reader.read_until(b'\n', &mut discard).unwrap_or(0) as u64;
+        }
+
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            let n = reader.read_until(b'\n', &mut line).unwrap_or(0);
+            // 没有换行 = writer 还没写完这一行，下次再读
+            if n == 0 || line.last() != Some(&b'\n') {
+                break;
+            }
+            self.offset += n as u64;
+            if let Ok(event) = serde_json::from_slice::<AcpUpdate>(&line) {
+                batch.events.push(event);
+            }
+        }
+        batch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn append(path: &std::path::Path, raw: &str) {
+        let mut f = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        f.write_all(raw.as_bytes()).unwrap();
+    }
+
+    fn chunk(text: &str) -> String {
+        serde_json::to_string(&AcpUpdate::MessageChunk {
+            text: text.to_string(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn tail_reads_only_complete_new_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let mut tail = HistoryTail::new(path.clone());
+        assert!(tail.poll().events.is_empty());
+
+        append(&path, &format!("{}\n", chunk("a")));
+        let line_b = chunk("b");
+        let (head, rest) = line_b.split_at(5);
+        append(&path, head);
+        let batch = tail.poll();
+        assert_eq!(batch.events.len(), 1);
+        assert!(!batch.reset);
+
+        append(&path, &format!("{}\n", rest));
+        let batch = tail.poll();
+        assert!(matches!(
+            batch.events.as_slice(),
+            [AcpUpdate::MessageChunk { text }] if text == "b"
+        ));
+        assert!(tail.poll().events.is_empty());
+    }
+
+    #[test]
+    fn tail_resets_when_file_shrinks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        append(&path, &format!("{}\n{}\n", chunk("a"), chunk("b")));
+        let mut tail = HistoryTail::new(path.clone());
+        assert_eq!(tail.poll().events.len(), 2);
+
+        fs::write(&path, format!("{}\n", chunk("c"))).unwrap();
+        let batch = tail.poll();
+        assert!(batch.reset);
+        assert_eq!(batch.events.len(), 1);
+    }
+
+    #[test]
+    fn detached_client_refuses_commands() {
+        let client = ChatClient {
+            project_key: "p".into(),
+            task_id: "t".into(),
+            chat_id: "c".into(),
+            access: None,
+        };
+        assert_eq!(client.host(), ChatHost::Detached);
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        assert!(rt.block_on(client.send(SocketCommand::Cancel)).is_err());
+    }
+}
@@ -6,6 +6,8 @@
 #![allow(dead_code)] // Public API — used by CLI now, Web frontend later
 
 pub mod adapter;
+pub mod client;
+mod mux;
 
 // ACP 0.11 migration shim.
 //
@@ -40,7 +42,7 @@ static ACP_SESSIONS: once_cell::sync::Lazy<RwLock<HashMap<String, Arc<AcpSession
 /// ACP 会话句柄 — 外部持有，用于查询状态和发送操作
 pub struct AcpSessionHandle {
     pub key: String,
-    pub update_tx: broadcast::Sender<AcpUpdate>,
+    pub update_tx: broadcast::Sender<AcpEvent>,
     cmd_tx: mpsc::Sender<AcpCommand>,
     /// Agent info stored after initialization: (session_id, name, version)
     pub agent_info: std::sync::RwLock<Option<(String, String, String)>>,
@@ -191,6 +193,20 @@ enum AcpCommand {
     },
 }
 
+/// 广播给订阅者的更新，附带持久化到 history.jsonl 时分配的 seq
+/// （不持久化的事件为 None）。WS 把 seq 转发给前端作为重连回放的 cursor。
+#[derive(Debug, Clone)]
+pub struct AcpEvent {
+    pub seq: Option<u64>,
+    pub update: AcpUpdate,
+}
+
+impl From<AcpUpdate> for AcpEvent {
+    fn from(update: AcpUpdate) -> Self {
+        Self { seq: None, update }
+    }
+}
+
 /// 从 agent 接收的流式更新
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -249,6 +265,11 @@ pub enum AcpUpdate {
         /// 才透出 — 这里也带上,前端按更新覆盖。
         #[serde(default, skip_serializing_if = "Option::is_none")]
         raw_input: Option<serde_json::Value>,
+        /// 该 update 的发出时间;终态(completed / failed / cancelled)update 的
+        /// 时间即工具结束时间,与 `ToolCall.timestamp` 一起算出工具耗时。
+        /// 老 history.jsonl 无此字段。
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        timestamp: Option<DateTime<Utc>>,
     },
     /// 权限请求（带选项，等待用户交互）。`id` 是 ACP tool_call.id，
     /// 用于把后续的 PermissionResponse 精确对应到这条 Request；老历史里的
@@ -396,6 +417,11 @@ pub struct TurnUsage {
     pub total_tokens: u64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cached_read_tokens: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_write_tokens: Option<u64>,
+    /// 思考 / reasoning token（Claude extended thinking、Codex reasoning）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thought_tokens: Option<u64>,
 }
 
 /// Latest context-window usage snapshot for a chat. Persisted into
@@ -631,6 +657,19 @@ pub enum SocketCommand {
         option_id: String,
     },
     Kill,
+    /// 加入待发送队列（agent 空闲后自动发出）
+    QueueMessage {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        config: Option<QueuedConfig>,
+    },
+    /// 按 id 移除一条排队消息
+    DequeueMessage {
+        id: String,
+    },
+    ClearQueue,
+    /// 查询 busy + 队列（这两者不落盘，跨进程只能问 owner）
+    Status,
 }
 
 /// Unix socket 响应
@@ -638,7 +677,14 @@ pub enum SocketCommand {
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SocketResponse {
     Ok,
-    Error { message: String },
+    Error {
+        message: String,
+    },
+    Status {
+        busy: bool,
+        #[serde(default)]
+        queue: Vec<QueuedMessage>,
+    },
 }
 
 /// Session 访问方式（本地进程内 vs 远程 socket）
@@ -686,6 +732,23 @@ pub struct AcpStartConfig {
     /// already in chat history. Wrapped in a `<grove-meta>` envelope of type
     /// `custom_agent_init` (see `agent_graph::inject::build_custom_agent_init_prompt`).
     pub persona_injection: Option<PersonaInjection>,
+    /// Mode / model requested for a **fresh** session (e.g. `grove_start_chat`
+    /// params). Override the project's pinned / last-used defaults
+    /// (`storage::chat_defaults`); matched like persona settings.
+    pub initial_mode: Option<String>,
+    pub initial_model: Option<String>,
+    /// 与同一任务的其他 chat 共享一个 agent 进程（见 `mux`，仅 local agent）
+    pub share_process: bool,
+}
+
+/// 该 agent 是否配置为多个 chat 共享进程（`[acp] shared_process_agents`，
+/// 只对支持单进程多 session 的 agent 开启）
+pub fn shares_agent_process(agent_name: &str) -> bool {
+    crate::storage::config::load_config()
+        .acp
+        .shared_process_agents
+        .iter()
+        .any(|name| name == agent_name)
 }
 
 /// Custom Agent (persona) identity bundle injected once per fresh session.
@@ -828,8 +891,8 @@ fn load_plugin_mcp_servers(base_env: &HashMap<String, String>) -> Vec<acp::McpSe
         let _ = std::fs::create_dir_all(&storage_root);
         if crate::plugins::runtime::is_node_command(&command) {
             if !crate::plugins::runtime::node_supports_permissions(&command) {
-                eprintln!(
-                    "grove: skipping MCP server for plugin '{}': node >= {} is required \
+                tracing::warn!(
+                    "skipping MCP server for plugin '{}': node >= {} is required \
                      for enforced permissions (check `node --version`)",
                     plugin.name,
                     crate::plugins::runtime::MIN_NODE_MAJOR
@@ -1429,6 +1492,7 @@ async fn handle_session_notification(
                 content,
                 locations: locations.clone(),
                 raw_input: update.fields.raw_input.clone(),
+                timestamp: Some(Utc::now()),
             });
 
             // 检测 Plan File:Write 工具 completed 且在 plan mode 下写入 .md 文件
@@ -1496,6 +1560,7 @@ async fn handle_session_notification(
                     content: None,
                     locations: Default::default(),
                     raw_input: None,
+                    timestamp: Some(Utc::now()),
                 });
             }
         }
@@ -1628,6 +1693,52 @@ pub fn content_block_to_text(block: &acp::ContentBlock) -> String {
     }
 }
 
+/// 新 chat 首个 prompt 附带的项目笔记（`include_in_chat`）。agent 支持
+/// embedded context 时内嵌全文，否则（或超出预算）只给文件链接。
+fn project_notes_context_blocks(project_key: &str, embedded: bool) -> Vec<acp::ContentBlock> {
+    let notes = match crate::storage::project_notes::chat_context_notes(project_key) {
+        Ok(notes) => notes,
+        Err(e) => {
+            tracing::warn!("Failed to load project notes for chat context: {}", e);
+            return Vec::new();
+        }
+    };
+    notes
+        .into_iter()
+        .map(|n| {
+            let uri = format!("file://{}", n.path.display());
+            match n.content {
+                Some(text) if embedded => acp::ContentBlock::Resource(acp::EmbeddedResource::new(
+                    acp::EmbeddedResourceResource::TextResourceContents(
+                        acp::TextResourceContents::new(text, uri).mime_type("text/markdown"),
+                    ),
+                )),
+                _ => acp::ContentBlock::ResourceLink(
+                    acp::ResourceLink::new(n.note.title.clone(), uri)
+                        .mime_type("text/markdown".to_string())
+                        .title(n.note.title)
+                        .description("Grove project note".to_string()),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// 新 session 首个 prompt 前置的指令前言（全局 / 项目 / agent 级），
+/// 支持 embedded context 时作为 resource 发送，否则退化为文本块
+fn instructions_context_block(text: String, embedded: bool) -> acp::ContentBlock {
+    if embedded {
+        acp::ContentBlock::Resource(acp::EmbeddedResource::new(
+            acp::EmbeddedResourceResource::TextResourceContents(
+                acp::TextResourceContents::new(text, "grove://instructions")
+                    .mime_type("text/markdown"),
+            ),
+        ))
+    } else {
+        format!("<grove-instructions>\n{}\n</grove-instructions>", text).into()
+    }
+}
+
 /// 将 ContentBlockData 转换为 ACP ContentBlock
 fn to_acp_content_block(block: &ContentBlockData) -> acp::ContentBlock {
     match block {
@@ -1777,7 +1888,7 @@ fn append_terminal_output(
 pub async fn get_or_start_session(
     key: String,
     config: AcpStartConfig,
-) -> crate::error::Result<(Arc<AcpSessionHandle>, broadcast::Receiver<AcpUpdate>)> {
+) -> crate::error::Result<(Arc<AcpSessionHandle>, broadcast::Receiver<AcpEvent>)> {
     // Serialize concurrent get_or_start for the same key. Without this, two
     // callers can both pass the read check below before either gets a chance
     // to insert, then both spawn full ACP subprocesses; the second insert
@@ -1847,7 +1958,7 @@ pub async fn get_or_start_session(
             rt.block_on(local.run_until(async move {
                 let key_clone = key.clone();
 
-                let (update_tx, update_rx) = broadcast::channel::<AcpUpdate>(256);
+                let (update_tx, update_rx) = broadcast::channel::<AcpEvent>(256);
                 let (cmd_tx, cmd_rx) = mpsc::channel::<AcpCommand>(32);
 
                 let handle = Arc::new(AcpSessionHandle {
@@ -1875,8 +1986,8 @@ pub async fn get_or_start_session(
                     is_busy: std::sync::atomic::AtomicBool::new(false),
                     last_assistant_text: Mutex::new(String::new()),
                     pending_text_separator: std::sync::atomic::AtomicBool::new(false),
-        last_user_prompt: Mutex::new(None),
-        last_plan: Mutex::new(None),
+                    last_user_prompt: Mutex::new(None),
+                    last_plan: Mutex::new(None),
                     last_permission_info: Mutex::new(None),
                     auth_methods: Mutex::new(Vec::new()),
                     pending_auth_retry: Mutex::new(None),
@@ -1980,7 +2091,10 @@ pub async fn get_or_start_session(
 
                 // 发送 handle 给调用方（在启动会话循环之前）
                 if result_tx.send(Ok((handle.clone(), update_rx))).is_err() {
-                    eprintln!("[ACP] result_tx send failed — caller dropped before session started (key={})", key_clone);
+                    tracing::warn!(
+                        "result_tx send failed — caller dropped before session started (key={})",
+                        key_clone
+                    );
                 }
 
                 // 运行会话循环（阻塞直到 Kill 或错误）
@@ -1992,22 +2106,32 @@ pub async fn get_or_start_session(
                 let session_result = run_acp_session(handle, config, cmd_rx).await;
                 match &session_result {
                     Ok(()) => {
-                        eprintln!(
-                            "[ACP] session ended normally (key={} agent={} task={} chat={:?})",
-                            key_clone, session_agent_name, session_task_id, session_chat_id
+                        tracing::info!(
+                            "session ended normally (key={} agent={} task={} chat={:?})",
+                            key_clone,
+                            session_agent_name,
+                            session_task_id,
+                            session_chat_id
                         );
                     }
                     Err(e) => {
-                        eprintln!(
-                            "[ACP] session ended with error (key={} agent={} task={} chat={:?}): {}",
-                            key_clone, session_agent_name, session_task_id, session_chat_id, e
+                        tracing::warn!(
+                            "session ended with error (key={} agent={} task={} chat={:?}): {}",
+                            key_clone,
+                            session_agent_name,
+                            session_task_id,
+                            session_chat_id,
+                            e
+                        );
+                        let _ = update_tx.send(
+                            AcpUpdate::Error {
+                                message: format!("ACP session error: {}", e),
+                            }
+                            .into(),
                         );
-                        let _ = update_tx.send(AcpUpdate::Error {
-                            message: format!("ACP session error: {}", e),
-                        });
                     }
                 }
-                let _ = update_tx.send(AcpUpdate::SessionEnded);
+                let _ = update_tx.send(AcpUpdate::SessionEnded.into());
 
                 // Normal-exit cleanup. Mark EndGuard finalized FIRST so any
                 // panic during the cleanup ops below doesn't trigger Drop's
@@ -2047,7 +2171,7 @@ pub async fn get_or_start_session(
             } else {
                 "unknown panic".to_string()
             };
-            eprintln!("[Grove] ACP session thread panicked: {}", msg);
+            tracing::error!("ACP session thread panicked: {}", msg);
         }
     });
 
@@ -2109,6 +2233,12 @@ async fn run_acp_session(
         let (r, w) = connect_remote_agent(&config).await?;
         reader = Box::new(r);
         writer = Box::new(w);
+    } else if config.share_process && config.chat_id.is_some() {
+        // Shared: 接入同任务的共享 agent 进程，子进程生命周期由 mux 管理
+        child = None;
+        let (r, w) = mux::connect_shared(&config).await?;
+        reader = Box::new(r.compat());
+        writer = Box::new(w.compat_write());
     } else {
         // Pre-warm npm cache for npx-spawned agents. First-run npx fetches
         // can stall for ~30s; without this hint the user stares at
@@ -2352,16 +2482,18 @@ async fn run_acp_session(
     if let Some(ref mut c) = child {
         match c.try_wait() {
             Ok(Some(status)) => {
-                eprintln!(
-                    "[ACP] agent process had already exited when session I/O ended: {} (agent={})",
-                    status, agent_name_for_log
+                tracing::warn!(
+                    "agent process had already exited when session I/O ended: {} (agent={})",
+                    status,
+                    agent_name_for_log
                 );
             }
             Ok(None) => { /* still running — we're the ones tearing it down below */ }
             Err(e) => {
-                eprintln!(
-                    "[ACP] failed to check agent process status (agent={}): {}",
-                    agent_name_for_log, e
+                tracing::warn!(
+                    "failed to check agent process status (agent={}): {}",
+                    agent_name_for_log,
+                    e
                 );
             }
         }
@@ -2428,9 +2560,9 @@ async fn drive_session(
     }
 
     /// Lowercase fuzzy-match a free-text query against `(id, name)` pairs.
-    /// Used by the Custom Agent (persona) layer to translate the user's
-    /// free-text `model` / `mode` / `effort` strings into the live session's
-    /// real ids.
+    /// Used by the Custom Agent (persona) layer and the project chat
+    /// defaults to translate free-text `model` / `mode` / `effort` strings
+    /// into the live session's real ids.
     ///
     /// Resolution order:
     ///   1. Exact lowercase id or name → return immediately (deterministic).
@@ -2459,8 +2591,8 @@ async fn drive_session(
             0 => None,
             1 => Some(hits[0].clone()),
             _ => {
-                eprintln!(
-                    "[ACP] Persona config: query '{}' matched {} options ambiguously \
+                tracing::warn!(
+                    "Session config: query '{}' matched {} options ambiguously \
                      ({}); leaving agent default.",
                     query,
                     hits.len(),
@@ -2655,6 +2787,9 @@ async fn drive_session(
             .and_then(|c| c.acp_session_id)
     });
 
+    // 全新 session（非 resume / load）的首个 prompt 附带项目笔记
+    let mut project_context_pending = saved_id.is_none() || !(supports_resume || supports_load);
+
     let persist_session_id = |sid: &str| {
         if let Some(ref cid) = config.chat_id {
             let _ = crate::storage::tasks::update_chat_acp_session_id(
@@ -2687,6 +2822,16 @@ async fn drive_session(
                         &config.task_id,
                         cid,
                     );
+                    crate::storage::turn_manifests::clear_manifests(
+                        &config.project_key,
+                        &config.task_id,
+                        cid,
+                    );
+                    crate::storage::chat_pins::clear_pins(
+                        &config.project_key,
+                        &config.task_id,
+                        cid,
+                    );
                 }
             }
             let mcp_servers =
@@ -2898,7 +3043,7 @@ async fn drive_session(
                         .block_task()
                         .await;
                     if let Err(e) = inject {
-                        eprintln!("[ACP] Persona system prompt injection failed: {:?}", e);
+                        tracing::warn!("Persona system prompt injection failed: {:?}", e);
                     }
                 }
             }
@@ -2906,6 +3051,8 @@ async fn drive_session(
         }};
     }
 
+    // 没有 saved_id 的 chat 若已有历史，只可能是导入的外部 session，保留
+    let fresh_preserves_history = saved_id.is_none();
     let session_id = match (saved_id, supports_resume, supports_load) {
         // Resume 路线(优先):agent 支持 session/resume。不 replay 历史,所以
         // 完全不需要 suppress_emit + 300ms 那套抛弃 agent 回放的机制 — 直接发
@@ -2992,11 +3139,69 @@ async fn drive_session(
         }
         // Fresh 路线:无 saved_id,或有 saved_id 但 agent 既不支持 resume 也不支持
         // load。与现状一致。
-        _ => create_new_session!(false),
+        _ => create_new_session!(fresh_preserves_history),
     };
 
     let session_id_arc = acp::SessionId::new(&*session_id);
 
+    // 全新 session:应用启动参数或项目记住/固定的 mode 和 model(persona 自带
+    // 设置时只应用显式启动参数)。失败或匹配不到时保留 agent 默认值。
+    let (current_mode_id, current_model_id) = if project_context_pending {
+        let defaults = if config.persona_injection.is_none() {
+            crate::storage::chat_defaults::for_agent(&config.project_key, &config.agent_name)
+        } else {
+            Default::default()
+        };
+        let mut mode_id = current_mode_id;
+        let mut model_id = current_model_id;
+        let wanted_mode = config.initial_mode.as_deref().or(defaults.mode());
+        if let Some(id) = wanted_mode.and_then(|q| fuzzy_pick_id(q, &available_modes)) {
+            if mode_id.as_deref() != Some(id.as_str()) {
+                let resp = conn
+                    .send_request(acp::SetSessionModeRequest::new(
+                        session_id_arc.clone(),
+                        acp::SessionModeId::new(id.clone()),
+                    ))
+                    .block_task()
+                    .await;
+                match resp {
+                    Ok(_) => mode_id = Some(id),
+                    Err(e) => tracing::warn!("Failed to apply default mode {}: {:?}", id, e),
+                }
+            }
+        }
+        let wanted_model = config.initial_model.as_deref().or(defaults.model());
+        if let Some(id) = wanted_model.and_then(|q| fuzzy_pick_id(q, &available_models)) {
+            if model_id.as_deref() != Some(id.as_str()) {
+                let resp: Result<(), _> = if let Some(ref config_id) = model_config_id {
+                    conn.send_request(acp::SetSessionConfigOptionRequest::new(
+                        session_id_arc.clone(),
+                        acp::SessionConfigId::new(config_id.clone()),
+                        acp::SessionConfigValueId::new(id.clone()),
+                    ))
+                    .block_task()
+                    .await
+                    .map(|_| ())
+                } else {
+                    conn.send_request(acp::SetSessionModelRequest::new(
+                        session_id_arc.clone(),
+                        acp::ModelId::new(id.clone()),
+                    ))
+                    .block_task()
+                    .await
+                    .map(|_| ())
+                };
+                match resp {
+                    Ok(_) => model_id = Some(id),
+                    Err(e) => tracing::warn!("Failed to apply default model {}: {:?}", id, e),
+                }
+            }
+        }
+        (mode_id, model_id)
+    } else {
+        (current_mode_id, current_model_id)
+    };
+
     let prompt_capabilities = PromptCapabilitiesData {
         image: init_resp.agent_capabilities.prompt_capabilities.image,
         audio: init_resp.agent_capabilities.prompt_capabilities.audio,
@@ -3006,6 +3211,8 @@ async fn drive_session(
             .embedded_context,
     };
 
+    let embedded_context_capable = prompt_capabilities.embedded_context;
+
     if let Ok(mut info) = handle.agent_info.write() {
         *info = Some((
             session_id.clone(),
@@ -3227,6 +3434,23 @@ async fn drive_session(
                 for block in &attachments {
                     content_blocks.push(to_acp_content_block(block));
                 }
+                let mut instructions = Vec::new();
+                if std::mem::take(&mut project_context_pending) {
+                    instructions = crate::storage::instructions::effective(
+                        &config.project_key,
+                        &config.agent_name,
+                    );
+                    if let Some(text) = crate::storage::instructions::render(&instructions) {
+                        content_blocks.insert(
+                            0,
+                            instructions_context_block(text, embedded_context_capable),
+                        );
+                    }
+                    content_blocks.extend(project_notes_context_blocks(
+                        &config.project_key,
+                        embedded_context_capable,
+                    ));
+                }
 
                 // Grove-instrumented turn timer. `start_ts` 记录 send_request 这一刻的
                 // wall clock,用来算 duration 和写入 chat_token_usage.start_ts。
@@ -3234,6 +3458,72 @@ async fn drive_session(
                 // 等待时间,只反映 agent 真正"思考"了多久。
                 let turn_start_ts = chrono::Utc::now().timestamp();
 
+                // 每个 turn 前对工作区拍快照,供 chat 时间线按 turn diff / 回滚
+                if let Some(chat_id) = config.chat_id.clone() {
+                    let (pk, tid) = (config.project_key.clone(), config.task_id.clone());
+                    let wd = config.working_dir.to_string_lossy().to_string();
+                    let model = handle.current_model_id.lock().ok().and_then(|g| g.clone());
+                    let mode = handle.current_mode_id.lock().ok().and_then(|g| g.clone());
+                    let _ = tokio::task::spawn_blocking(move || {
+                        crate::operations::snapshots::begin_turn(
+                            &pk,
+                            &tid,
+                            &chat_id,
+                            &wd,
+                            turn_start_ts,
+                            (model, mode),
+                        );
+                    })
+                    .await;
+                }
+
+                // 每个 turn 记录复现清单(agent / model / prompt 哈希 / skills / HEAD)
+                if let Some(chat_id) = config.chat_id.clone() {
+                    let (pk, tid) = (config.project_key.clone(), config.task_id.clone());
+                    let wd = config.working_dir.to_string_lossy().to_string();
+                    let (name, version) = handle
+                        .agent_info
+                        .read()
+                        .ok()
+                        .and_then(|g| g.as_ref().map(|(_, n, v)| (n.clone(), v.clone())))
+                        .unwrap_or_else(|| (config.agent_name.clone(), String::new()));
+                    let agent = crate::operations::turn_manifests::TurnAgent {
+                        id: config.agent_name.clone(),
+                        name,
+                        version,
+                        model: handle.current_model_id.lock().ok().and_then(|g| g.clone()),
+                        mode: handle.current_mode_id.lock().ok().and_then(|g| g.clone()),
+                        instructions,
+                    };
+                    let prompt = handle
+                        .last_user_prompt
+                        .lock()
+                        .ok()
+                        .and_then(|g| g.clone())
+                        .unwrap_or_default();
+                    let _ = tokio::task::spawn_blocking(move || {
+                        // 记住本项目该 agent 最近使用的 mode / model,供新 chat 默认应用
+                        if let Err(e) = crate::storage::chat_defaults::remember(
+                            &pk,
+                            &agent.id,
+                            agent.mode.as_deref(),
+                            agent.model.as_deref(),
+                        ) {
+                            tracing::warn!("Failed to remember chat defaults: {}", e);
+                        }
+                        crate::operations::turn_manifests::begin_turn(
+                            &pk,
+                            &tid,
+                            &chat_id,
+                            &wd,
+                            turn_start_ts,
+                            agent,
+                            &prompt,
+                        );
+                    })
+                    .await;
+                }
+
                 // 用 SentRequest::block_task() 得到可被 select 的 future
                 let prompt_fut = conn
                     .send_request(acp::PromptRequest::new(
@@ -3325,6 +3615,29 @@ async fn drive_session(
                     break;
                 }
 
+                // turn 结束快照(在 Complete 之前,前端收到 Complete 即可查询)
+                if let Some(chat_id) = config.chat_id.clone() {
+                    let (pk, tid) = (config.project_key.clone(), config.task_id.clone());
+                    let wd = config.working_dir.to_string_lossy().to_string();
+                    let _ = tokio::task::spawn_blocking(move || {
+                        crate::operations::snapshots::end_turn(
+                            &pk,
+                            &tid,
+                            &chat_id,
+                            &wd,
+                            turn_start_ts,
+                        );
+                        crate::operations::turn_manifests::end_turn(
+                            &pk,
+                            &tid,
+                            &chat_id,
+                            &wd,
+                            turn_start_ts,
+                        );
+                    })
+                    .await;
+                }
+
                 match result {
                     Ok(resp) => {
                         // Prompt 成功 = agent 当前认账户已登录,任何 stale 的
@@ -3364,6 +3677,8 @@ async fn drive_session(
                             output_tokens: u.output_tokens,
                             total_tokens: u.total_tokens,
                             cached_read_tokens: u.cached_read_tokens,
+                            cached_write_tokens: u.cached_write_tokens,
+                            thought_tokens: u.thought_tokens,
                         });
                         // Layer A: persist per-turn usage to SQLite for stats.
                         // Best-effort — a write error here must not fail the turn.
@@ -3385,6 +3700,8 @@ async fn drive_session(
                                 model: model_owned.as_deref(),
                                 input_tokens: usage.input_tokens,
                                 cached_read_tokens: usage.cached_read_tokens,
+                                cached_write_tokens: usage.cached_write_tokens,
+                                thought_tokens: usage.thought_tokens,
                                 output_tokens: usage.output_tokens,
                                 total_tokens: usage.total_tokens,
                                 start_ts: turn_start_ts,
@@ -3393,7 +3710,7 @@ async fn drive_session(
                                 cost_currency: cost_owned.as_ref().map(|c| c.currency.as_str()),
                             };
                             if let Err(e) = crate::storage::token_usage::insert(&rec) {
-                                eprintln!("[token_usage] insert failed: {}", e);
+                                tracing::warn!("[token_usage] insert failed: {}", e);
                             }
                         }
                         handle.emit(AcpUpdate::Complete {
@@ -3403,6 +3720,28 @@ async fn drive_session(
                             end_ts: Some(turn_end_ts),
                             cost: cost_owned,
                         });
+                        // Auto-commit checkpoint (no-op unless enabled for the task)
+                        let (pk, tid) = (config.project_key.clone(), config.task_id.clone());
+                        std::thread::spawn(move || {
+                            crate::operations::checkpoints::on_turn_complete(&pk, &tid);
+                        });
+                        // "What was done" summary into the task notes (no-op unless
+                        // enabled for the project and this stop reason)
+                        if let Some(chat_id) = config.chat_id.clone() {
+                            let (pk, tid) = (config.project_key.clone(), config.task_id.clone());
+                            let wd = config.working_dir.to_string_lossy().to_string();
+                            let stop_reason = format!("{:?}", resp.stop_reason);
+                            std::thread::spawn(move || {
+                                crate::operations::task_summary::on_turn_complete(
+                                    &pk,
+                                    &tid,
+                                    &chat_id,
+                                    &wd,
+                                    turn_start_ts,
+                                    &stop_reason,
+                                );
+                            });
+                        }
                     }
                     Err(e) => {
                         handle.emit(AcpUpdate::Busy { value: false });
@@ -3911,9 +4250,10 @@ impl AcpSessionHandle {
         }
 
         // 实时 append 到磁盘
+        let mut seq = None;
         if crate::storage::chat_history::should_persist(&update) {
             if let Some(ref chat_id) = self.chat_id {
-                crate::storage::chat_history::append_event(
+                seq = crate::storage::chat_history::append_event(
                     &self.project_key,
                     &self.task_id,
                     chat_id,
@@ -4117,7 +4457,7 @@ impl AcpSessionHandle {
         }
 
         // broadcast
-        let _ = self.update_tx.send(update);
+        let _ = self.update_tx.send(AcpEvent { seq, update });
 
         if should_compact {
             if let Some(ref chat_id) = self.chat_id {
@@ -4223,7 +4563,7 @@ impl AcpSessionHandle {
     }
 
     /// 订阅更新流
-    pub fn subscribe(&self) -> broadcast::Receiver<AcpUpdate> {
+    pub fn subscribe(&self) -> broadcast::Receiver<AcpEvent> {
         self.update_tx.subscribe()
     }
 
@@ -4563,6 +4903,14 @@ pub fn get_session_handle(key: &str) -> Option<Arc<AcpSessionHandle>> {
         .and_then(|sessions| sessions.get(key).cloned())
 }
 
+/// 当前活跃的 ACP 会话数量
+pub fn session_count() -> usize {
+    ACP_SESSIONS
+        .read()
+        .map(|sessions| sessions.len())
+        .unwrap_or(0)
+}
+
 /// 检查 ACP 会话是否存在
 pub fn session_exists(key: &str) -> bool {
     ACP_SESSIONS
@@ -4699,10 +5047,10 @@ pub fn new_handle_for_test(
     chat_id: &str,
 ) -> (
     Arc<AcpSessionHandle>,
-    broadcast::Receiver<AcpUpdate>,
+    broadcast::Receiver<AcpEvent>,
     TestSessionGuard,
 ) {
-    let (update_tx, update_rx) = broadcast::channel::<AcpUpdate>(256);
+    let (update_tx, update_rx) = broadcast::channel::<AcpEvent>(256);
     let (cmd_tx, mut cmd_rx) = mpsc::channel::<AcpCommand>(32);
 
     let handle = Arc::new(AcpSessionHandle {
@@ -4905,7 +5253,7 @@ async fn run_socket_listener(path: PathBuf, handle: Arc<AcpSessionHandle>) {
     let listener = match tokio::net::UnixListener::bind(&path) {
         Ok(l) => l,
         Err(e) => {
-            eprintln!("[ACP] Failed to bind socket {}: {}", path.display(), e);
+            tracing::error!("Failed to bind socket {}: {}", path.display(), e);
             return;
         }
     };
@@ -4918,14 +5266,14 @@ async fn run_socket_listener(path: PathBuf, handle: Arc<AcpSessionHandle>) {
                     if let Err(e) = handle_socket_connection(stream, &handle).await {
                         // BrokenPipe = client disconnected early, benign
                         if e.kind() != std::io::ErrorKind::BrokenPipe {
-                            eprintln!("[ACP] Socket connection error: {}", e);
+                            tracing::warn!("Socket connection error: {}", e);
                         }
                     }
                 });
             }
             Err(e) => {
                 // Listener closed
-                eprintln!("[ACP] Socket accept error: {}", e);
+                tracing::warn!("Socket accept error: {}", e);
                 break;
             }
         }
@@ -5007,6 +5355,36 @@ async fn dispatch_socket_command(handle: &AcpSessionHandle, cmd: SocketCommand)
                 message: e.to_string(),
             },
         },
+        // 队列操作与 WS 的 ClientMessage 同语义：变更后广播 QueueUpdate，
+        // 让同时打开的 Web 前端保持同步。
+        SocketCommand::QueueMessage { text, config } => {
+            let config = config.or_else(|| Some(handle.snapshot_config()));
+            let messages =
+                handle.queue_message(QueuedMessage::new(text, vec![], None, false, config));
+            handle.emit(AcpUpdate::QueueUpdate { messages });
+            SocketResponse::Ok
+        }
+        SocketCommand::DequeueMessage { id } => {
+            let (found, messages) = handle.dequeue_message_by_id(&id);
+            handle.emit(AcpUpdate::QueueUpdate { messages });
+            if found {
+                SocketResponse::Ok
+            } else {
+                handle.emit(AcpUpdate::QueueMessageGone { id });
+                SocketResponse::Error {
+                    message: "Queued message already sent".to_string(),
+                }
+            }
+        }
+        SocketCommand::ClearQueue => {
+            let messages = handle.clear_queue();
+            handle.emit(AcpUpdate::QueueUpdate { messages });
+            SocketResponse::Ok
+        }
+        SocketCommand::Status => SocketResponse::Status {
+            busy: handle.is_busy.load(std::sync::atomic::Ordering::Relaxed),
+            queue: handle.get_queue(),
+        },
     }
 }
 
@@ -5283,10 +5661,7 @@ pub fn init_agent_defaults() {
 
     if changed {
         if let Err(e) = crate::storage::config::save_config(&config) {
-            eprintln!(
-                "[warning] Failed to persist auto-selected agent defaults: {}",
-                e
-            );
+            tracing::warn!("Failed to persist auto-selected agent defaults: {}", e);
         }
     }
 }
@@ -5369,12 +5744,14 @@ fn notify_acp_event(
             }
         }
     };
-    if let Some(s) = sound {
+    // 勿扰：跳过声音和横幅，下面的 update_hook 照常记录
+    let muted = notif_cfg.is_muted_at(chrono::Local::now().time());
+    if let Some(s) = sound.filter(|_| !muted) {
         hooks::play_sound(s);
     }
 
     // ── 系统横幅 ───────────────────────────────────────────────────────────
-    {
+    if !muted {
         let project_name = crate::storage::workspace::load_project_by_hash(project_key)
             .ok()
             .flatten()
@@ -5647,6 +6024,13 @@ mod tests {
                 option_id: "allow_once".into(),
             },
             SocketCommand::Kill,
+            SocketCommand::QueueMessage {
+                text: "later".into(),
+                config: None,
+            },
+            SocketCommand::DequeueMessage { id: "q-1".into() },
+            SocketCommand::ClearQueue,
+            SocketCommand::Status,
         ];
 
         for cmd in &commands {
@@ -5688,6 +6072,17 @@ mod tests {
         assert!(json.contains(r#""type":"error""#));
         let parsed: SocketResponse = serde_json::from_str(&json).unwrap();
         assert!(matches!(parsed, SocketResponse::Error { .. }));
+
+        let status = SocketResponse::Status {
+            busy: true,
+            queue: vec![QueuedMessage::new("next".into(), vec![], None, false, None)],
+        };
+        let json = serde_json::to_string(&status).unwrap();
+        assert!(json.contains(r#""type":"status""#));
+        let parsed: SocketResponse = serde_json::from_str(&json).unwrap();
+        assert!(
+            matches!(parsed, SocketResponse::Status { busy: true, ref queue } if queue.len() == 1)
+        );
     }
 
     #[test]
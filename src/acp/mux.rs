@@ -0,0 +1,658 @@
+//! 共享 agent 进程（`[acp] shared_process_agents`）
+//!
+//! 同一任务、同一 agent 命令的多个 chat 复用一个子进程。每个 chat 仍然有自己的
+//! session 线程、`AcpSessionHandle`、历史和消息队列，只是 transport 从子进程
+//! stdio 换成一条内存 duplex 管道（与 remote agent 的桥接方式相同）。mux 在
+//! NDJSON 层做 JSON-RPC 转发：
+//! - chat 发出的请求 id 改写成进程内唯一的 id，响应回来时再换回原 id
+//! - `initialize` 只发给 agent 一次，之后接入的 chat 直接拿缓存的结果
+//! - 从响应里的 `result.sessionId` 和请求里的 `params.sessionId` 登记 session
+//!   归属，agent 发来的通知 / 请求按 `params.sessionId` 路由到对应 chat
+//! - 最后一个 chat 断开时结束子进程
+//!
+//! 子进程跑在独立线程的 runtime 上，不随首个 chat 的线程退出。
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader, DuplexStream};
+use tokio::io::{ReadHalf, WriteHalf};
+use tokio::sync::{mpsc, oneshot};
+
+use super::AcpStartConfig;
+
+/// 每个 chat 管道的缓冲大小
+const PIPE_BUFFER: usize = 1 << 20;
+
+/// 尚未登记归属的 session 最多暂存多少条消息
+const MAX_ORPHAN_LINES: usize = 256;
+
+/// 只属于单个 chat 的环境变量，不带进共享进程（per-session MCP 注入里已带上）
+const PER_CHAT_ENV: &[&str] = &["GROVE_CHAT_ID", "GROVE_MCP_TOKEN"];
+
+/// 共享进程注册表：进程 key → 新 chat 接入通道
+static SHARED_AGENTS: once_cell::sync::Lazy<
+    Mutex<HashMap<String, mpsc::UnboundedSender<DuplexStream>>>,
+> = once_cell::sync::Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 同一任务、同一工作目录、同一命令行的 chat 共享进程
+fn share_key(config: &AcpStartConfig) -> String {
+    let mut parts = vec![
+        config.project_key.clone(),
+        config.task_id.clone(),
+        config.working_dir.display().to_string(),
+        config.agent_command.clone(),
+    ];
+    parts.extend(config.agent_args.iter().cloned());
+    parts.join("\u{1f}")
+}
+
+/// 接入（必要时启动）共享 agent 进程，返回该 chat 的读写两端
+pub(super) async fn connect_shared(
+    config: &AcpStartConfig,
+) -> crate::error::Result<(ReadHalf<DuplexStream>, WriteHalf<DuplexStream>)> {
+    let key = share_key(config);
+    let (client_end, mut mux_end) = tokio::io::duplex(PIPE_BUFFER);
+
+    let ready_rx = {
+        let mut agents = SHARED_AGENTS.lock().unwrap();
+        if let Some(tx) = agents.get(&key) {
+            match tx.send(mux_end) {
+                Ok(()) => return Ok(tokio::io::split(client_end)),
+                // 进程已退出但还没来得及注销
+                Err(mpsc::error::SendError(end)) => mux_end = end,
+            }
+        }
+
+        let resolved = crate::check::resolve_program(&config.agent_command).ok_or_else(|| {
+            crate::error::GroveError::Session(format!(
+                "Failed to spawn ACP agent '{}': program not found on PATH",
+                config.agent_command
+            ))
+        })?;
+        let mut env_vars = config.env_vars.clone();
+        for name in PER_CHAT_ENV {
+            env_vars.remove(*name);
+        }
+        let spec = SpawnSpec {
+            key: key.clone(),
+            command: config.agent_command.clone(),
+            program: resolved,
+            args: config.agent_args.clone(),
+            working_dir: config.working_dir.clone(),
+            env_vars,
+            log_path: super::agent_log_path(&config.project_key, &config.task_id, None),
+        };
+
+        // 先注册再启动：并发接入的 chat 排在通道里，不会各起一个进程
+        let (conn_tx, conn_rx) = mpsc::unbounded_channel();
+        let _ = conn_tx.send(mux_end);
+        agents.insert(key, conn_tx.clone());
+
+        let (ready_tx, ready_rx) = oneshot::channel();
+        std::thread::Builder::new()
+            .name("acp-shared-agent".to_string())
+            .spawn(move || run_shared_agent(spec, conn_tx, conn_rx, ready_tx))
+            .map_err(|e| {
+                crate::error::GroveError::Session(format!(
+                    "Failed to start shared agent thread: {}",
+                    e
+                ))
+            })?;
+        ready_rx
+    };
+
+    match ready_rx.await {
+        Ok(Ok(())) => Ok(tokio::io::split(client_end)),
+        Ok(Err(msg)) => Err(crate::error::GroveError::Session(msg)),
+        Err(_) => Err(crate::error::GroveError::Session(
+            "Shared agent thread terminated".to_string(),
+        )),
+    }
+}
+
+struct SpawnSpec {
+    key: String,
+    command: String,
+    program: PathBuf,
+    args: Vec<String>,
+    working_dir: PathBuf,
+    env_vars: HashMap<String, String>,
+    log_path: PathBuf,
+}
+
+/// 注销共享进程（只删自己的那一项，避免误删之后新起的进程）
+fn unregister(key: &str, own: &mpsc::UnboundedSender<DuplexStream>) {
+    let mut agents = SHARED_AGENTS.lock().unwrap();
+    if agents.get(key).is_some_and(|tx| tx.same_channel(own)) {
+        agents.remove(key);
+    }
+}
+
+/// 共享进程线程：启动子进程并转发，直到 agent 退出或最后一个 chat 断开
+fn run_shared_agent(
+    spec: SpawnSpec,
+    own: mpsc::UnboundedSender<DuplexStream>,
+    conn_rx: mpsc::UnboundedReceiver<DuplexStream>,
+    ready_tx: oneshot::Sender<Result<(), String>>,
+) {
+    let rt = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(rt) => rt,
+        Err(e) => {
+            unregister(&spec.key, &own);
+            let _ = ready_tx.send(Err(format!("Failed to create runtime: {}", e)));
+            return;
+        }
+    };
+    let local = tokio::task::LocalSet::new();
+    local.block_on(&rt, async move {
+        let spawned = tokio::process::Command::new(&spec.program)
+            .args(&spec.args)
+            .current_dir(&spec.working_dir)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .envs(&spec.env_vars)
+            .kill_on_drop(true)
+            .spawn();
+        let mut proc = match spawned {
+            Ok(proc) => proc,
+            Err(e) => {
+                unregister(&spec.key, &own);
+                let _ = ready_tx.send(Err(format!(
+                    "Failed to spawn ACP agent '{}' ({}): {}",
+                    spec.command,
+                    spec.program.display(),
+                    e
+                )));
+                return;
+            }
+        };
+        let _ = ready_tx.send(Ok(()));
+
+        if let Some(stderr) = proc.stderr.take() {
+            tokio::task::spawn_local(super::drain_stderr_to_file(stderr, spec.log_path.clone()));
+        }
+        let stdin = proc.stdin.take().unwrap();
+        let stdout = proc.stdout.take().unwrap();
+        serve(&spec.key, &own, conn_rx, stdin, stdout).await;
+
+        unregister(&spec.key, &own);
+        let _ = proc.kill().await;
+    });
+}
+
+enum Event {
+    Agent(String),
+    AgentClosed,
+    Client(u64, String),
+    ClientClosed(u64),
+}
+
+async fn write_line<W: AsyncWrite + Unpin>(w: &mut W, line: &str) -> std::io::Result<()> {
+    w.write_all(line.as_bytes()).await?;
+    w.write_all(b"\n").await?;
+    w.flush().await
+}
+
+/// 转发主循环
+async fn serve(
+    key: &str,
+    own: &mpsc::UnboundedSender<DuplexStream>,
+    mut conn_rx: mpsc::UnboundedReceiver<DuplexStream>,
+    mut stdin: tokio::process::ChildStdin,
+    stdout: tokio::process::ChildStdout,
+) {
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel::<Event>();
+
+    let (agent_tx, mut agent_rx) = mpsc::unbounded_channel::<String>();
+    tokio::task::spawn_local(async move {
+        while let Some(line) = agent_rx.recv().await {
+            if write_line(&mut stdin, &line).await.is_err() {
+                break;
+            }
+        }
+    });
+    {
+        let events = event_tx.clone();
+        tokio::task::spawn_local(async move {
+            let mut lines = BufReader::new(stdout).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if !line.trim().is_empty() {
+                    let _ = events.send(Event::Agent(line));
+                }
+            }
+            let _ = events.send(Event::AgentClosed);
+        });
+    }
+
+    let mut router = Router::default();
+    let mut writers: HashMap<u64, mpsc::UnboundedSender<String>> = HashMap::new();
+    let mut next_client = 0u64;
+    let mut add_client =
+        |stream: DuplexStream,
+         router: &mut Router,
+         writers: &mut HashMap<u64, mpsc::UnboundedSender<String>>| {
+            let id = next_client;
+            next_client += 1;
+            let (r, mut w) = tokio::io::split(stream);
+            let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+            writers.insert(id, tx);
+            router.add_client(id);
+            tokio::task::spawn_local(async move {
+                while let Some(line) = rx.recv().await {
+                    if write_line(&mut w, &line).await.is_err() {
+                        break;
+                    }
+                }
+                let _ = w.shutdown().await;
+            });
+            let events = event_tx.clone();
+            tokio::task::spawn_local(async move {
+                let mut lines = BufReader::new(r).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if !line.trim().is_empty() {
+                        let _ = events.send(Event::Client(id, line));
+                    }
+                }
+                let _ = events.send(Event::ClientClosed(id));
+            });
+        };
+
+    loop {
+        tokio::select! {
+            Some(stream) = conn_rx.recv() => add_client(stream, &mut router, &mut writers),
+            Some(event) = event_rx.recv() => {
+                let out = match event {
+                    Event::Agent(line) => router.on_agent_line(&line),
+                    Event::AgentClosed => break,
+                    Event::Client(id, line) => router.on_client_line(id, &line),
+                    Event::ClientClosed(id) => {
+                        writers.remove(&id);
+                        router.remove_client(id)
+                    }
+                };
+                for o in out {
+                    match o {
+                        Out::Agent(line) => {
+                            let _ = agent_tx.send(line);
+                        }
+                        Out::Client(id, line) => {
+                            if let Some(tx) = writers.get(&id) {
+                                let _ = tx.send(line);
+                            }
+                        }
+                    }
+                }
+                if writers.is_empty() {
+                    // 持锁检查，避免和正在接入的 chat 竞争
+                    let mut agents = SHARED_AGENTS.lock().unwrap();
+                    match conn_rx.try_recv() {
+                        Ok(stream) => {
+                            drop(agents);
+                            add_client(stream, &mut router, &mut writers);
+                        }
+                        Err(_) => {
+                            if agents.get(key).is_some_and(|tx| tx.same_channel(own)) {
+                                agents.remove(key);
+                            }
+                            break;
+                        }
+                    }
+                }
+            }
+            else => break,
+        }
+    }
+}
+
+/// 路由结果：发给 agent，或发给某个 chat
+#[derive(Debug, PartialEq)]
+enum Out {
+    Agent(String),
+    Client(u64, String),
+}
+
+/// chat 发出、等待 agent 响应的请求
+struct Pending {
+    client: u64,
+    id: Value,
+    method: String,
+}
+
+#[derive(Default)]
+enum InitState {
+    #[default]
+    NotSent,
+    /// 首个 initialize 已发出，其余 chat 在等结果
+    InFlight(Vec<(u64, Value)>),
+    Done(Value),
+}
+
+/// JSON-RPC 路由状态（纯同步，便于测试）
+#[derive(Default)]
+struct Router {
+    next_id: u64,
+    clients: BTreeSet<u64>,
+    pending: HashMap<u64, Pending>,
+    /// sessionId → chat
+    sessions: HashMap<String, u64>,
+    /// 已断开 chat 的 session，之后的消息直接丢弃
+    closed: HashSet<String>,
+    /// agent 在响应登记归属之前就发来的消息
+    orphans: HashMap<String, Vec<String>>,
+    init: InitState,
+}
+
+fn session_id(msg: &Value) -> Option<&str> {
+    msg.pointer("/params/sessionId").and_then(Value::as_str)
+}
+
+fn error_response(id: Value, message: &str) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": -32603, "message": message },
+    })
+    .to_string()
+}
+
+impl Router {
+    fn add_client(&mut self, client: u64) {
+        self.clients.insert(client);
+    }
+
+    /// chat 断开：释放它的 session，并让 agent 停掉还在跑的 turn
+    fn remove_client(&mut self, client: u64) -> Vec<Out> {
+        self.clients.remove(&client);
+        let owned: Vec<String> = self
+            .sessions
+            .iter()
+            .filter(|(_, c)| **c == client)
+            .map(|(sid, _)| sid.clone())
+            .collect();
+        owned
+            .into_iter()
+            .map(|sid| {
+                self.sessions.remove(&sid);
+                let cancel = json!({
+                    "jsonrpc": "2.0",
+                    "method": "session/cancel",
+                    "params": { "sessionId": sid },
+                });
+                self.closed.insert(sid);
+                Out::Agent(cancel.to_string())
+            })
+            .collect()
+    }
+
+    /// 登记 session 归属，并补发之前暂存的消息
+    fn claim(&mut self, sid: &str, client: u64) -> Vec<Out> {
+        if self.closed.contains(sid) {
+            return Vec::new();
+        }
+        self.sessions.insert(sid.to_string(), client);
+        self.orphans
+            .remove(sid)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|line| Out::Client(client, line))
+            .collect()
+    }
+
+    fn on_client_line(&mut self, client: u64, line: &str) -> Vec<Out> {
+        let Ok(mut msg) = serde_json::from_str::<Value>(line) else {
+            return Vec::new();
+        };
+        let method = msg.get("method").and_then(Value::as_str).map(String::from);
+        let id = msg.get("id").cloned();
+        match (method, id) {
+            (Some(method), Some(id)) => self.client_request(client, method, id, msg),
+            (Some(method), None) => {
+                // 取消请求要带上改写后的 id
+                if method == "$/cancel_request" {
+                    let request_id = msg.pointer("/params/requestId").cloned();
+                    let global = self
+                        .pending
+                        .iter()
+                        .find(|(_, p)| p.client == client && Some(&p.id) == request_id.as_ref())
+                        .map(|(global, _)| *global);
+                    match global {
+                        Some(global) => msg["params"]["requestId"] = json!(global),
+                        None => return Vec::new(),
+                    }
+                }
+                vec![Out::Agent(msg.to_string())]
+            }
+            // 对 agent 请求的响应：id 本来就是 agent 分配的
+            (None, Some(_)) => vec![Out::Agent(line.to_string())],
+            (None, None) => Vec::new(),
+        }
+    }
+
+    fn client_request(
+        &mut self,
+        client: u64,
+        method: String,
+        id: Value,
+        mut msg: Value,
+    ) -> Vec<Out> {
+        let mut out = Vec::new();
+        if method == "initialize" {
+            match self.init {
+                InitState::Done(ref result) => {
+                    let reply = json!({ "jsonrpc": "2.0", "id": id, "result": result });
+                    return vec![Out::Client(client, reply.to_string())];
+                }
+                InitState::InFlight(ref mut waiters) => {
+                    waiters.push((client, id));
+                    return Vec::new();
+                }
+                InitState::NotSent => self.init = InitState::InFlight(Vec::new()),
+            }
+        }
+        // session/load、session/resume 以及之后针对该 session 的请求
+        if let Some(sid) = session_id(&msg).map(String::from) {
+            out.extend(self.claim(&sid, client));
+        }
+        let global = self.next_id;
+        self.next_id += 1;
+        msg["id"] = json!(global);
+        self.pending.insert(global, Pending { client, id, method });
+        out.push(Out::Agent(msg.to_string()));
+        out
+    }
+
+    fn on_agent_line(&mut self, line: &str) -> Vec<Out> {
+        let Ok(msg) = serde_json::from_str::<Value>(line) else {
+            return Vec::new();
+        };
+        let is_request = msg.get("method").is_some();
+        let id = msg.get("id").cloned();
+
+        if !is_request {
+            let Some(pending) = id
+                .as_ref()
+                .and_then(Value::as_u64)
+                .and_then(|global| self.pending.remove(&global))
+            else {
+                return Vec::new();
+            };
+            return self.agent_response(pending, msg);
+        }
+
+        match session_id(&msg).map(String::from) {
+            Some(sid) => {
+                if let Some(&client) = self.sessions.get(&sid) {
+                    return vec![Out::Client(client, line.to_string())];
+                }
+                if self.closed.contains(&sid) {
+                    return Vec::new();
+                }
+                let queue = self.orphans.entry(sid).or_default();
+                if queue.len() < MAX_ORPHAN_LINES {
+                    queue.push(line.to_string());
+                    return Vec::new();
+                }
+            }
+            // 不属于任何 session 的通知发给所有 chat
+            None if id.is_none() => {
+                return self
+                    .clients
+                    .iter()
+                    .map(|client| Out::Client(*client, line.to_string()))
+                    .collect();
+            }
+            None => {}
+        }
+        match id {
+            Some(id) => vec![Out::Agent(error_response(
+                id,
+                "Grove could not route this request to a chat",
+            ))],
+            None => Vec::new(),
+        }
+    }
+
+    fn agent_response(&mut self, pending: Pending, mut msg: Value) -> Vec<Out> {
+        let mut out = Vec::new();
+        if pending.method == "initialize" {
+            let waiters = match std::mem::take(&mut self.init) {
+                InitState::InFlight(waiters) => waiters,
+                _ => Vec::new(),
+            };
+            // 失败时不缓存，下一个 chat 重新 initialize
+            if let Some(result) = msg.get("result") {
+                self.init = InitState::Done(result.clone());
+            }
+            for (client, id) in waiters {
+                if self.clients.contains(&client) {
+                    let mut reply = msg.clone();
+                    reply["id"] = id;
+                    out.push(Out::Client(client, reply.to_string()));
+                }
+            }
+        }
+        // session/new、session/fork 等返回的新 session
+        if let Some(sid) = msg.pointer("/result/sessionId").and_then(Value::as_str) {
+            let sid = sid.to_string();
+            out.extend(self.claim(&sid, pending.client));
+        }
+        if self.clients.contains(&pending.client) {
+            msg["id"] = pending.id;
+            out.push(Out::Client(pending.client, msg.to_string()));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(out: &Out) -> (Option<u64>, Value) {
+        match out {
+            Out::Agent(line) => (None, serde_json::from_str(line).unwrap()),
+            Out::Client(client, line) => (Some(*client), serde_json::from_str(line).unwrap()),
+        }
+    }
+
+    fn request(id: u64, method: &str, params: Value) -> String {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params }).to_string()
+    }
+
+    #[test]
+    fn test_request_ids_are_rewritten_and_restored() {
+        let mut router = Router::default();
+        router.add_client(1);
+        router.add_client(2);
+
+        // 两个 chat 用同一个 id 发 session/new
+        let a = router.on_client_line(1, &request(0, "session/new", json!({ "cwd": "/w" })));
+        let b = router.on_client_line(2, &request(0, "session/new", json!({ "cwd": "/w" })));
+        let (_, a) = parse(&a[0]);
+        let (_, b) = parse(&b[0]);
+        assert_ne!(a["id"], b["id"]);
+
+        let reply = json!({ "jsonrpc": "2.0", "id": b["id"], "result": { "sessionId": "s2" } });
+        let out = router.on_agent_line(&reply.to_string());
+        assert_eq!(out.len(), 1);
+        let (client, msg) = parse(&out[0]);
+        assert_eq!(client, Some(2));
+        assert_eq!(msg["id"], json!(0));
+        assert_eq!(router.sessions.get("s2"), Some(&2));
+    }
+
+    #[test]
+    fn test_initialize_is_sent_once() {
+        let mut router = Router::default();
+        router.add_client(1);
+        router.add_client(2);
+
+        let first = router.on_client_line(1, &request(0, "initialize", json!({})));
+        assert!(matches!(first[0], Out::Agent(_)));
+        // 第二个 chat 等第一个的结果
+        assert!(router
+            .on_client_line(2, &request(5, "initialize", json!({})))
+            .is_empty());
+
+        let (_, sent) = parse(&first[0]);
+        let reply =
+            json!({ "jsonrpc": "2.0", "id": sent["id"], "result": { "protocolVersion": 1 } });
+        let out = router.on_agent_line(&reply.to_string());
+        let replies: Vec<_> = out.iter().map(parse).collect();
+        assert_eq!(replies.len(), 2);
+        assert!(replies
+            .iter()
+            .any(|(c, m)| *c == Some(2) && m["id"] == json!(5)));
+        assert!(replies
+            .iter()
+            .any(|(c, m)| *c == Some(1) && m["id"] == json!(0)));
+
+        // 之后接入的 chat 直接拿缓存
+        router.add_client(3);
+        let out = router.on_client_line(3, &request(9, "initialize", json!({})));
+        let (client, msg) = parse(&out[0]);
+        assert_eq!(client, Some(3));
+        assert_eq!(msg["result"]["protocolVersion"], json!(1));
+    }
+
+    #[test]
+    fn test_agent_messages_route_by_session() {
+        let mut router = Router::default();
+        router.add_client(1);
+        router.add_client(2);
+        router.on_client_line(1, &request(0, "session/load", json!({ "sessionId": "s1" })));
+
+        let update = json!({
+            "jsonrpc": "2.0",
+            "method": "session/update",
+            "params": { "sessionId": "s1", "update": {} },
+        })
+        .to_string();
+        assert_eq!(
+            router.on_agent_line(&update),
+            vec![Out::Client(1, update.clone())]
+        );
+
+        // 未登记的 session 先暂存，登记后补发
+        let early = update.replace("s1", "s2");
+        assert!(router.on_agent_line(&early).is_empty());
+        let out = router.on_client_line(
+            2,
+            &request(1, "session/prompt", json!({ "sessionId": "s2" })),
+        );
+        assert_eq!(out[0], Out::Client(2, early));
+
+        // chat 断开后取消它的 session，之后的消息丢弃
+        let out = router.remove_client(1);
+        let (_, cancel) = parse(&out[0]);
+        assert_eq!(cancel["method"], json!("session/cancel"));
+        assert!(router.on_agent_line(&update).is_empty());
+    }
+}
@@ -662,6 +662,7 @@ pub async fn grove_agent_spawn(
         created_at: now,
         duty: None, // duty 等 ACP ready 之后再设定，避免半成品记录被外界看到锁定的 duty
         launch_mode: "acp".to_string(),
+        work_subdir: None,
     };
     tasks::add_chat_session(&project_key, &task_id, new_chat.clone())
         .map_err(AgentGraphError::from)?;
@@ -696,6 +697,7 @@ pub async fn grove_agent_spawn(
     );
     let working_dir = std::path::PathBuf::from(&task.worktree_path);
     let session_key = format!("{}:{}:{}", project_key, task_id, new_chat_id);
+    let share_process = acp::shares_agent_process(&resolved.agent_name);
     let config = AcpStartConfig {
         agent_command: resolved.command,
         agent_name: resolved.agent_name,
@@ -710,6 +712,9 @@ pub async fn grove_agent_spawn(
         remote_auth: resolved.auth_header,
         suppress_initial_connecting: false,
         persona_injection,
+        initial_mode: None,
+        initial_model: None,
+        share_process,
     };
 
     let start_res = acp::get_or_start_session(session_key.clone(), config).await;
@@ -734,7 +739,7 @@ pub async fn grove_agent_spawn(
     // 等 SessionReady
     let ready = tokio::time::timeout(ACP_SESSION_READY_TIMEOUT, async {
         loop {
-            match rx.recv().await {
+            match rx.recv().await.map(|e| e.update) {
                 Ok(AcpUpdate::SessionReady {
                     session_id,
                     available_modes,
@@ -916,8 +921,8 @@ async fn deliver_to_session(
             .as_ref()
             .and_then(|m| m.thought_level_config_id.clone());
         if cfg.thought_level.is_some() && thought_level_config_id.is_none() {
-            eprintln!(
-                "[ACP] deliver_to_session: thought_level requested but thought_level_config_id \
+            tracing::warn!(
+                "deliver_to_session: thought_level requested but thought_level_config_id \
                  not yet in session.json for chat {target_chat_id} — thought_level will be \
                  skipped at apply time (config_id snapshot is None)"
             );
@@ -1114,7 +1119,8 @@ pub(crate) async fn ensure_target_handle(
         &task,
         Some(target_chat_id),
     );
-    let working_dir = std::path::PathBuf::from(&task.worktree_path);
+    let working_dir = target_chat.working_dir(&task.worktree_path);
+    let share_process = acp::shares_agent_process(&resolved.agent_name);
     let config = AcpStartConfig {
         agent_command: resolved.command,
         agent_name: resolved.agent_name,
@@ -1129,6 +1135,9 @@ pub(crate) async fn ensure_target_handle(
         remote_auth: resolved.auth_header,
         suppress_initial_connecting: false,
         persona_injection,
+        initial_mode: None,
+        initial_model: None,
+        share_process,
     };
 
     let (handle, mut rx) = acp::get_or_start_session(session_key.clone(), config)
@@ -1140,7 +1149,7 @@ pub(crate) async fn ensure_target_handle(
     // failure so the caller knows nothing was delivered.
     let wait = tokio::time::timeout(ACP_SESSION_READY_TIMEOUT, async {
         loop {
-            match rx.recv().await {
+            match rx.recv().await.map(|e| e.update) {
                 Ok(AcpUpdate::SessionReady { .. }) => return Ok::<(), AgentGraphError>(()),
                 Ok(AcpUpdate::Error { message }) => {
                     return Err(AgentGraphError::Internal(format!("acp error: {}", message)))
@@ -1225,6 +1234,7 @@ pub async fn grove_agent_set_title(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::acp::AcpEvent;
     use crate::storage::database::test_lock;
     use rusqlite::Connection;
 
@@ -1617,7 +1627,7 @@ mod tests {
     // that emits `UserMessage` on `Prompt` commands — the same observable
     // behaviour as the real `run_acp_session` cmd loop.
 
-    async fn drain_until<F>(rx: &mut broadcast::Receiver<AcpUpdate>, mut f: F) -> AcpUpdate
+    async fn drain_until<F>(rx: &mut broadcast::Receiver<AcpEvent>, mut f: F) -> AcpUpdate
     where
         F: FnMut(&AcpUpdate) -> bool,
     {
@@ -1628,7 +1638,7 @@ mod tests {
                 panic!("timed out waiting for matching AcpUpdate");
             }
             match tokio::time::timeout(deadline - now, rx.recv()).await {
-                Ok(Ok(u)) => {
+                Ok(Ok(AcpEvent { update: u, .. })) => {
                     if f(&u) {
                         return u;
                     }
@@ -1747,7 +1757,7 @@ mod tests {
 
         // Drain remaining events; ensure no UserMessage was broadcast (busy → queued).
         let mut saw_user = false;
-        while let Ok(u) = rx_b.try_recv() {
+        while let Ok(AcpEvent { update: u, .. }) = rx_b.try_recv() {
             if matches!(u, AcpUpdate::UserMessage { .. }) {
                 saw_user = true;
             }
@@ -69,6 +69,7 @@ pub async fn user_spawn_node(
         created_at: Utc::now(),
         duty: None,
         launch_mode: "acp".to_string(),
+        work_subdir: None,
     };
     tasks::add_chat_session(project_key, task_id, new_chat)
         .map_err(|e| format!("add_session: {}", e))?;
@@ -119,6 +120,7 @@ pub async fn user_spawn_node(
         Some(&new_chat_id),
     );
     let session_key = format!("{}:{}:{}", project_key, task_id, new_chat_id);
+    let share_process = acp::shares_agent_process(&resolved.agent_name);
     let config = AcpStartConfig {
         agent_command: resolved.command,
         agent_name: resolved.agent_name,
@@ -133,6 +135,9 @@ pub async fn user_spawn_node(
         remote_auth: resolved.auth_header,
         suppress_initial_connecting: true,
         persona_injection,
+        initial_mode: None,
+        initial_model: None,
+        share_process,
     };
 
     // 6. Set duty BEFORE the broadcast so the new chat row is fully formed
@@ -189,9 +194,12 @@ pub async fn user_spawn_node(
         // H2: 任何启动失败 / SessionReady 等不到都广播一次 disconnected，
         // 让前端图上能看到死节点而不是停在 "connecting"。
         let broadcast_disconnected = |msg: &str| {
-            eprintln!(
+            tracing::warn!(
                 "[user_spawn_node] (project={} task={} chat={}): {}",
-                project_key_clone, task_id_clone, new_chat_id_clone, msg
+                project_key_clone,
+                task_id_clone,
+                new_chat_id_clone,
+                msg
             );
             broadcast_radio_event(RadioEvent::ChatStatus {
                 project_id: project_key_clone.clone(),
@@ -219,7 +227,7 @@ pub async fn user_spawn_node(
         };
         let wait = tokio::time::timeout(ACP_SESSION_READY_TIMEOUT, async {
             loop {
-                match rx.recv().await {
+                match rx.recv().await.map(|e| e.update) {
                     Ok(AcpUpdate::SessionReady { .. }) => return Ok::<_, String>(()),
                     Ok(AcpUpdate::Error { message }) => {
                         return Err(format!("acp_error: {}", message))
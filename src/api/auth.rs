@@ -4,6 +4,20 @@
 //! - **No auth** (`grove web`): all requests pass through
 //! - **HMAC-SHA256** (`grove mobile`): every request must carry a valid signature;
 //!   the secret key never travels over the wire
+//!
+//! In HMAC mode the `[auth]` config section can additionally require a
+//! session: the client answers a one-time challenge to get a session token,
+//! which then expires after a sliding inactivity timeout (and optionally a
+//! maximum lifetime, pinned to the client IP). An expired session makes the
+//! web UI lock until the secret key is entered again.
+//!
+//! Failed `/auth/verify` attempts are counted per client IP and device; too
+//! many in a row lock that client out with an exponentially growing delay.
+//! Recent attempts are kept in memory for `GET /auth/attempts`.
+//!
+//! Routes used by machines use a bearer token instead: a static one for
+//! Prometheus scrapes ([`bearer_auth_middleware`]) and one per member for the
+//! organization team server ([`org_member_auth_middleware`]).
 
 use axum::{
     body::Body,
@@ -15,8 +29,12 @@ use axum::{
 use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::net::{IpAddr, SocketAddr};
 use std::sync::{Arc, Mutex};
+use subtle::ConstantTimeEq;
+
+use crate::storage::config::AuthConfig;
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -40,6 +58,103 @@ pub struct ServerAuth {
     pub key_is_generated: bool,
     /// Nonce replay-prevention map: nonce → timestamp (epoch secs).
     used_nonces: Mutex<HashMap<String, i64>>,
+    /// Session expiry policy (`[auth]` in config.toml)
+    pub session_policy: AuthConfig,
+    /// Outstanding re-auth challenges: challenge → issued at (epoch secs).
+    challenges: Mutex<HashMap<String, i64>>,
+    /// Live sessions: token → session.
+    sessions: Mutex<HashMap<String, Session>>,
+    /// Failed verify bookkeeping: `ip:…` / `device:…` → state.
+    failures: FailureTracker,
+    /// Recent verify attempts, newest last.
+    attempts: Mutex<VecDeque<AuthAttempt>>,
+}
+
+/// Seconds a re-auth challenge stays answerable.
+const CHALLENGE_TTL_SECS: i64 = 60;
+
+/// Header (and `grove_session` query param for WebSockets) carrying the
+/// session token. Also set to `expired` on 401 responses caused by the
+/// session rather than the signature.
+pub const SESSION_HEADER: &str = "x-grove-session";
+const SESSION_QUERY_KEY: &str = "grove_session";
+
+struct Session {
+    created: i64,
+    last_active: i64,
+    ip: Option<IpAddr>,
+}
+
+/// Header carrying a client-generated device id on `/auth/verify`, so
+/// clients behind one address (reverse proxy, tunnel) are told apart.
+pub const DEVICE_HEADER: &str = "x-grove-device";
+
+/// Verify attempts kept for `GET /auth/attempts`.
+const MAX_ATTEMPT_LOG: usize = 200;
+
+/// Upper bound of one lockout.
+const MAX_LOCKOUT_SECS: i64 = 24 * 3600;
+
+/// Failure counts are forgotten after this long without a new failure.
+const FAILURE_MEMORY_SECS: i64 = 24 * 3600;
+
+#[derive(Default)]
+struct FailureState {
+    /// Failures since the last lockout or success
+    failures: u32,
+    /// Lockouts so far; each one doubles the next
+    lockouts: u32,
+    locked_until: i64,
+    last_failure: i64,
+}
+
+/// Result of one `/auth/verify` attempt or signed request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptOutcome {
+    Success,
+    Failure,
+    /// Rejected without checking the proof
+    LockedOut,
+}
+
+/// One `/auth/verify` attempt.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthAttempt {
+    /// Epoch secs
+    pub timestamp: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<IpAddr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+    pub outcome: AttemptOutcome,
+}
+
+/// A client currently locked out.
+#[derive(Debug, Clone, Serialize)]
+pub struct LockoutInfo {
+    /// `ip:<addr>` or `device:<id>`
+    pub key: String,
+    pub locked_until: i64,
+    pub lockouts: u32,
+}
+
+/// Why a signed request was rejected by the session check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionError {
+    Missing,
+    Expired,
+    IpMismatch,
+}
+
+impl SessionError {
+    fn message(self) -> &'static str {
+        match self {
+            SessionError::Missing => "Session required",
+            SessionError::Expired => "Session expired",
+            SessionError::IpMismatch => "Session bound to another address",
+        }
+    }
 }
 
 impl ServerAuth {
@@ -50,6 +165,11 @@ impl ServerAuth {
             secret_key: None,
             key_is_generated: false,
             used_nonces: Mutex::new(HashMap::new()),
+            session_policy: AuthConfig::default(),
+            challenges: Mutex::new(HashMap::new()),
+            sessions: Mutex::new(HashMap::new()),
+            failures: FailureTracker::default(),
+            attempts: Mutex::new(VecDeque::new()),
         }
     }
 
@@ -62,9 +182,168 @@ impl ServerAuth {
             secret_key: Some(secret_key),
             key_is_generated: is_generated,
             used_nonces: Mutex::new(HashMap::new()),
+            session_policy: AuthConfig::default(),
+            challenges: Mutex::new(HashMap::new()),
+            sessions: Mutex::new(HashMap::new()),
+            failures: FailureTracker::default(),
+            attempts: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Require sessions according to `policy` (HMAC mode only).
+    pub fn with_session_policy(mut self, policy: AuthConfig) -> Self {
+        self.session_policy = policy;
+        self
+    }
+
+    /// Whether signed requests must also carry a live session.
+    pub fn sessions_enabled(&self) -> bool {
+        self.secret_key.is_some() && self.session_policy.idle_timeout_minutes > 0
+    }
+
+    fn idle_timeout_secs(&self) -> i64 {
+        self.session_policy.idle_timeout_minutes as i64 * 60
+    }
+
+    fn max_lifetime_secs(&self) -> Option<i64> {
+        match self.session_policy.max_session_hours {
+            0 => None,
+            hours => Some(hours as i64 * 3600),
+        }
+    }
+
+    /// Issue a one-time challenge for `/auth/verify`.
+    pub fn issue_challenge(&self, now: i64) -> String {
+        let challenge = generate_secret_key();
+        let mut challenges = self.challenges.lock().expect("challenge lock poisoned");
+        challenges.retain(|_, issued| now - *issued <= CHALLENGE_TTL_SECS);
+        challenges.insert(challenge.clone(), now);
+        challenge
+    }
+
+    /// Consume a challenge; false if unknown, already used or stale.
+    fn take_challenge(&self, challenge: &str, now: i64) -> bool {
+        let mut challenges = self.challenges.lock().expect("challenge lock poisoned");
+        matches!(challenges.remove(challenge), Some(issued) if now - issued <= CHALLENGE_TTL_SECS)
+    }
+
+    /// Create a session for a client that just proved the secret key.
+    pub fn create_session(&self, ip: Option<IpAddr>, now: i64) -> String {
+        let token = generate_secret_key();
+        let mut sessions = self.sessions.lock().expect("session lock poisoned");
+        sessions.insert(
+            token.clone(),
+            Session {
+                created: now,
+                last_active: now,
+                ip,
+            },
+        );
+        token
+    }
+
+    /// End a session (manual lock).
+    pub fn revoke_session(&self, token: &str) {
+        let mut sessions = self.sessions.lock().expect("session lock poisoned");
+        sessions.remove(token);
+    }
+
+    /// Check the session of a signed request. `activity` slides the
+    /// inactivity window; background reads (polling) leave it alone so an
+    /// unattended page still locks.
+    pub fn check_session(
+        &self,
+        token: Option<&str>,
+        ip: Option<IpAddr>,
+        activity: bool,
+        now: i64,
+    ) -> Result<(), SessionError> {
+        if !self.sessions_enabled() {
+            return Ok(());
+        }
+        let token = token.ok_or(SessionError::Missing)?;
+        let idle = self.idle_timeout_secs();
+        let max_lifetime = self.max_lifetime_secs();
+
+        let mut sessions = self.sessions.lock().expect("session lock poisoned");
+        sessions.retain(|_, session| {
+            now - session.last_active <= idle
+                && max_lifetime.is_none_or(|max| now - session.created <= max)
+        });
+        let session = sessions.get_mut(token).ok_or(SessionError::Expired)?;
+        if self.session_policy.pin_ip && session.ip != ip {
+            return Err(SessionError::IpMismatch);
+        }
+        if activity {
+            session.last_active = now;
+        }
+        Ok(())
+    }
+
+    /// Seconds until the client may try `/auth/verify` again, `None` when
+    /// it isn't locked out. Either its IP or its device being locked counts.
+    pub fn locked_out(&self, ip: Option<IpAddr>, device: Option<&str>, now: i64) -> Option<i64> {
+        if self.secret_key.is_none() || self.session_policy.max_failed_attempts == 0 {
+            return None;
+        }
+        self.failures.locked_out(&attempt_keys(ip, device), now)
+    }
+
+    /// Record a verify attempt. A failure that reaches the limit locks the
+    /// client out; returns that lockout's length in seconds.
+    pub fn record_attempt(
+        &self,
+        ip: Option<IpAddr>,
+        device: Option<&str>,
+        outcome: AttemptOutcome,
+        now: i64,
+    ) -> Option<i64> {
+        {
+            let mut attempts = self.attempts.lock().expect("attempt lock poisoned");
+            if attempts.len() >= MAX_ATTEMPT_LOG {
+                attempts.pop_front();
+            }
+            attempts.push_back(AuthAttempt {
+                timestamp: now,
+                ip,
+                device: device.map(str::to_string),
+                outcome,
+            });
+        }
+
+        let max_failed = self.session_policy.max_failed_attempts;
+        if max_failed == 0 {
+            return None;
+        }
+        let keys = attempt_keys(ip, device);
+        match outcome {
+            AttemptOutcome::Success => {
+                self.failures.clear(&keys);
+                None
+            }
+            AttemptOutcome::LockedOut => None,
+            AttemptOutcome::Failure => self.failures.record_failure(
+                &keys,
+                max_failed,
+                self.session_policy.lockout_secs,
+                now,
+            ),
         }
     }
 
+    /// Recent verify attempts (newest first) and active lockouts.
+    pub fn attempt_log(&self, now: i64) -> (Vec<AuthAttempt>, Vec<LockoutInfo>) {
+        let attempts = self
+            .attempts
+            .lock()
+            .expect("attempt lock poisoned")
+            .iter()
+            .rev()
+            .cloned()
+            .collect();
+        (attempts, self.failures.lockouts(now))
+    }
+
     /// Verify an HMAC-SHA256 signature.
     ///
     /// The message is `"{timestamp}|{nonce}|{METHOD}|{canonical_path}"`, where
@@ -111,16 +390,192 @@ impl ServerAuth {
 
         // 3. Compute expected HMAC
         let message = format!("{}|{}|{}|{}", timestamp, nonce, method, path);
-        let mut mac =
-            HmacSha256::new_from_slice(sk.as_bytes()).expect("HMAC accepts any key length");
-        mac.update(message.as_bytes());
-        let expected = hex::encode(mac.finalize().into_bytes());
+        let expected = hmac_hex(sk, &message);
+
+        constant_time_eq(&expected, signature)
+    }
+}
+
+/// Consecutive-failure lockout: `max_failed` failures in a row lock a key
+/// out for `lockout_secs`, doubling with every further lockout up to a day.
+/// Used for sign-ins (keyed by client) and share-link passwords (keyed by
+/// link).
+#[derive(Default)]
+pub struct FailureTracker {
+    states: Mutex<HashMap<String, FailureState>>,
+}
+
+impl FailureTracker {
+    /// Seconds left on the longest lockout among `keys`, `None` if none.
+    pub fn locked_out(&self, keys: &[String], now: i64) -> Option<i64> {
+        let states = self.states.lock().expect("failure lock poisoned");
+        Self::remaining(&states, keys, now)
+    }
+
+    fn remaining(states: &HashMap<String, FailureState>, keys: &[String], now: i64) -> Option<i64> {
+        keys.iter()
+            .filter_map(|key| states.get(key))
+            .map(|state| state.locked_until - now)
+            .filter(|remaining| *remaining > 0)
+            .max()
+    }
+
+    /// Count a failure for every key; returns the length of the lockout it
+    /// started, if any.
+    pub fn record_failure(
+        &self,
+        keys: &[String],
+        max_failed: u32,
+        lockout_secs: u32,
+        now: i64,
+    ) -> Option<i64> {
+        let mut states = self.states.lock().expect("failure lock poisoned");
+        Self::count_failure(&mut states, keys, max_failed, lockout_secs, now)
+    }
+
+    fn count_failure(
+        states: &mut HashMap<String, FailureState>,
+        keys: &[String],
+        max_failed: u32,
+        lockout_secs: u32,
+        now: i64,
+    ) -> Option<i64> {
+        states.retain(|_, state| {
+            state.locked_until > now || now - state.last_failure <= FAILURE_MEMORY_SECS
+        });
+        let base = lockout_secs.max(1) as i64;
+        let mut started = None;
+        for key in keys {
+            let state = states.entry(key.clone()).or_default();
+            state.failures += 1;
+            state.last_failure = now;
+            if state.failures >= max_failed.max(1) {
+                state.failures = 0;
+                state.lockouts += 1;
+                let secs = base
+                    .saturating_mul(1 << (state.lockouts - 1).min(20))
+                    .min(MAX_LOCKOUT_SECS);
+                state.locked_until = now + secs;
+                started = started.max(Some(secs));
+            }
+        }
+        started
+    }
+
+    /// Start an attempt whose outcome is only known later: rejected with
+    /// the seconds left when locked out, otherwise counted as a failure up
+    /// front (undone by [`clear`](Self::clear) on success). Checking and
+    /// counting under one lock caps parallel guesses at `max_failed` per
+    /// lockout.
+    pub fn begin_attempt(
+        &self,
+        keys: &[String],
+        max_failed: u32,
+        lockout_secs: u32,
+        now: i64,
+    ) -> Result<(), i64> {
+        let mut states = self.states.lock().expect("failure lock poisoned");
+        if let Some(remaining) = Self::remaining(&states, keys, now) {
+            return Err(remaining);
+        }
+        Self::count_failure(&mut states, keys, max_failed, lockout_secs, now);
+        Ok(())
+    }
+
+    /// Forget the failures of `keys` (after a success).
+    pub fn clear(&self, keys: &[String]) {
+        let mut states = self.states.lock().expect("failure lock poisoned");
+        for key in keys {
+            states.remove(key);
+        }
+    }
 
-        // Constant-time-ish comparison (hex strings, both lowercase)
-        expected == signature
+    /// Active lockouts, longest first.
+    fn lockouts(&self, now: i64) -> Vec<LockoutInfo> {
+        let mut lockouts: Vec<LockoutInfo> = self
+            .states
+            .lock()
+            .expect("failure lock poisoned")
+            .iter()
+            .filter(|(_, state)| state.locked_until > now)
+            .map(|(key, state)| LockoutInfo {
+                key: key.clone(),
+                locked_until: state.locked_until,
+                lockouts: state.lockouts,
+            })
+            .collect();
+        lockouts.sort_by_key(|l| std::cmp::Reverse(l.locked_until));
+        lockouts
     }
 }
 
+/// Failure-tracking keys of a client: its address and, when sent, its device.
+fn attempt_keys(ip: Option<IpAddr>, device: Option<&str>) -> Vec<String> {
+    ip.map(|ip| format!("ip:{}", ip))
+        .into_iter()
+        .chain(device.map(|d| format!("device:{}", d)))
+        .collect()
+}
+
+/// Device id from [`DEVICE_HEADER`]; ignored unless short and plain.
+fn device_id(headers: &axum::http::HeaderMap) -> Option<&str> {
+    headers
+        .get(DEVICE_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|d| {
+            !d.is_empty()
+                && d.len() <= 64
+                && d.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+/// Alert the user that someone keeps failing to authenticate: desktop
+/// banner plus the `[hooks.commands]` critical command.
+fn notify_lockout(ip: Option<IpAddr>, device: Option<&str>, secs: i64) {
+    let client = match (ip, device) {
+        (Some(ip), Some(device)) => format!("{} (device {})", ip, device),
+        (Some(ip), None) => ip.to_string(),
+        (None, Some(device)) => format!("device {}", device),
+        (None, None) => "unknown client".to_string(),
+    };
+    let message = format!(
+        "Repeated failed sign-ins from {}; locked out for {}s",
+        client, secs
+    );
+    tracing::warn!("[auth] {}", message);
+    std::thread::spawn(move || {
+        if !crate::hooks::notifications_muted() {
+            crate::hooks::send_banner("Grove - Sign-in", &message, "", "", None, false, None, None);
+        }
+        crate::hooks::run_level_command(
+            "",
+            "",
+            crate::hooks::NotificationLevel::Critical,
+            Some(&message),
+            None,
+        );
+    });
+}
+
+/// Compare secrets without leaking how many leading bytes match.
+pub fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.as_bytes().ct_eq(b.as_bytes()).into()
+}
+
+/// `HMAC(sk, message)` as lowercase hex.
+fn hmac_hex(sk: &str, message: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(sk.as_bytes()).expect("HMAC accepts any key length");
+    mac.update(message.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Client address, when the server was started with connect info.
+fn client_ip(extensions: &axum::http::Extensions) -> Option<IpAddr> {
+    extensions
+        .get::<axum::extract::ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip())
+}
+
 /// Generate a cryptographically random 64-character hex secret key.
 pub fn generate_secret_key() -> String {
     let mut bytes = [0u8; 32];
@@ -212,6 +667,21 @@ pub async fn auth_middleware(
         return next.run(request).await;
     }
 
+    // Bad signatures count toward the same lockout as `/auth/verify`, so the
+    // secret can't be guessed through protected routes instead.
+    let now = chrono::Utc::now().timestamp();
+    let ip = client_ip(request.extensions());
+    let device = device_id(request.headers());
+    if let Some(retry_after) = auth.locked_out(ip, device, now) {
+        auth.record_attempt(ip, device, AttemptOutcome::LockedOut, now);
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(axum::http::header::RETRY_AFTER, retry_after.to_string())],
+            "Too many failed attempts",
+        )
+            .into_response();
+    }
+
     let method = request.method().as_str().to_uppercase();
     // Use the original (un-stripped) URI so the path matches what the client signed.
     let path = original_uri.path();
@@ -226,38 +696,126 @@ pub async fn auth_middleware(
         Some((ts.to_string(), nonce.to_string(), sig.to_string()))
     })();
 
-    if let Some((ts, nonce, sig)) = from_headers {
+    let signed = if let Some((ts, nonce, sig)) = from_headers {
         let canonical = canonical_path(path, query, &[]);
-        if auth.verify_signature(&ts, &nonce, &method, &canonical, &sig) {
-            return next.run(request).await;
-        }
-        return (StatusCode::UNAUTHORIZED, "Invalid signature").into_response();
-    }
-
-    // Fallback: query params (WebSocket upgrade). The signature itself rides
-    // on the URL as `ts/nonce/sig`, so we strip those three keys before
-    // canonicalizing — otherwise the signature would have to sign itself.
-    if let Some(query) = query {
-        if let (Some(ts), Some(nonce), Some(sig)) = (
-            query_param(query, "ts"),
-            query_param(query, "nonce"),
-            query_param(query, "sig"),
-        ) {
+        Some(auth.verify_signature(&ts, &nonce, &method, &canonical, &sig))
+    } else {
+        // Fallback: query params (WebSocket upgrade). The signature itself
+        // rides on the URL as `ts/nonce/sig`, so we strip those three keys
+        // before canonicalizing — otherwise the signature would have to sign
+        // itself.
+        query.and_then(|query| {
+            let (ts, nonce, sig) = (
+                query_param(query, "ts")?,
+                query_param(query, "nonce")?,
+                query_param(query, "sig")?,
+            );
             let canonical = canonical_path(path, Some(query), &["ts", "nonce", "sig"]);
-            if auth.verify_signature(ts, nonce, &method, &canonical, sig) {
-                return next.run(request).await;
+            Some(auth.verify_signature(ts, nonce, &method, &canonical, sig))
+        })
+    };
+    match signed {
+        Some(true) => {}
+        Some(false) => {
+            if let Some(secs) = auth.record_attempt(ip, device, AttemptOutcome::Failure, now) {
+                notify_lockout(ip, device, secs);
             }
             return (StatusCode::UNAUTHORIZED, "Invalid signature").into_response();
         }
+        None => return (StatusCode::UNAUTHORIZED, "Unauthorized").into_response(),
+    }
+
+    // Session check. Reads don't count as activity: pages poll in the
+    // background, so the UI sends an explicit heartbeat on user input.
+    let token = request
+        .headers()
+        .get(SESSION_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .or_else(|| query.and_then(|q| query_param(q, SESSION_QUERY_KEY)));
+    let activity = !matches!(method.as_str(), "GET" | "HEAD" | "OPTIONS");
+    if let Err(e) = auth.check_session(token, ip, activity, now) {
+        return (
+            StatusCode::UNAUTHORIZED,
+            [(SESSION_HEADER, "expired")],
+            e.message(),
+        )
+            .into_response();
     }
 
-    (StatusCode::UNAUTHORIZED, "Unauthorized").into_response()
+    next.run(request).await
+}
+
+/// The token of `Authorization: Bearer <token>`, if present.
+fn bearer_token(headers: &axum::http::HeaderMap) -> Option<&str> {
+    headers
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+}
+
+/// Whether the request carries `Authorization: Bearer <token>`.
+fn bearer_token_matches(headers: &axum::http::HeaderMap, token: &str) -> bool {
+    bearer_token(headers).is_some_and(|provided| constant_time_eq(provided, token))
+}
+
+/// Require `Authorization: Bearer <token>` (`[metrics] bearer_token`).
+pub async fn bearer_auth_middleware(
+    axum::extract::State(token): axum::extract::State<Arc<String>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if bearer_token_matches(request.headers(), &token) {
+        next.run(request).await
+    } else {
+        (StatusCode::UNAUTHORIZED, "Invalid or missing token").into_response()
+    }
+}
+
+/// Team member authenticated by [`org_member_auth_middleware`], available to
+/// handlers as a request extension.
+#[derive(Debug, Clone)]
+pub struct OrgMember(pub String);
+
+/// Require the bearer token issued to one of the members in
+/// `[organization] members` and record who it belongs to.
+pub async fn org_member_auth_middleware(
+    axum::extract::State(members): axum::extract::State<Arc<HashMap<String, String>>>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response {
+    let member = bearer_token(request.headers()).and_then(|provided| {
+        members
+            .iter()
+            .find(|(_, token)| !token.is_empty() && constant_time_eq(provided, token))
+            .map(|(name, _)| name.clone())
+    });
+    match member {
+        Some(name) => {
+            request.extensions_mut().insert(OrgMember(name));
+            next.run(request).await
+        }
+        None => (StatusCode::UNAUTHORIZED, "Invalid or missing token").into_response(),
+    }
+}
+
+/// Session policy exposed to the SPA so it can lock itself locally too.
+#[derive(Serialize)]
+pub struct SessionPolicyInfo {
+    pub idle_timeout_secs: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lifetime_secs: Option<i64>,
+    pub pin_ip: bool,
 }
 
 #[derive(Serialize)]
 pub struct AuthInfoResponse {
     pub required: bool,
     pub mode: AuthMode,
+    /// Present when signed requests also need a session
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<SessionPolicyInfo>,
+    /// Mutations are rejected (`grove web --read-only`)
+    pub read_only: bool,
 }
 
 /// `GET /api/v1/auth/info` — tells the SPA whether auth is required and which mode.
@@ -267,44 +825,396 @@ pub async fn auth_info(
     Json(AuthInfoResponse {
         required: auth.secret_key.is_some(),
         mode: auth.mode,
+        session: auth.sessions_enabled().then(|| SessionPolicyInfo {
+            idle_timeout_secs: auth.idle_timeout_secs(),
+            max_lifetime_secs: auth.max_lifetime_secs(),
+            pin_ip: auth.session_policy.pin_ip,
+        }),
+        read_only: super::read_only::enabled(),
+    })
+}
+
+#[derive(Serialize)]
+pub struct ChallengeResponse {
+    pub challenge: String,
+    pub expires_in: i64,
+}
+
+/// `POST /api/v1/auth/challenge` — one-time challenge for (re-)authentication.
+/// The client answers with `HMAC(SK, "grove-verify|{challenge}")`.
+pub async fn auth_challenge(
+    axum::extract::State(auth): axum::extract::State<Arc<ServerAuth>>,
+) -> Json<ChallengeResponse> {
+    Json(ChallengeResponse {
+        challenge: auth.issue_challenge(chrono::Utc::now().timestamp()),
+        expires_in: CHALLENGE_TTL_SECS,
     })
 }
 
 #[derive(Deserialize)]
 pub struct VerifyRequest {
     pub proof: String,
+    /// Challenge from `/auth/challenge`; required to get a session
+    #[serde(default)]
+    pub challenge: Option<String>,
 }
 
 #[derive(Serialize)]
 pub struct VerifyResponse {
     pub valid: bool,
+    /// Session token to send as `X-Grove-Session` (sessions enabled only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session: Option<String>,
 }
 
-/// `POST /api/v1/auth/verify` — client sends `HMAC(SK, "grove-verify")` as proof.
+/// `POST /api/v1/auth/verify` — client sends `HMAC(SK, "grove-verify")` as
+/// proof, or `HMAC(SK, "grove-verify|{challenge}")` to a fresh challenge.
+/// Only a challenge answer creates a session: the static proof is
+/// replayable.
+///
+/// Locked-out clients get 429 with `Retry-After` before the proof is looked
+/// at.
 pub async fn auth_verify(
     axum::extract::State(auth): axum::extract::State<Arc<ServerAuth>>,
+    extensions: axum::http::Extensions,
+    headers: axum::http::HeaderMap,
     Json(req): Json<VerifyRequest>,
-) -> Result<Json<VerifyResponse>, StatusCode> {
-    match &auth.secret_key {
-        None => Ok(Json(VerifyResponse { valid: true })),
-        Some(sk) => {
-            let mut mac =
-                HmacSha256::new_from_slice(sk.as_bytes()).expect("HMAC accepts any key length");
-            mac.update(b"grove-verify");
-            let expected = hex::encode(mac.finalize().into_bytes());
-
-            if req.proof == expected {
-                Ok(Json(VerifyResponse { valid: true }))
-            } else {
-                Err(StatusCode::UNAUTHORIZED)
-            }
+) -> Response {
+    let Some(sk) = &auth.secret_key else {
+        return Json(VerifyResponse {
+            valid: true,
+            session: None,
+        })
+        .into_response();
+    };
+    let now = chrono::Utc::now().timestamp();
+    let ip = client_ip(&extensions);
+    let device = device_id(&headers);
+
+    if let Some(retry_after) = auth.locked_out(ip, device, now) {
+        auth.record_attempt(ip, device, AttemptOutcome::LockedOut, now);
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(axum::http::header::RETRY_AFTER, retry_after.to_string())],
+            "Too many failed attempts",
+        )
+            .into_response();
+    }
+
+    let valid = match req.challenge.as_deref() {
+        Some(challenge) => {
+            let expected = hmac_hex(sk, &format!("grove-verify|{}", challenge));
+            auth.take_challenge(challenge, now) && constant_time_eq(&req.proof, &expected)
+        }
+        None => constant_time_eq(&req.proof, &hmac_hex(sk, "grove-verify")),
+    };
+    if !valid {
+        if let Some(secs) = auth.record_attempt(ip, device, AttemptOutcome::Failure, now) {
+            notify_lockout(ip, device, secs);
         }
+        return StatusCode::UNAUTHORIZED.into_response();
     }
+    auth.record_attempt(ip, device, AttemptOutcome::Success, now);
+
+    // Only a challenge answer creates a session
+    let session =
+        (req.challenge.is_some() && auth.sessions_enabled()).then(|| auth.create_session(ip, now));
+    Json(VerifyResponse {
+        valid: true,
+        session,
+    })
+    .into_response()
+}
+
+#[derive(Serialize)]
+pub struct AuthAttemptsResponse {
+    /// Newest first
+    pub attempts: Vec<AuthAttempt>,
+    pub lockouts: Vec<LockoutInfo>,
+}
+
+/// `GET /api/v1/auth/attempts` — recent `/auth/verify` attempts, rejected
+/// request signatures and active lockouts (authenticated).
+pub async fn auth_attempts(
+    axum::extract::State(auth): axum::extract::State<Arc<ServerAuth>>,
+) -> Json<AuthAttemptsResponse> {
+    let (attempts, lockouts) = auth.attempt_log(chrono::Utc::now().timestamp());
+    Json(AuthAttemptsResponse { attempts, lockouts })
+}
+
+#[derive(Serialize)]
+pub struct HeartbeatResponse {
+    pub idle_timeout_secs: i64,
+}
+
+/// `POST /api/v1/auth/session/heartbeat` — user activity on a page that only
+/// reads; the auth middleware already slid the session window.
+pub async fn session_heartbeat(
+    axum::extract::State(auth): axum::extract::State<Arc<ServerAuth>>,
+) -> Json<HeartbeatResponse> {
+    Json(HeartbeatResponse {
+        idle_timeout_secs: auth.idle_timeout_secs(),
+    })
+}
+
+/// `POST /api/v1/auth/session/lock` — end the caller's session right away.
+pub async fn session_lock(
+    axum::extract::State(auth): axum::extract::State<Arc<ServerAuth>>,
+    headers: axum::http::HeaderMap,
+) -> StatusCode {
+    if let Some(token) = headers.get(SESSION_HEADER).and_then(|v| v.to_str().ok()) {
+        auth.revoke_session(token);
+    }
+    StatusCode::NO_CONTENT
 }
 
 #[cfg(test)]
 mod tests {
-    use super::canonical_path;
+    use super::*;
+
+    fn session_auth(pin_ip: bool) -> ServerAuth {
+        ServerAuth::hmac("sk".to_string(), false).with_session_policy(AuthConfig {
+            idle_timeout_minutes: 10,
+            max_session_hours: 1,
+            pin_ip,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn sessions_disabled_by_default() {
+        let auth = ServerAuth::hmac("sk".to_string(), false);
+        assert!(!auth.sessions_enabled());
+        assert_eq!(auth.check_session(None, None, false, 0), Ok(()));
+        assert!(!ServerAuth::no_auth()
+            .with_session_policy(AuthConfig {
+                idle_timeout_minutes: 5,
+                ..Default::default()
+            })
+            .sessions_enabled());
+    }
+
+    #[test]
+    fn session_idle_timeout_slides_on_activity() {
+        let auth = session_auth(false);
+        let token = auth.create_session(None, 0);
+        assert_eq!(
+            auth.check_session(None, None, true, 0),
+            Err(SessionError::Missing)
+        );
+
+        // Reads don't extend the window, activity does
+        assert_eq!(auth.check_session(Some(&token), None, false, 500), Ok(()));
+        assert_eq!(auth.check_session(Some(&token), None, true, 590), Ok(()));
+        assert_eq!(auth.check_session(Some(&token), None, false, 1100), Ok(()));
+        assert_eq!(
+            auth.check_session(Some(&token), None, false, 1200),
+            Err(SessionError::Expired)
+        );
+
+        // Maximum lifetime holds even with constant activity
+        let token = auth.create_session(None, 0);
+        for now in (0..=3600).step_by(300) {
+            assert_eq!(auth.check_session(Some(&token), None, true, now), Ok(()));
+        }
+        assert_eq!(
+            auth.check_session(Some(&token), None, true, 3900),
+            Err(SessionError::Expired)
+        );
+
+        let token = auth.create_session(None, 0);
+        auth.revoke_session(&token);
+        assert_eq!(
+            auth.check_session(Some(&token), None, true, 1),
+            Err(SessionError::Expired)
+        );
+    }
+
+    #[test]
+    fn session_pinned_to_ip() {
+        let auth = session_auth(true);
+        let phone: IpAddr = "192.168.1.20".parse().unwrap();
+        let other: IpAddr = "192.168.1.99".parse().unwrap();
+        let token = auth.create_session(Some(phone), 0);
+        assert_eq!(
+            auth.check_session(Some(&token), Some(phone), true, 1),
+            Ok(())
+        );
+        assert_eq!(
+            auth.check_session(Some(&token), Some(other), true, 1),
+            Err(SessionError::IpMismatch)
+        );
+    }
+
+    #[test]
+    fn challenge_is_single_use() {
+        let auth = session_auth(false);
+        let challenge = auth.issue_challenge(0);
+        assert!(!auth.take_challenge("unknown", 0));
+        assert!(auth.take_challenge(&challenge, 30));
+        assert!(!auth.take_challenge(&challenge, 30));
+
+        let stale = auth.issue_challenge(0);
+        assert!(!auth.take_challenge(&stale, CHALLENGE_TTL_SECS + 1));
+    }
+
+    #[test]
+    fn failed_verifies_lock_out_exponentially() {
+        let auth = ServerAuth::hmac("sk".to_string(), false).with_session_policy(AuthConfig {
+            max_failed_attempts: 3,
+            lockout_secs: 30,
+            ..Default::default()
+        });
+        let ip: IpAddr = "10.0.0.5".parse().unwrap();
+        let other: IpAddr = "10.0.0.6".parse().unwrap();
+
+        for now in 0..2 {
+            assert_eq!(
+                auth.record_attempt(Some(ip), None, AttemptOutcome::Failure, now),
+                None
+            );
+        }
+        assert_eq!(auth.locked_out(Some(ip), None, 2), None);
+        assert_eq!(
+            auth.record_attempt(Some(ip), None, AttemptOutcome::Failure, 2),
+            Some(30)
+        );
+        assert_eq!(auth.locked_out(Some(ip), None, 12), Some(20));
+        assert_eq!(auth.locked_out(Some(other), None, 12), None);
+        assert_eq!(auth.locked_out(Some(ip), None, 32), None);
+
+        // The next lockout doubles
+        for now in 40..42 {
+            auth.record_attempt(Some(ip), None, AttemptOutcome::Failure, now);
+        }
+        assert_eq!(
+            auth.record_attempt(Some(ip), None, AttemptOutcome::Failure, 42),
+            Some(60)
+        );
+
+        let (attempts, lockouts) = auth.attempt_log(50);
+        assert_eq!(attempts.len(), 6);
+        assert_eq!(attempts[0].timestamp, 42);
+        assert_eq!(lockouts.len(), 1);
+        assert_eq!(lockouts[0].key, "ip:10.0.0.5");
+        assert_eq!(lockouts[0].lockouts, 2);
+
+        // A device is locked on its own too, and success clears the counts
+        let device = Some("phone-1");
+        for now in 200..202 {
+            auth.record_attempt(Some(other), device, AttemptOutcome::Failure, now);
+        }
+        auth.record_attempt(Some(other), device, AttemptOutcome::Success, 203);
+        assert_eq!(
+            auth.record_attempt(Some(other), device, AttemptOutcome::Failure, 204),
+            None
+        );
+        for now in 205..207 {
+            auth.record_attempt(None, device, AttemptOutcome::Failure, now);
+        }
+        assert!(auth.locked_out(Some(other), device, 207).is_some());
+        assert_eq!(auth.locked_out(Some(other), None, 207), None);
+    }
+
+    #[tokio::test]
+    async fn bad_signatures_on_protected_routes_lock_out() {
+        let auth = Arc::new(
+            ServerAuth::hmac("sk".to_string(), false).with_session_policy(AuthConfig {
+                max_failed_attempts: 3,
+                lockout_secs: 60,
+                ..Default::default()
+            }),
+        );
+        let app = axum::Router::new()
+            .route("/api/v1/projects", axum::routing::get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                auth.clone(),
+                auth_middleware,
+            ));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(
+                listener,
+                app.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await;
+        });
+
+        let client = reqwest::Client::new();
+        let url = format!("http://127.0.0.1:{port}/api/v1/projects");
+        let mut statuses = Vec::new();
+        for i in 0..4 {
+            let resp = client
+                .get(&url)
+                .header("x-timestamp", chrono::Utc::now().timestamp().to_string())
+                .header("x-nonce", format!("nonce-{i}"))
+                .header("x-signature", "00")
+                .send()
+                .await
+                .expect("request");
+            statuses.push(resp.status().as_u16());
+        }
+        assert_eq!(statuses, vec![401, 401, 401, 429]);
+
+        let (attempts, lockouts) = auth.attempt_log(chrono::Utc::now().timestamp());
+        assert_eq!(attempts[0].outcome, AttemptOutcome::LockedOut);
+        assert_eq!(lockouts.len(), 1);
+        assert_eq!(lockouts[0].key, "ip:127.0.0.1");
+
+        server.abort();
+    }
+
+    #[test]
+    fn bearer_token_must_match_exactly() {
+        let headers = |value: &str| {
+            let mut h = axum::http::HeaderMap::new();
+            h.insert(axum::http::header::AUTHORIZATION, value.parse().unwrap());
+            h
+        };
+        assert!(bearer_token_matches(&headers("Bearer s3cret"), "s3cret"));
+        assert!(!bearer_token_matches(&headers("Bearer s3cre"), "s3cret"));
+        assert!(!bearer_token_matches(&headers("Bearer s3cret2"), "s3cret"));
+        assert!(!bearer_token_matches(&headers("s3cret"), "s3cret"));
+        assert!(!bearer_token_matches(
+            &axum::http::HeaderMap::new(),
+            "s3cret"
+        ));
+    }
+
+    #[test]
+    fn begin_attempt_caps_guesses_before_outcomes_are_known() {
+        let tracker = FailureTracker::default();
+        let keys = vec!["share:abc".to_string()];
+        // Five guesses in flight at once; the sixth is already refused
+        for _ in 0..5 {
+            assert_eq!(tracker.begin_attempt(&keys, 5, 60, 0), Ok(()));
+        }
+        assert_eq!(tracker.begin_attempt(&keys, 5, 60, 10), Err(50));
+        assert_eq!(tracker.begin_attempt(&keys, 5, 60, 60), Ok(()));
+
+        // A success clears the count
+        tracker.clear(&keys);
+        assert_eq!(tracker.locked_out(&keys, 61), None);
+    }
+
+    #[test]
+    fn lockout_can_be_disabled() {
+        let auth = ServerAuth::hmac("sk".to_string(), false).with_session_policy(AuthConfig {
+            max_failed_attempts: 0,
+            ..Default::default()
+        });
+        for now in 0..100 {
+            assert_eq!(
+                auth.record_attempt(None, Some("d"), AttemptOutcome::Failure, now),
+                None
+            );
+        }
+        assert_eq!(auth.locked_out(None, Some("d"), 100), None);
+        assert_eq!(auth.attempt_log(100).0.len(), 100);
+    }
 
     #[test]
     fn no_query() {
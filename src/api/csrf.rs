@@ -15,15 +15,80 @@
 //!  3. Else if `Referer` is present, host must match the request `Host`.
 //!  4. Else allow — likely a non-browser client (curl, mobile native code).
 //!     Browsers always send at least one of the above for non-safe methods.
+//!
+//! In no-auth mode a same-site token backs this up: API responses to a
+//! trusted `Host` carry a `SameSite=Strict` cookie, and non-safe requests
+//! from a browser (any of the headers above present) must send it back.
+//! Browsers never attach it cross-site, and it is only handed out to
+//! loopback, IP-literal and LAN host names (plus `[web] allowed_hosts`), so
+//! a DNS-rebound public domain — same-origin as far as the browser is
+//! concerned — can't obtain it either. The token is persisted so open tabs
+//! survive a server restart.
+
+use std::path::Path;
+use std::sync::Arc;
 
 use axum::{
     body::Body,
-    extract::Request,
-    http::{header, Method, StatusCode},
+    extract::{Request, State},
+    http::{header, HeaderValue, Method, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
 
+/// Cookie carrying the same-site token.
+const TOKEN_COOKIE: &str = "grove_csrf";
+
+/// Token file under `~/.grove`.
+const TOKEN_FILE: &str = "csrf_token";
+
+/// Middleware state.
+pub struct CsrfPolicy {
+    /// `Some` in no-auth mode: browser writes must carry this cookie value
+    token: Option<String>,
+    /// Extra trusted host names (`[web] allowed_hosts`)
+    allowed_hosts: Vec<String>,
+}
+
+impl CsrfPolicy {
+    /// Origin checks only (HMAC mode: the signature already binds requests).
+    pub fn origin_only() -> Self {
+        Self {
+            token: None,
+            allowed_hosts: Vec::new(),
+        }
+    }
+
+    /// Origin checks plus the same-site token (no-auth mode).
+    pub fn with_token(allowed_hosts: Vec<String>) -> Self {
+        Self {
+            token: Some(load_or_create_token(&crate::storage::grove_dir())),
+            allowed_hosts,
+        }
+    }
+}
+
+/// Read the persisted token, creating it on first use. Falls back to a
+/// per-run token when `~/.grove` isn't writable.
+fn load_or_create_token(dir: &Path) -> String {
+    let path = dir.join(TOKEN_FILE);
+    if let Ok(token) = std::fs::read_to_string(&path) {
+        let token = token.trim();
+        if token.len() >= 32 && token.chars().all(|c| c.is_ascii_hexdigit()) {
+            return token.to_string();
+        }
+    }
+    let token = super::auth::generate_secret_key();
+    if std::fs::create_dir_all(dir).is_ok() && std::fs::write(&path, &token).is_ok() {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let _ = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600));
+        }
+    }
+    token
+}
+
 fn is_safe_method(method: &Method) -> bool {
     matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
 }
@@ -46,41 +111,173 @@ fn host_matches(value: &str, host_header: &str) -> bool {
     host_part.eq_ignore_ascii_case(host_header)
 }
 
-pub async fn csrf_middleware(request: Request<Body>, next: Next) -> Response {
-    if is_safe_method(request.method()) {
-        return next.run(request).await;
+/// Whether a `Host` header names this machine or the local network rather
+/// than a public domain: loopback, IP literals, single-label names and
+/// LAN-only suffixes, plus the configured extras.
+fn trusted_host(host_header: &str, allowed_hosts: &[String]) -> bool {
+    let host = match host_header.strip_prefix('[') {
+        // [v6]:port
+        Some(rest) => rest.split(']').next().unwrap_or(""),
+        None => host_header
+            .rsplit_once(':')
+            .filter(|(_, port)| port.chars().all(|c| c.is_ascii_digit()))
+            .map(|(host, _)| host)
+            .unwrap_or(host_header),
+    };
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    if host.is_empty() {
+        return false;
     }
+    host.parse::<std::net::IpAddr>().is_ok()
+        || !host.contains('.')
+        || host == "localhost"
+        || [".localhost", ".local", ".lan", ".internal", ".home.arpa"]
+            .iter()
+            .any(|suffix| host.ends_with(suffix))
+        || allowed_hosts.iter().any(|h| h.eq_ignore_ascii_case(&host))
+}
 
+/// Value of the token cookie, if sent.
+fn token_cookie(req: &Request<Body>) -> Option<&str> {
+    req.headers()
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(';'))
+        .find_map(|pair| pair.trim().strip_prefix(TOKEN_COOKIE)?.strip_prefix('='))
+}
+
+/// Origin checks for a non-safe request. `None` when blocked, else whether
+/// it came from a browser (carries Sec-Fetch-Site / Origin / Referer).
+fn check_origin(request: &Request<Body>) -> Option<bool> {
     // Sec-Fetch-Site is the strongest signal — modern browsers always send it.
-    if let Some(site) = header_str(&request, header::HeaderName::from_static("sec-fetch-site")) {
+    if let Some(site) = header_str(request, header::HeaderName::from_static("sec-fetch-site")) {
         return match site {
-            "same-origin" | "none" => next.run(request).await,
-            _ => (StatusCode::FORBIDDEN, "Cross-origin request blocked").into_response(),
+            "same-origin" | "none" => Some(true),
+            _ => None,
         };
     }
 
-    let host = match header_str(&request, header::HOST) {
-        Some(h) => h.to_string(),
-        None => return next.run(request).await,
+    let origin = header_str(request, header::ORIGIN);
+    let referer = header_str(request, header::REFERER);
+    let Some(host) = header_str(request, header::HOST) else {
+        return Some(origin.is_some() || referer.is_some());
     };
 
-    if let Some(origin) = header_str(&request, header::ORIGIN) {
-        return if host_matches(origin, &host) {
-            next.run(request).await
+    if let Some(origin) = origin {
+        return if host_matches(origin, host) {
+            Some(true)
         } else {
-            (StatusCode::FORBIDDEN, "Cross-origin request blocked").into_response()
+            None
         };
     }
 
-    if let Some(referer) = header_str(&request, header::REFERER) {
-        return if host_matches(referer, &host) {
-            next.run(request).await
+    if let Some(referer) = referer {
+        return if host_matches(referer, host) {
+            Some(true)
         } else {
-            (StatusCode::FORBIDDEN, "Cross-origin request blocked").into_response()
+            None
         };
     }
 
     // No Sec-Fetch-Site, no Origin, no Referer — almost certainly a
     // non-browser client. Browsers attach at least one for non-safe methods.
-    next.run(request).await
+    Some(false)
+}
+
+pub async fn csrf_middleware(
+    State(policy): State<Arc<CsrfPolicy>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    if !is_safe_method(request.method()) {
+        let Some(from_browser) = check_origin(&request) else {
+            return (StatusCode::FORBIDDEN, "Cross-origin request blocked").into_response();
+        };
+        if let Some(token) = &policy.token {
+            if from_browser && token_cookie(&request) != Some(token.as_str()) {
+                return (
+                    StatusCode::FORBIDDEN,
+                    "Missing CSRF token — reload the page (if Grove is reached through a \
+                     host name, add it to [web] allowed_hosts)",
+                )
+                    .into_response();
+            }
+        }
+    }
+
+    // Hand the token out to trusted hosts that don't have it yet
+    let issue = policy.token.as_ref().filter(|token| {
+        token_cookie(&request) != Some(token.as_str())
+            && header_str(&request, header::HOST)
+                .is_some_and(|host| trusted_host(host, &policy.allowed_hosts))
+    });
+    let cookie = issue.and_then(|token| {
+        HeaderValue::from_str(&format!(
+            "{}={}; Path=/; HttpOnly; SameSite=Strict",
+            TOKEN_COOKIE, token
+        ))
+        .ok()
+    });
+
+    let mut response = next.run(request).await;
+    if let Some(cookie) = cookie {
+        response.headers_mut().append(header::SET_COOKIE, cookie);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trusted_hosts() {
+        let allowed = vec!["grove.example.com".to_string()];
+        for host in [
+            "localhost:3001",
+            "127.0.0.1:3001",
+            "[::1]:3001",
+            "192.168.1.20",
+            "devbox:3001",
+            "devbox.local:3001",
+            "app.localhost",
+            "GROVE.example.com:443",
+        ] {
+            assert!(trusted_host(host, &allowed), "{}", host);
+        }
+        for host in [
+            "evil.com",
+            "127.0.0.1.evil.com:3001",
+            "localhost.evil.com",
+            "",
+        ] {
+            assert!(!trusted_host(host, &allowed), "{}", host);
+        }
+    }
+
+    #[test]
+    fn reads_token_cookie() {
+        let req = Request::builder()
+            .header(header::COOKIE, "a=1; grove_csrf=abc; grove_csrf_x=2")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(token_cookie(&req), Some("abc"));
+        let req = Request::builder()
+            .header(header::COOKIE, "grove_csrf_x=2")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(token_cookie(&req), None);
+    }
+
+    #[test]
+    fn token_is_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let token = load_or_create_token(dir.path());
+        assert_eq!(token.len(), 64);
+        assert_eq!(load_or_create_token(dir.path()), token);
+
+        std::fs::write(dir.path().join(TOKEN_FILE), "garbage").unwrap();
+        assert_ne!(load_or_create_token(dir.path()), token);
+    }
 }
@@ -14,10 +14,14 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::acp::{
-    self, AcpStartConfig, AcpUpdate, ContentBlockData, PromptCapabilitiesData, QueueMode,
+    self, AcpEvent, AcpStartConfig, AcpUpdate, ContentBlockData, PromptCapabilitiesData, QueueMode,
     QueuedConfig, QueuedMessage,
 };
-use crate::storage::{chat_attachments, chat_history, config, tasks, workspace};
+use crate::error::GroveError;
+use crate::operations::tool_timeline;
+use crate::storage::{
+    chat_attachments, chat_history, chat_pins, config, tasks, turn_manifests, workspace,
+};
 
 /// Client-to-server messages
 #[derive(Debug, Deserialize)]
@@ -260,6 +264,19 @@ enum ServerMessage {
     /// authenticate 调用成功。前端把 banner 状态切到"登录成功,正在重试...",
     /// 后续会自动收到原 prompt 的 UserMessage / Busy 流。
     AuthSucceeded,
+    /// 重连时无法按 `since_seq` 增量回放（cursor 已失效或缺失太多），
+    /// 前端需要通过 GET /history 重新加载完整历史。
+    HistoryReset,
+}
+
+/// ServerMessage 附带 history seq — 前端记录收到的最大 seq，
+/// 重连时作为 `since_seq` 带回，只回放缺失的事件。
+#[derive(Serialize)]
+struct SequencedMessage<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seq: Option<u64>,
+    #[serde(flatten)]
+    msg: &'a ServerMessage,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -395,6 +412,7 @@ impl From<AcpUpdate> for ServerMessage {
                 content,
                 locations,
                 raw_input,
+                ..
             } => ServerMessage::ToolCallUpdate {
                 id,
                 status,
@@ -525,7 +543,13 @@ impl From<AcpUpdate> for ServerMessage {
 }
 
 /// Handle the ACP WebSocket connection
-async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartConfig) {
+async fn handle_acp_ws(
+    socket: WebSocket,
+    session_key: String,
+    config: AcpStartConfig,
+    since_seq: Option<u64>,
+) {
+    let _conn = crate::metrics::ws_connection("acp");
     let (mut ws_sender, mut ws_receiver) = socket.split();
 
     // Check if we're reattaching to an existing session
@@ -568,6 +592,21 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
         }
     }
 
+    // Read-only clients only watch a session that is already running; starting
+    // an agent (or rewriting its history) is a write.
+    let read_only = crate::api::read_only::enabled();
+    if read_only && !is_existing {
+        let msg = ServerMessage::Error {
+            message: "Grove is running in read-only mode; no session is running for this chat"
+                .to_string(),
+        };
+        if let Ok(json) = serde_json::to_string(&msg) {
+            let _ = ws_sender.send(Message::Text(json.into())).await;
+        }
+        let _ = ws_sender.close().await;
+        return;
+    }
+
     // Cancel unresolved events for new sessions
     if !is_existing {
         if let Some(ref chat_id) = config.chat_id {
@@ -752,8 +791,15 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
     //
     // 不再发 WS-only synthetic Cancelled —— 所有 cancel 都走 emit，确保多 WS
     // 视图状态一致。
+    //
+    // 带 `since_seq` 的重连同时在这里回放断线期间落盘的事件。回放之后 live
+    // 流里 seq 不超过 `replayed_through` 的事件已经发过，转发时跳过。
+    let mut replayed_through = 0u64;
     if let Some(ref chat_id) = history_chat_id {
-        let history = chat_history::load_history(&history_project_key, &history_task_id, chat_id);
+        let (seqs, history): (Vec<u64>, Vec<AcpUpdate>) =
+            chat_history::load_history_sequenced(&history_project_key, &history_task_id, chat_id)
+                .into_iter()
+                .unzip();
         let unresolved_ids = chat_history::unresolved_permission_ids(&history);
         let live_id = handle.pending_permission_id();
         for id in unresolved_ids {
@@ -765,6 +811,31 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
                 option_id: "Cancelled".to_string(),
             });
         }
+
+        if let Some(since) = since_seq {
+            let history = seqs.into_iter().zip(history).collect();
+            match chat_history::replay_since(history, since, chat_history::MAX_REPLAY_EVENTS) {
+                chat_history::Replay::Events(events) => {
+                    replayed_through = since;
+                    for (seq, update) in events {
+                        replayed_through = seq;
+                        let msg: ServerMessage = update.into();
+                        let seq_msg = SequencedMessage {
+                            seq: Some(seq),
+                            msg: &msg,
+                        };
+                        if let Ok(json) = serde_json::to_string(&seq_msg) {
+                            let _ = ws_sender.send(Message::Text(json.into())).await;
+                        }
+                    }
+                }
+                chat_history::Replay::Reset => {
+                    if let Ok(json) = serde_json::to_string(&ServerMessage::HistoryReset) {
+                        let _ = ws_sender.send(Message::Text(json.into())).await;
+                    }
+                }
+            }
+        }
     }
 
     // Sync busy state on (re)connect
@@ -792,10 +863,13 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
         let mut end_reason = "update_rx closed unexpectedly";
         loop {
             match update_rx.recv().await {
-                Ok(update) => {
+                Ok(AcpEvent { seq, update }) => {
+                    if seq.is_some_and(|seq| seq <= replayed_through) {
+                        continue;
+                    }
                     let is_ended = matches!(update, AcpUpdate::SessionEnded);
                     let msg: ServerMessage = update.into();
-                    if let Ok(json) = serde_json::to_string(&msg) {
+                    if let Ok(json) = serde_json::to_string(&SequencedMessage { seq, msg: &msg }) {
                         if ws_sender.send(Message::Text(json.into())).await.is_err() {
                             end_reason = "client write failed (browser likely already gone)";
                             break;
@@ -817,9 +891,10 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
         // half and the underlying connection stays technically open, so the
         // browser never learns the session is gone and sits on "Connecting…"
         // forever until the user manually refreshes.
-        eprintln!(
-            "[ACP] chat ws: closing connection to client (key={}, reason={})",
-            updates_ws_log_key, end_reason
+        tracing::info!(
+            "chat ws: closing connection to client (key={}, reason={})",
+            updates_ws_log_key,
+            end_reason
         );
         let _ = ws_sender.send(Message::Close(None)).await;
         let _ = ws_sender.close().await;
@@ -832,6 +907,14 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
             let mut end_reason = "ws_receiver stream ended (client socket closed)";
             while let Some(msg) = ws_receiver.next().await {
                 match msg {
+                    // Every client message drives the session; read-only
+                    // clients only receive updates.
+                    Ok(Message::Text(_)) if read_only => {
+                        tracing::debug!(
+                            "chat ws: dropped client message in read-only mode (key={})",
+                            ws_to_acp_log_key
+                        );
+                    }
                     Ok(Message::Text(text)) => {
                         if let Ok(client_msg) = serde_json::from_str::<ClientMessage>(&text) {
                             match client_msg {
@@ -846,7 +929,7 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
                                         .send_prompt(text, attachments, sender, terminal, config)
                                         .await
                                     {
-                                        eprintln!("Failed to send prompt: {}", e);
+                                        tracing::warn!("Failed to send prompt: {}", e);
                                         end_reason = "send_prompt failed";
                                         break;
                                     }
@@ -943,9 +1026,10 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
                         break;
                     }
                     Err(e) => {
-                        eprintln!(
-                            "[ACP] chat ws: read error from client (key={}): {}",
-                            ws_to_acp_log_key, e
+                        tracing::warn!(
+                            "chat ws: read error from client (key={}): {}",
+                            ws_to_acp_log_key,
+                            e
                         );
                         end_reason = "read error from client socket";
                         break;
@@ -953,9 +1037,10 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
                     _ => {}
                 }
             }
-            eprintln!(
-                "[ACP] chat ws: ws-to-acp task ending (key={}, reason={})",
-                ws_to_acp_log_key, end_reason
+            tracing::info!(
+                "chat ws: ws-to-acp task ending (key={}, reason={})",
+                ws_to_acp_log_key,
+                end_reason
             );
         });
 
@@ -968,11 +1053,11 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
     // never fired. See TaskChat.tsx's reconnect logic, which depends on it.
     tokio::select! {
         result = &mut updates_to_ws => {
-            if let Err(ref e) = result { if e.is_panic() { eprintln!("[Grove] ACP updates-to-WS task panicked"); } }
+            if let Err(ref e) = result { if e.is_panic() { tracing::error!("ACP updates-to-WS task panicked"); } }
             ws_to_acp.abort();
         },
         result = &mut ws_to_acp => {
-            if let Err(ref e) = result { if e.is_panic() { eprintln!("[Grove] ACP WS-to-ACP task panicked"); } }
+            if let Err(ref e) = result { if e.is_panic() { tracing::error!("ACP WS-to-ACP task panicked"); } }
             updates_to_ws.abort();
         },
     }
@@ -985,6 +1070,7 @@ async fn handle_acp_ws(socket: WebSocket, session_key: String, config: AcpStartC
 /// Error type for ACP handler
 pub enum AcpError {
     NotFound(String),
+    BadRequest(String),
     Internal(String),
 }
 
@@ -992,6 +1078,7 @@ impl IntoResponse for AcpError {
     fn into_response(self) -> Response {
         match self {
             AcpError::NotFound(msg) => (StatusCode::NOT_FOUND, msg).into_response(),
+            AcpError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg).into_response(),
             AcpError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg).into_response(),
         }
     }
@@ -1012,6 +1099,10 @@ pub struct ChatSessionResponse {
     /// "acp" (default) or "terminal" — frontend routes WS / renders chat area
     /// accordingly. Snapshotted at chat creation and immutable.
     pub launch_mode: String,
+    /// Agent working directory relative to the worktree (e.g. "frontend");
+    /// absent when the chat runs at the worktree root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work_subdir: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -1023,6 +1114,9 @@ pub struct ChatListResponse {
 pub struct CreateChatRequest {
     pub title: Option<String>,
     pub agent: Option<String>,
+    /// Run the agent in this subdirectory of the worktree (validated, immutable)
+    #[serde(default)]
+    pub work_subdir: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -1049,6 +1143,7 @@ impl ChatSessionResponse {
             created_at: chat.created_at.to_rfc3339(),
             history_path,
             launch_mode: chat.launch_mode.clone(),
+            work_subdir: chat.work_subdir.clone(),
         }
     }
 }
@@ -1070,9 +1165,17 @@ pub(crate) fn build_grove_env(
     env.insert("GROVE_PROJECT_NAME".into(), project_name.into());
     env.insert("GROVE_PROJECT".into(), project_path.into());
     env.insert("GROVE_PROJECT_KEY".into(), project_key.into());
+    // 路径范围（monorepo 子目录），告知 agent 改动应限制在此目录
+    if let Some(scope) = crate::storage::task_scope::load_scope(project_key, &task.id) {
+        env.insert("GROVE_SCOPE".into(), scope);
+    }
     if let Some(cid) = chat_id {
         env.insert("GROVE_CHAT_ID".into(), cid.into());
     }
+    // 任务模板的环境变量（不覆盖 GROVE_*）
+    for (key, value) in crate::storage::templates::task_env(project_key, &task.id) {
+        env.entry(key).or_insert(value);
+    }
     env
 }
 
@@ -1116,9 +1219,14 @@ pub async fn create_chat(
     Json(body): Json<CreateChatRequest>,
 ) -> Result<Json<ChatSessionResponse>, AcpError> {
     let (project_key, _, _) = resolve_project_key(&project_id)?;
-    let _ = tasks::get_task(&project_key, &task_id)
+    let task = tasks::get_task(&project_key, &task_id)
         .map_err(|e| AcpError::Internal(e.to_string()))?
         .ok_or(AcpError::NotFound("Task not found".to_string()))?;
+    let work_subdir = match body.work_subdir.as_deref() {
+        Some(subdir) => tasks::normalize_work_subdir(&task.worktree_path, subdir)
+            .map_err(|e| AcpError::BadRequest(e.to_string()))?,
+        None => None,
+    };
 
     let cfg = config::load_config();
     // Resolve to canonical id BEFORE any installed_agents / registry
@@ -1126,13 +1234,18 @@ pub async fn create_chat(
     // hardcoded default below would otherwise miss post-v2.6 canonical
     // rows (`claude` → `claude-acp` etc.). The chat row is also persisted
     // with the canonical id so future reads stay consistent.
-    let agent =
-        crate::storage::installed_agents::canonicalize_agent_id(&body.agent.unwrap_or_else(|| {
-            cfg.acp
-                .agent_command
-                .clone()
-                .unwrap_or_else(|| "claude-acp".to_string())
-        }));
+    // 未指定时依次使用任务模板的 agent、配置的默认 agent
+    let agent = crate::storage::installed_agents::canonicalize_agent_id(
+        &body
+            .agent
+            .or_else(|| crate::storage::templates::task_agent(&project_key, &task.id))
+            .unwrap_or_else(|| {
+                cfg.acp
+                    .agent_command
+                    .clone()
+                    .unwrap_or_else(|| "claude-acp".to_string())
+            }),
+    );
     // Snapshot launch_mode from the agent's selected channel at chat-creation
     // time. Subsequent edits do not retroactively change existing chats —
     // that contract is what makes ACP/terminal switching safe.
@@ -1182,6 +1295,7 @@ pub async fn create_chat(
         created_at: now,
         duty: None,
         launch_mode,
+        work_subdir,
     };
 
     tasks::add_chat_session(&project_key, &task_id, chat.clone())
@@ -1369,6 +1483,7 @@ pub async fn fork_chat(
         created_at: now,
         duty: src_chat.duty.clone(),
         launch_mode: src_chat.launch_mode.clone(),
+        work_subdir: src_chat.work_subdir.clone(),
     };
     tasks::add_chat_session(&project_key, &task_id, new_chat.clone())
         .map_err(|e| AcpError::Internal(e.to_string()))?;
@@ -1378,7 +1493,20 @@ pub async fn fork_chat(
     if let Err(e) =
         crate::storage::chat_history::copy_history(&project_key, &task_id, &chat_id, &new_chat.id)
     {
-        eprintln!("[fork_chat] copy_history failed (non-fatal): {}", e);
+        tracing::warn!("[fork_chat] copy_history failed (non-fatal): {}", e);
+    }
+    if let Err(e) = crate::storage::turn_manifests::copy_manifests(
+        &project_key,
+        &task_id,
+        &chat_id,
+        &new_chat.id,
+    ) {
+        tracing::warn!("[fork_chat] copy_manifests failed (non-fatal): {}", e);
+    }
+    if let Err(e) =
+        crate::storage::chat_pins::copy_pins(&project_key, &task_id, &chat_id, &new_chat.id)
+    {
+        tracing::warn!("[fork_chat] copy_pins failed (non-fatal): {}", e);
     }
 
     // 同步 copy session.json:usage / mode / model / available_commands 等元数据
@@ -1395,11 +1523,11 @@ pub async fn fork_chat(
         match serde_json::to_string_pretty(&meta) {
             Ok(json) => {
                 if let Err(e) = std::fs::write(&dst, json) {
-                    eprintln!("[fork_chat] write session.json failed (non-fatal): {}", e);
+                    tracing::warn!("[fork_chat] write session.json failed (non-fatal): {}", e);
                 }
             }
             Err(e) => {
-                eprintln!(
+                tracing::warn!(
                     "[fork_chat] serialize session.json failed (non-fatal): {}",
                     e
                 );
@@ -1481,10 +1609,18 @@ pub async fn upload_chat_attachment(
 
 // ─── Chat WebSocket Handler ─────────────────────────────────────────────────
 
+#[derive(Deserialize)]
+pub struct ChatWsQuery {
+    /// Last history seq the client has seen; events persisted after it are
+    /// replayed on connect (used by reconnects).
+    pub since_seq: Option<u64>,
+}
+
 /// WebSocket upgrade handler for per-chat ACP sessions
 pub async fn chat_ws_handler(
     ws: WebSocketUpgrade,
     Path((project_id, task_id, chat_id)): Path<(String, String, String)>,
+    Query(params): Query<ChatWsQuery>,
 ) -> Result<Response, AcpError> {
     let (project_key, project_path, project_name) = resolve_project_key(&project_id)?;
 
@@ -1533,7 +1669,7 @@ pub async fn chat_ws_handler(
         &task,
         Some(&chat_id),
     );
-    let working_dir = std::path::PathBuf::from(&task.worktree_path);
+    let working_dir = chat.working_dir(&task.worktree_path);
     let session_key = format!("{}:{}:{}", project_key, task_id, chat_id);
 
     // Per-agent overrides from the marketplace settings sheet. Look up by
@@ -1572,6 +1708,7 @@ pub async fn chat_ws_handler(
         (resolved.command, resolved.args)
     };
 
+    let share_process = acp::shares_agent_process(&resolved.agent_name);
     let config = AcpStartConfig {
         agent_command: final_command,
         agent_name: resolved.agent_name,
@@ -1586,9 +1723,12 @@ pub async fn chat_ws_handler(
         remote_auth: resolved.auth_header,
         suppress_initial_connecting: false,
         persona_injection,
+        initial_mode: None,
+        initial_model: None,
+        share_process,
     };
 
-    Ok(ws.on_upgrade(move |socket| handle_acp_ws(socket, session_key, config)))
+    Ok(ws.on_upgrade(move |socket| handle_acp_ws(socket, session_key, config, params.since_seq)))
 }
 
 // ─── History & Take Control Handlers ─────────────────────────────────────────
@@ -1602,6 +1742,9 @@ pub struct HistoryQuery {
 pub(crate) struct HistoryResponse {
     events: Vec<ServerMessage>,
     total: usize,
+    /// Seq of the newest persisted event — the cursor to pass as `since_seq`
+    /// when reconnecting the chat WebSocket.
+    last_seq: u64,
     session: Option<acp::SessionMetadata>,
 }
 
@@ -1614,13 +1757,14 @@ pub async fn get_chat_history(
 ) -> Result<Json<HistoryResponse>, AcpError> {
     let (project_key, _, _) = resolve_project_key(&project_id)?;
 
-    let history = chat_history::load_history(&project_key, &task_id, &chat_id);
+    let history = chat_history::load_history_sequenced(&project_key, &task_id, &chat_id);
     let total = history.len();
+    let last_seq = history.last().map_or(0, |(seq, _)| *seq);
     let offset = params.offset.unwrap_or(0).min(total);
-    let events: Vec<ServerMessage> = history[offset..]
-        .iter()
-        .cloned()
-        .map(ServerMessage::from)
+    let events: Vec<ServerMessage> = history
+        .into_iter()
+        .skip(offset)
+        .map(|(_, event)| ServerMessage::from(event))
         .collect();
 
     let session = acp::read_session_metadata(&project_key, &task_id, &chat_id);
@@ -1628,10 +1772,147 @@ pub async fn get_chat_history(
     Ok(Json(HistoryResponse {
         events,
         total,
+        last_seq,
         session,
     }))
 }
 
+/// Full chat transcript with per-turn reproducibility manifests.
+#[derive(Serialize)]
+pub struct TranscriptExport {
+    pub chat_id: String,
+    pub title: String,
+    pub agent: String,
+    pub exported_at: String,
+    pub session: Option<acp::SessionMetadata>,
+    pub manifests: Vec<turn_manifests::TurnManifest>,
+    pub events: Vec<acp::AcpUpdate>,
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/export
+///
+/// Export the whole chat history together with the manifest of every turn
+/// (agent, model, mode, prompt hash, skills, HEAD before/after).
+pub async fn export_chat_transcript(
+    Path((project_id, task_id, chat_id)): Path<(String, String, String)>,
+) -> Result<Json<TranscriptExport>, AcpError> {
+    let (project_key, _, _) = resolve_project_key(&project_id)?;
+
+    let chat = tasks::get_chat_session(&project_key, &task_id, &chat_id)
+        .map_err(|e| AcpError::Internal(e.to_string()))?
+        .ok_or_else(|| AcpError::NotFound("Chat not found".to_string()))?;
+
+    Ok(Json(TranscriptExport {
+        title: chat.title,
+        agent: chat.agent,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        session: acp::read_session_metadata(&project_key, &task_id, &chat_id),
+        manifests: turn_manifests::load_manifests(&project_key, &task_id, &chat_id),
+        events: chat_history::load_history(&project_key, &task_id, &chat_id),
+        chat_id,
+    }))
+}
+
+#[derive(Serialize)]
+pub struct ChatTimelineResponse {
+    pub turns: Vec<tool_timeline::TurnTimeline>,
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/timeline
+///
+/// Per-turn tool-call timeline: start/end/duration of every tool call and
+/// the lane it occupies, so parallel and slow tools can be drawn as a gantt.
+pub async fn get_chat_timeline(
+    Path((project_id, task_id, chat_id)): Path<(String, String, String)>,
+) -> Result<Json<ChatTimelineResponse>, AcpError> {
+    let (project_key, _, _) = resolve_project_key(&project_id)?;
+    tasks::get_chat_session(&project_key, &task_id, &chat_id)
+        .map_err(|e| AcpError::Internal(e.to_string()))?
+        .ok_or_else(|| AcpError::NotFound("Chat not found".to_string()))?;
+
+    Ok(Json(ChatTimelineResponse {
+        turns: tool_timeline::for_chat(&project_key, &task_id, &chat_id),
+    }))
+}
+
+#[derive(Serialize)]
+pub struct ChatPinsResponse {
+    pub pins: Vec<chat_pins::ChatPin>,
+}
+
+#[derive(Deserialize)]
+pub struct AddChatPinRequest {
+    pub kind: chat_pins::PinKind,
+    /// Content of the pinned message (tool: title and result)
+    #[serde(default)]
+    pub text: String,
+    #[serde(default)]
+    pub tool_call_id: Option<String>,
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+fn ensure_chat(project_key: &str, task_id: &str, chat_id: &str) -> Result<(), AcpError> {
+    tasks::get_chat_session(project_key, task_id, chat_id)
+        .map_err(|e| AcpError::Internal(e.to_string()))?
+        .ok_or_else(|| AcpError::NotFound("Chat not found".to_string()))?;
+    Ok(())
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/pins
+///
+/// Pinned messages and tool results of a chat, oldest pin first.
+pub async fn list_chat_pins(
+    Path((project_id, task_id, chat_id)): Path<(String, String, String)>,
+) -> Result<Json<ChatPinsResponse>, AcpError> {
+    let (project_key, _, _) = resolve_project_key(&project_id)?;
+    ensure_chat(&project_key, &task_id, &chat_id)?;
+
+    Ok(Json(ChatPinsResponse {
+        pins: chat_pins::load_pins(&project_key, &task_id, &chat_id),
+    }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/pins
+///
+/// Pin a message or tool result. Pinning the same content (or tool call)
+/// again returns the existing pin with its note updated.
+pub async fn add_chat_pin(
+    Path((project_id, task_id, chat_id)): Path<(String, String, String)>,
+    Json(req): Json<AddChatPinRequest>,
+) -> Result<Json<chat_pins::ChatPin>, AcpError> {
+    let (project_key, _, _) = resolve_project_key(&project_id)?;
+    ensure_chat(&project_key, &task_id, &chat_id)?;
+
+    chat_pins::add_pin(
+        &project_key,
+        &task_id,
+        &chat_id,
+        req.kind,
+        &req.text,
+        req.tool_call_id,
+        req.note,
+    )
+    .map(Json)
+    .map_err(|e| match e {
+        GroveError::InvalidData(msg) => AcpError::BadRequest(msg),
+        e => AcpError::Internal(e.to_string()),
+    })
+}
+
+/// DELETE /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/pins/{pinId}
+pub async fn delete_chat_pin(
+    Path((project_id, task_id, chat_id, pin_id)): Path<(String, String, String, String)>,
+) -> Result<StatusCode, AcpError> {
+    let (project_key, _, _) = resolve_project_key(&project_id)?;
+
+    match chat_pins::remove_pin(&project_key, &task_id, &chat_id, &pin_id) {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err(AcpError::NotFound("Pin not found".to_string())),
+        Err(e) => Err(AcpError::Internal(e.to_string())),
+    }
+}
+
 #[derive(Serialize)]
 pub struct TakeControlResponse {
     pub success: bool,
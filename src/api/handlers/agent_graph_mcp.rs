@@ -114,11 +114,7 @@ pub fn set_listener_port(port: u16) {
     let path = crate::storage::grove_dir().join("mcp.port");
     let payload = format!("{} {}\n", port, std::process::id());
     if let Err(e) = atomic_write(&path, payload.as_bytes()) {
-        eprintln!(
-            "[agent_graph_mcp] warning: failed to write {}: {}",
-            path.display(),
-            e
-        );
+        tracing::warn!("warning: failed to write {}: {}", path.display(), e);
     }
 }
 
@@ -778,7 +774,7 @@ pub async fn start_listener(base_port: u16, max_attempts: u16) -> std::io::Resul
     let app = build_router();
     tokio::spawn(async move {
         if let Err(e) = axum::serve(listener, app).await {
-            eprintln!("[agent_graph_mcp] listener error: {}", e);
+            tracing::error!("listener error: {}", e);
         }
     });
     Ok(actual_port)
@@ -240,7 +240,7 @@ pub async fn agent_pty_handler(
     for arg in &extra_args {
         cmd.arg(arg);
     }
-    cmd.cwd(&task.worktree_path);
+    cmd.cwd(chat.working_dir(&task.worktree_path));
     apply_terminal_env_defaults(&mut cmd);
     for (k, v) in &grove_env {
         cmd.env(k, v);
@@ -259,6 +259,7 @@ pub async fn agent_pty_handler(
             }
         }
         let _token_guard = TokenGuard(agent_graph_token);
+        let _conn = crate::metrics::ws_connection("agent_pty");
 
         handle_pty_terminal(socket, cmd, cols, rows).await;
     }))
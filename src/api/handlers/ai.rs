@@ -481,7 +481,7 @@ pub async fn transcribe(mut multipart: Multipart) -> Result<Json<TranscribeRespo
     // ── Step 1: Transcribe ──────────────────────────────────────────────────
 
     let transcribe_provider = find_provider(&global.transcribe_provider).ok_or_else(|| {
-        eprintln!(
+        tracing::warn!(
             "[transcribe] Provider not found: {}",
             global.transcribe_provider
         );
@@ -507,7 +507,7 @@ pub async fn transcribe(mut multipart: Multipart) -> Result<Json<TranscribeRespo
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .map_err(|e| {
-        eprintln!("[transcribe] Transcription API error: {}", e);
+        tracing::warn!("[transcribe] Transcription API error: {}", e);
         StatusCode::BAD_GATEWAY
     })?;
 
@@ -538,7 +538,7 @@ pub async fn transcribe(mut multipart: Multipart) -> Result<Json<TranscribeRespo
             {
                 Ok(text) => Some(text),
                 Err(e) => {
-                    eprintln!("[transcribe] Revision API error: {}", e);
+                    tracing::warn!("[transcribe] Revision API error: {}", e);
                     None
                 }
             }
@@ -1089,7 +1089,7 @@ pub async fn execute_voice_control(
 
     // ── Step 1: Transcribe ──────────────────────────────────────────────────
     let stt_provider = find_provider(&vc_settings.stt_provider_id).ok_or_else(|| {
-        eprintln!(
+        tracing::warn!(
             "[voice_control] STT Provider not found: {}",
             vc_settings.stt_provider_id
         );
@@ -1118,13 +1118,13 @@ pub async fn execute_voice_control(
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .map_err(|e| {
-        eprintln!("[voice_control] Transcription error: {}", e);
+        tracing::warn!("[voice_control] Transcription error: {}", e);
         StatusCode::BAD_GATEWAY
     })?;
 
     // ── Step 2: Call LLM with Tools ─────────────────────────────────────────
     let llm_provider = find_provider(&vc_settings.llm_provider_id).ok_or_else(|| {
-        eprintln!(
+        tracing::warn!(
             "[voice_control] LLM Provider not found: {}",
             vc_settings.llm_provider_id
         );
@@ -1154,7 +1154,7 @@ pub async fn execute_voice_control(
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
     .map_err(|e| {
-        eprintln!("[voice_control] LLM error: {}", e);
+        tracing::warn!("[voice_control] LLM error: {}", e);
         StatusCode::BAD_GATEWAY
     })?;
 
@@ -303,7 +303,7 @@ impl Session {
         self.in_flight = false;
 
         if let Some(err) = result.error {
-            eprintln!("[transcribe-stream] transcription error: {}", err);
+            tracing::warn!("[transcribe-stream] transcription error: {}", err);
             // Back off so we don't hammer the API (especially 429 rate limits) —
             // ~4s at 250ms/tick. The next pump after cooldown retries.
             self.cooldown_ticks = 16;
@@ -403,6 +403,7 @@ impl Session {
 }
 
 async fn handle_stream(socket: WebSocket, params: StreamQuery) {
+    let _conn = crate::metrics::ws_connection("ai_stream");
     use futures::{SinkExt, StreamExt};
     let (mut ws_tx, mut ws_rx) = socket.split();
 
@@ -1,23 +1,18 @@
 //! Shared utilities for API handlers.
 
+use std::collections::HashMap;
+
 use axum::http::StatusCode;
 
+use crate::git;
 use crate::model;
-use crate::storage::workspace;
+use crate::storage::{tasks, workspace};
 
 use super::projects::TaskResponse;
 
 /// Convert [`model::WorktreeStatus`] to the string the frontend expects.
 pub(crate) fn status_to_string(status: &model::WorktreeStatus) -> &'static str {
-    match status {
-        model::WorktreeStatus::Live => "live",
-        model::WorktreeStatus::Idle => "idle",
-        model::WorktreeStatus::Merged => "merged",
-        model::WorktreeStatus::Conflict => "conflict",
-        model::WorktreeStatus::Broken => "broken",
-        model::WorktreeStatus::Error => "broken",
-        model::WorktreeStatus::Archived => "archived",
-    }
+    status.as_str()
 }
 
 /// Convert [`model::Worktree`] to [`TaskResponse`].
@@ -28,9 +23,9 @@ pub(crate) fn worktree_to_response(wt: &model::Worktree) -> TaskResponse {
         branch: wt.branch.clone(),
         target: wt.target.clone(),
         status: status_to_string(&wt.status).to_string(),
-        additions: 0,
-        deletions: 0,
-        files_changed: 0,
+        additions: wt.file_changes.additions,
+        deletions: wt.file_changes.deletions,
+        files_changed: wt.file_changes.files_changed,
         initial_commit: None,
         commits: Vec::new(),
         created_at: wt.created_at.to_rfc3339(),
@@ -39,9 +34,31 @@ pub(crate) fn worktree_to_response(wt: &model::Worktree) -> TaskResponse {
         multiplexer: wt.multiplexer.clone(),
         created_by: wt.created_by.clone(),
         is_local: wt.is_local,
+        agent_state: wt.agent_state,
+        assignee: wt.assignee.clone(),
+        pinned: wt.pinned,
+        warning: None,
     }
 }
 
+/// Order task responses pinned-first, then most recently updated.
+pub(crate) fn sort_task_responses(tasks: &mut [TaskResponse]) {
+    tasks.sort_by(|a, b| {
+        b.pinned
+            .cmp(&a.pinned)
+            .then_with(|| b.updated_at.cmp(&a.updated_at))
+    });
+}
+
+/// RFC 3339 timestamp for a Unix-seconds commit time, sent next to the
+/// humanized `time_ago` so clients can show absolute times. Empty when the
+/// time is out of range.
+pub(crate) fn iso_time(secs: i64) -> String {
+    chrono::DateTime::<chrono::Utc>::from_timestamp(secs, 0)
+        .map(|dt| dt.to_rfc3339())
+        .unwrap_or_default()
+}
+
 /// Find project by ID (hash) and return (project, project_key).
 pub(crate) fn find_project_by_id(
     id: &str,
@@ -56,3 +73,78 @@ pub(crate) fn find_project_by_id(
     let project_key = workspace::project_hash(&project.path);
     Ok((project, project_key))
 }
+
+/// Task / chat a commit links back to through its `Grove-Task` /
+/// `Grove-Chat` trailers. Names are absent when the target no longer exists.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct CommitLinks {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_name: Option<String>,
+    /// The linked task is archived
+    pub task_archived: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_title: Option<String>,
+}
+
+/// Resolves commit trailers into [`CommitLinks`], caching lookups across a
+/// commit list (most commits of a list share the same task).
+pub(crate) struct CommitLinkResolver<'a> {
+    project_key: &'a str,
+    tasks: HashMap<String, Option<(String, bool)>>,
+    chats: HashMap<(String, String), Option<String>>,
+}
+
+impl<'a> CommitLinkResolver<'a> {
+    pub(crate) fn new(project_key: &'a str) -> Self {
+        Self {
+            project_key,
+            tasks: HashMap::new(),
+            chats: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn resolve(&mut self, trailers: &git::GroveTrailers) -> Option<CommitLinks> {
+        if trailers.is_empty() {
+            return None;
+        }
+        let project_key = self.project_key;
+        let task = trailers.task_id.as_ref().and_then(|task_id| {
+            self.tasks
+                .entry(task_id.clone())
+                .or_insert_with(|| {
+                    if let Ok(Some(t)) = tasks::get_task(project_key, task_id) {
+                        Some((t.name, false))
+                    } else if let Ok(Some(t)) = tasks::get_archived_task(project_key, task_id) {
+                        Some((t.name, true))
+                    } else {
+                        None
+                    }
+                })
+                .clone()
+        });
+        let chat_title = match (&trailers.task_id, &trailers.chat_id) {
+            (Some(task_id), Some(chat_id)) => self
+                .chats
+                .entry((task_id.clone(), chat_id.clone()))
+                .or_insert_with(|| {
+                    tasks::get_chat_session(project_key, task_id, chat_id)
+                        .ok()
+                        .flatten()
+                        .map(|c| c.title)
+                })
+                .clone(),
+            _ => None,
+        };
+        Some(CommitLinks {
+            task_id: trailers.task_id.clone(),
+            task_archived: task.as_ref().is_some_and(|(_, archived)| *archived),
+            task_name: task.map(|(name, _)| name),
+            chat_id: trailers.chat_id.clone(),
+            chat_title,
+        })
+    }
+}
@@ -34,6 +34,48 @@ pub struct ConfigResponse {
     /// without doing a separate `listApplications` round-trip.
     pub platform: &'static str,
     pub browser_control: BrowserControlConfigDto,
+    pub review: ReviewConfigDto,
+    pub sync: SyncConfigDto,
+    pub organization: OrganizationConfigDto,
+    pub clipboard: ClipboardConfigDto,
+    /// Commit signing for commits and merges made by Grove
+    pub signing: config::SigningConfig,
+    /// Task risk scoring rules
+    pub risk: config::RiskConfig,
+    /// Relative vs absolute timestamps and the date locale
+    pub display: config::DisplayConfig,
+    /// User-defined "open with" applications, in display order
+    pub open_with: Vec<config::OpenWithApp>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClipboardConfigDto {
+    /// TUI copy actions also emit OSC 52
+    pub osc52: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OrganizationConfigDto {
+    pub server_url: Option<String>,
+    /// token 不回传，只告知是否已设置
+    pub has_token: bool,
+    /// 本机是否作为团队服务器（成员 token 仅在配置文件中设置）
+    pub serving: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SyncConfigDto {
+    pub enabled: bool,
+    pub remote: Option<String>,
+    pub interval_minutes: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReviewConfigDto {
+    pub ignore_whitespace: bool,
+    pub ignore_blank_lines: bool,
+    pub detect_renames: bool,
+    pub detect_copies: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -71,6 +113,10 @@ pub struct NotificationsConfigDto {
     pub notification_show_running: bool,
     pub menubar_shortcut: Option<String>,
     pub tray_done_retention: config::RetentionPolicy,
+    pub do_not_disturb: bool,
+    pub quiet_hours: config::QuietHours,
+    /// Whether sounds/banners are suppressed right now (manual DND or quiet hours)
+    pub muted: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -79,6 +125,8 @@ pub struct HooksConfigDto {
     pub response_sound: String,
     pub permission_sound_enabled: bool,
     pub permission_sound: String,
+    pub commands: config::HookCommands,
+    pub install_agent_hooks: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -88,6 +136,10 @@ pub struct ThemeConfigDto {
     pub light_theme: String,
     pub dark_theme: String,
     pub custom_themes: Vec<config::CustomThemeConfig>,
+    /// Color-blind safe status colors (overrides success / warning / error)
+    pub status_palette: config::StatusPalette,
+    /// Use the TUI theme's full colors (GET /config/theme) instead of the web palette
+    pub sync_tui_colors: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -112,6 +164,11 @@ pub struct WebConfigDto {
 #[derive(Debug, Serialize)]
 pub struct AutoLinkConfigDto {
     pub patterns: Vec<String>,
+    /// Per-pattern link mode; patterns not listed use `symlink`
+    pub modes: std::collections::HashMap<String, config::LinkMode>,
+    pub copy_max_mb: u64,
+    /// Commands run in a new worktree; `{branch}` / `{task_id}` / `{target}` expanded
+    pub post_create: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -147,6 +204,8 @@ impl From<&Config> for ConfigResponse {
                 light_theme: config.theme.light_theme.clone(),
                 dark_theme: config.theme.dark_theme.clone(),
                 custom_themes: config.theme.custom_themes.clone(),
+                status_palette: config.theme.status_palette,
+                sync_tui_colors: config.theme.sync_tui_colors,
             },
             layout: LayoutConfigDto {
                 default: config.layout.default.clone(),
@@ -163,6 +222,9 @@ impl From<&Config> for ConfigResponse {
             },
             auto_link: AutoLinkConfigDto {
                 patterns: config.auto_link.patterns.clone(),
+                modes: config.auto_link.modes.clone(),
+                copy_max_mb: config.auto_link.copy_max_mb,
+                post_create: config.auto_link.post_create.clone(),
             },
             acp: AcpConfigDto {
                 agent_command: config.acp.agent_command.clone(),
@@ -188,6 +250,8 @@ impl From<&Config> for ConfigResponse {
                 response_sound: config.hooks.response_sound.clone(),
                 permission_sound_enabled: config.hooks.permission_sound_enabled,
                 permission_sound: config.hooks.permission_sound.clone(),
+                commands: config.hooks.commands.clone(),
+                install_agent_hooks: config.hooks.install_agent_hooks,
             },
             notifications: NotificationsConfigDto {
                 tray_enabled: config.notifications.tray_enabled,
@@ -200,6 +264,11 @@ impl From<&Config> for ConfigResponse {
                 notification_show_running: config.notifications.notification_show_running,
                 menubar_shortcut: config.notifications.menubar_shortcut.clone(),
                 tray_done_retention: config.notifications.tray_done_retention,
+                do_not_disturb: config.notifications.do_not_disturb,
+                quiet_hours: config.notifications.quiet_hours.clone(),
+                muted: config
+                    .notifications
+                    .is_muted_at(chrono::Local::now().time()),
             },
             indexing: IndexingConfigDto {
                 enabled: config.indexing.enabled,
@@ -219,6 +288,29 @@ impl From<&Config> for ConfigResponse {
                 enabled: config.browser_control.enabled,
                 auto_groups: config.browser_control.auto_groups,
             },
+            review: ReviewConfigDto {
+                ignore_whitespace: config.review.ignore_whitespace,
+                ignore_blank_lines: config.review.ignore_blank_lines,
+                detect_renames: config.review.detect_renames,
+                detect_copies: config.review.detect_copies,
+            },
+            sync: SyncConfigDto {
+                enabled: config.sync.enabled,
+                remote: config.sync.remote.clone(),
+                interval_minutes: config.sync.interval_minutes,
+            },
+            organization: OrganizationConfigDto {
+                server_url: config.organization.server_url.clone(),
+                has_token: config.organization.token.is_some(),
+                serving: !config.organization.members.is_empty(),
+            },
+            clipboard: ClipboardConfigDto {
+                osc52: config.clipboard.osc52,
+            },
+            signing: config.signing.clone(),
+            risk: config.risk.clone(),
+            display: config.display.clone(),
+            open_with: config.open_with.clone(),
         }
     }
 }
@@ -235,10 +327,57 @@ pub struct ConfigPatchRequest {
     pub notifications: Option<NotificationsConfigPatch>,
     pub indexing: Option<IndexingConfigPatch>,
     pub browser_control: Option<BrowserControlConfigPatch>,
+    pub review: Option<ReviewConfigPatch>,
+    pub sync: Option<SyncConfigPatch>,
+    pub organization: Option<OrganizationConfigPatch>,
+    pub clipboard: Option<ClipboardConfigPatch>,
+    pub signing: Option<SigningConfigPatch>,
+    /// Replaces the whole section
+    pub risk: Option<config::RiskConfig>,
+    /// Replaces the whole section
+    pub display: Option<config::DisplayConfig>,
+    /// Replaces the whole list (order included)
+    pub open_with: Option<Vec<config::OpenWithApp>>,
     /// Terminal 模式使用的复用器 ("tmux" | "zellij")
     pub terminal_multiplexer: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SyncConfigPatch {
+    pub enabled: Option<bool>,
+    /// 空字符串表示清除
+    pub remote: Option<String>,
+    pub interval_minutes: Option<u32>,
+}
+
+/// 空字符串表示清除
+#[derive(Debug, Deserialize)]
+pub struct OrganizationConfigPatch {
+    pub server_url: Option<String>,
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ClipboardConfigPatch {
+    pub osc52: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SigningConfigPatch {
+    pub enabled: Option<bool>,
+    pub format: Option<config::SigningFormat>,
+    /// Empty string clears the key (git's `user.signingkey` is used)
+    pub key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewConfigPatch {
+    pub ignore_whitespace: Option<bool>,
+    pub ignore_blank_lines: Option<bool>,
+    pub detect_renames: Option<bool>,
+    pub detect_copies: Option<bool>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BrowserControlConfigPatch {
     pub enabled: Option<bool>,
@@ -266,6 +405,9 @@ pub struct NotificationsConfigPatch {
     pub menubar_shortcut: Option<String>,
     /// `Some(Forever)` / `Some(Expire{..})` sets; `None` leaves untouched.
     pub tray_done_retention: Option<config::RetentionPolicy>,
+    pub do_not_disturb: Option<bool>,
+    /// Times must be "HH:MM"; invalid values are rejected with 400.
+    pub quiet_hours: Option<config::QuietHours>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -274,6 +416,8 @@ pub struct HooksConfigPatch {
     pub response_sound: Option<String>,
     pub permission_sound_enabled: Option<bool>,
     pub permission_sound: Option<String>,
+    pub commands: Option<config::HookCommands>,
+    pub install_agent_hooks: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -291,6 +435,8 @@ pub struct ThemeConfigPatch {
     pub light_theme: Option<String>,
     pub dark_theme: Option<String>,
     pub custom_themes: Option<Vec<config::CustomThemeConfig>>,
+    pub status_palette: Option<config::StatusPalette>,
+    pub sync_tui_colors: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -315,6 +461,9 @@ pub struct WebConfigPatch {
 #[derive(Debug, Deserialize)]
 pub struct AutoLinkConfigPatch {
     pub patterns: Option<Vec<String>>,
+    pub modes: Option<std::collections::HashMap<String, config::LinkMode>>,
+    pub copy_max_mb: Option<u64>,
+    pub post_create: Option<Vec<String>>,
 }
 
 /// GET /api/v1/config
@@ -366,6 +515,18 @@ pub async fn patch_config(
                 config.theme.custom_themes = custom_themes;
             }
         }
+        if let Some(status_palette) = theme_patch.status_palette {
+            if config.theme.status_palette != status_palette {
+                theme_changed = true;
+                config.theme.status_palette = status_palette;
+            }
+        }
+        if let Some(sync_tui_colors) = theme_patch.sync_tui_colors {
+            if config.theme.sync_tui_colors != sync_tui_colors {
+                theme_changed = true;
+                config.theme.sync_tui_colors = sync_tui_colors;
+            }
+        }
     }
 
     // Apply layout patch
@@ -426,6 +587,16 @@ pub async fn patch_config(
         if let Some(patterns) = auto_link_patch.patterns {
             config.auto_link.patterns = patterns;
         }
+        if let Some(modes) = auto_link_patch.modes {
+            config.auto_link.modes = modes;
+        }
+        if let Some(copy_max_mb) = auto_link_patch.copy_max_mb {
+            config.auto_link.copy_max_mb = copy_max_mb;
+        }
+        if let Some(post_create) = auto_link_patch.post_create {
+            config.auto_link.post_create = post_create;
+        }
+        config.auto_link.normalize();
     }
 
     // Apply acp patch
@@ -470,6 +641,12 @@ pub async fn patch_config(
         if let Some(permission_sound) = hooks_patch.permission_sound {
             config.hooks.permission_sound = permission_sound;
         }
+        if let Some(commands) = hooks_patch.commands {
+            config.hooks.commands = commands;
+        }
+        if let Some(install_agent_hooks) = hooks_patch.install_agent_hooks {
+            config.hooks.install_agent_hooks = install_agent_hooks;
+        }
     }
 
     // Apply notifications patch
@@ -519,6 +696,21 @@ pub async fn patch_config(
                 }
             };
         }
+        if let Some(v) = n.do_not_disturb {
+            config.notifications.do_not_disturb = v;
+        }
+        if let Some(quiet) = n.quiet_hours {
+            if config::parse_hhmm(&quiet.start).is_none()
+                || config::parse_hhmm(&quiet.end).is_none()
+            {
+                return Err(StatusCode::BAD_REQUEST);
+            }
+            config.notifications.quiet_hours = config::QuietHours {
+                start: quiet.start.trim().to_string(),
+                end: quiet.end.trim().to_string(),
+                ..quiet
+            };
+        }
     }
 
     // Apply indexing patch
@@ -550,27 +742,210 @@ pub async fn patch_config(
         }
     }
 
+    // Apply review patch
+    if let Some(r) = patch.review {
+        if let Some(v) = r.ignore_whitespace {
+            config.review.ignore_whitespace = v;
+        }
+        if let Some(v) = r.ignore_blank_lines {
+            config.review.ignore_blank_lines = v;
+        }
+        if let Some(v) = r.detect_renames {
+            config.review.detect_renames = v;
+        }
+        if let Some(v) = r.detect_copies {
+            config.review.detect_copies = v;
+        }
+    }
+
+    // Apply clipboard patch
+    if let Some(c) = patch.clipboard {
+        if let Some(v) = c.osc52 {
+            config.clipboard.osc52 = v;
+        }
+    }
+
+    // Apply signing patch
+    if let Some(s) = patch.signing {
+        if let Some(v) = s.enabled {
+            config.signing.enabled = v;
+        }
+        if let Some(v) = s.format {
+            config.signing.format = v;
+        }
+        if let Some(v) = s.key {
+            let v = v.trim();
+            config.signing.key = (!v.is_empty()).then(|| v.to_string());
+        }
+    }
+
+    // Apply risk scoring rules
+    if let Some(risk) = patch.risk {
+        if crate::operations::risk::validate_config(&risk).is_err() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        config.risk = risk;
+    }
+
+    // Apply time display settings
+    if let Some(display) = patch.display {
+        config.display = config::DisplayConfig {
+            locale: display.locale.trim().to_string(),
+            ..display
+        };
+    }
+
+    // Apply open-with applications
+    if let Some(apps) = patch.open_with {
+        if config::validate_open_with(&apps).is_err() {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        config.open_with = apps
+            .into_iter()
+            .map(|a| config::OpenWithApp {
+                name: a.name.trim().to_string(),
+                command: a.command.trim().to_string(),
+            })
+            .collect();
+    }
+
+    // Apply sync patch
+    if let Some(s) = patch.sync {
+        if let Some(v) = s.enabled {
+            config.sync.enabled = v;
+        }
+        if let Some(v) = s.remote {
+            let v = v.trim();
+            config.sync.remote = (!v.is_empty()).then(|| v.to_string());
+        }
+        if let Some(v) = s.interval_minutes {
+            config.sync.interval_minutes = v.max(1);
+        }
+    }
+
+    // Apply organization patch
+    if let Some(o) = patch.organization {
+        let non_empty = |v: String| {
+            let v = v.trim();
+            (!v.is_empty()).then(|| v.to_string())
+        };
+        if let Some(v) = o.server_url {
+            config.organization.server_url = non_empty(v);
+        }
+        if let Some(v) = o.token {
+            config.organization.token = non_empty(v);
+        }
+    }
+
     // Save config
     config::save_config(&config).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     // Notify Radio clients if theme changed
     if theme_changed {
-        use crate::api::handlers::walkie_talkie::{broadcast_radio_event, RadioEvent};
-        // For "auto" mode, send the literal "auto" string so the Radio client
-        // can resolve against ITS OWN system color scheme. Hardcoding a slot
-        // id here would force every Radio client onto the desktop's guess
-        // regardless of the device's actual dark/light preference.
-        let name = match config.theme.mode.as_str() {
-            "light" => config.theme.light_theme.clone(),
-            "dark" => config.theme.dark_theme.clone(),
-            _ => "auto".to_string(),
-        };
-        broadcast_radio_event(RadioEvent::ThemeChanged { name });
+        broadcast_theme_changed(&config.theme);
     }
 
     Ok(Json(ConfigResponse::from(&config)))
 }
 
+/// Theme last announced over the events channel, so the config watcher
+/// doesn't re-announce a change the PATCH handler already broadcast
+static LAST_THEME: std::sync::Mutex<Option<config::ThemeConfig>> = std::sync::Mutex::new(None);
+
+/// Broadcast `ThemeChanged` to Radio and web clients
+fn broadcast_theme_changed(theme: &config::ThemeConfig) {
+    use crate::api::handlers::walkie_talkie::{broadcast_radio_event, RadioEvent};
+    if let Ok(mut last) = LAST_THEME.lock() {
+        *last = Some(theme.clone());
+    }
+    // For "auto" mode, send the literal "auto" string so the Radio client
+    // can resolve against ITS OWN system color scheme. Hardcoding a slot
+    // id here would force every Radio client onto the desktop's guess
+    // regardless of the device's actual dark/light preference.
+    let name = match theme.mode.as_str() {
+        "light" => theme.light_theme.clone(),
+        "dark" => theme.dark_theme.clone(),
+        _ => "auto".to_string(),
+    };
+    broadcast_radio_event(RadioEvent::ThemeChanged { name });
+}
+
+/// Watch config.toml for theme changes made outside this server (the TUI
+/// writes the theme directly) and broadcast them, so open web pages follow
+/// the terminal theme live.
+pub fn spawn_theme_watcher() {
+    if let Ok(mut last) = LAST_THEME.lock() {
+        *last = Some(config::load_config().theme);
+    }
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(2));
+        loop {
+            interval.tick().await;
+            let theme = tokio::task::spawn_blocking(|| config::load_config().theme).await;
+            let Ok(theme) = theme else { continue };
+            let changed = LAST_THEME
+                .lock()
+                .map(|last| last.as_ref() != Some(&theme))
+                .unwrap_or(false);
+            if changed {
+                broadcast_theme_changed(&theme);
+            }
+        }
+    });
+}
+
+/// GET /api/v1/config/theme query
+#[derive(Debug, Deserialize)]
+pub struct TuiThemeQuery {
+    /// Browser color scheme ("light" / "dark"), picks the slot in auto mode
+    pub appearance: Option<String>,
+}
+
+/// GET /api/v1/config/theme response: the full colors of the selected TUI theme
+#[derive(Debug, Serialize)]
+pub struct TuiThemeResponse {
+    pub id: String,
+    pub name: String,
+    pub is_light: bool,
+    pub is_custom: bool,
+    /// Every ThemeColors field as `#rrggbb`, keyed by field name
+    pub colors: std::collections::BTreeMap<String, String>,
+    pub card_backgrounds: Vec<String>,
+    pub accent_palette: Vec<String>,
+    /// The colors mapped onto the web theme slots
+    pub web_colors: config::CustomThemeColors,
+    /// CSS custom properties to set on the document root
+    pub css_variables: std::collections::BTreeMap<String, String>,
+}
+
+/// GET /api/v1/config/theme
+pub async fn get_tui_theme(Query(query): Query<TuiThemeQuery>) -> Json<TuiThemeResponse> {
+    use crate::theme;
+
+    let config = config::load_config();
+    let prefer_dark = query.appearance.as_deref() != Some("light");
+    let resolved = theme::resolve_configured_theme(&config.theme, prefer_dark);
+    let colors = &resolved.colors;
+    let hex_list = |list: &[ratatui::style::Color]| -> Vec<String> {
+        list.iter().map(|c| theme::color_hex(*c)).collect()
+    };
+
+    Json(TuiThemeResponse {
+        colors: theme::named_colors(colors)
+            .into_iter()
+            .map(|(name, color)| (name.to_string(), theme::color_hex(color)))
+            .collect(),
+        card_backgrounds: hex_list(&colors.card_backgrounds),
+        accent_palette: hex_list(&colors.accent_palette),
+        web_colors: theme::web_colors(colors),
+        css_variables: theme::css_variables(colors),
+        id: resolved.id,
+        name: resolved.name,
+        is_light: resolved.is_light,
+        is_custom: resolved.is_custom,
+    })
+}
+
 /// Application info for picker
 #[derive(Debug, Serialize)]
 pub struct AppInfo {
@@ -269,6 +269,7 @@ mod tests {
             created_at: Utc::now(),
             duty: None,
             launch_mode: "acp".to_string(),
+            work_subdir: None,
         };
         crate::storage::tasks::add_chat_session("p", "t", chat.clone()).unwrap();
         chat.id
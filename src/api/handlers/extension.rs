@@ -48,10 +48,7 @@ pub async fn get_extension_status() -> Json<serde_json::Value> {
             // Lock poisoning means some writer panicked while holding the lock.
             // Don't silently report `connected=false` — surface it so the
             // underlying writer crash can be diagnosed. Then degrade gracefully.
-            eprintln!(
-                "[grove] WARN: EXTENSION_SESSION RwLock poisoned: {}",
-                poisoned
-            );
+            tracing::warn!("EXTENSION_SESSION RwLock poisoned: {}", poisoned);
             false
         }
     };
@@ -142,6 +139,7 @@ pub async fn ws_handler(ws: WebSocketUpgrade) -> Response {
 }
 
 async fn handle_ws(socket: WebSocket) {
+    let _conn = crate::metrics::ws_connection("extension");
     let (mut ws_sender, mut ws_receiver) = socket.split();
     let (tx, mut rx) = mpsc::unbounded_channel::<serde_json::Value>();
 
@@ -834,10 +832,10 @@ fn open_url_with_default_browser(url: &str) -> Result<String, String> {
                 // sensible binary name in 90% of cases (firefox, brave-browser,
                 // google-chrome, microsoft-edge, opera, vivaldi, chromium).
                 let binary = desktop.trim_end_matches(".desktop").to_string();
-                if !binary.is_empty() {
-                    if std::process::Command::new(&binary).arg(url).spawn().is_ok() {
-                        return Ok(binary);
-                    }
+                if !binary.is_empty()
+                    && std::process::Command::new(&binary).arg(url).spawn().is_ok()
+                {
+                    return Ok(binary);
                 }
             }
         }
@@ -51,6 +51,14 @@ pub struct RepoCommitEntry {
     pub message: String,
     pub author: String,
     pub time_ago: String,
+    /// Commit time (RFC 3339)
+    pub time: String,
+    /// Signature verification status (unsigned commits omit it)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<git::SignatureStatus>,
+    /// Task / chat from the commit's Grove trailers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<super::common::CommitLinks>,
 }
 
 /// Recent commits response
@@ -79,6 +87,48 @@ pub struct StashRequest {
     pub pop: bool,
 }
 
+/// Stash entry with the task it was created in (matched by branch)
+#[derive(Debug, Serialize)]
+pub struct StashEntryDto {
+    pub index: usize,
+    pub reference: String,
+    pub hash: String,
+    pub branch: Option<String>,
+    pub message: String,
+    pub created_at: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_name: Option<String>,
+}
+
+/// Stash list response
+#[derive(Debug, Serialize)]
+pub struct StashListResponse {
+    pub stashes: Vec<StashEntryDto>,
+}
+
+/// Stash content response
+#[derive(Debug, Serialize)]
+pub struct StashShowResponse {
+    pub index: usize,
+    pub patch: String,
+}
+
+/// Stash apply/drop request
+#[derive(Debug, Default, Deserialize)]
+pub struct StashActionRequest {
+    /// Remove the entry after a successful apply
+    #[serde(default)]
+    pub pop: bool,
+    /// Apply inside this task's worktree instead of the main repository
+    #[serde(default)]
+    pub task_id: Option<String>,
+    /// Expected stash hash; rejects the request if the index now points elsewhere
+    #[serde(default)]
+    pub hash: Option<String>,
+}
+
 /// Commit request
 #[derive(Debug, Deserialize)]
 pub struct CommitRequest {
@@ -113,12 +163,14 @@ fn find_project_path(id: &str) -> Result<String, StatusCode> {
 fn git_cmd(path: &str, args: &[&str]) -> crate::error::Result<String> {
     use std::process::{Command, Stdio};
 
+    let started = std::time::Instant::now();
     let output = Command::new("git")
         .current_dir(path)
         .args(args)
         .stdin(Stdio::null())
         .output()
         .map_err(|e| crate::error::GroveError::git(format!("Failed to execute git: {}", e)))?;
+    crate::metrics::observe_git(args, started.elapsed());
 
     if output.status.success() {
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -417,13 +469,19 @@ pub async fn get_commits(
     repo.object_cache_size_if_unset(64 * 1024 * 1024);
 
     let entries = git::gix_recent_log(&repo, limit, min_committer_time).unwrap_or_default();
+    let hashes: Vec<String> = entries.iter().map(|e| e.hash.clone()).collect();
+    let signatures = git::signature_statuses(&project_path, &hashes).unwrap_or_default();
 
+    let mut links = super::common::CommitLinkResolver::new(&id);
     let commits: Vec<RepoCommitEntry> = entries
         .into_iter()
         .map(|e| RepoCommitEntry {
+            signature: signatures.get(&e.hash).copied(),
+            links: links.resolve(&e.trailers),
             hash: e.hash,
             message: e.message,
             author: e.author,
+            time: super::common::iso_time(e.committer_time),
             time_ago: e.time_ago,
         })
         .collect();
@@ -609,6 +667,116 @@ pub async fn stash(
     }
 }
 
+/// Check that `stash@{index}` still points at `hash` (indices shift as stashes come and go)
+fn verify_stash(path: &str, index: usize, hash: Option<&str>) -> Result<(), StatusCode> {
+    let Some(hash) = hash else {
+        return Ok(());
+    };
+    let stashes = git::list_stashes(path).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    match stashes.get(index) {
+        Some(entry) if entry.hash == hash => Ok(()),
+        Some(_) => Err(StatusCode::CONFLICT),
+        None => Err(StatusCode::NOT_FOUND),
+    }
+}
+
+/// GET /api/v1/projects/{id}/git/stash/list
+/// List stashes of the repository and all task worktrees (they share refs/stash)
+pub async fn list_stashes(Path(id): Path<String>) -> Result<Json<StashListResponse>, StatusCode> {
+    let project_path = find_project_path(&id)?;
+    let stashes =
+        git::list_stashes(&project_path).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let active = tasks::load_tasks(&id).unwrap_or_default();
+
+    let stashes = stashes
+        .into_iter()
+        .map(|entry| {
+            let task = entry
+                .branch
+                .as_deref()
+                .and_then(|b| active.iter().find(|t| t.branch == b));
+            StashEntryDto {
+                index: entry.index,
+                reference: entry.reference(),
+                task_id: task.map(|t| t.id.clone()),
+                task_name: task.map(|t| t.name.clone()),
+                hash: entry.hash,
+                branch: entry.branch,
+                message: entry.message,
+                created_at: entry.created_at,
+            }
+        })
+        .collect();
+    Ok(Json(StashListResponse { stashes }))
+}
+
+/// GET /api/v1/projects/{id}/git/stash/{index}
+/// Show a stash as a stat summary plus patch
+pub async fn show_stash(
+    Path((id, index)): Path<(String, usize)>,
+) -> Result<Json<StashShowResponse>, StatusCode> {
+    let project_path = find_project_path(&id)?;
+    let patch = git::stash_show(&project_path, index).map_err(|_| StatusCode::NOT_FOUND)?;
+    Ok(Json(StashShowResponse { index, patch }))
+}
+
+/// POST /api/v1/projects/{id}/git/stash/{index}/apply
+/// Apply (or pop) a stash in the main repository or a task worktree
+pub async fn apply_stash(
+    Path((id, index)): Path<(String, usize)>,
+    Json(req): Json<StashActionRequest>,
+) -> Result<Json<GitOpResponse>, StatusCode> {
+    let project_path = find_project_path(&id)?;
+    verify_stash(&project_path, index, req.hash.as_deref())?;
+
+    let target = match req.task_id.as_deref() {
+        Some(task_id) => {
+            tasks::get_task(&id, task_id)
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .ok_or(StatusCode::NOT_FOUND)?
+                .worktree_path
+        }
+        None => project_path,
+    };
+
+    match git::stash_apply(&target, index, req.pop) {
+        Ok(()) => Ok(Json(GitOpResponse {
+            success: true,
+            message: if req.pop {
+                "Stash popped".to_string()
+            } else {
+                "Stash applied".to_string()
+            },
+        })),
+        Err(e) => Ok(Json(GitOpResponse {
+            success: false,
+            message: e.to_string(),
+        })),
+    }
+}
+
+/// POST /api/v1/projects/{id}/git/stash/{index}/drop
+/// Drop a stash entry
+pub async fn drop_stash(
+    Path((id, index)): Path<(String, usize)>,
+    body: Option<Json<StashActionRequest>>,
+) -> Result<Json<GitOpResponse>, StatusCode> {
+    let project_path = find_project_path(&id)?;
+    let req = body.map(|Json(r)| r).unwrap_or_default();
+    verify_stash(&project_path, index, req.hash.as_deref())?;
+
+    match git::stash_drop(&project_path, index) {
+        Ok(()) => Ok(Json(GitOpResponse {
+            success: true,
+            message: "Stash dropped".to_string(),
+        })),
+        Err(e) => Ok(Json(GitOpResponse {
+            success: false,
+            message: e.to_string(),
+        })),
+    }
+}
+
 /// POST /api/v1/projects/{id}/git/branches
 /// Create a new branch
 pub async fn create_branch(
@@ -699,15 +867,12 @@ pub async fn commit(
         }));
     }
 
-    // Commit with the provided message
-    match git_cmd(&project_path, &["commit", "-m", &req.message]) {
-        Ok(output) => Ok(Json(GitOpResponse {
+    // Commit with the provided message (project signing / identity applied)
+    let options = git::CommitOptions::resolve(&id, None);
+    match git::commit(&project_path, &req.message, &options) {
+        Ok(()) => Ok(Json(GitOpResponse {
             success: true,
-            message: if output.is_empty() {
-                "Changes committed".to_string()
-            } else {
-                output
-            },
+            message: "Changes committed".to_string(),
         })),
         Err(e) => {
             let error_msg = e.to_string();
@@ -0,0 +1,100 @@
+//! Liveness / readiness probes for supervisors (systemd, launchd, k8s, uptime
+//! checkers). Mounted at the server root, outside auth.
+
+use axum::{http::StatusCode, Json};
+use serde::Serialize;
+
+use crate::storage;
+
+#[derive(Debug, Serialize)]
+pub struct HealthResponse {
+    pub status: &'static str,
+    pub version: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReadyCheck {
+    pub name: &'static str,
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReadyResponse {
+    /// "ready" | "not_ready"
+    pub status: &'static str,
+    pub checks: Vec<ReadyCheck>,
+}
+
+/// GET /healthz — the process is up and serving requests
+pub async fn healthz() -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: "ok",
+        version: env!("CARGO_PKG_VERSION"),
+    })
+}
+
+/// GET /readyz — storage is reachable; 503 when any check fails
+pub async fn readyz() -> (StatusCode, Json<ReadyResponse>) {
+    let checks = tokio::task::spawn_blocking(run_checks)
+        .await
+        .unwrap_or_else(|e| {
+            vec![ReadyCheck {
+                name: "database",
+                ok: false,
+                error: Some(format!("check panicked: {}", e)),
+            }]
+        });
+
+    let ready = checks.iter().all(|c| c.ok);
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status,
+        Json(ReadyResponse {
+            status: if ready { "ready" } else { "not_ready" },
+            checks,
+        }),
+    )
+}
+
+fn run_checks() -> Vec<ReadyCheck> {
+    let grove_dir = storage::grove_dir();
+    let storage_check = match std::fs::metadata(&grove_dir) {
+        Ok(meta) if meta.is_dir() => ReadyCheck {
+            name: "storage",
+            ok: true,
+            error: None,
+        },
+        Ok(_) => ReadyCheck {
+            name: "storage",
+            ok: false,
+            error: Some(format!("{} is not a directory", grove_dir.display())),
+        },
+        Err(e) => ReadyCheck {
+            name: "storage",
+            ok: false,
+            error: Some(format!("{}: {}", grove_dir.display(), e)),
+        },
+    };
+
+    let db_check =
+        match storage::database::connection().query_row("SELECT 1", [], |r| r.get::<_, i64>(0)) {
+            Ok(_) => ReadyCheck {
+                name: "database",
+                ok: true,
+                error: None,
+            },
+            Err(e) => ReadyCheck {
+                name: "database",
+                ok: false,
+                error: Some(e.to_string()),
+            },
+        };
+
+    vec![storage_check, db_check]
+}
@@ -0,0 +1,20 @@
+//! Inbox API handler
+
+use axum::{http::StatusCode, Json};
+use serde::Serialize;
+
+use crate::operations::inbox::{self, InboxItem};
+
+#[derive(Debug, Serialize)]
+pub struct InboxResponse {
+    pub items: Vec<InboxItem>,
+}
+
+/// GET /inbox — everything across all projects awaiting the user's input
+pub async fn get_inbox() -> Result<Json<InboxResponse>, StatusCode> {
+    // Conflict detection shells out to git per task
+    let items = tokio::task::spawn_blocking(inbox::collect_inbox)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(InboxResponse { items }))
+}
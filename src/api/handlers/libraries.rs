@@ -26,7 +26,7 @@ pub struct UpsertRequest {
 /// Map a storage-layer `GroveError` onto an HTTP status. Validation-class
 /// errors (per-item / total-items / total-bytes caps from `upsert`, JSON
 /// parse, bad request data) become 400; everything else (IO, lock, sqlite,
-/// unexpected) becomes 500. Internal errors are logged via `eprintln!`
+/// unexpected) becomes 500. Internal errors are logged via `tracing`
 /// for ops; the client never sees the raw message.
 fn map_err(context: &str, e: GroveError) -> StatusCode {
     // Validation errors from `libraries::upsert` are tagged with
@@ -34,20 +34,20 @@ fn map_err(context: &str, e: GroveError) -> StatusCode {
     // internal so we never accidentally leak storage_tagged business codes
     // out as 400s.
     if e.storage_tag() == Some("library_validation") {
-        eprintln!("{context}: validation rejected: {e}");
+        tracing::warn!("{context}: validation rejected: {e}");
         return StatusCode::BAD_REQUEST;
     }
     match e {
         GroveError::JsonParse(err) => {
-            eprintln!("{context}: bad JSON: {err}");
+            tracing::warn!("{context}: bad JSON: {err}");
             StatusCode::BAD_REQUEST
         }
         GroveError::InvalidData(msg) => {
-            eprintln!("{context}: invalid data: {msg}");
+            tracing::warn!("{context}: invalid data: {msg}");
             StatusCode::BAD_REQUEST
         }
         other => {
-            eprintln!("{context}: {other}");
+            tracing::error!("{context}: {other}");
             StatusCode::INTERNAL_SERVER_ERROR
         }
     }
@@ -65,7 +65,7 @@ where
         Ok(Ok(v)) => Ok(v),
         Ok(Err(e)) => Err(map_err("library op", e)),
         Err(join) => {
-            eprintln!("library blocking task panicked: {join}");
+            tracing::error!("library blocking task panicked: {join}");
             Err(StatusCode::INTERNAL_SERVER_ERROR)
         }
     }
@@ -0,0 +1,35 @@
+//! Recent log entries API handler
+
+use axum::extract::Query;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+
+use crate::logging::{self, LogEntry};
+
+/// Default number of entries returned when `limit` is omitted.
+const DEFAULT_LIMIT: usize = 200;
+
+#[derive(Debug, Deserialize)]
+pub struct LogsQuery {
+    /// Max entries (newest kept), default 200
+    pub limit: Option<usize>,
+    /// Minimum severity: "error" | "warn" | "info" | "debug" | "trace"
+    pub level: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LogsResponse {
+    /// Oldest first
+    pub entries: Vec<LogEntry>,
+    /// Directory holding the rolling log files
+    pub log_dir: String,
+}
+
+/// GET /api/v1/logs?limit=200&level=warn
+pub async fn get_logs(Query(q): Query<LogsQuery>) -> Json<LogsResponse> {
+    let min_level = q.level.as_deref().and_then(|l| l.parse().ok());
+    Json(LogsResponse {
+        entries: logging::recent(q.limit.unwrap_or(DEFAULT_LIMIT), min_level),
+        log_dir: logging::logs_dir().to_string_lossy().to_string(),
+    })
+}
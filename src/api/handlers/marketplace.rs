@@ -639,8 +639,8 @@ pub async fn uninstall_agent(
                     let _ = std::fs::remove_dir_all(&install_canon);
                 }
                 _ => {
-                    eprintln!(
-                        "[marketplace] refusing to remove install_root outside ~/.grove/agents/: {:?}",
+                    tracing::warn!(
+                        "refusing to remove install_root outside ~/.grove/agents/: {:?}",
                         install_root
                     );
                 }
@@ -0,0 +1,45 @@
+//! Prometheus metrics endpoint and HTTP instrumentation middleware
+
+use std::time::Instant;
+
+use axum::{
+    body::Body,
+    extract::{MatchedPath, Request},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::metrics;
+
+/// GET /metrics — Prometheus text exposition format
+pub async fn metrics_handler() -> impl IntoResponse {
+    (
+        [(
+            header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        metrics::render(),
+    )
+}
+
+/// Record latency and status of every API request, keyed by the matched
+/// route pattern so concrete ids don't blow up label cardinality.
+pub async fn http_metrics_middleware(req: Request, next: Next) -> Response<Body> {
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|m| m.as_str().to_owned())
+        .unwrap_or_else(|| "unmatched".to_string());
+    let method = req.method().clone();
+
+    let started = Instant::now();
+    let response = next.run(req).await;
+    metrics::observe_http(
+        method.as_str(),
+        &route,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    response
+}
@@ -15,20 +15,29 @@ pub mod extension;
 pub mod files;
 pub mod folder;
 pub mod git;
+pub mod health;
 pub mod hooks;
+pub mod inbox;
 pub mod keymap;
 pub mod libraries;
+pub mod logs;
 pub mod marketplace;
+pub mod metrics;
+pub mod org;
 #[cfg(feature = "perf-monitor")]
 pub mod perf;
 pub mod plugins;
+pub mod preamble;
 pub mod projects;
 pub mod render;
+pub mod search;
+pub mod share;
 pub mod skills;
 pub mod statistics;
 pub mod stats;
 pub mod studio_common;
 pub mod symbols;
+pub mod sync;
 pub mod taskgroups;
 pub mod tasks;
 pub mod terminal;
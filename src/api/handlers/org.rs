@@ -0,0 +1,277 @@
+//! Organization mode API handlers
+//!
+//! Two sides live here: the `/org` routes a team server mounts for its
+//! members (per-member bearer tokens, outside `/api/v1`), and the `/api/v1`
+//! routes the local web UI uses to browse and comment on teammates' tasks.
+
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use serde::{Deserialize, Serialize};
+
+use crate::api::auth::OrgMember;
+use crate::api::error::ApiError;
+use crate::error::GroveError;
+use crate::operations::org::{self, MemberBody, NotesBody, OrgStatus, SharedTask};
+use crate::operations::sync::TaskRecord;
+use crate::storage::backend::{CommentStore, NoteStore};
+use crate::storage::comments::Comment;
+use crate::storage::config;
+use crate::storage::org::MemberStore;
+
+type ApiResult<T> = Result<Json<T>, (StatusCode, Json<ApiError>)>;
+
+/// Run blocking org work, mapping errors. `upstream` marks calls that go
+/// through the team server, whose failures are reported as 502.
+async fn run_blocking<T, F>(upstream: bool, f: F) -> ApiResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> crate::error::Result<T> + Send + 'static,
+{
+    let result = tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+    result.map(Json).map_err(|e| match e {
+        GroveError::NotFound(msg) => ApiError::not_found(msg),
+        GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+        e if upstream => ApiError::with_status(StatusCode::BAD_GATEWAY, e.to_string()),
+        e => ApiError::internal(e.to_string()),
+    })
+}
+
+// ============================================================================
+// Team server side
+// ============================================================================
+
+/// Members may only write their own tasks and notes.
+fn ensure_owner(member: &str, owner: &str) -> Result<(), (StatusCode, Json<ApiError>)> {
+    if member == owner {
+        Ok(())
+    } else {
+        Err(ApiError::forbidden(format!(
+            "Only {owner} can publish {owner}'s tasks"
+        )))
+    }
+}
+
+/// GET /org/me — the member the token was issued to
+pub async fn server_me(Extension(OrgMember(member)): Extension<OrgMember>) -> Json<MemberBody> {
+    Json(MemberBody { member })
+}
+
+/// GET /org/projects/{project}/tasks — tasks published by every member
+pub async fn server_list_tasks(Path(project): Path<String>) -> ApiResult<Vec<SharedTask>> {
+    run_blocking(false, move || org::server_list_tasks(&project)).await
+}
+
+/// GET /org/projects/{project}/members/{owner}/tasks
+pub async fn server_member_tasks(
+    Path((project, owner)): Path<(String, String)>,
+) -> ApiResult<Vec<TaskRecord>> {
+    run_blocking(false, move || org::server_load_tasks(&project, &owner)).await
+}
+
+/// PUT /org/projects/{project}/members/{owner}/tasks — replace the caller's
+/// published tasks
+pub async fn server_publish_tasks(
+    Extension(OrgMember(member)): Extension<OrgMember>,
+    Path((project, owner)): Path<(String, String)>,
+    Json(tasks): Json<Vec<TaskRecord>>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    ensure_owner(&member, &owner)?;
+    run_blocking(false, move || {
+        org::server_replace_tasks(&project, &owner, tasks)
+    })
+    .await
+    .map(|_| StatusCode::NO_CONTENT)
+}
+
+/// GET /org/projects/{project}/members/{owner}/tasks/{taskId}/notes
+pub async fn server_get_notes(
+    Path((project, owner, task_id)): Path<(String, String, String)>,
+) -> ApiResult<NotesBody> {
+    run_blocking(false, move || {
+        let content = MemberStore::new(&owner).load_notes(&project, &task_id)?;
+        Ok(NotesBody { content })
+    })
+    .await
+}
+
+/// PUT /org/projects/{project}/members/{owner}/tasks/{taskId}/notes
+pub async fn server_save_notes(
+    Extension(OrgMember(member)): Extension<OrgMember>,
+    Path((project, owner, task_id)): Path<(String, String, String)>,
+    Json(body): Json<NotesBody>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    ensure_owner(&member, &owner)?;
+    run_blocking(false, move || {
+        MemberStore::new(&owner).save_notes(&project, &task_id, &body.content)
+    })
+    .await
+    .map(|_| StatusCode::NO_CONTENT)
+}
+
+/// GET /org/projects/{project}/members/{owner}/tasks/{taskId}/comments
+pub async fn server_get_comments(
+    Path((project, owner, task_id)): Path<(String, String, String)>,
+) -> ApiResult<Vec<Comment>> {
+    run_blocking(false, move || {
+        MemberStore::new(&owner).load_comments(&project, &task_id)
+    })
+    .await
+}
+
+/// PUT /org/projects/{project}/members/{owner}/tasks/{taskId}/comments —
+/// merge the caller's review threads into a shared task
+pub async fn server_merge_comments(
+    Extension(OrgMember(member)): Extension<OrgMember>,
+    Path((project, owner, task_id)): Path<(String, String, String)>,
+    Json(comments): Json<Vec<Comment>>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    run_blocking(false, move || {
+        org::server_merge_comments(&project, &owner, &task_id, &member, &comments)
+    })
+    .await
+    .map(|_| StatusCode::NO_CONTENT)
+}
+
+// ============================================================================
+// Client side (local web UI)
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct OrgStatusResponse {
+    /// Whether a team server is configured
+    pub configured: bool,
+    #[serde(flatten)]
+    pub status: OrgStatus,
+}
+
+fn status_response(status: OrgStatus) -> OrgStatusResponse {
+    let cfg = config::load_config().organization;
+    OrgStatusResponse {
+        configured: cfg
+            .server_url
+            .as_deref()
+            .is_some_and(|u| !u.trim().is_empty()),
+        status,
+    }
+}
+
+/// GET /org/status — organization mode state and last publish outcome
+pub async fn get_status() -> ApiResult<OrgStatusResponse> {
+    run_blocking(false, || Ok(status_response(org::status()))).await
+}
+
+/// POST /org/publish — publish local tasks to the team server now
+pub async fn publish_now() -> ApiResult<OrgStatusResponse> {
+    run_blocking(true, || org::publish_now().map(status_response)).await
+}
+
+#[derive(Debug, Serialize)]
+pub struct TeamTasksResponse {
+    pub member: String,
+    pub tasks: Vec<SharedTask>,
+}
+
+/// GET /projects/{id}/team-tasks — teammates' tasks in this project
+pub async fn list_team_tasks(Path(id): Path<String>) -> ApiResult<TeamTasksResponse> {
+    run_blocking(true, move || {
+        let (member, tasks) = org::team_tasks(&id)?;
+        Ok(TeamTasksResponse { member, tasks })
+    })
+    .await
+}
+
+/// GET /projects/{id}/team-tasks/{owner}/{taskId}/comments
+pub async fn get_team_comments(
+    Path((id, owner, task_id)): Path<(String, String, String)>,
+) -> ApiResult<Vec<Comment>> {
+    run_blocking(true, move || org::team_comments(&id, &owner, &task_id)).await
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TeamCommentRequest {
+    pub content: String,
+    /// Reply to this thread instead of starting a new one
+    pub reply_to: Option<u32>,
+}
+
+/// POST /projects/{id}/team-tasks/{owner}/{taskId}/comments
+pub async fn add_team_comment(
+    Path((id, owner, task_id)): Path<(String, String, String)>,
+    Json(req): Json<TeamCommentRequest>,
+) -> ApiResult<Vec<Comment>> {
+    let content = req.content.trim().to_string();
+    if content.is_empty() {
+        return Err(ApiError::bad_request("Comment is empty"));
+    }
+    run_blocking(true, move || {
+        org::comment_on_team_task(&id, &owner, &task_id, &content, req.reply_to)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use axum::routing::get;
+
+    use super::*;
+    use crate::api::auth::org_member_auth_middleware;
+
+    #[tokio::test]
+    async fn members_are_named_by_their_token_and_publish_only_as_themselves() {
+        let members: HashMap<String, String> = [("alice", "ta"), ("bob", "tb")]
+            .into_iter()
+            .map(|(m, t)| (m.to_string(), t.to_string()))
+            .collect();
+        let app = axum::Router::new()
+            .route("/org/me", get(server_me))
+            .route(
+                "/org/projects/{project}/members/{owner}/tasks",
+                get(server_member_tasks).put(server_publish_tasks),
+            )
+            .layer(axum::middleware::from_fn_with_state(
+                Arc::new(members),
+                org_member_auth_middleware,
+            ));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, app).await;
+        });
+
+        let client = reqwest::Client::new();
+        let base = format!("http://127.0.0.1:{port}/org");
+        let me = client
+            .get(format!("{base}/me"))
+            .bearer_auth("tb")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(me.json::<MemberBody>().await.unwrap().member, "bob");
+
+        let unknown = client
+            .get(format!("{base}/me"))
+            .bearer_auth("nope")
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(unknown.status(), StatusCode::UNAUTHORIZED);
+
+        // Bob can't replace (or clear) Alice's tasks
+        let publish = client
+            .put(format!("{base}/projects/p/members/alice/tasks"))
+            .bearer_auth("tb")
+            .json(&serde_json::json!([]))
+            .send()
+            .await
+            .expect("request");
+        assert_eq!(publish.status(), StatusCode::FORBIDDEN);
+
+        server.abort();
+    }
+}
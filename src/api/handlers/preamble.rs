@@ -0,0 +1,24 @@
+//! Global instruction preamble handlers
+//!
+//! Preambles are prepended (as a hidden context block) to the first prompt
+//! of every new ACP session; see `storage::instructions`.
+
+use axum::{http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::storage::instructions::{self, Instructions};
+
+/// GET /api/v1/preamble
+pub async fn get_global_preamble() -> Json<Instructions> {
+    Json(instructions::load_global())
+}
+
+/// PUT /api/v1/preamble
+pub async fn update_global_preamble(
+    Json(mut body): Json<Instructions>,
+) -> Result<Json<Instructions>, (StatusCode, Json<ApiError>)> {
+    body.normalize();
+    instructions::save_global(&body)
+        .map_err(|e| ApiError::internal(format!("Failed to save preamble: {}", e)))?;
+    Ok(Json(body))
+}
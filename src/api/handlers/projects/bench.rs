@@ -0,0 +1,39 @@
+//! Project benchmark settings handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::bench::{self, BenchSettings};
+
+/// GET /api/v1/projects/{id}/bench
+pub async fn get_bench_settings(
+    Path(id): Path<String>,
+) -> Result<Json<BenchSettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    Ok(Json(bench::load_settings(&project_key)))
+}
+
+/// PUT /api/v1/projects/{id}/bench
+///
+/// An empty command turns the integration off.
+pub async fn update_bench_settings(
+    Path(id): Path<String>,
+    Json(mut body): Json<BenchSettings>,
+) -> Result<Json<BenchSettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    if !body.threshold_percent.is_finite() || body.threshold_percent < 0.0 {
+        return Err(ApiError::bad_request(
+            "threshold_percent must be a non-negative number",
+        ));
+    }
+    if body.timeout_secs == 0 {
+        return Err(ApiError::bad_request("timeout_secs must be positive"));
+    }
+    body.command = body.command.trim().to_string();
+    bench::save_settings(&project_key, &body)
+        .map_err(|e| ApiError::internal(format!("Failed to save bench settings: {}", e)))?;
+    Ok(Json(body))
+}
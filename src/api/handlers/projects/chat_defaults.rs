@@ -0,0 +1,31 @@
+//! Project chat mode/model defaults handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::chat_defaults::{self, ChatDefaults};
+
+/// GET /api/v1/projects/{id}/chat-defaults
+pub async fn get_chat_defaults(
+    Path(id): Path<String>,
+) -> Result<Json<ChatDefaults>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    Ok(Json(chat_defaults::load_settings(&project_key)))
+}
+
+/// PUT /api/v1/projects/{id}/chat-defaults
+///
+/// Pinned values win over the last-used ones when a new chat starts.
+pub async fn update_chat_defaults(
+    Path(id): Path<String>,
+    Json(mut body): Json<ChatDefaults>,
+) -> Result<Json<ChatDefaults>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    body.normalize();
+    chat_defaults::save_settings(&project_key, &body)
+        .map_err(|e| ApiError::internal(format!("Failed to save chat defaults: {}", e)))?;
+    Ok(Json(body))
+}
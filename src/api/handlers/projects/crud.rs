@@ -39,6 +39,10 @@ pub fn storage_task_to_response(task: &tasks::Task) -> TaskResponse {
         multiplexer: task.multiplexer.clone(),
         created_by: task.created_by.clone(),
         is_local: task.is_local,
+        agent_state: None,
+        assignee: task.assignee.clone(),
+        pinned: task.pinned,
+        warning: None,
     }
 }
 
@@ -231,6 +235,7 @@ pub async fn list_projects() -> Result<Json<ProjectListResponse>, StatusCode> {
                 is_git_repo,
                 exists,
                 project_type: p.project_type.as_str().to_string(),
+                pinned: p.pinned,
             }
         })
         .collect();
@@ -267,6 +272,7 @@ pub async fn get_project(Path(id): Path<String>) -> Result<Json<ProjectResponse>
     let project_path = project.path.clone();
     let added_at = project.added_at.to_rfc3339();
     let project_type = project.project_type.as_str().to_string();
+    let pinned = project.pinned;
     let is_studio = project.project_type == workspace::ProjectType::Studio;
     let exists = if is_studio {
         workspace::studio_project_dir(&project_path).exists()
@@ -285,6 +291,10 @@ pub async fn get_project(Path(id): Path<String>) -> Result<Json<ProjectResponse>
             is_git_repo: false,
             exists: false,
             project_type,
+            default_target: None,
+            commit_signing: None,
+            commit_trailers: true,
+            pinned,
         }));
     }
 
@@ -298,7 +308,7 @@ pub async fn get_project(Path(id): Path<String>) -> Result<Json<ProjectResponse>
                 .chain(archived_tasks.iter())
                 .map(storage_task_to_response)
                 .collect();
-            all.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+            common::sort_task_responses(&mut all);
             (all, ())
         })
         .await
@@ -315,6 +325,10 @@ pub async fn get_project(Path(id): Path<String>) -> Result<Json<ProjectResponse>
             is_git_repo: false,
             exists: true,
             project_type,
+            default_target: None,
+            commit_signing: None,
+            commit_trailers: true,
+            pinned,
         }));
     }
 
@@ -351,7 +365,7 @@ pub async fn get_project(Path(id): Path<String>) -> Result<Json<ProjectResponse>
             .chain(archived_tasks.iter())
             .map(storage_task_to_response)
             .collect();
-        all_tasks.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        common::sort_task_responses(&mut all_tasks);
 
         let local_task = local_task_db.as_ref().map(storage_task_to_response);
 
@@ -360,6 +374,9 @@ pub async fn get_project(Path(id): Path<String>) -> Result<Json<ProjectResponse>
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let default_target = workspace::load_default_target(&id).ok().flatten();
+    let commit_signing = workspace::load_commit_signing(&id).ok().flatten();
+    let commit_trailers = workspace::load_commit_trailers(&id).unwrap_or(true);
     Ok(Json(ProjectResponse {
         id,
         name: project_name,
@@ -371,6 +388,10 @@ pub async fn get_project(Path(id): Path<String>) -> Result<Json<ProjectResponse>
         is_git_repo,
         exists: true,
         project_type,
+        default_target,
+        commit_signing,
+        commit_trailers,
+        pinned,
     }))
 }
 
@@ -492,6 +513,10 @@ pub async fn add_project(
         multiplexer: wt.multiplexer,
         created_by: wt.created_by,
         is_local: true,
+        agent_state: None,
+        assignee: wt.assignee,
+        pinned: false,
+        warning: None,
     });
 
     let _ = crate::storage::taskgroups::ensure_system_groups();
@@ -509,6 +534,10 @@ pub async fn add_project(
         is_git_repo: is_git,
         exists: true,
         project_type: "repo".to_string(),
+        default_target: None,
+        commit_signing: None,
+        commit_trailers: true,
+        pinned: false,
     }))
 }
 
@@ -546,6 +575,10 @@ pub async fn create_new_project(
             is_git_repo: false,
             exists: true,
             project_type: "studio".to_string(),
+            default_target: None,
+            commit_signing: None,
+            commit_trailers: true,
+            pinned: false,
         }))
     } else {
         let init_git = req.init_git;
@@ -592,6 +625,10 @@ pub async fn create_new_project(
             multiplexer: wt.multiplexer,
             created_by: wt.created_by,
             is_local: true,
+            agent_state: None,
+            assignee: wt.assignee,
+            pinned: false,
+            warning: None,
         });
         let _ = crate::storage::taskgroups::ensure_system_groups();
         use crate::api::handlers::walkie_talkie::{broadcast_radio_event, RadioEvent};
@@ -608,6 +645,10 @@ pub async fn create_new_project(
             is_git_repo: init_git,
             exists: true,
             project_type: "repo".to_string(),
+            default_target: None,
+            commit_signing: None,
+            commit_trailers: true,
+            pinned: false,
         }))
     }
 }
@@ -653,6 +694,83 @@ pub async fn rename_project(
     })
 }
 
+/// PUT /api/v1/projects/{id}/default-target
+pub async fn set_default_target(
+    Path(id): Path<String>,
+    Json(req): Json<DefaultTargetRequest>,
+) -> Result<Json<ProjectResponse>, (StatusCode, Json<ApiError>)> {
+    let (project, project_key) = common::find_project_by_id(&id)
+        .map_err(|s| ApiError::map_status(s, "Project not found"))?;
+
+    let target = req
+        .target
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty());
+    if let Some(ref branch) = target {
+        if !git::branch_exists(&project.path, branch) {
+            return Err(ApiError::bad_request(format!(
+                "Branch '{}' does not exist",
+                branch
+            )));
+        }
+    }
+
+    workspace::set_default_target(&project_key, target.as_deref())
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+
+    get_project(Path(id))
+        .await
+        .map_err(|s| ApiError::map_status(s, "Failed to load updated project"))
+}
+
+/// PUT /api/v1/projects/{id}/commit-signing
+pub async fn set_commit_signing(
+    Path(id): Path<String>,
+    Json(req): Json<CommitSigningRequest>,
+) -> Result<Json<ProjectResponse>, (StatusCode, Json<ApiError>)> {
+    let (_, project_key) = common::find_project_by_id(&id)
+        .map_err(|s| ApiError::map_status(s, "Project not found"))?;
+
+    workspace::set_commit_signing(&project_key, req.enabled)
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+
+    get_project(Path(id))
+        .await
+        .map_err(|s| ApiError::map_status(s, "Failed to load updated project"))
+}
+
+/// PUT /api/v1/projects/{id}/commit-trailers
+pub async fn set_commit_trailers(
+    Path(id): Path<String>,
+    Json(req): Json<CommitTrailersRequest>,
+) -> Result<Json<ProjectResponse>, (StatusCode, Json<ApiError>)> {
+    let (_, project_key) = common::find_project_by_id(&id)
+        .map_err(|s| ApiError::map_status(s, "Project not found"))?;
+
+    workspace::set_commit_trailers(&project_key, req.enabled)
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+
+    get_project(Path(id))
+        .await
+        .map_err(|s| ApiError::map_status(s, "Failed to load updated project"))
+}
+
+/// PUT /api/v1/projects/{id}/pin
+pub async fn set_project_pinned(
+    Path(id): Path<String>,
+    Json(req): Json<PinRequest>,
+) -> Result<Json<ProjectResponse>, (StatusCode, Json<ApiError>)> {
+    let (_, project_key) = common::find_project_by_id(&id)
+        .map_err(|s| ApiError::map_status(s, "Project not found"))?;
+
+    workspace::set_project_pinned(&project_key, req.pinned)
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+
+    get_project(Path(id))
+        .await
+        .map_err(|s| ApiError::map_status(s, "Failed to load updated project"))
+}
+
 pub async fn delete_project(Path(id): Path<String>) -> Result<StatusCode, StatusCode> {
     let (project, _) = common::find_project_by_id(&id)?;
 
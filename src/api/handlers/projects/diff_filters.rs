@@ -0,0 +1,63 @@
+//! Project diff filter handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::diff_filters::{self, DiffFilterSettings};
+
+use super::types::*;
+
+/// GET /api/v1/projects/{id}/diff-filters
+pub async fn get_diff_filters(
+    Path(id): Path<String>,
+) -> Result<Json<DiffFiltersDto>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    Ok(Json(to_dto(diff_filters::load_settings(&project_key))))
+}
+
+/// PUT /api/v1/projects/{id}/diff-filters
+///
+/// Patterns are trimmed and de-duplicated; an invalid glob is a 400.
+pub async fn update_diff_filters(
+    Path(id): Path<String>,
+    Json(body): Json<DiffFiltersDto>,
+) -> Result<Json<DiffFiltersDto>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+
+    let test_patterns = match body.test_patterns {
+        Some(patterns) => normalize_patterns(patterns)?,
+        None => diff_filters::load_settings(&project_key).test_patterns,
+    };
+    let settings = DiffFilterSettings {
+        collapsed_patterns: normalize_patterns(body.collapsed_patterns)?,
+        test_patterns,
+    };
+    diff_filters::save_settings(&project_key, &settings)
+        .map_err(|e| ApiError::internal(format!("Failed to save diff filters: {}", e)))?;
+    Ok(Json(to_dto(settings)))
+}
+
+fn to_dto(settings: DiffFilterSettings) -> DiffFiltersDto {
+    DiffFiltersDto {
+        collapsed_patterns: settings.collapsed_patterns,
+        test_patterns: Some(settings.test_patterns),
+    }
+}
+
+fn normalize_patterns(raw: Vec<String>) -> Result<Vec<String>, (StatusCode, Json<ApiError>)> {
+    let mut patterns: Vec<String> = Vec::new();
+    for pattern in raw {
+        let pattern = pattern.trim().to_string();
+        if pattern.is_empty() || patterns.contains(&pattern) {
+            continue;
+        }
+        globset::Glob::new(&pattern).map_err(|e| {
+            ApiError::bad_request(format!("Invalid glob pattern '{}': {}", pattern, e))
+        })?;
+        patterns.push(pattern);
+    }
+    Ok(patterns)
+}
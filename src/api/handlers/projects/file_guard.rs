@@ -0,0 +1,54 @@
+//! Project file guard settings handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::file_guard::{self, FileGuardSettings};
+
+/// Trim entries and drop blank ones
+fn normalize(list: Vec<String>) -> Vec<String> {
+    list.into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// GET /api/v1/projects/{id}/file-guard
+pub async fn get_file_guard(
+    Path(id): Path<String>,
+) -> Result<Json<FileGuardSettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let settings = file_guard::load_settings(&project_key)
+        .map_err(|e| ApiError::internal(format!("Failed to load file guard: {}", e)))?;
+    Ok(Json(settings))
+}
+
+/// PUT /api/v1/projects/{id}/file-guard
+pub async fn update_file_guard(
+    Path(id): Path<String>,
+    Json(body): Json<FileGuardSettings>,
+) -> Result<Json<FileGuardSettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    if let Some(bad) = body
+        .blocked_paths
+        .iter()
+        .find(|p| globset::Glob::new(p.trim()).is_err())
+    {
+        return Err(ApiError::bad_request(format!("Invalid glob: {}", bad)));
+    }
+    let settings = FileGuardSettings {
+        blocked_paths: normalize(body.blocked_paths),
+        blocked_extensions: normalize(body.blocked_extensions)
+            .into_iter()
+            .map(|e| e.trim_start_matches('.').to_ascii_lowercase())
+            .collect(),
+        disallowed_licenses: normalize(body.disallowed_licenses),
+        ..body
+    };
+    file_guard::save_settings(&project_key, &settings)
+        .map_err(|e| ApiError::internal(format!("Failed to save file guard: {}", e)))?;
+    Ok(Json(settings))
+}
@@ -0,0 +1,65 @@
+//! Project forge (GitHub / GitLab / Gitea) settings handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+use serde::Serialize;
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::forge;
+use crate::storage::forge::{self as forge_settings, ForgeKind, ForgeSettings};
+
+/// Forge settings plus what Grove resolves for the project
+#[derive(Debug, Serialize)]
+pub struct ForgeSettingsResponse {
+    #[serde(flatten)]
+    pub settings: ForgeSettings,
+    /// Forge guessed from the `origin` host (`null` when unknown / no origin)
+    pub detected: Option<ForgeKind>,
+    /// Whether a token is configured for the effective forge
+    pub token_configured: bool,
+}
+
+fn response(project_path: &str, settings: ForgeSettings) -> ForgeSettingsResponse {
+    let detected = crate::git::remote_url(project_path, "origin")
+        .ok()
+        .and_then(|url| forge::RemoteRepo::parse(&url))
+        .and_then(|repo| forge::detect_kind(&repo.host));
+    let config = crate::storage::config::load_config();
+    let token_configured = settings
+        .kind
+        .or(detected)
+        .is_some_and(|kind| forge::auth(&config, kind).token().is_some());
+    ForgeSettingsResponse {
+        settings,
+        detected,
+        token_configured,
+    }
+}
+
+/// GET /api/v1/projects/{id}/forge
+pub async fn get_forge_settings(
+    Path(id): Path<String>,
+) -> Result<Json<ForgeSettingsResponse>, (StatusCode, Json<ApiError>)> {
+    let (project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let settings = forge_settings::load_settings(&project_key);
+    Ok(Json(response(&project.path, settings)))
+}
+
+/// PUT /api/v1/projects/{id}/forge
+///
+/// `kind: null` detects the forge from the `origin` host; `api_url: null`
+/// derives the API root from it.
+pub async fn update_forge_settings(
+    Path(id): Path<String>,
+    Json(body): Json<ForgeSettings>,
+) -> Result<Json<ForgeSettingsResponse>, (StatusCode, Json<ApiError>)> {
+    let (project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let settings = body
+        .normalized()
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    forge_settings::save_settings(&project_key, &settings)
+        .map_err(|e| ApiError::internal(format!("Failed to save forge settings: {}", e)))?;
+    Ok(Json(response(&project.path, settings)))
+}
@@ -0,0 +1,44 @@
+//! Project git identity override handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::git_identity::{self, GitIdentity};
+
+use super::types::*;
+
+/// GET /api/v1/projects/{id}/git-identity
+pub async fn get_git_identity(
+    Path(id): Path<String>,
+) -> Result<Json<GitIdentityResponse>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let identity = git_identity::load_project(&project_key);
+    Ok(Json(GitIdentityResponse {
+        effective: identity.clone(),
+        identity,
+    }))
+}
+
+/// PUT /api/v1/projects/{id}/git-identity
+///
+/// Replaces the whole override; empty fields fall back to git's own config.
+pub async fn update_git_identity(
+    Path(id): Path<String>,
+    Json(body): Json<GitIdentity>,
+) -> Result<Json<GitIdentityResponse>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+
+    let identity = body.normalized();
+    identity
+        .validate()
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    git_identity::save_project(&project_key, &identity)
+        .map_err(|e| ApiError::internal(format!("Failed to save git identity: {}", e)))?;
+    Ok(Json(GitIdentityResponse {
+        effective: identity.clone(),
+        identity,
+    }))
+}
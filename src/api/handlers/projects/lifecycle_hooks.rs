@@ -0,0 +1,33 @@
+//! Project task lifecycle hook handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::lifecycle_hooks::{self, LifecycleHookSettings};
+
+/// GET /api/v1/projects/{id}/lifecycle-hooks
+pub async fn get_lifecycle_hooks(
+    Path(id): Path<String>,
+) -> Result<Json<LifecycleHookSettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    Ok(Json(lifecycle_hooks::load_settings(&project_key)))
+}
+
+/// PUT /api/v1/projects/{id}/lifecycle-hooks
+///
+/// Hooks with an empty command are dropped; a zero timeout is a 400.
+pub async fn update_lifecycle_hooks(
+    Path(id): Path<String>,
+    Json(body): Json<LifecycleHookSettings>,
+) -> Result<Json<LifecycleHookSettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let settings = body
+        .normalized()
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    lifecycle_hooks::save_settings(&project_key, &settings)
+        .map_err(|e| ApiError::internal(format!("Failed to save lifecycle hooks: {}", e)))?;
+    Ok(Json(settings))
+}
@@ -0,0 +1,33 @@
+//! Project merge gate handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::merge_gate::{self, MergeGate};
+
+/// GET /api/v1/projects/{id}/merge-gate
+pub async fn get_merge_gate(
+    Path(id): Path<String>,
+) -> Result<Json<MergeGate>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let settings = merge_gate::load_settings(&project_key)
+        .map_err(|e| ApiError::internal(format!("Failed to load merge gate: {}", e)))?;
+    Ok(Json(settings))
+}
+
+/// PUT /api/v1/projects/{id}/merge-gate
+///
+/// Reviewer names are trimmed and de-duplicated (case-insensitive).
+pub async fn update_merge_gate(
+    Path(id): Path<String>,
+    Json(body): Json<MergeGate>,
+) -> Result<Json<MergeGate>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let gate = body.normalized();
+    merge_gate::save_settings(&project_key, &gate)
+        .map_err(|e| ApiError::internal(format!("Failed to save merge gate: {}", e)))?;
+    Ok(Json(gate))
+}
@@ -1,14 +1,40 @@
 //! Project API handlers
 
+pub mod bench;
+pub mod chat_defaults;
 pub mod crud;
+pub mod diff_filters;
+pub mod file_guard;
+pub mod forge;
+pub mod git_identity;
 pub mod instructions;
+pub mod lifecycle_hooks;
+pub mod merge_gate;
+pub mod notes;
+pub mod preamble;
 pub mod project_git;
 pub mod resources;
+pub mod security_scan;
+pub mod task_summary;
+pub mod templates;
 pub mod types;
 
 // Re-export all public items so routing table needs zero changes.
+pub use bench::*;
+pub use chat_defaults::*;
 pub use crud::*;
+pub use diff_filters::*;
+pub use file_guard::*;
+pub use forge::*;
+pub use git_identity::*;
 pub use instructions::*;
+pub use lifecycle_hooks::*;
+pub use merge_gate::*;
+pub use notes::*;
+pub use preamble::*;
 pub use project_git::*;
 pub use resources::*;
+pub use security_scan::*;
+pub use task_summary::*;
+pub use templates::*;
 pub use types::*;
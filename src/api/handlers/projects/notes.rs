@@ -0,0 +1,100 @@
+//! Project-level notes (shared knowledge base) handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::error::GroveError;
+use crate::storage::project_notes::{self, ProjectNote};
+
+use super::types::*;
+
+type ApiResult<T> = Result<Json<T>, (StatusCode, Json<ApiError>)>;
+
+fn project_key(id: &str) -> Result<String, (StatusCode, Json<ApiError>)> {
+    common::find_project_by_id(id)
+        .map(|(_, key)| key)
+        .map_err(|_| ApiError::not_found("Project not found"))
+}
+
+fn note_error(e: GroveError) -> (StatusCode, Json<ApiError>) {
+    match e {
+        GroveError::NotFound(msg) => ApiError::not_found(msg),
+        GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+        other => ApiError::internal(other.to_string()),
+    }
+}
+
+fn to_dto(note: ProjectNote) -> ProjectNoteDto {
+    ProjectNoteDto {
+        slug: note.slug,
+        title: note.title,
+        include_in_chat: note.include_in_chat,
+        updated_at: note.updated_at,
+    }
+}
+
+/// GET /api/v1/projects/{id}/notes
+pub async fn list_project_notes(Path(id): Path<String>) -> ApiResult<ProjectNotesResponse> {
+    let key = project_key(&id)?;
+    let notes = project_notes::list_notes(&key).map_err(note_error)?;
+    Ok(Json(ProjectNotesResponse {
+        notes: notes.into_iter().map(to_dto).collect(),
+    }))
+}
+
+/// POST /api/v1/projects/{id}/notes
+pub async fn create_project_note(
+    Path(id): Path<String>,
+    Json(body): Json<CreateProjectNoteRequest>,
+) -> ApiResult<ProjectNoteDetail> {
+    let key = project_key(&id)?;
+    let note = project_notes::create_note(&key, &body.title, &body.content, body.include_in_chat)
+        .map_err(note_error)?;
+    Ok(Json(ProjectNoteDetail {
+        note: to_dto(note),
+        content: body.content,
+    }))
+}
+
+/// GET /api/v1/projects/{id}/notes/{slug}
+pub async fn get_project_note(
+    Path((id, slug)): Path<(String, String)>,
+) -> ApiResult<ProjectNoteDetail> {
+    let key = project_key(&id)?;
+    let (note, content) = project_notes::load_note(&key, &slug).map_err(note_error)?;
+    Ok(Json(ProjectNoteDetail {
+        note: to_dto(note),
+        content,
+    }))
+}
+
+/// PUT /api/v1/projects/{id}/notes/{slug}
+pub async fn update_project_note(
+    Path((id, slug)): Path<(String, String)>,
+    Json(body): Json<UpdateProjectNoteRequest>,
+) -> ApiResult<ProjectNoteDetail> {
+    let key = project_key(&id)?;
+    project_notes::update_note(
+        &key,
+        &slug,
+        body.title.as_deref(),
+        body.content.as_deref(),
+        body.include_in_chat,
+    )
+    .map_err(note_error)?;
+    let (note, content) = project_notes::load_note(&key, &slug).map_err(note_error)?;
+    Ok(Json(ProjectNoteDetail {
+        note: to_dto(note),
+        content,
+    }))
+}
+
+/// DELETE /api/v1/projects/{id}/notes/{slug}
+pub async fn delete_project_note(
+    Path((id, slug)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let key = project_key(&id)?;
+    project_notes::delete_note(&key, &slug).map_err(note_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,31 @@
+//! Project instruction preamble handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::instructions::{self, Instructions};
+
+/// GET /api/v1/projects/{id}/preamble
+pub async fn get_project_preamble(
+    Path(id): Path<String>,
+) -> Result<Json<Instructions>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    Ok(Json(instructions::load_project(&project_key)))
+}
+
+/// PUT /api/v1/projects/{id}/preamble
+///
+/// Applies to new sessions only; running chats keep the preamble they started with.
+pub async fn update_project_preamble(
+    Path(id): Path<String>,
+    Json(mut body): Json<Instructions>,
+) -> Result<Json<Instructions>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    body.normalize();
+    instructions::save_project(&project_key, &body)
+        .map_err(|e| ApiError::internal(format!("Failed to save preamble: {}", e)))?;
+    Ok(Json(body))
+}
@@ -9,14 +9,46 @@ use chrono::Utc;
 use std::process::Command;
 
 use crate::git;
-use crate::git::git_cmd;
 use crate::model::loader;
+use crate::operations::projects;
 use crate::storage::{tasks, workspace};
 use crate::watcher;
 
 use super::types::*;
 use crate::api::handlers::common::find_project_by_id;
 
+/// GET /api/v1/projects/{id}/health
+///
+/// Repo-level warnings for the project header. Missing directories and
+/// non-git projects report as healthy (there is nothing to check).
+pub async fn get_health(Path(id): Path<String>) -> Result<Json<ProjectHealthResponse>, StatusCode> {
+    let (project, _) = find_project_by_id(&id)?;
+    if project.project_type == workspace::ProjectType::Studio {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let project_path = project.path.clone();
+    let health = tokio::task::spawn_blocking(move || {
+        if std::path::Path::new(&project_path).exists() && git::is_git_usable(&project_path) {
+            projects::project_health(&project_path)
+        } else {
+            projects::ProjectHealth::default()
+        }
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(ProjectHealthResponse {
+        warnings: health.warnings(),
+        target_branch: health.target_branch,
+        target_behind_origin: health.target_behind_origin,
+        uncommitted_changes: health.uncommitted_changes,
+        stash_count: health.stash_count,
+        stale_worktrees: health.stale_worktrees,
+        broken_links: health.broken_links,
+    }))
+}
+
 /// GET /api/v1/projects/{id}/stats
 pub async fn get_stats(Path(id): Path<String>) -> Result<Json<ProjectStatsResponse>, StatusCode> {
     let (project, _) = find_project_by_id(&id)?;
@@ -93,34 +125,27 @@ pub async fn get_branches(
 
     let current = git::current_branch(&project.path).unwrap_or_else(|_| "unknown".to_string());
 
-    let branch_names = if params.remote == "local" {
-        git::list_branches(&project.path).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+    let refs = if params.remote == "local" {
+        "refs/heads".to_string()
     } else {
-        let remote_pattern = format!("{}/*", params.remote);
-        let remote_output = git_cmd(
-            &project.path,
-            &[
-                "branch",
-                "-r",
-                "--format=%(refname:short)",
-                "--list",
-                &remote_pattern,
-            ],
-        )
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-        remote_output
-            .lines()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty() && !s.contains("HEAD"))
-            .collect()
+        format!("refs/remotes/{}", params.remote)
     };
+    let base = params.base.unwrap_or_else(|| current.clone());
+    let details = git::branch_details(&project.path, &refs, &base)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    let branches: Vec<BranchInfo> = branch_names
+    let branches: Vec<BranchInfo> = details
         .into_iter()
-        .map(|name| {
-            let is_current = name == current;
-            BranchInfo { name, is_current }
+        .map(|d| {
+            let is_current = d.name == current;
+            BranchInfo {
+                name: d.name,
+                is_current,
+                ahead: d.ahead,
+                behind: d.behind,
+                last_commit_at: d.last_commit_at,
+                worktree: d.worktree,
+            }
         })
         .collect();
 
@@ -139,7 +164,7 @@ pub async fn init_git(Path(id): Path<String>) -> Result<Json<ProjectResponse>, S
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     if let Err(e) = init_result {
-        eprintln!("init_git failed for {}: {}", project.path, e);
+        tracing::warn!("init_git failed for {}: {}", project.path, e);
         return Err(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
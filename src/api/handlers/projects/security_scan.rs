@@ -0,0 +1,48 @@
+//! Project security scan settings handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::security_scan::{self, ScanSettings};
+
+/// GET /api/v1/projects/{id}/security-scan
+pub async fn get_security_scan_settings(
+    Path(id): Path<String>,
+) -> Result<Json<ScanSettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let settings = security_scan::load_settings(&project_key)
+        .map_err(|e| ApiError::internal(format!("Failed to load security scan settings: {}", e)))?;
+    Ok(Json(settings))
+}
+
+/// PUT /api/v1/projects/{id}/security-scan
+///
+/// Scanners with a blank command are dropped; no scanners turns the scan off.
+pub async fn update_security_scan_settings(
+    Path(id): Path<String>,
+    Json(mut body): Json<ScanSettings>,
+) -> Result<Json<ScanSettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    if body.timeout_secs == 0 {
+        return Err(ApiError::bad_request("timeout_secs must be positive"));
+    }
+    body.scanners.retain(|s| !s.command.trim().is_empty());
+    for scanner in &mut body.scanners {
+        scanner.command = scanner.command.trim().to_string();
+        scanner.name = scanner.name.trim().to_string();
+        if scanner.name.is_empty() {
+            scanner.name = scanner
+                .command
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+        }
+    }
+    security_scan::save_settings(&project_key, &body)
+        .map_err(|e| ApiError::internal(format!("Failed to save security scan settings: {}", e)))?;
+    Ok(Json(body))
+}
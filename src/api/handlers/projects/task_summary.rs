@@ -0,0 +1,31 @@
+//! Project automatic task summary handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::task_summary::{self, SummarySettings};
+
+/// GET /api/v1/projects/{id}/task-summary
+pub async fn get_task_summary_settings(
+    Path(id): Path<String>,
+) -> Result<Json<SummarySettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    Ok(Json(task_summary::load_settings(&project_key)))
+}
+
+/// PUT /api/v1/projects/{id}/task-summary
+///
+/// Stop reasons are normalized to snake_case (`EndTurn` → `end_turn`).
+pub async fn update_task_summary_settings(
+    Path(id): Path<String>,
+    Json(body): Json<SummarySettings>,
+) -> Result<Json<SummarySettings>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let settings = body.normalized();
+    task_summary::save_settings(&project_key, &settings)
+        .map_err(|e| ApiError::internal(format!("Failed to save task summary settings: {}", e)))?;
+    Ok(Json(settings))
+}
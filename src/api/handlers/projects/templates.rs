@@ -0,0 +1,38 @@
+//! Project task template handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::common;
+use crate::storage::templates;
+
+use super::types::*;
+
+/// GET /api/v1/projects/{id}/templates
+pub async fn get_task_templates(
+    Path(id): Path<String>,
+) -> Result<Json<TaskTemplatesDto>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    Ok(Json(TaskTemplatesDto {
+        templates: templates::load_templates(&project_key),
+    }))
+}
+
+/// PUT /api/v1/projects/{id}/templates
+///
+/// Replaces the whole list; an empty or duplicate name, or an invalid env
+/// var name, is a 400.
+pub async fn update_task_templates(
+    Path(id): Path<String>,
+    Json(body): Json<TaskTemplatesDto>,
+) -> Result<Json<TaskTemplatesDto>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        common::find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+
+    let list = templates::normalize_templates(body.templates)
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    templates::save_templates(&project_key, &list)
+        .map_err(|e| ApiError::internal(format!("Failed to save task templates: {}", e)))?;
+    Ok(Json(TaskTemplatesDto { templates: list }))
+}
@@ -2,6 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::session::agent_state::AgentState;
+use crate::storage::tasks::TaskAssignee;
+use crate::storage::templates::TaskTemplate;
+
 /// Project list item (for GET /projects)
 #[derive(Debug, Serialize)]
 pub struct ProjectListItem {
@@ -18,6 +22,8 @@ pub struct ProjectListItem {
     pub exists: bool,
     /// Project type: "repo" or "studio"
     pub project_type: String,
+    /// Pinned projects sort ahead of the rest
+    pub pinned: bool,
 }
 
 /// Project list response
@@ -47,6 +53,17 @@ pub struct TaskResponse {
     pub multiplexer: String,
     pub created_by: String,
     pub is_local: bool,
+    /// Inferred terminal agent state ("busy" | "waiting" | "idle"), live tmux tasks only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub agent_state: Option<AgentState>,
+    /// Who currently owns the task ("human" or an agent chat); omitted when unassigned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<TaskAssignee>,
+    /// Pinned tasks sort ahead of the rest
+    pub pinned: bool,
+    /// Non-blocking post-create hook failures (create responses only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 /// Commit response
@@ -55,6 +72,8 @@ pub struct CommitResponse {
     pub hash: String,
     pub message: String,
     pub time_ago: String,
+    /// Commit time (RFC 3339)
+    pub time: String,
 }
 
 /// Full project response (for GET /projects/{id})
@@ -74,6 +93,16 @@ pub struct ProjectResponse {
     pub exists: bool,
     /// Project type: "repo" or "studio"
     pub project_type: String,
+    /// Default target branch for new tasks (unset = current branch)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_target: Option<String>,
+    /// Commit signing override (unset = follow the global `[signing]` setting)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_signing: Option<bool>,
+    /// Whether Grove-Task / Grove-Chat trailers are added to Grove commits
+    pub commit_trailers: bool,
+    /// Whether the project is pinned
+    pub pinned: bool,
 }
 
 /// Rename project request
@@ -82,6 +111,30 @@ pub struct RenameProjectRequest {
     pub name: String,
 }
 
+/// Pin / unpin request (projects and tasks)
+#[derive(Debug, Deserialize)]
+pub struct PinRequest {
+    pub pinned: bool,
+}
+
+/// Set default target branch request (null clears it)
+#[derive(Debug, Deserialize)]
+pub struct DefaultTargetRequest {
+    pub target: Option<String>,
+}
+
+/// Per-project commit signing request (null follows the global setting)
+#[derive(Debug, Deserialize)]
+pub struct CommitSigningRequest {
+    pub enabled: Option<bool>,
+}
+
+/// Per-project Grove-Task / Grove-Chat commit trailer toggle
+#[derive(Debug, Deserialize)]
+pub struct CommitTrailersRequest {
+    pub enabled: bool,
+}
+
 /// Add project request
 #[derive(Debug, Deserialize)]
 pub struct AddProjectRequest {
@@ -122,11 +175,37 @@ pub struct ProjectStatsResponse {
     pub weekly_activity: Vec<u32>,
 }
 
+/// Project health response (`GET /projects/{id}/health`)
+#[derive(Debug, Serialize)]
+pub struct ProjectHealthResponse {
+    pub target_branch: String,
+    /// Commits the target branch is behind origin (null = no remote-tracking ref)
+    pub target_behind_origin: Option<u32>,
+    pub uncommitted_changes: usize,
+    pub stash_count: usize,
+    pub stale_worktrees: Vec<String>,
+    pub broken_links: Vec<String>,
+    /// Human-readable summary of the checks above; empty = healthy
+    pub warnings: Vec<String>,
+}
+
 /// Branch info response
 #[derive(Debug, Serialize)]
 pub struct BranchInfo {
     pub name: String,
     pub is_current: bool,
+    /// Commits ahead of the base branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ahead: Option<u32>,
+    /// Commits behind the base branch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behind: Option<u32>,
+    /// Last commit time (unix seconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_commit_at: Option<i64>,
+    /// Worktree path if the branch is checked out somewhere
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worktree: Option<String>,
 }
 
 /// Branches list response
@@ -168,6 +247,80 @@ pub struct InstructionsUpdateRequest {
     pub content: String,
 }
 
+/// Project diff filter settings (GET response / PUT body)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DiffFiltersDto {
+    /// Globs collapsed by default in review diffs
+    #[serde(default)]
+    pub collapsed_patterns: Vec<String>,
+    /// Globs that identify test files (empty = the `[risk] test_paths`
+    /// defaults). Omitted in a PUT to keep the saved patterns.
+    #[serde(default)]
+    pub test_patterns: Option<Vec<String>>,
+}
+
+/// Project task templates (GET response / PUT body)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskTemplatesDto {
+    #[serde(default)]
+    pub templates: Vec<TaskTemplate>,
+}
+
+/// Git identity override (stored) and the identity commits will actually use
+#[derive(Debug, Serialize)]
+pub struct GitIdentityResponse {
+    /// Override stored at this level (project or task)
+    pub identity: crate::storage::git_identity::GitIdentity,
+    /// Task override merged over the project override
+    pub effective: crate::storage::git_identity::GitIdentity,
+}
+
+/// Project note metadata (list item)
+#[derive(Debug, Serialize)]
+pub struct ProjectNoteDto {
+    pub slug: String,
+    pub title: String,
+    /// Attached as context to newly started chats
+    pub include_in_chat: bool,
+    pub updated_at: String,
+}
+
+/// Project notes list response
+#[derive(Debug, Serialize)]
+pub struct ProjectNotesResponse {
+    pub notes: Vec<ProjectNoteDto>,
+}
+
+/// A single project note with its content
+#[derive(Debug, Serialize)]
+pub struct ProjectNoteDetail {
+    #[serde(flatten)]
+    pub note: ProjectNoteDto,
+    pub content: String,
+}
+
+/// Create project note request
+#[derive(Debug, Deserialize)]
+pub struct CreateProjectNoteRequest {
+    pub title: String,
+    #[serde(default)]
+    pub content: String,
+    #[serde(default = "default_include_in_chat")]
+    pub include_in_chat: bool,
+}
+
+fn default_include_in_chat() -> bool {
+    true
+}
+
+/// Update project note request (omitted fields are left unchanged)
+#[derive(Debug, Deserialize)]
+pub struct UpdateProjectNoteRequest {
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub include_in_chat: Option<bool>,
+}
+
 /// Resource delete query
 #[derive(Debug, Deserialize)]
 pub struct ResourceDeleteQuery {
@@ -222,6 +375,9 @@ pub struct BranchQueryParams {
     /// Remote name: "local" (default), "origin", "upstream", etc.
     #[serde(default = "default_remote")]
     pub remote: String,
+    /// Base for ahead/behind counts (defaults to the current branch)
+    #[serde(default)]
+    pub base: Option<String>,
 }
 
 fn default_remote() -> String {
@@ -1,4 +1,4 @@
-//! Diagram rendering handlers
+//! Diagram and Markdown rendering handlers
 
 use axum::{
     http::StatusCode,
@@ -138,3 +138,34 @@ pub async fn render_d2(Json(body): Json<RenderD2Request>) -> Response {
         }
     }
 }
+
+/// Markdown sources larger than this are rejected.
+const MAX_MARKDOWN_BYTES: usize = 1024 * 1024;
+
+#[derive(Deserialize)]
+pub struct RenderMarkdownRequest {
+    pub source: String,
+}
+
+#[derive(Serialize)]
+pub struct RenderMarkdownResponse {
+    pub html: String,
+}
+
+/// POST /api/v1/render/markdown
+/// Renders Markdown to sanitized HTML with the shared server-side renderer
+/// (the same parser the TUI uses for notes), so every client shows notes
+/// and messages identically. Raw HTML in the source is escaped and only
+/// http(s)/mailto/relative URLs are kept.
+/// Returns 413 if the source exceeds 1 MiB.
+pub async fn render_markdown(
+    Json(body): Json<RenderMarkdownRequest>,
+) -> Result<Json<RenderMarkdownResponse>, StatusCode> {
+    if body.source.len() > MAX_MARKDOWN_BYTES {
+        return Err(StatusCode::PAYLOAD_TOO_LARGE);
+    }
+    let html = tokio::task::spawn_blocking(move || crate::markdown::html::render(&body.source))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(RenderMarkdownResponse { html }))
+}
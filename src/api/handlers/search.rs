@@ -0,0 +1,46 @@
+//! Global search API handler
+
+use axum::{extract::Query, http::StatusCode, Json};
+use serde::Deserialize;
+
+use crate::search::{self, HitKind, SearchQuery, SearchResults};
+
+#[derive(Debug, Deserialize)]
+pub struct SearchParams {
+    /// Whitespace-separated terms; all must match
+    #[serde(default)]
+    pub q: String,
+    /// Comma-separated kinds (`task,note,project_note,chat,comment`)
+    #[serde(default)]
+    pub kinds: Option<String>,
+    /// Restrict to one project
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// GET /search?q= — task names, notes, project notes, chat history and
+/// review comments across all projects
+pub async fn search(Query(params): Query<SearchParams>) -> Result<Json<SearchResults>, StatusCode> {
+    let mut kinds = Vec::new();
+    for kind in params.kinds.iter().flat_map(|k| k.split(',')) {
+        let kind = kind.trim();
+        if kind.is_empty() {
+            continue;
+        }
+        kinds.push(HitKind::parse(kind).ok_or(StatusCode::BAD_REQUEST)?);
+    }
+    let query = SearchQuery {
+        text: params.q,
+        kinds,
+        project_id: params.project.filter(|p| !p.is_empty()),
+        limit: params.limit.unwrap_or(search::DEFAULT_LIMIT),
+    };
+
+    // Reads every project's tasks, notes and chat history from disk
+    let results = tokio::task::spawn_blocking(move || search::search(&query))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(results))
+}
@@ -0,0 +1,273 @@
+//! Public share link API handlers
+//!
+//! Owners create links through `/api/v1`; the links themselves are served by
+//! a restricted router at `/share/{token}` that bypasses API auth and can
+//! only read the one task the token was issued for: its diff, commits and
+//! published review threads.
+
+use axum::{
+    extract::Path,
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::api::auth::FailureTracker;
+use crate::api::error::ApiError;
+use crate::git;
+use crate::storage::comments::{self, Comment};
+use crate::storage::shares::{self, ShareLink};
+use crate::storage::tasks;
+
+use super::common::find_project_by_id;
+
+type ApiResult<T> = Result<Json<T>, (StatusCode, Json<ApiError>)>;
+
+/// Standalone viewer page; fetches `/share/{token}/data` itself.
+const SHARE_PAGE: &str = include_str!("../share_page.html");
+
+/// Header carrying the password of a protected link.
+const PASSWORD_HEADER: &str = "x-share-password";
+
+/// Larger diffs are cut off in the shared view.
+const MAX_DIFF_BYTES: usize = 2 * 1024 * 1024;
+
+/// Wrong passwords in a row before a link is locked.
+const MAX_PASSWORD_FAILURES: u32 = 5;
+
+/// First lockout of a link; doubles with each further one.
+const PASSWORD_LOCKOUT_SECS: u32 = 60;
+
+/// Wrong-password lockouts, keyed by link so guesses spread over many
+/// clients or parallel requests count together.
+static PASSWORD_FAILURES: Lazy<FailureTracker> = Lazy::new(FailureTracker::default);
+
+// ============================================================================
+// Owner side
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+pub struct CreateShareRequest {
+    /// Require this password to open the link
+    pub password: Option<String>,
+    /// Expire the link after this many hours; never when absent
+    pub expires_in_hours: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareLinkResponse {
+    pub token: String,
+    /// Path of the public page, relative to the server origin
+    pub url_path: String,
+    pub has_password: bool,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub expired: bool,
+}
+
+impl From<ShareLink> for ShareLinkResponse {
+    fn from(link: ShareLink) -> Self {
+        Self {
+            url_path: format!("/share/{}", link.token),
+            has_password: link.has_password(),
+            expired: link.is_expired(),
+            token: link.token,
+            created_at: link.created_at,
+            expires_at: link.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareLinksResponse {
+    pub shares: Vec<ShareLinkResponse>,
+}
+
+fn task_project_key(id: &str, task_id: &str) -> Result<String, (StatusCode, Json<ApiError>)> {
+    let (_, project_key) =
+        find_project_by_id(id).map_err(|s| ApiError::map_status(s, "Project not found"))?;
+    let exists = tasks::get_task(&project_key, task_id)
+        .map_err(|e| ApiError::internal(e.to_string()))?
+        .is_some();
+    if !exists {
+        return Err(ApiError::not_found("Task not found"));
+    }
+    Ok(project_key)
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/shares
+pub async fn create_share(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<CreateShareRequest>,
+) -> ApiResult<ShareLinkResponse> {
+    let project_key = task_project_key(&id, &task_id)?;
+    let expires_at = req
+        .expires_in_hours
+        .filter(|h| *h > 0)
+        .map(|h| Utc::now() + chrono::Duration::hours(i64::from(h)));
+    // Hashing the password is deliberately slow
+    let link = tokio::task::spawn_blocking(move || {
+        shares::create_share(
+            &project_key,
+            &task_id,
+            req.password.as_deref().map(str::trim),
+            expires_at,
+        )
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?
+    .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(link.into()))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/shares
+pub async fn list_shares(
+    Path((id, task_id)): Path<(String, String)>,
+) -> ApiResult<ShareLinksResponse> {
+    let project_key = task_project_key(&id, &task_id)?;
+    let shares = shares::list_shares(&project_key, &task_id)
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(ShareLinksResponse {
+        shares: shares.into_iter().map(Into::into).collect(),
+    }))
+}
+
+/// DELETE /api/v1/projects/{id}/tasks/{taskId}/shares/{token}
+pub async fn revoke_share(
+    Path((id, task_id, token)): Path<(String, String, String)>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let project_key = task_project_key(&id, &task_id)?;
+    match shares::delete_share(&project_key, &task_id, &token) {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err(ApiError::not_found("Share link not found")),
+        Err(e) => Err(ApiError::internal(e.to_string())),
+    }
+}
+
+// ============================================================================
+// Public side
+// ============================================================================
+
+#[derive(Debug, Serialize)]
+pub struct SharedTaskInfo {
+    pub name: String,
+    pub branch: String,
+    pub target: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SharedCommit {
+    pub time_ago: String,
+    /// Commit time (RFC 3339)
+    pub time: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ShareData {
+    pub task: SharedTaskInfo,
+    pub commits: Vec<SharedCommit>,
+    /// Unified diff against the target's fork point
+    pub diff: String,
+    pub diff_truncated: bool,
+    pub comments: Vec<Comment>,
+}
+
+/// GET /share/{token} — viewer page
+pub async fn share_page() -> Html<&'static str> {
+    Html(SHARE_PAGE)
+}
+
+/// GET /share/{token}/data — the shared task's diff, commits and review
+pub async fn share_data(Path(token): Path<String>, headers: HeaderMap) -> ApiResult<ShareData> {
+    let not_found = || ApiError::not_found("This link is invalid or has expired");
+    let link = shares::get_share(&token)
+        .map_err(|e| ApiError::internal(e.to_string()))?
+        .filter(|l| !l.is_expired())
+        .ok_or_else(not_found)?;
+
+    if link.has_password() {
+        let Some(password) = headers
+            .get(PASSWORD_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+        else {
+            return Err(ApiError::with_status(
+                StatusCode::UNAUTHORIZED,
+                "password_required",
+            ));
+        };
+        let keys = [format!("share:{}", link.token)];
+        if let Err(retry_after) = PASSWORD_FAILURES.begin_attempt(
+            &keys,
+            MAX_PASSWORD_FAILURES,
+            PASSWORD_LOCKOUT_SECS,
+            Utc::now().timestamp(),
+        ) {
+            return Err(ApiError::with_status(
+                StatusCode::TOO_MANY_REQUESTS,
+                format!("Too many wrong passwords, try again in {}s", retry_after),
+            ));
+        }
+        // Key derivation is deliberately slow
+        let verified = {
+            let link = link.clone();
+            tokio::task::spawn_blocking(move || link.verify_password(Some(&password)))
+                .await
+                .unwrap_or(false)
+        };
+        if !verified {
+            return Err(ApiError::with_status(
+                StatusCode::UNAUTHORIZED,
+                "Wrong password",
+            ));
+        }
+        PASSWORD_FAILURES.clear(&keys);
+    }
+
+    let task = tasks::get_task(&link.project, &link.task_id)
+        .map_err(|e| ApiError::internal(e.to_string()))?
+        .ok_or_else(not_found)?;
+    let comments = comments::load_comments(&link.project, &link.task_id)
+        .map(|d| d.comments)
+        .unwrap_or_default();
+
+    // Shells out to git
+    let data = tokio::task::spawn_blocking(move || {
+        let commits = git::recent_log(&task.worktree_path, &task.target, usize::MAX)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|e| SharedCommit {
+                time_ago: e.time_ago,
+                time: e.time,
+                message: e.message,
+            })
+            .collect();
+        let mut diff = git::unified_diff(&task.worktree_path, &task.target).unwrap_or_default();
+        let diff_truncated = diff.len() > MAX_DIFF_BYTES;
+        if diff_truncated {
+            let mut end = MAX_DIFF_BYTES;
+            while !diff.is_char_boundary(end) {
+                end -= 1;
+            }
+            diff.truncate(end);
+        }
+        ShareData {
+            task: SharedTaskInfo {
+                name: task.name,
+                branch: task.branch,
+                target: task.target,
+            },
+            commits,
+            diff,
+            diff_truncated,
+            comments,
+        }
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(data))
+}
@@ -5,6 +5,8 @@ use chrono::Utc;
 use serde::Serialize;
 use std::collections::HashMap;
 
+use crate::operations::time_tracking;
+use crate::storage::{tasks, token_usage};
 use crate::watcher;
 
 use super::common;
@@ -42,8 +44,59 @@ pub struct TaskStatsResponse {
     pub file_edits: Vec<FileEditEntry>,
     /// Hourly activity (last 24 hours)
     pub hourly_activity: Vec<ActivityEntry>,
+    /// Time spent, from edit bursts and session attaches
+    pub time_spent: time_tracking::TaskTime,
 }
 
+/// Agent usage of one chat
+#[derive(Debug, Serialize)]
+pub struct ChatUsageResponse {
+    pub chat_id: String,
+    /// None when the chat never recorded a turn
+    pub agent: Option<String>,
+    pub model: Option<String>,
+    pub totals: token_usage::UsageTotals,
+    /// Latest turns, newest first
+    pub turns: Vec<token_usage::TurnUsageRow>,
+}
+
+/// Per-chat row of the task usage dashboard
+#[derive(Debug, Serialize)]
+pub struct ChatUsageEntry {
+    pub chat_id: String,
+    /// Chat title, None when the chat was deleted
+    pub title: Option<String>,
+    pub agent: String,
+    pub model: Option<String>,
+    pub totals: token_usage::UsageTotals,
+}
+
+/// Cumulative agent usage of a task
+#[derive(Debug, Serialize)]
+pub struct TaskUsageResponse {
+    pub totals: token_usage::UsageTotals,
+    pub chats: Vec<ChatUsageEntry>,
+}
+
+/// Per-task row of the project usage dashboard
+#[derive(Debug, Serialize)]
+pub struct TaskUsageEntry {
+    pub task_id: String,
+    /// Task name, None when the task was deleted
+    pub task_name: Option<String>,
+    pub totals: token_usage::UsageTotals,
+}
+
+/// Cumulative agent usage of a project
+#[derive(Debug, Serialize)]
+pub struct ProjectUsageResponse {
+    pub totals: token_usage::UsageTotals,
+    pub tasks: Vec<TaskUsageEntry>,
+}
+
+/// Turns returned by the chat usage endpoint
+const CHAT_USAGE_TURNS: usize = 200;
+
 // ============================================================================
 // API Handlers
 // ============================================================================
@@ -54,6 +107,7 @@ pub async fn get_task_stats(
     Path((id, task_id)): Path<(String, String)>,
 ) -> Result<Json<TaskStatsResponse>, StatusCode> {
     let (_project, project_key) = common::find_project_by_id(&id)?;
+    let time_spent = time_tracking::task_time(&project_key, &task_id);
 
     // Load edit history
     let events = watcher::load_edit_history(&project_key, &task_id)
@@ -66,6 +120,7 @@ pub async fn get_task_stats(
             last_activity: None,
             file_edits: Vec::new(),
             hourly_activity: Vec::new(),
+            time_spent,
         }));
     }
 
@@ -142,5 +197,113 @@ pub async fn get_task_stats(
         last_activity: Some(last_activity.to_rfc3339()),
         file_edits,
         hourly_activity,
+        time_spent,
+    }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/usage
+/// Token usage and cost reported by the agent of one chat
+pub async fn get_chat_usage(
+    Path((id, task_id, chat_id)): Path<(String, String, String)>,
+) -> Result<Json<ChatUsageResponse>, StatusCode> {
+    let (_project, project_key) = common::find_project_by_id(&id)?;
+
+    let chat = token_usage::usage_by_chat(&project_key, Some(&task_id))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .into_iter()
+        .find(|c| c.chat_id == chat_id);
+    let turns = token_usage::chat_turns(&project_key, &task_id, &chat_id, CHAT_USAGE_TURNS)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(match chat {
+        Some(chat) => ChatUsageResponse {
+            chat_id,
+            agent: Some(chat.agent),
+            model: chat.model,
+            totals: chat.totals,
+            turns,
+        },
+        None => ChatUsageResponse {
+            chat_id,
+            agent: None,
+            model: None,
+            totals: token_usage::UsageTotals::default(),
+            turns,
+        },
+    }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/usage
+/// Cumulative agent usage of a task, broken down by chat
+pub async fn get_task_usage(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<TaskUsageResponse>, StatusCode> {
+    let (_project, project_key) = common::find_project_by_id(&id)?;
+
+    let chats = token_usage::usage_by_chat(&project_key, Some(&task_id))
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let titles: HashMap<String, String> = tasks::load_chat_sessions(&project_key, &task_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| (c.id, c.title))
+        .collect();
+
+    let totals = token_usage::grand_total(&chats);
+    let mut chats: Vec<ChatUsageEntry> = chats
+        .into_iter()
+        .map(|c| ChatUsageEntry {
+            title: titles.get(&c.chat_id).cloned(),
+            chat_id: c.chat_id,
+            agent: c.agent,
+            model: c.model,
+            totals: c.totals,
+        })
+        .collect();
+    chats.sort_by_key(|c| std::cmp::Reverse(c.totals.total_tokens));
+
+    Ok(Json(TaskUsageResponse { totals, chats }))
+}
+
+/// GET /api/v1/projects/{id}/usage
+/// Cumulative agent usage of a project, broken down by task
+pub async fn get_project_usage(
+    Path(id): Path<String>,
+) -> Result<Json<ProjectUsageResponse>, StatusCode> {
+    let (_project, project_key) = common::find_project_by_id(&id)?;
+
+    let chats = token_usage::usage_by_chat(&project_key, None)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let names: HashMap<String, String> = tasks::load_tasks(&project_key)
+        .unwrap_or_default()
+        .into_iter()
+        .chain(tasks::load_archived_tasks(&project_key).unwrap_or_default())
+        .map(|t| (t.id, t.name))
+        .collect();
+
+    let tasks = token_usage::totals_by_task(&chats)
+        .into_iter()
+        .map(|(task_id, totals)| TaskUsageEntry {
+            task_name: names.get(&task_id).cloned(),
+            task_id,
+            totals,
+        })
+        .collect();
+
+    Ok(Json(ProjectUsageResponse {
+        totals: token_usage::grand_total(&chats),
+        tasks,
     }))
 }
+
+/// GET /api/v1/projects/{id}/time
+/// Daily and weekly time spent per task; refreshes the stored rollup
+pub async fn get_project_time(
+    Path(id): Path<String>,
+) -> Result<Json<time_tracking::TimeRollup>, StatusCode> {
+    let (_project, project_key) = common::find_project_by_id(&id)?;
+    let rollup = tokio::task::spawn_blocking(move || time_tracking::refresh_rollup(&project_key))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(rollup))
+}
@@ -7,9 +7,10 @@ use axum::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::error::GroveError;
 use crate::model::loader;
 use crate::storage::{tasks, workspace};
-use crate::symbols::{self, SymbolDef, SymbolKind};
+use crate::symbols::{self, Language, SymbolDef, SymbolKind};
 
 use super::common;
 
@@ -35,6 +36,16 @@ pub struct LookupResponse {
     pub candidates: Vec<CandidateResponse>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct OutlineResponse {
+    pub path: String,
+    /// Language of the file, or `None` when no grammar handles it.
+    pub language: Option<&'static str>,
+    /// Declarations in source order. Methods and fields carry their
+    /// enclosing type in `container`.
+    pub symbols: Vec<CandidateResponse>,
+}
+
 // ============================================================================
 // Request DTOs
 // ============================================================================
@@ -53,6 +64,12 @@ pub struct LookupParams {
     pub from_line: Option<u32>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct OutlineParams {
+    /// File path relative to the worktree, forward slashes.
+    pub path: String,
+}
+
 // ============================================================================
 // Handlers
 // ============================================================================
@@ -77,6 +94,43 @@ pub async fn lookup_symbol(
     }))
 }
 
+/// GET /api/v1/projects/{id}/tasks/{taskId}/symbols/outline?path=...
+///
+/// Parses the file on demand (independent of the index build), so the
+/// outline is always current. Files in unsupported languages return an
+/// empty list with `language: null`.
+pub async fn get_outline(
+    Path((id, task_id)): Path<(String, String)>,
+    Query(params): Query<OutlineParams>,
+) -> Result<Json<OutlineResponse>, StatusCode> {
+    let Some((_, worktree)) = resolve_active_worktree(&id, &task_id).await? else {
+        return Err(StatusCode::NOT_FOUND);
+    };
+
+    let path = params.path.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        symbols::outline(std::path::Path::new(&worktree), &path)
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let defs = result.map_err(|e| match e {
+        GroveError::NotFound(_) => StatusCode::NOT_FOUND,
+        GroveError::InvalidData(_) => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    })?;
+
+    let language = std::path::Path::new(&params.path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(Language::from_extension)
+        .map(Language::as_str);
+    Ok(Json(OutlineResponse {
+        path: params.path,
+        language,
+        symbols: defs.into_iter().map(into_candidate).collect(),
+    }))
+}
+
 /// POST /api/v1/projects/{id}/tasks/{taskId}/symbols/reindex
 ///
 /// Force a from-scratch rebuild. Drops cached rows so the build can't
@@ -186,7 +240,6 @@ fn rank_candidates(hits: &mut [SymbolDef], from_file: Option<&str>, from_line: O
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::symbols::Language;
 
     fn sym(name: &str, file: &str, line: u32) -> SymbolDef {
         SymbolDef {
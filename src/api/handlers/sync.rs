@@ -0,0 +1,25 @@
+//! Workspace sync API handlers
+
+use axum::{http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::error::GroveError;
+use crate::operations::sync::{self, SyncStatus};
+
+/// GET /sync/status — outcome of the last sync run by this server
+pub async fn get_status() -> Json<SyncStatus> {
+    Json(sync::status())
+}
+
+/// POST /sync/run — sync with the configured remote now
+pub async fn run_sync() -> Result<Json<SyncStatus>, (StatusCode, Json<ApiError>)> {
+    // Shells out to git, including network fetch/push
+    let result = tokio::task::spawn_blocking(sync::sync_now)
+        .await
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+    match result {
+        Ok(status) => Ok(Json(status)),
+        Err(GroveError::InvalidData(msg)) => Err(ApiError::bad_request(msg)),
+        Err(e) => Err(ApiError::internal(e.to_string())),
+    }
+}
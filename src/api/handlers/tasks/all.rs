@@ -0,0 +1,141 @@
+//! Cross-project task list handler
+
+use axum::{extract::Query, http::StatusCode, Json};
+
+use crate::model::loader;
+use crate::session::agent_state::AgentState;
+use crate::storage::{tasks, workspace};
+
+use super::super::common;
+use super::super::projects::{storage_task_to_response, TaskResponse};
+use super::types::*;
+
+/// GET /api/v1/tasks?all=true
+///
+/// Flat list of active tasks across every registered project (Local Tasks
+/// included), pinned first, then most recently updated. Optional filters:
+/// `status=live,idle`, `agent_busy=true`, `has_conflicts=true`.
+pub async fn list_all_tasks(
+    Query(query): Query<AllTasksQuery>,
+) -> Result<Json<AllTasksResponse>, StatusCode> {
+    if !query.all {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let tasks = tokio::task::spawn_blocking(move || {
+        let mut result: Vec<ProjectTaskResponse> = loader::load_all_worktrees()
+            .iter()
+            .map(|t| ProjectTaskResponse {
+                project_id: workspace::project_hash(&t.project_path),
+                project_name: t.project_name.clone(),
+                project_type: workspace::ProjectType::Repo.as_str().to_string(),
+                task: common::worktree_to_response(&t.worktree),
+            })
+            .collect();
+
+        // Studio tasks have no worktree; take them straight from storage
+        let studios = workspace::load_projects()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|p| p.project_type == workspace::ProjectType::Studio);
+        for project in studios {
+            let project_id = workspace::project_hash(&project.path);
+            let stored = tasks::load_tasks(&project_id).unwrap_or_default();
+            result.extend(stored.iter().map(|task| ProjectTaskResponse {
+                project_id: project_id.clone(),
+                project_name: project.name.clone(),
+                project_type: project.project_type.as_str().to_string(),
+                task: storage_task_to_response(task),
+            }));
+        }
+
+        result.retain(|t| matches_query(&query, &t.task));
+        result.sort_by(|a, b| {
+            b.task
+                .pinned
+                .cmp(&a.task.pinned)
+                .then_with(|| b.task.updated_at.cmp(&a.task.updated_at))
+        });
+        result
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(AllTasksResponse { tasks }))
+}
+
+fn matches_query(query: &AllTasksQuery, task: &TaskResponse) -> bool {
+    if let Some(statuses) = query.status.as_deref().filter(|s| !s.is_empty()) {
+        if !statuses.split(',').any(|s| s.trim() == task.status) {
+            return false;
+        }
+    }
+    if query.agent_busy && task.agent_state != Some(AgentState::Busy) {
+        return false;
+    }
+    if query.has_conflicts && task.status != "conflict" {
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(status: &str, agent_state: Option<AgentState>) -> TaskResponse {
+        TaskResponse {
+            id: "t".to_string(),
+            name: "t".to_string(),
+            branch: "grove/t".to_string(),
+            target: "main".to_string(),
+            status: status.to_string(),
+            additions: 0,
+            deletions: 0,
+            files_changed: 0,
+            initial_commit: None,
+            commits: Vec::new(),
+            created_at: String::new(),
+            updated_at: String::new(),
+            path: String::new(),
+            multiplexer: "tmux".to_string(),
+            created_by: "user".to_string(),
+            is_local: false,
+            agent_state,
+            assignee: None,
+            pinned: false,
+            warning: None,
+        }
+    }
+
+    fn query(status: Option<&str>, agent_busy: bool, has_conflicts: bool) -> AllTasksQuery {
+        AllTasksQuery {
+            all: true,
+            status: status.map(str::to_string),
+            agent_busy,
+            has_conflicts,
+        }
+    }
+
+    #[test]
+    fn test_matches_query_filters() {
+        let live_busy = task("live", Some(AgentState::Busy));
+        let conflict = task("conflict", None);
+
+        assert!(matches_query(&query(None, false, false), &live_busy));
+        assert!(matches_query(
+            &query(Some("idle,live"), false, false),
+            &live_busy
+        ));
+        assert!(!matches_query(
+            &query(Some("idle"), false, false),
+            &live_busy
+        ));
+
+        assert!(matches_query(&query(None, true, false), &live_busy));
+        assert!(!matches_query(&query(None, true, false), &conflict));
+
+        assert!(matches_query(&query(None, false, true), &conflict));
+        assert!(!matches_query(&query(None, false, true), &live_busy));
+    }
+}
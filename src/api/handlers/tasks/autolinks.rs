@@ -0,0 +1,102 @@
+//! Task AutoLink management handlers
+
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Json,
+};
+
+use crate::api::error::ApiError;
+use crate::error::GroveError;
+use crate::operations::autolink as ops;
+
+use super::super::common::find_project_by_id;
+use super::types::*;
+
+type ApiResult<T> = Result<Json<T>, (StatusCode, Json<ApiError>)>;
+
+fn map_err(e: GroveError) -> (StatusCode, Json<ApiError>) {
+    match e {
+        GroveError::NotFound(msg) => ApiError::not_found(msg),
+        GroveError::InvalidData(msg) | GroveError::Config(msg) => ApiError::bad_request(msg),
+        e => ApiError::internal(e.to_string()),
+    }
+}
+
+fn project(id: &str) -> Result<(String, String), (StatusCode, Json<ApiError>)> {
+    let (project, project_key) =
+        find_project_by_id(id).map_err(|s| ApiError::map_status(s, "Project not found"))?;
+    Ok((project.path, project_key))
+}
+
+fn links(
+    path: &str,
+    key: &str,
+    task_id: &str,
+) -> Result<Vec<ops::TaskLink>, (StatusCode, Json<ApiError>)> {
+    ops::list_task_links(path, key, task_id).map_err(map_err)
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/links
+pub async fn list_task_links(
+    Path((id, task_id)): Path<(String, String)>,
+) -> ApiResult<TaskLinksResponse> {
+    let (path, key) = project(&id)?;
+    Ok(Json(TaskLinksResponse {
+        links: links(&path, &key, &task_id)?,
+    }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/links/resync
+///
+/// Re-applies the current AutoLink patterns to the task worktree.
+pub async fn resync_task_links(
+    Path((id, task_id)): Path<(String, String)>,
+) -> ApiResult<TaskLinksChangeResponse> {
+    let (path, key) = project(&id)?;
+    let changed = ops::resync_task_links(&path, &key, &task_id).map_err(map_err)?;
+    Ok(Json(TaskLinksChangeResponse {
+        changed,
+        links: links(&path, &key, &task_id)?,
+    }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/links/clean
+///
+/// Removes broken links and forgets links whose path no longer exists.
+pub async fn clean_task_links(
+    Path((id, task_id)): Path<(String, String)>,
+) -> ApiResult<TaskLinksChangeResponse> {
+    let (path, key) = project(&id)?;
+    let changed = ops::clean_broken_links(&path, &key, &task_id).map_err(map_err)?;
+    Ok(Json(TaskLinksChangeResponse {
+        changed,
+        links: links(&path, &key, &task_id)?,
+    }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/links/refresh
+///
+/// Re-creates copies and hardlinked trees that went stale.
+pub async fn refresh_task_links(
+    Path((id, task_id)): Path<(String, String)>,
+) -> ApiResult<TaskLinksChangeResponse> {
+    let (path, key) = project(&id)?;
+    let changed = ops::refresh_stale_copies(&path, &key, &task_id).map_err(map_err)?;
+    Ok(Json(TaskLinksChangeResponse {
+        changed,
+        links: links(&path, &key, &task_id)?,
+    }))
+}
+
+/// DELETE /api/v1/projects/{id}/tasks/{taskId}/links?path=<relative path>
+pub async fn remove_task_link(
+    Path((id, task_id)): Path<(String, String)>,
+    Query(query): Query<TaskLinkQuery>,
+) -> ApiResult<TaskLinksResponse> {
+    let (path, key) = project(&id)?;
+    ops::remove_task_link(&path, &key, &task_id, &query.path).map_err(map_err)?;
+    Ok(Json(TaskLinksResponse {
+        links: links(&path, &key, &task_id)?,
+    }))
+}
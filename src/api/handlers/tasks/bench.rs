@@ -0,0 +1,42 @@
+//! Task benchmark comparison handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::api::error::ApiError;
+use crate::error::GroveError;
+use crate::operations::bench;
+use crate::storage::bench::BenchRun;
+
+use super::super::common::find_project_by_id;
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/bench
+///
+/// Latest target-vs-HEAD comparison (`null` when never run). Poll while
+/// `status` is `running`.
+pub async fn get_task_bench(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<Option<BenchRun>>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    Ok(Json(bench::latest(&project_key, &task_id)))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/bench
+///
+/// Start a comparison in the background: the project's bench command runs
+/// on the target commit and on the task HEAD in temporary worktrees.
+/// Uncommitted changes are not benchmarked.
+pub async fn run_task_bench(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<BenchRun>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let run = tokio::task::spawn_blocking(move || bench::start(&project_key, &task_id))
+        .await
+        .map_err(|e| ApiError::internal(e.to_string()))?
+        .map_err(|e| match e {
+            GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+            e => ApiError::internal(format!("Failed to start benchmark: {}", e)),
+        })?;
+    Ok(Json(run))
+}
@@ -0,0 +1,92 @@
+//! Import Claude Code / Codex session logs as task chats
+
+use axum::{extract::Path, http::StatusCode, Json};
+use serde::{Deserialize, Serialize};
+
+use crate::api::error::ApiError;
+use crate::api::handlers::walkie_talkie::{broadcast_radio_event, RadioEvent};
+use crate::error::GroveError;
+use crate::operations::chat_import::{self, NativeSession, SessionSource};
+
+use super::super::common::find_project_by_id;
+
+#[derive(Debug, Serialize)]
+pub struct NativeSessionsResponse {
+    pub sessions: Vec<NativeSession>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportChatRequest {
+    pub source: SessionSource,
+    /// Session log path as returned by the list endpoint
+    pub path: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportChatResponse {
+    pub chat_id: String,
+    pub title: String,
+    pub agent: String,
+    /// Number of history events written
+    pub events: usize,
+    /// Whether the chat resumes the original agent session
+    pub resumable: bool,
+}
+
+fn to_api_error(e: GroveError) -> (StatusCode, Json<ApiError>) {
+    match e {
+        GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+        GroveError::NotFound(msg) => ApiError::not_found(msg),
+        e => ApiError::internal(format!("Chat import failed: {}", e)),
+    }
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/chat-import
+///
+/// Claude Code and Codex sessions that ran in the task worktree or the
+/// project root, newest first.
+pub async fn list_native_sessions(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<NativeSessionsResponse>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+
+    let sessions =
+        tokio::task::spawn_blocking(move || chat_import::list_sessions(&project_key, &task_id))
+            .await
+            .map_err(|e| ApiError::internal(e.to_string()))?
+            .map_err(to_api_error)?;
+    Ok(Json(NativeSessionsResponse { sessions }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/chat-import
+///
+/// Convert a native session log into the history of a new chat.
+pub async fn import_native_session(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<ImportChatRequest>,
+) -> Result<Json<ImportChatResponse>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+
+    let tid = task_id.clone();
+    let imported = tokio::task::spawn_blocking(move || {
+        chat_import::import(&project_key, &tid, req.source, &req.path)
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?
+    .map_err(to_api_error)?;
+
+    broadcast_radio_event(RadioEvent::ChatListChanged {
+        project_id: id,
+        task_id,
+    });
+
+    Ok(Json(ImportChatResponse {
+        resumable: imported.chat.acp_session_id.is_some(),
+        chat_id: imported.chat.id,
+        title: imported.chat.title,
+        agent: imported.chat.agent,
+        events: imported.events,
+    }))
+}
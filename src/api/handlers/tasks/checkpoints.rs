@@ -0,0 +1,105 @@
+//! Task auto-commit checkpoint handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::operations::checkpoints as ops;
+use crate::storage::{checkpoints, tasks};
+
+use super::super::common::find_project_by_id;
+use super::types::*;
+
+fn load_response(project_key: &str, task: &tasks::Task) -> CheckpointsResponse {
+    let settings = checkpoints::load_settings(project_key, &task.id);
+    CheckpointsResponse {
+        enabled: settings.enabled,
+        interval_mins: settings.interval_mins,
+        checkpoints: ops::list_checkpoints(&task.worktree_path, &task.target).unwrap_or_default(),
+    }
+}
+
+fn find_task(project_key: &str, task_id: &str) -> Result<tasks::Task, StatusCode> {
+    tasks::get_task(project_key, task_id)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/checkpoints
+pub async fn get_checkpoints(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<CheckpointsResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+    let task = find_task(&project_key, &task_id)?;
+    Ok(Json(load_response(&project_key, &task)))
+}
+
+/// PUT /api/v1/projects/{id}/tasks/{taskId}/checkpoints
+pub async fn update_checkpoints(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<UpdateCheckpointsRequest>,
+) -> Result<Json<CheckpointsResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+    let task = find_task(&project_key, &task_id)?;
+    if task.branch.is_empty() || task.is_local {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let mut settings = checkpoints::load_settings(&project_key, &task_id);
+    if let Some(enabled) = req.enabled {
+        settings.enabled = enabled;
+    }
+    if let Some(interval_mins) = req.interval_mins {
+        settings.interval_mins = interval_mins.max(1);
+    }
+    checkpoints::save_settings(&project_key, &task_id, &settings)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(load_response(&project_key, &task)))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/checkpoints/squash
+pub async fn squash_checkpoints(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<SquashCheckpointsRequest>,
+) -> Result<Json<GitOperationResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+    let task = find_task(&project_key, &task_id)?;
+
+    let options = crate::git::CommitOptions::resolve(&project_key, Some(&task.id));
+    Ok(Json(
+        match ops::squash_checkpoints(&task.worktree_path, &task.target, &req.message, &options) {
+            Ok(count) => GitOperationResponse {
+                success: true,
+                message: format!("Squashed {} checkpoint(s)", count),
+                warning: None,
+            },
+            Err(e) => GitOperationResponse {
+                success: false,
+                message: e.to_string(),
+                warning: None,
+            },
+        },
+    ))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/checkpoints/drop
+pub async fn drop_checkpoints(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<GitOperationResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+    let task = find_task(&project_key, &task_id)?;
+
+    Ok(Json(
+        match ops::drop_checkpoints(&task.worktree_path, &task.target) {
+            Ok(count) => GitOperationResponse {
+                success: true,
+                message: format!("Dropped {} checkpoint(s); changes are kept staged", count),
+                warning: None,
+            },
+            Err(e) => GitOperationResponse {
+                success: false,
+                message: e.to_string(),
+                warning: None,
+            },
+        },
+    ))
+}
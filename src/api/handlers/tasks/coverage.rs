@@ -0,0 +1,90 @@
+//! Task coverage report handlers
+
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+
+use crate::api::error::ApiError;
+use crate::diff::coverage::{self, CoverageSummary};
+use crate::error::GroveError;
+use crate::storage::coverage::{self as coverage_store, CoverageFormat, CoverageReport};
+
+use super::git_ops::load_diff_task;
+
+#[derive(Debug, Deserialize)]
+pub struct UploadCoverageQuery {
+    /// `lcov` or `cobertura`; detected from the content when omitted
+    pub format: Option<CoverageFormat>,
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/coverage
+///
+/// Summary of the latest uploaded report against the task's current
+/// changes (`null` when none was uploaded).
+pub async fn get_task_coverage(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<Option<CoverageSummary>>, (StatusCode, Json<ApiError>)> {
+    let (project_key, task) = load_diff_task(&id, &task_id, [None, None])?;
+    let Some(report) = coverage_store::load_report(&project_key, &task.id) else {
+        return Ok(Json(None));
+    };
+    let summary = tokio::task::spawn_blocking(move || {
+        let changed = coverage::changed_lines(&task.worktree_path, &task.target);
+        coverage::summarize(&report, &changed)
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(Some(summary)))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/coverage?format=
+///
+/// Body is the raw lcov / Cobertura XML report. Paths are resolved against
+/// the task worktree; the report replaces the previous one, whose totals
+/// are kept for the coverage delta.
+pub async fn upload_task_coverage(
+    Path((id, task_id)): Path<(String, String)>,
+    Query(query): Query<UploadCoverageQuery>,
+    body: String,
+) -> Result<Json<CoverageSummary>, (StatusCode, Json<ApiError>)> {
+    let (project_key, task) = load_diff_task(&id, &task_id, [None, None])?;
+    let format = query
+        .format
+        .or_else(|| coverage::detect_format(&body))
+        .ok_or_else(|| {
+            ApiError::bad_request("Unrecognized coverage format (expected lcov or Cobertura XML)")
+        })?;
+
+    let summary = tokio::task::spawn_blocking(move || {
+        let files = coverage::parse(&body, format, &task.worktree_path)?;
+        let report = CoverageReport {
+            format,
+            uploaded_at: chrono::Utc::now().to_rfc3339(),
+            files,
+            previous: coverage_store::load_report(&project_key, &task.id).map(|r| r.totals()),
+        };
+        coverage_store::save_report(&project_key, &task.id, &report)?;
+        let changed = coverage::changed_lines(&task.worktree_path, &task.target);
+        Ok::<_, GroveError>(coverage::summarize(&report, &changed))
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?
+    .map_err(|e| match e {
+        GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+        e => ApiError::internal(format!("Failed to save coverage: {}", e)),
+    })?;
+    Ok(Json(summary))
+}
+
+/// DELETE /api/v1/projects/{id}/tasks/{taskId}/coverage
+pub async fn delete_task_coverage(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let (project_key, task) = load_diff_task(&id, &task_id, [None, None])?;
+    coverage_store::delete_report(&project_key, &task.id)
+        .map_err(|e| ApiError::internal(format!("Failed to delete coverage: {}", e)))?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,67 @@
+//! Agent-to-agent cross review handler
+
+use axum::{extract::Path, http::StatusCode, Json};
+use serde::{Deserialize, Serialize};
+
+use crate::api::error::ApiError;
+use crate::error::GroveError;
+use crate::operations::cross_review;
+
+use super::super::common::find_project_by_id;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CrossReviewRequest {
+    /// Reviewer agent id; defaults to the first installed agent that has no
+    /// chat on the task yet
+    #[serde(default)]
+    pub agent: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CrossReviewResponse {
+    pub chat_id: String,
+    pub title: String,
+    pub agent: String,
+    /// Agents of the chats that worked on the task
+    pub author_agents: Vec<String>,
+}
+
+fn to_api_error(e: GroveError) -> (StatusCode, Json<ApiError>) {
+    match e {
+        GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+        GroveError::NotFound(msg) => ApiError::not_found(msg),
+        e => ApiError::internal(format!("Cross review failed: {}", e)),
+    }
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/cross-review
+///
+/// Start a reviewer chat with a different agent, prompted with the task's
+/// diff and notes; its findings land as review comments attributed to it.
+pub async fn start_cross_review(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<CrossReviewRequest>,
+) -> Result<Json<CrossReviewResponse>, (StatusCode, Json<ApiError>)> {
+    let (project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+
+    let (key, tid) = (project_key.clone(), task_id.clone());
+    let plan = tokio::task::spawn_blocking(move || {
+        cross_review::prepare(&key, &tid, req.agent.as_deref())
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?
+    .map_err(to_api_error)?;
+    let author_agents = plan.author_agents.clone();
+
+    let started = cross_review::launch(&project_key, &project.path, &project.name, &task_id, plan)
+        .await
+        .map_err(to_api_error)?;
+
+    Ok(Json(CrossReviewResponse {
+        chat_id: started.chat_id,
+        title: started.title,
+        agent: started.agent,
+        author_agents,
+    }))
+}
@@ -9,14 +9,16 @@ use axum::{
 use std::fs;
 
 use crate::api::error::ApiError;
+use crate::error::GroveError;
 use crate::git;
 use crate::hooks;
-use crate::model::loader;
+use crate::model::{loader, views};
 use crate::session::{self, SessionType};
+use crate::storage::task_views::{self, TaskSort};
 use crate::storage::{self, notes, tasks, workspace};
 
 use super::super::common;
-use super::super::projects::{storage_task_to_response, TaskResponse};
+use super::super::projects::{storage_task_to_response, OpenResponse, PinRequest, TaskResponse};
 use super::types::*;
 
 /// Get git user.name for a task's worktree (used for display purposes in frontend).
@@ -49,26 +51,56 @@ pub async fn list_tasks(
         .await
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-        tasks.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        common::sort_task_responses(&mut tasks);
         return Ok(Json(TaskListResponse { tasks }));
     }
 
+    // Saved view / explicit sort: filter and order the worktrees before conversion.
+    let view = match query.view.as_deref() {
+        Some(name) => Some(
+            task_views::load_views(&project_key)
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+                .into_iter()
+                .find(|v| v.name == name)
+                .ok_or(StatusCode::NOT_FOUND)?,
+        ),
+        None => None,
+    };
+    let sort = match query.sort.as_deref() {
+        Some(s) => Some(s.parse::<TaskSort>().map_err(|_| StatusCode::BAD_REQUEST)?),
+        None => view.as_ref().map(|v| v.sort),
+    };
+
     let project_path = project.path.clone();
     let filter_owned = filter.to_string();
-    let mut tasks: Vec<TaskResponse> = tokio::task::spawn_blocking(move || {
-        if filter_owned == "archived" {
-            let archived = loader::load_archived_worktrees(&project_path);
-            archived.iter().map(common::worktree_to_response).collect()
+    let tasks: Vec<TaskResponse> = tokio::task::spawn_blocking(move || {
+        let mut worktrees = if filter_owned == "archived" {
+            loader::load_archived_worktrees(&project_path)
         } else {
-            let active = loader::load_worktrees(&project_path);
-            active.iter().map(common::worktree_to_response).collect()
+            loader::load_worktrees(&project_path)
+        };
+        if let Some(view) = &view {
+            worktrees.retain(|wt| views::matches(&view.filter, wt));
+        }
+        match sort {
+            Some(sort) => {
+                if sort == TaskSort::DiffSize {
+                    views::load_diff_sizes(&mut worktrees);
+                }
+                views::sort_worktrees(&mut worktrees, sort);
+                worktrees.iter().map(common::worktree_to_response).collect()
+            }
+            None => {
+                let mut tasks: Vec<TaskResponse> =
+                    worktrees.iter().map(common::worktree_to_response).collect();
+                common::sort_task_responses(&mut tasks);
+                tasks
+            }
         }
     })
     .await
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    tasks.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
-
     Ok(Json(TaskListResponse { tasks }))
 }
 
@@ -180,6 +212,12 @@ pub async fn create_task(
         )
     })?;
 
+    let scope = crate::storage::task_scope::normalize_scope(req.scope.as_deref().unwrap_or(""))
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    let template =
+        crate::operations::tasks::resolve_template(&project_key, req.template.as_deref())
+            .map_err(|e| ApiError::not_found(e.to_string()))?;
+
     let full_config = storage::config::load_config();
     let is_studio = project.project_type == workspace::ProjectType::Studio;
 
@@ -192,25 +230,34 @@ pub async fn create_task(
             "user",
         )
     } else {
-        let target = req.target.unwrap_or_else(|| {
-            git::current_branch(&project.path).unwrap_or_else(|_| "main".to_string())
-        });
-        let autolink_patterns = &full_config.auto_link.patterns;
+        let target = req
+            .target
+            .clone()
+            .filter(|t| !t.is_empty())
+            .or_else(|| template.as_ref().and_then(|t| t.target.clone()))
+            .unwrap_or_else(|| crate::operations::projects::default_target_branch(&project.path));
+        let auto_link = match &template {
+            Some(t) => t.auto_link(&full_config.auto_link),
+            None => full_config.auto_link.clone(),
+        };
 
-        crate::operations::tasks::create_task(
+        crate::operations::tasks::create_sparse_task(
             &project.path,
             &project_key,
             req.name.clone(),
             target,
             &full_config.default_session_type(),
-            autolink_patterns,
+            &auto_link,
             "user",
+            &req.sparse_paths,
         )
     }
     .map_err(|e| {
         let msg = e.to_string();
         if msg.contains("already exists") {
             (StatusCode::CONFLICT, Json(ApiError { error: msg }))
+        } else if matches!(e, crate::error::GroveError::InvalidData(_)) {
+            ApiError::bad_request(msg)
         } else {
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -221,10 +268,32 @@ pub async fn create_task(
 
     if let Some(ref notes_content) = req.notes {
         if !notes_content.is_empty() {
-            let _ = notes::save_notes(&project_key, &result.task.id, notes_content);
+            let _ = notes::save_notes(
+                &project_key,
+                &result.task.id,
+                notes_content,
+                notes::NoteSource::Web,
+            );
+        }
+    }
+
+    if let Some(template) = &template {
+        let has_notes = req.notes.as_deref().is_some_and(|n| !n.is_empty());
+        if let Err(e) = crate::operations::tasks::apply_template(
+            &project_key,
+            &result.task,
+            template,
+            !has_notes,
+            notes::NoteSource::Web,
+        ) {
+            tracing::warn!("Failed to apply task template: {}", e);
         }
     }
 
+    if let Some(scope) = scope.filter(|_| !is_studio) {
+        let _ = crate::storage::task_scope::save_scope(&project_key, &result.task.id, Some(&scope));
+    }
+
     let _ = crate::storage::taskgroups::ensure_system_groups();
     use crate::api::handlers::walkie_talkie::{broadcast_radio_event, RadioEvent};
     broadcast_radio_event(RadioEvent::GroupChanged);
@@ -246,9 +315,85 @@ pub async fn create_task(
         multiplexer: result.task.multiplexer.clone(),
         created_by: result.task.created_by.clone(),
         is_local: false,
+        agent_state: None,
+        assignee: None,
+        pinned: false,
+        warning: result.warning,
     }))
 }
 
+/// GET /api/v1/projects/{id}/worktrees/unmanaged
+pub async fn list_unmanaged_worktrees(
+    Path(id): Path<String>,
+) -> Result<Json<UnmanagedWorktreesResponse>, (StatusCode, Json<ApiError>)> {
+    let (project, project_key) = common::find_project_by_id(&id)
+        .map_err(|s| ApiError::map_status(s, "Project not found"))?;
+    if !project.is_git_repo {
+        return Ok(Json(UnmanagedWorktreesResponse {
+            worktrees: Vec::new(),
+        }));
+    }
+
+    let found = tokio::task::spawn_blocking(move || {
+        crate::operations::tasks::find_unmanaged_worktrees(&project.path, &project_key)
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?
+    .map_err(|e| ApiError::internal(e.to_string()))?;
+
+    Ok(Json(UnmanagedWorktreesResponse {
+        worktrees: found
+            .into_iter()
+            .map(|w| UnmanagedWorktreeResponse {
+                path: w.path,
+                branch: w.branch,
+                suggested_name: w.suggested_name,
+                suggested_target: w.suggested_target,
+            })
+            .collect(),
+    }))
+}
+
+/// POST /api/v1/projects/{id}/worktrees/adopt
+pub async fn adopt_worktree(
+    Path(id): Path<String>,
+    Json(req): Json<AdoptWorktreeRequest>,
+) -> Result<Json<TaskResponse>, (StatusCode, Json<ApiError>)> {
+    let (project, project_key) = common::find_project_by_id(&id)
+        .map_err(|s| ApiError::map_status(s, "Project not found"))?;
+    if !project.is_git_repo {
+        return Err(ApiError::bad_request("Project is not a git repository"));
+    }
+
+    let session_type = storage::config::load_config().default_session_type();
+    let task = tokio::task::spawn_blocking(move || {
+        crate::operations::tasks::adopt_worktree(
+            &project.path,
+            &project_key,
+            &req.path,
+            req.name,
+            req.target,
+            &session_type,
+        )
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?
+    .map_err(|e| match e {
+        GroveError::NotFound(msg) => ApiError::not_found(msg),
+        GroveError::InvalidData(msg) if msg.contains("already exists") => {
+            ApiError::map_status(StatusCode::CONFLICT, &msg)
+        }
+        GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+        e => ApiError::internal(e.to_string()),
+    })?;
+
+    let _ = crate::storage::taskgroups::ensure_system_groups();
+    use crate::api::handlers::walkie_talkie::{broadcast_radio_event, RadioEvent};
+    broadcast_radio_event(RadioEvent::GroupChanged);
+
+    Ok(Json(storage_task_to_response(&task)))
+}
+
 /// POST /api/v1/projects/{id}/tasks/{taskId}/archive
 pub async fn archive_task(
     Path((id, task_id)): Path<(String, String)>,
@@ -531,11 +676,11 @@ pub async fn rename_task(
     tokio::task::spawn_blocking(move || tasks::update_task_name(&pk, &tid, &new_name))
         .await
         .map_err(|e| {
-            eprintln!("[rename_task] join error for task {}: {}", task_id, e);
+            tracing::error!("[rename_task] join error for task {}: {}", task_id, e);
             StatusCode::INTERNAL_SERVER_ERROR
         })?
         .map_err(|e| {
-            eprintln!("[rename_task] storage error for task {}: {}", task_id, e);
+            tracing::warn!("[rename_task] storage error for task {}: {}", task_id, e);
             match e {
                 crate::error::GroveError::NotFound(_) => StatusCode::NOT_FOUND,
                 _ => StatusCode::INTERNAL_SERVER_ERROR,
@@ -546,6 +691,106 @@ pub async fn rename_task(
     get_task(Path((id, task_id))).await
 }
 
+/// PUT /api/v1/projects/{id}/tasks/{taskId}/assignee
+///
+/// Hands the task to the human or an agent chat. Assigning an agent sends it
+/// a prompt built from the unresolved review comments and notes.
+pub async fn handback_task(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<HandbackRequest>,
+) -> Result<Json<HandbackResponse>, StatusCode> {
+    let (_project, project_key) = common::find_project_by_id(&id)?;
+
+    let assignee = req.assignee.clone();
+    let result = crate::operations::tasks::handback_task(
+        &project_key,
+        &task_id,
+        req.assignee,
+        req.message.as_deref(),
+    )
+    .map_err(|e| {
+        tracing::warn!("[handback_task] task {}: {}", task_id, e);
+        match e {
+            crate::error::GroveError::NotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    })?;
+
+    let delivered = match (assignee, result.prompt) {
+        (Some(tasks::TaskAssignee::Agent { chat_id }), Some(prompt)) => {
+            super::review::send_to_chat(&project_key, &task_id, &chat_id, prompt).await
+        }
+        _ => false,
+    };
+
+    let Json(task) = get_task(Path((id, task_id))).await?;
+    Ok(Json(HandbackResponse { task, delivered }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/copy-targets
+///
+/// Values for the "copy to clipboard" quick actions. The task URL is built
+/// by the client from its own origin.
+pub async fn get_task_copy_targets(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<TaskCopyTargetsResponse>, StatusCode> {
+    let (_project, project_key) = common::find_project_by_id(&id)?;
+    let Json(task) = get_task(Path((id, task_id))).await?;
+    let (tid, mux) = (task.id.clone(), task.multiplexer.clone());
+    let attach_command = tokio::task::spawn_blocking(move || {
+        crate::operations::tasks::task_attach_command(&project_key, &tid, &mux)
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(TaskCopyTargetsResponse {
+        branch: task.branch,
+        path: task.path,
+        attach_command,
+    }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/open-with
+///
+/// Launches one of the user-defined `open_with` applications on the task worktree.
+pub async fn open_task_with(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<OpenWithRequest>,
+) -> Result<Json<OpenResponse>, StatusCode> {
+    let Json(task) = get_task(Path((id, task_id))).await?;
+    let result = tokio::task::spawn_blocking(move || {
+        crate::operations::tasks::open_task_with(&req.name, &task.path, &task.branch)
+            .map(|name| format!("Opening {} in {}", task.name, name))
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    match result {
+        Ok(message) => Ok(Json(OpenResponse {
+            success: true,
+            message,
+        })),
+        Err(GroveError::NotFound(_)) => Err(StatusCode::NOT_FOUND),
+        Err(e) => Ok(Json(OpenResponse {
+            success: false,
+            message: e.to_string(),
+        })),
+    }
+}
+
+/// PUT /api/v1/projects/{id}/tasks/{taskId}/pin
+pub async fn set_task_pinned(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<PinRequest>,
+) -> Result<Json<TaskResponse>, StatusCode> {
+    let (_project, project_key) = common::find_project_by_id(&id)?;
+
+    tasks::update_task_pinned(&project_key, &task_id, req.pinned).map_err(|e| match e {
+        GroveError::NotFound(_) => StatusCode::NOT_FOUND,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    })?;
+
+    get_task(Path((id, task_id))).await
+}
+
 /// DELETE /api/v1/projects/{id}/tasks/{taskId}
 pub async fn delete_task(
     Path((id, task_id)): Path<(String, String)>,
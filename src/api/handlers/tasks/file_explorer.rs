@@ -1,14 +1,16 @@
 //! Task file explorer handlers
 
 use axum::{
-    extract::{Path, Query},
-    http::StatusCode,
+    extract::{Multipart, Path, Query},
+    http::{header, HeaderMap, HeaderName, StatusCode},
+    response::IntoResponse,
     Json,
 };
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use crate::api::error::ApiError;
+use crate::api::handlers::studio_common::MAX_UPLOAD_SIZE;
 use crate::storage::tasks;
 
 use super::super::common::find_project_by_id;
@@ -341,9 +343,12 @@ pub async fn dir_entries(
     Ok(Json(DirEntriesResponse { entries }))
 }
 
-/// GET /api/v1/projects/{id}/tasks/{taskId}/files
+/// GET /api/v1/projects/{id}/tasks/{taskId}/files?full=
+///
+/// Path-scoped tasks only list files under their scope unless `full=true`.
 pub async fn list_files(
     Path((id, task_id)): Path<(String, String)>,
+    Query(query): Query<ScopeQuery>,
 ) -> Result<Json<FilesResponse>, StatusCode> {
     let (_project, project_key) = find_project_by_id(&id)?;
 
@@ -357,6 +362,18 @@ pub async fn list_files(
         Ok(f) => f,
         Err(_) => list_files_fs(&task.worktree_path),
     };
+    let scope = if query.full {
+        None
+    } else {
+        crate::storage::task_scope::load_scope(&project_key, &task_id)
+    };
+    let files: Vec<String> = match &scope {
+        Some(scope) => files
+            .into_iter()
+            .filter(|f| crate::storage::task_scope::in_scope(f, scope))
+            .collect(),
+        None => files,
+    };
 
     let worktree_path_owned = task.worktree_path.clone();
     let files_for_blocking = files.clone();
@@ -385,6 +402,7 @@ pub async fn list_files(
 
     Ok(Json(FilesResponse {
         files,
+        scope,
         metadata: if metadata.is_empty() {
             None
         } else {
@@ -394,50 +412,39 @@ pub async fn list_files(
 }
 
 /// GET /api/v1/projects/{id}/tasks/{taskId}/file?path=src/main.rs
+///
+/// The response carries a content hash (also sent as `ETag`) that must be
+/// echoed in `If-Match` when saving the file back.
 pub async fn get_file(
     Path((id, task_id)): Path<(String, String)>,
     Query(params): Query<FilePathQuery>,
-) -> Result<Json<FileContentResponse>, (StatusCode, Json<ApiError>)> {
-    let (_project, project_key) = find_project_by_id(&id).map_err(|s| {
-        (
-            s,
-            Json(ApiError {
-                error: "Project not found".to_string(),
-            }),
-        )
-    })?;
-
-    let task = tasks::get_task(&project_key, &task_id)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to load task: {}", e),
-                }),
-            )
-        })?
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ApiError {
-                    error: "Task not found".to_string(),
-                }),
-            )
-        })?;
+) -> Result<([(HeaderName, String); 1], Json<FileContentResponse>), (StatusCode, Json<ApiError>)> {
+    let task = load_task(&id, &task_id)?;
 
     let content = crate::git::read_file(&task.worktree_path, &params.path).map_err(|e| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ApiError {
-                error: e.to_string(),
-            }),
-        )
+        if is_binary_file(&task.worktree_path, &params.path) {
+            fs_error(
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                format!(
+                    "{} is a binary file ({}); use the download endpoint",
+                    params.path,
+                    mime_guess::from_path(&params.path).first_or_octet_stream()
+                ),
+            )
+        } else {
+            fs_error(StatusCode::BAD_REQUEST, e.to_string())
+        }
     })?;
 
-    Ok(Json(FileContentResponse {
-        content,
-        path: params.path,
-    }))
+    let hash = content_hash(&content);
+    Ok((
+        [(header::ETAG, format!("\"{}\"", hash))],
+        Json(FileContentResponse {
+            content,
+            path: params.path,
+            hash,
+        }),
+    ))
 }
 
 /// GET /api/v1/projects/{id}/tasks/{taskId}/file/raw?path=...
@@ -466,52 +473,268 @@ pub async fn get_file_raw(
     .await
 }
 
-/// PUT /api/v1/projects/{id}/tasks/{taskId}/file?path=src/main.rs
-pub async fn update_file(
+/// GET /api/v1/projects/{id}/tasks/{taskId}/fs/download?path=...
+///
+/// Streams any file (text or binary) as an attachment, with the MIME type
+/// derived from the extension. Same path rules as `file/raw`.
+pub async fn download_file(
     Path((id, task_id)): Path<(String, String)>,
     Query(params): Query<FilePathQuery>,
-    Json(body): Json<WriteFileRequest>,
-) -> Result<Json<FileContentResponse>, (StatusCode, Json<ApiError>)> {
-    let (_project, project_key) = find_project_by_id(&id).map_err(|s| {
-        (
-            s,
-            Json(ApiError {
-                error: "Project not found".to_string(),
-            }),
+    headers: HeaderMap,
+) -> Result<axum::response::Response, (StatusCode, Json<ApiError>)> {
+    crate::api::handlers::files::serve(
+        id,
+        crate::api::handlers::files::FileRoot::Task(task_id),
+        crate::api::handlers::files::RawFileQuery {
+            path: params.path,
+            disposition: crate::api::handlers::files::Disposition::Attachment,
+        },
+        headers,
+    )
+    .await
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/fs/upload?dir=assets&overwrite=false
+///
+/// Multipart upload of one or more files into a worktree directory. Each file
+/// is streamed to disk (limited to `MAX_UPLOAD_SIZE`) and only replaces an
+/// existing file when `overwrite=true`.
+pub async fn upload_files(
+    Path((id, task_id)): Path<(String, String)>,
+    Query(params): Query<UploadFilesQuery>,
+    mut multipart: Multipart,
+) -> Result<Json<FsUploadResponse>, (StatusCode, Json<ApiError>)> {
+    let task = load_task(&id, &task_id)?;
+    let dir = params.dir.trim_matches('/');
+    let dir_path = resolve_safe_path(&task.worktree_path, dir)?;
+    if dir_path.is_file() {
+        return Err(fs_error(
+            StatusCode::BAD_REQUEST,
+            format!("Not a directory: {}", dir),
+        ));
+    }
+    std::fs::create_dir_all(&dir_path).map_err(|e| {
+        fs_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to create directory: {}", e),
         )
     })?;
 
-    let task = tasks::get_task(&project_key, &task_id)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to load task: {}", e),
-                }),
-            )
-        })?
+    let mut files = Vec::new();
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| fs_error(StatusCode::BAD_REQUEST, format!("Invalid upload: {}", e)))?
+    {
+        let Some(name) = field.file_name().and_then(upload_file_name) else {
+            continue;
+        };
+        let rel = if dir.is_empty() {
+            name
+        } else {
+            format!("{}/{}", dir, name)
+        };
+        let full_path = resolve_safe_path(&task.worktree_path, &rel)?;
+        if full_path.is_dir() || (full_path.exists() && !params.overwrite) {
+            return Err(fs_error(
+                StatusCode::CONFLICT,
+                format!("Destination already exists: {}", rel),
+            ));
+        }
+
+        let size = stream_upload(field, &full_path).await?;
+        files.push(FsUploadedFile {
+            mime: mime_guess::from_path(&full_path)
+                .first_or_octet_stream()
+                .essence_str()
+                .to_string(),
+            path: rel,
+            size,
+        });
+    }
+
+    Ok(Json(FsUploadResponse { files }))
+}
+
+/// PUT /api/v1/projects/{id}/tasks/{taskId}/file?path=src/main.rs
+///
+/// Optimistic concurrency: `If-Match` must carry the hash returned when the
+/// file was loaded (or `*` to overwrite unconditionally). When the file has
+/// changed on disk since then, nothing is written and a 409 with a three-way
+/// merge payload is returned so the client can resolve the conflict.
+pub async fn update_file(
+    Path((id, task_id)): Path<(String, String)>,
+    Query(params): Query<FilePathQuery>,
+    headers: HeaderMap,
+    Json(body): Json<WriteFileRequest>,
+) -> Result<axum::response::Response, (StatusCode, Json<ApiError>)> {
+    let task = load_task(&id, &task_id)?;
+
+    let expected = headers
+        .get(header::IF_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| {
+            v.trim()
+                .trim_start_matches("W/")
+                .trim_matches('"')
+                .to_string()
+        })
         .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ApiError {
-                    error: "Task not found".to_string(),
-                }),
+            fs_error(
+                StatusCode::PRECONDITION_REQUIRED,
+                "If-Match header with the file's content hash is required",
             )
         })?;
 
-    crate::git::write_file(&task.worktree_path, &params.path, &body.content).map_err(|e| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ApiError {
-                error: e.to_string(),
-            }),
+    if expected != "*" {
+        // A file that no longer exists compares as empty content
+        let current = crate::git::read_file(&task.worktree_path, &params.path).unwrap_or_default();
+        let current_hash = content_hash(&current);
+        if current_hash != expected {
+            let conflict = build_conflict(params.path, body, current, current_hash);
+            return Ok((StatusCode::CONFLICT, Json(conflict)).into_response());
+        }
+    }
+
+    crate::git::write_file(&task.worktree_path, &params.path, &body.content)
+        .map_err(|e| fs_error(StatusCode::BAD_REQUEST, e.to_string()))?;
+
+    let hash = content_hash(&body.content);
+    Ok((
+        [(header::ETAG, format!("\"{}\"", hash))],
+        Json(FileContentResponse {
+            content: body.content,
+            path: params.path,
+            hash,
+        }),
+    )
+        .into_response())
+}
+
+/// Leading bytes inspected by `is_binary_file`.
+const BINARY_SNIFF_BYTES: u64 = 8 * 1024;
+
+/// Whether the file exists and looks binary. Only the first
+/// `BINARY_SNIFF_BYTES` are read, so large files are not loaded just to
+/// pick an error message.
+fn is_binary_file(worktree_path: &str, path: &str) -> bool {
+    use std::io::Read;
+
+    let Some(file) = resolve_safe_path(worktree_path, path)
+        .ok()
+        .and_then(|full| std::fs::File::open(full).ok())
+    else {
+        return false;
+    };
+    let mut head = Vec::new();
+    file.take(BINARY_SNIFF_BYTES)
+        .read_to_end(&mut head)
+        .is_ok_and(|_| looks_binary(&head))
+}
+
+/// A NUL byte or invalid UTF-8 in `head`. A multi-byte character cut off
+/// at the end of the sample does not count.
+fn looks_binary(head: &[u8]) -> bool {
+    head.contains(&0) || std::str::from_utf8(head).is_err_and(|e| e.error_len().is_some())
+}
+
+/// File name of an uploaded part: the last path component, rejecting names
+/// that cannot be a plain file in the worktree.
+fn upload_file_name(raw: &str) -> Option<String> {
+    let name = raw.rsplit(['/', '\\']).next()?.trim();
+    match name {
+        "" | "." | ".." | ".git" => None,
+        _ => Some(name.to_string()),
+    }
+}
+
+/// Stream one multipart field to `dest` via a temp file, enforcing the upload
+/// size limit. Returns the number of bytes written.
+async fn stream_upload(
+    mut field: axum::extract::multipart::Field<'_>,
+    dest: &std::path::Path,
+) -> Result<u64, FsError> {
+    use tokio::io::AsyncWriteExt;
+
+    let file_name = dest
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let temp = dest.with_file_name(format!(".{}.grove-upload", file_name));
+    let write_error = |e: std::io::Error| {
+        fs_error(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to write file: {}", e),
         )
-    })?;
+    };
 
-    Ok(Json(FileContentResponse {
-        content: body.content,
-        path: params.path,
-    }))
+    let mut out = tokio::fs::File::create(&temp).await.map_err(write_error)?;
+    let mut size: u64 = 0;
+    let result = async {
+        while let Some(chunk) = field.chunk().await.map_err(|e| {
+            fs_error(
+                StatusCode::BAD_REQUEST,
+                format!("Failed to read upload: {}", e),
+            )
+        })? {
+            size += chunk.len() as u64;
+            if size > MAX_UPLOAD_SIZE as u64 {
+                return Err(fs_error(
+                    StatusCode::PAYLOAD_TOO_LARGE,
+                    format!("File '{}' too large (max 100 MB)", file_name),
+                ));
+            }
+            out.write_all(&chunk).await.map_err(write_error)?;
+        }
+        out.flush().await.map_err(write_error)
+    }
+    .await;
+    drop(out);
+
+    if let Err(e) = result {
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(e);
+    }
+    if let Err(e) = tokio::fs::rename(&temp, dest).await {
+        let _ = tokio::fs::remove_file(&temp).await;
+        return Err(write_error(e));
+    }
+    Ok(size)
+}
+
+/// Hex sha256 of a file's content, used as its version for `If-Match`.
+fn content_hash(content: &str) -> String {
+    use sha2::{Digest, Sha256};
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+/// Conflict payload for a rejected save. The merge is only attempted when the
+/// client sent the content it originally loaded (`base`).
+fn build_conflict(
+    path: String,
+    body: WriteFileRequest,
+    theirs: String,
+    current_hash: String,
+) -> FileConflictResponse {
+    let merge = body.base.as_deref().and_then(|base| {
+        crate::git::merge_text(base, &body.content, &theirs, "editor", "disk")
+            .map_err(|e| tracing::warn!("Three-way merge of {} failed: {}", path, e))
+            .ok()
+    });
+    let (merged, has_conflicts) = match merge {
+        Some((merged, conflicted)) => (Some(merged), conflicted),
+        None => (None, true),
+    };
+    FileConflictResponse {
+        error: format!("{} was modified on disk since it was loaded", path),
+        path,
+        current_hash,
+        base: body.base,
+        ours: body.content,
+        theirs,
+        merged,
+        has_conflicts,
+    }
 }
 
 /// POST /api/v1/projects/{id}/tasks/{taskId}/fs/create-file
@@ -647,63 +870,8 @@ pub async fn delete_path(
     Path((id, task_id)): Path<(String, String)>,
     Query(params): Query<DeletePathQuery>,
 ) -> Result<Json<FsOperationResponse>, (StatusCode, Json<ApiError>)> {
-    let (_project, project_key) = find_project_by_id(&id).map_err(|s| {
-        (
-            s,
-            Json(ApiError {
-                error: "Project not found".to_string(),
-            }),
-        )
-    })?;
-
-    let task = tasks::get_task(&project_key, &task_id)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to load task: {}", e),
-                }),
-            )
-        })?
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ApiError {
-                    error: "Task not found".to_string(),
-                }),
-            )
-        })?;
-
-    let full_path = resolve_safe_path(&task.worktree_path, &params.path)?;
-
-    if !full_path.exists() {
-        return Err((
-            StatusCode::NOT_FOUND,
-            Json(ApiError {
-                error: format!("Path not found: {}", params.path),
-            }),
-        ));
-    }
-
-    if full_path.is_dir() {
-        std::fs::remove_dir_all(&full_path).map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to delete directory: {}", e),
-                }),
-            )
-        })?;
-    } else {
-        std::fs::remove_file(&full_path).map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to delete file: {}", e),
-                }),
-            )
-        })?;
-    }
+    let task = load_task(&id, &task_id)?;
+    delete_one(&task.worktree_path, &params.path)?;
 
     Ok(Json(FsOperationResponse {
         success: true,
@@ -772,180 +940,334 @@ pub async fn copy_file(
     Path((id, task_id)): Path<(String, String)>,
     Json(req): Json<CopyFileRequest>,
 ) -> Result<Json<FsOperationResponse>, (StatusCode, Json<ApiError>)> {
-    let (_project, project_key) = find_project_by_id(&id).map_err(|s| {
-        (
-            s,
-            Json(ApiError {
-                error: "Project not found".to_string(),
-            }),
-        )
-    })?;
+    let task = load_task(&id, &task_id)?;
+    copy_one(&task.worktree_path, &req.source, &req.destination)?;
 
-    let task = tasks::get_task(&project_key, &task_id)
+    Ok(Json(FsOperationResponse {
+        success: true,
+        message: format!("Copied {} to {}", req.source, req.destination),
+    }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/fs/move
+///
+/// Moves or renames a file or directory. Paths tracked by git are moved with
+/// `git mv` so the rename is staged instead of showing up as delete + add.
+pub async fn move_file(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<MoveFileRequest>,
+) -> Result<Json<FsOperationResponse>, (StatusCode, Json<ApiError>)> {
+    let task = load_task(&id, &task_id)?;
+    let message = match move_one(&task.worktree_path, &req.source, &req.destination)? {
+        MoveOutcome::Unchanged => format!("Source and destination are identical: {}", req.source),
+        MoveOutcome::Moved => format!("Moved {} to {}", req.source, req.destination),
+        MoveOutcome::GitMoved => {
+            format!("Moved {} to {} (git mv)", req.source, req.destination)
+        }
+    };
+
+    Ok(Json(FsOperationResponse {
+        success: true,
+        message,
+    }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/fs/batch-delete
+///
+/// Paths nested under another path in the same request are skipped, since
+/// deleting the parent already removes them.
+pub async fn batch_delete_paths(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<BatchDeleteRequest>,
+) -> Result<Json<FsBatchResponse>, (StatusCode, Json<ApiError>)> {
+    let task = load_task(&id, &task_id)?;
+    let results = drop_nested(&req.paths)
+        .into_iter()
+        .map(|path| {
+            let result = delete_one(&task.worktree_path, &path);
+            FsBatchItemResult::new(path, result)
+        })
+        .collect();
+    Ok(Json(FsBatchResponse::new(results)))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/fs/batch-move
+///
+/// Moves are applied in order; a failed item does not stop the rest.
+pub async fn batch_move_paths(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<BatchMoveRequest>,
+) -> Result<Json<FsBatchResponse>, (StatusCode, Json<ApiError>)> {
+    let task = load_task(&id, &task_id)?;
+    let results = req
+        .moves
+        .into_iter()
+        .map(|m| {
+            let result = move_one(&task.worktree_path, &m.source, &m.destination).map(|_| ());
+            FsBatchItemResult::new(m.source, result)
+        })
+        .collect();
+    Ok(Json(FsBatchResponse::new(results)))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/fs/batch-copy
+pub async fn batch_copy_paths(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<BatchCopyRequest>,
+) -> Result<Json<FsBatchResponse>, (StatusCode, Json<ApiError>)> {
+    let task = load_task(&id, &task_id)?;
+    let results = req
+        .copies
+        .into_iter()
+        .map(|c| {
+            let result = copy_one(&task.worktree_path, &c.source, &c.destination);
+            FsBatchItemResult::new(c.source, result)
+        })
+        .collect();
+    Ok(Json(FsBatchResponse::new(results)))
+}
+
+type FsError = (StatusCode, Json<ApiError>);
+
+fn fs_error(status: StatusCode, error: impl Into<String>) -> FsError {
+    (
+        status,
+        Json(ApiError {
+            error: error.into(),
+        }),
+    )
+}
+
+/// Load the task whose worktree the file system endpoints operate on.
+fn load_task(id: &str, task_id: &str) -> Result<tasks::Task, FsError> {
+    let (_project, project_key) =
+        find_project_by_id(id).map_err(|s| fs_error(s, "Project not found"))?;
+
+    tasks::get_task(&project_key, task_id)
         .map_err(|e| {
-            (
+            fs_error(
                 StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to load task: {}", e),
-                }),
+                format!("Failed to load task: {}", e),
             )
         })?
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ApiError {
-                    error: "Task not found".to_string(),
-                }),
+        .ok_or_else(|| fs_error(StatusCode::NOT_FOUND, "Task not found"))
+}
+
+/// Drop paths that live under another path of the same list (and duplicates).
+fn drop_nested(paths: &[String]) -> Vec<String> {
+    let mut kept: Vec<String> = Vec::new();
+    for path in paths {
+        let trimmed = path.trim_end_matches('/');
+        let nested = paths.iter().any(|other| {
+            let other = other.trim_end_matches('/');
+            trimmed.len() > other.len()
+                && trimmed.starts_with(other)
+                && trimmed.as_bytes()[other.len()] == b'/'
+        });
+        if !nested && !kept.iter().any(|k| k == trimmed) {
+            kept.push(trimmed.to_string());
+        }
+    }
+    kept
+}
+
+fn create_parent_dirs(path: &std::path::Path) -> Result<(), FsError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            fs_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to create parent directories: {}", e),
             )
         })?;
+    }
+    Ok(())
+}
 
-    let source_path = resolve_safe_path(&task.worktree_path, &req.source)?;
-    let dest_path = resolve_safe_path(&task.worktree_path, &req.destination)?;
+/// Delete one file or directory inside the worktree.
+fn delete_one(worktree_path: &str, path: &str) -> Result<(), FsError> {
+    let full_path = resolve_safe_path(worktree_path, path)?;
+
+    if !full_path.exists() {
+        return Err(fs_error(
+            StatusCode::NOT_FOUND,
+            format!("Path not found: {}", path),
+        ));
+    }
+
+    if full_path.is_dir() {
+        std::fs::remove_dir_all(&full_path).map_err(|e| {
+            fs_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to delete directory: {}", e),
+            )
+        })
+    } else {
+        std::fs::remove_file(&full_path).map_err(|e| {
+            fs_error(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("Failed to delete file: {}", e),
+            )
+        })
+    }
+}
+
+/// Copy one file inside the worktree.
+fn copy_one(worktree_path: &str, source: &str, destination: &str) -> Result<(), FsError> {
+    let source_path = resolve_safe_path(worktree_path, source)?;
+    let dest_path = resolve_safe_path(worktree_path, destination)?;
 
     if !source_path.exists() {
-        return Err((
+        return Err(fs_error(
             StatusCode::NOT_FOUND,
-            Json(ApiError {
-                error: format!("Source file not found: {}", req.source),
-            }),
+            format!("Source file not found: {}", source),
         ));
     }
 
     if !source_path.is_file() {
-        return Err((
+        return Err(fs_error(
             StatusCode::BAD_REQUEST,
-            Json(ApiError {
-                error: "Source must be a file, not a directory".to_string(),
-            }),
+            "Source must be a file, not a directory",
         ));
     }
 
     if dest_path.exists() {
-        return Err((
+        return Err(fs_error(
             StatusCode::CONFLICT,
-            Json(ApiError {
-                error: format!("Destination already exists: {}", req.destination),
-            }),
+            format!("Destination already exists: {}", destination),
         ));
     }
 
-    if let Some(parent) = dest_path.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to create parent directories: {}", e),
-                }),
-            )
-        })?;
-    }
-
+    create_parent_dirs(&dest_path)?;
     std::fs::copy(&source_path, &dest_path).map_err(|e| {
-        (
+        fs_error(
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiError {
-                error: format!("Failed to copy file: {}", e),
-            }),
+            format!("Failed to copy file: {}", e),
         )
     })?;
-
-    Ok(Json(FsOperationResponse {
-        success: true,
-        message: format!("Copied {} to {}", req.source, req.destination),
-    }))
+    Ok(())
 }
 
-/// POST /api/v1/projects/{id}/tasks/{taskId}/fs/move
-pub async fn move_file(
-    Path((id, task_id)): Path<(String, String)>,
-    Json(req): Json<MoveFileRequest>,
-) -> Result<Json<FsOperationResponse>, (StatusCode, Json<ApiError>)> {
-    let (_project, project_key) = find_project_by_id(&id).map_err(|s| {
-        (
-            s,
-            Json(ApiError {
-                error: "Project not found".to_string(),
-            }),
-        )
-    })?;
-
-    let task = tasks::get_task(&project_key, &task_id)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to load task: {}", e),
-                }),
-            )
-        })?
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ApiError {
-                    error: "Task not found".to_string(),
-                }),
-            )
-        })?;
+enum MoveOutcome {
+    Unchanged,
+    Moved,
+    GitMoved,
+}
 
-    let source_path = resolve_safe_path(&task.worktree_path, &req.source)?;
-    let dest_path = resolve_safe_path(&task.worktree_path, &req.destination)?;
+/// Move one file or directory inside the worktree, using `git mv` when the
+/// source is tracked.
+fn move_one(worktree_path: &str, source: &str, destination: &str) -> Result<MoveOutcome, FsError> {
+    let source_path = resolve_safe_path(worktree_path, source)?;
+    let dest_path = resolve_safe_path(worktree_path, destination)?;
 
     if !source_path.exists() {
-        return Err((
+        return Err(fs_error(
             StatusCode::NOT_FOUND,
-            Json(ApiError {
-                error: format!("Source file or directory not found: {}", req.source),
-            }),
+            format!("Source file or directory not found: {}", source),
         ));
     }
 
     if source_path == dest_path {
-        return Ok(Json(FsOperationResponse {
-            success: true,
-            message: format!("Source and destination are identical: {}", req.source),
-        }));
+        return Ok(MoveOutcome::Unchanged);
     }
 
     if dest_path.starts_with(&source_path) {
-        return Err((
+        return Err(fs_error(
             StatusCode::BAD_REQUEST,
-            Json(ApiError {
-                error: "Cannot move a directory into itself or its subdirectories".to_string(),
-            }),
+            "Cannot move a directory into itself or its subdirectories",
         ));
     }
 
     if dest_path.exists() {
-        return Err((
+        return Err(fs_error(
             StatusCode::CONFLICT,
-            Json(ApiError {
-                error: format!("Destination already exists: {}", req.destination),
-            }),
+            format!("Destination already exists: {}", destination),
         ));
     }
 
-    if let Some(parent) = dest_path.parent() {
-        std::fs::create_dir_all(parent).map_err(|e| {
-            (
+    create_parent_dirs(&dest_path)?;
+
+    if crate::git::is_tracked(worktree_path, source) {
+        crate::git::move_path(worktree_path, source, destination).map_err(|e| {
+            fs_error(
                 StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to create parent directories: {}", e),
-                }),
+                format!("Failed to move file/directory: {}", e),
             )
         })?;
+        return Ok(MoveOutcome::GitMoved);
     }
 
     std::fs::rename(&source_path, &dest_path).map_err(|e| {
-        (
+        fs_error(
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiError {
-                error: format!("Failed to move file/directory: {}", e),
-            }),
+            format!("Failed to move file/directory: {}", e),
         )
     })?;
+    Ok(MoveOutcome::Moved)
+}
 
-    Ok(Json(FsOperationResponse {
-        success: true,
-        message: format!("Moved {} to {}", req.source, req.destination),
-    }))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_looks_binary() {
+        assert!(!looks_binary(b"fn main() {}\n"));
+        assert!(looks_binary(b"PNG\0\0\x01"));
+        assert!(looks_binary(b"caf\xe9 latin-1"));
+        // A character split by the sample boundary is still text
+        assert!(!looks_binary(&"héllo".as_bytes()[..2]));
+    }
+
+    #[test]
+    fn test_upload_file_name() {
+        assert_eq!(upload_file_name("logo.png").as_deref(), Some("logo.png"));
+        assert_eq!(
+            upload_file_name("../../etc/passwd").as_deref(),
+            Some("passwd")
+        );
+        assert_eq!(upload_file_name("C:\\tmp\\a.bin").as_deref(), Some("a.bin"));
+        assert_eq!(upload_file_name("dir/.."), None);
+        assert_eq!(upload_file_name(".git"), None);
+        assert_eq!(upload_file_name(""), None);
+    }
+
+    #[test]
+    fn test_drop_nested() {
+        let paths: Vec<String> = ["src", "src/main.rs", "docs/", "docs/a.md", "srcx", "src"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(drop_nested(&paths), vec!["src", "docs", "srcx"]);
+    }
+
+    #[test]
+    fn test_move_one_uses_git_mv_for_tracked_files() {
+        let temp = tempfile::tempdir().unwrap();
+        let repo = temp.path().to_str().unwrap();
+        let git = |args: &[&str]| {
+            std::process::Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .output()
+                .unwrap()
+        };
+        git(&["init", "-q"]);
+        std::fs::write(temp.path().join("tracked.txt"), "a").unwrap();
+        std::fs::write(temp.path().join("untracked.txt"), "b").unwrap();
+        git(&["add", "tracked.txt"]);
+
+        assert!(matches!(
+            move_one(repo, "tracked.txt", "dir/renamed.txt"),
+            Ok(MoveOutcome::GitMoved)
+        ));
+        assert!(matches!(
+            move_one(repo, "untracked.txt", "moved.txt"),
+            Ok(MoveOutcome::Moved)
+        ));
+        assert!(move_one(repo, "../escape.txt", "x.txt").is_err());
+
+        let staged = String::from_utf8(git(&["diff", "--cached", "--name-only"]).stdout).unwrap();
+        assert_eq!(staged.trim(), "dir/renamed.txt");
+        assert!(temp.path().join("moved.txt").exists());
+    }
 }
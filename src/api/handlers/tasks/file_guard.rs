@@ -0,0 +1,39 @@
+//! Task file guard handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+use serde::Serialize;
+
+use crate::api::error::ApiError;
+use crate::storage::file_guard::{self, GuardViolation};
+
+use super::super::common::find_project_by_id;
+
+/// Added files of a task that violate the project's file guard
+#[derive(Debug, Serialize)]
+pub struct FileGuardStatus {
+    pub enabled: bool,
+    /// Violations block the merge (otherwise they only warn)
+    pub block: bool,
+    pub violations: Vec<GuardViolation>,
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/file-guard
+pub async fn get_task_file_guard(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<FileGuardStatus>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let settings = file_guard::load_settings(&project_key)
+        .map_err(|e| ApiError::internal(format!("Failed to load file guard: {}", e)))?;
+    let guard = settings.clone();
+    let violations = tokio::task::spawn_blocking(move || {
+        file_guard::task_violations(&project_key, &task_id, &guard)
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(FileGuardStatus {
+        enabled: settings.enabled,
+        block: settings.block,
+        violations,
+    }))
+}
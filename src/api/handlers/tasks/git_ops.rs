@@ -1,5 +1,7 @@
 //! Task git operation handlers
 
+use std::collections::HashMap;
+
 use axum::{
     extract::{Path, Query},
     http::StatusCode,
@@ -8,35 +10,56 @@ use axum::{
 };
 
 use crate::api::error::ApiError;
+use crate::api::handlers::projects::GitIdentityResponse;
+use crate::diff::codeowners::{self, CodeOwners, OwnersSummary};
+use crate::diff::test_changes::{self, TestChanges};
+use crate::error::GroveError;
+use crate::forge::PullRequestStatus;
 use crate::git;
+use crate::operations::conflicts::{self, RebaseConflicts};
+use crate::operations::risk::{self, RiskAssessment};
+use crate::storage::git_identity::{self, GitIdentity};
+use crate::storage::merge_gate;
+use crate::storage::task_scope;
 use crate::storage::tasks;
 
-use super::super::common::find_project_by_id;
+use super::super::common::{find_project_by_id, iso_time, CommitLinkResolver};
 use super::types::*;
 
 /// POST /api/v1/projects/{id}/tasks/{taskId}/sync
+///
+/// A rebase that stops on conflicts is left in progress; `conflicts` lists
+/// the files for the conflict view (`GET …/conflicts`).
 pub async fn sync_task(
     Path((id, task_id)): Path<(String, String)>,
-) -> Result<Json<GitOperationResponse>, StatusCode> {
+) -> Result<Json<SyncResponse>, StatusCode> {
     let (project, project_key) = find_project_by_id(&id)?;
 
     match crate::operations::tasks::sync_task(&project.path, &project_key, &task_id) {
-        Ok(target) => Ok(Json(GitOperationResponse {
+        Ok(target) => Ok(Json(SyncResponse {
             success: true,
             message: format!("Synced with {}", target),
-            warning: None,
+            conflicts: Vec::new(),
         })),
         Err(e) => {
-            let error_msg = e.to_string();
-            let message = if error_msg.contains("conflict") || error_msg.contains("CONFLICT") {
-                "Conflict detected - please resolve in terminal".to_string()
+            let conflicts = tasks::get_task(&project_key, &task_id)
+                .ok()
+                .flatten()
+                .filter(|task| git::rebase_in_progress(&task.worktree_path))
+                .and_then(|task| git::get_conflict_files(&task.worktree_path).ok())
+                .unwrap_or_default();
+            let message = if !conflicts.is_empty() {
+                format!(
+                    "Rebase stopped on conflicts in {} file(s) - resolve them in the conflict view",
+                    conflicts.len()
+                )
             } else {
-                format!("Sync failed: {}", error_msg)
+                format!("Sync failed: {}", e)
             };
-            Ok(Json(GitOperationResponse {
+            Ok(Json(SyncResponse {
                 success: false,
                 message,
-                warning: None,
+                conflicts,
             }))
         }
     }
@@ -53,7 +76,8 @@ pub async fn commit_task(
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .ok_or(StatusCode::NOT_FOUND)?;
 
-    if let Err(e) = git::add_and_commit(&task.worktree_path, &req.message) {
+    let options = git::CommitOptions::resolve(&project_key, Some(&task.id));
+    if let Err(e) = git::add_and_commit(&task.worktree_path, &req.message, &options) {
         return Ok(Json(GitOperationResponse {
             success: false,
             message: e.to_string(),
@@ -74,7 +98,7 @@ pub async fn commit_task(
 pub async fn merge_task(
     Path((id, task_id)): Path<(String, String)>,
     body: Option<Json<MergeRequest>>,
-) -> Result<Json<GitOperationResponse>, StatusCode> {
+) -> Result<Json<MergeResponse>, StatusCode> {
     let (project, project_key) = find_project_by_id(&id)?;
 
     let method_str = body.as_ref().and_then(|b| b.method.as_deref());
@@ -95,18 +119,173 @@ pub async fn merge_task(
         }
     };
 
-    match crate::operations::tasks::merge_task(&project.path, &project_key, &task_id, method) {
-        Ok(result) => Ok(Json(GitOperationResponse {
+    let include_review = body.as_ref().is_some_and(|b| b.include_review);
+
+    match crate::operations::tasks::merge_task(
+        &project.path,
+        &project_key,
+        &task_id,
+        method,
+        include_review,
+    ) {
+        Ok(result) => Ok(Json(MergeResponse {
             success: true,
             message: format!("Merged into {}", result.target_branch),
             warning: result.warning,
+            blockers: Vec::new(),
         })),
-        Err(e) => Ok(Json(GitOperationResponse {
+        Err(e) => {
+            // Refused by the merge gate: list what is blocking it
+            let blockers = if e.storage_tag() == Some(merge_gate::MERGE_BLOCKED_TAG) {
+                merge_gate::check(&project_key, &task_id).unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+            let message = if blockers.is_empty() {
+                e.to_string()
+            } else {
+                merge_gate::describe(&blockers)
+            };
+            Ok(Json(MergeResponse {
+                success: false,
+                message,
+                warning: None,
+                blockers,
+            }))
+        }
+    }
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/pr
+///
+/// Push the task branch to `origin` and open a pull / merge request into its
+/// target on the project's forge (an already open one for the branch is
+/// reused).
+pub async fn create_pull_request(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<PullRequestResponse>, StatusCode> {
+    let (project, project_key) = find_project_by_id(&id)?;
+    let result = tokio::task::spawn_blocking(move || {
+        crate::operations::tasks::create_pull_request(&project.path, &project_key, &task_id)
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(match result {
+        Ok(pr) => PullRequestResponse {
+            success: true,
+            message: format!("Opened pull request #{}", pr.number),
+            pull_request: Some(pr),
+        },
+        Err(e) => PullRequestResponse {
             success: false,
             message: e.to_string(),
-            warning: None,
-        })),
-    }
+            pull_request: None,
+        },
+    }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/pr
+///
+/// The task's pull / merge request (`null` when none was opened) with its
+/// review and CI state from the forge; the recorded state is refreshed while
+/// it is still open.
+pub async fn get_pull_request(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<Option<PullRequestStatus>>, (StatusCode, Json<ApiError>)> {
+    let (project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    tokio::task::spawn_blocking(move || {
+        crate::operations::tasks::pull_request_status(&project.path, &project_key, &task_id)
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?
+    .map(Json)
+    .map_err(|e| ApiError::internal(format!("Failed to load pull request: {}", e)))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/risk
+///
+/// Heuristic risk score of the task's changes (`null` when scoring is
+/// disabled in `[risk]`, for Local Tasks and for archived tasks).
+pub async fn get_task_risk(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<Option<RiskAssessment>>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let task = tasks::get_task(&project_key, &task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?;
+    let Some(task) = task.filter(|t| !t.is_local) else {
+        return Ok(Json(None));
+    };
+    let risk =
+        tokio::task::spawn_blocking(move || risk::task_risk(&task.worktree_path, &task.target))
+            .await
+            .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(risk))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/test-changes
+///
+/// Source vs test files the task changed against its target, with
+/// `tests_missing` set when source changed but no test did (`null` for
+/// Local Tasks and when the diff can't be read).
+pub async fn get_task_test_changes(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<Option<TestChanges>>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let task = tasks::get_task(&project_key, &task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?;
+    let Some(task) = task.filter(|t| !t.is_local) else {
+        return Ok(Json(None));
+    };
+    let result = tokio::task::spawn_blocking(move || {
+        test_changes::task_test_changes(&project_key, &task.worktree_path, &task.target)
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(result))
+}
+
+/// GET /api/v1/projects/{id}/task-risks
+///
+/// Risk scores of every active worktree task, keyed by task id; the task
+/// list fetches this separately so the project response stays git-free.
+pub async fn list_task_risks(
+    Path(id): Path<String>,
+) -> Result<Json<HashMap<String, RiskAssessment>>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let risks = tokio::task::spawn_blocking(move || {
+        use rayon::prelude::*;
+        tasks::load_tasks(&project_key)
+            .unwrap_or_default()
+            .into_par_iter()
+            .filter(|t| !t.is_local)
+            .filter_map(|t| risk::task_risk(&t.worktree_path, &t.target).map(|r| (t.id, r)))
+            .collect::<HashMap<_, _>>()
+    })
+    .await
+    .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(risks))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/merge-gate
+///
+/// What currently blocks merging the task under the project's merge gate.
+pub async fn get_task_merge_blockers(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<MergeGateStatus>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let blockers = merge_gate::check(&project_key, &task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to check merge gate: {}", e)))?;
+    Ok(Json(MergeGateStatus {
+        enabled: merge_gate::load_settings(&project_key)
+            .map_err(|e| ApiError::internal(format!("Failed to check merge gate: {}", e)))?
+            .is_enabled(),
+        blockers,
+    }))
 }
 
 /// POST /api/v1/projects/{id}/tasks/{taskId}/reset
@@ -161,95 +340,133 @@ pub async fn reset_task(
 }
 
 /// POST /api/v1/projects/{id}/tasks/{taskId}/rebase-to
+///
+/// Rebases the task onto a new target branch and records the change in the
+/// task's target history.
 pub async fn rebase_to_task(
     Path((id, task_id)): Path<(String, String)>,
     Json(req): Json<RebaseToRequest>,
 ) -> Result<Json<GitOperationResponse>, (StatusCode, Json<ApiError>)> {
     if task_id == crate::storage::tasks::LOCAL_TASK_ID {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ApiError {
-                error: "Cannot rebase local task".to_string(),
-            }),
-        ));
+        return Err(ApiError::bad_request("Cannot rebase local task"));
     }
 
-    let (project, project_key) = find_project_by_id(&id).map_err(|s| {
-        (
-            s,
-            Json(ApiError {
-                error: "Project not found".to_string(),
-            }),
-        )
-    })?;
-
-    let _task = tasks::get_task(&project_key, &task_id)
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError {
-                    error: format!("Failed to load task: {}", e),
-                }),
-            )
-        })?
-        .ok_or_else(|| {
-            (
-                StatusCode::NOT_FOUND,
-                Json(ApiError {
-                    error: "Task not found".to_string(),
-                }),
-            )
-        })?;
+    let (project, project_key) =
+        find_project_by_id(&id).map_err(|s| ApiError::map_status(s, "Project not found"))?;
 
-    if !git::branch_exists(&project.path, &req.target) {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            Json(ApiError {
-                error: format!("Branch '{}' does not exist", req.target),
-            }),
-        ));
+    match crate::operations::tasks::retarget_task(
+        &project.path,
+        &project_key,
+        &task_id,
+        &req.target,
+    ) {
+        Ok(previous) => Ok(Json(GitOperationResponse {
+            success: true,
+            message: format!("Rebased from '{}' onto '{}'", previous, req.target),
+            warning: None,
+        })),
+        Err(GroveError::NotFound(msg)) => Err(ApiError::not_found(msg)),
+        Err(GroveError::InvalidData(msg)) => Err(ApiError::bad_request(msg)),
+        Err(e) => Ok(Json(GitOperationResponse {
+            success: false,
+            message: e.to_string(),
+            warning: None,
+        })),
     }
-
-    tasks::update_task_target(&project_key, &task_id, &req.target).map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiError {
-                error: format!("Failed to update task target: {}", e),
-            }),
-        )
-    })?;
-
-    Ok(Json(GitOperationResponse {
-        success: true,
-        message: format!("Target branch changed to '{}'", req.target),
-        warning: None,
-    }))
 }
 
-/// GET /api/v1/projects/{id}/tasks/{taskId}/diff
-pub async fn get_diff(
+/// GET /api/v1/projects/{id}/tasks/{taskId}/target-history
+pub async fn get_target_history(
     Path((id, task_id)): Path<(String, String)>,
-    Query(query): Query<DiffQuery>,
-) -> Result<Json<DiffResponse>, StatusCode> {
+) -> Result<Json<TargetHistoryResponse>, StatusCode> {
     let (_project, project_key) = find_project_by_id(&id)?;
+    let changes = tasks::load_target_history(&project_key, &task_id)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(Json(TargetHistoryResponse { changes }))
+}
 
-    let task = tasks::get_task(&project_key, &task_id)
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+/// Load a task (active or archived) for the diff endpoints and validate the
+/// requested refs, so an unknown ref is a 400 rather than an empty diff.
+pub(super) fn load_diff_task(
+    id: &str,
+    task_id: &str,
+    refs: [Option<&str>; 2],
+) -> Result<(String, tasks::Task), (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(id).map_err(|_| ApiError::not_found("Project not found"))?;
+
+    let task = tasks::get_task(&project_key, task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?
         .or_else(|| {
-            tasks::get_archived_task(&project_key, &task_id)
+            tasks::get_archived_task(&project_key, task_id)
                 .ok()
                 .flatten()
         })
-        .ok_or(StatusCode::NOT_FOUND)?;
+        .ok_or_else(|| ApiError::not_found("Task not found"))?;
+
+    for reference in refs.into_iter().flatten() {
+        git::resolve_commit(&task.worktree_path, reference)
+            .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    }
+    Ok((project_key, task))
+}
 
+/// GET /api/v1/projects/{id}/tasks/{taskId}/diff?from_ref=&to_ref=&full=
+///
+/// `from_ref` / `to_ref` accept any commit-ish (another task branch, a tag,
+/// `origin/main`, a hash); both default to the task target / working tree.
+/// Path-scoped tasks only list files under their scope unless `full=true`.
+/// `ignore_whitespace` / `ignore_blank_lines` / `detect_renames` /
+/// `detect_copies` override the user's review preferences.
+pub async fn get_diff(
+    Path((id, task_id)): Path<(String, String)>,
+    Query(query): Query<DiffQuery>,
+    Query(options): Query<DiffOptionsQuery>,
+) -> Result<Json<DiffResponse>, (StatusCode, Json<ApiError>)> {
+    let (project_key, task) = load_diff_task(
+        &id,
+        &task_id,
+        [query.from_ref.as_deref(), query.to_ref.as_deref()],
+    )?;
+
+    let options = options.resolve();
     let diff_entries = if let Some(to_ref) = query.to_ref.as_deref() {
         let from = query.from_ref.as_deref().unwrap_or(&task.target);
-        git::diff_stat_range(&task.worktree_path, from, to_ref).unwrap_or_default()
+        git::diff_stat_range_with_options(&task.worktree_path, from, to_ref, &options)
+            .unwrap_or_default()
     } else {
         let target = query.from_ref.as_deref().unwrap_or(&task.target);
-        git::diff_stat(&task.worktree_path, target).unwrap_or_default()
+        git::diff_stat_with_options(&task.worktree_path, target, &options).unwrap_or_default()
     };
 
+    // Tests count wherever they live, so classify before scoping
+    let all_paths: Vec<String> = diff_entries.iter().map(|e| e.path.clone()).collect();
+    let tests = test_changes::analyze(
+        &all_paths,
+        &test_changes::project_test_patterns(&project_key),
+    );
+
+    // Path-scoped tasks only list their subtree unless `full` is asked for
+    let scope = if query.full {
+        None
+    } else {
+        task_scope::load_scope(&project_key, &task.id)
+    };
+    let before = diff_entries.len();
+    let diff_entries: Vec<_> = match &scope {
+        Some(scope) => diff_entries
+            .into_iter()
+            .filter(|e| task_scope::in_scope(&e.path, scope))
+            .collect(),
+        None => diff_entries,
+    };
+    let outside_scope = (before - diff_entries.len()) as u32;
+
+    let paths: Vec<String> = diff_entries.iter().map(|e| e.path.clone()).collect();
+    let patterns = crate::storage::diff_filters::load_settings(&project_key).collapsed_patterns;
+    let collapsed = crate::diff::collapsed_paths(&task.worktree_path, &paths, &patterns);
+    let codeowners = CodeOwners::load(&task.worktree_path);
+
     let mut total_additions = 0u32;
     let mut total_deletions = 0u32;
 
@@ -264,11 +481,18 @@ pub async fn get_diff(
                 'A' => DiffStatus::Added,
                 'D' => DiffStatus::Deleted,
                 'R' => DiffStatus::Renamed,
+                // A copy is a new file as far as review is concerned
+                'C' => DiffStatus::Added,
                 'U' => DiffStatus::Untracked,
                 _ => DiffStatus::Modified,
             };
 
             DiffFileEntry {
+                collapsed: collapsed.contains(&entry.path),
+                owners: codeowners
+                    .as_ref()
+                    .map(|co| co.owners_of(&entry.path).to_vec())
+                    .unwrap_or_default(),
                 path: entry.path,
                 status,
                 additions: entry.additions,
@@ -282,9 +506,151 @@ pub async fn get_diff(
         files,
         total_additions,
         total_deletions,
+        scope,
+        outside_scope,
+        tests,
     }))
 }
 
+/// GET /api/v1/projects/{id}/tasks/{taskId}/owners
+///
+/// CODEOWNERS summary of everything the task changed against its target
+/// (ignores the path scope so files outside it can be flagged): owners to
+/// request review from, unowned files, and warnings for files outside the
+/// scope or owned by teams other than the scope's owners.
+pub async fn get_task_owners(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<OwnersSummary>, (StatusCode, Json<ApiError>)> {
+    let (project_key, task) = load_diff_task(&id, &task_id, [None, None])?;
+    let paths: Vec<String> = git::diff_stat(&task.worktree_path, &task.target)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| entry.path)
+        .collect();
+    let scope = task_scope::load_scope(&project_key, &task.id);
+    let codeowners = CodeOwners::load(&task.worktree_path);
+    Ok(Json(codeowners::summarize(
+        codeowners.as_ref(),
+        &paths,
+        scope.as_deref(),
+    )))
+}
+
+/// Active task whose worktree is staged into
+fn load_index_task(id: &str, task_id: &str) -> Result<tasks::Task, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(id).map_err(|_| ApiError::not_found("Project not found"))?;
+    tasks::get_task(&project_key, task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?
+        .ok_or_else(|| ApiError::not_found("Task not found"))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/index
+///
+/// Staged (index ↔ HEAD) and unstaged (worktree ↔ index) diffs plus untracked
+/// files. Hunk `header`s from these diffs identify hunks for stage / unstage.
+pub async fn get_index(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<crate::operations::staging::IndexDiff>, (StatusCode, Json<ApiError>)> {
+    let task = load_index_task(&id, &task_id)?;
+    crate::operations::staging::index_diff(&task.worktree_path)
+        .map(Json)
+        .map_err(|e| ApiError::internal(e.to_string()))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/index/stage
+///
+/// Stages the whole file, or only the listed hunks of its unstaged diff.
+/// Returns the updated index view; 400 if a hunk no longer matches.
+pub async fn stage_hunks(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<StageRequest>,
+) -> Result<Json<crate::operations::staging::IndexDiff>, (StatusCode, Json<ApiError>)> {
+    let task = load_index_task(&id, &task_id)?;
+    crate::operations::staging::stage(&task.worktree_path, &req.path, req.hunks.as_deref())
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    crate::operations::staging::index_diff(&task.worktree_path)
+        .map(Json)
+        .map_err(|e| ApiError::internal(e.to_string()))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/index/unstage
+///
+/// Unstages the whole file, or only the listed hunks of its staged diff.
+pub async fn unstage_hunks(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<StageRequest>,
+) -> Result<Json<crate::operations::staging::IndexDiff>, (StatusCode, Json<ApiError>)> {
+    let task = load_index_task(&id, &task_id)?;
+    crate::operations::staging::unstage(&task.worktree_path, &req.path, req.hunks.as_deref())
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    crate::operations::staging::index_diff(&task.worktree_path)
+        .map(Json)
+        .map_err(|e| ApiError::internal(e.to_string()))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/conflicts
+///
+/// State of the task's stopped rebase: progress, the commit being applied and
+/// each unresolved file with its ours / base / theirs hunks. `ours` is the
+/// target branch, `theirs` the task commit.
+pub async fn get_conflicts(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<RebaseConflicts>, (StatusCode, Json<ApiError>)> {
+    let task = load_index_task(&id, &task_id)?;
+    conflicts::status(&task.worktree_path)
+        .map(Json)
+        .map_err(|e| ApiError::internal(e.to_string()))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/conflicts/resolve
+///
+/// Takes ours / theirs in every hunk of the file, or writes manual content,
+/// and stages it. 400 if the file isn't conflicted or markers remain.
+pub async fn resolve_conflict(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<ResolveConflictRequest>,
+) -> Result<Json<RebaseConflicts>, (StatusCode, Json<ApiError>)> {
+    let task = load_index_task(&id, &task_id)?;
+    conflicts::resolve(&task.worktree_path, &req.path, &req.resolution)
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    conflicts::status(&task.worktree_path)
+        .map(Json)
+        .map_err(|e| ApiError::internal(e.to_string()))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/rebase/continue
+///
+/// Continues once every file is resolved; the response is the next stop's
+/// conflicts, or `in_progress: false` when the sync finished.
+pub async fn continue_rebase(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<RebaseConflicts>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let task = load_index_task(&id, &task_id)?;
+    let state = conflicts::continue_rebase(&task.worktree_path)
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    if !state.in_progress {
+        let _ = tasks::touch_task(&project_key, &task_id);
+    }
+    Ok(Json(state))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/rebase/abort
+pub async fn abort_rebase(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<RebaseConflicts>, (StatusCode, Json<ApiError>)> {
+    let task = load_index_task(&id, &task_id)?;
+    conflicts::abort(&task.worktree_path).map_err(|e| ApiError::bad_request(e.to_string()))?;
+    conflicts::status(&task.worktree_path)
+        .map(Json)
+        .map_err(|e| ApiError::internal(e.to_string()))
+}
+
+/// Signature checks shell out to gpg / ssh-keygen per signed commit
+const MAX_SIGNATURE_CHECKS: usize = 200;
+
 /// GET /api/v1/projects/{id}/tasks/{taskId}/commits
 pub async fn get_commits(
     Path((id, task_id)): Path<(String, String)>,
@@ -328,11 +694,22 @@ pub async fn get_commits(
         0
     };
 
+    let hashes: Vec<String> = log_entries
+        .iter()
+        .take(MAX_SIGNATURE_CHECKS)
+        .map(|e| e.hash.clone())
+        .collect();
+    let signatures = git::signature_statuses(&task.worktree_path, &hashes).unwrap_or_default();
+
+    let mut links = CommitLinkResolver::new(&project_key);
     let commits: Vec<CommitEntry> = log_entries
         .into_iter()
         .map(|entry| CommitEntry {
+            signature: signatures.get(&entry.hash).copied(),
+            links: links.resolve(&entry.trailers),
             hash: entry.hash,
             message: entry.message,
+            time: iso_time(entry.committer_time),
             time_ago: entry.time_ago,
         })
         .collect();
@@ -348,24 +725,276 @@ pub async fn get_commits(
 pub async fn get_single_file_diff(
     Path((id, task_id)): Path<(String, String)>,
     Query(query): Query<SingleFileDiffQuery>,
-) -> Result<axum::response::Response, StatusCode> {
-    let (_project, project_key) = find_project_by_id(&id)?;
-
-    let task = tasks::get_task(&project_key, &task_id)
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
-        .or_else(|| {
-            tasks::get_archived_task(&project_key, &task_id)
-                .ok()
-                .flatten()
-        })
-        .ok_or(StatusCode::NOT_FOUND)?;
+    Query(options): Query<DiffOptionsQuery>,
+) -> Result<axum::response::Response, (StatusCode, Json<ApiError>)> {
+    let (project_key, task) = load_diff_task(
+        &id,
+        &task_id,
+        [query.from_ref.as_deref(), query.to_ref.as_deref()],
+    )?;
 
     let from_ref = query.from_ref.as_deref().or(Some(&task.target));
     let to_ref = query.to_ref.as_deref();
 
-    let result =
-        crate::diff::get_single_file_diff(&task.worktree_path, &query.path, from_ref, to_ref)
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let mut result = crate::diff::get_single_file_diff(
+        &task.worktree_path,
+        &query.path,
+        from_ref,
+        to_ref,
+        &options.resolve(),
+    )
+    .map_err(|e| ApiError::internal(e.to_string()))?;
+
+    // Lockfiles: summarize package changes instead of raw lines
+    crate::diff::dependencies::annotate_file(
+        &task.worktree_path,
+        from_ref.unwrap_or(&task.target),
+        to_ref,
+        &mut result,
+    );
+
+    // Only the live working tree diff lines up with recorded chat turns
+    // and with coverage from the task's latest test run
+    if to_ref.is_none() {
+        crate::operations::snapshots::attribute_file(
+            &project_key,
+            &task_id,
+            &task.worktree_path,
+            &mut result,
+        );
+        if let Some(report) = crate::storage::coverage::load_report(&project_key, &task.id) {
+            crate::diff::coverage::annotate_file(&report, &mut result);
+        }
+    }
 
     Ok(Json(result).into_response())
 }
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/file/history?path=...&include_target=false&limit=100
+///
+/// Commits touching `path` (following renames), newest first. By default only
+/// the task branch (`target..HEAD`) is searched; `include_target=true` walks
+/// the full history, with `on_branch` marking the task's own commits.
+pub async fn get_file_history(
+    Path((id, task_id)): Path<(String, String)>,
+    Query(query): Query<FileHistoryQuery>,
+) -> Result<Json<FileHistoryResponse>, (StatusCode, Json<ApiError>)> {
+    let (_project_key, task) = load_diff_task(&id, &task_id, [None, None])?;
+    let limit = query.limit.unwrap_or(100).clamp(1, 1000);
+    let branch_range = format!("{}..HEAD", task.target);
+
+    let branch_log = git::file_log(&task.worktree_path, &query.path, Some(&branch_range), limit)
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+    let entries = if query.include_target {
+        git::file_log(&task.worktree_path, &query.path, None, limit)
+            .map_err(|e| ApiError::internal(e.to_string()))?
+    } else {
+        branch_log.clone()
+    };
+
+    let commits = entries
+        .into_iter()
+        .map(|entry| FileHistoryEntry {
+            on_branch: branch_log.iter().any(|b| b.hash == entry.hash),
+            hash: entry.hash,
+            author: entry.author,
+            time: iso_time(entry.timestamp),
+            timestamp: entry.timestamp,
+            time_ago: entry.time_ago,
+            message: entry.message,
+            path: entry.path,
+        })
+        .collect();
+
+    Ok(Json(FileHistoryResponse {
+        path: query.path,
+        commits,
+    }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/file/history/{commit}?path=...
+///
+/// The patch of one history commit restricted to the file; `path` is the
+/// file's path at that commit (as returned by the history endpoint).
+pub async fn get_file_history_patch(
+    Path((id, task_id, commit)): Path<(String, String, String)>,
+    Query(query): Query<FilePathQuery>,
+) -> Result<Json<FilePatchResponse>, (StatusCode, Json<ApiError>)> {
+    let (_project_key, task) = load_diff_task(&id, &task_id, [Some(&commit), None])?;
+    let patch = git::file_patch(&task.worktree_path, &commit, &query.path)
+        .map_err(|e| ApiError::internal(e.to_string()))?;
+    Ok(Json(FilePatchResponse {
+        commit,
+        path: query.path,
+        patch,
+    }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/diff/refs
+///
+/// Refs the diff can be compared against. Branches owned by other tasks are
+/// reported as `task` entries labelled with the task name; the task's own
+/// branch is omitted.
+pub async fn get_diff_refs(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<DiffRefsResponse>, (StatusCode, Json<ApiError>)> {
+    let (project_key, task) = load_diff_task(&id, &task_id, [None, None])?;
+
+    let task_names: std::collections::HashMap<String, String> = tasks::load_tasks(&project_key)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|t| !t.branch.is_empty())
+        .map(|t| (t.branch, t.name))
+        .collect();
+
+    let refs = git::list_compare_refs(&task.worktree_path)
+        .map_err(|e| ApiError::internal(e.to_string()))?
+        .into_iter()
+        .filter(|(_, name)| *name != task.branch)
+        .map(|(kind, name)| match task_names.get(&name) {
+            Some(task_name) => DiffRefEntry {
+                label: task_name.clone(),
+                name,
+                kind: "task".to_string(),
+            },
+            None => DiffRefEntry {
+                label: name.clone(),
+                name,
+                kind: kind.to_string(),
+            },
+        })
+        .collect();
+
+    Ok(Json(DiffRefsResponse { refs }))
+}
+
+/// Project key of an existing task, for per-task settings
+fn task_project_key(id: &str, task_id: &str) -> Result<String, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(id).map_err(|_| ApiError::not_found("Project not found"))?;
+    tasks::get_task(&project_key, task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?
+        .ok_or_else(|| ApiError::not_found("Task not found"))?;
+    Ok(project_key)
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/git-identity
+///
+/// The task's own override and the identity its commits use (task fields
+/// over project fields).
+pub async fn get_task_git_identity(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<GitIdentityResponse>, (StatusCode, Json<ApiError>)> {
+    let project_key = task_project_key(&id, &task_id)?;
+    Ok(Json(GitIdentityResponse {
+        identity: git_identity::load_task(&project_key, &task_id),
+        effective: git_identity::resolve(&project_key, Some(&task_id)),
+    }))
+}
+
+/// PUT /api/v1/projects/{id}/tasks/{taskId}/git-identity
+///
+/// Replaces the task override; empty fields fall back to the project's.
+pub async fn update_task_git_identity(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(body): Json<GitIdentity>,
+) -> Result<Json<GitIdentityResponse>, (StatusCode, Json<ApiError>)> {
+    let project_key = task_project_key(&id, &task_id)?;
+    let identity = body.normalized();
+    identity
+        .validate()
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    git_identity::save_task(&project_key, &task_id, &identity)
+        .map_err(|e| ApiError::internal(format!("Failed to save git identity: {}", e)))?;
+    Ok(Json(GitIdentityResponse {
+        identity,
+        effective: git_identity::resolve(&project_key, Some(&task_id)),
+    }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/scope
+pub async fn get_task_scope(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<TaskScopeDto>, (StatusCode, Json<ApiError>)> {
+    let project_key = task_project_key(&id, &task_id)?;
+    Ok(Json(TaskScopeDto {
+        scope: task_scope::load_scope(&project_key, &task_id),
+    }))
+}
+
+/// PUT /api/v1/projects/{id}/tasks/{taskId}/scope
+///
+/// The scope must be an existing directory of the worktree; null or ""
+/// clears it. A watched task is re-watched on the new scope.
+pub async fn update_task_scope(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(body): Json<TaskScopeDto>,
+) -> Result<Json<TaskScopeDto>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let task = tasks::get_task(&project_key, &task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?
+        .ok_or_else(|| ApiError::not_found("Task not found"))?;
+    let scope = task_scope::normalize_scope(body.scope.as_deref().unwrap_or(""))
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    if let Some(scope) = &scope {
+        if !std::path::Path::new(&task.worktree_path)
+            .join(scope)
+            .is_dir()
+        {
+            return Err(ApiError::bad_request(format!(
+                "'{}' is not a directory of the task worktree",
+                scope
+            )));
+        }
+    }
+    task_scope::save_scope(&project_key, &task_id, scope.as_deref())
+        .map_err(|e| ApiError::internal(format!("Failed to save scope: {}", e)))?;
+    crate::api::state::refresh_task_watch(&project_key, &task_id, &task.worktree_path);
+    Ok(Json(TaskScopeDto { scope }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/sparse
+pub async fn get_task_sparse(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<TaskSparseDto>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let task = tasks::get_task(&project_key, &task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?
+        .ok_or_else(|| ApiError::not_found("Task not found"))?;
+    Ok(Json(TaskSparseDto {
+        paths: git::sparse_checkout::sparse_paths(&task.worktree_path).unwrap_or_default(),
+    }))
+}
+
+/// PUT /api/v1/projects/{id}/tasks/{taskId}/sparse
+///
+/// Replaces the checked-out paths of the worktree; an empty list disables
+/// sparse checkout. Files outside the new set must be committed first, git
+/// refuses to drop modified files. A watched task is re-watched afterwards.
+pub async fn update_task_sparse(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(body): Json<TaskSparseDto>,
+) -> Result<Json<TaskSparseDto>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let task = tasks::get_task(&project_key, &task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?
+        .ok_or_else(|| ApiError::not_found("Task not found"))?;
+    if task.is_local {
+        return Err(ApiError::bad_request(
+            "Local tasks have no worktree to check out sparsely",
+        ));
+    }
+    let paths = git::sparse_checkout::normalize_paths(&body.paths)
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    let worktree = task.worktree_path.clone();
+    let to_set = paths.clone();
+    tokio::task::spawn_blocking(move || git::sparse_checkout::set_paths(&worktree, &to_set))
+        .await
+        .map_err(|e| ApiError::internal(format!("Task join error: {}", e)))?
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+    crate::api::state::refresh_task_watch(&project_key, &task_id, &task.worktree_path);
+    Ok(Json(TaskSparseDto { paths }))
+}
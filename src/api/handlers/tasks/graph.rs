@@ -609,6 +609,8 @@ mod tests {
             code_deletions: 0,
             files_changed: 0,
             is_local: false,
+            assignee: None,
+            pinned: false,
         };
         add_task(project_id, task).unwrap();
 
@@ -626,6 +628,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             add_chat_session(project_id, task_id, chat).unwrap();
 
@@ -651,6 +654,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: Some("review".to_string()),
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             let chat2 = ChatSession {
                 id: "chat-2".to_string(),
@@ -660,6 +664,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             add_chat_session(project_id, task_id, chat1).unwrap();
             add_chat_session(project_id, task_id, chat2).unwrap();
@@ -727,6 +732,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             let chat2 = ChatSession {
                 id: "chat-b".to_string(),
@@ -736,6 +742,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             add_chat_session("proj-1", task_id, chat1).unwrap();
             add_chat_session("proj-1", task_id, chat2).unwrap();
@@ -760,6 +767,7 @@ mod tests {
                     created_at: chrono::Utc::now(),
                     duty: None,
                     launch_mode: "acp".to_string(),
+                    work_subdir: None,
                 };
                 add_chat_session("proj-1", task_id, chat).unwrap();
             }
@@ -787,6 +795,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             let chat2 = ChatSession {
                 id: "chat-y".to_string(),
@@ -796,6 +805,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             add_chat_session("proj-1", task_id, chat1).unwrap();
             add_chat_session("proj-1", task_id, chat2).unwrap();
@@ -822,6 +832,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             let chat2 = ChatSession {
                 id: "chat-d2".to_string(),
@@ -831,6 +842,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             add_chat_session("proj-1", task_id, chat1).unwrap();
             add_chat_session("proj-1", task_id, chat2).unwrap();
@@ -855,6 +867,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             let chat_b = ChatSession {
                 id: "chat-b".to_string(),
@@ -864,6 +877,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: Some("frontend tests".to_string()),
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             let chat_c = ChatSession {
                 id: "chat-c".to_string(),
@@ -873,6 +887,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             add_chat_session(project_id, task_id, chat_a).unwrap();
             add_chat_session(project_id, task_id, chat_b).unwrap();
@@ -942,6 +957,7 @@ mod tests {
                         created_at: chrono::Utc::now(),
                         duty: None,
                         launch_mode: "acp".to_string(),
+                        work_subdir: None,
                     },
                 )
                 .unwrap();
@@ -977,6 +993,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             add_chat_session(project_id, task_id, chat).unwrap();
 
@@ -1010,6 +1027,8 @@ mod tests {
                 code_deletions: 0,
                 files_changed: 0,
                 is_local: false,
+                assignee: None,
+                pinned: false,
             };
             add_task(project_id, other_task).unwrap();
 
@@ -1021,6 +1040,7 @@ mod tests {
                 created_at: chrono::Utc::now(),
                 duty: None,
                 launch_mode: "acp".to_string(),
+                work_subdir: None,
             };
             add_chat_session(project_id, "task-2", chat_in_other).unwrap();
 
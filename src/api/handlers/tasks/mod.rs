@@ -1,25 +1,49 @@
 //! Task API handlers
 
+pub mod all;
 pub mod artifacts;
+pub mod autolinks;
+pub mod bench;
+pub mod chat_import;
+pub mod checkpoints;
+pub mod coverage;
+pub mod cross_review;
 pub mod crud;
 pub mod file_explorer;
+pub mod file_guard;
 pub mod git_ops;
 pub mod graph;
 pub mod notes;
 pub mod review;
+pub mod search;
+pub mod security_scan;
 pub mod sketch_events;
 pub mod sketch_ws;
 pub mod sketches;
+pub mod snapshots;
 pub mod types;
+pub mod views;
 
 // Re-export all public items so routing table needs zero changes.
+pub use all::*;
 pub use artifacts::*;
+pub use autolinks::*;
+pub use bench::*;
+pub use chat_import::*;
+pub use checkpoints::*;
+pub use coverage::*;
+pub use cross_review::*;
 pub use crud::*;
 pub use file_explorer::*;
+pub use file_guard::*;
 pub use git_ops::*;
 pub use graph::*;
 pub use notes::*;
 pub use review::*;
+pub use search::*;
+pub use security_scan::*;
 pub use sketches::*;
+pub use snapshots::*;
 #[allow(unused_imports)]
 pub use types::*;
+pub use views::*;
@@ -1,12 +1,25 @@
 //! Task notes handlers
 
-use axum::{extract::Path, http::StatusCode, Json};
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Json,
+};
 
-use crate::storage::notes;
+use crate::error::GroveError;
+use crate::storage::notes::{self, NoteSource};
 
 use super::super::common::find_project_by_id;
 use super::types::*;
 
+fn revision_status(e: GroveError) -> StatusCode {
+    match e {
+        GroveError::NotFound(_) => StatusCode::NOT_FOUND,
+        GroveError::InvalidData(_) => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
 /// GET /api/v1/projects/{id}/tasks/{taskId}/notes
 pub async fn get_notes(
     Path((id, task_id)): Path<(String, String)>,
@@ -26,10 +39,68 @@ pub async fn update_notes(
 ) -> Result<Json<NotesResponse>, StatusCode> {
     let (_project, project_key) = find_project_by_id(&id)?;
 
-    notes::save_notes(&project_key, &task_id, &req.content)
+    notes::save_notes(&project_key, &task_id, &req.content, NoteSource::Web)
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
     Ok(Json(NotesResponse {
         content: req.content,
     }))
 }
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/notes/revisions
+///
+/// Bounded revision history of the notes, newest first. Every write
+/// (web, TUI editor, MCP `grove_edit_note`, sync, automations) records one.
+pub async fn list_note_revisions(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<NoteRevisionsResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    let revisions = notes::list_revisions(&project_key, &task_id)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(NoteRevisionsResponse { revisions }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/notes/revisions/{revId}
+pub async fn get_note_revision(
+    Path((id, task_id, rev_id)): Path<(String, String, String)>,
+) -> Result<Json<NoteRevisionResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    let (revision, content) =
+        notes::load_revision(&project_key, &task_id, &rev_id).map_err(revision_status)?;
+
+    Ok(Json(NoteRevisionResponse { revision, content }))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/notes/revisions/{revId}/diff?against=
+///
+/// Unified diff from the revision to `against` (another revision id), or to
+/// the current notes when `against` is omitted.
+pub async fn diff_note_revision(
+    Path((id, task_id, rev_id)): Path<(String, String, String)>,
+    Query(query): Query<NoteRevisionDiffQuery>,
+) -> Result<Json<NoteRevisionDiffResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    let diff = notes::diff_revision(&project_key, &task_id, &rev_id, query.against.as_deref())
+        .map_err(revision_status)?;
+
+    Ok(Json(NoteRevisionDiffResponse { diff }))
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/notes/revisions/{revId}/restore
+///
+/// Restores the notes to the revision. The restore is itself recorded as a
+/// new revision, so it can be undone the same way.
+pub async fn restore_note_revision(
+    Path((id, task_id, rev_id)): Path<(String, String, String)>,
+) -> Result<Json<NotesResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    let content =
+        notes::restore_revision(&project_key, &task_id, &rev_id).map_err(revision_status)?;
+
+    Ok(Json(NotesResponse { content }))
+}
@@ -2,6 +2,7 @@
 
 use axum::{extract::Path, http::StatusCode, Json};
 
+use crate::operations::review;
 use crate::storage::{comments, tasks};
 
 use super::super::common::find_project_by_id;
@@ -34,50 +35,31 @@ pub async fn get_review_comments(
 
     let (open, resolved, outdated) = data.count_by_status();
 
+    let git_user_name = get_git_user_name(&project_key, &task_id);
+    let drafts = comments::load_draft_comments(
+        &project_key,
+        &task_id,
+        &reviewer_name(git_user_name.as_deref()),
+    )
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let draft_count = drafts.comments.len() as u32;
+
+    let last_review = comments::latest_review_submission(&project_key, &task_id)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(|r| ReviewSubmissionEntry {
+            id: r.id,
+            reviewer: r.reviewer,
+            verdict: r.verdict.as_str().to_string(),
+            summary: r.summary,
+            comment_ids: r.comment_ids,
+            timestamp: r.timestamp,
+        });
+
     let comment_entries: Vec<ReviewCommentEntry> = data
         .comments
         .into_iter()
-        .map(|c| {
-            let status = match c.status {
-                comments::CommentStatus::Open => "open",
-                comments::CommentStatus::Resolved => "resolved",
-                comments::CommentStatus::Outdated => "outdated",
-            }
-            .to_string();
-
-            let replies = c
-                .replies
-                .into_iter()
-                .map(|r| ReviewCommentReplyEntry {
-                    id: r.id,
-                    content: r.content,
-                    agent: r.agent,
-                    model: r.model,
-                    role: r.role,
-                    timestamp: r.timestamp,
-                })
-                .collect();
-
-            ReviewCommentEntry {
-                id: c.id,
-                comment_type: Some(match c.comment_type {
-                    comments::CommentType::Inline => "inline".to_string(),
-                    comments::CommentType::File => "file".to_string(),
-                    comments::CommentType::Project => "project".to_string(),
-                }),
-                file_path: c.file_path,
-                side: c.side,
-                start_line: c.start_line,
-                end_line: c.end_line,
-                content: c.content,
-                agent: c.agent,
-                model: c.model,
-                role: c.role,
-                timestamp: c.timestamp,
-                status,
-                replies,
-            }
-        })
+        .map(|c| comment_entry(c, false))
+        .chain(drafts.comments.into_iter().map(|c| comment_entry(c, true)))
         .collect();
 
     Ok(Json(ReviewCommentsResponse {
@@ -85,10 +67,73 @@ pub async fn get_review_comments(
         open_count: open as u32,
         resolved_count: resolved as u32,
         outdated_count: outdated as u32,
-        git_user_name: get_git_user_name(&project_key, &task_id),
+        draft_count,
+        last_review,
+        git_user_name,
     }))
 }
 
+/// Reviewer identity that owns pending drafts: the agent part of the
+/// display author, matching what `create_review_comment` stores.
+fn reviewer_name(author: Option<&str>) -> String {
+    comments::parse_author_to_agent_role(author.unwrap_or("You")).0
+}
+
+fn comment_entry(c: comments::Comment, draft: bool) -> ReviewCommentEntry {
+    let status = match c.status {
+        comments::CommentStatus::Open => "open",
+        comments::CommentStatus::Resolved => "resolved",
+        comments::CommentStatus::Outdated => "outdated",
+    }
+    .to_string();
+
+    let replies = c
+        .replies
+        .into_iter()
+        .map(|r| ReviewCommentReplyEntry {
+            id: r.id,
+            content: r.content,
+            agent: r.agent,
+            model: r.model,
+            role: r.role,
+            timestamp: r.timestamp,
+            reactions: reaction_entries(r.reactions),
+        })
+        .collect();
+
+    ReviewCommentEntry {
+        id: c.id,
+        comment_type: Some(match c.comment_type {
+            comments::CommentType::Inline => "inline".to_string(),
+            comments::CommentType::File => "file".to_string(),
+            comments::CommentType::Project => "project".to_string(),
+        }),
+        file_path: c.file_path,
+        side: c.side,
+        start_line: c.start_line,
+        end_line: c.end_line,
+        content: c.content,
+        agent: c.agent,
+        model: c.model,
+        role: c.role,
+        timestamp: c.timestamp,
+        status,
+        replies,
+        draft,
+        reactions: reaction_entries(c.reactions),
+    }
+}
+
+fn reaction_entries(reactions: Vec<comments::Reaction>) -> Vec<ReviewReactionEntry> {
+    reactions
+        .into_iter()
+        .map(|r| ReviewReactionEntry {
+            emoji: r.emoji,
+            authors: r.authors,
+        })
+        .collect()
+}
+
 /// POST /api/v1/projects/{id}/tasks/{taskId}/review
 pub async fn reply_review_comment(
     Path((id, task_id)): Path<(String, String)>,
@@ -104,7 +149,7 @@ pub async fn reply_review_comment(
         .unwrap_or("You");
     let (agent, role) = comments::parse_author_to_agent_role(author);
 
-    comments::reply_comment(
+    let replied = comments::reply_comment(
         &project_key,
         &task_id,
         req.comment_id,
@@ -115,6 +160,10 @@ pub async fn reply_review_comment(
     )
     .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    if replied {
+        notify_mentions(&project_key, &task_id, req.comment_id, author, &req.message).await;
+    }
+
     get_review_comments(Path((id, task_id))).await
 }
 
@@ -157,8 +206,9 @@ pub async fn create_review_comment(
         .or(default_name.as_deref())
         .unwrap_or("You");
     let (agent, role) = comments::parse_author_to_agent_role(author);
+    let draft_owner = req.draft.unwrap_or(false).then_some(agent.as_str());
 
-    match comment_type {
+    let comment = match comment_type {
         comments::CommentType::Inline => {
             let (file_path, side, start_line, end_line) = if let Some(ref fp) = req.file_path {
                 let side = req.side.as_deref().unwrap_or("ADD");
@@ -194,8 +244,9 @@ pub async fn create_review_comment(
                 "",
                 &role,
                 anchor_text,
+                draft_owner,
             )
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         }
         comments::CommentType::File => {
             let file_path = req.file_path.ok_or(StatusCode::BAD_REQUEST)?;
@@ -213,27 +264,194 @@ pub async fn create_review_comment(
                 "",
                 &role,
                 None,
+                draft_owner,
             )
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         }
-        comments::CommentType::Project => {
-            comments::add_comment(
-                &project_key,
-                &task_id,
-                comment_type,
-                None,
-                None,
-                None,
-                None,
-                &req.content,
-                &agent,
-                "",
-                &role,
-                None,
-            )
-            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        comments::CommentType::Project => comments::add_comment(
+            &project_key,
+            &task_id,
+            comment_type,
+            None,
+            None,
+            None,
+            None,
+            &req.content,
+            &agent,
+            "",
+            &role,
+            None,
+            draft_owner,
+        )
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?,
+    };
+
+    // Drafts stay silent until the review is submitted
+    if draft_owner.is_none() {
+        notify_mentions(&project_key, &task_id, comment.id, author, &comment.content).await;
+    }
+
+    get_review_comments(Path((id, task_id))).await
+}
+
+/// Raise notifications for @mentions in a comment or reply posted from the
+/// web UI (always the local user), prompting any mentioned agent chats.
+async fn notify_mentions(
+    project_key: &str,
+    task_id: &str,
+    comment_id: u32,
+    author: &str,
+    text: &str,
+) {
+    let mentions = review::dispatch_mentions(project_key, task_id, comment_id, author, text, true);
+    for mention in mentions {
+        send_to_chat(project_key, task_id, &mention.chat_id, mention.prompt).await;
+    }
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/review/submit
+///
+/// Publishes the reviewer's pending drafts together with an overall verdict,
+/// then sends one aggregated prompt to the task's live chat (the requested
+/// chat, or the newest one with a running session).
+pub async fn submit_review(
+    Path((id, task_id)): Path<(String, String)>,
+    Json(req): Json<SubmitReviewRequest>,
+) -> Result<Json<SubmitReviewResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    let verdict = comments::ReviewVerdict::parse(&req.verdict).ok_or(StatusCode::BAD_REQUEST)?;
+    let summary = req.summary.unwrap_or_default();
+
+    let default_name = get_git_user_name(&project_key, &task_id);
+    let reviewer = reviewer_name(req.author.as_deref().or(default_name.as_deref()));
+
+    let pending = comments::load_draft_comments(&project_key, &task_id, &reviewer)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    if pending.is_empty()
+        && summary.trim().is_empty()
+        && verdict == comments::ReviewVerdict::Comment
+    {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let submission = comments::submit_review(&project_key, &task_id, &reviewer, verdict, &summary)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    for comment in &pending.comments {
+        notify_mentions(
+            &project_key,
+            &task_id,
+            comment.id,
+            &reviewer,
+            &comment.content,
+        )
+        .await;
+    }
+
+    let prompt = comments::build_review_prompt(&submission, &pending.comments);
+    let delivered_chat_id =
+        deliver_review_prompt(&project_key, &task_id, req.chat_id.as_deref(), prompt).await;
+
+    let Json(review) = get_review_comments(Path((id, task_id))).await?;
+    Ok(Json(SubmitReviewResponse {
+        delivered_chat_id,
+        review,
+    }))
+}
+
+/// Send the review prompt to a live chat session. Returns the chat it was
+/// delivered to, or `None` when no session is running (the comments are
+/// still published and readable through `grove_read_review`).
+async fn deliver_review_prompt(
+    project_key: &str,
+    task_id: &str,
+    preferred_chat: Option<&str>,
+    prompt: String,
+) -> Option<String> {
+    let candidates: Vec<String> = match preferred_chat {
+        Some(chat_id) => vec![chat_id.to_string()],
+        None => tasks::load_chat_sessions(project_key, task_id)
+            .unwrap_or_default()
+            .into_iter()
+            .rev()
+            .map(|c| c.id)
+            .collect(),
+    };
+
+    for chat_id in candidates {
+        if crate::acp::session_exists(&format!("{}:{}:{}", project_key, task_id, chat_id)) {
+            return send_to_chat(project_key, task_id, &chat_id, prompt)
+                .await
+                .then_some(chat_id);
         }
     }
+    None
+}
+
+/// Prompt a chat if its ACP session is live. Returns whether it was sent.
+pub(super) async fn send_to_chat(
+    project_key: &str,
+    task_id: &str,
+    chat_id: &str,
+    prompt: String,
+) -> bool {
+    let session_key = format!("{}:{}:{}", project_key, task_id, chat_id);
+    match crate::acp::get_session_handle(&session_key) {
+        Some(handle) => handle
+            .send_prompt(prompt, vec![], Some("review".to_string()), false, None)
+            .await
+            .is_ok(),
+        None => false,
+    }
+}
+
+/// DELETE /api/v1/projects/{id}/tasks/{taskId}/review/drafts
+pub async fn discard_review_drafts(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<ReviewCommentsResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    let reviewer = reviewer_name(get_git_user_name(&project_key, &task_id).as_deref());
+    comments::discard_draft_comments(&project_key, &task_id, &reviewer)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    get_review_comments(Path((id, task_id))).await
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/review/comments/{commentId}/reactions
+///
+/// Toggles the caller's reaction on a comment (or one of its replies).
+pub async fn toggle_review_reaction(
+    Path((id, task_id, comment_id)): Path<(String, String, u32)>,
+    Json(req): Json<ToggleReactionRequest>,
+) -> Result<Json<ReviewCommentsResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    if !comments::REACTION_EMOJIS.contains(&req.emoji.as_str()) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let default_name = get_git_user_name(&project_key, &task_id);
+    let author = req
+        .author
+        .as_deref()
+        .or(default_name.as_deref())
+        .unwrap_or("You");
+
+    let toggled = comments::toggle_reaction(
+        &project_key,
+        &task_id,
+        comment_id,
+        req.reply_id,
+        &req.emoji,
+        author,
+    )
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    if toggled.is_none() {
+        return Err(StatusCode::NOT_FOUND);
+    }
 
     get_review_comments(Path((id, task_id))).await
 }
@@ -0,0 +1,49 @@
+//! Task worktree content search handler
+
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    Json,
+};
+
+use crate::api::error::ApiError;
+use crate::error::GroveError;
+use crate::operations::search::{self as ops, SearchOptions, SearchResult};
+use crate::storage::tasks;
+
+use super::super::common::find_project_by_id;
+use super::types::*;
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/search?q=...&regex=false&case_sensitive=false&context=2&limit=200&glob=
+///
+/// Searches the worktree's tracked and non-ignored untracked files. Files over
+/// 1 MiB and binary files are skipped; `truncated` is set when more than
+/// `limit` matches exist.
+pub async fn search_task_files(
+    Path((id, task_id)): Path<(String, String)>,
+    Query(query): Query<TaskSearchQuery>,
+) -> Result<Json<SearchResult>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|s| ApiError::map_status(s, "Project not found"))?;
+    let task = tasks::get_task(&project_key, &task_id)
+        .map_err(|e| ApiError::internal(format!("Failed to load task: {}", e)))?
+        .ok_or_else(|| ApiError::not_found("Task not found"))?;
+
+    let opts = SearchOptions {
+        query: query.q,
+        regex: query.regex,
+        case_sensitive: query.case_sensitive,
+        context: query.context.unwrap_or(2),
+        max_results: query.limit.unwrap_or(200),
+        glob: query.glob,
+    };
+    let worktree = std::path::PathBuf::from(task.worktree_path);
+    tokio::task::spawn_blocking(move || ops::search_worktree(&worktree, &opts))
+        .await
+        .map_err(|e| ApiError::internal(e.to_string()))?
+        .map(Json)
+        .map_err(|e| match e {
+            GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+            e => ApiError::internal(e.to_string()),
+        })
+}
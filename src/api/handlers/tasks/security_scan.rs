@@ -0,0 +1,72 @@
+//! Task security scan handlers
+
+use axum::{extract::Path, http::StatusCode, Json};
+use serde::Serialize;
+
+use crate::api::error::ApiError;
+use crate::error::GroveError;
+use crate::operations::security_scan;
+use crate::storage::security_scan::{self as scan_storage, ScanReport, Severity};
+
+use super::super::common::find_project_by_id;
+
+/// Latest scan of a task with the project's thresholds
+#[derive(Debug, Serialize)]
+pub struct SecurityScanStatus {
+    /// Whether the project has any scanners configured
+    pub configured: bool,
+    /// Findings at or above this block the merge (`null` = warn only)
+    pub block_severity: Option<Severity>,
+    pub warn_severity: Severity,
+    /// `null` when the task was never scanned
+    pub report: Option<ScanReport>,
+    /// Non-blocking summary of the report
+    pub warning: Option<String>,
+}
+
+fn scan_status(
+    project_key: &str,
+    report: Option<ScanReport>,
+) -> Result<SecurityScanStatus, (StatusCode, Json<ApiError>)> {
+    let settings = scan_storage::load_settings(project_key)
+        .map_err(|e| ApiError::internal(format!("Failed to load security scan settings: {}", e)))?;
+    Ok(SecurityScanStatus {
+        configured: settings.is_configured(),
+        block_severity: settings.block.then_some(settings.block_severity),
+        warn_severity: settings.warn_severity,
+        warning: report
+            .as_ref()
+            .and_then(|r| security_scan::warning(r, &settings)),
+        report,
+    })
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/security-scan
+pub async fn get_task_security_scan(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<SecurityScanStatus>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let report = scan_storage::load_report(&project_key, &task_id);
+    scan_status(&project_key, report).map(Json)
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/security-scan
+///
+/// Run the project's scanners in the task worktree now and store the report.
+pub async fn run_task_security_scan(
+    Path((id, task_id)): Path<(String, String)>,
+) -> Result<Json<SecurityScanStatus>, (StatusCode, Json<ApiError>)> {
+    let (_project, project_key) =
+        find_project_by_id(&id).map_err(|_| ApiError::not_found("Project not found"))?;
+    let key = project_key.clone();
+    let report = tokio::task::spawn_blocking(move || security_scan::scan(&key, &task_id))
+        .await
+        .map_err(|e| ApiError::internal(e.to_string()))?
+        .map_err(|e| match e {
+            GroveError::InvalidData(msg) => ApiError::bad_request(msg),
+            GroveError::NotFound(msg) => ApiError::not_found(msg),
+            e => ApiError::internal(format!("Security scan failed: {}", e)),
+        })?;
+    scan_status(&project_key, Some(report)).map(Json)
+}
@@ -27,6 +27,7 @@ pub async fn ws_handler(
 }
 
 async fn handle(mut socket: WebSocket, project_key: String, task_id: String) {
+    let _conn = crate::metrics::ws_connection("sketch");
     let mut rx = subscribe();
     let mut heartbeat = tokio::time::interval(Duration::from_secs(30));
     // First tick fires immediately; skip it so we don't ping before any event.
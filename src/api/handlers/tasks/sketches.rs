@@ -26,7 +26,7 @@ fn scene_error_response(e: GroveError) -> (StatusCode, String) {
     match e {
         GroveError::Storage(msg) => (StatusCode::BAD_REQUEST, msg),
         other => {
-            eprintln!("[sketches] server-side scene error: {other}");
+            tracing::warn!("server-side scene error: {other}");
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "internal server error".to_string(),
@@ -0,0 +1,72 @@
+//! Per-turn worktree snapshot handlers (chat timeline diff / revert)
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::operations::snapshots::{self as ops, TurnDiff};
+use crate::storage::tasks;
+use crate::storage::turn_snapshots::{self, TurnSnapshot};
+
+use super::super::common::find_project_by_id;
+use super::types::GitOperationResponse;
+
+fn find_worktree(project_key: &str, task_id: &str) -> Result<String, StatusCode> {
+    tasks::get_task(project_key, task_id)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(|t| t.worktree_path)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/snapshots
+pub async fn list_turn_snapshots(
+    Path((id, task_id, chat_id)): Path<(String, String, String)>,
+) -> Result<Json<Vec<TurnSnapshot>>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+    Ok(Json(turn_snapshots::load_snapshots(
+        &project_key,
+        &task_id,
+        &chat_id,
+    )))
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/snapshots/{turnTs}/diff
+pub async fn get_turn_diff(
+    Path((id, task_id, chat_id, turn_ts)): Path<(String, String, String, i64)>,
+) -> Result<Json<TurnDiff>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+    let worktree = find_worktree(&project_key, &task_id)?;
+    let snapshot = turn_snapshots::find_snapshot(&project_key, &task_id, &chat_id, turn_ts)
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    tokio::task::spawn_blocking(move || ops::turn_diff(&worktree, &snapshot))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(Json)
+        .map_err(|_| StatusCode::CONFLICT)
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/snapshots/{turnTs}/revert
+pub async fn revert_turn(
+    Path((id, task_id, chat_id, turn_ts)): Path<(String, String, String, i64)>,
+) -> Result<Json<GitOperationResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+    let worktree = find_worktree(&project_key, &task_id)?;
+    let snapshot = turn_snapshots::find_snapshot(&project_key, &task_id, &chat_id, turn_ts)
+        .ok_or(StatusCode::NOT_FOUND)?;
+
+    let result = tokio::task::spawn_blocking(move || ops::revert_turn(&worktree, &snapshot))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(match result {
+        Ok(()) => GitOperationResponse {
+            success: true,
+            message: "Reverted the turn's changes".to_string(),
+            warning: None,
+        },
+        Err(e) => GitOperationResponse {
+            success: false,
+            message: e.to_string(),
+            warning: None,
+        },
+    }))
+}
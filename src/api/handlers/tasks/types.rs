@@ -3,11 +3,63 @@
 use serde::{Deserialize, Serialize};
 
 use super::super::projects::TaskResponse;
+use crate::diff::test_changes::TestChanges;
+use crate::storage::merge_gate::MergeBlocker;
+use crate::storage::task_views::{TaskSort, TaskView, TaskViewFilter};
+use crate::storage::tasks::PullRequest;
 
 /// Task list query parameters
 #[derive(Debug, Deserialize)]
 pub struct TaskListQuery {
     pub filter: Option<String>, // "active" | "archived"
+    /// "activity" | "diff_size" | "status"; overrides the view's sort
+    pub sort: Option<String>,
+    /// Name of a saved task view to filter (and sort) by
+    pub view: Option<String>,
+}
+
+/// Cross-project task list query parameters (`GET /tasks?all=true`)
+#[derive(Debug, Deserialize)]
+pub struct AllTasksQuery {
+    /// Must be true; reserved so `/tasks` can grow other scopes later
+    #[serde(default)]
+    pub all: bool,
+    /// Comma-separated statuses, e.g. "live,idle"
+    pub status: Option<String>,
+    /// Only tasks whose terminal agent is busy
+    #[serde(default)]
+    pub agent_busy: bool,
+    /// Only tasks with merge conflicts
+    #[serde(default)]
+    pub has_conflicts: bool,
+}
+
+/// A task joined with the project it belongs to
+#[derive(Debug, Serialize)]
+pub struct ProjectTaskResponse {
+    pub project_id: String,
+    pub project_name: String,
+    pub project_type: String,
+    #[serde(flatten)]
+    pub task: TaskResponse,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AllTasksResponse {
+    pub tasks: Vec<ProjectTaskResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskViewListResponse {
+    pub views: Vec<TaskView>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SaveTaskViewRequest {
+    #[serde(default)]
+    pub sort: TaskSort,
+    #[serde(default)]
+    pub filter: TaskViewFilter,
 }
 
 #[derive(Debug, Deserialize)]
@@ -86,6 +138,40 @@ pub struct CreateTaskRequest {
     pub target: Option<String>,
     #[serde(default)]
     pub notes: Option<String>,
+    /// Path scope for monorepos (e.g. `services/api`)
+    #[serde(default)]
+    pub scope: Option<String>,
+    /// Only check these paths out in the worktree (sparse checkout); empty = full
+    #[serde(default)]
+    pub sparse_paths: Vec<String>,
+    /// Task template to create from (target, notes, agent, env, autolink)
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+/// Worktree not yet tracked as a task
+#[derive(Debug, Serialize)]
+pub struct UnmanagedWorktreeResponse {
+    pub path: String,
+    pub branch: String,
+    pub suggested_name: String,
+    pub suggested_target: String,
+}
+
+/// Unmanaged worktrees response
+#[derive(Debug, Serialize)]
+pub struct UnmanagedWorktreesResponse {
+    pub worktrees: Vec<UnmanagedWorktreeResponse>,
+}
+
+/// Adopt worktree request; name/target default to the suggestions
+#[derive(Debug, Deserialize)]
+pub struct AdoptWorktreeRequest {
+    pub path: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub target: Option<String>,
 }
 
 /// Rename task request
@@ -94,6 +180,40 @@ pub struct RenameTaskRequest {
     pub name: String,
 }
 
+/// Handback request; `null` clears the assignee
+#[derive(Debug, Deserialize)]
+pub struct HandbackRequest {
+    pub assignee: Option<crate::storage::tasks::TaskAssignee>,
+    /// Included in the agent prompt / human notification
+    pub message: Option<String>,
+}
+
+/// Values for the task's copy-to-clipboard quick actions
+#[derive(Debug, Serialize)]
+pub struct TaskCopyTargetsResponse {
+    pub branch: String,
+    /// Worktree path
+    pub path: String,
+    /// `tmux attach -t …` / `zellij attach …`; omitted for ACP tasks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attach_command: Option<String>,
+}
+
+/// Open-with request (`name` of a configured `open_with` application)
+#[derive(Debug, Deserialize)]
+pub struct OpenWithRequest {
+    pub name: String,
+}
+
+/// Handback response
+#[derive(Debug, Serialize)]
+pub struct HandbackResponse {
+    #[serde(flatten)]
+    pub task: TaskResponse,
+    /// Whether the handback prompt reached a live agent chat
+    pub delivered: bool,
+}
+
 /// Notes response
 #[derive(Debug, Serialize)]
 pub struct NotesResponse {
@@ -106,18 +226,137 @@ pub struct UpdateNotesRequest {
     pub content: String,
 }
 
+/// Notes revision list (newest first)
+#[derive(Debug, Serialize)]
+pub struct NoteRevisionsResponse {
+    pub revisions: Vec<crate::storage::notes::NoteRevision>,
+}
+
+/// A single notes revision with its content
+#[derive(Debug, Serialize)]
+pub struct NoteRevisionResponse {
+    pub revision: crate::storage::notes::NoteRevision,
+    pub content: String,
+}
+
+/// Query for diffing a notes revision
+#[derive(Debug, Deserialize)]
+pub struct NoteRevisionDiffQuery {
+    /// Revision to compare against; defaults to the current notes
+    pub against: Option<String>,
+}
+
+/// Unified diff between a notes revision and another revision (or current)
+#[derive(Debug, Serialize)]
+pub struct NoteRevisionDiffResponse {
+    pub diff: String,
+}
+
 /// Commit request
 #[derive(Debug, Deserialize)]
 pub struct CommitRequest {
     pub message: String,
 }
 
+/// Stage / unstage request
+#[derive(Debug, Deserialize)]
+pub struct StageRequest {
+    pub path: String,
+    /// `@@` header lines of the hunks to move; omitted = the whole file
+    #[serde(default)]
+    pub hunks: Option<Vec<String>>,
+}
+
+/// Resolve one conflicted file of a stopped rebase
+#[derive(Debug, Deserialize)]
+pub struct ResolveConflictRequest {
+    pub path: String,
+    /// `{"resolution": "ours" | "theirs"}` or
+    /// `{"resolution": "manual", "content": "…"}`
+    #[serde(flatten)]
+    pub resolution: crate::operations::conflicts::Resolution,
+}
+
+/// Checkpoint settings + trailing checkpoint commits
+#[derive(Debug, Serialize)]
+pub struct CheckpointsResponse {
+    pub enabled: bool,
+    pub interval_mins: u32,
+    /// Trailing `grove-checkpoint:` commits on the task branch, newest first
+    pub checkpoints: Vec<crate::operations::checkpoints::CheckpointCommit>,
+}
+
+/// Checkpoint settings update (omitted fields unchanged)
+#[derive(Debug, Deserialize)]
+pub struct UpdateCheckpointsRequest {
+    pub enabled: Option<bool>,
+    pub interval_mins: Option<u32>,
+}
+
+/// Squash checkpoints request
+#[derive(Debug, Deserialize)]
+pub struct SquashCheckpointsRequest {
+    pub message: String,
+}
+
+/// AutoLinks of a task worktree
+#[derive(Debug, Serialize)]
+pub struct TaskLinksResponse {
+    pub links: Vec<crate::operations::autolink::TaskLink>,
+}
+
+/// Result of a re-sync or cleanup, with the updated link list
+#[derive(Debug, Serialize)]
+pub struct TaskLinksChangeResponse {
+    /// Links created (re-sync), refreshed, or removed (cleanup)
+    pub changed: Vec<String>,
+    pub links: Vec<crate::operations::autolink::TaskLink>,
+}
+
+/// Link removal query
+#[derive(Debug, Deserialize)]
+pub struct TaskLinkQuery {
+    /// Path relative to the worktree root
+    pub path: String,
+}
+
 /// Merge request
 #[derive(Debug, Deserialize)]
 pub struct MergeRequest {
     /// Merge method: "squash" or "merge-commit" (default: auto-select based on commit count)
     #[serde(default)]
     pub method: Option<String>,
+    /// Append a review summary (comments, reviewers, verdicts) to the commit message
+    #[serde(default)]
+    pub include_review: bool,
+}
+
+/// Merge response: a `GitOperationResponse` plus the merge gate blockers
+/// when the merge was refused by the project's gate
+#[derive(Debug, Serialize)]
+pub struct MergeResponse {
+    pub success: bool,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blockers: Vec<MergeBlocker>,
+}
+
+/// Pull request response: the task's GitHub pull request (if any) plus a
+/// status message
+#[derive(Debug, Serialize)]
+pub struct PullRequestResponse {
+    pub success: bool,
+    pub message: String,
+    pub pull_request: Option<PullRequest>,
+}
+
+/// Merge gate status of a task
+#[derive(Debug, Serialize)]
+pub struct MergeGateStatus {
+    pub enabled: bool,
+    pub blockers: Vec<MergeBlocker>,
 }
 
 /// Rebase-to request (change target branch)
@@ -126,6 +365,12 @@ pub struct RebaseToRequest {
     pub target: String,
 }
 
+/// Target branch changes of a task, oldest first
+#[derive(Debug, Serialize)]
+pub struct TargetHistoryResponse {
+    pub changes: Vec<crate::storage::tasks::TargetChange>,
+}
+
 /// Git operation response
 #[derive(Debug, Serialize)]
 pub struct GitOperationResponse {
@@ -135,6 +380,16 @@ pub struct GitOperationResponse {
     pub warning: Option<String>,
 }
 
+/// Sync response: a `GitOperationResponse` plus the files the rebase stopped
+/// on, left for the conflict view to resolve
+#[derive(Debug, Serialize)]
+pub struct SyncResponse {
+    pub success: bool,
+    pub message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<String>,
+}
+
 /// Diff file status
 #[derive(Debug, Clone, Serialize)]
 pub enum DiffStatus {
@@ -158,6 +413,12 @@ pub struct DiffFileEntry {
     pub additions: u32,
     pub deletions: u32,
     pub is_binary: bool,
+    /// Generated / vendored file (gitattributes or project collapse globs):
+    /// collapsed by default in review, still counted in totals
+    pub collapsed: bool,
+    /// CODEOWNERS owners of the file
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
 }
 
 /// Diff response
@@ -166,6 +427,41 @@ pub struct DiffResponse {
     pub files: Vec<DiffFileEntry>,
     pub total_additions: u32,
     pub total_deletions: u32,
+    /// Task path scope the file list was limited to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    /// Changed files hidden because they are outside the scope
+    #[serde(skip_serializing_if = "is_zero")]
+    pub outside_scope: u32,
+    /// Source vs test files among the changes ("tests missing" warning)
+    pub tests: TestChanges,
+}
+
+fn is_zero(n: &u32) -> bool {
+    *n == 0
+}
+
+/// Whitespace / rename options shared by the diff endpoints (extracted as a
+/// second `Query`). Unset fields fall back to the user's review preferences.
+#[derive(Debug, Default, Deserialize)]
+pub struct DiffOptionsQuery {
+    pub ignore_whitespace: Option<bool>,
+    pub ignore_blank_lines: Option<bool>,
+    pub detect_renames: Option<bool>,
+    pub detect_copies: Option<bool>,
+}
+
+impl DiffOptionsQuery {
+    /// Merge with the saved review preferences
+    pub fn resolve(&self) -> crate::git::DiffOptions {
+        let saved = crate::storage::config::load_config().review.diff_options();
+        crate::git::DiffOptions {
+            ignore_whitespace: self.ignore_whitespace.unwrap_or(saved.ignore_whitespace),
+            ignore_blank_lines: self.ignore_blank_lines.unwrap_or(saved.ignore_blank_lines),
+            detect_renames: self.detect_renames.unwrap_or(saved.detect_renames),
+            detect_copies: self.detect_copies.unwrap_or(saved.detect_copies),
+        }
+    }
 }
 
 /// Diff query parameters
@@ -175,6 +471,26 @@ pub struct DiffQuery {
     pub from_ref: Option<String>,
     /// End ref (commit hash); omit for working tree comparison
     pub to_ref: Option<String>,
+    /// Ignore the task's path scope and diff the whole tree
+    #[serde(default)]
+    pub full: bool,
+}
+
+/// A ref the diff can be compared against
+#[derive(Debug, Serialize)]
+pub struct DiffRefEntry {
+    /// Ref name to pass as `from_ref` / `to_ref`
+    pub name: String,
+    /// "task", "branch", "tag" or "remote"
+    pub kind: String,
+    /// Display label (task name for task branches)
+    pub label: String,
+}
+
+/// Diff refs response
+#[derive(Debug, Serialize)]
+pub struct DiffRefsResponse {
+    pub refs: Vec<DiffRefEntry>,
 }
 
 /// Single file diff query parameters
@@ -191,6 +507,14 @@ pub struct CommitEntry {
     pub hash: String,
     pub message: String,
     pub time_ago: String,
+    /// Commit time (RFC 3339)
+    pub time: String,
+    /// Signature verification status (unsigned commits omit it)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<crate::git::SignatureStatus>,
+    /// Task / chat from the commit's Grove trailers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<crate::api::handlers::common::CommitLinks>,
 }
 
 /// Commits response
@@ -205,6 +529,66 @@ pub struct CommitsResponse {
     pub skip_versions: u32,
 }
 
+/// Worktree content search query parameters
+#[derive(Debug, Deserialize)]
+pub struct TaskSearchQuery {
+    pub q: String,
+    /// Treat `q` as a regular expression
+    #[serde(default)]
+    pub regex: bool,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// Context lines before/after each match (default 2, max 10)
+    pub context: Option<usize>,
+    /// Max matches (default 200, max 1000)
+    pub limit: Option<usize>,
+    /// Only search paths matching this glob
+    pub glob: Option<String>,
+}
+
+/// File history query parameters
+#[derive(Debug, Deserialize)]
+pub struct FileHistoryQuery {
+    pub path: String,
+    /// Also include commits from the target branch history
+    #[serde(default)]
+    pub include_target: bool,
+    /// Max commits to return (default 100)
+    pub limit: Option<usize>,
+}
+
+/// One commit in a file's history
+#[derive(Debug, Serialize)]
+pub struct FileHistoryEntry {
+    pub hash: String,
+    pub author: String,
+    /// Commit time (Unix seconds)
+    pub timestamp: i64,
+    pub time_ago: String,
+    /// Commit time (RFC 3339)
+    pub time: String,
+    pub message: String,
+    /// Path of the file in this commit (differs from the query after a rename)
+    pub path: String,
+    /// Commit belongs to the task branch (`target..HEAD`)
+    pub on_branch: bool,
+}
+
+/// File history response
+#[derive(Debug, Serialize)]
+pub struct FileHistoryResponse {
+    pub path: String,
+    pub commits: Vec<FileHistoryEntry>,
+}
+
+/// Patch of a single file in one commit
+#[derive(Debug, Serialize)]
+pub struct FilePatchResponse {
+    pub commit: String,
+    pub path: String,
+    pub patch: String,
+}
+
 /// Review comment reply entry
 #[derive(Debug, Serialize)]
 pub struct ReviewCommentReplyEntry {
@@ -214,6 +598,8 @@ pub struct ReviewCommentReplyEntry {
     pub model: String,
     pub role: String,
     pub timestamp: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<ReviewReactionEntry>,
 }
 
 /// Review comment entry
@@ -237,6 +623,30 @@ pub struct ReviewCommentEntry {
     pub timestamp: String,
     pub status: String,
     pub replies: Vec<ReviewCommentReplyEntry>,
+    /// Pending comment in the caller's unsubmitted review (hidden from agents)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub draft: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<ReviewReactionEntry>,
+}
+
+/// Emoji reaction with the authors who left it
+#[derive(Debug, Serialize)]
+pub struct ReviewReactionEntry {
+    pub emoji: String,
+    pub authors: Vec<String>,
+}
+
+/// Last submitted review batch
+#[derive(Debug, Serialize)]
+pub struct ReviewSubmissionEntry {
+    pub id: u32,
+    pub reviewer: String,
+    /// "approve" | "request_changes" | "comment"
+    pub verdict: String,
+    pub summary: String,
+    pub comment_ids: Vec<u32>,
+    pub timestamp: String,
 }
 
 /// Review comments response
@@ -246,10 +656,24 @@ pub struct ReviewCommentsResponse {
     pub open_count: u32,
     pub resolved_count: u32,
     pub outdated_count: u32,
+    /// Number of the caller's pending draft comments
+    pub draft_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_review: Option<ReviewSubmissionEntry>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub git_user_name: Option<String>,
 }
 
+/// Submit review response
+#[derive(Debug, Serialize)]
+pub struct SubmitReviewResponse {
+    /// Chat that received the aggregated review prompt, if any session was live
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered_chat_id: Option<String>,
+    #[serde(flatten)]
+    pub review: ReviewCommentsResponse,
+}
+
 /// File metadata
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FileMetadata {
@@ -264,6 +688,31 @@ pub struct FilesResponse {
     pub files: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Vec<FileMetadata>>,
+    /// Task path scope the listing was limited to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+}
+
+/// `?full=true` lifts the task's path scope
+#[derive(Debug, Default, Deserialize)]
+pub struct ScopeQuery {
+    #[serde(default)]
+    pub full: bool,
+}
+
+/// Task path scope (GET response / PUT body); null or "" clears it
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskScopeDto {
+    #[serde(default)]
+    pub scope: Option<String>,
+}
+
+/// Sparse checkout paths of a task worktree (GET response / PUT body);
+/// an empty list means the whole repository is checked out
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TaskSparseDto {
+    #[serde(default)]
+    pub paths: Vec<String>,
 }
 
 /// File content response
@@ -271,12 +720,61 @@ pub struct FilesResponse {
 pub struct FileContentResponse {
     pub content: String,
     pub path: String,
+    /// Content hash to send back as `If-Match` when saving
+    pub hash: String,
 }
 
 /// Write file request
 #[derive(Debug, Deserialize)]
 pub struct WriteFileRequest {
     pub content: String,
+    /// Content the editor originally loaded; enables a three-way merge on conflict
+    #[serde(default)]
+    pub base: Option<String>,
+}
+
+/// Conflict payload (409) when a save is rejected because the file changed on disk
+#[derive(Debug, Serialize)]
+pub struct FileConflictResponse {
+    pub error: String,
+    pub path: String,
+    /// Hash of the content currently on disk (use as `If-Match` to overwrite)
+    pub current_hash: String,
+    pub base: Option<String>,
+    /// Content the client tried to save
+    pub ours: String,
+    /// Content currently on disk
+    pub theirs: String,
+    /// Three-way merge result (with conflict markers when `has_conflicts`)
+    pub merged: Option<String>,
+    pub has_conflicts: bool,
+}
+
+/// Upload target query parameters
+#[derive(Debug, Deserialize)]
+pub struct UploadFilesQuery {
+    /// Worktree-relative directory to upload into (default: worktree root)
+    #[serde(default)]
+    pub dir: String,
+    /// Replace files that already exist
+    #[serde(default)]
+    pub overwrite: bool,
+}
+
+/// One uploaded file
+#[derive(Debug, Serialize)]
+pub struct FsUploadedFile {
+    /// Worktree-relative path
+    pub path: String,
+    pub size: u64,
+    /// MIME type detected from the file extension
+    pub mime: String,
+}
+
+/// Upload response
+#[derive(Debug, Serialize)]
+pub struct FsUploadResponse {
+    pub files: Vec<FsUploadedFile>,
 }
 
 /// File path query parameter
@@ -332,6 +830,29 @@ pub struct CreateReviewCommentRequest {
     pub start_line: Option<u32>,
     pub end_line: Option<u32>,
     pub author: Option<String>,
+    /// Add to the author's pending review instead of publishing immediately
+    pub draft: Option<bool>,
+}
+
+/// Submit pending review request
+#[derive(Debug, Deserialize)]
+pub struct SubmitReviewRequest {
+    /// "approve" | "request_changes" | "comment"
+    pub verdict: String,
+    pub summary: Option<String>,
+    pub author: Option<String>,
+    /// Chat to notify; defaults to the newest chat with a live session
+    pub chat_id: Option<String>,
+}
+
+/// Toggle reaction request
+#[derive(Debug, Deserialize)]
+pub struct ToggleReactionRequest {
+    /// One of 👍 👀 🚀
+    pub emoji: String,
+    /// React to a reply instead of the comment itself
+    pub reply_id: Option<u32>,
+    pub author: Option<String>,
 }
 
 /// Create file request
@@ -368,6 +889,71 @@ pub struct MoveFileRequest {
     pub destination: String,
 }
 
+/// Batch delete request
+#[derive(Debug, Deserialize)]
+pub struct BatchDeleteRequest {
+    pub paths: Vec<String>,
+}
+
+/// Batch move/rename request (applied in order)
+#[derive(Debug, Deserialize)]
+pub struct BatchMoveRequest {
+    pub moves: Vec<MoveFileRequest>,
+}
+
+/// Batch copy request
+#[derive(Debug, Deserialize)]
+pub struct BatchCopyRequest {
+    pub copies: Vec<CopyFileRequest>,
+}
+
+/// Outcome of one item of a batch file system operation
+#[derive(Debug, Serialize)]
+pub struct FsBatchItemResult {
+    /// Path the item was addressed by (the source for move/copy)
+    pub path: String,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl FsBatchItemResult {
+    pub fn new<T>(
+        path: String,
+        result: Result<
+            T,
+            (
+                axum::http::StatusCode,
+                axum::Json<crate::api::error::ApiError>,
+            ),
+        >,
+    ) -> Self {
+        let error = result.err().map(|(_, body)| body.0.error);
+        Self {
+            path,
+            success: error.is_none(),
+            error,
+        }
+    }
+}
+
+/// Batch file system operation response
+#[derive(Debug, Serialize)]
+pub struct FsBatchResponse {
+    /// True when every item succeeded
+    pub success: bool,
+    pub results: Vec<FsBatchItemResult>,
+}
+
+impl FsBatchResponse {
+    pub fn new(results: Vec<FsBatchItemResult>) -> Self {
+        Self {
+            success: results.iter().all(|r| r.success),
+            results,
+        }
+    }
+}
+
 /// File system operation response
 #[derive(Debug, Serialize)]
 pub struct FsOperationResponse {
@@ -0,0 +1,56 @@
+//! Saved task view handlers (named filter + sort presets, per project)
+
+use axum::{extract::Path, http::StatusCode, Json};
+
+use crate::storage::task_views::{self, TaskView};
+
+use super::super::common::find_project_by_id;
+use super::types::*;
+
+/// GET /api/v1/projects/{id}/task-views
+pub async fn list_task_views(
+    Path(id): Path<String>,
+) -> Result<Json<TaskViewListResponse>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    let views =
+        task_views::load_views(&project_key).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(TaskViewListResponse { views }))
+}
+
+/// PUT /api/v1/projects/{id}/task-views/{name}
+///
+/// Creates the view or replaces the sort/filter of an existing one.
+pub async fn save_task_view(
+    Path((id, name)): Path<(String, String)>,
+    Json(req): Json<SaveTaskViewRequest>,
+) -> Result<Json<TaskView>, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    let view = TaskView {
+        name,
+        sort: req.sort,
+        filter: req.filter,
+    };
+    task_views::save_view(&project_key, &view).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(view))
+}
+
+/// DELETE /api/v1/projects/{id}/task-views/{name}
+pub async fn delete_task_view(
+    Path((id, name)): Path<(String, String)>,
+) -> Result<StatusCode, StatusCode> {
+    let (_project, project_key) = find_project_by_id(&id)?;
+
+    match task_views::delete_view(&project_key, &name) {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err(StatusCode::NOT_FOUND),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
@@ -1,5 +1,6 @@
 //! Terminal WebSocket handler for Grove Web
 
+use axum::Json;
 use axum::{
     extract::{
         ws::{Message, WebSocket, WebSocketUpgrade},
@@ -8,16 +9,17 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use chrono::{DateTime, Utc};
 use futures::{SinkExt, StreamExt};
 use portable_pty::{native_pty_system, CommandBuilder, PtySize};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::io::{Read, Write};
 use std::sync::Arc;
 use tokio::sync::mpsc;
 
 use crate::api::state;
 use crate::operations::tasks::create_task_session;
-use crate::session::SessionType;
+use crate::session::{attach_log, SessionType};
 use crate::storage::{config, tasks, workspace};
 
 #[derive(Debug, Deserialize)]
@@ -76,7 +78,11 @@ pub async fn task_terminal_handler(
         // Direct mode: spawn a plain shell in the task's worktree (no multiplexer)
         state::ensure_task_active(&project_key, &task.id, &task.worktree_path);
 
-        Ok(ws.on_upgrade(move |socket| handle_shell_terminal(socket, working_dir, cols, rows)))
+        Ok(ws.on_upgrade(move |socket| async move {
+            let attached_at = Utc::now();
+            handle_shell_terminal(socket, working_dir, cols, rows).await;
+            record_attach(&project_key, &task.id, attached_at);
+        }))
     } else {
         // Multiplexer mode: use shared create_task_session
         let session_info = create_task_session(&project_key, &task, &project.path)
@@ -84,7 +90,8 @@ pub async fn task_terminal_handler(
 
         state::ensure_task_active(&project_key, &task.id, &task.worktree_path);
 
-        Ok(ws.on_upgrade(move |socket| {
+        Ok(ws.on_upgrade(move |socket| async move {
+            let attached_at = Utc::now();
             handle_mux_terminal(
                 socket,
                 MuxTerminalParams {
@@ -97,13 +104,163 @@ pub async fn task_terminal_handler(
                     rows,
                 },
             )
+            .await;
+            record_attach(&project_key, &task.id, attached_at);
         }))
     }
 }
 
+/// Log how long a task terminal stayed connected (task time tracking)
+fn record_attach(project_key: &str, task_id: &str, attached_at: DateTime<Utc>) {
+    if let Err(e) = attach_log::record_attach(project_key, task_id, attached_at, Utc::now()) {
+        tracing::warn!("failed to record terminal attach for {}: {}", task_id, e);
+        return;
+    }
+    let project_key = project_key.to_string();
+    tokio::task::spawn_blocking(move || {
+        let _ = crate::operations::time_tracking::refresh_rollup(&project_key);
+    });
+}
+
+/// Default / max number of scrollback lines returned by the screen endpoint
+const SCREEN_DEFAULT_LINES: usize = 200;
+const SCREEN_MAX_LINES: usize = 5000;
+
+#[derive(Debug, Deserialize)]
+pub struct TerminalScreenQuery {
+    /// Scrollback lines to include (default: 200, max: 5000)
+    pub lines: Option<usize>,
+    /// Pane index within the session (default: 0, the agent pane in built-in layouts)
+    pub pane: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TerminalScreenResponse {
+    pub session_name: String,
+    /// false when the tmux session is not running; `content` is empty then
+    pub running: bool,
+    pub pane_id: Option<String>,
+    pub pane_count: usize,
+    /// Captured buffer with ANSI escape sequences preserved
+    pub content: String,
+}
+
+/// GET /api/v1/projects/{id}/tasks/{taskId}/terminal/screen
+///
+/// Snapshot of what is on the task's tmux pane (`tmux capture-pane -p -e`),
+/// for a read-only view without attaching an interactive terminal.
+/// Never creates the session.
+pub async fn task_terminal_screen(
+    Path((project_id, task_id)): Path<(String, String)>,
+    Query(query): Query<TerminalScreenQuery>,
+) -> Result<Json<TerminalScreenResponse>, TaskTerminalError> {
+    let lines = query
+        .lines
+        .unwrap_or(SCREEN_DEFAULT_LINES)
+        .clamp(1, SCREEN_MAX_LINES);
+    let pane_index = query.pane.unwrap_or(0);
+
+    tokio::task::spawn_blocking(move || {
+        let project_key = workspace::load_projects()
+            .map_err(|e| TaskTerminalError::Internal(format!("Failed to load projects: {}", e)))?
+            .iter()
+            .map(|p| workspace::project_hash(&p.path))
+            .find(|key| *key == project_id)
+            .ok_or(TaskTerminalError::NotFound("Project not found".to_string()))?;
+
+        let task = tasks::get_task(&project_key, &task_id)
+            .map_err(|e| TaskTerminalError::Internal(format!("Failed to get task: {}", e)))?
+            .ok_or(TaskTerminalError::NotFound("Task not found".to_string()))?;
+
+        if crate::session::resolve_session_type(&task.multiplexer) != SessionType::Tmux {
+            return Err(TaskTerminalError::BadRequest(
+                "Screen capture is only available for tmux tasks".to_string(),
+            ));
+        }
+
+        let session_name =
+            crate::session::resolve_session_name(&task.session_name, &project_key, &task.id);
+        if !crate::tmux::session_exists(&session_name) {
+            return Ok(Json(TerminalScreenResponse {
+                session_name,
+                running: false,
+                pane_id: None,
+                pane_count: 0,
+                content: String::new(),
+            }));
+        }
+
+        let panes = crate::tmux::layout::list_pane_ids(&session_name)
+            .map_err(|e| TaskTerminalError::Internal(e.to_string()))?;
+        let pane_id = panes
+            .get(pane_index)
+            .cloned()
+            .ok_or(TaskTerminalError::NotFound("Pane not found".to_string()))?;
+        let content = crate::tmux::capture_pane(&pane_id, lines, true)
+            .map_err(|e| TaskTerminalError::Internal(e.to_string()))?;
+
+        Ok(Json(TerminalScreenResponse {
+            session_name,
+            running: true,
+            pane_id: Some(pane_id),
+            pane_count: panes.len(),
+            content,
+        }))
+    })
+    .await
+    .map_err(|e| TaskTerminalError::Internal(e.to_string()))?
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SessionKeysRequest {
+    /// Text typed literally into the pane
+    pub text: Option<String>,
+    /// Named keys sent after `text` (tmux key names: `Enter`, `Escape`, `C-c`, `Up`, ...)
+    #[serde(default)]
+    pub keys: Vec<String>,
+    /// Press Enter at the end (turns `text` into a submitted command line)
+    #[serde(default)]
+    pub enter: bool,
+    /// tmux pane index (default: 0, the agent pane); ignored for zellij
+    pub pane: Option<usize>,
+}
+
+/// POST /api/v1/projects/{id}/tasks/{taskId}/session/keys
+///
+/// Send keystrokes or a command line into the task's running tmux / zellij session.
+pub async fn send_session_keys(
+    Path((project_id, task_id)): Path<(String, String)>,
+    Json(req): Json<SessionKeysRequest>,
+) -> Result<StatusCode, TaskTerminalError> {
+    tokio::task::spawn_blocking(move || {
+        let project_key = workspace::load_projects()
+            .map_err(|e| TaskTerminalError::Internal(format!("Failed to load projects: {}", e)))?
+            .iter()
+            .map(|p| workspace::project_hash(&p.path))
+            .find(|key| *key == project_id)
+            .ok_or(TaskTerminalError::NotFound("Project not found".to_string()))?;
+
+        let task = tasks::get_task(&project_key, &task_id)
+            .map_err(|e| TaskTerminalError::Internal(format!("Failed to get task: {}", e)))?
+            .ok_or(TaskTerminalError::NotFound("Task not found".to_string()))?;
+
+        let input = crate::session::SessionInput {
+            text: req.text,
+            keys: req.keys,
+            enter: req.enter,
+        };
+        crate::operations::tasks::send_task_input(&project_key, &task, req.pane, &input)
+            .map_err(|e| TaskTerminalError::BadRequest(e.to_string()))?;
+        Ok(StatusCode::NO_CONTENT)
+    })
+    .await
+    .map_err(|e| TaskTerminalError::Internal(e.to_string()))?
+}
+
 /// Error type for task terminal handler
 pub enum TaskTerminalError {
     NotFound(String),
+    BadRequest(String),
     Internal(String),
 }
 
@@ -111,6 +268,7 @@ impl IntoResponse for TaskTerminalError {
     fn into_response(self) -> Response {
         match self {
             TaskTerminalError::NotFound(msg) => (StatusCode::NOT_FOUND, msg).into_response(),
+            TaskTerminalError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg).into_response(),
             TaskTerminalError::Internal(msg) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, msg).into_response()
             }
@@ -191,6 +349,7 @@ pub(crate) fn apply_terminal_env_defaults(cmd: &mut CommandBuilder) {
 
 /// Handle the WebSocket connection for a simple shell terminal
 async fn handle_shell_terminal(socket: WebSocket, cwd: String, cols: u16, rows: u16) {
+    let _conn = crate::metrics::ws_connection("terminal");
     let (shell, args) = pick_default_shell();
 
     let mut cmd = CommandBuilder::new(&shell);
@@ -216,6 +375,7 @@ struct MuxTerminalParams {
 
 /// Handle the WebSocket connection for a multiplexer session terminal
 async fn handle_mux_terminal(socket: WebSocket, params: MuxTerminalParams) {
+    let _conn = crate::metrics::ws_connection("terminal");
     let MuxTerminalParams {
         session_name,
         mux,
@@ -264,7 +424,7 @@ async fn handle_mux_terminal(socket: WebSocket, params: MuxTerminalParams) {
             handle_pty_terminal(socket, cmd, cols, rows).await;
         }
         SessionType::Acp => {
-            eprintln!("Warning: ACP task reached terminal handler — this should not happen");
+            tracing::warn!("ACP task reached terminal handler — this should not happen");
         }
     }
 }
@@ -357,11 +517,11 @@ pub(crate) async fn handle_pty_terminal(
     let (master, reader, writer, child) = match pty_result {
         Ok(Ok(result)) => result,
         Ok(Err(e)) => {
-            eprintln!("Failed to setup PTY: {}", e);
+            tracing::error!("Failed to setup PTY: {}", e);
             return;
         }
         Err(e) => {
-            eprintln!("Task failed: {}", e);
+            tracing::error!("Task failed: {}", e);
             return;
         }
     };
@@ -391,7 +551,7 @@ pub(crate) async fn handle_pty_terminal(
                     Ok(0) => break, // EOF
                     Ok(n) => n,
                     Err(e) => {
-                        eprintln!("PTY read error: {}", e);
+                        tracing::warn!("PTY read error: {}", e);
                         break;
                     }
                 }
@@ -461,16 +621,16 @@ pub(crate) async fn handle_pty_terminal(
     // Wait for any task to complete, detect panics
     tokio::select! {
         result = &mut pty_reader_task => {
-            if let Err(ref e) = result { if e.is_panic() { eprintln!("[Grove] PTY reader task panicked"); } }
+            if let Err(ref e) = result { if e.is_panic() { tracing::error!("PTY reader task panicked"); } }
         },
         result = &mut pty_to_ws => {
-            if let Err(ref e) = result { if e.is_panic() { eprintln!("[Grove] PTY-to-WS task panicked"); } }
+            if let Err(ref e) = result { if e.is_panic() { tracing::error!("PTY-to-WS task panicked"); } }
         },
         result = &mut pty_writer_task => {
-            if let Err(ref e) = result { if e.is_panic() { eprintln!("[Grove] PTY writer task panicked"); } }
+            if let Err(ref e) = result { if e.is_panic() { tracing::error!("PTY writer task panicked"); } }
         },
         result = &mut ws_to_pty => {
-            if let Err(ref e) = result { if e.is_panic() { eprintln!("[Grove] WS-to-PTY task panicked"); } }
+            if let Err(ref e) = result { if e.is_panic() { tracing::error!("WS-to-PTY task panicked"); } }
         },
     }
 
@@ -456,6 +456,7 @@ pub async fn ws_handler(ws: WebSocketUpgrade) -> Response {
 
 /// Inner WS handler, exposed so the Radio server can reuse it.
 pub async fn handle_walkie_talkie_ws_inner(socket: WebSocket) {
+    let _conn = crate::metrics::ws_connection("walkie_talkie");
     let (mut ws_tx, mut ws_rx) = socket.split();
 
     // Channel for sending messages back to the client from background tasks
@@ -1423,6 +1424,7 @@ pub async fn radio_events_ws_handler(ws: WebSocketUpgrade) -> Response {
 }
 
 async fn handle_radio_events_ws(socket: WebSocket) {
+    let _conn = crate::metrics::ws_connection("radio_events");
     let (mut ws_tx, mut ws_rx) = socket.split();
     let mut event_rx = RADIO_EVENTS.subscribe();
 
@@ -9,7 +9,9 @@ pub mod perf_middleware;
 #[cfg(feature = "perf-monitor")]
 pub mod perf_tracing;
 pub mod radio_server;
+pub mod read_only;
 pub mod state;
+pub mod systemd;
 pub mod tls;
 
 pub use state::{init_file_watchers, shutdown_file_watchers};
@@ -66,6 +68,7 @@ pub fn create_api_router() -> Router {
         // Config API
         .route("/config", get(handlers::config::get_config))
         .route("/config", patch(handlers::config::patch_config))
+        .route("/config/theme", get(handlers::config::get_tui_theme))
         .route(
             "/config/applications",
             get(handlers::config::list_applications),
@@ -95,6 +98,14 @@ pub fn create_api_router() -> Router {
             delete(handlers::keymap::remove_override),
         )
         .route("/keymap/disabled", put(handlers::keymap::set_disabled))
+        // Instruction preamble injected into new ACP sessions
+        .route(
+            "/preamble",
+            get(handlers::preamble::get_global_preamble)
+                .put(handlers::preamble::update_global_preamble),
+        )
+        // Recent log entries (in-memory ring buffer)
+        .route("/logs", get(handlers::logs::get_logs))
         // Environment API
         .route("/env/check", get(handlers::env::check_all))
         .route("/env/check/{name}", get(handlers::env::check_one))
@@ -138,6 +149,7 @@ pub fn create_api_router() -> Router {
     let v1 = v1
         // Diagram rendering API
         .route("/render/d2", post(handlers::render::render_d2))
+        .route("/render/markdown", post(handlers::render::render_markdown))
         // URL metadata (used by Add Link dialog)
         .route(
             "/url/metadata",
@@ -271,6 +283,14 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/terminal",
             get(handlers::terminal::task_terminal_handler),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/terminal/screen",
+            get(handlers::terminal::task_terminal_screen),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/session/keys",
+            post(handlers::terminal::send_session_keys),
+        )
         // Chat CRUD
         .route(
             "/projects/{id}/tasks/{taskId}/chats",
@@ -318,6 +338,35 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/chats/{chatId}/history",
             get(handlers::acp::get_chat_history),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/chats/{chatId}/export",
+            get(handlers::acp::export_chat_transcript),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/chats/{chatId}/timeline",
+            get(handlers::acp::get_chat_timeline),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/chats/{chatId}/pins",
+            get(handlers::acp::list_chat_pins).post(handlers::acp::add_chat_pin),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/chats/{chatId}/pins/{pinId}",
+            delete(handlers::acp::delete_chat_pin),
+        )
+        // Per-turn worktree snapshots (diff / revert a single turn)
+        .route(
+            "/projects/{id}/tasks/{taskId}/chats/{chatId}/snapshots",
+            get(handlers::tasks::list_turn_snapshots),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/chats/{chatId}/snapshots/{turnTs}/diff",
+            get(handlers::tasks::get_turn_diff),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/chats/{chatId}/snapshots/{turnTs}/revert",
+            post(handlers::tasks::revert_turn),
+        )
         // Take Control (kill remote session owner)
         .route(
             "/projects/{id}/tasks/{taskId}/chats/{chatId}/take-control",
@@ -331,6 +380,8 @@ pub fn create_api_router() -> Router {
         // Projects API
         .route("/projects", get(handlers::projects::list_projects))
         .route("/projects", post(handlers::projects::add_project))
+        // Cross-project task list
+        .route("/tasks", get(handlers::tasks::list_all_tasks))
         .route(
             "/projects/new",
             post(handlers::projects::create_new_project),
@@ -338,8 +389,37 @@ pub fn create_api_router() -> Router {
         .route("/projects/clone", post(handlers::projects::clone_project))
         .route("/projects/{id}", get(handlers::projects::get_project))
         .route("/projects/{id}", patch(handlers::projects::rename_project))
+        .route(
+            "/projects/{id}/default-target",
+            put(handlers::projects::set_default_target),
+        )
+        .route(
+            "/projects/{id}/commit-signing",
+            put(handlers::projects::set_commit_signing),
+        )
+        .route(
+            "/projects/{id}/commit-trailers",
+            put(handlers::projects::set_commit_trailers),
+        )
+        .route(
+            "/projects/{id}/git-identity",
+            get(handlers::projects::get_git_identity).put(handlers::projects::update_git_identity),
+        )
+        .route(
+            "/projects/{id}/pin",
+            put(handlers::projects::set_project_pinned),
+        )
         .route("/projects/{id}", delete(handlers::projects::delete_project))
         .route("/projects/{id}/stats", get(handlers::projects::get_stats))
+        .route(
+            "/projects/{id}/usage",
+            get(handlers::stats::get_project_usage),
+        )
+        .route(
+            "/projects/{id}/time",
+            get(handlers::stats::get_project_time),
+        )
+        .route("/projects/{id}/health", get(handlers::projects::get_health))
         // Unified read-only file API. Project, Resource and Task routes share
         // the same resolver, access policy and streaming response builder.
         .route(
@@ -408,6 +488,69 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/memory",
             get(handlers::projects::get_memory).put(handlers::projects::update_memory),
         )
+        .route(
+            "/projects/{id}/preamble",
+            get(handlers::projects::get_project_preamble)
+                .put(handlers::projects::update_project_preamble),
+        )
+        .route(
+            "/projects/{id}/chat-defaults",
+            get(handlers::projects::get_chat_defaults)
+                .put(handlers::projects::update_chat_defaults),
+        )
+        .route(
+            "/projects/{id}/diff-filters",
+            get(handlers::projects::get_diff_filters).put(handlers::projects::update_diff_filters),
+        )
+        .route(
+            "/projects/{id}/merge-gate",
+            get(handlers::projects::get_merge_gate).put(handlers::projects::update_merge_gate),
+        )
+        .route(
+            "/projects/{id}/bench",
+            get(handlers::projects::get_bench_settings)
+                .put(handlers::projects::update_bench_settings),
+        )
+        .route(
+            "/projects/{id}/file-guard",
+            get(handlers::projects::get_file_guard).put(handlers::projects::update_file_guard),
+        )
+        .route(
+            "/projects/{id}/security-scan",
+            get(handlers::projects::get_security_scan_settings)
+                .put(handlers::projects::update_security_scan_settings),
+        )
+        .route(
+            "/projects/{id}/forge",
+            get(handlers::projects::get_forge_settings)
+                .put(handlers::projects::update_forge_settings),
+        )
+        .route(
+            "/projects/{id}/templates",
+            get(handlers::projects::get_task_templates)
+                .put(handlers::projects::update_task_templates),
+        )
+        .route(
+            "/projects/{id}/lifecycle-hooks",
+            get(handlers::projects::get_lifecycle_hooks)
+                .put(handlers::projects::update_lifecycle_hooks),
+        )
+        .route(
+            "/projects/{id}/task-summary",
+            get(handlers::projects::get_task_summary_settings)
+                .put(handlers::projects::update_task_summary_settings),
+        )
+        .route(
+            "/projects/{id}/notes",
+            get(handlers::projects::list_project_notes)
+                .post(handlers::projects::create_project_note),
+        )
+        .route(
+            "/projects/{id}/notes/{slug}",
+            get(handlers::projects::get_project_note)
+                .put(handlers::projects::update_project_note)
+                .delete(handlers::projects::delete_project_note),
+        )
         .route(
             "/statistics/global",
             get(handlers::statistics::get_global_statistics),
@@ -442,6 +585,38 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}",
             get(handlers::tasks::get_task).patch(handlers::tasks::rename_task),
         )
+        .route(
+            "/projects/{id}/worktrees/unmanaged",
+            get(handlers::tasks::list_unmanaged_worktrees),
+        )
+        .route(
+            "/projects/{id}/worktrees/adopt",
+            post(handlers::tasks::adopt_worktree),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/assignee",
+            put(handlers::tasks::handback_task),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/pin",
+            put(handlers::tasks::set_task_pinned),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/copy-targets",
+            get(handlers::tasks::get_task_copy_targets),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/open-with",
+            post(handlers::tasks::open_task_with),
+        )
+        .route(
+            "/projects/{id}/task-views",
+            get(handlers::tasks::list_task_views),
+        )
+        .route(
+            "/projects/{id}/task-views/{name}",
+            put(handlers::tasks::save_task_view).delete(handlers::tasks::delete_task_view),
+        )
         .route(
             "/projects/{id}/tasks/{taskId}",
             delete(handlers::tasks::delete_task),
@@ -454,6 +629,10 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/symbols/lookup",
             get(handlers::symbols::lookup_symbol),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/symbols/outline",
+            get(handlers::symbols::get_outline),
+        )
         .route(
             "/projects/{id}/tasks/{taskId}/symbols/reindex",
             post(handlers::symbols::reindex_symbols),
@@ -503,6 +682,22 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/notes",
             get(handlers::tasks::get_notes).put(handlers::tasks::update_notes),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/notes/revisions",
+            get(handlers::tasks::list_note_revisions),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/notes/revisions/{revId}",
+            get(handlers::tasks::get_note_revision),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/notes/revisions/{revId}/diff",
+            get(handlers::tasks::diff_note_revision),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/notes/revisions/{revId}/restore",
+            post(handlers::tasks::restore_note_revision),
+        )
         // Sketches API
         .route(
             "/projects/{id}/tasks/{taskId}/sketches",
@@ -558,6 +753,10 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/merge",
             post(handlers::tasks::merge_task),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/pr",
+            get(handlers::tasks::get_pull_request).post(handlers::tasks::create_pull_request),
+        )
         .route(
             "/projects/{id}/tasks/{taskId}/reset",
             post(handlers::tasks::reset_task),
@@ -566,6 +765,99 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/rebase-to",
             post(handlers::tasks::rebase_to_task),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/target-history",
+            get(handlers::tasks::get_target_history),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/links",
+            get(handlers::tasks::list_task_links).delete(handlers::tasks::remove_task_link),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/links/resync",
+            post(handlers::tasks::resync_task_links),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/links/clean",
+            post(handlers::tasks::clean_task_links),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/links/refresh",
+            post(handlers::tasks::refresh_task_links),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/checkpoints",
+            get(handlers::tasks::get_checkpoints).put(handlers::tasks::update_checkpoints),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/checkpoints/squash",
+            post(handlers::tasks::squash_checkpoints),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/checkpoints/drop",
+            post(handlers::tasks::drop_checkpoints),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/git-identity",
+            get(handlers::tasks::get_task_git_identity)
+                .put(handlers::tasks::update_task_git_identity),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/scope",
+            get(handlers::tasks::get_task_scope).put(handlers::tasks::update_task_scope),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/sparse",
+            get(handlers::tasks::get_task_sparse).put(handlers::tasks::update_task_sparse),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/owners",
+            get(handlers::tasks::get_task_owners),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/merge-gate",
+            get(handlers::tasks::get_task_merge_blockers),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/risk",
+            get(handlers::tasks::get_task_risk),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/test-changes",
+            get(handlers::tasks::get_task_test_changes),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/bench",
+            get(handlers::tasks::get_task_bench).post(handlers::tasks::run_task_bench),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/file-guard",
+            get(handlers::tasks::get_task_file_guard),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/security-scan",
+            get(handlers::tasks::get_task_security_scan)
+                .post(handlers::tasks::run_task_security_scan),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/cross-review",
+            post(handlers::tasks::start_cross_review),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/chat-import",
+            get(handlers::tasks::list_native_sessions).post(handlers::tasks::import_native_session),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/coverage",
+            get(handlers::tasks::get_task_coverage)
+                .post(handlers::tasks::upload_task_coverage)
+                .delete(handlers::tasks::delete_task_coverage)
+                .layer(DefaultBodyLimit::max(64 * 1024 * 1024)),
+        )
+        .route(
+            "/projects/{id}/task-risks",
+            get(handlers::tasks::list_task_risks),
+        )
         // Task Files API
         .route(
             "/projects/{id}/tasks/{taskId}/files",
@@ -575,6 +867,18 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/file",
             get(handlers::tasks::get_file).put(handlers::tasks::update_file),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/search",
+            get(handlers::tasks::search_task_files),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/file/history",
+            get(handlers::tasks::get_file_history),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/file/history/{commit}",
+            get(handlers::tasks::get_file_history_patch),
+        )
         .route(
             "/projects/{id}/tasks/{taskId}/file/raw",
             get(handlers::tasks::get_file_raw),
@@ -650,6 +954,28 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/fs/move",
             post(handlers::tasks::move_file),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/fs/download",
+            get(handlers::tasks::download_file),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/fs/upload",
+            post(handlers::tasks::upload_files).layer(DefaultBodyLimit::max(
+                handlers::studio_common::MAX_UPLOAD_SIZE,
+            )),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/fs/batch-delete",
+            post(handlers::tasks::batch_delete_paths),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/fs/batch-move",
+            post(handlers::tasks::batch_move_paths),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/fs/batch-copy",
+            post(handlers::tasks::batch_copy_paths),
+        )
         .route(
             "/projects/{id}/tasks/{taskId}/fs/open",
             post(handlers::tasks::open_file),
@@ -659,6 +985,14 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/stats",
             get(handlers::stats::get_task_stats),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/usage",
+            get(handlers::stats::get_task_usage),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/chats/{chatId}/usage",
+            get(handlers::stats::get_chat_usage),
+        )
         // Diff/Changes API
         .route(
             "/projects/{id}/tasks/{taskId}/diff",
@@ -668,10 +1002,50 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/diff/file",
             get(handlers::tasks::get_single_file_diff),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/index",
+            get(handlers::tasks::get_index),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/index/stage",
+            post(handlers::tasks::stage_hunks),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/index/unstage",
+            post(handlers::tasks::unstage_hunks),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/conflicts",
+            get(handlers::tasks::get_conflicts),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/conflicts/resolve",
+            post(handlers::tasks::resolve_conflict),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/rebase/continue",
+            post(handlers::tasks::continue_rebase),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/rebase/abort",
+            post(handlers::tasks::abort_rebase),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/diff/refs",
+            get(handlers::tasks::get_diff_refs),
+        )
         .route(
             "/projects/{id}/tasks/{taskId}/commits",
             get(handlers::tasks::get_commits),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/shares",
+            get(handlers::share::list_shares).post(handlers::share::create_share),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/shares/{token}",
+            delete(handlers::share::revoke_share),
+        )
         // Review Comments API
         .route(
             "/projects/{id}/tasks/{taskId}/review",
@@ -693,10 +1067,22 @@ pub fn create_api_router() -> Router {
             "/projects/{id}/tasks/{taskId}/review/comments/{commentId}/content",
             put(handlers::tasks::edit_review_comment),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/review/comments/{commentId}/reactions",
+            post(handlers::tasks::toggle_review_reaction),
+        )
         .route(
             "/projects/{id}/tasks/{taskId}/review/comments/{commentId}/replies/{replyId}",
             put(handlers::tasks::edit_review_reply).delete(handlers::tasks::delete_review_reply),
         )
+        .route(
+            "/projects/{id}/tasks/{taskId}/review/submit",
+            post(handlers::tasks::submit_review),
+        )
+        .route(
+            "/projects/{id}/tasks/{taskId}/review/drafts",
+            delete(handlers::tasks::discard_review_drafts),
+        )
         .route(
             "/projects/{id}/tasks/{taskId}/review/bulk-delete",
             post(handlers::tasks::bulk_delete_review_comments),
@@ -707,6 +1093,22 @@ pub fn create_api_router() -> Router {
             get(handlers::hooks::list_all_hooks).delete(handlers::hooks::clear_all_hooks),
         )
         .route("/hooks/preview", post(handlers::hooks::preview_sound))
+        .route("/inbox", get(handlers::inbox::get_inbox))
+        .route("/search", get(handlers::search::search))
+        // Organization mode API (client side)
+        .route("/org/status", get(handlers::org::get_status))
+        .route("/org/publish", post(handlers::org::publish_now))
+        .route(
+            "/projects/{id}/team-tasks",
+            get(handlers::org::list_team_tasks),
+        )
+        .route(
+            "/projects/{id}/team-tasks/{owner}/{taskId}/comments",
+            get(handlers::org::get_team_comments).post(handlers::org::add_team_comment),
+        )
+        // Workspace sync API
+        .route("/sync/status", get(handlers::sync::get_status))
+        .route("/sync/run", post(handlers::sync::run_sync))
         .route(
             "/projects/{id}/hooks/{taskId}",
             delete(handlers::hooks::dismiss_hook),
@@ -738,6 +1140,22 @@ pub fn create_api_router() -> Router {
         .route("/projects/{id}/git/push", post(handlers::git::push))
         .route("/projects/{id}/git/fetch", post(handlers::git::fetch))
         .route("/projects/{id}/git/stash", post(handlers::git::stash))
+        .route(
+            "/projects/{id}/git/stash/list",
+            get(handlers::git::list_stashes),
+        )
+        .route(
+            "/projects/{id}/git/stash/{index}",
+            get(handlers::git::show_stash),
+        )
+        .route(
+            "/projects/{id}/git/stash/{index}/apply",
+            post(handlers::git::apply_stash),
+        )
+        .route(
+            "/projects/{id}/git/stash/{index}/drop",
+            post(handlers::git::drop_stash),
+        )
         .route("/projects/{id}/git/commit", post(handlers::git::commit))
         // AI Settings API — Providers
         .route(
@@ -1002,6 +1420,25 @@ pub fn has_embedded_assets() -> bool {
     FrontendAssets::get("index.html").is_some()
 }
 
+/// Startup banner line for the `[auth]` session policy (mobile mode)
+fn print_session_policy(auth: &ServerAuth) {
+    if !auth.sessions_enabled() {
+        return;
+    }
+    let policy = &auth.session_policy;
+    let mut line = format!(
+        "  Sessions: lock after {} min idle",
+        policy.idle_timeout_minutes
+    );
+    if policy.max_session_hours > 0 {
+        line.push_str(&format!(", expire after {} h", policy.max_session_hours));
+    }
+    if policy.pin_ip {
+        line.push_str(", pinned to client IP");
+    }
+    println!("{}", line);
+}
+
 /// Create the full router with static file serving and optional auth
 pub fn create_router(
     static_dir: Option<PathBuf>,
@@ -1015,33 +1452,64 @@ pub fn create_router(
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
-        .allow_headers(Any);
+        .allow_headers(Any)
+        // Lets a cross-origin SPA tell an expired session from a bad signature
+        .expose_headers([axum::http::HeaderName::from_static(auth::SESSION_HEADER)]);
+
+    let metrics_config = crate::storage::config::load_config().metrics;
 
     let api_router = if let Some(remote) = remote_url {
         create_proxy_router(remote)
     } else {
         let api_router = create_api_router();
+        let api_router = if metrics_config.enabled {
+            api_router.layer(middleware::from_fn(
+                handlers::metrics::http_metrics_middleware,
+            ))
+        } else {
+            api_router
+        };
 
         // Auth endpoints are NOT protected by middleware
         let auth_router = Router::new()
             .route("/auth/info", get(auth::auth_info))
+            .route("/auth/challenge", post(auth::auth_challenge))
             .route("/auth/verify", post(auth::auth_verify))
             .with_state(auth.clone());
 
+        // Session endpoints and the attempt log sit behind the auth layer like
+        // the rest of the API
+        let session_router = Router::new()
+            .route("/auth/session/heartbeat", post(auth::session_heartbeat))
+            .route("/auth/session/lock", post(auth::session_lock))
+            .route("/auth/attempts", get(auth::auth_attempts))
+            .with_state(auth.clone());
+
         // Protected API routes get the HMAC auth layer.
-        let protected_api = api_router.layer(middleware::from_fn_with_state(
-            auth.clone(),
-            auth::auth_middleware,
-        ));
+        let protected_api = api_router
+            .merge(session_router)
+            .layer(middleware::from_fn_with_state(
+                auth.clone(),
+                auth::auth_middleware,
+            ));
 
         // CSRF guard wraps EVERYTHING under /api/v1 — including auth_router, so
         // /auth/verify can't be probed cross-origin. Sec-Fetch-Site / Origin /
         // Referer are checked for non-safe methods; safe methods (GET/HEAD/OPTIONS,
-        // including WebSocket upgrades and CORS preflight) pass through.
+        // including WebSocket upgrades and CORS preflight) pass through. Without
+        // auth, browser writes must also carry the same-site token cookie.
+        let csrf_policy = if auth.secret_key.is_some() {
+            csrf::CsrfPolicy::origin_only()
+        } else {
+            csrf::CsrfPolicy::with_token(crate::storage::config::load_config().web.allowed_hosts)
+        };
         let base = Router::new()
             .nest("/api/v1", protected_api)
             .nest("/api/v1", auth_router)
-            .layer(middleware::from_fn(csrf::csrf_middleware));
+            .layer(middleware::from_fn_with_state(
+                Arc::new(csrf_policy),
+                csrf::csrf_middleware,
+            ));
 
         // GUI-only loopback endpoints: only registered in gui builds (server binds
         // 127.0.0.1 there). Non-gui builds (web, mobile) simply don't expose these routes.
@@ -1059,7 +1527,83 @@ pub fn create_router(
             base.nest("/api/v1", gui_router)
         };
 
-        base
+        // Liveness / readiness probes for supervisors. Unauthenticated and
+        // outside /api/v1 so probes don't need to sign requests.
+        let base = base
+            .route("/healthz", get(handlers::health::healthz))
+            .route("/readyz", get(handlers::health::readyz));
+
+        // Public share links. Unauthenticated by design: the token (plus the
+        // optional password) grants read access to exactly one task.
+        let base = base
+            .route("/share/{token}", get(handlers::share::share_page))
+            .route("/share/{token}/data", get(handlers::share::share_data));
+
+        // Organization mode team-server endpoints. Members' Grove instances
+        // call these from other machines and can't sign HMAC requests, so the
+        // bearer token issued to each member replaces the API auth.
+        let org_members = crate::storage::config::load_config().organization.members;
+        let base = if org_members.is_empty() {
+            base
+        } else {
+            let member_route = "/projects/{project}/members/{owner}/tasks";
+            base.nest(
+                "/org",
+                Router::new()
+                    .route("/me", get(handlers::org::server_me))
+                    .route(
+                        "/projects/{project}/tasks",
+                        get(handlers::org::server_list_tasks),
+                    )
+                    .route(
+                        member_route,
+                        get(handlers::org::server_member_tasks)
+                            .put(handlers::org::server_publish_tasks),
+                    )
+                    .route(
+                        &format!("{member_route}/{{task_id}}/notes"),
+                        get(handlers::org::server_get_notes).put(handlers::org::server_save_notes),
+                    )
+                    .route(
+                        &format!("{member_route}/{{task_id}}/comments"),
+                        get(handlers::org::server_get_comments)
+                            .put(handlers::org::server_merge_comments),
+                    )
+                    .layer(middleware::from_fn_with_state(
+                        Arc::new(org_members),
+                        auth::org_member_auth_middleware,
+                    )),
+            )
+        };
+
+        // Prometheus scrape endpoint. Lives outside /api/v1 (the conventional
+        // path) and skips CSRF since it is GET-only. Scrapers can't sign HMAC
+        // requests, so a configured bearer token replaces the API auth.
+        if metrics_config.enabled {
+            crate::metrics::init();
+            let metrics_router =
+                Router::new().route("/metrics", get(handlers::metrics::metrics_handler));
+            let metrics_router = match metrics_config.bearer_token {
+                Some(token) => metrics_router.layer(middleware::from_fn_with_state(
+                    Arc::new(token),
+                    auth::bearer_auth_middleware,
+                )),
+                None => metrics_router.layer(middleware::from_fn_with_state(
+                    auth.clone(),
+                    auth::auth_middleware,
+                )),
+            };
+            base.merge(metrics_router)
+        } else {
+            base
+        }
+    };
+
+    // Dashboard mode: only reads and event subscriptions reach the API
+    let api_router = if read_only::enabled() {
+        api_router.layer(middleware::from_fn(read_only::read_only_middleware))
+    } else {
+        api_router
     };
 
     // Priority: external static_dir > embedded assets
@@ -1210,7 +1754,7 @@ async fn http_proxy_handler(
             axum_resp
         }
         Err(e) => {
-            eprintln!("Proxy HTTP error to {}: {}", remote_target, e);
+            tracing::warn!("Proxy HTTP error to {}: {}", remote_target, e);
             (StatusCode::BAD_GATEWAY, format!("Proxy error: {}", e)).into_response()
         }
     }
@@ -1239,7 +1783,7 @@ async fn handle_websocket_proxy(
         let mut request = match ws_url.as_str().into_client_request() {
             Ok(r) => r,
             Err(e) => {
-                eprintln!("Failed to build WebSocket request for {}: {}", ws_url, e);
+                tracing::warn!("Failed to build WebSocket request for {}: {}", ws_url, e);
                 return;
             }
         };
@@ -1260,7 +1804,7 @@ async fn handle_websocket_proxy(
         let remote_conn = match tokio_tungstenite::connect_async(request).await {
             Ok((ws_stream, _)) => ws_stream,
             Err(e) => {
-                eprintln!("Failed to connect to remote WebSocket {}: {}", ws_url, e);
+                tracing::warn!("Failed to connect to remote WebSocket {}: {}", ws_url, e);
                 return;
             }
         };
@@ -1291,12 +1835,12 @@ async fn handle_websocket_proxy(
                             }
                         };
                         if let Err(e) = remote_write.send(mapped).await {
-                            eprintln!("Error sending to remote WS: {}", e);
+                            tracing::warn!("Error sending to remote WS: {}", e);
                             break;
                         }
                     }
                     Err(e) => {
-                        eprintln!("Error reading from client WS: {}", e);
+                        tracing::warn!("Error reading from client WS: {}", e);
                         break;
                     }
                 }
@@ -1328,12 +1872,12 @@ async fn handle_websocket_proxy(
                             tokio_tungstenite::tungstenite::Message::Frame(_) => continue,
                         };
                         if let Err(e) = client_write.send(mapped).await {
-                            eprintln!("Error sending to client WS: {}", e);
+                            tracing::warn!("Error sending to client WS: {}", e);
                             break;
                         }
                     }
                     Err(e) => {
-                        eprintln!("Error reading from remote WS: {}", e);
+                        tracing::warn!("Error reading from remote WS: {}", e);
                         break;
                     }
                 }
@@ -1525,10 +2069,7 @@ pub async fn start_server(
     // grove was killed mid-download. Marking them failed lets the user
     // retry from Marketplace instead of staring at a perpetual spinner.
     if let Err(e) = crate::storage::installed_agents::recover_orphaned_installing() {
-        eprintln!(
-            "[startup] failed to recover orphaned installing rows: {}",
-            e
-        );
+        tracing::warn!("failed to recover orphaned installing rows: {}", e);
     }
 
     // Agent onboarding is a startup prerequisite, not a fire-and-forget task:
@@ -1547,8 +2088,8 @@ pub async fn start_server(
     .await
     .map_err(|e| std::io::Error::other(format!("agent onboarding task failed: {}", e)))?
     .map_err(|e| std::io::Error::other(format!("agent onboarding reconciliation failed: {}", e)))?;
-    eprintln!(
-        "[startup] onboarding agents reconciled ({} Npx channel(s) added)",
+    tracing::info!(
+        "onboarding agents reconciled ({} Npx channel(s) added)",
         seeded
     );
 
@@ -1587,6 +2128,9 @@ pub async fn start_server(
         }
     }
     crate::automation::scheduler::spawn();
+    crate::operations::sync::spawn();
+    crate::operations::org::spawn();
+    handlers::config::spawn_theme_watcher();
 
     // Start the in-process agent_graph MCP listener (loopback-only). Failure to
     // bind is non-fatal — the rest of the server still boots; ACP sessions will
@@ -1602,8 +2146,8 @@ pub async fn start_server(
             // print since they disable agent_graph tools downstream.
         }
         Err(e) => {
-            eprintln!(
-                "[agent_graph_mcp] failed to bind listener: {} — agent_graph tools disabled",
+            tracing::error!(
+                "failed to bind listener: {} — agent_graph tools disabled",
                 e
             );
         }
@@ -1614,7 +2158,7 @@ pub async fn start_server(
 
     // Ensure _main and _local system groups exist
     if let Err(e) = crate::storage::taskgroups::ensure_system_groups() {
-        eprintln!("[warning] Failed to ensure system groups: {}", e);
+        tracing::warn!("Failed to ensure system groups: {}", e);
     }
 
     // Pre-build Grove.app notification bundle (macOS only, first run compiles Swift)
@@ -1666,6 +2210,7 @@ pub async fn start_server(
         println!("Grove Mobile UI: {}", base_url);
         println!();
         println!("  Authentication: HMAC-SHA256");
+        print_session_policy(&auth);
         println!("  TLS: enabled ({})", tls_label);
         println!("  Secret Key: {}", sk);
         if auth.key_is_generated {
@@ -1684,8 +2229,9 @@ pub async fn start_server(
         std::env::set_var("GROVE_PORT", port.to_string());
         std::env::set_var("GROVE_PROTOCOL", "https");
 
+        systemd::notify_ready(&base_url);
         axum_server::bind_rustls(bind_addr, tls_config)
-            .serve(app.into_make_service())
+            .serve(app.into_make_service_with_connect_info::<std::net::SocketAddr>())
             .await
             .map_err(std::io::Error::other)?;
 
@@ -1711,6 +2257,7 @@ pub async fn start_server(
         println!("Grove Mobile UI: {}", base_url);
         println!();
         println!("  Authentication: HMAC-SHA256");
+        print_session_policy(&auth);
         println!("  Secret Key: {}", sk);
         if auth.key_is_generated {
             println!(
@@ -1746,22 +2293,49 @@ pub async fn start_server(
     // necessary because axum::serve keeps blocking while in-flight WebSocket
     // connections stay open (e.g. ACP stream, walkie-talkie), and browsers
     // won't close them on their own.
-    axum::serve(listener, app)
-        .with_graceful_shutdown(async {
+    systemd::notify_ready(&format!("http://{}:{}", host, actual_port));
+    // Connect info gives the auth layer the client address (session IP pinning)
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .with_graceful_shutdown(async {
+        shutdown_signal().await;
+        println!("\nShutting down... (press Ctrl-C again to force exit)");
+        systemd::notify_stopping();
+        shutdown_file_watchers();
+        tokio::spawn(async {
             tokio::signal::ctrl_c().await.ok();
-            println!("\nShutting down... (press Ctrl-C again to force exit)");
+            eprintln!("Forced exit.");
+            // Process::exit skips Drop — flush FileWatcher buffers
+            // explicitly so second-Ctrl-C doesn't lose pending writes.
             shutdown_file_watchers();
-            tokio::spawn(async {
+            std::process::exit(130);
+        });
+    })
+    .await
+    .map_err(std::io::Error::other)
+}
+
+/// Resolve on Ctrl-C, or on SIGTERM (what systemd/launchd send on stop).
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut term) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = term.recv() => {}
+                }
+            }
+            Err(_) => {
                 tokio::signal::ctrl_c().await.ok();
-                eprintln!("Forced exit.");
-                // Process::exit skips Drop — flush FileWatcher buffers
-                // explicitly so second-Ctrl-C doesn't lose pending writes.
-                shutdown_file_watchers();
-                std::process::exit(130);
-            });
-        })
-        .await
-        .map_err(std::io::Error::other)
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    tokio::signal::ctrl_c().await.ok();
 }
 
 #[cfg(test)]
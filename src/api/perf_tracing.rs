@@ -273,10 +273,3 @@ pub fn get_trace(trace_id: u64) -> Option<TraceRecord> {
     }
     None
 }
-
-pub fn install() {
-    use tracing_subscriber::layer::SubscriberExt;
-    use tracing_subscriber::util::SubscriberInitExt;
-    let subscriber = tracing_subscriber::registry().with(PerfTraceLayer);
-    let _ = subscriber.try_init();
-}
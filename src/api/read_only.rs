@@ -0,0 +1,172 @@
+//! Read-only guard for `grove web --read-only` / `[web] read_only = true`.
+//!
+//! Meant for a dashboard on a shared screen: every read keeps working, but
+//! nothing can be changed from the browser. The guard sits in front of the
+//! whole router (local and proxy mode alike) and answers 403 to:
+//!  - any method other than GET/HEAD/OPTIONS, except signing in (`/auth/*`),
+//!  - WebSocket upgrades other than event subscriptions — terminals and the
+//!    walkie-talkie accept input over the socket. Chat streams are watched
+//!    read-only: the chat socket drops every client message itself,
+//!  - GET endpoints that act on the host (native folder pickers).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{header, HeaderMap, Method, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// WebSockets that only push events to the client.
+const SUBSCRIPTION_SOCKETS: &[&str] = &["/api/v1/radio/events/ws"];
+
+/// Whether `path` is a per-chat stream (`/api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/ws`).
+fn is_chat_stream(path: &str) -> bool {
+    let segments: Vec<&str> = path.split('/').collect();
+    matches!(
+        segments.as_slice(),
+        ["", "api", "v1", "projects", p, "tasks", t, "chats", c, "ws"]
+            if !p.is_empty() && !t.is_empty() && !c.is_empty()
+    )
+}
+
+/// GET endpoints with side effects on the server machine.
+const ACTION_GETS: &[&str] = &[
+    "/api/v1/browse-folder",
+    "/api/v1/extension/browse-install-folder",
+    "/api/v1/plugins/browse-folder",
+];
+
+/// Turn read-only mode on for this process (`grove web --read-only`).
+pub fn enable() {
+    READ_ONLY.store(true, Ordering::Relaxed);
+}
+
+/// Whether the server rejects mutations (CLI flag or `[web] read_only`).
+pub fn enabled() -> bool {
+    READ_ONLY.load(Ordering::Relaxed) || crate::storage::config::load_config().web.read_only
+}
+
+fn is_websocket_upgrade(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::UPGRADE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.eq_ignore_ascii_case("websocket"))
+}
+
+/// Whether a request may pass in read-only mode.
+fn is_allowed(method: &Method, path: &str, websocket: bool) -> bool {
+    match *method {
+        Method::OPTIONS | Method::HEAD => true,
+        Method::GET if websocket => SUBSCRIPTION_SOCKETS.contains(&path) || is_chat_stream(path),
+        Method::GET => !ACTION_GETS.contains(&path),
+        Method::POST => path.starts_with("/api/v1/auth/"),
+        _ => false,
+    }
+}
+
+pub async fn read_only_middleware(request: Request<Body>, next: Next) -> Response {
+    let websocket = is_websocket_upgrade(request.headers());
+    if !is_allowed(request.method(), request.uri().path(), websocket) {
+        return (StatusCode::FORBIDDEN, "Grove is running in read-only mode").into_response();
+    }
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_pass_and_writes_are_rejected() {
+        assert!(is_allowed(&Method::GET, "/api/v1/projects", false));
+        assert!(is_allowed(&Method::HEAD, "/api/v1/projects", false));
+        assert!(is_allowed(&Method::OPTIONS, "/api/v1/projects", false));
+        assert!(is_allowed(&Method::GET, "/share/abc/data", false));
+        assert!(is_allowed(&Method::POST, "/api/v1/auth/verify", false));
+
+        assert!(!is_allowed(
+            &Method::POST,
+            "/api/v1/projects/p/tasks/t/merge",
+            false
+        ));
+        assert!(!is_allowed(&Method::DELETE, "/api/v1/projects/p", false));
+        assert!(!is_allowed(&Method::PUT, "/api/v1/config", false));
+        assert!(!is_allowed(
+            &Method::PATCH,
+            "/api/v1/projects/p/tasks/t",
+            false
+        ));
+        assert!(!is_allowed(&Method::GET, "/api/v1/browse-folder", false));
+    }
+
+    #[test]
+    fn only_subscription_sockets_upgrade() {
+        assert!(is_allowed(&Method::GET, "/api/v1/radio/events/ws", true));
+        assert!(is_allowed(
+            &Method::GET,
+            "/api/v1/projects/p/tasks/t/chats/c/ws",
+            true
+        ));
+        assert!(!is_allowed(&Method::GET, "/api/v1/terminal", true));
+        assert!(!is_allowed(
+            &Method::GET,
+            "/api/v1/projects/p/tasks/t/chats/c/agent-pty",
+            true
+        ));
+        assert!(!is_allowed(
+            &Method::GET,
+            "/api/v1/projects/p/tasks/t/chats//ws",
+            true
+        ));
+        assert!(!is_allowed(&Method::GET, "/api/v1/walkie-talkie/ws", true));
+    }
+
+    #[tokio::test]
+    async fn read_only_clients_connect_to_subscription_and_chat_sockets() {
+        use axum::extract::ws::{Message, WebSocketUpgrade};
+        use axum::routing::get;
+        use futures::StreamExt;
+
+        async fn greet(ws: WebSocketUpgrade) -> Response {
+            ws.on_upgrade(|mut socket| async move {
+                let _ = socket.send(Message::Text("hello".into())).await;
+            })
+        }
+
+        let app = axum::Router::new()
+            .route("/api/v1/radio/events/ws", get(greet))
+            .route(
+                "/api/v1/projects/{id}/tasks/{taskId}/chats/{chatId}/ws",
+                get(greet),
+            )
+            .route("/api/v1/terminal", get(greet))
+            .layer(axum::middleware::from_fn(read_only_middleware));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        for path in [
+            "/api/v1/radio/events/ws",
+            "/api/v1/projects/p/tasks/t/chats/c/ws",
+        ] {
+            let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{addr}{path}"))
+                .await
+                .unwrap_or_else(|e| panic!("{path}: {e}"));
+            let first = socket.next().await.unwrap().unwrap();
+            assert_eq!(first.into_text().unwrap().as_str(), "hello", "{path}");
+        }
+
+        let err = tokio_tungstenite::connect_async(format!("ws://{addr}/api/v1/terminal"))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(&err, tokio_tungstenite::tungstenite::Error::Http(r) if r.status() == StatusCode::FORBIDDEN),
+            "{err}"
+        );
+    }
+}
@@ -34,7 +34,7 @@ pub fn shutdown_file_watchers() {
             watcher.shutdown();
         }
     }
-    eprintln!("FileWatcher: all watchers shut down");
+    tracing::info!("FileWatcher: all watchers shut down");
 }
 
 /// Ensure file watching is active for the given task. Idempotent: repeated
@@ -61,6 +61,18 @@ pub fn ensure_task_active(project_key: &str, task_id: &str, worktree_path: &str)
     crate::symbols::on_watch_started(project_key, task_id, Path::new(worktree_path));
 }
 
+/// Re-send the watch for a task that is already being watched, so a changed
+/// path scope takes effect. Tasks that are not watched yet are left alone.
+pub fn refresh_task_watch(project_key: &str, task_id: &str, worktree_path: &str) {
+    if let Ok(watchers) = FILE_WATCHERS.read() {
+        if let Some(watcher) = watchers.get(project_key) {
+            if watcher.get_history(task_id).is_some() {
+                watcher.watch(task_id, Path::new(worktree_path));
+            }
+        }
+    }
+}
+
 /// Global session for the connected browser companion extension.
 /// Manages the WebSocket channel and handles async request-response mapping.
 pub struct ExtensionSession {
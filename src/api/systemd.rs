@@ -0,0 +1,66 @@
+//! Minimal `sd_notify(3)` client.
+//!
+//! When `grove web` / `grove mobile` runs under a `Type=notify` systemd unit
+//! (see `grove service install`), systemd passes `NOTIFY_SOCKET` and waits for
+//! `READY=1` before considering the service started. Outside systemd the
+//! variable is unset and every call is a no-op.
+
+/// Send `state` (e.g. `"READY=1"`) to the supervisor. Returns whether a
+/// message was delivered.
+pub fn notify(state: &str) -> bool {
+    match std::env::var_os("NOTIFY_SOCKET") {
+        Some(socket) => send(&socket.to_string_lossy(), state).is_ok(),
+        None => false,
+    }
+}
+
+/// Server finished startup and is accepting connections.
+pub fn notify_ready(url: &str) {
+    notify(&format!("READY=1\nSTATUS=Serving {}", url));
+}
+
+/// Graceful shutdown has begun.
+pub fn notify_stopping() {
+    notify("STOPPING=1");
+}
+
+#[cfg(unix)]
+fn send(socket: &str, state: &str) -> std::io::Result<()> {
+    use std::os::unix::net::UnixDatagram;
+
+    let sock = UnixDatagram::unbound()?;
+    // `@name` is a Linux abstract-namespace socket.
+    #[cfg(target_os = "linux")]
+    if let Some(name) = socket.strip_prefix('@') {
+        use std::os::linux::net::SocketAddrExt;
+        let addr = std::os::unix::net::SocketAddr::from_abstract_name(name.as_bytes())?;
+        sock.send_to_addr(state.as_bytes(), &addr)?;
+        return Ok(());
+    }
+    sock.send_to(state.as_bytes(), socket)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn send(_socket: &str, _state: &str) -> std::io::Result<()> {
+    Err(std::io::Error::from(std::io::ErrorKind::Unsupported))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixDatagram;
+
+    #[test]
+    fn send_delivers_state_to_socket_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notify.sock");
+        let server = UnixDatagram::bind(&path).unwrap();
+
+        send(path.to_str().unwrap(), "READY=1").unwrap();
+
+        let mut buf = [0u8; 64];
+        let n = server.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"READY=1");
+    }
+}
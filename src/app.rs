@@ -4,6 +4,8 @@ use std::path::Path;
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Utc};
+
 use ratatui::widgets::ListState;
 
 use crate::async_ops_state::AsyncOpsState;
@@ -11,11 +13,18 @@ use crate::config_state::ConfigState;
 use crate::dialogs::DialogState;
 use crate::git;
 use crate::hooks::{self, HookEntry, HooksFile};
-use crate::model::{loader, ProjectInfo, ProjectTab, WorkspaceState, Worktree, WorktreeStatus};
+use crate::model::{
+    loader, views, ProjectInfo, ProjectTab, WorkspaceState, Worktree, WorktreeStatus,
+};
 use crate::notification_state::NotificationState;
+use crate::operations::inbox::InboxKind;
+use crate::operations::time_tracking::{self, TaskTime};
 use crate::session::{self, SessionType};
 use crate::storage::{
-    self, comments, notes,
+    self, chat_history, comments,
+    config::{StatusPalette, TaskColumn, TimeFormat},
+    notes, project_notes,
+    task_views::{self, TaskSort, TaskView},
     tasks::{self},
     workspace::project_hash,
 };
@@ -24,16 +33,27 @@ use crate::tmux;
 use crate::tmux::layout::{
     self as layout_mod, CustomLayout, LayoutNode, PaneRole, SplitDirection, TaskLayout,
 };
+use crate::ui::clipboard;
 use crate::ui::components::action_palette::{ActionPaletteData, ActionType};
 use crate::ui::components::add_project_dialog::AddProjectData;
+use crate::ui::components::autolink_panel::AutoLinkPanelData;
 use crate::ui::components::branch_selector::BranchSelectorData;
+use crate::ui::components::chat_view::ChatViewData;
+use crate::ui::components::column_picker::ColumnPickerData;
 use crate::ui::components::commit_dialog::CommitDialogData;
 use crate::ui::components::config_panel::{ConfigPanelData, ConfigStep};
 use crate::ui::components::confirm_dialog::ConfirmType;
 use crate::ui::components::delete_project_dialog::{DeleteMode, DeleteProjectData};
+use crate::ui::components::global_search_panel::GlobalSearchData;
 use crate::ui::components::hook_panel::HookConfigStep;
+use crate::ui::components::inbox_panel::InboxPanelData;
 use crate::ui::components::input_confirm_dialog::InputConfirmData;
 use crate::ui::components::merge_dialog::{MergeDialogData, MergeMethod};
+use crate::ui::components::notes_history_panel::NotesHistoryData;
+use crate::ui::components::open_with_picker::OpenWithData;
+use crate::ui::components::project_notes_panel::ProjectNotesData;
+use crate::ui::components::search_panel::SearchPanelData;
+use crate::ui::components::stash_panel::StashPanelData;
 use crate::ui_state::Toast;
 use crate::ui_state::UiState;
 use crate::update::UpdateInfo;
@@ -75,6 +95,8 @@ pub struct PanelData {
     pub notes_content: String,
     /// Review tab: structured comments
     pub review_comments: comments::CommentsData,
+    /// Stats tab: 任务耗时
+    pub time_spent: Option<TaskTime>,
     /// 上次加载的 task id
     pub last_task_id: Option<String>,
 }
@@ -113,10 +135,20 @@ pub struct ProjectState {
     pub stats_scroll: u16,
     /// 待打开外部编辑器的 notes 文件路径
     pub pending_notes_edit: Option<String>,
+    /// 待打开外部编辑器的项目笔记 (slug, 文件路径)
+    pub pending_project_note_edit: Option<(String, String)>,
     /// 项目目录是否还存在(false = "missing")
     pub exists: bool,
     /// git 状态是否可用(是 git repo 且有至少一个 commit)
     pub is_git_usable: bool,
+    /// 当前排序方式
+    pub sort: TaskSort,
+    /// 项目保存的任务视图
+    pub views: Vec<TaskView>,
+    /// 当前启用的视图（`views` 下标，None = 全部任务）
+    pub active_view: Option<usize>,
+    /// 任务列表显示的列（来自 config.toml `[task_list]`）
+    pub columns: Vec<TaskColumn>,
 }
 
 impl ProjectState {
@@ -151,8 +183,10 @@ impl ProjectState {
 
         let exists = Path::new(project_path).exists();
         let is_git_usable = exists && git::is_git_usable(project_path);
+        let views = task_views::load_views(&project_key).unwrap_or_default();
+        let columns = storage::config::load_config().task_list.columns;
 
-        Self {
+        let mut state = Self {
             current_tab: ProjectTab::Active,
             list_states: [active_state, archived_state],
             worktrees: [active, archived],
@@ -169,9 +203,18 @@ impl ProjectState {
             diff_scroll: 0,
             stats_scroll: 0,
             pending_notes_edit: None,
+            pending_project_note_edit: None,
             exists,
             is_git_usable,
+            sort: TaskSort::default(),
+            views,
+            active_view: None,
+            columns,
+        };
+        if state.columns.contains(&TaskColumn::Diff) {
+            views::load_diff_sizes(&mut state.worktrees[0]);
         }
+        state
     }
 
     /// TUI 过滤：现在所有任务都支持 Terminal（通过 multiplexer 字段）
@@ -200,6 +243,8 @@ impl ProjectState {
         let archived = loader::load_archived_worktrees(&self.project_path);
         let archived = Self::filter_tui_tasks(archived);
         self.worktrees = [active, archived];
+        self.reload_views();
+        self.apply_sort();
 
         // 刷新 existence / git 可用性(用户可能中途 `git init` 或删除目录)
         self.exists = Path::new(&self.project_path).exists();
@@ -304,6 +349,9 @@ impl ProjectState {
         self.panel_data.review_comments =
             comments::load_comments(&self.project_key, &wt.id).unwrap_or_default();
 
+        // 任务耗时
+        self.panel_data.time_spent = Some(time_tracking::task_time(&self.project_key, &wt.id));
+
         // 智能默认 sub-tab：仅首次打开面板时设置，切换任务时保持用户选择
         if changed && first_open {
             self.preview_sub_tab = PreviewSubTab::Stats;
@@ -322,6 +370,14 @@ impl ProjectState {
         }
     }
 
+    /// 外部编辑器退出后记录 notes 修订
+    pub fn finish_notes_edit(&mut self) {
+        let Some(wt) = self.selected_worktree_cloned() else {
+            return;
+        };
+        let _ = notes::record_notes_revision(&self.project_key, &wt.id, notes::NoteSource::Tui);
+    }
+
     /// 向下滚动 notes
     pub fn scroll_notes_down(&mut self) {
         let line_count = self.panel_data.notes_content.lines().count() as u16;
@@ -379,6 +435,76 @@ impl ProjectState {
     /// 懒加载归档任务
     fn load_archived(&mut self) {
         self.worktrees[1] = loader::load_archived_worktrees(&self.project_path);
+        self.apply_sort();
+        self.update_filter();
+    }
+
+    // ========== 排序与视图 ==========
+
+    /// 重新加载项目保存的视图（视图被删除时取消选中）
+    fn reload_views(&mut self) {
+        let active_name = self.active_view_ref().map(|v| v.name.clone());
+        self.views = task_views::load_views(&self.project_key).unwrap_or_default();
+        self.active_view =
+            active_name.and_then(|name| self.views.iter().position(|v| v.name == name));
+    }
+
+    /// 当前启用的视图
+    pub fn active_view_ref(&self) -> Option<&TaskView> {
+        self.active_view.and_then(|i| self.views.get(i))
+    }
+
+    /// 按当前排序方式重排两个 Tab 的列表
+    fn apply_sort(&mut self) {
+        let need_diff = self.sort == TaskSort::DiffSize || self.columns.contains(&TaskColumn::Diff);
+        for worktrees in self.worktrees.iter_mut() {
+            if need_diff {
+                views::load_diff_sizes(worktrees);
+            }
+            views::sort_worktrees(worktrees, self.sort);
+        }
+    }
+
+    /// 更新显示的列（新增 Diff 列时计算 diff 统计）
+    pub fn set_columns(&mut self, columns: Vec<TaskColumn>) {
+        let load_diff = columns.contains(&TaskColumn::Diff)
+            && !self.columns.contains(&TaskColumn::Diff)
+            && self.sort != TaskSort::DiffSize;
+        self.columns = columns;
+        if load_diff {
+            views::load_diff_sizes(&mut self.worktrees[0]);
+        }
+    }
+
+    /// 切换到下一种排序方式
+    pub fn cycle_sort(&mut self) -> TaskSort {
+        self.sort = self.sort.next();
+        self.apply_sort();
+        self.update_filter();
+        self.sort
+    }
+
+    /// 切换到下一个保存的视图（最后一个之后回到全部任务）
+    pub fn cycle_view(&mut self) -> Option<&TaskView> {
+        self.active_view = match self.active_view {
+            None if !self.views.is_empty() => Some(0),
+            Some(i) if i + 1 < self.views.len() => Some(i + 1),
+            _ => None,
+        };
+        if let Some(sort) = self.active_view_ref().map(|v| v.sort) {
+            self.sort = sort;
+        }
+        self.apply_sort();
+        self.update_filter();
+        self.active_view_ref()
+    }
+
+    /// Tab 栏右侧显示的排序/视图标签
+    pub fn view_label(&self) -> String {
+        match self.active_view_ref() {
+            Some(view) => format!("view: {} · sort: {}", view.name, self.sort.label()),
+            None => format!("sort: {}", self.sort.label()),
+        }
     }
 
     /// 确保当前 Tab 有选中项
@@ -435,32 +561,36 @@ impl ProjectState {
 
     // ========== 搜索功能 ==========
 
-    /// 重置过滤索引（显示全部）
+    /// 重置过滤索引（仅保留视图过滤）
     fn reset_filter(&mut self) {
-        for (i, worktrees) in self.worktrees.iter().enumerate() {
-            self.filtered_indices[i] = (0..worktrees.len()).collect();
-        }
+        self.rebuild_filter("");
     }
 
-    /// 更新过滤索引
-    fn update_filter(&mut self) {
-        let query_lower = self.search_query.to_lowercase();
+    /// 按视图过滤与搜索词重建过滤索引
+    fn rebuild_filter(&mut self, query: &str) {
+        let query_lower = query.to_lowercase();
+        let view = self.active_view.and_then(|i| self.views.get(i));
 
         for (tab_idx, worktrees) in self.worktrees.iter().enumerate() {
-            if query_lower.is_empty() {
-                self.filtered_indices[tab_idx] = (0..worktrees.len()).collect();
-            } else {
-                self.filtered_indices[tab_idx] = worktrees
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, wt)| {
-                        wt.task_name.to_lowercase().contains(&query_lower)
-                            || wt.branch.to_lowercase().contains(&query_lower)
-                    })
-                    .map(|(i, _)| i)
-                    .collect();
-            }
+            self.filtered_indices[tab_idx] = worktrees
+                .iter()
+                .enumerate()
+                .filter(|(_, wt)| view.is_none_or(|v| views::matches(&v.filter, wt)))
+                .filter(|(_, wt)| {
+                    query_lower.is_empty()
+                        || wt.task_name.to_lowercase().contains(&query_lower)
+                        || wt.branch.to_lowercase().contains(&query_lower)
+                })
+                .map(|(i, _)| i)
+                .collect();
         }
+    }
+
+    /// 更新过滤索引
+    fn update_filter(&mut self) {
+        let query = std::mem::take(&mut self.search_query);
+        self.rebuild_filter(&query);
+        self.search_query = query;
 
         // 确保选中项在过滤范围内
         self.ensure_filter_selection();
@@ -519,6 +649,30 @@ impl ProjectState {
         self.update_filter();
     }
 
+    /// 切到 Active Tab 并选中指定任务（清除搜索），找不到返回 false
+    pub fn select_task(&mut self, task_id: &str) -> bool {
+        if !self.worktrees[0].iter().any(|w| w.id == task_id) {
+            return false;
+        }
+        self.current_tab = ProjectTab::Active;
+        self.search_mode = false;
+        self.search_query.clear();
+        // 目标任务可能被视图过滤掉，先回到全部任务
+        if self.active_view_ref().is_some_and(|v| {
+            !self.worktrees[0]
+                .iter()
+                .any(|w| w.id == task_id && views::matches(&v.filter, w))
+        }) {
+            self.active_view = None;
+        }
+        self.reset_filter();
+        let index = self.filtered_indices[0]
+            .iter()
+            .position(|&i| self.worktrees[0][i].id == task_id);
+        self.list_states[0].select(index);
+        true
+    }
+
     /// 获取当前 Tab 过滤后的 worktrees
     pub fn filtered_worktrees(&self) -> Vec<&Worktree> {
         let tab_idx = self.current_tab.index();
@@ -611,6 +765,64 @@ impl MonitorAction {
     }
 }
 
+/// Monitor grid 最多同时展示的 task 数
+const MONITOR_GRID_MAX: usize = 6;
+/// Monitor grid 每个 task 抓取的预览行数
+const MONITOR_GRID_LINES: usize = 40;
+
+/// Monitor grid 中单个 task 的实时预览
+#[derive(Debug, Clone)]
+pub struct GridCell {
+    pub task_id: String,
+    pub task_name: String,
+    /// 预览来源（"tmux" / "chat"），没有可用预览时为空
+    pub source: &'static str,
+    pub lines: Vec<String>,
+}
+
+/// 抓取单个 task 的预览：tmux task 取 agent pane 内容，否则取最近一条 chat 消息
+fn grid_cell(project_key: &str, task: &tasks::Task) -> GridCell {
+    let mut cell = GridCell {
+        task_id: task.id.clone(),
+        task_name: task.name.clone(),
+        source: "",
+        lines: Vec::new(),
+    };
+
+    if session::resolve_session_type(&task.multiplexer) == SessionType::Tmux {
+        let name = session::resolve_session_name(&task.session_name, project_key, &task.id);
+        // 内置布局中第一个 pane 是 agent
+        let captured = tmux::layout::list_pane_ids(&name)
+            .ok()
+            .and_then(|panes| panes.into_iter().next())
+            .and_then(|pane| tmux::capture_pane(&pane, MONITOR_GRID_LINES, false).ok());
+        if let Some(content) = captured {
+            cell.source = "tmux";
+            cell.lines = content.trim_end().lines().map(str::to_string).collect();
+            return cell;
+        }
+    }
+
+    let chats = tasks::load_chat_sessions(project_key, &task.id).unwrap_or_default();
+    if let Some(text) = chats
+        .iter()
+        .rev()
+        .find_map(|chat| chat_history::last_message(project_key, &task.id, &chat.id))
+    {
+        cell.source = "chat";
+        cell.lines = text.lines().map(str::to_string).collect();
+    }
+    cell
+}
+
+/// 单个 task 的最近活动（文件编辑 / chat 写入）
+#[derive(Debug, Clone)]
+pub struct TaskActivity {
+    pub task_id: String,
+    pub task_name: String,
+    pub last_activity: DateTime<Utc>,
+}
+
 /// Monitor 模式状态
 #[allow(dead_code)]
 pub struct MonitorState {
@@ -652,6 +864,18 @@ pub struct MonitorState {
     pub pending_notes_edit: Option<String>,
     /// 当前 session 使用的 session type
     pub session_type: SessionType,
+    /// 跟随活动：自动切到项目内最近有活动的 task（挂墙展示用）
+    pub follow_activity: bool,
+    /// 锁定当前 task，跟随模式下也不切换
+    pub pinned: bool,
+    /// 项目内 active task 的最近活动，按时间倒序
+    pub activity: Vec<TaskActivity>,
+    /// 多 task 预览 grid 视图
+    pub grid_view: bool,
+    /// Grid 中选中的格子
+    pub grid_selected: usize,
+    /// Grid 数据（当前 task 在前，其余按活动排序）
+    pub grid: Vec<GridCell>,
 }
 
 impl Default for MonitorState {
@@ -676,6 +900,12 @@ impl Default for MonitorState {
             project_key: String::new(),
             pending_notes_edit: None,
             session_type: SessionType::Tmux,
+            follow_activity: false,
+            pinned: false,
+            activity: Vec::new(),
+            grid_view: false,
+            grid_selected: 0,
+            grid: Vec::new(),
         }
     }
 }
@@ -723,10 +953,17 @@ impl MonitorState {
             project_key,
             pending_notes_edit: None,
             session_type: multiplexer,
+            follow_activity: false,
+            pinned: false,
+            activity: Vec::new(),
+            grid_view: false,
+            grid_selected: 0,
+            grid: Vec::new(),
         };
 
         // 加载初始数据
         state.refresh_panel_data();
+        state.scan_activity();
         state
     }
 
@@ -754,6 +991,112 @@ impl MonitorState {
         // Review comments 数据
         self.panel_data.review_comments =
             comments::load_comments(&self.project_key, &self.task_id).unwrap_or_default();
+
+        // 任务耗时
+        self.panel_data.time_spent =
+            Some(time_tracking::task_time(&self.project_key, &self.task_id));
+    }
+
+    /// 扫描项目内 active task 的最近活动（activity.jsonl / chat history 的写入时间）
+    pub fn scan_activity(&mut self) {
+        if self.project_key.is_empty() {
+            return;
+        }
+        let Ok(active_tasks) = tasks::load_tasks(&self.project_key) else {
+            return;
+        };
+        let mut activity: Vec<TaskActivity> = active_tasks
+            .into_iter()
+            .filter_map(|task| {
+                let last_activity = crate::watcher::last_edit_at(&self.project_key, &task.id)
+                    .max(chat_history::last_write_at(&self.project_key, &task.id))?;
+                Some(TaskActivity {
+                    task_id: task.id,
+                    task_name: task.name,
+                    last_activity,
+                })
+            })
+            .collect();
+        activity.sort_by_key(|a| std::cmp::Reverse(a.last_activity));
+        self.activity = activity;
+    }
+
+    /// 项目内最近有活动的 task
+    pub fn most_active(&self) -> Option<&TaskActivity> {
+        self.activity.first()
+    }
+
+    /// 跟随模式下应切换到的 task（未跟随 / 已锁定 / 已是当前 task 时返回 None）
+    pub fn follow_target(&self) -> Option<&str> {
+        if !self.follow_activity || self.pinned {
+            return None;
+        }
+        self.most_active()
+            .map(|a| a.task_id.as_str())
+            .filter(|id| *id != self.task_id)
+    }
+
+    /// 刷新 grid 预览（仅 grid 视图打开时）
+    pub fn refresh_grid(&mut self) {
+        if !self.grid_view || self.project_key.is_empty() {
+            return;
+        }
+        let Ok(mut active_tasks) = tasks::load_tasks(&self.project_key) else {
+            return;
+        };
+        let last_activity = |id: &str| {
+            self.activity
+                .iter()
+                .find(|a| a.task_id == id)
+                .map(|a| a.last_activity)
+        };
+        active_tasks.sort_by_key(|t| {
+            (
+                t.id != self.task_id,
+                std::cmp::Reverse(last_activity(&t.id)),
+            )
+        });
+        self.grid = active_tasks
+            .iter()
+            .take(MONITOR_GRID_MAX)
+            .map(|t| grid_cell(&self.project_key, t))
+            .collect();
+        self.grid_selected = self.grid_selected.min(self.grid.len().saturating_sub(1));
+    }
+
+    /// g 键：打开 / 关闭 grid 视图
+    pub fn toggle_grid(&mut self) {
+        self.grid_view = !self.grid_view;
+        if self.grid_view {
+            self.grid_selected = 0;
+            self.refresh_grid();
+        } else {
+            self.grid.clear();
+        }
+    }
+
+    pub fn grid_next(&mut self) {
+        if self.grid_selected + 1 < self.grid.len() {
+            self.grid_selected += 1;
+        }
+    }
+
+    pub fn grid_prev(&mut self) {
+        self.grid_selected = self.grid_selected.saturating_sub(1);
+    }
+
+    /// 切换 Monitor 展示的 task
+    pub fn switch_task(&mut self, task: &tasks::Task) {
+        self.task_id = task.id.clone();
+        self.task_name = task.name.clone();
+        self.branch = task.branch.clone();
+        self.target = task.target.clone();
+        self.worktree_path = task.worktree_path.clone();
+        self.git_scroll = 0;
+        self.notes_scroll = 0;
+        self.diff_scroll = 0;
+        self.stats_scroll = 0;
+        self.refresh_panel_data();
     }
 
     /// Tab 键：展开/折叠 sidebar
@@ -841,6 +1184,22 @@ impl MonitorState {
             self.pending_notes_edit = Some(path);
         }
     }
+
+    /// 外部编辑器退出后记录 notes 修订
+    pub fn finish_notes_edit(&mut self) {
+        let _ =
+            notes::record_notes_revision(&self.project_key, &self.task_id, notes::NoteSource::Tui);
+    }
+}
+
+/// 任务在 Web 端的 review 页面地址
+fn task_review_url(project_key: &str, task_id: &str) -> String {
+    format!(
+        "http://localhost:{}/review/{}/{}",
+        crate::cli::web::DEFAULT_PORT,
+        project_key,
+        task_id
+    )
 }
 
 /// 待 attach 的 session 信息
@@ -942,14 +1301,25 @@ impl ArchivePreflight {
 
 /// 后台操作结果
 pub enum BgResult {
-    MergeOk { task_id: String, task_name: String },
+    MergeOk {
+        task_id: String,
+        task_name: String,
+    },
     MergeErr(String),
+    PullRequestOk {
+        task_id: String,
+        pr: tasks::PullRequest,
+    },
+    PullRequestErr(String),
 }
 
 impl App {
     pub fn new() -> Self {
         // 加载配置
         let config = storage::config::load_config();
+        crate::model::set_time_display(&config.display);
+        crate::theme::set_reduced_decoration(config.accessibility.reduced_decoration);
+        crate::theme::set_status_palette(config.theme.status_palette);
         let last_system_dark = detect_system_theme();
         // 优先按 mode + 对应 slot 解析(Web 端写的就是 mode/light_theme/dark_theme,
         // 完全不写 name); auto 时用终端 dark/light 偏好选 slot,与 Web 行为对齐;
@@ -1026,8 +1396,7 @@ impl App {
             let project_path =
                 git::get_main_repo_path(&repo_path).unwrap_or_else(|_| repo_path.clone());
 
-            let target_branch =
-                git::current_branch(&project_path).unwrap_or_else(|_| "main".to_string());
+            let target_branch = crate::operations::projects::default_target_branch(&project_path);
 
             // 自动注册/更新项目 metadata
             let project_name = Path::new(&project_path)
@@ -1198,14 +1567,14 @@ impl App {
 
         self.project = ProjectState::new(project_path);
         self.async_ops.target_branch =
-            git::current_branch(project_path).unwrap_or_else(|_| "main".to_string());
+            crate::operations::projects::default_target_branch(project_path);
         self.mode = AppMode::Project;
 
         set_terminal_title(&format!("{} (grove)", project_name));
 
         // 加载 hook 通知数据（自动清理不存在的 task）
         let hooks_file = hooks::load_hooks_with_cleanup(project_path);
-        self.notification.notifications = hooks_file.tasks;
+        self.notification.set_notifications(hooks_file.tasks);
 
         // 重新初始化 FileWatcher（关闭旧的，启动新的）
         if let Some(ref watcher) = self.file_watcher {
@@ -1215,11 +1584,28 @@ impl App {
         self.start_file_watcher_project();
     }
 
+    /// 从 All Tasks tab 打开选中的任务（进入所属项目并选中）
+    pub fn open_selected_all_task(&mut self) {
+        let Some(task) = self.workspace.selected_all_task() else {
+            return;
+        };
+        let path = task.project_path.clone();
+        let task_id = task.worktree.id.clone();
+        if !Path::new(&path).exists() {
+            self.show_toast("Project directory is missing");
+            return;
+        }
+        self.enter_project(&path);
+        if !self.project.select_task(&task_id) {
+            self.show_toast("Task no longer exists");
+        }
+    }
+
     /// 从 Project 返回 Workspace
     pub fn back_to_workspace(&mut self) {
-        self.workspace.reload_projects();
-        self.notification.workspace_notifications =
-            load_all_project_notifications(&self.workspace.projects);
+        self.workspace.refresh();
+        self.notification
+            .set_workspace_notifications(load_all_project_notifications(&self.workspace.projects));
         self.mode = AppMode::Workspace;
         set_terminal_title("Grove");
     }
@@ -1328,10 +1714,12 @@ impl App {
         }
 
         // 刷新目标分支
-        if let Ok(branch) = git::current_branch(&self.project.project_path) {
-            self.async_ops.target_branch = branch;
-        }
+        self.async_ops.target_branch =
+            crate::operations::projects::default_target_branch(&self.project.project_path);
         self.dialogs.new_task_input.clear();
+        self.dialogs.new_task_templates =
+            storage::templates::load_templates(&self.project.project_key);
+        self.dialogs.new_task_template = None;
         self.dialogs.show_new_task_dialog = true;
     }
 
@@ -1339,6 +1727,34 @@ impl App {
     pub fn close_new_task_dialog(&mut self) {
         self.dialogs.show_new_task_dialog = false;
         self.dialogs.new_task_input.clear();
+        self.dialogs.new_task_template = None;
+    }
+
+    /// New Task 弹窗中的当前模板
+    fn new_task_selected_template(&self) -> Option<&storage::templates::TaskTemplate> {
+        self.dialogs
+            .new_task_template
+            .and_then(|i| self.dialogs.new_task_templates.get(i))
+    }
+
+    /// 切换 New Task 模板（无 → 模板 1 → … → 无），并按模板更新目标分支
+    pub fn new_task_cycle_template(&mut self) {
+        if self.dialogs.new_task_templates.is_empty() {
+            self.show_toast("No task templates for this project");
+            return;
+        }
+        let next = match self.dialogs.new_task_template {
+            None => Some(0),
+            Some(i) if i + 1 < self.dialogs.new_task_templates.len() => Some(i + 1),
+            Some(_) => None,
+        };
+        self.dialogs.new_task_template = next;
+        self.async_ops.target_branch = self
+            .new_task_selected_template()
+            .and_then(|t| t.target.clone())
+            .unwrap_or_else(|| {
+                crate::operations::projects::default_target_branch(&self.project.project_path)
+            });
     }
 
     /// 在 New Task 弹窗中打开分支选择器
@@ -1352,10 +1768,10 @@ impl App {
         };
         let current_target = self.async_ops.target_branch.clone();
         self.async_ops.pending_action = Some(PendingAction::NewTaskTarget);
-        self.dialogs.branch_selector = Some(BranchSelectorData::new_task_target(
-            branches,
-            current_target,
-        ));
+        self.dialogs.branch_selector = Some(
+            BranchSelectorData::new_task_target(branches, current_target)
+                .with_details(&self.project.project_path),
+        );
     }
 
     /// New Task 输入字符
@@ -1378,7 +1794,12 @@ impl App {
 
         let repo_root = self.project.project_path.clone();
         let project_key = project_hash(&repo_root);
-        let autolink_patterns = crate::storage::config::load_config().auto_link.patterns;
+        let template = self.new_task_selected_template().cloned();
+        let auto_link = crate::storage::config::load_config().auto_link;
+        let auto_link = match &template {
+            Some(t) => t.auto_link(&auto_link),
+            None => auto_link,
+        };
 
         // Phase 1: Core operation
         let result = match crate::operations::tasks::create_task(
@@ -1387,7 +1808,7 @@ impl App {
             name.clone(),
             self.async_ops.target_branch.clone(),
             &self.config.default_session_type(),
-            &autolink_patterns,
+            &auto_link,
             "user",
         ) {
             Ok(r) => r,
@@ -1398,6 +1819,22 @@ impl App {
             }
         };
 
+        // 模板: notes 骨架 + 默认 agent / 环境变量（需在构建 session 环境之前写入）
+        if let Some(ref t) = template {
+            if let Err(e) = crate::operations::tasks::apply_template(
+                &project_key,
+                &result.task,
+                t,
+                true,
+                notes::NoteSource::Tui,
+            ) {
+                self.show_toast(format!("Template: {}", e));
+            }
+        }
+        let agent_command = template
+            .and_then(|t| t.agent)
+            .unwrap_or_else(|| self.config.agent_command.clone());
+
         // Phase 2: TUI-specific session creation
         let slug = result.task.id.clone();
         let session = result.task.session_name.clone();
@@ -1434,7 +1871,7 @@ impl App {
                         &session,
                         &wt_dir,
                         &self.config.task_layout,
-                        &self.config.agent_command,
+                        &agent_command,
                         self.config.custom_layout.as_ref(),
                     ) {
                         self.show_toast(format!("Layout: {}", e));
@@ -1445,7 +1882,7 @@ impl App {
                 // Zellij: 始终生成 KDL layout 以注入环境变量
                 let kdl = crate::zellij::layout::generate_kdl(
                     &self.config.task_layout,
-                    &self.config.agent_command,
+                    &agent_command,
                     self.config.custom_layout.as_ref(),
                     &session_env.shell_export_prefix(),
                 );
@@ -1467,7 +1904,11 @@ impl App {
         // 9. 关闭弹窗，刷新数据
         self.close_new_task_dialog();
         self.project.refresh();
-        self.show_toast(format!("Created: {}", name));
+        match &result.warning {
+            // 非阻塞 post-create hook 失败
+            Some(warning) => self.show_toast(format!("Created: {} — {}", name, warning)),
+            None => self.show_toast(format!("Created: {}", name)),
+        }
 
         // 10. 标记需要 attach（主循环会暂停 TUI，attach 完成后恢复）
         self.async_ops.pending_attach = Some(PendingAttach {
@@ -1489,26 +1930,16 @@ impl App {
             return;
         };
 
-        let project_key = self.project.project_key.clone();
         let task_id = wt.id.clone();
-        let port = crate::cli::web::DEFAULT_PORT;
-        let url = format!(
-            "http://localhost:{}/review/{}/{}",
-            port, project_key, task_id
-        );
+        let url = task_review_url(&self.project.project_key, &task_id);
         self.show_toast(format!("Opening diff review: {}", task_id));
         let _ = open::that(&url);
     }
 
     /// 在浏览器中打开 diff review (Monitor 模式)
     pub fn open_diff_review_monitor(&mut self) {
-        let project_key = self.monitor.project_key.clone();
         let task_id = self.monitor.task_id.clone();
-        let port = crate::cli::web::DEFAULT_PORT;
-        let url = format!(
-            "http://localhost:{}/review/{}/{}",
-            port, project_key, task_id
-        );
+        let url = task_review_url(&self.monitor.project_key, &task_id);
         self.show_toast(format!("Opening diff review: {}", task_id));
         let _ = open::that(&url);
     }
@@ -1622,11 +2053,14 @@ impl App {
             worktree: worktree.to_string(),
             project_name,
             project_path: self.project.project_path.clone(),
+            extra: storage::templates::task_env(&self.project.project_key, task_id),
         }
     }
 
     /// 显示 Toast 消息
     pub fn show_toast(&mut self, message: impl Into<String>) {
+        let message = message.into();
+        self.notification.record_toast(&message);
         self.ui.toast = Some(Toast::new(message, Duration::from_secs(2)));
     }
 
@@ -1665,13 +2099,15 @@ impl App {
                 self.project.refresh();
                 // 重新加载通知
                 let hooks_file = hooks::load_hooks_with_cleanup(&self.project.project_path);
-                self.notification.notifications = hooks_file.tasks;
+                self.notification.set_notifications(hooks_file.tasks);
             }
             AppMode::Workspace => {
                 self.workspace.refresh();
                 // 重新加载所有项目的通知
-                self.notification.workspace_notifications =
-                    load_all_project_notifications(&self.workspace.projects);
+                self.notification
+                    .set_workspace_notifications(load_all_project_notifications(
+                        &self.workspace.projects,
+                    ));
             }
             AppMode::Monitor => {
                 self.monitor.refresh_panel_data();
@@ -1679,7 +2115,65 @@ impl App {
                 if let Some(watcher) = &self.file_watcher {
                     watcher.reload_history(&self.monitor.task_id);
                 }
+                self.refresh_monitor_activity();
+                self.monitor.refresh_grid();
+            }
+        }
+    }
+
+    /// Monitor：重新扫描活动，跟随模式下切到最近活跃的 task
+    fn refresh_monitor_activity(&mut self) {
+        self.monitor.scan_activity();
+        let Some(task_id) = self.monitor.follow_target().map(str::to_string) else {
+            return;
+        };
+        if let Ok(Some(task)) = tasks::get_task(&self.monitor.project_key, &task_id) {
+            self.monitor.switch_task(&task);
+            if let Some(watcher) = &self.file_watcher {
+                watcher.reload_history(&task.id);
+            }
+            set_terminal_title(&format!("{} (monitor)", task.name));
+        }
+    }
+
+    /// Monitor grid - Enter：切到选中的 task 并关闭 grid
+    pub fn monitor_focus_grid_task(&mut self) {
+        let Some(cell) = self.monitor.grid.get(self.monitor.grid_selected) else {
+            return;
+        };
+        let task_id = cell.task_id.clone();
+        self.monitor.toggle_grid();
+        if task_id == self.monitor.task_id {
+            return;
+        }
+        if let Ok(Some(task)) = tasks::get_task(&self.monitor.project_key, &task_id) {
+            self.monitor.switch_task(&task);
+            if let Some(watcher) = &self.file_watcher {
+                watcher.reload_history(&task.id);
             }
+            set_terminal_title(&format!("{} (monitor)", task.name));
+        }
+    }
+
+    /// Monitor - f：开关跟随活动
+    pub fn monitor_toggle_follow(&mut self) {
+        self.monitor.follow_activity = !self.monitor.follow_activity;
+        if self.monitor.follow_activity {
+            self.show_toast("Following most active task");
+            self.refresh_monitor_activity();
+        } else {
+            self.show_toast("Follow off");
+        }
+    }
+
+    /// Monitor - p：锁定 / 解锁当前 task
+    pub fn monitor_toggle_pin(&mut self) {
+        self.monitor.pinned = !self.monitor.pinned;
+        if self.monitor.pinned {
+            self.show_toast(format!("Pinned to {}", self.monitor.task_name));
+        } else {
+            self.show_toast("Unpinned");
+            self.refresh_monitor_activity();
         }
     }
 
@@ -2048,9 +2542,12 @@ impl App {
         // Generate zellij layout if applicable
         let mut layout_path: Option<String> = None;
         if matches!(task_session_type, SessionType::Zellij) {
+            let agent_command =
+                storage::templates::task_agent(&self.project.project_key, &result.task.id)
+                    .unwrap_or_else(|| self.config.agent_command.clone());
             let kdl = crate::zellij::layout::generate_kdl(
                 &self.config.task_layout,
-                &self.config.agent_command,
+                &agent_command,
                 self.config.custom_layout.as_ref(),
                 &session_env.shell_export_prefix(),
             );
@@ -2318,8 +2815,10 @@ impl App {
         self.async_ops.pending_action = Some(PendingAction::Checkout);
 
         // 打开选择器
-        self.dialogs.branch_selector =
-            Some(BranchSelectorData::new_checkout(branches, current_branch));
+        self.dialogs.branch_selector = Some(
+            BranchSelectorData::new_checkout(branches, current_branch)
+                .with_details(&self.project.project_path),
+        );
     }
 
     // ========== Rebase To 功能 ==========
@@ -2352,8 +2851,10 @@ impl App {
         self.async_ops.pending_action = Some(PendingAction::RebaseTo { task_id });
 
         // 打开选择器
-        self.dialogs.branch_selector =
-            Some(BranchSelectorData::new(branches, task_name, current_target));
+        self.dialogs.branch_selector = Some(
+            BranchSelectorData::new(branches, task_name, current_target)
+                .with_details(&self.project.project_path),
+        );
     }
 
     /// 分支选择器 - 向上
@@ -2400,14 +2901,18 @@ impl App {
 
         match self.async_ops.pending_action.take() {
             Some(PendingAction::RebaseTo { task_id }) => {
-                // 更新 task target
-                if let Err(e) =
-                    tasks::update_task_target(&self.project.project_key, &task_id, &branch)
-                {
-                    self.show_toast(format!("Failed to update target: {}", e));
-                } else {
-                    self.project.refresh();
-                    self.show_toast(format!("Target changed to {}", branch));
+                // rebase 到新 target 并记录变更历史
+                match crate::operations::tasks::retarget_task(
+                    &self.project.project_path,
+                    &self.project.project_key,
+                    &task_id,
+                    &branch,
+                ) {
+                    Ok(previous) => {
+                        self.project.refresh();
+                        self.show_toast(format!("Rebased from {} onto {}", previous, branch));
+                    }
+                    Err(e) => self.show_toast(format!("Retarget failed: {}", e)),
                 }
             }
             Some(PendingAction::NewTaskTarget) => {
@@ -2544,7 +3049,7 @@ impl App {
             Err(e) => {
                 let error_msg = e.to_string();
                 if error_msg.contains("conflict") || error_msg.contains("CONFLICT") {
-                    self.show_toast("Conflict - resolve in worktree");
+                    self.show_toast("Conflict - resolve in worktree or web Review");
                 } else {
                     self.show_toast(format!("Sync failed: {}", error_msg));
                 }
@@ -2638,16 +3143,37 @@ impl App {
         let commit_count =
             git::commits_behind(&task.worktree_path, &task.branch, &task.target).unwrap_or(0);
 
-        // 如果只有 1 个 commit，没必要 squash，直接 merge
-        if commit_count <= 1 {
-            self.do_merge(task_id, MergeMethod::MergeCommit);
+        // 如果只有 1 个 commit，没必要 squash，直接 merge；高风险任务仍需确认
+        let risk = crate::operations::risk::task_risk(&task.worktree_path, &task.target);
+        let high_risk = risk
+            .as_ref()
+            .is_some_and(|r| r.level == crate::operations::risk::RiskLevel::High);
+        // 已有 PR 或可创建 PR 时始终显示弹窗（展示 PR 状态 / 提供 Create PR）
+        let pull_request = tasks::get_pull_request(&self.project.project_key, task_id)
+            .ok()
+            .flatten();
+        let can_create_pr =
+            crate::forge::resolve(&self.project.project_path, &self.project.project_key).is_ok_and(
+                |(kind, _, _)| {
+                    crate::forge::auth(&storage::config::load_config(), kind)
+                        .token()
+                        .is_some()
+                },
+            );
+        if commit_count <= 1 && !high_risk && pull_request.is_none() && !can_create_pr {
+            self.do_merge(task_id, MergeMethod::MergeCommit, false);
         } else {
-            self.dialogs.merge_dialog = Some(MergeDialogData::new(
-                task_id.to_string(),
-                task.name,
-                task.branch,
-                task.target,
-            ));
+            let tests = crate::diff::test_changes::task_test_changes(
+                &self.project.project_key,
+                &task.worktree_path,
+                &task.target,
+            );
+            self.dialogs.merge_dialog = Some(
+                MergeDialogData::new(task_id.to_string(), task.name, task.branch, task.target)
+                    .with_risk(risk)
+                    .with_test_changes(tests)
+                    .with_pull_request(pull_request, can_create_pr),
+            );
         }
     }
 
@@ -2658,12 +3184,63 @@ impl App {
         }
     }
 
+    /// Merge 弹窗 - 切换是否附加 review 摘要
+    pub fn merge_dialog_toggle_review(&mut self) {
+        if let Some(ref mut data) = self.dialogs.merge_dialog {
+            data.toggle_include_review();
+        }
+    }
+
+    /// Merge 弹窗 - 推送分支并创建 PR / MR（后台线程，弹窗保持打开显示状态）
+    pub fn merge_dialog_create_pr(&mut self) {
+        let Some(ref mut data) = self.dialogs.merge_dialog else {
+            return;
+        };
+        if data.pr_pending || data.pull_request.is_some() {
+            return;
+        }
+        if !data.can_create_pr {
+            self.show_toast(
+                "Set a [github] / [gitlab] / [gitea] token in config.toml to create pull requests",
+            );
+            return;
+        }
+        data.pr_pending = true;
+
+        let repo_path = self.project.project_path.clone();
+        let project_key = self.project.project_key.clone();
+        let task_id = data.task_id.clone();
+
+        let (tx, rx) = mpsc::channel();
+        self.async_ops.bg_result_rx = Some(rx);
+
+        std::thread::spawn(move || {
+            let bg_result = match crate::operations::tasks::create_pull_request(
+                &repo_path,
+                &project_key,
+                &task_id,
+            ) {
+                Ok(pr) => BgResult::PullRequestOk { task_id, pr },
+                Err(e) => BgResult::PullRequestErr(e.to_string()),
+            };
+            let _ = tx.send(bg_result);
+        });
+    }
+
     /// Merge 弹窗 - 确认
     pub fn merge_dialog_confirm(&mut self) {
+        if self
+            .dialogs
+            .merge_dialog
+            .as_ref()
+            .is_some_and(|d| d.pr_pending)
+        {
+            return;
+        }
         let dialog_data = self.dialogs.merge_dialog.take();
         let Some(data) = dialog_data else { return };
 
-        self.do_merge(&data.task_id, data.selected);
+        self.do_merge(&data.task_id, data.selected, data.include_review);
     }
 
     /// Merge 弹窗 - 取消
@@ -2672,7 +3249,7 @@ impl App {
     }
 
     /// 执行 Merge（后台线程）
-    fn do_merge(&mut self, task_id: &str, method: MergeMethod) {
+    fn do_merge(&mut self, task_id: &str, method: MergeMethod, include_review: bool) {
         // 设置 loading 状态
         self.async_ops.loading_message = Some("Merging...".to_string());
 
@@ -2698,11 +3275,16 @@ impl App {
                     &project_key,
                     &task_id,
                     ops_method,
+                    include_review,
                 ) {
                     Ok(result) => BgResult::MergeOk {
                         task_id: result.task_id,
                         task_name: result.task_name,
                     },
+                    // merge 门禁的 tagged error 只展示说明文字
+                    Err(crate::error::GroveError::StorageTagged { msg, .. }) => {
+                        BgResult::MergeErr(msg)
+                    }
                     Err(e) => BgResult::MergeErr(e.to_string()),
                 };
                 let _ = tx.send(bg_result);
@@ -2715,7 +3297,7 @@ impl App {
                 } else {
                     "unknown panic".to_string()
                 };
-                eprintln!("[Grove] Merge thread panicked: {}", msg);
+                tracing::error!("Merge thread panicked: {}", msg);
             }
         });
     }
@@ -2741,6 +3323,21 @@ impl App {
                 BgResult::MergeErr(e) => {
                     self.show_toast(e);
                 }
+                BgResult::PullRequestOk { task_id, pr } => {
+                    self.show_toast(format!("Opened PR #{}: {}", pr.number, pr.url));
+                    if let Some(ref mut data) = self.dialogs.merge_dialog {
+                        if data.task_id == task_id {
+                            data.pr_pending = false;
+                            data.pull_request = Some(pr);
+                        }
+                    }
+                }
+                BgResult::PullRequestErr(e) => {
+                    if let Some(ref mut data) = self.dialogs.merge_dialog {
+                        data.pr_pending = false;
+                    }
+                    self.show_toast(format!("Create PR failed: {}", e));
+                }
             }
         }
     }
@@ -3004,21 +3601,60 @@ impl App {
         let actions = if is_local {
             // Local Task: 仅 Commit 和 Review(非 git 项目禁用 Commit)
             if self.project.is_git_usable {
-                vec![ActionType::Commit, ActionType::Review]
+                vec![
+                    ActionType::Commit,
+                    ActionType::Review,
+                    ActionType::Search,
+                    ActionType::NotesHistory,
+                    ActionType::ProjectNotes,
+                    ActionType::OpenWith,
+                    ActionType::CopyBranch,
+                    ActionType::CopyPath,
+                    ActionType::CopyAttach,
+                    ActionType::CopyUrl,
+                    ActionType::Stashes,
+                ]
             } else {
-                vec![ActionType::Review]
+                vec![
+                    ActionType::Review,
+                    ActionType::Search,
+                    ActionType::NotesHistory,
+                    ActionType::ProjectNotes,
+                    ActionType::OpenWith,
+                    ActionType::CopyBranch,
+                    ActionType::CopyPath,
+                    ActionType::CopyAttach,
+                    ActionType::CopyUrl,
+                ]
             }
         } else {
             match self.project.current_tab {
-                ProjectTab::Archived => vec![ActionType::Clean, ActionType::Recover],
+                ProjectTab::Archived => vec![
+                    ActionType::ProjectNotes,
+                    ActionType::CopyBranch,
+                    ActionType::CopyUrl,
+                    ActionType::Clean,
+                    ActionType::Recover,
+                ],
                 ProjectTab::Active => vec![
                     // Edit
                     ActionType::Commit,
                     ActionType::Review,
+                    ActionType::Links,
+                    ActionType::Search,
+                    ActionType::NotesHistory,
+                    ActionType::ProjectNotes,
+                    ActionType::OpenWith,
+                    // Copy
+                    ActionType::CopyBranch,
+                    ActionType::CopyPath,
+                    ActionType::CopyAttach,
+                    ActionType::CopyUrl,
                     // Branch
                     ActionType::RebaseTo,
                     ActionType::Sync,
                     ActionType::Merge,
+                    ActionType::Stashes,
                     // Session
                     ActionType::Archive,
                     ActionType::Clean,
@@ -3078,6 +3714,16 @@ impl App {
                 ActionType::Recover => self.start_recover(),
                 ActionType::Commit => self.open_commit_dialog(),
                 ActionType::Review => self.open_diff_review_project(),
+                ActionType::Links => self.open_autolink_panel(),
+                ActionType::Search => self.open_search_panel(),
+                ActionType::NotesHistory => self.open_notes_history(),
+                ActionType::ProjectNotes => self.open_project_notes(),
+                ActionType::OpenWith => self.open_open_with(),
+                ActionType::Stashes => self.open_stash_panel(),
+                ActionType::CopyBranch
+                | ActionType::CopyPath
+                | ActionType::CopyAttach
+                | ActionType::CopyUrl => self.copy_task_value(action),
                 ActionType::Reset => self.start_reset(),
             }
         }
@@ -3099,6 +3745,7 @@ impl App {
         if let Some(idx) = selected_idx {
             if let Some(worktree) = worktrees.get(idx) {
                 self.dialogs.commit_dialog = Some(CommitDialogData::new(
+                    worktree.id.clone(),
                     worktree.task_name.clone(),
                     worktree.path.clone(),
                 ));
@@ -3136,7 +3783,9 @@ impl App {
             }
 
             // 执行 git add -A && git commit
-            let result = git::add_and_commit(&dialog.worktree_path, &dialog.message);
+            let options =
+                git::CommitOptions::resolve(&self.project.project_key, Some(&dialog.task_id));
+            let result = git::add_and_commit(&dialog.worktree_path, &dialog.message, &options);
 
             match result {
                 Ok(_) => {
@@ -3150,16 +3799,532 @@ impl App {
             }
         }
     }
+    // ========== Notification Center 功能 ==========
+
+    /// 打开通知中心
+    pub fn open_notification_center(&mut self) {
+        self.dialogs.notification_center = Some(Default::default());
+    }
+
+    /// 切换勿扰模式（静音声音和系统横幅，通知仍进入 Inbox）
+    pub fn toggle_do_not_disturb(&mut self) {
+        let mut config = storage::config::load_config();
+        let enabled = !config.notifications.do_not_disturb;
+        config.notifications.do_not_disturb = enabled;
+        if let Err(e) = storage::config::save_config(&config) {
+            self.show_toast(format!("Do Not Disturb failed: {}", e));
+            return;
+        }
+        self.show_toast(if enabled {
+            "Do Not Disturb on"
+        } else if config
+            .notifications
+            .quiet_hours
+            .contains(chrono::Local::now().time())
+        {
+            "Do Not Disturb off (quiet hours still active)"
+        } else {
+            "Do Not Disturb off"
+        });
+    }
+
+    // ========== Inbox 功能 ==========
+
+    /// 切换选中项目的置顶状态
+    pub fn toggle_pin_project(&mut self) {
+        let Some(project) = self.workspace.selected_project() else {
+            return;
+        };
+        let path = project.path.clone();
+        let pinned = !project.pinned;
+        if let Err(e) = storage::workspace::set_project_pinned(&project_hash(&path), pinned) {
+            self.show_toast(format!("Pin failed: {}", e));
+            return;
+        }
+        self.workspace.reload_projects();
+        self.workspace.select_project(&path);
+        self.show_toast(if pinned { "Pinned" } else { "Unpinned" });
+    }
+
+    /// 切换选中任务的置顶状态（Local Task 固定在最前，不参与置顶）
+    pub fn toggle_pin_task(&mut self) {
+        let Some(wt) = self.project.selected_worktree() else {
+            return;
+        };
+        if wt.is_local {
+            self.show_toast("Local Task is always first");
+            return;
+        }
+        let task_id = wt.id.clone();
+        let pinned = !wt.pinned;
+        if let Err(e) = tasks::update_task_pinned(&self.project.project_key, &task_id, pinned) {
+            self.show_toast(format!("Pin failed: {}", e));
+            return;
+        }
+        self.project.refresh();
+        self.project.select_task(&task_id);
+        self.show_toast(if pinned { "Pinned" } else { "Unpinned" });
+    }
+
+    /// 切换任务列表排序方式
+    pub fn cycle_task_sort(&mut self) {
+        let sort = self.project.cycle_sort();
+        self.show_toast(format!("Sort: {}", sort.label()));
+    }
+
+    /// 切换到下一个保存的视图
+    pub fn cycle_task_view(&mut self) {
+        if self.project.views.is_empty() {
+            self.show_toast("No saved views (create one in the web UI)");
+            return;
+        }
+        let msg = match self.project.cycle_view() {
+            Some(view) => format!("View: {}", view.name),
+            None => "View: All tasks".to_string(),
+        };
+        self.show_toast(msg);
+    }
+
+    /// 打开当前选中任务的 AutoLink 面板
+    pub fn open_autolink_panel(&mut self) {
+        let Some(wt) = self.project.selected_worktree_cloned() else {
+            return;
+        };
+        match AutoLinkPanelData::load(
+            &self.project.project_path,
+            &self.project.project_key,
+            &wt.id,
+            &wt.task_name,
+        ) {
+            Ok(data) => self.dialogs.autolink_panel = Some(data),
+            Err(e) => self.show_toast(format!("AutoLinks: {}", e)),
+        }
+    }
+
+    /// AutoLink 面板 - 按当前配置重新创建链接
+    pub fn autolink_resync(&mut self) {
+        let Some(ref mut data) = self.dialogs.autolink_panel else {
+            return;
+        };
+        let result = crate::operations::autolink::resync_task_links(
+            &data.project_path,
+            &data.project_key,
+            &data.task_id,
+        );
+        let _ = data.reload();
+        match result {
+            Ok(created) if created.is_empty() => self.show_toast("AutoLinks already up to date"),
+            Ok(created) => self.show_toast(format!("Created {} link(s)", created.len())),
+            Err(e) => self.show_toast(format!("Re-sync failed: {}", e)),
+        }
+    }
+
+    /// AutoLink 面板 - 刷新过期的副本
+    pub fn autolink_refresh(&mut self) {
+        let Some(ref mut data) = self.dialogs.autolink_panel else {
+            return;
+        };
+        let result = crate::operations::autolink::refresh_stale_copies(
+            &data.project_path,
+            &data.project_key,
+            &data.task_id,
+        );
+        let _ = data.reload();
+        match result {
+            Ok(refreshed) if refreshed.is_empty() => self.show_toast("No stale copies"),
+            Ok(refreshed) => self.show_toast(format!("Refreshed {} copy(s)", refreshed.len())),
+            Err(e) => self.show_toast(format!("Refresh failed: {}", e)),
+        }
+    }
+
+    /// AutoLink 面板 - 删除选中的链接
+    pub fn autolink_remove_selected(&mut self) {
+        let Some(ref mut data) = self.dialogs.autolink_panel else {
+            return;
+        };
+        let Some(path) = data.selected_link().map(|l| l.path.clone()) else {
+            return;
+        };
+        let result = crate::operations::autolink::remove_task_link(
+            &data.project_path,
+            &data.project_key,
+            &data.task_id,
+            &path,
+        );
+        let _ = data.reload();
+        match result {
+            Ok(()) => self.show_toast(format!("Removed {}", path)),
+            Err(e) => self.show_toast(format!("Remove failed: {}", e)),
+        }
+    }
+
+    /// AutoLink 面板 - 清理坏链接
+    pub fn autolink_clean(&mut self) {
+        let Some(ref mut data) = self.dialogs.autolink_panel else {
+            return;
+        };
+        let result = crate::operations::autolink::clean_broken_links(
+            &data.project_path,
+            &data.project_key,
+            &data.task_id,
+        );
+        let _ = data.reload();
+        match result {
+            Ok(removed) if removed.is_empty() => self.show_toast("No broken links"),
+            Ok(removed) => self.show_toast(format!("Cleaned {} link(s)", removed.len())),
+            Err(e) => self.show_toast(format!("Clean failed: {}", e)),
+        }
+    }
+
+    /// 打开当前选中任务的内容搜索面板
+    pub fn open_search_panel(&mut self) {
+        let Some(wt) = self.project.selected_worktree_cloned() else {
+            return;
+        };
+        self.dialogs.search_panel = Some(SearchPanelData::new(&wt.path, &wt.task_name));
+    }
+
+    /// 打开当前选中任务的 notes 历史版本
+    pub fn open_notes_history(&mut self) {
+        let Some(wt) = self.project.selected_worktree_cloned() else {
+            return;
+        };
+        self.dialogs.notes_history = Some(NotesHistoryData::load(
+            &self.project.project_key,
+            &wt.id,
+            &wt.task_name,
+        ));
+    }
+
+    /// 复制当前选中任务的分支 / 路径 / attach 命令 / review URL
+    pub fn copy_task_value(&mut self, action: ActionType) {
+        let Some(wt) = self.project.selected_worktree_cloned() else {
+            return;
+        };
+        let project_key = &self.project.project_key;
+        let (label, text) = match action {
+            ActionType::CopyBranch => ("branch", Some(wt.branch.clone())),
+            ActionType::CopyPath => ("path", Some(wt.path.clone())),
+            ActionType::CopyAttach => (
+                "attach command",
+                crate::operations::tasks::task_attach_command(project_key, &wt.id, &wt.multiplexer),
+            ),
+            ActionType::CopyUrl => ("task URL", Some(task_review_url(project_key, &wt.id))),
+            _ => return,
+        };
+        match text {
+            Some(text) => self.show_toast(clipboard::copy_with_message(label, &text)),
+            None => self.show_toast("Chat task has no terminal session to attach"),
+        }
+    }
+
+    /// 打开 Open With 应用选择器
+    pub fn open_open_with(&mut self) {
+        let Some(wt) = self.project.selected_worktree_cloned() else {
+            return;
+        };
+        let data = OpenWithData::load(&wt.task_name, &wt.path, &wt.branch);
+        if data.apps.is_empty() {
+            self.show_toast("No Open With apps — add [[open_with]] to config.toml");
+            return;
+        }
+        self.dialogs.open_with = Some(data);
+    }
+
+    /// 打开任务列表列选择器
+    pub fn open_column_picker(&mut self) {
+        self.dialogs.column_picker = Some(ColumnPickerData::new(&self.project.columns));
+    }
+
+    /// 保存列选择器的结果到 config.toml 并应用到当前列表
+    pub fn save_task_columns(&mut self) {
+        let Some(data) = self.dialogs.column_picker.take() else {
+            return;
+        };
+        let columns = data.columns();
+        let mut config = storage::config::load_config();
+        config.task_list.columns = columns.clone();
+        if let Err(e) = storage::config::save_config(&config) {
+            self.show_toast(format!("Save columns failed: {}", e));
+            return;
+        }
+        self.project.set_columns(columns);
+        self.show_toast("Task columns saved");
+    }
+
+    /// 打开 Stash 管理面板（默认应用到当前选中任务的 worktree）
+    pub fn open_stash_panel(&mut self) {
+        let task = self
+            .project
+            .selected_worktree_cloned()
+            .filter(|wt| !wt.is_local && std::path::Path::new(&wt.path).is_dir())
+            .map(|wt| (wt.task_name, wt.branch, wt.path));
+        self.dialogs.stash_panel = Some(StashPanelData::load(&self.project.project_path, task));
+    }
+
+    /// 用所选应用打开任务 worktree
+    pub fn open_with_selected(&mut self) {
+        let Some(data) = self.dialogs.open_with.take() else {
+            return;
+        };
+        let Some(app) = data.selected_app() else {
+            return;
+        };
+        match crate::operations::tasks::open_task_with(&app.name, &data.path, &data.branch) {
+            Ok(name) => self.show_toast(format!("Opening in {}", name)),
+            Err(e) => self.show_toast(format!("Open failed: {}", e)),
+        }
+    }
+
+    /// 打开项目级共享笔记
+    pub fn open_project_notes(&mut self) {
+        let project_name = Path::new(&self.project.project_path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+        self.dialogs.project_notes = Some(ProjectNotesData::load(
+            &self.project.project_key,
+            &project_name,
+        ));
+    }
+
+    /// 外部编辑器退出后同步项目笔记元数据并刷新面板
+    pub fn finish_project_note_edit(&mut self, slug: &str) {
+        let _ = project_notes::touch_note(&self.project.project_key, slug);
+        if let Some(ref mut data) = self.dialogs.project_notes {
+            data.reload();
+            data.select_slug(slug);
+        }
+    }
+
+    /// 打开 Inbox
+    pub fn open_inbox(&mut self) {
+        self.dialogs.inbox = Some(InboxPanelData::load());
+    }
+
+    /// 跳转到 Inbox 选中项：进入项目并选中任务，权限请求直接打开对应 chat
+    pub fn jump_to_inbox_item(&mut self) {
+        let Some(item) = self
+            .dialogs
+            .inbox
+            .as_ref()
+            .and_then(|d| d.selected_item())
+            .cloned()
+        else {
+            return;
+        };
+        if self.mode == AppMode::Monitor {
+            self.show_toast("Open Grove outside a task to jump");
+            return;
+        }
+        if item.project_path.is_empty() {
+            self.show_toast("Project is no longer registered");
+            return;
+        }
+        self.dialogs.inbox = None;
+
+        if self.mode != AppMode::Project || self.project.project_path != item.project_path {
+            self.enter_project(&item.project_path);
+        }
+        if !self.project.select_task(&item.task_id) {
+            self.show_toast(format!("Task '{}' not found", item.task_name));
+            return;
+        }
+
+        if let InboxKind::Permission { chat_id, .. } = &item.kind {
+            self.open_chat_view();
+            if let Some(ref mut data) = self.dialogs.chat_view {
+                if let Some(index) = data.chats.iter().position(|c| &c.id == chat_id) {
+                    data.list_state.select(Some(index));
+                    data.open_selected();
+                }
+            }
+        }
+    }
+
+    /// 打开全局搜索
+    pub fn open_global_search(&mut self) {
+        self.dialogs.global_search = Some(GlobalSearchData::default());
+    }
+
+    /// 跳转到搜索结果：进入项目并选中任务，chat 结果直接打开对应 chat
+    pub fn jump_to_search_hit(&mut self) {
+        let Some(hit) = self
+            .dialogs
+            .global_search
+            .as_ref()
+            .and_then(|d| d.selected_hit())
+            .cloned()
+        else {
+            return;
+        };
+        if hit.archived {
+            self.show_toast("Task is archived");
+            return;
+        }
+        self.dialogs.global_search = None;
+
+        if self.mode != AppMode::Project || self.project.project_path != hit.project_path {
+            self.enter_project(&hit.project_path);
+        }
+        let Some(ref task_id) = hit.task_id else {
+            // 项目笔记
+            return;
+        };
+        if !self.project.select_task(task_id) {
+            self.show_toast(format!(
+                "Task '{}' not found",
+                hit.task_name.as_deref().unwrap_or(task_id)
+            ));
+            return;
+        }
+
+        if let Some(ref chat_id) = hit.chat_id {
+            self.open_chat_view();
+            if let Some(ref mut data) = self.dialogs.chat_view {
+                if let Some(index) = data.chats.iter().position(|c| &c.id == chat_id) {
+                    data.list_state.select(Some(index));
+                    data.open_selected();
+                }
+            }
+        }
+    }
+
+    /// 复制选中通知的完整内容到剪贴板
+    pub fn copy_selected_notification(&mut self) {
+        let Some(ref data) = self.dialogs.notification_center else {
+            return;
+        };
+        let Some(record) = self.notification.history.get(data.selected) else {
+            return;
+        };
+        let text = format!(
+            "[{}] {} {}\n{}",
+            record.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            record.source,
+            match record.level {
+                hooks::NotificationLevel::Notice => "info",
+                hooks::NotificationLevel::Warn => "warn",
+                hooks::NotificationLevel::Critical => "error",
+            },
+            record.message
+        );
+        // 复制提示只走 Toast，不记入通知中心
+        let msg = if clipboard::copy(&text).copied() {
+            "Copied to clipboard"
+        } else {
+            "Clipboard unavailable"
+        };
+        self.ui.toast = Some(Toast::new(msg, Duration::from_secs(2)));
+    }
+
+    // ========== Chat View 功能 ==========
+
+    /// 打开选中任务的 ACP Chat 面板
+    pub fn open_chat_view(&mut self) {
+        let Some(wt) = self.project.selected_worktree() else {
+            return;
+        };
+        let project_key = self.project.project_key.clone();
+        let task = match tasks::get_task(&project_key, &wt.id) {
+            Ok(Some(task)) => task,
+            Ok(None) => {
+                self.show_toast("Task not found");
+                return;
+            }
+            Err(e) => {
+                self.show_toast(format!("Failed to load task: {}", e));
+                return;
+            }
+        };
+        let project_path = self.project.project_path.clone();
+        let project_name = Path::new(&project_path)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        self.dialogs.chat_view = Some(ChatViewData::new(
+            &project_key,
+            &project_path,
+            &project_name,
+            task,
+        ));
+    }
+
+    /// 刷新 Chat 面板（主循环调用）
+    pub fn poll_chat_view(&mut self) {
+        if let Some(ref mut data) = self.dialogs.chat_view {
+            data.poll();
+        }
+    }
+
     // ========== Config Panel 功能 ==========
 
     /// 打开 Config 配置面板
     pub fn open_config_panel(&mut self) {
         let config = storage::config::load_config();
-        self.dialogs.config_panel = Some(ConfigPanelData::with_multiplexer(
+        let mut panel = ConfigPanelData::with_multiplexer(
             &config.layout,
             &config.terminal_multiplexer,
             &config.auto_link,
-        ));
+        );
+        panel.time_format = config.display.time_format;
+        panel.reduced_decoration = config.accessibility.reduced_decoration;
+        panel.status_palette = config.theme.status_palette;
+        self.dialogs.config_panel = Some(panel);
+    }
+
+    /// Config Panel - 循环切换状态色方案（默认 → 绿色盲友好 → 红色盲友好）
+    fn config_cycle_status_palette(&mut self) {
+        let mut config = storage::config::load_config();
+        let all = StatusPalette::all();
+        let index = all
+            .iter()
+            .position(|p| *p == config.theme.status_palette)
+            .unwrap_or(0);
+        let palette = all[(index + 1) % all.len()];
+        config.theme.status_palette = palette;
+        if let Err(e) = storage::config::save_config(&config) {
+            self.show_toast(format!("Save failed: {}", e));
+            return;
+        }
+        crate::theme::set_status_palette(palette);
+        self.ui.colors = get_theme_colors(self.ui.theme);
+        if let Some(ref mut panel) = self.dialogs.config_panel {
+            panel.status_palette = palette;
+        }
+    }
+
+    /// Config Panel - 切换减少装饰（屏幕阅读器友好）模式
+    fn config_toggle_reduced_decoration(&mut self) {
+        let mut config = storage::config::load_config();
+        let enabled = !config.accessibility.reduced_decoration;
+        config.accessibility.reduced_decoration = enabled;
+        if let Err(e) = storage::config::save_config(&config) {
+            self.show_toast(format!("Save failed: {}", e));
+            return;
+        }
+        crate::theme::set_reduced_decoration(enabled);
+        if let Some(ref mut panel) = self.dialogs.config_panel {
+            panel.reduced_decoration = enabled;
+        }
+    }
+
+    /// Config Panel - 切换相对 / 绝对时间显示
+    fn config_toggle_time_format(&mut self) {
+        let mut config = storage::config::load_config();
+        config.display.time_format = match config.display.time_format {
+            TimeFormat::Relative => TimeFormat::Absolute,
+            TimeFormat::Absolute => TimeFormat::Relative,
+        };
+        if let Err(e) = storage::config::save_config(&config) {
+            self.show_toast(format!("Save failed: {}", e));
+            return;
+        }
+        crate::model::set_time_display(&config.display);
+        if let Some(ref mut panel) = self.dialogs.config_panel {
+            panel.time_format = config.display.time_format;
+        }
     }
 
     /// Config Panel - 上移选择
@@ -3168,7 +4333,7 @@ impl App {
             match panel.step {
                 ConfigStep::Main => {
                     if panel.main_selected == 0 {
-                        panel.main_selected = 5;
+                        panel.main_selected = 8;
                     } else {
                         panel.main_selected -= 1;
                     }
@@ -3219,7 +4384,7 @@ impl App {
         if let Some(ref mut panel) = self.dialogs.config_panel {
             match panel.step {
                 ConfigStep::Main => {
-                    panel.main_selected = (panel.main_selected + 1) % 6;
+                    panel.main_selected = (panel.main_selected + 1) % 9;
                 }
                 ConfigStep::SelectLayout => {
                     let count = TaskLayout::all().len() + 1;
@@ -3266,6 +4431,9 @@ impl App {
                                 crate::ui::components::hook_panel::HookConfigData::new();
                         }
                         5 => panel.step = ConfigStep::McpConfig,
+                        6 => self.config_toggle_time_format(),
+                        7 => self.config_toggle_reduced_decoration(),
+                        8 => self.config_cycle_status_palette(),
                         _ => {}
                     }
                 }
@@ -3337,9 +4505,7 @@ impl App {
 
                     // 如果进入结果页，复制到剪贴板
                     if panel.hook_data.step == HookConfigStep::ShowResult {
-                        if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                            let _ = clipboard.set_text(&panel.hook_data.generated_command);
-                        }
+                        clipboard::copy(&panel.hook_data.generated_command);
                     }
                 }
             }
@@ -3478,6 +4644,27 @@ impl App {
         }
     }
 
+    /// Config Panel - 切换选中模式的创建方式（symlink → copy → hardlink）
+    pub fn config_autolink_cycle_mode(&mut self) {
+        if let Some(ref mut panel) = self.dialogs.config_panel {
+            let Some(pattern) = panel
+                .autolink_patterns
+                .get(panel.autolink_selected)
+                .cloned()
+            else {
+                return;
+            };
+            let next = panel
+                .autolink_modes
+                .get(&pattern)
+                .copied()
+                .unwrap_or_default()
+                .next();
+            panel.autolink_modes.insert(pattern, next);
+            self.config_save_autolink();
+        }
+    }
+
     /// Config Panel - AutoLink 输入字符
     pub fn config_autolink_input_char(&mut self, c: char) {
         if let Some(ref mut panel) = self.dialogs.config_panel {
@@ -3499,11 +4686,9 @@ impl App {
         if let Some(ref panel) = self.dialogs.config_panel {
             if panel.step == ConfigStep::HookWizard
                 && panel.hook_data.step == HookConfigStep::ShowResult
+                && clipboard::copy(&panel.hook_data.generated_command).copied()
             {
-                if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                    let _ = clipboard.set_text(&panel.hook_data.generated_command);
-                    self.show_toast("Copied to clipboard");
-                }
+                self.show_toast("Copied to clipboard");
             }
         }
     }
@@ -3623,16 +4808,18 @@ impl App {
 
     /// Config Panel - 保存 AutoLink 配置
     fn config_save_autolink(&mut self) {
-        let patterns = self
+        let (patterns, modes) = self
             .dialogs
             .config_panel
             .as_ref()
-            .map(|p| p.autolink_patterns.clone())
+            .map(|p| (p.autolink_patterns.clone(), p.autolink_modes.clone()))
             .unwrap_or_default();
 
         // 保存到文件
         let mut config = storage::config::load_config();
         config.auto_link.patterns = patterns;
+        config.auto_link.modes = modes;
+        config.auto_link.normalize();
         let _ = storage::config::save_config(&config);
 
         self.show_toast("AutoLink saved");
@@ -3652,7 +4839,11 @@ impl App {
             if let Some(idx) = panel.autolink_editing {
                 // 编辑现有模式
                 if idx < panel.autolink_patterns.len() {
-                    panel.autolink_patterns[idx] = pattern;
+                    // 模式改名时保留其创建方式
+                    let old = std::mem::replace(&mut panel.autolink_patterns[idx], pattern.clone());
+                    if let Some(mode) = panel.autolink_modes.remove(&old) {
+                        panel.autolink_modes.insert(pattern, mode);
+                    }
                 }
             } else {
                 // 添加新模式
@@ -3855,7 +5046,11 @@ impl App {
                     self.show_toast("No worktree path");
                     return;
                 }
-                self.dialogs.commit_dialog = Some(CommitDialogData::new(task_name, worktree_path));
+                self.dialogs.commit_dialog = Some(CommitDialogData::new(
+                    self.monitor.task_id.clone(),
+                    task_name,
+                    worktree_path,
+                ));
             }
             MonitorAction::Sync => {
                 self.monitor_sync();
@@ -3871,12 +5066,24 @@ impl App {
                 let commit_count =
                     git::commits_behind(&worktree_path, &branch, &target).unwrap_or(0);
 
-                // 如果只有 1 个 commit，没必要 squash，直接 merge
-                if commit_count <= 1 {
-                    self.do_merge(&task_id, MergeMethod::MergeCommit);
+                // 如果只有 1 个 commit，没必要 squash，直接 merge；高风险任务仍需确认
+                let risk = crate::operations::risk::task_risk(&worktree_path, &target);
+                let high_risk = risk
+                    .as_ref()
+                    .is_some_and(|r| r.level == crate::operations::risk::RiskLevel::High);
+                if commit_count <= 1 && !high_risk {
+                    self.do_merge(&task_id, MergeMethod::MergeCommit, false);
                 } else {
-                    self.dialogs.merge_dialog =
-                        Some(MergeDialogData::new(task_id, task_name, branch, target));
+                    let tests = crate::diff::test_changes::task_test_changes(
+                        &self.project.project_key,
+                        &worktree_path,
+                        &target,
+                    );
+                    self.dialogs.merge_dialog = Some(
+                        MergeDialogData::new(task_id, task_name, branch, target)
+                            .with_risk(risk)
+                            .with_test_changes(tests),
+                    );
                 }
             }
             MonitorAction::Archive => {
@@ -4009,3 +5216,39 @@ fn load_all_project_notifications(
     }
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn activity(task_id: &str, secs_ago: i64) -> TaskActivity {
+        TaskActivity {
+            task_id: task_id.to_string(),
+            task_name: task_id.to_string(),
+            last_activity: Utc::now() - chrono::Duration::seconds(secs_ago),
+        }
+    }
+
+    #[test]
+    fn follow_target_switches_to_most_active_unless_pinned() {
+        let mut monitor = MonitorState {
+            task_id: "a".to_string(),
+            activity: vec![activity("b", 5), activity("a", 60)],
+            ..MonitorState::default()
+        };
+        // 未开启跟随时不切换
+        assert_eq!(monitor.follow_target(), None);
+
+        monitor.follow_activity = true;
+        assert_eq!(monitor.follow_target(), Some("b"));
+
+        // 锁定后保持当前 task
+        monitor.pinned = true;
+        assert_eq!(monitor.follow_target(), None);
+
+        // 已经在最活跃的 task 上
+        monitor.pinned = false;
+        monitor.task_id = "b".to_string();
+        assert_eq!(monitor.follow_target(), None);
+    }
+}
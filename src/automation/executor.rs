@@ -24,7 +24,7 @@ use std::time::Duration;
 use chrono::Utc;
 use tokio::sync::broadcast::error::RecvError;
 
-use crate::acp::{AcpUpdate, QueuedMessage};
+use crate::acp::{AcpEvent, AcpUpdate, QueuedMessage};
 use crate::agent_graph::tools::ensure_target_handle;
 use crate::storage::{
     automations::{self, Automation, TargetMode},
@@ -322,7 +322,7 @@ fn agent_snapshot(a: &Automation) -> String {
 async fn wait_for_completion(
     run_id: String,
     sender: String,
-    mut rx: tokio::sync::broadcast::Receiver<AcpUpdate>,
+    mut rx: tokio::sync::broadcast::Receiver<AcpEvent>,
 ) {
     let mut started = false;
     let mut response = String::new();
@@ -372,7 +372,7 @@ async fn wait_for_completion(
                 awarn!("broadcast lagged for run {run_id}: skipped {n} events");
                 continue;
             }
-            Ok(Ok(update)) => match update {
+            Ok(Ok(AcpEvent { update, .. })) => match update {
                 AcpUpdate::UserMessage {
                     sender: Some(s), ..
                 } if s == sender => {
@@ -517,12 +517,10 @@ fn resolve_task(a: &Automation) -> Result<String, String> {
                     "automation",
                 )
             } else {
-                let target = template
-                    .target
-                    .clone()
-                    .or_else(|| crate::git::current_branch(&project.path).ok())
-                    .unwrap_or_else(|| "main".to_string());
-                let autolink = &cfg.auto_link.patterns;
+                let target = template.target.clone().unwrap_or_else(|| {
+                    crate::operations::projects::default_target_branch(&project.path)
+                });
+                let autolink = &cfg.auto_link;
                 crate::operations::tasks::create_task(
                     &project.path,
                     &a.project,
@@ -537,7 +535,12 @@ fn resolve_task(a: &Automation) -> Result<String, String> {
 
             if let Some(notes) = template.notes.as_ref() {
                 if !notes.is_empty() {
-                    let _ = crate::storage::notes::save_notes(&a.project, &result.task.id, notes);
+                    let _ = crate::storage::notes::save_notes(
+                        &a.project,
+                        &result.task.id,
+                        notes,
+                        crate::storage::notes::NoteSource::Automation,
+                    );
                 }
             }
 
@@ -614,6 +617,7 @@ fn resolve_chat(a: &Automation, task_id: &str) -> Result<String, String> {
                 created_at: now,
                 duty: None,
                 launch_mode,
+                work_subdir: None,
             };
             tasks::add_chat_session(&a.project, task_id, chat.clone())
                 .map_err(|e| e.to_string())?;
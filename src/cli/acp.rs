@@ -4,10 +4,29 @@ use std::collections::HashMap;
 use std::io::{self, BufRead, Write};
 use std::path::PathBuf;
 
-use crate::acp::{self, AcpStartConfig, AcpUpdate};
+use crate::acp::{self, AcpStartConfig, AcpUpdate, ContentBlockData};
+
+/// 解析 `--attach`：JSON 数组本身，或包含 JSON 数组的文件路径
+fn load_attachments(arg: &str) -> Result<Vec<ContentBlockData>, String> {
+    let json = if arg.trim_start().starts_with('[') {
+        arg.to_string()
+    } else {
+        std::fs::read_to_string(arg).map_err(|e| format!("cannot read {}: {}", arg, e))?
+    };
+    serde_json::from_str(&json).map_err(|e| format!("invalid content blocks: {}", e))
+}
 
 /// 执行 ACP 交互式聊天
-pub async fn execute(agent: String, cwd: String) {
+pub async fn execute(agent: String, cwd: String, attach: Option<String>) {
+    // 附件随第一条 prompt 发送
+    let mut attachments = match attach.as_deref().map(load_attachments).transpose() {
+        Ok(blocks) => blocks.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
     let working_dir = PathBuf::from(&cwd)
         .canonicalize()
         .unwrap_or_else(|_| PathBuf::from(&cwd));
@@ -38,6 +57,9 @@ pub async fn execute(agent: String, cwd: String) {
         remote_auth: resolved.auth_header,
         suppress_initial_connecting: false,
         persona_injection: None,
+        initial_mode: None,
+        initial_model: None,
+        share_process: false,
     };
 
     let (handle, mut update_rx) = match acp::get_or_start_session("cli".to_string(), config).await {
@@ -50,7 +72,7 @@ pub async fn execute(agent: String, cwd: String) {
 
     // 等待 SessionReady
     loop {
-        match update_rx.recv().await {
+        match update_rx.recv().await.map(|e| e.update) {
             Ok(AcpUpdate::SessionReady {
                 session_id,
                 agent_name,
@@ -79,6 +101,13 @@ pub async fn execute(agent: String, cwd: String) {
         }
     }
 
+    if !attachments.is_empty() {
+        eprintln!(
+            "{} attachment(s) will be sent with the first prompt.",
+            attachments.len()
+        );
+    }
+
     // 交互式 prompt 循环
     let stdin = io::stdin();
     let mut reader = stdin.lock();
@@ -110,7 +139,7 @@ pub async fn execute(agent: String, cwd: String) {
 
         // 发送 prompt
         if let Err(e) = handle_for_input
-            .send_prompt(text, vec![], None, false, None)
+            .send_prompt(text, std::mem::take(&mut attachments), None, false, None)
             .await
         {
             eprintln!("Failed to send prompt: {}", e);
@@ -119,7 +148,7 @@ pub async fn execute(agent: String, cwd: String) {
 
         // 读取流式响应直到 Complete 或 Error
         loop {
-            match update_rx.recv().await {
+            match update_rx.recv().await.map(|e| e.update) {
                 Ok(AcpUpdate::MessageChunk { text }) => {
                     print!("{}", text);
                     io::stdout().flush().ok();
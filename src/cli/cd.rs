@@ -0,0 +1,366 @@
+//! `grove cd` — jump to a task worktree from any shell
+//!
+//! `grove cd foo` prints the worktree path of the best-matching active task
+//! (for `cd "$(grove cd foo)"`); without a query it opens an fzf picker.
+//! `--env` prints shell code that also exports the `GROVE_*` variables task
+//! sessions get, and `--init` / `--install` set up a shell function that
+//! evaluates it.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use clap::ValueEnum;
+
+use crate::check::check_fzf;
+use crate::storage::{tasks, workspace};
+
+/// Marker line that makes `--install` idempotent
+const INSTALL_MARKER: &str = "# grove cd shell integration";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    /// Guess from `$SHELL`
+    fn detect() -> Option<Self> {
+        let shell = std::env::var("SHELL").ok()?;
+        match Path::new(&shell).file_name()?.to_str()? {
+            "bash" => Some(Shell::Bash),
+            "zsh" => Some(Shell::Zsh),
+            "fish" => Some(Shell::Fish),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Shell::Bash => "bash",
+            Shell::Zsh => "zsh",
+            Shell::Fish => "fish",
+        }
+    }
+}
+
+/// An active task worktree
+#[derive(Debug, Clone)]
+struct Candidate {
+    project_name: String,
+    project_path: String,
+    task: tasks::Task,
+}
+
+impl Candidate {
+    fn label(&self) -> String {
+        format!(
+            "{}/{}  [{}]",
+            self.project_name, self.task.name, self.task.branch
+        )
+    }
+}
+
+pub struct CdArgs {
+    pub query: Vec<String>,
+    pub env: bool,
+    pub shell: Option<Shell>,
+    pub init: bool,
+    pub install: bool,
+    pub name: String,
+}
+
+/// Execute the `grove cd` command
+pub fn execute(args: CdArgs) {
+    let shell = args.shell.or_else(Shell::detect).unwrap_or(Shell::Bash);
+    let result = if args.init {
+        print!("{}", shell_function(shell, &args.name));
+        Ok(())
+    } else if args.install {
+        install(shell, &args.name)
+    } else {
+        pick(&args.query.join(" ")).map(|c| {
+            if args.env {
+                print!("{}", env_script(shell, &c));
+            } else {
+                println!("{}", c.task.worktree_path);
+            }
+        })
+    };
+    if let Err(e) = result {
+        eprintln!("grove cd: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Active tasks of every registered project whose worktree exists
+fn load_candidates() -> Vec<Candidate> {
+    let mut out = Vec::new();
+    for project in workspace::load_projects().unwrap_or_default() {
+        let key = workspace::project_hash(&project.path);
+        for task in tasks::load_tasks(&key).unwrap_or_default() {
+            if task.worktree_path.is_empty() || !Path::new(&task.worktree_path).is_dir() {
+                continue;
+            }
+            out.push(Candidate {
+                project_name: project.name.clone(),
+                project_path: project.path.clone(),
+                task,
+            });
+        }
+    }
+    out
+}
+
+fn pick(query: &str) -> Result<Candidate, String> {
+    let candidates = load_candidates();
+    if candidates.is_empty() {
+        return Err("no active tasks".to_string());
+    }
+    if query.trim().is_empty() {
+        if check_fzf() {
+            return fzf_pick(&candidates);
+        }
+        return Err("pass a query (or install fzf for an interactive picker)".to_string());
+    }
+    best_match(&candidates, query)
+        .cloned()
+        .ok_or_else(|| format!("no task matches '{}'", query))
+}
+
+/// Highest score wins; ties go to the most recently updated task
+fn best_match<'a>(candidates: &'a [Candidate], query: &str) -> Option<&'a Candidate> {
+    candidates
+        .iter()
+        .filter_map(|c| score(c, query).map(|s| (s, c)))
+        .max_by(|(sa, a), (sb, b)| sa.cmp(sb).then(a.task.updated_at.cmp(&b.task.updated_at)))
+        .map(|(_, c)| c)
+}
+
+/// Match score of a task, `None` when some query token does not match.
+///
+/// Every whitespace-separated token must match the task name, id, branch or
+/// project name — exactly, as a prefix, as a substring, or as an in-order
+/// subsequence (`fxlg` → `fix-login`), in decreasing score.
+fn score(candidate: &Candidate, query: &str) -> Option<u32> {
+    let fields = [
+        candidate.task.name.to_lowercase(),
+        candidate.task.id.to_lowercase(),
+        candidate.task.branch.to_lowercase(),
+        candidate.project_name.to_lowercase(),
+    ];
+    let mut total = 0;
+    for token in query.to_lowercase().split_whitespace() {
+        total += fields
+            .iter()
+            .enumerate()
+            .filter_map(|(i, field)| {
+                // Task fields outweigh the project name
+                let weight = if i == 3 { 1 } else { 2 };
+                token_score(field, token).map(|s| s * weight)
+            })
+            .max()?;
+    }
+    Some(total)
+}
+
+fn token_score(field: &str, token: &str) -> Option<u32> {
+    if field == token {
+        Some(100)
+    } else if field.starts_with(token) {
+        Some(60)
+    } else if field.contains(token) {
+        Some(40)
+    } else if is_subsequence(field, token) {
+        Some(10)
+    } else {
+        None
+    }
+}
+
+fn is_subsequence(haystack: &str, needle: &str) -> bool {
+    let mut chars = haystack.chars();
+    needle.chars().all(|n| chars.any(|h| h == n))
+}
+
+fn fzf_pick(candidates: &[Candidate]) -> Result<Candidate, String> {
+    let mut child = Command::new("fzf")
+        .args(["--height=40%", "--reverse", "--prompt=task> "])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run fzf: {}", e))?;
+    {
+        let mut stdin = child.stdin.take().ok_or("failed to open fzf stdin")?;
+        for (i, c) in candidates.iter().enumerate() {
+            let _ = writeln!(stdin, "{}\t{}", i, c.label());
+        }
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("fzf failed: {}", e))?;
+    let selected = String::from_utf8_lossy(&output.stdout);
+    selected
+        .split('\t')
+        .next()
+        .and_then(|i| i.trim().parse::<usize>().ok())
+        .and_then(|i| candidates.get(i).cloned())
+        .ok_or_else(|| "nothing selected".to_string())
+}
+
+fn quote(shell: Shell, s: &str) -> String {
+    match shell {
+        Shell::Fish => format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'")),
+        Shell::Bash | Shell::Zsh => format!("'{}'", s.replace('\'', "'\\''")),
+    }
+}
+
+/// Shell code exporting the session `GROVE_*` variables and changing directory
+fn env_script(shell: Shell, c: &Candidate) -> String {
+    let vars = [
+        ("GROVE_TASK_ID", c.task.id.as_str()),
+        ("GROVE_TASK_NAME", c.task.name.as_str()),
+        ("GROVE_BRANCH", c.task.branch.as_str()),
+        ("GROVE_TARGET", c.task.target.as_str()),
+        ("GROVE_WORKTREE", c.task.worktree_path.as_str()),
+        ("GROVE_PROJECT_NAME", c.project_name.as_str()),
+        ("GROVE_PROJECT", c.project_path.as_str()),
+    ];
+    let mut out = String::new();
+    for (k, v) in vars {
+        match shell {
+            Shell::Fish => out.push_str(&format!("set -gx {} {}\n", k, quote(shell, v))),
+            Shell::Bash | Shell::Zsh => {
+                out.push_str(&format!("export {}={}\n", k, quote(shell, v)))
+            }
+        }
+    }
+    out.push_str(&format!("cd {}\n", quote(shell, &c.task.worktree_path)));
+    out
+}
+
+/// Shell function wrapping `grove cd --env`
+fn shell_function(shell: Shell, name: &str) -> String {
+    match shell {
+        Shell::Fish => format!(
+            "function {name} --description 'Jump to a Grove task worktree'\n    \
+             set -l out (command grove cd --env --shell fish $argv); or return\n    \
+             eval (string join ';' $out)\n\
+             end\n"
+        ),
+        Shell::Bash | Shell::Zsh => format!(
+            "{name}() {{\n  \
+             local out\n  \
+             out=\"$(command grove cd --env --shell {shell} \"$@\")\" || return\n  \
+             eval \"$out\"\n\
+             }}\n",
+            shell = shell.as_str()
+        ),
+    }
+}
+
+fn rc_file(shell: Shell) -> Result<PathBuf, String> {
+    let home = dirs::home_dir().ok_or("cannot resolve home directory")?;
+    Ok(match shell {
+        Shell::Bash => home.join(".bashrc"),
+        Shell::Zsh => home.join(".zshrc"),
+        Shell::Fish => home.join(".config/fish/config.fish"),
+    })
+}
+
+/// Append the `--init` hook to the shell rc file (once)
+fn install(shell: Shell, name: &str) -> Result<(), String> {
+    let path = rc_file(shell)?;
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    if existing.contains(INSTALL_MARKER) {
+        println!("Already installed in {}", path.display());
+        return Ok(());
+    }
+    let hook = match shell {
+        Shell::Fish => format!("grove cd --init --shell fish --name {} | source", name),
+        Shell::Bash | Shell::Zsh => format!(
+            "eval \"$(grove cd --init --shell {} --name {})\"",
+            shell.as_str(),
+            name
+        ),
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("cannot open {}: {}", path.display(), e))?;
+    writeln!(file, "\n{}\n{}", INSTALL_MARKER, hook).map_err(|e| e.to_string())?;
+    println!(
+        "Added `{}` to {} — restart the shell, then run `{} <query>`",
+        name,
+        path.display(),
+        name
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(project: &str, name: &str, branch: &str, age_secs: i64) -> Candidate {
+        let updated = chrono::Utc::now() - chrono::Duration::seconds(age_secs);
+        Candidate {
+            project_name: project.to_string(),
+            project_path: format!("/src/{}", project),
+            task: tasks::Task {
+                id: name.to_string(),
+                name: name.to_string(),
+                branch: branch.to_string(),
+                target: "main".to_string(),
+                worktree_path: format!("/wt/{}/{}", project, name),
+                initial_commit: None,
+                created_at: updated,
+                updated_at: updated,
+                status: tasks::TaskStatus::Active,
+                multiplexer: "tmux".to_string(),
+                session_name: String::new(),
+                created_by: String::new(),
+                archived_at: None,
+                code_additions: 0,
+                code_deletions: 0,
+                files_changed: 0,
+                is_local: false,
+                assignee: None,
+                pinned: false,
+            },
+        }
+    }
+
+    #[test]
+    fn best_match_prefers_exact_then_recent() {
+        let list = vec![
+            candidate("api", "fix-login", "grove/fix-login-1", 10),
+            candidate("web", "login", "grove/login-2", 100),
+            candidate("web", "logout-button", "grove/logout-3", 1),
+        ];
+        assert_eq!(best_match(&list, "login").unwrap().task.name, "login");
+        assert_eq!(best_match(&list, "fxlg").unwrap().task.name, "fix-login");
+        assert_eq!(best_match(&list, "web login").unwrap().task.name, "login");
+        // Ties go to the most recently updated task
+        assert_eq!(best_match(&list, "lo").unwrap().task.name, "logout-button");
+        assert!(best_match(&list, "zzz").is_none());
+    }
+
+    #[test]
+    fn env_script_quotes_for_each_shell() {
+        let c = candidate("it's", "t", "b", 0);
+        let bash = env_script(Shell::Bash, &c);
+        assert!(bash.contains("export GROVE_PROJECT_NAME='it'\\''s'\n"));
+        assert!(bash.ends_with("cd '/wt/it'\\''s/t'\n"));
+        let fish = env_script(Shell::Fish, &c);
+        assert!(fish.contains("set -gx GROVE_TASK_ID 't'\n"));
+        assert!(fish.ends_with("cd '/wt/it\\'s/t'\n"));
+    }
+}
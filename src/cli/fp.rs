@@ -1,10 +1,31 @@
 //! fp 子命令实现 - File Picker
+//!
+//! 默认是循环的交互式选择器（Enter 复制路径、Ctrl-O 打开）；`--print` 选一次
+//! 并逐行输出路径，`--json` 输出 ACP 内容块（可直接交给 `grove acp --attach`）。
 
-use std::process::Command;
+use std::path::Path;
+use std::process::{Command, Stdio};
 
+use crate::acp::ContentBlockData;
 use crate::check::check_fzf;
 
-pub fn execute() {
+/// fzf 预览：目录列出内容，文件优先用 bat 高亮（带行号），否则 head
+const PREVIEW: &str = "if [ -d {} ]; then ls -la {}; \
+elif command -v bat >/dev/null 2>&1; then bat --color=always --style=numbers,changes --line-range=:500 {}; \
+else head -n 500 {}; fi";
+
+/// 输出模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FpOutput {
+    /// 循环选择，复制到剪贴板
+    Interactive,
+    /// 选一次，逐行输出路径
+    Paths,
+    /// 选一次，输出 ACP Resource 内容块 JSON
+    Resources,
+}
+
+pub fn execute(output: FpOutput, query: Option<String>, max_kb: u64) {
     // 检查 fzf 依赖
     if !check_fzf() {
         eprintln!("Error: fzf is not installed.");
@@ -12,13 +33,93 @@ pub fn execute() {
         std::process::exit(1);
     }
 
-    // 使用 shell 脚本实现交互式文件选择器
+    match output {
+        FpOutput::Interactive => interactive(),
+        FpOutput::Paths => {
+            for path in pick_files(query.as_deref()) {
+                println!("{}", path);
+            }
+        }
+        FpOutput::Resources => {
+            let blocks: Vec<ContentBlockData> = pick_files(query.as_deref())
+                .iter()
+                .filter_map(|p| resource_block(Path::new(p), max_kb * 1024))
+                .collect();
+            match serde_json::to_string_pretty(&blocks) {
+                Ok(json) => println!("{}", json),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+/// 运行一次多选 fzf，返回所选路径（相对当前目录）
+fn pick_files(query: Option<&str>) -> Vec<String> {
+    // git 仓库内按 .gitignore 过滤，否则退回 find
+    let script = r#"{ git ls-files -co --exclude-standard 2>/dev/null ||
+    find . -type f -not -path '*/.*' -not -path '*/node_modules/*' | sed 's|^\./||'; } |
+fzf --multi --query="$1" --preview "$2" --preview-window=right:60%:wrap \
+    --bind 'ctrl-/:toggle-preview' --header 'Tab: multi-select | Enter: done'"#;
+    let output = Command::new("bash")
+        .args(["-c", script, "fp", query.unwrap_or(""), PREVIEW])
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .output();
+    match output {
+        Ok(out) => String::from_utf8_lossy(&out.stdout)
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// 文件 → ACP 内容块：不超过上限的 UTF-8 文本内嵌为 Resource，其余给 ResourceLink
+fn resource_block(path: &Path, max_bytes: u64) -> Option<ContentBlockData> {
+    let abs = path.canonicalize().ok()?;
+    if !abs.is_file() {
+        return None;
+    }
+    let uri = format!("file://{}", abs.display());
+    let mime = mime_guess::from_path(&abs)
+        .first()
+        .map(|m| m.essence_str().to_string());
+    let size = abs.metadata().map(|m| m.len()).unwrap_or(0);
+    if size <= max_bytes {
+        if let Ok(text) = std::fs::read_to_string(&abs) {
+            return Some(ContentBlockData::Resource {
+                uri,
+                mime_type: Some(mime.unwrap_or_else(|| "text/plain".to_string())),
+                text: Some(text),
+            });
+        }
+    }
+    Some(ContentBlockData::ResourceLink {
+        uri,
+        name: abs.file_name()?.to_string_lossy().to_string(),
+        mime_type: mime,
+        size: Some(size as i64),
+        title: None,
+        description: None,
+        label: None,
+    })
+}
+
+/// 循环的交互式选择器
+fn interactive() {
     let script = r#"
 while true; do
     selected=$(find . -not -path '*/\.*' -not -path '*/node_modules/*' 2>/dev/null | \
                fzf --multi \
                    --query="" \
-                   --preview '[[ -f {} ]] && bat --color=always {} 2>/dev/null || cat {} 2>/dev/null || ls -la {}' \
+                   --preview "$1" \
                    --preview-window=right:60%:wrap \
                    --bind 'ctrl-/:toggle-preview' \
                    --header 'Tab: multi-select | Enter: copy path | Ctrl-O: open | Ctrl-C: exit' \
@@ -53,10 +154,49 @@ while true; do
 done
 "#;
 
-    let status = Command::new("bash").arg("-c").arg(script).status();
+    let status = Command::new("bash")
+        .args(["-c", script, "fp", PREVIEW])
+        .status();
 
     if let Err(e) = status {
         eprintln!("Error: {}", e);
         std::process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_block_embeds_small_text_only() {
+        let temp = tempfile::tempdir().unwrap();
+        let small = temp.path().join("main.rs");
+        std::fs::write(&small, "fn main() {}\n").unwrap();
+        let binary = temp.path().join("logo.png");
+        std::fs::write(&binary, [0x89, 0x50, 0xff, 0xfe]).unwrap();
+
+        match resource_block(&small, 1024) {
+            Some(ContentBlockData::Resource {
+                uri,
+                mime_type,
+                text,
+            }) => {
+                assert!(uri.starts_with("file://") && uri.ends_with("main.rs"));
+                assert_eq!(mime_type.as_deref(), Some("text/x-rust"));
+                assert_eq!(text.as_deref(), Some("fn main() {}\n"));
+            }
+            other => panic!("unexpected block: {:?}", other),
+        }
+        // 超出上限 / 非 UTF-8 → 只给链接
+        assert!(matches!(
+            resource_block(&small, 4),
+            Some(ContentBlockData::ResourceLink { size: Some(13), .. })
+        ));
+        assert!(matches!(
+            resource_block(&binary, 1024),
+            Some(ContentBlockData::ResourceLink { .. })
+        ));
+        assert!(resource_block(temp.path(), 1024).is_none());
+    }
+}
@@ -390,6 +390,12 @@ pub async fn execute(port: u16, remote_url: Option<String>) {
     #[cfg(target_os = "macos")]
     expand_path_for_app_bundle();
 
+    // Attach to a running `grove notifyd` so agents outlive the window.
+    let remote_url = remote_url.or_else(|| {
+        let url = super::notifyd::attach_url()?;
+        println!("Grove GUI: attaching to grove notifyd at {}", url);
+        Some(url)
+    });
     let is_remote = remote_url.is_some();
     let remote_url_clone = remote_url.clone();
 
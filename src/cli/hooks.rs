@@ -40,6 +40,12 @@ pub enum HookLevel {
         #[arg(long, short = 'm')]
         message: Option<String>,
     },
+    /// Install Grove hooks into agent settings (Claude Code) of a worktree
+    Install {
+        /// Worktree path (defaults to the current directory)
+        #[arg(long)]
+        path: Option<String>,
+    },
 }
 
 impl HookLevel {
@@ -49,6 +55,7 @@ impl HookLevel {
             HookLevel::Notice { .. } => NotificationLevel::Notice,
             HookLevel::Warn { .. } => NotificationLevel::Warn,
             HookLevel::Critical { .. } => NotificationLevel::Critical,
+            HookLevel::Install { .. } => unreachable!("install is not a notification"),
         }
     }
 
@@ -58,6 +65,7 @@ impl HookLevel {
             HookLevel::Notice { sound, .. } => sound,
             HookLevel::Warn { sound, .. } => sound,
             HookLevel::Critical { sound, .. } => sound,
+            HookLevel::Install { .. } => unreachable!("install is not a notification"),
         }
     }
 
@@ -67,6 +75,7 @@ impl HookLevel {
             HookLevel::Notice { message, .. } => message.as_deref(),
             HookLevel::Warn { message, .. } => message.as_deref(),
             HookLevel::Critical { message, .. } => message.as_deref(),
+            HookLevel::Install { .. } => unreachable!("install is not a notification"),
         }
     }
 
@@ -90,6 +99,7 @@ impl HookLevel {
                 // critical 默认显示
                 !*no_banner
             }
+            HookLevel::Install { .. } => false,
         }
     }
 
@@ -99,12 +109,18 @@ impl HookLevel {
             HookLevel::Notice { .. } => "Notice",
             HookLevel::Warn { .. } => "Warning",
             HookLevel::Critical { .. } => "Critical",
+            HookLevel::Install { .. } => unreachable!("install is not a notification"),
         }
     }
 }
 
 /// 执行 hook 命令
 pub fn execute(level: HookLevel) {
+    if let HookLevel::Install { path } = level {
+        install(path);
+        return;
+    }
+
     // 先检查所有必要的环境变量
     let project_path = match env::var("GROVE_PROJECT") {
         Ok(p) => p,
@@ -125,9 +141,12 @@ pub fn execute(level: HookLevel) {
 
     let message = level.message().map(|s| s.to_string());
 
+    // 勿扰：不播放声音、不发横幅，但仍记录通知
+    let muted = hooks::notifications_muted();
+
     // 播放声音
     let sound = level.sound();
-    if sound.to_lowercase() != "none" {
+    if !muted && sound.to_lowercase() != "none" {
         hooks::play_sound(sound);
     }
 
@@ -139,7 +158,7 @@ pub fn execute(level: HookLevel) {
     let chat_id = env::var("GROVE_CHAT_ID").ok().filter(|s| !s.is_empty());
 
     // 发送系统通知横幅
-    if level.should_banner() {
+    if !muted && level.should_banner() {
         let title = format!("Grove - {}", level.level_name());
         let banner_msg = if let Some(ref msg) = message {
             format!("[{}] {} - {}", project_name, task_name, msg)
@@ -160,3 +179,30 @@ pub fn execute(level: HookLevel) {
 
     hooks::update_hook(&project_key, &task_id, level.level(), message, chat_id);
 }
+
+/// 安装 agent hooks 到指定 worktree（默认当前目录）
+fn install(path: Option<String>) {
+    let worktree = match path {
+        Some(p) => std::path::PathBuf::from(p),
+        None => match env::current_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+        },
+    };
+    match crate::operations::agent_hooks::install_claude_hooks(&worktree) {
+        Ok(true) => println!(
+            "Installed Grove hooks into {}",
+            worktree
+                .join(crate::operations::agent_hooks::CLAUDE_SETTINGS_PATH)
+                .display()
+        ),
+        Ok(false) => println!("Grove hooks already installed"),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
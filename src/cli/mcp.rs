@@ -5,6 +5,7 @@
 //! - grove_read_notes: Read user-written notes
 //! - grove_read_review: Read review comments
 //! - grove_reply_review: Reply to review comments
+//! - grove_handback: Hand the task back to the user
 //! - grove_complete_task: Complete task (commit, sync, merge)
 
 use std::{collections::HashSet, env};
@@ -19,10 +20,14 @@ use rmcp::{
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 
-use crate::acp;
+use crate::acp::{self, client::ChatClient};
+use crate::diff::test_changes;
 use crate::git;
-use crate::operations;
-use crate::storage::{chat_history, comments, config, notes, tasks, workspace};
+use crate::operations::{self, lifecycle_hooks};
+use crate::storage::lifecycle_hooks::HookEvent;
+use crate::storage::{
+    chat_history, comments, config, file_guard, merge_gate, notes, tasks, workspace,
+};
 
 // ============================================================================
 // Grove Instructions for AI
@@ -49,7 +54,10 @@ without affecting other tasks or the main codebase.
 7. **grove_start_chat** — Create and start a chat session (returns chat_id)
 8. **grove_chat_status** — Get chat state, auto-connects if needed, returns available modes/models
 9. **grove_send_prompt** — Send prompt / respond to permission / cancel turn
-10. **grove_list_chats** — List chat sessions for a task
+10. **grove_broadcast_prompt** — Send one prompt to several chats (or all running chats) at once
+11. **grove_cross_review** — Have a different agent review a finished task and post review comments
+12. **grove_list_chats** — List chat sessions for a task
+13. **grove_send_keys** — Type into a task's tmux/zellij session (for CLI agents, not ACP chats)
 
 ## Orchestration Workflow
 1. Find or register the target project
@@ -59,7 +67,7 @@ without affecting other tasks or the main codebase.
 5. Call `grove_start_chat` to launch a worker agent
 6. Call `grove_chat_status` to wait for agent ready and get available modes/models
 7. Call `grove_send_prompt` to instruct the worker (always call `grove_chat_status` first!)
-8. Poll with `grove_chat_status` until idle
+8. Call `grove_chat_status` with `wait_for_idle: true` to block until the turn ends
    - If `permission_needed`: use `grove_send_prompt` with `permission_option_id`
    - If stuck: use `grove_send_prompt` with `cancel: true`
 9. Review results in `last_message` / `plan`, send follow-ups as needed
@@ -82,7 +90,8 @@ You are running inside a **Coding** Grove task: an isolated git worktree + tmux
    - **File**: Comment on entire file (e.g., "file too large, split modules")
    - **Project**: Overall feedback (e.g., "add integration tests")
    Use to review code, raise questions, suggest improvements, or **visualize implementation plans** by marking key points.
-6. **grove_complete_task** - Complete task: commit → sync (rebase) → merge. **ONLY call when the user explicitly asks.**
+6. **grove_handback** - Hand the task back to the user when you need their input or are done with a round of work
+7. **grove_complete_task** - Complete task: commit → sync (rebase) → merge. **ONLY call when the user explicitly asks.**
 
 ## Recommended Workflow
 
@@ -90,7 +99,8 @@ You are running inside a **Coding** Grove task: an isolated git worktree + tmux
 2. Call `grove_read_notes` to understand user requirements and context
 3. Call `grove_read_review` to check for code review feedback
 4. After addressing review comments, use `grove_reply_review` to respond
-5. When the user explicitly requests, call `grove_complete_task` to finalize
+5. Call `grove_handback` when the user needs to review or decide something
+6. When the user explicitly requests, call `grove_complete_task` to finalize
 
 ## Completing a Task
 
@@ -378,6 +388,7 @@ fn filter_tools(all: Vec<Tool>) -> Vec<Tool> {
         "read_review",
         "reply_review",
         "add_comment",
+        "handback",
         "complete_task",
     ]);
     // Studio-task-only tools (also exposed to the orchestrator, see below).
@@ -552,6 +563,13 @@ pub struct AddCommentParams {
     pub role: Option<String>,
 }
 
+/// Handback parameters
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct HandbackParams {
+    /// What the user should look at or decide (shown in the notification)
+    pub message: Option<String>,
+}
+
 /// Complete task parameters
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CompleteTaskParams {
@@ -580,6 +598,14 @@ pub struct CreateTaskParams {
     pub project_id: String,
     /// Human-readable task name
     pub name: String,
+    /// Optional repo-relative paths to check out (sparse checkout, for large
+    /// monorepos). Files at the repo root are always included. Omit for a full checkout.
+    #[serde(default)]
+    pub sparse_paths: Vec<String>,
+    /// Optional task template name (see the project's templates). Supplies the
+    /// target branch, notes skeleton, default agent, env vars and autolink patterns.
+    #[serde(default)]
+    pub template: Option<String>,
 }
 
 /// List active tasks under a project (workspace-scoped)
@@ -626,6 +652,17 @@ pub struct StartChatParams {
     pub name: Option<String>,
     /// Agent to use (default: config default). Use grove_list_agents to see available agents.
     pub agent: Option<String>,
+    /// Initial agent mode (e.g., "plan"). Default: the mode pinned in project settings,
+    /// else the one last used with this agent in the project.
+    #[serde(default)]
+    pub mode: Option<String>,
+    /// Initial agent model (e.g., "opus"). Default: pinned, else last used in the project.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Run the agent in this subdirectory of the task worktree (e.g., "frontend")
+    /// so it stays focused on one package. Default: the worktree root.
+    #[serde(default)]
+    pub work_subdir: Option<String>,
 }
 
 /// Send a prompt, respond to permission, or cancel a chat turn (management tool).
@@ -676,6 +713,46 @@ pub struct ChatStatusParams {
     pub task_id: String,
     /// Chat ID
     pub chat_id: String,
+    /// Long-poll: block until the chat is no longer busy (idle or permission_needed)
+    /// or `timeout_secs` elapses, instead of polling repeatedly.
+    #[serde(default)]
+    pub wait_for_idle: bool,
+    /// Max seconds to wait with `wait_for_idle` (default 60, max 600).
+    pub timeout_secs: Option<u64>,
+}
+
+/// One target chat of a broadcast
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ChatTarget {
+    /// Task ID
+    pub task_id: String,
+    /// Chat ID
+    pub chat_id: String,
+}
+
+/// Send the same prompt to several chats (management tool)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BroadcastPromptParams {
+    /// Project ID (hash)
+    pub project_id: String,
+    /// Prompt text to send to every target chat
+    pub text: String,
+    /// Target chats. Omit to target every chat with a running session in the project.
+    pub chats: Option<Vec<ChatTarget>>,
+    /// Sender name (e.g., "Claude Code (Orchestrator)"). Shown in chat UI to identify who sent the message.
+    pub sender: Option<String>,
+}
+
+/// Start an agent-to-agent cross review of a task (management tool)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct CrossReviewParams {
+    /// Project ID (hash)
+    pub project_id: String,
+    /// Task ID
+    pub task_id: String,
+    /// Reviewer agent id (see grove_list_agents). Must differ from the agents that
+    /// worked on the task; defaults to the first installed one that didn't.
+    pub agent: Option<String>,
 }
 
 /// List chats for a task (management tool)
@@ -689,6 +766,25 @@ pub struct ListChatsParams {
     pub query: Option<String>,
 }
 
+/// Send keys into a task's terminal session (management tool)
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SendKeysParams {
+    /// Project ID (hash)
+    pub project_id: String,
+    /// Task ID
+    pub task_id: String,
+    /// Text typed literally into the pane
+    pub text: Option<String>,
+    /// Named keys sent after `text`, tmux key names: "Enter", "Escape", "Tab", "Up", "C-c", ...
+    #[serde(default)]
+    pub keys: Vec<String>,
+    /// Press Enter at the end, submitting `text` as a command line
+    #[serde(default)]
+    pub enter: bool,
+    /// tmux pane index (default 0 = agent pane); ignored for zellij
+    pub pane: Option<usize>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema, Default)]
 pub struct SketchListParams {}
 
@@ -951,6 +1047,13 @@ pub struct CompleteTaskResult {
     /// List of conflict files if rebase conflict
     #[serde(skip_serializing_if = "Option::is_none")]
     pub conflicts: Option<Vec<String>>,
+    /// What blocks the merge under the project's merge gate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blockers: Option<Vec<String>>,
+    /// Non-blocking warning, e.g. source files changed without test changes,
+    /// benchmark regressions or low-severity security findings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
     /// Human-readable message
     pub message: String,
 }
@@ -1254,7 +1357,7 @@ impl GroveMcpServer {
     /// Write or update notes for a task (management tool for orchestrator agents)
     #[tool(
         name = "edit_note",
-        description = "Write or update notes for a task. Used to set task spec, context, and instructions before the task agent starts working."
+        description = "Write or update notes for a task. Used to set task spec, context, and instructions before the task agent starts working. Replaces the whole note; previous versions are kept in the note history."
     )]
     async fn grove_edit_note(
         &self,
@@ -1278,7 +1381,7 @@ impl GroveMcpServer {
     /// Start a new chat session for a task
     #[tool(
         name = "start_chat",
-        description = "Create and start a chat session for a task. Spawns the agent process. Returns chat_id, name, and agent. After calling this, use grove_chat_status to wait for the agent to be ready and get available modes/models."
+        description = "Create and start a chat session for a task. Spawns the agent process. Mode/model default to the ones pinned in project settings, else the last used with that agent in the project. Returns chat_id, name, and agent. After calling this, use grove_chat_status to wait for the agent to be ready and get available modes/models."
     )]
     async fn grove_start_chat(
         &self,
@@ -1306,7 +1409,7 @@ impl GroveMcpServer {
     /// Query chat status (auto-connects the session if not running)
     #[tool(
         name = "chat_status",
-        description = "Get the current state of a chat session. Auto-connects the agent if not already running. Returns: state (idle/busy/permission_needed), available_modes, available_models, available_thought_levels (+ current_thought_level_id / thought_level_config_id when the agent exposes one), turn_count, last_message, plan, and permission details. Always call this before grove_send_prompt to know the session state and available selectors."
+        description = "Get the current state of a chat session. Auto-connects the agent if not already running. Returns: state (idle/busy/permission_needed), available_modes, available_models, available_thought_levels (+ current_thought_level_id / thought_level_config_id when the agent exposes one), turn_count, last_message, plan, and permission details. Set `wait_for_idle: true` (optionally `timeout_secs`) to long-poll until the chat is no longer busy instead of polling; the result then has `timed_out: true` if it was still busy at the deadline. Always call this before grove_send_prompt to know the session state and available selectors."
     )]
     async fn grove_chat_status(
         &self,
@@ -1317,6 +1420,34 @@ impl GroveMcpServer {
         chat_status_impl(p).await
     }
 
+    /// Send the same prompt to several chats concurrently
+    #[tool(
+        name = "broadcast_prompt",
+        description = "Send the same prompt to a list of chats (`chats: [{task_id, chat_id}]`) or, when `chats` is omitted, to every chat with a running session in the project. Prompts are sent concurrently (listed chats auto-connect like grove_send_prompt). Returns per-chat `accepted` / `failed` status with the error, plus totals. Use grove_chat_status (with wait_for_idle) on each chat to follow up."
+    )]
+    async fn grove_broadcast_prompt(
+        &self,
+        params: Parameters<BroadcastPromptParams>,
+    ) -> Result<CallToolResult, McpError> {
+        ensure_not_in_grove_task()?;
+        let p = params.0;
+        broadcast_prompt_impl(p).await
+    }
+
+    /// Cross-review a task with a different agent
+    #[tool(
+        name = "cross_review",
+        description = "One-call agent-to-agent review: starts a new \"Cross-review\" chat on the task with a different agent than the ones that implemented it, feeds it the task diff and notes, and has it post its findings as review comments attributed to that agent (read them with the task's review comments). Returns chat_id, name, agent and author_agents; follow progress with grove_chat_status."
+    )]
+    async fn grove_cross_review(
+        &self,
+        params: Parameters<CrossReviewParams>,
+    ) -> Result<CallToolResult, McpError> {
+        ensure_not_in_grove_task()?;
+        let p = params.0;
+        cross_review_impl(p).await
+    }
+
     /// List chats for a task
     #[tool(
         name = "list_chats",
@@ -1331,6 +1462,32 @@ impl GroveMcpServer {
         list_chats_impl(p).await
     }
 
+    /// Send keystrokes / a command line into a task's tmux or zellij session
+    #[tool(
+        name = "send_keys",
+        description = "Type into a task's running terminal session (tmux/zellij), e.g. to nudge a CLI agent such as claude running in tmux. `text` is typed literally, then `keys` (tmux key names like \"Enter\", \"Escape\", \"C-c\") are pressed, then Enter if `enter` is true. Does not start the session. For ACP chats use grove_send_prompt instead."
+    )]
+    async fn grove_send_keys(
+        &self,
+        params: Parameters<SendKeysParams>,
+    ) -> Result<CallToolResult, McpError> {
+        ensure_not_in_grove_task()?;
+        let p = params.0;
+        blocking_json_result(move || {
+            let (project_key, _, _) = resolve_project_for_mcp(&p.project_id)?;
+            let task = resolve_task_for_mcp(&project_key, &p.task_id)?;
+            let input = crate::session::SessionInput {
+                text: p.text,
+                keys: p.keys,
+                enter: p.enter,
+            };
+            crate::operations::tasks::send_task_input(&project_key, &task, p.pane, &input)
+                .map_err(|e| mcp_err(&e.to_string()))?;
+            Ok(json!({ "sent": true }))
+        })
+        .await
+    }
+
     /// Return the Grove sketch element-format reference (cheat sheet).
     #[tool(
         name = "sketch_read_me",
@@ -1673,6 +1830,17 @@ impl GroveMcpServer {
                 &role,
             ) {
                 Ok(true) => {
+                    // Agent prompts need the web server's live ACP handles;
+                    // from here only the local user's notification fires.
+                    operations::review::dispatch_mentions(
+                        &project_key,
+                        &task_id,
+                        reply.comment_id,
+                        &agent,
+                        &reply.message,
+                        false,
+                    );
+
                     // Handle resolve if requested
                     let (resolved, resolve_error) = if reply.resolve == Some(true) {
                         // Load comment to check creator permission
@@ -1812,6 +1980,7 @@ impl GroveMcpServer {
                                 &model,
                                 &role,
                                 anchor,
+                                None,
                             )
                             .map_err(|e| e.to_string())
                         }
@@ -1833,6 +2002,7 @@ impl GroveMcpServer {
                         &model,
                         &role,
                         None,
+                        None,
                     )
                     .map_err(|e| e.to_string()),
                     None => Err("file_path required for file comments".to_string()),
@@ -1850,12 +2020,21 @@ impl GroveMcpServer {
                     &model,
                     &role,
                     None,
+                    None,
                 )
                 .map_err(|e| e.to_string()),
             };
 
             match result {
                 Ok(comment) => {
+                    operations::review::dispatch_mentions(
+                        &project_key,
+                        &task_id,
+                        comment.id,
+                        &agent,
+                        &comment.content,
+                        false,
+                    );
                     let type_str = match comment.comment_type {
                         comments::CommentType::Inline => "inline",
                         comments::CommentType::File => "file",
@@ -1897,6 +2076,37 @@ impl GroveMcpServer {
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 
+    /// Hand the current task back to the user
+    #[tool(
+        name = "handback",
+        description = "Hand the current Grove task back to the user: marks the user as the assignee, notifies them, and pauses queued prompts until they hand it back to you. Use when you need review, a decision, or have finished a round of work. Call grove_status first to ensure you are in a Grove task."
+    )]
+    async fn grove_handback(
+        &self,
+        params: Parameters<HandbackParams>,
+    ) -> Result<CallToolResult, McpError> {
+        let (task_id, project_path) = get_task_context()
+            .ok_or_else(|| McpError::invalid_request("Not in a Grove task", None))?;
+
+        let project_key = workspace::project_hash(&project_path);
+        validate_task_exists(&project_key, &task_id)?;
+
+        // Queue pausing only reaches sessions hosted by this process; the
+        // assignee and notification are what the user sees either way.
+        let result = operations::tasks::handback_task(
+            &project_key,
+            &task_id,
+            Some(tasks::TaskAssignee::Human),
+            params.0.message.as_deref(),
+        )
+        .map_err(|e| McpError::internal_error(format!("Failed to hand back: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "Handed '{}' back to the user. Stop here and wait for their reply.",
+            result.task_name
+        ))]))
+    }
+
     /// Complete the current task: commit, sync (rebase), and merge
     #[tool(
         name = "complete_task",
@@ -1924,6 +2134,46 @@ impl GroveMcpServer {
             .map_err(|_| McpError::internal_error("GROVE_TARGET not set", None))?;
         let branch = env::var("GROVE_BRANCH")
             .map_err(|_| McpError::internal_error("GROVE_BRANCH not set", None))?;
+        // Set when the agent runs inside a Grove chat; recorded as Grove-Chat
+        let chat_id = env::var("GROVE_CHAT_ID").ok();
+        let project_key = workspace::project_hash(&project_path);
+
+        // Step 0: Security scan, then the merge gate (open review comments /
+        // missing approvals / blocking findings)
+        let scan_warning = tasks::get_task(&project_key, &task_id)
+            .ok()
+            .flatten()
+            .and_then(|task| operations::security_scan::scan_before_merge(&project_key, &task));
+        let blockers = merge_gate::check(&project_key, &task_id).map_err(|e| {
+            McpError::internal_error(format!("Failed to check merge gate: {}", e), None)
+        })?;
+        if !blockers.is_empty() {
+            let hint = if blockers
+                .iter()
+                .all(|b| matches!(b, merge_gate::MergeBlocker::SecurityFindings { .. }))
+            {
+                "Upgrade or replace the vulnerable dependencies reported by the project's security scanners before completing the task."
+            } else if blockers
+                .iter()
+                .all(|b| matches!(b, merge_gate::MergeBlocker::GuardedFiles { .. }))
+            {
+                "Remove the flagged files from the branch (and add them to .gitignore if generated) before completing the task."
+            } else {
+                "Address the review (use grove_read_review) and ask the reviewers to approve before completing the task."
+            };
+            return Ok(CallToolResult::success(vec![Content::text(
+                serde_json::to_string_pretty(&CompleteTaskResult {
+                    success: false,
+                    error: Some(merge_gate::MERGE_BLOCKED_TAG.to_string()),
+                    commit_hash: None,
+                    conflicts: None,
+                    message: format!("{}. {}", merge_gate::describe(&blockers), hint),
+                    blockers: Some(blockers.iter().map(|b| b.to_string()).collect()),
+                    warning: None,
+                })
+                .unwrap(),
+            )]));
+        }
 
         // Step 1: Check for uncommitted changes and commit if any
         let has_changes = git::has_uncommitted_changes(&worktree_path).map_err(|e| {
@@ -1944,13 +2194,17 @@ impl GroveMcpServer {
             }
 
             // git commit
-            if let Err(e) = git::commit(&worktree_path, &params.0.commit_message) {
+            let options = git::CommitOptions::resolve(&project_key, Some(&task_id))
+                .with_chat(chat_id.as_deref());
+            if let Err(e) = git::commit(&worktree_path, &params.0.commit_message, &options) {
                 return Ok(CallToolResult::success(vec![Content::text(
                     serde_json::to_string_pretty(&CompleteTaskResult {
                         success: false,
                         error: Some("commit_failed".to_string()),
                         commit_hash: None,
                         conflicts: None,
+                        blockers: None,
+                        warning: None,
                         message: format!("Commit failed: {}", e),
                     })
                     .unwrap(),
@@ -1962,11 +2216,25 @@ impl GroveMcpServer {
             None
         };
 
+        // Tests-missing / benchmark / security / file guard warnings, computed before the merge empties the diff
+        let warnings: Vec<String> = [
+            test_changes::task_test_changes(&project_key, &worktree_path, &target_branch)
+                .and_then(|t| t.warning()),
+            operations::bench::latest(&project_key, &task_id)
+                .and_then(|run| run.regression_summary()),
+            scan_warning,
+            file_guard::warning(&project_key, &task_id),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let mut warnings = warnings;
+
         // Step 2: Fetch and rebase
         let origin_target = format!("origin/{}", target_branch);
         if let Err(e) = git::fetch_origin(&worktree_path, &target_branch) {
             // Fetch failure is not fatal, continue with local target
-            eprintln!("Warning: fetch failed: {}", e);
+            tracing::warn!("fetch failed: {}", e);
         }
 
         if let Err(_e) = git::rebase(&worktree_path, &origin_target) {
@@ -1983,12 +2251,41 @@ impl GroveMcpServer {
                         error: Some("rebase_conflict".to_string()),
                         commit_hash,
                         conflicts: Some(conflicts),
+                        blockers: None,
+                        warning: None,
                         message: "Rebase conflict detected. Please resolve conflicts and call grove_complete_task again.".to_string(),
                     }).unwrap()
                 )]));
             }
         }
 
+        // Step 2.5: Pre-merge lifecycle hooks on the rebased worktree
+        let hook_env = tasks::get_task(&project_key, &task_id)
+            .ok()
+            .flatten()
+            .map(|task| operations::tasks::task_env(&project_key, &task, &project_path));
+        if let Some(hook_env) = &hook_env {
+            let report = lifecycle_hooks::run(&project_key, HookEvent::PreMerge, hook_env);
+            if let Some(e) = report.blocking_error(HookEvent::PreMerge) {
+                return Ok(CallToolResult::success(vec![Content::text(
+                    serde_json::to_string_pretty(&CompleteTaskResult {
+                        success: false,
+                        error: Some("hook_failed".to_string()),
+                        commit_hash,
+                        conflicts: None,
+                        blockers: None,
+                        warning: None,
+                        message: format!(
+                            "{}. Fix the failure and call grove_complete_task again.",
+                            e
+                        ),
+                    })
+                    .unwrap(),
+                )]));
+            }
+            warnings.extend(report.warning(HookEvent::PreMerge));
+        }
+
         // Step 3: Merge into target branch (in main repo)
         // First checkout target branch in main repo
         if let Err(e) = git::checkout(&project_path, &target_branch) {
@@ -1998,22 +2295,32 @@ impl GroveMcpServer {
                     error: Some("checkout_failed".to_string()),
                     commit_hash,
                     conflicts: None,
+                    blockers: None,
+                    warning: None,
                     message: format!("Failed to checkout target branch: {}", e),
                 })
                 .unwrap(),
             )]));
         }
 
-        // Load notes for merge commit message (non-fatal)
-        let project_key = workspace::project_hash(&project_path);
+        // Append the automatic task summary (if enabled), then load notes for
+        // the merge commit message (non-fatal)
+        if let Ok(Some(task)) = tasks::get_task(&project_key, &task_id) {
+            crate::operations::task_summary::before_merge(&project_key, &task);
+        }
         let notes_content = notes::load_notes(&project_key, &task_id)
             .ok()
+            .map(|notes| {
+                crate::operations::task_summary::notes_for_merge_commit(&project_key, notes)
+            })
             .filter(|s| !s.trim().is_empty());
 
         // Merge with --no-ff
         let merge_title = format!("Merge branch '{}' into {}", branch, target_branch);
-        let merge_message = git::build_commit_message(&merge_title, notes_content.as_deref());
-        if let Err(e) = git::merge_no_ff(&project_path, &branch, &merge_message) {
+        let merge_message = git::build_commit_message(&merge_title, notes_content.as_deref(), None);
+        let options =
+            git::CommitOptions::resolve(&project_key, Some(&task_id)).with_chat(chat_id.as_deref());
+        if let Err(e) = git::merge_no_ff(&project_path, &branch, &merge_message, &options) {
             // Reset merge state
             let _ = git::reset_merge(&project_path);
             // Checkout back to original branch (best effort)
@@ -2025,19 +2332,38 @@ impl GroveMcpServer {
                     error: Some("merge_failed".to_string()),
                     commit_hash,
                     conflicts: None,
+                    blockers: None,
+                    warning: None,
                     message: format!("Merge failed: {}", e),
                 })
                 .unwrap(),
             )]));
         }
 
+        // Post-merge hooks can no longer undo the merge: failures only warn
+        if let Some(hook_env) = &hook_env {
+            warnings.extend(
+                lifecycle_hooks::run(&project_key, HookEvent::PostMerge, hook_env)
+                    .warning(HookEvent::PostMerge),
+            );
+        }
+        let warning = (!warnings.is_empty()).then(|| warnings.join("; "));
+
         // Build success result
         let result = CompleteTaskResult {
             success: true,
             error: None,
             commit_hash,
             conflicts: None,
-            message: "Task completed successfully. Branch merged into target.".to_string(),
+            blockers: None,
+            message: match &warning {
+                Some(warning) => format!(
+                    "Task completed successfully. Branch merged into target. Warning: {}.",
+                    warning
+                ),
+                None => "Task completed successfully. Branch merged into target.".to_string(),
+            },
+            warning,
         };
 
         let json = serde_json::to_string_pretty(&result)
@@ -2568,21 +2894,46 @@ fn create_task_json(params: &CreateTaskParams) -> serde_json::Value {
         Err(e) => return error_json("internal_error", format!("Failed to load project: {e}")),
     };
 
-    let target = git::current_branch(&project.path).unwrap_or_else(|_| "main".to_string());
+    let template =
+        match operations::tasks::resolve_template(&params.project_id, params.template.as_deref()) {
+            Ok(t) => t,
+            Err(e) => return error_json("template_not_found", e.to_string()),
+        };
+
+    let target = template
+        .as_ref()
+        .and_then(|t| t.target.clone())
+        .unwrap_or_else(|| operations::projects::default_target_branch(&project.path));
 
     let full_config = config::load_config();
-    let autolink_patterns = &full_config.auto_link.patterns;
+    let auto_link = match &template {
+        Some(t) => t.auto_link(&full_config.auto_link),
+        None => full_config.auto_link.clone(),
+    };
 
-    match operations::tasks::create_task(
+    match operations::tasks::create_sparse_task(
         &project.path,
         &params.project_id,
         params.name.clone(),
         target.clone(),
         &full_config.default_session_type(),
-        autolink_patterns,
+        &auto_link,
         "agent",
+        &params.sparse_paths,
     ) {
-        Ok(result) => json!({
+        Ok(result) => {
+            if let Some(t) = &template {
+                if let Err(e) = operations::tasks::apply_template(
+                    &params.project_id,
+                    &result.task,
+                    t,
+                    true,
+                    notes::NoteSource::Mcp,
+                ) {
+                    tracing::warn!("failed to apply task template: {}", e);
+                }
+            }
+            json!({
             "success": true,
             "task": {
                 "task_id": result.task.id,
@@ -2590,8 +2941,13 @@ fn create_task_json(params: &CreateTaskParams) -> serde_json::Value {
                 "branch": result.task.branch,
                 "target": result.task.target,
                 "worktree_path": result.worktree_path,
-            }
-        }),
+                "sparse_paths": crate::git::sparse_checkout::sparse_paths(&result.worktree_path)
+                    .unwrap_or_default(),
+                "template": template.as_ref().map(|t| t.name.clone()),
+            },
+            "warning": result.warning,
+            })
+        }
         Err(e) => error_json("task_create_failed", format!("Failed to create task: {e}")),
     }
 }
@@ -2649,7 +3005,12 @@ fn edit_note_json(params: &EditNoteParams) -> serde_json::Value {
         Err(e) => return error_json("internal_error", format!("Failed to verify task: {e}")),
     }
 
-    if let Err(e) = notes::save_notes(&params.project_id, &params.task_id, &params.content) {
+    if let Err(e) = notes::save_notes(
+        &params.project_id,
+        &params.task_id,
+        &params.content,
+        notes::NoteSource::Mcp,
+    ) {
         return error_json("save_failed", format!("Failed to save notes: {e}"));
     }
 
@@ -2761,6 +3122,12 @@ fn build_grove_env(
     env.insert("GROVE_PROJECT_NAME".into(), project_name.into());
     env.insert("GROVE_PROJECT".into(), project_path.into());
     env.insert("GROVE_PROJECT_KEY".into(), project_key.into());
+    if let Some(scope) = crate::storage::task_scope::load_scope(project_key, &task.id) {
+        env.insert("GROVE_SCOPE".into(), scope);
+    }
+    for (key, value) in crate::storage::templates::task_env(project_key, &task.id) {
+        env.entry(key).or_insert(value);
+    }
     env
 }
 
@@ -2768,11 +3135,10 @@ async fn start_chat_impl(p: StartChatParams) -> Result<CallToolResult, McpError>
     let (project_key, project_path, project_name) = resolve_project_for_mcp(&p.project_id)?;
     let task = resolve_task_for_mcp(&project_key, &p.task_id)?;
 
-    let cfg = config::load_config();
     let agent_name = p
         .agent
-        .or_else(|| cfg.acp.agent_command.clone())
-        .or_else(acp::pick_first_available_acp_agent)
+        .or_else(|| crate::storage::templates::task_agent(&project_key, &task.id))
+        .or_else(acp::client::default_agent)
         .ok_or_else(|| {
             McpError::invalid_params(
                 "no agent configured and no installed agents found — install one via the Marketplace first"
@@ -2785,31 +3151,24 @@ async fn start_chat_impl(p: StartChatParams) -> Result<CallToolResult, McpError>
     let resolved = acp::resolve_agent(&agent_name)
         .ok_or_else(|| McpError::invalid_params(format!("Unknown agent: {}", agent_name), None))?;
 
-    // Create chat session in storage
-    let now = chrono::Utc::now();
-    let title = p
-        .name
-        .unwrap_or_else(|| format!("New Chat {}", now.format("%Y-%m-%d %H:%M")));
-    let chat_id = tasks::generate_chat_id();
-
-    let chat = tasks::ChatSession {
-        id: chat_id.clone(),
-        title: title.clone(),
-        agent: agent_name.clone(),
-        acp_session_id: None,
-        created_at: now,
-        duty: None,
-        launch_mode: "acp".to_string(),
+    let work_subdir = match p.work_subdir.as_deref() {
+        Some(subdir) => tasks::normalize_work_subdir(&task.worktree_path, subdir)
+            .map_err(|e| McpError::invalid_params(e.to_string(), None))?,
+        None => None,
     };
 
-    tasks::add_chat_session(&project_key, &p.task_id, chat)
+    // Create chat session in storage
+    let chat = acp::client::create_chat(&project_key, &p.task_id, &agent_name, p.name, work_subdir)
         .map_err(|e| McpError::internal_error(format!("Failed to save chat: {e}"), None))?;
+    let working_dir = chat.working_dir(&task.worktree_path);
+    let chat_id = chat.id;
+    let title = chat.title;
 
     // Build ACP start config
     let env_vars = build_grove_env(&project_key, &project_path, &project_name, &task);
     let session_key = build_session_key(&project_key, &p.task_id, &chat_id);
-    let working_dir = std::path::PathBuf::from(&task.worktree_path);
 
+    let share_process = acp::shares_agent_process(&resolved.agent_name);
     let acp_config = acp::AcpStartConfig {
         agent_command: resolved.command,
         agent_name: resolved.agent_name,
@@ -2824,6 +3183,9 @@ async fn start_chat_impl(p: StartChatParams) -> Result<CallToolResult, McpError>
         remote_auth: resolved.auth_header,
         suppress_initial_connecting: false,
         persona_injection: None,
+        initial_mode: p.mode,
+        initial_model: p.model,
+        share_process,
     };
 
     // Start session (non-blocking — caller should use grove_chat_status to wait for ready)
@@ -2835,60 +3197,22 @@ async fn start_chat_impl(p: StartChatParams) -> Result<CallToolResult, McpError>
         "chat_id": chat_id,
         "name": title,
         "agent": agent_name,
+        "work_subdir": chat.work_subdir,
     }))
 }
 
-/// Get an existing session handle, or auto-start one if the chat exists in storage.
-/// Resolve session access: discover existing (local or remote), or auto-start a new one.
-async fn resolve_session_access(
+/// Resolve a chat client: discover an existing session (local or remote), or
+/// auto-start one if the chat exists in storage.
+async fn resolve_chat_client(
     project_key: &str,
     project_path: &str,
     project_name: &str,
     task: &tasks::Task,
     chat_id: &str,
-) -> Result<acp::SessionAccess, McpError> {
-    let session_key = build_session_key(project_key, &task.id, chat_id);
-
-    // Try discover (in-process HashMap → socket probe)
-    if let Some(access) = acp::discover_session(project_key, &task.id, chat_id, &session_key) {
-        return Ok(access);
-    }
-
-    // Not found anywhere — look up chat in storage and auto-start
-    let chat = tasks::get_chat_session(project_key, &task.id, chat_id)
-        .map_err(|e| McpError::internal_error(format!("Failed to load chat: {e}"), None))?
-        .ok_or_else(|| McpError::invalid_params("Chat not found", None))?;
-
-    let resolved = acp::resolve_agent(&chat.agent)
-        .ok_or_else(|| McpError::internal_error(format!("Unknown agent: {}", chat.agent), None))?;
-
-    let env_vars = build_grove_env(project_key, project_path, project_name, task);
-    let working_dir = std::path::PathBuf::from(&task.worktree_path);
-
-    let config = acp::AcpStartConfig {
-        agent_command: resolved.command,
-        agent_name: resolved.agent_name,
-        agent_args: resolved.args,
-        working_dir,
-        env_vars,
-        project_key: project_key.to_string(),
-        task_id: task.id.clone(),
-        chat_id: Some(chat_id.to_string()),
-        agent_type: resolved.agent_type,
-        remote_url: resolved.url,
-        remote_auth: resolved.auth_header,
-        suppress_initial_connecting: false,
-        persona_injection: None,
-    };
-
-    // Start session — may race with another process. If bind() fails (AddrInUse),
-    // the socket listener inside get_or_start_session will log and skip, which is fine.
-    // A subsequent discover_session would find the remote.
-    let (handle, _rx) = acp::get_or_start_session(session_key, config)
+) -> Result<ChatClient, McpError> {
+    ChatClient::connect(project_key, project_path, project_name, task, chat_id)
         .await
-        .map_err(|e| McpError::internal_error(format!("Failed to start ACP session: {e}"), None))?;
-
-    Ok(acp::SessionAccess::Local(handle))
+        .map_err(|e| McpError::internal_error(format!("Failed to connect to chat: {e}"), None))
 }
 
 async fn send_prompt_impl(p: SendPromptParams) -> Result<CallToolResult, McpError> {
@@ -2911,7 +3235,7 @@ async fn send_prompt_impl(p: SendPromptParams) -> Result<CallToolResult, McpErro
     let (project_key, project_path, project_name) = resolve_project_for_mcp(&p.project_id)?;
     let task = resolve_task_for_mcp(&project_key, &p.task_id)?;
 
-    let access = resolve_session_access(
+    let client = resolve_chat_client(
         &project_key,
         &project_path,
         &project_name,
@@ -2920,49 +3244,167 @@ async fn send_prompt_impl(p: SendPromptParams) -> Result<CallToolResult, McpErro
     )
     .await?;
 
-    match access {
-        acp::SessionAccess::Local(handle) => send_prompt_local(&handle, p).await,
-        acp::SessionAccess::Remote { sock_path, .. } => send_prompt_remote(&sock_path, p).await,
-    }
+    let (cmd, action_name) = if p.cancel {
+        (acp::SocketCommand::Cancel, "cancelled")
+    } else if let Some(option_id) = p.permission_option_id {
+        (
+            acp::SocketCommand::RespondPermission { option_id },
+            "permission_responded",
+        )
+    } else {
+        // Bundle any model/mode/thought_level into config so the cmd_loop
+        // applies them as ACP requests right before sending the prompt.
+        let text = p.text.unwrap(); // safe: validated above
+        let config = build_queued_config(
+            p.model_id.clone(),
+            p.mode_id.clone(),
+            p.thought_level_config_id.clone(),
+            p.thought_level_value_id.clone(),
+        )?;
+        (
+            acp::SocketCommand::Prompt {
+                text,
+                attachments: vec![],
+                sender: p.sender,
+                config,
+            },
+            "prompt_sent",
+        )
+    };
+
+    client
+        .send(cmd)
+        .await
+        .map_err(|e| McpError::internal_error(format!("Chat command failed: {e}"), None))?;
+
+    ok_json(json!({ "action": action_name }))
 }
 
-/// Send prompt via local in-process handle
-async fn send_prompt_local(
-    handle: &acp::AcpSessionHandle,
-    p: SendPromptParams,
-) -> Result<CallToolResult, McpError> {
-    // Action: cancel
-    if p.cancel {
-        handle
-            .cancel()
-            .await
-            .map_err(|e| McpError::internal_error(format!("Failed to cancel: {e}"), None))?;
-        return ok_json(json!({ "action": "cancelled" }));
-    }
+async fn cross_review_impl(p: CrossReviewParams) -> Result<CallToolResult, McpError> {
+    let (project_key, project_path, project_name) = resolve_project_for_mcp(&p.project_id)?;
+    let _ = resolve_task_for_mcp(&project_key, &p.task_id)?;
 
-    // Action: respond to permission
-    if let Some(option_id) = p.permission_option_id {
-        handle.respond_permission(option_id);
-        return ok_json(json!({ "action": "permission_responded" }));
+    let to_mcp = |e: crate::error::GroveError| match e {
+        crate::error::GroveError::InvalidData(msg) | crate::error::GroveError::NotFound(msg) => {
+            McpError::invalid_params(msg, None)
+        }
+        e => McpError::internal_error(format!("Cross review failed: {e}"), None),
+    };
+    let plan = operations::cross_review::prepare(&project_key, &p.task_id, p.agent.as_deref())
+        .map_err(to_mcp)?;
+    let author_agents = plan.author_agents.clone();
+    let started = operations::cross_review::launch(
+        &project_key,
+        &project_path,
+        &project_name,
+        &p.task_id,
+        plan,
+    )
+    .await
+    .map_err(to_mcp)?;
+
+    ok_json(json!({
+        "chat_id": started.chat_id,
+        "name": started.title,
+        "agent": started.agent,
+        "author_agents": author_agents,
+    }))
+}
+
+async fn broadcast_prompt_impl(p: BroadcastPromptParams) -> Result<CallToolResult, McpError> {
+    if p.text.trim().is_empty() {
+        return Err(McpError::invalid_params("`text` must not be empty", None));
     }
+    let (project_key, project_path, project_name) = resolve_project_for_mcp(&p.project_id)?;
 
-    // Action: send prompt — bundle any model/mode/thought_level into config so the
-    // cmd_loop applies them as ACP requests right before sending the prompt.
-    let text = p.text.unwrap(); // safe: validated above
+    let targets = match p.chats {
+        Some(chats) if chats.is_empty() => {
+            return Err(McpError::invalid_params(
+                "`chats` must not be empty (omit it to target all running chats)",
+                None,
+            ));
+        }
+        Some(chats) => dedupe_targets(chats),
+        None => running_chats(&project_key)?,
+    };
 
-    let config = build_queued_config(
-        p.model_id.clone(),
-        p.mode_id.clone(),
-        p.thought_level_config_id.clone(),
-        p.thought_level_value_id.clone(),
-    )?;
+    let sends = targets.into_iter().map(|target| {
+        let (project_key, project_path, project_name) =
+            (&project_key, &project_path, &project_name);
+        let (text, sender) = (p.text.clone(), p.sender.clone());
+        async move {
+            let result = async {
+                let task = resolve_task_for_mcp(project_key, &target.task_id)
+                    .map_err(|e| e.message.to_string())?;
+                let client = resolve_chat_client(
+                    project_key,
+                    project_path,
+                    project_name,
+                    &task,
+                    &target.chat_id,
+                )
+                .await
+                .map_err(|e| e.message.to_string())?;
+                client
+                    .send(acp::SocketCommand::Prompt {
+                        text,
+                        attachments: vec![],
+                        sender,
+                        config: None,
+                    })
+                    .await
+                    .map_err(|e| format!("Chat command failed: {e}"))
+            }
+            .await;
+            let mut entry = json!({
+                "task_id": target.task_id,
+                "chat_id": target.chat_id,
+                "status": if result.is_ok() { "accepted" } else { "failed" },
+            });
+            if let Err(error) = result {
+                entry["error"] = json!(error);
+            }
+            entry
+        }
+    });
+    let results = futures::future::join_all(sends).await;
 
-    handle
-        .send_prompt(text, vec![], p.sender, false, config)
-        .await
-        .map_err(|e| McpError::internal_error(format!("Failed to send prompt: {e}"), None))?;
+    let accepted = results.iter().filter(|r| r["status"] == "accepted").count();
+    ok_json(json!({
+        "accepted": accepted,
+        "failed": results.len() - accepted,
+        "results": results,
+    }))
+}
 
-    ok_json(json!({ "action": "prompt_sent" }))
+/// Drop repeated (task, chat) pairs so a chat listed twice gets the prompt once.
+/// Keeps the first occurrence, preserving the caller's order.
+fn dedupe_targets(targets: Vec<ChatTarget>) -> Vec<ChatTarget> {
+    let mut seen = HashSet::new();
+    targets
+        .into_iter()
+        .filter(|t| seen.insert((t.task_id.clone(), t.chat_id.clone())))
+        .collect()
+}
+
+/// Chats of the project's active tasks whose agent session is currently running.
+fn running_chats(project_key: &str) -> Result<Vec<ChatTarget>, McpError> {
+    let task_list = tasks::load_tasks(project_key)
+        .map_err(|e| McpError::internal_error(format!("Failed to load tasks: {e}"), None))?;
+    let mut targets = Vec::new();
+    for task in task_list {
+        let chats = tasks::load_chat_sessions(project_key, &task.id).unwrap_or_default();
+        for chat in chats {
+            let session_key = build_session_key(project_key, &task.id, &chat.id);
+            if acp::discover_session(project_key, &task.id, &chat.id, &session_key).is_some() {
+                targets.push(ChatTarget {
+                    task_id: task.id.clone(),
+                    chat_id: chat.id,
+                });
+            }
+        }
+    }
+    Ok(targets)
 }
 
 /// Assemble a `QueuedConfig` from the MCP params; returns None when no fields are set.
@@ -3003,56 +3445,12 @@ fn build_queued_config(
     }))
 }
 
-/// Send prompt via Unix socket to remote session owner
-async fn send_prompt_remote(
-    sock_path: &std::path::Path,
-    p: SendPromptParams,
-) -> Result<CallToolResult, McpError> {
-    let cmd = if p.cancel {
-        acp::SocketCommand::Cancel
-    } else if let Some(option_id) = p.permission_option_id {
-        acp::SocketCommand::RespondPermission { option_id }
-    } else {
-        let text = p.text.unwrap(); // safe: validated above
-        let config = build_queued_config(
-            p.model_id.clone(),
-            p.mode_id.clone(),
-            p.thought_level_config_id.clone(),
-            p.thought_level_value_id.clone(),
-        )?;
-        acp::SocketCommand::Prompt {
-            text,
-            attachments: vec![],
-            sender: p.sender,
-            config,
-        }
-    };
-
-    let action_name = match &cmd {
-        acp::SocketCommand::Cancel => "cancelled",
-        acp::SocketCommand::RespondPermission { .. } => "permission_responded",
-        _ => "prompt_sent",
-    };
-
-    let resp = acp::send_socket_command(sock_path, &cmd)
-        .await
-        .map_err(|e| McpError::internal_error(format!("Socket command failed: {e}"), None))?;
-
-    match resp {
-        acp::SocketResponse::Ok => ok_json(json!({ "action": action_name })),
-        acp::SocketResponse::Error { message } => Err(McpError::internal_error(
-            format!("Remote command failed: {}", message),
-            None,
-        )),
-    }
-}
-
 async fn chat_status_impl(p: ChatStatusParams) -> Result<CallToolResult, McpError> {
     let (project_key, project_path, project_name) = resolve_project_for_mcp(&p.project_id)?;
     let task = resolve_task_for_mcp(&project_key, &p.task_id)?;
 
     // Auto-connect: resolve session (local, remote, or start new)
-    let access = resolve_session_access(
+    let client = resolve_chat_client(
         &project_key,
         &project_path,
         &project_name,
@@ -3061,21 +3459,150 @@ async fn chat_status_impl(p: ChatStatusParams) -> Result<CallToolResult, McpErro
     )
     .await?;
 
-    match access {
-        acp::SessionAccess::Local(handle) => chat_status_from_handle(&handle).await,
-        acp::SessionAccess::Remote {
-            project_key,
-            task_id,
-            chat_id,
-            ..
-        } => chat_status_from_disk(&project_key, &task_id, &chat_id).await,
+    let deadline = p.wait_for_idle.then(|| {
+        let secs = p
+            .timeout_secs
+            .unwrap_or(WAIT_FOR_IDLE_DEFAULT_SECS)
+            .clamp(1, WAIT_FOR_IDLE_MAX_SECS);
+        tokio::time::Instant::now() + tokio::time::Duration::from_secs(secs)
+    });
+
+    loop {
+        let mut status = match client.access() {
+            Some(acp::SessionAccess::Local(handle)) => chat_status_from_handle(handle).await?,
+            _ => {
+                chat_status_from_disk(&client.project_key, &client.task_id, &client.chat_id).await?
+            }
+        };
+        let Some(deadline) = deadline else {
+            return ok_json(status);
+        };
+        if status["state"] != "busy" {
+            return ok_json(status);
+        }
+        if tokio::time::Instant::now() >= deadline {
+            status["timed_out"] = json!(true);
+            return ok_json(status);
+        }
+        tokio::time::sleep_until(
+            (tokio::time::Instant::now() + WAIT_FOR_IDLE_POLL_INTERVAL).min(deadline),
+        )
+        .await;
+    }
+}
+
+/// `wait_for_idle` default / max wait and re-check interval.
+const WAIT_FOR_IDLE_DEFAULT_SECS: u64 = 60;
+const WAIT_FOR_IDLE_MAX_SECS: u64 = 600;
+const WAIT_FOR_IDLE_POLL_INTERVAL: tokio::time::Duration = tokio::time::Duration::from_millis(500);
+
+/// Finished `chat_status` answers cached per chat and query, so aggressive
+/// polling doesn't reload and re-compact history.jsonl every call. An entry
+/// is reused only while the file's size / mtime are unchanged and it is
+/// younger than `STATUS_CACHE_TTL`.
+const STATUS_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(2);
+
+type HistoryFingerprint = Option<(u64, std::time::SystemTime)>;
+
+/// Mode / model selectors reported next to the history-derived status. Part
+/// of the cache key: the same history with other selectors is another answer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+struct StatusSelectors {
+    modes: Vec<(String, String)>,
+    models: Vec<(String, String)>,
+    thought_levels: Vec<(String, String)>,
+    current_thought_level_id: Option<String>,
+    thought_level_config_id: Option<String>,
+}
+
+struct CachedStatus {
+    fingerprint: HistoryFingerprint,
+    loaded_at: std::time::Instant,
+    status: std::sync::Arc<serde_json::Value>,
+}
+
+/// (project_key, task_id, chat_id, selectors)
+type StatusKey = (String, String, String, StatusSelectors);
+
+static STATUS_CACHE: once_cell::sync::Lazy<
+    std::sync::Mutex<std::collections::HashMap<StatusKey, CachedStatus>>,
+> = once_cell::sync::Lazy::new(Default::default);
+
+fn history_fingerprint(project_key: &str, task_id: &str, chat_id: &str) -> HistoryFingerprint {
+    let meta = std::fs::metadata(chat_history::history_file_path(
+        project_key,
+        task_id,
+        chat_id,
+    ))
+    .ok()?;
+    Some((meta.len(), meta.modified().ok()?))
+}
+
+/// Chat status built from history on disk, served from `STATUS_CACHE` when
+/// still fresh. Blocking: call through `chat_status_json`.
+fn cached_chat_status(
+    project_key: &str,
+    task_id: &str,
+    chat_id: &str,
+    selectors: StatusSelectors,
+) -> std::sync::Arc<serde_json::Value> {
+    let key = (
+        project_key.to_string(),
+        task_id.to_string(),
+        chat_id.to_string(),
+        selectors,
+    );
+    let fingerprint = history_fingerprint(project_key, task_id, chat_id);
+    if let Ok(cache) = STATUS_CACHE.lock() {
+        if let Some(hit) = cache.get(&key) {
+            if hit.fingerprint == fingerprint && hit.loaded_at.elapsed() < STATUS_CACHE_TTL {
+                return hit.status.clone();
+            }
+        }
     }
+
+    let compacted =
+        chat_history::compact_events(chat_history::load_history(project_key, task_id, chat_id));
+    let status = std::sync::Arc::new(build_chat_status_json(&compacted, &key.3));
+    if let Ok(mut cache) = STATUS_CACHE.lock() {
+        cache.retain(|_, c| c.loaded_at.elapsed() < STATUS_CACHE_TTL);
+        cache.insert(
+            key,
+            CachedStatus {
+                fingerprint,
+                loaded_at: std::time::Instant::now(),
+                status: status.clone(),
+            },
+        );
+    }
+    status
+}
+
+/// `cached_chat_status` on the blocking pool, so history reads and
+/// compaction don't stall the runtime's worker threads.
+async fn chat_status_json(
+    project_key: &str,
+    task_id: &str,
+    chat_id: &str,
+    selectors: StatusSelectors,
+) -> Result<serde_json::Value, McpError> {
+    let (project_key, task_id, chat_id) = (
+        project_key.to_string(),
+        task_id.to_string(),
+        chat_id.to_string(),
+    );
+    tokio::task::spawn_blocking(move || {
+        cached_chat_status(&project_key, &task_id, &chat_id, selectors)
+    })
+    .await
+    .map(|status| (*status).clone())
+    .map_err(|e| McpError::internal_error(format!("Failed to load chat history: {e}"), None))
 }
 
 /// Build chat status from local in-process handle
 async fn chat_status_from_handle(
     handle: &acp::AcpSessionHandle,
-) -> Result<CallToolResult, McpError> {
+) -> Result<serde_json::Value, McpError> {
     // Get modes/models: check agent_info first (already initialized), else wait for broadcast
     let timeout = tokio::time::Duration::from_secs(60);
     let (persist_project, persist_task, persist_chat) = handle.persist_info();
@@ -3089,15 +3616,17 @@ async fn chat_status_from_handle(
         Option<String>,
     );
     let from_meta: Option<Selectors> = if let Some(ref cid) = persist_chat {
-        acp::read_session_metadata(&persist_project, &persist_task, cid).map(|m| {
-            (
-                m.available_modes,
-                m.available_models,
-                m.available_thought_levels,
-                m.current_thought_level_id,
-                m.thought_level_config_id,
-            )
-        })
+        read_session_metadata_async(&persist_project, &persist_task, cid)
+            .await
+            .map(|m| {
+                (
+                    m.available_modes,
+                    m.available_models,
+                    m.available_thought_levels,
+                    m.current_thought_level_id,
+                    m.thought_level_config_id,
+                )
+            })
     } else {
         None
     };
@@ -3115,7 +3644,7 @@ async fn chat_status_from_handle(
         let mut rx = handle.subscribe();
         tokio::time::timeout(timeout, async move {
             loop {
-                match rx.recv().await {
+                match rx.recv().await.map(|e| e.update) {
                     Ok(acp::AcpUpdate::SessionReady {
                         available_modes,
                         available_models,
@@ -3149,22 +3678,37 @@ async fn chat_status_from_handle(
         })?
     };
 
-    // Read history from disk (single source of truth)
-    let history = if let Some(ref cid) = persist_chat {
-        chat_history::load_history(&persist_project, &persist_task, cid)
-    } else {
-        vec![]
+    let selectors = StatusSelectors {
+        modes: available_modes,
+        models: available_models,
+        thought_levels: available_thought_levels,
+        current_thought_level_id,
+        thought_level_config_id,
     };
-    let compacted = chat_history::compact_events(history);
-
-    build_chat_status_json(
-        &compacted,
-        &available_modes,
-        &available_models,
-        &available_thought_levels,
-        current_thought_level_id.as_deref(),
-        thought_level_config_id.as_deref(),
-    )
+    // Read history from disk (single source of truth)
+    match persist_chat {
+        Some(ref cid) => chat_status_json(&persist_project, &persist_task, cid, selectors).await,
+        None => Ok(build_chat_status_json(&[], &selectors)),
+    }
+}
+
+/// `acp::read_session_metadata` on the blocking pool
+async fn read_session_metadata_async(
+    project_key: &str,
+    task_id: &str,
+    chat_id: &str,
+) -> Option<acp::SessionMetadata> {
+    let (project_key, task_id, chat_id) = (
+        project_key.to_string(),
+        task_id.to_string(),
+        chat_id.to_string(),
+    );
+    tokio::task::spawn_blocking(move || {
+        acp::read_session_metadata(&project_key, &task_id, &chat_id)
+    })
+    .await
+    .ok()
+    .flatten()
 }
 
 /// Build chat status from disk (for remote sessions owned by another process)
@@ -3172,12 +3716,12 @@ async fn chat_status_from_disk(
     project_key: &str,
     task_id: &str,
     chat_id: &str,
-) -> Result<CallToolResult, McpError> {
+) -> Result<serde_json::Value, McpError> {
     // Poll session.json for modes/models (may not be written yet if agent just started)
     let timeout = tokio::time::Duration::from_secs(60);
     let metadata = tokio::time::timeout(timeout, async {
         loop {
-            if let Some(meta) = acp::read_session_metadata(project_key, task_id, chat_id) {
+            if let Some(meta) = read_session_metadata_async(project_key, task_id, chat_id).await {
                 return meta;
             }
             tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
@@ -3188,29 +3732,29 @@ async fn chat_status_from_disk(
         McpError::internal_error("Timeout waiting for remote session metadata (60s)", None)
     })?;
 
+    let selectors = StatusSelectors {
+        modes: metadata.available_modes,
+        models: metadata.available_models,
+        thought_levels: metadata.available_thought_levels,
+        current_thought_level_id: metadata.current_thought_level_id,
+        thought_level_config_id: metadata.thought_level_config_id,
+    };
     // Load history from disk and compact
-    let history = chat_history::load_history(project_key, task_id, chat_id);
-    let compacted = chat_history::compact_events(history);
-
-    build_chat_status_json(
-        &compacted,
-        &metadata.available_modes,
-        &metadata.available_models,
-        &metadata.available_thought_levels,
-        metadata.current_thought_level_id.as_deref(),
-        metadata.thought_level_config_id.as_deref(),
-    )
+    chat_status_json(project_key, task_id, chat_id, selectors).await
 }
 
 /// Build the chat status JSON response from compacted events and mode/model info
 fn build_chat_status_json(
     compacted: &[acp::AcpUpdate],
-    available_modes: &[(String, String)],
-    available_models: &[(String, String)],
-    available_thought_levels: &[(String, String)],
-    current_thought_level_id: Option<&str>,
-    thought_level_config_id: Option<&str>,
-) -> Result<CallToolResult, McpError> {
+    selectors: &StatusSelectors,
+) -> serde_json::Value {
+    let StatusSelectors {
+        modes: available_modes,
+        models: available_models,
+        thought_levels: available_thought_levels,
+        current_thought_level_id,
+        thought_level_config_id,
+    } = selectors;
     let last_message = extract_last_message(compacted);
     let plan = extract_last_plan(compacted);
     let turn_count = compacted
@@ -3273,7 +3817,7 @@ fn build_chat_status_json(
         result["plan_file"] = json!(plan_file);
     }
 
-    ok_json(result)
+    result
 }
 
 /// Extract the last plan file path from events
@@ -3303,6 +3847,7 @@ async fn list_chats_impl(p: ListChatsParams) -> Result<CallToolResult, McpError>
                 "title": c.title,
                 "agent": c.agent,
                 "created_at": c.created_at.to_rfc3339(),
+                "work_subdir": c.work_subdir,
             })
         })
         .collect();
@@ -3460,6 +4005,26 @@ mod tests {
     // us and produced flaky failures.
     use crate::storage::database::test_lock;
 
+    #[test]
+    fn broadcast_targets_are_deduplicated_in_order() {
+        let target = |task: &str, chat: &str| ChatTarget {
+            task_id: task.into(),
+            chat_id: chat.into(),
+        };
+        let deduped = dedupe_targets(vec![
+            target("t1", "c1"),
+            target("t2", "c1"),
+            target("t1", "c1"),
+            target("t1", "c2"),
+            target("t2", "c1"),
+        ]);
+        let pairs: Vec<(&str, &str)> = deduped
+            .iter()
+            .map(|t| (t.task_id.as_str(), t.chat_id.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("t1", "c1"), ("t2", "c1"), ("t1", "c2")]);
+    }
+
     /// Regression: `focus` and `gap` in arrow bindings must round-trip
     /// through the typed `SketchElement` schema. Before the fix these fields
     /// were silently dropped by serde because `SketchElementBinding` only
@@ -3693,6 +4258,55 @@ mod tests {
 
     // ---- Sync tests ----
 
+    #[test]
+    fn chat_status_cache_reuses_answer_per_query_until_file_changes() {
+        with_isolated_home(|_| {
+            let user = |text: &str| acp::AcpUpdate::UserMessage {
+                text: text.to_string(),
+                attachments: Vec::new(),
+                sender: None,
+                terminal: false,
+            };
+            let (project, task, chat) = ("p-cache", "t-cache", "chat-cache");
+            chat_history::append_event(project, task, chat, &user("first"));
+
+            let plain = StatusSelectors::default();
+            let with_modes = StatusSelectors {
+                modes: vec![("code".to_string(), "Code".to_string())],
+                ..StatusSelectors::default()
+            };
+
+            let first = cached_chat_status(project, task, chat, plain.clone());
+            assert_eq!(first["state"], "busy");
+            let again = cached_chat_status(project, task, chat, plain.clone());
+            assert!(std::sync::Arc::ptr_eq(&first, &again));
+
+            // Another query on the same history is cached separately
+            let other = cached_chat_status(project, task, chat, with_modes.clone());
+            assert!(!std::sync::Arc::ptr_eq(&first, &other));
+            assert_eq!(other["available_modes"][0]["id"], "code");
+            let other_again = cached_chat_status(project, task, chat, with_modes);
+            assert!(std::sync::Arc::ptr_eq(&other, &other_again));
+
+            chat_history::append_event(
+                project,
+                task,
+                chat,
+                &acp::AcpUpdate::Complete {
+                    stop_reason: "end_turn".to_string(),
+                    usage: None,
+                    start_ts: None,
+                    end_ts: None,
+                    cost: None,
+                },
+            );
+            let refreshed = cached_chat_status(project, task, chat, plain);
+            assert!(!std::sync::Arc::ptr_eq(&first, &refreshed));
+            assert_eq!(refreshed["state"], "idle");
+            assert_eq!(refreshed["turn_count"], 1);
+        })
+    }
+
     #[test]
     fn add_project_invalid_path_returns_success_false() {
         with_isolated_home(|home| {
@@ -3761,6 +4375,8 @@ mod tests {
             let v = create_task_json(&CreateTaskParams {
                 project_id: "deadbeef".to_string(),
                 name: "task".to_string(),
+                sparse_paths: Vec::new(),
+                template: None,
             });
             assert_eq!(v["success"].as_bool(), Some(false));
             assert_eq!(v["error"].as_str(), Some("project_not_found"));
@@ -3779,6 +4395,8 @@ mod tests {
             let created = create_task_json(&CreateTaskParams {
                 project_id: project_id.clone(),
                 name: "MCP Task".to_string(),
+                sparse_paths: Vec::new(),
+                template: None,
             });
             assert_eq!(created["success"].as_bool(), Some(true));
             let task_id = created["task"]["task_id"].as_str().unwrap().to_string();
@@ -3806,6 +4424,60 @@ mod tests {
         })
     }
 
+    #[test]
+    fn create_task_from_template() {
+        with_isolated_home(|home| {
+            let repo = home.join("repo");
+            init_git_repo(&repo);
+
+            let add = add_project_by_path_json(repo.to_string_lossy().as_ref());
+            let project_id = add["project_id"].as_str().unwrap().to_string();
+            crate::storage::templates::save_templates(
+                &project_id,
+                &[crate::storage::templates::TaskTemplate {
+                    name: "bugfix".to_string(),
+                    notes: "# Fix {branch}".to_string(),
+                    agent: Some("codex".to_string()),
+                    env: [("RUST_LOG".to_string(), "debug".to_string())].into(),
+                    ..Default::default()
+                }],
+            )
+            .unwrap();
+
+            let missing = create_task_json(&CreateTaskParams {
+                project_id: project_id.clone(),
+                name: "Nope".to_string(),
+                sparse_paths: Vec::new(),
+                template: Some("missing".to_string()),
+            });
+            assert_eq!(missing["error"].as_str(), Some("template_not_found"));
+
+            let created = create_task_json(&CreateTaskParams {
+                project_id: project_id.clone(),
+                name: "Crash".to_string(),
+                sparse_paths: Vec::new(),
+                template: Some("bugfix".to_string()),
+            });
+            assert_eq!(created["success"].as_bool(), Some(true));
+            assert_eq!(created["task"]["template"].as_str(), Some("bugfix"));
+            let task_id = created["task"]["task_id"].as_str().unwrap();
+            let branch = created["task"]["branch"].as_str().unwrap();
+
+            assert_eq!(
+                notes::load_notes(&project_id, task_id).unwrap(),
+                format!("# Fix {branch}")
+            );
+            assert_eq!(
+                crate::storage::templates::task_agent(&project_id, task_id).as_deref(),
+                Some("codex")
+            );
+            assert_eq!(
+                crate::storage::templates::task_env(&project_id, task_id)["RUST_LOG"],
+                "debug"
+            );
+        })
+    }
+
     #[test]
     fn list_projects_query_filters_correctly() {
         with_isolated_home(|home| {
@@ -3860,10 +4532,14 @@ mod tests {
             create_task_json(&CreateTaskParams {
                 project_id: project_id.clone(),
                 name: "Auth Login".to_string(),
+                sparse_paths: Vec::new(),
+                template: None,
             });
             create_task_json(&CreateTaskParams {
                 project_id: project_id.clone(),
                 name: "Dashboard UI".to_string(),
+                sparse_paths: Vec::new(),
+                template: None,
             });
 
             // No filter → both created tasks plus the auto-created Local task
@@ -3937,8 +4613,11 @@ mod tests {
             "list_agents",
             "start_chat",
             "send_prompt",
+            "broadcast_prompt",
+            "cross_review",
             "chat_status",
             "list_chats",
+            "send_keys",
         ] {
             assert!(
                 names.contains(name),
@@ -3953,6 +4632,7 @@ mod tests {
             "read_review",
             "reply_review",
             "add_comment",
+            "handback",
             "complete_task",
             "sketch_read_me",
             "sketch_list",
@@ -3984,6 +4664,7 @@ mod tests {
             "read_review",
             "reply_review",
             "add_comment",
+            "handback",
             "complete_task",
             "sketch_read_me",
             "sketch_list",
@@ -3997,8 +4678,11 @@ mod tests {
             "list_agents",
             "start_chat",
             "send_prompt",
+            "broadcast_prompt",
+            "cross_review",
             "chat_status",
             "list_chats",
+            "send_keys",
         ] {
             assert!(
                 !names.contains(name),
@@ -4187,6 +4871,15 @@ mod tests {
             .await;
         assert_tool_rejected(&resp_11);
 
+        let resp_12 = client
+            .call_tool(
+                12,
+                "send_keys",
+                json!({"project_id": "x", "task_id": "y", "text": "hi", "enter": true}),
+            )
+            .await;
+        assert_tool_rejected(&resp_12);
+
         client.shutdown().await;
         drop(env);
         let _ = std::fs::remove_dir_all(&temp_home);
@@ -4257,6 +4950,8 @@ mod tests {
         let created = create_task_json(&CreateTaskParams {
             project_id,
             name: "Roundtrip Task".to_string(),
+            sparse_paths: Vec::new(),
+            template: None,
         });
         assert_eq!(created["success"].as_bool(), Some(true));
         let task_id = created["task"]["task_id"].as_str().unwrap().to_string();
@@ -4273,7 +4968,13 @@ mod tests {
         assert_eq!(text, "No notes yet.");
 
         // --- read_notes: save notes, then read back ---
-        notes::save_notes(&project_key, &task_id, "Remember to add tests").unwrap();
+        notes::save_notes(
+            &project_key,
+            &task_id,
+            "Remember to add tests",
+            notes::NoteSource::Mcp,
+        )
+        .unwrap();
         let resp = client.call_tool(3, "read_notes", json!({})).await;
         let text = resp["result"]["content"][0]["text"].as_str().unwrap();
         assert_eq!(text, "Remember to add tests");
@@ -4496,6 +5197,8 @@ mod tests {
         let created = create_task_json(&CreateTaskParams {
             project_id: project_id.clone(),
             name: "Note Test Task".to_string(),
+            sparse_paths: Vec::new(),
+            template: None,
         });
         assert_eq!(created["success"].as_bool(), Some(true));
         let task_id = created["task"]["task_id"].as_str().unwrap().to_string();
@@ -96,6 +96,7 @@ impl LegacyComment {
                     model: String::new(),
                     role: String::new(),
                     timestamp: default_timestamp(),
+                    reactions: Vec::new(),
                 });
             }
         }
@@ -122,6 +123,7 @@ impl LegacyComment {
             status: self.status,
             replies: self.replies,
             anchor_text: self.anchor_text,
+            reactions: Vec::new(),
         }
     }
 }
@@ -171,6 +173,7 @@ fn parse_diff_comments(content: &str) -> Vec<crate::storage::comments::Comment>
                 status: CommentStatus::Open,
                 replies: Vec::new(),
                 anchor_text: None,
+                reactions: Vec::new(),
             });
             id += 1;
         }
@@ -589,6 +592,7 @@ fn load_legacy_ai_comments(ai_task_dir: &Path) -> Vec<crate::storage::comments::
                         model: String::new(),
                         role: String::new(),
                         timestamp: default_timestamp(),
+                        reactions: Vec::new(),
                     });
                 }
             }
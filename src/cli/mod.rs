@@ -1,12 +1,16 @@
 //! CLI 模块
 
 pub mod acp;
+pub mod cd;
 pub mod diff;
 pub mod fp;
 pub mod hooks;
 pub mod mcp;
 pub mod mcp_bridge;
 pub mod migrate;
+pub mod notifyd;
+pub mod report;
+pub mod service;
 pub mod web;
 
 #[cfg(feature = "gui")]
@@ -44,7 +48,40 @@ pub enum Commands {
     /// running Grove server's HTTP MCP listener.
     McpBridge,
     /// Interactive file picker using fzf
-    Fp,
+    Fp {
+        /// Pick once and print the selected paths, one per line
+        #[arg(long)]
+        print: bool,
+        /// Pick once and print ACP resource blocks as JSON (for `grove acp --attach`)
+        #[arg(long, conflicts_with = "print")]
+        json: bool,
+        /// Initial fzf query (with --print / --json)
+        #[arg(long)]
+        query: Option<String>,
+        /// Files larger than this (KiB) are linked instead of embedded (--json)
+        #[arg(long, default_value_t = 256)]
+        max_kb: u64,
+    },
+    /// Print the worktree path of the best-matching task (`cd "$(grove cd foo)"`)
+    Cd {
+        /// Fuzzy query over task name, id, branch and project (fzf picker when omitted)
+        query: Vec<String>,
+        /// Print shell code that exports the GROVE_* variables and cd's into the worktree
+        #[arg(long)]
+        env: bool,
+        /// Shell syntax for --env / --init / --install (defaults to $SHELL)
+        #[arg(long, value_enum)]
+        shell: Option<cd::Shell>,
+        /// Print a shell function wrapping `grove cd --env` (`eval "$(grove cd --init)"`)
+        #[arg(long, conflicts_with_all = ["env", "install"])]
+        init: bool,
+        /// Append the --init hook to the shell rc file
+        #[arg(long, conflicts_with = "env")]
+        install: bool,
+        /// Name of the shell function for --init / --install
+        #[arg(long, default_value = "gw")]
+        name: String,
+    },
     /// Start the web UI server (API + frontend)
     Web {
         /// Port to listen on
@@ -61,6 +98,10 @@ pub enum Commands {
         /// will be proxied/directed to this URL (e.g. http://192.168.1.5:3001).
         #[arg(long, value_name = "URL")]
         remote_url: Option<String>,
+        /// Serve a read-only dashboard: only reads and event subscriptions
+        /// are allowed, every change is rejected with 403
+        #[arg(long)]
+        read_only: bool,
     },
     /// Open diff review for a task in the browser
     Diff {
@@ -88,6 +129,10 @@ pub enum Commands {
         /// Working directory
         #[arg(long, default_value = ".")]
         cwd: String,
+        /// Content blocks attached to the first prompt: a JSON array or a file
+        /// containing one (e.g. `--attach "$(grove fp --json)"`)
+        #[arg(long)]
+        attach: Option<String>,
     },
     /// Start the mobile-friendly web server (LAN-accessible with HMAC-SHA256 auth)
     Mobile {
@@ -133,6 +178,27 @@ pub enum Commands {
         /// Path to the project (defaults to current directory)
         path: Option<String>,
     },
+    /// Bundle recent crash logs, environment info and redacted config into a
+    /// zip for attaching to GitHub issues
+    Report {
+        /// Output path (defaults to ./grove-report-<timestamp>.zip)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Headless session host: keeps ACP agents alive and delivers their
+    /// notifications with no frontend open. `grove web` / `grove gui` attach
+    /// to it automatically while it runs.
+    Notifyd {
+        /// Loopback port to listen on
+        #[arg(short, long, default_value_t = notifyd::DEFAULT_PORT)]
+        port: u16,
+    },
+    /// Install or remove a login service (systemd user unit / launchd agent)
+    /// that keeps `grove web` or `grove mobile` running
+    Service {
+        #[command(subcommand)]
+        action: service::ServiceAction,
+    },
 }
 
 impl Commands {
@@ -145,11 +211,13 @@ impl Commands {
                 no_open,
                 dev,
                 remote_url,
+                read_only,
             } => Some(LastLaunch::Web {
                 port: *port,
                 no_open: *no_open,
                 dev: *dev,
                 remote_url: remote_url.clone(),
+                read_only: *read_only,
             }),
             Commands::Mobile {
                 port,
@@ -189,11 +257,13 @@ impl LastLaunch {
                 no_open,
                 dev,
                 remote_url,
+                read_only,
             } => Commands::Web {
                 port: *port,
                 no_open: *no_open,
                 dev: *dev,
                 remote_url: remote_url.clone(),
+                read_only: *read_only,
             },
             LastLaunch::Mobile {
                 port,
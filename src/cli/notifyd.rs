@@ -0,0 +1,162 @@
+//! `grove notifyd` — headless session host
+//!
+//! Runs the API server on loopback with no frontend. ACP sessions started
+//! through it live in this process, so permission / turn-complete
+//! notifications keep firing after the browser or GUI window closes.
+//!
+//! While it runs, `~/.grove/notifyd.json` records its pid and port. `grove
+//! web` and `grove gui` check it at startup and, when the daemon answers
+//! `/healthz`, serve their frontend in remote mode against it instead of
+//! hosting sessions themselves.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::web::TlsMode;
+use crate::api::{self, auth::ServerAuth};
+use crate::storage;
+
+/// Default loopback port; kept apart from `grove web` (3001) so both can run.
+pub const DEFAULT_PORT: u16 = 3100;
+
+/// Set to "1" to make `grove web` / `grove gui` ignore a running daemon.
+pub const NO_ATTACH_ENV: &str = "GROVE_NO_NOTIFYD";
+
+const STATE_FILE: &str = "notifyd.json";
+
+/// How long the `/healthz` probe may take before the daemon counts as dead.
+const PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DaemonState {
+    pid: u32,
+    port: u16,
+}
+
+fn state_path() -> PathBuf {
+    storage::grove_dir().join(STATE_FILE)
+}
+
+/// Entry point for `grove notifyd`.
+pub async fn execute(port: u16) {
+    if let Some(url) = running_url() {
+        eprintln!("grove notifyd is already running at {}", url);
+        std::process::exit(1);
+    }
+    // Clients find the daemon through the state file, so it must own exactly
+    // this port rather than silently falling back to the next free one.
+    if let Err(e) = std::net::TcpListener::bind(("127.0.0.1", port)) {
+        eprintln!("Cannot bind 127.0.0.1:{}: {}", port, e);
+        eprintln!("Pick another port with: grove notifyd --port <port>");
+        std::process::exit(1);
+    }
+
+    let state = DaemonState {
+        pid: std::process::id(),
+        port,
+    };
+    if let Err(e) = serde_json::to_string(&state)
+        .map_err(std::io::Error::other)
+        .and_then(|json| std::fs::write(state_path(), json))
+    {
+        eprintln!("Failed to write {}: {}", state_path().display(), e);
+        std::process::exit(1);
+    }
+
+    println!(
+        "grove notifyd: hosting agent sessions on http://127.0.0.1:{}",
+        port
+    );
+    println!("`grove web` and `grove gui` will attach to it automatically.");
+
+    let result = api::start_server(
+        "127.0.0.1",
+        port,
+        None,
+        false,
+        Arc::new(ServerAuth::no_auth()),
+        TlsMode::Off,
+    )
+    .await;
+
+    let _ = std::fs::remove_file(state_path());
+    if let Err(e) = result {
+        eprintln!("Server error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Base URL of a live daemon, if one is running. Stale state files (daemon
+/// killed without cleanup) are removed.
+pub fn running_url() -> Option<String> {
+    let path = state_path();
+    let content = std::fs::read_to_string(&path).ok()?;
+    let state: DaemonState = match serde_json::from_str(&content) {
+        Ok(s) => s,
+        Err(_) => {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+    };
+    if probe_health(state.port) {
+        Some(format!("http://127.0.0.1:{}", state.port))
+    } else {
+        let _ = std::fs::remove_file(&path);
+        None
+    }
+}
+
+/// Remote URL `grove web` / `grove gui` should attach to, unless the user
+/// opted out via [`NO_ATTACH_ENV`].
+pub fn attach_url() -> Option<String> {
+    if std::env::var(NO_ATTACH_ENV).as_deref() == Ok("1") {
+        return None;
+    }
+    running_url()
+}
+
+fn probe_health(port: u16) -> bool {
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+    let Ok(mut stream) = TcpStream::connect_timeout(&addr, PROBE_TIMEOUT) else {
+        return false;
+    };
+    let _ = stream.set_read_timeout(Some(PROBE_TIMEOUT));
+    let _ = stream.set_write_timeout(Some(PROBE_TIMEOUT));
+    let request = "GET /healthz HTTP/1.0\r\nHost: 127.0.0.1\r\n\r\n";
+    if stream.write_all(request.as_bytes()).is_err() {
+        return false;
+    }
+    let mut response = String::new();
+    let _ = stream.read_to_string(&mut response);
+    is_healthy_response(&response)
+}
+
+fn is_healthy_response(response: &str) -> bool {
+    let status_ok = response
+        .lines()
+        .next()
+        .is_some_and(|l| l.split_whitespace().nth(1) == Some("200"));
+    status_ok && response.contains("\"status\":\"ok\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn healthy_response_requires_200_and_ok_body() {
+        assert!(is_healthy_response(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\r\n{\"status\":\"ok\",\"version\":\"1\"}"
+        ));
+        assert!(!is_healthy_response(
+            "HTTP/1.1 404 Not Found\r\n\r\n{\"status\":\"ok\"}"
+        ));
+        assert!(!is_healthy_response("HTTP/1.1 200 OK\r\n\r\n<html></html>"));
+        assert!(!is_healthy_response(""));
+    }
+}
@@ -0,0 +1,429 @@
+//! `grove service` — run `grove web` / `grove mobile` as a login service
+//!
+//! Linux gets a systemd user unit (`Type=notify`, restarted on failure);
+//! macOS gets a launchd agent (`KeepAlive` on crash). Both capture the
+//! installing shell's `PATH` so git, tmux and agent CLIs resolve the same way
+//! they do interactively.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use clap::{Subcommand, ValueEnum};
+
+use super::{notifyd, web};
+use crate::api::auth;
+use crate::storage;
+
+/// systemd unit name / launchd label
+const SYSTEMD_UNIT: &str = "grove.service";
+const LAUNCHD_LABEL: &str = "com.grove.server";
+
+/// Holds `GROVE_PASSKEY` for the mobile unit (mode 0600).
+const SERVICE_ENV_FILE: &str = "service.env";
+
+#[derive(Subcommand)]
+pub enum ServiceAction {
+    /// Install and start a login service running the Grove server
+    Install {
+        /// Server to run
+        #[arg(value_enum, default_value_t = ServiceMode::Web)]
+        mode: ServiceMode,
+        /// Port to listen on (default: 3001, or 3100 for notifyd)
+        #[arg(short, long)]
+        port: Option<u16>,
+        /// (mobile) Enable TLS with a self-signed certificate
+        #[arg(long)]
+        tls: bool,
+        /// (mobile) Bind to 0.0.0.0 (all interfaces)
+        #[arg(long)]
+        public: bool,
+        /// Only write the service file; don't enable or start it
+        #[arg(long)]
+        no_start: bool,
+    },
+    /// Stop and remove the login service
+    Uninstall,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ServiceMode {
+    Web,
+    Mobile,
+    /// Headless session host (`grove notifyd`)
+    Notifyd,
+}
+
+/// Entry point for `grove service`.
+pub fn execute(action: ServiceAction) {
+    let result = match action {
+        ServiceAction::Install {
+            mode,
+            port,
+            tls,
+            public,
+            no_start,
+        } => install(mode, &server_args(mode, port, tls, public), !no_start),
+        ServiceAction::Uninstall => uninstall(),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// `grove` arguments the service runs with.
+fn server_args(mode: ServiceMode, port: Option<u16>, tls: bool, public: bool) -> Vec<String> {
+    let (mut args, default_port) = match mode {
+        ServiceMode::Web => (vec!["web".to_string()], web::DEFAULT_PORT),
+        ServiceMode::Mobile => (vec!["mobile".to_string()], web::DEFAULT_PORT),
+        ServiceMode::Notifyd => (vec!["notifyd".to_string()], notifyd::DEFAULT_PORT),
+    };
+    let port = port.unwrap_or(default_port);
+    if mode != ServiceMode::Notifyd {
+        args.push("--no-open".to_string());
+    }
+    args.push("--port".to_string());
+    args.push(port.to_string());
+    if mode == ServiceMode::Mobile {
+        if tls {
+            args.push("--tls".to_string());
+        }
+        if public {
+            args.push("--public".to_string());
+        }
+    }
+    args
+}
+
+fn install(mode: ServiceMode, args: &[String], start: bool) -> Result<(), String> {
+    let exe =
+        std::env::current_exe().map_err(|e| format!("cannot resolve grove executable: {}", e))?;
+    let path_env = std::env::var("PATH").unwrap_or_default();
+    let passkey = match mode {
+        ServiceMode::Mobile => Some(ensure_passkey()?),
+        ServiceMode::Web | ServiceMode::Notifyd => None,
+    };
+
+    if cfg!(target_os = "linux") {
+        let unit_path = systemd_unit_path()?;
+        let unit = systemd_unit(&exe, args, &path_env, &storage::grove_dir());
+        write_file(&unit_path, &unit)?;
+        println!("Wrote {}", unit_path.display());
+        if start {
+            run(&["systemctl", "--user", "daemon-reload"])?;
+            run(&["systemctl", "--user", "enable", "--now", SYSTEMD_UNIT])?;
+            println!("Service enabled and started.");
+        } else {
+            println!(
+                "Start it with: systemctl --user enable --now {}",
+                SYSTEMD_UNIT
+            );
+        }
+        println!(
+            "To keep it running after logout: loginctl enable-linger {}",
+            whoami()
+        );
+    } else if cfg!(target_os = "macos") {
+        let plist_path = launchd_plist_path()?;
+        let plist = launchd_plist(&exe, args, &path_env, passkey.as_deref())?;
+        write_file(&plist_path, &plist)?;
+        restrict_permissions(&plist_path);
+        println!("Wrote {}", plist_path.display());
+        let target = plist_path.to_string_lossy().to_string();
+        if start {
+            // Reinstall: unload any previous copy first; failure is expected
+            // when it wasn't loaded.
+            let _ = Command::new("launchctl").args(["unload", &target]).output();
+            run(&["launchctl", "load", "-w", &target])?;
+            println!("Service loaded and started.");
+        } else {
+            println!("Start it with: launchctl load -w {}", target);
+        }
+    } else {
+        return Err("`grove service` supports systemd (Linux) and launchd (macOS) only".into());
+    }
+
+    println!(
+        "Health check: curl http://127.0.0.1:{}/healthz",
+        port_of(args)
+    );
+    if let Some(pk) = passkey {
+        println!("Mobile passkey: {}", pk);
+    }
+    Ok(())
+}
+
+fn uninstall() -> Result<(), String> {
+    if cfg!(target_os = "linux") {
+        let unit_path = systemd_unit_path()?;
+        let _ = Command::new("systemctl")
+            .args(["--user", "disable", "--now", SYSTEMD_UNIT])
+            .output();
+        remove_if_exists(&unit_path)?;
+        let _ = Command::new("systemctl")
+            .args(["--user", "daemon-reload"])
+            .output();
+    } else if cfg!(target_os = "macos") {
+        let plist_path = launchd_plist_path()?;
+        let _ = Command::new("launchctl")
+            .args(["unload", "-w", &plist_path.to_string_lossy()])
+            .output();
+        remove_if_exists(&plist_path)?;
+    } else {
+        return Err("`grove service` supports systemd (Linux) and launchd (macOS) only".into());
+    }
+    remove_if_exists(&storage::grove_dir().join(SERVICE_ENV_FILE))?;
+    println!("Service removed.");
+    Ok(())
+}
+
+/// Reuse the passkey from a previous install so paired phones keep working;
+/// otherwise generate one and store it in `~/.grove/service.env`.
+fn ensure_passkey() -> Result<String, String> {
+    let env_path = storage::grove_dir().join(SERVICE_ENV_FILE);
+    if let Some(existing) = fs::read_to_string(&env_path).ok().and_then(|content| {
+        content.lines().find_map(|l| {
+            l.strip_prefix(&format!("{}=", web::PASSKEY_ENV))
+                .map(|v| v.trim().to_string())
+        })
+    }) {
+        return Ok(existing);
+    }
+
+    let key = auth::generate_secret_key();
+    write_file(&env_path, &format!("{}={}\n", web::PASSKEY_ENV, key))?;
+    restrict_permissions(&env_path);
+    Ok(key)
+}
+
+fn systemd_unit_path() -> Result<PathBuf, String> {
+    dirs::config_dir()
+        .map(|d| d.join("systemd").join("user").join(SYSTEMD_UNIT))
+        .ok_or_else(|| "cannot determine config directory".to_string())
+}
+
+fn launchd_plist_path() -> Result<PathBuf, String> {
+    dirs::home_dir()
+        .map(|h| {
+            h.join("Library")
+                .join("LaunchAgents")
+                .join(format!("{}.plist", LAUNCHD_LABEL))
+        })
+        .ok_or_else(|| "cannot determine home directory".to_string())
+}
+
+fn systemd_unit(exe: &Path, args: &[String], path_env: &str, grove_dir: &Path) -> String {
+    let exec = std::iter::once(systemd_quote(&exe.to_string_lossy()))
+        .chain(args.iter().map(|a| systemd_quote(a)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "[Unit]
+Description=Grove server ({mode})
+After=network-online.target
+Wants=network-online.target
+
+[Service]
+Type=notify
+NotifyAccess=main
+ExecStart={exec}
+Environment={path}
+EnvironmentFile=-{env_file}
+Restart=on-failure
+RestartSec=5
+# First start may download the agent registry.
+TimeoutStartSec=300
+TimeoutStopSec=30
+
+[Install]
+WantedBy=default.target
+",
+        mode = args.first().map(String::as_str).unwrap_or("web"),
+        exec = exec,
+        path = systemd_quote(&format!("PATH={}", path_env)),
+        env_file = grove_dir.join(SERVICE_ENV_FILE).display(),
+    )
+}
+
+/// Quote a word for systemd's command-line / `Environment=` parsing.
+fn systemd_quote(s: &str) -> String {
+    if !s.is_empty()
+        && !s
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | '%' | '$'))
+    {
+        return s.to_string();
+    }
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('%', "%%")
+        .replace('$', "$$");
+    format!("\"{}\"", escaped)
+}
+
+fn launchd_plist(
+    exe: &Path,
+    args: &[String],
+    path_env: &str,
+    passkey: Option<&str>,
+) -> Result<String, String> {
+    use plist::{Dictionary, Value};
+
+    let logs = crate::logging::logs_dir();
+    let program_args = std::iter::once(exe.to_string_lossy().to_string())
+        .chain(args.iter().cloned())
+        .map(Value::String)
+        .collect();
+
+    let mut env = Dictionary::new();
+    env.insert("PATH".into(), Value::String(path_env.to_string()));
+    if let Some(pk) = passkey {
+        env.insert(web::PASSKEY_ENV.into(), Value::String(pk.to_string()));
+    }
+
+    // Restart on crash, but not after a clean exit (e.g. port conflict
+    // handled by the user).
+    let mut keep_alive = Dictionary::new();
+    keep_alive.insert("SuccessfulExit".into(), Value::Boolean(false));
+
+    let mut dict = Dictionary::new();
+    dict.insert("Label".into(), Value::String(LAUNCHD_LABEL.into()));
+    dict.insert("ProgramArguments".into(), Value::Array(program_args));
+    dict.insert("EnvironmentVariables".into(), Value::Dictionary(env));
+    dict.insert("RunAtLoad".into(), Value::Boolean(true));
+    dict.insert("KeepAlive".into(), Value::Dictionary(keep_alive));
+    dict.insert("ThrottleInterval".into(), Value::Integer(5.into()));
+    dict.insert(
+        "StandardOutPath".into(),
+        Value::String(logs.join("service.out.log").to_string_lossy().to_string()),
+    );
+    dict.insert(
+        "StandardErrorPath".into(),
+        Value::String(logs.join("service.err.log").to_string_lossy().to_string()),
+    );
+
+    let mut buf = Vec::new();
+    Value::Dictionary(dict)
+        .to_writer_xml(&mut buf)
+        .map_err(|e| format!("failed to serialize plist: {}", e))?;
+    String::from_utf8(buf).map_err(|e| e.to_string())
+}
+
+fn port_of(args: &[String]) -> &str {
+    args.iter()
+        .position(|a| a == "--port")
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+        .unwrap_or("3001")
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+    }
+    fs::write(path, content).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+fn remove_if_exists(path: &Path) -> Result<(), String> {
+    match fs::remove_file(path) {
+        Ok(()) => {
+            println!("Removed {}", path.display());
+            Ok(())
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("failed to remove {}: {}", path.display(), e)),
+    }
+}
+
+/// Files holding the passkey are readable by the owner only.
+fn restrict_permissions(path: &Path) {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = fs::set_permissions(path, fs::Permissions::from_mode(0o600));
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+fn run(cmd: &[&str]) -> Result<(), String> {
+    let output = Command::new(cmd[0])
+        .args(&cmd[1..])
+        .output()
+        .map_err(|e| format!("failed to run {}: {}", cmd[0], e))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(format!(
+            "`{}` failed: {}",
+            cmd.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+fn whoami() -> String {
+    std::env::var("USER").unwrap_or_else(|_| "$USER".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn server_args_only_pass_mobile_flags_to_mobile() {
+        assert_eq!(
+            server_args(ServiceMode::Web, Some(4000), true, true),
+            vec!["web", "--no-open", "--port", "4000"]
+        );
+        assert_eq!(
+            server_args(ServiceMode::Mobile, None, true, false),
+            vec!["mobile", "--no-open", "--port", "3001", "--tls"]
+        );
+        assert_eq!(
+            server_args(ServiceMode::Notifyd, None, false, false),
+            vec!["notifyd", "--port", "3100"]
+        );
+    }
+
+    #[test]
+    fn systemd_unit_quotes_exec_and_path() {
+        let unit = systemd_unit(
+            Path::new("/opt/my apps/grove"),
+            &server_args(ServiceMode::Web, None, false, false),
+            "/usr/bin:/home/me/.cargo/bin",
+            Path::new("/home/me/.grove"),
+        );
+        assert!(unit.contains("Type=notify\n"));
+        assert!(unit.contains("ExecStart=\"/opt/my apps/grove\" web --no-open --port 3001\n"));
+        assert!(unit.contains("Environment=PATH=/usr/bin:/home/me/.cargo/bin\n"));
+        assert!(unit.contains("EnvironmentFile=-/home/me/.grove/service.env\n"));
+        assert!(unit.contains("Restart=on-failure\n"));
+    }
+
+    #[test]
+    fn systemd_quote_escapes_specifiers() {
+        assert_eq!(systemd_quote("plain"), "plain");
+        assert_eq!(systemd_quote("50%"), "\"50%%\"");
+        assert_eq!(systemd_quote("a\"b"), "\"a\\\"b\"");
+    }
+
+    #[test]
+    fn launchd_plist_contains_program_and_passkey() {
+        let plist = launchd_plist(
+            Path::new("/usr/local/bin/grove"),
+            &server_args(ServiceMode::Mobile, None, false, false),
+            "/usr/bin",
+            Some("abc123def456"),
+        )
+        .unwrap();
+        assert!(plist.contains("<string>com.grove.server</string>"));
+        assert!(plist.contains("<string>/usr/local/bin/grove</string>"));
+        assert!(plist.contains("<string>mobile</string>"));
+        assert!(plist.contains("<key>GROVE_PASSKEY</key>"));
+        assert!(plist.contains("<key>SuccessfulExit</key>"));
+    }
+}
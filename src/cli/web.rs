@@ -99,6 +99,20 @@ fn find_project_dir() -> Option<PathBuf> {
 /// `AuthGate` and `apiClient` direct every API call to the remote Grove
 /// server (typically `grove mobile`). The form appears as usual for password input.
 pub async fn execute(port: u16, no_open: bool, dev: bool, remote_url: Option<String>) {
+    let read_only = api::read_only::enabled();
+    if read_only {
+        println!("Read-only mode: changes from the browser are rejected");
+    }
+
+    let remote_url =
+        match resolve_api_target(remote_url, dev, read_only, super::notifyd::attach_url) {
+            Ok(url) => url,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                std::process::exit(2);
+            }
+        };
+
     if let Some(base_url) = remote_url {
         execute_remote_mode(port, no_open, base_url).await;
     } else if dev {
@@ -110,6 +124,37 @@ pub async fn execute(port: u16, no_open: bool, dev: bool, remote_url: Option<Str
     }
 }
 
+/// Decide which API server the frontend talks to: `Some(url)` for a remote
+/// Grove (explicit `--remote-url` or a running `grove notifyd`), `None` for
+/// the local one.
+///
+/// The read-only guard only exists in this process, so read-only mode never
+/// attaches to notifyd and refuses an explicit remote URL — the browser
+/// would otherwise write to a server that doesn't enforce it.
+fn resolve_api_target(
+    remote_url: Option<String>,
+    dev: bool,
+    read_only: bool,
+    attach_url: impl FnOnce() -> Option<String>,
+) -> Result<Option<String>, String> {
+    if read_only {
+        if remote_url.is_some() {
+            return Err(
+                "--read-only cannot be combined with --remote-url: the remote server \
+                 would not enforce it"
+                    .to_string(),
+            );
+        }
+        return Ok(None);
+    }
+    if remote_url.is_some() || dev {
+        return Ok(remote_url);
+    }
+    // A running `grove notifyd` owns the agent sessions; serve the frontend
+    // against it so closing this server doesn't take agents down with it.
+    Ok(attach_url().inspect(|url| println!("Attaching to grove notifyd at {}", url)))
+}
+
 /// Resolve the bind host for `grove mobile`.
 ///
 /// Priority: `--private` (localhost) > `--host` > `--public` (0.0.0.0) > auto-detected LAN IP > fallback 0.0.0.0
@@ -171,8 +216,13 @@ pub enum TlsMode {
     Custom { cert: String, key: String },
 }
 
+/// Env var supplying a fixed passkey for unattended runs (e.g. the
+/// `grove service install mobile` unit), skipping the prompt.
+pub const PASSKEY_ENV: &str = "GROVE_PASSKEY";
+
 /// Read a passkey interactively, ssh-keygen style.
 ///
+/// - `GROVE_PASSKEY` set → validate and use, no prompt.
 /// - TTY + empty input → auto-generate.
 /// - TTY + non-empty → validate and use.
 /// - No TTY (piped/CI) → auto-generate (we can't prompt).
@@ -181,6 +231,16 @@ pub enum TlsMode {
 /// never appears as a process argv either, so it stays out of `~/.zsh_history`.
 fn read_passkey_interactive(public_bind: bool) -> (String, bool) {
     use std::io::IsTerminal;
+    if let Some(pk) = std::env::var(PASSKEY_ENV)
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+    {
+        if let Err(msg) = validate_passkey(&pk) {
+            eprintln!("Error: {}: {}", PASSKEY_ENV, msg);
+            std::process::exit(2);
+        }
+        return (pk.trim().to_string(), false);
+    }
     if !std::io::stdin().is_terminal() {
         return (auth::generate_secret_key(), true);
     }
@@ -228,7 +288,10 @@ pub async fn execute_mobile(
 ) {
     let bind_host = resolve_mobile_host(host, public, private);
     let (sk, key_is_generated) = read_passkey_interactive(bind_host == "0.0.0.0");
-    let auth = Arc::new(ServerAuth::hmac(sk, key_is_generated));
+    let auth = Arc::new(
+        ServerAuth::hmac(sk, key_is_generated)
+            .with_session_policy(crate::storage::config::load_config().auth),
+    );
 
     // Determine TLS mode: --cert/--key implies --tls
     let tls_mode = match (cert, key) {
@@ -451,3 +514,32 @@ async fn execute_remote_mode(port: u16, no_open: bool, base_url: String) {
         std::process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTIFYD: &str = "http://127.0.0.1:4100";
+
+    #[test]
+    fn attaches_to_notifyd_unless_read_only() {
+        let attach = || Some(NOTIFYD.to_string());
+        assert_eq!(
+            resolve_api_target(None, false, false, attach),
+            Ok(Some(NOTIFYD.to_string()))
+        );
+        // Read-only serves the local API so the guard applies
+        assert_eq!(resolve_api_target(None, false, true, attach), Ok(None));
+        assert_eq!(resolve_api_target(None, true, false, attach), Ok(None));
+    }
+
+    #[test]
+    fn read_only_rejects_remote_url() {
+        let remote = Some("https://grove.example:3002".to_string());
+        assert!(resolve_api_target(remote.clone(), false, true, || None).is_err());
+        assert_eq!(
+            resolve_api_target(remote.clone(), false, false, || None),
+            Ok(remote)
+        );
+    }
+}
@@ -5,14 +5,28 @@
 // 从 ui/components 导入对话框数据类型
 pub use crate::ui::components::action_palette::ActionPaletteData;
 pub use crate::ui::components::add_project_dialog::AddProjectData;
+pub use crate::ui::components::autolink_panel::AutoLinkPanelData;
 pub use crate::ui::components::branch_selector::BranchSelectorData;
+pub use crate::ui::components::chat_view::ChatViewData;
+pub use crate::ui::components::column_picker::ColumnPickerData;
 pub use crate::ui::components::commit_dialog::CommitDialogData;
 pub use crate::ui::components::config_panel::ConfigPanelData;
 pub use crate::ui::components::confirm_dialog::ConfirmType;
 pub use crate::ui::components::delete_project_dialog::DeleteProjectData;
+pub use crate::ui::components::global_search_panel::GlobalSearchData;
+pub use crate::ui::components::inbox_panel::InboxPanelData;
 pub use crate::ui::components::input_confirm_dialog::InputConfirmData;
+pub use crate::ui::components::log_viewer::LogViewerData;
 pub use crate::ui::components::merge_dialog::MergeDialogData;
 pub use crate::ui::components::new_project_dialog::NewProjectData;
+pub use crate::ui::components::notes_history_panel::NotesHistoryData;
+pub use crate::ui::components::notification_center::NotificationCenterData;
+pub use crate::ui::components::open_with_picker::OpenWithData;
+pub use crate::ui::components::project_notes_panel::ProjectNotesData;
+pub use crate::ui::components::search_panel::SearchPanelData;
+pub use crate::ui::components::stash_panel::StashPanelData;
+
+use crate::storage::templates::TaskTemplate;
 
 /// 对话框状态
 #[derive(Debug)]
@@ -22,6 +36,10 @@ pub struct DialogState {
     pub show_new_task_dialog: bool,
     /// New Task 输入内容
     pub new_task_input: String,
+    /// New Task 可选的任务模板
+    pub new_task_templates: Vec<TaskTemplate>,
+    /// New Task 选中的模板（None = 不使用模板）
+    pub new_task_template: Option<usize>,
 
     // === Help ===
     /// 是否显示帮助面板
@@ -60,6 +78,54 @@ pub struct DialogState {
     // === Config Panel ===
     /// Config 配置面板
     pub config_panel: Option<ConfigPanelData>,
+
+    // === Log Viewer ===
+    /// 日志查看器
+    pub log_viewer: Option<LogViewerData>,
+
+    // === Chat View ===
+    /// ACP Chat 面板
+    pub chat_view: Option<ChatViewData>,
+
+    // === Notification Center ===
+    /// 通知中心
+    pub notification_center: Option<NotificationCenterData>,
+
+    // === Inbox ===
+    /// 等待用户处理的事项
+    pub inbox: Option<InboxPanelData>,
+
+    // === Global Search ===
+    /// 跨项目全局搜索
+    pub global_search: Option<GlobalSearchData>,
+
+    // === AutoLink Panel ===
+    /// 任务 worktree 的 AutoLink 管理
+    pub autolink_panel: Option<AutoLinkPanelData>,
+
+    // === Search Panel ===
+    /// 任务 worktree 内容搜索
+    pub search_panel: Option<SearchPanelData>,
+
+    // === Notes History ===
+    /// 任务 notes 的历史版本
+    pub notes_history: Option<NotesHistoryData>,
+
+    // === Project Notes ===
+    /// 项目级共享笔记
+    pub project_notes: Option<ProjectNotesData>,
+
+    // === Open With ===
+    /// 自定义应用选择器
+    pub open_with: Option<OpenWithData>,
+
+    // === Stashes ===
+    /// Stash 管理面板
+    pub stash_panel: Option<StashPanelData>,
+
+    // === Task Columns ===
+    /// 任务列表列选择器
+    pub column_picker: Option<ColumnPickerData>,
 }
 
 impl Default for DialogState {
@@ -74,6 +140,8 @@ impl DialogState {
         Self {
             show_new_task_dialog: false,
             new_task_input: String::new(),
+            new_task_templates: Vec::new(),
+            new_task_template: None,
             show_help: false,
             confirm_dialog: None,
             input_confirm_dialog: None,
@@ -85,6 +153,18 @@ impl DialogState {
             action_palette: None,
             commit_dialog: None,
             config_panel: None,
+            log_viewer: None,
+            chat_view: None,
+            notification_center: None,
+            inbox: None,
+            global_search: None,
+            autolink_panel: None,
+            search_panel: None,
+            notes_history: None,
+            project_notes: None,
+            open_with: None,
+            stash_panel: None,
+            column_picker: None,
         }
     }
 
@@ -93,6 +173,7 @@ impl DialogState {
     pub fn close_all(&mut self) {
         self.show_new_task_dialog = false;
         self.new_task_input.clear();
+        self.new_task_template = None;
         self.show_help = false;
         self.confirm_dialog = None;
         self.input_confirm_dialog = None;
@@ -104,6 +185,18 @@ impl DialogState {
         self.action_palette = None;
         self.commit_dialog = None;
         self.config_panel = None;
+        self.log_viewer = None;
+        self.chat_view = None;
+        self.notification_center = None;
+        self.inbox = None;
+        self.global_search = None;
+        self.autolink_panel = None;
+        self.search_panel = None;
+        self.notes_history = None;
+        self.project_notes = None;
+        self.open_with = None;
+        self.stash_panel = None;
+        self.column_picker = None;
     }
 
     /// 检查是否有活跃的对话框
@@ -121,6 +214,18 @@ impl DialogState {
             || self.action_palette.is_some()
             || self.commit_dialog.is_some()
             || self.config_panel.is_some()
+            || self.log_viewer.is_some()
+            || self.chat_view.is_some()
+            || self.notification_center.is_some()
+            || self.inbox.is_some()
+            || self.global_search.is_some()
+            || self.autolink_panel.is_some()
+            || self.search_panel.is_some()
+            || self.notes_history.is_some()
+            || self.project_notes.is_some()
+            || self.open_with.is_some()
+            || self.stash_panel.is_some()
+            || self.column_picker.is_some()
     }
 
     /// 检查是否有需要用户输入的对话框
@@ -132,6 +237,9 @@ impl DialogState {
             || self.new_project_dialog.is_some()
             || self.action_palette.is_some()
             || self.commit_dialog.is_some()
+            || self.chat_view.is_some()
+            || self.search_panel.is_some()
+            || self.global_search.is_some()
     }
 }
 
@@ -190,6 +298,18 @@ mod tests {
         assert!(state.action_palette.is_none());
         assert!(state.commit_dialog.is_none());
         assert!(state.config_panel.is_none());
+        assert!(state.log_viewer.is_none());
+        assert!(state.chat_view.is_none());
+        assert!(state.notification_center.is_none());
+        assert!(state.inbox.is_none());
+        assert!(state.global_search.is_none());
+        assert!(state.autolink_panel.is_none());
+        assert!(state.search_panel.is_none());
+        assert!(state.notes_history.is_none());
+        assert!(state.project_notes.is_none());
+        assert!(state.open_with.is_none());
+        assert!(state.stash_panel.is_none());
+        assert!(state.column_picker.is_none());
     }
 
     #[test]
@@ -0,0 +1,299 @@
+//! CODEOWNERS support for review
+//!
+//! Parses the repository's CODEOWNERS file (GitHub / GitLab syntax) and maps
+//! changed files to their owners, so review can show who owns each file and
+//! warn when a task reaches into code owned by other teams.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use globset::{GlobBuilder, GlobMatcher};
+use serde::Serialize;
+
+use crate::storage::task_scope;
+
+/// Where CODEOWNERS may live, in the order GitHub looks for it
+pub const CODEOWNERS_LOCATIONS: &[&str] = &[
+    ".github/CODEOWNERS",
+    "CODEOWNERS",
+    "docs/CODEOWNERS",
+    ".gitlab/CODEOWNERS",
+];
+
+struct Rule {
+    matchers: Vec<GlobMatcher>,
+    owners: Vec<String>,
+}
+
+/// Parsed CODEOWNERS rules
+pub struct CodeOwners {
+    /// Repo-relative path of the file the rules came from
+    pub file: String,
+    rules: Vec<Rule>,
+}
+
+impl CodeOwners {
+    /// Load the first CODEOWNERS file found in the worktree
+    pub fn load(worktree_path: &str) -> Option<Self> {
+        CODEOWNERS_LOCATIONS.iter().find_map(|location| {
+            let content = std::fs::read_to_string(Path::new(worktree_path).join(location)).ok()?;
+            Some(Self::parse(location, &content))
+        })
+    }
+
+    /// Parse CODEOWNERS content. Invalid patterns are skipped.
+    pub fn parse(file: &str, content: &str) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let line = strip_comment(line).trim();
+                // GitLab section headers (`[Docs]`, `^[Optional] @team`)
+                if line.is_empty() || line.starts_with('[') || line.starts_with("^[") {
+                    return None;
+                }
+                let mut parts = line.split_whitespace();
+                let matchers = pattern_matchers(parts.next()?)?;
+                Some(Rule {
+                    matchers,
+                    owners: parts.map(str::to_string).collect(),
+                })
+            })
+            .collect();
+        Self {
+            file: file.to_string(),
+            rules,
+        }
+    }
+
+    /// Owners of a repo-relative path; the last matching rule wins.
+    /// A matching rule without owners leaves the path unowned.
+    pub fn owners_of(&self, path: &str) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matchers.iter().any(|m| m.is_match(path)))
+            .map(|rule| rule.owners.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Owners of a directory, i.e. of files directly inside it
+    pub fn owners_of_dir(&self, dir: &str) -> &[String] {
+        self.owners_of(&format!("{}/*", dir.trim_end_matches('/')))
+    }
+}
+
+/// Drop a trailing `# comment` (`\#` stays literal)
+fn strip_comment(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    for (i, &b) in bytes.iter().enumerate() {
+        if b == b'#' && (i == 0 || bytes[i - 1] != b'\\') {
+            return &line[..i];
+        }
+    }
+    line
+}
+
+/// Translate a gitignore-style CODEOWNERS pattern into glob matchers.
+///
+/// A leading or inner `/` anchors the pattern at the repo root, otherwise it
+/// matches at any depth; a pattern also matches everything below a directory
+/// of that name, and a trailing `/` matches directories only.
+fn pattern_matchers(pattern: &str) -> Option<Vec<GlobMatcher>> {
+    let pattern = pattern.replace("\\#", "#");
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.contains('/');
+    let trimmed = trimmed.trim_start_matches('/');
+    if trimmed.is_empty() {
+        return None;
+    }
+    let base = if anchored || trimmed.starts_with("**") {
+        trimmed.to_string()
+    } else {
+        format!("**/{}", trimmed)
+    };
+
+    let mut globs = vec![format!("{}/**", base)];
+    if !dir_only {
+        globs.push(base);
+    }
+    globs
+        .iter()
+        .map(|glob| {
+            GlobBuilder::new(glob)
+                .literal_separator(true)
+                .build()
+                .ok()
+                .map(|g| g.compile_matcher())
+        })
+        .collect()
+}
+
+/// Why a changed file is flagged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OwnershipWarningKind {
+    /// Outside the task's path scope
+    OutsideScope,
+    /// Owned by owners other than the scope's owners
+    OtherTeam,
+}
+
+/// A changed file that needs attention before opening a PR
+#[derive(Debug, Clone, Serialize)]
+pub struct OwnershipWarning {
+    pub path: String,
+    pub kind: OwnershipWarningKind,
+    pub owners: Vec<String>,
+}
+
+/// Changed files owned by one owner
+#[derive(Debug, Clone, Serialize)]
+pub struct OwnerGroup {
+    pub owner: String,
+    pub files: Vec<String>,
+}
+
+/// Ownership overview of a task's changes
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OwnersSummary {
+    /// CODEOWNERS file in use (None when the repo has none)
+    pub codeowners_file: Option<String>,
+    pub scope: Option<String>,
+    /// Owners of the scope directory, treated as the task's team
+    pub scope_owners: Vec<String>,
+    /// Owners to request review from, with the files each one owns
+    pub owners: Vec<OwnerGroup>,
+    /// Changed files no rule assigns an owner
+    pub unowned: Vec<String>,
+    pub warnings: Vec<OwnershipWarning>,
+}
+
+/// Summarize who owns `paths` and flag files outside `scope` or owned by
+/// teams other than the scope's owners.
+pub fn summarize(
+    codeowners: Option<&CodeOwners>,
+    paths: &[String],
+    scope: Option<&str>,
+) -> OwnersSummary {
+    let scope_owners = match (codeowners, scope) {
+        (Some(co), Some(scope)) => co.owners_of_dir(scope).to_vec(),
+        _ => Vec::new(),
+    };
+
+    let mut groups: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    let mut unowned = Vec::new();
+    let mut warnings = Vec::new();
+    for path in paths {
+        let owners = codeowners.map(|co| co.owners_of(path)).unwrap_or(&[]);
+        if owners.is_empty() {
+            unowned.push(path.clone());
+        }
+        for owner in owners {
+            groups.entry(owner).or_default().push(path.clone());
+        }
+
+        if scope.is_some_and(|scope| !task_scope::in_scope(path, scope)) {
+            warnings.push(OwnershipWarning {
+                path: path.clone(),
+                kind: OwnershipWarningKind::OutsideScope,
+                owners: owners.to_vec(),
+            });
+        } else if !scope_owners.is_empty()
+            && !owners.is_empty()
+            && !owners.iter().any(|o| scope_owners.contains(o))
+        {
+            warnings.push(OwnershipWarning {
+                path: path.clone(),
+                kind: OwnershipWarningKind::OtherTeam,
+                owners: owners.to_vec(),
+            });
+        }
+    }
+
+    OwnersSummary {
+        codeowners_file: codeowners.map(|co| co.file.clone()),
+        scope: scope.map(str::to_string),
+        scope_owners,
+        owners: groups
+            .into_iter()
+            .map(|(owner, files)| OwnerGroup {
+                owner: owner.to_string(),
+                files,
+            })
+            .collect(),
+        unowned,
+        warnings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+# Default owners
+*                 @org/core
+*.md              @org/docs   # docs everywhere
+/services/api/    @org/api @alice
+services/web/     @org/web
+docs/generated/
+[Infra]
+/infra/**/*.tf    @org/infra
+";
+
+    fn paths(list: &[&str]) -> Vec<String> {
+        list.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_owners_of_last_match_wins() {
+        let co = CodeOwners::parse("CODEOWNERS", SAMPLE);
+        assert_eq!(co.owners_of("src/main.rs"), ["@org/core"]);
+        assert_eq!(
+            co.owners_of("services/api/README.md"),
+            ["@org/api", "@alice"]
+        );
+        assert_eq!(co.owners_of("README.md"), ["@org/docs"]);
+        assert_eq!(co.owners_of("services/web/app.ts"), ["@org/web"]);
+        assert_eq!(co.owners_of("infra/prod/main.tf"), ["@org/infra"]);
+        // Rule without owners clears ownership
+        assert!(co.owners_of("docs/generated/api.html").is_empty());
+        // Anchored pattern does not match nested directories of the same name
+        assert_eq!(co.owners_of("vendor/services/api/x.go"), ["@org/core"]);
+        assert_eq!(co.owners_of_dir("services/api"), ["@org/api", "@alice"]);
+    }
+
+    #[test]
+    fn test_summarize_warnings() {
+        let co = CodeOwners::parse("CODEOWNERS", SAMPLE);
+        let changed = paths(&[
+            "services/api/handler.go",
+            "services/web/app.ts",
+            "docs/generated/api.html",
+        ]);
+        let summary = summarize(Some(&co), &changed, Some("services/api"));
+        assert_eq!(summary.scope_owners, ["@org/api", "@alice"]);
+        assert_eq!(summary.unowned, ["docs/generated/api.html"]);
+        assert_eq!(summary.owners.len(), 3);
+        assert_eq!(summary.warnings.len(), 2);
+        assert!(summary
+            .warnings
+            .iter()
+            .all(|w| w.kind == OwnershipWarningKind::OutsideScope));
+
+        // Without a scope nothing is outside it, and there is no team to compare against
+        let summary = summarize(Some(&co), &changed, None);
+        assert!(summary.warnings.is_empty());
+
+        // Scope at the repo level: other teams' files inside it are flagged
+        let summary = summarize(
+            Some(&co),
+            &paths(&["services/web/app.ts"]),
+            Some("services"),
+        );
+        assert_eq!(summary.scope_owners, ["@org/core"]);
+        assert_eq!(summary.warnings[0].kind, OwnershipWarningKind::OtherTeam);
+        assert_eq!(summary.warnings[0].owners, ["@org/web"]);
+    }
+}
@@ -0,0 +1,223 @@
+//! Conflict marker parser
+//!
+//! Splits a file a merge or rebase left with conflict markers into its
+//! conflicted hunks — `<<<<<<<` ours, an optional `|||||||` base section
+//! (`merge.conflictStyle=diff3`), `=======`, `>>>>>>>` theirs — and rebuilds
+//! the file with one side of every hunk taken, keeping the cleanly merged
+//! text around them.
+
+use serde::{Deserialize, Serialize};
+
+/// One conflicted region of a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConflictHunk {
+    /// 1-based line of the `<<<<<<<` marker
+    pub start_line: u32,
+    /// 1-based line of the `>>>>>>>` marker
+    pub end_line: u32,
+    /// Text after `<<<<<<<` (e.g. `HEAD`)
+    pub ours_label: String,
+    /// Text after `>>>>>>>` (e.g. `abc1234 (Fix login)`)
+    pub theirs_label: String,
+    pub ours: String,
+    /// Common ancestor text (diff3 markers only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<String>,
+    pub theirs: String,
+}
+
+/// Side of a conflict to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictSide {
+    Ours,
+    Theirs,
+}
+
+enum Segment {
+    Text(String),
+    Conflict(ConflictHunk),
+}
+
+#[derive(Clone, Copy)]
+enum Section {
+    Ours,
+    Base,
+    Theirs,
+}
+
+/// A conflict whose closing marker hasn't been seen yet
+struct Open {
+    hunk: ConflictHunk,
+    section: Section,
+    /// The raw lines so far, restored as text if the file ends first
+    raw: String,
+}
+
+/// Label after a 7-character marker, `None` if the line isn't that marker
+fn marker_label<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    let rest = line.trim_end_matches(['\n', '\r']).strip_prefix(marker)?;
+    if rest.is_empty() {
+        Some("")
+    } else {
+        rest.strip_prefix(' ')
+    }
+}
+
+fn is_separator(line: &str) -> bool {
+    line.trim_end_matches(['\n', '\r']) == "======="
+}
+
+fn segments(content: &str) -> Vec<Segment> {
+    let mut out = Vec::new();
+    let mut text = String::new();
+    let mut open: Option<Open> = None;
+
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let line_no = index as u32 + 1;
+        let Some(current) = open.as_mut() else {
+            match marker_label(line, "<<<<<<<") {
+                Some(label) => {
+                    if !text.is_empty() {
+                        out.push(Segment::Text(std::mem::take(&mut text)));
+                    }
+                    open = Some(Open {
+                        hunk: ConflictHunk {
+                            start_line: line_no,
+                            end_line: line_no,
+                            ours_label: label.to_string(),
+                            theirs_label: String::new(),
+                            ours: String::new(),
+                            base: None,
+                            theirs: String::new(),
+                        },
+                        section: Section::Ours,
+                        raw: line.to_string(),
+                    });
+                }
+                None => text.push_str(line),
+            }
+            continue;
+        };
+
+        current.raw.push_str(line);
+        let hunk = &mut current.hunk;
+        match current.section {
+            Section::Ours if marker_label(line, "|||||||").is_some() => {
+                hunk.base = Some(String::new());
+                current.section = Section::Base;
+            }
+            Section::Ours | Section::Base if is_separator(line) => {
+                current.section = Section::Theirs;
+            }
+            Section::Ours => hunk.ours.push_str(line),
+            Section::Base => hunk.base.get_or_insert_with(String::new).push_str(line),
+            Section::Theirs => match marker_label(line, ">>>>>>>") {
+                Some(label) => {
+                    hunk.end_line = line_no;
+                    hunk.theirs_label = label.to_string();
+                    if let Some(done) = open.take() {
+                        out.push(Segment::Conflict(done.hunk));
+                    }
+                }
+                None => hunk.theirs.push_str(line),
+            },
+        }
+    }
+
+    // An unterminated conflict is plain text
+    if let Some(unclosed) = open {
+        text.push_str(&unclosed.raw);
+    }
+    if !text.is_empty() {
+        out.push(Segment::Text(text));
+    }
+    out
+}
+
+/// Conflicted hunks of a file, in order
+pub fn parse_conflicts(content: &str) -> Vec<ConflictHunk> {
+    segments(content)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            Segment::Conflict(hunk) => Some(hunk),
+            Segment::Text(_) => None,
+        })
+        .collect()
+}
+
+/// The file with `side` taken in every conflicted hunk
+pub fn take_side(content: &str, side: ConflictSide) -> String {
+    segments(content)
+        .into_iter()
+        .map(|segment| match segment {
+            Segment::Text(text) => text,
+            Segment::Conflict(hunk) => match side {
+                ConflictSide::Ours => hunk.ours,
+                ConflictSide::Theirs => hunk.theirs,
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFLICTED: &str = "\
+fn main() {
+<<<<<<< HEAD
+    println!(\"hello\");
+||||||| base
+    println!(\"hi\");
+=======
+    println!(\"hey\");
+>>>>>>> abc1234 (Greet)
+    run();
+<<<<<<< HEAD
+=======
+    cleanup();
+>>>>>>> abc1234 (Greet)
+}
+";
+
+    #[test]
+    fn test_parse_conflicts() {
+        let hunks = parse_conflicts(CONFLICTED);
+        assert_eq!(hunks.len(), 2);
+
+        let first = &hunks[0];
+        assert_eq!((first.start_line, first.end_line), (2, 8));
+        assert_eq!(first.ours_label, "HEAD");
+        assert_eq!(first.theirs_label, "abc1234 (Greet)");
+        assert_eq!(first.ours, "    println!(\"hello\");\n");
+        assert_eq!(first.base.as_deref(), Some("    println!(\"hi\");\n"));
+        assert_eq!(first.theirs, "    println!(\"hey\");\n");
+
+        // Plain merge-style markers, empty ours side
+        let second = &hunks[1];
+        assert_eq!((second.start_line, second.end_line), (10, 13));
+        assert_eq!(second.ours, "");
+        assert_eq!(second.base, None);
+        assert_eq!(second.theirs, "    cleanup();\n");
+
+        // No markers, or an unterminated conflict
+        assert!(parse_conflicts("a\nb\n").is_empty());
+        assert!(parse_conflicts("<<<<<<< HEAD\na\n=======\n").is_empty());
+    }
+
+    #[test]
+    fn test_take_side() {
+        assert_eq!(
+            take_side(CONFLICTED, ConflictSide::Ours),
+            "fn main() {\n    println!(\"hello\");\n    run();\n}\n"
+        );
+        assert_eq!(
+            take_side(CONFLICTED, ConflictSide::Theirs),
+            "fn main() {\n    println!(\"hey\");\n    run();\n    cleanup();\n}\n"
+        );
+        // Text without a closing marker is kept as-is
+        let unterminated = "x\n<<<<<<< HEAD\na\n";
+        assert_eq!(take_side(unterminated, ConflictSide::Theirs), unterminated);
+    }
+}
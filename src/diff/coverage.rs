@@ -0,0 +1,401 @@
+//! Coverage report ingestion for review
+//!
+//! Parses lcov and Cobertura reports produced by a task's test run into
+//! per-line coverage, marks inserted lines in the diff view as covered or
+//! uncovered, and summarizes overall / changed-line (patch) coverage with the
+//! delta against the previously uploaded report.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+use super::{parse_diff, DiffFile};
+use crate::error::{GroveError, Result};
+use crate::git;
+use crate::storage::coverage::{CoverageFormat, CoverageReport, CoverageTotals, FileCoverage};
+
+static COBERTURA_SOURCE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"<source>\s*([^<]*?)\s*</source>").unwrap());
+static COBERTURA_TAG: Lazy<Regex> = Lazy::new(|| Regex::new(r"<(class|line)\s([^>]*)>").unwrap());
+static XML_ATTR: Lazy<Regex> = Lazy::new(|| Regex::new(r#"([\w-]+)="([^"]*)""#).unwrap());
+
+/// Guess the report format from its content
+pub fn detect_format(content: &str) -> Option<CoverageFormat> {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with("<?xml") || trimmed.starts_with("<coverage") {
+        Some(CoverageFormat::Cobertura)
+    } else if content.lines().any(|l| l.trim_start().starts_with("SF:")) {
+        Some(CoverageFormat::Lcov)
+    } else {
+        None
+    }
+}
+
+/// Parse a report into per-file line coverage keyed by worktree-relative
+/// path. Files outside the worktree are dropped; a line hit by any record
+/// counts as covered.
+pub fn parse(
+    content: &str,
+    format: CoverageFormat,
+    worktree_path: &str,
+) -> Result<BTreeMap<String, FileCoverage>> {
+    let hits = match format {
+        CoverageFormat::Lcov => parse_lcov(content),
+        CoverageFormat::Cobertura => parse_cobertura(content),
+    };
+
+    let mut files: BTreeMap<String, FileCoverage> = BTreeMap::new();
+    for ((raw_path, bases), lines) in hits {
+        let Some(path) = relativize(&raw_path, &bases, worktree_path) else {
+            continue;
+        };
+        let file = files.entry(path).or_default();
+        let mut covered: BTreeSet<u32> = file.covered.iter().copied().collect();
+        let mut uncovered: BTreeSet<u32> = file.uncovered.iter().copied().collect();
+        for (line, count) in lines {
+            if count > 0 {
+                uncovered.remove(&line);
+                covered.insert(line);
+            } else if !covered.contains(&line) {
+                uncovered.insert(line);
+            }
+        }
+        file.covered = covered.into_iter().collect();
+        file.uncovered = uncovered.into_iter().collect();
+    }
+
+    if files.is_empty() {
+        return Err(GroveError::invalid_data(
+            "No coverage data for files in this worktree",
+        ));
+    }
+    Ok(files)
+}
+
+/// Raw line hits keyed by (reported path, source roots it may be relative to)
+type RawHits = HashMap<(String, Vec<String>), Vec<(u32, u64)>>;
+
+fn parse_lcov(content: &str) -> RawHits {
+    let mut hits: RawHits = HashMap::new();
+    let mut current: Option<String> = None;
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(path) = line.strip_prefix("SF:") {
+            current = Some(path.to_string());
+        } else if line == "end_of_record" {
+            current = None;
+        } else if let (Some(data), Some(path)) = (line.strip_prefix("DA:"), &current) {
+            let mut parts = data.split(',');
+            let number = parts.next().and_then(|n| n.parse::<u32>().ok());
+            // Some tools write fractional or negative counts; only "hit or not" matters
+            let count =
+                parts
+                    .next()
+                    .and_then(|c| c.parse::<f64>().ok())
+                    .map(|c| if c > 0.0 { 1 } else { 0 });
+            if let (Some(number), Some(count)) = (number, count) {
+                hits.entry((path.clone(), Vec::new()))
+                    .or_default()
+                    .push((number, count));
+            }
+        }
+    }
+    hits
+}
+
+fn parse_cobertura(content: &str) -> RawHits {
+    let bases: Vec<String> = COBERTURA_SOURCE
+        .captures_iter(content)
+        .map(|c| c[1].to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let mut hits: RawHits = HashMap::new();
+    let mut current: Option<String> = None;
+    for tag in COBERTURA_TAG.captures_iter(content) {
+        let attrs: HashMap<&str, &str> = XML_ATTR
+            .captures_iter(tag.get(2).map_or("", |m| m.as_str()))
+            .filter_map(|a| Some((a.get(1)?.as_str(), a.get(2)?.as_str())))
+            .collect();
+        if &tag[1] == "class" {
+            current = attrs.get("filename").map(|f| f.to_string());
+        } else if let Some(path) = &current {
+            let number = attrs.get("number").and_then(|n| n.parse::<u32>().ok());
+            let count = attrs.get("hits").and_then(|h| h.parse::<u64>().ok());
+            if let (Some(number), Some(count)) = (number, count) {
+                hits.entry((path.clone(), bases.clone()))
+                    .or_default()
+                    .push((number, count));
+            }
+        }
+    }
+    hits
+}
+
+/// Map a reported path to a worktree-relative one. Relative paths are tried
+/// against each source root, then as-is; the first that exists wins.
+fn relativize(raw: &str, bases: &[String], worktree_path: &str) -> Option<String> {
+    let raw = raw.replace('\\', "/");
+    let worktree = worktree_path.replace('\\', "/");
+    let worktree = worktree.trim_end_matches('/');
+
+    let mut candidates: Vec<String> = Vec::new();
+    if !raw.starts_with('/') {
+        candidates.extend(
+            bases
+                .iter()
+                .map(|base| format!("{}/{}", base.replace('\\', "/").trim_end_matches('/'), raw)),
+        );
+    }
+    candidates.push(raw.clone());
+
+    let relative: Vec<String> = candidates
+        .iter()
+        .filter_map(|candidate| {
+            if candidate.starts_with('/') {
+                candidate
+                    .strip_prefix(worktree)
+                    .and_then(|rest| rest.strip_prefix('/'))
+                    .map(str::to_string)
+            } else {
+                Some(candidate.trim_start_matches("./").to_string())
+            }
+        })
+        .filter(|path| !path.is_empty() && !path.split('/').any(|part| part == ".."))
+        .collect();
+
+    relative
+        .iter()
+        .find(|path| Path::new(worktree_path).join(path).exists())
+        .or_else(|| relative.first())
+        .cloned()
+}
+
+/// Lines the task added or changed against `target` (working tree included);
+/// untracked files count as entirely new
+pub fn changed_lines(worktree_path: &str, target: &str) -> HashMap<String, Vec<u32>> {
+    let mut changed: HashMap<String, Vec<u32>> = HashMap::new();
+    let raw = git::git_cmd(worktree_path, &["diff", "-U0", target]).unwrap_or_default();
+    for file in parse_diff(&raw).files {
+        let lines: Vec<u32> = file
+            .hunks
+            .iter()
+            .flat_map(|h| &h.lines)
+            .filter(|l| l.line_type == "insert")
+            .filter_map(|l| l.new_line)
+            .collect();
+        if !lines.is_empty() {
+            changed.insert(file.new_path, lines);
+        }
+    }
+
+    let untracked = git::git_cmd(
+        worktree_path,
+        &["ls-files", "--others", "--exclude-standard"],
+    )
+    .unwrap_or_default();
+    for path in untracked.lines().filter(|p| !p.is_empty()) {
+        if let Ok(content) = std::fs::read_to_string(Path::new(worktree_path).join(path)) {
+            let count = content.lines().count() as u32;
+            changed.insert(path.to_string(), (1..=count).collect());
+        }
+    }
+    changed
+}
+
+/// Coverage of one changed file's new lines
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangedFileCoverage {
+    pub path: String,
+    /// Changed lines that are executable
+    pub lines: u32,
+    pub covered: u32,
+    pub uncovered_lines: Vec<u32>,
+}
+
+/// Overall and changed-line coverage of a task
+#[derive(Debug, Clone, Serialize)]
+pub struct CoverageSummary {
+    pub format: CoverageFormat,
+    pub uploaded_at: String,
+    pub totals: CoverageTotals,
+    pub percent: Option<f64>,
+    /// Overall coverage of the previous report
+    pub previous_percent: Option<f64>,
+    /// `percent - previous_percent`, in percentage points
+    pub delta: Option<f64>,
+    /// Executable changed lines and how many are covered
+    pub patch: CoverageTotals,
+    pub patch_percent: Option<f64>,
+    /// Changed files with executable changed lines, least covered first
+    pub files: Vec<ChangedFileCoverage>,
+}
+
+/// Summarize a report against the task's changed lines
+pub fn summarize(report: &CoverageReport, changed: &HashMap<String, Vec<u32>>) -> CoverageSummary {
+    let totals = report.totals();
+    let percent = totals.percent();
+    let previous_percent = report.previous.and_then(|p| p.percent());
+
+    let mut patch = CoverageTotals::default();
+    let mut files = Vec::new();
+    for (path, lines) in changed {
+        let Some(coverage) = report.files.get(path) else {
+            continue;
+        };
+        let mut file = ChangedFileCoverage {
+            path: path.clone(),
+            lines: 0,
+            covered: 0,
+            uncovered_lines: Vec::new(),
+        };
+        for &line in lines {
+            match coverage.status(line) {
+                Some(true) => {
+                    file.lines += 1;
+                    file.covered += 1;
+                }
+                Some(false) => {
+                    file.lines += 1;
+                    file.uncovered_lines.push(line);
+                }
+                None => {}
+            }
+        }
+        if file.lines > 0 {
+            patch.lines += file.lines;
+            patch.covered += file.covered;
+            files.push(file);
+        }
+    }
+    files.sort_by(|a, b| {
+        let ratio = |f: &ChangedFileCoverage| f.covered as f64 / f.lines as f64;
+        ratio(a)
+            .total_cmp(&ratio(b))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    CoverageSummary {
+        format: report.format,
+        uploaded_at: report.uploaded_at.clone(),
+        totals,
+        percent,
+        previous_percent,
+        delta: percent
+            .zip(previous_percent)
+            .map(|(now, before)| now - before),
+        patch,
+        patch_percent: patch.percent(),
+        files,
+    }
+}
+
+/// Mark the inserted lines of a diff file as covered / uncovered
+pub fn annotate_file(report: &CoverageReport, file: &mut DiffFile) {
+    let Some(coverage) = report.files.get(&file.new_path) else {
+        return;
+    };
+    for line in file.hunks.iter_mut().flat_map(|h| h.lines.iter_mut()) {
+        if line.line_type == "insert" {
+            line.covered = line.new_line.and_then(|n| coverage.status(n));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lcov() {
+        let content = "\
+TN:
+SF:/work/repo/src/lib.rs
+DA:1,3
+DA:2,0
+DA:4,0
+end_of_record
+SF:/work/repo/src/lib.rs
+DA:2,1
+end_of_record
+SF:/usr/lib/rust/core.rs
+DA:1,1
+end_of_record
+SF:src/main.rs
+DA:10,0
+end_of_record
+";
+        assert_eq!(detect_format(content), Some(CoverageFormat::Lcov));
+        let files = parse(content, CoverageFormat::Lcov, "/work/repo").unwrap();
+        assert_eq!(files.len(), 2);
+        // A later record hitting line 2 makes it covered
+        assert_eq!(files["src/lib.rs"].covered, [1, 2]);
+        assert_eq!(files["src/lib.rs"].uncovered, [4]);
+        assert_eq!(files["src/main.rs"].uncovered, [10]);
+    }
+
+    #[test]
+    fn test_parse_cobertura() {
+        let content = r#"<?xml version="1.0" ?>
+<coverage line-rate="0.5">
+  <sources><source>/work/repo/app</source></sources>
+  <packages><package name="app"><classes>
+    <class name="util" filename="util.py" line-rate="0.5">
+      <lines>
+        <line number="1" hits="1"/>
+        <line number="2" hits="0" branch="false"/>
+      </lines>
+    </class>
+  </classes></package></packages>
+</coverage>"#;
+        assert_eq!(detect_format(content), Some(CoverageFormat::Cobertura));
+        let files = parse(content, CoverageFormat::Cobertura, "/work/repo").unwrap();
+        // Nothing exists on disk, so the path under the source root wins
+        let file = &files["app/util.py"];
+        assert_eq!(file.covered, [1]);
+        assert_eq!(file.uncovered, [2]);
+
+        assert!(parse("<coverage/>", CoverageFormat::Cobertura, "/work/repo").is_err());
+    }
+
+    #[test]
+    fn test_summarize_patch_and_delta() {
+        let mut files = BTreeMap::new();
+        files.insert(
+            "src/lib.rs".to_string(),
+            FileCoverage {
+                covered: vec![1, 2, 3],
+                uncovered: vec![4, 5],
+            },
+        );
+        let report = CoverageReport {
+            format: CoverageFormat::Lcov,
+            uploaded_at: String::new(),
+            files,
+            previous: Some(CoverageTotals {
+                lines: 10,
+                covered: 5,
+            }),
+        };
+        let mut changed = HashMap::new();
+        // Line 6 is not executable, README isn't in the report
+        changed.insert("src/lib.rs".to_string(), vec![3, 4, 6]);
+        changed.insert("README.md".to_string(), vec![1]);
+
+        let summary = summarize(&report, &changed);
+        assert_eq!(summary.percent, Some(60.0));
+        assert_eq!(summary.delta, Some(10.0));
+        assert_eq!(
+            summary.patch,
+            CoverageTotals {
+                lines: 2,
+                covered: 1
+            }
+        );
+        assert_eq!(summary.files.len(), 1);
+        assert_eq!(summary.files[0].uncovered_lines, [4]);
+    }
+}
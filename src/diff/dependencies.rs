@@ -0,0 +1,520 @@
+//! Dependency change summaries for lockfiles
+//!
+//! Lockfile diffs run to thousands of lines that nobody reads. When a changed
+//! file is a known lockfile, both sides are parsed into package → versions
+//! and the review shows which packages were added, removed or changed
+//! version instead. Each lockfile format is a [`LockfileParser`]; add a new
+//! format by implementing it and listing it in [`PARSERS`].
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+use super::DiffFile;
+use crate::git;
+
+/// Package name → resolved versions (a lockfile may pin several versions)
+pub type Packages = BTreeMap<String, BTreeSet<String>>;
+
+/// Parses one lockfile format
+pub trait LockfileParser: Sync {
+    /// Ecosystem shown in the summary, e.g. "cargo"
+    fn ecosystem(&self) -> &'static str;
+    /// Whether the file (by its name, without directories) is handled here
+    fn matches(&self, file_name: &str) -> bool;
+    /// Resolved packages; None when the content isn't a valid lockfile
+    fn parse(&self, content: &str) -> Option<Packages>;
+}
+
+fn insert(packages: &mut Packages, name: &str, version: &str) {
+    if !name.is_empty() && !version.is_empty() {
+        packages
+            .entry(name.to_string())
+            .or_default()
+            .insert(version.to_string());
+    }
+}
+
+/// `[[package]]` tables with `name` / `version` (Cargo.lock, poetry.lock, uv.lock)
+struct TomlPackages {
+    file_name: &'static str,
+    ecosystem: &'static str,
+}
+
+impl LockfileParser for TomlPackages {
+    fn ecosystem(&self) -> &'static str {
+        self.ecosystem
+    }
+
+    fn matches(&self, file_name: &str) -> bool {
+        file_name == self.file_name
+    }
+
+    fn parse(&self, content: &str) -> Option<Packages> {
+        let value: toml::Value = toml::from_str(content).ok()?;
+        let mut packages = Packages::new();
+        for package in value.get("package")?.as_array()? {
+            let field = |key: &str| package.get(key).and_then(|v| v.as_str()).unwrap_or("");
+            insert(&mut packages, field("name"), field("version"));
+        }
+        Some(packages)
+    }
+}
+
+/// package-lock.json / npm-shrinkwrap.json: v2+ `packages`, v1 nested `dependencies`
+struct NpmLock;
+
+impl NpmLock {
+    fn walk_v1(deps: &serde_json::Map<String, serde_json::Value>, packages: &mut Packages) {
+        for (name, dep) in deps {
+            if let Some(version) = dep.get("version").and_then(|v| v.as_str()) {
+                insert(packages, name, version);
+            }
+            if let Some(nested) = dep.get("dependencies").and_then(|d| d.as_object()) {
+                Self::walk_v1(nested, packages);
+            }
+        }
+    }
+}
+
+impl LockfileParser for NpmLock {
+    fn ecosystem(&self) -> &'static str {
+        "npm"
+    }
+
+    fn matches(&self, file_name: &str) -> bool {
+        matches!(file_name, "package-lock.json" | "npm-shrinkwrap.json")
+    }
+
+    fn parse(&self, content: &str) -> Option<Packages> {
+        let json: serde_json::Value = serde_json::from_str(content).ok()?;
+        let mut packages = Packages::new();
+        if let Some(entries) = json.get("packages").and_then(|p| p.as_object()) {
+            for (path, entry) in entries {
+                // "" is the root project; workspace links have no version
+                let Some(idx) = path.rfind("node_modules/") else {
+                    continue;
+                };
+                let name = &path[idx + "node_modules/".len()..];
+                let version = entry.get("version").and_then(|v| v.as_str()).unwrap_or("");
+                insert(&mut packages, name, version);
+            }
+        } else if let Some(deps) = json.get("dependencies").and_then(|d| d.as_object()) {
+            Self::walk_v1(deps, &mut packages);
+        } else {
+            return None;
+        }
+        Some(packages)
+    }
+}
+
+/// yarn.lock (classic and berry): `"name@range", name@range:` headers
+/// followed by an indented `version` line
+struct YarnLock;
+
+impl LockfileParser for YarnLock {
+    fn ecosystem(&self) -> &'static str {
+        "yarn"
+    }
+
+    fn matches(&self, file_name: &str) -> bool {
+        file_name == "yarn.lock"
+    }
+
+    fn parse(&self, content: &str) -> Option<Packages> {
+        let mut packages = Packages::new();
+        let mut current: Option<String> = None;
+        for line in content.lines() {
+            if line.starts_with('#') || line.trim().is_empty() {
+                continue;
+            }
+            if !line.starts_with(' ') {
+                // First descriptor of the header decides the name
+                let first = line
+                    .trim_end_matches(':')
+                    .split(',')
+                    .next()
+                    .unwrap_or("")
+                    .trim()
+                    .trim_matches('"');
+                // Scoped packages start with '@'; the range follows the next '@'
+                current = first
+                    .char_indices()
+                    .skip(1)
+                    .find(|(_, c)| *c == '@')
+                    .map(|(i, _)| first[..i].to_string());
+                continue;
+            }
+            let trimmed = line.trim();
+            let version = trimmed
+                .strip_prefix("version ")
+                .or_else(|| trimmed.strip_prefix("version: "));
+            if let (Some(name), Some(version)) = (current.as_deref(), version) {
+                insert(&mut packages, name, version.trim().trim_matches('"'));
+            }
+        }
+        Some(packages)
+    }
+}
+
+/// pnpm-lock.yaml: keys under `packages:` such as `/name@1.0.0:` (v6+),
+/// `name@1.0.0:` (v9) or `/name/1.0.0:` (v5)
+struct PnpmLock;
+
+impl LockfileParser for PnpmLock {
+    fn ecosystem(&self) -> &'static str {
+        "pnpm"
+    }
+
+    fn matches(&self, file_name: &str) -> bool {
+        file_name == "pnpm-lock.yaml"
+    }
+
+    fn parse(&self, content: &str) -> Option<Packages> {
+        let mut packages = Packages::new();
+        let mut in_packages = false;
+        for line in content.lines() {
+            if !line.starts_with(' ') && !line.trim().is_empty() {
+                in_packages = line.trim_end() == "packages:";
+                continue;
+            }
+            // Package keys sit at two spaces of indentation
+            if !in_packages || !line.starts_with("  ") || line.starts_with("   ") {
+                continue;
+            }
+            let key = line
+                .trim()
+                .trim_end_matches(':')
+                .trim_matches(|c| c == '\'' || c == '"');
+            let key = key.strip_prefix('/').unwrap_or(key);
+            // Drop peer-dependency suffixes: name@1.0.0(react@18.0.0)
+            let key = key.split('(').next().unwrap_or(key);
+            let split = key
+                .char_indices()
+                .skip(1)
+                .find(|(_, c)| *c == '@')
+                .map(|(i, _)| (&key[..i], &key[i + 1..]))
+                .or_else(|| key.rsplit_once('/'));
+            if let Some((name, version)) = split {
+                insert(&mut packages, name, version);
+            }
+        }
+        Some(packages)
+    }
+}
+
+/// Gemfile.lock: `    name (version)` lines under `specs:`
+struct GemfileLock;
+
+impl LockfileParser for GemfileLock {
+    fn ecosystem(&self) -> &'static str {
+        "bundler"
+    }
+
+    fn matches(&self, file_name: &str) -> bool {
+        file_name == "Gemfile.lock"
+    }
+
+    fn parse(&self, content: &str) -> Option<Packages> {
+        let mut packages = Packages::new();
+        for line in content.lines() {
+            // Exactly four spaces: gems; six are their dependency constraints
+            let Some(spec) = line.strip_prefix("    ") else {
+                continue;
+            };
+            if spec.starts_with(' ') {
+                continue;
+            }
+            if let Some((name, rest)) = spec.split_once(" (") {
+                insert(&mut packages, name, rest.trim_end_matches(')'));
+            }
+        }
+        Some(packages)
+    }
+}
+
+/// go.sum: `module version[/go.mod] hash`
+struct GoSum;
+
+impl LockfileParser for GoSum {
+    fn ecosystem(&self) -> &'static str {
+        "go"
+    }
+
+    fn matches(&self, file_name: &str) -> bool {
+        file_name == "go.sum"
+    }
+
+    fn parse(&self, content: &str) -> Option<Packages> {
+        let mut packages = Packages::new();
+        for line in content.lines() {
+            let mut parts = line.split_whitespace();
+            if let (Some(module), Some(version)) = (parts.next(), parts.next()) {
+                insert(&mut packages, module, version.trim_end_matches("/go.mod"));
+            }
+        }
+        Some(packages)
+    }
+}
+
+/// Registered lockfile parsers
+pub static PARSERS: &[&dyn LockfileParser] = &[
+    &TomlPackages {
+        file_name: "Cargo.lock",
+        ecosystem: "cargo",
+    },
+    &TomlPackages {
+        file_name: "poetry.lock",
+        ecosystem: "poetry",
+    },
+    &TomlPackages {
+        file_name: "uv.lock",
+        ecosystem: "uv",
+    },
+    &NpmLock,
+    &YarnLock,
+    &PnpmLock,
+    &GemfileLock,
+    &GoSum,
+];
+
+/// Parser for a path, by file name
+pub fn parser_for(path: &str) -> Option<&'static dyn LockfileParser> {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    PARSERS.iter().copied().find(|p| p.matches(file_name))
+}
+
+/// How a package changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Upgraded,
+    Downgraded,
+    /// Versions changed without a clear direction (e.g. a second version added)
+    Changed,
+}
+
+/// One package whose resolved versions differ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencyChange {
+    pub name: String,
+    pub kind: ChangeKind,
+    pub old_versions: Vec<String>,
+    pub new_versions: Vec<String>,
+}
+
+/// Package-level summary of a lockfile change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DependencySummary {
+    pub ecosystem: String,
+    pub added: usize,
+    pub removed: usize,
+    /// Upgraded, downgraded or otherwise changed
+    pub updated: usize,
+    /// Sorted by package name
+    pub changes: Vec<DependencyChange>,
+}
+
+/// Compare versions by their numeric components, e.g. 1.10.0 > 1.9.3
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parts = |v: &str| -> Vec<u64> {
+        v.trim_start_matches('v')
+            .split(['.', '-', '+'])
+            .map_while(|p| p.parse().ok())
+            .collect()
+    };
+    parts(a).cmp(&parts(b)).then_with(|| a.cmp(b))
+}
+
+/// Package-level differences between two parsed lockfiles
+pub fn diff_packages(old: &Packages, new: &Packages) -> Vec<DependencyChange> {
+    let names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let before = old.get(name);
+            let after = new.get(name);
+            if before == after {
+                return None;
+            }
+            let max = |set: Option<&BTreeSet<String>>| {
+                set.and_then(|s| s.iter().max_by(|a, b| compare_versions(a, b)).cloned())
+            };
+            let kind = match (max(before), max(after)) {
+                (None, _) => ChangeKind::Added,
+                (_, None) => ChangeKind::Removed,
+                (Some(a), Some(b)) => match compare_versions(&a, &b) {
+                    Ordering::Less => ChangeKind::Upgraded,
+                    Ordering::Greater => ChangeKind::Downgraded,
+                    Ordering::Equal => ChangeKind::Changed,
+                },
+            };
+            let list = |set: Option<&BTreeSet<String>>| {
+                let mut versions: Vec<String> = set.into_iter().flatten().cloned().collect();
+                versions.sort_by(|a, b| compare_versions(a, b));
+                versions
+            };
+            Some(DependencyChange {
+                name: name.clone(),
+                kind,
+                old_versions: list(before),
+                new_versions: list(after),
+            })
+        })
+        .collect()
+}
+
+/// Summarize a lockfile change; None when either side fails to parse.
+/// A missing side (added / deleted lockfile) counts as no packages.
+pub fn summarize(
+    parser: &dyn LockfileParser,
+    old: Option<&str>,
+    new: Option<&str>,
+) -> Option<DependencySummary> {
+    let parse = |content: Option<&str>| match content {
+        Some(content) => parser.parse(content),
+        None => Some(Packages::new()),
+    };
+    let changes = diff_packages(&parse(old)?, &parse(new)?);
+    let count = |kind: ChangeKind| changes.iter().filter(|c| c.kind == kind).count();
+    Some(DependencySummary {
+        ecosystem: parser.ecosystem().to_string(),
+        added: count(ChangeKind::Added),
+        removed: count(ChangeKind::Removed),
+        updated: changes.len() - count(ChangeKind::Added) - count(ChangeKind::Removed),
+        changes,
+    })
+}
+
+/// Attach a dependency summary to a lockfile's diff. `to_ref` None means
+/// the working tree.
+pub fn annotate_file(
+    worktree_path: &str,
+    from_ref: &str,
+    to_ref: Option<&str>,
+    file: &mut DiffFile,
+) {
+    let Some(parser) = parser_for(&file.new_path) else {
+        return;
+    };
+    let old = git::show_file(worktree_path, from_ref, &file.old_path).ok();
+    let new = match to_ref {
+        Some(to) => git::show_file(worktree_path, to, &file.new_path).ok(),
+        None => {
+            std::fs::read_to_string(std::path::Path::new(worktree_path).join(&file.new_path)).ok()
+        }
+    };
+    if old.is_none() && new.is_none() {
+        return;
+    }
+    file.dependencies = summarize(parser, old.as_deref(), new.as_deref());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(path: &str, content: &str) -> Packages {
+        parser_for(path).unwrap().parse(content).unwrap()
+    }
+
+    fn versions(packages: &Packages, name: &str) -> Vec<String> {
+        packages[name].iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_parsers() {
+        let cargo = parse(
+            "Cargo.lock",
+            "version = 3\n\n[[package]]\nname = \"serde\"\nversion = \"1.0.200\"\n\n[[package]]\nname = \"syn\"\nversion = \"1.0.109\"\n\n[[package]]\nname = \"syn\"\nversion = \"2.0.60\"\n",
+        );
+        assert_eq!(versions(&cargo, "syn"), ["1.0.109", "2.0.60"]);
+
+        let npm = parse(
+            "web/package-lock.json",
+            r#"{"lockfileVersion": 3, "packages": {
+                "": {"name": "app"},
+                "node_modules/@types/node": {"version": "20.1.0"},
+                "node_modules/a/node_modules/lodash": {"version": "4.17.21"}
+            }}"#,
+        );
+        assert_eq!(versions(&npm, "@types/node"), ["20.1.0"]);
+        assert_eq!(versions(&npm, "lodash"), ["4.17.21"]);
+        assert!(!npm.contains_key(""));
+
+        let npm_v1 = parse(
+            "package-lock.json",
+            r#"{"lockfileVersion": 1, "dependencies": {
+                "a": {"version": "1.0.0", "dependencies": {"b": {"version": "2.0.0"}}}
+            }}"#,
+        );
+        assert_eq!(versions(&npm_v1, "b"), ["2.0.0"]);
+
+        let yarn = parse(
+            "yarn.lock",
+            "# yarn lockfile v1\n\n\"@babel/core@^7.0.0\", \"@babel/core@^7.1.0\":\n  version \"7.24.0\"\n  resolved \"https://…\"\n\nlodash@^4.17.0:\n  version \"4.17.21\"\n",
+        );
+        assert_eq!(versions(&yarn, "@babel/core"), ["7.24.0"]);
+        assert_eq!(versions(&yarn, "lodash"), ["4.17.21"]);
+
+        let pnpm = parse(
+            "pnpm-lock.yaml",
+            "lockfileVersion: '6.0'\n\ndependencies:\n  react:\n    specifier: ^18\n\npackages:\n\n  /@babel/core@7.24.0:\n    resolution: {integrity: x}\n\n  /react-dom@18.2.0(react@18.2.0):\n    dev: false\n\n  /left-pad/1.3.0:\n    dev: false\n",
+        );
+        assert_eq!(versions(&pnpm, "@babel/core"), ["7.24.0"]);
+        assert_eq!(versions(&pnpm, "react-dom"), ["18.2.0"]);
+        assert_eq!(versions(&pnpm, "left-pad"), ["1.3.0"]);
+        assert!(!pnpm.contains_key("react"));
+
+        let gems = parse(
+            "Gemfile.lock",
+            "GEM\n  remote: https://rubygems.org/\n  specs:\n    rack (3.0.8)\n    rails (7.1.2)\n      rack (>= 2.2.4)\n\nDEPENDENCIES\n  rails\n",
+        );
+        assert_eq!(versions(&gems, "rack"), ["3.0.8"]);
+        assert_eq!(gems.len(), 2);
+
+        let go = parse(
+            "go.sum",
+            "golang.org/x/text v0.14.0 h1:abc=\ngolang.org/x/text v0.14.0/go.mod h1:def=\n",
+        );
+        assert_eq!(versions(&go, "golang.org/x/text"), ["v0.14.0"]);
+
+        assert!(parser_for("src/main.rs").is_none());
+        assert!(parser_for("Cargo.toml").is_none());
+    }
+
+    #[test]
+    fn test_summarize() {
+        let parser = parser_for("Cargo.lock").unwrap();
+        let old = "[[package]]\nname = \"a\"\nversion = \"1.9.0\"\n\n[[package]]\nname = \"b\"\nversion = \"0.2.0\"\n\n[[package]]\nname = \"c\"\nversion = \"3.0.0\"\n\n[[package]]\nname = \"same\"\nversion = \"1.0.0\"\n";
+        let new = "[[package]]\nname = \"a\"\nversion = \"1.10.0\"\n\n[[package]]\nname = \"c\"\nversion = \"2.5.0\"\n\n[[package]]\nname = \"d\"\nversion = \"0.1.0\"\n\n[[package]]\nname = \"same\"\nversion = \"1.0.0\"\n";
+        let summary = summarize(parser, Some(old), Some(new)).unwrap();
+        assert_eq!((summary.added, summary.removed, summary.updated), (1, 1, 2));
+        let kinds: Vec<(&str, ChangeKind)> = summary
+            .changes
+            .iter()
+            .map(|c| (c.name.as_str(), c.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("a", ChangeKind::Upgraded),
+                ("b", ChangeKind::Removed),
+                ("c", ChangeKind::Downgraded),
+                ("d", ChangeKind::Added),
+            ]
+        );
+        assert_eq!(summary.changes[0].old_versions, ["1.9.0"]);
+        assert_eq!(summary.changes[0].new_versions, ["1.10.0"]);
+
+        // Newly added lockfile: everything is added
+        let summary = summarize(parser, None, Some(new)).unwrap();
+        assert_eq!(summary.added, 4);
+
+        // Unparsable side gives no summary
+        assert!(summarize(parser, Some("not toml ["), Some(new)).is_none());
+    }
+}
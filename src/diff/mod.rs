@@ -3,9 +3,15 @@
 //! Parses unified diff output from `git diff` into structured data
 //! for the built-in diff review UI.
 
+pub mod codeowners;
+pub mod conflicts;
+pub mod coverage;
+pub mod dependencies;
+pub mod test_changes;
+
 use serde::Serialize;
 
-use crate::error::Result;
+use crate::error::{GroveError, Result};
 use crate::git;
 
 /// A single line in a diff hunk
@@ -19,6 +25,10 @@ pub struct DiffLine {
     pub new_line: Option<u32>,
     /// Line content (without the leading +/-/space)
     pub content: String,
+    /// Test coverage of an inserted line from the task's uploaded report
+    /// (None when not executable or no report)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub covered: Option<bool>,
 }
 
 /// A hunk (section) within a diff file
@@ -30,6 +40,23 @@ pub struct DiffHunk {
     pub new_lines: u32,
     pub header: String,
     pub lines: Vec<DiffLine>,
+    /// The chat turn that most likely produced this hunk (working tree diffs only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attribution: Option<HunkAttribution>,
+}
+
+/// Which chat turn produced a hunk
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HunkAttribution {
+    pub chat_id: String,
+    pub chat_title: String,
+    pub agent: String,
+    /// Turn start (unix seconds)
+    pub turn_ts: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
 }
 
 /// A single file's diff
@@ -45,6 +72,9 @@ pub struct DiffFile {
     pub deletions: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_untracked: Option<bool>,
+    /// Package-level summary when the file is a known lockfile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependencies: Option<dependencies::DependencySummary>,
 }
 
 /// Complete diff result across all files
@@ -55,6 +85,39 @@ pub struct DiffResult {
     pub total_deletions: u32,
 }
 
+/// gitattributes that mark a file as generated / vendored. `grove-collapse`
+/// lets a repo opt files in without claiming they are generated.
+const COLLAPSE_ATTRS: &[&str] = &["linguist-generated", "linguist-vendored", "grove-collapse"];
+
+/// Paths that should be collapsed by default in review: files marked via
+/// `.gitattributes` plus files matching the project's collapse globs.
+/// An explicit `linguist-generated=false` does not override a glob match.
+pub fn collapsed_paths(
+    worktree_path: &str,
+    paths: &[String],
+    patterns: &[String],
+) -> std::collections::HashSet<String> {
+    let mut collapsed: std::collections::HashSet<String> =
+        git::check_attr(worktree_path, COLLAPSE_ATTRS, paths)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, _, value)| value == "set" || value == "true")
+            .map(|(path, _, _)| path)
+            .collect();
+
+    let mut builder = globset::GlobSetBuilder::new();
+    for pattern in patterns {
+        // Invalid globs are rejected when saved; skip any that slipped through
+        if let Ok(glob) = globset::Glob::new(pattern) {
+            builder.add(glob);
+        }
+    }
+    if let Ok(set) = builder.build() {
+        collapsed.extend(paths.iter().filter(|p| set.is_match(p)).cloned());
+    }
+    collapsed
+}
+
 /// Parse raw unified diff output into structured DiffResult
 pub fn parse_diff(raw: &str) -> DiffResult {
     let mut files = Vec::new();
@@ -150,6 +213,7 @@ fn parse_file_diff(chunk: &str) -> Option<DiffFile> {
         additions,
         deletions,
         is_untracked: None,
+        dependencies: None,
     })
 }
 
@@ -268,6 +332,7 @@ fn parse_hunk(lines: &[&str]) -> Option<(DiffHunk, usize)> {
                 old_line: None,
                 new_line: Some(new_line),
                 content: content.to_string(),
+                covered: None,
             });
             new_line += 1;
         } else if let Some(content) = line.strip_prefix('-') {
@@ -276,6 +341,7 @@ fn parse_hunk(lines: &[&str]) -> Option<(DiffHunk, usize)> {
                 old_line: Some(old_line),
                 new_line: None,
                 content: content.to_string(),
+                covered: None,
             });
             old_line += 1;
         } else if line.starts_with('\\') {
@@ -288,6 +354,7 @@ fn parse_hunk(lines: &[&str]) -> Option<(DiffHunk, usize)> {
                 old_line: Some(old_line),
                 new_line: Some(new_line),
                 content: content.to_string(),
+                covered: None,
             });
             old_line += 1;
             new_line += 1;
@@ -302,6 +369,7 @@ fn parse_hunk(lines: &[&str]) -> Option<(DiffHunk, usize)> {
             new_lines,
             header: header.to_string(),
             lines: diff_lines,
+            attribution: None,
         },
         consumed,
     ))
@@ -338,6 +406,55 @@ fn parse_range(s: &str) -> (u32, u32) {
     }
 }
 
+/// Build a patch from a raw single-file diff keeping only the hunks whose
+/// `@@` header line is in `headers`.
+///
+/// The file header (`diff --git`, mode, `---`/`+++` lines) is kept as is and
+/// every selected hunk is copied verbatim, including `\ No newline` markers.
+/// Hunk positions of later hunks are left alone — `git apply` locates them by
+/// context. Errors if a requested header is not in the diff (it went stale).
+pub fn select_hunks(raw: &str, headers: &[String]) -> Result<String> {
+    let mut file_header = String::new();
+    let mut hunks: Vec<(String, String)> = Vec::new();
+    let mut files = 0;
+    for line in raw.split_inclusive('\n') {
+        if line.starts_with("diff --git ") {
+            files += 1;
+            if files > 1 {
+                return Err(GroveError::invalid_data("Expected a single-file diff"));
+            }
+        }
+        if line.starts_with("@@") {
+            hunks.push((line.trim_end().to_string(), line.to_string()));
+        } else if let Some((_, body)) = hunks.last_mut() {
+            body.push_str(line);
+        } else {
+            file_header.push_str(line);
+        }
+    }
+
+    if let Some(missing) = headers
+        .iter()
+        .find(|h| !hunks.iter().any(|(header, _)| header == h.trim_end()))
+    {
+        return Err(GroveError::invalid_data(format!(
+            "Hunk not found (diff changed?): {}",
+            missing
+        )));
+    }
+
+    let mut patch = file_header;
+    for (header, body) in &hunks {
+        if headers.iter().any(|h| h.trim_end() == header) {
+            patch.push_str(body);
+            if !body.ends_with('\n') {
+                patch.push('\n');
+            }
+        }
+    }
+    Ok(patch)
+}
+
 /// Get diff for a single file
 ///
 /// Handles both tracked and untracked files.
@@ -347,6 +464,7 @@ pub fn get_single_file_diff(
     file_path: &str,
     from_ref: Option<&str>,
     to_ref: Option<&str>,
+    options: &git::DiffOptions,
 ) -> Result<DiffFile> {
     let is_untracked = git::git_cmd(
         worktree_path,
@@ -367,14 +485,15 @@ pub fn get_single_file_diff(
             worktree_path,
             &["diff", "--no-index", "-U3", "--", null_device, file_path],
         )?
-    } else if let Some(to) = to_ref {
-        let range = format!("{}..{}", from_ref.unwrap_or("HEAD"), to);
-        git::git_cmd(worktree_path, &["diff", "-U3", &range, "--", file_path])?
     } else {
-        git::git_cmd(
-            worktree_path,
-            &["diff", "-U3", from_ref.unwrap_or("HEAD"), "--", file_path],
-        )?
+        let range = match to_ref {
+            Some(to) => format!("{}..{}", from_ref.unwrap_or("HEAD"), to),
+            None => from_ref.unwrap_or("HEAD").to_string(),
+        };
+        let mut args = vec!["diff", "-U3"];
+        args.extend(options.args());
+        args.extend([range.as_str(), "--", file_path]);
+        git::git_cmd(worktree_path, &args)?
     };
 
     let mut result = parse_diff(&raw);
@@ -396,6 +515,7 @@ pub fn get_single_file_diff(
             additions: 0,
             deletions: 0,
             is_untracked: Some(is_untracked),
+            dependencies: None,
         }),
     }
 }
@@ -404,6 +524,37 @@ pub fn get_single_file_diff(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_collapsed_paths_from_attributes_and_globs() {
+        let repo = tempfile::tempdir().unwrap();
+        let wt = repo.path().to_str().unwrap();
+        std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(wt)
+            .output()
+            .unwrap();
+        std::fs::write(
+            repo.path().join(".gitattributes"),
+            "gen/** linguist-generated\nvendor/** linguist-vendored\nsrc/keep.rs linguist-generated=false\n",
+        )
+        .unwrap();
+
+        let paths: Vec<String> = [
+            "gen/api.rs",
+            "vendor/lib.js",
+            "src/keep.rs",
+            "src/main.rs",
+            "Cargo.lock",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        let collapsed = collapsed_paths(wt, &paths, &["*.lock".to_string()]);
+        let mut collapsed: Vec<_> = collapsed.into_iter().collect();
+        collapsed.sort();
+        assert_eq!(collapsed, vec!["Cargo.lock", "gen/api.rs", "vendor/lib.js"]);
+    }
+
     #[test]
     fn test_parse_hunk_header() {
         let result = parse_hunk_header("@@ -10,5 +10,7 @@ fn main()");
@@ -0,0 +1,160 @@
+//! Changed-tests detection
+//!
+//! Classifies a task's changed files into source, test and docs files so
+//! review, merge confirmations and `grove_complete_task` can warn when source
+//! changes come without any test changes. Test files are recognised by the
+//! project's test globs, falling back to the `[risk] test_paths` defaults.
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::Serialize;
+
+use crate::git;
+use crate::storage::{config, diff_filters};
+
+/// Source files listed in the warning before eliding the rest
+const MAX_LISTED_FILES: usize = 3;
+
+/// Test coverage of a set of changed files
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TestChanges {
+    /// Changed files that are neither tests nor docs
+    pub source_files: Vec<String>,
+    /// Changed files matching the test globs
+    pub test_files: Vec<String>,
+    /// Source files changed but no test file did
+    pub tests_missing: bool,
+}
+
+impl TestChanges {
+    /// Warning shown to the user, e.g. "3 source files changed without test
+    /// changes (src/a.rs, src/b.rs, …)"; None when tests aren't missing
+    pub fn warning(&self) -> Option<String> {
+        if !self.tests_missing {
+            return None;
+        }
+        let count = self.source_files.len();
+        let mut listed = self
+            .source_files
+            .iter()
+            .take(MAX_LISTED_FILES)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(", ");
+        if count > MAX_LISTED_FILES {
+            listed.push_str(", …");
+        }
+        Some(format!(
+            "{} source file{} changed without test changes ({})",
+            count,
+            if count == 1 { "" } else { "s" },
+            listed
+        ))
+    }
+}
+
+/// Docs-only files don't call for test changes
+pub fn is_doc(path: &str) -> bool {
+    let lower = path.to_ascii_lowercase();
+    [".md", ".mdx", ".txt", ".rst"]
+        .iter()
+        .any(|ext| lower.ends_with(ext))
+}
+
+/// Build a glob set, skipping invalid patterns
+fn glob_set(patterns: &[String]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        if let Ok(glob) = Glob::new(pattern) {
+            builder.add(glob);
+        }
+    }
+    builder.build().unwrap_or_else(|_| GlobSet::empty())
+}
+
+/// Classify changed paths with the given test globs
+pub fn analyze(paths: &[String], test_patterns: &[String]) -> TestChanges {
+    let tests = glob_set(test_patterns);
+    let mut result = TestChanges::default();
+    for path in paths {
+        if tests.is_match(path) {
+            result.test_files.push(path.clone());
+        } else if !is_doc(path) {
+            result.source_files.push(path.clone());
+        }
+    }
+    result.tests_missing = !result.source_files.is_empty() && result.test_files.is_empty();
+    result
+}
+
+/// Test globs of a project: its own patterns, or the `[risk] test_paths`
+/// defaults when none are set
+pub fn project_test_patterns(project: &str) -> Vec<String> {
+    let patterns = diff_filters::load_settings(project).test_patterns;
+    if patterns.is_empty() {
+        config::load_config().risk.test_paths
+    } else {
+        patterns
+    }
+}
+
+/// Test coverage of a task's changes against its target; None when the
+/// diff can't be read
+pub fn task_test_changes(project: &str, worktree_path: &str, target: &str) -> Option<TestChanges> {
+    let entries = git::diff_stat(worktree_path, target).ok()?;
+    let paths: Vec<String> = entries.into_iter().map(|e| e.path).collect();
+    Some(analyze(&paths, &project_test_patterns(project)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_analyze() {
+        let patterns = config::RiskConfig::default().test_paths;
+
+        let result = analyze(&strings(&["src/lib.rs", "tests/lib_test.rs"]), &patterns);
+        assert!(!result.tests_missing);
+        assert_eq!(result.test_files, ["tests/lib_test.rs"]);
+        assert_eq!(result.warning(), None);
+
+        let result = analyze(
+            &strings(&[
+                "src/a.rs",
+                "src/b.rs",
+                "web/app.ts",
+                "web/util.ts",
+                "README.md",
+            ]),
+            &patterns,
+        );
+        assert!(result.tests_missing);
+        assert_eq!(result.source_files.len(), 4);
+        assert_eq!(
+            result.warning().unwrap(),
+            "4 source files changed without test changes (src/a.rs, src/b.rs, web/app.ts, …)"
+        );
+
+        // Docs-only changes don't need tests
+        assert!(!analyze(&strings(&["README.md", "docs/guide.mdx"]), &patterns).tests_missing);
+        assert!(!analyze(&[], &patterns).tests_missing);
+    }
+
+    #[test]
+    fn test_analyze_custom_patterns() {
+        let patterns = strings(&["spec/**"]);
+        let result = analyze(
+            &strings(&["lib/parser.rb", "spec/parser_spec.rb"]),
+            &patterns,
+        );
+        assert!(!result.tests_missing);
+
+        // `tests/` is not a test directory under these patterns
+        let result = analyze(&strings(&["lib/parser.rb", "tests/parser.rb"]), &patterns);
+        assert!(result.tests_missing);
+    }
+}
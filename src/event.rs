@@ -1,10 +1,14 @@
 use std::io;
 use std::time::Duration;
 
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEventKind};
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEventKind,
+};
 
 use crate::app::{App, AppMode, MonitorFocus, PreviewSubTab};
+use crate::dialogs::{InboxPanelData, LogViewerData, NotificationCenterData};
 use crate::model::ProjectTab;
+use crate::storage::project_notes;
 use crate::ui::click_areas::{contains, DialogAction};
 
 /// 每帧最多处理的事件数（防止事件风暴阻塞渲染）
@@ -189,6 +193,78 @@ fn handle_key(app: &mut App, key: KeyEvent) {
         return;
     }
 
+    // 日志查看器
+    if app.dialogs.log_viewer.is_some() {
+        handle_log_viewer_key(app, key);
+        return;
+    }
+
+    // 通知中心
+    if app.dialogs.notification_center.is_some() {
+        handle_notification_center_key(app, key);
+        return;
+    }
+
+    // Inbox
+    if app.dialogs.inbox.is_some() {
+        handle_inbox_key(app, key);
+        return;
+    }
+
+    // 全局搜索
+    if app.dialogs.global_search.is_some() {
+        handle_global_search_key(app, key);
+        return;
+    }
+
+    // AutoLink 面板
+    if app.dialogs.autolink_panel.is_some() {
+        handle_autolink_panel_key(app, key);
+        return;
+    }
+
+    // 搜索面板
+    if app.dialogs.search_panel.is_some() {
+        handle_search_panel_key(app, key);
+        return;
+    }
+
+    // Notes 历史面板
+    if app.dialogs.notes_history.is_some() {
+        handle_notes_history_key(app, key);
+        return;
+    }
+
+    // 项目笔记面板
+    if app.dialogs.project_notes.is_some() {
+        handle_project_notes_key(app, key);
+        return;
+    }
+
+    // Open With 选择器
+    if app.dialogs.open_with.is_some() {
+        handle_open_with_key(app, key);
+        return;
+    }
+
+    // Stash 面板
+    if app.dialogs.stash_panel.is_some() {
+        handle_stash_panel_key(app, key);
+        return;
+    }
+
+    // 任务列表列选择器
+    if app.dialogs.column_picker.is_some() {
+        handle_column_picker_key(app, key);
+        return;
+    }
+
+    // Chat 面板
+    if app.dialogs.chat_view.is_some() {
+        handle_chat_view_key(app, key);
+        return;
+    }
+
     // 根据模式分发事件
     match app.mode {
         AppMode::Workspace => handle_workspace_key(app, key),
@@ -205,10 +281,20 @@ fn handle_workspace_key(app: &mut App, key: KeyEvent) {
         return;
     }
 
+    // All Tasks tab：导航 / 打开任务，其余按键走通用处理
+    if app.workspace.all_tasks_mode && handle_all_tasks_key(app, key) {
+        return;
+    }
+
     match key.code {
         // 退出
         KeyCode::Char('q') => app.quit(),
 
+        // 切换 Projects / All Tasks tab
+        KeyCode::Tab | KeyCode::BackTab => {
+            app.workspace.toggle_all_tasks();
+        }
+
         // 导航 - 下移
         KeyCode::Char('j') | KeyCode::Down => {
             app.workspace.select_down();
@@ -258,6 +344,11 @@ fn handle_workspace_key(app: &mut App, key: KeyEvent) {
             app.open_delete_project_dialog();
         }
 
+        // 功能按键 - 置顶 / 取消置顶项目
+        KeyCode::Char('p') => {
+            app.toggle_pin_project();
+        }
+
         // 功能按键 - 搜索
         KeyCode::Char('/') => {
             app.workspace.enter_search_mode();
@@ -283,10 +374,50 @@ fn handle_workspace_key(app: &mut App, key: KeyEvent) {
             app.open_config_panel();
         }
 
+        // 功能按键 - 日志查看器
+        KeyCode::Char('L') => {
+            app.dialogs.log_viewer = Some(LogViewerData::load());
+        }
+
+        // 功能按键 - 通知中心
+        KeyCode::Char('m') => {
+            app.open_notification_center();
+        }
+
+        // 功能按键 - 勿扰模式
+        KeyCode::Char('M') => {
+            app.toggle_do_not_disturb();
+        }
+
+        // 功能按键 - Inbox
+        KeyCode::Char('I') => {
+            app.open_inbox();
+        }
+
         _ => {}
     }
 }
 
+/// 处理 All Tasks tab 的键盘事件，返回是否已处理
+fn handle_all_tasks_key(app: &mut App, key: KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => app.workspace.all_tasks_next(),
+        KeyCode::Char('k') | KeyCode::Up => app.workspace.all_tasks_previous(),
+        KeyCode::Enter => app.open_selected_all_task(),
+        KeyCode::Char('r') | KeyCode::Char('R') => app.workspace.reload_all_tasks(),
+        KeyCode::Char('/') => app.open_global_search(),
+        // 项目网格专属操作在此 tab 下不生效
+        KeyCode::Char('h')
+        | KeyCode::Char('l')
+        | KeyCode::Left
+        | KeyCode::Right
+        | KeyCode::Char('x')
+        | KeyCode::Char('p') => {}
+        _ => return false,
+    }
+    true
+}
+
 /// 处理 Workspace 搜索模式的键盘事件
 fn handle_workspace_search_key(app: &mut App, key: KeyEvent) {
     match key.code {
@@ -441,6 +572,22 @@ fn handle_project_key(app: &mut App, key: KeyEvent) {
             app.start_clean();
         }
 
+        // 功能按键 - 置顶 / 取消置顶任务 (非 Archived Tab)
+        KeyCode::Char('p') if app.project.current_tab != ProjectTab::Archived => {
+            app.toggle_pin_task();
+        }
+
+        // 功能按键 - 切换排序方式 / 保存的视图
+        KeyCode::Char('s') => {
+            app.cycle_task_sort();
+        }
+        KeyCode::Char('v') => {
+            app.cycle_task_view();
+        }
+        KeyCode::Char('V') => {
+            app.open_column_picker();
+        }
+
         // 功能按键 - Theme 选择器
         KeyCode::Char('T') | KeyCode::Char('t') => {
             app.open_theme_selector();
@@ -476,6 +623,31 @@ fn handle_project_key(app: &mut App, key: KeyEvent) {
             app.open_config_panel();
         }
 
+        // 功能按键 - 日志查看器
+        KeyCode::Char('L') => {
+            app.dialogs.log_viewer = Some(LogViewerData::load());
+        }
+
+        // 功能按键 - 通知中心
+        KeyCode::Char('m') => {
+            app.open_notification_center();
+        }
+
+        // 功能按键 - 勿扰模式
+        KeyCode::Char('M') => {
+            app.toggle_do_not_disturb();
+        }
+
+        // 功能按键 - Inbox
+        KeyCode::Char('I') => {
+            app.open_inbox();
+        }
+
+        // 功能按键 - ACP Chat 面板
+        KeyCode::Char('a') if app.project.current_tab != ProjectTab::Archived => {
+            app.open_chat_view();
+        }
+
         _ => {}
     }
 }
@@ -643,6 +815,11 @@ fn handle_new_task_dialog_key(app: &mut App, key: KeyEvent) {
             app.new_task_open_branch_selector();
         }
 
+        // Ctrl+T 切换任务模板
+        KeyCode::Char('t') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.new_task_cycle_template();
+        }
+
         // 删除字符
         KeyCode::Backspace => {
             app.new_task_delete_char();
@@ -705,6 +882,342 @@ fn handle_help_key(app: &mut App, key: KeyEvent) {
     }
 }
 
+/// 处理日志查看器的键盘事件
+fn handle_log_viewer_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.log_viewer else {
+        return;
+    };
+    match key.code {
+        KeyCode::Char('k') | KeyCode::Up => data.scroll_up(1),
+        KeyCode::Char('j') | KeyCode::Down => data.scroll_down(1),
+        KeyCode::PageUp => data.scroll_up(10),
+        KeyCode::PageDown => data.scroll_down(10),
+        KeyCode::Char('r') => data.refresh(),
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('L') => {
+            app.dialogs.log_viewer = None;
+        }
+        _ => {}
+    }
+}
+
+/// 处理通知中心的键盘事件
+fn handle_notification_center_key(app: &mut App, key: KeyEvent) {
+    let len = app.notification.history.len();
+    if app.dialogs.notification_center.is_none() {
+        return;
+    }
+    match key.code {
+        KeyCode::Char('y') => app.copy_selected_notification(),
+        KeyCode::Char('D') => {
+            app.notification.clear_history();
+            app.dialogs.notification_center = Some(NotificationCenterData::default());
+        }
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('m') => {
+            app.dialogs.notification_center = None;
+        }
+        code => {
+            let Some(ref mut data) = app.dialogs.notification_center else {
+                return;
+            };
+            match code {
+                KeyCode::Char('j') | KeyCode::Down => data.select_next(len),
+                KeyCode::Char('k') | KeyCode::Up => data.select_previous(),
+                KeyCode::Char('g') | KeyCode::Home => data.selected = 0,
+                KeyCode::Char('G') | KeyCode::End => data.selected = len.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// 处理 Inbox 的键盘事件
+fn handle_inbox_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Enter => app.jump_to_inbox_item(),
+        KeyCode::Char('r') => app.dialogs.inbox = Some(InboxPanelData::load()),
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('I') => {
+            app.dialogs.inbox = None;
+        }
+        code => {
+            let Some(ref mut data) = app.dialogs.inbox else {
+                return;
+            };
+            match code {
+                KeyCode::Char('j') | KeyCode::Down => data.select_next(),
+                KeyCode::Char('k') | KeyCode::Up => data.select_previous(),
+                KeyCode::Char('g') | KeyCode::Home => data.selected = 0,
+                KeyCode::Char('G') | KeyCode::End => {
+                    data.selected = data.items.len().saturating_sub(1)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// 处理全局搜索的键盘事件：输入变化后 Enter 搜索，否则 Enter 跳转
+fn handle_global_search_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.global_search else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc => app.dialogs.global_search = None,
+        KeyCode::Enter => {
+            if data.is_stale() {
+                data.run();
+            } else {
+                app.jump_to_search_hit();
+            }
+        }
+        KeyCode::Backspace => {
+            data.query.pop();
+        }
+        KeyCode::Down => data.select_next(),
+        KeyCode::Up => data.select_previous(),
+        KeyCode::PageDown => {
+            for _ in 0..10 {
+                data.select_next();
+            }
+        }
+        KeyCode::PageUp => data.selected = data.selected.saturating_sub(10),
+        KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => data.query.push(c),
+        _ => {}
+    }
+}
+
+/// 处理 AutoLink 面板的键盘事件
+fn handle_autolink_panel_key(app: &mut App, key: KeyEvent) {
+    match key.code {
+        KeyCode::Char('r') => app.autolink_resync(),
+        KeyCode::Char('f') => app.autolink_refresh(),
+        KeyCode::Char('d') | KeyCode::Delete => app.autolink_remove_selected(),
+        KeyCode::Char('c') => app.autolink_clean(),
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.dialogs.autolink_panel = None;
+        }
+        code => {
+            let Some(ref mut data) = app.dialogs.autolink_panel else {
+                return;
+            };
+            match code {
+                KeyCode::Char('j') | KeyCode::Down => data.select_next(),
+                KeyCode::Char('k') | KeyCode::Up => data.select_previous(),
+                KeyCode::Char('g') | KeyCode::Home => data.selected = 0,
+                KeyCode::Char('G') | KeyCode::End => {
+                    data.selected = data.links.len().saturating_sub(1)
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// 处理搜索面板的键盘事件
+fn handle_search_panel_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.search_panel else {
+        return;
+    };
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Esc => app.dialogs.search_panel = None,
+        KeyCode::Char('r') if ctrl => data.regex = !data.regex,
+        KeyCode::Char('t') if ctrl => data.case_sensitive = !data.case_sensitive,
+        KeyCode::Char(_) if ctrl => {}
+        KeyCode::Char(c) => data.query.push(c),
+        KeyCode::Backspace => {
+            data.query.pop();
+        }
+        KeyCode::Enter => data.run(),
+        KeyCode::Down => data.select_next(),
+        KeyCode::Up => data.select_previous(),
+        KeyCode::PageDown => (0..10).for_each(|_| data.select_next()),
+        KeyCode::PageUp => data.selected = data.selected.saturating_sub(10),
+        _ => {}
+    }
+}
+
+/// 处理 Notes 历史面板的键盘事件
+fn handle_notes_history_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.notes_history else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.dialogs.notes_history = None,
+        KeyCode::Char('j') | KeyCode::Down => data.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => data.select_previous(),
+        KeyCode::Char('J') | KeyCode::PageDown => data.scroll_diff_down(10),
+        KeyCode::Char('K') | KeyCode::PageUp => data.scroll_diff_up(10),
+        KeyCode::Tab => data.toggle_compare(),
+        KeyCode::Char('r') => match data.restore_selected() {
+            Ok(Some(_)) => {
+                app.project.refresh_panel_data();
+                app.show_toast("Notes restored");
+            }
+            Ok(None) => {}
+            Err(e) => app.show_toast(format!("Restore failed: {}", e)),
+        },
+        _ => {}
+    }
+}
+
+/// 处理项目笔记面板的键盘事件
+fn handle_project_notes_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.project_notes else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.dialogs.project_notes = None,
+        KeyCode::Char('j') | KeyCode::Down => data.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => data.select_previous(),
+        KeyCode::Char('J') | KeyCode::PageDown => data.scroll_down(10),
+        KeyCode::Char('K') | KeyCode::PageUp => data.scroll_up(10),
+        KeyCode::Char('e') | KeyCode::Enter => {
+            let key = data.project_key.clone();
+            if let Some(note) = data.selected_note() {
+                if let Ok(path) = project_notes::note_file_path(&key, &note.slug) {
+                    app.project.pending_project_note_edit =
+                        Some((note.slug.clone(), path.to_string_lossy().to_string()));
+                }
+            }
+        }
+        KeyCode::Char('n') => match data.create() {
+            Ok(pending) => app.project.pending_project_note_edit = Some(pending),
+            Err(e) => app.show_toast(format!("Create failed: {}", e)),
+        },
+        KeyCode::Char('c') => match data.toggle_include_in_chat() {
+            Ok(Some(true)) => app.show_toast("Attached to new chats"),
+            Ok(Some(false)) => app.show_toast("No longer attached to new chats"),
+            Ok(None) => {}
+            Err(e) => app.show_toast(format!("Update failed: {}", e)),
+        },
+        _ => {}
+    }
+}
+
+/// 处理 Open With 选择器的键盘事件
+fn handle_open_with_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.open_with else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.dialogs.open_with = None,
+        KeyCode::Char('j') | KeyCode::Down => data.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => data.select_previous(),
+        KeyCode::Enter => app.open_with_selected(),
+        _ => {}
+    }
+}
+
+/// 处理列选择器的键盘事件
+fn handle_column_picker_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.column_picker else {
+        return;
+    };
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => app.dialogs.column_picker = None,
+        KeyCode::Char('j') | KeyCode::Down => data.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => data.select_previous(),
+        KeyCode::Char('J') => data.move_down(),
+        KeyCode::Char('K') => data.move_up(),
+        KeyCode::Char(' ') => data.toggle(),
+        KeyCode::Enter => app.save_task_columns(),
+        _ => {}
+    }
+}
+
+/// 处理 Stash 面板的键盘事件
+fn handle_stash_panel_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.stash_panel else {
+        return;
+    };
+    // drop 确认中：再按 d 执行，其它键取消
+    if data.confirm_drop && key.code != KeyCode::Char('d') {
+        data.confirm_drop = false;
+        return;
+    }
+    let result = match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.dialogs.stash_panel = None;
+            return;
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            data.select_next();
+            return;
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            data.select_previous();
+            return;
+        }
+        KeyCode::Char('J') | KeyCode::PageDown => {
+            data.scroll_patch_down(10);
+            return;
+        }
+        KeyCode::Char('K') | KeyCode::PageUp => {
+            data.scroll_patch_up(10);
+            return;
+        }
+        KeyCode::Tab => {
+            data.toggle_target();
+            return;
+        }
+        KeyCode::Char('a') | KeyCode::Enter => data.apply_selected(false),
+        KeyCode::Char('p') => data.apply_selected(true),
+        KeyCode::Char('d') => data.drop_selected(),
+        _ => return,
+    };
+    match result {
+        Ok(Some(message)) => {
+            app.project.refresh_panel_data();
+            app.show_toast(message);
+        }
+        Ok(None) => {}
+        Err(e) => app.show_toast(format!("Stash failed: {}", e)),
+    }
+}
+
+/// 处理 Chat 面板的键盘事件
+fn handle_chat_view_key(app: &mut App, key: KeyEvent) {
+    let Some(ref mut data) = app.dialogs.chat_view else {
+        return;
+    };
+
+    // Chat 列表
+    let Some(ref mut open) = data.open else {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => data.select_next(),
+            KeyCode::Char('k') | KeyCode::Up => data.select_previous(),
+            KeyCode::Enter => data.open_selected(),
+            KeyCode::Char('n') => data.create_chat(),
+            KeyCode::Esc | KeyCode::Char('q') => app.dialogs.chat_view = None,
+            _ => {}
+        }
+        return;
+    };
+
+    // 对话
+    let has_permission = open.permission.is_some();
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('c') if ctrl => data.cancel_turn(),
+        KeyCode::Char('d') if ctrl => data.drop_last_queued(),
+        KeyCode::Char('x') if ctrl => data.clear_queue(),
+        KeyCode::Tab => open.cycle_mode(),
+        KeyCode::BackTab => open.cycle_model(),
+        KeyCode::Char(c @ '1'..='9') if has_permission && data.input.is_empty() => {
+            data.respond_permission(c as usize - '1' as usize);
+        }
+        KeyCode::Char(c) => data.input.push(c),
+        KeyCode::Backspace => {
+            data.input.pop();
+        }
+        KeyCode::Enter => data.submit_input(),
+        KeyCode::PageUp => data.scroll_up(10),
+        KeyCode::PageDown => data.scroll_down(10),
+        KeyCode::Esc => data.close_chat(),
+        _ => {}
+    }
+}
+
 /// 处理 Merge 选择弹窗的键盘事件
 fn handle_merge_dialog_key(app: &mut App, key: KeyEvent) {
     match key.code {
@@ -713,6 +1226,16 @@ fn handle_merge_dialog_key(app: &mut App, key: KeyEvent) {
             app.merge_dialog_toggle();
         }
 
+        // 切换 review 摘要
+        KeyCode::Char('r') => {
+            app.merge_dialog_toggle_review();
+        }
+
+        // 推送并创建 PR / MR
+        KeyCode::Char('p') => {
+            app.merge_dialog_create_pr();
+        }
+
         // 确认
         KeyCode::Enter => {
             app.merge_dialog_confirm();
@@ -906,6 +1429,7 @@ fn handle_config_panel_key(app: &mut App, key: KeyEvent) {
             KeyCode::Esc => app.config_panel_back(),
             KeyCode::Char('a') => app.config_autolink_add(),
             KeyCode::Char('e') => app.config_autolink_edit(),
+            KeyCode::Char('m') => app.config_autolink_cycle_mode(),
             KeyCode::Char('d') | KeyCode::Delete => app.config_autolink_delete(),
             _ => {}
         },
@@ -966,6 +1490,21 @@ fn handle_config_panel_key(app: &mut App, key: KeyEvent) {
 /// 处理 Monitor 模式的键盘事件
 fn handle_monitor_key(app: &mut App, key: KeyEvent) {
     match key.code {
+        // 多 task grid：打开时方向键在格子间移动，Enter 切到选中 task
+        KeyCode::Char('g') => app.monitor.toggle_grid(),
+        KeyCode::Esc if app.monitor.grid_view => app.monitor.toggle_grid(),
+        KeyCode::Char('j') | KeyCode::Char('l') | KeyCode::Down | KeyCode::Right
+            if app.monitor.grid_view =>
+        {
+            app.monitor.grid_next()
+        }
+        KeyCode::Char('k') | KeyCode::Char('h') | KeyCode::Up | KeyCode::Left
+            if app.monitor.grid_view =>
+        {
+            app.monitor.grid_prev()
+        }
+        KeyCode::Enter if app.monitor.grid_view => app.monitor_focus_grid_task(),
+
         // Tab: 展开/折叠 sidebar
         KeyCode::Tab => app.monitor.toggle_sidebar(),
 
@@ -1009,12 +1548,25 @@ fn handle_monitor_key(app: &mut App, key: KeyEvent) {
         // 刷新
         KeyCode::Char('r') | KeyCode::Char('R') => app.monitor.refresh_panel_data(),
 
+        // 跟随活动 / 锁定当前 task
+        KeyCode::Char('f') => app.monitor_toggle_follow(),
+        KeyCode::Char('p') => app.monitor_toggle_pin(),
+
         // 主题
         KeyCode::Char('T') | KeyCode::Char('t') => app.open_theme_selector(),
 
         // 帮助
         KeyCode::Char('?') => app.dialogs.show_help = !app.dialogs.show_help,
 
+        // 日志查看器
+        KeyCode::Char('L') => app.dialogs.log_viewer = Some(LogViewerData::load()),
+
+        // 通知中心
+        KeyCode::Char('m') => app.open_notification_center(),
+
+        // Inbox
+        KeyCode::Char('I') => app.open_inbox(),
+
         // 退出
         KeyCode::Char('q') => app.quit(),
 
@@ -1062,6 +1614,18 @@ fn has_active_popup(app: &App) -> bool {
         || app.dialogs.action_palette.is_some()
         || app.dialogs.commit_dialog.is_some()
         || app.dialogs.config_panel.is_some()
+        || app.dialogs.log_viewer.is_some()
+        || app.dialogs.chat_view.is_some()
+        || app.dialogs.notification_center.is_some()
+        || app.dialogs.inbox.is_some()
+        || app.dialogs.global_search.is_some()
+        || app.dialogs.autolink_panel.is_some()
+        || app.dialogs.search_panel.is_some()
+        || app.dialogs.notes_history.is_some()
+        || app.dialogs.project_notes.is_some()
+        || app.dialogs.open_with.is_some()
+        || app.dialogs.stash_panel.is_some()
+        || app.dialogs.column_picker.is_some()
 }
 
 fn handle_left_click(app: &mut App, col: u16, row: u16) {
@@ -1073,6 +1637,50 @@ fn handle_left_click(app: &mut App, col: u16, row: u16) {
         app.dialogs.show_help = false;
         return;
     }
+    if app.dialogs.log_viewer.is_some() {
+        app.dialogs.log_viewer = None;
+        return;
+    }
+    if app.dialogs.notification_center.is_some() {
+        app.dialogs.notification_center = None;
+        return;
+    }
+    if app.dialogs.inbox.is_some() {
+        app.dialogs.inbox = None;
+        return;
+    }
+    if app.dialogs.global_search.is_some() {
+        app.dialogs.global_search = None;
+        return;
+    }
+    if app.dialogs.autolink_panel.is_some() {
+        app.dialogs.autolink_panel = None;
+        return;
+    }
+    if app.dialogs.search_panel.is_some() {
+        app.dialogs.search_panel = None;
+        return;
+    }
+    if app.dialogs.notes_history.is_some() {
+        app.dialogs.notes_history = None;
+        return;
+    }
+    if app.dialogs.project_notes.is_some() {
+        app.dialogs.project_notes = None;
+        return;
+    }
+    if app.dialogs.open_with.is_some() {
+        app.dialogs.open_with = None;
+        return;
+    }
+    if app.dialogs.stash_panel.is_some() {
+        app.dialogs.stash_panel = None;
+        return;
+    }
+    if app.dialogs.column_picker.is_some() {
+        app.dialogs.column_picker = None;
+        return;
+    }
     if has_active_popup(app) {
         handle_popup_click(app, col, row);
         return;
@@ -1234,6 +1842,34 @@ fn handle_scroll_down(app: &mut App, col: u16, row: u16) {
             app.branch_selector_next();
         } else if app.dialogs.config_panel.is_some() {
             app.config_panel_next();
+        } else if let Some(ref mut data) = app.dialogs.log_viewer {
+            data.scroll_down(3);
+        } else if let Some(ref mut data) = app.dialogs.notification_center {
+            data.select_next(app.notification.history.len());
+        } else if let Some(ref mut data) = app.dialogs.inbox {
+            data.select_next();
+        } else if let Some(ref mut data) = app.dialogs.global_search {
+            data.select_next();
+        } else if let Some(ref mut data) = app.dialogs.autolink_panel {
+            data.select_next();
+        } else if let Some(ref mut data) = app.dialogs.search_panel {
+            data.select_next();
+        } else if let Some(ref mut data) = app.dialogs.notes_history {
+            data.scroll_diff_down(3);
+        } else if let Some(ref mut data) = app.dialogs.project_notes {
+            data.scroll_down(3);
+        } else if let Some(ref mut data) = app.dialogs.open_with {
+            data.select_next();
+        } else if let Some(ref mut data) = app.dialogs.column_picker {
+            data.select_next();
+        } else if let Some(ref mut data) = app.dialogs.stash_panel {
+            data.scroll_patch_down(3);
+        } else if let Some(ref mut data) = app.dialogs.chat_view {
+            if data.open.is_some() {
+                data.scroll_down(3);
+            } else {
+                data.select_next();
+            }
         }
         return;
     }
@@ -1281,6 +1917,34 @@ fn handle_scroll_up(app: &mut App, col: u16, row: u16) {
             app.branch_selector_prev();
         } else if app.dialogs.config_panel.is_some() {
             app.config_panel_prev();
+        } else if let Some(ref mut data) = app.dialogs.log_viewer {
+            data.scroll_up(3);
+        } else if let Some(ref mut data) = app.dialogs.notification_center {
+            data.select_previous();
+        } else if let Some(ref mut data) = app.dialogs.inbox {
+            data.select_previous();
+        } else if let Some(ref mut data) = app.dialogs.global_search {
+            data.select_previous();
+        } else if let Some(ref mut data) = app.dialogs.autolink_panel {
+            data.select_previous();
+        } else if let Some(ref mut data) = app.dialogs.search_panel {
+            data.select_previous();
+        } else if let Some(ref mut data) = app.dialogs.notes_history {
+            data.scroll_diff_up(3);
+        } else if let Some(ref mut data) = app.dialogs.project_notes {
+            data.scroll_up(3);
+        } else if let Some(ref mut data) = app.dialogs.open_with {
+            data.select_previous();
+        } else if let Some(ref mut data) = app.dialogs.column_picker {
+            data.select_previous();
+        } else if let Some(ref mut data) = app.dialogs.stash_panel {
+            data.scroll_patch_up(3);
+        } else if let Some(ref mut data) = app.dialogs.chat_view {
+            if data.open.is_some() {
+                data.scroll_up(3);
+            } else {
+                data.select_previous();
+            }
         }
         return;
     }
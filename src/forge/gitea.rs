@@ -0,0 +1,164 @@
+//! Gitea / Forgejo (incl. Codeberg) backend
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{
+    api_error, ci_state, summarize_reviews, CiState, Client, Forge, PullRequest, RemoteRepo,
+    ReviewState, ReviewStatus,
+};
+use crate::error::Result;
+use crate::storage::forge::ForgeKind;
+
+pub struct Gitea {
+    client: Client,
+    repo: RemoteRepo,
+}
+
+impl Gitea {
+    pub(super) fn new(client: Client, repo: RemoteRepo) -> Self {
+        Self { client, repo }
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.client
+            .request(
+                method,
+                &format!("/repos/{}/{}{}", self.repo.owner, self.repo.name, path),
+            )
+            .set("Authorization", &format!("token {}", self.client.token))
+    }
+
+    fn find_open_pull(&self, branch: &str) -> Result<Option<PullRequest>> {
+        let pulls: Vec<PullResponse> = self
+            .request("GET", "/pulls")
+            .query("state", "open")
+            .call()
+            .map_err(|e| api_error(ForgeKind::Gitea, e))?
+            .into_json()?;
+        Ok(pulls
+            .into_iter()
+            .find(|pr| pr.head.as_ref().is_some_and(|h| h.r#ref == branch))
+            .map(Into::into))
+    }
+}
+
+#[derive(Deserialize)]
+struct PullResponse {
+    number: u64,
+    html_url: String,
+    state: String,
+    #[serde(default)]
+    merged: bool,
+    created_at: DateTime<Utc>,
+    #[serde(default)]
+    head: Option<Branch>,
+}
+
+#[derive(Deserialize)]
+struct Branch {
+    r#ref: String,
+}
+
+impl From<PullResponse> for PullRequest {
+    fn from(pr: PullResponse) -> Self {
+        let state = if pr.merged {
+            "merged".to_string()
+        } else {
+            pr.state
+        };
+        PullRequest {
+            number: pr.number,
+            url: pr.html_url,
+            state,
+            created_at: pr.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Review {
+    user: Option<User>,
+    state: String,
+    #[serde(default)]
+    dismissed: bool,
+}
+
+#[derive(Deserialize)]
+struct User {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct CombinedStatus {
+    #[serde(default)]
+    state: String,
+    #[serde(default)]
+    total_count: u64,
+}
+
+impl Forge for Gitea {
+    fn kind(&self) -> ForgeKind {
+        ForgeKind::Gitea
+    }
+
+    fn create_pull(&self, head: &str, base: &str, title: &str, body: &str) -> Result<PullRequest> {
+        let response = self.request("POST", "/pulls").send_json(json!({
+            "title": title,
+            "head": head,
+            "base": base,
+            "body": body,
+        }));
+        match response {
+            Ok(resp) => Ok(resp.into_json::<PullResponse>()?.into()),
+            // 409 / 422: a pull request for this branch already exists
+            Err(ureq::Error::Status(status @ (409 | 422), resp)) => {
+                match self.find_open_pull(head)? {
+                    Some(pr) => Ok(pr),
+                    None => Err(api_error(self.kind(), ureq::Error::Status(status, resp))),
+                }
+            }
+            Err(e) => Err(api_error(self.kind(), e)),
+        }
+    }
+
+    fn fetch_pull(&self, number: u64) -> Result<PullRequest> {
+        Ok(self
+            .request("GET", &format!("/pulls/{}", number))
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json::<PullResponse>()?
+            .into())
+    }
+
+    fn review_status(&self, number: u64) -> Result<ReviewStatus> {
+        let reviews: Vec<Review> = self
+            .request("GET", &format!("/pulls/{}/reviews", number))
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json()?;
+        Ok(summarize_reviews(reviews.iter().filter_map(|r| {
+            let verdict = match r.state.as_str() {
+                _ if r.dismissed => ReviewState::Pending,
+                "APPROVED" => ReviewState::Approved,
+                "REQUEST_CHANGES" => ReviewState::ChangesRequested,
+                _ => return None,
+            };
+            Some((r.user.as_ref()?.login.as_str(), verdict))
+        })))
+    }
+
+    fn ci_status(&self, sha: &str) -> Result<CiState> {
+        let status: CombinedStatus = self
+            .request("GET", &format!("/commits/{}/status", sha))
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json()?;
+        Ok(if status.total_count == 0 {
+            CiState::None
+        } else {
+            ci_state(&status.state)
+        })
+    }
+}
@@ -0,0 +1,213 @@
+//! GitHub (github.com and Enterprise Server) backend
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{
+    api_error, summarize_reviews, CiState, Client, Forge, PullRequest, RemoteRepo, ReviewState,
+    ReviewStatus,
+};
+use crate::error::Result;
+use crate::storage::forge::ForgeKind;
+
+pub struct GitHub {
+    client: Client,
+    repo: RemoteRepo,
+}
+
+impl GitHub {
+    pub(super) fn new(client: Client, repo: RemoteRepo) -> Self {
+        Self { client, repo }
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.client
+            .request(
+                method,
+                &format!("/repos/{}/{}{}", self.repo.owner, self.repo.name, path),
+            )
+            .set("Accept", "application/vnd.github+json")
+            .set("Authorization", &format!("Bearer {}", self.client.token))
+    }
+
+    fn find_open_pull(&self, branch: &str) -> Result<Option<PullRequest>> {
+        let pulls: Vec<PullResponse> = self
+            .request("GET", "/pulls")
+            .query("state", "open")
+            .query("head", &format!("{}:{}", self.repo.owner, branch))
+            .call()
+            .map_err(|e| api_error(ForgeKind::Github, e))?
+            .into_json()?;
+        Ok(pulls.into_iter().next().map(Into::into))
+    }
+}
+
+#[derive(Deserialize)]
+struct PullResponse {
+    number: u64,
+    html_url: String,
+    state: String,
+    #[serde(default)]
+    merged_at: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<PullResponse> for PullRequest {
+    fn from(pr: PullResponse) -> Self {
+        let state = if pr.merged_at.is_some() {
+            "merged".to_string()
+        } else {
+            pr.state
+        };
+        PullRequest {
+            number: pr.number,
+            url: pr.html_url,
+            state,
+            created_at: pr.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Review {
+    user: Option<User>,
+    state: String,
+}
+
+#[derive(Deserialize)]
+struct User {
+    login: String,
+}
+
+#[derive(Deserialize)]
+struct CheckRuns {
+    check_runs: Vec<CheckRun>,
+}
+
+#[derive(Deserialize)]
+struct CheckRun {
+    status: String,
+    #[serde(default)]
+    conclusion: Option<String>,
+}
+
+impl Forge for GitHub {
+    fn kind(&self) -> ForgeKind {
+        ForgeKind::Github
+    }
+
+    fn create_pull(&self, head: &str, base: &str, title: &str, body: &str) -> Result<PullRequest> {
+        let response = self.request("POST", "/pulls").send_json(json!({
+            "title": title,
+            "head": head,
+            "base": base,
+            "body": body,
+        }));
+        match response {
+            Ok(resp) => Ok(resp.into_json::<PullResponse>()?.into()),
+            // 422: a pull request for this branch already exists
+            Err(ureq::Error::Status(422, resp)) => match self.find_open_pull(head)? {
+                Some(pr) => Ok(pr),
+                None => Err(api_error(self.kind(), ureq::Error::Status(422, resp))),
+            },
+            Err(e) => Err(api_error(self.kind(), e)),
+        }
+    }
+
+    fn fetch_pull(&self, number: u64) -> Result<PullRequest> {
+        Ok(self
+            .request("GET", &format!("/pulls/{}", number))
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json::<PullResponse>()?
+            .into())
+    }
+
+    fn review_status(&self, number: u64) -> Result<ReviewStatus> {
+        let reviews: Vec<Review> = self
+            .request("GET", &format!("/pulls/{}/reviews", number))
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json()?;
+        Ok(summarize_reviews(reviews.iter().filter_map(|r| {
+            let verdict = match r.state.as_str() {
+                "APPROVED" => ReviewState::Approved,
+                "CHANGES_REQUESTED" => ReviewState::ChangesRequested,
+                "DISMISSED" => ReviewState::Pending,
+                _ => return None,
+            };
+            Some((r.user.as_ref()?.login.as_str(), verdict))
+        })))
+    }
+
+    fn ci_status(&self, sha: &str) -> Result<CiState> {
+        let runs: CheckRuns = self
+            .request("GET", &format!("/commits/{}/check-runs", sha))
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json()?;
+        Ok(aggregate_check_runs(&runs.check_runs))
+    }
+}
+
+/// Any failed run → failure; any unfinished run → pending
+fn aggregate_check_runs(runs: &[CheckRun]) -> CiState {
+    if runs.is_empty() {
+        return CiState::None;
+    }
+    let failed = runs.iter().any(|r| {
+        matches!(
+            r.conclusion.as_deref(),
+            Some("failure" | "timed_out" | "cancelled" | "action_required")
+        )
+    });
+    if failed {
+        CiState::Failure
+    } else if runs.iter().any(|r| r.status != "completed") {
+        CiState::Pending
+    } else {
+        CiState::Success
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merged_pull_state() {
+        let pr: PullRequest = serde_json::from_value::<PullResponse>(json!({
+            "number": 7,
+            "html_url": "https://github.com/acme/app/pull/7",
+            "state": "closed",
+            "merged_at": "2026-01-02T00:00:00Z",
+            "created_at": "2026-01-01T00:00:00Z",
+        }))
+        .unwrap()
+        .into();
+        assert_eq!(pr.state, "merged");
+        assert_eq!(pr.number, 7);
+    }
+
+    #[test]
+    fn test_aggregate_check_runs() {
+        let run = |status: &str, conclusion: Option<&str>| CheckRun {
+            status: status.to_string(),
+            conclusion: conclusion.map(str::to_string),
+        };
+        assert_eq!(aggregate_check_runs(&[]), CiState::None);
+        assert_eq!(
+            aggregate_check_runs(&[run("completed", Some("success")), run("in_progress", None)]),
+            CiState::Pending
+        );
+        assert_eq!(
+            aggregate_check_runs(&[run("completed", Some("failure")), run("queued", None)]),
+            CiState::Failure
+        );
+        assert_eq!(
+            aggregate_check_runs(&[run("completed", Some("skipped"))]),
+            CiState::Success
+        );
+    }
+}
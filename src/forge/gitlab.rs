@@ -0,0 +1,169 @@
+//! GitLab (gitlab.com and self-managed) backend — merge requests and pipelines
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+use super::{
+    api_error, ci_state, CiState, Client, Forge, PullRequest, RemoteRepo, ReviewState, ReviewStatus,
+};
+use crate::error::Result;
+use crate::storage::forge::ForgeKind;
+
+pub struct GitLab {
+    client: Client,
+    /// URL-encoded `namespace/name`, GitLab's project id in API paths
+    project: String,
+}
+
+impl GitLab {
+    pub(super) fn new(client: Client, repo: RemoteRepo) -> Self {
+        let path = format!("{}/{}", repo.owner, repo.name);
+        let project = url::form_urlencoded::byte_serialize(path.as_bytes()).collect();
+        Self { client, project }
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.client
+            .request(method, &format!("/projects/{}{}", self.project, path))
+            .set("PRIVATE-TOKEN", &self.client.token)
+    }
+
+    fn find_open_merge_request(&self, branch: &str) -> Result<Option<PullRequest>> {
+        let mrs: Vec<MergeRequest> = self
+            .request("GET", "/merge_requests")
+            .query("state", "opened")
+            .query("source_branch", branch)
+            .call()
+            .map_err(|e| api_error(ForgeKind::Gitlab, e))?
+            .into_json()?;
+        Ok(mrs.into_iter().next().map(Into::into))
+    }
+}
+
+#[derive(Deserialize)]
+struct MergeRequest {
+    iid: u64,
+    web_url: String,
+    state: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<MergeRequest> for PullRequest {
+    fn from(mr: MergeRequest) -> Self {
+        let state = match mr.state.as_str() {
+            "opened" | "locked" => "open",
+            "merged" => "merged",
+            _ => "closed",
+        };
+        PullRequest {
+            number: mr.iid,
+            url: mr.web_url,
+            state: state.to_string(),
+            created_at: mr.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Approvals {
+    #[serde(default)]
+    approved: bool,
+    #[serde(default)]
+    approved_by: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct Pipeline {
+    status: String,
+}
+
+impl Forge for GitLab {
+    fn kind(&self) -> ForgeKind {
+        ForgeKind::Gitlab
+    }
+
+    fn create_pull(&self, head: &str, base: &str, title: &str, body: &str) -> Result<PullRequest> {
+        let response = self.request("POST", "/merge_requests").send_json(json!({
+            "source_branch": head,
+            "target_branch": base,
+            "title": title,
+            "description": body,
+        }));
+        match response {
+            Ok(resp) => Ok(resp.into_json::<MergeRequest>()?.into()),
+            // 409: a merge request for this branch already exists
+            Err(ureq::Error::Status(409, resp)) => match self.find_open_merge_request(head)? {
+                Some(mr) => Ok(mr),
+                None => Err(api_error(self.kind(), ureq::Error::Status(409, resp))),
+            },
+            Err(e) => Err(api_error(self.kind(), e)),
+        }
+    }
+
+    fn fetch_pull(&self, number: u64) -> Result<PullRequest> {
+        Ok(self
+            .request("GET", &format!("/merge_requests/{}", number))
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json::<MergeRequest>()?
+            .into())
+    }
+
+    fn review_status(&self, number: u64) -> Result<ReviewStatus> {
+        let approvals: Approvals = self
+            .request("GET", &format!("/merge_requests/{}/approvals", number))
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json()?;
+        let count = approvals.approved_by.len() as u32;
+        Ok(ReviewStatus {
+            state: if approvals.approved && count > 0 {
+                ReviewState::Approved
+            } else {
+                ReviewState::Pending
+            },
+            approvals: count,
+        })
+    }
+
+    fn ci_status(&self, sha: &str) -> Result<CiState> {
+        let pipelines: Vec<Pipeline> = self
+            .request("GET", "/pipelines")
+            .query("sha", sha)
+            .query("per_page", "1")
+            .call()
+            .map_err(|e| api_error(self.kind(), e))?
+            .into_json()?;
+        Ok(pipelines
+            .first()
+            .map(|p| ci_state(&p.status))
+            .unwrap_or(CiState::None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_id_and_state() {
+        let repo = RemoteRepo::parse("git@gitlab.com:group/sub/app.git").unwrap();
+        let client = Client {
+            api_url: "https://gitlab.com/api/v4".to_string(),
+            token: String::new(),
+        };
+        assert_eq!(GitLab::new(client, repo).project, "group%2Fsub%2Fapp");
+
+        let mr: PullRequest = serde_json::from_value::<MergeRequest>(json!({
+            "iid": 3,
+            "web_url": "https://gitlab.com/group/sub/app/-/merge_requests/3",
+            "state": "opened",
+            "created_at": "2026-01-01T00:00:00Z",
+        }))
+        .unwrap()
+        .into();
+        assert_eq!(mr.state, "open");
+        assert_eq!(mr.number, 3);
+    }
+}
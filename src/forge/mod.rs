@@ -0,0 +1,317 @@
+//! Code forge integration with a backend per hosting platform.
+//!
+//! Each forge (GitHub, GitLab, Gitea) implements the [`Forge`] trait: open a
+//! pull / merge request for a task branch, read its state and review status,
+//! and report the CI state of a commit. [`open`] picks the backend from the
+//! project's forge settings or, by default, from the `origin` remote host.
+//! Tokens come from `[github]` / `[gitlab]` / `[gitea]` in config.toml.
+
+mod gitea;
+mod github;
+mod gitlab;
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::error::{GroveError, Result};
+use crate::git;
+use crate::storage::config::{self, Config, ForgeAuthConfig};
+use crate::storage::forge::{self as forge_settings, ForgeKind};
+pub use crate::storage::tasks::PullRequest;
+
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Aggregated review verdict of a pull / merge request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    Pending,
+}
+
+/// Review status of a pull / merge request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReviewStatus {
+    pub state: ReviewState,
+    pub approvals: u32,
+}
+
+/// CI state of a commit (`None` when no pipeline / check ran)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CiState {
+    Success,
+    Failure,
+    Pending,
+    None,
+}
+
+/// A task's pull / merge request with its live review and CI state
+/// (`None` when the forge couldn't be reached)
+#[derive(Debug, Clone, Serialize)]
+pub struct PullRequestStatus {
+    #[serde(flatten)]
+    pub pull_request: PullRequest,
+    pub forge: Option<ForgeKind>,
+    pub review: Option<ReviewStatus>,
+    pub ci: Option<CiState>,
+}
+
+/// A hosting platform backend bound to one repository
+pub trait Forge: Send {
+    fn kind(&self) -> ForgeKind;
+
+    /// Open a pull / merge request `head` → `base`; an already open one for
+    /// `head` is returned instead.
+    fn create_pull(&self, head: &str, base: &str, title: &str, body: &str) -> Result<PullRequest>;
+
+    /// Current state of pull / merge request `number`
+    fn fetch_pull(&self, number: u64) -> Result<PullRequest>;
+
+    /// Review verdicts of pull / merge request `number`
+    fn review_status(&self, number: u64) -> Result<ReviewStatus>;
+
+    /// CI state of commit `sha`
+    fn ci_status(&self, sha: &str) -> Result<CiState>;
+}
+
+/// Host and `owner/name` path of a remote repository. GitLab owners can be
+/// nested groups (`group/subgroup`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteRepo {
+    pub host: String,
+    pub owner: String,
+    pub name: String,
+}
+
+impl RemoteRepo {
+    /// Parse a remote URL (`git@host:a/b.git`, `https://host/a/b`,
+    /// `ssh://git@host:2222/a/b.git`, …)
+    pub fn parse(url: &str) -> Option<Self> {
+        let normalized = crate::operations::sync::normalize_remote(url);
+        let (host, path) = normalized.split_once('/')?;
+        // SSH port is not the API port
+        let host = host.split(':').next().unwrap_or(host);
+        let (owner, name) = path.rsplit_once('/')?;
+        if host.is_empty() || owner.is_empty() || name.is_empty() {
+            return None;
+        }
+        Some(Self {
+            host: host.to_string(),
+            owner: owner.to_string(),
+            name: name.to_string(),
+        })
+    }
+}
+
+/// Guess the forge from a remote host
+pub fn detect_kind(host: &str) -> Option<ForgeKind> {
+    let host = host.to_ascii_lowercase();
+    if host == "github.com" || host.contains("github") {
+        Some(ForgeKind::Github)
+    } else if host.contains("gitlab") {
+        Some(ForgeKind::Gitlab)
+    } else if host.contains("gitea") || host == "codeberg.org" {
+        Some(ForgeKind::Gitea)
+    } else {
+        None
+    }
+}
+
+/// Default REST API root of a forge on `host`
+pub fn default_api_url(kind: ForgeKind, host: &str) -> String {
+    match kind {
+        ForgeKind::Github if host == "github.com" => "https://api.github.com".to_string(),
+        // GitHub Enterprise Server
+        ForgeKind::Github => format!("https://{}/api/v3", host),
+        ForgeKind::Gitlab => format!("https://{}/api/v4", host),
+        ForgeKind::Gitea => format!("https://{}/api/v1", host),
+    }
+}
+
+/// Token section of config.toml for `kind`
+pub fn auth(config: &Config, kind: ForgeKind) -> &ForgeAuthConfig {
+    match kind {
+        ForgeKind::Github => &config.github,
+        ForgeKind::Gitlab => &config.gitlab,
+        ForgeKind::Gitea => &config.gitea,
+    }
+}
+
+/// Forge of the project's `origin`, resolved from the project's forge
+/// settings first and the remote host otherwise
+pub fn resolve(repo_path: &str, project_key: &str) -> Result<(ForgeKind, RemoteRepo, String)> {
+    let settings = forge_settings::load_settings(project_key);
+    let remote = git::remote_url(repo_path, "origin")
+        .map_err(|_| GroveError::git("Project has no 'origin' remote"))?;
+    let repo = RemoteRepo::parse(&remote)
+        .ok_or_else(|| GroveError::git(format!("Unrecognized 'origin' URL: {}", remote)))?;
+    let kind = settings
+        .kind
+        .or_else(|| detect_kind(&repo.host))
+        .ok_or_else(|| {
+            GroveError::invalid_data(format!(
+                "Can't tell which forge hosts {} — choose one in the project's forge settings",
+                repo.host
+            ))
+        })?;
+    let api_url = settings
+        .api_url
+        .unwrap_or_else(|| default_api_url(kind, &repo.host));
+    Ok((kind, repo, api_url))
+}
+
+/// Backend for the project's `origin`
+pub fn open(repo_path: &str, project_key: &str) -> Result<Box<dyn Forge>> {
+    let (kind, repo, api_url) = resolve(repo_path, project_key)?;
+    let config = config::load_config();
+    let token = auth(&config, kind)
+        .token()
+        .ok_or_else(|| {
+            GroveError::invalid_data(format!(
+                "{} token not configured — set [{}] token in config.toml",
+                kind.label(),
+                kind.as_str()
+            ))
+        })?
+        .to_string();
+    let client = Client { api_url, token };
+    Ok(match kind {
+        ForgeKind::Github => Box::new(github::GitHub::new(client, repo)),
+        ForgeKind::Gitlab => Box::new(gitlab::GitLab::new(client, repo)),
+        ForgeKind::Gitea => Box::new(gitea::Gitea::new(client, repo)),
+    })
+}
+
+/// API root + token shared by the backends
+struct Client {
+    api_url: String,
+    token: String,
+}
+
+impl Client {
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        ureq::request(method, &format!("{}{}", self.api_url, path))
+            .set("User-Agent", "grove-rs")
+            .timeout(TIMEOUT)
+    }
+}
+
+/// Turn an HTTP failure into a `GroveError`, keeping the forge's message
+fn api_error(kind: ForgeKind, e: ureq::Error) -> GroveError {
+    match e {
+        ureq::Error::Status(status, resp) => {
+            let message = resp
+                .into_json::<serde_json::Value>()
+                .ok()
+                .and_then(|body| body.get("message").cloned())
+                .map(|m| match m {
+                    serde_json::Value::String(s) => s,
+                    other => other.to_string(),
+                })
+                .unwrap_or_default();
+            GroveError::git(format!(
+                "{} API returned {}: {}",
+                kind.label(),
+                status,
+                message
+            ))
+        }
+        e => GroveError::git(format!("{} API request failed: {}", kind.label(), e)),
+    }
+}
+
+/// Latest verdict per reviewer → overall status. `verdicts` are
+/// `(reviewer, verdict)` in chronological order; comments don't count.
+fn summarize_reviews<'a>(
+    verdicts: impl IntoIterator<Item = (&'a str, ReviewState)>,
+) -> ReviewStatus {
+    let mut latest: Vec<(&str, ReviewState)> = Vec::new();
+    for (user, verdict) in verdicts {
+        match latest.iter_mut().find(|(u, _)| *u == user) {
+            Some(entry) => entry.1 = verdict,
+            None => latest.push((user, verdict)),
+        }
+    }
+    let approvals = latest
+        .iter()
+        .filter(|(_, v)| *v == ReviewState::Approved)
+        .count() as u32;
+    let state = if latest
+        .iter()
+        .any(|(_, v)| *v == ReviewState::ChangesRequested)
+    {
+        ReviewState::ChangesRequested
+    } else if approvals > 0 {
+        ReviewState::Approved
+    } else {
+        ReviewState::Pending
+    };
+    ReviewStatus { state, approvals }
+}
+
+/// Commit / pipeline status string → `CiState`
+fn ci_state(status: &str) -> CiState {
+    match status {
+        "success" | "passed" => CiState::Success,
+        "failure" | "failed" | "error" | "canceled" | "cancelled" | "timed_out" => CiState::Failure,
+        "" | "skipped" => CiState::None,
+        _ => CiState::Pending,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_remote_and_detect() {
+        let repo = RemoteRepo::parse("git@gitlab.example.com:group/sub/app.git").unwrap();
+        assert_eq!(repo.host, "gitlab.example.com");
+        assert_eq!(repo.owner, "group/sub");
+        assert_eq!(repo.name, "app");
+        assert_eq!(detect_kind(&repo.host), Some(ForgeKind::Gitlab));
+
+        let repo = RemoteRepo::parse("ssh://git@codeberg.org:2222/me/tool").unwrap();
+        assert_eq!(repo.host, "codeberg.org");
+        assert_eq!(detect_kind(&repo.host), Some(ForgeKind::Gitea));
+
+        let repo = RemoteRepo::parse("https://github.com/acme/app.git").unwrap();
+        assert_eq!(detect_kind(&repo.host), Some(ForgeKind::Github));
+        assert_eq!(
+            default_api_url(ForgeKind::Github, &repo.host),
+            "https://api.github.com"
+        );
+        assert_eq!(
+            default_api_url(ForgeKind::Gitea, "git.example.com"),
+            "https://git.example.com/api/v1"
+        );
+
+        assert_eq!(detect_kind("git.example.com"), None);
+        assert_eq!(RemoteRepo::parse("https://github.com/acme"), None);
+    }
+
+    #[test]
+    fn test_summarize_reviews_uses_latest_verdict() {
+        let status = summarize_reviews([
+            ("ann", ReviewState::ChangesRequested),
+            ("bob", ReviewState::Approved),
+            ("ann", ReviewState::Approved),
+        ]);
+        assert_eq!(status.state, ReviewState::Approved);
+        assert_eq!(status.approvals, 2);
+
+        let status = summarize_reviews([
+            ("ann", ReviewState::Approved),
+            ("bob", ReviewState::ChangesRequested),
+        ]);
+        assert_eq!(status.state, ReviewState::ChangesRequested);
+        assert_eq!(summarize_reviews([]).state, ReviewState::Pending);
+
+        assert_eq!(ci_state("running"), CiState::Pending);
+        assert_eq!(ci_state("failed"), CiState::Failure);
+        assert_eq!(ci_state(""), CiState::None);
+    }
+}
@@ -49,3 +49,81 @@ pub fn is_link(path: &Path) -> bool {
     #[cfg(not(windows))]
     false
 }
+
+/// Remove a link created by `create_link` without touching its source.
+///
+/// Junctions on Windows are directories and must be removed with
+/// `remove_dir`; everything else (symlinks, hardlinks) is a file entry.
+pub fn remove_link(path: &Path) -> std::io::Result<()> {
+    #[cfg(windows)]
+    {
+        if junction::exists(path).unwrap_or(false) {
+            junction::delete(path)?;
+            return std::fs::remove_dir(path);
+        }
+    }
+    std::fs::remove_file(path)
+}
+
+/// Copy (or hardlink) a file or directory tree from `source` to `target`.
+///
+/// With `hardlink`, every regular file is hard-linked instead of copied, so a
+/// directory becomes a tree of real directories whose files share storage
+/// with the source. Symlinks inside the tree are recreated as-is.
+pub fn copy_tree(source: &Path, target: &Path, hardlink: bool) -> std::io::Result<()> {
+    let meta = std::fs::symlink_metadata(source)?;
+    if meta.is_dir() {
+        std::fs::create_dir_all(target)?;
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            copy_tree(&entry.path(), &target.join(entry.file_name()), hardlink)?;
+        }
+        Ok(())
+    } else if meta.file_type().is_symlink() {
+        let dest = std::fs::read_link(source)?;
+        create_link(&dest, target)
+    } else if hardlink {
+        std::fs::hard_link(source, target)
+    } else {
+        std::fs::copy(source, target).map(|_| ())
+    }
+}
+
+/// Summary of a file tree used to tell whether a copy has gone stale.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TreeStat {
+    /// Number of regular files
+    pub files: u64,
+    /// Total size of regular files in bytes
+    pub bytes: u64,
+    /// Newest modification time among regular files
+    pub newest: Option<std::time::SystemTime>,
+}
+
+/// Walk a file or directory tree without following symlinks.
+pub fn tree_stat(path: &Path) -> std::io::Result<TreeStat> {
+    let meta = std::fs::symlink_metadata(path)?;
+    let mut stat = TreeStat::default();
+    if meta.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            let child = tree_stat(&entry?.path())?;
+            stat.files += child.files;
+            stat.bytes += child.bytes;
+            stat.newest = stat.newest.max(child.newest);
+        }
+    } else if meta.is_file() {
+        stat.files = 1;
+        stat.bytes = meta.len();
+        stat.newest = meta.modified().ok();
+    }
+    Ok(stat)
+}
+
+/// Remove a copied file or directory tree.
+pub fn remove_tree(path: &Path) -> std::io::Result<()> {
+    if std::fs::symlink_metadata(path)?.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    }
+}
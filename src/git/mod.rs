@@ -1,9 +1,13 @@
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::time::Instant;
 
 use crate::error::{GroveError, Result};
+use crate::storage::config::{AutoLinkConfig, LinkMode, SigningConfig};
+use crate::storage::git_identity::{self, GitIdentity};
 
 pub mod cache;
+pub mod sparse_checkout;
 
 // ============================================================================
 // Git 命令执行助手函数
@@ -11,12 +15,14 @@ pub mod cache;
 
 /// 执行 git 命令并返回 stdout (trim 后)
 pub(crate) fn git_cmd(path: &str, args: &[&str]) -> Result<String> {
+    let started = Instant::now();
     let output = Command::new("git")
         .current_dir(path)
         .args(args)
         .stdin(Stdio::null())
         .output()
         .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    crate::metrics::observe_git(args, started.elapsed());
 
     if output.status.success() {
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -37,12 +43,14 @@ fn git_cmd_unit(path: &str, args: &[&str]) -> Result<()> {
 
 /// 执行 git 命令，允许 exit code 1（如 `git diff --no-index` 在文件不同时返回 1）
 pub(crate) fn git_cmd_allow_exit1(path: &str, args: &[&str]) -> Result<String> {
+    let started = Instant::now();
     let output = Command::new("git")
         .current_dir(path)
         .args(args)
         .stdin(Stdio::null())
         .output()
         .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    crate::metrics::observe_git(args, started.elapsed());
 
     if output.status.success() || output.status.code() == Some(1) {
         String::from_utf8(output.stdout)
@@ -162,18 +170,28 @@ pub struct GixLogEntry {
     pub time_ago: String,
     pub committer_time: i64,
     pub tree_id: String,
+    /// 提交信息里的 Grove-Task / Grove-Chat trailer
+    pub trailers: GroveTrailers,
 }
 
 fn extract_log_entry(commit: &gix::Commit<'_>) -> Option<GixLogEntry> {
     let hash = commit.id().to_hex().to_string();
-    let message = {
+    let (message, trailers) = {
         let raw = commit.message_raw_sloppy();
         let bytes: &[u8] = raw.as_ref();
         let line_end = bytes
             .iter()
             .position(|&b| b == b'\n')
             .unwrap_or(bytes.len());
-        String::from_utf8_lossy(&bytes[..line_end]).into_owned()
+        let trailers = if line_end < bytes.len() {
+            parse_grove_trailers(&String::from_utf8_lossy(bytes))
+        } else {
+            GroveTrailers::default()
+        };
+        (
+            String::from_utf8_lossy(&bytes[..line_end]).into_owned(),
+            trailers,
+        )
     };
     let author = commit
         .author()
@@ -196,6 +214,7 @@ fn extract_log_entry(commit: &gix::Commit<'_>) -> Option<GixLogEntry> {
         time_ago,
         committer_time,
         tree_id,
+        trailers,
     })
 }
 
@@ -555,7 +574,6 @@ pub fn commits_behind(worktree_path: &str, branch: &str, target: &str) -> Result
 /// 获取文件变更统计 (相对于 target)
 /// 复用 diff_stat，保证和 diff API 的计算逻辑完全一致
 /// 返回: (additions, deletions, files_changed)
-#[allow(dead_code)]
 pub fn file_changes(worktree_path: &str, target: &str) -> Result<(u32, u32, u32)> {
     let entries = diff_stat(worktree_path, target)?;
     let additions = entries.iter().map(|e| e.additions).sum();
@@ -570,6 +588,15 @@ pub fn remove_worktree(repo_path: &str, worktree_path: &str) -> Result<()> {
     git_cmd_unit(repo_path, &["worktree", "remove", worktree_path, "--force"])
 }
 
+/// 在指定 commit 上创建 detached worktree（临时用途，如 bench 对比）
+/// 执行: git worktree add --detach {path} {commit}
+pub fn add_detached_worktree(repo_path: &str, worktree_path: &str, commit: &str) -> Result<()> {
+    git_cmd_unit(
+        repo_path,
+        &["worktree", "add", "--detach", worktree_path, commit],
+    )
+}
+
 /// 从现有分支创建 worktree（不创建新分支）
 /// 执行: git worktree add {path} {branch}
 pub fn create_worktree_from_branch(
@@ -612,6 +639,277 @@ pub fn list_branches(repo_path: &str) -> Result<Vec<String>> {
     })
 }
 
+/// 分支详情（分支选择器用）
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BranchDetail {
+    pub name: String,
+    /// 最后一次提交时间（unix 秒）
+    pub last_commit_at: Option<i64>,
+    /// 相对 base 领先的提交数
+    pub ahead: Option<u32>,
+    /// 相对 base 落后的提交数
+    pub behind: Option<u32>,
+    /// 已检出该分支的 worktree 路径
+    pub worktree: Option<String>,
+}
+
+/// 老版本 git（无 `%(ahead-behind)`）逐分支计算 ahead/behind 的数量上限
+const AHEAD_BEHIND_FALLBACK_LIMIT: usize = 100;
+
+/// 批量获取分支详情
+///
+/// `refs` 为 `refs/heads` 或 `refs/remotes/<remote>`。一次 for-each-ref 取提交时间
+/// 与 ahead/behind（git ≥ 2.41 的 `%(ahead-behind:<base>)`），一次 worktree list
+/// 标记已检出的分支；老版本 git 退回并行的 `rev-list --left-right --count`，
+/// 只算最近提交的前 [`AHEAD_BEHIND_FALLBACK_LIMIT`] 个分支。`base` 为空时不算。
+pub fn branch_details(repo_path: &str, refs: &str, base: &str) -> Result<Vec<BranchDetail>> {
+    const FORMAT: &str = "--format=%(refname)%09%(refname:short)%09%(committerdate:unix)";
+    let batched = (!base.is_empty())
+        .then(|| {
+            let format = format!("{FORMAT}%09%(ahead-behind:{base})");
+            git_cmd(repo_path, &["for-each-ref", &format, refs]).ok()
+        })
+        .flatten();
+    let has_ahead_behind = batched.is_some();
+    let output = match batched {
+        Some(out) => out,
+        None => git_cmd(repo_path, &["for-each-ref", FORMAT, refs])?,
+    };
+    let mut details = parse_branch_details(&output);
+
+    if !has_ahead_behind && !base.is_empty() {
+        use rayon::prelude::*;
+        let mut recent: Vec<usize> = (0..details.len()).collect();
+        recent.sort_by_key(|&i| std::cmp::Reverse(details[i].last_commit_at));
+        recent.truncate(AHEAD_BEHIND_FALLBACK_LIMIT);
+        let counts: Vec<(usize, Option<(u32, u32)>)> = recent
+            .into_par_iter()
+            .map(|i| {
+                let range = format!("{}...{}", details[i].name, base);
+                let counts = git_cmd(repo_path, &["rev-list", "--left-right", "--count", &range])
+                    .ok()
+                    .and_then(|out| parse_ahead_behind(&out));
+                (i, counts)
+            })
+            .collect();
+        for (i, counts) in counts {
+            if let Some((ahead, behind)) = counts {
+                details[i].ahead = Some(ahead);
+                details[i].behind = Some(behind);
+            }
+        }
+    }
+
+    if let Ok(worktrees) = list_worktrees(repo_path) {
+        for wt in worktrees.into_iter().filter(|w| !w.prunable) {
+            if let Some(d) = details
+                .iter_mut()
+                .find(|d| wt.branch.as_deref() == Some(d.name.as_str()))
+            {
+                d.worktree = Some(wt.path);
+            }
+        }
+    }
+    Ok(details)
+}
+
+/// 解析 for-each-ref 输出：`refname \t short \t unix [\t "ahead behind"]`（跳过 `*/HEAD`）
+fn parse_branch_details(output: &str) -> Vec<BranchDetail> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\t');
+            let refname = parts.next()?;
+            if refname.ends_with("/HEAD") {
+                return None;
+            }
+            let name = parts.next()?.trim();
+            if name.is_empty() {
+                return None;
+            }
+            let last_commit_at = parts.next().and_then(|t| t.trim().parse().ok());
+            let counts = parts.next().and_then(parse_ahead_behind);
+            Some(BranchDetail {
+                name: name.to_string(),
+                last_commit_at,
+                ahead: counts.map(|c| c.0),
+                behind: counts.map(|c| c.1),
+                worktree: None,
+            })
+        })
+        .collect()
+}
+
+/// "3 1" / "3\t1" → (3, 1)
+fn parse_ahead_behind(s: &str) -> Option<(u32, u32)> {
+    let mut it = s.split_whitespace();
+    let ahead = it.next()?.parse().ok()?;
+    let behind = it.next()?.parse().ok()?;
+    Some((ahead, behind))
+}
+
+/// `git worktree list` 中的一项
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeEntry {
+    pub path: String,
+    pub head: String,
+    /// 短分支名；detached HEAD 时为 None
+    pub branch: Option<String>,
+    pub bare: bool,
+    /// 目录已不存在，可被 `git worktree prune` 清理
+    pub prunable: bool,
+}
+
+/// 列出仓库的所有 worktree（第一项是主 worktree）
+/// 执行: git worktree list --porcelain
+pub fn list_worktrees(repo_path: &str) -> Result<Vec<WorktreeEntry>> {
+    git_cmd(repo_path, &["worktree", "list", "--porcelain"]).map(|out| parse_worktree_list(&out))
+}
+
+/// 解析 `git worktree list --porcelain` 输出（空行分隔的记录）
+fn parse_worktree_list(output: &str) -> Vec<WorktreeEntry> {
+    let mut entries = Vec::new();
+    for record in output.split("\n\n") {
+        let mut entry: Option<WorktreeEntry> = None;
+        for line in record.lines() {
+            let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+            if key == "worktree" {
+                entry = Some(WorktreeEntry {
+                    path: value.to_string(),
+                    head: String::new(),
+                    branch: None,
+                    bare: false,
+                    prunable: false,
+                });
+                continue;
+            }
+            let Some(e) = entry.as_mut() else {
+                continue;
+            };
+            match key {
+                "HEAD" => e.head = value.to_string(),
+                "branch" => {
+                    e.branch = Some(
+                        value
+                            .strip_prefix("refs/heads/")
+                            .unwrap_or(value)
+                            .to_string(),
+                    )
+                }
+                "bare" => e.bare = true,
+                "prunable" => e.prunable = true,
+                _ => {}
+            }
+        }
+        entries.extend(entry);
+    }
+    entries
+}
+
+/// 两个 ref 的最近公共祖先
+/// 执行: git merge-base {a} {b}
+pub fn merge_base(repo_path: &str, a: &str, b: &str) -> Result<String> {
+    git_cmd(repo_path, &["merge-base", a, b])
+}
+
+/// 解析 ref 为 commit hash；拒绝空串和以 `-` 开头（会被当作 git 选项）的输入
+/// 执行: git rev-parse --verify --quiet {ref}^{commit}
+pub fn resolve_commit(repo_path: &str, reference: &str) -> Result<String> {
+    let reference = reference.trim();
+    if reference.is_empty() || reference.starts_with('-') {
+        return Err(GroveError::invalid_data(format!(
+            "Invalid ref '{}'",
+            reference
+        )));
+    }
+    let spec = format!("{}^{{commit}}", reference);
+    git_cmd(repo_path, &["rev-parse", "--verify", "--quiet", &spec])
+        .ok()
+        .filter(|hash| !hash.is_empty())
+        .ok_or_else(|| GroveError::invalid_data(format!("Unknown ref '{}'", reference)))
+}
+
+/// 列出可用于 diff 对比的 ref：(kind, 短名)，kind 为 "branch" / "tag" / "remote"
+/// 执行: git for-each-ref refs/heads refs/tags refs/remotes
+pub fn list_compare_refs(repo_path: &str) -> Result<Vec<(&'static str, String)>> {
+    let output = git_cmd(
+        repo_path,
+        &[
+            "for-each-ref",
+            "--format=%(refname)",
+            "refs/heads",
+            "refs/tags",
+            "refs/remotes",
+        ],
+    )?;
+    Ok(parse_compare_refs(&output))
+}
+
+fn parse_compare_refs(output: &str) -> Vec<(&'static str, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if let Some(name) = line.strip_prefix("refs/heads/") {
+                Some(("branch", name.to_string()))
+            } else if let Some(name) = line.strip_prefix("refs/tags/") {
+                Some(("tag", name.to_string()))
+            } else {
+                // origin/HEAD 只是符号引用，跳过
+                line.strip_prefix("refs/remotes/")
+                    .filter(|name| !name.ends_with("/HEAD"))
+                    .map(|name| ("remote", name.to_string()))
+            }
+        })
+        .collect()
+}
+
+/// 批量查询 gitattributes，返回 (path, attr, value)；value 为 "set" / "unset" /
+/// "unspecified" 或具体取值
+/// 执行: git check-attr -z --stdin {attrs}
+pub fn check_attr(
+    repo_path: &str,
+    attrs: &[&str],
+    paths: &[String],
+) -> Result<Vec<(String, String, String)>> {
+    use std::io::Write;
+
+    if paths.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut child = Command::new("git")
+        .current_dir(repo_path)
+        .args(["check-attr", "-z", "--stdin"])
+        .args(attrs)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let input: Vec<u8> = paths
+            .iter()
+            .flat_map(|p| p.bytes().chain(std::iter::once(0)))
+            .collect();
+        stdin.write_all(&input)?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| GroveError::git(format!("git check-attr failed: {}", e)))?;
+    if !output.status.success() {
+        return Err(GroveError::git(format!(
+            "git check-attr failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let fields: Vec<&str> = stdout.split('\0').collect();
+    Ok(fields
+        .chunks_exact(3)
+        .map(|c| (c[0].to_string(), c[1].to_string(), c[2].to_string()))
+        .collect())
+}
+
 /// 列出所有远端
 /// 执行: git remote
 pub fn list_remotes(repo_path: &str) -> Result<Vec<String>> {
@@ -687,10 +985,113 @@ pub fn branch_head_equals(repo_path: &str, branch: &str, commit: &str) -> bool {
         .unwrap_or(false)
 }
 
-/// 执行 rebase
-/// 执行: git rebase {target}
+/// 执行 rebase（冲突标记带 base 段，供冲突解决界面展示）
+/// 执行: git -c merge.conflictStyle=diff3 rebase {target}
 pub fn rebase(worktree_path: &str, target: &str) -> Result<()> {
-    git_cmd_unit(worktree_path, &["rebase", target])
+    git_cmd_unit(
+        worktree_path,
+        &["-c", "merge.conflictStyle=diff3", "rebase", target],
+    )
+}
+
+/// rebase 状态目录（`rebase-merge` 或 `rebase-apply`），未在 rebase 中返回 None
+fn rebase_state_dir(worktree_path: &str) -> Option<std::path::PathBuf> {
+    ["rebase-merge", "rebase-apply"].iter().find_map(|name| {
+        let dir = git_cmd(
+            worktree_path,
+            &["rev-parse", "--path-format=absolute", "--git-path", name],
+        )
+        .ok()?;
+        let dir = std::path::PathBuf::from(dir);
+        dir.is_dir().then_some(dir)
+    })
+}
+
+/// 是否有进行中的 rebase（冲突时停下）
+pub fn rebase_in_progress(worktree_path: &str) -> bool {
+    rebase_state_dir(worktree_path).is_some()
+}
+
+/// 进行中 rebase 的进度 (当前第几个 commit, 共几个)
+pub fn rebase_progress(worktree_path: &str) -> Option<(u32, u32)> {
+    let dir = rebase_state_dir(worktree_path)?;
+    let read = |names: [&str; 2]| {
+        names.iter().find_map(|name| {
+            std::fs::read_to_string(dir.join(name))
+                .ok()
+                .and_then(|s| s.trim().parse::<u32>().ok())
+        })
+    };
+    Some((read(["msgnum", "next"])?, read(["end", "last"])?))
+}
+
+/// 冲突停下时正在应用的 commit（短 hash + 标题）
+pub fn rebase_stopped_commit(worktree_path: &str) -> Option<String> {
+    git_cmd(
+        worktree_path,
+        &["log", "-1", "--format=%h %s", "REBASE_HEAD"],
+    )
+    .ok()
+    .filter(|s| !s.is_empty())
+}
+
+/// 继续 rebase（冲突已全部解决并暂存）；不打开编辑器，沿用原提交信息
+/// 执行: git -c merge.conflictStyle=diff3 rebase --continue
+pub fn continue_rebase(worktree_path: &str) -> Result<()> {
+    let args = ["-c", "merge.conflictStyle=diff3", "rebase", "--continue"];
+    let started = Instant::now();
+    let output = Command::new("git")
+        .current_dir(worktree_path)
+        .args(args)
+        .env("GIT_EDITOR", "true")
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    crate::metrics::observe_git(&args, started.elapsed());
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let detail = if stderr.trim().is_empty() {
+            stdout
+        } else {
+            stderr
+        };
+        Err(GroveError::git(format!(
+            "git rebase --continue failed: {}",
+            detail.trim()
+        )))
+    }
+}
+
+/// 跳过当前 commit（冲突解决后已无改动）
+/// 执行: git rebase --skip
+pub fn skip_rebase_commit(worktree_path: &str) -> Result<()> {
+    git_cmd_unit(
+        worktree_path,
+        &["-c", "merge.conflictStyle=diff3", "rebase", "--skip"],
+    )
+}
+
+/// 冲突文件取某一侧的完整版本
+/// 执行: git checkout --ours|--theirs -- {file}
+pub fn checkout_conflict_side(worktree_path: &str, file: &str, ours: bool) -> Result<()> {
+    let side = if ours { "--ours" } else { "--theirs" };
+    git_cmd_unit(worktree_path, &["checkout", side, "--", file])
+}
+
+/// 暂存区相对 HEAD 是否没有改动
+/// 执行: git diff --cached --quiet
+pub fn index_clean(worktree_path: &str) -> bool {
+    git_cmd_check(worktree_path, &["diff", "--cached", "--quiet"])
+}
+
+/// 把 upstream 之后的提交移到 new_base 上
+/// 执行: git rebase --onto {new_base} {upstream}
+pub fn rebase_onto(worktree_path: &str, new_base: &str, upstream: &str) -> Result<()> {
+    git_cmd_unit(worktree_path, &["rebase", "--onto", new_base, upstream])
 }
 
 /// Fetch origin 分支
@@ -699,6 +1100,18 @@ pub fn fetch_origin(repo_path: &str, branch: &str) -> Result<()> {
     git_cmd_unit(repo_path, &["fetch", "origin", branch])
 }
 
+/// 推送分支到 origin 并设置 upstream
+/// 执行: git push -u origin {branch}
+pub fn push_branch(repo_path: &str, branch: &str) -> Result<()> {
+    git_cmd_unit(repo_path, &["push", "-u", "origin", branch])
+}
+
+/// 远端 URL
+/// 执行: git remote get-url {remote}
+pub fn remote_url(repo_path: &str, remote: &str) -> Result<String> {
+    git_cmd(repo_path, &["remote", "get-url", remote])
+}
+
 /// 中止 rebase
 /// 执行: git rebase --abort
 pub fn abort_rebase(repo_path: &str) -> Result<()> {
@@ -730,12 +1143,14 @@ pub fn get_head_commit(repo_path: &str) -> Result<String> {
 
 /// 通用 merge 命令执行函数 (带 merge 错误格式化)
 fn git_merge_cmd(repo_path: &str, args: &[&str]) -> Result<()> {
+    let started = Instant::now();
     let output = Command::new("git")
         .current_dir(repo_path)
         .args(args)
         .stdin(Stdio::null())
         .output()
         .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    crate::metrics::observe_git(args, started.elapsed());
 
     if output.status.success() {
         Ok(())
@@ -753,9 +1168,82 @@ pub fn merge_squash(repo_path: &str, branch: &str) -> Result<()> {
 }
 
 /// 执行 merge commit（保留历史）
-/// 执行: git merge --no-ff {branch} -m {message}
-pub fn merge_no_ff(repo_path: &str, branch: &str, message: &str) -> Result<()> {
-    git_merge_cmd(repo_path, &["merge", "--no-ff", branch, "-m", message])
+/// 执行: git [-c ..] merge --no-ff [-S] {branch} -m {message}
+pub fn merge_no_ff(
+    repo_path: &str,
+    branch: &str,
+    message: &str,
+    options: &CommitOptions,
+) -> Result<()> {
+    let message = options.message(message);
+    let args = options.args(&["merge", "--no-ff"], &[branch, "-m", &message]);
+    git_merge_cmd(repo_path, &as_strs(&args))
+}
+
+/// Grove 发起的 commit / merge 的附加选项：签名和身份覆盖
+#[derive(Debug, Clone, Default)]
+pub struct CommitOptions {
+    /// 启用签名时的签名设置
+    pub signing: Option<SigningConfig>,
+    /// author / committer / trailer 覆盖
+    pub identity: GitIdentity,
+    /// 项目开启时追加的 `Grove-Task` / `Grove-Chat` trailer
+    pub trailers: Vec<String>,
+    /// 项目是否开启 Grove trailer
+    pub link_trailers: bool,
+}
+
+impl CommitOptions {
+    /// 项目（及任务）生效的提交选项
+    pub fn resolve(project_key: &str, task_id: Option<&str>) -> Self {
+        let link_trailers =
+            crate::storage::workspace::load_commit_trailers(project_key).unwrap_or(true);
+        let trailers = match task_id {
+            Some(task_id) if link_trailers => vec![format!("{}: {}", TASK_TRAILER, task_id)],
+            _ => Vec::new(),
+        };
+        Self {
+            signing: crate::storage::workspace::commit_signing(project_key),
+            identity: git_identity::resolve(project_key, task_id),
+            trailers,
+            link_trailers,
+        }
+    }
+
+    /// 提交来自某个 chat 时追加 `Grove-Chat`
+    pub fn with_chat(mut self, chat_id: Option<&str>) -> Self {
+        if let Some(chat_id) = chat_id.filter(|c| !c.is_empty() && self.link_trailers) {
+            self.trailers.push(format!("{}: {}", CHAT_TRAILER, chat_id));
+        }
+        self
+    }
+
+    /// 追加所有 trailer 后的提交信息
+    pub fn message(&self, message: &str) -> String {
+        let message = self
+            .trailers
+            .iter()
+            .fold(message.to_string(), |msg, t| append_trailer(&msg, t));
+        self.identity.apply_trailer(&message)
+    }
+
+    /// 组装参数：`-c ..` 放在子命令前，`-S[key]` 紧跟子命令
+    pub(crate) fn args(&self, command: &[&str], rest: &[&str]) -> Vec<String> {
+        let mut args = self.identity.config_args();
+        if let Some(signing) = &self.signing {
+            args.extend(signing.config_args());
+        }
+        args.extend(command.iter().map(|a| a.to_string()));
+        if let Some(signing) = &self.signing {
+            args.push(signing.sign_flag());
+        }
+        args.extend(rest.iter().map(|a| a.to_string()));
+        args
+    }
+}
+
+fn as_strs(args: &[String]) -> Vec<&str> {
+    args.iter().map(String::as_str).collect()
 }
 
 /// 格式化 merge 错误信息
@@ -794,20 +1282,153 @@ pub fn reset_merge(repo_path: &str) -> Result<()> {
 }
 
 /// 提交（用于 squash merge 后）
-/// 执行: git commit -m {message}
-pub fn commit(repo_path: &str, message: &str) -> Result<()> {
-    git_cmd_unit(repo_path, &["commit", "-m", message])
+/// 执行: git [-c ..] commit [-S] -m {message}
+pub fn commit(repo_path: &str, message: &str, options: &CommitOptions) -> Result<()> {
+    let message = options.message(message);
+    let args = options.args(&["commit"], &["-m", &message]);
+    git_cmd_unit(repo_path, &as_strs(&args))
+}
+
+/// commit 签名校验结果（`git log %G?`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureStatus {
+    /// 有效签名（G）
+    Good,
+    /// 有效签名但密钥可信度未知（U）
+    Unknown,
+    /// 签名无效（B）
+    Bad,
+    /// 签名或密钥已过期（X / Y）
+    Expired,
+    /// 密钥已吊销（R）
+    Revoked,
+    /// 有签名但无法校验，如缺少公钥 / allowedSignersFile（E）
+    Unverified,
+}
+
+impl SignatureStatus {
+    /// `%G?` 代码；N（无签名）返回 None
+    fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "G" => Some(Self::Good),
+            "U" => Some(Self::Unknown),
+            "B" => Some(Self::Bad),
+            "X" | "Y" => Some(Self::Expired),
+            "R" => Some(Self::Revoked),
+            "E" => Some(Self::Unverified),
+            _ => None,
+        }
+    }
 }
 
-/// 构建包含 notes 的 commit message
-/// 如果 notes 为空或 None，返回原始标题
-pub fn build_commit_message(title: &str, notes: Option<&str>) -> String {
-    match notes {
-        Some(n) if !n.trim().is_empty() => {
-            format!("{}\n\n## Notes\n\n{}", title, n.trim())
+/// 一次 git log 查出一批 commit 的签名状态（未签名的不在结果里）
+/// 执行: git log --no-walk=unsorted --format=%H%x09%G? {hashes}
+pub fn signature_statuses(
+    repo_path: &str,
+    hashes: &[String],
+) -> Result<std::collections::HashMap<String, SignatureStatus>> {
+    if hashes.is_empty() {
+        return Ok(Default::default());
+    }
+    let mut args = vec!["log", "--no-walk=unsorted", "--format=%H%x09%G?"];
+    args.extend(hashes.iter().map(String::as_str));
+    git_cmd(repo_path, &args).map(|output| parse_signature_statuses(&output))
+}
+
+fn parse_signature_statuses(output: &str) -> std::collections::HashMap<String, SignatureStatus> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (hash, code) = line.split_once('\t')?;
+            Some((
+                hash.trim().to_string(),
+                SignatureStatus::from_code(code.trim())?,
+            ))
+        })
+        .collect()
+}
+
+/// 构建包含 notes / review 摘要的 commit message
+/// 各段为空或 None 时省略；全部为空则返回原始标题
+pub fn build_commit_message(title: &str, notes: Option<&str>, review: Option<&str>) -> String {
+    let mut msg = title.to_string();
+    for (heading, body) in [("Notes", notes), ("Review", review)] {
+        if let Some(b) = body.filter(|b| !b.trim().is_empty()) {
+            msg.push_str(&format!("\n\n## {}\n\n{}", heading, b.trim()));
         }
-        _ => title.to_string(),
     }
+    msg
+}
+
+/// 关联任务的 trailer key
+pub const TASK_TRAILER: &str = "Grove-Task";
+/// 关联 chat 的 trailer key
+pub const CHAT_TRAILER: &str = "Grove-Chat";
+
+/// `Token: value`，token 不含空白
+pub fn is_trailer_line(line: &str) -> bool {
+    match line.split_once(": ") {
+        Some((key, value)) => {
+            !key.is_empty() && !key.contains(char::is_whitespace) && !value.trim().is_empty()
+        }
+        None => false,
+    }
+}
+
+/// 在提交信息末尾追加一行 trailer（已存在则不重复追加）
+pub fn append_trailer(message: &str, trailer: &str) -> String {
+    let message = message.trim_end();
+    if message.lines().any(|l| l.trim() == trailer) {
+        return message.to_string();
+    }
+    // 最后一段已经是 trailer 块时直接接在后面
+    let last_paragraph = message.rsplit("\n\n").next().unwrap_or("");
+    let in_trailer_block = message.contains("\n\n") && last_paragraph.lines().all(is_trailer_line);
+    if in_trailer_block {
+        format!("{}\n{}", message, trailer)
+    } else {
+        format!("{}\n\n{}", message, trailer)
+    }
+}
+
+/// 从提交信息中解析出的 Grove trailer
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GroveTrailers {
+    pub task_id: Option<String>,
+    pub chat_id: Option<String>,
+}
+
+impl GroveTrailers {
+    pub fn is_empty(&self) -> bool {
+        self.task_id.is_none() && self.chat_id.is_none()
+    }
+}
+
+/// 解析提交信息末尾 trailer 块中的 `Grove-Task` / `Grove-Chat`
+pub fn parse_grove_trailers(message: &str) -> GroveTrailers {
+    let mut trailers = GroveTrailers::default();
+    let message = message.trim_end();
+    let Some((_, block)) = message.rsplit_once("\n\n") else {
+        return trailers;
+    };
+    if !block.lines().all(is_trailer_line) {
+        return trailers;
+    }
+    for line in block.lines() {
+        let Some((key, value)) = line.split_once(": ") else {
+            continue;
+        };
+        let slot = if key.eq_ignore_ascii_case(TASK_TRAILER) {
+            &mut trailers.task_id
+        } else if key.eq_ignore_ascii_case(CHAT_TRAILER) {
+            &mut trailers.chat_id
+        } else {
+            continue;
+        };
+        slot.get_or_insert_with(|| value.trim().to_string());
+    }
+    trailers
 }
 
 /// 获取 git 跟踪的文件列表
@@ -855,8 +1476,8 @@ pub(crate) fn git_unquote(s: &str) -> String {
 }
 
 pub fn list_files(repo_path: &str) -> Result<Vec<String>> {
-    // List tracked files
-    let tracked = git_cmd(repo_path, &["ls-files"])?;
+    // List tracked files (sparse worktrees: only the checked-out ones)
+    let tracked = sparse_checkout::checked_out_files(repo_path)?;
 
     // List untracked files (excluding ignored files)
     let untracked =
@@ -864,9 +1485,8 @@ pub fn list_files(repo_path: &str) -> Result<Vec<String>> {
 
     // Combine and deduplicate, unquoting git's octal-escaped paths
     let mut all_files: Vec<String> = tracked
-        .lines()
-        .chain(untracked.lines())
-        .map(git_unquote)
+        .into_iter()
+        .chain(untracked.lines().map(git_unquote))
         .collect();
 
     // Remove duplicates (shouldn't happen, but just in case)
@@ -884,6 +1504,74 @@ pub fn show_file(repo_path: &str, git_ref: &str, file_path: &str) -> Result<Stri
     git_cmd(repo_path, &["show", &object])
 }
 
+/// 单个文件历史中的一条提交
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileLogEntry {
+    pub hash: String,
+    pub author: String,
+    /// 提交时间（Unix 秒）
+    pub timestamp: i64,
+    pub time_ago: String,
+    pub message: String,
+    /// 该提交中文件的路径（跟随重命名，可能与当前路径不同）
+    pub path: String,
+}
+
+/// 获取触及某个路径的提交（最新在前，跟随重命名）
+/// `range` 为空时遍历 HEAD 的全部历史
+/// 执行: git log --follow --name-only -n {limit} {range} -- {path}
+pub fn file_log(
+    repo_path: &str,
+    path: &str,
+    range: Option<&str>,
+    limit: usize,
+) -> Result<Vec<FileLogEntry>> {
+    let n = format!("-{}", limit);
+    let mut args = vec![
+        "log",
+        "--follow",
+        "--name-only",
+        "--format=%x1e%H%x1f%an%x1f%at%x1f%cr%x1f%s",
+        n.as_str(),
+    ];
+    args.extend(range);
+    args.extend(["--", path]);
+    let output = git_cmd(repo_path, &args)?;
+    Ok(parse_file_log(&output, path))
+}
+
+fn parse_file_log(output: &str, path: &str) -> Vec<FileLogEntry> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut lines = record.lines();
+            let fields: Vec<&str> = lines.next()?.splitn(5, '\x1f').collect();
+            if fields.len() != 5 {
+                return None;
+            }
+            let file = lines.rfind(|l| !l.trim().is_empty()).unwrap_or(path);
+            Some(FileLogEntry {
+                hash: fields[0].to_string(),
+                author: fields[1].to_string(),
+                timestamp: fields[2].parse().unwrap_or(0),
+                time_ago: fields[3].to_string(),
+                message: fields[4].to_string(),
+                path: file.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 某个提交对单个文件的改动（unified diff，检测重命名）
+/// 执行: git show --format= --no-color -M {commit} -- {path}
+pub fn file_patch(repo_path: &str, commit: &str, path: &str) -> Result<String> {
+    let hash = resolve_commit(repo_path, commit)?;
+    git_cmd(
+        repo_path,
+        &["show", "--format=", "--no-color", "-M", &hash, "--", path],
+    )
+}
+
 /// 纯逻辑判断路径是否在基础目录内（不解析符号链接）
 ///
 /// 遍历 `path` 相对于 `base` 的各组件，遇到 `..` 时深度 -1，
@@ -997,6 +1685,68 @@ pub fn write_file(repo_path: &str, file_path: &str, content: &str) -> Result<()>
     Err(GroveError::git("Path traversal detected"))
 }
 
+/// 三方合并文本：`ours` 与 `theirs` 都从 `base` 修改而来
+/// 返回 (合并结果, 是否有冲突)；冲突处带有 `<<<<<<<` 标记
+/// 执行: git merge-file -p -L {ours_label} -L base -L {theirs_label} ours base theirs
+pub fn merge_text(
+    base: &str,
+    ours: &str,
+    theirs: &str,
+    ours_label: &str,
+    theirs_label: &str,
+) -> Result<(String, bool)> {
+    let dur = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    let dir = std::env::temp_dir().join(format!(
+        "grove_merge_{}_{}_{}",
+        std::process::id(),
+        dur.as_secs(),
+        dur.subsec_nanos()
+    ));
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| GroveError::git(format!("Failed to create temp dir: {}", e)))?;
+
+    let result = (|| {
+        for (name, content) in [("ours", ours), ("base", base), ("theirs", theirs)] {
+            std::fs::write(dir.join(name), content)
+                .map_err(|e| GroveError::git(format!("Failed to write temp file: {}", e)))?;
+        }
+        let output = Command::new("git")
+            .current_dir(&dir)
+            .args([
+                "merge-file",
+                "-p",
+                "-L",
+                ours_label,
+                "-L",
+                "base",
+                "-L",
+                theirs_label,
+                "ours",
+                "base",
+                "theirs",
+            ])
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+        // 退出码：0 = 无冲突，正数 = 冲突块数量，负数（>127）= 出错
+        match output.status.code() {
+            Some(code) if (0..128).contains(&code) => Ok((
+                String::from_utf8_lossy(&output.stdout).into_owned(),
+                code > 0,
+            )),
+            _ => Err(GroveError::git(format!(
+                "git merge-file failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))),
+        }
+    })();
+
+    let _ = std::fs::remove_dir_all(&dir);
+    result
+}
+
 /// 获取相对于 origin 的 commits ahead 数量
 /// 执行: git rev-list --count origin/{branch}..HEAD
 pub fn commits_ahead_of_origin(repo_path: &str) -> Result<Option<u32>> {
@@ -1015,6 +1765,22 @@ pub fn commits_ahead_of_origin(repo_path: &str) -> Result<Option<u32>> {
         .map_or(Ok(None), |n| Ok(Some(n)))
 }
 
+/// 本地分支落后 origin 的 commit 数（基于本地的 origin/* 引用，不 fetch）
+/// 执行: git rev-list --count {branch}..origin/{branch}
+/// origin/{branch} 不存在时返回 None
+pub fn commits_behind_origin(repo_path: &str, branch: &str) -> Result<Option<u32>> {
+    let origin_ref = format!("origin/{}", branch);
+    if !git_cmd_check(repo_path, &["rev-parse", "--verify", &origin_ref]) {
+        return Ok(None);
+    }
+
+    let range = format!("{}..{}", branch, origin_ref);
+    git_cmd(repo_path, &["rev-list", "--count", &range])?
+        .parse::<u32>()
+        .map(Some)
+        .map_err(|e| GroveError::git(format!("Failed to parse count: {}", e)))
+}
+
 /// 获取最近提交的相对时间
 /// 执行: git log -1 --format=%cr
 pub fn last_commit_time(repo_path: &str) -> Result<String> {
@@ -1052,28 +1818,32 @@ pub fn checkout_branch(worktree_path: &str, branch: &str) -> Result<()> {
 #[derive(Debug, Clone)]
 pub struct LogEntry {
     pub time_ago: String,
+    /// 提交时间（ISO 8601）
+    pub time: String,
     pub message: String,
 }
 
 /// 获取最近的 commit 日志
-/// 执行: git log --format="%cr\t%s" -n {count} {target}..HEAD
+/// 执行: git log --format="%cr\t%cI\t%s" -n {count} {target}..HEAD
 pub fn recent_log(worktree_path: &str, target: &str, count: usize) -> Result<Vec<LogEntry>> {
     let range = format!("{}..HEAD", target);
     let n = format!("-{}", count);
-    let output = git_cmd(worktree_path, &["log", "--format=%cr\t%s", &n, &range])?;
+    let output = git_cmd(worktree_path, &["log", "--format=%cr\t%cI\t%s", &n, &range])?;
     Ok(output
         .lines()
         .filter(|l| !l.is_empty())
         .map(|line| {
-            let parts: Vec<&str> = line.splitn(2, '\t').collect();
-            if parts.len() == 2 {
+            let parts: Vec<&str> = line.splitn(3, '\t').collect();
+            if parts.len() == 3 {
                 LogEntry {
                     time_ago: parts[0].to_string(),
-                    message: parts[1].to_string(),
+                    time: parts[1].to_string(),
+                    message: parts[2].to_string(),
                 }
             } else {
                 LogEntry {
                     time_ago: String::new(),
+                    time: String::new(),
                     message: line.to_string(),
                 }
             }
@@ -1081,6 +1851,13 @@ pub fn recent_log(worktree_path: &str, target: &str, count: usize) -> Result<Vec
         .collect())
 }
 
+/// 工作区相对 target 分叉点的完整 unified diff（含未提交改动）
+/// 执行: git diff --no-color $(git merge-base {target} HEAD)
+pub fn unified_diff(worktree_path: &str, target: &str) -> Result<String> {
+    let base = merge_base(worktree_path, target, "HEAD")?;
+    git_cmd(worktree_path, &["diff", "--no-color", &base])
+}
+
 /// 变更文件条目
 #[derive(Debug, Clone)]
 pub struct DiffStatEntry {
@@ -1091,36 +1868,118 @@ pub struct DiffStatEntry {
     pub is_binary: bool,
 }
 
-pub fn diff_stat(worktree_path: &str, target: &str) -> Result<Vec<DiffStatEntry>> {
-    let untracked = git_cmd(
-        worktree_path,
-        &["ls-files", "--others", "--exclude-standard"],
-    )?;
-    let untracked_set: std::collections::HashSet<String> = untracked
-        .lines()
-        .map(|l| git_unquote(l.trim()))
-        .filter(|p| !p.is_empty())
-        .collect();
+/// diff 选项：忽略空白 / 空行，rename / copy 检测
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DiffOptions {
+    /// `-w`：忽略所有空白差异
+    pub ignore_whitespace: bool,
+    /// `--ignore-blank-lines`
+    pub ignore_blank_lines: bool,
+    /// `-M`：检测 rename（关闭时传 `--no-renames`）
+    pub detect_renames: bool,
+    /// `-C`：检测 copy（隐含 rename 检测）
+    pub detect_copies: bool,
+}
+
+impl DiffOptions {
+    /// 转换为 `git diff` 参数
+    pub fn args(&self) -> Vec<&'static str> {
+        let mut args = Vec::new();
+        if self.ignore_whitespace {
+            args.push("-w");
+        }
+        if self.ignore_blank_lines {
+            args.push("--ignore-blank-lines");
+        }
+        args.push(if self.detect_copies {
+            "-C"
+        } else if self.detect_renames {
+            "-M"
+        } else {
+            "--no-renames"
+        });
+        args
+    }
+}
 
-    let numstat_res = git_cmd(worktree_path, &["diff", "--numstat", target]);
-    let name_status_res = git_cmd(worktree_path, &["diff", "--name-status", target]);
-    let numstat = numstat_res?;
-    let name_status = name_status_res?;
+/// numstat 中 rename 的路径形如 `old => new` 或 `dir/{old => new}/file`，取新路径
+fn numstat_path(raw: &str) -> String {
+    let raw = git_unquote(raw);
+    let Some(arrow) = raw.find(" => ") else {
+        return raw;
+    };
+    match (raw[..arrow].rfind('{'), raw[arrow..].find('}')) {
+        (Some(open), Some(close)) => {
+            let close = arrow + close;
+            let path = format!(
+                "{}{}{}",
+                &raw[..open],
+                &raw[arrow + 4..close],
+                &raw[close + 1..]
+            );
+            // `{ => sub}/f` 之类会留下双斜杠
+            path.replace("//", "/")
+        }
+        _ => raw[arrow + 4..].to_string(),
+    }
+}
 
-    let status_map: std::collections::HashMap<String, char> = name_status
+/// 解析 `--name-status` 输出为 path → 状态字符；rename / copy 取新路径
+fn parse_name_status(name_status: &str) -> std::collections::HashMap<String, char> {
+    name_status
         .lines()
         .filter_map(|line| {
             let parts: Vec<&str> = line.split('\t').collect();
             if parts.len() >= 2 {
                 Some((
-                    git_unquote(parts[1]),
+                    git_unquote(parts[parts.len() - 1]),
                     parts[0].chars().next().unwrap_or('M'),
                 ))
             } else {
                 None
             }
         })
+        .collect()
+}
+
+pub fn diff_stat(worktree_path: &str, target: &str) -> Result<Vec<DiffStatEntry>> {
+    diff_stat_args(worktree_path, target, &[])
+}
+
+/// 同 `diff_stat`，附带空白 / rename 选项
+pub fn diff_stat_with_options(
+    worktree_path: &str,
+    target: &str,
+    options: &DiffOptions,
+) -> Result<Vec<DiffStatEntry>> {
+    diff_stat_args(worktree_path, target, &options.args())
+}
+
+fn diff_stat_args(worktree_path: &str, target: &str, extra: &[&str]) -> Result<Vec<DiffStatEntry>> {
+    let untracked = git_cmd(
+        worktree_path,
+        &["ls-files", "--others", "--exclude-standard"],
+    )?;
+    let untracked_set: std::collections::HashSet<String> = untracked
+        .lines()
+        .map(|l| git_unquote(l.trim()))
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    let numstat_args: Vec<&str> = ["diff", "--numstat"]
+        .into_iter()
+        .chain(extra.iter().copied())
+        .chain([target])
+        .collect();
+    let name_status_args: Vec<&str> = ["diff", "--name-status"]
+        .into_iter()
+        .chain(extra.iter().copied())
+        .chain([target])
         .collect();
+    let numstat = git_cmd(worktree_path, &numstat_args)?;
+    let name_status = git_cmd(worktree_path, &name_status_args)?;
+
+    let status_map = parse_name_status(&name_status);
 
     let mut entries: Vec<DiffStatEntry> = numstat
         .lines()
@@ -1128,7 +1987,7 @@ pub fn diff_stat(worktree_path: &str, target: &str) -> Result<Vec<DiffStatEntry>
         .map(|line| {
             let parts: Vec<&str> = line.split('\t').collect();
             if parts.len() >= 3 {
-                let path = git_unquote(parts[2]);
+                let path = numstat_path(parts[2]);
                 let is_binary = parts[0].trim() == "-";
                 let status = status_map.get(&path).copied().unwrap_or('M');
                 DiffStatEntry {
@@ -1158,6 +2017,15 @@ pub fn diff_stat(worktree_path: &str, target: &str) -> Result<Vec<DiffStatEntry>
         })
         .collect();
 
+    // Sparse worktree：sparse 集合外、磁盘上也不存在的文件只是 target 自身的
+    // 变化（task 分支不可能改到它们），不算 task 的 diff
+    if let Some(paths) = sparse_checkout::sparse_paths(worktree_path) {
+        entries.retain(|e| {
+            sparse_checkout::in_sparse_set(&paths, &e.path)
+                || Path::new(worktree_path).join(&e.path).exists()
+        });
+    }
+
     // If a Deleted entry has a same-named untracked file on disk, the file was
     // replaced (e.g. git rm + codegen) — flip status to U so the UI shows it
     // as present, not gone.
@@ -1213,30 +2081,29 @@ pub fn diff_stat(worktree_path: &str, target: &str) -> Result<Vec<DiffStatEntry>
     Ok(entries)
 }
 
-/// 获取两个 ref 之间的变更文件统计（不含 working tree）
-pub fn diff_stat_range(
+/// 获取两个 ref 之间的变更文件统计（不含 working tree），附带空白 / rename 选项
+pub fn diff_stat_range_with_options(
     worktree_path: &str,
     from_ref: &str,
     to_ref: &str,
+    options: &DiffOptions,
 ) -> Result<Vec<DiffStatEntry>> {
+    let extra = options.args();
     let range = format!("{}..{}", from_ref, to_ref);
-    let numstat = git_cmd(worktree_path, &["diff", "--numstat", &range])?;
-    let name_status = git_cmd(worktree_path, &["diff", "--name-status", &range])?;
-
-    let status_map: std::collections::HashMap<String, char> = name_status
-        .lines()
-        .filter_map(|line| {
-            let parts: Vec<&str> = line.split('\t').collect();
-            if parts.len() >= 2 {
-                Some((
-                    git_unquote(parts[1]),
-                    parts[0].chars().next().unwrap_or('M'),
-                ))
-            } else {
-                None
-            }
-        })
+    let numstat_args: Vec<&str> = ["diff", "--numstat"]
+        .into_iter()
+        .chain(extra.iter().copied())
+        .chain([range.as_str()])
         .collect();
+    let name_status_args: Vec<&str> = ["diff", "--name-status"]
+        .into_iter()
+        .chain(extra.iter().copied())
+        .chain([range.as_str()])
+        .collect();
+    let numstat = git_cmd(worktree_path, &numstat_args)?;
+    let name_status = git_cmd(worktree_path, &name_status_args)?;
+
+    let status_map = parse_name_status(&name_status);
 
     Ok(numstat
         .lines()
@@ -1244,7 +2111,7 @@ pub fn diff_stat_range(
         .filter_map(|line| {
             let parts: Vec<&str> = line.split('\t').collect();
             if parts.len() >= 3 {
-                let path = git_unquote(parts[2]);
+                let path = numstat_path(parts[2]);
                 let is_binary = parts[0].trim() == "-";
                 let status = status_map.get(&path).copied().unwrap_or('M');
                 Some(DiffStatEntry {
@@ -1282,7 +2149,188 @@ pub fn stash_count(path: &str) -> Result<usize> {
         .map(|output| output.lines().filter(|l| !l.trim().is_empty()).count())
 }
 
-pub fn add_and_commit(worktree_path: &str, message: &str) -> Result<()> {
+/// stash 条目
+///
+/// refs/stash 由主仓库和所有 worktree 共享，`branch` 记录 stash 创建时所在的分支，
+/// 据此可以区分主仓库与各任务 worktree 里的 stash。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StashEntry {
+    /// `stash@{n}` 中的 n
+    pub index: usize,
+    /// stash commit hash
+    pub hash: String,
+    /// 创建 stash 时所在分支（detached HEAD 时为 None）
+    pub branch: Option<String>,
+    /// stash 说明（去掉 "WIP on <branch>:" / "On <branch>:" 前缀）
+    pub message: String,
+    /// 创建时间（unix 秒）
+    pub created_at: i64,
+}
+
+impl StashEntry {
+    /// `stash@{n}`
+    pub fn reference(&self) -> String {
+        format!("stash@{{{}}}", self.index)
+    }
+}
+
+/// 列出 stash（新 → 旧）
+/// 执行: git stash list --format=%H%x09%ct%x09%gs
+pub fn list_stashes(path: &str) -> Result<Vec<StashEntry>> {
+    git_cmd(path, &["stash", "list", "--format=%H%x09%ct%x09%gs"])
+        .map(|output| parse_stash_list(&output))
+}
+
+/// 解析 `hash \t unix \t subject`，subject 形如 "WIP on main: abc123 msg" / "On main: msg"
+fn parse_stash_list(output: &str) -> Vec<StashEntry> {
+    output
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let mut parts = line.splitn(3, '\t');
+            let hash = parts.next()?.trim().to_string();
+            let created_at = parts.next()?.trim().parse().unwrap_or(0);
+            let subject = parts.next().unwrap_or("").trim();
+            let rest = subject
+                .strip_prefix("WIP on ")
+                .or_else(|| subject.strip_prefix("On "));
+            let (branch, message) = match rest.and_then(|r| r.split_once(": ")) {
+                Some((branch, message)) if branch != "(no branch)" => {
+                    (Some(branch.to_string()), message.to_string())
+                }
+                Some((_, message)) => (None, message.to_string()),
+                None => (None, subject.to_string()),
+            };
+            Some(StashEntry {
+                index,
+                hash,
+                branch,
+                message,
+                created_at,
+            })
+        })
+        .collect()
+}
+
+/// stash 内容：`--stat` 摘要 + patch（含 untracked 部分）
+pub fn stash_show(path: &str, index: usize) -> Result<String> {
+    let reference = format!("stash@{{{}}}", index);
+    let mut out = git_cmd(path, &["stash", "show", "--stat", "-p", &reference])?;
+    // untracked 文件存放在 stash 的第三个父提交里
+    let untracked = format!("{}^3", reference);
+    if let Ok(extra) = git_cmd(path, &["show", "--format=", "--stat", "-p", &untracked]) {
+        if !extra.trim().is_empty() {
+            out.push_str("\n\n# untracked files\n");
+            out.push_str(&extra);
+        }
+    }
+    Ok(out)
+}
+
+/// 在 `path` 所在的工作区应用 stash；`pop` 为 true 时成功后删除该条目
+pub fn stash_apply(path: &str, index: usize, pop: bool) -> Result<()> {
+    let reference = format!("stash@{{{}}}", index);
+    let action = if pop { "pop" } else { "apply" };
+    git_cmd_unit(path, &["stash", action, &reference])
+}
+
+/// 删除 stash 条目
+pub fn stash_drop(path: &str, index: usize) -> Result<()> {
+    let reference = format!("stash@{{{}}}", index);
+    git_cmd_unit(path, &["stash", "drop", &reference])
+}
+
+/// 暂存区 diff 的原始输出（不 trim，patch 才能原样回放给 `git apply`）
+///
+/// `cached` 为 true 时是已暂存的改动（index ↔ HEAD），否则为未暂存的改动
+/// （worktree ↔ index）。`file` 为 None 时返回全部文件。
+pub fn index_diff_raw(path: &str, file: Option<&str>, cached: bool) -> Result<String> {
+    let mut args = vec!["diff", "--no-color", "--no-ext-diff", "-U3"];
+    if cached {
+        args.push("--cached");
+    }
+    if let Some(file) = file {
+        args.extend(["--", file]);
+    }
+    let output = Command::new("git")
+        .current_dir(path)
+        .args(&args)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(GroveError::git(format!(
+            "git diff failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// 把 patch 应用到 index（`reverse` 为 true 时撤销，即 unstage）
+/// 执行: git apply --cached [--reverse] -
+pub fn apply_to_index(path: &str, patch: &str, reverse: bool) -> Result<()> {
+    use std::io::Write;
+
+    let mut args = vec!["apply", "--cached", "--whitespace=nowarn"];
+    if reverse {
+        args.push("--reverse");
+    }
+    args.push("-");
+    let mut child = Command::new("git")
+        .current_dir(path)
+        .args(&args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(patch.as_bytes())?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| GroveError::git(format!("git apply failed: {}", e)))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(GroveError::git(format!(
+            "git apply failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// 文件是否未被追踪（且未被 ignore）
+pub fn is_untracked(path: &str, file: &str) -> Result<bool> {
+    git_cmd(
+        path,
+        &["ls-files", "--others", "--exclude-standard", "--", file],
+    )
+    .map(|out| !out.trim().is_empty())
+}
+
+/// 标记未追踪文件为 intent-to-add，让它出现在 `git diff` 里以便按 hunk 暂存
+/// 执行: git add -N -- <file>
+pub fn intent_to_add(path: &str, file: &str) -> Result<()> {
+    git_cmd_unit(path, &["add", "-N", "--", file])
+}
+
+/// 暂存整个文件
+/// 执行: git add -A -- <file>
+pub fn stage_file(path: &str, file: &str) -> Result<()> {
+    git_cmd_unit(path, &["add", "-A", "--", file])
+}
+
+/// 取消暂存整个文件（保留工作区改动）
+/// 执行: git reset -q -- <file>
+pub fn unstage_file(path: &str, file: &str) -> Result<()> {
+    git_cmd_unit(path, &["reset", "-q", "--", file])
+}
+
+pub fn add_and_commit(worktree_path: &str, message: &str, options: &CommitOptions) -> Result<()> {
     // 先 add
     git_cmd_unit(worktree_path, &["add", "-A"])?;
 
@@ -1292,7 +2340,7 @@ pub fn add_and_commit(worktree_path: &str, message: &str) -> Result<()> {
     }
 
     // 再 commit
-    git_cmd_unit(worktree_path, &["commit", "-m", message])
+    commit(worktree_path, message, options)
 }
 
 // ============================================================================
@@ -1331,26 +2379,40 @@ pub fn is_gitignored(repo_path: &str, file_path: &str) -> Result<bool> {
     }
 }
 
+/// 路径（文件或目录）下是否有被 git 跟踪的文件
+pub fn is_tracked(repo_path: &str, path: &str) -> bool {
+    git_cmd(repo_path, &["ls-files", "--", path])
+        .map(|out| !out.is_empty())
+        .unwrap_or(false)
+}
+
+/// 移动/重命名被跟踪的路径（暂存为 rename）
+/// 执行: git mv -- {source} {destination}
+pub fn move_path(repo_path: &str, source: &str, destination: &str) -> Result<()> {
+    git_cmd_unit(repo_path, &["mv", "--", source, destination])
+}
+
 /// 为 worktree 创建软链接
 ///
 /// # Arguments
 /// * `worktree_path` - 新创建的 worktree 路径
 /// * `main_repo_path` - 主仓库路径
-/// * `patterns` - Glob 模式列表（支持 **, *, ? 等）
+/// * `auto_link` - AutoLink 配置（Glob 模式、每个模式的创建方式、copy 大小上限）
 /// * `check_gitignore` - 是否检查 git ignore 状态
 ///
 /// # Returns
-/// 成功创建的软链接路径列表（相对于主仓库根目录）
+/// 成功创建的路径及其创建方式（路径相对于主仓库根目录）
 pub fn create_worktree_symlinks(
     worktree_path: &Path,
     main_repo_path: &Path,
-    patterns: &[String],
+    auto_link: &AutoLinkConfig,
     _check_gitignore: bool,
-) -> Result<Vec<String>> {
+) -> Result<Vec<(String, LinkMode)>> {
     use globset::{Glob, GlobSetBuilder};
     use std::collections::HashSet;
     use std::process::Command;
 
+    let patterns = &auto_link.patterns;
     if patterns.is_empty() {
         return Ok(Vec::new());
     }
@@ -1395,10 +2457,11 @@ pub fn create_worktree_symlinks(
     let mut linked_paths: HashSet<String> = HashSet::new();
 
     for path_str in ignored_paths {
-        // 检查是否匹配 glob
-        if !globset.is_match(&path_str) {
+        // 检查是否匹配 glob（多个模式匹配时取第一个模式的创建方式）
+        let Some(&pattern_idx) = globset.matches(&path_str).iter().min() else {
             continue;
-        }
+        };
+        let mode = auto_link.mode_for(&patterns[pattern_idx]);
 
         // 检查是否已经链接过
         if linked_paths.contains(&path_str) {
@@ -1433,42 +2496,97 @@ pub fn create_worktree_symlinks(
         if let Some(parent) = target.parent() {
             if !parent.exists() {
                 if let Err(e) = std::fs::create_dir_all(parent) {
-                    eprintln!(
-                        "Warning: Failed to create parent dir for '{}': {}",
-                        path_str, e
-                    );
+                    tracing::warn!("Failed to create parent dir for '{}': {}", path_str, e);
                     continue;
                 }
             }
         }
 
-        // 创建符号链接
-        let result = crate::fs_link::create_link(&source, &target);
-
-        match result {
+        // 按模式创建链接或副本
+        match materialize_link(&source, &target, mode, auto_link.copy_max_bytes()) {
             Ok(_) => {
-                created_links.push(path_str.clone());
+                created_links.push((path_str.clone(), mode));
                 linked_paths.insert(path_str);
             }
             Err(e) => {
-                eprintln!(
-                    "Warning: Failed to create symlink for '{}': {}",
-                    path_str, e
-                );
+                tracing::warn!("Failed to {} '{}': {}", mode.as_str(), path_str, e);
             }
         }
     }
 
     // 将符号链接写入 worktree 的 git exclude，防止被 git 追踪
     if !created_links.is_empty() {
-        if let Err(e) = add_to_worktree_exclude(worktree_path, &created_links) {
-            eprintln!("Warning: Failed to update git exclude: {}", e);
+        let paths: Vec<String> = created_links.iter().map(|(p, _)| p.clone()).collect();
+        if let Err(e) = add_to_worktree_exclude(worktree_path, &paths) {
+            tracing::warn!("Failed to update git exclude: {}", e);
         }
     }
 
     Ok(created_links)
 }
 
+/// 按创建方式在 `target` 处生成 `source` 的链接或副本
+///
+/// copy 模式会先统计源路径大小，超过 `copy_max_bytes` 时拒绝复制。
+pub fn materialize_link(
+    source: &Path,
+    target: &Path,
+    mode: LinkMode,
+    copy_max_bytes: u64,
+) -> Result<()> {
+    if mode == LinkMode::Copy {
+        let size = crate::fs_link::tree_stat(source)?.bytes;
+        if size > copy_max_bytes {
+            return Err(GroveError::invalid_data(format!(
+                "{} MB exceeds the AutoLink copy limit of {} MB",
+                size / (1024 * 1024),
+                copy_max_bytes / (1024 * 1024)
+            )));
+        }
+    }
+
+    let result = match mode {
+        LinkMode::Symlink => crate::fs_link::create_link(source, target),
+        LinkMode::Copy => crate::fs_link::copy_tree(source, target, false),
+        LinkMode::Hardlink => crate::fs_link::copy_tree(source, target, true),
+    };
+    // 复制中途失败时清理残留，避免下次被当作"已存在"跳过
+    if result.is_err() && mode != LinkMode::Symlink {
+        let _ = crate::fs_link::remove_tree(target);
+    }
+    Ok(result?)
+}
+
+/// AutoLink exclude 段的标记注释
+const AUTOLINK_EXCLUDE_MARKER: &str = "# Grove AutoLink excludes";
+
+/// 读取 autolink 记录过的符号链接路径（共享 git exclude 中标记之后的条目）
+pub fn autolink_paths(repo_path: &str) -> Vec<String> {
+    let Ok(git_dir) = git_cmd(repo_path, &["rev-parse", "--git-common-dir"]) else {
+        return Vec::new();
+    };
+    let git_dir_path = if Path::new(&git_dir).is_absolute() {
+        std::path::PathBuf::from(&git_dir)
+    } else {
+        Path::new(repo_path).join(&git_dir)
+    };
+    let content =
+        std::fs::read_to_string(git_dir_path.join("info").join("exclude")).unwrap_or_default();
+    parse_autolink_excludes(&content)
+}
+
+/// 解析 exclude 文件：标记之后的非空、非注释行
+fn parse_autolink_excludes(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .skip_while(|l| l.trim() != AUTOLINK_EXCLUDE_MARKER)
+        .skip(1)
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .map(str::to_string)
+        .collect()
+}
+
 /// 将 autolink 创建的符号链接路径写入共享的 git exclude 文件
 ///
 /// 写入 `<git-common-dir>/info/exclude`，该文件不会被提交。
@@ -1527,9 +2645,8 @@ fn add_to_worktree_exclude(worktree_path: &Path, paths: &[String]) -> Result<()>
     }
 
     // 写入标记注释（仅当之前没有时）
-    let marker = "# Grove AutoLink excludes";
-    if !existing.contains(marker) {
-        writeln!(file, "{}", marker)
+    if !existing.contains(AUTOLINK_EXCLUDE_MARKER) {
+        writeln!(file, "{}", AUTOLINK_EXCLUDE_MARKER)
             .map_err(|e| GroveError::git(format!("Failed to write exclude: {}", e)))?;
     }
 
@@ -1545,9 +2662,43 @@ fn add_to_worktree_exclude(worktree_path: &Path, paths: &[String]) -> Result<()>
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_file_log() {
+        let output =
+            "\x1eaaa\x1fAlice\x1f1700000000\x1f2 days ago\x1fRename: a\x1fb\n\nsrc/new.rs\n\
+                      \x1ebbb\x1fBob\x1f1690000000\x1f3 months ago\x1fAdd file\n\nsrc/old.rs\n";
+        let entries = parse_file_log(output, "src/new.rs");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].hash, "aaa");
+        assert_eq!(entries[0].message, "Rename: a\x1fb");
+        assert_eq!(entries[0].path, "src/new.rs");
+        assert_eq!(entries[1].author, "Bob");
+        assert_eq!(entries[1].timestamp, 1690000000);
+        assert_eq!(entries[1].path, "src/old.rs");
+    }
+
+    #[test]
+    fn test_merge_text() {
+        let base = "a\nb\nc\n";
+        let (merged, conflict) =
+            merge_text(base, "A\nb\nc\n", "a\nb\nC\n", "editor", "disk").unwrap();
+        assert_eq!(merged, "A\nb\nC\n");
+        assert!(!conflict);
+
+        let (merged, conflict) =
+            merge_text(base, "x\nb\nc\n", "y\nb\nc\n", "editor", "disk").unwrap();
+        assert!(conflict);
+        assert!(merged.contains("<<<<<<< editor"));
+        assert!(merged.contains(">>>>>>> disk"));
+    }
+
     #[test]
     fn test_build_commit_message_with_notes() {
-        let msg = build_commit_message("Add feature", Some("This is a note\nWith multiple lines"));
+        let msg = build_commit_message(
+            "Add feature",
+            Some("This is a note\nWith multiple lines"),
+            None,
+        );
         assert_eq!(
             msg,
             "Add feature\n\n## Notes\n\nThis is a note\nWith multiple lines"
@@ -1556,24 +2707,146 @@ mod tests {
 
     #[test]
     fn test_build_commit_message_no_notes() {
-        assert_eq!(build_commit_message("Add feature", None), "Add feature");
+        assert_eq!(
+            build_commit_message("Add feature", None, None),
+            "Add feature"
+        );
     }
 
     #[test]
     fn test_build_commit_message_empty_notes() {
-        assert_eq!(build_commit_message("Add feature", Some("")), "Add feature");
         assert_eq!(
-            build_commit_message("Add feature", Some("  \n  ")),
+            build_commit_message("Add feature", Some(""), None),
+            "Add feature"
+        );
+        assert_eq!(
+            build_commit_message("Add feature", Some("  \n  "), None),
             "Add feature"
         );
     }
 
     #[test]
     fn test_build_commit_message_trims_notes() {
-        let msg = build_commit_message("Title", Some("\n  content here  \n\n"));
+        let msg = build_commit_message("Title", Some("\n  content here  \n\n"), None);
         assert_eq!(msg, "Title\n\n## Notes\n\ncontent here");
     }
 
+    #[test]
+    fn test_build_commit_message_with_review() {
+        assert_eq!(
+            build_commit_message("Title", Some("note"), Some("- Comments: 2\n")),
+            "Title\n\n## Notes\n\nnote\n\n## Review\n\n- Comments: 2"
+        );
+        assert_eq!(
+            build_commit_message("Title", None, Some("- Comments: 2")),
+            "Title\n\n## Review\n\n- Comments: 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_signature_statuses() {
+        let statuses = parse_signature_statuses("aaa\tG\nbbb\tN\nccc\tE\nddd\tY");
+        assert_eq!(statuses.get("aaa"), Some(&SignatureStatus::Good));
+        assert_eq!(statuses.get("bbb"), None);
+        assert_eq!(statuses.get("ccc"), Some(&SignatureStatus::Unverified));
+        assert_eq!(statuses.get("ddd"), Some(&SignatureStatus::Expired));
+    }
+
+    #[test]
+    fn test_grove_trailers_round_trip() {
+        let options = CommitOptions {
+            trailers: vec![
+                format!("{}: fix-login", TASK_TRAILER),
+                format!("{}: chat-abc123", CHAT_TRAILER),
+            ],
+            ..Default::default()
+        };
+        let message = options.message(&build_commit_message("Fix login", Some("notes"), None));
+        assert!(message.ends_with("notes\n\nGrove-Task: fix-login\nGrove-Chat: chat-abc123"));
+        assert_eq!(
+            parse_grove_trailers(&message),
+            GroveTrailers {
+                task_id: Some("fix-login".to_string()),
+                chat_id: Some("chat-abc123".to_string()),
+            }
+        );
+
+        // Only the final trailer block counts
+        assert!(parse_grove_trailers("Grove-Task: x").is_empty());
+        assert!(parse_grove_trailers("Title\n\nGrove-Task: x\n\nbody text").is_empty());
+        assert_eq!(
+            parse_grove_trailers("T\n\nSigned-off-by: A <a@b>\ngrove-task: y")
+                .task_id
+                .as_deref(),
+            Some("y")
+        );
+    }
+
+    #[test]
+    fn test_parse_stash_list() {
+        let output = "aaa\t1700000000\tWIP on main: 1234567 init\n\
+                      bbb\t1700000100\tOn grove/fix-login: half done: part 1\n\
+                      ccc\t1700000200\tWIP on (no branch): 89abcde detached\n";
+        let stashes = parse_stash_list(output);
+        assert_eq!(stashes.len(), 3);
+        assert_eq!(stashes[0].reference(), "stash@{0}");
+        assert_eq!(stashes[0].branch.as_deref(), Some("main"));
+        assert_eq!(stashes[0].message, "1234567 init");
+        assert_eq!(stashes[1].index, 1);
+        assert_eq!(stashes[1].branch.as_deref(), Some("grove/fix-login"));
+        assert_eq!(stashes[1].message, "half done: part 1");
+        assert_eq!(stashes[1].created_at, 1700000100);
+        assert_eq!(stashes[2].branch, None);
+        assert_eq!(stashes[2].message, "89abcde detached");
+    }
+
+    #[test]
+    fn test_parse_branch_details() {
+        let output = "refs/heads/feat/x\tfeat/x\t1700000000\t3 1\n\
+                      refs/remotes/origin/HEAD\torigin\t1700000001\n\
+                      refs/heads/main\tmain\t1690000000";
+        let details = parse_branch_details(output);
+        assert_eq!(details.len(), 2);
+        assert_eq!(details[0].name, "feat/x");
+        assert_eq!(details[0].last_commit_at, Some(1700000000));
+        assert_eq!((details[0].ahead, details[0].behind), (Some(3), Some(1)));
+        assert_eq!(details[1].name, "main");
+        assert_eq!(details[1].ahead, None);
+        assert_eq!(parse_ahead_behind("0\t12"), Some((0, 12)));
+    }
+
+    #[test]
+    fn test_parse_worktree_list() {
+        let output = "worktree /repo\nHEAD aaa\nbranch refs/heads/main\n\n\
+                      worktree /tmp/feature\nHEAD bbb\nbranch refs/heads/feature/x\n\n\
+                      worktree /tmp/detached\nHEAD ccc\ndetached\n\n\
+                      worktree /tmp/gone\nHEAD ddd\nbranch refs/heads/old\nprunable gitdir file points to non-existent location";
+        let entries = parse_worktree_list(output);
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[0].path, "/repo");
+        assert_eq!(entries[0].branch.as_deref(), Some("main"));
+        assert_eq!(entries[1].branch.as_deref(), Some("feature/x"));
+        assert_eq!(entries[1].head, "bbb");
+        assert_eq!(entries[2].branch, None);
+        assert!(!entries[2].prunable);
+        assert!(entries[3].prunable);
+    }
+
+    #[test]
+    fn test_parse_autolink_excludes() {
+        let content = "# git ls-files --others --exclude-from=.git/info/exclude\n\
+                       *.log\n\
+                       # Grove AutoLink excludes\n\
+                       node_modules\n\
+                       \n\
+                       .env\n";
+        assert_eq!(
+            parse_autolink_excludes(content),
+            vec!["node_modules", ".env"]
+        );
+        assert!(parse_autolink_excludes("*.log\n").is_empty());
+    }
+
     #[test]
     fn test_git_unquote_plain() {
         assert_eq!(git_unquote("README.md"), "README.md");
@@ -1596,4 +2869,47 @@ mod tests {
             "03-Proxy层设计.md"
         );
     }
+
+    #[test]
+    fn test_parse_compare_refs() {
+        let refs = parse_compare_refs(
+            "refs/heads/main\nrefs/heads/grove/task-a\nrefs/tags/v1.0\nrefs/remotes/origin/HEAD\nrefs/remotes/origin/main\n",
+        );
+        assert_eq!(
+            refs,
+            vec![
+                ("branch", "main".to_string()),
+                ("branch", "grove/task-a".to_string()),
+                ("tag", "v1.0".to_string()),
+                ("remote", "origin/main".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_commit_rejects_options() {
+        assert!(resolve_commit(".", "--output=/tmp/x").is_err());
+        assert!(resolve_commit(".", "  ").is_err());
+    }
+
+    #[test]
+    fn test_numstat_rename_paths() {
+        assert_eq!(numstat_path("src/main.rs"), "src/main.rs");
+        assert_eq!(numstat_path("old.rs => new.rs"), "new.rs");
+        assert_eq!(numstat_path("src/{a => b}/mod.rs"), "src/b/mod.rs");
+        assert_eq!(numstat_path("src/{ => nested}/mod.rs"), "src/nested/mod.rs");
+        assert_eq!(numstat_path("src/{old.rs => new.rs}"), "src/new.rs");
+    }
+
+    #[test]
+    fn test_diff_options_args() {
+        assert_eq!(DiffOptions::default().args(), vec!["--no-renames"]);
+        let opts = DiffOptions {
+            ignore_whitespace: true,
+            ignore_blank_lines: true,
+            detect_renames: true,
+            detect_copies: true,
+        };
+        assert_eq!(opts.args(), vec!["-w", "--ignore-blank-lines", "-C"]);
+    }
 }
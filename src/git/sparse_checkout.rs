@@ -0,0 +1,231 @@
+//! Sparse checkout：只检出仓库的一部分路径到 task worktree
+//!
+//! 大型 monorepo 为每个 task 完整检出一份 worktree 既慢又占空间。这里用 git 的
+//! non-cone sparse-checkout：根目录下的文件始终检出（构建配置等），其余只检出
+//! 用户选择的路径。pattern 写在 worktree 自己的 `info/sparse-checkout` 里
+//! （git 自动开启 `extensions.worktreeConfig`），不影响主仓库和其他 worktree。
+
+use std::path::Path;
+
+use super::{git_cmd, git_cmd_unit};
+use crate::error::{GroveError, Result};
+
+/// 根目录文件始终检出
+const ROOT_PATTERNS: [&str; 2] = ["/*", "!/*/"];
+
+fn is_glob(path: &str) -> bool {
+    path.contains(['*', '?', '['])
+}
+
+/// 规范化用户输入的路径：去掉首尾空白、`./`、前后 `/`，去重；拒绝 `..`
+pub fn normalize_paths(paths: &[String]) -> Result<Vec<String>> {
+    let mut out: Vec<String> = Vec::new();
+    for raw in paths {
+        let mut path = raw.trim();
+        while let Some(rest) = path.strip_prefix("./") {
+            path = rest;
+        }
+        let path = path.trim_matches('/');
+        if path.is_empty() {
+            continue;
+        }
+        if path.split('/').any(|part| part == "..") {
+            return Err(GroveError::invalid_data(format!(
+                "Sparse path must stay inside the repository: {}",
+                raw.trim()
+            )));
+        }
+        if !out.iter().any(|p| p == path) {
+            out.push(path.to_string());
+        }
+    }
+    Ok(out)
+}
+
+/// 路径 → non-cone pattern（锚定到仓库根，同时匹配文件和目录）
+pub fn to_patterns(paths: &[String]) -> Vec<String> {
+    ROOT_PATTERNS
+        .iter()
+        .map(|p| p.to_string())
+        .chain(paths.iter().map(|p| format!("/{}", p)))
+        .collect()
+}
+
+/// pattern → 路径（`to_patterns` 的逆操作，忽略根目录 pattern）
+fn from_patterns(patterns: &str) -> Vec<String> {
+    patterns
+        .lines()
+        .map(str::trim)
+        .filter(|p| !p.is_empty() && !ROOT_PATTERNS.contains(p) && !p.starts_with('#'))
+        .map(|p| p.trim_matches('/').to_string())
+        .collect()
+}
+
+/// `file`（相对仓库根）是否在 sparse 路径集合内；空集合表示完整检出
+pub fn in_sparse_set(paths: &[String], file: &str) -> bool {
+    if paths.is_empty() || !file.contains('/') {
+        return true;
+    }
+    paths.iter().any(|path| {
+        if is_glob(path) {
+            globset::Glob::new(path)
+                .map(|g| g.compile_matcher().is_match(file))
+                .unwrap_or(false)
+        } else {
+            file == path
+                || file
+                    .strip_prefix(path.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+        }
+    })
+}
+
+/// 创建只检出 `paths` 的 worktree（新分支 `branch` 基于 `base_branch`）。
+/// 失败时清理已创建的 worktree 和分支。
+pub fn create_sparse_worktree(
+    repo_path: &str,
+    branch: &str,
+    worktree_path: &Path,
+    base_branch: &str,
+    paths: &[String],
+) -> Result<()> {
+    let wt = worktree_path.to_str().unwrap_or_default();
+    git_cmd_unit(
+        repo_path,
+        &[
+            "worktree",
+            "add",
+            "--no-checkout",
+            "-b",
+            branch,
+            wt,
+            base_branch,
+        ],
+    )?;
+    let populate = set_paths(wt, paths).and_then(|_| git_cmd_unit(wt, &["checkout"]));
+    if let Err(e) = populate {
+        let _ = git_cmd_unit(repo_path, &["worktree", "remove", "--force", wt]);
+        let _ = git_cmd_unit(repo_path, &["branch", "-D", branch]);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// 修改 worktree 的 sparse 路径；空列表关闭 sparse-checkout（恢复完整检出）
+pub fn set_paths(worktree_path: &str, paths: &[String]) -> Result<()> {
+    if paths.is_empty() {
+        return disable(worktree_path);
+    }
+    let patterns = to_patterns(paths);
+    let mut args = vec!["sparse-checkout", "set", "--no-cone", "--"];
+    args.extend(patterns.iter().map(String::as_str));
+    git_cmd_unit(worktree_path, &args)
+}
+
+/// 关闭 sparse-checkout（已关闭时直接成功）
+pub fn disable(worktree_path: &str) -> Result<()> {
+    if !is_sparse(worktree_path) {
+        return Ok(());
+    }
+    git_cmd_unit(worktree_path, &["sparse-checkout", "disable"])
+}
+
+/// worktree 是否启用了 sparse-checkout
+pub fn is_sparse(worktree_path: &str) -> bool {
+    git_cmd(worktree_path, &["config", "--get", "core.sparseCheckout"])
+        .map(|v| v == "true")
+        .unwrap_or(false)
+}
+
+/// worktree 的 sparse 路径；未启用时返回 None
+pub fn sparse_paths(worktree_path: &str) -> Option<Vec<String>> {
+    if !is_sparse(worktree_path) {
+        return None;
+    }
+    git_cmd(worktree_path, &["sparse-checkout", "list"])
+        .ok()
+        .map(|out| from_patterns(&out))
+}
+
+/// 实际检出的 tracked 文件（去掉 skip-worktree，即 sparse 集合外的文件）
+pub fn checked_out_files(repo_path: &str) -> Result<Vec<String>> {
+    let out = git_cmd(repo_path, &["ls-files", "-t"])?;
+    Ok(out
+        .lines()
+        .filter(|line| !line.starts_with("S "))
+        .filter_map(|line| line.get(2..))
+        .map(super::git_unquote)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[test]
+    fn paths_roundtrip_through_patterns() {
+        let paths = normalize_paths(&[
+            " ./services/api/ ".to_string(),
+            "/libs/*".to_string(),
+            "services/api".to_string(),
+            "".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(paths, vec!["services/api", "libs/*"]);
+        assert!(normalize_paths(&["../outside".to_string()]).is_err());
+
+        let patterns = to_patterns(&paths);
+        assert_eq!(patterns, vec!["/*", "!/*/", "/services/api", "/libs/*"]);
+        assert_eq!(from_patterns(&patterns.join("\n")), paths);
+
+        assert!(in_sparse_set(&paths, "Cargo.toml"));
+        assert!(in_sparse_set(&paths, "services/api/main.rs"));
+        assert!(!in_sparse_set(&paths, "services/api2/main.rs"));
+        assert!(in_sparse_set(&paths, "libs/core"));
+        assert!(!in_sparse_set(&paths, "web/index.ts"));
+        assert!(in_sparse_set(&[], "web/index.ts"));
+    }
+
+    #[test]
+    fn sparse_worktree_checks_out_selected_paths() {
+        let root = std::env::temp_dir().join("grove-sparse-checkout");
+        let _ = std::fs::remove_dir_all(&root);
+        let repo = root.join("repo");
+        for dir in ["a/x", "b"] {
+            std::fs::create_dir_all(repo.join(dir)).unwrap();
+        }
+        std::fs::write(repo.join("README"), "r").unwrap();
+        std::fs::write(repo.join("a/x/f"), "1").unwrap();
+        std::fs::write(repo.join("b/g"), "2").unwrap();
+        let run = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(&repo)
+                .output()
+                .unwrap();
+        };
+        run(&["init", "-q", "-b", "main"]);
+        run(&["config", "user.email", "t@e"]);
+        run(&["config", "user.name", "t"]);
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "init"]);
+
+        let repo_str = repo.to_str().unwrap();
+        let wt = root.join("wt");
+        let wt_str = wt.to_str().unwrap();
+        create_sparse_worktree(repo_str, "task", &wt, "main", &["a".to_string()]).unwrap();
+        assert!(wt.join("README").exists());
+        assert!(wt.join("a/x/f").exists());
+        assert!(!wt.join("b").exists());
+        assert_eq!(sparse_paths(wt_str), Some(vec!["a".to_string()]));
+        assert_eq!(checked_out_files(wt_str).unwrap(), vec!["README", "a/x/f"]);
+        // 主仓库不受影响
+        assert_eq!(sparse_paths(repo_str), None);
+
+        set_paths(wt_str, &[]).unwrap();
+        assert!(wt.join("b/g").exists());
+        assert_eq!(sparse_paths(wt_str), None);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
@@ -7,9 +7,10 @@ use std::collections::{HashMap, HashSet};
 use std::fs;
 #[cfg(any(target_os = "macos", target_os = "windows", target_os = "linux"))]
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Stdio};
 
 use crate::error::Result;
+use crate::storage::config::HookCommands;
 use crate::storage::{database, tasks, workspace::project_hash};
 
 /// 通知级别
@@ -237,15 +238,19 @@ pub fn update_hook(
     message: Option<String>,
     chat_id: Option<String>,
 ) {
+    run_level_command(
+        project_key,
+        task_id,
+        level,
+        message.as_deref(),
+        chat_id.as_deref(),
+    );
+
     let mut hooks = load_hooks(project_key);
     hooks.update(task_id, level, message.clone(), chat_id);
     match save_hooks(project_key, &hooks) {
         Ok(()) => {
-            let level_str = match level {
-                NotificationLevel::Notice => "notice",
-                NotificationLevel::Warn => "warn",
-                NotificationLevel::Critical => "critical",
-            };
+            let level_str = level_to_str(level);
             crate::api::handlers::walkie_talkie::broadcast_radio_event(
                 crate::api::handlers::walkie_talkie::RadioEvent::HookAdded {
                     project_id: project_key.to_string(),
@@ -256,14 +261,187 @@ pub fn update_hook(
             );
         }
         Err(e) => {
-            eprintln!(
+            tracing::warn!(
                 "hooks: failed to save hook for {}/{}: {}",
-                project_key, task_id, e
+                project_key,
+                task_id,
+                e
             );
         }
     }
 }
 
+/// 执行 `[hooks.commands]` 中该级别配置的命令（后台运行，不等待）。
+///
+/// 事件通过环境变量传给命令：`GROVE_HOOK_LEVEL`、`GROVE_HOOK_MESSAGE`、
+/// `GROVE_HOOK_TIMESTAMP`、`GROVE_PROJECT_ID`、`GROVE_PROJECT_NAME`、
+/// `GROVE_TASK_ID`、`GROVE_TASK_NAME`、`GROVE_CHAT_ID`。
+pub fn run_level_command(
+    project_key: &str,
+    task_id: &str,
+    level: NotificationLevel,
+    message: Option<&str>,
+    chat_id: Option<&str>,
+) {
+    let commands = crate::storage::config::load_config().hooks.commands;
+    let Some(command) = level_command(&commands, level) else {
+        return;
+    };
+
+    let project_name = crate::storage::workspace::load_project_by_hash(project_key)
+        .ok()
+        .flatten()
+        .map(|p| p.name)
+        .unwrap_or_default();
+    let task_name = tasks::get_task(project_key, task_id)
+        .ok()
+        .flatten()
+        .map(|t| t.name)
+        .unwrap_or_default();
+
+    let mut cmd = shell_command(command);
+    cmd.env("GROVE_HOOK_LEVEL", level_to_str(level))
+        .env("GROVE_HOOK_MESSAGE", message.unwrap_or(""))
+        .env("GROVE_HOOK_TIMESTAMP", Utc::now().to_rfc3339())
+        .env("GROVE_PROJECT_ID", project_key)
+        .env("GROVE_PROJECT_NAME", project_name)
+        .env("GROVE_TASK_ID", task_id)
+        .env("GROVE_TASK_NAME", task_name)
+        .env("GROVE_CHAT_ID", chat_id.unwrap_or(""))
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+
+    match cmd.spawn() {
+        // 后台回收子进程，避免长驻进程（grove web）里留下僵尸进程
+        Ok(mut child) => {
+            std::thread::spawn(move || {
+                let _ = child.wait();
+            });
+        }
+        Err(e) => tracing::warn!(
+            "hooks: failed to run {} command: {}",
+            level_to_str(level),
+            e
+        ),
+    }
+}
+
+/// 该级别配置的命令（未配置或空白返回 None）
+fn level_command(commands: &HookCommands, level: NotificationLevel) -> Option<&str> {
+    let command = match level {
+        NotificationLevel::Notice => &commands.notice,
+        NotificationLevel::Warn => &commands.warn,
+        NotificationLevel::Critical => &commands.critical,
+    };
+    command.as_deref().filter(|c| !c.trim().is_empty())
+}
+
+#[cfg(windows)]
+pub(crate) fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.args(["/C", command]);
+    cmd
+}
+
+#[cfg(not(windows))]
+pub(crate) fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", command]);
+    cmd
+}
+
+/// 带超时执行的 shell 命令结果
+pub(crate) struct ShellOutput {
+    pub status: std::process::ExitStatus,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl ShellOutput {
+    /// 失败时展示的输出：优先 stderr，只保留末尾 max 字节
+    pub fn error_tail(&self, max: usize) -> &str {
+        let text = if self.stderr.trim().is_empty() {
+            &self.stdout
+        } else {
+            &self.stderr
+        };
+        let start = text.len().saturating_sub(max);
+        let start = (start..text.len())
+            .find(|i| text.is_char_boundary(*i))
+            .unwrap_or(text.len());
+        text[start..].trim()
+    }
+}
+
+/// 在 dir 中执行 shell 命令并收集 stdout / stderr；超时则 kill 并返回 None
+pub(crate) fn run_shell_with_timeout(
+    dir: &std::path::Path,
+    command: &str,
+    timeout: std::time::Duration,
+) -> std::io::Result<Option<ShellOutput>> {
+    let mut cmd = shell_command(command);
+    cmd.current_dir(dir);
+    run_with_timeout(cmd, timeout)
+}
+
+/// 执行已配置好（目录、环境变量）的命令并收集输出；超时则 kill 并返回 None
+pub(crate) fn run_with_timeout(
+    mut cmd: Command,
+    timeout: std::time::Duration,
+) -> std::io::Result<Option<ShellOutput>> {
+    use std::io::Read;
+    use std::time::Instant;
+
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // 两个管道各用一个线程读完，避免输出过多时子进程阻塞在写管道上
+    let read_all = |pipe: Option<Box<dyn Read + Send>>| {
+        std::thread::spawn(move || {
+            let mut out = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut out);
+            }
+            String::from_utf8_lossy(&out).into_owned()
+        })
+    };
+    let stdout = read_all(
+        child
+            .stdout
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    );
+    let stderr = read_all(
+        child
+            .stderr
+            .take()
+            .map(|p| Box::new(p) as Box<dyn Read + Send>),
+    );
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(None);
+        }
+        std::thread::sleep(std::time::Duration::from_millis(200));
+    };
+
+    Ok(Some(ShellOutput {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    }))
+}
+
 /// 加载 hooks 并自动清理不存在的 task
 /// project_path: 项目的完整路径
 pub fn load_hooks_with_cleanup(project_path: &str) -> HooksFile {
@@ -307,6 +485,7 @@ pub fn load_hooks_with_cleanup(project_path: &str) -> HooksFile {
 
 // === Notification utilities (shared by CLI hooks and ACP) ===
 
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
 pub static ACTIVE_BASE_URL: once_cell::sync::OnceCell<String> = once_cell::sync::OnceCell::new();
 
 #[cfg(feature = "gui")]
@@ -319,6 +498,7 @@ pub fn set_active_base_url(url: String) {
     }
 }
 
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
 pub fn get_active_base_url() -> String {
     if let Some(url) = ACTIVE_BASE_URL.get() {
         return url.clone();
@@ -334,6 +514,14 @@ pub fn get_active_base_url() -> String {
     "http://127.0.0.1:3001".to_string()
 }
 
+/// 当前是否处于勿扰状态（手动开启或落在定时勿扰时段）。
+/// 勿扰只跳过 `play_sound` / `send_banner`，通知仍写入 hooks.toml 和 Inbox。
+pub fn notifications_muted() -> bool {
+    crate::storage::config::load_config()
+        .notifications
+        .is_muted_at(chrono::Local::now().time())
+}
+
 /// Play a system sound.
 #[cfg(target_os = "macos")]
 pub fn play_sound(sound: &str) {
@@ -449,8 +637,8 @@ $toast = [Windows.UI.Notifications.ToastNotification]::new($xml)
         })
         .unwrap_or_else(|_| "powershell".to_string());
 
-    // Capture stderr to a log file so toast failures aren't completely silent —
-    // and don't use eprintln!, which would scribble onto the TUI's alternate screen.
+    // Capture stderr and log it so toast failures aren't completely silent
+    // (tracing is file-only under the TUI, so this never hits the alternate screen).
     match Command::new(&ps_exe)
         .args(["-NoProfile", "-Command", &script])
         .stdout(std::process::Stdio::null())
@@ -462,40 +650,22 @@ $toast = [Windows.UI.Notifications.ToastNotification]::new($xml)
             std::thread::spawn(move || {
                 if let Ok(output) = child.wait_with_output() {
                     if !output.status.success() && !output.stderr.is_empty() {
-                        log_notify_error(&format!(
+                        tracing::warn!(
                             "toast notification failed: {}",
                             String::from_utf8_lossy(&output.stderr).trim()
-                        ));
+                        );
                     }
                 }
             });
         }
         Err(e) => {
-            log_notify_error(&format!("failed to spawn powershell for toast: {}", e));
+            tracing::warn!("failed to spawn powershell for toast: {}", e);
         }
     }
 }
 
-/// Append a notification-related error to `~/.grove/logs/notify.log`.
-/// Used in lieu of eprintln! so messages don't corrupt the TUI alternate screen.
-#[cfg(target_os = "windows")]
-fn log_notify_error(msg: &str) {
-    use std::io::Write;
-    let log_dir = crate::storage::grove_dir().join("logs");
-    if fs::create_dir_all(&log_dir).is_err() {
-        return;
-    }
-    let log_path = log_dir.join("notify.log");
-    if let Ok(mut f) = std::fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_path)
-    {
-        let _ = writeln!(f, "{} {}", chrono::Utc::now().to_rfc3339(), msg);
-    }
-}
-
 #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+#[allow(clippy::too_many_arguments)]
 pub fn send_banner(
     title: &str,
     message: &str,
@@ -884,6 +1054,21 @@ mod tests {
         let _ = std::fs::remove_dir_all(temp_home);
     }
 
+    #[test]
+    fn test_level_command_skips_blank() {
+        let commands = HookCommands {
+            notice: Some("  ".to_string()),
+            warn: None,
+            critical: Some("say critical".to_string()),
+        };
+        assert_eq!(level_command(&commands, NotificationLevel::Notice), None);
+        assert_eq!(level_command(&commands, NotificationLevel::Warn), None);
+        assert_eq!(
+            level_command(&commands, NotificationLevel::Critical),
+            Some("say critical")
+        );
+    }
+
     #[test]
     fn test_update_keeps_existing_higher_level() {
         let mut hooks = HooksFile::default();
@@ -0,0 +1,223 @@
+//! Structured logging
+//!
+//! Everything goes through `tracing`. One subscriber is installed per process:
+//! - daily-rolling `~/.grove/logs/grove.<date>.log` (last 7 days kept)
+//! - an in-memory ring buffer of recent entries, served by `GET /api/v1/logs`
+//!   and the TUI log viewer (`L`)
+//! - stderr, except in the TUI where it would scribble over the alternate screen
+//!
+//! The filter uses `EnvFilter` directives (e.g. `info,grove::acp=debug`) and
+//! is resolved from `GROVE_LOG`, then `[logging] filter` in config.toml, then
+//! [`DEFAULT_FILTER`].
+
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+use crate::storage;
+
+/// Env var holding filter directives; overrides config.toml.
+pub const LOG_ENV: &str = "GROVE_LOG";
+
+/// Filter used when neither env nor config specify one.
+pub const DEFAULT_FILTER: &str = "info";
+
+/// How many entries the in-memory buffer keeps.
+const RECENT_CAPACITY: usize = 1000;
+
+/// Rolled log files kept on disk.
+const MAX_LOG_FILES: usize = 7;
+
+static RECENT: Lazy<Mutex<VecDeque<LogEntry>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(RECENT_CAPACITY)));
+
+/// One captured log event.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEntry {
+    /// RFC 3339 local time
+    pub timestamp: String,
+    /// "ERROR" | "WARN" | "INFO" | "DEBUG" | "TRACE"
+    pub level: String,
+    /// Module path of the call site (e.g. `grove::acp`)
+    pub target: String,
+    pub message: String,
+}
+
+/// ~/.grove/logs/
+pub fn logs_dir() -> PathBuf {
+    storage::grove_dir().join("logs")
+}
+
+/// Install the global subscriber. `stderr` mirrors entries to stderr (off for
+/// the TUI). Safe to call more than once — later calls are no-ops.
+pub fn init(stderr: bool) {
+    let file_layer = file_appender().map(|appender| {
+        tracing_subscriber::fmt::layer()
+            .with_writer(appender)
+            .with_ansi(false)
+    });
+    let stderr_layer = stderr.then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_ansi(false)
+            .without_time()
+    });
+
+    let registry = tracing_subscriber::registry()
+        .with(build_filter())
+        .with(RecentLogLayer)
+        .with(file_layer)
+        .with(stderr_layer);
+    #[cfg(feature = "perf-monitor")]
+    let registry = registry.with(crate::api::perf_tracing::PerfTraceLayer);
+    let _ = registry.try_init();
+}
+
+/// Resolve filter directives: env → config → default. Invalid directives fall
+/// back to the default rather than silencing logging.
+fn build_filter() -> EnvFilter {
+    let directives = std::env::var(LOG_ENV)
+        .ok()
+        .filter(|s| !s.trim().is_empty())
+        .or_else(|| storage::config::load_config().logging.filter)
+        .unwrap_or_else(|| DEFAULT_FILTER.to_string());
+    EnvFilter::try_new(&directives).unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER))
+}
+
+fn file_appender() -> Option<RollingFileAppender> {
+    RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("grove")
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(logs_dir())
+        .ok()
+}
+
+/// Most recent entries (oldest first), optionally only those at `min_level`
+/// or more severe, capped at `limit`.
+pub fn recent(limit: usize, min_level: Option<Level>) -> Vec<LogEntry> {
+    let buf = RECENT.lock().unwrap_or_else(|e| e.into_inner());
+    let mut entries: Vec<LogEntry> = buf
+        .iter()
+        .rev()
+        .filter(|e| match min_level {
+            // tracing orders ERROR < WARN < ... < TRACE
+            Some(min) => e.level.parse::<Level>().is_ok_and(|l| l <= min),
+            None => true,
+        })
+        .take(limit)
+        .cloned()
+        .collect();
+    entries.reverse();
+    entries
+}
+
+fn push_recent(entry: LogEntry) {
+    let mut buf = RECENT.lock().unwrap_or_else(|e| e.into_inner());
+    if buf.len() >= RECENT_CAPACITY {
+        buf.pop_front();
+    }
+    buf.push_back(entry);
+}
+
+/// Layer that copies every event into [`RECENT`].
+struct RecentLogLayer;
+
+impl<S: Subscriber> Layer<S> for RecentLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let meta = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        push_recent(LogEntry {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            level: meta.level().to_string(),
+            target: meta.target().to_string(),
+            message: visitor.finish(),
+        });
+    }
+}
+
+/// Flattens `message` plus any structured fields into one line.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: Vec<String>,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        if self.fields.is_empty() {
+            self.message
+        } else {
+            format!("{} {}", self.message, self.fields.join(" "))
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.push(format!("{}={}", field.name(), value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn recent_layer_captures_message_and_fields() {
+        let subscriber = tracing_subscriber::registry().with(RecentLogLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(chat_id = "c1", "logging-test-marker {}", 42);
+        });
+
+        let entry = recent(RECENT_CAPACITY, Some(Level::WARN))
+            .into_iter()
+            .rev()
+            .find(|e| e.message.starts_with("logging-test-marker"))
+            .expect("entry captured");
+        assert_eq!(entry.level, "WARN");
+        assert_eq!(entry.message, "logging-test-marker 42 chat_id=c1");
+        assert!(entry.target.ends_with("logging::tests"));
+    }
+
+    #[test]
+    fn recent_filters_by_min_level() {
+        let subscriber = tracing_subscriber::registry().with(RecentLogLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!("logging-level-debug-marker");
+            tracing::error!("logging-level-error-marker");
+        });
+
+        let errors = recent(RECENT_CAPACITY, Some(Level::ERROR));
+        assert!(errors
+            .iter()
+            .any(|e| e.message == "logging-level-error-marker"));
+        assert!(!errors
+            .iter()
+            .any(|e| e.message == "logging-level-debug-marker"));
+    }
+}
@@ -17,14 +17,19 @@ mod diff;
 mod error;
 #[cfg(not(windows))]
 mod event;
+mod forge;
 mod fs_link;
 mod git;
 mod hooks;
+mod logging;
+mod markdown;
+mod metrics;
 mod model;
 #[cfg(not(windows))]
 mod notification_state;
 mod operations;
 mod plugins;
+mod search;
 mod session;
 mod stats;
 mod storage;
@@ -73,7 +78,7 @@ fn ensure_storage_version() {
         let _ = storage::database::connection();
         storage::database::run_agent_graph_startup_maintenance();
         if let Err(e) = storage::curated_agents::ensure_curated_file() {
-            eprintln!("[startup] failed to update curated agents file: {}", e);
+            tracing::warn!("failed to update curated agents file: {}", e);
         }
         return;
     }
@@ -178,7 +183,7 @@ fn ensure_storage_version() {
     // reshape sees canonical ids.
     if version != Some(CURRENT_STORAGE_VERSION) {
         if let Err(e) = storage::database::migrate_installed_agents_to_v26() {
-            eprintln!("[migrate] installed_agents v2.6 failed: {}", e);
+            tracing::error!("installed_agents v2.6 failed: {}", e);
         }
     }
 
@@ -201,7 +206,7 @@ fn ensure_storage_version() {
     // doesn't, we drop the embedded default there. The marketplace modal
     // reads this file to render its default landing view.
     if let Err(e) = storage::curated_agents::ensure_curated_file() {
-        eprintln!("[startup] failed to bootstrap curated agents file: {}", e);
+        tracing::warn!("failed to bootstrap curated agents file: {}", e);
     }
 }
 
@@ -226,8 +231,15 @@ fn run_tui() -> io::Result<()> {
     // 创建应用
     let mut app = App::new();
 
-    // 运行主循环
-    let result = run(&mut terminal, &mut app);
+    // 运行主循环（panic 时 hook 已恢复终端，这里额外提示生成 crash report）
+    let result = match panic::catch_unwind(panic::AssertUnwindSafe(|| run(&mut terminal, &mut app)))
+    {
+        Ok(result) => result,
+        Err(payload) => {
+            cli::report::prompt_after_panic();
+            panic::resume_unwind(payload);
+        }
+    };
 
     // 恢复终端
     execute!(io::stdout(), DisableMouseCapture)?;
@@ -249,16 +261,16 @@ fn main() -> io::Result<()> {
         }
     }
 
-    #[cfg(feature = "perf-monitor")]
-    api::perf_tracing::install();
-
-    // Set up panic hook to restore terminal state on panic (TUI only)
-    #[cfg(not(windows))]
+    // Set up panic hook to persist the panic to ~/.grove/logs/ (picked up by
+    // `grove report`) and restore terminal state on panic (TUI only)
     let original_hook = panic::take_hook();
-    #[cfg(not(windows))]
     panic::set_hook(Box::new(move |panic_info| {
-        let _ = execute!(io::stdout(), DisableMouseCapture);
-        ratatui::restore();
+        #[cfg(not(windows))]
+        {
+            let _ = execute!(io::stdout(), DisableMouseCapture);
+            ratatui::restore();
+        }
+        let _ = cli::report::record_panic(panic_info);
         original_hook(panic_info);
     }));
 
@@ -305,6 +317,7 @@ fn main() -> io::Result<()> {
                         let _ = storage::config::save_config(&cfg);
                     }
                     // 直接执行 GUI
+                    logging::init(true);
                     tokio::runtime::Runtime::new()
                         .expect("Failed to create tokio runtime")
                         .block_on(async {
@@ -329,6 +342,9 @@ fn main() -> io::Result<()> {
         }
     };
 
+    // TUI 占用 alternate screen，日志只写文件；其它命令同时输出到 stderr
+    logging::init(!matches!(command, Commands::Tui));
+
     // 如果是新的启动模式命令（非重放），保存到配置
     if !from_replay {
         if let Some(last_launch) = command.to_last_launch() {
@@ -341,7 +357,11 @@ fn main() -> io::Result<()> {
     // Ensure storage is migrated and DB initialized for UI commands
     let needs_storage = matches!(
         command,
-        Commands::Tui | Commands::Web { .. } | Commands::Mobile { .. } | Commands::Gui { .. }
+        Commands::Tui
+            | Commands::Web { .. }
+            | Commands::Mobile { .. }
+            | Commands::Gui { .. }
+            | Commands::Notifyd { .. }
     );
     if needs_storage {
         // Check for updates at CLI startup and prompt user to auto-update
@@ -398,21 +418,40 @@ fn main() -> io::Result<()> {
         Commands::McpBridge => {
             std::process::exit(cli::mcp_bridge::run());
         }
-        Commands::Fp => {
+        Commands::Fp {
+            print,
+            json,
+            query,
+            max_kb,
+        } => {
             #[cfg(windows)]
             {
+                let _ = (print, json, query, max_kb);
                 eprintln!("grove fp is not supported on Windows. Please use WSL2.");
                 std::process::exit(1);
             }
             #[cfg(not(windows))]
-            cli::fp::execute();
+            {
+                let output = if json {
+                    cli::fp::FpOutput::Resources
+                } else if print {
+                    cli::fp::FpOutput::Paths
+                } else {
+                    cli::fp::FpOutput::Interactive
+                };
+                cli::fp::execute(output, query, max_kb);
+            }
         }
         Commands::Web {
             port,
             no_open,
             dev,
             remote_url,
+            read_only,
         } => {
+            if read_only {
+                api::read_only::enable();
+            }
             tokio::runtime::Runtime::new()
                 .expect("Failed to create tokio runtime")
                 .block_on(async {
@@ -468,12 +507,12 @@ fn main() -> io::Result<()> {
                 std::process::exit(1);
             }
         }
-        Commands::Acp { agent, cwd } => {
+        Commands::Acp { agent, cwd, attach } => {
             tokio::runtime::Runtime::new()
                 .expect("Failed to create tokio runtime")
                 .block_on(async {
                     let local = tokio::task::LocalSet::new();
-                    local.run_until(cli::acp::execute(agent, cwd)).await;
+                    local.run_until(cli::acp::execute(agent, cwd, attach)).await;
                 });
         }
         Commands::Migrate { prune } => {
@@ -528,6 +567,36 @@ fn main() -> io::Result<()> {
                 }
             }
         }
+        Commands::Cd {
+            query,
+            env,
+            shell,
+            init,
+            install,
+            name,
+        } => {
+            cli::cd::execute(cli::cd::CdArgs {
+                query,
+                env,
+                shell,
+                init,
+                install,
+                name,
+            });
+        }
+        Commands::Report { output } => {
+            cli::report::execute(output);
+        }
+        Commands::Notifyd { port } => {
+            tokio::runtime::Runtime::new()
+                .expect("Failed to create tokio runtime")
+                .block_on(async {
+                    cli::notifyd::execute(port).await;
+                });
+        }
+        Commands::Service { action } => {
+            cli::service::execute(action);
+        }
     }
 
     Ok(())
@@ -544,7 +613,8 @@ fn run(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
             execute!(io::stdout(), DisableMouseCapture)?;
             ratatui::restore();
 
-            // attach 到 session（阻塞，直到用户 detach）
+            // attach 到 session（阻塞，直到用户 detach），记录 attach 时段用于耗时统计
+            let attached_at = chrono::Utc::now();
             let _ = session::attach_session(
                 &att.session_type,
                 &att.session,
@@ -553,6 +623,20 @@ fn run(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
                 att.layout_path.as_deref(),
             );
 
+            let project_key = storage::workspace::project_hash(&att.env.project_path);
+            if session::attach_log::record_attach(
+                &project_key,
+                &att.env.task_id,
+                attached_at,
+                chrono::Utc::now(),
+            )
+            .is_ok()
+            {
+                std::thread::spawn(move || {
+                    let _ = operations::time_tracking::refresh_rollup(&project_key);
+                });
+            }
+
             // 清除 tmux detach 消息（只清除一行，仅 tmux 需要）
             if matches!(att.session_type, session::SessionType::Tmux) {
                 print!("\x1b[1A\x1b[2K\r");
@@ -584,7 +668,8 @@ fn run(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
             *terminal = ratatui::init();
             execute!(io::stdout(), EnableMouseCapture)?;
 
-            // 重新加载 notes 内容
+            // 记录修订并重新加载 notes 内容
+            app.monitor.finish_notes_edit();
             app.monitor.refresh_panel_data();
         }
 
@@ -602,10 +687,28 @@ fn run(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
             *terminal = ratatui::init();
             execute!(io::stdout(), EnableMouseCapture)?;
 
-            // 重新加载 notes 内容
+            // 记录修订并重新加载 notes 内容
+            app.project.finish_notes_edit();
             app.project.refresh_panel_data();
         }
 
+        // 检查是否有待打开的外部编辑器（项目笔记）
+        if let Some((slug, file_path)) = app.project.pending_project_note_edit.take() {
+            // 暂停 TUI
+            execute!(io::stdout(), DisableMouseCapture)?;
+            ratatui::restore();
+
+            // 打开外部编辑器
+            let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vim".to_string());
+            let _ = std::process::Command::new(&editor).arg(&file_path).status();
+
+            // 恢复 TUI
+            *terminal = ratatui::init();
+            execute!(io::stdout(), EnableMouseCapture)?;
+
+            app.finish_project_note_edit(&slug);
+        }
+
         // 定时自动刷新（每 5 秒）
         if last_refresh.elapsed().as_secs() >= AUTO_REFRESH_INTERVAL_SECS {
             app.refresh();
@@ -623,6 +726,9 @@ fn run(terminal: &mut DefaultTerminal, app: &mut App) -> io::Result<()> {
         // 检查后台操作结果
         app.poll_bg_result();
 
+        // 刷新 Chat 面板 transcript
+        app.poll_chat_view();
+
         // 渲染界面
         app.ui.click_areas.reset();
         terminal.draw(|frame| match app.mode {
@@ -0,0 +1,234 @@
+//! Sanitized HTML rendering
+//!
+//! The output is safe to inject into a page as-is: all text and
+//! attribute values are escaped, raw HTML from the source never reaches
+//! the output, and link/image URLs are limited to safe schemes (anything
+//! else is rendered as plain text).
+
+use super::{Align, Block, Inline};
+
+/// Render Markdown source to sanitized HTML.
+pub fn render(source: &str) -> String {
+    let mut out = String::new();
+    render_blocks(&mut out, &super::parse(source), false);
+    out
+}
+
+fn render_blocks(out: &mut String, blocks: &[Block], tight: bool) {
+    for block in blocks {
+        render_block(out, block, tight);
+    }
+}
+
+fn render_block(out: &mut String, block: &Block, tight: bool) {
+    match block {
+        Block::Heading { level, content } => {
+            out.push_str(&format!("<h{level}>"));
+            render_inlines(out, content);
+            out.push_str(&format!("</h{level}>\n"));
+        }
+        Block::Paragraph(content) if tight => {
+            render_inlines(out, content);
+            out.push('\n');
+        }
+        Block::Paragraph(content) => {
+            out.push_str("<p>");
+            render_inlines(out, content);
+            out.push_str("</p>\n");
+        }
+        Block::Code { lang, text } => {
+            match lang {
+                Some(lang) => {
+                    out.push_str(&format!("<pre><code class=\"language-{}\">", escape(lang)))
+                }
+                None => out.push_str("<pre><code>"),
+            }
+            out.push_str(&escape(text));
+            if !text.is_empty() {
+                out.push('\n');
+            }
+            out.push_str("</code></pre>\n");
+        }
+        Block::Quote(inner) => {
+            out.push_str("<blockquote>\n");
+            render_blocks(out, inner, false);
+            out.push_str("</blockquote>\n");
+        }
+        Block::List {
+            ordered,
+            start,
+            items,
+        } => {
+            let tag = if *ordered { "ol" } else { "ul" };
+            if *ordered && *start != 1 {
+                out.push_str(&format!("<ol start=\"{start}\">\n"));
+            } else {
+                out.push_str(&format!("<{tag}>\n"));
+            }
+            // 单段落项渲染为紧凑列表（不包 <p>）
+            let tight = items.iter().all(|item| {
+                item.blocks
+                    .iter()
+                    .filter(|b| matches!(b, Block::Paragraph(_)))
+                    .count()
+                    <= 1
+            });
+            for item in items {
+                out.push_str("<li>");
+                match item.checked {
+                    Some(true) => out.push_str("<input type=\"checkbox\" checked disabled> "),
+                    Some(false) => out.push_str("<input type=\"checkbox\" disabled> "),
+                    None => {}
+                }
+                render_blocks(out, &item.blocks, tight);
+                if out.ends_with('\n') {
+                    out.pop();
+                }
+                out.push_str("</li>\n");
+            }
+            out.push_str(&format!("</{tag}>\n"));
+        }
+        Block::Table {
+            align,
+            header,
+            rows,
+        } => {
+            out.push_str("<table>\n<thead>\n<tr>");
+            for (cell, a) in header.iter().zip(align) {
+                render_cell(out, "th", cell, *a);
+            }
+            out.push_str("</tr>\n</thead>\n");
+            if !rows.is_empty() {
+                out.push_str("<tbody>\n");
+                for row in rows {
+                    out.push_str("<tr>");
+                    for (cell, a) in row.iter().zip(align) {
+                        render_cell(out, "td", cell, *a);
+                    }
+                    out.push_str("</tr>\n");
+                }
+                out.push_str("</tbody>\n");
+            }
+            out.push_str("</table>\n");
+        }
+        Block::Rule => out.push_str("<hr>\n"),
+    }
+}
+
+fn render_cell(out: &mut String, tag: &str, cell: &[Inline], align: Align) {
+    match align {
+        Align::None => out.push_str(&format!("<{tag}>")),
+        Align::Left => out.push_str(&format!("<{tag} style=\"text-align:left\">")),
+        Align::Center => out.push_str(&format!("<{tag} style=\"text-align:center\">")),
+        Align::Right => out.push_str(&format!("<{tag} style=\"text-align:right\">")),
+    }
+    render_inlines(out, cell);
+    out.push_str(&format!("</{tag}>"));
+}
+
+fn render_inlines(out: &mut String, inlines: &[Inline]) {
+    for inline in inlines {
+        match inline {
+            Inline::Text(t) => out.push_str(&escape(t)),
+            Inline::Code(t) => {
+                out.push_str("<code>");
+                out.push_str(&escape(t));
+                out.push_str("</code>");
+            }
+            Inline::Emphasis(c) => wrap(out, "em", c),
+            Inline::Strong(c) => wrap(out, "strong", c),
+            Inline::Strike(c) => wrap(out, "del", c),
+            Inline::Link { href, content } => match safe_url(href) {
+                Some(url) => {
+                    out.push_str(&format!(
+                        "<a href=\"{}\" rel=\"noopener noreferrer\">",
+                        escape(url)
+                    ));
+                    render_inlines(out, content);
+                    out.push_str("</a>");
+                }
+                None => render_inlines(out, content),
+            },
+            Inline::Image { src, alt } => match safe_url(src) {
+                Some(url) => out.push_str(&format!(
+                    "<img src=\"{}\" alt=\"{}\">",
+                    escape(url),
+                    escape(alt)
+                )),
+                None => out.push_str(&escape(alt)),
+            },
+            Inline::Break => out.push_str("<br>\n"),
+        }
+    }
+}
+
+fn wrap(out: &mut String, tag: &str, content: &[Inline]) {
+    out.push_str(&format!("<{tag}>"));
+    render_inlines(out, content);
+    out.push_str(&format!("</{tag}>"));
+}
+
+/// Accept http(s), mailto and scheme-less (relative / fragment) URLs.
+fn safe_url(url: &str) -> Option<&str> {
+    let url = url.trim();
+    if url.is_empty() {
+        return None;
+    }
+    // 浏览器解析 scheme 时会忽略控制字符和空白
+    let normalized: String = url
+        .chars()
+        .filter(|c| !c.is_control() && !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    match normalized.find(':') {
+        Some(colon) if !normalized[..colon].contains(['/', '?', '#']) => {
+            let scheme = &normalized[..colon];
+            matches!(scheme, "http" | "https" | "mailto").then_some(url)
+        }
+        _ => Some(url),
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_html() {
+        let html = render("## Notes\n\n- [x] **done**\n- see [x](https://a.b)\n\n| k | v |\n|---|:-:|\n| a | 1 |\n");
+        assert_eq!(
+            html,
+            "<h2>Notes</h2>\n<ul>\n<li><input type=\"checkbox\" checked disabled> <strong>done</strong></li>\n<li>see <a href=\"https://a.b\" rel=\"noopener noreferrer\">x</a></li>\n</ul>\n<table>\n<thead>\n<tr><th>k</th><th style=\"text-align:center\">v</th></tr>\n</thead>\n<tbody>\n<tr><td>a</td><td style=\"text-align:center\">1</td></tr>\n</tbody>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn test_render_html_is_sanitized() {
+        let html = render(
+            "<script>alert(1)</script>\n\n[x](javascript:alert(1)) [y](JaVaScript:x) ![i](data:image/png;base64,AA)\n\n```\n\"><img>\n<b>\n```\n",
+        );
+        assert!(!html.contains("<script"));
+        assert!(html.contains("&lt;script&gt;"));
+        assert!(!html.contains("href=\"javascript"));
+        assert!(!html.to_lowercase().contains("href=\"javascript"));
+        assert!(!html.contains("<img src=\"data:"));
+        assert!(html.contains("<pre><code>&quot;&gt;&lt;img&gt;\n&lt;b&gt;\n</code></pre>"));
+        assert_eq!(safe_url("docs/a.md#x"), Some("docs/a.md#x"));
+        assert_eq!(safe_url("./a:b"), Some("./a:b"));
+    }
+}
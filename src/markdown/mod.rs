@@ -0,0 +1,1088 @@
+//! Markdown module
+//!
+//! A small CommonMark/GFM-subset parser shared by every surface that
+//! shows notes or chat text: the web API renders the tree to sanitized
+//! HTML (`html`), the TUI renders it to styled terminal lines.
+//!
+//! Supported: ATX and setext headings, paragraphs with hard breaks,
+//! fenced and indented code blocks, block quotes, nested bullet/ordered
+//! and task lists, GFM tables, thematic breaks, and inline code,
+//! emphasis, strong, strikethrough, links, images and autolinks.
+//! Raw HTML is never passed through — it is treated as text.
+
+pub mod html;
+
+/// Block-level element
+#[derive(Debug, Clone, PartialEq)]
+pub enum Block {
+    Heading {
+        level: u8,
+        content: Vec<Inline>,
+    },
+    Paragraph(Vec<Inline>),
+    Code {
+        lang: Option<String>,
+        text: String,
+    },
+    Quote(Vec<Block>),
+    List {
+        ordered: bool,
+        start: u64,
+        items: Vec<ListItem>,
+    },
+    Table {
+        align: Vec<Align>,
+        header: Vec<Vec<Inline>>,
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+    Rule,
+}
+
+/// One list item; `checked` is set for GFM task items (`- [ ]` / `- [x]`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListItem {
+    pub checked: Option<bool>,
+    pub blocks: Vec<Block>,
+}
+
+/// Table column alignment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    None,
+    Left,
+    Center,
+    Right,
+}
+
+/// Inline element
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inline {
+    Text(String),
+    Code(String),
+    Emphasis(Vec<Inline>),
+    Strong(Vec<Inline>),
+    Strike(Vec<Inline>),
+    Link {
+        href: String,
+        content: Vec<Inline>,
+    },
+    Image {
+        src: String,
+        alt: String,
+    },
+    /// Hard line break (two trailing spaces or a trailing backslash)
+    Break,
+}
+
+/// Parse Markdown source into blocks.
+pub fn parse(source: &str) -> Vec<Block> {
+    let lines: Vec<String> = source
+        .replace("\r\n", "\n")
+        .split('\n')
+        .map(expand_tabs)
+        .collect();
+    parse_blocks(&lines)
+}
+
+/// Concatenated text of inlines without any markup (used for alt text,
+/// table column widths and heading anchors).
+pub fn plain_text(inlines: &[Inline]) -> String {
+    let mut out = String::new();
+    for inline in inlines {
+        match inline {
+            Inline::Text(t) | Inline::Code(t) => out.push_str(t),
+            Inline::Emphasis(c) | Inline::Strong(c) | Inline::Strike(c) => {
+                out.push_str(&plain_text(c))
+            }
+            Inline::Link { content, .. } => out.push_str(&plain_text(content)),
+            Inline::Image { alt, .. } => out.push_str(alt),
+            Inline::Break => out.push(' '),
+        }
+    }
+    out
+}
+
+// ============================================================================
+// Block parsing
+// ============================================================================
+
+fn expand_tabs(line: &str) -> String {
+    if !line.contains('\t') {
+        return line.to_string();
+    }
+    let mut out = String::with_capacity(line.len() + 8);
+    for c in line.chars() {
+        if c == '\t' {
+            let pad = 4 - (out.chars().count() % 4);
+            out.extend(std::iter::repeat_n(' ', pad));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Opening code fence: (fence char, fence length, info string)
+fn fence_open(line: &str) -> Option<(char, usize, String)> {
+    if indent_of(line) > 3 {
+        return None;
+    }
+    let t = line.trim_start();
+    let ch = t.chars().next()?;
+    if ch != '`' && ch != '~' {
+        return None;
+    }
+    let len = t.chars().take_while(|&c| c == ch).count();
+    if len < 3 {
+        return None;
+    }
+    let info = t[len..].trim();
+    if ch == '`' && info.contains('`') {
+        return None;
+    }
+    Some((ch, len, info.to_string()))
+}
+
+fn is_fence_close(line: &str, ch: char, len: usize) -> bool {
+    if indent_of(line) > 3 {
+        return false;
+    }
+    let t = line.trim();
+    t.chars().count() >= len && t.chars().all(|c| c == ch)
+}
+
+fn heading(line: &str) -> Option<(u8, &str)> {
+    if indent_of(line) > 3 {
+        return None;
+    }
+    let t = line.trim_start();
+    let level = t.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &t[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    let mut text = rest.trim();
+    // 去掉可选的结尾 #（前面须有空格）
+    let stripped = text.trim_end_matches('#');
+    if stripped.is_empty() || stripped.ends_with(' ') {
+        text = stripped.trim_end();
+    }
+    Some((level as u8, text))
+}
+
+fn is_rule(line: &str) -> bool {
+    if indent_of(line) > 3 {
+        return false;
+    }
+    let t: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    let Some(ch) = t.chars().next() else {
+        return false;
+    };
+    matches!(ch, '-' | '*' | '_') && t.len() >= 3 && t.chars().all(|c| c == ch)
+}
+
+/// Setext underline: 1 for `===`, 2 for `---`
+fn setext_level(line: &str) -> Option<u8> {
+    if indent_of(line) > 3 {
+        return None;
+    }
+    let t = line.trim();
+    if !t.is_empty() && t.chars().all(|c| c == '=') {
+        Some(1)
+    } else if !t.is_empty() && t.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// A list item marker at the start of `line`
+struct Marker {
+    ordered: bool,
+    /// Bullet char, or the ordered delimiter (`.` / `)`)
+    symbol: char,
+    start: u64,
+    /// Column where the item content begins
+    content_indent: usize,
+}
+
+fn list_marker(line: &str) -> Option<Marker> {
+    let indent = indent_of(line);
+    if indent > 3 {
+        return None;
+    }
+    let t = &line[indent..];
+    let (ordered, symbol, start, marker_len) = match t.chars().next()? {
+        c @ ('-' | '*' | '+') => (false, c, 1, 1),
+        c if c.is_ascii_digit() => {
+            let digits = t.chars().take_while(|c| c.is_ascii_digit()).count();
+            if digits > 9 {
+                return None;
+            }
+            let delim = t[digits..].chars().next()?;
+            if delim != '.' && delim != ')' {
+                return None;
+            }
+            (true, delim, t[..digits].parse().ok()?, digits + 1)
+        }
+        _ => return None,
+    };
+    let after = &t[marker_len..];
+    if after.is_empty() {
+        return Some(Marker {
+            ordered,
+            symbol,
+            start,
+            content_indent: indent + marker_len + 1,
+        });
+    }
+    if !after.starts_with(' ') {
+        return None;
+    }
+    let spaces = indent_of(after);
+    // 5 个及以上空格时内容视为缩进代码，只算 1 个空格
+    let spaces = if spaces > 4 || is_blank(after) {
+        1
+    } else {
+        spaces
+    };
+    Some(Marker {
+        ordered,
+        symbol,
+        start,
+        content_indent: indent + marker_len + spaces,
+    })
+}
+
+fn quote_content(line: &str) -> Option<&str> {
+    if indent_of(line) > 3 {
+        return None;
+    }
+    let rest = line.trim_start().strip_prefix('>')?;
+    Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
+
+fn split_table_row(line: &str) -> Vec<String> {
+    let t = line.trim();
+    let t = t.strip_prefix('|').unwrap_or(t);
+    let t = if t.ends_with('|') && !t.ends_with("\\|") {
+        &t[..t.len() - 1]
+    } else {
+        t
+    };
+    let mut cells = Vec::new();
+    let mut cell = String::new();
+    let mut in_code = false;
+    let mut chars = t.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'|') => {
+                cell.push('|');
+                chars.next();
+            }
+            '`' => {
+                in_code = !in_code;
+                cell.push(c);
+            }
+            '|' if !in_code => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    cells.push(cell.trim().to_string());
+    cells
+}
+
+fn table_delimiter(line: &str) -> Option<Vec<Align>> {
+    if !line.contains('-') {
+        return None;
+    }
+    split_table_row(line)
+        .iter()
+        .map(|cell| {
+            let left = cell.starts_with(':');
+            let right = cell.ends_with(':');
+            let dashes = cell.trim_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+            Some(match (left, right) {
+                (true, true) => Align::Center,
+                (true, false) => Align::Left,
+                (false, true) => Align::Right,
+                (false, false) => Align::None,
+            })
+        })
+        .collect()
+}
+
+fn table_start(lines: &[String], i: usize) -> Option<Vec<Align>> {
+    let header = lines.get(i)?;
+    if !header.contains('|') || indent_of(header) > 3 {
+        return None;
+    }
+    let align = table_delimiter(lines.get(i + 1)?)?;
+    (split_table_row(header).len() == align.len()).then_some(align)
+}
+
+/// Does this line start a block that interrupts a paragraph?
+fn interrupts_paragraph(lines: &[String], i: usize) -> bool {
+    let line = &lines[i];
+    fence_open(line).is_some()
+        || heading(line).is_some()
+        || is_rule(line)
+        || quote_content(line).is_some()
+        || list_marker(line).is_some_and(|m| {
+            !is_blank(&line[m.content_indent.min(line.len())..]) && (!m.ordered || m.start == 1)
+        })
+        || table_start(lines, i).is_some()
+}
+
+fn parse_blocks(lines: &[String]) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = &lines[i];
+        if is_blank(line) {
+            i += 1;
+            continue;
+        }
+
+        if let Some((ch, len, info)) = fence_open(line) {
+            let fence_indent = indent_of(line);
+            let mut body = Vec::new();
+            i += 1;
+            while i < lines.len() && !is_fence_close(&lines[i], ch, len) {
+                let l = &lines[i];
+                body.push(&l[indent_of(l).min(fence_indent)..]);
+                i += 1;
+            }
+            i += 1; // 结束 fence（或文件末尾）
+            let lang = info.split_whitespace().next().map(str::to_string);
+            blocks.push(Block::Code {
+                lang,
+                text: body.join("\n"),
+            });
+            continue;
+        }
+
+        if indent_of(line) >= 4 {
+            let mut body: Vec<&str> = Vec::new();
+            while i < lines.len() && (indent_of(&lines[i]) >= 4 || is_blank(&lines[i])) {
+                body.push(lines[i].get(4..).unwrap_or(""));
+                i += 1;
+            }
+            while body.last().is_some_and(|l| is_blank(l)) {
+                body.pop();
+            }
+            blocks.push(Block::Code {
+                lang: None,
+                text: body.join("\n"),
+            });
+            continue;
+        }
+
+        if let Some((level, text)) = heading(line) {
+            blocks.push(Block::Heading {
+                level,
+                content: parse_inlines(text),
+            });
+            i += 1;
+            continue;
+        }
+
+        if is_rule(line) {
+            blocks.push(Block::Rule);
+            i += 1;
+            continue;
+        }
+
+        if quote_content(line).is_some() {
+            let mut inner = Vec::new();
+            while i < lines.len() {
+                match quote_content(&lines[i]) {
+                    Some(c) => inner.push(c.to_string()),
+                    // 懒惰续行：引用中段落的后续行
+                    None if !is_blank(&lines[i])
+                        && inner.last().is_some_and(|l: &String| !is_blank(l))
+                        && !interrupts_paragraph(lines, i) =>
+                    {
+                        inner.push(lines[i].clone())
+                    }
+                    None => break,
+                }
+                i += 1;
+            }
+            blocks.push(Block::Quote(parse_blocks(&inner)));
+            continue;
+        }
+
+        if let Some(marker) = list_marker(line) {
+            let (block, next) = parse_list(lines, i, marker);
+            blocks.push(block);
+            i = next;
+            continue;
+        }
+
+        if let Some(align) = table_start(lines, i) {
+            let header = split_table_row(line)
+                .iter()
+                .map(|c| parse_inlines(c))
+                .collect();
+            let mut rows = Vec::new();
+            i += 2;
+            while i < lines.len() && !is_blank(&lines[i]) && lines[i].contains('|') {
+                let mut cells: Vec<Vec<Inline>> = split_table_row(&lines[i])
+                    .iter()
+                    .map(|c| parse_inlines(c))
+                    .collect();
+                cells.resize(align.len(), Vec::new());
+                rows.push(cells);
+                i += 1;
+            }
+            blocks.push(Block::Table {
+                align,
+                header,
+                rows,
+            });
+            continue;
+        }
+
+        // 段落（可能以 setext 下划线结束成为标题）
+        let mut para: Vec<&str> = vec![line.trim_start()];
+        i += 1;
+        let mut setext = None;
+        while i < lines.len() && !is_blank(&lines[i]) {
+            if let Some(level) = setext_level(&lines[i]) {
+                setext = Some(level);
+                i += 1;
+                break;
+            }
+            if interrupts_paragraph(lines, i) {
+                break;
+            }
+            para.push(lines[i].trim_start());
+            i += 1;
+        }
+        let text = para.join("\n");
+        match setext {
+            Some(level) => blocks.push(Block::Heading {
+                level,
+                content: parse_inlines(text.trim()),
+            }),
+            None => blocks.push(Block::Paragraph(parse_inlines(&text))),
+        }
+    }
+    blocks
+}
+
+/// Parse a list starting at `start`; returns the list and the index of
+/// the first line after it.
+fn parse_list(lines: &[String], start: usize, first: Marker) -> (Block, usize) {
+    let mut items = Vec::new();
+    let mut i = start;
+    let mut marker = Some(first);
+    let ordered = marker.as_ref().is_some_and(|m| m.ordered);
+    let list_start = marker.as_ref().map_or(1, |m| m.start);
+    let symbol = marker.as_ref().map_or('-', |m| m.symbol);
+
+    while let Some(m) = marker.take() {
+        let content_indent = m.content_indent;
+        let first_line = &lines[i];
+        let mut body = vec![first_line.get(content_indent..).unwrap_or("").to_string()];
+        i += 1;
+        while i < lines.len() {
+            let l = &lines[i];
+            if is_blank(l) {
+                // 空行后仍有缩进内容则属于当前项
+                let next = lines[i..].iter().position(|l| !is_blank(l));
+                match next {
+                    Some(off) if indent_of(&lines[i + off]) >= content_indent => {
+                        body.extend(std::iter::repeat_n(String::new(), off));
+                        i += off;
+                        continue;
+                    }
+                    _ => break,
+                }
+            }
+            if indent_of(l) >= content_indent {
+                body.push(l[content_indent..].to_string());
+                i += 1;
+                continue;
+            }
+            if list_marker(l).is_some() || interrupts_paragraph(lines, i) {
+                break;
+            }
+            // 懒惰续行
+            if body.last().is_some_and(|b| !is_blank(b)) {
+                body.push(l.trim_start().to_string());
+                i += 1;
+                continue;
+            }
+            break;
+        }
+
+        let mut checked = None;
+        if let Some(first) = body.first_mut() {
+            for (prefix, state) in [("[ ] ", false), ("[x] ", true), ("[X] ", true)] {
+                if let Some(rest) = first.strip_prefix(prefix) {
+                    checked = Some(state);
+                    *first = rest.to_string();
+                    break;
+                }
+            }
+        }
+        items.push(ListItem {
+            checked,
+            blocks: parse_blocks(&body),
+        });
+
+        // 跳过项之间的空行，寻找同类型的下一项
+        let mut j = i;
+        while j < lines.len() && is_blank(&lines[j]) {
+            j += 1;
+        }
+        if j < lines.len() {
+            if let Some(next) = list_marker(&lines[j]) {
+                if next.ordered == ordered && next.symbol == symbol && !is_rule(&lines[j]) {
+                    i = j;
+                    marker = Some(next);
+                }
+            }
+        }
+    }
+
+    (
+        Block::List {
+            ordered,
+            start: list_start,
+            items,
+        },
+        i,
+    )
+}
+
+// ============================================================================
+// Inline parsing
+// ============================================================================
+
+fn parse_inlines(text: &str) -> Vec<Inline> {
+    let chars: Vec<char> = text.chars().collect();
+    InlineParser {
+        chars: &chars,
+        out: Vec::new(),
+        text: String::new(),
+    }
+    .run(0, chars.len())
+}
+
+struct InlineParser<'a> {
+    chars: &'a [char],
+    out: Vec<Inline>,
+    text: String,
+}
+
+impl InlineParser<'_> {
+    fn flush(&mut self) {
+        if !self.text.is_empty() {
+            self.out.push(Inline::Text(std::mem::take(&mut self.text)));
+        }
+    }
+
+    fn push(&mut self, inline: Inline) {
+        self.flush();
+        self.out.push(inline);
+    }
+
+    fn nested(&self, start: usize, end: usize) -> Vec<Inline> {
+        InlineParser {
+            chars: self.chars,
+            out: Vec::new(),
+            text: String::new(),
+        }
+        .run(start, end)
+    }
+
+    fn run(mut self, start: usize, end: usize) -> Vec<Inline> {
+        let c = self.chars;
+        let mut i = start;
+        while i < end {
+            match c[i] {
+                '\\' if i + 1 < end && c[i + 1] == '\n' => {
+                    self.push(Inline::Break);
+                    i += 2;
+                }
+                '\\' if i + 1 < end && c[i + 1].is_ascii_punctuation() => {
+                    self.text.push(c[i + 1]);
+                    i += 2;
+                }
+                '\n' => {
+                    // 两个以上的行尾空格为硬换行
+                    let trailing = self.text.len() - self.text.trim_end_matches(' ').len();
+                    if trailing >= 2 {
+                        self.text.truncate(self.text.len() - trailing);
+                        self.push(Inline::Break);
+                    } else {
+                        self.text.truncate(self.text.len() - trailing);
+                        self.text.push('\n');
+                    }
+                    i += 1;
+                }
+                '`' => {
+                    let run = count_run(c, i, end, '`');
+                    match find_run(c, i + run, end, '`', run) {
+                        Some(close) => {
+                            let mut code: String = c[i + run..close]
+                                .iter()
+                                .map(|&ch| if ch == '\n' { ' ' } else { ch })
+                                .collect();
+                            if code.len() >= 2
+                                && code.starts_with(' ')
+                                && code.ends_with(' ')
+                                && !code.trim().is_empty()
+                            {
+                                code = code[1..code.len() - 1].to_string();
+                            }
+                            self.push(Inline::Code(code));
+                            i = close + run;
+                        }
+                        None => {
+                            self.text.extend(&c[i..i + run]);
+                            i += run;
+                        }
+                    }
+                }
+                '!' if i + 1 < end && c[i + 1] == '[' => match self.link(i + 1, end) {
+                    Some((label_end, href, next)) => {
+                        let alt = plain_text(&self.nested(i + 2, label_end));
+                        self.push(Inline::Image { src: href, alt });
+                        i = next;
+                    }
+                    None => {
+                        self.text.push('!');
+                        i += 1;
+                    }
+                },
+                '[' => match self.link(i, end) {
+                    Some((label_end, href, next)) => {
+                        let content = self.nested(i + 1, label_end);
+                        self.push(Inline::Link { href, content });
+                        i = next;
+                    }
+                    None => {
+                        self.text.push('[');
+                        i += 1;
+                    }
+                },
+                '<' => match autolink(c, i, end) {
+                    Some((href, next)) => {
+                        self.push(Inline::Link {
+                            content: vec![Inline::Text(href.clone())],
+                            href,
+                        });
+                        i = next;
+                    }
+                    None => {
+                        self.text.push('<');
+                        i += 1;
+                    }
+                },
+                'h' if i == start || !c[i - 1].is_alphanumeric() => match bare_url(c, i, end) {
+                    Some(next) => {
+                        let url: String = c[i..next].iter().collect();
+                        self.push(Inline::Link {
+                            content: vec![Inline::Text(url.clone())],
+                            href: url,
+                        });
+                        i = next;
+                    }
+                    None => {
+                        self.text.push('h');
+                        i += 1;
+                    }
+                },
+                ch @ ('*' | '_' | '~') => match self.delimited(i, end, ch) {
+                    Some((inline, next)) => {
+                        self.push(inline);
+                        i = next;
+                    }
+                    None => {
+                        let run = count_run(c, i, end, ch);
+                        self.text.extend(&c[i..i + run]);
+                        i += run;
+                    }
+                },
+                ch => {
+                    self.text.push(ch);
+                    i += 1;
+                }
+            }
+        }
+        self.flush();
+        self.out
+    }
+
+    /// `[label](href)` at `open`; returns (label end, href, index after `)`)
+    fn link(&self, open: usize, end: usize) -> Option<(usize, String, usize)> {
+        let c = self.chars;
+        let mut depth = 0;
+        let mut j = open;
+        let label_end = loop {
+            if j >= end {
+                return None;
+            }
+            match c[j] {
+                '\\' => j += 1,
+                '`' => {
+                    let run = count_run(c, j, end, '`');
+                    j = find_run(c, j + run, end, '`', run).map_or(j + run - 1, |k| k + run - 1);
+                }
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break j;
+                    }
+                }
+                _ => {}
+            }
+            j += 1;
+        };
+        if c.get(label_end + 1) != Some(&'(') {
+            return None;
+        }
+        let mut k = label_end + 2;
+        let mut paren = 0;
+        let mut dest = String::new();
+        while k < end {
+            match c[k] {
+                '(' => paren += 1,
+                ')' if paren == 0 => break,
+                ')' => paren -= 1,
+                '\n' => return None,
+                _ => {}
+            }
+            dest.push(c[k]);
+            k += 1;
+        }
+        if k >= end {
+            return None;
+        }
+        // 去掉可选的标题部分：url "title"
+        let dest = dest.trim();
+        let href = match dest.find([' ', '\t']) {
+            Some(pos) => &dest[..pos],
+            None => dest,
+        };
+        let href = href
+            .strip_prefix('<')
+            .and_then(|h| h.strip_suffix('>'))
+            .unwrap_or(href);
+        Some((label_end, href.to_string(), k + 1))
+    }
+
+    /// Emphasis (`*x*`, `_x_`), strong (`**x**`, `__x__`) or strike (`~~x~~`)
+    fn delimited(&self, i: usize, end: usize, ch: char) -> Option<(Inline, usize)> {
+        let c = self.chars;
+        let run = count_run(c, i, end, ch).min(2);
+        if ch == '~' && run != 2 {
+            return None;
+        }
+        let open_end = i + run;
+        // 左侧定界符：后面不能是空白
+        if open_end >= end || c[open_end].is_whitespace() {
+            return None;
+        }
+        // `_` 不在单词内部生效
+        if ch == '_' && i > 0 && c[i - 1].is_alphanumeric() {
+            return None;
+        }
+        let mut j = open_end;
+        while j < end {
+            if c[j] == '\\' {
+                j += 2;
+                continue;
+            }
+            if c[j] == '`' {
+                let r = count_run(c, j, end, '`');
+                j = find_run(c, j + r, end, '`', r).map_or(j + r, |k| k + r);
+                continue;
+            }
+            if c[j] == ch {
+                let r = count_run(c, j, end, ch);
+                let closes = !c[j - 1].is_whitespace()
+                    && j > open_end
+                    && (ch != '_' || j + r >= end || !c[j + r].is_alphanumeric());
+                if closes && r >= run && (r == run || run == 2 || r >= 3) {
+                    let inner = self.nested(open_end, j);
+                    let inline = match (ch, run) {
+                        ('~', _) => Inline::Strike(inner),
+                        (_, 2) => Inline::Strong(inner),
+                        _ => Inline::Emphasis(inner),
+                    };
+                    return Some((inline, j + run));
+                }
+                j += r;
+                continue;
+            }
+            j += 1;
+        }
+        None
+    }
+}
+
+fn count_run(c: &[char], i: usize, end: usize, ch: char) -> usize {
+    c[i..end].iter().take_while(|&&x| x == ch).count()
+}
+
+/// Find a run of exactly `len` `ch` characters at or after `from`
+fn find_run(c: &[char], from: usize, end: usize, ch: char, len: usize) -> Option<usize> {
+    let mut j = from;
+    while j < end {
+        if c[j] == ch {
+            let r = count_run(c, j, end, ch);
+            if r == len {
+                return Some(j);
+            }
+            j += r;
+        } else {
+            j += 1;
+        }
+    }
+    None
+}
+
+/// `<https://…>` / `<mailto:…>` autolink
+fn autolink(c: &[char], i: usize, end: usize) -> Option<(String, usize)> {
+    let close = (i + 1..end).find(|&j| c[j] == '>')?;
+    let inner: String = c[i + 1..close].iter().collect();
+    if inner.contains(char::is_whitespace) || inner.contains('<') {
+        return None;
+    }
+    let lower = inner.to_ascii_lowercase();
+    if lower.starts_with("http://") || lower.starts_with("https://") || lower.starts_with("mailto:")
+    {
+        Some((inner, close + 1))
+    } else {
+        None
+    }
+}
+
+/// GFM-style bare `http(s)://` URL; returns the index after it
+fn bare_url(c: &[char], i: usize, end: usize) -> Option<usize> {
+    let rest: String = c[i..end.min(i + 8)].iter().collect();
+    let scheme = if rest.starts_with("https://") {
+        8
+    } else if rest.starts_with("http://") {
+        7
+    } else {
+        return None;
+    };
+    let mut j = i + scheme;
+    while j < end && !c[j].is_whitespace() && c[j] != '<' {
+        j += 1;
+    }
+    // 去掉结尾标点和不成对的右括号
+    while j > i + scheme {
+        let last = c[j - 1];
+        let unbalanced_paren = last == ')'
+            && c[i..j].iter().filter(|&&x| x == '(').count()
+                < c[i..j].iter().filter(|&&x| x == ')').count();
+        if matches!(
+            last,
+            '.' | ',' | ':' | ';' | '!' | '?' | '"' | '\'' | '*' | '_' | '~'
+        ) || unbalanced_paren
+        {
+            j -= 1;
+        } else {
+            break;
+        }
+    }
+    (j > i + scheme).then_some(j)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> Inline {
+        Inline::Text(s.to_string())
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let src = "# Title #\n\nSome *text*\nmore  \nend\n\n```rust\nfn main() {}\n```\n\n> quoted\n\n---\n\nSub\n===\n";
+        let blocks = parse(src);
+        assert_eq!(
+            blocks[0],
+            Block::Heading {
+                level: 1,
+                content: vec![text("Title")]
+            }
+        );
+        assert_eq!(
+            blocks[1],
+            Block::Paragraph(vec![
+                text("Some "),
+                Inline::Emphasis(vec![text("text")]),
+                text("\nmore"),
+                Inline::Break,
+                text("end"),
+            ])
+        );
+        assert_eq!(
+            blocks[2],
+            Block::Code {
+                lang: Some("rust".into()),
+                text: "fn main() {}".into()
+            }
+        );
+        assert_eq!(
+            blocks[3],
+            Block::Quote(vec![Block::Paragraph(vec![text("quoted")])])
+        );
+        assert_eq!(blocks[4], Block::Rule);
+        assert_eq!(
+            blocks[5],
+            Block::Heading {
+                level: 1,
+                content: vec![text("Sub")]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_lists() {
+        let blocks = parse("- [ ] todo\n- [x] done\n  - nested\n\n3. three\n4. four\n");
+        let Block::List {
+            ordered: false,
+            items,
+            ..
+        } = &blocks[0]
+        else {
+            panic!("expected bullet list: {:?}", blocks[0]);
+        };
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].checked, Some(false));
+        assert_eq!(items[1].checked, Some(true));
+        assert!(matches!(items[1].blocks[1], Block::List { .. }));
+        assert!(matches!(
+            blocks[1],
+            Block::List {
+                ordered: true,
+                start: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_table() {
+        let blocks = parse("| a | b |\n|:--|--:|\n| `x|y` | 2 |\n| only |\n");
+        let Block::Table {
+            align,
+            header,
+            rows,
+        } = &blocks[0]
+        else {
+            panic!("expected table: {:?}", blocks[0]);
+        };
+        assert_eq!(align, &vec![Align::Left, Align::Right]);
+        assert_eq!(header[1], vec![text("b")]);
+        assert_eq!(rows[0][0], vec![Inline::Code("x|y".into())]);
+        assert_eq!(rows[1].len(), 2);
+    }
+
+    #[test]
+    fn test_parse_inlines() {
+        assert_eq!(
+            parse_inlines("**bold** and ~~gone~~ `a*b*` snake_case_name"),
+            vec![
+                Inline::Strong(vec![text("bold")]),
+                text(" and "),
+                Inline::Strike(vec![text("gone")]),
+                text(" "),
+                Inline::Code("a*b*".into()),
+                text(" snake_case_name"),
+            ]
+        );
+        assert_eq!(
+            parse_inlines("see [docs](https://x.dev/a \"t\") or https://x.dev/b."),
+            vec![
+                text("see "),
+                Inline::Link {
+                    href: "https://x.dev/a".into(),
+                    content: vec![text("docs")]
+                },
+                text(" or "),
+                Inline::Link {
+                    href: "https://x.dev/b".into(),
+                    content: vec![text("https://x.dev/b")]
+                },
+                text("."),
+            ]
+        );
+        assert_eq!(
+            parse_inlines("![logo](a.png) 2 * 3 \\*lit\\*"),
+            vec![
+                Inline::Image {
+                    src: "a.png".into(),
+                    alt: "logo".into()
+                },
+                text(" 2 * 3 *lit*"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_degenerate_input() {
+        for src in [
+            "",
+            "[",
+            "]",
+            "![",
+            "[a](",
+            "`",
+            "``a`",
+            "*",
+            "**",
+            "_a",
+            "~~",
+            "~~~",
+            "```",
+            "-",
+            "1.",
+            "1)",
+            "|",
+            "|-|",
+            "> ",
+            ">",
+            "\\",
+            "#",
+            "#######",
+            "a\\\n",
+            "  \n  ",
+            "- \n  \n",
+            "* * *",
+            "\t- a\n\t\tb",
+            "<",
+            "<http://a",
+            "http://",
+            "h",
+            "中文 **粗体** _斜_ [链接](https://例子.cn)",
+        ] {
+            let _ = parse(src);
+            let _ = html::render(src);
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Prometheus metrics
+//!
+//! Process-wide counters and histograms rendered in the Prometheus text
+//! exposition format by `GET /metrics` (see `api::handlers::metrics`). The
+//! endpoint is only mounted when `[metrics] enabled = true`; recording itself
+//! is cheap enough to stay on unconditionally.
+//!
+//! Exposed series:
+//! - `grove_http_requests_total{method,route,status}` / `grove_http_request_duration_seconds{method,route}`
+//! - `grove_acp_sessions_active`
+//! - `grove_ws_connections_active{kind}` / `grove_ws_connections_total{kind}`
+//! - `grove_git_command_duration_seconds{subcommand}`
+//! - `grove_watcher_fs_events_total` / `grove_watcher_edit_events_total`
+//! - `grove_uptime_seconds`, `grove_build_info{version}`
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+
+/// Histogram upper bounds in seconds (`+Inf` is implicit).
+const BUCKETS: [f64; 12] = [
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+
+static STARTED: Lazy<Instant> = Lazy::new(Instant::now);
+
+static REGISTRY: Lazy<Mutex<Registry>> = Lazy::new(|| Mutex::new(Registry::default()));
+
+static WATCHER_FS_EVENTS: AtomicU64 = AtomicU64::new(0);
+static WATCHER_EDIT_EVENTS: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Default)]
+struct Histogram {
+    /// Non-cumulative per-bucket counts; the last slot is `+Inf`.
+    counts: [u64; BUCKETS.len() + 1],
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, secs: f64) {
+        let idx = BUCKETS
+            .iter()
+            .position(|&le| secs <= le)
+            .unwrap_or(BUCKETS.len());
+        self.counts[idx] += 1;
+        self.sum += secs;
+        self.count += 1;
+    }
+}
+
+#[derive(Default)]
+struct Registry {
+    http_requests: HashMap<(String, String, u16), u64>,
+    http_latency: HashMap<(String, String), Histogram>,
+    git_duration: HashMap<String, Histogram>,
+    ws_active: HashMap<&'static str, i64>,
+    ws_total: HashMap<&'static str, u64>,
+}
+
+fn registry() -> std::sync::MutexGuard<'static, Registry> {
+    REGISTRY.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Start the uptime clock. Called once when the web server starts.
+pub fn init() {
+    Lazy::force(&STARTED);
+}
+
+/// Record one finished HTTP request. `route` should be the matched route
+/// pattern, not the concrete URL, to keep label cardinality bounded.
+pub fn observe_http(method: &str, route: &str, status: u16, elapsed: Duration) {
+    let mut reg = registry();
+    *reg.http_requests
+        .entry((method.to_string(), route.to_string(), status))
+        .or_default() += 1;
+    reg.http_latency
+        .entry((method.to_string(), route.to_string()))
+        .or_default()
+        .observe(elapsed.as_secs_f64());
+}
+
+/// Record the wall time of one `git` invocation.
+pub fn observe_git(args: &[&str], elapsed: Duration) {
+    registry()
+        .git_duration
+        .entry(git_subcommand(args).to_string())
+        .or_default()
+        .observe(elapsed.as_secs_f64());
+}
+
+/// First real subcommand in `args`, skipping global options such as
+/// `-C <path>` / `-c key=value`.
+fn git_subcommand<'a>(args: &[&'a str]) -> &'a str {
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match *arg {
+            "-C" | "-c" | "--git-dir" | "--work-tree" => {
+                iter.next();
+            }
+            a if a.starts_with('-') => {}
+            a => return a,
+        }
+    }
+    "unknown"
+}
+
+/// Count raw filesystem events delivered to the edit watcher.
+pub fn record_watcher_fs_events(n: u64) {
+    WATCHER_FS_EVENTS.fetch_add(n, Ordering::Relaxed);
+}
+
+/// Count edit events the watcher actually recorded (after filtering).
+pub fn record_watcher_edit_events(n: u64) {
+    WATCHER_EDIT_EVENTS.fetch_add(n, Ordering::Relaxed);
+}
+
+/// RAII guard tracking one open WebSocket connection of the given kind.
+pub struct WsConnection {
+    kind: &'static str,
+}
+
+/// Mark a WebSocket connection as open until the returned guard is dropped.
+pub fn ws_connection(kind: &'static str) -> WsConnection {
+    let mut reg = registry();
+    *reg.ws_active.entry(kind).or_default() += 1;
+    *reg.ws_total.entry(kind).or_default() += 1;
+    WsConnection { kind }
+}
+
+impl Drop for WsConnection {
+    fn drop(&mut self) {
+        if let Some(active) = registry().ws_active.get_mut(self.kind) {
+            *active -= 1;
+        }
+    }
+}
+
+/// Render every metric in the Prometheus text exposition format (0.0.4).
+pub fn render() -> String {
+    let mut out = String::new();
+    let reg = registry();
+
+    header(
+        &mut out,
+        "grove_build_info",
+        "gauge",
+        "Grove build information",
+    );
+    let _ = writeln!(
+        out,
+        "grove_build_info{{version=\"{}\"}} 1",
+        env!("CARGO_PKG_VERSION")
+    );
+
+    header(
+        &mut out,
+        "grove_uptime_seconds",
+        "gauge",
+        "Seconds since the server started",
+    );
+    let _ = writeln!(
+        out,
+        "grove_uptime_seconds {}",
+        STARTED.elapsed().as_secs_f64()
+    );
+
+    header(
+        &mut out,
+        "grove_http_requests_total",
+        "counter",
+        "HTTP requests handled, by route and status",
+    );
+    let mut requests: Vec<_> = reg.http_requests.iter().collect();
+    requests.sort();
+    for ((method, route, status), n) in requests {
+        let _ = writeln!(
+            out,
+            "grove_http_requests_total{{method=\"{}\",route=\"{}\",status=\"{}\"}} {}",
+            escape(method),
+            escape(route),
+            status,
+            n
+        );
+    }
+
+    header(
+        &mut out,
+        "grove_http_request_duration_seconds",
+        "histogram",
+        "HTTP request latency",
+    );
+    let mut latency: Vec<_> = reg.http_latency.iter().collect();
+    latency.sort_by(|a, b| a.0.cmp(b.0));
+    for ((method, route), hist) in latency {
+        let labels = format!("method=\"{}\",route=\"{}\"", escape(method), escape(route));
+        write_histogram(
+            &mut out,
+            "grove_http_request_duration_seconds",
+            &labels,
+            hist,
+        );
+    }
+
+    header(
+        &mut out,
+        "grove_acp_sessions_active",
+        "gauge",
+        "Live ACP agent sessions",
+    );
+    let _ = writeln!(
+        out,
+        "grove_acp_sessions_active {}",
+        crate::acp::session_count()
+    );
+
+    header(
+        &mut out,
+        "grove_ws_connections_active",
+        "gauge",
+        "Open WebSocket connections, by endpoint",
+    );
+    let mut ws_active: Vec<_> = reg.ws_active.iter().collect();
+    ws_active.sort();
+    for (kind, n) in ws_active {
+        let _ = writeln!(
+            out,
+            "grove_ws_connections_active{{kind=\"{}\"}} {}",
+            kind, n
+        );
+    }
+
+    header(
+        &mut out,
+        "grove_ws_connections_total",
+        "counter",
+        "WebSocket connections accepted, by endpoint",
+    );
+    let mut ws_total: Vec<_> = reg.ws_total.iter().collect();
+    ws_total.sort();
+    for (kind, n) in ws_total {
+        let _ = writeln!(out, "grove_ws_connections_total{{kind=\"{}\"}} {}", kind, n);
+    }
+
+    header(
+        &mut out,
+        "grove_git_command_duration_seconds",
+        "histogram",
+        "Wall time of git invocations, by subcommand",
+    );
+    let mut git: Vec<_> = reg.git_duration.iter().collect();
+    git.sort_by(|a, b| a.0.cmp(b.0));
+    for (sub, hist) in git {
+        let labels = format!("subcommand=\"{}\"", escape(sub));
+        write_histogram(
+            &mut out,
+            "grove_git_command_duration_seconds",
+            &labels,
+            hist,
+        );
+    }
+
+    header(
+        &mut out,
+        "grove_watcher_fs_events_total",
+        "counter",
+        "Raw filesystem events received by the edit watcher",
+    );
+    let _ = writeln!(
+        out,
+        "grove_watcher_fs_events_total {}",
+        WATCHER_FS_EVENTS.load(Ordering::Relaxed)
+    );
+
+    header(
+        &mut out,
+        "grove_watcher_edit_events_total",
+        "counter",
+        "File edits recorded by the edit watcher after filtering",
+    );
+    let _ = writeln!(
+        out,
+        "grove_watcher_edit_events_total {}",
+        WATCHER_EDIT_EVENTS.load(Ordering::Relaxed)
+    );
+
+    out
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn write_histogram(out: &mut String, name: &str, labels: &str, hist: &Histogram) {
+    let mut cumulative = 0;
+    for (i, le) in BUCKETS.iter().enumerate() {
+        cumulative += hist.counts[i];
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{},le=\"{}\"}} {}",
+            name, labels, le, cumulative
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{}_bucket{{{},le=\"+Inf\"}} {}",
+        name, labels, hist.count
+    );
+    let _ = writeln!(out, "{}_sum{{{}}} {}", name, labels, hist.sum);
+    let _ = writeln!(out, "{}_count{{{}}} {}", name, labels, hist.count);
+}
+
+/// Escape a label value per the exposition format.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_buckets_are_cumulative() {
+        let mut hist = Histogram::default();
+        hist.observe(0.0005);
+        hist.observe(0.02);
+        hist.observe(30.0);
+
+        let mut out = String::new();
+        write_histogram(&mut out, "h", "a=\"b\"", &hist);
+        assert!(out.contains("h_bucket{a=\"b\",le=\"0.001\"} 1\n"));
+        assert!(out.contains("h_bucket{a=\"b\",le=\"0.025\"} 2\n"));
+        assert!(out.contains("h_bucket{a=\"b\",le=\"10\"} 2\n"));
+        assert!(out.contains("h_bucket{a=\"b\",le=\"+Inf\"} 3\n"));
+        assert!(out.contains("h_count{a=\"b\"} 3\n"));
+    }
+
+    #[test]
+    fn git_subcommand_skips_global_options() {
+        assert_eq!(git_subcommand(&["status", "--porcelain"]), "status");
+        assert_eq!(git_subcommand(&["-C", "/tmp/repo", "log"]), "log");
+        assert_eq!(
+            git_subcommand(&["-c", "core.quotepath=false", "diff"]),
+            "diff"
+        );
+        assert_eq!(git_subcommand(&["--no-pager", "show"]), "show");
+        assert_eq!(git_subcommand(&[]), "unknown");
+    }
+
+    #[test]
+    fn render_includes_recorded_series() {
+        observe_http("GET", "/api/v1/metrics-test", 200, Duration::from_millis(3));
+        observe_git(&["metrics-test-sub"], Duration::from_millis(7));
+        let conn = ws_connection("metrics-test");
+
+        let out = render();
+        assert!(out.contains(
+            "grove_http_requests_total{method=\"GET\",route=\"/api/v1/metrics-test\",status=\"200\"} 1"
+        ));
+        assert!(out.contains(
+            "grove_git_command_duration_seconds_count{subcommand=\"metrics-test-sub\"} 1"
+        ));
+        assert!(out.contains("grove_ws_connections_active{kind=\"metrics-test\"} 1"));
+
+        drop(conn);
+        assert!(render().contains("grove_ws_connections_active{kind=\"metrics-test\"} 0"));
+    }
+
+    #[test]
+    fn escape_label_values() {
+        assert_eq!(escape("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+}
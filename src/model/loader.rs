@@ -8,12 +8,16 @@
 //!
 //! 两者都会读取 Local Task,但对外的数据契约里 Local Task 和 worktree 任务是隔离的
 
+use std::cmp::Reverse;
 use std::path::Path;
 
+use rayon::prelude::*;
+
 use crate::git;
+use crate::session::agent_state::{self, AgentState};
 use crate::session::{self, SessionType};
 use crate::storage::tasks::{self, Task, TaskStatus, LOCAL_TASK_ID};
-use crate::storage::workspace::{self, project_hash};
+use crate::storage::workspace::{self, project_hash, ProjectType};
 
 use super::{FileChanges, Worktree, WorktreeStatus};
 
@@ -32,10 +36,7 @@ fn ensure_local_task_synced(project_path: &str) -> (Vec<Task>, String) {
     let load = || match tasks::load_tasks(&project_key) {
         Ok(t) => t,
         Err(e) => {
-            eprintln!(
-                "Warning: failed to load active tasks for {}: {}",
-                project_key, e
-            );
+            tracing::warn!("failed to load active tasks for {}: {}", project_key, e);
             Vec::new()
         }
     };
@@ -65,10 +66,7 @@ fn ensure_local_task_synced(project_path: &str) -> (Vec<Task>, String) {
                     .to_string()
             });
         if let Err(e) = tasks::ensure_local_task(&project_key, project_path, &project_name) {
-            eprintln!(
-                "Warning: failed to backfill Local Task for {}: {}",
-                project_key, e
-            );
+            tracing::warn!("failed to backfill Local Task for {}: {}", project_key, e);
         } else {
             active_tasks = load();
         }
@@ -144,8 +142,8 @@ pub fn load_worktrees(project_path: &str) -> Vec<Worktree> {
         .map(|task| task_to_worktree(task, &project_key, project_path, merging_commit.as_deref()))
         .collect();
 
-    // 按 updated_at 降序排列
-    worktrees.sort_by_key(|b| std::cmp::Reverse(b.updated_at));
+    // 置顶在前，其余按 updated_at 降序排列
+    worktrees.sort_by_key(|b| Reverse((b.pinned, b.updated_at)));
     worktrees
 }
 
@@ -171,10 +169,7 @@ pub fn load_archived_worktrees(project_path: &str) -> Vec<Worktree> {
     let archived_tasks = match tasks::load_archived_tasks(&project_key) {
         Ok(t) => t,
         Err(e) => {
-            eprintln!(
-                "Warning: failed to load archived tasks for {}: {}",
-                project_key, e
-            );
+            tracing::warn!("failed to load archived tasks for {}: {}", project_key, e);
             Vec::new()
         }
     };
@@ -183,10 +178,43 @@ pub fn load_archived_worktrees(project_path: &str) -> Vec<Worktree> {
         .into_iter()
         .map(archived_task_to_worktree)
         .collect();
-    archived.sort_by_key(|b| std::cmp::Reverse(b.updated_at));
+    archived.sort_by_key(|b| Reverse((b.pinned, b.updated_at)));
     archived
 }
 
+/// 跨项目任务：worktree + 所属项目
+#[derive(Debug, Clone)]
+pub struct ProjectWorktree {
+    pub project_name: String,
+    pub project_path: String,
+    pub worktree: Worktree,
+}
+
+/// 加载所有项目的活跃任务(含 Local Task),用于跨项目任务列表
+///
+/// 跳过 Studio 项目和目录已丢失的项目;置顶优先,其余按最近更新排序。
+pub fn load_all_worktrees() -> Vec<ProjectWorktree> {
+    let projects = workspace::load_projects().unwrap_or_default();
+
+    let mut all: Vec<ProjectWorktree> = projects
+        .par_iter()
+        .filter(|p| p.project_type != ProjectType::Studio && Path::new(&p.path).exists())
+        .flat_map_iter(|p| {
+            let local = load_local_task(&p.path);
+            load_worktrees(&p.path)
+                .into_iter()
+                .chain(local)
+                .map(move |worktree| ProjectWorktree {
+                    project_name: p.name.clone(),
+                    project_path: p.path.clone(),
+                    worktree,
+                })
+        })
+        .collect();
+    all.sort_by_key(|t| Reverse((t.worktree.pinned, t.worktree.updated_at)));
+    all
+}
+
 /// 将 Archived Task 转换为 UI Worktree(直接标记为 Archived 状态)
 fn archived_task_to_worktree(task: Task) -> Worktree {
     let resolved_session_type = session::resolve_session_type(&task.multiplexer);
@@ -203,7 +231,12 @@ fn archived_task_to_worktree(task: Task) -> Worktree {
         target: task.target,
         status: WorktreeStatus::Archived,
         commits_behind: None,
-        file_changes: FileChanges::default(),
+        // 归档时记录的代码统计快照
+        file_changes: FileChanges::new(
+            task.code_additions,
+            task.code_deletions,
+            task.files_changed,
+        ),
         archived: true,
         path: task.worktree_path,
         multiplexer: mux_str.to_string(),
@@ -211,6 +244,9 @@ fn archived_task_to_worktree(task: Task) -> Worktree {
         updated_at: task.updated_at,
         created_by: task.created_by,
         is_local: false,
+        agent_state: None,
+        assignee: task.assignee,
+        pinned: task.pinned,
     }
 }
 
@@ -280,6 +316,9 @@ fn task_to_worktree(
             updated_at: task.updated_at,
             created_by: task.created_by.clone(),
             is_local: true,
+            agent_state: agent_state(task, project, status, &resolved_session_type),
+            assignee: task.assignee.clone(),
+            pinned: task.pinned,
         };
     }
 
@@ -359,5 +398,22 @@ fn task_to_worktree(
         updated_at: task.updated_at,
         created_by: task.created_by.clone(),
         is_local: false,
+        agent_state: agent_state(task, project, status, &resolved_session_type),
+        assignee: task.assignee.clone(),
+        pinned: task.pinned,
+    }
+}
+
+/// Live 的 tmux session 推断 agent 状态（zellij / acp 不支持）
+fn agent_state(
+    task: &Task,
+    project: &str,
+    status: WorktreeStatus,
+    session_type: &SessionType,
+) -> Option<AgentState> {
+    if status != WorktreeStatus::Live || *session_type != SessionType::Tmux {
+        return None;
     }
+    let session_key = session::resolve_session_name(&task.session_name, project, &task.id);
+    agent_state::infer_tmux_state(&session_key)
 }
@@ -1,6 +1,10 @@
 pub mod loader;
+pub mod views;
 pub mod workspace;
 pub mod worktree;
 
 pub use workspace::{ProjectInfo, WorkspaceState};
-pub use worktree::{format_relative_time, FileChanges, ProjectTab, Worktree, WorktreeStatus};
+pub use worktree::{
+    format_relative_time, format_time, set_time_display, FileChanges, ProjectTab, Worktree,
+    WorktreeStatus,
+};
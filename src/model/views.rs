@@ -0,0 +1,91 @@
+//! 任务视图：按过滤条件与排序方式整理 worktree 列表（TUI 与 API 共用）
+
+use std::cmp::Ordering;
+
+use rayon::prelude::*;
+
+use super::worktree::{FileChanges, Worktree, WorktreeStatus};
+use crate::git;
+use crate::storage::task_views::{TaskSort, TaskViewFilter};
+use crate::storage::tasks::TaskAssignee;
+
+/// 任务是否满足视图过滤条件
+pub fn matches(filter: &TaskViewFilter, wt: &Worktree) -> bool {
+    if filter.pinned_only && !wt.pinned {
+        return false;
+    }
+    if !filter.statuses.is_empty() && !filter.statuses.iter().any(|s| s == wt.status.as_str()) {
+        return false;
+    }
+    if let Some(state) = &filter.agent_state {
+        if !wt
+            .agent_state
+            .is_some_and(|a| a.label().eq_ignore_ascii_case(state))
+        {
+            return false;
+        }
+    }
+    if let Some(assignee) = &filter.assignee {
+        let ok = matches!(
+            (assignee.as_str(), &wt.assignee),
+            ("human", Some(TaskAssignee::Human)) | ("agent", Some(TaskAssignee::Agent { .. }))
+        );
+        if !ok {
+            return false;
+        }
+    }
+    if let Some(query) = filter.query.as_deref().filter(|q| !q.is_empty()) {
+        let query = query.to_lowercase();
+        if !wt.task_name.to_lowercase().contains(&query)
+            && !wt.branch.to_lowercase().contains(&query)
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// 状态排序优先级：需要处理的在前
+fn status_rank(status: &WorktreeStatus) -> u8 {
+    match status {
+        WorktreeStatus::Conflict => 0,
+        WorktreeStatus::Broken | WorktreeStatus::Error => 1,
+        WorktreeStatus::Live => 2,
+        WorktreeStatus::Idle => 3,
+        WorktreeStatus::Merged => 4,
+        WorktreeStatus::Archived => 5,
+    }
+}
+
+/// 排序：Local Task 与置顶任务始终在前，其余按 `sort`
+///
+/// `DiffSize` 需先调用 [`load_diff_sizes`] 填充 `file_changes`。
+pub fn sort_worktrees(worktrees: &mut [Worktree], sort: TaskSort) {
+    let diff = |w: &Worktree| w.file_changes.additions + w.file_changes.deletions;
+    worktrees.sort_by(|a, b| {
+        b.is_local
+            .cmp(&a.is_local)
+            .then_with(|| b.pinned.cmp(&a.pinned))
+            .then_with(|| match sort {
+                TaskSort::Activity => Ordering::Equal,
+                TaskSort::DiffSize => diff(b).cmp(&diff(a)),
+                TaskSort::Status => status_rank(&a.status).cmp(&status_rank(&b.status)),
+            })
+            .then_with(|| b.updated_at.cmp(&a.updated_at))
+    });
+}
+
+/// 为活跃任务计算相对 target 的 diff 统计（并行，worktree 缺失时保持 0）
+///
+/// 归档任务的统计在加载时已从归档快照填充。
+pub fn load_diff_sizes(worktrees: &mut [Worktree]) {
+    worktrees
+        .par_iter_mut()
+        .filter(|w| !w.archived && std::path::Path::new(&w.path).exists())
+        .for_each(|w| {
+            if let Ok((additions, deletions, files_changed)) = git::file_changes(&w.path, &w.target)
+            {
+                w.file_changes = FileChanges::new(additions, deletions, files_changed);
+            }
+        });
+}
@@ -3,6 +3,7 @@
 
 use chrono::{DateTime, Utc};
 
+use super::loader::{self, ProjectWorktree};
 use crate::storage::tasks;
 use crate::storage::workspace::{self as storage, project_hash, ProjectType};
 
@@ -24,6 +25,8 @@ pub struct ProjectInfo {
     pub is_git_repo: bool,
     /// 文件系统路径是否还存在(false = "missing" 状态)
     pub exists: bool,
+    /// 是否置顶
+    pub pinned: bool,
 }
 
 /// Workspace 状态
@@ -43,6 +46,12 @@ pub struct WorkspaceState {
     pub search_query: String,
     /// 过滤后的索引
     pub filtered_indices: Vec<usize>,
+    /// 是否显示跨项目任务列表（All Tasks tab）
+    pub all_tasks_mode: bool,
+    /// 跨项目任务列表
+    pub all_tasks: Vec<ProjectWorktree>,
+    /// 跨项目任务列表选中项
+    pub all_tasks_selected: Option<usize>,
 }
 
 impl WorkspaceState {
@@ -77,6 +86,7 @@ impl WorkspaceState {
                     live_count: 0,
                     is_git_repo,
                     exists,
+                    pinned: p.pinned,
                 }
             })
             .collect();
@@ -123,6 +133,19 @@ impl WorkspaceState {
             .and_then(|&i| self.projects.get(i))
     }
 
+    /// 按路径选中项目（在过滤列表中），找不到返回 false
+    pub fn select_project(&mut self, path: &str) -> bool {
+        let Some(pos) = self
+            .filtered_indices
+            .iter()
+            .position(|&i| self.projects.get(i).is_some_and(|p| p.path == path))
+        else {
+            return false;
+        };
+        self.selected_index = Some(pos);
+        true
+    }
+
     /// 向右移动选择
     pub fn select_right(&mut self) {
         let count = self.filtered_indices.len();
@@ -240,6 +263,60 @@ impl WorkspaceState {
     /// 刷新数据（重新加载项目列表）
     pub fn refresh(&mut self) {
         self.reload_projects();
+        if self.all_tasks_mode {
+            self.reload_all_tasks();
+        }
+    }
+
+    // ========== All Tasks tab ==========
+
+    /// 在项目网格与跨项目任务列表之间切换（进入时加载任务）
+    pub fn toggle_all_tasks(&mut self) {
+        self.all_tasks_mode = !self.all_tasks_mode;
+        self.search_mode = false;
+        if self.all_tasks_mode {
+            self.reload_all_tasks();
+        }
+    }
+
+    /// 重新加载跨项目任务列表（尽量保持选中同一个任务）
+    pub fn reload_all_tasks(&mut self) {
+        let selected = self
+            .selected_all_task()
+            .map(|t| (t.project_path.clone(), t.worktree.id.clone()));
+        self.all_tasks = loader::load_all_worktrees();
+        self.all_tasks_selected = selected
+            .and_then(|(path, id)| {
+                self.all_tasks
+                    .iter()
+                    .position(|t| t.project_path == path && t.worktree.id == id)
+            })
+            .or_else(|| (!self.all_tasks.is_empty()).then_some(0));
+    }
+
+    /// 当前选中的跨项目任务
+    pub fn selected_all_task(&self) -> Option<&ProjectWorktree> {
+        self.all_tasks_selected.and_then(|i| self.all_tasks.get(i))
+    }
+
+    /// 跨项目任务列表：下一项
+    pub fn all_tasks_next(&mut self) {
+        let count = self.all_tasks.len();
+        if count == 0 {
+            return;
+        }
+        let i = self.all_tasks_selected.unwrap_or(0);
+        self.all_tasks_selected = Some((i + 1) % count);
+    }
+
+    /// 跨项目任务列表：上一项
+    pub fn all_tasks_previous(&mut self) {
+        let count = self.all_tasks.len();
+        if count == 0 {
+            return;
+        }
+        let i = self.all_tasks_selected.unwrap_or(0);
+        self.all_tasks_selected = Some(if i == 0 { count - 1 } else { i - 1 });
     }
 
     /// 确保选中项在可见区域内，更新 grid_scroll
@@ -1,4 +1,11 @@
-use chrono::{DateTime, Utc};
+use std::sync::RwLock;
+
+use chrono::{DateTime, Local, Utc};
+use once_cell::sync::Lazy;
+
+use crate::session::agent_state::AgentState;
+use crate::storage::config::{locale_datetime_pattern, DisplayConfig, TimeFormat};
+use crate::storage::tasks::TaskAssignee;
 
 /// Worktree 的运行状态
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -35,6 +42,39 @@ impl WorktreeStatus {
         }
     }
 
+    /// 减少装饰模式下的 ASCII 图标（配合文字标签使用）
+    pub fn ascii_icon(&self) -> &'static str {
+        match self {
+            WorktreeStatus::Idle => "-",
+            WorktreeStatus::Live => "*",
+            WorktreeStatus::Merged => "+",
+            WorktreeStatus::Conflict => "!",
+            WorktreeStatus::Broken | WorktreeStatus::Error => "x",
+            WorktreeStatus::Archived => "~",
+        }
+    }
+
+    /// 当前装饰模式下的状态图标
+    pub fn display_icon(&self) -> &'static str {
+        if crate::theme::reduced_decoration() {
+            self.ascii_icon()
+        } else {
+            self.icon()
+        }
+    }
+
+    /// API / 视图过滤使用的状态 key（Error 归入 broken）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorktreeStatus::Live => "live",
+            WorktreeStatus::Idle => "idle",
+            WorktreeStatus::Merged => "merged",
+            WorktreeStatus::Conflict => "conflict",
+            WorktreeStatus::Broken | WorktreeStatus::Error => "broken",
+            WorktreeStatus::Archived => "archived",
+        }
+    }
+
     /// 返回状态文字标签
     pub fn label(&self) -> &'static str {
         match self {
@@ -59,7 +99,6 @@ pub struct FileChanges {
 }
 
 impl FileChanges {
-    #[allow(dead_code)]
     pub fn new(additions: u32, deletions: u32, files_changed: u32) -> Self {
         Self {
             additions,
@@ -84,8 +123,7 @@ pub struct Worktree {
     pub status: WorktreeStatus,
     /// 落后 target branch 的 commit 数（None 表示无需显示）
     pub commits_behind: Option<u32>,
-    /// 文件变更统计（overview 阶段不再计算）
-    #[allow(dead_code)]
+    /// 文件变更统计（overview 阶段不计算；按 diff 排序时或归档快照中填充）
     pub file_changes: FileChanges,
     /// 是否已归档
     pub archived: bool,
@@ -103,6 +141,42 @@ pub struct Worktree {
     pub created_by: String,
     /// 是否为 Local Task（指向主仓库，非 worktree）
     pub is_local: bool,
+    /// 终端 agent 推断状态（仅 Live 的 tmux session）
+    pub agent_state: Option<AgentState>,
+    /// 当前负责人（human / agent chat）
+    pub assignee: Option<TaskAssignee>,
+    /// 是否置顶
+    pub pinned: bool,
+}
+
+/// TUI 时间显示设置（启动时从 config 载入，切换时更新）
+static TIME_DISPLAY: Lazy<RwLock<(TimeFormat, &'static str)>> =
+    Lazy::new(|| RwLock::new(resolve_time_display(&DisplayConfig::default())));
+
+fn resolve_time_display(display: &DisplayConfig) -> (TimeFormat, &'static str) {
+    (
+        display.time_format,
+        locale_datetime_pattern(&display.effective_locale()),
+    )
+}
+
+/// 更新时间显示设置
+pub fn set_time_display(display: &DisplayConfig) {
+    if let Ok(mut current) = TIME_DISPLAY.write() {
+        *current = resolve_time_display(display);
+    }
+}
+
+/// 按 `[display]` 设置格式化时间（相对或按 locale 的本地绝对时间）
+pub fn format_time(dt: DateTime<Utc>) -> String {
+    let (format, pattern) = TIME_DISPLAY
+        .read()
+        .map(|d| *d)
+        .unwrap_or((TimeFormat::Relative, ""));
+    match format {
+        TimeFormat::Relative => format_relative_time(dt),
+        TimeFormat::Absolute => dt.with_timezone(&Local).format(pattern).to_string(),
+    }
 }
 
 /// 格式化相对时间
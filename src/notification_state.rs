@@ -1,10 +1,26 @@
 //! Notification 状态管理
 //!
-//! 管理所有与 Hook 通知相关的状态，包括当前项目通知和 Workspace 级别通知。
+//! 管理所有与 Hook 通知相关的状态，包括当前项目通知和 Workspace 级别通知，
+//! 以及通知中心的历史记录（Toast + Hook 通知，按时间倒序）。
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use crate::hooks::HookEntry;
+use chrono::{DateTime, Local, Utc};
+
+use crate::hooks::{HookEntry, NotificationLevel};
+
+/// 通知中心保留的最大条数
+const HISTORY_LIMIT: usize = 200;
+
+/// 通知中心的一条记录
+#[derive(Debug, Clone)]
+pub struct NotificationRecord {
+    pub timestamp: DateTime<Local>,
+    pub level: NotificationLevel,
+    /// 来源：Toast 为 "grove"，Hook 通知为 task（Workspace 下为 "project/task"）
+    pub source: String,
+    pub message: String,
+}
 
 /// Notification 状态
 #[derive(Debug)]
@@ -13,6 +29,10 @@ pub struct NotificationState {
     pub notifications: HashMap<String, HookEntry>,
     /// Workspace 级别的通知数据 (project_name -> task_id -> HookEntry)
     pub workspace_notifications: HashMap<String, HashMap<String, HookEntry>>,
+    /// 通知中心历史（最新在前）
+    pub history: VecDeque<NotificationRecord>,
+    /// 已记入历史的 Hook 通知（source + timestamp），避免每次刷新重复记录
+    seen_hooks: HashSet<(String, DateTime<Utc>)>,
 }
 
 impl NotificationState {
@@ -21,6 +41,8 @@ impl NotificationState {
         Self {
             notifications: HashMap::new(),
             workspace_notifications: HashMap::new(),
+            history: VecDeque::new(),
+            seen_hooks: HashSet::new(),
         }
     }
 
@@ -29,10 +51,77 @@ impl NotificationState {
         notifications: HashMap<String, HookEntry>,
         workspace_notifications: HashMap<String, HashMap<String, HookEntry>>,
     ) -> Self {
-        Self {
-            notifications,
-            workspace_notifications,
+        let mut state = Self::new();
+        state.set_notifications(notifications);
+        state.set_workspace_notifications(workspace_notifications);
+        state
+    }
+
+    /// 替换当前项目的通知，新出现的条目记入历史
+    pub fn set_notifications(&mut self, notifications: HashMap<String, HookEntry>) {
+        for (task_id, entry) in &notifications {
+            self.record_hook(task_id.clone(), entry);
+        }
+        self.notifications = notifications;
+    }
+
+    /// 替换 Workspace 级别的通知，新出现的条目记入历史
+    pub fn set_workspace_notifications(
+        &mut self,
+        workspace_notifications: HashMap<String, HashMap<String, HookEntry>>,
+    ) {
+        for (project, tasks) in &workspace_notifications {
+            for (task_id, entry) in tasks {
+                self.record_hook(format!("{}/{}", project, task_id), entry);
+            }
         }
+        self.workspace_notifications = workspace_notifications;
+    }
+
+    fn record_hook(&mut self, source: String, entry: &HookEntry) {
+        if !self.seen_hooks.insert((source.clone(), entry.timestamp)) {
+            return;
+        }
+        let message = entry.message.clone().unwrap_or_else(|| {
+            match entry.level {
+                NotificationLevel::Notice => "Task finished",
+                NotificationLevel::Warn => "Task needs attention",
+                NotificationLevel::Critical => "Task failed",
+            }
+            .to_string()
+        });
+        self.insert_record(NotificationRecord {
+            timestamp: entry.timestamp.with_timezone(&Local),
+            level: entry.level,
+            source,
+            message,
+        });
+    }
+
+    /// 记录一条 Toast（失败类消息按 Critical 记）
+    pub fn record_toast(&mut self, message: &str) {
+        self.insert_record(NotificationRecord {
+            timestamp: Local::now(),
+            level: toast_level(message),
+            source: "grove".to_string(),
+            message: message.to_string(),
+        });
+    }
+
+    /// 按时间插入（保持最新在前），超出上限丢弃最旧的
+    fn insert_record(&mut self, record: NotificationRecord) {
+        let pos = self
+            .history
+            .iter()
+            .position(|r| r.timestamp <= record.timestamp)
+            .unwrap_or(self.history.len());
+        self.history.insert(pos, record);
+        self.history.truncate(HISTORY_LIMIT);
+    }
+
+    /// 清空通知中心
+    pub fn clear_history(&mut self) {
+        self.history.clear();
     }
 
     /// 添加当前项目的通知
@@ -118,6 +207,19 @@ impl NotificationState {
     }
 }
 
+/// Toast 没有显式级别，按文案判断是否为错误
+fn toast_level(message: &str) -> NotificationLevel {
+    let lower = message.to_lowercase();
+    if ["fail", "error", "cannot", "can't", "unable"]
+        .iter()
+        .any(|w| lower.contains(w))
+    {
+        NotificationLevel::Critical
+    } else {
+        NotificationLevel::Notice
+    }
+}
+
 impl Default for NotificationState {
     fn default() -> Self {
         Self::new()
@@ -131,8 +233,6 @@ impl Default for NotificationState {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::hooks::NotificationLevel;
-    use chrono::Utc;
 
     fn create_test_entry() -> HookEntry {
         HookEntry {
@@ -228,6 +328,37 @@ mod tests {
         assert_eq!(state.workspace_notification_count(), 1);
     }
 
+    #[test]
+    fn test_hook_notifications_recorded_once() {
+        let mut state = NotificationState::new();
+        let entry = create_test_entry();
+        let mut map = HashMap::new();
+        map.insert("task1".to_string(), entry.clone());
+
+        state.set_notifications(map.clone());
+        state.set_notifications(map);
+        assert_eq!(state.history.len(), 1);
+        assert_eq!(state.history[0].source, "task1");
+        assert_eq!(state.history[0].message, "Test message");
+    }
+
+    #[test]
+    fn test_history_newest_first_and_levels() {
+        let mut state = NotificationState::new();
+        state.record_toast("Task created");
+        state.record_toast("Failed to merge: conflict");
+        assert_eq!(state.history.len(), 2);
+        assert_eq!(state.history[0].level, NotificationLevel::Critical);
+        assert_eq!(state.history[1].level, NotificationLevel::Notice);
+
+        for i in 0..HISTORY_LIMIT {
+            state.record_toast(&format!("toast {}", i));
+        }
+        assert_eq!(state.history.len(), HISTORY_LIMIT);
+        state.clear_history();
+        assert!(state.history.is_empty());
+    }
+
     #[test]
     fn test_default_trait() {
         let state = NotificationState::default();
@@ -0,0 +1,171 @@
+//! Agent hook installation
+//!
+//! Writes Grove notification hooks into an agent's per-worktree settings so
+//! that turn-end / needs-input events flow into Grove without manual setup.
+//! Currently targets Claude Code (`.claude/settings.local.json`).
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use crate::error::{GroveError, Result};
+use crate::git;
+
+/// Worktree-relative path of the Claude Code local settings file.
+pub const CLAUDE_SETTINGS_PATH: &str = ".claude/settings.local.json";
+
+/// Claude Code hook events mapped to the `grove hooks` command they run.
+const CLAUDE_HOOKS: &[(&str, &str)] = &[
+    ("Stop", "grove hooks notice -m \"Turn finished\""),
+    (
+        "Notification",
+        "grove hooks warn -m \"Needs your attention\"",
+    ),
+];
+
+/// Install Grove hooks into the Claude Code settings of `worktree`.
+///
+/// Existing settings and hooks are preserved; events that already run a
+/// `grove hooks` command are left untouched. The settings file is added to
+/// the repository's `info/exclude` so it never shows up as a change.
+/// Returns `true` if the settings file was written.
+pub fn install_claude_hooks(worktree: &Path) -> Result<bool> {
+    let settings_path = worktree.join(CLAUDE_SETTINGS_PATH);
+    let existing = match fs::read_to_string(&settings_path) {
+        Ok(content) if !content.trim().is_empty() => {
+            serde_json::from_str(&content).map_err(|e| {
+                GroveError::storage(format!(
+                    "Invalid JSON in {}: {}",
+                    settings_path.display(),
+                    e
+                ))
+            })?
+        }
+        _ => json!({}),
+    };
+
+    let (merged, changed) = merge_claude_hooks(existing);
+    if changed {
+        if let Some(parent) = settings_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(&merged)
+            .map_err(|e| GroveError::storage(e.to_string()))?;
+        fs::write(&settings_path, content + "\n")?;
+    }
+
+    // Best effort: a non-git directory simply has nothing to exclude from.
+    let _ = exclude_from_git(worktree, CLAUDE_SETTINGS_PATH);
+    Ok(changed)
+}
+
+/// Merge Grove's hook entries into a Claude Code settings object.
+/// Returns the merged settings and whether anything was added.
+fn merge_claude_hooks(mut settings: Value) -> (Value, bool) {
+    if !settings.is_object() {
+        settings = json!({});
+    }
+    let root = settings.as_object_mut().expect("settings is an object");
+    let hooks = root.entry("hooks").or_insert_with(|| json!({}));
+    if !hooks.is_object() {
+        *hooks = json!({});
+    }
+    let hooks = hooks.as_object_mut().expect("hooks is an object");
+
+    let mut changed = false;
+    for (event, command) in CLAUDE_HOOKS {
+        let groups = hooks.entry(*event).or_insert_with(|| json!([]));
+        if !groups.is_array() {
+            *groups = json!([]);
+        }
+        let groups = groups.as_array_mut().expect("event hooks is an array");
+        if groups.iter().any(runs_grove_hook) {
+            continue;
+        }
+        groups.push(json!({
+            "hooks": [{ "type": "command", "command": command }]
+        }));
+        changed = true;
+    }
+    (settings, changed)
+}
+
+/// Whether a Claude Code hook group already invokes `grove hooks`.
+fn runs_grove_hook(group: &Value) -> bool {
+    group
+        .get("hooks")
+        .and_then(Value::as_array)
+        .map(|hooks| {
+            hooks.iter().any(|h| {
+                h.get("command")
+                    .and_then(Value::as_str)
+                    .is_some_and(|c| c.contains("grove hooks"))
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Append `pattern` to the repository's `info/exclude` unless already listed.
+fn exclude_from_git(worktree: &Path, pattern: &str) -> Result<()> {
+    let worktree_str = worktree.to_string_lossy();
+    let exclude = git::git_cmd(
+        &worktree_str,
+        &[
+            "rev-parse",
+            "--path-format=absolute",
+            "--git-path",
+            "info/exclude",
+        ],
+    )?;
+    let exclude = Path::new(&exclude);
+    let current = fs::read_to_string(exclude).unwrap_or_default();
+    if current.lines().any(|l| l.trim() == pattern) {
+        return Ok(());
+    }
+    if let Some(parent) = exclude.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut content = current;
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(pattern);
+    content.push('\n');
+    fs::write(exclude, content)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_adds_hooks_and_keeps_existing_settings() {
+        let existing = json!({
+            "permissions": { "allow": ["Bash(ls)"] },
+            "hooks": {
+                "Stop": [{ "hooks": [{ "type": "command", "command": "say done" }] }]
+            }
+        });
+        let (merged, changed) = merge_claude_hooks(existing);
+        assert!(changed);
+        assert_eq!(merged["permissions"]["allow"][0], "Bash(ls)");
+        let stop = merged["hooks"]["Stop"].as_array().unwrap();
+        assert_eq!(stop.len(), 2);
+        assert_eq!(stop[0]["hooks"][0]["command"], "say done");
+        assert!(stop[1]["hooks"][0]["command"]
+            .as_str()
+            .unwrap()
+            .starts_with("grove hooks notice"));
+        assert_eq!(merged["hooks"]["Notification"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn merge_is_idempotent() {
+        let (once, _) = merge_claude_hooks(json!({}));
+        let (twice, changed) = merge_claude_hooks(once.clone());
+        assert!(!changed);
+        assert_eq!(once, twice);
+    }
+}
@@ -0,0 +1,340 @@
+//! AutoLink management for existing task worktrees
+//!
+//! AutoLink symlinks are created once when a task is created. These
+//! operations let the user inspect them later, re-apply the configured
+//! patterns (e.g. after editing the config), and remove links that are no
+//! longer wanted or whose source has disappeared.
+//!
+//! Patterns configured with the `copy` or `hardlink` mode produce real files
+//! instead of symlinks. Those can drift from the main repo; they are reported
+//! as stale and can be refreshed on demand.
+//!
+//! The set of links for a task is the union of what was recorded in task
+//! storage at creation/re-sync time and the AutoLink entries in the shared
+//! git exclude file that are still links in this worktree (covers tasks
+//! created before links were recorded).
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::error::{GroveError, Result};
+use crate::fs_link;
+use crate::git;
+use crate::storage::config::{self, LinkMode};
+use crate::storage::tasks;
+
+/// Health of a single AutoLink path inside a worktree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LinkState {
+    /// Link exists and its source is reachable.
+    Ok,
+    /// Link exists but its source is gone.
+    Broken,
+    /// Nothing at this path any more.
+    Missing,
+    /// Something that is not a link now occupies the path.
+    NotLink,
+    /// Copy (or hardlinked tree) no longer matches its source.
+    Stale,
+}
+
+/// One AutoLink path of a task, relative to the worktree root.
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskLink {
+    pub path: String,
+    pub mode: LinkMode,
+    pub state: LinkState,
+}
+
+/// Load the task, rejecting tasks that cannot carry links.
+fn load_task(project_key: &str, task_id: &str) -> Result<tasks::Task> {
+    let task = tasks::get_task(project_key, task_id)?
+        .ok_or_else(|| GroveError::not_found(format!("task {task_id} not found")))?;
+    if task.is_local {
+        return Err(GroveError::invalid_data(
+            "the local task works in the main repository and has no AutoLinks",
+        ));
+    }
+    if !Path::new(&task.worktree_path).exists() {
+        return Err(GroveError::not_found(format!(
+            "worktree for task {task_id} does not exist"
+        )));
+    }
+    Ok(task)
+}
+
+/// Resolve the task's worktree, rejecting tasks that cannot carry links.
+fn task_worktree(project_key: &str, task_id: &str) -> Result<PathBuf> {
+    load_task(project_key, task_id).map(|task| PathBuf::from(task.worktree_path))
+}
+
+/// Main repo root the links point into.
+fn main_repo(project_path: &str) -> PathBuf {
+    PathBuf::from(
+        git::get_main_repo_path(project_path).unwrap_or_else(|_| project_path.to_string()),
+    )
+}
+
+/// Inspect what currently sits at `rel` inside `worktree`.
+fn link_state(worktree: &Path, source_root: &Path, rel: &str, mode: LinkMode) -> LinkState {
+    let path = worktree.join(rel);
+    if mode != LinkMode::Symlink {
+        return copy_state(&source_root.join(rel), &path);
+    }
+    if fs_link::is_link(&path) {
+        // `exists()` follows the link, so false means the source is gone
+        if path.exists() {
+            LinkState::Ok
+        } else {
+            LinkState::Broken
+        }
+    } else if path.symlink_metadata().is_ok() {
+        LinkState::NotLink
+    } else {
+        LinkState::Missing
+    }
+}
+
+/// State of a copied or hardlinked tree: stale when the source has files the
+/// copy lacks, or was modified after the copy was made.
+fn copy_state(source: &Path, target: &Path) -> LinkState {
+    if target.symlink_metadata().is_err() {
+        return LinkState::Missing;
+    }
+    if fs_link::is_link(target) {
+        return LinkState::NotLink;
+    }
+    let Ok(src) = fs_link::tree_stat(source) else {
+        return LinkState::Broken;
+    };
+    let Ok(dst) = fs_link::tree_stat(target) else {
+        return LinkState::Broken;
+    };
+    if src.files != dst.files || src.newest > dst.newest {
+        LinkState::Stale
+    } else {
+        LinkState::Ok
+    }
+}
+
+/// List all AutoLinks of a task with their current state.
+pub fn list_task_links(
+    project_path: &str,
+    project_key: &str,
+    task_id: &str,
+) -> Result<Vec<TaskLink>> {
+    let worktree = task_worktree(project_key, task_id)?;
+    Ok(collect_links(project_path, project_key, task_id, &worktree))
+}
+
+fn collect_links(
+    project_path: &str,
+    project_key: &str,
+    task_id: &str,
+    worktree: &Path,
+) -> Vec<TaskLink> {
+    let mut links: BTreeMap<String, LinkMode> = tasks::load_autolinks(project_key, task_id)
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+    for path in git::autolink_paths(project_path) {
+        if fs_link::is_link(&worktree.join(&path)) {
+            links.entry(path).or_insert(LinkMode::Symlink);
+        }
+    }
+
+    let source_root = main_repo(project_path);
+    links
+        .into_iter()
+        .map(|(path, mode)| TaskLink {
+            state: link_state(worktree, &source_root, &path, mode),
+            path,
+            mode,
+        })
+        .collect()
+}
+
+/// Re-apply the currently configured AutoLink patterns to a task worktree.
+///
+/// Patterns are expanded with the task's `{branch}` / `{task_id}` / `{target}`.
+/// Existing paths are left alone; returns the newly created links.
+pub fn resync_task_links(
+    project_path: &str,
+    project_key: &str,
+    task_id: &str,
+) -> Result<Vec<String>> {
+    let task = load_task(project_key, task_id)?;
+    let worktree = PathBuf::from(&task.worktree_path);
+    let auto_link = config::load_config()
+        .auto_link
+        .expand(&config::TemplateVars {
+            branch: &task.branch,
+            task_id: &task.id,
+            target: &task.target,
+        });
+    let created =
+        git::create_worktree_symlinks(&worktree, &main_repo(project_path), &auto_link, true)?;
+    tasks::record_autolinks(project_key, task_id, &created)?;
+    Ok(created.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Remove one AutoLink from a task worktree and forget it.
+///
+/// Only paths known as AutoLinks of this task are accepted. Symlinks are
+/// unlinked; copies and hardlinked trees Grove created are deleted. Anything
+/// else that took over the path is left alone.
+pub fn remove_task_link(
+    project_path: &str,
+    project_key: &str,
+    task_id: &str,
+    path: &str,
+) -> Result<()> {
+    let worktree = task_worktree(project_key, task_id)?;
+    let link = collect_links(project_path, project_key, task_id, &worktree)
+        .into_iter()
+        .find(|l| l.path == path)
+        .ok_or_else(|| {
+            GroveError::not_found(format!("'{path}' is not an AutoLink of this task"))
+        })?;
+
+    match link.state {
+        LinkState::Ok | LinkState::Broken | LinkState::Stale => {
+            remove_entry(&worktree.join(path), link.mode)?
+        }
+        LinkState::Missing => {}
+        LinkState::NotLink => {
+            return Err(GroveError::invalid_data(format!(
+                "'{path}' is no longer a link; refusing to delete it"
+            )))
+        }
+    }
+    tasks::remove_autolink(project_key, task_id, path)
+}
+
+/// Remove broken links and forget records whose path is gone.
+///
+/// Returns the paths that were cleaned up.
+pub fn clean_broken_links(
+    project_path: &str,
+    project_key: &str,
+    task_id: &str,
+) -> Result<Vec<String>> {
+    let worktree = task_worktree(project_key, task_id)?;
+    let mut removed = Vec::new();
+    for link in collect_links(project_path, project_key, task_id, &worktree) {
+        match link.state {
+            LinkState::Broken => remove_entry(&worktree.join(&link.path), link.mode)?,
+            LinkState::Missing => {}
+            LinkState::Ok | LinkState::NotLink | LinkState::Stale => continue,
+        }
+        tasks::remove_autolink(project_key, task_id, &link.path)?;
+        removed.push(link.path);
+    }
+    Ok(removed)
+}
+
+/// Re-create stale copies and hardlinked trees from the main repo.
+///
+/// Copies still honour the configured size limit. Returns the refreshed paths.
+pub fn refresh_stale_copies(
+    project_path: &str,
+    project_key: &str,
+    task_id: &str,
+) -> Result<Vec<String>> {
+    let worktree = task_worktree(project_key, task_id)?;
+    let source_root = main_repo(project_path);
+    let copy_max_bytes = config::load_config().auto_link.copy_max_bytes();
+    let mut refreshed = Vec::new();
+    for link in collect_links(project_path, project_key, task_id, &worktree) {
+        if link.state != LinkState::Stale {
+            continue;
+        }
+        let target = worktree.join(&link.path);
+        fs_link::remove_tree(&target)?;
+        git::materialize_link(
+            &source_root.join(&link.path),
+            &target,
+            link.mode,
+            copy_max_bytes,
+        )?;
+        refreshed.push(link.path);
+    }
+    Ok(refreshed)
+}
+
+/// Remove what Grove created at `path` for the given mode.
+fn remove_entry(path: &Path, mode: LinkMode) -> Result<()> {
+    match mode {
+        LinkMode::Symlink => fs_link::remove_link(path)?,
+        LinkMode::Copy | LinkMode::Hardlink => fs_link::remove_tree(path)?,
+    }
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_state() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        std::fs::write(root.join("source"), "x").unwrap();
+        std::fs::write(root.join("plain"), "x").unwrap();
+        fs_link::create_link(&root.join("source"), &root.join("ok")).unwrap();
+        fs_link::create_link(&root.join("gone"), &root.join("broken")).unwrap();
+
+        let state = |rel| link_state(root, root, rel, LinkMode::Symlink);
+        assert_eq!(state("ok"), LinkState::Ok);
+        assert_eq!(state("broken"), LinkState::Broken);
+        assert_eq!(state("plain"), LinkState::NotLink);
+        assert_eq!(state("nothing"), LinkState::Missing);
+
+        fs_link::remove_link(&root.join("broken")).unwrap();
+        assert_eq!(state("broken"), LinkState::Missing);
+        assert!(root.join("source").exists());
+    }
+
+    #[test]
+    fn test_copy_state() {
+        let temp = tempfile::tempdir().unwrap();
+        let (main, wt) = (temp.path().join("main"), temp.path().join("wt"));
+        std::fs::create_dir_all(main.join("deps/lib")).unwrap();
+        std::fs::create_dir_all(&wt).unwrap();
+        std::fs::write(main.join("deps/lib/a.txt"), "a").unwrap();
+
+        git::materialize_link(
+            &main.join("deps"),
+            &wt.join("deps"),
+            LinkMode::Copy,
+            u64::MAX,
+        )
+        .unwrap();
+        assert_eq!(
+            link_state(&wt, &main, "deps", LinkMode::Copy),
+            LinkState::Ok
+        );
+
+        // A new file in the source makes the copy stale
+        std::fs::write(main.join("deps/lib/b.txt"), "b").unwrap();
+        assert_eq!(
+            link_state(&wt, &main, "deps", LinkMode::Copy),
+            LinkState::Stale
+        );
+
+        // Over the size limit: refused, and nothing is left behind
+        assert!(
+            git::materialize_link(&main.join("deps"), &wt.join("big"), LinkMode::Copy, 1).is_err()
+        );
+        assert!(!wt.join("big").exists());
+
+        std::fs::remove_dir_all(main.join("deps")).unwrap();
+        assert_eq!(
+            link_state(&wt, &main, "deps", LinkMode::Copy),
+            LinkState::Broken
+        );
+    }
+}
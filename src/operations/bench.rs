@@ -0,0 +1,408 @@
+//! Benchmark comparison between a task branch and its target
+//!
+//! Runs the project's configured benchmark command on the target commit and
+//! on the task HEAD, each in a temporary detached worktree, parses criterion
+//! or hyperfine output and flags benchmarks that got slower than the
+//! project's threshold. Runs happen on a background thread; the latest
+//! comparison is stored per task and shown before merge.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+
+use crate::error::{GroveError, Result};
+use crate::git;
+use crate::hooks::run_shell_with_timeout;
+use crate::storage::bench::{
+    self, BenchComparison, BenchFormat, BenchRun, BenchRunStatus, BenchSettings, BenchVerdict,
+};
+use crate::storage::tasks;
+
+/// `project/task` keys with a run in progress in this process
+static RUNNING: Lazy<Mutex<HashSet<String>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Output kept in an error message when the command fails
+const MAX_ERROR_OUTPUT: usize = 2000;
+
+/// Nanoseconds per unit as printed by criterion / hyperfine
+fn unit_ns(unit: &str) -> Option<f64> {
+    match unit {
+        "ps" => Some(0.001),
+        "ns" => Some(1.0),
+        "us" | "µs" | "μs" => Some(1e3),
+        "ms" => Some(1e6),
+        "s" => Some(1e9),
+        _ => None,
+    }
+}
+
+/// `<value> <unit>` pairs from a whitespace-separated token stream
+fn value_ns(value: &str, unit: &str) -> Option<f64> {
+    Some(value.parse::<f64>().ok()? * unit_ns(unit)?)
+}
+
+/// Criterion's `name  time:   [low mid high]` lines; long names sit on the
+/// line above. Uses the middle (point) estimate.
+pub fn parse_criterion(output: &str) -> Vec<(String, f64)> {
+    let mut results = Vec::new();
+    let mut previous = "";
+    for line in output.lines() {
+        if let Some((name, rest)) = line.split_once("time:") {
+            let estimate = rest
+                .trim()
+                .strip_prefix('[')
+                .and_then(|r| r.split(']').next())
+                .map(|r| r.split_whitespace().collect::<Vec<_>>())
+                .filter(|tokens| tokens.len() == 6)
+                .and_then(|tokens| value_ns(tokens[2], tokens[3]));
+            let name = match name.trim() {
+                "" => previous,
+                name => name,
+            };
+            if let (Some(ns), false) = (estimate, name.is_empty()) {
+                results.push((name.to_string(), ns));
+            }
+        }
+        let trimmed = line.trim();
+        if !trimmed.is_empty() {
+            previous = trimmed;
+        }
+    }
+    results
+}
+
+/// hyperfine output: `--export-json` JSON (mean in seconds) or the default
+/// text output (`Benchmark 1: name` followed by `Time (mean ± σ): 1.2 ms …`)
+pub fn parse_hyperfine(output: &str) -> Vec<(String, f64)> {
+    if let Some(json) = output
+        .find('{')
+        .and_then(|start| serde_json::from_str::<serde_json::Value>(&output[start..]).ok())
+    {
+        if let Some(results) = json.get("results").and_then(|r| r.as_array()) {
+            return results
+                .iter()
+                .filter_map(|r| {
+                    let name = r.get("command")?.as_str()?.to_string();
+                    let mean = r.get("mean")?.as_f64()?;
+                    Some((name, mean * 1e9))
+                })
+                .collect();
+        }
+    }
+
+    let mut results = Vec::new();
+    let mut current: Option<String> = None;
+    for line in output.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("Benchmark ") {
+            current = rest
+                .split_once(": ")
+                .map(|(_, name)| name.trim().to_string());
+        } else if let (Some(rest), Some(name)) = (line.strip_prefix("Time ("), &current) {
+            let tokens: Vec<&str> = rest
+                .split_once("):")
+                .map(|(_, values)| values.split_whitespace().take(2).collect())
+                .unwrap_or_default();
+            if let [value, unit] = tokens[..] {
+                if let Some(ns) = value_ns(value, unit) {
+                    results.push((name.clone(), ns));
+                    current = None;
+                }
+            }
+        }
+    }
+    results
+}
+
+/// Parse benchmark output in the configured format
+pub fn parse_output(output: &str, format: BenchFormat) -> Vec<(String, f64)> {
+    match format {
+        BenchFormat::Criterion => parse_criterion(output),
+        BenchFormat::Hyperfine => parse_hyperfine(output),
+        BenchFormat::Auto => {
+            let criterion = parse_criterion(output);
+            if criterion.is_empty() {
+                parse_hyperfine(output)
+            } else {
+                criterion
+            }
+        }
+    }
+}
+
+/// Pair up benchmarks by name and classify the change
+pub fn compare(
+    base: &[(String, f64)],
+    head: &[(String, f64)],
+    threshold_percent: f64,
+) -> Vec<BenchComparison> {
+    let find = |list: &[(String, f64)], name: &str| {
+        list.iter().find(|(n, _)| n == name).map(|(_, ns)| *ns)
+    };
+
+    let mut results: Vec<BenchComparison> = head
+        .iter()
+        .map(|(name, head_ns)| {
+            let base_ns = find(base, name);
+            let change_percent = base_ns
+                .filter(|b| *b > 0.0)
+                .map(|b| (head_ns - b) / b * 100.0);
+            let verdict = match change_percent {
+                None => BenchVerdict::Added,
+                Some(c) if c > threshold_percent => BenchVerdict::Regression,
+                Some(c) if c < -threshold_percent => BenchVerdict::Improvement,
+                Some(_) => BenchVerdict::Unchanged,
+            };
+            BenchComparison {
+                name: name.clone(),
+                base_ns,
+                head_ns: Some(*head_ns),
+                change_percent,
+                verdict,
+            }
+        })
+        .collect();
+    results.extend(
+        base.iter()
+            .filter(|(name, _)| find(head, name).is_none())
+            .map(|(name, base_ns)| BenchComparison {
+                name: name.clone(),
+                base_ns: Some(*base_ns),
+                head_ns: None,
+                change_percent: None,
+                verdict: BenchVerdict::Removed,
+            }),
+    );
+    results
+}
+
+/// Run `command` in `dir`, returning stdout followed by stderr
+fn run_command(dir: &Path, command: &str, timeout: Duration) -> Result<String> {
+    let Some(output) = run_shell_with_timeout(dir, command, timeout)? else {
+        return Err(GroveError::invalid_data(format!(
+            "Benchmark timed out after {}s",
+            timeout.as_secs()
+        )));
+    };
+    if !output.status.success() {
+        return Err(GroveError::invalid_data(format!(
+            "Benchmark command failed ({}): {}",
+            output.status,
+            output.error_tail(MAX_ERROR_OUTPUT)
+        )));
+    }
+    Ok(format!("{}\n{}", output.stdout, output.stderr))
+}
+
+/// Removes the temporary worktrees when the run ends, however it ends
+struct TempWorktrees {
+    repo: String,
+    root: std::path::PathBuf,
+    added: Vec<String>,
+}
+
+impl TempWorktrees {
+    fn add(&mut self, name: &str, commit: &str) -> Result<std::path::PathBuf> {
+        let path = self.root.join(name);
+        let path_str = path.to_string_lossy().to_string();
+        git::add_detached_worktree(&self.repo, &path_str, commit)?;
+        self.added.push(path_str);
+        Ok(path)
+    }
+}
+
+impl Drop for TempWorktrees {
+    fn drop(&mut self) {
+        for path in &self.added {
+            let _ = git::remove_worktree(&self.repo, path);
+        }
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+/// Benchmark the target and HEAD commits one after the other
+fn run_comparison(
+    worktree_path: &str,
+    base_commit: &str,
+    head_commit: &str,
+    settings: &BenchSettings,
+) -> Result<Vec<BenchComparison>> {
+    let mut worktrees = TempWorktrees {
+        repo: worktree_path.to_string(),
+        root: std::env::temp_dir().join(format!("grove-bench-{}", uuid::Uuid::new_v4())),
+        added: Vec::new(),
+    };
+    let timeout = Duration::from_secs(settings.timeout_secs.max(1));
+
+    let base_dir = worktrees.add("base", base_commit)?;
+    let base_output = run_command(&base_dir, &settings.command, timeout)?;
+    let head_dir = worktrees.add("head", head_commit)?;
+    let head_output = run_command(&head_dir, &settings.command, timeout)?;
+
+    let base = parse_output(&base_output, settings.format);
+    let head = parse_output(&head_output, settings.format);
+    if base.is_empty() && head.is_empty() {
+        return Err(GroveError::invalid_data(
+            "No criterion or hyperfine results found in the benchmark output",
+        ));
+    }
+    Ok(compare(&base, &head, settings.threshold_percent))
+}
+
+fn run_key(project: &str, task_id: &str) -> String {
+    format!("{}/{}", project, task_id)
+}
+
+/// Start a comparison in the background; returns the `running` record
+pub fn start(project: &str, task_id: &str) -> Result<BenchRun> {
+    let settings = bench::load_settings(project);
+    if !settings.is_configured() {
+        return Err(GroveError::invalid_data(
+            "No benchmark command configured for this project",
+        ));
+    }
+    let task = tasks::get_task(project, task_id)?
+        .filter(|t| !t.is_local)
+        .ok_or_else(|| GroveError::invalid_data("Task not found"))?;
+
+    let key = run_key(project, task_id);
+    if !RUNNING.lock().unwrap().insert(key.clone()) {
+        return Err(GroveError::invalid_data(
+            "A benchmark run is already in progress for this task",
+        ));
+    }
+
+    let commits = git::resolve_commit(&task.worktree_path, &task.target)
+        .and_then(|base| git::resolve_commit(&task.worktree_path, "HEAD").map(|head| (base, head)));
+    let (base_commit, head_commit) = match commits {
+        Ok(commits) => commits,
+        Err(e) => {
+            RUNNING.lock().unwrap().remove(&key);
+            return Err(e);
+        }
+    };
+
+    let mut run = BenchRun {
+        status: BenchRunStatus::Running,
+        started_at: chrono::Utc::now().to_rfc3339(),
+        finished_at: None,
+        command: settings.command.clone(),
+        threshold_percent: settings.threshold_percent,
+        base_ref: task.target.clone(),
+        base_commit: base_commit.clone(),
+        head_commit: head_commit.clone(),
+        results: Vec::new(),
+        error: None,
+    };
+    if let Err(e) = bench::save_run(project, task_id, &run) {
+        RUNNING.lock().unwrap().remove(&key);
+        return Err(e);
+    }
+
+    let project = project.to_string();
+    let task_id = task_id.to_string();
+    let started = run.clone();
+    std::thread::spawn(move || {
+        match run_comparison(&task.worktree_path, &base_commit, &head_commit, &settings) {
+            Ok(results) => {
+                run.status = BenchRunStatus::Done;
+                run.results = results;
+            }
+            Err(e) => {
+                run.status = BenchRunStatus::Failed;
+                run.error = Some(e.to_string());
+            }
+        }
+        run.finished_at = Some(chrono::Utc::now().to_rfc3339());
+        if let Err(e) = bench::save_run(&project, &task_id, &run) {
+            tracing::warn!("bench: failed to save result: {}", e);
+        }
+        RUNNING.lock().unwrap().remove(&key);
+    });
+    Ok(started)
+}
+
+/// Latest comparison of a task. A `running` record no run in this process
+/// owns was cut short by a restart and is reported as failed.
+pub fn latest(project: &str, task_id: &str) -> Option<BenchRun> {
+    let mut run = bench::load_run(project, task_id)?;
+    if run.status == BenchRunStatus::Running
+        && !RUNNING.lock().unwrap().contains(&run_key(project, task_id))
+    {
+        run.status = BenchRunStatus::Failed;
+        run.error = Some("Interrupted (Grove was restarted during the run)".to_string());
+    }
+    Some(run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_criterion() {
+        let output = "\
+Benchmarking fib 20: Analyzing
+fib 20                  time:   [26.029 µs 26.251 µs 26.505 µs]
+                        change: [-1.2% +0.4% +2.1%] (p = 0.62 > 0.05)
+parser/very_long_benchmark_name_that_wraps
+                        time:   [1.2000 ms 1.5000 ms 1.8000 ms]
+";
+        let results = parse_output(output, BenchFormat::Auto);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "fib 20");
+        assert!((results[0].1 - 26_251.0).abs() < 1e-6);
+        assert_eq!(results[1].0, "parser/very_long_benchmark_name_that_wraps");
+        assert!((results[1].1 - 1_500_000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_parse_hyperfine() {
+        let text = "\
+Benchmark 1: sleep 0.3
+  Time (mean ± σ):     302.6 ms ±   0.5 ms    [User: 1.0 ms, System: 1.5 ms]
+  Range (min … max):   301.9 ms … 303.4 ms    10 runs
+";
+        let results = parse_output(text, BenchFormat::Auto);
+        assert_eq!(results, vec![("sleep 0.3".to_string(), 302_600_000.0)]);
+
+        let json = r#"{"results": [{"command": "build", "mean": 0.25, "stddev": 0.01}]}"#;
+        assert_eq!(
+            parse_output(json, BenchFormat::Hyperfine),
+            vec![("build".to_string(), 250_000_000.0)]
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let base = vec![
+            ("a".to_string(), 100.0),
+            ("b".to_string(), 100.0),
+            ("c".to_string(), 100.0),
+            ("gone".to_string(), 1.0),
+        ];
+        let head = vec![
+            ("a".to_string(), 110.0),
+            ("b".to_string(), 80.0),
+            ("c".to_string(), 103.0),
+            ("new".to_string(), 1.0),
+        ];
+        let verdicts: Vec<BenchVerdict> = compare(&base, &head, 5.0)
+            .iter()
+            .map(|r| r.verdict)
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                BenchVerdict::Regression,
+                BenchVerdict::Improvement,
+                BenchVerdict::Unchanged,
+                BenchVerdict::Added,
+                BenchVerdict::Removed,
+            ]
+        );
+    }
+}
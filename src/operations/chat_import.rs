@@ -0,0 +1,773 @@
+//! Import native agent session logs as Grove chats
+//!
+//! Claude Code keeps every session as JSONL under
+//! `~/.claude/projects/{encoded cwd}/{session id}.jsonl`, Codex keeps rollouts
+//! under `~/.codex/sessions/YYYY/MM/DD/rollout-*.jsonl`. Both are converted to
+//! `AcpUpdate` events and written as the history of a new chat on the task, so
+//! work that started outside Grove shows up in its chat UI. When the session
+//! ran inside the task worktree the chat keeps the native session id, so its
+//! first prompt resumes the original agent session; otherwise the agent
+//! starts fresh with only the imported history on display.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::acp::{self, AcpUpdate};
+use crate::error::{GroveError, Result};
+use crate::storage::{chat_history, tasks, workspace};
+
+/// Max characters of the first prompt used as the session title.
+const TITLE_CHARS: usize = 60;
+
+/// Sessions listed per source (newest first).
+const MAX_LISTED_SESSIONS: usize = 50;
+
+/// User "messages" Codex injects itself; not shown as prompts.
+const CODEX_INJECTED_PREFIXES: &[&str] = &[
+    "<environment_context>",
+    "<user_instructions>",
+    "# AGENTS.md instructions",
+];
+
+/// Agent whose session logs are imported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionSource {
+    Claude,
+    Codex,
+}
+
+impl SessionSource {
+    /// Grove agent id of the imported chat
+    pub fn agent_id(self) -> &'static str {
+        match self {
+            SessionSource::Claude => "claude",
+            SessionSource::Codex => "codex",
+        }
+    }
+
+    /// Directory holding this agent's session logs
+    fn root(self) -> Option<PathBuf> {
+        match self {
+            SessionSource::Claude => std::env::var_os("CLAUDE_CONFIG_DIR")
+                .map(PathBuf::from)
+                .or_else(|| dirs::home_dir().map(|h| h.join(".claude")))
+                .map(|dir| dir.join("projects")),
+            SessionSource::Codex => std::env::var_os("CODEX_HOME")
+                .map(PathBuf::from)
+                .or_else(|| dirs::home_dir().map(|h| h.join(".codex")))
+                .map(|dir| dir.join("sessions")),
+        }
+    }
+
+    fn parse(self, content: &str) -> ParsedSession {
+        match self {
+            SessionSource::Claude => parse_claude(content),
+            SessionSource::Codex => parse_codex(content),
+        }
+    }
+}
+
+/// A native session that can be imported.
+#[derive(Debug, Clone, Serialize)]
+pub struct NativeSession {
+    pub source: SessionSource,
+    /// Absolute path of the session log (pass back to `import`)
+    pub path: String,
+    pub session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<String>,
+    /// Beginning of the first prompt
+    pub title: String,
+    pub prompts: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Result of `import`.
+#[derive(Debug, Clone)]
+pub struct ImportedChat {
+    pub chat: tasks::ChatSession,
+    pub events: usize,
+}
+
+/// A session log converted to Grove history events.
+#[derive(Debug, Default)]
+pub struct ParsedSession {
+    pub session_id: String,
+    pub cwd: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub events: Vec<AcpUpdate>,
+}
+
+impl ParsedSession {
+    fn title(&self) -> Option<String> {
+        self.events.iter().find_map(|e| match e {
+            AcpUpdate::UserMessage { text, .. } => {
+                let line = text.lines().find(|l| !l.trim().is_empty())?.trim();
+                Some(line.chars().take(TITLE_CHARS).collect())
+            }
+            _ => None,
+        })
+    }
+
+    fn prompts(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|e| matches!(e, AcpUpdate::UserMessage { .. }))
+            .count()
+    }
+}
+
+/// Builds the event list, closing each turn with a `Complete`.
+#[derive(Default)]
+struct HistoryBuilder {
+    parsed: ParsedSession,
+    /// Timestamp of the prompt that opened the current turn
+    turn_start: Option<DateTime<Utc>>,
+    /// Whether the current turn produced any agent output
+    turn_has_output: bool,
+}
+
+impl HistoryBuilder {
+    fn touch(&mut self, ts: Option<DateTime<Utc>>) {
+        if let Some(ts) = ts {
+            self.parsed.started_at.get_or_insert(ts);
+            self.parsed.updated_at = Some(ts);
+        }
+    }
+
+    fn end_turn(&mut self) {
+        if self.turn_has_output {
+            self.parsed.events.push(AcpUpdate::Complete {
+                stop_reason: "end_turn".to_string(),
+                usage: None,
+                start_ts: self.turn_start.map(|t| t.timestamp()),
+                end_ts: self.parsed.updated_at.map(|t| t.timestamp()),
+                cost: None,
+            });
+        }
+        self.turn_has_output = false;
+    }
+
+    fn user(&mut self, text: String, ts: Option<DateTime<Utc>>) {
+        self.end_turn();
+        self.turn_start = ts;
+        self.parsed.events.push(AcpUpdate::UserMessage {
+            text,
+            attachments: Vec::new(),
+            sender: None,
+            terminal: false,
+        });
+    }
+
+    fn output(&mut self, event: AcpUpdate) {
+        self.turn_has_output = true;
+        self.parsed.events.push(event);
+    }
+
+    fn finish(mut self) -> ParsedSession {
+        self.end_turn();
+        self.parsed
+    }
+}
+
+fn timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    value
+        .get("timestamp")
+        .and_then(Value::as_str)
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(Value::as_str)
+}
+
+/// Tool title with the most telling input (command, path, pattern ...).
+fn tool_title(name: &str, input: &Value) -> String {
+    let detail = [
+        "command",
+        "file_path",
+        "path",
+        "pattern",
+        "url",
+        "description",
+    ]
+    .iter()
+    .find_map(|key| match input.get(key) {
+        Some(Value::String(s)) => Some(s.clone()),
+        // Codex shell: ["bash", "-lc", "cmd"]
+        Some(Value::Array(parts)) => {
+            let parts: Vec<&str> = parts.iter().filter_map(Value::as_str).collect();
+            match parts.as_slice() {
+                [_, "-lc", cmd] | [_, "-c", cmd] => Some(cmd.to_string()),
+                _ if !parts.is_empty() => Some(parts.join(" ")),
+                _ => None,
+            }
+        }
+        _ => None,
+    });
+    match detail {
+        Some(detail) => format!("{}: {}", name, detail.lines().next().unwrap_or_default()),
+        None => name.to_string(),
+    }
+}
+
+fn tool_locations(input: &Value) -> Vec<(String, Option<u32>)> {
+    ["file_path", "path", "notebook_path"]
+        .iter()
+        .find_map(|key| str_field(input, key))
+        .map(|path| vec![(path.to_string(), None)])
+        .unwrap_or_default()
+}
+
+/// Text of a Claude `tool_result` content (string or list of text blocks).
+fn claude_result_text(content: &Value) -> Option<String> {
+    match content {
+        Value::String(s) => Some(s.clone()),
+        Value::Array(blocks) => {
+            let text: Vec<&str> = blocks.iter().filter_map(|b| str_field(b, "text")).collect();
+            (!text.is_empty()).then(|| text.join("\n"))
+        }
+        _ => None,
+    }
+}
+
+/// Convert a Claude Code session log.
+pub fn parse_claude(content: &str) -> ParsedSession {
+    let mut b = HistoryBuilder::default();
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        let kind = str_field(&value, "type").unwrap_or_default();
+        if !matches!(kind, "user" | "assistant") {
+            continue;
+        }
+        // Sub-agent transcripts and hidden meta messages are not part of the chat
+        if value.get("isSidechain").and_then(Value::as_bool) == Some(true)
+            || value.get("isMeta").and_then(Value::as_bool) == Some(true)
+        {
+            continue;
+        }
+        if b.parsed.session_id.is_empty() {
+            if let Some(id) = str_field(&value, "sessionId") {
+                b.parsed.session_id = id.to_string();
+            }
+        }
+        if b.parsed.cwd.is_none() {
+            b.parsed.cwd = str_field(&value, "cwd").map(str::to_string);
+        }
+        let ts = timestamp(&value);
+        b.touch(ts);
+
+        let content = value.get("message").and_then(|m| m.get("content"));
+        let blocks: Vec<Value> = match content {
+            Some(Value::String(text)) => vec![serde_json::json!({ "type": "text", "text": text })],
+            Some(Value::Array(blocks)) => blocks.clone(),
+            _ => continue,
+        };
+
+        if kind == "user" {
+            let mut prompt = Vec::new();
+            for block in &blocks {
+                match str_field(block, "type") {
+                    Some("text") => {
+                        let text = str_field(block, "text").unwrap_or_default();
+                        // Slash-command bookkeeping (`<command-name>/clear</command-name>` ...)
+                        if !text.starts_with("<command-") && !text.starts_with("<local-command-") {
+                            prompt.push(text.to_string());
+                        }
+                    }
+                    Some("tool_result") => {
+                        let is_error = block.get("is_error").and_then(Value::as_bool) == Some(true);
+                        b.output(AcpUpdate::ToolCallUpdate {
+                            id: str_field(block, "tool_use_id")
+                                .unwrap_or_default()
+                                .to_string(),
+                            status: if is_error { "failed" } else { "completed" }.to_string(),
+                            content: block.get("content").and_then(claude_result_text),
+                            locations: Vec::new(),
+                            raw_input: None,
+                            timestamp: ts,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            let prompt = prompt.join("\n");
+            if !prompt.trim().is_empty() {
+                b.user(prompt, ts);
+            }
+            continue;
+        }
+
+        for block in &blocks {
+            match str_field(block, "type") {
+                Some("text") => {
+                    let text = str_field(block, "text").unwrap_or_default();
+                    if !text.is_empty() {
+                        b.output(AcpUpdate::MessageChunk {
+                            text: text.to_string(),
+                        });
+                    }
+                }
+                Some("thinking") => {
+                    let text = str_field(block, "thinking").unwrap_or_default();
+                    if !text.is_empty() {
+                        b.output(AcpUpdate::ThoughtChunk {
+                            text: text.to_string(),
+                        });
+                    }
+                }
+                Some("tool_use") => {
+                    let input = block.get("input").cloned().unwrap_or(Value::Null);
+                    let name = str_field(block, "name").unwrap_or("tool");
+                    b.output(AcpUpdate::ToolCall {
+                        id: str_field(block, "id").unwrap_or_default().to_string(),
+                        title: tool_title(name, &input),
+                        locations: tool_locations(&input),
+                        timestamp: ts,
+                        raw_input: Some(input),
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+    b.finish()
+}
+
+/// Output text of a Codex tool call (plain or `{"output": ..., "metadata": ...}`).
+fn codex_output(output: &Value) -> (Option<String>, bool) {
+    let Some(raw) = output.as_str() else {
+        return (None, false);
+    };
+    match serde_json::from_str::<Value>(raw) {
+        Ok(parsed) if parsed.get("output").is_some() => {
+            let failed = parsed
+                .get("metadata")
+                .and_then(|m| m.get("exit_code"))
+                .and_then(Value::as_i64)
+                .is_some_and(|code| code != 0);
+            (str_field(&parsed, "output").map(str::to_string), failed)
+        }
+        _ => (Some(raw.to_string()), false),
+    }
+}
+
+/// Convert a Codex rollout log.
+pub fn parse_codex(content: &str) -> ParsedSession {
+    let mut b = HistoryBuilder::default();
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        let ts = timestamp(&value);
+        // Newer rollouts wrap every item as {timestamp, type, payload}
+        let (kind, item) = match value.get("payload") {
+            Some(payload) => (str_field(&value, "type").unwrap_or_default(), payload),
+            None => ("response_item", &value),
+        };
+        if kind == "session_meta"
+            || (b.parsed.session_id.is_empty()
+                && item.get("id").is_some()
+                && item.get("type").is_none())
+        {
+            b.parsed.session_id = str_field(item, "id").unwrap_or_default().to_string();
+            b.parsed.cwd = str_field(item, "cwd").map(str::to_string);
+            b.touch(ts.or_else(|| timestamp(item)));
+            continue;
+        }
+        if kind != "response_item" {
+            continue;
+        }
+        b.touch(ts);
+
+        match str_field(item, "type").unwrap_or_default() {
+            "message" => {
+                let text: Vec<&str> = item
+                    .get("content")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|c| str_field(c, "text"))
+                    .collect();
+                let text = text.join("\n");
+                if text.trim().is_empty() {
+                    continue;
+                }
+                match str_field(item, "role") {
+                    Some("user") => {
+                        let trimmed = text.trim_start();
+                        if !CODEX_INJECTED_PREFIXES
+                            .iter()
+                            .any(|p| trimmed.starts_with(p))
+                        {
+                            b.user(text, ts);
+                        }
+                    }
+                    Some("assistant") => b.output(AcpUpdate::MessageChunk { text }),
+                    _ => {}
+                }
+            }
+            "reasoning" => {
+                let text: Vec<&str> = item
+                    .get("summary")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|s| str_field(s, "text"))
+                    .collect();
+                if !text.is_empty() {
+                    b.output(AcpUpdate::ThoughtChunk {
+                        text: text.join("\n"),
+                    });
+                }
+            }
+            "function_call" | "custom_tool_call" => {
+                let name = str_field(item, "name").unwrap_or("tool");
+                // function_call carries JSON arguments, custom tools free-form input
+                let input = match str_field(item, "arguments") {
+                    Some(args) => serde_json::from_str(args).unwrap_or(Value::String(args.into())),
+                    None => item.get("input").cloned().unwrap_or(Value::Null),
+                };
+                b.output(AcpUpdate::ToolCall {
+                    id: str_field(item, "call_id").unwrap_or_default().to_string(),
+                    title: tool_title(name, &input),
+                    locations: tool_locations(&input),
+                    timestamp: ts,
+                    raw_input: Some(input),
+                });
+            }
+            "function_call_output" | "custom_tool_call_output" => {
+                let (content, failed) = codex_output(item.get("output").unwrap_or(&Value::Null));
+                b.output(AcpUpdate::ToolCallUpdate {
+                    id: str_field(item, "call_id").unwrap_or_default().to_string(),
+                    status: if failed { "failed" } else { "completed" }.to_string(),
+                    content,
+                    locations: Vec::new(),
+                    raw_input: None,
+                    timestamp: ts,
+                });
+            }
+            _ => {}
+        }
+    }
+    b.finish()
+}
+
+/// Claude Code's directory name for a working directory.
+fn claude_dir_name(path: &str) -> String {
+    path.trim_end_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+fn jsonl_files(dir: &Path, recursive: bool, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if recursive {
+                jsonl_files(&path, true, out);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "jsonl") {
+            out.push(path);
+        }
+    }
+}
+
+fn modified(path: &Path) -> std::time::SystemTime {
+    path.metadata()
+        .and_then(|m| m.modified())
+        .unwrap_or(std::time::UNIX_EPOCH)
+}
+
+/// Whether `cwd` is one of `dirs` or inside one.
+fn within_any(cwd: &str, dirs: &[String]) -> bool {
+    let cwd = Path::new(cwd);
+    dirs.iter().any(|dir| cwd.starts_with(dir))
+}
+
+/// `cwd` relative to `root` ("" for `root` itself), None when outside it.
+fn subdir_of(cwd: &str, root: &str) -> Option<String> {
+    Path::new(cwd)
+        .strip_prefix(root)
+        .ok()
+        .map(|rel| rel.to_string_lossy().trim_matches('/').to_string())
+}
+
+/// Codex session cwd, read from the first line only.
+fn codex_cwd(path: &Path) -> Option<String> {
+    use std::io::BufRead;
+    let file = std::fs::File::open(path).ok()?;
+    let mut first = String::new();
+    std::io::BufReader::new(file).read_line(&mut first).ok()?;
+    let value: Value = serde_json::from_str(&first).ok()?;
+    let meta = value.get("payload").unwrap_or(&value);
+    str_field(meta, "cwd").map(str::to_string)
+}
+
+fn summarize(source: SessionSource, path: &Path) -> Option<NativeSession> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let parsed = source.parse(&content);
+    let title = parsed.title()?;
+    let session_id = if parsed.session_id.is_empty() {
+        path.file_stem()?.to_string_lossy().to_string()
+    } else {
+        parsed.session_id.clone()
+    };
+    Some(NativeSession {
+        source,
+        path: path.to_string_lossy().to_string(),
+        session_id,
+        cwd: parsed.cwd.clone(),
+        title,
+        prompts: parsed.prompts(),
+        started_at: parsed.started_at,
+        updated_at: parsed.updated_at,
+    })
+}
+
+/// Sessions of `source` that ran in one of `dirs`, newest first.
+fn sessions_in(source: SessionSource, root: &Path, dirs: &[String]) -> Vec<NativeSession> {
+    let mut files = Vec::new();
+    match source {
+        SessionSource::Claude => {
+            let mut names: Vec<String> = dirs.iter().map(|d| claude_dir_name(d)).collect();
+            names.dedup();
+            for name in names {
+                jsonl_files(&root.join(name), false, &mut files);
+            }
+        }
+        SessionSource::Codex => {
+            jsonl_files(root, true, &mut files);
+            files.retain(|f| codex_cwd(f).is_some_and(|cwd| within_any(&cwd, dirs)));
+        }
+    }
+    files.sort_by_key(|f| std::cmp::Reverse(modified(f)));
+    files
+        .iter()
+        .take(MAX_LISTED_SESSIONS)
+        .filter_map(|f| summarize(source, f))
+        .collect()
+}
+
+/// Task worktree and project root — the directories whose sessions belong
+/// to the task.
+fn task_dirs(project_key: &str, task_id: &str) -> Result<Vec<String>> {
+    let task = tasks::get_task(project_key, task_id)?
+        .ok_or_else(|| GroveError::not_found("Task not found"))?;
+    let mut dirs = vec![task.worktree_path];
+    if let Some(project) = workspace::load_project_by_hash(project_key)? {
+        if !dirs.contains(&project.path) {
+            dirs.push(project.path);
+        }
+    }
+    Ok(dirs
+        .into_iter()
+        .map(|d| d.trim_end_matches('/').to_string())
+        .filter(|d| !d.is_empty())
+        .collect())
+}
+
+/// Claude Code and Codex sessions that ran in the task's worktree or the
+/// project root.
+pub fn list_sessions(project_key: &str, task_id: &str) -> Result<Vec<NativeSession>> {
+    let dirs = task_dirs(project_key, task_id)?;
+    let mut sessions = Vec::new();
+    for source in [SessionSource::Claude, SessionSource::Codex] {
+        if let Some(root) = source.root() {
+            sessions.extend(sessions_in(source, &root, &dirs));
+        }
+    }
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
+    Ok(sessions)
+}
+
+/// Import a session log as a new chat on the task.
+///
+/// `path` must be a `.jsonl` file inside the source's session directory.
+pub fn import(
+    project_key: &str,
+    task_id: &str,
+    source: SessionSource,
+    path: &str,
+) -> Result<ImportedChat> {
+    let root = source
+        .root()
+        .and_then(|r| r.canonicalize().ok())
+        .ok_or_else(|| GroveError::not_found("No session directory for this agent"))?;
+    let file = Path::new(path)
+        .canonicalize()
+        .map_err(|_| GroveError::not_found("Session file not found"))?;
+    if !file.starts_with(&root) || file.extension().is_none_or(|ext| ext != "jsonl") {
+        return Err(GroveError::invalid_data(
+            "Session file is outside the agent's session directory",
+        ));
+    }
+
+    let parsed = source.parse(&std::fs::read_to_string(&file)?);
+    let Some(title) = parsed.title() else {
+        return Err(GroveError::invalid_data("Session has no prompts to import"));
+    };
+    let task = tasks::get_task(project_key, task_id)?
+        .ok_or_else(|| GroveError::not_found("Task not found"))?;
+
+    // Resuming only works from the directory the session ran in
+    let resume_dir = parsed
+        .cwd
+        .as_deref()
+        .and_then(|cwd| subdir_of(cwd, &task.worktree_path))
+        .filter(|_| !parsed.session_id.is_empty());
+    let mut chat = acp::client::create_chat(
+        project_key,
+        task_id,
+        source.agent_id(),
+        Some(format!("{} (imported)", title)),
+        resume_dir.clone().filter(|d| !d.is_empty()),
+    )?;
+    if resume_dir.is_some() {
+        tasks::update_chat_acp_session_id(project_key, task_id, &chat.id, &parsed.session_id)?;
+        chat.acp_session_id = Some(parsed.session_id.clone());
+    }
+    chat_history::append_all(project_key, task_id, &chat.id, &parsed.events);
+    chat_history::compact_history(project_key, task_id, &chat.id);
+    Ok(ImportedChat {
+        chat,
+        events: parsed.events.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(events: &[AcpUpdate]) -> Vec<&'static str> {
+        events
+            .iter()
+            .map(|e| match e {
+                AcpUpdate::UserMessage { .. } => "user",
+                AcpUpdate::MessageChunk { .. } => "message",
+                AcpUpdate::ThoughtChunk { .. } => "thought",
+                AcpUpdate::ToolCall { .. } => "tool",
+                AcpUpdate::ToolCallUpdate { .. } => "tool_update",
+                AcpUpdate::Complete { .. } => "complete",
+                _ => "other",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_claude_session() {
+        let log = [
+            r#"{"type":"summary","summary":"Fix bug"}"#,
+            r#"{"type":"user","isMeta":true,"sessionId":"s1","cwd":"/repo","message":{"role":"user","content":"Caveat"},"timestamp":"2026-01-01T10:00:00Z"}"#,
+            r#"{"type":"user","sessionId":"s1","cwd":"/repo","message":{"role":"user","content":"fix the bug"},"timestamp":"2026-01-01T10:00:01Z"}"#,
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"thinking","thinking":"hmm"},{"type":"text","text":"Looking"}]},"timestamp":"2026-01-01T10:00:02Z"}"#,
+            r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"tool_use","id":"t1","name":"Read","input":{"file_path":"/repo/a.rs"}}]},"timestamp":"2026-01-01T10:00:03Z"}"#,
+            r#"{"type":"user","message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t1","content":"fn main() {}"}]},"timestamp":"2026-01-01T10:00:04Z"}"#,
+            r#"{"type":"assistant","isSidechain":true,"message":{"role":"assistant","content":[{"type":"text","text":"subagent"}]}}"#,
+            r#"{"type":"user","message":{"role":"user","content":"<command-name>/clear</command-name>"},"timestamp":"2026-01-01T10:00:05Z"}"#,
+            r#"{"type":"user","message":{"role":"user","content":"thanks"},"timestamp":"2026-01-01T10:01:00Z"}"#,
+        ]
+        .join("\n");
+        let parsed = parse_claude(&log);
+        assert_eq!(parsed.session_id, "s1");
+        assert_eq!(parsed.cwd.as_deref(), Some("/repo"));
+        assert_eq!(
+            kinds(&parsed.events),
+            vec![
+                "user",
+                "thought",
+                "message",
+                "tool",
+                "tool_update",
+                "complete",
+                "user"
+            ]
+        );
+        assert_eq!(parsed.title().as_deref(), Some("fix the bug"));
+        assert_eq!(parsed.prompts(), 2);
+        match &parsed.events[3] {
+            AcpUpdate::ToolCall {
+                title, locations, ..
+            } => {
+                assert_eq!(title, "Read: /repo/a.rs");
+                assert_eq!(locations, &vec![("/repo/a.rs".to_string(), None)]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_codex_rollout() {
+        let log = [
+            r#"{"timestamp":"2026-01-01T10:00:00Z","type":"session_meta","payload":{"id":"c1","cwd":"/repo/sub"}}"#,
+            r#"{"timestamp":"2026-01-01T10:00:00Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"<environment_context>cwd</environment_context>"}]}}"#,
+            r#"{"timestamp":"2026-01-01T10:00:01Z","type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"run tests"}]}}"#,
+            r#"{"timestamp":"2026-01-01T10:00:02Z","type":"event_msg","payload":{"type":"agent_message","message":"dup"}}"#,
+            r#"{"timestamp":"2026-01-01T10:00:02Z","type":"response_item","payload":{"type":"reasoning","summary":[{"type":"summary_text","text":"plan"}]}}"#,
+            r#"{"timestamp":"2026-01-01T10:00:03Z","type":"response_item","payload":{"type":"function_call","name":"shell","arguments":"{\"command\":[\"bash\",\"-lc\",\"cargo test\"]}","call_id":"k1"}}"#,
+            r#"{"timestamp":"2026-01-01T10:00:09Z","type":"response_item","payload":{"type":"function_call_output","call_id":"k1","output":"{\"output\":\"1 failed\",\"metadata\":{\"exit_code\":101}}"}}"#,
+            r#"{"timestamp":"2026-01-01T10:00:10Z","type":"response_item","payload":{"type":"message","role":"assistant","content":[{"type":"output_text","text":"One test fails."}]}}"#,
+        ]
+        .join("\n");
+        let parsed = parse_codex(&log);
+        assert_eq!(parsed.session_id, "c1");
+        assert!(within_any(
+            parsed.cwd.as_deref().unwrap(),
+            &["/repo".to_string()]
+        ));
+        assert_eq!(
+            kinds(&parsed.events),
+            vec![
+                "user",
+                "thought",
+                "tool",
+                "tool_update",
+                "message",
+                "complete"
+            ]
+        );
+        match (&parsed.events[2], &parsed.events[3]) {
+            (
+                AcpUpdate::ToolCall { title, .. },
+                AcpUpdate::ToolCallUpdate {
+                    status, content, ..
+                },
+            ) => {
+                assert_eq!(title, "shell: cargo test");
+                assert_eq!(status, "failed");
+                assert_eq!(content.as_deref(), Some("1 failed"));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn subdir_of_resolves_relative_dir() {
+        assert_eq!(subdir_of("/wt/app", "/wt/app").as_deref(), Some(""));
+        assert_eq!(subdir_of("/wt/app/web", "/wt/app/").as_deref(), Some("web"));
+        assert_eq!(subdir_of("/wt/application", "/wt/app"), None);
+    }
+
+    #[test]
+    fn claude_dir_name_replaces_separators() {
+        assert_eq!(
+            claude_dir_name("/Users/me/my.app/"),
+            "-Users-me-my-app".to_string()
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Auto-commit checkpoints
+//!
+//! With checkpoints enabled on a task, Grove commits the worktree with a
+//! `grove-checkpoint:` subject after every ACP turn and periodically while
+//! the file watcher sees activity, so agent work survives crashes. Before
+//! merging, the trailing run of checkpoint commits can be squashed into one
+//! commit or dropped (un-committed, changes kept staged).
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::error::{GroveError, Result};
+use crate::git;
+use crate::storage::git_identity::{self, GitIdentity};
+use crate::storage::{checkpoints, tasks};
+
+/// Subject prefix that marks a checkpoint commit.
+pub const CHECKPOINT_PREFIX: &str = "grove-checkpoint:";
+
+/// Last periodic checkpoint (or first observed activity) per `project/task`.
+static LAST_CHECKPOINT: Lazy<Mutex<HashMap<String, Instant>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// A checkpoint commit on the task branch.
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckpointCommit {
+    pub hash: String,
+    pub message: String,
+    /// Commit time (unix seconds)
+    pub timestamp: i64,
+}
+
+/// Commit all worktree changes as a checkpoint.
+/// Returns `Ok(false)` when there was nothing to commit.
+///
+/// Checkpoints take the identity override but are never signed and get no
+/// trailer — they are meant to be squashed before merging.
+pub fn create_checkpoint(
+    worktree_path: &str,
+    reason: &str,
+    identity: &GitIdentity,
+) -> Result<bool> {
+    if !git::has_uncommitted_changes(worktree_path)? {
+        return Ok(false);
+    }
+    let message = format!(
+        "{} {} ({})",
+        CHECKPOINT_PREFIX,
+        reason,
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+    );
+    git::git_cmd(worktree_path, &["add", "-A"])?;
+    let mut args = identity.config_args();
+    args.extend(["commit", "--no-verify", "-m", &message].map(String::from));
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    git::git_cmd(worktree_path, &args)?;
+    Ok(true)
+}
+
+/// Checkpoint a task if it has checkpoints enabled. Studio and Local tasks
+/// are skipped (no task branch to commit onto).
+fn checkpoint_task(project_key: &str, task_id: &str, reason: &str) -> Result<bool> {
+    if !checkpoints::load_settings(project_key, task_id).enabled {
+        return Ok(false);
+    }
+    let task = match tasks::get_task(project_key, task_id)? {
+        Some(t) if !t.branch.is_empty() && !t.is_local => t,
+        _ => return Ok(false),
+    };
+    let identity = git_identity::resolve(project_key, Some(task_id));
+    create_checkpoint(&task.worktree_path, reason, &identity)
+}
+
+/// Called after an agent turn completes.
+pub fn on_turn_complete(project_key: &str, task_id: &str) {
+    if let Err(e) = checkpoint_task(project_key, task_id, "turn complete") {
+        tracing::warn!("Checkpoint after turn failed for {}: {}", task_id, e);
+    }
+}
+
+/// Called by the file watcher for every batch of edits on a task.
+/// Commits at most once per configured interval of ongoing activity.
+pub fn on_activity(project_key: &str, task_id: &str) {
+    let key = format!("{}/{}", project_key, task_id);
+    let now = Instant::now();
+    let since = {
+        let mut last = LAST_CHECKPOINT.lock().unwrap_or_else(|e| e.into_inner());
+        now.duration_since(*last.entry(key.clone()).or_insert(now))
+    };
+
+    let settings = checkpoints::load_settings(project_key, task_id);
+    let interval = Duration::from_secs(u64::from(settings.interval_mins.max(1)) * 60);
+    if !settings.enabled || since < interval {
+        return;
+    }
+
+    LAST_CHECKPOINT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, now);
+    let (project_key, task_id) = (project_key.to_string(), task_id.to_string());
+    // Off the watcher thread: git add/commit can take a while on big trees
+    std::thread::spawn(move || {
+        if let Err(e) = checkpoint_task(&project_key, &task_id, "periodic") {
+            tracing::warn!("Periodic checkpoint failed for {}: {}", task_id, e);
+        }
+    });
+}
+
+/// Trailing checkpoint commits on the task branch (newest first): the
+/// commits since `target` up to the first non-checkpoint commit.
+pub fn list_checkpoints(worktree_path: &str, target: &str) -> Result<Vec<CheckpointCommit>> {
+    let range = format!("{}..HEAD", target);
+    let output = git::git_cmd(worktree_path, &["log", "--format=%H%x1f%s%x1f%ct", &range])?;
+    Ok(parse_trailing_checkpoints(&output))
+}
+
+fn parse_trailing_checkpoints(log: &str) -> Vec<CheckpointCommit> {
+    log.lines()
+        .map(|line| line.split('\x1f').collect::<Vec<_>>())
+        .take_while(|parts| parts.len() == 3 && parts[1].starts_with(CHECKPOINT_PREFIX))
+        .map(|parts| CheckpointCommit {
+            hash: parts[0].to_string(),
+            message: parts[1].to_string(),
+            timestamp: parts[2].parse().unwrap_or(0),
+        })
+        .collect()
+}
+
+/// Soft-reset to the commit before the oldest trailing checkpoint.
+/// Returns the number of checkpoints removed.
+fn reset_checkpoints(worktree_path: &str, target: &str) -> Result<usize> {
+    let list = list_checkpoints(worktree_path, target)?;
+    let oldest = list
+        .last()
+        .ok_or_else(|| GroveError::invalid_data("No checkpoint commits to rewrite"))?;
+    let base = format!("{}^", oldest.hash);
+    git::git_cmd(worktree_path, &["reset", "--soft", &base])?;
+    Ok(list.len())
+}
+
+/// Squash the trailing checkpoints into a single commit with `message`.
+pub fn squash_checkpoints(
+    worktree_path: &str,
+    target: &str,
+    message: &str,
+    options: &git::CommitOptions,
+) -> Result<usize> {
+    if message.trim().is_empty() {
+        return Err(GroveError::invalid_data("Commit message is required"));
+    }
+    let count = reset_checkpoints(worktree_path, target)?;
+    let message = options.message(message.trim());
+    let args = options.args(&["commit", "--no-verify"], &["-m", &message]);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    git::git_cmd(worktree_path, &args)?;
+    Ok(count)
+}
+
+/// Drop the trailing checkpoint commits; their changes stay staged.
+pub fn drop_checkpoints(worktree_path: &str, target: &str) -> Result<usize> {
+    reset_checkpoints(worktree_path, target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailing_checkpoints_stop_at_regular_commit() {
+        let log = [
+            "c3\x1fgrove-checkpoint: periodic (2026-01-01 10:20:00)\x1f300",
+            "c2\x1fgrove-checkpoint: turn complete (2026-01-01 10:10:00)\x1f200",
+            "c1\x1fAdd parser\x1f100",
+            "c0\x1fgrove-checkpoint: turn complete (2026-01-01 09:00:00)\x1f50",
+        ]
+        .join("\n");
+        let list = parse_trailing_checkpoints(&log);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].hash, "c3");
+        assert_eq!(list[1].timestamp, 200);
+        assert!(parse_trailing_checkpoints("c1\x1fAdd parser\x1f100").is_empty());
+    }
+}
@@ -0,0 +1,289 @@
+//! Rebase conflict resolution
+//!
+//! When `sync_task` stops on conflicts the task worktree is left mid-rebase.
+//! These operations list the conflicted files with their parsed hunks,
+//! resolve a file by taking one side (hunk by hunk, keeping the cleanly
+//! merged text) or hand-edited content, and continue or abort the rebase.
+//!
+//! Sides follow git's rebase semantics: "ours" is the target branch the task
+//! is replayed onto, "theirs" the task commit being applied.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::diff::conflicts::{self, ConflictHunk, ConflictSide};
+use crate::error::{GroveError, Result};
+use crate::git;
+
+/// A conflicted file of the stopped rebase
+#[derive(Debug, Clone, Serialize)]
+pub struct ConflictFile {
+    pub path: String,
+    /// Empty for binary files and delete / modify conflicts — resolve those
+    /// by taking a side
+    pub hunks: Vec<ConflictHunk>,
+    /// Current worktree content with markers, for manual editing (None when
+    /// deleted or not UTF-8)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+/// State of a task's rebase
+#[derive(Debug, Clone, Serialize)]
+pub struct RebaseConflicts {
+    pub in_progress: bool,
+    /// 1-based index of the commit being applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u32>,
+    /// `<short hash> <subject>` of the commit that stopped on conflicts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// Files still unresolved
+    pub files: Vec<ConflictFile>,
+}
+
+/// How to resolve one conflicted file
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "resolution", rename_all = "snake_case")]
+pub enum Resolution {
+    Ours,
+    Theirs,
+    Manual { content: String },
+}
+
+/// Rebase state and unresolved files of a worktree
+pub fn status(worktree: &str) -> Result<RebaseConflicts> {
+    if !git::rebase_in_progress(worktree) {
+        return Ok(RebaseConflicts {
+            in_progress: false,
+            step: None,
+            total: None,
+            commit: None,
+            files: Vec::new(),
+        });
+    }
+
+    let files = git::get_conflict_files(worktree)?
+        .into_iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(Path::new(worktree).join(&path)).ok();
+            ConflictFile {
+                hunks: content
+                    .as_deref()
+                    .map(conflicts::parse_conflicts)
+                    .unwrap_or_default(),
+                path,
+                content,
+            }
+        })
+        .collect();
+    let progress = git::rebase_progress(worktree);
+    Ok(RebaseConflicts {
+        in_progress: true,
+        step: progress.map(|(step, _)| step),
+        total: progress.map(|(_, total)| total),
+        commit: git::rebase_stopped_commit(worktree),
+        files,
+    })
+}
+
+/// Resolve a conflicted file and stage it
+///
+/// Taking a side keeps that side of every conflicted hunk; a file without
+/// markers (binary, deleted on one side) is replaced by that side's version,
+/// or removed when the side deleted it. Manual content must not contain
+/// conflict markers anymore.
+pub fn resolve(worktree: &str, path: &str, resolution: &Resolution) -> Result<()> {
+    // Only conflicted files can be written through here
+    if !git::get_conflict_files(worktree)?.iter().any(|p| p == path) {
+        return Err(GroveError::invalid_data(format!(
+            "'{}' has no unresolved conflict",
+            path
+        )));
+    }
+    let full_path = Path::new(worktree).join(path);
+
+    let side = match resolution {
+        Resolution::Manual { content } => {
+            if !conflicts::parse_conflicts(content).is_empty() {
+                return Err(GroveError::invalid_data(
+                    "Content still contains conflict markers",
+                ));
+            }
+            std::fs::write(&full_path, content)?;
+            return git::stage_file(worktree, path);
+        }
+        Resolution::Ours => ConflictSide::Ours,
+        Resolution::Theirs => ConflictSide::Theirs,
+    };
+
+    let merged = std::fs::read_to_string(&full_path)
+        .ok()
+        .filter(|content| !conflicts::parse_conflicts(content).is_empty())
+        .map(|content| conflicts::take_side(&content, side));
+    match merged {
+        Some(content) => std::fs::write(&full_path, content)?,
+        None => {
+            let ours = side == ConflictSide::Ours;
+            if git::checkout_conflict_side(worktree, path, ours).is_err() {
+                // The side deleted the file
+                if full_path.exists() {
+                    std::fs::remove_file(&full_path)?;
+                }
+            }
+        }
+    }
+    git::stage_file(worktree, path)
+}
+
+/// Continue the rebase once every file is resolved
+///
+/// Returns the new state: stopped on the next commit's conflicts, or done
+/// (`in_progress: false`). A commit left empty by the resolution is skipped.
+pub fn continue_rebase(worktree: &str) -> Result<RebaseConflicts> {
+    if !git::rebase_in_progress(worktree) {
+        return Err(GroveError::invalid_data("No rebase in progress"));
+    }
+    let unresolved = git::get_conflict_files(worktree)?;
+    if !unresolved.is_empty() {
+        return Err(GroveError::invalid_data(format!(
+            "Resolve the remaining conflicts first: {}",
+            unresolved.join(", ")
+        )));
+    }
+
+    let mut result = if git::index_clean(worktree) {
+        git::skip_rebase_commit(worktree)
+    } else {
+        git::continue_rebase(worktree)
+    };
+    // Every later commit may stop again; empty ones are skipped
+    while result.is_err()
+        && git::rebase_in_progress(worktree)
+        && git::get_conflict_files(worktree)?.is_empty()
+        && git::index_clean(worktree)
+    {
+        result = git::skip_rebase_commit(worktree);
+    }
+
+    let state = status(worktree)?;
+    match result {
+        Err(e) if !state.in_progress || state.files.is_empty() => Err(e),
+        _ => Ok(state),
+    }
+}
+
+/// Abort the rebase, restoring the task branch
+pub fn abort(worktree: &str) -> Result<()> {
+    if !git::rebase_in_progress(worktree) {
+        return Err(GroveError::invalid_data("No rebase in progress"));
+    }
+    git::abort_rebase(worktree)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[test]
+    fn resolve_and_continue_rebase() {
+        let repo = tempfile::tempdir().unwrap();
+        let dir = repo.path();
+        let run = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap();
+        };
+        run(&["init", "-q", "-b", "main"]);
+        run(&["config", "user.email", "t@e"]);
+        run(&["config", "user.name", "t"]);
+        std::fs::write(dir.join("a.txt"), "one\ntwo\nthree\n").unwrap();
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "init"]);
+
+        run(&["checkout", "-q", "-b", "task"]);
+        std::fs::write(dir.join("a.txt"), "one\nTWO (task)\nthree\n").unwrap();
+        run(&["commit", "-q", "-am", "task edit"]);
+        std::fs::write(dir.join("b.txt"), "task only\n").unwrap();
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "task file"]);
+
+        run(&["checkout", "-q", "main"]);
+        std::fs::write(dir.join("a.txt"), "one\nTWO (main)\nthree\n").unwrap();
+        run(&["commit", "-q", "-am", "main edit"]);
+        run(&["checkout", "-q", "task"]);
+        let wt = dir.to_str().unwrap();
+
+        assert!(!status(wt).unwrap().in_progress);
+        assert!(git::rebase(wt, "main").is_err());
+
+        let state = status(wt).unwrap();
+        assert!(state.in_progress);
+        assert_eq!((state.step, state.total), (Some(1), Some(2)));
+        assert!(state.commit.unwrap().ends_with("task edit"));
+        assert_eq!(state.files.len(), 1);
+        let hunk = &state.files[0].hunks[0];
+        assert_eq!(hunk.ours, "TWO (main)\n");
+        assert_eq!(hunk.base.as_deref(), Some("two\n"));
+        assert_eq!(hunk.theirs, "TWO (task)\n");
+
+        // Only conflicted files, and no leftover markers
+        assert!(resolve(wt, "b.txt", &Resolution::Ours).is_err());
+        let manual = Resolution::Manual {
+            content: state.files[0].content.clone().unwrap(),
+        };
+        assert!(resolve(wt, "a.txt", &manual).is_err());
+        assert!(continue_rebase(wt).is_err());
+
+        resolve(wt, "a.txt", &Resolution::Theirs).unwrap();
+        let state = continue_rebase(wt).unwrap();
+        assert!(!state.in_progress);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("a.txt")).unwrap(),
+            "one\nTWO (task)\nthree\n"
+        );
+        assert!(dir.join("b.txt").exists());
+        assert!(abort(wt).is_err());
+    }
+
+    #[test]
+    fn empty_commit_after_taking_ours_is_skipped() {
+        let repo = tempfile::tempdir().unwrap();
+        let dir = repo.path();
+        let run = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap();
+        };
+        run(&["init", "-q", "-b", "main"]);
+        run(&["config", "user.email", "t@e"]);
+        run(&["config", "user.name", "t"]);
+        std::fs::write(dir.join("a.txt"), "base\n").unwrap();
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "init"]);
+        run(&["checkout", "-q", "-b", "task"]);
+        std::fs::write(dir.join("a.txt"), "task\n").unwrap();
+        run(&["commit", "-q", "-am", "task edit"]);
+        run(&["checkout", "-q", "main"]);
+        std::fs::write(dir.join("a.txt"), "main\n").unwrap();
+        run(&["commit", "-q", "-am", "main edit"]);
+        run(&["checkout", "-q", "task"]);
+        let wt = dir.to_str().unwrap();
+
+        assert!(git::rebase(wt, "main").is_err());
+        resolve(wt, "a.txt", &Resolution::Ours).unwrap();
+        assert!(!continue_rebase(wt).unwrap().in_progress);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("a.txt")).unwrap(),
+            "main\n"
+        );
+    }
+}
@@ -0,0 +1,248 @@
+//! Agent-to-agent cross review
+//!
+//! Spins up a "reviewer" chat on a finished task using an agent that did not
+//! work on it, and prompts it with the task's diff and notes. The reviewer
+//! posts its findings through `grove_add_comment`, which attributes each
+//! comment to the reviewer chat's agent and model, so the results show up
+//! in the normal review panel next to human comments.
+
+use std::collections::BTreeSet;
+
+use crate::acp::{self, client::ChatClient, SocketCommand};
+use crate::error::{GroveError, Result};
+use crate::git;
+use crate::storage::{installed_agents, notes, tasks};
+
+/// Patch bytes inlined into the reviewer prompt; the rest is left to the
+/// reviewer to read with git.
+const MAX_PATCH_BYTES: usize = 96 * 1024;
+
+/// Duty of the reviewer chat (also lets humans @mention it in review threads).
+const REVIEWER_DUTY: &str = "Cross-reviewer";
+
+/// A reviewer chat ready to be started.
+#[derive(Debug, Clone)]
+pub struct CrossReviewPlan {
+    pub reviewer_agent: String,
+    /// Agents of the task's existing chats
+    pub author_agents: Vec<String>,
+    pub title: String,
+    pub prompt: String,
+}
+
+/// Result of `launch`.
+#[derive(Debug, Clone)]
+pub struct CrossReviewStarted {
+    pub chat_id: String,
+    pub title: String,
+    pub agent: String,
+}
+
+/// Pick the reviewer agent and build its prompt.
+///
+/// `reviewer` must differ from every agent that already has a chat on the
+/// task; when omitted the first installed agent that didn't is used.
+pub fn prepare(
+    project_key: &str,
+    task_id: &str,
+    reviewer: Option<&str>,
+) -> Result<CrossReviewPlan> {
+    let task = tasks::get_task(project_key, task_id)?
+        .ok_or_else(|| GroveError::not_found("Task not found"))?;
+    if !std::path::Path::new(&task.worktree_path).exists() {
+        return Err(GroveError::invalid_data("Task worktree does not exist"));
+    }
+
+    let author_agents: Vec<String> = tasks::load_chat_sessions(project_key, task_id)?
+        .into_iter()
+        .map(|c| c.agent)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    let reviewer_agent = match reviewer.map(str::trim).filter(|r| !r.is_empty()) {
+        Some(agent) => {
+            if author_agents.iter().any(|a| a == agent) {
+                return Err(GroveError::invalid_data(format!(
+                    "{} already worked on this task; pick a different agent to cross-review",
+                    agent
+                )));
+            }
+            if acp::resolve_agent(agent).is_none() {
+                return Err(GroveError::invalid_data(format!(
+                    "Unknown agent: {}",
+                    agent
+                )));
+            }
+            agent.to_string()
+        }
+        None => installed_agents::list()?
+            .into_iter()
+            .map(|a| a.id)
+            .find(|id| !author_agents.contains(id) && acp::resolve_agent(id).is_some())
+            .ok_or_else(|| {
+                GroveError::invalid_data(
+                    "No installed agent other than the task's own agents is available to review",
+                )
+            })?,
+    };
+
+    let stat = git::diff_stat(&task.worktree_path, &task.target)?;
+    if stat.is_empty() {
+        return Err(GroveError::invalid_data("Task has no changes to review"));
+    }
+    let stat_lines: Vec<String> = stat
+        .iter()
+        .map(|e| {
+            if e.is_binary {
+                format!("{} {} (binary)", e.status, e.path)
+            } else {
+                format!("{} {} +{} -{}", e.status, e.path, e.additions, e.deletions)
+            }
+        })
+        .collect();
+    let patch = git::unified_diff(&task.worktree_path, &task.target)?;
+    let task_notes = notes::load_notes(project_key, task_id).unwrap_or_default();
+
+    Ok(CrossReviewPlan {
+        title: format!("Cross-review ({})", reviewer_agent),
+        prompt: build_prompt(
+            &task.name,
+            &task.target,
+            &author_agents,
+            &stat_lines,
+            &patch,
+            &task_notes,
+        ),
+        reviewer_agent,
+        author_agents,
+    })
+}
+
+/// Create the reviewer chat, start its session and send the review prompt.
+pub async fn launch(
+    project_key: &str,
+    project_path: &str,
+    project_name: &str,
+    task_id: &str,
+    plan: CrossReviewPlan,
+) -> Result<CrossReviewStarted> {
+    let task = tasks::get_task(project_key, task_id)?
+        .ok_or_else(|| GroveError::not_found("Task not found"))?;
+    let chat = acp::client::create_chat(
+        project_key,
+        task_id,
+        &plan.reviewer_agent,
+        Some(plan.title.clone()),
+        None,
+    )?;
+    tasks::update_chat_duty(
+        project_key,
+        task_id,
+        &chat.id,
+        Some(REVIEWER_DUTY.to_string()),
+        false,
+    )?;
+
+    let client =
+        ChatClient::connect(project_key, project_path, project_name, &task, &chat.id).await?;
+    client
+        .send(SocketCommand::Prompt {
+            text: plan.prompt,
+            attachments: vec![],
+            sender: Some("cross-review".to_string()),
+            config: None,
+        })
+        .await?;
+
+    Ok(CrossReviewStarted {
+        chat_id: chat.id,
+        title: chat.title,
+        agent: plan.reviewer_agent,
+    })
+}
+
+/// Compose the reviewer prompt from the diff and the task notes.
+fn build_prompt(
+    task_name: &str,
+    target: &str,
+    author_agents: &[String],
+    stat_lines: &[String],
+    patch: &str,
+    task_notes: &str,
+) -> String {
+    let mut out = format!(
+        "You are cross-reviewing task \"{}\" (changes against `{}`)",
+        task_name, target
+    );
+    if author_agents.is_empty() {
+        out.push_str(".\n");
+    } else {
+        out.push_str(&format!(", implemented by {}.\n", author_agents.join(", ")));
+    }
+    out.push_str("Do not modify any files — only review.\n");
+
+    let task_notes = task_notes.trim();
+    if !task_notes.is_empty() {
+        out.push_str(&format!("\nTask notes:\n{}\n", task_notes));
+    }
+
+    out.push_str(&format!(
+        "\nChanged files ({}):\n{}\n",
+        stat_lines.len(),
+        stat_lines.join("\n")
+    ));
+
+    let mut cut = patch.len().min(MAX_PATCH_BYTES);
+    while !patch.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    out.push_str(&format!("\n```diff\n{}\n```\n", patch[..cut].trim_end()));
+    if cut < patch.len() {
+        out.push_str(&format!(
+            "\nThe diff above is truncated; run `git diff $(git merge-base {} HEAD)` for the rest.\n",
+            target
+        ));
+    }
+
+    out.push_str(
+        "\nReport each finding (bugs, risky changes, missing tests, deviations from the notes) with \
+         `grove_add_comment` using role \"Cross-reviewer\": inline comments for specific lines, a \
+         project comment for overall feedback. Finish with one project comment summarizing your verdict.\n",
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prompt_includes_diff_notes_and_instructions() {
+        let prompt = build_prompt(
+            "Add cache",
+            "main",
+            &["claude".to_string()],
+            &["M src/lib.rs +3 -1".to_string()],
+            "diff --git a/src/lib.rs b/src/lib.rs\n+fn cached() {}\n",
+            "Use an LRU",
+        );
+        assert!(
+            prompt.contains("task \"Add cache\" (changes against `main`), implemented by claude")
+        );
+        assert!(prompt.contains("Task notes:\nUse an LRU"));
+        assert!(prompt.contains("M src/lib.rs +3 -1"));
+        assert!(prompt.contains("+fn cached() {}"));
+        assert!(prompt.contains("grove_add_comment"));
+        assert!(!prompt.contains("truncated"));
+    }
+
+    #[test]
+    fn prompt_truncates_large_patch() {
+        let patch = "+é\n".repeat(MAX_PATCH_BYTES);
+        let prompt = build_prompt("t", "main", &[], &[], &patch, "");
+        assert!(prompt.len() < patch.len());
+        assert!(prompt.contains("git diff $(git merge-base main HEAD)"));
+        assert!(!prompt.contains("Task notes"));
+    }
+}
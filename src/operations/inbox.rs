@@ -0,0 +1,314 @@
+//! Inbox of everything awaiting the local user's input
+//!
+//! Aggregates, across all registered projects:
+//! - agent chats hosted by this process that are blocked on a permission
+//!   request
+//! - open review threads where an agent replied to the user's comment or
+//!   @mentioned the user
+//! - tasks whose worktree is in a merge conflict
+//! - tasks whose last merge attempt failed
+//! - tasks handed back to the user
+//!
+//! Permission requests come first since they block an agent; the rest are
+//! sorted newest first.
+
+use serde::Serialize;
+
+use crate::model::{loader, WorktreeStatus};
+use crate::operations::review::{self, MentionCandidate, MentionTarget};
+use crate::storage::comments::{Comment, CommentStatus};
+use crate::storage::tasks::TaskAssignee;
+use crate::storage::{comments, tasks, workspace};
+
+/// Max characters kept from a review message.
+const EXCERPT_CHARS: usize = 120;
+
+/// What an inbox item is waiting on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InboxKind {
+    Permission {
+        chat_id: String,
+        chat_title: Option<String>,
+        description: String,
+    },
+    ReviewReply {
+        comment_id: u32,
+        from: String,
+        excerpt: String,
+    },
+    Conflict,
+    MergeFailed {
+        error: String,
+    },
+    Handback,
+}
+
+/// One thing awaiting the user, with enough context to jump to it.
+#[derive(Debug, Clone, Serialize)]
+pub struct InboxItem {
+    pub project_id: String,
+    pub project_name: String,
+    pub project_path: String,
+    pub task_id: String,
+    pub task_name: String,
+    #[serde(flatten)]
+    pub kind: InboxKind,
+    /// RFC 3339, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+}
+
+impl InboxItem {
+    /// Short label for list rendering.
+    pub fn kind_label(&self) -> &'static str {
+        match self.kind {
+            InboxKind::Permission { .. } => "Permission",
+            InboxKind::ReviewReply { .. } => "Review",
+            InboxKind::Conflict => "Conflict",
+            InboxKind::MergeFailed { .. } => "Merge failed",
+            InboxKind::Handback => "Your turn",
+        }
+    }
+
+    /// One-line description of what is being asked.
+    pub fn summary(&self) -> String {
+        match &self.kind {
+            InboxKind::Permission {
+                chat_title,
+                description,
+                ..
+            } => match chat_title {
+                Some(title) => format!("{title}: {description}"),
+                None => description.clone(),
+            },
+            InboxKind::ReviewReply { from, excerpt, .. } => format!("{from}: {excerpt}"),
+            InboxKind::Conflict => "Merge conflict needs resolving".to_string(),
+            InboxKind::MergeFailed { error } => error.clone(),
+            InboxKind::Handback => "Handed back to you".to_string(),
+        }
+    }
+}
+
+/// Collect the inbox across all registered projects.
+pub fn collect_inbox() -> Vec<InboxItem> {
+    let projects = workspace::load_projects().unwrap_or_default();
+    let mut items = Vec::new();
+
+    for chat in crate::acp::snapshot_active_chats() {
+        let Some(permission) = chat.permission else {
+            continue;
+        };
+        let project = projects
+            .iter()
+            .find(|p| workspace::project_hash(&p.path) == chat.project_id);
+        items.push(InboxItem {
+            project_name: chat
+                .project_name
+                .or_else(|| project.map(|p| p.name.clone()))
+                .unwrap_or_default(),
+            project_path: project.map(|p| p.path.clone()).unwrap_or_default(),
+            project_id: chat.project_id,
+            task_name: chat.task_name.unwrap_or_else(|| chat.task_id.clone()),
+            task_id: chat.task_id,
+            kind: InboxKind::Permission {
+                chat_id: chat.chat_id,
+                chat_title: chat.chat_title,
+                description: permission.description,
+            },
+            timestamp: None,
+        });
+    }
+
+    for project in &projects {
+        collect_project(project, &mut items);
+    }
+
+    sort_items(&mut items);
+    items
+}
+
+fn collect_project(project: &workspace::RegisteredProject, items: &mut Vec<InboxItem>) {
+    let project_key = workspace::project_hash(&project.path);
+    let Ok(task_list) = tasks::load_tasks(&project_key) else {
+        return;
+    };
+    let item = |task: &tasks::Task, kind: InboxKind, timestamp: Option<String>| InboxItem {
+        project_id: project_key.clone(),
+        project_name: project.name.clone(),
+        project_path: project.path.clone(),
+        task_id: task.id.clone(),
+        task_name: task.name.clone(),
+        kind,
+        timestamp,
+    };
+
+    for task in &task_list {
+        if task.assignee == Some(TaskAssignee::Human) {
+            items.push(item(
+                task,
+                InboxKind::Handback,
+                Some(task.updated_at.to_rfc3339()),
+            ));
+        }
+
+        let Ok(data) = comments::load_comments(&project_key, &task.id) else {
+            continue;
+        };
+        if data.comments.is_empty() {
+            continue;
+        }
+        let candidates = review::mention_candidates(&project_key, &task.id);
+        for comment in &data.comments {
+            if let Some((from, text, timestamp)) = awaiting_reply(comment, &candidates) {
+                items.push(item(
+                    task,
+                    InboxKind::ReviewReply {
+                        comment_id: comment.id,
+                        from: from.to_string(),
+                        excerpt: crate::agent_graph::excerpt_chars(text, EXCERPT_CHARS),
+                    },
+                    Some(timestamp.to_string()),
+                ));
+            }
+        }
+    }
+
+    for failure in tasks::load_merge_failures(&project_key).unwrap_or_default() {
+        if let Some(task) = task_list.iter().find(|t| t.id == failure.task_id) {
+            items.push(item(
+                task,
+                InboxKind::MergeFailed {
+                    error: failure.error,
+                },
+                Some(failure.timestamp.to_rfc3339()),
+            ));
+        }
+    }
+
+    if project.is_git_repo {
+        for wt in loader::load_worktrees(&project.path) {
+            if wt.status != WorktreeStatus::Conflict {
+                continue;
+            }
+            if let Some(task) = task_list.iter().find(|t| t.id == wt.id) {
+                items.push(item(task, InboxKind::Conflict, None));
+            }
+        }
+    }
+}
+
+/// Whether an open review thread is waiting on the local user.
+///
+/// It is when the latest message came from an agent (agents sign with a
+/// model, the user never does) and either the thread was started by the
+/// user or that message @mentions them. Returns the latest message's
+/// author, text and timestamp.
+fn awaiting_reply<'a>(
+    comment: &'a Comment,
+    candidates: &[MentionCandidate],
+) -> Option<(&'a str, &'a str, &'a str)> {
+    if comment.status != CommentStatus::Open {
+        return None;
+    }
+    let (agent, model, content, timestamp) = match comment.replies.last() {
+        Some(r) => (&r.agent, &r.model, &r.content, &r.timestamp),
+        None => (
+            &comment.agent,
+            &comment.model,
+            &comment.content,
+            &comment.timestamp,
+        ),
+    };
+    if model.is_empty() {
+        return None;
+    }
+    let user_thread = !comment.replies.is_empty() && comment.model.is_empty();
+    let mentions_user =
+        review::find_mentions(content, candidates).contains(&MentionTarget::LocalUser);
+    (user_thread || mentions_user).then_some((agent.as_str(), content.as_str(), timestamp.as_str()))
+}
+
+fn sort_items(items: &mut [InboxItem]) {
+    items.sort_by(|a, b| {
+        let a_perm = matches!(a.kind, InboxKind::Permission { .. });
+        let b_perm = matches!(b.kind, InboxKind::Permission { .. });
+        b_perm
+            .cmp(&a_perm)
+            .then_with(|| b.timestamp.cmp(&a.timestamp))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::comments::{CommentReply, CommentType};
+
+    fn comment(model: &str, replies: Vec<(&str, &str, &str)>) -> Comment {
+        Comment {
+            id: 1,
+            comment_type: CommentType::Project,
+            file_path: None,
+            side: None,
+            start_line: None,
+            end_line: None,
+            content: "Why is this here?".to_string(),
+            agent: if model.is_empty() { "Alice" } else { "Claude" }.to_string(),
+            model: model.to_string(),
+            role: String::new(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            status: CommentStatus::Open,
+            replies: replies
+                .into_iter()
+                .enumerate()
+                .map(|(i, (agent, model, content))| CommentReply {
+                    id: i as u32 + 1,
+                    content: content.to_string(),
+                    agent: agent.to_string(),
+                    model: model.to_string(),
+                    role: String::new(),
+                    timestamp: format!("2026-01-0{}T00:00:00Z", i + 2),
+                    reactions: Vec::new(),
+                })
+                .collect(),
+            anchor_text: None,
+            reactions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn review_threads_awaiting_user() {
+        let candidates = vec![MentionCandidate {
+            name: "Alice".to_string(),
+            target: MentionTarget::LocalUser,
+        }];
+
+        // Agent answered the user's comment
+        let c = comment("", vec![("Claude", "opus", "It caches the result")]);
+        assert_eq!(
+            awaiting_reply(&c, &candidates),
+            Some(("Claude", "It caches the result", "2026-01-02T00:00:00Z"))
+        );
+
+        // User already had the last word
+        let c = comment(
+            "",
+            vec![("Claude", "opus", "It caches"), ("Alice", "", "Thanks")],
+        );
+        assert_eq!(awaiting_reply(&c, &candidates), None);
+
+        // Unanswered user comment is waiting on the agent, not the user
+        assert_eq!(awaiting_reply(&comment("", vec![]), &candidates), None);
+
+        // Agent thread only counts when the user is mentioned
+        let c = comment("opus", vec![("Codex", "gpt", "Agreed")]);
+        assert_eq!(awaiting_reply(&c, &candidates), None);
+        let c = comment("opus", vec![("Codex", "gpt", "@Alice can you confirm?")]);
+        assert!(awaiting_reply(&c, &candidates).is_some());
+
+        // Resolved threads never count
+        let mut c = comment("", vec![("Claude", "opus", "Done")]);
+        c.status = CommentStatus::Resolved;
+        assert_eq!(awaiting_reply(&c, &candidates), None);
+    }
+}
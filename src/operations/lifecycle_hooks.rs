@@ -0,0 +1,326 @@
+//! Task lifecycle hooks
+//!
+//! Runs a project's post-create / pre-merge / post-merge hooks in the task
+//! worktree: the commands configured in `lifecycle_hooks.toml` first, then the
+//! repo's own `.grove/hooks/<event>` (or `<event>.sh`) script when the
+//! project opted in (`run_repo_scripts`). Post-create also runs the
+//! `[auto_link] post_create` commands, ahead of the others and never
+//! blocking. Every hook gets
+//! the task's `GROVE_*` variables plus `GROVE_HOOK_EVENT`. Failures of
+//! blocking hooks are returned as an error for the caller to abort on; the
+//! others become a warning.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::GroveError;
+use crate::hooks::{run_with_timeout, shell_command};
+use crate::storage::lifecycle_hooks::{self, HookEvent, LifecycleHookSettings};
+use crate::tmux::SessionEnv;
+
+/// Output kept in a failure message
+const MAX_ERROR_OUTPUT: usize = 500;
+
+/// A hook that failed, timed out or couldn't be started
+#[derive(Debug, Clone)]
+pub struct HookFailure {
+    /// The configured command or the script path
+    pub name: String,
+    pub blocking: bool,
+    pub message: String,
+}
+
+/// Outcome of running the hooks of one event
+#[derive(Debug, Default)]
+pub struct HookReport {
+    pub failures: Vec<HookFailure>,
+}
+
+impl HookReport {
+    /// Error for the blocking failures, e.g. `pre-merge hook `cargo test` failed: …`
+    pub fn blocking_error(&self, event: HookEvent) -> Option<GroveError> {
+        let messages = self.describe(event, true);
+        (!messages.is_empty()).then(|| GroveError::invalid_data(messages.join("; ")))
+    }
+
+    /// Warning for the non-blocking failures
+    pub fn warning(&self, event: HookEvent) -> Option<String> {
+        let messages = self.describe(event, false);
+        (!messages.is_empty()).then(|| messages.join("; "))
+    }
+
+    fn describe(&self, event: HookEvent, blocking: bool) -> Vec<String> {
+        self.failures
+            .iter()
+            .filter(|f| f.blocking == blocking)
+            .map(|f| {
+                format!(
+                    "{} hook `{}` failed: {}",
+                    event.script_name(),
+                    f.name,
+                    f.message
+                )
+            })
+            .collect()
+    }
+}
+
+/// A hook resolved for one run
+#[derive(Debug, PartialEq, Eq)]
+struct PlannedHook {
+    name: String,
+    command: String,
+    blocking: bool,
+}
+
+/// `.grove/hooks/<event>` or `.grove/hooks/<event>.sh` in the worktree
+fn repo_script(worktree: &Path, event: HookEvent) -> Option<PathBuf> {
+    let dir = worktree.join(".grove").join("hooks");
+    [
+        dir.join(event.script_name()),
+        dir.join(format!("{}.sh", event.script_name())),
+    ]
+    .into_iter()
+    .find(|p| p.is_file())
+}
+
+/// Shell command running a script: executable files directly (honouring
+/// their shebang), others through `sh`
+fn script_command(path: &Path) -> String {
+    let quoted = format!("'{}'", path.to_string_lossy().replace('\'', "'\\''"));
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let executable = std::fs::metadata(path)
+            .map(|m| m.permissions().mode() & 0o111 != 0)
+            .unwrap_or(false);
+        if executable {
+            return quoted;
+        }
+    }
+    format!("sh {}", quoted)
+}
+
+/// `extra` are non-blocking commands run before the configured ones
+fn planned_hooks(
+    settings: &LifecycleHookSettings,
+    event: HookEvent,
+    worktree: &Path,
+    extra: &[String],
+) -> Vec<PlannedHook> {
+    // A merge already happened by the time post-merge hooks run: nothing to block
+    let can_block = event != HookEvent::PostMerge;
+    let mut planned: Vec<PlannedHook> = extra
+        .iter()
+        .map(|command| PlannedHook {
+            name: command.clone(),
+            command: command.clone(),
+            blocking: false,
+        })
+        .collect();
+    planned.extend(settings.for_event(event).map(|hook| PlannedHook {
+        name: hook.command.clone(),
+        command: hook.command.clone(),
+        blocking: can_block && hook.is_blocking(),
+    }));
+    if settings.run_repo_scripts {
+        if let Some(script) = repo_script(worktree, event) {
+            planned.push(PlannedHook {
+                name: format!(
+                    ".grove/hooks/{}",
+                    script.file_name().unwrap().to_string_lossy()
+                ),
+                command: script_command(&script),
+                blocking: can_block && event.default_blocking(),
+            });
+        }
+    }
+    planned
+}
+
+/// Run the project's hooks for `event` in `env.worktree`, in order.
+///
+/// Every hook runs even after an earlier one failed, so one report lists all
+/// failures.
+pub fn run(project_key: &str, event: HookEvent, env: &SessionEnv) -> HookReport {
+    run_with_settings(
+        &lifecycle_hooks::load_settings(project_key),
+        event,
+        env,
+        &[],
+    )
+}
+
+/// Run the post-create hooks, preceded by the `[auto_link] post_create`
+/// commands (same runner, timeout and reporting; failures are warnings).
+pub fn run_post_create(
+    project_key: &str,
+    env: &SessionEnv,
+    auto_link_commands: &[String],
+) -> HookReport {
+    run_with_settings(
+        &lifecycle_hooks::load_settings(project_key),
+        HookEvent::PostCreate,
+        env,
+        auto_link_commands,
+    )
+}
+
+fn run_with_settings(
+    settings: &LifecycleHookSettings,
+    event: HookEvent,
+    env: &SessionEnv,
+    extra: &[String],
+) -> HookReport {
+    let worktree = Path::new(&env.worktree);
+    let timeout = Duration::from_secs(settings.timeout_secs.max(1));
+
+    let mut report = HookReport::default();
+    for hook in planned_hooks(settings, event, worktree, extra) {
+        let mut cmd = shell_command(&hook.command);
+        cmd.current_dir(worktree);
+        env.apply_to_command(&mut cmd);
+        cmd.env("GROVE_HOOK_EVENT", event.script_name());
+
+        let message = match run_with_timeout(cmd, timeout) {
+            Ok(Some(out)) if out.status.success() => continue,
+            Ok(Some(out)) => {
+                let tail = out.error_tail(MAX_ERROR_OUTPUT);
+                if tail.is_empty() {
+                    format!("exited with {}", out.status)
+                } else {
+                    tail.to_string()
+                }
+            }
+            Ok(None) => format!("timed out after {}s", timeout.as_secs()),
+            Err(e) => e.to_string(),
+        };
+        tracing::warn!(
+            "{} hook `{}` failed: {}",
+            event.script_name(),
+            hook.name,
+            message
+        );
+        report.failures.push(HookFailure {
+            name: hook.name,
+            blocking: hook.blocking,
+            message,
+        });
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::lifecycle_hooks::LifecycleHook;
+
+    fn settings(hooks: Vec<LifecycleHook>) -> LifecycleHookSettings {
+        LifecycleHookSettings {
+            hooks,
+            ..Default::default()
+        }
+    }
+
+    fn hook(event: HookEvent, command: &str, blocking: Option<bool>) -> LifecycleHook {
+        LifecycleHook {
+            event,
+            command: command.to_string(),
+            blocking,
+        }
+    }
+
+    #[test]
+    fn test_planned_hooks_include_repo_script() {
+        let dir = tempfile::tempdir().unwrap();
+        let hooks_dir = dir.path().join(".grove").join("hooks");
+        std::fs::create_dir_all(&hooks_dir).unwrap();
+        std::fs::write(hooks_dir.join("pre-merge.sh"), "exit 0\n").unwrap();
+
+        let s = settings(vec![
+            hook(HookEvent::PreMerge, "cargo test", Some(false)),
+            hook(HookEvent::PostCreate, "npm install", None),
+        ]);
+        // Repo scripts only run when the project opted in
+        assert_eq!(
+            planned_hooks(&s, HookEvent::PreMerge, dir.path(), &[]).len(),
+            1
+        );
+        let s = LifecycleHookSettings {
+            run_repo_scripts: true,
+            ..s
+        };
+        let planned = planned_hooks(&s, HookEvent::PreMerge, dir.path(), &[]);
+        assert_eq!(planned.len(), 2);
+        assert_eq!(planned[0].command, "cargo test");
+        assert!(!planned[0].blocking);
+        assert_eq!(planned[1].name, ".grove/hooks/pre-merge.sh");
+        assert!(planned[1].blocking);
+
+        // AutoLink post-create commands come first and never block
+        let planned = planned_hooks(
+            &s,
+            HookEvent::PostCreate,
+            dir.path(),
+            &["cp .env.example .env".to_string()],
+        );
+        assert_eq!(planned.len(), 2);
+        assert_eq!(planned[0].command, "cp .env.example .env");
+        assert!(!planned[0].blocking);
+        assert_eq!(planned[1].command, "npm install");
+
+        // post-merge hooks never block
+        let s = settings(vec![hook(HookEvent::PostMerge, "make clean", Some(true))]);
+        assert!(!planned_hooks(&s, HookEvent::PostMerge, dir.path(), &[])[0].blocking);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_reports_failures_with_env() {
+        let dir = tempfile::tempdir().unwrap();
+        let s = settings(vec![
+            hook(
+                HookEvent::PreMerge,
+                "test \"$GROVE_TASK_ID\" = fix-login",
+                None,
+            ),
+            hook(
+                HookEvent::PreMerge,
+                "echo \"$GROVE_HOOK_EVENT broke\" >&2; exit 1",
+                Some(false),
+            ),
+            hook(HookEvent::PreMerge, "exit 3", None),
+        ]);
+        let env = SessionEnv {
+            task_id: "fix-login".into(),
+            worktree: dir.path().to_string_lossy().into_owned(),
+            ..Default::default()
+        };
+
+        let report = run_with_settings(&s, HookEvent::PreMerge, &env, &[]);
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(
+            report.warning(HookEvent::PreMerge).as_deref(),
+            Some("pre-merge hook `echo \"$GROVE_HOOK_EVENT broke\" >&2; exit 1` failed: pre-merge broke")
+        );
+        let err = report.blocking_error(HookEvent::PreMerge).unwrap();
+        assert!(err
+            .to_string()
+            .starts_with("pre-merge hook `exit 3` failed: exited with"));
+
+        assert!(run_with_settings(&s, HookEvent::PostCreate, &env, &[])
+            .failures
+            .is_empty());
+
+        // AutoLink post-create commands report through the same runner
+        let report = run_with_settings(
+            &s,
+            HookEvent::PostCreate,
+            &env,
+            &["test \"$GROVE_TASK_ID\" = other".to_string()],
+        );
+        assert_eq!(report.failures.len(), 1);
+        assert!(report.blocking_error(HookEvent::PostCreate).is_none());
+        assert!(report.warning(HookEvent::PostCreate).is_some());
+    }
+}
@@ -3,6 +3,27 @@
 //! This module contains the core business logic for task operations,
 //! shared between the TUI and Web API to eliminate code duplication.
 
+pub mod agent_hooks;
+pub mod autolink;
+pub mod bench;
+pub mod chat_import;
+pub mod checkpoints;
+pub mod conflicts;
+pub mod cross_review;
+pub mod inbox;
+pub mod lifecycle_hooks;
+pub mod org;
 pub mod projects;
+pub mod review;
+pub mod risk;
+pub mod search;
+pub mod security_scan;
 pub mod skills;
+pub mod snapshots;
+pub mod staging;
+pub mod sync;
+pub mod task_summary;
 pub mod tasks;
+pub mod time_tracking;
+pub mod tool_timeline;
+pub mod turn_manifests;
@@ -0,0 +1,759 @@
+//! Organization mode: task metadata shared through a team Grove server
+//!
+//! Worktrees stay on each developer's machine. What is shared is each active
+//! task's metadata, notes and review comments, so teammates can follow and
+//! review one another's agent tasks. The team server is a regular Grove web
+//! server with `[organization] members` set, which mounts the `/org` routes.
+//! Every member gets their own token; the server names the caller after it,
+//! so members can only publish under their own name.
+//!
+//! Both sides go through the storage traits in [`storage::backend`]: the
+//! server keeps each member's data in a [`MemberStore`], and clients reach it
+//! through [`HttpStore`], publishing their tasks from [`Local`] every minute.
+//!
+//! Projects are matched across machines the same way as workspace sync (by
+//! `origin` URL), so teammates only see tasks of projects they also have.
+//!
+//! Review threads on a shared task are merged rather than overwritten:
+//! comments and replies are matched by author and timestamp, reactions are
+//! unioned, and deleting a comment only takes effect locally. The task's
+//! owner decides content and status; other members can only add to a thread
+//! and edit what they wrote themselves.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GroveError, Result};
+use crate::operations::sync::{self, TaskRecord};
+use crate::storage::backend::{CommentStore, Local, NoteStore, TaskStore};
+use crate::storage::comments::{Comment, CommentReply, CommentStatus, CommentType, Reaction};
+use crate::storage::org::{self as org_store, MemberStore};
+use crate::storage::tasks::Task;
+use crate::storage::{config, workspace};
+
+/// How often tasks are published while organization mode is configured.
+const PUBLISH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Timeout for a single request to the team server.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A task as listed by the team server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedTask {
+    /// Member who owns the task (and its worktree)
+    pub owner: String,
+    pub task: TaskRecord,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// Outcome of the last publish.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OrgStatus {
+    /// Name the team server knows this machine's user by
+    pub member: Option<String>,
+    /// RFC 3339
+    pub last_run_at: Option<String>,
+    pub last_error: Option<String>,
+    pub published: usize,
+}
+
+static STATUS: Mutex<OrgStatus> = Mutex::new(OrgStatus {
+    member: None,
+    last_run_at: None,
+    last_error: None,
+    published: 0,
+});
+
+/// Serializes comment merges on the team server (load → merge → save).
+static MERGE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Body of the notes routes.
+#[derive(Serialize, Deserialize)]
+pub struct NotesBody {
+    pub content: String,
+}
+
+/// Body of `GET /org/me`.
+#[derive(Serialize, Deserialize)]
+pub struct MemberBody {
+    pub member: String,
+}
+
+// ============================================================================
+// Team server side
+// ============================================================================
+
+/// Tasks published for a project, by every member.
+pub fn server_list_tasks(project: &str) -> Result<Vec<SharedTask>> {
+    Ok(org_store::load_shared_tasks(project)?
+        .into_iter()
+        .map(|row| SharedTask {
+            task: TaskRecord::from_task(&row.task),
+            owner: row.owner,
+            notes: row.notes,
+        })
+        .collect())
+}
+
+/// Tasks `owner` publishes for a project.
+pub fn server_load_tasks(project: &str, owner: &str) -> Result<Vec<TaskRecord>> {
+    Ok(MemberStore::new(owner)
+        .load_tasks(project)?
+        .iter()
+        .map(TaskRecord::from_task)
+        .collect())
+}
+
+/// Replace the tasks `owner` publishes for a project.
+pub fn server_replace_tasks(project: &str, owner: &str, records: Vec<TaskRecord>) -> Result<()> {
+    let tasks: Vec<Task> = records.into_iter().map(shared_task).collect();
+    MemberStore::new(owner).replace_tasks(project, &tasks)
+}
+
+/// Merge review threads sent by `caller` into a task of `owner`.
+pub fn server_merge_comments(
+    project: &str,
+    owner: &str,
+    task_id: &str,
+    caller: &str,
+    incoming: &[Comment],
+) -> Result<()> {
+    let _guard = MERGE_LOCK.lock().unwrap();
+    let store = MemberStore::new(owner);
+    let existing = store.load_comments(project, task_id)?;
+    let author = (caller != owner).then_some(caller);
+    let merged = merge_threads(&existing, incoming, author);
+    if !same_threads(&existing, &merged) {
+        store.replace_comments(project, task_id, &merged)?;
+    }
+    Ok(())
+}
+
+/// A published task has no worktree or session on the receiving side.
+fn shared_task(record: TaskRecord) -> Task {
+    record.into_task(String::new(), String::new(), String::new())
+}
+
+// ============================================================================
+// Client side
+// ============================================================================
+
+/// One member's data on a team server reached over HTTP.
+///
+/// Writes are only accepted for the member the token belongs to; comments
+/// are merged by the server (see the module docs), so replacing them never
+/// drops anything.
+#[derive(Clone)]
+pub struct HttpStore {
+    base_url: url::Url,
+    token: String,
+    /// Who the token belongs to
+    member: String,
+    /// Whose data this store reads and writes
+    owner: String,
+}
+
+impl HttpStore {
+    /// Client for the configured team server, asking it who the token
+    /// belongs to.
+    pub fn connect(cfg: &config::OrganizationConfig) -> Result<Self> {
+        let base_url = cfg
+            .server_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|u| !u.is_empty())
+            .ok_or_else(|| GroveError::invalid_data("No team server configured"))?;
+        let base_url = url::Url::parse(base_url)
+            .map_err(|e| GroveError::invalid_data(format!("Invalid team server URL: {e}")))?;
+        let mut store = Self {
+            base_url,
+            token: cfg.token.clone().unwrap_or_default(),
+            member: String::new(),
+            owner: String::new(),
+        };
+        let me: MemberBody = store.get(&["me"])?;
+        store.member = me.member.clone();
+        store.owner = me.member;
+        Ok(store)
+    }
+
+    /// Name the team server knows this machine's user by.
+    pub fn member(&self) -> &str {
+        &self.member
+    }
+
+    /// The same server, reading another member's data.
+    pub fn of(&self, owner: &str) -> Self {
+        Self {
+            owner: owner.to_string(),
+            ..self.clone()
+        }
+    }
+
+    /// Tasks published for a project, by every member.
+    pub fn list_team_tasks(&self, project: &str) -> Result<Vec<SharedTask>> {
+        self.get(&["projects", project, "tasks"])
+    }
+
+    fn send(
+        &self,
+        method: &str,
+        segments: &[&str],
+        body: Option<serde_json::Value>,
+    ) -> Result<ureq::Response> {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .map_err(|_| GroveError::invalid_data("Invalid team server URL"))?
+            .pop_if_empty()
+            .push("org")
+            .extend(segments);
+        let request = ureq::request_url(method, &url)
+            .set("Authorization", &format!("Bearer {}", self.token))
+            .timeout(REQUEST_TIMEOUT);
+        let response = match body {
+            Some(body) => request.send_json(body),
+            None => request.call(),
+        };
+        match response {
+            Ok(resp) => Ok(resp),
+            Err(ureq::Error::Status(401, _)) => {
+                Err(GroveError::invalid_data("Team server rejected the token"))
+            }
+            Err(ureq::Error::Status(403, resp)) => {
+                Err(GroveError::invalid_data(error_message(resp)))
+            }
+            Err(ureq::Error::Status(404, resp)) => Err(GroveError::not_found(error_message(resp))),
+            Err(ureq::Error::Status(status, resp)) => Err(GroveError::storage(format!(
+                "Team server returned HTTP {status}: {}",
+                error_message(resp)
+            ))),
+            Err(e) => Err(GroveError::storage(format!("Team server unreachable: {e}"))),
+        }
+    }
+
+    fn get<T: DeserializeOwned>(&self, segments: &[&str]) -> Result<T> {
+        self.send("GET", segments, None)?
+            .into_json()
+            .map_err(|e| GroveError::storage(format!("Invalid team server response: {e}")))
+    }
+
+    fn put(&self, segments: &[&str], body: impl Serialize) -> Result<()> {
+        self.send("PUT", segments, Some(serde_json::to_value(body)?))
+            .map(|_| ())
+    }
+}
+
+/// The `error` field of a Grove API error body, or the raw body.
+fn error_message(resp: ureq::Response) -> String {
+    let body = resp.into_string().unwrap_or_default();
+    serde_json::from_str::<serde_json::Value>(&body)
+        .ok()
+        .and_then(|v| v.get("error")?.as_str().map(str::to_string))
+        .unwrap_or(body)
+}
+
+impl TaskStore for HttpStore {
+    fn load_tasks(&self, project: &str) -> Result<Vec<Task>> {
+        let records: Vec<TaskRecord> =
+            self.get(&["projects", project, "members", &self.owner, "tasks"])?;
+        Ok(records.into_iter().map(shared_task).collect())
+    }
+
+    fn replace_tasks(&self, project: &str, tasks: &[Task]) -> Result<()> {
+        let records: Vec<TaskRecord> = tasks.iter().map(TaskRecord::from_task).collect();
+        self.put(
+            &["projects", project, "members", &self.owner, "tasks"],
+            records,
+        )
+    }
+}
+
+impl NoteStore for HttpStore {
+    fn load_notes(&self, project: &str, task_id: &str) -> Result<String> {
+        let body: NotesBody = self.get(&[
+            "projects",
+            project,
+            "members",
+            &self.owner,
+            "tasks",
+            task_id,
+            "notes",
+        ])?;
+        Ok(body.content)
+    }
+
+    fn save_notes(&self, project: &str, task_id: &str, content: &str) -> Result<()> {
+        self.put(
+            &[
+                "projects",
+                project,
+                "members",
+                &self.owner,
+                "tasks",
+                task_id,
+                "notes",
+            ],
+            NotesBody {
+                content: content.to_string(),
+            },
+        )
+    }
+}
+
+impl CommentStore for HttpStore {
+    fn load_comments(&self, project: &str, task_id: &str) -> Result<Vec<Comment>> {
+        self.get(&[
+            "projects",
+            project,
+            "members",
+            &self.owner,
+            "tasks",
+            task_id,
+            "comments",
+        ])
+    }
+
+    fn replace_comments(&self, project: &str, task_id: &str, comments: &[Comment]) -> Result<()> {
+        self.put(
+            &[
+                "projects",
+                project,
+                "members",
+                &self.owner,
+                "tasks",
+                task_id,
+                "comments",
+            ],
+            comments,
+        )
+    }
+}
+
+/// Outcome of the last publish run by this process.
+pub fn status() -> OrgStatus {
+    STATUS.lock().unwrap().clone()
+}
+
+/// Publish every local project's active tasks and exchange review threads
+/// with the team server.
+pub fn publish_now() -> Result<OrgStatus> {
+    let cfg = config::load_config().organization;
+    let result = HttpStore::connect(&cfg).and_then(|store| {
+        STATUS.lock().unwrap().member = Some(store.member().to_string());
+        publish(&store)
+    });
+    let mut status = STATUS.lock().unwrap();
+    status.last_run_at = Some(Utc::now().to_rfc3339());
+    match &result {
+        Ok(published) => {
+            status.last_error = None;
+            status.published = *published;
+        }
+        Err(e) => status.last_error = Some(e.to_string()),
+    }
+    result.map(|_| status.clone())
+}
+
+/// Start the background loop that publishes every minute while a team
+/// server is configured. Only runs while Grove is running.
+pub fn spawn() {
+    tokio::spawn(async {
+        let mut ticker = tokio::time::interval(PUBLISH_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            ticker.tick().await;
+            let cfg = config::load_config().organization;
+            if cfg
+                .server_url
+                .as_deref()
+                .is_none_or(|u| u.trim().is_empty())
+            {
+                continue;
+            }
+            match tokio::task::spawn_blocking(publish_now).await {
+                Ok(Err(e)) => tracing::warn!("organization publish failed: {}", e),
+                Err(e) => tracing::warn!("organization publish task panicked: {}", e),
+                Ok(Ok(_)) => {}
+            }
+        }
+    });
+}
+
+fn publish(remote: &HttpStore) -> Result<usize> {
+    let mut published = 0;
+    for (project_id, project) in sync::local_projects()? {
+        let key = workspace::project_hash(&project.path);
+        published += publish_project(&Local, &key, remote, &project_id)?;
+    }
+    Ok(published)
+}
+
+/// Publish one project's active tasks from `local` to `remote`, and bring
+/// the review threads teammates added back into `local`.
+fn publish_project<L, R>(local: &L, key: &str, remote: &R, project_id: &str) -> Result<usize>
+where
+    L: TaskStore + NoteStore + CommentStore,
+    R: TaskStore + NoteStore + CommentStore,
+{
+    let active = local.load_tasks(key)?;
+    remote.replace_tasks(project_id, &active)?;
+    for task in &active {
+        let notes = local.load_notes(key, &task.id).unwrap_or_default();
+        remote.save_notes(project_id, &task.id, &notes)?;
+
+        let ours = local.load_comments(key, &task.id)?;
+        let theirs = remote.load_comments(project_id, &task.id)?;
+        let merged = merge_threads(&theirs, &ours, None);
+        if !same_threads(&theirs, &merged) {
+            remote.replace_comments(project_id, &task.id, &merged)?;
+        }
+        if !same_threads(&ours, &merged) {
+            local.replace_comments(key, &task.id, &merged)?;
+        }
+    }
+    Ok(active.len())
+}
+
+/// Team server id of a locally registered project.
+fn shared_project_id(project_key: &str) -> Result<String> {
+    let project = workspace::load_project_by_hash(project_key)?
+        .ok_or_else(|| GroveError::not_found("Project not found"))?;
+    Ok(sync::sync_id(&project))
+}
+
+/// Teammates' tasks for a local project. Returns this machine's member
+/// name along with them.
+pub fn team_tasks(project_key: &str) -> Result<(String, Vec<SharedTask>)> {
+    let store = HttpStore::connect(&config::load_config().organization)?;
+    let member = store.member().to_string();
+    let tasks = store
+        .list_team_tasks(&shared_project_id(project_key)?)?
+        .into_iter()
+        .filter(|t| t.owner != member)
+        .collect();
+    Ok((member, tasks))
+}
+
+/// Review threads on a teammate's task.
+pub fn team_comments(project_key: &str, owner: &str, task_id: &str) -> Result<Vec<Comment>> {
+    let store = HttpStore::connect(&config::load_config().organization)?;
+    store
+        .of(owner)
+        .load_comments(&shared_project_id(project_key)?, task_id)
+}
+
+/// Comment on a teammate's task, or reply to one of its threads.
+pub fn comment_on_team_task(
+    project_key: &str,
+    owner: &str,
+    task_id: &str,
+    content: &str,
+    reply_to: Option<u32>,
+) -> Result<Vec<Comment>> {
+    let store = HttpStore::connect(&config::load_config().organization)?;
+    let member = store.member().to_string();
+    let remote = store.of(owner);
+    let project_id = shared_project_id(project_key)?;
+    let mut threads = remote.load_comments(&project_id, task_id)?;
+    let timestamp = Utc::now().to_rfc3339();
+
+    match reply_to {
+        None => {
+            threads = merge_threads(
+                &threads,
+                &[Comment {
+                    id: 0,
+                    comment_type: CommentType::Project,
+                    file_path: None,
+                    side: None,
+                    start_line: None,
+                    end_line: None,
+                    content: content.to_string(),
+                    agent: member,
+                    model: String::new(),
+                    role: String::new(),
+                    timestamp,
+                    status: CommentStatus::Open,
+                    replies: Vec::new(),
+                    anchor_text: None,
+                    reactions: Vec::new(),
+                }],
+                None,
+            )
+        }
+        Some(comment_id) => {
+            let thread = threads
+                .iter_mut()
+                .find(|c| c.id == comment_id)
+                .ok_or_else(|| GroveError::not_found("Comment not found"))?;
+            let id = thread.replies.iter().map(|r| r.id).max().unwrap_or(0) + 1;
+            thread.replies.push(CommentReply {
+                id,
+                content: content.to_string(),
+                agent: member,
+                model: String::new(),
+                role: String::new(),
+                timestamp,
+                reactions: Vec::new(),
+            });
+        }
+    }
+    remote.replace_comments(&project_id, task_id, &threads)?;
+    remote.load_comments(&project_id, task_id)
+}
+
+/// Merge `incoming` review threads into `base`.
+///
+/// Threads and replies are matched by author and timestamp. A match takes
+/// the incoming content and status and gains any replies and reactions it
+/// lacks; an unmatched thread is appended under the next free id.
+///
+/// With `author` set, only that member's own contributions are taken:
+/// their threads, replies and reactions, and edits to what they wrote.
+fn merge_threads(base: &[Comment], incoming: &[Comment], author: Option<&str>) -> Vec<Comment> {
+    let mut merged = base.to_vec();
+    let mut next_id = merged.iter().map(|c| c.id).max().unwrap_or(0);
+    for comment in incoming {
+        let existing = merged
+            .iter_mut()
+            .find(|m| m.agent == comment.agent && m.timestamp == comment.timestamp);
+        match existing {
+            Some(existing) => merge_thread(existing, comment, author),
+            None if author.is_none_or(|a| comment.agent == a) => {
+                next_id += 1;
+                merged.push(Comment {
+                    id: next_id,
+                    reactions: allowed_reactions(&comment.reactions, author),
+                    ..comment.clone()
+                });
+            }
+            None => {}
+        }
+    }
+    merged
+}
+
+fn merge_thread(into: &mut Comment, other: &Comment, author: Option<&str>) {
+    if author.is_none_or(|a| into.agent == a) {
+        into.content = other.content.clone();
+        into.status = other.status;
+    }
+    merge_reactions(&mut into.reactions, &other.reactions, author);
+    let mut next_id = into.replies.iter().map(|r| r.id).max().unwrap_or(0);
+    for reply in &other.replies {
+        let existing = into
+            .replies
+            .iter_mut()
+            .find(|r| r.agent == reply.agent && r.timestamp == reply.timestamp);
+        match existing {
+            Some(existing) => {
+                if author.is_none_or(|a| existing.agent == a) {
+                    existing.content = reply.content.clone();
+                }
+                merge_reactions(&mut existing.reactions, &reply.reactions, author);
+            }
+            None if author.is_none_or(|a| reply.agent == a) => {
+                next_id += 1;
+                into.replies.push(CommentReply {
+                    id: next_id,
+                    reactions: allowed_reactions(&reply.reactions, author),
+                    ..reply.clone()
+                });
+            }
+            None => {}
+        }
+    }
+}
+
+fn merge_reactions(into: &mut Vec<Reaction>, other: &[Reaction], author: Option<&str>) {
+    for reaction in allowed_reactions(other, author) {
+        match into.iter_mut().find(|r| r.emoji == reaction.emoji) {
+            Some(existing) => {
+                for name in reaction.authors {
+                    if !existing.authors.contains(&name) {
+                        existing.authors.push(name);
+                    }
+                }
+            }
+            None => into.push(reaction),
+        }
+    }
+}
+
+/// `reactions` limited to those left by `author` (all of them when unset).
+fn allowed_reactions(reactions: &[Reaction], author: Option<&str>) -> Vec<Reaction> {
+    reactions
+        .iter()
+        .filter_map(|r| {
+            let authors: Vec<String> = r
+                .authors
+                .iter()
+                .filter(|name| author.is_none_or(|a| name.as_str() == a))
+                .cloned()
+                .collect();
+            (!authors.is_empty()).then(|| Reaction {
+                authors,
+                ..r.clone()
+            })
+        })
+        .collect()
+}
+
+fn same_threads(a: &[Comment], b: &[Comment]) -> bool {
+    serde_json::to_value(a).ok() == serde_json::to_value(b).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(id: u32, agent: &str, timestamp: &str, replies: &[(&str, &str)]) -> Comment {
+        Comment {
+            id,
+            comment_type: CommentType::Project,
+            file_path: None,
+            side: None,
+            start_line: None,
+            end_line: None,
+            content: format!("from {agent}"),
+            agent: agent.to_string(),
+            model: String::new(),
+            role: String::new(),
+            timestamp: timestamp.to_string(),
+            status: CommentStatus::Open,
+            replies: replies
+                .iter()
+                .enumerate()
+                .map(|(i, (agent, timestamp))| CommentReply {
+                    id: i as u32 + 1,
+                    content: format!("reply from {agent}"),
+                    agent: agent.to_string(),
+                    model: String::new(),
+                    role: String::new(),
+                    timestamp: timestamp.to_string(),
+                    reactions: Vec::new(),
+                })
+                .collect(),
+            anchor_text: None,
+            reactions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_merge_threads_unions_comments_and_replies() {
+        // Server: the owner's thread plus a teammate's reply
+        let server = vec![comment(1, "alice", "t1", &[("bob", "t2")])];
+        // Owner's copy: the thread resolved with their own reply, plus a
+        // new comment that reused id 1's neighbour locally
+        let mut resolved = comment(1, "alice", "t1", &[("alice", "t3")]);
+        resolved.status = CommentStatus::Resolved;
+        let local = vec![resolved, comment(2, "alice", "t4", &[])];
+
+        let merged = merge_threads(&server, &local, None);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].status, CommentStatus::Resolved);
+        let replies: Vec<(u32, &str)> = merged[0]
+            .replies
+            .iter()
+            .map(|r| (r.id, r.agent.as_str()))
+            .collect();
+        assert_eq!(replies, [(1, "bob"), (2, "alice")]);
+        assert_eq!((merged[1].id, merged[1].timestamp.as_str()), (2, "t4"));
+
+        // Merging again changes nothing
+        assert!(same_threads(&merged, &merge_threads(&merged, &local, None)));
+
+        // A teammate's new thread colliding with a local id gets the next one
+        let merged = merge_threads(&merged, &[comment(1, "bob", "t5", &[])], None);
+        assert_eq!((merged[2].id, merged[2].agent.as_str()), (3, "bob"));
+    }
+
+    #[test]
+    fn test_merge_threads_limits_members_to_their_own_contributions() {
+        let server = vec![comment(1, "alice", "t1", &[("bob", "t2")])];
+
+        let mut forged = comment(1, "alice", "t1", &[("bob", "t2"), ("bob", "t3")]);
+        forged.content = "rewritten".to_string();
+        forged.status = CommentStatus::Resolved;
+        forged.replies[0].content = "edited".to_string();
+        forged.reactions = vec![Reaction {
+            emoji: "👍".to_string(),
+            authors: vec!["alice".to_string(), "bob".to_string()],
+        }];
+        let incoming = vec![forged, comment(9, "alice", "t4", &[])];
+
+        let merged = merge_threads(&server, &incoming, Some("bob"));
+        // Alice's thread keeps her content and status, and no new thread
+        // appears under her name
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].content, "from alice");
+        assert_eq!(merged[0].status, CommentStatus::Open);
+        // Bob's own reply edit, new reply and reaction go through
+        let replies: Vec<&str> = merged[0]
+            .replies
+            .iter()
+            .map(|r| r.content.as_str())
+            .collect();
+        assert_eq!(replies, ["edited", "reply from bob"]);
+        assert_eq!(merged[0].reactions[0].authors, ["bob"]);
+    }
+
+    #[test]
+    fn test_publish_project_through_member_stores() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        let record: TaskRecord = serde_json::from_value(serde_json::json!({
+            "id": "fix-login",
+            "name": "Fix login",
+            "branch": "grove/fix-login",
+            "target": "main",
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+            "status": "active",
+        }))
+        .unwrap();
+        let task = record.into_task("/wt".to_string(), "s".to_string(), "tmux".to_string());
+        crate::storage::tasks::add_task("k", task).unwrap();
+        Local.save_notes("k", "fix-login", "WIP").unwrap();
+        Local
+            .replace_comments("k", "fix-login", &[comment(1, "alice", "t1", &[])])
+            .unwrap();
+
+        let remote = MemberStore::new("alice");
+        assert_eq!(publish_project(&Local, "k", &remote, "p").unwrap(), 1);
+        let listed = server_list_tasks("p").unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].owner, "alice");
+        assert_eq!(listed[0].notes, "WIP");
+        // Worktree paths stay on the owner's machine
+        assert_eq!(remote.load_tasks("p").unwrap()[0].worktree_path, "");
+
+        // Bob replies on the server; the next publish brings it home
+        let mut thread = remote.load_comments("p", "fix-login").unwrap();
+        thread[0].replies.push(CommentReply {
+            id: 1,
+            content: "looks good".to_string(),
+            agent: "bob".to_string(),
+            model: String::new(),
+            role: String::new(),
+            timestamp: "t2".to_string(),
+            reactions: Vec::new(),
+        });
+        server_merge_comments("p", "alice", "fix-login", "bob", &thread).unwrap();
+        publish_project(&Local, "k", &remote, "p").unwrap();
+        let local = Local.load_comments("k", "fix-login").unwrap();
+        assert_eq!(local[0].replies.len(), 1);
+        assert_eq!(local[0].replies[0].agent, "bob");
+
+        assert!(server_merge_comments("p", "alice", "nope", "bob", &[]).is_err());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+}
@@ -8,7 +8,7 @@ use std::process::Command;
 
 use crate::error::{GroveError, Result};
 use crate::git;
-use crate::storage::workspace;
+use crate::storage::{tasks, workspace};
 
 /// Ensure the given directory is a Git repository with at least one commit.
 ///
@@ -325,3 +325,167 @@ pub async fn clone_project(url: &str, name: Option<&str>) -> Result<String> {
 
     Ok(resolved_path)
 }
+
+/// Target branch for a new task in a repo project.
+///
+/// Uses the project's default target when one is set and the branch still
+/// exists, otherwise the branch currently checked out in the main repo.
+pub fn default_target_branch(project_path: &str) -> String {
+    workspace::load_default_target(&workspace::project_hash(project_path))
+        .ok()
+        .flatten()
+        .filter(|branch| git::branch_exists(project_path, branch))
+        .or_else(|| git::current_branch(project_path).ok())
+        .unwrap_or_else(|| "main".to_string())
+}
+
+/// Project-level health checks shown as warnings in the project header.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectHealth {
+    /// Branch new tasks are based on (see [`default_target_branch`])
+    pub target_branch: String,
+    /// Commits the local target branch is behind `origin/<target>`;
+    /// `None` when there is no such remote-tracking ref
+    pub target_behind_origin: Option<u32>,
+    /// Uncommitted files in the main repo
+    pub uncommitted_changes: usize,
+    /// Stash entries in the main repo
+    pub stash_count: usize,
+    /// Worktree paths git reports as prunable, plus task worktrees whose
+    /// directory no longer exists
+    pub stale_worktrees: Vec<String>,
+    /// AutoLink symlinks in task worktrees whose source has disappeared
+    pub broken_links: Vec<String>,
+}
+
+impl ProjectHealth {
+    /// Human-readable warnings; empty when everything looks fine.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if let Some(n) = self.target_behind_origin.filter(|n| *n > 0) {
+            warnings.push(format!(
+                "{} is {} commit{} behind origin",
+                self.target_branch,
+                n,
+                if n == 1 { "" } else { "s" }
+            ));
+        }
+        if self.uncommitted_changes > 0 {
+            warnings.push(format!(
+                "Main repo has {} uncommitted change{}",
+                self.uncommitted_changes,
+                if self.uncommitted_changes == 1 {
+                    ""
+                } else {
+                    "s"
+                }
+            ));
+        }
+        if self.stash_count > 0 {
+            warnings.push(format!(
+                "{} stash entr{}",
+                self.stash_count,
+                if self.stash_count == 1 { "y" } else { "ies" }
+            ));
+        }
+        if !self.stale_worktrees.is_empty() {
+            warnings.push(format!(
+                "{} stale worktree{}",
+                self.stale_worktrees.len(),
+                if self.stale_worktrees.len() == 1 {
+                    ""
+                } else {
+                    "s"
+                }
+            ));
+        }
+        if !self.broken_links.is_empty() {
+            warnings.push(format!(
+                "{} broken AutoLink symlink{}",
+                self.broken_links.len(),
+                if self.broken_links.len() == 1 {
+                    ""
+                } else {
+                    "s"
+                }
+            ));
+        }
+        warnings
+    }
+}
+
+/// Compute [`ProjectHealth`] for a repo project.
+///
+/// Read-only and offline: divergence is measured against the local
+/// `origin/*` refs, so it is only as fresh as the last fetch.
+pub fn project_health(project_path: &str) -> ProjectHealth {
+    let target_branch = default_target_branch(project_path);
+    let target_behind_origin = git::commits_behind_origin(project_path, &target_branch)
+        .ok()
+        .flatten();
+
+    let mut stale_worktrees: Vec<String> = git::list_worktrees(project_path)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|wt| wt.prunable)
+        .map(|wt| wt.path)
+        .collect();
+
+    let project_key = workspace::project_hash(project_path);
+    let active = tasks::load_tasks(&project_key).unwrap_or_default();
+    let link_paths = git::autolink_paths(project_path);
+    let mut broken_links = Vec::new();
+    for task in active.iter().filter(|t| t.id != tasks::LOCAL_TASK_ID) {
+        let worktree = Path::new(&task.worktree_path);
+        if !worktree.exists() {
+            if !stale_worktrees.contains(&task.worktree_path) {
+                stale_worktrees.push(task.worktree_path.clone());
+            }
+            continue;
+        }
+        for link in &link_paths {
+            let path = worktree.join(link);
+            // is_symlink() looks at the link itself, exists() follows it
+            if path.is_symlink() && !path.exists() {
+                broken_links.push(path.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    ProjectHealth {
+        target_branch,
+        target_behind_origin,
+        uncommitted_changes: git::uncommitted_count(project_path).unwrap_or(0),
+        stash_count: git::stash_count(project_path).unwrap_or(0),
+        stale_worktrees,
+        broken_links,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_warnings() {
+        assert!(ProjectHealth::default().warnings().is_empty());
+
+        let health = ProjectHealth {
+            target_branch: "main".to_string(),
+            target_behind_origin: Some(3),
+            uncommitted_changes: 1,
+            stash_count: 2,
+            stale_worktrees: vec!["/tmp/gone".to_string()],
+            broken_links: Vec::new(),
+        };
+        assert_eq!(
+            health.warnings(),
+            vec![
+                "main is 3 commits behind origin",
+                "Main repo has 1 uncommitted change",
+                "2 stash entries",
+                "1 stale worktree",
+            ]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Review thread @mentions
+//!
+//! Comments and replies can mention the local user (`@<git user.name>` or
+//! `@You`) or an agent (`@<chat title>`, `@<agent name>`, `@<duty>`, or the
+//! role an agent signed its review comments with). Mentions are matched
+//! against these known names only, so `@path/to/file` mentions never
+//! trigger anything. Mentioning the local user raises a hook notification;
+//! mentioning an agent yields a prompt for its chat, which the caller
+//! delivers if a session is live.
+
+use crate::git;
+use crate::hooks::{self, NotificationLevel};
+use crate::storage::{comments, tasks};
+
+/// Who a mention resolves to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MentionTarget {
+    LocalUser,
+    Agent { chat_id: String },
+}
+
+/// A name that can be @mentioned in a task's review threads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentionCandidate {
+    pub name: String,
+    pub target: MentionTarget,
+}
+
+/// A prompt to deliver to an agent chat because it was mentioned.
+#[derive(Debug, Clone)]
+pub struct AgentMention {
+    pub chat_id: String,
+    pub prompt: String,
+}
+
+/// Collect the names that can be mentioned in a task's review threads.
+pub fn mention_candidates(project_key: &str, task_id: &str) -> Vec<MentionCandidate> {
+    let mut out = Vec::new();
+    let mut push = |name: &str, target: MentionTarget| {
+        let name = name.trim();
+        if !name.is_empty() {
+            out.push(MentionCandidate {
+                name: name.to_string(),
+                target,
+            });
+        }
+    };
+
+    push("You", MentionTarget::LocalUser);
+    let local_user = tasks::get_task(project_key, task_id)
+        .ok()
+        .flatten()
+        .and_then(|task| git::git_user_name(&task.worktree_path));
+    if let Some(name) = &local_user {
+        push(name, MentionTarget::LocalUser);
+    }
+
+    let chats = tasks::load_chat_sessions(project_key, task_id).unwrap_or_default();
+    for chat in &chats {
+        let target = MentionTarget::Agent {
+            chat_id: chat.id.clone(),
+        };
+        push(&chat.title, target.clone());
+        push(&chat.agent, target.clone());
+        if let Some(duty) = &chat.duty {
+            push(duty, target);
+        }
+    }
+
+    // Roles agents signed their comments with ("Reviewer", "Implementer"),
+    // routed to the newest chat running that agent.
+    if let Ok(data) = comments::load_comments(project_key, task_id) {
+        let authored = data.comments.iter().map(|c| (&c.agent, &c.role, &c.model));
+        let replies = data
+            .comments
+            .iter()
+            .flat_map(|c| c.replies.iter().map(|r| (&r.agent, &r.role, &r.model)));
+        for (agent, role, model) in authored.chain(replies) {
+            if role.is_empty() || model.is_empty() {
+                continue;
+            }
+            let chat = chats
+                .iter()
+                .rev()
+                .find(|c| c.agent.eq_ignore_ascii_case(agent) || agent.contains(&c.agent));
+            if let Some(chat) = chat {
+                push(
+                    role,
+                    MentionTarget::Agent {
+                        chat_id: chat.id.clone(),
+                    },
+                );
+            }
+        }
+    }
+
+    out
+}
+
+/// Find the distinct targets mentioned in `text`.
+///
+/// A candidate matches `@name` case-insensitively when the name is followed
+/// by the end of text or a character that can't continue a name. Longer
+/// names win, so `@Claude Code` isn't also read as `@Claude`.
+pub fn find_mentions(text: &str, candidates: &[MentionCandidate]) -> Vec<MentionTarget> {
+    let lower = text.to_lowercase();
+    let mut sorted: Vec<&MentionCandidate> = candidates.iter().collect();
+    sorted.sort_by_key(|c| std::cmp::Reverse(c.name.len()));
+
+    let mut claimed: Vec<std::ops::Range<usize>> = Vec::new();
+    let mut found: Vec<MentionTarget> = Vec::new();
+    for candidate in sorted {
+        let needle = format!("@{}", candidate.name.to_lowercase());
+        let mut from = 0;
+        while let Some(pos) = lower[from..].find(&needle) {
+            let start = from + pos;
+            let end = start + needle.len();
+            from = end;
+            let boundary = ends_name(&lower[end..]);
+            let before_ok = lower[..start]
+                .chars()
+                .next_back()
+                .is_none_or(|ch| !ch.is_alphanumeric());
+            if !boundary || !before_ok || claimed.iter().any(|r| r.contains(&start)) {
+                continue;
+            }
+            claimed.push(start..end);
+            if !found.contains(&candidate.target) {
+                found.push(candidate.target.clone());
+            }
+        }
+    }
+    found
+}
+
+/// Whether a mention may end right before `rest`. `.` and `/` only continue
+/// a name when more name characters follow (`@Claude.md` vs `@Claude.`).
+fn ends_name(rest: &str) -> bool {
+    let mut chars = rest.chars();
+    match chars.next() {
+        None => true,
+        Some(ch) if ch.is_alphanumeric() || matches!(ch, '_' | '-') => false,
+        Some('.' | '/') => !chars.next().is_some_and(char::is_alphanumeric),
+        Some(_) => true,
+    }
+}
+
+/// Handle mentions in a newly posted comment or reply.
+///
+/// Notifies the local user through the hook system (unless they mentioned
+/// themselves) and returns the prompts to deliver to mentioned agent chats.
+pub fn dispatch_mentions(
+    project_key: &str,
+    task_id: &str,
+    comment_id: u32,
+    author: &str,
+    text: &str,
+    from_local_user: bool,
+) -> Vec<AgentMention> {
+    if !text.contains('@') {
+        return Vec::new();
+    }
+    let candidates = mention_candidates(project_key, task_id);
+
+    let mut agents = Vec::new();
+    for target in find_mentions(text, &candidates) {
+        match target {
+            MentionTarget::LocalUser => {
+                if !from_local_user {
+                    hooks::update_hook(
+                        project_key,
+                        task_id,
+                        NotificationLevel::Warn,
+                        Some(format!(
+                            "{} mentioned you in review comment #{}",
+                            author, comment_id
+                        )),
+                        None,
+                    );
+                }
+            }
+            MentionTarget::Agent { chat_id } => agents.push(AgentMention {
+                chat_id,
+                prompt: format!(
+                    "{} mentioned you in review comment #{}:\n\n{}\n\nUse `grove_read_review` for the full thread and answer with `grove_reply_review`.",
+                    author, comment_id, text
+                ),
+            }),
+        }
+    }
+    agents
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(name: &str, target: MentionTarget) -> MentionCandidate {
+        MentionCandidate {
+            name: name.to_string(),
+            target,
+        }
+    }
+
+    #[test]
+    fn finds_known_mentions_only() {
+        let agent = MentionTarget::Agent {
+            chat_id: "chat-1".to_string(),
+        };
+        let candidates = vec![
+            candidate("You", MentionTarget::LocalUser),
+            candidate("Jane Doe", MentionTarget::LocalUser),
+            candidate("Claude", agent.clone()),
+            candidate("Claude Code", agent.clone()),
+        ];
+
+        assert_eq!(
+            find_mentions("@claude code please check, cc @Jane Doe.", &candidates),
+            vec![agent.clone(), MentionTarget::LocalUser]
+        );
+        // File mentions, e-mail addresses and partial names don't match
+        assert!(find_mentions("see @src/main.rs and a@you.com", &candidates).is_empty());
+        assert!(find_mentions("@Youth", &candidates).is_empty());
+        assert!(find_mentions("@Claude.md", &candidates).is_empty());
+    }
+}
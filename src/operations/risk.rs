@@ -0,0 +1,313 @@
+//! Task risk scoring
+//!
+//! A heuristic 0–100 score computed from a task's diff against its target:
+//! how many files it touches, whether it hits critical paths, how much of
+//! the change is deletions, and whether code changed without any test
+//! changes. Rules and weights come from the `[risk]` config section. The
+//! score is shown as a badge in task lists and in merge confirmations.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::diff::test_changes;
+use crate::git::{self, DiffStatEntry};
+use crate::storage::config::{self, RiskConfig};
+
+/// How long a task's assessment is reused before the diff is re-read
+const CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Critical files listed in a factor detail before eliding the rest
+const MAX_LISTED_FILES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskLevel {
+    Low,
+    Medium,
+    High,
+}
+
+impl RiskLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            RiskLevel::Low => "low",
+            RiskLevel::Medium => "medium",
+            RiskLevel::High => "high",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskFactorKind {
+    Files,
+    CriticalPaths,
+    Deletions,
+    NoTests,
+}
+
+/// One contribution to the score
+#[derive(Debug, Clone, Serialize)]
+pub struct RiskFactor {
+    pub kind: RiskFactorKind,
+    pub points: u32,
+    /// Human-readable explanation, e.g. "42 files changed"
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RiskAssessment {
+    /// 0–100
+    pub score: u32,
+    pub level: RiskLevel,
+    /// Factors that added points, highest first
+    pub factors: Vec<RiskFactor>,
+    /// Changed files matching the critical path globs
+    pub critical_files: Vec<String>,
+}
+
+impl RiskAssessment {
+    /// One-line summary, e.g. "high (72): 2 critical paths, no test changes"
+    pub fn summary(&self) -> String {
+        let details: Vec<&str> = self.factors.iter().map(|f| f.detail.as_str()).collect();
+        if details.is_empty() {
+            format!("{} ({})", self.level.as_str(), self.score)
+        } else {
+            format!(
+                "{} ({}): {}",
+                self.level.as_str(),
+                self.score,
+                details.join(", ")
+            )
+        }
+    }
+}
+
+/// Build a glob set, skipping invalid patterns
+fn glob_set(patterns: &[String]) -> GlobSet {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        if let Ok(glob) = Glob::new(pattern) {
+            builder.add(glob);
+        }
+    }
+    builder.build().unwrap_or_else(|_| GlobSet::empty())
+}
+
+/// Validate the `[risk]` globs (used when the config is saved)
+pub fn validate_config(cfg: &RiskConfig) -> Result<(), String> {
+    for pattern in cfg.critical_paths.iter().chain(&cfg.test_paths) {
+        Glob::new(pattern).map_err(|e| format!("Invalid glob '{}': {}", pattern, e))?;
+    }
+    if cfg.medium > cfg.high {
+        return Err("The medium threshold must not exceed the high threshold".to_string());
+    }
+    Ok(())
+}
+
+/// Score a diff
+pub fn assess(entries: &[DiffStatEntry], cfg: &RiskConfig) -> RiskAssessment {
+    let weights = &cfg.weights;
+    let mut factors = Vec::new();
+
+    // Files touched: scales linearly up to `max_files`
+    let files = entries.len() as u32;
+    let max_files = cfg.max_files.max(1);
+    let points = weights.files * files.min(max_files) / max_files;
+    if points > 0 {
+        factors.push(RiskFactor {
+            kind: RiskFactorKind::Files,
+            points,
+            detail: format!(
+                "{} file{} changed",
+                files,
+                if files == 1 { "" } else { "s" }
+            ),
+        });
+    }
+
+    // Critical paths: any match adds the full weight
+    let critical = glob_set(&cfg.critical_paths);
+    let critical_files: Vec<String> = entries
+        .iter()
+        .filter(|e| critical.is_match(&e.path))
+        .map(|e| e.path.clone())
+        .collect();
+    if !critical_files.is_empty() && weights.critical_paths > 0 {
+        let mut listed = critical_files
+            .iter()
+            .take(MAX_LISTED_FILES)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(", ");
+        if critical_files.len() > MAX_LISTED_FILES {
+            listed.push_str(", …");
+        }
+        factors.push(RiskFactor {
+            kind: RiskFactorKind::CriticalPaths,
+            points: weights.critical_paths,
+            detail: format!(
+                "{} critical path{} ({})",
+                critical_files.len(),
+                if critical_files.len() == 1 { "" } else { "s" },
+                listed
+            ),
+        });
+    }
+
+    // Deletion ratio: share of changed lines that are deletions
+    let additions: u32 = entries.iter().map(|e| e.additions).sum();
+    let deletions: u32 = entries.iter().map(|e| e.deletions).sum();
+    if let Some(percent) = (deletions * 100).checked_div(additions + deletions) {
+        let points = weights.deletions * percent / 100;
+        if points > 0 {
+            factors.push(RiskFactor {
+                kind: RiskFactorKind::Deletions,
+                points,
+                detail: format!("{}% deletions", percent),
+            });
+        }
+    }
+
+    // Code changed without touching any test
+    let paths: Vec<String> = entries.iter().map(|e| e.path.clone()).collect();
+    if test_changes::analyze(&paths, &cfg.test_paths).tests_missing && weights.no_tests > 0 {
+        factors.push(RiskFactor {
+            kind: RiskFactorKind::NoTests,
+            points: weights.no_tests,
+            detail: "no test changes".to_string(),
+        });
+    }
+
+    factors.sort_by_key(|f| std::cmp::Reverse(f.points));
+    let score = factors.iter().map(|f| f.points).sum::<u32>().min(100);
+    let level = if score >= cfg.high {
+        RiskLevel::High
+    } else if score >= cfg.medium {
+        RiskLevel::Medium
+    } else {
+        RiskLevel::Low
+    };
+
+    RiskAssessment {
+        score,
+        level,
+        factors,
+        critical_files,
+    }
+}
+
+struct CachedAssessment {
+    value: Option<RiskAssessment>,
+    expires_at: Instant,
+}
+
+static CACHE: Lazy<Mutex<HashMap<String, CachedAssessment>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Risk of a task's worktree against its target; None when scoring is
+/// disabled or the diff can't be read. Cached briefly so task lists can
+/// ask for every task on each refresh.
+pub fn task_risk(worktree_path: &str, target: &str) -> Option<RiskAssessment> {
+    let cfg = config::load_config().risk;
+    if !cfg.enabled {
+        return None;
+    }
+
+    let key = format!("{}\0{}", worktree_path, target);
+    if let Some(entry) = CACHE.lock().unwrap().get(&key) {
+        if Instant::now() < entry.expires_at {
+            return entry.value.clone();
+        }
+    }
+
+    let value = git::diff_stat(worktree_path, target)
+        .ok()
+        .map(|entries| assess(&entries, &cfg));
+    CACHE.lock().unwrap().insert(
+        key,
+        CachedAssessment {
+            value: value.clone(),
+            expires_at: Instant::now() + CACHE_TTL,
+        },
+    );
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, additions: u32, deletions: u32) -> DiffStatEntry {
+        DiffStatEntry {
+            status: 'M',
+            path: path.to_string(),
+            additions,
+            deletions,
+            is_binary: false,
+        }
+    }
+
+    #[test]
+    fn test_assess_low_risk() {
+        let cfg = RiskConfig::default();
+        let result = assess(
+            &[
+                entry("src/lib.rs", 20, 2),
+                entry("tests/lib_test.rs", 10, 0),
+            ],
+            &cfg,
+        );
+        assert_eq!(result.level, RiskLevel::Low);
+        assert!(result.critical_files.is_empty());
+        assert!(result
+            .factors
+            .iter()
+            .all(|f| f.kind != RiskFactorKind::NoTests));
+
+        // Docs-only changes don't need tests
+        let docs = assess(&[entry("README.md", 5, 1)], &cfg);
+        assert!(docs
+            .factors
+            .iter()
+            .all(|f| f.kind != RiskFactorKind::NoTests));
+        assert_eq!(assess(&[], &cfg).score, 0);
+    }
+
+    #[test]
+    fn test_assess_high_risk() {
+        let cfg = RiskConfig::default();
+        let result = assess(
+            &[
+                entry("db/migrations/001_drop_users.sql", 1, 40),
+                entry("src/auth/session.rs", 5, 30),
+            ],
+            &cfg,
+        );
+        assert_eq!(result.level, RiskLevel::High);
+        assert_eq!(result.critical_files.len(), 2);
+        // critical (35) + no tests (20) + deletions (20 * 70 / 76 = 18) + files (25 * 2 / 30 = 1)
+        assert_eq!(result.score, 74);
+        assert_eq!(result.factors[0].kind, RiskFactorKind::CriticalPaths);
+        assert!(result.summary().starts_with("high (74): 2 critical paths"));
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let mut cfg = RiskConfig::default();
+        assert!(validate_config(&cfg).is_ok());
+        cfg.critical_paths.push("src/[".to_string());
+        assert!(validate_config(&cfg).is_err());
+        let cfg = RiskConfig {
+            medium: 80,
+            high: 50,
+            ..Default::default()
+        };
+        assert!(validate_config(&cfg).is_err());
+    }
+}
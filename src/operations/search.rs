@@ -0,0 +1,257 @@
+//! Content search inside a task worktree
+//!
+//! A small ripgrep-style search: the candidate files are what git lists for
+//! the worktree (tracked plus untracked files that are not ignored, so
+//! `.gitignore` is respected), large and binary files are skipped, and the
+//! number of matches is capped.
+
+use std::path::Path;
+
+use rayon::prelude::*;
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+
+use crate::error::{GroveError, Result};
+use crate::git;
+
+/// Files larger than this are not searched.
+pub const MAX_FILE_BYTES: u64 = 1024 * 1024;
+/// Upper bound for the requested number of matches.
+pub const MAX_RESULTS: usize = 1000;
+/// Upper bound for the requested context lines.
+pub const MAX_CONTEXT: usize = 10;
+/// Longer lines are cut when returned.
+const MAX_LINE_CHARS: usize = 500;
+
+/// What to search for and how.
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    pub query: String,
+    /// Treat `query` as a regular expression instead of a literal.
+    pub regex: bool,
+    pub case_sensitive: bool,
+    /// Lines of context before and after each match.
+    pub context: usize,
+    pub max_results: usize,
+    /// Only search paths matching this glob (e.g. `src/**/*.rs`).
+    pub glob: Option<String>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            regex: false,
+            case_sensitive: false,
+            context: 2,
+            max_results: 200,
+            glob: None,
+        }
+    }
+}
+
+/// One matching line.
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchMatch {
+    /// Path relative to the worktree root.
+    pub path: String,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column (in characters) of the first match on the line.
+    pub column: usize,
+    pub text: String,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+/// Search outcome.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SearchResult {
+    pub matches: Vec<SearchMatch>,
+    pub files_searched: usize,
+    /// More matches exist than were returned.
+    pub truncated: bool,
+}
+
+/// Search the files of a worktree.
+pub fn search_worktree(worktree: &Path, opts: &SearchOptions) -> Result<SearchResult> {
+    let pattern = build_pattern(opts)?;
+    let glob = match opts.glob.as_deref().map(str::trim) {
+        Some(g) if !g.is_empty() => Some(
+            globset::Glob::new(g)
+                .map_err(|e| GroveError::invalid_data(format!("Invalid glob '{g}': {e}")))?
+                .compile_matcher(),
+        ),
+        _ => None,
+    };
+    let max_results = opts.max_results.clamp(1, MAX_RESULTS);
+    let context = opts.context.min(MAX_CONTEXT);
+
+    let files: Vec<String> = git::list_files(&worktree.to_string_lossy())?
+        .into_iter()
+        .filter(|path| glob.as_ref().is_none_or(|g| g.is_match(path)))
+        .collect();
+
+    // Per file at most max_results + 1 matches, enough to detect truncation
+    let per_file: Vec<(usize, Vec<SearchMatch>)> = files
+        .par_iter()
+        .filter_map(|path| {
+            let content = read_text(&worktree.join(path))?;
+            Some((
+                1,
+                search_text(path, &content, &pattern, context, max_results + 1),
+            ))
+        })
+        .collect();
+
+    let files_searched = per_file.iter().map(|(n, _)| n).sum();
+    let mut matches: Vec<SearchMatch> = per_file.into_iter().flat_map(|(_, m)| m).collect();
+    matches.sort_by(|a, b| a.path.cmp(&b.path).then(a.line.cmp(&b.line)));
+    let truncated = matches.len() > max_results;
+    matches.truncate(max_results);
+
+    Ok(SearchResult {
+        matches,
+        files_searched,
+        truncated,
+    })
+}
+
+fn build_pattern(opts: &SearchOptions) -> Result<Regex> {
+    if opts.query.is_empty() {
+        return Err(GroveError::invalid_data("Search query is empty"));
+    }
+    let source = if opts.regex {
+        opts.query.clone()
+    } else {
+        regex::escape(&opts.query)
+    };
+    RegexBuilder::new(&source)
+        .case_insensitive(!opts.case_sensitive)
+        .size_limit(1 << 20)
+        .build()
+        .map_err(|e| GroveError::invalid_data(format!("Invalid pattern: {e}")))
+}
+
+/// Read a file as UTF-8 text; `None` for missing, oversized or binary files.
+fn read_text(path: &Path) -> Option<String> {
+    let meta = std::fs::metadata(path).ok()?;
+    if !meta.is_file() || meta.len() > MAX_FILE_BYTES {
+        return None;
+    }
+    let bytes = std::fs::read(path).ok()?;
+    if bytes[..bytes.len().min(8192)].contains(&0) {
+        return None;
+    }
+    String::from_utf8(bytes).ok()
+}
+
+fn search_text(
+    path: &str,
+    content: &str,
+    pattern: &Regex,
+    context: usize,
+    limit: usize,
+) -> Vec<SearchMatch> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut matches = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let Some(m) = pattern.find(line) else {
+            continue;
+        };
+        matches.push(SearchMatch {
+            path: path.to_string(),
+            line: i + 1,
+            column: line[..m.start()].chars().count() + 1,
+            text: clip(line),
+            before: lines[i.saturating_sub(context)..i]
+                .iter()
+                .map(|l| clip(l))
+                .collect(),
+            after: lines[i + 1..(i + 1 + context).min(lines.len())]
+                .iter()
+                .map(|l| clip(l))
+                .collect(),
+        });
+        if matches.len() >= limit {
+            break;
+        }
+    }
+    matches
+}
+
+fn clip(line: &str) -> String {
+    match line.char_indices().nth(MAX_LINE_CHARS) {
+        Some((idx, _)) => format!("{}…", &line[..idx]),
+        None => line.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_text_literal_and_context() {
+        let opts = SearchOptions {
+            query: "fn main(".to_string(),
+            ..Default::default()
+        };
+        let pattern = build_pattern(&opts).unwrap();
+        let content = "use std::io;\n\nFN MAIN() {}\nfn main() {\n    run();\n}\n";
+        let found = search_text("src/main.rs", content, &pattern, 1, 10);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].line, 3);
+        assert_eq!(found[1].line, 4);
+        assert_eq!(found[1].column, 1);
+        assert_eq!(found[1].before, vec!["FN MAIN() {}"]);
+        assert_eq!(found[1].after, vec!["    run();"]);
+    }
+
+    #[test]
+    fn test_build_pattern_regex_and_case() {
+        let opts = SearchOptions {
+            query: r"struct \w+Config".to_string(),
+            regex: true,
+            case_sensitive: true,
+            ..Default::default()
+        };
+        let pattern = build_pattern(&opts).unwrap();
+        assert!(pattern.is_match("pub struct AutoLinkConfig {"));
+        assert!(!pattern.is_match("pub STRUCT AutoLinkConfig {"));
+
+        let invalid = SearchOptions {
+            query: "(".to_string(),
+            regex: true,
+            ..Default::default()
+        };
+        assert!(build_pattern(&invalid).is_err());
+        assert!(build_pattern(&SearchOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_search_worktree_respects_gitignore() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(root)
+            .output()
+            .unwrap();
+        std::fs::write(root.join(".gitignore"), "target/\n").unwrap();
+        std::fs::create_dir_all(root.join("target")).unwrap();
+        std::fs::write(root.join("target/out.txt"), "needle").unwrap();
+        std::fs::write(root.join("a.txt"), "one needle\ntwo needle\n").unwrap();
+        std::fs::write(root.join("bin.dat"), b"needle\0\x01").unwrap();
+
+        let opts = SearchOptions {
+            query: "needle".to_string(),
+            max_results: 1,
+            ..Default::default()
+        };
+        let result = search_worktree(root, &opts).unwrap();
+        assert_eq!(result.matches.len(), 1);
+        assert_eq!(result.matches[0].path, "a.txt");
+        assert!(result.truncated);
+    }
+}
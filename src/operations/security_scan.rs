@@ -0,0 +1,462 @@
+//! Security scan of a task worktree
+//!
+//! Runs the project's configured audit commands (e.g. `cargo audit --json`,
+//! `npm audit --json`) in the task worktree, parses their JSON output into
+//! findings and stores the latest report per task. Findings at or above the
+//! project's block severity stop the merge through the merge gate; lower
+//! ones down to the warn severity are reported as warnings.
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::error::{GroveError, Result};
+use crate::git;
+use crate::hooks::run_shell_with_timeout;
+use crate::storage::security_scan::{
+    self, ScanFinding, ScanFormat, ScanReport, ScanSettings, Scanner, Severity,
+};
+use crate::storage::tasks::{self, Task};
+
+/// Output kept in an error message when a scanner fails
+const MAX_ERROR_OUTPUT: usize = 500;
+
+/// CVSS v3 base score from a vector like `CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H`
+pub fn cvss3_score(vector: &str) -> Option<f64> {
+    let metric = |name: &str| {
+        vector
+            .split('/')
+            .find_map(|part| part.strip_prefix(name)?.strip_prefix(':'))
+    };
+    let changed = match metric("S")? {
+        "U" => false,
+        "C" => true,
+        _ => return None,
+    };
+    let av = match metric("AV")? {
+        "N" => 0.85,
+        "A" => 0.62,
+        "L" => 0.55,
+        "P" => 0.2,
+        _ => return None,
+    };
+    let ac = match metric("AC")? {
+        "L" => 0.77,
+        "H" => 0.44,
+        _ => return None,
+    };
+    let pr = match (metric("PR")?, changed) {
+        ("N", _) => 0.85,
+        ("L", false) => 0.62,
+        ("L", true) => 0.68,
+        ("H", false) => 0.27,
+        ("H", true) => 0.5,
+        _ => return None,
+    };
+    let ui = match metric("UI")? {
+        "N" => 0.85,
+        "R" => 0.62,
+        _ => return None,
+    };
+    let cia = |name: &str| match metric(name)? {
+        "H" => Some(0.56),
+        "L" => Some(0.22),
+        "N" => Some(0.0),
+        _ => None,
+    };
+    let iss = 1.0 - (1.0 - cia("C")?) * (1.0 - cia("I")?) * (1.0 - cia("A")?);
+    let impact = if changed {
+        7.52 * (iss - 0.029) - 3.25 * (iss - 0.02f64).powi(15)
+    } else {
+        6.42 * iss
+    };
+    if impact <= 0.0 {
+        return Some(0.0);
+    }
+    let exploitability = 8.22 * av * ac * pr * ui;
+    let score = if changed {
+        1.08 * (impact + exploitability)
+    } else {
+        impact + exploitability
+    };
+    // CVSS rounds up to one decimal
+    Some((score.min(10.0) * 10.0 - 1e-9).ceil() / 10.0)
+}
+
+/// Severity rating of a CVSS score
+fn cvss_severity(score: f64) -> Severity {
+    match score {
+        s if s >= 9.0 => Severity::Critical,
+        s if s >= 7.0 => Severity::High,
+        s if s >= 4.0 => Severity::Moderate,
+        s if s > 0.0 => Severity::Low,
+        _ => Severity::Info,
+    }
+}
+
+fn str_field(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// Findings from `cargo audit --json`. Vulnerabilities are rated by their CVSS
+/// vector (High when the advisory has none); warnings such as unmaintained or
+/// yanked crates are Low.
+pub fn parse_cargo_audit(json: &Value, scanner: &str) -> Vec<ScanFinding> {
+    let mut findings = Vec::new();
+    let finding = |entry: &Value, default_severity: Severity, kind: Option<&str>| {
+        let advisory = entry.get("advisory").filter(|a| !a.is_null());
+        let package = entry.get("package");
+        let severity = advisory
+            .and_then(|a| str_field(a, "cvss"))
+            .and_then(|v| cvss3_score(&v))
+            .map(cvss_severity)
+            .unwrap_or(default_severity);
+        ScanFinding {
+            scanner: scanner.to_string(),
+            id: advisory
+                .and_then(|a| str_field(a, "id"))
+                .or_else(|| kind.map(str::to_string))
+                .unwrap_or_default(),
+            package: package
+                .and_then(|p| str_field(p, "name"))
+                .unwrap_or_default(),
+            version: package.and_then(|p| str_field(p, "version")),
+            severity,
+            title: advisory
+                .and_then(|a| str_field(a, "title"))
+                .or_else(|| kind.map(|k| format!("Crate is {}", k)))
+                .unwrap_or_default(),
+            url: advisory.and_then(|a| str_field(a, "url")),
+        }
+    };
+
+    if let Some(list) = json
+        .pointer("/vulnerabilities/list")
+        .and_then(Value::as_array)
+    {
+        findings.extend(list.iter().map(|v| finding(v, Severity::High, None)));
+    }
+    if let Some(warnings) = json.get("warnings").and_then(Value::as_object) {
+        for (kind, entries) in warnings {
+            for entry in entries.as_array().into_iter().flatten() {
+                findings.push(finding(entry, Severity::Low, Some(kind)));
+            }
+        }
+    }
+    findings
+}
+
+/// Findings from `npm audit --json`: the v7+ `vulnerabilities` map (one
+/// finding per advisory in `via`) or the v6 / pnpm `advisories` map
+pub fn parse_npm_audit(json: &Value, scanner: &str) -> Vec<ScanFinding> {
+    let mut findings: Vec<ScanFinding> = Vec::new();
+
+    if let Some(vulns) = json.get("vulnerabilities").and_then(Value::as_object) {
+        for (name, vuln) in vulns {
+            // String entries in `via` point at other packages, which have their own entry
+            for via in vuln
+                .get("via")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                if !via.is_object() {
+                    continue;
+                }
+                let url = str_field(via, "url");
+                let id = url
+                    .as_deref()
+                    .and_then(|u| u.rsplit('/').next())
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .or_else(|| via.get("source").map(|s| s.to_string()))
+                    .unwrap_or_default();
+                let package = str_field(via, "name").unwrap_or_else(|| name.clone());
+                if findings.iter().any(|f| f.id == id && f.package == package) {
+                    continue;
+                }
+                findings.push(ScanFinding {
+                    scanner: scanner.to_string(),
+                    id,
+                    package,
+                    version: str_field(via, "range"),
+                    severity: str_field(via, "severity")
+                        .or_else(|| str_field(vuln, "severity"))
+                        .and_then(|s| Severity::parse(&s))
+                        .unwrap_or(Severity::Moderate),
+                    title: str_field(via, "title").unwrap_or_default(),
+                    url,
+                });
+            }
+        }
+    } else if let Some(advisories) = json.get("advisories").and_then(Value::as_object) {
+        for (key, advisory) in advisories {
+            findings.push(ScanFinding {
+                scanner: scanner.to_string(),
+                id: str_field(advisory, "github_advisory_id").unwrap_or_else(|| key.clone()),
+                package: str_field(advisory, "module_name").unwrap_or_default(),
+                version: advisory
+                    .pointer("/findings/0/version")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                severity: str_field(advisory, "severity")
+                    .and_then(|s| Severity::parse(&s))
+                    .unwrap_or(Severity::Moderate),
+                title: str_field(advisory, "title").unwrap_or_default(),
+                url: str_field(advisory, "url"),
+            });
+        }
+    }
+    findings
+}
+
+/// Parse a scanner's stdout. Leading non-JSON lines are skipped; None when
+/// no JSON report is found.
+pub fn parse_output(output: &str, format: ScanFormat, scanner: &str) -> Option<Vec<ScanFinding>> {
+    let start = output.find('{')?;
+    let json: Value = serde_json::from_str(output[start..].trim()).ok()?;
+    let format = match format {
+        ScanFormat::Auto if json.pointer("/vulnerabilities/list").is_some() => {
+            ScanFormat::CargoAudit
+        }
+        ScanFormat::Auto if json.get("vulnerabilities").is_some() => ScanFormat::NpmAudit,
+        ScanFormat::Auto if json.get("advisories").is_some() => ScanFormat::NpmAudit,
+        ScanFormat::Auto => return None,
+        other => other,
+    };
+    Some(match format {
+        ScanFormat::CargoAudit => parse_cargo_audit(&json, scanner),
+        _ => parse_npm_audit(&json, scanner),
+    })
+}
+
+/// Run one scanner. Audit tools exit non-zero when they find something, so
+/// the exit code only matters when the output has no report.
+fn run_scanner(dir: &Path, scanner: &Scanner, timeout: Duration) -> Result<Vec<ScanFinding>> {
+    let output = run_shell_with_timeout(dir, &scanner.command, timeout)?.ok_or_else(|| {
+        GroveError::invalid_data(format!("timed out after {}s", timeout.as_secs()))
+    })?;
+    if let Some(findings) = parse_output(&output.stdout, scanner.format, &scanner.name) {
+        return Ok(findings);
+    }
+    Err(GroveError::invalid_data(if output.status.success() {
+        "no JSON audit report in the output".to_string()
+    } else {
+        format!(
+            "failed ({}): {}",
+            output.status,
+            output.error_tail(MAX_ERROR_OUTPUT)
+        )
+    }))
+}
+
+/// Run every configured scanner in the task worktree
+fn run_scan(task: &Task, settings: &ScanSettings) -> ScanReport {
+    let timeout = Duration::from_secs(settings.timeout_secs.max(1));
+    let mut report = ScanReport {
+        scanned_at: chrono::Utc::now().to_rfc3339(),
+        head_commit: git::resolve_commit(&task.worktree_path, "HEAD").unwrap_or_default(),
+        ..Default::default()
+    };
+    for scanner in &settings.scanners {
+        if scanner.command.trim().is_empty() {
+            continue;
+        }
+        match run_scanner(Path::new(&task.worktree_path), scanner, timeout) {
+            Ok(findings) => report.findings.extend(findings),
+            Err(e) => report.errors.push(format!("{}: {}", scanner.name, e)),
+        }
+    }
+    report
+        .findings
+        .sort_by_key(|f| std::cmp::Reverse(f.severity));
+    report
+}
+
+/// Scan a task now and store the report
+pub fn scan(project: &str, task_id: &str) -> Result<ScanReport> {
+    let settings = security_scan::load_settings(project)?;
+    if !settings.is_configured() {
+        return Err(GroveError::invalid_data(
+            "No security scanners configured for this project",
+        ));
+    }
+    let task = tasks::get_task(project, task_id)?
+        .ok_or_else(|| GroveError::not_found("Task not found"))?;
+    if task.is_local {
+        return Err(GroveError::invalid_data("Cannot scan local task"));
+    }
+    let report = run_scan(&task, &settings);
+    security_scan::save_report(project, task_id, &report)?;
+    Ok(report)
+}
+
+/// Pre-merge step: scan the task unless its clean HEAD was already scanned.
+/// Returns the warning for findings below the block threshold (or failed
+/// scanners); blocking findings are enforced by the merge gate.
+pub fn scan_before_merge(project: &str, task: &Task) -> Option<String> {
+    // An invalid settings file also fails the merge gate; just surface it here
+    let settings = match security_scan::load_settings(project) {
+        Ok(settings) => settings,
+        Err(e) => return Some(e.to_string()),
+    };
+    if !settings.is_configured() {
+        return None;
+    }
+    // Uncommitted changes may touch lockfiles, so only a clean HEAD is cached
+    let clean = !git::has_uncommitted_changes(&task.worktree_path).unwrap_or(true);
+    let head = git::resolve_commit(&task.worktree_path, "HEAD").ok();
+    let cached = security_scan::load_report(project, &task.id)
+        .filter(|r| clean && head.as_deref() == Some(r.head_commit.as_str()));
+    let report = match cached {
+        Some(report) => report,
+        None if settings.run_before_merge => {
+            let report = run_scan(task, &settings);
+            if let Err(e) = security_scan::save_report(project, &task.id, &report) {
+                tracing::warn!("security scan: failed to save report: {}", e);
+            }
+            report
+        }
+        None => return None,
+    };
+    warning(&report, &settings)
+}
+
+/// Warning for a report under the project's thresholds; findings that block
+/// the merge are left to the merge gate
+pub fn warning(report: &ScanReport, settings: &ScanSettings) -> Option<String> {
+    let mut report = report.clone();
+    if settings.block {
+        report
+            .findings
+            .retain(|f| f.severity < settings.block_severity);
+    }
+    report.warning(settings.warn_severity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cvss3_score() {
+        assert_eq!(
+            cvss3_score("CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H"),
+            Some(9.8)
+        );
+        assert_eq!(
+            cvss3_score("CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:N/I:N/A:H"),
+            Some(5.9)
+        );
+        assert_eq!(
+            cvss3_score("CVSS:3.0/AV:N/AC:L/PR:N/UI:R/S:C/C:L/I:L/A:N"),
+            Some(6.1)
+        );
+        assert_eq!(
+            cvss3_score("CVSS:3.1/AV:L/AC:L/PR:L/UI:N/S:U/C:N/I:N/A:N"),
+            Some(0.0)
+        );
+        assert_eq!(cvss3_score("garbage"), None);
+        assert_eq!(cvss_severity(9.8), Severity::Critical);
+        assert_eq!(cvss_severity(5.9), Severity::Moderate);
+    }
+
+    #[test]
+    fn test_parse_cargo_audit() {
+        let output = r#"{
+            "database": {"advisory-count": 600},
+            "vulnerabilities": {"found": true, "count": 2, "list": [
+                {"advisory": {"id": "RUSTSEC-2020-0071", "package": "time",
+                    "title": "Potential segfault in the time crate",
+                    "url": "https://github.com/time-rs/time/issues/293",
+                    "cvss": "CVSS:3.1/AV:N/AC:H/PR:N/UI:N/S:U/C:N/I:N/A:H"},
+                 "package": {"name": "time", "version": "0.1.45"}},
+                {"advisory": {"id": "RUSTSEC-2024-0001", "title": "No score", "cvss": null},
+                 "package": {"name": "foo", "version": "1.0.0"}}
+            ]},
+            "warnings": {"unmaintained": [
+                {"kind": "unmaintained", "package": {"name": "ansi_term", "version": "0.12.1"},
+                 "advisory": {"id": "RUSTSEC-2021-0139", "title": "ansi_term is Unmaintained"}}
+            ], "yanked": [
+                {"kind": "yanked", "package": {"name": "bar", "version": "0.2.0"}, "advisory": null}
+            ]}
+        }"#;
+        let findings = parse_output(output, ScanFormat::Auto, "cargo-audit").unwrap();
+        assert_eq!(findings.len(), 4);
+        assert_eq!(findings[0].id, "RUSTSEC-2020-0071");
+        assert_eq!(findings[0].severity, Severity::Moderate);
+        assert_eq!(findings[0].version.as_deref(), Some("0.1.45"));
+        assert_eq!(findings[1].severity, Severity::High);
+        assert_eq!(findings[2].package, "ansi_term");
+        assert_eq!(findings[2].severity, Severity::Low);
+        assert_eq!(findings[3].id, "yanked");
+        assert_eq!(findings[3].title, "Crate is yanked");
+    }
+
+    #[test]
+    fn test_parse_npm_audit() {
+        let v7 = r#"npm WARN something
+        {"auditReportVersion": 2, "vulnerabilities": {
+            "lodash": {"name": "lodash", "severity": "critical", "via": [
+                {"source": 1094, "name": "lodash", "title": "Prototype Pollution in lodash",
+                 "url": "https://github.com/advisories/GHSA-p6mc-m468-83gw",
+                 "severity": "critical", "range": "<4.17.19"}
+            ]},
+            "wrapper": {"name": "wrapper", "severity": "critical", "via": ["lodash"]}
+        }}"#;
+        let findings = parse_output(v7, ScanFormat::Auto, "npm").unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].id, "GHSA-p6mc-m468-83gw");
+        assert_eq!(findings[0].severity, Severity::Critical);
+
+        let v6 = r#"{"advisories": {"1065": {"module_name": "minimist", "severity": "low",
+            "title": "Prototype Pollution", "url": "https://npmjs.com/advisories/1065",
+            "findings": [{"version": "0.0.8"}]}}}"#;
+        let findings = parse_output(v6, ScanFormat::NpmAudit, "npm").unwrap();
+        assert_eq!(findings[0].id, "1065");
+        assert_eq!(findings[0].version.as_deref(), Some("0.0.8"));
+        assert_eq!(findings[0].severity, Severity::Low);
+
+        assert!(parse_output("error: not found", ScanFormat::Auto, "npm").is_none());
+    }
+
+    #[test]
+    fn test_warning_thresholds() {
+        let finding = |id: &str, severity| ScanFinding {
+            scanner: "npm".to_string(),
+            id: id.to_string(),
+            package: "pkg".to_string(),
+            version: None,
+            severity,
+            title: String::new(),
+            url: None,
+        };
+        let report = ScanReport {
+            findings: vec![
+                finding("A", Severity::Critical),
+                finding("B", Severity::Moderate),
+                finding("C", Severity::Info),
+            ],
+            ..Default::default()
+        };
+        let settings = ScanSettings::default();
+        // Critical blocks (via the merge gate), info is below the warn level
+        assert_eq!(
+            warning(&report, &settings).unwrap(),
+            "1 security finding (1 moderate): B in pkg"
+        );
+        let settings = ScanSettings {
+            block: false,
+            ..ScanSettings::default()
+        };
+        assert_eq!(
+            warning(&report, &settings).unwrap(),
+            "2 security findings (1 critical, 1 moderate): A in pkg, B in pkg"
+        );
+    }
+}
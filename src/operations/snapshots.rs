@@ -0,0 +1,457 @@
+//! Per-turn worktree snapshots
+//!
+//! Before and after every ACP turn the worktree (tracked + untracked files,
+//! minus ignored ones) is captured as a detached git commit built from a
+//! throwaway index, so the user's real index and branch are never touched.
+//! Snapshots are kept alive by refs under `refs/grove/snapshots/` and
+//! recorded per chat, which lets the chat timeline diff or revert any single
+//! turn's filesystem changes.
+
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+
+use crate::diff::{self, DiffFile, DiffHunk, HunkAttribution};
+use crate::error::{GroveError, Result};
+use crate::git;
+use crate::storage::tasks;
+use crate::storage::turn_snapshots::{self, TurnSnapshot, MAX_TURN_SNAPSHOTS};
+
+/// Patches larger than this are truncated in diff responses.
+const MAX_PATCH_BYTES: usize = 512 * 1024;
+
+/// Per-file change counts of a turn.
+#[derive(Debug, Clone, Serialize)]
+pub struct TurnFileChange {
+    pub path: String,
+    pub additions: u32,
+    pub deletions: u32,
+}
+
+/// Changes made during one turn.
+#[derive(Debug, Clone, Serialize)]
+pub struct TurnDiff {
+    pub files: Vec<TurnFileChange>,
+    pub patch: String,
+    pub truncated: bool,
+}
+
+/// Run git with a private index file and a fixed identity (snapshot commits
+/// must not depend on the user's git config).
+fn git_with_index(worktree: &str, index: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .current_dir(worktree)
+        .args(args)
+        .env("GIT_INDEX_FILE", index)
+        .env("GIT_AUTHOR_NAME", "Grove")
+        .env("GIT_AUTHOR_EMAIL", "grove@localhost")
+        .env("GIT_COMMITTER_NAME", "Grove")
+        .env("GIT_COMMITTER_EMAIL", "grove@localhost")
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    } else {
+        Err(GroveError::git(format!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// Capture the current worktree state as a detached commit; returns its hash.
+pub fn capture(worktree: &str) -> Result<String> {
+    let index = std::env::temp_dir().join(format!(
+        "grove-snapshot-{}-{}.index",
+        std::process::id(),
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    let result = capture_with_index(worktree, &index);
+    let _ = std::fs::remove_file(&index);
+    result
+}
+
+fn capture_with_index(worktree: &str, index: &Path) -> Result<String> {
+    let head = git::git_cmd(worktree, &["rev-parse", "--verify", "-q", "HEAD"]).ok();
+
+    // Start from a copy of the real index so unchanged files keep their stat
+    // cache and `add -A` only hashes what actually changed.
+    let real_index = git::git_cmd(
+        worktree,
+        &["rev-parse", "--path-format=absolute", "--git-path", "index"],
+    )?;
+    if std::fs::copy(&real_index, index).is_err() && head.is_some() {
+        git_with_index(worktree, index, &["read-tree", "HEAD"])?;
+    }
+
+    git_with_index(worktree, index, &["add", "-A"])?;
+    let tree = git_with_index(worktree, index, &["write-tree"])?;
+    let mut args = vec!["commit-tree", tree.as_str(), "-m", "grove-snapshot"];
+    if let Some(ref head) = head {
+        args.extend(["-p", head.as_str()]);
+    }
+    git_with_index(worktree, index, &args)
+}
+
+fn snapshot_ref(task_id: &str, chat_id: &str, turn_ts: i64, phase: &str) -> String {
+    format!(
+        "refs/grove/snapshots/{}/{}/{}-{}",
+        task_id, chat_id, turn_ts, phase
+    )
+}
+
+/// Snapshot the worktree before a turn starts. `agent` is the
+/// `(model, mode)` in effect for the turn, kept for diff attribution.
+pub fn begin_turn(
+    project_key: &str,
+    task_id: &str,
+    chat_id: &str,
+    worktree: &str,
+    turn_ts: i64,
+    agent: (Option<String>, Option<String>),
+) {
+    let before = match capture(worktree) {
+        Ok(hash) => hash,
+        Err(e) => {
+            tracing::debug!("Skipping turn snapshot for {}: {}", task_id, e);
+            return;
+        }
+    };
+    let _ = git::git_cmd(
+        worktree,
+        &[
+            "update-ref",
+            &snapshot_ref(task_id, chat_id, turn_ts, "before"),
+            &before,
+        ],
+    );
+
+    let mut list = turn_snapshots::load_snapshots(project_key, task_id, chat_id);
+    list.retain(|s| s.turn_ts != turn_ts);
+    list.push(TurnSnapshot {
+        turn_ts,
+        before,
+        after: None,
+        model: agent.0,
+        mode: agent.1,
+    });
+    // Prune the oldest turns and their refs
+    let excess = list.len().saturating_sub(MAX_TURN_SNAPSHOTS);
+    for old in list.drain(..excess) {
+        for phase in ["before", "after"] {
+            let _ = git::git_cmd(
+                worktree,
+                &[
+                    "update-ref",
+                    "-d",
+                    &snapshot_ref(task_id, chat_id, old.turn_ts, phase),
+                ],
+            );
+        }
+    }
+    if let Err(e) = turn_snapshots::save_snapshots(project_key, task_id, chat_id, &list) {
+        tracing::warn!("Failed to save turn snapshots: {}", e);
+    }
+}
+
+/// Snapshot the worktree after a turn ends.
+pub fn end_turn(project_key: &str, task_id: &str, chat_id: &str, worktree: &str, turn_ts: i64) {
+    let mut list = turn_snapshots::load_snapshots(project_key, task_id, chat_id);
+    let Some(entry) = list.iter_mut().find(|s| s.turn_ts == turn_ts) else {
+        return;
+    };
+    let after = match capture(worktree) {
+        Ok(hash) => hash,
+        Err(e) => {
+            tracing::debug!("Skipping turn snapshot for {}: {}", task_id, e);
+            return;
+        }
+    };
+    let _ = git::git_cmd(
+        worktree,
+        &[
+            "update-ref",
+            &snapshot_ref(task_id, chat_id, turn_ts, "after"),
+            &after,
+        ],
+    );
+    entry.after = Some(after);
+    if let Err(e) = turn_snapshots::save_snapshots(project_key, task_id, chat_id, &list) {
+        tracing::warn!("Failed to save turn snapshots: {}", e);
+    }
+}
+
+fn turn_range(snapshot: &TurnSnapshot) -> Result<(&str, &str)> {
+    let after = snapshot
+        .after
+        .as_deref()
+        .ok_or_else(|| GroveError::invalid_data("Turn is still running"))?;
+    Ok((snapshot.before.as_str(), after))
+}
+
+/// Files and patch changed during a turn.
+pub fn turn_diff(worktree: &str, snapshot: &TurnSnapshot) -> Result<TurnDiff> {
+    let (before, after) = turn_range(snapshot)?;
+    let numstat = git::git_cmd(worktree, &["diff", "--numstat", before, after])?;
+    let files = numstat
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let additions = parts.next()?.parse().unwrap_or(0);
+            let deletions = parts.next()?.parse().unwrap_or(0);
+            Some(TurnFileChange {
+                path: parts.next()?.to_string(),
+                additions,
+                deletions,
+            })
+        })
+        .collect();
+
+    let mut patch = git::git_cmd(worktree, &["diff", before, after])?;
+    let truncated = patch.len() > MAX_PATCH_BYTES;
+    if truncated {
+        let mut cut = MAX_PATCH_BYTES;
+        while !patch.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        patch.truncate(cut);
+    }
+    Ok(TurnDiff {
+        files,
+        patch,
+        truncated,
+    })
+}
+
+/// Undo a single turn's changes in the worktree by reverse-applying its diff.
+/// Fails without touching anything if later edits conflict with it.
+pub fn revert_turn(worktree: &str, snapshot: &TurnSnapshot) -> Result<()> {
+    let (before, after) = turn_range(snapshot)?;
+    // Raw bytes: binary patches must not be trimmed
+    let diff = Command::new("git")
+        .current_dir(worktree)
+        .args(["diff", "--binary", before, after])
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    if !diff.status.success() {
+        return Err(GroveError::git(format!(
+            "git diff failed: {}",
+            String::from_utf8_lossy(&diff.stderr).trim()
+        )));
+    }
+    if diff.stdout.is_empty() {
+        return Err(GroveError::invalid_data("Turn made no file changes"));
+    }
+
+    let mut child = Command::new("git")
+        .current_dir(worktree)
+        .args(["apply", "-R", "--whitespace=nowarn", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| GroveError::git(format!("Failed to execute git: {}", e)))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(&diff.stdout)?;
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| GroveError::git(format!("git apply failed: {}", e)))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(GroveError::git(format!(
+            "Cannot revert turn cleanly: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
+/// Non-blank lines (trimmed) a turn added to and removed from one file.
+#[derive(Debug, Default)]
+struct TurnLines {
+    added: HashSet<String>,
+    removed: HashSet<String>,
+}
+
+fn turn_lines(raw: &str) -> TurnLines {
+    let mut lines = TurnLines::default();
+    for line in diff::parse_diff(raw)
+        .files
+        .into_iter()
+        .flat_map(|f| f.hunks)
+        .flat_map(|h| h.lines)
+    {
+        let content = line.content.trim();
+        if content.is_empty() {
+            continue;
+        }
+        match line.line_type.as_str() {
+            "insert" => lines.added.insert(content.to_string()),
+            "delete" => lines.removed.insert(content.to_string()),
+            _ => false,
+        };
+    }
+    lines
+}
+
+/// Attribute every hunk to the turn that produced most of its changed lines.
+/// Each line votes for the latest turn (`turns` sorted oldest first) that
+/// added / removed the same content; ties go to the later turn.
+fn attribute_hunks(hunks: &mut [DiffHunk], turns: &[(HunkAttribution, TurnLines)]) {
+    for hunk in hunks {
+        let mut votes: HashMap<usize, usize> = HashMap::new();
+        for line in &hunk.lines {
+            let content = line.content.trim();
+            if content.is_empty() {
+                continue;
+            }
+            let hit = match line.line_type.as_str() {
+                "insert" => turns.iter().rposition(|(_, t)| t.added.contains(content)),
+                "delete" => turns.iter().rposition(|(_, t)| t.removed.contains(content)),
+                _ => None,
+            };
+            if let Some(i) = hit {
+                *votes.entry(i).or_default() += 1;
+            }
+        }
+        hunk.attribution = votes
+            .into_iter()
+            .max_by_key(|&(i, count)| (count, i))
+            .map(|(i, _)| turns[i].0.clone());
+    }
+}
+
+/// Annotate the hunks of a working-tree diff with the chat turn (and the
+/// agent model / mode) that produced them, using every chat's recorded
+/// turn snapshots. Hunks no turn accounts for (manual edits) stay unset.
+pub fn attribute_file(project_key: &str, task_id: &str, worktree: &str, file: &mut DiffFile) {
+    if file.is_binary || file.hunks.is_empty() {
+        return;
+    }
+    let path = if file.change_type == "deleted" {
+        file.old_path.clone()
+    } else {
+        file.new_path.clone()
+    };
+
+    let mut turns = Vec::new();
+    for chat in tasks::load_chat_sessions(project_key, task_id).unwrap_or_default() {
+        for snapshot in turn_snapshots::load_snapshots(project_key, task_id, &chat.id) {
+            let Some(after) = snapshot.after.as_deref() else {
+                continue;
+            };
+            let raw = git::git_cmd(
+                worktree,
+                &["diff", "-U0", &snapshot.before, after, "--", &path],
+            )
+            .unwrap_or_default();
+            if raw.is_empty() {
+                continue;
+            }
+            turns.push((
+                HunkAttribution {
+                    chat_id: chat.id.clone(),
+                    chat_title: chat.title.clone(),
+                    agent: chat.agent.clone(),
+                    turn_ts: snapshot.turn_ts,
+                    model: snapshot.model.clone(),
+                    mode: snapshot.mode.clone(),
+                },
+                turn_lines(&raw),
+            ));
+        }
+    }
+    if turns.is_empty() {
+        return;
+    }
+    turns.sort_by_key(|(a, _)| a.turn_ts);
+    attribute_hunks(&mut file.hunks, &turns);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_diff_and_revert_single_turn() {
+        let repo = tempfile::tempdir().unwrap();
+        let dir = repo.path();
+        let run = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap();
+        };
+        run(&["init", "-q", "-b", "main"]);
+        run(&["config", "user.email", "t@e"]);
+        run(&["config", "user.name", "t"]);
+        std::fs::write(dir.join("a.txt"), "one\n").unwrap();
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "init"]);
+        let wt = dir.to_str().unwrap();
+
+        // Turn 1 edits a.txt, turn 2 adds an untracked file
+        let t1_before = capture(wt).unwrap();
+        std::fs::write(dir.join("a.txt"), "two\n").unwrap();
+        let t1_after = capture(wt).unwrap();
+        std::fs::write(dir.join("new.txt"), "fresh\n").unwrap();
+
+        let turn1 = TurnSnapshot {
+            turn_ts: 1,
+            before: t1_before,
+            after: Some(t1_after),
+            model: None,
+            mode: None,
+        };
+        let diff = turn_diff(wt, &turn1).unwrap();
+        assert_eq!(diff.files.len(), 1);
+        assert_eq!(diff.files[0].path, "a.txt");
+
+        // Reverting turn 1 keeps turn 2's file and leaves the index alone
+        revert_turn(wt, &turn1).unwrap();
+        assert_eq!(std::fs::read_to_string(dir.join("a.txt")).unwrap(), "one\n");
+        assert!(dir.join("new.txt").exists());
+        let staged = git::git_cmd(wt, &["diff", "--cached", "--name-only"]).unwrap();
+        assert!(staged.is_empty());
+    }
+
+    #[test]
+    fn hunks_attributed_to_latest_matching_turn() {
+        let turn = |ts: i64, raw: &str| {
+            (
+                HunkAttribution {
+                    chat_id: "chat-1".to_string(),
+                    chat_title: "Chat".to_string(),
+                    agent: "claude".to_string(),
+                    turn_ts: ts,
+                    model: None,
+                    mode: None,
+                },
+                turn_lines(raw),
+            )
+        };
+        let header = "diff --git a/f.rs b/f.rs\n--- a/f.rs\n+++ b/f.rs\n";
+        let turns = vec![
+            turn(1, &format!("{}@@ -1 +1,2 @@\n-old\n+a\n+b\n", header)),
+            turn(2, &format!("{}@@ -2 +2 @@\n-b\n+c\n", header)),
+        ];
+
+        let final_diff = format!(
+            "{}@@ -1 +1,2 @@\n-old\n+a\n+c\n@@ -9 +10 @@\n-x\n+manual\n",
+            header
+        );
+        let mut hunks = diff::parse_diff(&final_diff).files.remove(0).hunks;
+        attribute_hunks(&mut hunks, &turns);
+        // "-old" and "+a" vote for turn 1, "+c" for turn 2
+        assert_eq!(hunks[0].attribution.as_ref().unwrap().turn_ts, 1);
+        assert!(hunks[1].attribution.is_none());
+    }
+}
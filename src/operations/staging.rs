@@ -0,0 +1,141 @@
+//! Partial staging — stage and unstage individual diff hunks of a worktree
+//!
+//! Hunks are identified by their `@@` header line in the index diff they
+//! belong to: unstaged hunks come from worktree ↔ index, staged hunks from
+//! index ↔ HEAD. The patch containing just the selected hunks is built
+//! server-side and applied to the index, like `git add -p` / `git reset -p`.
+
+use serde::Serialize;
+
+use crate::diff::{self, DiffResult};
+use crate::error::{GroveError, Result};
+use crate::git;
+
+/// Both sides of the index for an index view
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexDiff {
+    /// index ↔ HEAD
+    pub staged: DiffResult,
+    /// worktree ↔ index (tracked and intent-to-add files)
+    pub unstaged: DiffResult,
+    /// Untracked files — stage them whole or by hunk
+    pub untracked: Vec<String>,
+}
+
+/// Current staged / unstaged diffs of a worktree
+pub fn index_diff(worktree: &str) -> Result<IndexDiff> {
+    let staged = diff::parse_diff(&git::index_diff_raw(worktree, None, true)?);
+    let unstaged = diff::parse_diff(&git::index_diff_raw(worktree, None, false)?);
+    let untracked = git::git_cmd(worktree, &["ls-files", "--others", "--exclude-standard"])?
+        .lines()
+        .map(str::to_string)
+        .collect();
+    Ok(IndexDiff {
+        staged,
+        unstaged,
+        untracked,
+    })
+}
+
+/// Stage `file`, or only the given hunks of its unstaged diff
+pub fn stage(worktree: &str, file: &str, hunks: Option<&[String]>) -> Result<()> {
+    let Some(hunks) = hunks else {
+        return git::stage_file(worktree, file);
+    };
+    if hunks.is_empty() {
+        return Err(GroveError::invalid_data("No hunks selected"));
+    }
+
+    // Untracked files only show up in `git diff` once they are intent-to-add
+    let untracked = git::is_untracked(worktree, file)?;
+    if untracked {
+        git::intent_to_add(worktree, file)?;
+    }
+    let result = git::index_diff_raw(worktree, Some(file), false)
+        .and_then(|raw| diff::select_hunks(&raw, hunks))
+        .and_then(|patch| git::apply_to_index(worktree, &patch, false));
+    if result.is_err() && untracked {
+        let _ = git::unstage_file(worktree, file);
+    }
+    result
+}
+
+/// Unstage `file`, or only the given hunks of its staged diff
+pub fn unstage(worktree: &str, file: &str, hunks: Option<&[String]>) -> Result<()> {
+    let Some(hunks) = hunks else {
+        return git::unstage_file(worktree, file);
+    };
+    if hunks.is_empty() {
+        return Err(GroveError::invalid_data("No hunks selected"));
+    }
+    let raw = git::index_diff_raw(worktree, Some(file), true)?;
+    let patch = diff::select_hunks(&raw, hunks)?;
+    git::apply_to_index(worktree, &patch, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[test]
+    fn stage_and_unstage_single_hunk() {
+        let repo = tempfile::tempdir().unwrap();
+        let dir = repo.path();
+        let run = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap();
+        };
+        run(&["init", "-q", "-b", "main"]);
+        run(&["config", "user.email", "t@e"]);
+        run(&["config", "user.name", "t"]);
+        let original: String = (1..=20).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(dir.join("a.txt"), &original).unwrap();
+        run(&["add", "."]);
+        run(&["commit", "-q", "-m", "init"]);
+        let wt = dir.to_str().unwrap();
+
+        // Two separate hunks: one near the top, one at the end
+        let edited = original
+            .replace("line 2\n", "line two\n")
+            .replace("line 19\n", "line nineteen\n");
+        std::fs::write(dir.join("a.txt"), edited).unwrap();
+        std::fs::write(dir.join("new.txt"), "fresh\n").unwrap();
+
+        let before = index_diff(wt).unwrap();
+        assert!(before.staged.files.is_empty());
+        assert_eq!(before.unstaged.files[0].hunks.len(), 2);
+        assert_eq!(before.untracked, vec!["new.txt".to_string()]);
+
+        // Stage only the second hunk
+        let second = before.unstaged.files[0].hunks[1].header.clone();
+        stage(wt, "a.txt", Some(std::slice::from_ref(&second))).unwrap();
+        let after = index_diff(wt).unwrap();
+        assert_eq!(after.staged.files.len(), 1);
+        let staged = &after.staged.files[0];
+        assert_eq!(staged.hunks.len(), 1);
+        assert!(staged.hunks[0]
+            .lines
+            .iter()
+            .any(|l| l.content == "line nineteen"));
+        assert_eq!(after.unstaged.files[0].hunks.len(), 1);
+
+        // A stale header is rejected
+        assert!(stage(wt, "a.txt", Some(&["@@ -1,1 +1,1 @@".to_string()])).is_err());
+
+        // Unstage it again by hunk, then stage the untracked file by hunk
+        let header = staged.hunks[0].header.clone();
+        unstage(wt, "a.txt", Some(&[header])).unwrap();
+        assert!(index_diff(wt).unwrap().staged.files.is_empty());
+
+        // Untracked files are staged by hunk through intent-to-add
+        let new_hunks = ["@@ -0,0 +1 @@".to_string()];
+        stage(wt, "new.txt", Some(&new_hunks)).unwrap();
+        let d = index_diff(wt).unwrap();
+        assert_eq!(d.staged.files[0].new_path, "new.txt");
+        assert!(d.untracked.is_empty());
+    }
+}
@@ -0,0 +1,839 @@
+//! Sync of Grove metadata between machines through a git remote
+//!
+//! The SQLite database can't be merged by git, so each sync exports the
+//! syncable records of every registered project into a working copy at
+//! `~/.grove/sync/`, one file per record:
+//!
+//! ```text
+//! projects/{sync_id}/project.toml
+//! projects/{sync_id}/tasks/{task_id}.toml
+//! projects/{sync_id}/notes/{task_id}.md
+//! projects/{sync_id}/comments/{task_id}/{comment_id}.toml
+//! ```
+//!
+//! then commits, merges the remote branch, imports back whatever the merge
+//! changed and pushes. Git merges edits to different records by itself; when
+//! both machines touched the same file the conflict is resolved per record:
+//! - tasks: the side with the newer `updated_at` wins
+//! - comments: the side with the latest activity wins; two different
+//!   comments created under the same id are both kept, ours renumbered
+//! - notes: both versions are kept, one after the other
+//!
+//! An edit always beats a delete.
+//!
+//! Worktrees, chats and the Local Task are machine-specific and never synced.
+//! Projects are matched across machines by their `origin` remote URL (the
+//! path when there is none); only projects registered on both machines
+//! exchange tasks. A task synced in from another machine has no worktree
+//! here until its branch is fetched and the task is reset.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GroveError, Result};
+use crate::git::git_cmd;
+use crate::session;
+use crate::storage::comments::{self, Comment};
+use crate::storage::tasks::{self, Task, TaskAssignee, TaskStatus};
+use crate::storage::{self, config, notes, workspace};
+
+/// Branch of the sync repository shared by all machines.
+const BRANCH: &str = "main";
+
+/// How often the background loop checks whether a sync is due.
+const TICK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Outcome of the last sync.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SyncStatus {
+    /// RFC 3339
+    pub last_run_at: Option<String>,
+    pub last_error: Option<String>,
+    /// Records changed locally by the last merge
+    pub imported: usize,
+    /// Same-file conflicts resolved by the last merge
+    pub conflicts: usize,
+}
+
+static STATUS: Mutex<SyncStatus> = Mutex::new(SyncStatus {
+    last_run_at: None,
+    last_error: None,
+    imported: 0,
+    conflicts: 0,
+});
+
+/// Held for the duration of a sync so runs never overlap.
+static RUNNING: Mutex<()> = Mutex::new(());
+
+/// Synced form of a task: everything but the machine-specific paths,
+/// session and pin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TaskRecord {
+    pub(crate) id: String,
+    name: String,
+    branch: String,
+    target: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    initial_commit: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    status: TaskStatus,
+    #[serde(default)]
+    created_by: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    archived_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    code_additions: u32,
+    #[serde(default)]
+    code_deletions: u32,
+    #[serde(default)]
+    files_changed: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    assignee: Option<TaskAssignee>,
+}
+
+impl TaskRecord {
+    pub(crate) fn from_task(task: &Task) -> Self {
+        Self {
+            id: task.id.clone(),
+            name: task.name.clone(),
+            branch: task.branch.clone(),
+            target: task.target.clone(),
+            initial_commit: task.initial_commit.clone(),
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+            status: task.status.clone(),
+            created_by: task.created_by.clone(),
+            archived_at: task.archived_at,
+            code_additions: task.code_additions,
+            code_deletions: task.code_deletions,
+            files_changed: task.files_changed,
+            assignee: task.assignee.clone(),
+        }
+    }
+
+    pub(crate) fn into_task(
+        self,
+        worktree_path: String,
+        session_name: String,
+        multiplexer: String,
+    ) -> Task {
+        Task {
+            id: self.id,
+            name: self.name,
+            branch: self.branch,
+            target: self.target,
+            worktree_path,
+            initial_commit: self.initial_commit,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            status: self.status,
+            multiplexer,
+            session_name,
+            created_by: self.created_by,
+            archived_at: self.archived_at,
+            code_additions: self.code_additions,
+            code_deletions: self.code_deletions,
+            files_changed: self.files_changed,
+            is_local: false,
+            assignee: self.assignee,
+            pinned: false,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ProjectRecord<'a> {
+    name: &'a str,
+}
+
+/// Working copy of the sync repository.
+pub fn sync_dir() -> PathBuf {
+    storage::grove_dir().join("sync")
+}
+
+/// Outcome of the last sync run by this process.
+pub fn status() -> SyncStatus {
+    STATUS.lock().unwrap().clone()
+}
+
+/// Run one sync against the configured remote.
+pub fn sync_now() -> Result<SyncStatus> {
+    let Ok(_running) = RUNNING.try_lock() else {
+        return Err(GroveError::invalid_data("A sync is already running"));
+    };
+    let cfg = config::load_config().sync;
+    let remote = cfg
+        .remote
+        .as_deref()
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .ok_or_else(|| GroveError::invalid_data("No sync remote configured"))?
+        .to_string();
+
+    let result = run(&sync_dir(), &remote);
+    let mut status = STATUS.lock().unwrap();
+    status.last_run_at = Some(Utc::now().to_rfc3339());
+    match &result {
+        Ok(done) => {
+            status.last_error = None;
+            status.imported = done.imported;
+            status.conflicts = done.conflicts;
+        }
+        Err(e) => status.last_error = Some(e.to_string()),
+    }
+    result.map(|_| status.clone())
+}
+
+/// Start the background loop that syncs every `sync.interval_minutes`
+/// while enabled. Only runs while Grove is running.
+pub fn spawn() {
+    tokio::spawn(async {
+        let mut ticker = tokio::time::interval(TICK_INTERVAL);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let mut last_run: Option<Instant> = None;
+        loop {
+            ticker.tick().await;
+            let cfg = config::load_config().sync;
+            if !cfg.enabled || cfg.remote.as_deref().is_none_or(|r| r.trim().is_empty()) {
+                continue;
+            }
+            let interval = Duration::from_secs(u64::from(cfg.interval_minutes.max(1)) * 60);
+            if last_run.is_some_and(|t| t.elapsed() < interval) {
+                continue;
+            }
+            last_run = Some(Instant::now());
+            match tokio::task::spawn_blocking(sync_now).await {
+                Ok(Err(e)) => tracing::warn!("workspace sync failed: {}", e),
+                Err(e) => tracing::warn!("workspace sync task panicked: {}", e),
+                Ok(Ok(_)) => {}
+            }
+        }
+    });
+}
+
+struct RunResult {
+    imported: usize,
+    conflicts: usize,
+}
+
+fn run(dir: &Path, remote: &str) -> Result<RunResult> {
+    ensure_repo(dir, remote)?;
+    export(dir)?;
+    commit_all(dir, &format!("Sync from {}", machine_name()))?;
+    let before = git(dir, &["rev-parse", "HEAD"])?;
+    let conflicts = merge_remote(dir)?;
+    let imported = import(dir, &before)?;
+    git(
+        dir,
+        &["push", "-q", "origin", &format!("HEAD:refs/heads/{BRANCH}")],
+    )?;
+    Ok(RunResult {
+        imported,
+        conflicts,
+    })
+}
+
+/// git in the sync repo, committing as Grove so a missing user identity
+/// never blocks a sync.
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let mut full = vec!["-c", "user.name=Grove", "-c", "user.email=grove@localhost"];
+    full.extend_from_slice(args);
+    git_cmd(&dir.to_string_lossy(), &full)
+}
+
+fn machine_name() -> String {
+    std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| {
+            std::process::Command::new("hostname")
+                .output()
+                .ok()
+                .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        })
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "unknown host".to_string())
+}
+
+fn ensure_repo(dir: &Path, remote: &str) -> Result<()> {
+    if !dir.join(".git").exists() {
+        std::fs::create_dir_all(dir)?;
+        git(dir, &["init", "-q", "-b", BRANCH])?;
+        git(
+            dir,
+            &[
+                "commit",
+                "-q",
+                "--allow-empty",
+                "-m",
+                "Initialize Grove sync",
+            ],
+        )?;
+    }
+    // A crash mid-merge leaves MERGE_HEAD behind; start over from our side
+    if dir.join(".git").join("MERGE_HEAD").exists() {
+        let _ = git(dir, &["merge", "--abort"]);
+    }
+    match git(dir, &["remote", "get-url", "origin"]) {
+        Ok(url) if url == remote => Ok(()),
+        Ok(_) => git(dir, &["remote", "set-url", "origin", remote]).map(|_| ()),
+        Err(_) => git(dir, &["remote", "add", "origin", remote]).map(|_| ()),
+    }
+}
+
+/// Identity of a project shared across machines.
+pub(crate) fn sync_id(project: &workspace::RegisteredProject) -> String {
+    if project.is_git_repo {
+        if let Ok(url) = git_cmd(&project.path, &["remote", "get-url", "origin"]) {
+            return workspace::project_hash(&normalize_remote(&url));
+        }
+    }
+    workspace::project_hash(&project.path)
+}
+
+/// `git@host:a/b.git`, `https://host/a/b` and `ssh://git@host/a/b.git` all
+/// become `host/a/b`.
+pub(crate) fn normalize_remote(url: &str) -> String {
+    let url = url.trim().trim_end_matches('/');
+    let url = url.strip_suffix(".git").unwrap_or(url);
+    let (rest, scp_like) = match url.split_once("://") {
+        Some((_, rest)) => (rest, false),
+        None => (url, true),
+    };
+    let rest = rest.split_once('@').map(|(_, r)| r).unwrap_or(rest);
+    if scp_like {
+        rest.replacen(':', "/", 1)
+    } else {
+        rest.to_string()
+    }
+}
+
+/// Locally registered projects by sync id (first registration wins when two
+/// clones share a remote).
+pub(crate) fn local_projects() -> Result<BTreeMap<String, workspace::RegisteredProject>> {
+    let mut map = BTreeMap::new();
+    for project in workspace::load_projects()? {
+        map.entry(sync_id(&project)).or_insert(project);
+    }
+    Ok(map)
+}
+
+/// Rewrite each local project's directory from the database.
+fn export(dir: &Path) -> Result<()> {
+    for (id, project) in local_projects()? {
+        let key = workspace::project_hash(&project.path);
+        let root = dir.join("projects").join(&id);
+        let mut files = vec![(
+            root.join("project.toml"),
+            toml::to_string_pretty(&ProjectRecord {
+                name: &project.name,
+            })?,
+        )];
+
+        let all = tasks::load_tasks(&key)?
+            .into_iter()
+            .chain(tasks::load_archived_tasks(&key)?)
+            .filter(|t| !t.is_local);
+        for task in all {
+            files.push((
+                root.join("tasks").join(format!("{}.toml", task.id)),
+                toml::to_string_pretty(&TaskRecord::from_task(&task))?,
+            ));
+            let note = notes::load_notes(&key, &task.id).unwrap_or_default();
+            if !note.trim().is_empty() {
+                files.push((root.join("notes").join(format!("{}.md", task.id)), note));
+            }
+            for comment in comments::load_comments(&key, &task.id)?.comments {
+                files.push((
+                    root.join("comments")
+                        .join(&task.id)
+                        .join(format!("{}.toml", comment.id)),
+                    toml::to_string_pretty(&comment)?,
+                ));
+            }
+        }
+
+        if root.exists() {
+            std::fs::remove_dir_all(&root)?;
+        }
+        for (path, content) in files {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, content)?;
+        }
+    }
+    Ok(())
+}
+
+fn commit_all(dir: &Path, message: &str) -> Result<()> {
+    git(dir, &["add", "-A"])?;
+    if !git(dir, &["status", "--porcelain"])?.is_empty() {
+        git(dir, &["commit", "-q", "-m", message])?;
+    }
+    Ok(())
+}
+
+/// Merge the remote branch, resolving conflicts per record. Returns the
+/// number of conflicted files.
+fn merge_remote(dir: &Path) -> Result<usize> {
+    git(dir, &["fetch", "-q", "origin"])?;
+    let remote_ref = format!("refs/remotes/origin/{BRANCH}");
+    if git(dir, &["rev-parse", "--verify", "-q", &remote_ref]).is_err() {
+        // Empty remote: our push creates the branch
+        return Ok(0);
+    }
+    if git(
+        dir,
+        &[
+            "merge",
+            "-q",
+            "--no-edit",
+            "--allow-unrelated-histories",
+            &remote_ref,
+        ],
+    )
+    .is_ok()
+    {
+        return Ok(0);
+    }
+
+    let conflicted = git(dir, &["diff", "--name-only", "--diff-filter=U"])?;
+    if conflicted.is_empty() {
+        let _ = git(dir, &["merge", "--abort"]);
+        return Err(GroveError::git("Sync merge failed without conflicts"));
+    }
+    let paths: Vec<&str> = conflicted.lines().collect();
+    for path in &paths {
+        if let Err(e) = resolve_conflict(dir, path) {
+            let _ = git(dir, &["merge", "--abort"]);
+            return Err(e);
+        }
+    }
+    git(dir, &["commit", "-q", "--no-edit"])?;
+    Ok(paths.len())
+}
+
+/// What a path inside a project directory holds.
+#[derive(Debug, PartialEq, Eq)]
+enum RecordKind<'a> {
+    Task(&'a str),
+    Notes(&'a str),
+    Comments(&'a str),
+    Other,
+}
+
+/// Split `projects/{sync_id}/...` into the sync id and record kind.
+fn classify(path: &str) -> Option<(&str, RecordKind<'_>)> {
+    let parts: Vec<&str> = path.split('/').collect();
+    let ["projects", id, rest @ ..] = parts.as_slice() else {
+        return None;
+    };
+    let kind = match rest {
+        ["tasks", file] => file.strip_suffix(".toml").map(RecordKind::Task),
+        ["notes", file] => file.strip_suffix(".md").map(RecordKind::Notes),
+        ["comments", task, _] => Some(RecordKind::Comments(task)),
+        _ => None,
+    };
+    Some((id, kind.unwrap_or(RecordKind::Other)))
+}
+
+fn resolve_conflict(dir: &Path, path: &str) -> Result<()> {
+    let stage = |n: u8| git(dir, &["show", &format!(":{n}:{path}")]).ok();
+    let (base, ours, theirs) = (stage(1), stage(2), stage(3));
+    let resolved = match (ours, theirs) {
+        (Some(ours), Some(theirs)) => {
+            let kind = classify(path).map(|(_, k)| k).unwrap_or(RecordKind::Other);
+            match kind {
+                RecordKind::Task(_) => newer_task(ours, theirs),
+                RecordKind::Notes(_) => merge_notes(&ours, &theirs),
+                RecordKind::Comments(_) if base.is_none() => {
+                    // Both machines created a comment under the same id:
+                    // keep theirs here and move ours to a free id
+                    if let Some(moved) = renumber_comment(dir, path, &ours)? {
+                        git(dir, &["add", "--", &moved])?;
+                    }
+                    theirs
+                }
+                RecordKind::Comments(_) => newer_comment(ours, theirs),
+                RecordKind::Other => ours,
+            }
+        }
+        // Modified on one side, deleted on the other: keep the edit
+        (Some(kept), None) | (None, Some(kept)) => kept,
+        (None, None) => {
+            git(
+                dir,
+                &["rm", "-q", "--cached", "--ignore-unmatch", "--", path],
+            )?;
+            return Ok(());
+        }
+    };
+    std::fs::write(dir.join(path), resolved)?;
+    git(dir, &["add", "--", path])?;
+    Ok(())
+}
+
+fn newer_task(ours: String, theirs: String) -> String {
+    let parse = |s: &str| toml::from_str::<TaskRecord>(s).ok();
+    match (parse(&ours), parse(&theirs)) {
+        (Some(o), Some(t)) if t.updated_at > o.updated_at => theirs,
+        (None, Some(_)) => theirs,
+        _ => ours,
+    }
+}
+
+/// Latest timestamp on a comment thread.
+fn last_activity(comment: &Comment) -> Option<DateTime<Utc>> {
+    std::iter::once(&comment.timestamp)
+        .chain(comment.replies.iter().map(|r| &r.timestamp))
+        .filter_map(|t| DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| t.with_timezone(&Utc))
+        .max()
+}
+
+fn newer_comment(ours: String, theirs: String) -> String {
+    let parse = |s: &str| toml::from_str::<Comment>(s).ok();
+    match (parse(&ours), parse(&theirs)) {
+        (Some(o), Some(t)) if last_activity(&t) > last_activity(&o) => theirs,
+        (None, Some(_)) => theirs,
+        _ => ours,
+    }
+}
+
+fn merge_notes(ours: &str, theirs: &str) -> String {
+    format!("{}\n\n---\n\n{}\n", ours.trim_end(), theirs.trim_end())
+}
+
+/// Write our side of an add/add comment conflict under the next free id in
+/// the same task. Returns the new repo-relative path.
+fn renumber_comment(dir: &Path, path: &str, ours: &str) -> Result<Option<String>> {
+    let Ok(mut comment) = toml::from_str::<Comment>(ours) else {
+        return Ok(None);
+    };
+    let rel_dir = path.rsplit_once('/').map(|(d, _)| d).unwrap_or("");
+    let max_id = std::fs::read_dir(dir.join(rel_dir))?
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            e.file_name()
+                .to_str()
+                .and_then(|n| n.strip_suffix(".toml"))
+                .and_then(|n| n.parse::<u32>().ok())
+        })
+        .max()
+        .unwrap_or(0);
+    comment.id = max_id + 1;
+    let moved = format!("{}/{}.toml", rel_dir, comment.id);
+    std::fs::write(dir.join(&moved), toml::to_string_pretty(&comment)?)?;
+    Ok(Some(moved))
+}
+
+/// Apply everything the merge changed since `before` to the database.
+/// Returns the number of records applied.
+fn import(dir: &Path, before: &str) -> Result<usize> {
+    let changed = git(
+        dir,
+        &["diff", "--name-only", "--no-renames", before, "HEAD"],
+    )?;
+    if changed.is_empty() {
+        return Ok(0);
+    }
+
+    let mut task_ids: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut note_ids: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut comment_ids: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for path in changed.lines() {
+        let Some((id, kind)) = classify(path) else {
+            continue;
+        };
+        let (map, task_id) = match kind {
+            RecordKind::Task(t) => (&mut task_ids, t),
+            RecordKind::Notes(t) => (&mut note_ids, t),
+            RecordKind::Comments(t) => (&mut comment_ids, t),
+            RecordKind::Other => continue,
+        };
+        map.entry(id).or_default().insert(task_id);
+    }
+
+    let projects = local_projects()?;
+    let multiplexer = config::load_config().default_session_type();
+    let mut applied = 0;
+    for (id, project) in &projects {
+        let key = workspace::project_hash(&project.path);
+        let root = dir.join("projects").join(id);
+
+        for task_id in task_ids.get(id.as_str()).into_iter().flatten() {
+            let file = root.join("tasks").join(format!("{task_id}.toml"));
+            apply_task(project, &key, task_id, &file, &multiplexer)?;
+            applied += 1;
+        }
+
+        let known: HashSet<String> = tasks::load_tasks(&key)?
+            .into_iter()
+            .chain(tasks::load_archived_tasks(&key)?)
+            .map(|t| t.id)
+            .collect();
+
+        for task_id in note_ids.get(id.as_str()).into_iter().flatten() {
+            if known.contains(*task_id) {
+                let file = root.join("notes").join(format!("{task_id}.md"));
+                let content = std::fs::read_to_string(file).unwrap_or_default();
+                notes::save_notes(&key, task_id, &content, notes::NoteSource::Sync)?;
+                applied += 1;
+            }
+        }
+
+        for task_id in comment_ids.get(id.as_str()).into_iter().flatten() {
+            if known.contains(*task_id) {
+                let list = read_comments(&root.join("comments").join(task_id));
+                comments::replace_comments(&key, task_id, &list)?;
+                applied += 1;
+            }
+        }
+    }
+    Ok(applied)
+}
+
+fn apply_task(
+    project: &workspace::RegisteredProject,
+    key: &str,
+    task_id: &str,
+    file: &Path,
+    multiplexer: &str,
+) -> Result<()> {
+    let active = tasks::get_task(key, task_id)?;
+    let archived = tasks::get_archived_task(key, task_id)?;
+    let Some(record) = std::fs::read_to_string(file)
+        .ok()
+        .and_then(|s| toml::from_str::<TaskRecord>(&s).ok())
+    else {
+        // Deleted on the other machine. The worktree stays on disk and can
+        // be adopted again.
+        if active.is_some() {
+            tasks::remove_task(key, task_id)?;
+        } else if archived.is_some() {
+            tasks::remove_archived_task(key, task_id)?;
+        }
+        return Ok(());
+    };
+
+    match active.or(archived) {
+        Some(existing) => tasks::update_task_metadata(
+            key,
+            &record.into_task(
+                existing.worktree_path,
+                existing.session_name,
+                existing.multiplexer,
+            ),
+        ),
+        None => {
+            let worktree_path = if project.project_type == workspace::ProjectType::Studio {
+                workspace::studio_project_dir(&project.path)
+                    .join("tasks")
+                    .join(task_id)
+            } else {
+                storage::grove_dir()
+                    .join("worktrees")
+                    .join(key)
+                    .join(task_id)
+            };
+            let task = record.into_task(
+                worktree_path.to_string_lossy().to_string(),
+                session::session_name(key, task_id),
+                multiplexer.to_string(),
+            );
+            tasks::add_task(key, task)
+        }
+    }
+}
+
+fn read_comments(dir: &Path) -> Vec<Comment> {
+    let mut list: Vec<Comment> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| std::fs::read_to_string(e.path()).ok())
+        .filter_map(|s| toml::from_str::<Comment>(&s).ok())
+        .filter(|c| c.validate().is_ok())
+        .collect();
+    list.sort_by_key(|c| c.id);
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::comments::{CommentStatus, CommentType};
+
+    fn record(id: &str, name: &str, updated: &str) -> TaskRecord {
+        TaskRecord {
+            id: id.to_string(),
+            name: name.to_string(),
+            branch: format!("grove/{id}"),
+            target: "main".to_string(),
+            initial_commit: None,
+            created_at: "2026-01-01T00:00:00Z".parse().unwrap(),
+            updated_at: updated.parse().unwrap(),
+            status: TaskStatus::Active,
+            created_by: String::new(),
+            archived_at: None,
+            code_additions: 0,
+            code_deletions: 0,
+            files_changed: 0,
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_normalize_remote() {
+        for url in [
+            "git@github.com:acme/app.git",
+            "https://github.com/acme/app.git",
+            "ssh://git@github.com/acme/app",
+            "https://github.com/acme/app/",
+        ] {
+            assert_eq!(normalize_remote(url), "github.com/acme/app", "{url}");
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(
+            classify("projects/p1/tasks/fix-bug.toml"),
+            Some(("p1", RecordKind::Task("fix-bug")))
+        );
+        assert_eq!(
+            classify("projects/p1/notes/fix-bug.md"),
+            Some(("p1", RecordKind::Notes("fix-bug")))
+        );
+        assert_eq!(
+            classify("projects/p1/comments/fix-bug/3.toml"),
+            Some(("p1", RecordKind::Comments("fix-bug")))
+        );
+        assert_eq!(
+            classify("projects/p1/project.toml"),
+            Some(("p1", RecordKind::Other))
+        );
+        assert_eq!(classify("README.md"), None);
+    }
+
+    #[test]
+    fn test_conflict_resolution_prefers_newer_record() {
+        let task = |name: &str, updated: &str| {
+            toml::to_string_pretty(&record("t", name, updated)).unwrap()
+        };
+        let old = task("desktop", "2026-01-02T00:00:00Z");
+        let new = task("laptop", "2026-01-03T00:00:00Z");
+        assert_eq!(newer_task(old.clone(), new.clone()), new);
+        assert_eq!(newer_task(new.clone(), old), new);
+
+        let comment = |reply_at: Option<&str>| {
+            let mut c = Comment {
+                id: 1,
+                comment_type: CommentType::Project,
+                file_path: None,
+                side: None,
+                start_line: None,
+                end_line: None,
+                content: "why?".to_string(),
+                agent: "You".to_string(),
+                model: String::new(),
+                role: String::new(),
+                timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+                status: CommentStatus::Open,
+                replies: Vec::new(),
+                anchor_text: None,
+                reactions: Vec::new(),
+            };
+            if let Some(at) = reply_at {
+                c.replies.push(comments::CommentReply {
+                    id: 1,
+                    content: "because".to_string(),
+                    agent: "Claude".to_string(),
+                    model: "opus".to_string(),
+                    role: String::new(),
+                    timestamp: at.to_string(),
+                    reactions: Vec::new(),
+                });
+            }
+            toml::to_string_pretty(&c).unwrap()
+        };
+        let plain = comment(None);
+        let replied = comment(Some("2026-01-05T00:00:00+00:00"));
+        assert_eq!(newer_comment(plain.clone(), replied.clone()), replied);
+        assert_eq!(newer_comment(replied.clone(), plain), replied);
+
+        assert_eq!(merge_notes("a\n", "b"), "a\n\n---\n\nb\n");
+    }
+
+    #[test]
+    fn test_sync_round_trip_between_machines() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        let remote = temp.path().join("remote.git");
+        let status = std::process::Command::new("git")
+            .args(["init", "-q", "--bare", "-b", "main"])
+            .arg(&remote)
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let remote = remote.to_string_lossy().to_string();
+        let project = temp.path().join("proj");
+        std::fs::create_dir_all(&project).unwrap();
+        let project = project.to_string_lossy().to_string();
+        let key = workspace::project_hash(&project);
+        let machine = |name: &str| {
+            storage::set_grove_dir_override(Some(temp.path().join(name)));
+        };
+        let rename = |name: &str, updated: &str| {
+            let task = tasks::get_task(&key, "t1").unwrap().unwrap();
+            let renamed = record("t1", name, updated).into_task(
+                task.worktree_path,
+                task.session_name,
+                task.multiplexer,
+            );
+            tasks::update_task_metadata(&key, &renamed).unwrap();
+        };
+
+        // Desktop creates a task and pushes it
+        machine("desktop");
+        workspace::add_project("proj", &project).unwrap();
+        let task = record("t1", "Fix login", "2026-01-02T00:00:00Z").into_task(
+            "/desktop/wt".to_string(),
+            "s".to_string(),
+            "tmux".to_string(),
+        );
+        tasks::add_task(&key, task).unwrap();
+        run(&sync_dir(), &remote).unwrap();
+
+        // Laptop picks it up, renames it and pushes
+        machine("laptop");
+        workspace::add_project("proj", &project).unwrap();
+        let done = run(&sync_dir(), &remote).unwrap();
+        assert_eq!(done.imported, 1);
+        assert_eq!(
+            tasks::get_task(&key, "t1").unwrap().unwrap().name,
+            "Fix login"
+        );
+        rename("Fix login flow", "2026-01-03T00:00:00Z");
+        run(&sync_dir(), &remote).unwrap();
+
+        // Desktop's older concurrent edit loses the conflict but keeps its
+        // own worktree path
+        machine("desktop");
+        rename("Fix login (desktop)", "2026-01-02T12:00:00Z");
+        let done = run(&sync_dir(), &remote).unwrap();
+        assert_eq!(done.conflicts, 1);
+        let task = tasks::get_task(&key, "t1").unwrap().unwrap();
+        assert_eq!(task.name, "Fix login flow");
+        assert_eq!(task.worktree_path, "/desktop/wt");
+
+        storage::set_grove_dir_override(None);
+    }
+}
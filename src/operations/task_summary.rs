@@ -0,0 +1,362 @@
+//! Automatic "What was done" summaries
+//!
+//! When enabled for a project, a summary is appended to the task notes after
+//! a chat turn that ends with one of the configured stop reasons, and for the
+//! whole task right before it is merged. The summary is rendered from a
+//! template (prompts, the agent's final reply, changed files) or, when an AI
+//! provider is configured, written by that provider from the same material.
+//! Notes end up in the merge commit body, so the record outlives the chat
+//! history; `include_in_merge_commit` strips the summary blocks from it.
+
+use crate::acp::AcpUpdate;
+use crate::error::Result;
+use crate::git;
+use crate::operations::snapshots::{self, TurnFileChange};
+use crate::storage::ai::{self, ProviderProfile};
+use crate::storage::notes::{self, NoteSource};
+use crate::storage::task_summary::{self, SummarySettings};
+use crate::storage::{chat_history, tasks, turn_snapshots};
+
+/// Max characters kept from the agent's final reply.
+const MAX_OUTCOME_CHARS: usize = 800;
+
+/// Max prompts listed (most recent kept).
+const MAX_PROMPTS: usize = 10;
+
+/// Max changed files listed.
+const MAX_FILES: usize = 20;
+
+const PROVIDER_PROMPT: &str = "You write the \"What was done\" section of a software task's notes \
+from a coding agent's session. Summarize what was changed and the key decisions in at most 8 \
+concise markdown bullets. Only use facts from the material. Output only the bullets.";
+
+/// Material a summary is written from.
+#[derive(Debug, Clone, Default)]
+pub struct SummaryInput {
+    pub heading: String,
+    pub prompts: Vec<String>,
+    pub outcome: Option<String>,
+    pub files: Vec<TurnFileChange>,
+}
+
+fn first_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}…", text[..end].trim_end()),
+        None => text.to_string(),
+    }
+}
+
+/// The agent's last reply: the final run of message chunks.
+fn final_reply(events: &[AcpUpdate]) -> Option<String> {
+    let mut reply = String::new();
+    let mut in_run = false;
+    for event in events {
+        match event {
+            AcpUpdate::MessageChunk { text } => {
+                if !in_run {
+                    reply.clear();
+                    in_run = true;
+                }
+                reply.push_str(text);
+            }
+            AcpUpdate::ToolCall { .. } | AcpUpdate::UserMessage { .. } => in_run = false,
+            _ => {}
+        }
+    }
+    let reply = reply.trim();
+    (!reply.is_empty()).then(|| reply.to_string())
+}
+
+/// Prompt and final reply of the last turn in a chat's history.
+fn last_turn(events: &[AcpUpdate]) -> (Option<String>, Option<String>) {
+    let start = events
+        .iter()
+        .rposition(|e| matches!(e, AcpUpdate::UserMessage { .. }))
+        .unwrap_or(0);
+    let prompt = match events.get(start) {
+        Some(AcpUpdate::UserMessage { text, .. }) => Some(first_line(text)),
+        _ => None,
+    };
+    (prompt, final_reply(&events[start..]))
+}
+
+fn parse_numstat(output: &str) -> Vec<TurnFileChange> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.splitn(3, '\t');
+            let additions = parts.next()?.parse().unwrap_or(0);
+            let deletions = parts.next()?.parse().unwrap_or(0);
+            Some(TurnFileChange {
+                path: parts.next()?.to_string(),
+                additions,
+                deletions,
+            })
+        })
+        .collect()
+}
+
+/// Markdown summary (without the block markers) from the template.
+pub fn render_template(input: &SummaryInput) -> String {
+    let mut out = format!("### {}\n", input.heading);
+    if !input.prompts.is_empty() {
+        out.push_str("\n**Asked**\n\n");
+        for prompt in &input.prompts {
+            out.push_str(&format!("- {}\n", prompt));
+        }
+    }
+    if let Some(outcome) = &input.outcome {
+        out.push_str(&format!(
+            "\n**Outcome**\n\n{}\n",
+            truncate(outcome, MAX_OUTCOME_CHARS)
+        ));
+    }
+    if !input.files.is_empty() {
+        out.push_str(&format!("\n**Files changed** ({})\n\n", input.files.len()));
+        for file in input.files.iter().take(MAX_FILES) {
+            out.push_str(&format!(
+                "- `{}` (+{} −{})\n",
+                file.path, file.additions, file.deletions
+            ));
+        }
+        if input.files.len() > MAX_FILES {
+            out.push_str(&format!("- … and {} more\n", input.files.len() - MAX_FILES));
+        }
+    }
+    out.trim_end().to_string()
+}
+
+/// Ask an OpenAI-compatible provider to write the summary bullets.
+fn ask_provider(provider: &ProviderProfile, material: &str) -> std::result::Result<String, String> {
+    let url = format!(
+        "{}/chat/completions",
+        provider.base_url.trim_end_matches('/')
+    );
+    let body = serde_json::json!({
+        "model": provider.model,
+        "messages": [
+            { "role": "system", "content": PROVIDER_PROMPT },
+            { "role": "user", "content": format!("<material>\n{}\n</material>", material) }
+        ],
+        "temperature": 0.2
+    });
+    let response = ureq::post(&url)
+        .set("Authorization", &format!("Bearer {}", provider.api_key))
+        .set("Content-Type", "application/json")
+        .timeout(std::time::Duration::from_secs(60))
+        .send_string(&body.to_string())
+        .map_err(|e| format!("HTTP error: {}", e))?;
+    let resp: serde_json::Value = response
+        .into_json()
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    resp["choices"][0]["message"]["content"]
+        .as_str()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| "No content in response".to_string())
+}
+
+/// Summary text: provider-written when configured (falling back to the
+/// template on failure), template otherwise.
+fn compose(settings: &SummarySettings, input: &SummaryInput) -> String {
+    let template = render_template(input);
+    if settings.provider.is_empty() {
+        return template;
+    }
+    let providers = ai::load_providers();
+    let Some(provider) = providers
+        .providers
+        .iter()
+        .find(|p| p.id == settings.provider || p.name == settings.provider)
+    else {
+        tracing::warn!("Summary provider '{}' not found", settings.provider);
+        return template;
+    };
+    match ask_provider(provider, &template) {
+        Ok(bullets) => format!("### {}\n\n{}", input.heading, bullets),
+        Err(e) => {
+            tracing::warn!("Summary provider failed, using template: {}", e);
+            template
+        }
+    }
+}
+
+/// Append a summary block to the task notes.
+fn append_to_notes(project_key: &str, task_id: &str, summary: &str) -> Result<()> {
+    let existing = notes::load_notes(project_key, task_id).unwrap_or_default();
+    let block = task_summary::wrap_summary(summary);
+    let content = if existing.trim().is_empty() {
+        block
+    } else {
+        format!("{}\n\n{}", existing.trim_end(), block)
+    };
+    notes::save_notes(project_key, task_id, &content, NoteSource::Summary)
+}
+
+fn timestamp() -> String {
+    chrono::Local::now().format("%Y-%m-%d %H:%M").to_string()
+}
+
+/// Summarize a finished turn if its stop reason is configured. Runs off the
+/// session loop (may call an AI provider).
+pub fn on_turn_complete(
+    project_key: &str,
+    task_id: &str,
+    chat_id: &str,
+    worktree: &str,
+    turn_ts: i64,
+    stop_reason: &str,
+) {
+    let settings = task_summary::load_settings(project_key);
+    if !settings.triggers_on_turn(stop_reason) {
+        return;
+    }
+    let events = chat_history::load_history(project_key, task_id, chat_id);
+    let (prompt, outcome) = last_turn(&events);
+    let files = turn_snapshots::find_snapshot(project_key, task_id, chat_id, turn_ts)
+        .and_then(|snapshot| snapshots::turn_diff(worktree, &snapshot).ok())
+        .map(|diff| diff.files)
+        .unwrap_or_default();
+    let title = tasks::get_chat_session(project_key, task_id, chat_id)
+        .ok()
+        .flatten()
+        .map(|c| c.title)
+        .unwrap_or_else(|| chat_id.to_string());
+    let input = SummaryInput {
+        heading: format!("What was done — {} ({})", title, timestamp()),
+        prompts: prompt.into_iter().collect(),
+        outcome,
+        files,
+    };
+    if let Err(e) = append_to_notes(project_key, task_id, &compose(&settings, &input)) {
+        tracing::warn!("Failed to append turn summary for {}: {}", task_id, e);
+    }
+}
+
+/// Summarize the whole task before merging it into `task.target`.
+/// Non-fatal: failures only log.
+pub fn before_merge(project_key: &str, task: &tasks::Task) {
+    let settings = task_summary::load_settings(project_key);
+    if !settings.triggers_on_merge() {
+        return;
+    }
+    let chats = tasks::load_chat_sessions(project_key, &task.id).unwrap_or_default();
+    let mut prompts = Vec::new();
+    // (turn end, reply) of the most recently finished chat
+    let mut latest: Option<(i64, String)> = None;
+    for chat in &chats {
+        let events = chat_history::load_history(project_key, &task.id, &chat.id);
+        prompts.extend(events.iter().filter_map(|e| match e {
+            AcpUpdate::UserMessage { text, .. } => Some(first_line(text)),
+            _ => None,
+        }));
+        let ended = events
+            .iter()
+            .filter_map(|e| match e {
+                AcpUpdate::Complete { end_ts, .. } => *end_ts,
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        if let Some(reply) = final_reply(&events) {
+            if latest.as_ref().is_none_or(|(ts, _)| ended >= *ts) {
+                latest = Some((ended, reply));
+            }
+        }
+    }
+    prompts.retain(|p| !p.is_empty());
+    let skip = prompts.len().saturating_sub(MAX_PROMPTS);
+    let files = git::git_cmd(
+        &task.worktree_path,
+        &["diff", "--numstat", &format!("{}...HEAD", task.target)],
+    )
+    .map(|out| parse_numstat(&out))
+    .unwrap_or_default();
+    let input = SummaryInput {
+        heading: format!(
+            "What was done — merged into {} ({})",
+            task.target,
+            timestamp()
+        ),
+        prompts: prompts.split_off(skip),
+        outcome: latest.map(|(_, reply)| reply),
+        files,
+    };
+    if let Err(e) = append_to_notes(project_key, &task.id, &compose(&settings, &input)) {
+        tracing::warn!("Failed to append merge summary for {}: {}", task.id, e);
+    }
+}
+
+/// Notes as they should appear in the merge commit body.
+pub fn notes_for_merge_commit(project_key: &str, notes: String) -> String {
+    if task_summary::load_settings(project_key).include_in_merge_commit {
+        notes
+    } else {
+        task_summary::strip_summaries(&notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(text: &str) -> AcpUpdate {
+        AcpUpdate::UserMessage {
+            text: text.to_string(),
+            attachments: vec![],
+            sender: None,
+            terminal: false,
+        }
+    }
+
+    fn chunk(text: &str) -> AcpUpdate {
+        AcpUpdate::MessageChunk {
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn last_turn_takes_final_reply_after_tools() {
+        let events = vec![
+            user("first"),
+            chunk("old reply"),
+            user("\nAdd caching\nto the API"),
+            chunk("Let me look"),
+            AcpUpdate::ToolCall {
+                id: "t1".into(),
+                title: "Read".into(),
+                locations: vec![],
+                timestamp: None,
+                raw_input: None,
+            },
+            chunk("Added an LRU "),
+            chunk("cache."),
+        ];
+        let (prompt, outcome) = last_turn(&events);
+        assert_eq!(prompt.as_deref(), Some("Add caching"));
+        assert_eq!(outcome.as_deref(), Some("Added an LRU cache."));
+    }
+
+    #[test]
+    fn template_lists_prompts_outcome_and_files() {
+        let input = SummaryInput {
+            heading: "What was done".into(),
+            prompts: vec!["Add caching".into()],
+            outcome: Some("Done.".into()),
+            files: parse_numstat("3\t1\tsrc/cache.rs\n-\t-\tlogo.png\n"),
+        };
+        assert_eq!(
+            render_template(&input),
+            "### What was done\n\n**Asked**\n\n- Add caching\n\n**Outcome**\n\nDone.\n\n\
+             **Files changed** (2)\n\n- `src/cache.rs` (+3 −1)\n- `logo.png` (+0 −0)"
+        );
+    }
+}
@@ -18,11 +18,39 @@
 //! Web (handlers)  ────┘
 //! ```
 
+use super::lifecycle_hooks;
 use crate::error::{GroveError, Result};
 use crate::session::SessionType;
-use crate::storage::{self, config, notes, tasks, workspace};
+use crate::storage::lifecycle_hooks::HookEvent;
+use crate::storage::{
+    self, comments, config, file_guard, merge_gate, notes, tasks, templates, workspace,
+};
 use crate::tmux::layout::{parse_custom_layout_tree, CustomLayout, TaskLayout};
-use crate::{git, hooks, session, tmux};
+use crate::{forge, git, hooks, session, tmux};
+
+/// Directory name of the project, as exported in `GROVE_PROJECT_NAME`
+fn project_name(project_path: &str) -> String {
+    std::path::Path::new(project_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("unknown")
+        .to_string()
+}
+
+/// `GROVE_*` environment of a task (plus its template variables), shared by
+/// terminal sessions and lifecycle hooks
+pub fn task_env(project_key: &str, task: &tasks::Task, project_path: &str) -> tmux::SessionEnv {
+    tmux::SessionEnv {
+        task_id: task.id.clone(),
+        task_name: task.name.clone(),
+        branch: task.branch.clone(),
+        target: task.target.clone(),
+        worktree: task.worktree_path.clone(),
+        project_name: project_name(project_path),
+        project_path: project_path.to_string(),
+        extra: templates::task_env(project_key, &task.id),
+    }
+}
 
 /// Result of `create_task_session` — everything the caller needs to attach
 #[derive(Debug, Clone)]
@@ -33,6 +61,60 @@ pub struct SessionInfo {
     pub layout_path: Option<String>,
 }
 
+/// Send keystrokes / a command line into a task's running terminal session.
+///
+/// Never starts the session: nudging an agent only makes sense if it is running.
+pub fn send_task_input(
+    project_key: &str,
+    task: &tasks::Task,
+    pane: Option<usize>,
+    input: &session::SessionInput,
+) -> Result<()> {
+    let mux = session::resolve_session_type(&task.multiplexer);
+    let session_name = session::resolve_session_name(&task.session_name, project_key, &task.id);
+    if mux != SessionType::Acp && !session::session_exists(&mux, &session_name) {
+        return Err(GroveError::session("Task session is not running"));
+    }
+    session::send_input(&mux, &session_name, pane, input)
+}
+
+/// Shell command that attaches to the task's tmux / Zellij session from a
+/// terminal (`None` for ACP tasks, which have no terminal session).
+///
+/// Uses the persisted session name when present, like the loader does.
+pub fn task_attach_command(project_key: &str, task_id: &str, multiplexer: &str) -> Option<String> {
+    let stored_name = tasks::get_task(project_key, task_id)
+        .ok()
+        .flatten()
+        .map(|t| t.session_name)
+        .unwrap_or_default();
+    let name = session::resolve_session_name(&stored_name, project_key, task_id);
+    session::attach_command(&session::resolve_session_type(multiplexer), &name)
+}
+
+/// Launch a user-defined "open with" application on a task worktree.
+///
+/// The command template runs through the platform shell inside the worktree
+/// and is not waited on. Returns the application name for status messages.
+pub fn open_task_with(app_name: &str, path: &str, branch: &str) -> Result<String> {
+    let config = config::load_config();
+    let app = config
+        .open_with
+        .iter()
+        .find(|a| a.name == app_name)
+        .ok_or_else(|| GroveError::not_found(format!("Application '{}' not found", app_name)))?;
+    let command = app.render(path, branch);
+    hooks::shell_command(&command)
+        .current_dir(path)
+        .env("GROVE_BRANCH", branch)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| GroveError::config(format!("Failed to launch '{}': {}", app.name, e)))?;
+    Ok(app.name.clone())
+}
+
 /// Create (or find existing) task session.
 ///
 /// This is the single source of truth for session creation, shared by TUI and Web.
@@ -73,20 +155,7 @@ pub fn create_task_session(
     };
 
     // 4. Build session environment
-    let project_name = std::path::Path::new(project_path)
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("unknown");
-
-    let session_env = tmux::SessionEnv {
-        task_id: task.id.clone(),
-        task_name: task.name.clone(),
-        branch: task.branch.clone(),
-        target: task.target.clone(),
-        worktree: task.worktree_path.clone(),
-        project_name: project_name.to_string(),
-        project_path: project_path.to_string(),
-    };
+    let session_env = task_env(project_key, task, project_path);
 
     // 5. Create session
     session::create_session(
@@ -98,7 +167,10 @@ pub fn create_task_session(
 
     // 6. Apply layout
     let layout = TaskLayout::from_name(&cfg.layout.default).unwrap_or(TaskLayout::Single);
-    let agent_cmd = cfg.layout.agent_command.clone().unwrap_or_default();
+    // Tasks created from a template launch the template's agent
+    let agent_cmd = templates::task_agent(project_key, &task.id)
+        .or_else(|| cfg.layout.agent_command.clone())
+        .unwrap_or_default();
     let custom_layout = if layout == TaskLayout::Custom {
         cfg.layout.custom.as_ref().and_then(|c| {
             parse_custom_layout_tree(&c.tree, cfg.layout.selected_custom_id.as_deref())
@@ -119,7 +191,7 @@ pub fn create_task_session(
                     &agent_cmd,
                     custom_layout.as_ref(),
                 ) {
-                    eprintln!("Warning: Failed to apply layout: {}", e);
+                    tracing::warn!("Failed to apply layout: {}", e);
                 }
             }
         }
@@ -132,7 +204,7 @@ pub fn create_task_session(
             );
             match crate::zellij::layout::write_session_layout(&session_name, &kdl) {
                 Ok(path) => layout_path = Some(path),
-                Err(e) => eprintln!("Warning: Failed to write zellij layout: {}", e),
+                Err(e) => tracing::warn!("Failed to write zellij layout: {}", e),
             }
         }
         SessionType::Acp => {}
@@ -178,7 +250,8 @@ pub struct MergeResult {
     pub task_id: String,
     pub task_name: String,
     pub target_branch: String,
-    /// Warning message (e.g., failed to checkout back to original branch)
+    /// Warning message (e.g., failed to checkout back to original branch,
+    /// security findings below the block threshold or warn-only guarded files)
     pub warning: Option<String>,
 }
 
@@ -187,13 +260,17 @@ pub struct MergeResult {
 /// # Steps
 ///
 /// 1. Load task info
-/// 2. Validate: no uncommitted changes in worktree
-/// 3. Validate: no uncommitted changes in target branch
+/// 2. Validate: the project's merge gate (open comments, required approvals)
+///    and no uncommitted changes in worktree
+/// 3. Validate: no uncommitted changes in target branch; run pre-merge
+///    lifecycle hooks (a failing blocking hook aborts)
 /// 4. Checkout target branch
-/// 5. Load notes for commit message (non-fatal)
+/// 5. Append the automatic task summary, load notes (and optionally the
+///    review summary) for commit message (non-fatal)
 /// 6. Execute merge (squash or merge-commit)
 /// 7. Rollback on error
-/// 8. Update task timestamp
+/// 8. Run post-merge lifecycle hooks (failures become warnings), update
+///    task timestamp
 ///
 /// # Returns
 ///
@@ -204,7 +281,7 @@ pub struct MergeResult {
 /// ```ignore
 /// use crate::operations::tasks::{merge_task, MergeMethod};
 ///
-/// match merge_task(&repo_path, &project_key, &task_id, MergeMethod::Squash) {
+/// match merge_task(&repo_path, &project_key, &task_id, MergeMethod::Squash, false) {
 ///     Ok(result) => println!("Merged into {}", result.target_branch),
 ///     Err(e) => eprintln!("Merge failed: {}", e),
 /// }
@@ -214,6 +291,7 @@ pub fn merge_task(
     project_key: &str,
     task_id: &str,
     method: MergeMethod,
+    include_review: bool,
 ) -> Result<MergeResult> {
     // 1. Load task
     let task = tasks::get_task(project_key, task_id)?
@@ -224,7 +302,19 @@ pub fn merge_task(
         return Err(GroveError::invalid_data("Cannot merge local task"));
     }
 
-    // 2. Check worktree uncommitted
+    // 2. Security scan (reuses the report for an already-scanned HEAD), then the
+    // merge gate: unresolved review comments / missing approvals / blocking
+    // findings / guarded files. Warn-only checks run before the merge empties the diff.
+    let mut check_warnings: Vec<String> = [
+        super::security_scan::scan_before_merge(project_key, &task),
+        file_guard::warning(project_key, task_id),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    merge_gate::ensure_mergeable(project_key, task_id)?;
+
+    // Check worktree uncommitted
     if git::has_uncommitted_changes(&task.worktree_path)? {
         return Err(GroveError::git(
             "Worktree has uncommitted changes. Please commit or stash first.",
@@ -248,22 +338,44 @@ pub fn merge_task(
         )));
     }
 
+    // 3.6. Pre-merge lifecycle hooks (e.g. tests) in the task worktree
+    let hook_env = task_env(project_key, &task, repo_path);
+    let report = lifecycle_hooks::run(project_key, HookEvent::PreMerge, &hook_env);
+    if let Some(e) = report.blocking_error(HookEvent::PreMerge) {
+        return Err(e);
+    }
+    check_warnings.extend(report.warning(HookEvent::PreMerge));
+
     // 4. Record original branch and checkout target
     let original_branch = git::current_branch(repo_path)?;
     git::checkout(repo_path, &task.target)?;
 
-    // 5. Load notes (non-fatal)
+    // 5. Append the automatic task summary (if enabled), then load notes (non-fatal)
+    super::task_summary::before_merge(project_key, &task);
     let notes_content = notes::load_notes(project_key, task_id)
         .ok()
+        .map(|notes| super::task_summary::notes_for_merge_commit(project_key, notes))
         .filter(|s| !s.trim().is_empty());
+    let review_summary = if include_review {
+        comments::review_summary(project_key, task_id)
+            .ok()
+            .flatten()
+    } else {
+        None
+    };
 
     // 6. Execute merge
+    let options = git::CommitOptions::resolve(project_key, Some(&task.id));
     let result = match method {
         MergeMethod::Squash => {
             // Squash merge + commit; rollback on commit failure
-            let msg = git::build_commit_message(&task.name, notes_content.as_deref());
+            let msg = git::build_commit_message(
+                &task.name,
+                notes_content.as_deref(),
+                review_summary.as_deref(),
+            );
             git::merge_squash(repo_path, &task.branch).and_then(|()| {
-                git::commit(repo_path, &msg).inspect_err(|_| {
+                git::commit(repo_path, &msg, &options).inspect_err(|_| {
                     let _ = git::reset_merge(repo_path);
                 })
             })
@@ -271,8 +383,12 @@ pub fn merge_task(
         MergeMethod::MergeCommit => {
             // Merge with --no-ff
             let title = format!("Merge: {}", task.name);
-            let msg = git::build_commit_message(&title, notes_content.as_deref());
-            git::merge_no_ff(repo_path, &task.branch, &msg)
+            let msg = git::build_commit_message(
+                &title,
+                notes_content.as_deref(),
+                review_summary.as_deref(),
+            );
+            git::merge_no_ff(repo_path, &task.branch, &msg, &options)
         }
     };
 
@@ -281,8 +397,11 @@ pub fn merge_task(
         let _ = git::reset_merge(repo_path);
         // Checkout back to original branch on error
         let _ = git::checkout(repo_path, &original_branch);
+        // Surface the failure in the inbox until the next successful merge
+        let _ = tasks::record_merge_failure(project_key, task_id, &e.to_string());
         return Err(e);
     }
+    let _ = tasks::clear_merge_failure(project_key, task_id);
 
     // Checkout back to original branch after successful merge
     let warning = if let Err(e) = git::checkout(repo_path, &original_branch) {
@@ -290,11 +409,20 @@ pub fn merge_task(
             "Merge succeeded, but failed to switch back to '{}': {}",
             original_branch, e
         );
-        eprintln!("Warning: {}", msg);
+        tracing::warn!("{}", msg);
         Some(msg)
     } else {
         None
     };
+    // Post-merge hooks can no longer undo the merge: failures only warn
+    let post_merge = lifecycle_hooks::run(project_key, HookEvent::PostMerge, &hook_env)
+        .warning(HookEvent::PostMerge);
+    let warnings: Vec<String> = warning
+        .into_iter()
+        .chain(check_warnings)
+        .chain(post_merge)
+        .collect();
+    let warning = (!warnings.is_empty()).then(|| warnings.join("; "));
 
     // 7. Update task timestamp
     tasks::touch_task(project_key, task_id)?;
@@ -307,6 +435,98 @@ pub fn merge_task(
     })
 }
 
+/// Push a task branch to `origin` and open a pull / merge request into its
+/// target on the project's forge (GitHub, GitLab or Gitea)
+///
+/// # Steps
+///
+/// 1. Load task info (Local Tasks can't open PRs)
+/// 2. Resolve the forge (project forge settings, else the `origin` host) and
+///    its token; validate no uncommitted changes in worktree
+/// 3. Push the branch (`git push -u origin <branch>`)
+/// 4. Open the PR / MR (task name as title, notes as body); an already open
+///    one for the branch is reused
+/// 5. Record it on the task
+///
+/// # Returns
+///
+/// The recorded `PullRequest` for caller to display
+pub fn create_pull_request(
+    repo_path: &str,
+    project_key: &str,
+    task_id: &str,
+) -> Result<tasks::PullRequest> {
+    // 1. Load task
+    let task = tasks::get_task(project_key, task_id)?
+        .ok_or_else(|| GroveError::not_found("Task not found"))?;
+    if task.is_local {
+        return Err(GroveError::invalid_data(
+            "Cannot open a pull request for the local task",
+        ));
+    }
+
+    // 2. Validate
+    let forge = forge::open(repo_path, project_key)?;
+    if git::has_uncommitted_changes(&task.worktree_path)? {
+        return Err(GroveError::git(
+            "Worktree has uncommitted changes. Please commit or stash first.",
+        ));
+    }
+
+    // 3. Push
+    git::push_branch(&task.worktree_path, &task.branch)?;
+
+    // 4. Open PR / MR
+    let body = notes::load_notes(project_key, task_id).unwrap_or_default();
+    let pr = forge.create_pull(&task.branch, &task.target, &task.name, body.trim())?;
+
+    // 5. Record
+    tasks::save_pull_request(project_key, task_id, &pr)?;
+    tasks::touch_task(project_key, task_id)?;
+    Ok(pr)
+}
+
+/// Pull / merge request recorded for a task with its review and CI state.
+///
+/// The recorded state is refreshed while the PR is open; when the forge
+/// can't be reached (offline, no token) the recorded state is returned
+/// without review / CI.
+pub fn pull_request_status(
+    repo_path: &str,
+    project_key: &str,
+    task_id: &str,
+) -> Result<Option<forge::PullRequestStatus>> {
+    let Some(mut pr) = tasks::get_pull_request(project_key, task_id)? else {
+        return Ok(None);
+    };
+    let Ok(client) = forge::open(repo_path, project_key) else {
+        return Ok(Some(forge::PullRequestStatus {
+            pull_request: pr,
+            forge: None,
+            review: None,
+            ci: None,
+        }));
+    };
+    if pr.state == "open" {
+        if let Ok(latest) = client.fetch_pull(pr.number) {
+            if latest != pr {
+                tasks::save_pull_request(project_key, task_id, &latest)?;
+                pr = latest;
+            }
+        }
+    }
+    let review = client.review_status(pr.number).ok();
+    let ci = tasks::get_task(project_key, task_id)?
+        .and_then(|task| git::resolve_commit(repo_path, &task.branch).ok())
+        .and_then(|sha| client.ci_status(&sha).ok());
+    Ok(Some(forge::PullRequestStatus {
+        pull_request: pr,
+        forge: Some(client.kind()),
+        review,
+        ci,
+    }))
+}
+
 /// Sync a task with target branch (rebase)
 ///
 /// # Steps
@@ -314,7 +534,8 @@ pub fn merge_task(
 /// 1. Load task info
 /// 2. Validate: no uncommitted changes in worktree
 /// 3. Validate: no uncommitted changes in target branch
-/// 4. Execute rebase
+/// 4. Execute rebase; on conflicts the worktree is left mid-rebase for
+///    `operations::conflicts` to resolve, continue or abort
 /// 5. Update task timestamp
 ///
 /// # Returns
@@ -365,6 +586,81 @@ pub fn sync_task(repo_path: &str, project_key: &str, task_id: &str) -> Result<St
     Ok(task.target.clone())
 }
 
+/// Move a task onto a different target branch
+///
+/// # Steps
+///
+/// 1. Load task info
+/// 2. Validate: new target exists and differs from the current one
+/// 3. Validate: no uncommitted changes in worktree
+/// 4. Rebase the task's own commits onto the new target
+///    (`git rebase --onto <new> <old>`); on failure the rebase is aborted
+///    and the task stays on its old target
+/// 5. Update target and record the change in the task's history
+///
+/// # Returns
+///
+/// The previous target branch
+pub fn retarget_task(
+    repo_path: &str,
+    project_key: &str,
+    task_id: &str,
+    new_target: &str,
+) -> Result<String> {
+    // 1. Load task
+    let task = tasks::get_task(project_key, task_id)?
+        .ok_or_else(|| GroveError::not_found("Task not found"))?;
+
+    if task.is_local {
+        return Err(GroveError::invalid_data("Cannot retarget local task"));
+    }
+
+    // 2. Validate new target
+    if new_target == task.target {
+        return Err(GroveError::invalid_data(format!(
+            "Task already targets '{}'",
+            new_target
+        )));
+    }
+    if !git::branch_exists(repo_path, new_target) {
+        return Err(GroveError::invalid_data(format!(
+            "Branch '{}' does not exist",
+            new_target
+        )));
+    }
+
+    // 3. Check worktree uncommitted
+    if git::has_uncommitted_changes(&task.worktree_path)? {
+        return Err(GroveError::git(
+            "Worktree has uncommitted changes. Please commit or stash first.",
+        ));
+    }
+
+    // 4. Rebase; a stale old target falls back to a plain rebase
+    let rebased = if git::branch_exists(repo_path, &task.target) {
+        git::rebase_onto(&task.worktree_path, new_target, &task.target)
+    } else {
+        git::rebase(&task.worktree_path, new_target)
+    };
+    if let Err(e) = rebased {
+        let _ = git::abort_rebase(&task.worktree_path);
+        let detail = match e {
+            GroveError::Git(msg) => msg,
+            other => other.to_string(),
+        };
+        return Err(GroveError::git(format!(
+            "Rebase onto '{}' failed, task left on '{}': {}",
+            new_target, task.target, detail
+        )));
+    }
+
+    // 5. Update target + history
+    tasks::update_task_target(project_key, task_id, new_target)?;
+    tasks::record_target_change(project_key, task_id, &task.target, new_target)?;
+
+    Ok(task.target)
+}
+
 /// Archive a task (remove worktree, move to archived, cleanup)
 ///
 /// # Steps
@@ -471,6 +767,8 @@ pub fn archive_task(
 pub struct CreateTaskResult {
     pub task: tasks::Task,
     pub worktree_path: String,
+    /// Failures of non-blocking post-create hooks
+    pub warning: Option<String>,
 }
 
 /// Create a new task (worktree + branch + metadata)
@@ -480,8 +778,9 @@ pub struct CreateTaskResult {
 /// 1. Generate identifiers (slug, branch name)
 /// 2. Check for duplicate task ID (active + archived)
 /// 3. Ensure worktree directory
-/// 4. Create git worktree
-/// 5. Create AutoLink symlinks
+/// 4. Create git worktree (sparse when created via `create_sparse_task`)
+/// 5. Create AutoLink symlinks, run post-create commands and lifecycle hooks
+///    (a failing blocking hook removes the worktree and branch again)
 /// 6. Create task record
 ///
 /// # Note
@@ -498,7 +797,7 @@ pub struct CreateTaskResult {
 /// ```ignore
 /// use crate::operations::tasks::create_task;
 ///
-/// match create_task(&repo_path, &project_key, name, target, &mux, &autolink_patterns) {
+/// match create_task(&repo_path, &project_key, name, target, &mux, &auto_link, "user") {
 ///     Ok(result) => {
 ///         println!("Task created: {}", result.task.name);
 ///         // TUI: now create session
@@ -513,18 +812,50 @@ pub fn create_task(
     task_name: String,
     target_branch: String,
     session_type: &str,
-    autolink_patterns: &[String],
+    auto_link: &config::AutoLinkConfig,
+    created_by: &str,
+) -> Result<CreateTaskResult> {
+    create_task_inner(
+        repo_path,
+        project_key,
+        task_name,
+        target_branch,
+        session_type,
+        auto_link,
+        created_by,
+        false, // is_studio = false
+        &[],
+    )
+}
+
+/// Create a task whose worktree only checks out `sparse_paths` (plus the
+/// files at the repository root). An empty list behaves like `create_task`.
+///
+/// Paths are relative to the repository root; `..` is rejected. The sparse
+/// set lives in the worktree's own git config, so file listings, the watcher
+/// and diffs only see the checked-out part of the repo.
+#[allow(clippy::too_many_arguments)]
+pub fn create_sparse_task(
+    repo_path: &str,
+    project_key: &str,
+    task_name: String,
+    target_branch: String,
+    session_type: &str,
+    auto_link: &config::AutoLinkConfig,
     created_by: &str,
+    sparse_paths: &[String],
 ) -> Result<CreateTaskResult> {
+    let sparse_paths = git::sparse_checkout::normalize_paths(sparse_paths)?;
     create_task_inner(
         repo_path,
         project_key,
         task_name,
         target_branch,
         session_type,
-        autolink_patterns,
+        auto_link,
         created_by,
         false, // is_studio = false
+        &sparse_paths,
     )
 }
 
@@ -542,12 +873,61 @@ pub fn create_studio_task(
         task_name,
         String::new(), // no target branch
         session_type,
-        &[], // no autolink
+        &config::AutoLinkConfig::default(), // no autolink
         created_by,
         true, // is_studio = true
+        &[],
     )
 }
 
+/// Look up the template a task is created from.
+///
+/// `None` / an empty name means no template; an unknown name is an error so
+/// a typo does not silently create a task without the template's setup.
+pub fn resolve_template(
+    project_key: &str,
+    name: Option<&str>,
+) -> Result<Option<templates::TaskTemplate>> {
+    match name.map(str::trim).filter(|n| !n.is_empty()) {
+        None => Ok(None),
+        Some(name) => templates::find_template(project_key, name)
+            .map(Some)
+            .ok_or_else(|| GroveError::not_found(format!("Task template not found: {}", name))),
+    }
+}
+
+/// Apply a template to a freshly created task: write the notes skeleton
+/// (unless the caller already wrote notes) and record the template's agent
+/// and environment variables for the task's sessions and chats.
+pub fn apply_template(
+    project_key: &str,
+    task: &tasks::Task,
+    template: &templates::TaskTemplate,
+    write_notes: bool,
+    source: notes::NoteSource,
+) -> Result<()> {
+    if write_notes && !template.notes.is_empty() {
+        let vars = config::TemplateVars {
+            branch: &task.branch,
+            task_id: &task.id,
+            target: &task.target,
+        };
+        notes::save_notes(project_key, &task.id, &vars.expand(&template.notes), source)?;
+    }
+    if template.agent.is_some() || !template.env.is_empty() {
+        templates::save_task_defaults(
+            project_key,
+            &task.id,
+            &templates::TaskTemplateDefaults {
+                template: template.name.clone(),
+                agent: template.agent.clone(),
+                env: template.env.clone(),
+            },
+        )?;
+    }
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 fn create_task_inner(
     repo_path: &str,
@@ -555,36 +935,19 @@ fn create_task_inner(
     task_name: String,
     target_branch: String,
     session_type: &str,
-    autolink_patterns: &[String],
+    auto_link: &config::AutoLinkConfig,
     created_by: &str,
     is_studio: bool,
+    sparse_paths: &[String],
 ) -> Result<CreateTaskResult> {
     // 1. Generate identifiers
     let slug = tasks::to_slug(&task_name);
 
-    // 禁止创建与 Local Task 冲突的 ID
-    if slug == tasks::LOCAL_TASK_ID {
-        return Err(GroveError::invalid_data(
-            "Task name conflicts with reserved local task ID. Please use a different name.",
-        ));
-    }
-
     // 2. Check for duplicate task ID (active + archived)
-    let active_tasks = tasks::load_tasks(project_key).unwrap_or_default();
-    let archived_tasks = tasks::load_archived_tasks(project_key).unwrap_or_default();
-    if let Some(existing) = active_tasks.iter().find(|t| t.id == slug) {
-        return Err(GroveError::invalid_data(format!(
-            "Task '{}' (active) already exists. Please use a different name.",
-            existing.name
-        )));
-    }
-    if let Some(existing) = archived_tasks.iter().find(|t| t.id == slug) {
-        return Err(GroveError::invalid_data(format!(
-            "Task '{}' (archived) already exists. Please use a different name.",
-            existing.name
-        )));
-    }
+    ensure_task_id_available(project_key, &slug)?;
 
+    let mut autolinks = Vec::new();
+    let mut warning = None;
     let (task_path_str, branch) = if is_studio {
         // Studio: create folder structure under ~/.grove/studios/{project_key}/tasks/{slug}/
         let studio_dir = workspace::studio_project_dir(repo_path);
@@ -738,7 +1101,18 @@ fn create_task_inner(
         let worktree_dir = storage::ensure_worktree_dir(project_key)?;
         let worktree_path = worktree_dir.join(&slug);
 
-        git::create_worktree(repo_path, &branch, &worktree_path, &target_branch).map_err(|e| {
+        if sparse_paths.is_empty() {
+            git::create_worktree(repo_path, &branch, &worktree_path, &target_branch)
+        } else {
+            git::sparse_checkout::create_sparse_worktree(
+                repo_path,
+                &branch,
+                &worktree_path,
+                &target_branch,
+                sparse_paths,
+            )
+        }
+        .map_err(|e| {
             let msg = e.to_string();
             if msg.contains("invalid reference") || msg.contains("not a valid object name") {
                 GroveError::git(format!(
@@ -751,15 +1125,50 @@ fn create_task_inner(
             }
         })?;
 
-        // Create AutoLink symlinks
+        // Create AutoLink symlinks ({branch}/{task_id}/{target} expanded per task)
+        let vars = config::TemplateVars {
+            branch: &branch,
+            task_id: &slug,
+            target: &target_branch,
+        };
+        let auto_link = auto_link.expand(&vars);
         let main_repo =
             git::get_main_repo_path(repo_path).unwrap_or_else(|_| repo_path.to_string());
-        let _ = git::create_worktree_symlinks(
+        autolinks = git::create_worktree_symlinks(
             &worktree_path,
             std::path::Path::new(&main_repo),
-            autolink_patterns,
+            &auto_link,
             true,
-        );
+        )
+        .unwrap_or_default();
+
+        // AutoLink post-create commands, then the project lifecycle hooks
+        // (config + repo `.grove/hooks/post-create`)
+        let hook_env = tmux::SessionEnv {
+            task_id: slug.clone(),
+            task_name: task_name.clone(),
+            branch: branch.clone(),
+            target: target_branch.clone(),
+            worktree: worktree_path.to_string_lossy().to_string(),
+            project_name: project_name(repo_path),
+            project_path: repo_path.to_string(),
+            extra: Default::default(),
+        };
+        let report =
+            lifecycle_hooks::run_post_create(project_key, &hook_env, &auto_link.post_create);
+        if let Some(e) = report.blocking_error(HookEvent::PostCreate) {
+            let _ = git::remove_worktree(repo_path, &hook_env.worktree);
+            let _ = git::delete_branch(repo_path, &branch);
+            return Err(e);
+        }
+        warning = report.warning(HookEvent::PostCreate);
+
+        // Install agent hooks so session status flows back into Grove
+        if config::load_config().hooks.install_agent_hooks {
+            if let Err(e) = super::agent_hooks::install_claude_hooks(&worktree_path) {
+                tracing::warn!("Failed to install agent hooks: {}", e);
+            }
+        }
 
         (worktree_path.to_string_lossy().to_string(), branch)
     };
@@ -792,16 +1201,201 @@ fn create_task_inner(
         code_deletions: 0,
         files_changed: 0,
         is_local: false,
+        assignee: None,
+        pinned: false,
     };
 
     tasks::add_task(project_key, task.clone())?;
+    if let Err(e) = tasks::record_autolinks(project_key, &slug, &autolinks) {
+        tracing::warn!("Failed to record autolinks for {}: {}", slug, e);
+    }
 
     Ok(CreateTaskResult {
         task,
         worktree_path: task_path_str,
+        warning,
     })
 }
 
+/// Reject task IDs that are reserved or already used by an active or
+/// archived task.
+fn ensure_task_id_available(project_key: &str, slug: &str) -> Result<()> {
+    // 禁止创建与 Local Task 冲突的 ID
+    if slug == tasks::LOCAL_TASK_ID {
+        return Err(GroveError::invalid_data(
+            "Task name conflicts with reserved local task ID. Please use a different name.",
+        ));
+    }
+
+    let active_tasks = tasks::load_tasks(project_key).unwrap_or_default();
+    if let Some(existing) = active_tasks.iter().find(|t| t.id == slug) {
+        return Err(GroveError::invalid_data(format!(
+            "Task '{}' (active) already exists. Please use a different name.",
+            existing.name
+        )));
+    }
+    let archived_tasks = tasks::load_archived_tasks(project_key).unwrap_or_default();
+    if let Some(existing) = archived_tasks.iter().find(|t| t.id == slug) {
+        return Err(GroveError::invalid_data(format!(
+            "Task '{}' (archived) already exists. Please use a different name.",
+            existing.name
+        )));
+    }
+    Ok(())
+}
+
+/// A worktree of the project's repository that no Grove task owns,
+/// e.g. one created by hand with `git worktree add`.
+#[derive(Debug, Clone)]
+pub struct UnmanagedWorktree {
+    pub path: String,
+    pub branch: String,
+    /// Task name derived from the branch
+    pub suggested_name: String,
+    /// Closest local branch the worktree's branch forked from
+    pub suggested_target: String,
+}
+
+/// List worktrees of `repo_path` that are not tracked as tasks.
+///
+/// Skips the main worktree, bare and prunable entries, detached HEADs, and
+/// any worktree whose path or branch already belongs to an active task.
+pub fn find_unmanaged_worktrees(
+    repo_path: &str,
+    project_key: &str,
+) -> Result<Vec<UnmanagedWorktree>> {
+    let entries = git::list_worktrees(repo_path)?;
+    let active = tasks::load_tasks(project_key).unwrap_or_default();
+    let fallback_target = super::projects::default_target_branch(repo_path);
+    let branches = git::list_branches(repo_path).unwrap_or_default();
+
+    let mut found = Vec::new();
+    for entry in entries.into_iter().skip(1) {
+        if entry.bare || entry.prunable {
+            continue;
+        }
+        let Some(branch) = entry.branch else {
+            continue;
+        };
+        let owned = active
+            .iter()
+            .any(|t| t.branch == branch || same_path(&t.worktree_path, &entry.path));
+        if owned {
+            continue;
+        }
+        found.push(UnmanagedWorktree {
+            suggested_name: branch.rsplit('/').next().unwrap_or(&branch).to_string(),
+            suggested_target: infer_target(repo_path, &branch, &branches, &fallback_target),
+            path: entry.path,
+            branch,
+        });
+    }
+    Ok(found)
+}
+
+fn same_path(a: &str, b: &str) -> bool {
+    let canon = |p: &str| std::fs::canonicalize(p).unwrap_or_else(|_| p.into());
+    canon(a) == canon(b)
+}
+
+/// Guess which branch `branch` was created from: the local branch it has the
+/// fewest commits ahead of. Ties (e.g. a branch with no commits yet) go to
+/// `fallback`.
+fn infer_target(repo_path: &str, branch: &str, candidates: &[String], fallback: &str) -> String {
+    let mut best: Option<(u32, &str)> = None;
+    for candidate in candidates.iter().filter(|c| *c != branch) {
+        let Ok(ahead) = git::commits_behind(repo_path, branch, candidate) else {
+            continue;
+        };
+        let better = match best {
+            None => true,
+            Some((n, _)) => ahead < n || (ahead == n && candidate == fallback),
+        };
+        if better {
+            best = Some((ahead, candidate));
+        }
+    }
+    best.map(|(_, c)| c.to_string())
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// Import an unmanaged worktree as a task.
+///
+/// The worktree stays where it is; Grove only records it. `name` and
+/// `target` default to the inferred suggestions. The initial commit is the
+/// merge-base with the target, so diffs show only the branch's own work.
+pub fn adopt_worktree(
+    repo_path: &str,
+    project_key: &str,
+    worktree_path: &str,
+    name: Option<String>,
+    target: Option<String>,
+    session_type: &str,
+) -> Result<tasks::Task> {
+    let worktree = find_unmanaged_worktrees(repo_path, project_key)?
+        .into_iter()
+        .find(|w| same_path(&w.path, worktree_path))
+        .ok_or_else(|| {
+            GroveError::not_found(format!("No unmanaged worktree at '{}'", worktree_path))
+        })?;
+
+    let name = name
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .unwrap_or(worktree.suggested_name);
+    let target = target
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .unwrap_or(worktree.suggested_target);
+    if target == worktree.branch {
+        return Err(GroveError::invalid_data(
+            "Target branch must differ from the worktree's branch",
+        ));
+    }
+    if !git::branch_exists(repo_path, &target) {
+        return Err(GroveError::invalid_data(format!(
+            "Branch '{}' does not exist",
+            target
+        )));
+    }
+
+    let slug = tasks::to_slug(&name);
+    ensure_task_id_available(project_key, &slug)?;
+
+    if config::load_config().hooks.install_agent_hooks {
+        if let Err(e) =
+            super::agent_hooks::install_claude_hooks(std::path::Path::new(&worktree.path))
+        {
+            tracing::warn!("Failed to install agent hooks: {}", e);
+        }
+    }
+
+    let now = chrono::Utc::now();
+    let task = tasks::Task {
+        id: slug.clone(),
+        name,
+        initial_commit: git::merge_base(repo_path, &target, &worktree.branch).ok(),
+        branch: worktree.branch,
+        target,
+        worktree_path: worktree.path,
+        created_at: now,
+        updated_at: now,
+        status: tasks::TaskStatus::Active,
+        multiplexer: session_type.to_string(),
+        session_name: session::session_name(project_key, &slug),
+        created_by: "user".to_string(),
+        archived_at: None,
+        code_additions: 0,
+        code_deletions: 0,
+        files_changed: 0,
+        is_local: false,
+        assignee: None,
+        pinned: false,
+    };
+    tasks::add_task(project_key, task.clone())?;
+    Ok(task)
+}
+
 /// Result of recover_task operation
 pub struct RecoverTaskResult {
     pub task: tasks::Task,
@@ -968,3 +1562,203 @@ pub fn reset_task(
 
     Ok(ResetTaskResult { task: updated_task })
 }
+
+/// Result of `handback_task`
+pub struct HandbackResult {
+    pub task_name: String,
+    /// Prompt for the newly assigned agent chat. Delivery needs the async ACP
+    /// session handle, so it is the caller's job (and a no-op if the chat isn't live).
+    pub prompt: Option<String>,
+}
+
+/// Hand a task over to the human or to one of its agent chats.
+///
+/// - **Agent**: resumes that chat's message queue and composes a prompt from the
+///   unresolved review comments, task notes and `message`
+/// - **Human**: raises a notification (`message`, if given) and pauses the
+///   queues of the task's live chats, so queued prompts don't run while the
+///   task waits on the human
+/// - **None**: clears the assignee
+pub fn handback_task(
+    project_key: &str,
+    task_id: &str,
+    assignee: Option<tasks::TaskAssignee>,
+    message: Option<&str>,
+) -> Result<HandbackResult> {
+    let message = message.map(str::trim).filter(|m| !m.is_empty());
+    let task = tasks::get_task(project_key, task_id)?
+        .ok_or_else(|| GroveError::not_found("Task not found"))?;
+
+    if let Some(tasks::TaskAssignee::Agent { chat_id }) = &assignee {
+        if tasks::get_chat_session(project_key, task_id, chat_id)?.is_none() {
+            return Err(GroveError::not_found(format!("chat {chat_id} not found")));
+        }
+    }
+
+    tasks::update_task_assignee(project_key, task_id, assignee.as_ref())?;
+
+    let session_handle = |chat_id: &str| {
+        crate::acp::get_session_handle(&format!("{}:{}:{}", project_key, task_id, chat_id))
+    };
+
+    let prompt = match &assignee {
+        Some(tasks::TaskAssignee::Agent { chat_id }) => {
+            if let Some(handle) = session_handle(chat_id) {
+                handle.resume_queue();
+            }
+            let open: Vec<comments::Comment> = comments::load_comments(project_key, task_id)
+                .map(|data| {
+                    data.comments
+                        .into_iter()
+                        .filter(|c| c.status != comments::CommentStatus::Resolved)
+                        .collect()
+                })
+                .unwrap_or_default();
+            let task_notes = notes::load_notes(project_key, task_id).unwrap_or_default();
+            Some(build_handback_prompt(&open, &task_notes, message))
+        }
+        Some(tasks::TaskAssignee::Human) => {
+            for chat in tasks::load_chat_sessions(project_key, task_id).unwrap_or_default() {
+                if let Some(handle) = session_handle(&chat.id) {
+                    handle.pause_queue();
+                }
+            }
+            hooks::update_hook(
+                project_key,
+                task_id,
+                hooks::NotificationLevel::Warn,
+                Some(match message {
+                    Some(m) => format!("{} was handed back to you: {}", task.name, m),
+                    None => format!("{} was handed back to you", task.name),
+                }),
+                None,
+            );
+            None
+        }
+        None => None,
+    };
+
+    Ok(HandbackResult {
+        task_name: task.name,
+        prompt,
+    })
+}
+
+/// Compose the prompt sent to an agent when a task is handed to it.
+fn build_handback_prompt(
+    open_comments: &[comments::Comment],
+    task_notes: &str,
+    message: Option<&str>,
+) -> String {
+    let mut out = String::from("This task has been handed back to you.\n");
+    if let Some(message) = message {
+        out.push_str(&format!("\n{}\n", message));
+    }
+
+    if open_comments.is_empty() {
+        out.push_str("\nThere are no unresolved review comments.\n");
+    } else {
+        out.push_str(&format!(
+            "\nUnresolved review comments ({}):\n",
+            open_comments.len()
+        ));
+        for c in open_comments {
+            let outdated = if c.status == comments::CommentStatus::Outdated {
+                " (outdated)"
+            } else {
+                ""
+            };
+            out.push_str(&format!(
+                "- #{} {}{}: {}\n",
+                c.id,
+                comments::comment_location(c),
+                outdated,
+                c.content
+            ));
+        }
+    }
+
+    let task_notes = task_notes.trim();
+    if !task_notes.is_empty() {
+        out.push_str(&format!("\nTask notes:\n{}\n", task_notes));
+    }
+
+    out.push_str(
+        "\nAddress the comments and reply to each with `grove_reply_review`. When you need the human again, call `grove_handback`.\n",
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(id: u32, status: comments::CommentStatus) -> comments::Comment {
+        comments::Comment {
+            id,
+            comment_type: comments::CommentType::Inline,
+            file_path: Some("src/lib.rs".to_string()),
+            side: Some("ADD".to_string()),
+            start_line: Some(3),
+            end_line: Some(5),
+            content: "rename this".to_string(),
+            agent: "You".to_string(),
+            model: String::new(),
+            role: String::new(),
+            timestamp: String::new(),
+            status,
+            replies: Vec::new(),
+            anchor_text: None,
+            reactions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_handback_prompt() {
+        let prompt = build_handback_prompt(
+            &[
+                comment(1, comments::CommentStatus::Open),
+                comment(2, comments::CommentStatus::Outdated),
+            ],
+            "  keep the API stable \n",
+            Some("Please finish the rename"),
+        );
+        assert!(prompt.contains("\nPlease finish the rename\n"));
+        assert!(prompt.contains("Unresolved review comments (2):"));
+        assert!(prompt.contains("- #1 src/lib.rs:L3-L5: rename this"));
+        assert!(prompt.contains("- #2 src/lib.rs:L3-L5 (outdated): rename this"));
+        assert!(prompt.contains("Task notes:\nkeep the API stable\n"));
+
+        let empty = build_handback_prompt(&[], "", None);
+        assert!(empty.contains("no unresolved review comments"));
+        assert!(!empty.contains("Task notes"));
+    }
+
+    #[test]
+    fn test_infer_target_picks_fork_point() {
+        let repo = tempfile::tempdir().unwrap();
+        let path = repo.path().to_str().unwrap();
+        let git = |args: &[&str]| {
+            let out = std::process::Command::new("git")
+                .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+                .args(args)
+                .current_dir(path)
+                .output()
+                .unwrap();
+            assert!(out.status.success(), "{:?}", out);
+        };
+        git(&["init", "-q", "-b", "main"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "root"]);
+        git(&["checkout", "-q", "-b", "develop"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "d1"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "d2"]);
+        git(&["checkout", "-q", "-b", "feature"]);
+        git(&["commit", "-q", "--allow-empty", "-m", "f1"]);
+        git(&["branch", "fresh", "main"]);
+
+        let branches = git::list_branches(path).unwrap();
+        assert_eq!(infer_target(path, "feature", &branches, "main"), "develop");
+        // No commits of its own: every candidate ties, so the fallback wins
+        assert_eq!(infer_target(path, "fresh", &branches, "main"), "main");
+    }
+}
@@ -0,0 +1,253 @@
+//! Task time tracking
+//!
+//! Time spent on a task is the union of its active-work intervals: bursts of
+//! file edits recorded by the watcher (`activity.jsonl`) and the periods a
+//! session was attached from the TUI or a web terminal (`attach.jsonl`).
+//! Overlapping intervals count once, so an attached session with edits in it
+//! isn't double counted.
+//!
+//! Per-task daily and weekly rollups for a project are written to
+//! `projects/<project>/time_tracking.toml` whenever they are recomputed.
+//! Days and ISO weeks follow the local timezone.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::session::attach_log;
+use crate::storage::{grove_dir, save_toml, tasks};
+use crate::watcher;
+
+type Interval = (DateTime<Utc>, DateTime<Utc>);
+
+/// Time spent on one task
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TaskTime {
+    pub total_secs: i64,
+    /// Today (local time)
+    pub today_secs: i64,
+    /// Current ISO week (local time)
+    pub week_secs: i64,
+    /// Part of the total spent attached to the task's session
+    pub attached_secs: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_active_at: Option<DateTime<Utc>>,
+}
+
+/// Per-task seconds by local day (`2026-10-18`) and ISO week (`2026-W42`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimeRollup {
+    pub updated_at: Option<DateTime<Utc>>,
+    /// day -> task id -> seconds
+    #[serde(default)]
+    pub daily: BTreeMap<String, BTreeMap<String, i64>>,
+    /// week -> task id -> seconds
+    #[serde(default)]
+    pub weekly: BTreeMap<String, BTreeMap<String, i64>>,
+}
+
+/// Sort intervals and merge the overlapping or touching ones.
+fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_by_key(|(start, _)| *start);
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+fn total_secs(intervals: &[Interval]) -> i64 {
+    intervals
+        .iter()
+        .map(|(start, end)| (*end - *start).num_seconds())
+        .sum()
+}
+
+fn attach_intervals(project_key: &str, task_id: &str) -> Vec<Interval> {
+    attach_log::load_attach_intervals(project_key, task_id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|a| (a.start, a.end))
+        .collect()
+}
+
+/// Merged active-work intervals of a task.
+fn active_intervals(project_key: &str, task_id: &str) -> (Vec<Interval>, Vec<Interval>) {
+    let edits = watcher::load_edit_history(project_key, task_id).unwrap_or_default();
+    let attached = merge_intervals(attach_intervals(project_key, task_id));
+    let mut all = watcher::edit_intervals(&edits);
+    all.extend(attached.iter().copied());
+    (merge_intervals(all), attached)
+}
+
+/// Split intervals at local midnight and sum the seconds of each day.
+fn seconds_by_day(intervals: &[Interval]) -> BTreeMap<NaiveDate, i64> {
+    let mut days = BTreeMap::new();
+    for &(start, end) in intervals {
+        let mut cursor = start;
+        while cursor < end {
+            let day = cursor.with_timezone(&Local).date_naive();
+            let next_midnight = day
+                .succ_opt()
+                .and_then(|next| {
+                    Local
+                        .from_local_datetime(&next.and_time(Default::default()))
+                        .earliest()
+                })
+                .map(|t| t.with_timezone(&Utc))
+                .filter(|t| *t > cursor)
+                .unwrap_or(end);
+            let chunk_end = end.min(next_midnight);
+            *days.entry(day).or_insert(0) += (chunk_end - cursor).num_seconds();
+            cursor = chunk_end;
+        }
+    }
+    days
+}
+
+fn week_key(day: NaiveDate) -> String {
+    let week = day.iso_week();
+    format!("{}-W{:02}", week.year(), week.week())
+}
+
+fn summarize(intervals: &[Interval], attached: &[Interval], today: NaiveDate) -> TaskTime {
+    let days = seconds_by_day(intervals);
+    let this_week = today.iso_week();
+    TaskTime {
+        total_secs: total_secs(intervals),
+        today_secs: days.get(&today).copied().unwrap_or(0),
+        week_secs: days
+            .iter()
+            .filter(|(day, _)| day.iso_week() == this_week)
+            .map(|(_, secs)| secs)
+            .sum(),
+        attached_secs: total_secs(attached),
+        last_active_at: intervals.last().map(|(_, end)| *end),
+    }
+}
+
+/// Time spent on a task.
+pub fn task_time(project_key: &str, task_id: &str) -> TaskTime {
+    let (intervals, attached) = active_intervals(project_key, task_id);
+    summarize(&intervals, &attached, Local::now().date_naive())
+}
+
+/// Recompute the daily and weekly rollups of every task (active and
+/// archived) in a project and store them under the project directory.
+pub fn refresh_rollup(project_key: &str) -> Result<TimeRollup> {
+    let mut all_tasks = tasks::load_tasks(project_key)?;
+    all_tasks.extend(tasks::load_archived_tasks(project_key)?);
+
+    let mut rollup = TimeRollup {
+        updated_at: Some(Utc::now()),
+        ..Default::default()
+    };
+    for task in &all_tasks {
+        let (intervals, _) = active_intervals(project_key, &task.id);
+        for (day, secs) in seconds_by_day(&intervals) {
+            rollup
+                .daily
+                .entry(day.to_string())
+                .or_default()
+                .insert(task.id.clone(), secs);
+            *rollup
+                .weekly
+                .entry(week_key(day))
+                .or_default()
+                .entry(task.id.clone())
+                .or_insert(0) += secs;
+        }
+    }
+
+    let dir = grove_dir().join("projects").join(project_key);
+    std::fs::create_dir_all(&dir)?;
+    save_toml(&dir.join("time_tracking.toml"), &rollup)?;
+    Ok(rollup)
+}
+
+/// Compact duration for list views: `45m`, `3h 20m`
+pub fn format_duration(secs: i64) -> String {
+    let minutes = secs / 60;
+    if minutes < 60 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::watcher::EditEvent;
+    use chrono::Duration;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn edit(secs: i64) -> EditEvent {
+        EditEvent {
+            timestamp: at(secs),
+            file: "src/main.rs".into(),
+        }
+    }
+
+    #[test]
+    fn test_edit_bursts_become_intervals() {
+        // Two edits 2 minutes apart, then one 20 minutes later
+        let events = vec![edit(1200), edit(0), edit(120)];
+        let intervals = watcher::edit_intervals(&events);
+        assert_eq!(intervals, vec![(at(0), at(180)), (at(1200), at(1260))]);
+    }
+
+    #[test]
+    fn test_overlapping_attach_counts_once() {
+        let edits = watcher::edit_intervals(&[edit(0), edit(120)]);
+        let attached = vec![(at(60), at(600))];
+        let mut all = edits;
+        all.extend(attached.iter().copied());
+        let merged = merge_intervals(all);
+        assert_eq!(merged, vec![(at(0), at(600))]);
+
+        let today = at(0).with_timezone(&Local).date_naive();
+        let time = summarize(&merged, &attached, today);
+        assert_eq!(time.total_secs, 600);
+        assert_eq!(time.attached_secs, 540);
+        assert_eq!(time.last_active_at, Some(at(600)));
+    }
+
+    #[test]
+    fn test_days_split_at_local_midnight() {
+        let midnight = Local
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(2026, 3, 10)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap(),
+            )
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc);
+        let interval = (
+            midnight - Duration::minutes(30),
+            midnight + Duration::minutes(90),
+        );
+        let days = seconds_by_day(&[interval]);
+        let day = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        assert_eq!(days.get(&day.pred_opt().unwrap()), Some(&1800));
+        assert_eq!(days.get(&day), Some(&5400));
+        assert_eq!(week_key(day), "2026-W11");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(59), "0m");
+        assert_eq!(format_duration(45 * 60), "45m");
+        assert_eq!(format_duration(3 * 3600 + 5 * 60), "3h 05m");
+    }
+}
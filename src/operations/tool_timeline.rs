@@ -0,0 +1,258 @@
+//! Per-turn tool-call timeline
+//!
+//! Pairs each `ToolCall` (start) with its terminal `ToolCallUpdate` (end) from
+//! the chat history, computes durations and packs overlapping calls into
+//! lanes, so the UI can draw a gantt-like view where slow tools and parallel
+//! tool calls stand out. History written before tool updates carried a
+//! timestamp yields spans without an end.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::acp::AcpUpdate;
+use crate::storage::chat_history;
+
+/// Max characters of the prompt kept as the turn label.
+const PROMPT_LABEL_CHARS: usize = 80;
+
+/// One tool call on the timeline.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToolSpan {
+    pub id: String,
+    pub title: String,
+    /// Last reported status ("completed", "failed", ... or "" if none yet)
+    pub status: String,
+    /// Unix milliseconds
+    pub start_ms: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<i64>,
+    /// Row in the gantt view; calls sharing a lane never overlap
+    pub lane: usize,
+}
+
+/// Tool calls of one prompt turn.
+#[derive(Debug, Clone, Serialize)]
+pub struct TurnTimeline {
+    /// 0-based turn index within the chat
+    pub index: usize,
+    /// Beginning of the user prompt
+    pub prompt: String,
+    /// Turn start / end from the `Complete` event (unix seconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_ts: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_ts: Option<i64>,
+    pub tools: Vec<ToolSpan>,
+    /// Most tool calls running at the same time (= number of lanes)
+    pub max_parallel: usize,
+    /// Sum of finished tool durations (exceeds wall time when calls overlap)
+    pub tool_time_ms: i64,
+}
+
+fn is_terminal(status: &str) -> bool {
+    matches!(status, "completed" | "failed" | "error" | "cancelled")
+}
+
+#[derive(Default)]
+struct TurnBuilder {
+    prompt: String,
+    start_ts: Option<i64>,
+    end_ts: Option<i64>,
+    tools: Vec<ToolSpan>,
+    /// tool id → index in `tools`
+    by_id: HashMap<String, usize>,
+}
+
+impl TurnBuilder {
+    fn is_empty(&self) -> bool {
+        self.prompt.is_empty() && self.tools.is_empty() && self.end_ts.is_none()
+    }
+
+    fn finish(mut self, index: usize) -> TurnTimeline {
+        self.tools.sort_by_key(|t| t.start_ms);
+        let mut lane_ends: Vec<i64> = Vec::new();
+        for tool in &mut self.tools {
+            // Unfinished calls keep their lane for the rest of the turn
+            let end = tool.end_ms.unwrap_or(i64::MAX);
+            match lane_ends.iter().position(|&e| e <= tool.start_ms) {
+                Some(lane) => {
+                    lane_ends[lane] = end;
+                    tool.lane = lane;
+                }
+                None => {
+                    tool.lane = lane_ends.len();
+                    lane_ends.push(end);
+                }
+            }
+        }
+        TurnTimeline {
+            index,
+            prompt: self.prompt,
+            start_ts: self.start_ts,
+            end_ts: self.end_ts,
+            max_parallel: lane_ends.len(),
+            tool_time_ms: self.tools.iter().filter_map(|t| t.duration_ms).sum(),
+            tools: self.tools,
+        }
+    }
+}
+
+/// Build the per-turn timeline from chat history events.
+pub fn build(events: &[AcpUpdate]) -> Vec<TurnTimeline> {
+    let mut turns = Vec::new();
+    let mut current = TurnBuilder::default();
+
+    let millis = |ts: &DateTime<Utc>| ts.timestamp_millis();
+
+    for event in events {
+        match event {
+            AcpUpdate::UserMessage { text, .. } => {
+                if !current.is_empty() {
+                    let done = std::mem::take(&mut current);
+                    turns.push(done.finish(turns.len()));
+                }
+                current.prompt = text.chars().take(PROMPT_LABEL_CHARS).collect();
+            }
+            AcpUpdate::ToolCall {
+                id,
+                title,
+                timestamp: Some(ts),
+                ..
+            } => {
+                if let Some(&i) = current.by_id.get(id) {
+                    if !title.is_empty() {
+                        current.tools[i].title = title.clone();
+                    }
+                    continue;
+                }
+                current.by_id.insert(id.clone(), current.tools.len());
+                current.tools.push(ToolSpan {
+                    id: id.clone(),
+                    title: title.clone(),
+                    status: String::new(),
+                    start_ms: millis(ts),
+                    end_ms: None,
+                    duration_ms: None,
+                    lane: 0,
+                });
+            }
+            AcpUpdate::ToolCallUpdate {
+                id,
+                status,
+                timestamp,
+                ..
+            } => {
+                let Some(&i) = current.by_id.get(id) else {
+                    continue;
+                };
+                let span = &mut current.tools[i];
+                if !status.is_empty() {
+                    span.status = status.clone();
+                }
+                if let (true, Some(ts), None) = (is_terminal(status), timestamp, span.end_ms) {
+                    let end = millis(ts).max(span.start_ms);
+                    span.end_ms = Some(end);
+                    span.duration_ms = Some(end - span.start_ms);
+                }
+            }
+            AcpUpdate::Complete {
+                start_ts, end_ts, ..
+            } => {
+                current.start_ts = *start_ts;
+                current.end_ts = *end_ts;
+            }
+            _ => {}
+        }
+    }
+    if !current.is_empty() {
+        turns.push(current.finish(turns.len()));
+    }
+    turns
+}
+
+/// Timeline of a chat, read from its history.
+pub fn for_chat(project_key: &str, task_id: &str, chat_id: &str) -> Vec<TurnTimeline> {
+    build(&chat_history::load_history(project_key, task_id, chat_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(ms: i64) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp_millis(ms)
+    }
+
+    fn call(id: &str, ms: i64) -> AcpUpdate {
+        AcpUpdate::ToolCall {
+            id: id.to_string(),
+            title: format!("tool {}", id),
+            locations: vec![],
+            timestamp: at(ms),
+            raw_input: None,
+        }
+    }
+
+    fn done(id: &str, ms: i64) -> AcpUpdate {
+        AcpUpdate::ToolCallUpdate {
+            id: id.to_string(),
+            status: "completed".to_string(),
+            content: None,
+            locations: vec![],
+            raw_input: None,
+            timestamp: at(ms),
+        }
+    }
+
+    fn prompt(text: &str) -> AcpUpdate {
+        AcpUpdate::UserMessage {
+            text: text.to_string(),
+            attachments: vec![],
+            sender: None,
+            terminal: false,
+        }
+    }
+
+    #[test]
+    fn overlapping_calls_get_separate_lanes() {
+        let events = vec![
+            prompt("run checks"),
+            call("a", 1_000),
+            call("b", 1_100),
+            done("b", 1_600),
+            done("a", 3_000),
+            call("c", 3_000),
+            done("c", 3_500),
+        ];
+        let turns = build(&events);
+        assert_eq!(turns.len(), 1);
+        let turn = &turns[0];
+        assert_eq!(turn.prompt, "run checks");
+        assert_eq!(turn.max_parallel, 2);
+        assert_eq!(turn.tool_time_ms, 2_000 + 500 + 500);
+        let lanes: Vec<(&str, usize)> =
+            turn.tools.iter().map(|t| (t.id.as_str(), t.lane)).collect();
+        assert_eq!(lanes, vec![("a", 0), ("b", 1), ("c", 0)]);
+        assert_eq!(turn.tools[0].duration_ms, Some(2_000));
+    }
+
+    #[test]
+    fn turns_split_on_user_messages_and_unfinished_calls_have_no_end() {
+        let events = vec![
+            prompt("first"),
+            call("a", 0),
+            done("a", 10),
+            prompt("second"),
+            call("b", 20),
+        ];
+        let turns = build(&events);
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[1].index, 1);
+        assert_eq!(turns[1].tools[0].end_ms, None);
+        assert_eq!(turns[1].tool_time_ms, 0);
+    }
+}
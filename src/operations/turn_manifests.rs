@@ -0,0 +1,177 @@
+//! Per-turn reproducibility manifests
+//!
+//! Every ACP turn records which agent (name/version, model, mode), which
+//! prompt (by SHA-256), installed skills and instruction preambles produced
+//! it, plus the git HEAD before and after the turn. Manifests are stored
+//! next to the chat history and included in the transcript export so a
+//! change can be audited back to the exact run that made it.
+
+use sha2::{Digest, Sha256};
+
+use crate::git;
+use crate::storage::instructions::Preamble;
+use crate::storage::skills::{self, InstalledSkillDef};
+use crate::storage::turn_manifests::{self, TurnManifest};
+use crate::storage::workspace;
+
+/// Identity of the agent running a turn.
+#[derive(Debug, Clone, Default)]
+pub struct TurnAgent {
+    /// Logical agent id (as used for skill installs)
+    pub id: String,
+    /// Name / version reported by the agent on initialize
+    pub name: String,
+    pub version: String,
+    pub model: Option<String>,
+    pub mode: Option<String>,
+    /// Instruction preambles injected into this turn's prompt
+    pub instructions: Vec<Preamble>,
+}
+
+/// Hex SHA-256 of the prompt text.
+pub fn prompt_hash(prompt: &str) -> String {
+    hex::encode(Sha256::digest(prompt.as_bytes()))
+}
+
+fn head(worktree: &str) -> Option<String> {
+    git::resolve_commit(worktree, "HEAD").ok()
+}
+
+fn same_path(a: &str, b: &str) -> bool {
+    a.trim_end_matches('/') == b.trim_end_matches('/')
+}
+
+/// Skills installed for `agent_id` globally or into `project_path`, sorted
+/// and de-duplicated.
+fn skills_for(
+    installed: &[InstalledSkillDef],
+    agent_id: &str,
+    project_path: Option<&str>,
+) -> Vec<String> {
+    let mut names: Vec<String> = installed
+        .iter()
+        .filter(|skill| {
+            skill.global_agents.iter().any(|a| a.agent_id == agent_id)
+                || project_path.is_some_and(|path| {
+                    skill.project_installs.iter().any(|p| {
+                        same_path(&p.project_path, path)
+                            && p.agents.iter().any(|a| a.agent_id == agent_id)
+                    })
+                })
+        })
+        .map(|skill| skill.skill_name.clone())
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Record the manifest of a turn that is about to start.
+pub fn begin_turn(
+    project_key: &str,
+    task_id: &str,
+    chat_id: &str,
+    worktree: &str,
+    turn_ts: i64,
+    agent: TurnAgent,
+    prompt: &str,
+) {
+    let project_path = workspace::load_project_by_hash(project_key)
+        .ok()
+        .flatten()
+        .map(|p| p.path);
+    let manifest = TurnManifest {
+        turn_ts,
+        agent_name: agent.name,
+        agent_version: agent.version,
+        model: agent.model,
+        mode: agent.mode,
+        prompt_sha256: prompt_hash(prompt),
+        skills: skills_for(
+            &skills::load_installed().installed,
+            &agent.id,
+            project_path.as_deref(),
+        ),
+        instructions: agent.instructions,
+        head_before: head(worktree),
+        head_after: None,
+        finished_ts: None,
+    };
+    if let Err(e) = turn_manifests::record(project_key, task_id, chat_id, manifest) {
+        tracing::warn!("Failed to save turn manifest: {}", e);
+    }
+}
+
+/// Fill in the HEAD after a turn ends.
+pub fn end_turn(project_key: &str, task_id: &str, chat_id: &str, worktree: &str, turn_ts: i64) {
+    let finished = chrono::Utc::now().timestamp();
+    if let Err(e) = turn_manifests::finish(
+        project_key,
+        task_id,
+        chat_id,
+        turn_ts,
+        head(worktree),
+        finished,
+    ) {
+        tracing::warn!("Failed to update turn manifest: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::skills::{ProjectInstall, ScopeAgentRef};
+
+    fn skill(name: &str, global: &[&str], project: Option<(&str, &[&str])>) -> InstalledSkillDef {
+        let agents = |ids: &[&str]| {
+            ids.iter()
+                .map(|id| ScopeAgentRef {
+                    agent_id: id.to_string(),
+                    symlink_path: None,
+                })
+                .collect::<Vec<_>>()
+        };
+        InstalledSkillDef {
+            repo_key: String::new(),
+            repo_path: String::new(),
+            source_name: "src".to_string(),
+            skill_name: name.to_string(),
+            installed_at: chrono::Utc::now(),
+            global_agents: agents(global),
+            project_installs: project
+                .map(|(path, ids)| {
+                    vec![ProjectInstall {
+                        project_path: path.to_string(),
+                        agents: agents(ids),
+                    }]
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    #[test]
+    fn skills_for_filters_by_agent_and_project() {
+        let installed = vec![
+            skill("review", &["claude"], None),
+            skill("deploy", &[], Some(("/repo/app/", &["claude"]))),
+            skill("other-project", &[], Some(("/repo/other", &["claude"]))),
+            skill("codex-only", &["codex"], None),
+        ];
+        assert_eq!(
+            skills_for(&installed, "claude", Some("/repo/app")),
+            vec!["deploy".to_string(), "review".to_string()]
+        );
+        assert_eq!(
+            skills_for(&installed, "claude", None),
+            vec!["review".to_string()]
+        );
+    }
+
+    #[test]
+    fn prompt_hash_is_stable_hex() {
+        let hash = prompt_hash("fix the bug");
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, prompt_hash("fix the bug"));
+        assert_ne!(hash, prompt_hash("fix the bugs"));
+    }
+}
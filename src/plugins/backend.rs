@@ -407,7 +407,7 @@ async fn reader_loop(stdout: ChildStdout, weak: Weak<Backend>, plugin_id: String
 async fn drain_stderr(stderr: ChildStderr, plugin_name: String) {
     let mut lines = BufReader::new(stderr).lines();
     while let Ok(Some(line)) = lines.next_line().await {
-        eprintln!("[plugin:{}] {}", plugin_name, line);
+        tracing::info!("[plugin:{}] {}", plugin_name, line);
     }
 }
 
@@ -0,0 +1,488 @@
+//! Global search across tasks, notes, chats and review comments.
+//!
+//! A query walks the registered projects and builds one document per task
+//! name, task notes file, project note, chat and review thread, keeping the
+//! documents that contain every query term. Chat history is the expensive
+//! source (JSONL, up to tens of MB per chat), so the message text extracted
+//! from each `history.jsonl` is indexed in memory and only re-read when the
+//! file's size or mtime changes.
+//!
+//! Matching is case-insensitive (ASCII) substring search, so partial words
+//! and CJK text match too.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::acp::AcpUpdate;
+use crate::storage::{chat_history, comments, notes, project_notes, tasks, workspace};
+
+/// Hits returned when the caller doesn't ask for a limit
+pub const DEFAULT_LIMIT: usize = 50;
+
+/// Upper bound of `SearchQuery::limit`
+pub const MAX_LIMIT: usize = 200;
+
+/// Characters of context kept around the first match in a snippet
+const SNIPPET_BEFORE: usize = 60;
+const SNIPPET_AFTER: usize = 120;
+
+/// What a hit points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HitKind {
+    Task,
+    Note,
+    ProjectNote,
+    Chat,
+    Comment,
+}
+
+impl HitKind {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "task" => Some(HitKind::Task),
+            "note" => Some(HitKind::Note),
+            "project_note" => Some(HitKind::ProjectNote),
+            "chat" => Some(HitKind::Chat),
+            "comment" => Some(HitKind::Comment),
+            _ => None,
+        }
+    }
+
+    /// Short label for list views
+    pub fn label(self) -> &'static str {
+        match self {
+            HitKind::Task => "task",
+            HitKind::Note => "notes",
+            HitKind::ProjectNote => "project note",
+            HitKind::Chat => "chat",
+            HitKind::Comment => "review",
+        }
+    }
+
+    /// Ranking bonus: a task named after the query beats a passing mention
+    fn bonus(self) -> u32 {
+        match self {
+            HitKind::Task => 8,
+            HitKind::Note | HitKind::ProjectNote => 4,
+            HitKind::Comment => 2,
+            HitKind::Chat => 0,
+        }
+    }
+}
+
+/// One search result
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub kind: HitKind,
+    pub project_id: String,
+    pub project_name: String,
+    /// Project path (what the TUI opens)
+    pub project_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_name: Option<String>,
+    /// The task is archived
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chat_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note_slug: Option<String>,
+    /// Task name, note or chat title, or comment location
+    pub title: String,
+    /// Excerpt around the first match (empty for title-only matches)
+    pub snippet: String,
+    pub score: u32,
+}
+
+/// Search request
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    pub text: String,
+    /// Restrict to these kinds (empty = all)
+    pub kinds: Vec<HitKind>,
+    /// Restrict to one project (project id)
+    pub project_id: Option<String>,
+    /// Maximum hits (0 = `DEFAULT_LIMIT`)
+    pub limit: usize,
+}
+
+/// Search results
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SearchResults {
+    pub hits: Vec<SearchHit>,
+    /// More documents matched than `limit`
+    pub truncated: bool,
+}
+
+/// Searchable text with its ASCII-lowercased copy (same byte offsets)
+#[derive(Debug)]
+struct Section {
+    text: String,
+    lower: String,
+}
+
+impl Section {
+    fn new(text: String) -> Self {
+        let lower = text.to_ascii_lowercase();
+        Self { text, lower }
+    }
+}
+
+/// Split a query into lowercase terms (duplicates dropped)
+fn query_terms(text: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    text.split_whitespace()
+        .map(|t| t.to_ascii_lowercase())
+        .filter(|t| seen.insert(t.clone()))
+        .collect()
+}
+
+/// Score a document against the terms; `None` unless every term occurs in
+/// the title or a section. Returns the score and a snippet from the section
+/// holding the most terms.
+fn match_document(terms: &[String], title: &str, sections: &[Section]) -> Option<(u32, String)> {
+    if terms.is_empty() {
+        return None;
+    }
+    let title_lower = title.to_ascii_lowercase();
+    let mut score = 0;
+    for term in terms {
+        let in_title = title_lower.contains(term.as_str());
+        let occurrences: usize = sections
+            .iter()
+            .map(|s| s.lower.matches(term.as_str()).take(5).count())
+            .sum();
+        if !in_title && occurrences == 0 {
+            return None;
+        }
+        score += if in_title { 10 } else { 0 } + occurrences.min(5) as u32;
+    }
+
+    let best = sections
+        .iter()
+        .map(|s| {
+            let found = terms
+                .iter()
+                .filter(|t| s.lower.contains(t.as_str()))
+                .count();
+            (found, s)
+        })
+        .filter(|(found, _)| *found > 0)
+        .max_by_key(|(found, _)| *found)
+        .map(|(_, s)| s);
+    let snippet = best
+        .and_then(|s| {
+            let pos = terms
+                .iter()
+                .filter_map(|t| s.lower.find(t.as_str()))
+                .min()?;
+            Some(excerpt(&s.text, pos))
+        })
+        .unwrap_or_default();
+    Some((score, snippet))
+}
+
+/// Single-line excerpt of `text` around byte offset `pos`
+fn excerpt(text: &str, pos: usize) -> String {
+    let before: Vec<(usize, char)> = text[..pos].char_indices().collect();
+    let start = before
+        .len()
+        .checked_sub(SNIPPET_BEFORE)
+        .map_or(0, |i| before[i].0);
+    let end = text[pos..]
+        .char_indices()
+        .nth(SNIPPET_AFTER)
+        .map_or(text.len(), |(i, _)| pos + i);
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push('…');
+    }
+    snippet.push_str(
+        &text[start..end]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    if end < text.len() {
+        snippet.push('…');
+    }
+    snippet
+}
+
+// ── Chat index ──────────────────────────────────────────────────────────
+
+/// Extracted message text of one history file
+struct IndexedChat {
+    modified: Option<SystemTime>,
+    len: u64,
+    messages: Arc<Vec<Section>>,
+}
+
+static CHAT_INDEX: Lazy<Mutex<HashMap<PathBuf, IndexedChat>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// User and agent messages of a chat, re-extracted when the file changed
+fn chat_messages(project_key: &str, task_id: &str, chat_id: &str) -> Arc<Vec<Section>> {
+    let path = chat_history::history_file_path(project_key, task_id, chat_id);
+    let Ok(meta) = std::fs::metadata(&path) else {
+        return Arc::new(Vec::new());
+    };
+    let modified = meta.modified().ok();
+    if let Some(indexed) = CHAT_INDEX.lock().unwrap().get(&path) {
+        if indexed.modified == modified && indexed.len == meta.len() {
+            return indexed.messages.clone();
+        }
+    }
+
+    let history = chat_history::load_history(project_key, task_id, chat_id);
+    let messages: Vec<Section> = chat_history::compact_events(history)
+        .into_iter()
+        .filter_map(|event| match event {
+            AcpUpdate::UserMessage { text, .. } | AcpUpdate::MessageChunk { text } => {
+                (!text.trim().is_empty()).then(|| Section::new(text))
+            }
+            _ => None,
+        })
+        .collect();
+    let messages = Arc::new(messages);
+    CHAT_INDEX.lock().unwrap().insert(
+        path,
+        IndexedChat {
+            modified,
+            len: meta.len(),
+            messages: messages.clone(),
+        },
+    );
+    messages
+}
+
+/// Drop index entries of chats that no longer exist
+fn prune_chat_index(seen: &HashSet<PathBuf>) {
+    CHAT_INDEX
+        .lock()
+        .unwrap()
+        .retain(|path, _| seen.contains(path));
+}
+
+// ── Search ──────────────────────────────────────────────────────────────
+
+/// Where a document lives
+struct Location<'a> {
+    project: &'a workspace::RegisteredProject,
+    project_id: &'a str,
+    task: Option<&'a tasks::Task>,
+    archived: bool,
+}
+
+impl Location<'_> {
+    fn hit(&self, kind: HitKind, title: String, score: u32, snippet: String) -> SearchHit {
+        SearchHit {
+            kind,
+            project_id: self.project_id.to_string(),
+            project_name: self.project.name.clone(),
+            project_path: self.project.path.clone(),
+            task_id: self.task.map(|t| t.id.clone()),
+            task_name: self.task.map(|t| t.name.clone()),
+            archived: self.archived,
+            chat_id: None,
+            comment_id: None,
+            note_slug: None,
+            title,
+            snippet,
+            score: score + kind.bonus(),
+        }
+    }
+}
+
+/// Run a search over every registered project (or the one in the query)
+pub fn search(query: &SearchQuery) -> SearchResults {
+    let terms = query_terms(&query.text);
+    if terms.is_empty() {
+        return SearchResults::default();
+    }
+    let wants = |kind: HitKind| query.kinds.is_empty() || query.kinds.contains(&kind);
+    let limit = match query.limit {
+        0 => DEFAULT_LIMIT,
+        n => n.min(MAX_LIMIT),
+    };
+
+    let projects = workspace::load_projects().unwrap_or_default();
+    let mut hits = Vec::new();
+    let mut seen_chats = HashSet::new();
+
+    for project in &projects {
+        let project_id = workspace::project_hash(&project.path);
+        if query
+            .project_id
+            .as_ref()
+            .is_some_and(|id| *id != project_id)
+        {
+            continue;
+        }
+        let key = project_id.as_str();
+        let project_loc = Location {
+            project,
+            project_id: key,
+            task: None,
+            archived: false,
+        };
+
+        if wants(HitKind::ProjectNote) {
+            for note in project_notes::list_notes(key).unwrap_or_default() {
+                let Some(content) = project_notes::note_file_path(key, &note.slug)
+                    .ok()
+                    .and_then(|p| std::fs::read_to_string(p).ok())
+                else {
+                    continue;
+                };
+                let sections = [Section::new(content)];
+                if let Some((score, snippet)) = match_document(&terms, &note.title, &sections) {
+                    let mut hit = project_loc.hit(HitKind::ProjectNote, note.title, score, snippet);
+                    hit.note_slug = Some(note.slug);
+                    hits.push(hit);
+                }
+            }
+        }
+
+        let active = tasks::load_tasks(key).unwrap_or_default();
+        let archived = tasks::load_archived_tasks(key).unwrap_or_default();
+        let all_tasks = active
+            .iter()
+            .map(|t| (t, false))
+            .chain(archived.iter().map(|t| (t, true)));
+
+        for (task, is_archived) in all_tasks {
+            let loc = Location {
+                task: Some(task),
+                archived: is_archived,
+                ..project_loc
+            };
+
+            if wants(HitKind::Task) {
+                if let Some((score, snippet)) = match_document(&terms, &task.name, &[]) {
+                    hits.push(loc.hit(HitKind::Task, task.name.clone(), score, snippet));
+                }
+            }
+
+            if wants(HitKind::Note) {
+                let content = notes::load_notes(key, &task.id).unwrap_or_default();
+                if !content.trim().is_empty() {
+                    let sections = [Section::new(content)];
+                    if let Some((score, snippet)) = match_document(&terms, "", &sections) {
+                        hits.push(loc.hit(
+                            HitKind::Note,
+                            format!("Notes · {}", task.name),
+                            score,
+                            snippet,
+                        ));
+                    }
+                }
+            }
+
+            if wants(HitKind::Chat) {
+                for chat in tasks::load_chat_sessions(key, &task.id).unwrap_or_default() {
+                    seen_chats.insert(chat_history::history_file_path(key, &task.id, &chat.id));
+                    let messages = chat_messages(key, &task.id, &chat.id);
+                    if let Some((score, snippet)) = match_document(&terms, &chat.title, &messages) {
+                        let mut hit = loc.hit(HitKind::Chat, chat.title, score, snippet);
+                        hit.chat_id = Some(chat.id);
+                        hits.push(hit);
+                    }
+                }
+            }
+
+            if wants(HitKind::Comment) {
+                let data = comments::load_comments(key, &task.id).unwrap_or_default();
+                for comment in data.comments {
+                    let title = match (&comment.file_path, comment.start_line) {
+                        (Some(path), Some(line)) => format!("{}:{}", path, line),
+                        (Some(path), None) => path.clone(),
+                        _ => format!("Review · {}", task.name),
+                    };
+                    let sections: Vec<Section> = std::iter::once(comment.content)
+                        .chain(comment.replies.into_iter().map(|r| r.content))
+                        .map(Section::new)
+                        .collect();
+                    if let Some((score, snippet)) = match_document(&terms, &title, &sections) {
+                        let mut hit = loc.hit(HitKind::Comment, title, score, snippet);
+                        hit.comment_id = Some(comment.id);
+                        hits.push(hit);
+                    }
+                }
+            }
+        }
+    }
+
+    // Only a full walk knows which chats still exist
+    if query.project_id.is_none() && wants(HitKind::Chat) {
+        prune_chat_index(&seen_chats);
+    }
+
+    hits.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(a.archived.cmp(&b.archived))
+            .then_with(|| a.title.cmp(&b.title))
+    });
+    let truncated = hits.len() > limit;
+    hits.truncate(limit);
+    SearchResults { hits, truncated }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sections(texts: &[&str]) -> Vec<Section> {
+        texts.iter().map(|t| Section::new(t.to_string())).collect()
+    }
+
+    #[test]
+    fn every_term_must_match() {
+        let terms = query_terms("Auth  refactor auth");
+        assert_eq!(terms, vec!["auth", "refactor"]);
+
+        let body = sections(&[
+            "Let's look at the session code first.",
+            "The AUTH middleware needs a refactor before we add sessions.",
+        ]);
+        let (score, snippet) = match_document(&terms, "Chat 1", &body).unwrap();
+        assert_eq!(score, 2);
+        assert_eq!(
+            snippet,
+            "The AUTH middleware needs a refactor before we add sessions."
+        );
+        assert!(match_document(&terms, "Chat 1", &body[..1]).is_none());
+        assert!(match_document(&[], "auth", &body).is_none());
+
+        // Title matches weigh more and need no body
+        let (score, snippet) = match_document(&terms, "Auth refactor", &[]).unwrap();
+        assert_eq!(score, 20);
+        assert!(snippet.is_empty());
+    }
+
+    #[test]
+    fn excerpt_is_trimmed_to_one_line() {
+        let text = format!("{}\nneedle here\n{}", "a ".repeat(100), "b ".repeat(100));
+        let pos = text.find("needle").unwrap();
+        let snippet = excerpt(&text, pos);
+        assert!(snippet.starts_with('…') && snippet.ends_with('…'));
+        assert!(snippet.contains("a needle here b"));
+        assert!(!snippet.contains('\n'));
+
+        // Multi-byte text keeps char boundaries
+        let text = "认证重构：把 auth 中间件拆开";
+        assert_eq!(excerpt(text, text.find("auth").unwrap()), text);
+    }
+}
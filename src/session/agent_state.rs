@@ -0,0 +1,211 @@
+//! 终端 agent 状态推断 — 扫描 tmux pane 输出，推断 Busy / Waiting / Idle
+//!
+//! 非 ACP 的 agent 无法主动上报状态，这里用启发式规则代替：
+//! 屏幕末尾命中等待输入的提示（或 bell）→ Waiting；命中工作中提示或
+//! 输出仍在变化 → Busy；否则 Idle。
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+
+use crate::storage::config::{self, AgentStatePatterns, StatusInferenceConfig};
+use crate::tmux;
+
+/// 抓取的屏幕行数
+const CAPTURE_LINES: usize = 40;
+/// 参与匹配的末尾非空行数
+const TAIL_LINES: usize = 12;
+/// 输出在此时间内变化过 → 视为 Busy
+const BUSY_WINDOW: Duration = Duration::from_secs(3);
+
+/// 推断出的 agent 状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AgentState {
+    /// 正在输出 / 执行
+    Busy,
+    /// 等待用户确认或输入
+    Waiting,
+    /// 空闲（本轮结束）
+    Idle,
+}
+
+impl AgentState {
+    /// 状态文字标签
+    pub fn label(&self) -> &'static str {
+        match self {
+            AgentState::Busy => "Busy",
+            AgentState::Waiting => "Waiting",
+            AgentState::Idle => "Idle",
+        }
+    }
+}
+
+/// 内置规则: (agent 名, waiting, busy)
+const BUILTIN_PATTERNS: &[(&str, &[&str], &[&str])] = &[
+    (
+        "claude",
+        &["do you want to", "would you like to", "❯ 1. yes"],
+        &["esc to interrupt"],
+    ),
+    (
+        "codex",
+        &["allow command?", "press enter to confirm", "approve"],
+        &["esc to interrupt"],
+    ),
+    (
+        "gemini",
+        &["waiting for user confirmation", "allow execution"],
+        &["esc to cancel"],
+    ),
+    ("aider", &["(y)es/(n)o", "[yes]:"], &[]),
+];
+
+/// 未识别 agent 的通用规则
+const GENERIC_WAITING: &[&str] = &["(y/n)", "[y/n]", "press enter", "do you want to"];
+const GENERIC_BUSY: &[&str] = &["esc to interrupt"];
+
+/// pane 上一次采样: (内容 hash, 最近一次变化时间)
+type Sample = (u64, Option<Instant>);
+
+static SAMPLES: Lazy<Mutex<HashMap<String, Sample>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 从 agent 命令中取出 agent 名（首个 token 的文件名）
+pub fn agent_name(command: &str) -> String {
+    let program = command.split_whitespace().next().unwrap_or("");
+    program
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(program)
+        .to_lowercase()
+}
+
+/// 解析 agent 的匹配规则：用户配置优先，其次内置，最后通用
+pub fn patterns_for(agent: &str, cfg: &StatusInferenceConfig) -> AgentStatePatterns {
+    if let Some(custom) = cfg.agents.get(agent) {
+        return custom.clone();
+    }
+    let to_vec = |list: &[&str]| list.iter().map(|s| s.to_string()).collect();
+    match BUILTIN_PATTERNS.iter().find(|(name, _, _)| *name == agent) {
+        Some((_, waiting, busy)) => AgentStatePatterns {
+            waiting: to_vec(waiting),
+            busy: to_vec(busy),
+        },
+        None => AgentStatePatterns {
+            waiting: to_vec(GENERIC_WAITING),
+            busy: to_vec(GENERIC_BUSY),
+        },
+    }
+}
+
+/// 根据屏幕内容推断状态（纯函数）
+pub fn infer(
+    screen: &str,
+    patterns: &AgentStatePatterns,
+    changed_recently: bool,
+    bell: bool,
+) -> AgentState {
+    let lines: Vec<&str> = screen.lines().filter(|l| !l.trim().is_empty()).collect();
+    let tail = lines[lines.len().saturating_sub(TAIL_LINES)..]
+        .join("\n")
+        .to_lowercase();
+    let matches = |list: &[String]| {
+        list.iter()
+            .any(|p| !p.is_empty() && tail.contains(&p.to_lowercase()))
+    };
+
+    if bell || matches(&patterns.waiting) {
+        AgentState::Waiting
+    } else if changed_recently || matches(&patterns.busy) {
+        AgentState::Busy
+    } else {
+        AgentState::Idle
+    }
+}
+
+/// 记录一次采样，返回输出是否在 BUSY_WINDOW 内变化过
+fn note_sample(key: &str, screen: &str) -> bool {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    screen.hash(&mut hasher);
+    let hash = hasher.finish();
+    let now = Instant::now();
+
+    let mut samples = SAMPLES.lock().unwrap_or_else(|e| e.into_inner());
+    let entry = samples.entry(key.to_string()).or_insert((hash, None));
+    if entry.0 != hash {
+        *entry = (hash, Some(now));
+    }
+    entry
+        .1
+        .is_some_and(|changed| now.duration_since(changed) < BUSY_WINDOW)
+}
+
+/// 推断 tmux session 中 agent 的状态（session 不存在或关闭推断时返回 None）
+pub fn infer_tmux_state(session: &str) -> Option<AgentState> {
+    let cfg = config::load_config();
+    if !cfg.status_inference.enabled {
+        return None;
+    }
+    // 内置布局中第一个 pane 是 agent
+    let pane = tmux::layout::list_pane_ids(session)
+        .ok()?
+        .into_iter()
+        .next()?;
+    let screen = tmux::capture_pane(&pane, CAPTURE_LINES, false).ok()?;
+
+    let agent = cfg
+        .layout
+        .agent_command
+        .as_deref()
+        .map(agent_name)
+        .unwrap_or_default();
+    let patterns = patterns_for(&agent, &cfg.status_inference);
+    let changed = note_sample(&pane, &screen);
+    let bell = tmux::window_bell(session);
+    Some(infer(&screen, &patterns, changed, bell))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infer_detects_prompt_busy_and_idle() {
+        let cfg = StatusInferenceConfig::default();
+        let claude = patterns_for("claude", &cfg);
+
+        let prompt = "Edit src/main.rs\nDo you want to make this edit?\n❯ 1. Yes\n  2. No\n";
+        assert_eq!(infer(prompt, &claude, false, false), AgentState::Waiting);
+
+        let working = "✻ Thinking… (12s · esc to interrupt)\n";
+        assert_eq!(infer(working, &claude, false, false), AgentState::Busy);
+
+        let done = "Done.\n> \n";
+        assert_eq!(infer(done, &claude, false, false), AgentState::Idle);
+        assert_eq!(infer(done, &claude, true, false), AgentState::Busy);
+        assert_eq!(infer(done, &claude, false, true), AgentState::Waiting);
+    }
+
+    #[test]
+    fn patterns_prefer_user_config() {
+        let mut cfg = StatusInferenceConfig::default();
+        cfg.agents.insert(
+            "claude".to_string(),
+            AgentStatePatterns {
+                waiting: vec!["READY?".to_string()],
+                busy: Vec::new(),
+            },
+        );
+        let patterns = patterns_for("claude", &cfg);
+        assert_eq!(
+            infer("are you ready?", &patterns, false, false),
+            AgentState::Waiting
+        );
+        assert_eq!(agent_name("/usr/local/bin/Claude --resume"), "claude");
+        assert_eq!(patterns_for("unknown", &cfg).waiting, GENERIC_WAITING);
+    }
+}
@@ -0,0 +1,88 @@
+//! Session attach 记录 — 每次 attach 到任务 session 的起止时间（TUI attach 与 Web 终端），
+//! 追加写入 tasks/<task-id>/attach.jsonl，用于统计任务耗时
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::storage::ensure_task_data_dir;
+
+/// 一次 attach 的时间段
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachInterval {
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub start: DateTime<Utc>,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub end: DateTime<Utc>,
+}
+
+/// attach.jsonl 路径: tasks/<task-id>/attach.jsonl
+fn attach_file_path(project_key: &str, task_id: &str) -> Result<PathBuf> {
+    Ok(ensure_task_data_dir(project_key, task_id)?.join("attach.jsonl"))
+}
+
+/// 记录一次 attach（end 不晚于 start 时忽略）
+pub fn record_attach(
+    project_key: &str,
+    task_id: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<()> {
+    if end <= start {
+        return Ok(());
+    }
+    let path = attach_file_path(project_key, task_id)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    let json = serde_json::to_string(&AttachInterval { start, end })?;
+    writeln!(file, "{}", json)?;
+    Ok(())
+}
+
+/// 读取任务的全部 attach 记录（文件不存在返回空）
+pub fn load_attach_intervals(project_key: &str, task_id: &str) -> Result<Vec<AttachInterval>> {
+    let path = attach_file_path(project_key, task_id)?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let reader = BufReader::new(File::open(&path)?);
+    let mut intervals = Vec::new();
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Ok(interval) = serde_json::from_str::<AttachInterval>(&line) {
+            intervals.push(interval);
+        }
+    }
+    Ok(intervals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_load_attach() {
+        let dir = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(dir.path().to_path_buf()));
+
+        let start = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let end = DateTime::from_timestamp(1_700_000_600, 0).unwrap();
+        record_attach("proj", "task", start, end).unwrap();
+        // 空区间不记录
+        record_attach("proj", "task", end, end).unwrap();
+
+        let intervals = load_attach_intervals("proj", "task").unwrap();
+        assert_eq!(intervals.len(), 1);
+        assert_eq!(intervals[0].start, start);
+        assert_eq!(intervals[0].end, end);
+        assert!(load_attach_intervals("proj", "other").unwrap().is_empty());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+}
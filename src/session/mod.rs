@@ -1,11 +1,14 @@
 //! 统一 session 调度层 — 根据 SessionType 分发到 tmux、zellij 或 acp
 
+pub mod agent_state;
+pub mod attach_log;
+
 use std::process::Command;
 use std::str::FromStr;
 
 use once_cell::sync::Lazy;
 
-use crate::error::Result;
+use crate::error::{GroveError, Result};
 use crate::tmux::{self, SessionEnv};
 use crate::zellij;
 
@@ -230,6 +233,82 @@ pub fn kill_session(mux: &SessionType, name: &str) -> Result<()> {
     }
 }
 
+/// 发送到 session 的输入
+#[derive(Debug, Clone, Default)]
+pub struct SessionInput {
+    /// 按字面发送的文本
+    pub text: Option<String>,
+    /// 具名按键（tmux 键名：`Enter` / `Escape` / `C-c` / `Up` …），在 text 之后发送
+    pub keys: Vec<String>,
+    /// 最后是否回车
+    pub enter: bool,
+}
+
+impl SessionInput {
+    pub fn is_empty(&self) -> bool {
+        self.text.as_deref().is_none_or(str::is_empty) && self.keys.is_empty() && !self.enter
+    }
+}
+
+/// 向 session 发送按键
+/// tmux: send-keys 到指定下标的 pane（默认第一个，内置布局中为 agent pane）
+/// zellij: action write-chars / write 到当前聚焦的 pane（忽略 pane）
+/// acp: 不支持，应改用 chat prompt
+pub fn send_input(
+    mux: &SessionType,
+    name: &str,
+    pane: Option<usize>,
+    input: &SessionInput,
+) -> Result<()> {
+    if input.is_empty() {
+        return Err(GroveError::session("Nothing to send"));
+    }
+    let text = input.text.as_deref().filter(|t| !t.is_empty());
+    let enter = input.enter.then_some("Enter");
+    let keys = input.keys.iter().map(String::as_str).chain(enter);
+
+    match mux {
+        SessionType::Tmux => {
+            // 先校验全部键名，避免文本已发出、后面的键才报错
+            if let Some(bad) = keys.clone().find(|k| !tmux::is_key_name(k)) {
+                return Err(GroveError::session(format!("Unsupported key: {}", bad)));
+            }
+            let panes = tmux::layout::list_pane_ids(name)?;
+            let target = panes
+                .get(pane.unwrap_or(0))
+                .ok_or_else(|| GroveError::session("Pane not found"))?;
+            if let Some(text) = text {
+                tmux::send_literal(target, text)?;
+            }
+            for key in keys {
+                tmux::send_key(target, key)?;
+            }
+            Ok(())
+        }
+        SessionType::Zellij => {
+            if let Some(text) = text {
+                zellij::write_chars(name, text)?;
+            }
+            for key in keys {
+                zellij::write_key(name, key)?;
+            }
+            Ok(())
+        }
+        SessionType::Acp => Err(GroveError::session(
+            "ACP tasks have no terminal session; send a chat prompt instead",
+        )),
+    }
+}
+
+/// 在终端中手动 attach 该 session 的命令（ACP 没有终端 session，返回 None）
+pub fn attach_command(session_type: &SessionType, session: &str) -> Option<String> {
+    match session_type {
+        SessionType::Tmux => Some(format!("tmux attach -t {}", session)),
+        SessionType::Zellij => Some(format!("zellij attach {}", session)),
+        SessionType::Acp => None,
+    }
+}
+
 /// 从 task 记录的 multiplexer 字符串解析为 SessionType 枚举
 /// 如果 task 记录为空或未知值，默认返回 Tmux
 pub fn resolve_session_type(task_mux: &str) -> SessionType {
@@ -240,6 +319,19 @@ pub fn resolve_session_type(task_mux: &str) -> SessionType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_attach_command() {
+        assert_eq!(
+            attach_command(&SessionType::Tmux, "grove-p-t").as_deref(),
+            Some("tmux attach -t grove-p-t")
+        );
+        assert_eq!(
+            attach_command(&SessionType::Zellij, "grove-p-t").as_deref(),
+            Some("zellij attach grove-p-t")
+        );
+        assert_eq!(attach_command(&SessionType::Acp, "grove-p-t"), None);
+    }
+
     #[test]
     fn test_session_name_short() {
         let max = *MAX_SESSION_NAME_LEN;
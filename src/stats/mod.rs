@@ -320,8 +320,8 @@ fn log_negative_duration_once() {
     if LOGGED.swap(true, Ordering::Relaxed) {
         return;
     }
-    eprintln!(
-        "[stats] chat_token_usage contains rows with end_ts < start_ts; \
+    tracing::warn!(
+        "chat_token_usage contains rows with end_ts < start_ts; \
          clamping to 0 (likely clock skew / NTP correction)"
     );
 }
@@ -534,7 +534,7 @@ pub fn gc_orphans(conn: &Connection) -> Result<GcStats> {
         .query_row("SELECT COUNT(*) FROM session", [], |r| r.get(0))
         .unwrap_or(0);
     if task_count == 0 && session_count > 0 {
-        eprintln!(
+        tracing::warn!(
             "[gc_orphans] refusing to run: tasks table is empty but {} session row(s) exist; \
              likely a missed/incomplete migration",
             session_count
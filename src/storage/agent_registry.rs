@@ -537,7 +537,7 @@ pub async fn refresh_if_stale() {
         return;
     }
     if let Err(e) = refresh().await {
-        eprintln!("[agent_registry] background refresh failed: {}", e);
+        tracing::warn!("background refresh failed: {}", e);
     }
 }
 
@@ -0,0 +1,142 @@
+//! 任务元数据的存储接口：任务、笔记与 review comments
+//!
+//! 默认后端是本机（[`Local`]，即 tasks / notes / comments 模块）。Organization
+//! 模式下同一套接口还有两个实现：团队服务器上按成员隔离的
+//! [`MemberStore`](super::org::MemberStore)，以及客户端通过 HTTP 访问它的
+//! `operations::org::HttpStore`。
+
+use std::collections::HashSet;
+
+use super::comments::{self, Comment};
+use super::notes::{self, NoteSource};
+use super::tasks::{self, Task};
+use crate::error::Result;
+
+/// 活跃任务
+pub trait TaskStore {
+    /// 项目下的活跃任务
+    fn load_tasks(&self, project: &str) -> Result<Vec<Task>>;
+
+    /// 用 `tasks` 整体替换项目下的活跃任务（不在其中的被移除）
+    fn replace_tasks(&self, project: &str, tasks: &[Task]) -> Result<()>;
+}
+
+/// 任务笔记
+pub trait NoteStore {
+    /// 任务笔记，没有时为空
+    fn load_notes(&self, project: &str, task_id: &str) -> Result<String>;
+
+    fn save_notes(&self, project: &str, task_id: &str, content: &str) -> Result<()>;
+}
+
+/// 已发布的 review comments
+pub trait CommentStore {
+    fn load_comments(&self, project: &str, task_id: &str) -> Result<Vec<Comment>>;
+
+    /// 整体替换任务的 comments
+    fn replace_comments(&self, project: &str, task_id: &str, comments: &[Comment]) -> Result<()>;
+}
+
+/// 本机存储
+pub struct Local;
+
+impl TaskStore for Local {
+    fn load_tasks(&self, project: &str) -> Result<Vec<Task>> {
+        Ok(tasks::load_tasks(project)?
+            .into_iter()
+            .filter(|t| !t.is_local)
+            .collect())
+    }
+
+    /// 已有任务只更新元数据（保留 worktree、session 等本机字段）
+    fn replace_tasks(&self, project: &str, list: &[Task]) -> Result<()> {
+        let keep: HashSet<&str> = list.iter().map(|t| t.id.as_str()).collect();
+        for task in self.load_tasks(project)? {
+            if !keep.contains(task.id.as_str()) {
+                tasks::remove_task(project, &task.id)?;
+            }
+        }
+        for task in list {
+            let exists = tasks::get_task(project, &task.id)?.is_some()
+                || tasks::get_archived_task(project, &task.id)?.is_some();
+            if exists {
+                tasks::update_task_metadata(project, task)?;
+            } else {
+                tasks::add_task(project, task.clone())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl NoteStore for Local {
+    fn load_notes(&self, project: &str, task_id: &str) -> Result<String> {
+        notes::load_notes(project, task_id)
+    }
+
+    fn save_notes(&self, project: &str, task_id: &str, content: &str) -> Result<()> {
+        notes::save_notes(project, task_id, content, NoteSource::Sync)
+    }
+}
+
+impl CommentStore for Local {
+    fn load_comments(&self, project: &str, task_id: &str) -> Result<Vec<Comment>> {
+        Ok(comments::load_comments(project, task_id)?.comments)
+    }
+
+    fn replace_comments(&self, project: &str, task_id: &str, list: &[Comment]) -> Result<()> {
+        comments::replace_comments(project, task_id, list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, worktree_path: &str, is_local: bool) -> Task {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "branch": format!("grove/{id}"),
+            "target": "main",
+            "worktree_path": worktree_path,
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+            "status": "active",
+            "is_local": is_local,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_local_replace_tasks_keeps_machine_fields_and_local_task() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        tasks::add_task("p", task("_local", "/repo", true)).unwrap();
+        tasks::add_task("p", task("a", "/wt/a", false)).unwrap();
+        tasks::add_task("p", task("b", "/wt/b", false)).unwrap();
+
+        let mut renamed = task("a", "", false);
+        renamed.name = "Renamed".to_string();
+        Local
+            .replace_tasks("p", &[renamed, task("c", "/wt/c", false)])
+            .unwrap();
+
+        let mut ids: Vec<String> = tasks::load_tasks("p")
+            .unwrap()
+            .into_iter()
+            .map(|t| t.id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, ["_local", "a", "c"]);
+        let a = tasks::get_task("p", "a").unwrap().unwrap();
+        assert_eq!(
+            (a.name.as_str(), a.worktree_path.as_str()),
+            ("Renamed", "/wt/a")
+        );
+
+        crate::storage::set_grove_dir_override(None);
+    }
+}
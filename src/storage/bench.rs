@@ -0,0 +1,177 @@
+//! 基准测试对比
+//!
+//! 项目设置: ~/.grove/projects/{project}/bench.toml（bench 命令、输出格式、回归阈值）
+//! 任务结果: ~/.grove/projects/{project}/tasks/{task_id}/bench.json（最近一次对比）
+
+use serde::{Deserialize, Serialize};
+
+use super::{ensure_task_data_dir, project_settings};
+use crate::error::{GroveError, Result};
+
+const RESULT_FILE: &str = "bench.json";
+
+/// bench 命令的输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchFormat {
+    /// 依次尝试 hyperfine JSON、criterion、hyperfine 文本
+    #[default]
+    Auto,
+    Criterion,
+    Hyperfine,
+}
+
+/// 项目级 bench 设置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BenchSettings {
+    /// 在 worktree 根目录通过 shell 执行的命令（为空表示未配置）
+    #[serde(default)]
+    pub command: String,
+    #[serde(default)]
+    pub format: BenchFormat,
+    /// 变慢超过该百分比记为回归（变快超过记为提升）
+    #[serde(default = "default_threshold")]
+    pub threshold_percent: f64,
+    /// 单次运行的超时时间（秒）
+    #[serde(default = "default_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_threshold() -> f64 {
+    5.0
+}
+
+fn default_timeout() -> u64 {
+    600
+}
+
+impl Default for BenchSettings {
+    fn default() -> Self {
+        Self {
+            command: String::new(),
+            format: BenchFormat::Auto,
+            threshold_percent: default_threshold(),
+            timeout_secs: default_timeout(),
+        }
+    }
+}
+
+impl BenchSettings {
+    pub fn is_configured(&self) -> bool {
+        !self.command.trim().is_empty()
+    }
+}
+
+const SETTINGS_FILE: &str = "bench.toml";
+
+/// 读取 bench 设置（文件不存在返回默认值）
+pub fn load_settings(project: &str) -> BenchSettings {
+    project_settings::load(project, SETTINGS_FILE)
+}
+
+/// 保存 bench 设置
+pub fn save_settings(project: &str, settings: &BenchSettings) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, settings)
+}
+
+/// 运行状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchRunStatus {
+    Running,
+    Done,
+    Failed,
+}
+
+/// 单个 benchmark 的对比结论
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchVerdict {
+    Regression,
+    Improvement,
+    Unchanged,
+    /// 只在任务 HEAD 上出现
+    Added,
+    /// 只在 target 上出现
+    Removed,
+}
+
+/// 单个 benchmark 在 target / 任务 HEAD 上的耗时（纳秒）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchComparison {
+    pub name: String,
+    pub base_ns: Option<f64>,
+    pub head_ns: Option<f64>,
+    /// (head - base) / base * 100，正数表示变慢
+    pub change_percent: Option<f64>,
+    pub verdict: BenchVerdict,
+}
+
+/// 一次 target vs 任务 HEAD 的对比
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchRun {
+    pub status: BenchRunStatus,
+    /// 开始 / 结束时间（RFC 3339）
+    pub started_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+    pub command: String,
+    pub threshold_percent: f64,
+    /// target 分支名和对比时的 commit
+    pub base_ref: String,
+    #[serde(default)]
+    pub base_commit: String,
+    /// 任务 HEAD commit（未提交的改动不参与对比）
+    #[serde(default)]
+    pub head_commit: String,
+    #[serde(default)]
+    pub results: Vec<BenchComparison>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BenchRun {
+    pub fn regressions(&self) -> impl Iterator<Item = &BenchComparison> {
+        self.results
+            .iter()
+            .filter(|r| r.verdict == BenchVerdict::Regression)
+    }
+
+    /// 回归摘要，如 `2 benchmark regressions vs main: parse (+12.3%), fib (+6.0%)`；
+    /// 未完成或没有回归时返回 None
+    pub fn regression_summary(&self) -> Option<String> {
+        if self.status != BenchRunStatus::Done {
+            return None;
+        }
+        let items: Vec<String> = self
+            .regressions()
+            .map(|r| format!("{} (+{:.1}%)", r.name, r.change_percent.unwrap_or_default()))
+            .collect();
+        (!items.is_empty()).then(|| {
+            format!(
+                "{} benchmark regression{} vs {}: {}",
+                items.len(),
+                if items.len() == 1 { "" } else { "s" },
+                self.base_ref,
+                items.join(", ")
+            )
+        })
+    }
+}
+
+/// 读取任务最近一次 bench 对比
+pub fn load_run(project: &str, task_id: &str) -> Option<BenchRun> {
+    ensure_task_data_dir(project, task_id)
+        .ok()
+        .and_then(|dir| std::fs::read_to_string(dir.join(RESULT_FILE)).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+/// 保存任务的 bench 对比（覆盖旧结果）
+pub fn save_run(project: &str, task_id: &str, run: &BenchRun) -> Result<()> {
+    let path = ensure_task_data_dir(project, task_id)?.join(RESULT_FILE);
+    let content =
+        serde_json::to_string_pretty(run).map_err(|e| GroveError::storage(e.to_string()))?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
@@ -0,0 +1,171 @@
+//! 项目级 chat 默认 mode / model: ~/.grove/projects/{project}/chat_defaults.toml
+//!
+//! 按 agent id 记录最近一次使用的 mode / model（每个 turn 开始时自动更新），
+//! 以及在项目设置中固定（pin）的 mode / model。新 chat 的 session 建好后
+//! 自动应用：固定值优先，其次是最近使用的值。
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::project_settings;
+use crate::error::Result;
+
+/// 单个 agent 的默认 mode / model
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentChatDefaults {
+    /// 最近一次 turn 使用的 mode / model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_model: Option<String>,
+    /// 项目设置中固定的 mode / model（优先于最近使用的值）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pinned_model: Option<String>,
+}
+
+impl AgentChatDefaults {
+    /// 新 chat 应用的 mode
+    pub fn mode(&self) -> Option<&str> {
+        self.pinned_mode.as_deref().or(self.last_mode.as_deref())
+    }
+
+    /// 新 chat 应用的 model
+    pub fn model(&self) -> Option<&str> {
+        self.pinned_model.as_deref().or(self.last_model.as_deref())
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// 项目内所有 agent 的默认值
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatDefaults {
+    /// agent id → 默认值
+    #[serde(default)]
+    pub agents: BTreeMap<String, AgentChatDefaults>,
+}
+
+impl ChatDefaults {
+    /// 记录 agent 最近使用的 mode / model（None 表示 agent 未上报，保留旧值）；
+    /// 返回是否有变化
+    pub fn remember(&mut self, agent: &str, mode: Option<&str>, model: Option<&str>) -> bool {
+        if mode.is_none() && model.is_none() {
+            return false;
+        }
+        let entry = self.agents.entry(agent.to_string()).or_default();
+        let before = entry.clone();
+        if let Some(mode) = mode {
+            entry.last_mode = Some(mode.to_string());
+        }
+        if let Some(model) = model {
+            entry.last_model = Some(model.to_string());
+        }
+        *entry != before
+    }
+
+    /// 去掉空白值和空的 agent 条目
+    pub fn normalize(&mut self) {
+        fn clean(value: &mut Option<String>) {
+            *value = value
+                .take()
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty());
+        }
+        self.agents = std::mem::take(&mut self.agents)
+            .into_iter()
+            .map(|(agent, mut d)| {
+                clean(&mut d.last_mode);
+                clean(&mut d.last_model);
+                clean(&mut d.pinned_mode);
+                clean(&mut d.pinned_model);
+                (agent.trim().to_string(), d)
+            })
+            .filter(|(agent, d)| !agent.is_empty() && !d.is_empty())
+            .collect();
+    }
+}
+
+const SETTINGS_FILE: &str = "chat_defaults.toml";
+
+/// 读取项目的 chat 默认值（文件不存在返回空）
+pub fn load_settings(project: &str) -> ChatDefaults {
+    project_settings::load(project, SETTINGS_FILE)
+}
+
+/// 保存项目的 chat 默认值
+pub fn save_settings(project: &str, defaults: &ChatDefaults) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, defaults)
+}
+
+/// 单个 agent 的默认值
+pub fn for_agent(project: &str, agent: &str) -> AgentChatDefaults {
+    load_settings(project)
+        .agents
+        .remove(agent)
+        .unwrap_or_default()
+}
+
+/// 记录 agent 最近使用的 mode / model（无变化时不写文件）
+pub fn remember(project: &str, agent: &str, mode: Option<&str>, model: Option<&str>) -> Result<()> {
+    let mut defaults = load_settings(project);
+    if defaults.remember(agent, mode, model) {
+        save_settings(project, &defaults)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pinned_values_win_over_last_used() {
+        let mut defaults = ChatDefaults::default();
+        assert!(defaults.remember("claude", Some("plan"), Some("sonnet")));
+        assert!(!defaults.remember("claude", Some("plan"), None));
+        assert!(!defaults.remember("claude", None, None));
+
+        let entry = defaults.agents.get_mut("claude").unwrap();
+        assert_eq!(entry.mode(), Some("plan"));
+        entry.pinned_model = Some("opus".to_string());
+        assert_eq!(entry.model(), Some("opus"));
+
+        assert!(defaults.remember("claude", None, Some("haiku")));
+        let entry = &defaults.agents["claude"];
+        assert_eq!(entry.last_model.as_deref(), Some("haiku"));
+        assert_eq!(entry.model(), Some("opus"));
+    }
+
+    #[test]
+    fn normalize_drops_blank_values() {
+        let mut defaults = ChatDefaults {
+            agents: BTreeMap::from([
+                (
+                    "claude".to_string(),
+                    AgentChatDefaults {
+                        pinned_mode: Some(" plan ".to_string()),
+                        pinned_model: Some("  ".to_string()),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "codex".to_string(),
+                    AgentChatDefaults {
+                        pinned_mode: Some(String::new()),
+                        ..Default::default()
+                    },
+                ),
+            ]),
+        };
+        defaults.normalize();
+        assert_eq!(defaults.agents.len(), 1);
+        let entry = &defaults.agents["claude"];
+        assert_eq!(entry.pinned_mode.as_deref(), Some("plan"));
+        assert_eq!(entry.pinned_model, None);
+    }
+}
@@ -5,12 +5,17 @@
 //!
 //! 每条可持久化事件在 emit 时直接 append 到磁盘，避免 agent 中途断开丢失数据。
 //! - `Busy`、`Error`、`SessionEnded`、`SessionReady`、`AvailableCommands`、`QueueUpdate` 不持久化
+//!
+//! 每行带一个单调递增的 `seq`，WS 重连时客户端带上已收到的最后一个 seq，
+//! 只回放之后的事件（见 `replay_since`）。旧版本写入的行没有 seq，读取时按
+//! 前一行 +1 隐式编号。
 
 use std::fs;
-use std::io::{BufRead, Seek, SeekFrom, Write};
-use std::path::PathBuf;
+use std::io::{BufRead, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
 use crate::acp::AcpUpdate;
 
@@ -20,9 +25,116 @@ const MAX_TOOL_CONTENT_BYTES: usize = 32 * 1024;
 /// `load_history` 只读文件尾部这么多字节，避免超大 history.jsonl 把内存打爆。
 const MAX_HISTORY_READ_BYTES: u64 = 50 * 1024 * 1024;
 
+/// `last_message` 只回看文件尾部这么多字节。
+const LAST_MESSAGE_READ_BYTES: u64 = 64 * 1024;
+
 /// 截断标记，附加在被截断的 content 末尾。
 const TRUNCATED_MARKER: &str = "\n...[truncated]";
 
+/// 单次按 seq 回放的最大事件数，超过时让客户端整体重新加载
+pub const MAX_REPLAY_EVENTS: usize = 2000;
+
+/// history.jsonl 中的一行：事件 + 持久化序号
+#[derive(Serialize, Deserialize)]
+struct StoredLine<E> {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seq: Option<u64>,
+    #[serde(flatten)]
+    event: E,
+}
+
+/// 只用来读 seq 的行视图（忽略事件本身）
+#[derive(Deserialize)]
+struct SeqOnly {
+    #[serde(default)]
+    seq: Option<u64>,
+}
+
+/// history 文件的独占锁（OS 文件锁，跨进程生效）。
+///
+/// 锁加在同目录的 `history.lock` 上而不是 history.jsonl 本身：compact 会用
+/// rename 原子替换 history.jsonl，锁旧 inode 挡不住之后打开新文件的写者。
+/// 锁文件内容是 `clear_history` 时的 seq 水位，清空后继续往上编号。
+struct HistoryLock {
+    file: fs::File,
+}
+
+impl HistoryLock {
+    fn acquire(history: &Path) -> std::io::Result<Self> {
+        if let Some(parent) = history.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(history.with_extension("lock"))?;
+        file.lock()?;
+        Ok(Self { file })
+    }
+
+    /// `clear_history` 留下的 seq 水位
+    fn seq_floor(&mut self) -> u64 {
+        let mut content = String::new();
+        let _ = self.file.seek(SeekFrom::Start(0));
+        let _ = self.file.read_to_string(&mut content);
+        content.trim().parse().unwrap_or(0)
+    }
+
+    fn set_seq_floor(&mut self, seq: u64) -> std::io::Result<()> {
+        self.file.set_len(0)?;
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(seq.to_string().as_bytes())
+    }
+
+    /// 下一条事件之前的最后一个 seq：文件里最后一行的 seq 与清空水位取大者
+    fn last_seq(&mut self, history: &Path) -> u64 {
+        last_seq_in(history).max(self.seq_floor())
+    }
+}
+
+impl Drop for HistoryLock {
+    fn drop(&mut self) {
+        let _ = self.file.unlock();
+    }
+}
+
+/// 从文件尾部读出最后一个 seq。尾部找不到显式 seq（旧版本写入的行）时
+/// 退化为完整加载，按隐式编号计算。
+fn last_seq_in(path: &Path) -> u64 {
+    let mut file = match fs::File::open(path) {
+        Ok(f) => f,
+        Err(_) => return 0,
+    };
+    let file_len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let start = file_len.saturating_sub(LAST_MESSAGE_READ_BYTES);
+    let mut tail = Vec::new();
+    if file.seek(SeekFrom::Start(start)).is_err() || file.read_to_end(&mut tail).is_err() {
+        return 0;
+    }
+    let mut lines: Vec<&[u8]> = tail.split(|b| *b == b'\n').collect();
+    if start > 0 && !lines.is_empty() {
+        // 第一行可能是被 seek 切断的残缺行
+        lines.remove(0);
+    }
+    for line in lines.iter().rev() {
+        if line.iter().all(u8::is_ascii_whitespace) {
+            continue;
+        }
+        let seq = serde_json::Deserializer::from_slice(line)
+            .into_iter::<SeqOnly>()
+            .map_while(Result::ok)
+            .last()
+            .and_then(|l| l.seq);
+        if let Some(seq) = seq {
+            return seq;
+        }
+        break;
+    }
+    load_sequenced_from(path).last().map_or(0, |(seq, _)| *seq)
+}
+
 /// 对 `ToolCallUpdate.content` 做硬截断：超过 `MAX_TOOL_CONTENT_BYTES` 的尾部
 /// 直接砍掉，附加 `TRUNCATED_MARKER`。在 UTF-8 char 边界截断。
 fn truncate_tool_content(content: &mut Option<String>) {
@@ -65,6 +177,69 @@ pub fn history_file_path(project: &str, task_id: &str, chat_id: &str) -> PathBuf
         .join("history.jsonl")
 }
 
+/// 最近一条消息（用户或 agent）的文本；只读文件尾部，供 Monitor 预览使用
+pub fn last_message(project: &str, task_id: &str, chat_id: &str) -> Option<String> {
+    read_last_message(&history_file_path(project, task_id, chat_id))
+}
+
+fn read_last_message(path: &std::path::Path) -> Option<String> {
+    let mut file = fs::File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    let start = len.saturating_sub(LAST_MESSAGE_READ_BYTES);
+    file.seek(SeekFrom::Start(start)).ok()?;
+
+    let mut reader = std::io::BufReader::new(file);
+    let mut line = Vec::new();
+    if start > 0 {
+        // 丢弃残缺的第一行
+        let _ = reader.read_until(b'\n', &mut line);
+    }
+    let mut events = Vec::new();
+    loop {
+        line.clear();
+        match reader.read_until(b'\n', &mut line) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {
+                if let Ok(event) = serde_json::from_slice::<AcpUpdate>(&line) {
+                    events.push(event);
+                }
+            }
+        }
+    }
+
+    compact_events(events)
+        .into_iter()
+        .rev()
+        .find_map(|event| match event {
+            AcpUpdate::MessageChunk { text } | AcpUpdate::UserMessage { text, .. } => Some(text),
+            _ => None,
+        })
+}
+
+/// task 下所有 chat 的 history.jsonl 中最近一次写入时间
+pub fn last_write_at(project: &str, task_id: &str) -> Option<DateTime<Utc>> {
+    let chats_dir = super::grove_dir()
+        .join("projects")
+        .join(project)
+        .join("tasks")
+        .join(task_id)
+        .join("chats");
+    fs::read_dir(chats_dir)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            entry
+                .path()
+                .join("history.jsonl")
+                .metadata()
+                .ok()?
+                .modified()
+                .ok()
+        })
+        .max()
+        .map(DateTime::<Utc>::from)
+}
+
 /// 判断事件是否应该持久化
 pub fn should_persist(update: &AcpUpdate) -> bool {
     !matches!(
@@ -84,41 +259,79 @@ pub fn should_persist(update: &AcpUpdate) -> bool {
     )
 }
 
-/// 实时追加单条事件到 history.jsonl
+/// 实时追加单条事件到 history.jsonl，返回分配给它的 seq（写入失败为 None）
 ///
 /// 把 JSON 和换行拼成一个 buffer 后一次 `write_all` 写出，利用 `O_APPEND`
 /// 单次 write 的原子落尾保证：并发 emit 时不会出现 `{a}{b}\n\n` 这种
 /// 两个事件挤同一行的情况（历史上 `writeln!` 会拆成 json+`\n` 两次
 /// write，两次之间会被别的线程插入）。
-pub fn append_event(project: &str, task_id: &str, chat_id: &str, event: &AcpUpdate) {
+pub fn append_event(project: &str, task_id: &str, chat_id: &str, event: &AcpUpdate) -> Option<u64> {
     let path = history_file_path(project, task_id, chat_id);
-    if let Some(parent) = path.parent() {
-        let _ = fs::create_dir_all(parent);
+    if needs_truncation(event) {
+        let mut owned = event.clone();
+        truncate_update_in_place(&mut owned);
+        append_events(&path, std::slice::from_ref(&owned))
+    } else {
+        append_events(&path, std::slice::from_ref(event))
     }
+}
 
-    match fs::OpenOptions::new().create(true).append(true).open(&path) {
-        Ok(mut f) => {
-            if needs_truncation(event) {
-                let mut owned = event.clone();
-                truncate_update_in_place(&mut owned);
-                append_json_line(&mut f, &owned);
-            } else {
-                append_json_line(&mut f, event);
-            }
+/// 批量 append 事件（导入外部 session 时使用），返回最后一条的 seq
+pub fn append_all(
+    project: &str,
+    task_id: &str,
+    chat_id: &str,
+    events: &[AcpUpdate],
+) -> Option<u64> {
+    let mut events = events.to_vec();
+    events.iter_mut().for_each(truncate_update_in_place);
+    append_events(&history_file_path(project, task_id, chat_id), &events)
+}
+
+/// 按顺序 append 多条事件并分配连续的 seq，返回最后一条的 seq。
+///
+/// 在文件锁内从文件本身推出下一个 seq，多个进程写同一个 chat 时也不会重号。
+fn append_events(path: &Path, events: &[AcpUpdate]) -> Option<u64> {
+    let mut lock = match HistoryLock::acquire(path) {
+        Ok(lock) => lock,
+        Err(e) => {
+            tracing::warn!("Failed to lock {}: {}", path.display(), e);
+            return None;
         }
+    };
+    let mut f = match fs::OpenOptions::new().create(true).append(true).open(path) {
+        Ok(f) => f,
         Err(e) => {
-            eprintln!("[chat_history] Failed to open {}: {}", path.display(), e);
+            tracing::warn!("Failed to open {}: {}", path.display(), e);
+            return None;
+        }
+    };
+
+    let mut seq = lock.last_seq(path);
+    let mut written = None;
+    for event in events {
+        seq += 1;
+        if append_json_line(&mut f, seq, event) {
+            written = Some(seq);
         }
     }
+    written
 }
 
 /// 原子 append 一条 JSONL 事件：先把 `json + \n` 拼成单个 buffer，再一次
 /// `write_all` 落盘。`O_APPEND` 保证单次 write 原子写到文件尾部，并发 append
 /// 不会让两条 JSON 撞在同一行。
-fn append_json_line(f: &mut fs::File, event: &AcpUpdate) {
-    if let Ok(mut json) = serde_json::to_string(event) {
-        json.push('\n');
-        let _ = f.write_all(json.as_bytes());
+fn append_json_line(f: &mut fs::File, seq: u64, event: &AcpUpdate) -> bool {
+    let line = StoredLine {
+        seq: Some(seq),
+        event,
+    };
+    match serde_json::to_string(&line) {
+        Ok(mut json) => {
+            json.push('\n');
+            f.write_all(json.as_bytes()).is_ok()
+        }
+        Err(_) => false,
     }
 }
 
@@ -131,8 +344,23 @@ fn append_json_line(f: &mut fs::File, event: &AcpUpdate) {
 /// 另外对每条事件的 `ToolCallUpdate.content` 做兜底截断，保护历史文件里
 /// 已经写入的超大 content（在截断逻辑上线前生成的）。
 pub fn load_history(project: &str, task_id: &str, chat_id: &str) -> Vec<AcpUpdate> {
-    let path = history_file_path(project, task_id, chat_id);
-    let mut file = match fs::File::open(&path) {
+    load_history_sequenced(project, task_id, chat_id)
+        .into_iter()
+        .map(|(_, event)| event)
+        .collect()
+}
+
+/// 同 `load_history`，附带每条事件的 seq
+pub fn load_history_sequenced(
+    project: &str,
+    task_id: &str,
+    chat_id: &str,
+) -> Vec<(u64, AcpUpdate)> {
+    load_sequenced_from(&history_file_path(project, task_id, chat_id))
+}
+
+fn load_sequenced_from(path: &Path) -> Vec<(u64, AcpUpdate)> {
+    let mut file = match fs::File::open(path) {
         Ok(f) => f,
         Err(_) => return Vec::new(),
     };
@@ -154,6 +382,7 @@ pub fn load_history(project: &str, task_id: &str, chat_id: &str) -> Vec<AcpUpdat
     }
 
     let mut history = Vec::new();
+    let mut last_seq = 0u64;
 
     for line in reader.lines() {
         let line = match line {
@@ -165,14 +394,16 @@ pub fn load_history(project: &str, task_id: &str, chat_id: &str) -> Vec<AcpUpdat
         }
         // 容错：旧版本存在并发 append 竞态，可能把多个 JSON 对象写到同一行
         // （`{a}{b}` 这种），用 StreamDeserializer 把一行里所有能解出的对象都拿到。
-        let stream = serde_json::Deserializer::from_str(&line).into_iter::<AcpUpdate>();
+        let stream = serde_json::Deserializer::from_str(&line).into_iter::<StoredLine<AcpUpdate>>();
         let mut parsed_any = false;
         let mut last_err: Option<serde_json::Error> = None;
         for item in stream {
             match item {
-                Ok(mut update) => {
-                    truncate_update_in_place(&mut update);
-                    history.push(update);
+                Ok(StoredLine { seq, mut event }) => {
+                    truncate_update_in_place(&mut event);
+                    // 旧行没有 seq：接着前一行编号
+                    last_seq = seq.unwrap_or(last_seq + 1);
+                    history.push((last_seq, event));
                     parsed_any = true;
                 }
                 Err(e) => {
@@ -183,7 +414,7 @@ pub fn load_history(project: &str, task_id: &str, chat_id: &str) -> Vec<AcpUpdat
         }
         if !parsed_any {
             if let Some(e) = last_err {
-                eprintln!("[chat_history] Failed to parse line: {} — {}", line, e);
+                tracing::warn!("Failed to parse line: {} — {}", line, e);
             }
         }
     }
@@ -191,6 +422,43 @@ pub fn load_history(project: &str, task_id: &str, chat_id: &str) -> Vec<AcpUpdat
     history
 }
 
+/// `replay_since` 的结果
+#[derive(Debug)]
+pub enum Replay {
+    /// 客户端缺失的事件（可能为空）
+    Events(Vec<(u64, AcpUpdate)>),
+    /// 无法增量回放（cursor 落在被 compact 合并的事件中间、history 被清空，
+    /// 或缺失事件超过 `max`），客户端需要整体重新加载
+    Reset,
+}
+
+/// 计算已收到 `since` 为止的客户端需要补的事件。
+///
+/// `since` 必须是 history 中确实存在的 seq（0 表示客户端什么都没有）：compact
+/// 合并后的事件取被合并事件中最大的 seq，cursor 落在合并区间中间时找不到
+/// 对应 seq，只能 Reset，避免回放半条已经收到过的消息。
+pub fn replay_since(history: Vec<(u64, AcpUpdate)>, since: u64, max: usize) -> Replay {
+    let last = history.last().map_or(0, |(seq, _)| *seq);
+    if since == last {
+        return Replay::Events(Vec::new());
+    }
+    if since > last {
+        return Replay::Reset;
+    }
+    let start = if since == 0 {
+        0
+    } else {
+        match history.iter().rposition(|(seq, _)| *seq == since) {
+            Some(pos) => pos + 1,
+            None => return Replay::Reset,
+        }
+    };
+    if history.len() - start > max {
+        return Replay::Reset;
+    }
+    Replay::Events(history.into_iter().skip(start).collect())
+}
+
 /// On reconnect/history replay, unresolved tool calls and terminal executions are treated
 /// as cancelled and synthetic cancellation events are appended so replayed history is
 /// self-consistent. Unresolved permission requests are intentionally NOT cancelled here —
@@ -233,34 +501,29 @@ pub fn cancel_unresolved_events(project: &str, task_id: &str, chat_id: &str) ->
         return 0;
     }
 
+    let mut cancelled: Vec<AcpUpdate> = unresolved_tools
+        .into_iter()
+        .map(|(id, locations)| AcpUpdate::ToolCallUpdate {
+            id,
+            status: "cancelled".to_string(),
+            content: None,
+            locations,
+            raw_input: None,
+            timestamp: None,
+        })
+        .collect();
+    cancelled.extend(
+        std::iter::repeat_with(|| AcpUpdate::TerminalComplete { exit_code: Some(1) })
+            .take(unresolved_terminals),
+    );
+
     let path = history_file_path(project, task_id, chat_id);
-    let file = fs::OpenOptions::new().create(true).append(true).open(&path);
-    match file {
-        Ok(mut f) => {
-            for (id, locations) in unresolved_tools {
-                append_json_line(
-                    &mut f,
-                    &AcpUpdate::ToolCallUpdate {
-                        id,
-                        status: "cancelled".to_string(),
-                        content: None,
-                        locations,
-                        raw_input: None,
-                    },
-                );
-            }
-            for _ in 0..unresolved_terminals {
-                append_json_line(&mut f, &AcpUpdate::TerminalComplete { exit_code: Some(1) });
-            }
-        }
-        Err(e) => {
-            eprintln!(
-                "[chat_history] Failed to append cancelled replay events to {}: {}",
-                path.display(),
-                e
-            );
-            return 0;
-        }
+    if append_events(&path, &cancelled).is_none() {
+        tracing::warn!(
+            "Failed to append cancelled replay events to {}",
+            path.display()
+        );
+        return 0;
     }
 
     unresolved_total
@@ -304,34 +567,55 @@ pub fn copy_history(
         return Ok(());
     }
     let dst = history_file_path(project, task_id, dst_chat_id);
-    if let Some(parent) = dst.parent() {
-        fs::create_dir_all(parent)?;
-    }
+    let _src_lock = HistoryLock::acquire(&src)?;
+    let _dst_lock = HistoryLock::acquire(&dst)?;
     fs::copy(&src, &dst)?;
     Ok(())
 }
 
-/// 清空 chat 历史文件（新 session 时调用）
+/// 清空 chat 历史文件（新 session 时调用）。seq 不重置：清空前的最后一个 seq
+/// 记在锁文件里，之后继续往上编号，持有旧 cursor 的客户端重连时会收到 Reset。
 pub fn clear_history(project: &str, task_id: &str, chat_id: &str) {
     let path = history_file_path(project, task_id, chat_id);
-    if path.exists() {
-        let _ = fs::remove_file(&path);
+    if !path.exists() {
+        return;
     }
+    let mut lock = match HistoryLock::acquire(&path) {
+        Ok(lock) => lock,
+        Err(e) => {
+            tracing::warn!("Failed to lock {}: {}", path.display(), e);
+            return;
+        }
+    };
+    let last = lock.last_seq(&path);
+    if let Err(e) = lock.set_seq_floor(last) {
+        tracing::warn!("Failed to record seq floor for {}: {}", path.display(), e);
+    }
+    let _ = fs::remove_file(&path);
 }
 
-/// Turn 结束后 compact history.jsonl：合并碎片化的 chunk 事件
+/// Turn 结束后 compact history.jsonl：合并碎片化的 chunk 事件。
+/// 读取与重写都在文件锁内，期间的 append 会等 compact 完成后再写。
 pub fn compact_history(project: &str, task_id: &str, chat_id: &str) {
-    let events = load_history(project, task_id, chat_id);
+    let path = history_file_path(project, task_id, chat_id);
+    let _lock = match HistoryLock::acquire(&path) {
+        Ok(lock) => lock,
+        Err(e) => {
+            tracing::warn!("compact: failed to lock {}: {}", path.display(), e);
+            return;
+        }
+    };
+    let events = load_sequenced_from(&path);
     if events.is_empty() {
         return;
     }
 
-    let compacted = compact_events(events);
+    let compacted = compact_sequenced(events);
     write_history(project, task_id, chat_id, &compacted);
 }
 
-/// 原子性重写 history.jsonl
-fn write_history(project: &str, task_id: &str, chat_id: &str, events: &[AcpUpdate]) {
+/// 原子性重写 history.jsonl（保留每条事件的 seq）
+fn write_history(project: &str, task_id: &str, chat_id: &str, events: &[(u64, AcpUpdate)]) {
     let path = history_file_path(project, task_id, chat_id);
     let tmp = path.with_extension("jsonl.tmp");
 
@@ -342,14 +626,18 @@ fn write_history(project: &str, task_id: &str, chat_id: &str, events: &[AcpUpdat
     let file = match fs::File::create(&tmp) {
         Ok(f) => f,
         Err(e) => {
-            eprintln!("[chat_history] compact: failed to create tmp: {}", e);
+            tracing::warn!("compact: failed to create tmp: {}", e);
             return;
         }
     };
 
     let mut writer = std::io::BufWriter::new(file);
-    for event in events {
-        if let Ok(json) = serde_json::to_string(event) {
+    for (seq, event) in events {
+        let line = StoredLine {
+            seq: Some(*seq),
+            event,
+        };
+        if let Ok(json) = serde_json::to_string(&line) {
             let _ = writeln!(writer, "{}", json);
         }
     }
@@ -362,9 +650,10 @@ fn write_history(project: &str, task_id: &str, chat_id: &str, events: &[AcpUpdat
                 let _ = fs::remove_file(&tmp);
             }
             Err(copy_err) => {
-                eprintln!(
-                    "[chat_history] compact: rename failed ({}) and copy fallback failed ({})",
-                    e, copy_err
+                tracing::warn!(
+                    "compact: rename failed ({}) and copy fallback failed ({})",
+                    e,
+                    copy_err
                 );
                 let _ = fs::remove_file(&tmp);
             }
@@ -379,6 +668,8 @@ struct ToolCompactState {
     content: Option<String>,
     locations: Vec<(String, Option<u32>)>,
     timestamp: Option<DateTime<Utc>>,
+    /// 最近一次 ToolCallUpdate 的时间（终态时即工具结束时间）
+    updated_at: Option<DateTime<Utc>>,
     /// 最后一次见到的 raw_input(优先以非 None 覆盖,避免被 None 抹掉)。
     raw_input: Option<serde_json::Value>,
 }
@@ -394,7 +685,16 @@ fn merge_locations(existing: &mut Vec<(String, Option<u32>)>, new_locs: &[(Strin
 
 /// Compact 事件列表：合并连续 chunk，合并同 id 的 tool 事件
 pub fn compact_events(events: Vec<AcpUpdate>) -> Vec<AcpUpdate> {
-    let mut result: Vec<AcpUpdate> = Vec::new();
+    compact_sequenced(events.into_iter().map(|event| (0, event)).collect())
+        .into_iter()
+        .map(|(_, event)| event)
+        .collect()
+}
+
+/// 带 seq 的 compact：合并出来的事件取被合并事件中最大的 seq，
+/// 保证输出的 seq 仍然单调不减
+fn compact_sequenced(events: Vec<(u64, AcpUpdate)>) -> Vec<(u64, AcpUpdate)> {
+    let mut result: Vec<(u64, AcpUpdate)> = Vec::new();
     let mut msg_buf = String::new();
     let mut thought_buf = String::new();
     let mut terminal_buf = String::new();
@@ -402,31 +702,42 @@ pub fn compact_events(events: Vec<AcpUpdate>) -> Vec<AcpUpdate> {
     let mut tool_order: Vec<String> = Vec::new();
     let mut tool_map: std::collections::HashMap<String, ToolCompactState> =
         std::collections::HashMap::new();
+    // 当前未 flush 的 buffer 中最大的 seq（同一时刻只有一种 buffer 非空）
+    let mut group_seq = 0u64;
 
     /// Flush accumulated message chunks
-    fn flush_messages(buf: &mut String, result: &mut Vec<AcpUpdate>) {
+    fn flush_messages(buf: &mut String, seq: u64, result: &mut Vec<(u64, AcpUpdate)>) {
         if !buf.is_empty() {
-            result.push(AcpUpdate::MessageChunk {
-                text: std::mem::take(buf),
-            });
+            result.push((
+                seq,
+                AcpUpdate::MessageChunk {
+                    text: std::mem::take(buf),
+                },
+            ));
         }
     }
 
     /// Flush accumulated thought chunks
-    fn flush_thoughts(buf: &mut String, result: &mut Vec<AcpUpdate>) {
+    fn flush_thoughts(buf: &mut String, seq: u64, result: &mut Vec<(u64, AcpUpdate)>) {
         if !buf.is_empty() {
-            result.push(AcpUpdate::ThoughtChunk {
-                text: std::mem::take(buf),
-            });
+            result.push((
+                seq,
+                AcpUpdate::ThoughtChunk {
+                    text: std::mem::take(buf),
+                },
+            ));
         }
     }
 
     /// Flush accumulated terminal output chunks
-    fn flush_terminal(buf: &mut String, result: &mut Vec<AcpUpdate>) {
+    fn flush_terminal(buf: &mut String, seq: u64, result: &mut Vec<(u64, AcpUpdate)>) {
         if !buf.is_empty() {
-            result.push(AcpUpdate::TerminalChunk {
-                output: std::mem::take(buf),
-            });
+            result.push((
+                seq,
+                AcpUpdate::TerminalChunk {
+                    output: std::mem::take(buf),
+                },
+            ));
         }
     }
 
@@ -434,47 +745,58 @@ pub fn compact_events(events: Vec<AcpUpdate>) -> Vec<AcpUpdate> {
     fn flush_tools(
         order: &mut Vec<String>,
         map: &mut std::collections::HashMap<String, ToolCompactState>,
-        result: &mut Vec<AcpUpdate>,
+        seq: u64,
+        result: &mut Vec<(u64, AcpUpdate)>,
     ) {
         for id in order.drain(..) {
             if let Some(state) = map.remove(&id) {
-                result.push(AcpUpdate::ToolCall {
-                    id: id.clone(),
-                    title: state.title,
-                    locations: state.locations.clone(),
-                    timestamp: state.timestamp,
-                    raw_input: state.raw_input.clone(),
-                });
-                result.push(AcpUpdate::ToolCallUpdate {
-                    id,
-                    status: state.status,
-                    content: state.content,
-                    locations: state.locations,
-                    raw_input: state.raw_input,
-                });
+                result.push((
+                    seq,
+                    AcpUpdate::ToolCall {
+                        id: id.clone(),
+                        title: state.title,
+                        locations: state.locations.clone(),
+                        timestamp: state.timestamp,
+                        raw_input: state.raw_input.clone(),
+                    },
+                ));
+                result.push((
+                    seq,
+                    AcpUpdate::ToolCallUpdate {
+                        id,
+                        status: state.status,
+                        content: state.content,
+                        locations: state.locations,
+                        raw_input: state.raw_input,
+                        timestamp: state.updated_at,
+                    },
+                ));
             }
         }
     }
 
-    for event in events {
+    for (seq, event) in events {
         match &event {
             AcpUpdate::MessageChunk { text } => {
-                flush_thoughts(&mut thought_buf, &mut result);
-                flush_tools(&mut tool_order, &mut tool_map, &mut result);
-                flush_terminal(&mut terminal_buf, &mut result);
+                flush_thoughts(&mut thought_buf, group_seq, &mut result);
+                flush_tools(&mut tool_order, &mut tool_map, group_seq, &mut result);
+                flush_terminal(&mut terminal_buf, group_seq, &mut result);
                 msg_buf.push_str(text);
+                group_seq = seq;
             }
             AcpUpdate::ThoughtChunk { text } => {
-                flush_messages(&mut msg_buf, &mut result);
-                flush_tools(&mut tool_order, &mut tool_map, &mut result);
-                flush_terminal(&mut terminal_buf, &mut result);
+                flush_messages(&mut msg_buf, group_seq, &mut result);
+                flush_tools(&mut tool_order, &mut tool_map, group_seq, &mut result);
+                flush_terminal(&mut terminal_buf, group_seq, &mut result);
                 thought_buf.push_str(text);
+                group_seq = seq;
             }
             AcpUpdate::TerminalChunk { output } => {
-                flush_messages(&mut msg_buf, &mut result);
-                flush_thoughts(&mut thought_buf, &mut result);
-                flush_tools(&mut tool_order, &mut tool_map, &mut result);
+                flush_messages(&mut msg_buf, group_seq, &mut result);
+                flush_thoughts(&mut thought_buf, group_seq, &mut result);
+                flush_tools(&mut tool_order, &mut tool_map, group_seq, &mut result);
                 terminal_buf.push_str(output);
+                group_seq = seq;
             }
             AcpUpdate::ToolCall {
                 id,
@@ -483,9 +805,10 @@ pub fn compact_events(events: Vec<AcpUpdate>) -> Vec<AcpUpdate> {
                 timestamp,
                 raw_input,
             } => {
-                flush_messages(&mut msg_buf, &mut result);
-                flush_thoughts(&mut thought_buf, &mut result);
-                flush_terminal(&mut terminal_buf, &mut result);
+                flush_messages(&mut msg_buf, group_seq, &mut result);
+                flush_thoughts(&mut thought_buf, group_seq, &mut result);
+                flush_terminal(&mut terminal_buf, group_seq, &mut result);
+                group_seq = seq;
                 if let Some(state) = tool_map.get_mut(id) {
                     // 后续 ToolCall（同 id）更新 title/locations，timestamp 保留第一次的值
                     if !title.is_empty() {
@@ -506,6 +829,7 @@ pub fn compact_events(events: Vec<AcpUpdate>) -> Vec<AcpUpdate> {
                             content: None,
                             locations: locations.clone(),
                             timestamp: *timestamp,
+                            updated_at: None,
                             raw_input: raw_input.clone(),
                         },
                     );
@@ -517,11 +841,16 @@ pub fn compact_events(events: Vec<AcpUpdate>) -> Vec<AcpUpdate> {
                 content,
                 locations,
                 raw_input,
+                timestamp,
             } => {
                 if let Some(state) = tool_map.get_mut(id) {
+                    group_seq = seq;
                     if !status.is_empty() {
                         state.status = status.clone();
                     }
+                    if timestamp.is_some() {
+                        state.updated_at = *timestamp;
+                    }
                     // 按 ACP 规范：content block 是增量下发的，后续 update 是对
                     // 之前的补充而非替换。拼接而不是覆盖，避免最终只剩下最后
                     // 一条（例如把 bash 命令行覆盖成执行结果）。
@@ -558,29 +887,29 @@ pub fn compact_events(events: Vec<AcpUpdate>) -> Vec<AcpUpdate> {
                     }
                 } else {
                     // Orphan ToolCallUpdate（没有对应的 ToolCall），直接保留
-                    flush_messages(&mut msg_buf, &mut result);
-                    flush_thoughts(&mut thought_buf, &mut result);
-                    flush_tools(&mut tool_order, &mut tool_map, &mut result);
-                    flush_terminal(&mut terminal_buf, &mut result);
-                    result.push(event);
+                    flush_messages(&mut msg_buf, group_seq, &mut result);
+                    flush_thoughts(&mut thought_buf, group_seq, &mut result);
+                    flush_tools(&mut tool_order, &mut tool_map, group_seq, &mut result);
+                    flush_terminal(&mut terminal_buf, group_seq, &mut result);
+                    result.push((seq, event));
                 }
             }
             _ => {
                 // 其他事件：flush 所有 buffer，原样保留
-                flush_messages(&mut msg_buf, &mut result);
-                flush_thoughts(&mut thought_buf, &mut result);
-                flush_tools(&mut tool_order, &mut tool_map, &mut result);
-                flush_terminal(&mut terminal_buf, &mut result);
-                result.push(event);
+                flush_messages(&mut msg_buf, group_seq, &mut result);
+                flush_thoughts(&mut thought_buf, group_seq, &mut result);
+                flush_tools(&mut tool_order, &mut tool_map, group_seq, &mut result);
+                flush_terminal(&mut terminal_buf, group_seq, &mut result);
+                result.push((seq, event));
             }
         }
     }
 
     // Flush 尾部
-    flush_messages(&mut msg_buf, &mut result);
-    flush_thoughts(&mut thought_buf, &mut result);
-    flush_tools(&mut tool_order, &mut tool_map, &mut result);
-    flush_terminal(&mut terminal_buf, &mut result);
+    flush_messages(&mut msg_buf, group_seq, &mut result);
+    flush_thoughts(&mut thought_buf, group_seq, &mut result);
+    flush_tools(&mut tool_order, &mut tool_map, group_seq, &mut result);
+    flush_terminal(&mut terminal_buf, group_seq, &mut result);
 
     result
 }
@@ -604,6 +933,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn last_message_merges_trailing_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let events = [
+            AcpUpdate::UserMessage {
+                text: "hi".into(),
+                attachments: Vec::new(),
+                sender: None,
+                terminal: false,
+            },
+            AcpUpdate::MessageChunk { text: "Hel".into() },
+            AcpUpdate::MessageChunk { text: "lo".into() },
+            AcpUpdate::ModeChanged {
+                mode_id: "code".into(),
+            },
+        ];
+        let body: String = events
+            .iter()
+            .map(|e| format!("{}\n", serde_json::to_string(e).unwrap()))
+            .collect();
+        fs::write(&path, body).unwrap();
+        assert_eq!(read_last_message(&path).as_deref(), Some("Hello"));
+        assert!(read_last_message(&dir.path().join("missing.jsonl")).is_none());
+    }
+
     #[test]
     fn unresolved_ids_pairs_by_id() {
         // X resolved, Y not — only Y stays unresolved.
@@ -767,6 +1122,7 @@ mod tests {
                 content: Some("file content".into()),
                 locations: vec![("foo.rs".into(), Some(1))],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::Complete {
                 usage: None,
@@ -821,6 +1177,7 @@ mod tests {
                 content: None,
                 locations: vec![],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::MessageChunk { text: "m3".into() },
             AcpUpdate::Complete {
@@ -894,6 +1251,7 @@ mod tests {
                 content: Some("$ echo hi".into()),
                 locations: vec![],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::ToolCallUpdate {
                 id: "t1".into(),
@@ -901,6 +1259,7 @@ mod tests {
                 content: Some("hi".into()),
                 locations: vec![],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::Complete {
                 usage: None,
@@ -942,6 +1301,7 @@ mod tests {
                 content: Some("payload-A".into()),
                 locations: vec![],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::ToolCallUpdate {
                 id: "t1".into(),
@@ -949,6 +1309,7 @@ mod tests {
                 content: Some("payload-A".into()),
                 locations: vec![],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::Complete {
                 usage: None,
@@ -992,6 +1353,7 @@ mod tests {
                 content: Some("line1".into()),
                 locations: vec![],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::ToolCallUpdate {
                 id: "t1".into(),
@@ -999,6 +1361,7 @@ mod tests {
                 content: Some("line1\nline2".into()),
                 locations: vec![],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::ToolCallUpdate {
                 id: "t1".into(),
@@ -1006,6 +1369,7 @@ mod tests {
                 content: Some("line1\nline2\nline3".into()),
                 locations: vec![],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::Complete {
                 usage: None,
@@ -1076,6 +1440,7 @@ mod tests {
             content: Some("tiny".into()),
             locations: vec![],
             raw_input: None,
+            timestamp: None,
         };
         assert!(!needs_truncation(&small));
 
@@ -1085,6 +1450,7 @@ mod tests {
             content: Some("x".repeat(MAX_TOOL_CONTENT_BYTES + 1)),
             locations: vec![],
             raw_input: None,
+            timestamp: None,
         };
         assert!(needs_truncation(&big));
 
@@ -1112,6 +1478,7 @@ mod tests {
                 content: None,
                 locations: vec![("a.rs".into(), Some(1)), ("b.rs".into(), Some(2))],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::ToolCallUpdate {
                 id: "t1".into(),
@@ -1119,6 +1486,7 @@ mod tests {
                 content: None,
                 locations: vec![("b.rs".into(), Some(2)), ("c.rs".into(), None)],
                 raw_input: None,
+                timestamp: None,
             },
             AcpUpdate::Complete {
                 usage: None,
@@ -1143,4 +1511,132 @@ mod tests {
             "ToolCallUpdate flush should accumulate deduped locations in insertion order"
         );
     }
+
+    fn chunk(text: &str) -> AcpUpdate {
+        AcpUpdate::MessageChunk { text: text.into() }
+    }
+
+    fn seqs(events: &[(u64, AcpUpdate)]) -> Vec<u64> {
+        events.iter().map(|(seq, _)| *seq).collect()
+    }
+
+    #[test]
+    fn test_append_continues_implicit_seq_of_legacy_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        // 旧版本写入的行没有 seq
+        let legacy: String = [chunk("a"), chunk("b")]
+            .iter()
+            .map(|e| format!("{}\n", serde_json::to_string(e).unwrap()))
+            .collect();
+        fs::write(&path, legacy).unwrap();
+
+        assert_eq!(append_events(&path, &[chunk("c")]), Some(3));
+        assert_eq!(append_events(&path, &[chunk("d"), chunk("e")]), Some(5));
+        let loaded = load_sequenced_from(&path);
+        assert_eq!(seqs(&loaded), vec![1, 2, 3, 4, 5]);
+        assert!(matches!(&loaded[4].1, AcpUpdate::MessageChunk { text } if text == "e"));
+    }
+
+    #[test]
+    fn test_append_derives_seq_from_file_written_elsewhere() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        assert_eq!(append_events(&path, &[chunk("a")]), Some(1));
+
+        // 另一个进程在这之后写入的行
+        let line = StoredLine {
+            seq: Some(7),
+            event: chunk("b"),
+        };
+        let mut f = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        writeln!(f, "{}", serde_json::to_string(&line).unwrap()).unwrap();
+
+        assert_eq!(append_events(&path, &[chunk("c")]), Some(8));
+        assert_eq!(seqs(&load_sequenced_from(&path)), vec![1, 7, 8]);
+    }
+
+    #[test]
+    fn test_last_write_at_picks_most_recent_chat() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        assert_eq!(last_write_at("proj", "task"), None);
+
+        let recent = std::time::SystemTime::now();
+        let older = recent - std::time::Duration::from_secs(3600);
+        for (chat, mtime) in [("old", older), ("new", recent)] {
+            append_event("proj", "task", chat, &chunk("hi"));
+            let f = fs::File::options()
+                .write(true)
+                .open(history_file_path("proj", "task", chat))
+                .unwrap();
+            f.set_modified(mtime).unwrap();
+        }
+        assert_eq!(
+            last_write_at("proj", "task"),
+            Some(DateTime::<Utc>::from(recent))
+        );
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_concurrent_appends_get_unique_seqs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.jsonl");
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    for j in 0..10 {
+                        append_events(&path, &[chunk(&format!("{}-{}", i, j))]);
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+        let expected: Vec<u64> = (1..=80).collect();
+        assert_eq!(seqs(&load_sequenced_from(&path)), expected);
+    }
+
+    #[test]
+    fn test_compact_keeps_max_seq_of_merged_events() {
+        let events = vec![
+            (1, chunk("Hel")),
+            (2, chunk("lo")),
+            (
+                3,
+                AcpUpdate::ModeChanged {
+                    mode_id: "code".into(),
+                },
+            ),
+            (4, chunk("a")),
+            (5, chunk("b")),
+        ];
+        assert_eq!(seqs(&compact_sequenced(events)), vec![2, 3, 5]);
+    }
+
+    #[test]
+    fn test_replay_since_requires_known_cursor() {
+        let history = || vec![(2, chunk("a")), (3, chunk("b")), (7, chunk("c"))];
+
+        let Replay::Events(events) = replay_since(history(), 3, 10) else {
+            panic!("expected events");
+        };
+        assert_eq!(seqs(&events), vec![7]);
+        let Replay::Events(events) = replay_since(history(), 0, 10) else {
+            panic!("expected events");
+        };
+        assert_eq!(events.len(), 3);
+        assert!(matches!(replay_since(history(), 7, 10), Replay::Events(e) if e.is_empty()));
+
+        // cursor 落在被 compact 合并的区间里 / 比最新的还新 / 缺太多
+        assert!(matches!(replay_since(history(), 5, 10), Replay::Reset));
+        assert!(matches!(replay_since(history(), 9, 10), Replay::Reset));
+        assert!(matches!(replay_since(history(), 2, 1), Replay::Reset));
+    }
 }
@@ -0,0 +1,214 @@
+//! Chat 置顶消息: ~/.grove/projects/{project}/tasks/{task_id}/chats/{chat_id}/pins.json
+//!
+//! 与 history.jsonl 放在同一目录，记录用户在长对话中标记的关键消息 / 工具结果。
+//! 每个 pin 保存内容快照（history 压缩后消息仍可定位），工具结果额外记录
+//! tool call id，前端据此滚动到对应消息。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GroveError, Result};
+
+/// 每个 chat 最多保留的 pin 数
+pub const MAX_PINS: usize = 200;
+
+/// pin 内容快照的最大字符数
+pub const MAX_PIN_TEXT_CHARS: usize = 2000;
+
+/// 被 pin 的消息类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PinKind {
+    User,
+    Assistant,
+    Thinking,
+    Tool,
+}
+
+/// 单个置顶消息
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatPin {
+    /// Pin ID ("pin-XXXXXX")
+    pub id: String,
+    pub kind: PinKind,
+    /// 消息内容快照（工具为标题 + 结果，超长截断）
+    pub text: String,
+    /// 工具结果对应的 tool call id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_call_id: Option<String>,
+    /// 用户备注（如"决定改用 SQLite"）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+fn pins_path(project: &str, task_id: &str, chat_id: &str) -> std::path::PathBuf {
+    super::chat_history::history_file_path(project, task_id, chat_id).with_file_name("pins.json")
+}
+
+/// 截断到 `MAX_PIN_TEXT_CHARS` 个字符
+fn truncate_text(text: &str) -> String {
+    match text.char_indices().nth(MAX_PIN_TEXT_CHARS) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+/// 读取 chat 的所有 pin（按 pin 时间升序）
+pub fn load_pins(project: &str, task_id: &str, chat_id: &str) -> Vec<ChatPin> {
+    std::fs::read_to_string(pins_path(project, task_id, chat_id))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_pins(project: &str, task_id: &str, chat_id: &str, pins: &[ChatPin]) -> Result<()> {
+    let path = pins_path(project, task_id, chat_id);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content =
+        serde_json::to_string_pretty(pins).map_err(|e| GroveError::storage(e.to_string()))?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// 添加 pin 到列表：同一工具 / 同一内容已 pin 时只更新备注；超出上限时报错
+fn insert_pin(
+    pins: &mut Vec<ChatPin>,
+    kind: PinKind,
+    text: &str,
+    tool_call_id: Option<String>,
+    note: Option<String>,
+) -> Result<ChatPin> {
+    let text = truncate_text(text.trim());
+    let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    if text.is_empty() && tool_call_id.is_none() {
+        return Err(GroveError::invalid_data("Nothing to pin"));
+    }
+    let existing = pins
+        .iter_mut()
+        .find(|p| match (&tool_call_id, &p.tool_call_id) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => p.kind == kind && p.text == text,
+            _ => false,
+        });
+    if let Some(pin) = existing {
+        if note.is_some() {
+            pin.note = note;
+        }
+        return Ok(pin.clone());
+    }
+    if pins.len() >= MAX_PINS {
+        return Err(GroveError::invalid_data(format!(
+            "A chat can have at most {} pins",
+            MAX_PINS
+        )));
+    }
+    let pin = ChatPin {
+        id: format!("pin-{}", &uuid::Uuid::new_v4().simple().to_string()[..12]),
+        kind,
+        text,
+        tool_call_id,
+        note,
+        created_at: Utc::now(),
+    };
+    pins.push(pin.clone());
+    Ok(pin)
+}
+
+/// Pin 一条消息，返回（新建或已存在的）pin
+pub fn add_pin(
+    project: &str,
+    task_id: &str,
+    chat_id: &str,
+    kind: PinKind,
+    text: &str,
+    tool_call_id: Option<String>,
+    note: Option<String>,
+) -> Result<ChatPin> {
+    let mut pins = load_pins(project, task_id, chat_id);
+    let pin = insert_pin(&mut pins, kind, text, tool_call_id, note)?;
+    save_pins(project, task_id, chat_id, &pins)?;
+    Ok(pin)
+}
+
+/// 取消 pin；返回是否存在
+pub fn remove_pin(project: &str, task_id: &str, chat_id: &str, pin_id: &str) -> Result<bool> {
+    let mut pins = load_pins(project, task_id, chat_id);
+    let before = pins.len();
+    pins.retain(|p| p.id != pin_id);
+    if pins.len() == before {
+        return Ok(false);
+    }
+    save_pins(project, task_id, chat_id, &pins)?;
+    Ok(true)
+}
+
+/// 清空 chat 的 pin（与 `chat_history::clear_history` 一起调用）
+pub fn clear_pins(project: &str, task_id: &str, chat_id: &str) {
+    let _ = std::fs::remove_file(pins_path(project, task_id, chat_id));
+}
+
+/// 把一个 chat 的 pin 复制到另一个 chat（fork 场景，源不存在时静默成功）
+pub fn copy_pins(project: &str, task_id: &str, src_chat_id: &str, dst_chat_id: &str) -> Result<()> {
+    let pins = load_pins(project, task_id, src_chat_id);
+    if pins.is_empty() {
+        return Ok(());
+    }
+    save_pins(project, task_id, dst_chat_id, &pins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_pin_dedups_and_updates_note() {
+        let mut pins = Vec::new();
+        let first = insert_pin(&mut pins, PinKind::Assistant, " Use SQLite ", None, None).unwrap();
+        assert_eq!(first.text, "Use SQLite");
+
+        let again = insert_pin(
+            &mut pins,
+            PinKind::Assistant,
+            "Use SQLite",
+            None,
+            Some("storage decision".to_string()),
+        )
+        .unwrap();
+        assert_eq!(again.id, first.id);
+        assert_eq!(pins.len(), 1);
+        assert_eq!(pins[0].note.as_deref(), Some("storage decision"));
+
+        // 同一工具按 tool call id 去重，内容可以不同
+        let tool = insert_pin(
+            &mut pins,
+            PinKind::Tool,
+            "cargo test",
+            Some("t1".into()),
+            None,
+        )
+        .unwrap();
+        let tool_again = insert_pin(
+            &mut pins,
+            PinKind::Tool,
+            "cargo test: ok",
+            Some("t1".into()),
+            None,
+        )
+        .unwrap();
+        assert_eq!(tool.id, tool_again.id);
+        assert_eq!(pins.len(), 2);
+
+        assert!(insert_pin(&mut pins, PinKind::User, "   ", None, None).is_err());
+    }
+
+    #[test]
+    fn long_text_is_truncated() {
+        let text = "é".repeat(MAX_PIN_TEXT_CHARS + 10);
+        let truncated = truncate_text(&text);
+        assert_eq!(truncated.chars().count(), MAX_PIN_TEXT_CHARS + 1);
+        assert!(truncated.ends_with('…'));
+    }
+}
@@ -0,0 +1,49 @@
+//! Task checkpoint 设置: ~/.grove/projects/{project}/tasks/{task_id}/checkpoints.toml
+
+use serde::{Deserialize, Serialize};
+
+use super::{ensure_task_data_dir, load_toml, save_toml};
+use crate::error::Result;
+
+/// 默认自动 checkpoint 间隔（分钟）
+pub const DEFAULT_INTERVAL_MINS: u32 = 10;
+
+/// 单个 task 的自动 checkpoint 设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointSettings {
+    /// 是否开启自动 checkpoint
+    #[serde(default)]
+    pub enabled: bool,
+    /// 持续有文件活动时，每隔多少分钟提交一次
+    #[serde(default = "default_interval_mins")]
+    pub interval_mins: u32,
+}
+
+fn default_interval_mins() -> u32 {
+    DEFAULT_INTERVAL_MINS
+}
+
+impl Default for CheckpointSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_mins: DEFAULT_INTERVAL_MINS,
+        }
+    }
+}
+
+/// 读取 checkpoint 设置（文件不存在返回默认值）
+pub fn load_settings(project: &str, task_id: &str) -> CheckpointSettings {
+    ensure_task_data_dir(project, task_id)
+        .map(|dir| dir.join("checkpoints.toml"))
+        .ok()
+        .filter(|path| path.exists())
+        .and_then(|path| load_toml(&path).ok())
+        .unwrap_or_default()
+}
+
+/// 保存 checkpoint 设置
+pub fn save_settings(project: &str, task_id: &str, settings: &CheckpointSettings) -> Result<()> {
+    let path = ensure_task_data_dir(project, task_id)?.join("checkpoints.toml");
+    save_toml(&path, settings)
+}
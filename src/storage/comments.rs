@@ -24,6 +24,48 @@ pub enum CommentStatus {
     Outdated,
 }
 
+/// Review 提交结论
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewVerdict {
+    #[default]
+    Comment,
+    Approve,
+    RequestChanges,
+}
+
+impl ReviewVerdict {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ReviewVerdict::Comment => "comment",
+            ReviewVerdict::Approve => "approve",
+            ReviewVerdict::RequestChanges => "request_changes",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "comment" => Some(ReviewVerdict::Comment),
+            "approve" => Some(ReviewVerdict::Approve),
+            "request_changes" => Some(ReviewVerdict::RequestChanges),
+            _ => None,
+        }
+    }
+}
+
+/// 一次提交的 review：草稿批量发布 + 总体结论
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewSubmission {
+    pub id: u32,
+    pub reviewer: String,
+    pub verdict: ReviewVerdict,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub comment_ids: Vec<u32>,
+    pub timestamp: String,
+}
+
 // ============================================================================
 // Enum ↔ string helpers
 // ============================================================================
@@ -86,6 +128,16 @@ pub fn build_author(agent: &str, role: &str) -> String {
     format!("{} ({})", agent, role)
 }
 
+/// 可用的 reaction 表情
+pub const REACTION_EMOJIS: [&str; 3] = ["👍", "👀", "🚀"];
+
+/// 某个表情的 reaction 及其作者
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reaction {
+    pub emoji: String,
+    pub authors: Vec<String>,
+}
+
 /// Comment 回复
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommentReply {
@@ -99,6 +151,8 @@ pub struct CommentReply {
     pub role: String,
     #[serde(default = "default_timestamp")]
     pub timestamp: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<Reaction>,
 }
 
 /// 单条 Review Comment
@@ -143,6 +197,9 @@ pub struct Comment {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub anchor_text: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<Reaction>,
 }
 
 fn default_timestamp() -> String {
@@ -179,6 +236,7 @@ impl Comment {
             status: CommentStatus::Open,
             replies: Vec::new(),
             anchor_text,
+            reactions: Vec::new(),
         }
     }
 
@@ -309,6 +367,68 @@ pub fn find_anchor(content: &str, anchor: &str, hint_line: Option<u32>) -> Optio
     }
 }
 
+/// 模糊重定位时，在原行号上下搜索的行数
+const RELOCATE_WINDOW: usize = 50;
+
+/// 模糊匹配至少需要命中的锚点非空行比例（百分比）
+const RELOCATE_MIN_PERCENT: usize = 60;
+
+/// 归一化一行：去掉首尾空白并折叠内部空白，用于容忍格式化改动
+fn normalize_line(line: &str) -> String {
+    line.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 重新定位锚点，返回 (新起始行, 是否精确匹配)。
+///
+/// 依次尝试：精确匹配 → 忽略空白的匹配 → 在 `hint_line` 附近
+/// `RELOCATE_WINDOW` 行内按行相似度模糊匹配（多行锚点才启用，
+/// 需命中至少 `RELOCATE_MIN_PERCENT`% 的非空行）。
+pub fn relocate_anchor(content: &str, anchor: &str, hint_line: Option<u32>) -> Option<(u32, bool)> {
+    if let Some(line) = find_anchor(content, anchor, hint_line) {
+        return Some((line, true));
+    }
+
+    let file_lines: Vec<String> = content.lines().map(normalize_line).collect();
+    let anchor_lines: Vec<String> = anchor.lines().map(normalize_line).collect();
+    let significant = anchor_lines.iter().filter(|l| !l.is_empty()).count();
+    if significant == 0 || anchor_lines.len() > file_lines.len() {
+        return None;
+    }
+    let last_start = file_lines.len() - anchor_lines.len();
+    let distance = |start: usize| match hint_line {
+        Some(hint) => (start as i64 + 1 - hint as i64).unsigned_abs(),
+        None => start as u64,
+    };
+
+    // 忽略空白的完整匹配
+    let normalized = (0..=last_start)
+        .filter(|&i| file_lines[i..i + anchor_lines.len()] == anchor_lines[..])
+        .min_by_key(|&i| distance(i));
+    if let Some(i) = normalized {
+        return Some(((i + 1) as u32, false));
+    }
+
+    // 附近窗口内的模糊匹配
+    if anchor_lines.len() < 2 {
+        return None;
+    }
+    let hint = hint_line.map(|h| h.saturating_sub(1) as usize).unwrap_or(0);
+    let lo = hint.saturating_sub(RELOCATE_WINDOW);
+    let hi = (hint + RELOCATE_WINDOW).min(last_start);
+    (lo..=hi)
+        .map(|i| {
+            let hits = anchor_lines
+                .iter()
+                .enumerate()
+                .filter(|(j, l)| !l.is_empty() && file_lines[i + j] == **l)
+                .count();
+            (i, hits)
+        })
+        .filter(|&(_, hits)| hits * 100 >= significant * RELOCATE_MIN_PERCENT)
+        .max_by_key(|&(i, hits)| (hits, std::cmp::Reverse(distance(i))))
+        .map(|(i, _)| ((i + 1) as u32, false))
+}
+
 pub fn apply_outdated_detection<F>(data: &mut CommentsData, read_fn: F) -> bool
 where
     F: Fn(&str, &str) -> Option<String>,
@@ -341,7 +461,9 @@ where
             Some(file_content) => {
                 let file_line_count = file_content.lines().count().max(1) as u32;
 
-                if let Some(new_start) = find_anchor(&file_content, &anchor, comment.start_line) {
+                if let Some((new_start, exact)) =
+                    relocate_anchor(&file_content, &anchor, comment.start_line)
+                {
                     if let (Some(start), Some(end)) = (comment.start_line, comment.end_line) {
                         let span = end.saturating_sub(start);
                         if start != new_start {
@@ -349,6 +471,12 @@ where
                             comment.end_line = Some(new_start + span);
                             line_changed = true;
                         }
+                        // 非精确命中时刷新锚点文本，之后的迭代继续跟随代码
+                        if !exact {
+                            comment.anchor_text =
+                                extract_lines(&file_content, new_start, new_start + span);
+                            line_changed = true;
+                        }
                     }
                 } else {
                     comment.status = CommentStatus::Outdated;
@@ -392,29 +520,84 @@ fn load_replies_for_comment(
             model: row.get::<_, String>(3).unwrap_or_default(),
             role: row.get::<_, String>(4).unwrap_or_default(),
             timestamp: row.get(5)?,
+            reactions: Vec::new(),
         })
     })?;
 
     Ok(rows.filter_map(|r| r.ok()).collect())
 }
 
+/// 读取 task 下全部 reactions，按 (comment_id, reply_id) 分组；reply_id 0 表示 comment 本身
+fn load_reactions(
+    conn: &rusqlite::Connection,
+    project_key: &str,
+    task_id: &str,
+) -> Result<std::collections::HashMap<(u32, u32), Vec<Reaction>>> {
+    let mut stmt = conn.prepare(
+        "SELECT comment_id, reply_id, emoji, author
+         FROM review_reactions
+         WHERE project_key = ?1 AND task_id = ?2
+         ORDER BY timestamp",
+    )?;
+    let rows = stmt.query_map(rusqlite::params![project_key, task_id], |row| {
+        Ok((
+            row.get::<_, u32>(0)?,
+            row.get::<_, u32>(1)?,
+            row.get::<_, String>(2)?,
+            row.get::<_, String>(3)?,
+        ))
+    })?;
+
+    let mut map: std::collections::HashMap<(u32, u32), Vec<Reaction>> =
+        std::collections::HashMap::new();
+    for row in rows {
+        let (comment_id, reply_id, emoji, author) = row?;
+        let list = map.entry((comment_id, reply_id)).or_default();
+        match list.iter_mut().find(|r| r.emoji == emoji) {
+            Some(r) => r.authors.push(author),
+            None => list.push(Reaction {
+                emoji,
+                authors: vec![author],
+            }),
+        }
+    }
+    // 固定按 REACTION_EMOJIS 顺序展示
+    for list in map.values_mut() {
+        list.sort_by_key(|r| REACTION_EMOJIS.iter().position(|e| *e == r.emoji));
+    }
+    Ok(map)
+}
+
 // ============================================================================
 // Public API
 // ============================================================================
 
-/// 读取 Review Comments
+/// 读取已发布的 Review Comments（不含任何人的草稿）
 pub fn load_comments(project: &str, task_id: &str) -> Result<CommentsData> {
+    load_comments_owned_by(project, task_id, None)
+}
+
+/// 读取某位 reviewer 尚未提交的草稿 comments
+pub fn load_draft_comments(project: &str, task_id: &str, reviewer: &str) -> Result<CommentsData> {
+    load_comments_owned_by(project, task_id, Some(reviewer))
+}
+
+fn load_comments_owned_by(
+    project: &str,
+    task_id: &str,
+    draft_owner: Option<&str>,
+) -> Result<CommentsData> {
     let conn = database::connection();
 
     let mut stmt = conn.prepare(
         "SELECT id, comment_type, file_path, side, start_line, end_line,
                 content, agent, model_name, role, timestamp, status, anchor_text
          FROM review_comments
-         WHERE project_key = ?1 AND task_id = ?2
+         WHERE project_key = ?1 AND task_id = ?2 AND draft_owner IS ?3
          ORDER BY id",
     )?;
 
-    let rows = stmt.query_map(rusqlite::params![project, task_id], |row| {
+    let rows = stmt.query_map(rusqlite::params![project, task_id, draft_owner], |row| {
         let id: u32 = row.get(0)?;
         let comment_type_str: String = row.get(1)?;
         let file_path: Option<String> = row.get(2)?;
@@ -445,6 +628,7 @@ pub fn load_comments(project: &str, task_id: &str) -> Result<CommentsData> {
         ))
     })?;
 
+    let mut reactions = load_reactions(&conn, project, task_id)?;
     let mut comments = Vec::new();
     for row in rows {
         let (
@@ -465,7 +649,10 @@ pub fn load_comments(project: &str, task_id: &str) -> Result<CommentsData> {
 
         let comment_type = comment_type_from_str(&comment_type_str);
         let status = status_from_str(&status_str);
-        let replies = load_replies_for_comment(&conn, project, task_id, id)?;
+        let mut replies = load_replies_for_comment(&conn, project, task_id, id)?;
+        for reply in &mut replies {
+            reply.reactions = reactions.remove(&(id, reply.id)).unwrap_or_default();
+        }
 
         let comment = Comment {
             id,
@@ -482,6 +669,7 @@ pub fn load_comments(project: &str, task_id: &str) -> Result<CommentsData> {
             status,
             replies,
             anchor_text,
+            reactions: reactions.remove(&(id, 0)).unwrap_or_default(),
         };
         comment.validate()?;
         comments.push(comment);
@@ -565,6 +753,7 @@ pub fn add_comment(
     model: &str,
     role: &str,
     anchor_text: Option<String>,
+    draft_owner: Option<&str>,
 ) -> Result<Comment> {
     let conn = database::connection();
 
@@ -601,8 +790,8 @@ pub fn add_comment(
     let status_str = status_to_str(CommentStatus::Open);
 
     conn.execute(
-        "INSERT INTO review_comments (id, project_key, task_id, comment_type, file_path, side, start_line, end_line, content, agent, model_name, role, timestamp, status, anchor_text)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+        "INSERT INTO review_comments (id, project_key, task_id, comment_type, file_path, side, start_line, end_line, content, agent, model_name, role, timestamp, status, anchor_text, draft_owner)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
         rusqlite::params![
             comment.id,
             project,
@@ -619,18 +808,19 @@ pub fn add_comment(
             comment.timestamp,
             status_str,
             comment.anchor_text,
+            draft_owner,
         ],
     )?;
 
     Ok(comment)
 }
 
-/// 保存 comments（供 outdated detection 后持久化行号和状态变更）
+/// 保存 comments（供 outdated detection 后持久化行号、锚点和状态变更）
 pub fn save_comments(project: &str, task_id: &str, data: &CommentsData) -> Result<()> {
     let conn = database::connection();
     for comment in &data.comments {
         conn.execute(
-            "UPDATE review_comments SET status = ?1, start_line = ?2, end_line = ?3 WHERE project_key = ?4 AND task_id = ?5 AND id = ?6",
+            "UPDATE review_comments SET status = ?1, start_line = ?2, end_line = ?3, anchor_text = ?7 WHERE project_key = ?4 AND task_id = ?5 AND id = ?6",
             rusqlite::params![
                 status_to_str(comment.status),
                 comment.start_line,
@@ -638,12 +828,99 @@ pub fn save_comments(project: &str, task_id: &str, data: &CommentsData) -> Resul
                 project,
                 task_id,
                 comment.id,
+                comment.anchor_text,
             ],
         )?;
     }
     Ok(())
 }
 
+/// 用同步来的已发布 comments 整体替换某任务的 comments
+///
+/// 本机草稿保留；草稿 id 与同步来的 comment 冲突时，草稿挪到新 id。
+pub fn replace_comments(project: &str, task_id: &str, comments: &[Comment]) -> Result<()> {
+    let conn = database::connection();
+    let tx = conn.unchecked_transaction()?;
+    tx.execute(
+        "DELETE FROM review_comments WHERE project_key = ?1 AND task_id = ?2 AND draft_owner IS NULL",
+        rusqlite::params![project, task_id],
+    )?;
+
+    for comment in comments {
+        tx.execute(
+            "UPDATE review_comments
+             SET id = (SELECT MAX(id) + 1 FROM review_comments WHERE project_key = ?1 AND task_id = ?2)
+             WHERE project_key = ?1 AND task_id = ?2 AND id = ?3",
+            rusqlite::params![project, task_id, comment.id],
+        )?;
+        tx.execute(
+            "INSERT INTO review_comments (id, project_key, task_id, comment_type, file_path, side, start_line, end_line, content, agent, model_name, role, timestamp, status, anchor_text)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+            rusqlite::params![
+                comment.id,
+                project,
+                task_id,
+                comment_type_to_str(comment.comment_type),
+                comment.file_path,
+                comment.side,
+                comment.start_line,
+                comment.end_line,
+                comment.content,
+                comment.agent,
+                comment.model,
+                comment.role,
+                comment.timestamp,
+                status_to_str(comment.status),
+                comment.anchor_text,
+            ],
+        )?;
+        for reply in &comment.replies {
+            tx.execute(
+                "INSERT INTO review_replies (id, comment_id, project_key, task_id, content, agent, model_name, role, timestamp)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                rusqlite::params![
+                    reply.id,
+                    comment.id,
+                    project,
+                    task_id,
+                    reply.content,
+                    reply.agent,
+                    reply.model,
+                    reply.role,
+                    reply.timestamp,
+                ],
+            )?;
+        }
+        let reactions = std::iter::once((0, &comment.reactions, &comment.timestamp)).chain(
+            comment
+                .replies
+                .iter()
+                .map(|r| (r.id, &r.reactions, &r.timestamp)),
+        );
+        for (reply_id, list, timestamp) in reactions {
+            for reaction in list {
+                for author in &reaction.authors {
+                    tx.execute(
+                        "INSERT OR IGNORE INTO review_reactions (project_key, task_id, comment_id, reply_id, emoji, author, timestamp)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                        rusqlite::params![
+                            project,
+                            task_id,
+                            comment.id,
+                            reply_id,
+                            reaction.emoji,
+                            author,
+                            timestamp,
+                        ],
+                    )?;
+                }
+            }
+        }
+    }
+    tx.commit()?;
+    Ok(())
+}
+
 /// 批量删除 Comments（按 status 和 agent 过滤）
 pub fn bulk_delete_comments(
     project: &str,
@@ -653,7 +930,11 @@ pub fn bulk_delete_comments(
 ) -> Result<usize> {
     let conn = database::connection();
 
-    let mut conditions = vec!["project_key = ?1".to_string(), "task_id = ?2".to_string()];
+    let mut conditions = vec![
+        "project_key = ?1".to_string(),
+        "task_id = ?2".to_string(),
+        "draft_owner IS NULL".to_string(),
+    ];
     let mut param_values: Vec<Box<dyn rusqlite::types::ToSql>> =
         vec![Box::new(project.to_string()), Box::new(task_id.to_string())];
 
@@ -745,9 +1026,300 @@ pub fn delete_reply(project: &str, task_id: &str, comment_id: u32, reply_id: u32
         "DELETE FROM review_replies WHERE project_key = ?1 AND task_id = ?2 AND comment_id = ?3 AND id = ?4",
         rusqlite::params![project, task_id, comment_id, reply_id],
     )?;
+    conn.execute(
+        "DELETE FROM review_reactions WHERE project_key = ?1 AND task_id = ?2 AND comment_id = ?3 AND reply_id = ?4",
+        rusqlite::params![project, task_id, comment_id, reply_id],
+    )?;
     Ok(deleted > 0)
 }
 
+/// 切换 reaction（已存在则移除）。`reply_id` 为 None 表示 comment 本身。
+///
+/// 返回 Ok(None) 表示 comment / reply 不存在；Ok(Some(true)) 表示已添加。
+pub fn toggle_reaction(
+    project: &str,
+    task_id: &str,
+    comment_id: u32,
+    reply_id: Option<u32>,
+    emoji: &str,
+    author: &str,
+) -> Result<Option<bool>> {
+    if !REACTION_EMOJIS.contains(&emoji) {
+        return Err(crate::error::GroveError::Storage(format!(
+            "Unsupported reaction: {}",
+            emoji
+        )));
+    }
+
+    let conn = database::connection();
+    let target_exists: bool = match reply_id {
+        None => conn.query_row(
+            "SELECT COUNT(*) > 0 FROM review_comments WHERE project_key = ?1 AND task_id = ?2 AND id = ?3",
+            rusqlite::params![project, task_id, comment_id],
+            |row| row.get(0),
+        )?,
+        Some(rid) => conn.query_row(
+            "SELECT COUNT(*) > 0 FROM review_replies WHERE project_key = ?1 AND task_id = ?2 AND comment_id = ?3 AND id = ?4",
+            rusqlite::params![project, task_id, comment_id, rid],
+            |row| row.get(0),
+        )?,
+    };
+    if !target_exists {
+        return Ok(None);
+    }
+
+    let reply_id = reply_id.unwrap_or(0);
+    let removed = conn.execute(
+        "DELETE FROM review_reactions
+         WHERE project_key = ?1 AND task_id = ?2 AND comment_id = ?3 AND reply_id = ?4 AND emoji = ?5 AND author = ?6",
+        rusqlite::params![project, task_id, comment_id, reply_id, emoji, author],
+    )?;
+    if removed > 0 {
+        return Ok(Some(false));
+    }
+
+    conn.execute(
+        "INSERT INTO review_reactions (project_key, task_id, comment_id, reply_id, emoji, author, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        rusqlite::params![
+            project,
+            task_id,
+            comment_id,
+            reply_id,
+            emoji,
+            author,
+            chrono::Utc::now().to_rfc3339()
+        ],
+    )?;
+    Ok(Some(true))
+}
+
+/// 提交 review：在同一事务内发布该 reviewer 的全部草稿并记录结论。
+///
+/// 没有草稿、没有总结且结论只是 `Comment` 时无内容可提交，返回错误。
+pub fn submit_review(
+    project: &str,
+    task_id: &str,
+    reviewer: &str,
+    verdict: ReviewVerdict,
+    summary: &str,
+) -> Result<ReviewSubmission> {
+    let conn = database::connection();
+    let tx = conn.unchecked_transaction()?;
+
+    let comment_ids: Vec<u32> = {
+        let mut stmt = tx.prepare(
+            "SELECT id FROM review_comments
+             WHERE project_key = ?1 AND task_id = ?2 AND draft_owner = ?3
+             ORDER BY id",
+        )?;
+        let rows = stmt.query_map(rusqlite::params![project, task_id, reviewer], |row| {
+            row.get(0)
+        })?;
+        rows.collect::<std::result::Result<_, _>>()?
+    };
+
+    let summary = summary.trim();
+    if comment_ids.is_empty() && summary.is_empty() && verdict == ReviewVerdict::Comment {
+        return Err(crate::error::GroveError::Storage(
+            "Nothing to submit: no pending comments or summary".to_string(),
+        ));
+    }
+
+    tx.execute(
+        "UPDATE review_comments SET draft_owner = NULL
+         WHERE project_key = ?1 AND task_id = ?2 AND draft_owner = ?3",
+        rusqlite::params![project, task_id, reviewer],
+    )?;
+
+    let id: u32 = tx.query_row(
+        "SELECT COALESCE(MAX(id), 0) + 1 FROM review_submissions WHERE project_key = ?1 AND task_id = ?2",
+        rusqlite::params![project, task_id],
+        |row| row.get(0),
+    )?;
+    let submission = ReviewSubmission {
+        id,
+        reviewer: reviewer.to_string(),
+        verdict,
+        summary: summary.to_string(),
+        comment_ids,
+        timestamp: chrono::Utc::now().to_rfc3339(),
+    };
+    tx.execute(
+        "INSERT INTO review_submissions (id, project_key, task_id, reviewer, verdict, summary, comment_ids, timestamp)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        rusqlite::params![
+            submission.id,
+            project,
+            task_id,
+            submission.reviewer,
+            submission.verdict.as_str(),
+            submission.summary,
+            serde_json::to_string(&submission.comment_ids)?,
+            submission.timestamp,
+        ],
+    )?;
+    tx.commit()?;
+
+    Ok(submission)
+}
+
+/// 丢弃某位 reviewer 的全部草稿
+pub fn discard_draft_comments(project: &str, task_id: &str, reviewer: &str) -> Result<usize> {
+    let conn = database::connection();
+    let deleted = conn.execute(
+        "DELETE FROM review_comments WHERE project_key = ?1 AND task_id = ?2 AND draft_owner = ?3",
+        rusqlite::params![project, task_id, reviewer],
+    )?;
+    Ok(deleted)
+}
+
+/// 读取全部已提交的 review（按提交顺序）
+pub fn load_review_submissions(project: &str, task_id: &str) -> Result<Vec<ReviewSubmission>> {
+    let conn = database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT id, reviewer, verdict, summary, comment_ids, timestamp
+         FROM review_submissions
+         WHERE project_key = ?1 AND task_id = ?2
+         ORDER BY id",
+    )?;
+    let rows = stmt.query_map(rusqlite::params![project, task_id], |row| {
+        let verdict: String = row.get(2)?;
+        let ids: String = row.get(4)?;
+        Ok(ReviewSubmission {
+            id: row.get(0)?,
+            reviewer: row.get(1)?,
+            verdict: ReviewVerdict::parse(&verdict).unwrap_or_default(),
+            summary: row.get(3)?,
+            comment_ids: serde_json::from_str(&ids).unwrap_or_default(),
+            timestamp: row.get(5)?,
+        })
+    })?;
+    Ok(rows.collect::<std::result::Result<_, _>>()?)
+}
+
+/// 读取最近一次提交的 review
+pub fn latest_review_submission(project: &str, task_id: &str) -> Result<Option<ReviewSubmission>> {
+    Ok(load_review_submissions(project, task_id)?.pop())
+}
+
+/// 汇总 review 情况（评论数、处理结果、reviewer、结论），用于 merge commit message。
+/// 没有任何评论和提交时返回 None。
+pub fn format_review_summary(
+    data: &CommentsData,
+    submissions: &[ReviewSubmission],
+) -> Option<String> {
+    if data.is_empty() && submissions.is_empty() {
+        return None;
+    }
+
+    let mut out = String::new();
+    let (open, resolved, outdated) = data.count_by_status();
+    let replies: usize = data.comments.iter().map(|c| c.replies.len()).sum();
+    out.push_str(&format!(
+        "- Comments: {} ({} resolved, {} open, {} outdated), {} replies\n",
+        data.comments.len(),
+        resolved,
+        open,
+        outdated,
+        replies
+    ));
+
+    let mut reviewers: Vec<String> = Vec::new();
+    let names = data
+        .comments
+        .iter()
+        .map(|c| build_author(&c.agent, &c.role))
+        .chain(submissions.iter().map(|s| s.reviewer.clone()));
+    for name in names {
+        if !reviewers.contains(&name) {
+            reviewers.push(name);
+        }
+    }
+    if !reviewers.is_empty() {
+        out.push_str(&format!("- Reviewers: {}\n", reviewers.join(", ")));
+    }
+
+    // 每位 reviewer 取最后一次非 Comment 的结论
+    let mut verdicts: Vec<(&str, ReviewVerdict)> = Vec::new();
+    for s in submissions
+        .iter()
+        .filter(|s| s.verdict != ReviewVerdict::Comment)
+    {
+        match verdicts.iter_mut().find(|(r, _)| *r == s.reviewer) {
+            Some(entry) => entry.1 = s.verdict,
+            None => verdicts.push((&s.reviewer, s.verdict)),
+        }
+    }
+    if !verdicts.is_empty() {
+        let list: Vec<String> = verdicts
+            .iter()
+            .map(|(r, v)| match v {
+                ReviewVerdict::Approve => format!("{} approved", r),
+                _ => format!("{} requested changes", r),
+            })
+            .collect();
+        out.push_str(&format!("- Verdicts: {}\n", list.join(", ")));
+    }
+
+    Some(out)
+}
+
+/// 读取并汇总某个 task 的 review 情况
+pub fn review_summary(project: &str, task_id: &str) -> Result<Option<String>> {
+    let data = load_comments(project, task_id)?;
+    let submissions = load_review_submissions(project, task_id)?;
+    Ok(format_review_summary(&data, &submissions))
+}
+
+/// 评论位置的简短描述（"src/a.rs:L3-L5" / "src/a.rs" / "project"）
+pub fn comment_location(c: &Comment) -> String {
+    match (c.comment_type, &c.file_path) {
+        (CommentType::Inline, Some(path)) => match (c.start_line, c.end_line) {
+            (Some(s), Some(e)) if s != e => format!("{}:L{}-L{}", path, s, e),
+            (Some(s), _) => format!("{}:L{}", path, s),
+            _ => path.clone(),
+        },
+        (_, Some(path)) => path.clone(),
+        _ => "project".to_string(),
+    }
+}
+
+/// 为 agent 生成一次 review 提交的汇总 prompt
+pub fn build_review_prompt(submission: &ReviewSubmission, comments: &[Comment]) -> String {
+    let verdict = match submission.verdict {
+        ReviewVerdict::Approve => "approved your changes",
+        ReviewVerdict::RequestChanges => "requested changes",
+        ReviewVerdict::Comment => "left review comments",
+    };
+    let mut out = format!("{} {}.\n", submission.reviewer, verdict);
+
+    if !submission.summary.is_empty() {
+        out.push_str(&format!("\n{}\n", submission.summary));
+    }
+
+    let included: Vec<&Comment> = comments
+        .iter()
+        .filter(|c| submission.comment_ids.contains(&c.id))
+        .collect();
+    if !included.is_empty() {
+        out.push_str(&format!("\nComments ({}):\n", included.len()));
+        for c in &included {
+            out.push_str(&format!(
+                "- #{} {}: {}\n",
+                c.id,
+                comment_location(c),
+                c.content
+            ));
+        }
+        out.push_str(
+            "\nAddress each comment, then reply to it with `grove_reply_review` (use `grove_read_review` for full threads).\n",
+        );
+    }
+
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -859,6 +1431,7 @@ mod tests {
             status: CommentStatus::Open,
             replies: Vec::new(),
             anchor_text: Some("original_code".to_string()),
+            reactions: Vec::new(),
         }
     }
 
@@ -895,6 +1468,53 @@ mod tests {
         assert_eq!(data.comments[0].end_line, Some(6));
     }
 
+    #[test]
+    fn test_outdated_detection_follows_reformatted_anchor() {
+        let mut comment = test_comment();
+        comment.anchor_text = Some("if x {\n    run();\n}".to_string());
+        comment.start_line = Some(1);
+        comment.end_line = Some(3);
+        let mut data = CommentsData {
+            comments: vec![comment],
+        };
+
+        apply_outdated_detection(&mut data, |_, _| {
+            Some("// header\nif  x {\n\trun();\n}".to_string())
+        });
+
+        let c = &data.comments[0];
+        assert_eq!(c.status, CommentStatus::Open);
+        assert_eq!((c.start_line, c.end_line), (Some(2), Some(4)));
+        assert_eq!(c.anchor_text.as_deref(), Some("if  x {\n\trun();\n}"));
+    }
+
+    #[test]
+    fn test_outdated_detection_fuzzy_nearby_only() {
+        let anchor = "fn a() {\n    let v = 1;\n    call(v);\n    done();\n}";
+        let edited = "fn a() {\n    let v = 2;\n    call(v);\n    done();\n}";
+        let mut comment = test_comment();
+        comment.anchor_text = Some(anchor.to_string());
+        comment.start_line = Some(3);
+        comment.end_line = Some(7);
+
+        // One line edited, block shifted down by one: re-anchored
+        let nearby = format!("use x;\n\n\n{}", edited);
+        let mut data = CommentsData {
+            comments: vec![comment.clone()],
+        };
+        apply_outdated_detection(&mut data, |_, _| Some(nearby.clone()));
+        assert_eq!(data.comments[0].status, CommentStatus::Open);
+        assert_eq!(data.comments[0].start_line, Some(4));
+
+        // Same edit but moved far outside the search window: outdated
+        let far = format!("{}{}", "// pad\n".repeat(200), edited);
+        let mut data = CommentsData {
+            comments: vec![comment],
+        };
+        apply_outdated_detection(&mut data, |_, _| Some(far.clone()));
+        assert_eq!(data.comments[0].status, CommentStatus::Outdated);
+    }
+
     #[test]
     fn test_outdated_detection_skips_resolved() {
         let mut comment = test_comment();
@@ -934,4 +1554,190 @@ mod tests {
 
         assert_eq!(data.comments[0].status, CommentStatus::Outdated);
     }
+
+    #[test]
+    fn test_draft_review_submit_and_discard() {
+        let _lock = database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        let add = |content: &str, owner: Option<&str>| {
+            add_comment(
+                "p",
+                "t",
+                CommentType::Project,
+                None,
+                None,
+                None,
+                None,
+                content,
+                "alice",
+                "",
+                "",
+                None,
+                owner,
+            )
+            .unwrap()
+        };
+        add("published", None);
+        let d1 = add("draft one", Some("alice"));
+        add("someone else", Some("bob"));
+
+        assert_eq!(load_comments("p", "t").unwrap().comments.len(), 1);
+        assert_eq!(
+            load_draft_comments("p", "t", "alice")
+                .unwrap()
+                .comments
+                .len(),
+            1
+        );
+
+        let sub =
+            submit_review("p", "t", "alice", ReviewVerdict::RequestChanges, " fix it ").unwrap();
+        assert_eq!(sub.comment_ids, vec![d1.id]);
+        assert_eq!(sub.summary, "fix it");
+        assert_eq!(load_comments("p", "t").unwrap().comments.len(), 2);
+        assert!(load_draft_comments("p", "t", "alice").unwrap().is_empty());
+        let latest = latest_review_submission("p", "t").unwrap().unwrap();
+        assert_eq!(latest.verdict, ReviewVerdict::RequestChanges);
+        assert_eq!(latest.comment_ids, vec![d1.id]);
+
+        // Nothing pending and nothing to say
+        assert!(submit_review("p", "t", "alice", ReviewVerdict::Comment, "").is_err());
+
+        assert_eq!(discard_draft_comments("p", "t", "bob").unwrap(), 1);
+        assert_eq!(load_comments("p", "t").unwrap().comments.len(), 2);
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_toggle_reaction() {
+        let _lock = database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        let c = add_comment(
+            "p",
+            "t",
+            CommentType::Project,
+            None,
+            None,
+            None,
+            None,
+            "looks good",
+            "alice",
+            "",
+            "",
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(reply_comment("p", "t", c.id, "thanks", "Claude", "", "Implementer").unwrap());
+
+        assert_eq!(
+            toggle_reaction("p", "t", c.id, None, "👍", "bob").unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            toggle_reaction("p", "t", c.id, None, "👍", "alice").unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            toggle_reaction("p", "t", c.id, Some(1), "🚀", "bob").unwrap(),
+            Some(true)
+        );
+        assert!(toggle_reaction("p", "t", c.id, None, "🎉", "bob").is_err());
+        assert_eq!(
+            toggle_reaction("p", "t", 999, None, "👍", "bob").unwrap(),
+            None
+        );
+        assert_eq!(
+            toggle_reaction("p", "t", c.id, Some(9), "👍", "bob").unwrap(),
+            None
+        );
+
+        let loaded = &load_comments("p", "t").unwrap().comments[0];
+        assert_eq!(loaded.reactions.len(), 1);
+        assert_eq!(loaded.reactions[0].authors, vec!["bob", "alice"]);
+        assert_eq!(loaded.replies[0].reactions[0].emoji, "🚀");
+
+        // Toggling again removes it
+        assert_eq!(
+            toggle_reaction("p", "t", c.id, None, "👍", "bob").unwrap(),
+            Some(false)
+        );
+        let loaded = &load_comments("p", "t").unwrap().comments[0];
+        assert_eq!(loaded.reactions[0].authors, vec!["alice"]);
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_format_review_summary() {
+        assert_eq!(format_review_summary(&CommentsData::default(), &[]), None);
+
+        let mut resolved = test_comment();
+        resolved.status = CommentStatus::Resolved;
+        resolved.replies.push(CommentReply {
+            id: 1,
+            content: "done".to_string(),
+            agent: "Claude".to_string(),
+            model: String::new(),
+            role: String::new(),
+            timestamp: String::new(),
+            reactions: Vec::new(),
+        });
+        let mut other = test_comment();
+        other.id = 2;
+        other.agent = "bob".to_string();
+        let data = CommentsData {
+            comments: vec![resolved, other],
+        };
+        let submission = |id, reviewer: &str, verdict| ReviewSubmission {
+            id,
+            reviewer: reviewer.to_string(),
+            verdict,
+            summary: String::new(),
+            comment_ids: Vec::new(),
+            timestamp: String::new(),
+        };
+        let submissions = [
+            submission(1, "You", ReviewVerdict::RequestChanges),
+            submission(2, "bob", ReviewVerdict::Comment),
+            submission(3, "You", ReviewVerdict::Approve),
+        ];
+
+        assert_eq!(
+            format_review_summary(&data, &submissions).unwrap(),
+            "- Comments: 2 (1 resolved, 1 open, 0 outdated), 1 replies\n\
+             - Reviewers: You, bob\n\
+             - Verdicts: You approved\n"
+        );
+    }
+
+    #[test]
+    fn test_build_review_prompt() {
+        let mut inline = test_comment();
+        inline.end_line = Some(7);
+        let mut project = test_comment();
+        project.id = 2;
+        project.comment_type = CommentType::Project;
+        project.file_path = None;
+        project.content = "add tests".to_string();
+        let submission = ReviewSubmission {
+            id: 1,
+            reviewer: "alice".to_string(),
+            verdict: ReviewVerdict::RequestChanges,
+            summary: "Almost there".to_string(),
+            comment_ids: vec![1, 2],
+            timestamp: String::new(),
+        };
+
+        let prompt = build_review_prompt(&submission, &[inline, project]);
+        assert!(prompt.starts_with("alice requested changes.\n\nAlmost there\n"));
+        assert!(prompt.contains("- #1 src/main.rs:L5-L7: fix this\n"));
+        assert!(prompt.contains("- #2 project: add tests\n"));
+        assert!(prompt.contains("grove_reply_review"));
+    }
 }
@@ -1,6 +1,7 @@
 //! 应用配置持久化
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
 use std::fs;
 use std::path::PathBuf;
@@ -93,6 +94,24 @@ pub struct HooksConfig {
     /// Agent Permission Required 声音名称（默认 Purr）
     #[serde(default = "default_permission_sound")]
     pub permission_sound: String,
+    /// 各通知级别触发的自定义 shell 命令
+    #[serde(default)]
+    pub commands: HookCommands,
+    /// 创建 task 时自动写入 agent hooks 配置（如 `.claude/settings.local.json`）
+    #[serde(default = "default_true")]
+    pub install_agent_hooks: bool,
+}
+
+/// 按通知级别配置的 shell 命令（`[hooks.commands]`），为空则不执行。
+/// 命令通过 `GROVE_HOOK_*` / `GROVE_TASK_*` 环境变量拿到事件内容。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HookCommands {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notice: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warn: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical: Option<String>,
 }
 
 fn default_true() -> bool {
@@ -114,6 +133,8 @@ impl Default for HooksConfig {
             response_sound: default_response_sound(),
             permission_sound_enabled: true,
             permission_sound: default_permission_sound(),
+            commands: HookCommands::default(),
+            install_agent_hooks: true,
         }
     }
 }
@@ -136,6 +157,9 @@ pub struct AcpConfig {
     /// Prune when the frontend chat view reaches this many UI messages.
     #[serde(default = "default_acp_render_window_trigger")]
     pub render_window_trigger: u32,
+    /// 同一任务的多个 chat 共享一个进程的 agent（需 agent 支持单进程多 session）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shared_process_agents: Vec<String>,
 }
 
 impl Default for AcpConfig {
@@ -146,6 +170,7 @@ impl Default for AcpConfig {
             custom_agents: Vec::new(),
             render_window_limit: 0,
             render_window_trigger: default_acp_render_window_trigger(),
+            shared_process_agents: Vec::new(),
         }
     }
 }
@@ -181,6 +206,9 @@ pub enum LastLaunch {
         /// API server is started; the frontend connects to this URL instead.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         remote_url: Option<String>,
+        /// 只读模式（拒绝所有修改请求）
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        read_only: bool,
     },
     Mobile {
         #[serde(default = "default_web_port")]
@@ -227,14 +255,16 @@ impl LastLaunch {
                 port,
                 dev,
                 remote_url,
+                read_only,
                 ..
             } => {
+                let flag = if *read_only { " --read-only" } else { "" };
                 if let Some(url) = remote_url {
-                    format!("web --remote-url {} (port {})", url, port)
+                    format!("web --remote-url {}{} (port {})", url, flag, port)
                 } else if *dev {
-                    format!("web --dev (port {})", port)
+                    format!("web --dev{} (port {})", flag, port)
                 } else {
-                    format!("web (port {})", port)
+                    format!("web{} (port {})", flag, port)
                 }
             }
             LastLaunch::Mobile { port, tls, .. } => {
@@ -289,6 +319,58 @@ pub struct Config {
     #[serde(default)]
     pub voice_control: VoiceControlConfig,
 
+    #[serde(default)]
+    pub logging: LoggingConfig,
+
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+
+    #[serde(default)]
+    pub auth: AuthConfig,
+
+    #[serde(default)]
+    pub status_inference: StatusInferenceConfig,
+
+    #[serde(default)]
+    pub review: ReviewConfig,
+
+    #[serde(default)]
+    pub sync: SyncConfig,
+
+    #[serde(default)]
+    pub organization: OrganizationConfig,
+
+    #[serde(default)]
+    pub clipboard: ClipboardConfig,
+
+    #[serde(default)]
+    pub signing: SigningConfig,
+
+    #[serde(default)]
+    pub risk: RiskConfig,
+
+    #[serde(default)]
+    pub task_list: TaskListConfig,
+
+    #[serde(default)]
+    pub display: DisplayConfig,
+
+    #[serde(default)]
+    pub accessibility: AccessibilityConfig,
+
+    #[serde(default)]
+    pub github: ForgeAuthConfig,
+
+    #[serde(default)]
+    pub gitlab: ForgeAuthConfig,
+
+    #[serde(default)]
+    pub gitea: ForgeAuthConfig,
+
+    /// 自定义 "Open With" 应用（按列表顺序展示）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_with: Vec<OpenWithApp>,
+
     /// Storage layout version (None = legacy, "1.0" = task-centric layout)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub storage_version: Option<String>,
@@ -355,6 +437,73 @@ pub struct NotificationsConfig {
     /// dropped by this policy — only done ones.
     #[serde(default = "default_tray_done_retention")]
     pub tray_done_retention: RetentionPolicy,
+
+    /// 勿扰模式：静音所有声音和系统横幅（hooks.toml / Inbox 仍照常记录）
+    #[serde(default)]
+    pub do_not_disturb: bool,
+    /// 定时勿扰时段（本地时间）
+    #[serde(default)]
+    pub quiet_hours: QuietHours,
+}
+
+/// 每天的定时勿扰时段，`start` > `end` 表示跨午夜（如 22:00 → 08:00）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuietHours {
+    #[serde(default)]
+    pub enabled: bool,
+    /// "HH:MM"
+    #[serde(default = "default_quiet_start")]
+    pub start: String,
+    /// "HH:MM"
+    #[serde(default = "default_quiet_end")]
+    pub end: String,
+}
+
+fn default_quiet_start() -> String {
+    "22:00".to_string()
+}
+
+fn default_quiet_end() -> String {
+    "08:00".to_string()
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            start: default_quiet_start(),
+            end: default_quiet_end(),
+        }
+    }
+}
+
+/// 解析 "HH:MM"
+pub fn parse_hhmm(value: &str) -> Option<chrono::NaiveTime> {
+    chrono::NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+impl QuietHours {
+    /// `time` 是否落在勿扰时段内（时间格式非法或 start == end 时视为不生效）
+    pub fn contains(&self, time: chrono::NaiveTime) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let (Some(start), Some(end)) = (parse_hhmm(&self.start), parse_hhmm(&self.end)) else {
+            return false;
+        };
+        if start <= end {
+            start <= time && time < end
+        } else {
+            time >= start || time < end
+        }
+    }
+}
+
+impl NotificationsConfig {
+    /// 在 `now` 时刻声音 / 横幅是否被静音（手动勿扰或处于定时时段）
+    pub fn is_muted_at(&self, now: chrono::NaiveTime) -> bool {
+        self.do_not_disturb || self.quiet_hours.contains(now)
+    }
 }
 
 /// How long a tray "Done" chat is kept before automatic cleanup.
@@ -395,10 +544,612 @@ impl Default for NotificationsConfig {
             notification_show_running: false,
             menubar_shortcut: None,
             tray_done_retention: default_tray_done_retention(),
+            do_not_disturb: false,
+            quiet_hours: QuietHours::default(),
+        }
+    }
+}
+
+/// Logging config. `GROVE_LOG` env var takes precedence over `filter`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct LoggingConfig {
+    /// `EnvFilter` directives, e.g. "info,grove::acp=debug" (None = "info")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+}
+
+/// Prometheus `/metrics` endpoint (web server only).
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MetricsConfig {
+    /// Mount `GET /metrics` on the web server
+    #[serde(default)]
+    pub enabled: bool,
+    /// When set, scrapers must send `Authorization: Bearer <token>`.
+    /// When unset, the endpoint sits behind the same auth as the API.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bearer_token: Option<String>,
+}
+
+/// 移动端访问（`grove mobile`，HMAC 模式）的会话策略。
+/// 验证密钥后签发会话，超时或超过有效期后需重新验证（Web 端自动锁定）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuthConfig {
+    /// 无操作超时（分钟），用户操作会顺延；0 = 不启用会话
+    #[serde(default)]
+    pub idle_timeout_minutes: u32,
+    /// 会话最长有效期（小时），不随操作顺延；0 = 不限
+    #[serde(default)]
+    pub max_session_hours: u32,
+    /// 会话绑定签发时的客户端 IP（经反向代理访问时所有客户端 IP 相同）
+    #[serde(default)]
+    pub pin_ip: bool,
+    /// 同一 IP / 设备连续验证失败多少次后锁定；0 = 不限制
+    #[serde(default = "default_max_failed_attempts")]
+    pub max_failed_attempts: u32,
+    /// 首次锁定时长（秒），之后每次锁定翻倍，最长一天
+    #[serde(default = "default_lockout_secs")]
+    pub lockout_secs: u32,
+}
+
+impl Default for AuthConfig {
+    fn default() -> Self {
+        Self {
+            idle_timeout_minutes: 0,
+            max_session_hours: 0,
+            pin_ip: false,
+            max_failed_attempts: default_max_failed_attempts(),
+            lockout_secs: default_lockout_secs(),
+        }
+    }
+}
+
+fn default_max_failed_attempts() -> u32 {
+    5
+}
+
+fn default_lockout_secs() -> u32 {
+    30
+}
+
+/// 剪贴板（TUI 复制操作）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipboardConfig {
+    /// 同时写出 OSC 52 转义序列，使 SSH / tmux 内的复制也能到达本地剪贴板。
+    /// 部分终端会拦截或弹出提示，此时可关闭
+    #[serde(default = "default_true")]
+    pub osc52: bool,
+}
+
+impl Default for ClipboardConfig {
+    fn default() -> Self {
+        Self { osc52: true }
+    }
+}
+
+/// 代码托管平台的访问凭据（从任务创建 PR / MR）
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct ForgeAuthConfig {
+    /// Access token（需要仓库和 Pull / Merge Request 写权限）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl ForgeAuthConfig {
+    /// 去掉空白后的 token；未配置时为 None
+    pub fn token(&self) -> Option<&str> {
+        self.token
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+    }
+}
+
+/// TUI 任务列表中可选的列（TASK 列始终显示）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskColumn {
+    /// agent 状态（Live 任务）或任务状态
+    Status,
+    /// target 分支
+    Target,
+    /// 相对 target 的 diff 行数
+    Diff,
+    /// 落后 target 的 commit 数
+    Behind,
+    /// 最后活动时间
+    Updated,
+    /// 会话类型（tmux / zellij / acp）
+    Session,
+}
+
+impl TaskColumn {
+    pub const ALL: [TaskColumn; 6] = [
+        TaskColumn::Status,
+        TaskColumn::Target,
+        TaskColumn::Diff,
+        TaskColumn::Behind,
+        TaskColumn::Updated,
+        TaskColumn::Session,
+    ];
+
+    /// 列表头
+    pub fn header(&self) -> &'static str {
+        match self {
+            TaskColumn::Status => "STATUS",
+            TaskColumn::Target => "TARGET",
+            TaskColumn::Diff => "DIFF",
+            TaskColumn::Behind => "↓",
+            TaskColumn::Updated => "UPDATED",
+            TaskColumn::Session => "SESSION",
+        }
+    }
+
+    /// 列选择器中显示的名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            TaskColumn::Status => "Agent / task status",
+            TaskColumn::Target => "Target branch",
+            TaskColumn::Diff => "Diff stats",
+            TaskColumn::Behind => "Commits behind",
+            TaskColumn::Updated => "Last activity",
+            TaskColumn::Session => "Session type",
+        }
+    }
+}
+
+/// TUI 任务列表
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TaskListConfig {
+    /// 显示的列及顺序（宽度不足时从末尾开始隐藏）
+    #[serde(default = "default_task_columns")]
+    pub columns: Vec<TaskColumn>,
+}
+
+fn default_task_columns() -> Vec<TaskColumn> {
+    vec![
+        TaskColumn::Status,
+        TaskColumn::Target,
+        TaskColumn::Behind,
+        TaskColumn::Updated,
+    ]
+}
+
+impl Default for TaskListConfig {
+    fn default() -> Self {
+        Self {
+            columns: default_task_columns(),
+        }
+    }
+}
+
+/// 无障碍配置（`[accessibility]`）
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AccessibilityConfig {
+    /// 减少装饰：纯 ASCII 符号、无渐变色块 / 字符画 Logo，状态始终附带文字标签
+    #[serde(default)]
+    pub reduced_decoration: bool,
+}
+
+/// 时间戳显示方式
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeFormat {
+    /// "3 hours ago"
+    #[default]
+    Relative,
+    /// 按 locale 格式化的本地日期时间
+    Absolute,
+}
+
+/// 时间显示配置（`[display]`）
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DisplayConfig {
+    #[serde(default)]
+    pub time_format: TimeFormat,
+    /// 日期格式所用 locale（如 "en-US"、"de-DE"），为空时跟随 LC_ALL / LC_TIME / LANG
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub locale: String,
+}
+
+impl DisplayConfig {
+    /// 实际生效的 locale：配置优先，其次系统环境变量，最后 "en-US"
+    pub fn effective_locale(&self) -> String {
+        if !self.locale.trim().is_empty() {
+            return self.locale.trim().to_string();
+        }
+        ["LC_ALL", "LC_TIME", "LANG"]
+            .iter()
+            .filter_map(|k| std::env::var(k).ok())
+            .find(|v| !v.is_empty() && v != "C" && v != "POSIX")
+            .unwrap_or_else(|| "en-US".to_string())
+    }
+}
+
+/// 按 locale 选择绝对时间的 strftime 格式（统一 24 小时制，宽度固定 16 列）
+///
+/// 接受 "de_DE.UTF-8"、"en-GB"、"ja" 等写法。
+pub fn locale_datetime_pattern(locale: &str) -> &'static str {
+    let tag = locale
+        .split(['.', '@'])
+        .next()
+        .unwrap_or("")
+        .replace('_', "-")
+        .to_ascii_lowercase();
+    let mut parts = tag.split('-');
+    let lang = parts.next().unwrap_or("");
+    let region = parts.next().unwrap_or("");
+
+    match (lang, region) {
+        ("en", "us") | ("en", "ph") => "%m/%d/%Y %H:%M",
+        ("en", "ca") | ("sv", _) | ("lt", _) | ("fr", "ca") => "%Y-%m-%d %H:%M",
+        ("zh", _) | ("ja", _) => "%Y/%m/%d %H:%M",
+        ("ko", _) | ("hu", _) => "%Y.%m.%d %H:%M",
+        ("de", _)
+        | ("ru", _)
+        | ("pl", _)
+        | ("cs", _)
+        | ("fi", _)
+        | ("nb", _)
+        | ("no", _)
+        | ("da", _)
+        | ("tr", _)
+        | ("uk", _)
+        | ("ro", _) => "%d.%m.%Y %H:%M",
+        ("nl", _) => "%d-%m-%Y %H:%M",
+        ("en", "") => "%m/%d/%Y %H:%M",
+        ("en", _)
+        | ("fr", _)
+        | ("es", _)
+        | ("it", _)
+        | ("pt", _)
+        | ("el", _)
+        | ("vi", _)
+        | ("id", _) => "%d/%m/%Y %H:%M",
+        _ => "%Y-%m-%d %H:%M",
+    }
+}
+
+/// 签名格式（对应 git 的 `gpg.format`）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SigningFormat {
+    #[default]
+    Openpgp,
+    Ssh,
+    X509,
+}
+
+impl SigningFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SigningFormat::Openpgp => "openpgp",
+            SigningFormat::Ssh => "ssh",
+            SigningFormat::X509 => "x509",
+        }
+    }
+}
+
+/// Grove 发起的 commit / merge 的签名设置
+///
+/// `enabled` 是默认值，项目可以单独开启或关闭（存在 projects 表）。
+/// 自动 checkpoint 不签名，避免后台提交卡在密码输入上。
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct SigningConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub format: SigningFormat,
+    /// key id（GPG）/ 公钥路径或内容（SSH）；为空时使用 git 的 `user.signingkey`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+impl SigningConfig {
+    /// 项目覆盖优先，其次为全局开关
+    pub fn enabled_for(&self, project_override: Option<bool>) -> bool {
+        project_override.unwrap_or(self.enabled)
+    }
+
+    /// 放在子命令前的 `-c` 选项
+    pub fn config_args(&self) -> Vec<String> {
+        vec![
+            "-c".to_string(),
+            format!("gpg.format={}", self.format.as_str()),
+        ]
+    }
+
+    /// commit / merge 的签名参数：`-S` 或 `-S<key>`
+    pub fn sign_flag(&self) -> String {
+        match self.key.as_deref().map(str::trim) {
+            // SSH 公钥路径允许写 ~/
+            Some(key) if !key.is_empty() => format!("-S{}", super::workspace::expand_tilde(key)),
+            _ => "-S".to_string(),
         }
     }
 }
 
+/// 任务风险评分规则
+///
+/// 分数 0-100，由各项按权重累加：改动文件数、命中关键路径、删除占比、
+/// 改了代码却没改测试。在任务列表和 merge 确认弹窗中展示。
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RiskConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 关键路径 glob（如迁移脚本、CI 配置），命中越多分越高
+    #[serde(default = "default_risk_critical_paths")]
+    pub critical_paths: Vec<String>,
+    /// 识别测试文件的 glob
+    #[serde(default = "default_risk_test_paths")]
+    pub test_paths: Vec<String>,
+    /// 改动文件数达到该值时「文件数」一项拿满分
+    #[serde(default = "default_risk_max_files")]
+    pub max_files: u32,
+    #[serde(default)]
+    pub weights: RiskWeights,
+    /// 分数 >= medium 为中风险
+    #[serde(default = "default_risk_medium")]
+    pub medium: u32,
+    /// 分数 >= high 为高风险
+    #[serde(default = "default_risk_high")]
+    pub high: u32,
+}
+
+/// 各项的满分
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RiskWeights {
+    #[serde(default = "default_risk_weight_files")]
+    pub files: u32,
+    #[serde(default = "default_risk_weight_critical")]
+    pub critical_paths: u32,
+    #[serde(default = "default_risk_weight_deletions")]
+    pub deletions: u32,
+    #[serde(default = "default_risk_weight_no_tests")]
+    pub no_tests: u32,
+}
+
+fn default_risk_critical_paths() -> Vec<String> {
+    [
+        "**/migrations/**",
+        ".github/workflows/**",
+        "**/Dockerfile",
+        "**/*.lock",
+        "**/auth/**",
+        "**/security/**",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+fn default_risk_test_paths() -> Vec<String> {
+    [
+        "**/tests/**",
+        "**/test/**",
+        "**/__tests__/**",
+        "**/*_test.*",
+        "**/*.test.*",
+        "**/*.spec.*",
+        "**/test_*",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+fn default_risk_max_files() -> u32 {
+    30
+}
+
+fn default_risk_medium() -> u32 {
+    30
+}
+
+fn default_risk_high() -> u32 {
+    60
+}
+
+fn default_risk_weight_files() -> u32 {
+    25
+}
+
+fn default_risk_weight_critical() -> u32 {
+    35
+}
+
+fn default_risk_weight_deletions() -> u32 {
+    20
+}
+
+fn default_risk_weight_no_tests() -> u32 {
+    20
+}
+
+impl Default for RiskWeights {
+    fn default() -> Self {
+        Self {
+            files: default_risk_weight_files(),
+            critical_paths: default_risk_weight_critical(),
+            deletions: default_risk_weight_deletions(),
+            no_tests: default_risk_weight_no_tests(),
+        }
+    }
+}
+
+impl Default for RiskConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            critical_paths: default_risk_critical_paths(),
+            test_paths: default_risk_test_paths(),
+            max_files: default_risk_max_files(),
+            weights: RiskWeights::default(),
+            medium: default_risk_medium(),
+            high: default_risk_high(),
+        }
+    }
+}
+
+/// 自定义 "Open With" 应用：任务 worktree 用任意命令打开
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct OpenWithApp {
+    /// 显示名（唯一）
+    pub name: String,
+    /// 命令模板，经 shell 执行；`{path}` / `{branch}` 会替换为加引号的值
+    pub command: String,
+}
+
+impl OpenWithApp {
+    /// 展开命令模板
+    pub fn render(&self, path: &str, branch: &str) -> String {
+        self.command
+            .replace("{path}", &shell_quote(path))
+            .replace("{branch}", &shell_quote(branch))
+    }
+}
+
+/// 校验应用列表：名称与命令非空、名称不重复
+pub fn validate_open_with(apps: &[OpenWithApp]) -> std::result::Result<(), String> {
+    let mut seen = std::collections::HashSet::new();
+    for app in apps {
+        let name = app.name.trim();
+        if name.is_empty() {
+            return Err("Application name cannot be empty".to_string());
+        }
+        if app.command.trim().is_empty() {
+            return Err(format!("Command of '{}' cannot be empty", name));
+        }
+        if !seen.insert(name.to_lowercase()) {
+            return Err(format!("Duplicate application name '{}'", name));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(windows))]
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "'\\''"))
+}
+
+#[cfg(windows)]
+fn shell_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+/// Review diff 偏好（用户级）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewConfig {
+    /// 忽略空白差异（`-w`）
+    #[serde(default)]
+    pub ignore_whitespace: bool,
+    /// 忽略空行增删（`--ignore-blank-lines`）
+    #[serde(default)]
+    pub ignore_blank_lines: bool,
+    /// rename 检测（`-M`）
+    #[serde(default = "default_true")]
+    pub detect_renames: bool,
+    /// copy 检测（`-C`）
+    #[serde(default)]
+    pub detect_copies: bool,
+}
+
+impl Default for ReviewConfig {
+    fn default() -> Self {
+        Self {
+            ignore_whitespace: false,
+            ignore_blank_lines: false,
+            detect_renames: true,
+            detect_copies: false,
+        }
+    }
+}
+
+impl ReviewConfig {
+    /// 转换为 git diff 选项
+    pub fn diff_options(&self) -> crate::git::DiffOptions {
+        crate::git::DiffOptions {
+            ignore_whitespace: self.ignore_whitespace,
+            ignore_blank_lines: self.ignore_blank_lines,
+            detect_renames: self.detect_renames,
+            detect_copies: self.detect_copies,
+        }
+    }
+}
+
+/// 跨机器同步 ~/.grove 元数据（任务 / review comments / notes）到一个 git remote
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 用户提供的 git remote URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+    /// 自动同步间隔（分钟）
+    #[serde(default = "default_sync_interval")]
+    pub interval_minutes: u32,
+}
+
+fn default_sync_interval() -> u32 {
+    10
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            remote: None,
+            interval_minutes: default_sync_interval(),
+        }
+    }
+}
+
+/// Organization 模式：任务元数据 / notes / review comments 发布到团队 Grove 服务器，
+/// worktree 仍在本地
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OrganizationConfig {
+    /// 客户端：团队服务器地址（如 `https://grove.example.com`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_url: Option<String>,
+    /// 客户端：团队服务器发给本成员的 bearer token（服务器据此确定成员名）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// 服务端：成员名 → 发给该成员的 token。非空时本机 web 服务挂载 `/org`
+    /// 接口，成员只能改写以自己名义发布的任务
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub members: HashMap<String, String>,
+}
+
+/// 终端 agent 状态推断（Busy / Waiting / Idle）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusInferenceConfig {
+    /// 是否扫描 tmux pane 推断 agent 状态
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 按 agent 命令名（如 "claude"）覆盖内置匹配规则
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub agents: HashMap<String, AgentStatePatterns>,
+}
+
+impl Default for StatusInferenceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            agents: HashMap::new(),
+        }
+    }
+}
+
+/// 单个 agent 的屏幕匹配规则（子串匹配，忽略大小写）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AgentStatePatterns {
+    /// 出现在屏幕末尾时视为等待输入
+    #[serde(default)]
+    pub waiting: Vec<String>,
+    /// 出现在屏幕末尾时视为正在工作
+    #[serde(default)]
+    pub busy: Vec<String>,
+}
+
 /// MCP Server Config (Reserved for future extension)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct McpConfig {
@@ -599,6 +1350,13 @@ pub struct WebConfig {
     /// GUI-only global shortcut for showing or hiding the main window.
     #[serde(default)]
     pub show_hide_window_shortcut: Option<String>,
+    /// 无认证模式下额外信任的 Host 名（如经反向代理访问用的域名）。
+    /// 本机、IP 和局域网名称（单段名、`.local` 等）默认信任
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_hosts: Vec<String>,
+    /// 只读模式：只开放 GET 和事件订阅，其余请求返回 403（同 `grove web --read-only`）
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub read_only: bool,
 }
 
 /// 自定义布局配置
@@ -696,7 +1454,7 @@ fn default_dark_theme() -> String {
 }
 
 /// 主题配置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ThemeConfig {
     #[serde(default = "default_theme_name")]
     pub name: String,
@@ -708,6 +1466,43 @@ pub struct ThemeConfig {
     pub dark_theme: String,
     #[serde(default)]
     pub custom_themes: Vec<CustomThemeConfig>,
+    /// 状态色方案（色盲友好）
+    #[serde(default)]
+    pub status_palette: StatusPalette,
+    /// Web 端使用 TUI 当前主题的完整配色（含自定义主题）
+    #[serde(default)]
+    pub sync_tui_colors: bool,
+}
+
+/// 状态色方案：live / idle / conflict / error 等状态色是否避开红绿区分
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusPalette {
+    /// 使用主题自带的状态色
+    #[default]
+    Default,
+    /// 绿色弱 / 绿色盲：蓝 / 黄 / 朱红
+    Deuteranopia,
+    /// 红色弱 / 红色盲：蓝 / 黄 / 提亮的橙红
+    Protanopia,
+}
+
+impl StatusPalette {
+    pub fn all() -> [StatusPalette; 3] {
+        [
+            StatusPalette::Default,
+            StatusPalette::Deuteranopia,
+            StatusPalette::Protanopia,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatusPalette::Default => "Theme default",
+            StatusPalette::Deuteranopia => "Deuteranopia safe",
+            StatusPalette::Protanopia => "Protanopia safe",
+        }
+    }
 }
 
 /// 更新检查配置
@@ -719,20 +1514,111 @@ pub struct UpdateConfig {
     pub latest_version: Option<String>,
 }
 
+/// AutoLink 创建方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkMode {
+    /// 软链接（Windows 上为 hardlink/junction）
+    #[default]
+    Symlink,
+    /// 复制一份（受大小上限约束，可按需刷新）
+    Copy,
+    /// 硬链接；目录则逐文件硬链接
+    Hardlink,
+}
+
+impl LinkMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LinkMode::Symlink => "symlink",
+            LinkMode::Copy => "copy",
+            LinkMode::Hardlink => "hardlink",
+        }
+    }
+
+    /// 循环切换到下一个模式
+    pub fn next(&self) -> Self {
+        match self {
+            LinkMode::Symlink => LinkMode::Copy,
+            LinkMode::Copy => LinkMode::Hardlink,
+            LinkMode::Hardlink => LinkMode::Symlink,
+        }
+    }
+}
+
+impl FromStr for LinkMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "symlink" => Ok(LinkMode::Symlink),
+            "copy" => Ok(LinkMode::Copy),
+            "hardlink" => Ok(LinkMode::Hardlink),
+            _ => Err(format!("unknown link mode: {s}")),
+        }
+    }
+}
+
+/// 模式与命令中可展开的任务变量：`{branch}` / `{task_id}` / `{target}`
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateVars<'a> {
+    pub branch: &'a str,
+    pub task_id: &'a str,
+    pub target: &'a str,
+}
+
+impl TemplateVars<'_> {
+    /// 替换字符串中的变量；其它花括号（如 glob 的 `{a,b}`）保持不变
+    pub fn expand(&self, s: &str) -> String {
+        s.replace("{branch}", self.branch)
+            .replace("{task_id}", self.task_id)
+            .replace("{target}", self.target)
+    }
+
+    /// 用于 shell 命令的展开：变量换成对 `GROVE_BRANCH` / `GROVE_TASK_ID` /
+    /// `GROVE_TARGET` 环境变量的引用，而不是把值直接拼进命令——分支名里的
+    /// `$(...)`、反引号或 `;` 不会被 shell 执行。执行时需设置这些环境变量
+    pub fn expand_for_shell(s: &str) -> String {
+        #[cfg(windows)]
+        let var = |name: &str| format!("%{}%", name);
+        #[cfg(not(windows))]
+        let var = |name: &str| format!("\"${}\"", name);
+        s.replace("{branch}", &var("GROVE_BRANCH"))
+            .replace("{task_id}", &var("GROVE_TASK_ID"))
+            .replace("{target}", &var("GROVE_TARGET"))
+    }
+}
+
 /// AutoLink 配置：自动创建软链接
 ///
-/// 注意：AutoLink 始终启用，且仅链接被 gitignore 的路径
+/// 注意：AutoLink 始终启用，且仅链接被 gitignore 的路径。
+/// 模式与 `post_create` 命令支持 [`TemplateVars`] 变量。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutoLinkConfig {
     /// Glob 模式列表（支持 **, *, ? 等通配符）
     #[serde(default = "default_auto_link_patterns")]
     pub patterns: Vec<String>,
+    /// 按模式指定创建方式（未列出的模式使用 symlink）
+    #[serde(default)]
+    pub modes: HashMap<String, LinkMode>,
+    /// copy 模式下单个路径的大小上限（MB），超出则跳过
+    #[serde(default = "default_copy_max_mb")]
+    pub copy_max_mb: u64,
+    /// worktree 创建后在其中依次执行的命令（与 post-create 生命周期 hook 同一执行器，
+    /// 失败只作为警告，不影响任务创建；变量以环境变量引用的形式展开，
+    /// 见 [`TemplateVars::expand_for_shell`]）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub post_create: Vec<String>,
 }
 
 fn default_auto_link_patterns() -> Vec<String> {
     vec![]
 }
 
+fn default_copy_max_mb() -> u64 {
+    100
+}
+
 impl AutoLinkConfig {
     /// 规范化模式列表:去重、去除空白、过滤空模式
     pub fn normalize(&mut self) {
@@ -747,6 +1633,46 @@ impl AutoLinkConfig {
         // 去重(保持顺序)
         let mut seen = std::collections::HashSet::new();
         self.patterns.retain(|pattern| seen.insert(pattern.clone()));
+
+        // 只保留仍存在的模式，symlink 为默认值无需记录
+        let patterns = &self.patterns;
+        self.modes
+            .retain(|pattern, mode| *mode != LinkMode::Symlink && patterns.contains(pattern));
+
+        self.post_create = self
+            .post_create
+            .iter()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+    }
+
+    /// 为某个任务展开模式（连同其创建方式）与 post-create 命令
+    pub fn expand(&self, vars: &TemplateVars) -> Self {
+        Self {
+            patterns: self.patterns.iter().map(|p| vars.expand(p)).collect(),
+            modes: self
+                .modes
+                .iter()
+                .map(|(p, mode)| (vars.expand(p), *mode))
+                .collect(),
+            copy_max_mb: self.copy_max_mb,
+            post_create: self
+                .post_create
+                .iter()
+                .map(|c| TemplateVars::expand_for_shell(c))
+                .collect(),
+        }
+    }
+
+    /// 某个模式的创建方式
+    pub fn mode_for(&self, pattern: &str) -> LinkMode {
+        self.modes.get(pattern).copied().unwrap_or_default()
+    }
+
+    /// copy 模式的大小上限（字节）
+    pub fn copy_max_bytes(&self) -> u64 {
+        self.copy_max_mb.saturating_mul(1024 * 1024)
     }
 }
 
@@ -754,6 +1680,9 @@ impl Default for AutoLinkConfig {
     fn default() -> Self {
         Self {
             patterns: default_auto_link_patterns(),
+            modes: HashMap::new(),
+            copy_max_mb: default_copy_max_mb(),
+            post_create: Vec::new(),
         }
     }
 }
@@ -766,6 +1695,8 @@ impl Default for ThemeConfig {
             light_theme: default_light_theme(),
             dark_theme: default_dark_theme(),
             custom_themes: vec![],
+            status_palette: StatusPalette::default(),
+            sync_tui_colors: false,
         }
     }
 }
@@ -892,3 +1823,134 @@ impl Config {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_datetime_patterns() {
+        assert_eq!(locale_datetime_pattern("en-US"), "%m/%d/%Y %H:%M");
+        assert_eq!(locale_datetime_pattern("en_GB.UTF-8"), "%d/%m/%Y %H:%M");
+        assert_eq!(locale_datetime_pattern("de_DE.UTF-8"), "%d.%m.%Y %H:%M");
+        assert_eq!(locale_datetime_pattern("zh_CN"), "%Y/%m/%d %H:%M");
+        assert_eq!(locale_datetime_pattern("sv-SE"), "%Y-%m-%d %H:%M");
+        assert_eq!(locale_datetime_pattern(""), "%Y-%m-%d %H:%M");
+
+        let display = DisplayConfig {
+            time_format: TimeFormat::Absolute,
+            locale: " fr-FR ".into(),
+        };
+        assert_eq!(display.effective_locale(), "fr-FR");
+
+        let parsed: Config = toml::from_str("[display]\ntime_format = \"absolute\"\n").unwrap();
+        assert_eq!(parsed.display.time_format, TimeFormat::Absolute);
+        assert!(parsed.display.locale.is_empty());
+    }
+
+    #[test]
+    fn quiet_hours_wrap_midnight() {
+        let at = |h, m| chrono::NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        let mut quiet = QuietHours {
+            enabled: true,
+            ..Default::default()
+        };
+        assert!(quiet.contains(at(23, 30)));
+        assert!(quiet.contains(at(7, 59)));
+        assert!(!quiet.contains(at(8, 0)));
+        assert!(!quiet.contains(at(12, 0)));
+
+        quiet.start = "12:00".into();
+        quiet.end = "13:00".into();
+        assert!(quiet.contains(at(12, 30)));
+        assert!(!quiet.contains(at(23, 30)));
+
+        quiet.end = "bogus".into();
+        assert!(!quiet.contains(at(12, 30)));
+
+        let notifications = NotificationsConfig {
+            do_not_disturb: true,
+            ..Default::default()
+        };
+        assert!(notifications.is_muted_at(at(12, 0)));
+        assert!(!NotificationsConfig::default().is_muted_at(at(23, 0)));
+    }
+
+    #[test]
+    fn test_auto_link_expand() {
+        let config = AutoLinkConfig {
+            patterns: vec![".env.{branch}".into(), "**/{dist,build}".into()],
+            modes: HashMap::from([(".env.{branch}".to_string(), LinkMode::Copy)]),
+            copy_max_mb: 10,
+            post_create: vec!["cp .env.{target} .env.{task_id}".into()],
+        };
+        let vars = TemplateVars {
+            branch: "fix-login-1a2b",
+            task_id: "fix-login",
+            target: "main",
+        };
+
+        let expanded = config.expand(&vars);
+        assert_eq!(
+            expanded.patterns,
+            vec![".env.fix-login-1a2b", "**/{dist,build}"]
+        );
+        assert_eq!(expanded.mode_for(".env.fix-login-1a2b"), LinkMode::Copy);
+        #[cfg(not(windows))]
+        assert_eq!(
+            expanded.post_create,
+            vec![r#"cp .env."$GROVE_TARGET" .env."$GROVE_TASK_ID""#]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_post_create_vars_are_not_shell_code() {
+        let command = TemplateVars::expand_for_shell("printf %s {branch}");
+        let out = std::process::Command::new("sh")
+            .args(["-c", &command])
+            .env("GROVE_BRANCH", "x;$(echo pwned)`id`")
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&out.stdout), "x;$(echo pwned)`id`");
+    }
+
+    #[test]
+    fn test_signing_config() {
+        let cfg: SigningConfig =
+            toml::from_str("enabled = true\nformat = \"ssh\"\nkey = \"/keys/id_ed25519.pub\"")
+                .unwrap();
+        assert!(cfg.enabled_for(None));
+        assert!(!cfg.enabled_for(Some(false)));
+        assert_eq!(cfg.config_args(), vec!["-c", "gpg.format=ssh"]);
+        assert_eq!(cfg.sign_flag(), "-S/keys/id_ed25519.pub");
+        assert_eq!(SigningConfig::default().sign_flag(), "-S");
+        assert!(!SigningConfig::default().enabled_for(None));
+        assert!(SigningConfig::default().enabled_for(Some(true)));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_open_with_render_and_validate() {
+        let app = OpenWithApp {
+            name: "Fork".into(),
+            command: "open -a Fork {path} # {branch}".into(),
+        };
+        assert_eq!(
+            app.render("/tmp/it's here", "feat/x"),
+            "open -a Fork '/tmp/it'\\''s here' # 'feat/x'"
+        );
+
+        assert!(validate_open_with(std::slice::from_ref(&app)).is_ok());
+        let dup = OpenWithApp {
+            name: "fork".into(),
+            command: "fork {path}".into(),
+        };
+        assert!(validate_open_with(&[app.clone(), dup]).is_err());
+        let empty = OpenWithApp {
+            name: "Nvim".into(),
+            command: "  ".into(),
+        };
+        assert!(validate_open_with(&[empty]).is_err());
+    }
+}
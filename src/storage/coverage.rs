@@ -0,0 +1,106 @@
+//! Task 覆盖率报告: ~/.grove/projects/{project}/tasks/{task_id}/coverage.json
+//!
+//! 保存任务测试运行产出的最新一份覆盖率（lcov / cobertura 解析后的行级数据），
+//! 并保留上一份报告的汇总，用于计算覆盖率变化。
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::ensure_task_data_dir;
+use crate::error::{GroveError, Result};
+
+const FILE_NAME: &str = "coverage.json";
+
+/// 覆盖率报告格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverageFormat {
+    Lcov,
+    Cobertura,
+}
+
+/// 单个文件的行覆盖（行号升序）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileCoverage {
+    #[serde(default)]
+    pub covered: Vec<u32>,
+    #[serde(default)]
+    pub uncovered: Vec<u32>,
+}
+
+impl FileCoverage {
+    /// 行的覆盖状态：Some(true) 已覆盖，Some(false) 未覆盖，None 不可执行
+    pub fn status(&self, line: u32) -> Option<bool> {
+        if self.covered.binary_search(&line).is_ok() {
+            Some(true)
+        } else if self.uncovered.binary_search(&line).is_ok() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+}
+
+/// 可执行行数 / 已覆盖行数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoverageTotals {
+    pub lines: u32,
+    pub covered: u32,
+}
+
+impl CoverageTotals {
+    /// 覆盖率百分比（没有可执行行时为 None）
+    pub fn percent(&self) -> Option<f64> {
+        (self.lines > 0).then(|| self.covered as f64 * 100.0 / self.lines as f64)
+    }
+}
+
+/// 任务最新的覆盖率报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub format: CoverageFormat,
+    /// 上传时间（RFC 3339）
+    pub uploaded_at: String,
+    /// 相对 worktree 根目录的路径 -> 行覆盖
+    pub files: BTreeMap<String, FileCoverage>,
+    /// 上一份报告的汇总（第一次上传时为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous: Option<CoverageTotals>,
+}
+
+impl CoverageReport {
+    pub fn totals(&self) -> CoverageTotals {
+        self.files
+            .values()
+            .fold(CoverageTotals::default(), |acc, file| CoverageTotals {
+                lines: acc.lines + (file.covered.len() + file.uncovered.len()) as u32,
+                covered: acc.covered + file.covered.len() as u32,
+            })
+    }
+}
+
+/// 读取任务的覆盖率报告（未上传返回 None）
+pub fn load_report(project: &str, task_id: &str) -> Option<CoverageReport> {
+    ensure_task_data_dir(project, task_id)
+        .ok()
+        .and_then(|dir| std::fs::read_to_string(dir.join(FILE_NAME)).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+/// 保存覆盖率报告（覆盖旧报告）
+pub fn save_report(project: &str, task_id: &str, report: &CoverageReport) -> Result<()> {
+    let path = ensure_task_data_dir(project, task_id)?.join(FILE_NAME);
+    let content = serde_json::to_string(report).map_err(|e| GroveError::storage(e.to_string()))?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// 删除覆盖率报告
+pub fn delete_report(project: &str, task_id: &str) -> Result<()> {
+    let path = ensure_task_data_dir(project, task_id)?.join(FILE_NAME);
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
@@ -91,8 +91,8 @@ pub fn ensure_curated_file() -> Result<PathBuf> {
         // P3.11: log the first-touch so the boot output makes the
         // file's location discoverable. The marketplace modal links
         // to this same path in its "about curated" help text.
-        eprintln!(
-            "[curated_agents] first-touch bootstrap — wrote embedded default to {}",
+        tracing::info!(
+            "first-touch bootstrap — wrote embedded default to {}",
             path.display()
         );
     }
@@ -107,8 +107,8 @@ pub fn ensure_curated_file() -> Result<PathBuf> {
 pub fn load() -> CuratedList {
     match std::fs::read(curated_path()) {
         Ok(bytes) => serde_json::from_slice::<CuratedList>(&bytes).unwrap_or_else(|e| {
-            eprintln!(
-                "[curated_agents] on-disk curated.json is malformed ({}), falling back to embedded default",
+            tracing::warn!(
+                "on-disk curated.json is malformed ({}), falling back to embedded default",
                 e
             );
             CuratedList::embedded()
@@ -162,16 +162,6 @@ fn is_known_base_agent(id: &str) -> bool {
     false
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn unknown_id_rejected() {
-        assert!(!is_known_base_agent("definitely-not-a-real-agent-xyz"));
-    }
-}
-
 /// 创建。生成新的 id 并填充时间戳，返回创建后的完整记录。
 ///
 /// 校验 base_agent 必须是已知 ACP agent (registry / installed_agents /
@@ -301,3 +291,13 @@ pub fn delete(id: &str) -> Result<bool> {
     let n = conn.execute("DELETE FROM custom_agent WHERE id = ?1", params![id])?;
     Ok(n > 0)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_id_rejected() {
+        assert!(!is_known_base_agent("definitely-not-a-real-agent-xyz"));
+    }
+}
@@ -119,7 +119,7 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
         > 0;
     if has_legacy_automation_runs {
         conn.execute_batch("DROP TABLE IF EXISTS automation_runs;")?;
-        eprintln!("[automation] dropped legacy automation_runs (dev-phase schema reset)");
+        tracing::info!("dropped legacy automation_runs (dev-phase schema reset)");
     }
 
     conn.execute_batch(
@@ -131,7 +131,11 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
             path         TEXT NOT NULL UNIQUE,
             is_git_repo  INTEGER NOT NULL DEFAULT 1,
             added_at     TEXT NOT NULL,
-            project_type TEXT NOT NULL DEFAULT 'repo'
+            project_type TEXT NOT NULL DEFAULT 'repo',
+            default_target TEXT,
+            pinned       INTEGER NOT NULL DEFAULT 0,
+            commit_signing INTEGER,
+            commit_trailers INTEGER NOT NULL DEFAULT 1
         );
 
         -- Tasks (active + archived, unified)
@@ -155,6 +159,9 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
             code_additions INTEGER NOT NULL DEFAULT 0,
             code_deletions INTEGER NOT NULL DEFAULT 0,
             files_changed  INTEGER NOT NULL DEFAULT 0,
+            -- handback: 'human' | 'agent:<chat_id>' | NULL (unassigned)
+            assignee       TEXT,
+            pinned         INTEGER NOT NULL DEFAULT 0,
             PRIMARY KEY (project, id)
         );
 
@@ -186,6 +193,85 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
         CREATE INDEX IF NOT EXISTS ix_task_group_slots_task
             ON task_group_slots (project_id, task_id);
 
+        -- Target branch changes (retarget history)
+        CREATE TABLE IF NOT EXISTS task_target_history (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            project     TEXT NOT NULL,
+            task_id     TEXT NOT NULL,
+            from_target TEXT NOT NULL,
+            to_target   TEXT NOT NULL,
+            changed_at  TEXT NOT NULL
+        );
+
+        CREATE INDEX IF NOT EXISTS ix_task_target_history_task
+            ON task_target_history (project, task_id);
+
+        -- AutoLink symlinks created inside a task worktree (for later cleanup)
+        CREATE TABLE IF NOT EXISTS task_autolinks (
+            project    TEXT NOT NULL,
+            task_id    TEXT NOT NULL,
+            path       TEXT NOT NULL,          -- relative to the worktree root
+            mode       TEXT NOT NULL DEFAULT 'symlink',  -- symlink | copy | hardlink
+            created_at TEXT NOT NULL,
+            PRIMARY KEY (project, task_id, path)
+        );
+
+        -- Saved task list views (named filter + sort) per project
+        CREATE TABLE IF NOT EXISTS task_views (
+            project    TEXT NOT NULL,
+            name       TEXT NOT NULL,
+            sort       TEXT NOT NULL DEFAULT 'activity',
+            filter     TEXT NOT NULL DEFAULT '{}',   -- JSON
+            created_at TEXT NOT NULL,
+            PRIMARY KEY (project, name)
+        );
+
+        -- Public read-only share links for a task's diff and review
+        CREATE TABLE IF NOT EXISTS share_links (
+            token         TEXT PRIMARY KEY,
+            project       TEXT NOT NULL,
+            task_id       TEXT NOT NULL,
+            -- pbkdf2-sha256$<rounds>$<salt>$<hex 32-byte key> (HMAC-SHA256,
+            -- 210000 rounds); legacy rows: <salt>$<sha256 hex>. NULL = public
+            password_hash TEXT,
+            created_at    TEXT NOT NULL,
+            expires_at    TEXT                 -- NULL = never
+        );
+
+        CREATE INDEX IF NOT EXISTS ix_share_links_task
+            ON share_links (project, task_id);
+
+        -- Organization mode (team server side): tasks published by members
+        CREATE TABLE IF NOT EXISTS org_tasks (
+            project    TEXT NOT NULL,
+            owner      TEXT NOT NULL,
+            task_id    TEXT NOT NULL,
+            record     TEXT NOT NULL,          -- storage::tasks::Task JSON
+            notes      TEXT NOT NULL DEFAULT '',
+            updated_at TEXT NOT NULL,
+            PRIMARY KEY (project, owner, task_id)
+        );
+
+        -- Failed merges (cleared on the next successful merge)
+        CREATE TABLE IF NOT EXISTS task_merge_failures (
+            project   TEXT NOT NULL,
+            task_id   TEXT NOT NULL,
+            error     TEXT NOT NULL,
+            timestamp TEXT NOT NULL,
+            PRIMARY KEY (project, task_id)
+        );
+
+        -- GitHub pull requests opened from tasks (latest one per task)
+        CREATE TABLE IF NOT EXISTS task_pull_requests (
+            project    TEXT NOT NULL,
+            task_id    TEXT NOT NULL,
+            number     INTEGER NOT NULL,
+            url        TEXT NOT NULL,
+            state      TEXT NOT NULL,          -- open | closed | merged
+            created_at TEXT NOT NULL,
+            PRIMARY KEY (project, task_id)
+        );
+
         -- Hook notifications
         CREATE TABLE IF NOT EXISTS hook_notifications (
             project_key TEXT NOT NULL,
@@ -358,7 +444,8 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
             acp_session_id TEXT,
             duty           TEXT,
             created_at     TEXT NOT NULL,
-            launch_mode    TEXT NOT NULL DEFAULT 'acp'
+            launch_mode    TEXT NOT NULL DEFAULT 'acp',
+            work_subdir    TEXT
         );
         CREATE INDEX IF NOT EXISTS idx_session_task ON session(project, task_id);
 
@@ -475,6 +562,7 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
             timestamp    TEXT NOT NULL,
             status       TEXT NOT NULL DEFAULT 'open',
             anchor_text  TEXT,
+            draft_owner  TEXT,
             PRIMARY KEY (project_key, task_id, id)
         );
 
@@ -496,6 +584,33 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
         CREATE INDEX IF NOT EXISTS ix_review_comments_status
             ON review_comments(project_key, task_id, status);
 
+        -- Emoji reactions on review comments (reply_id = 0) and replies.
+        CREATE TABLE IF NOT EXISTS review_reactions (
+            project_key TEXT NOT NULL,
+            task_id     TEXT NOT NULL,
+            comment_id  INTEGER NOT NULL,
+            reply_id    INTEGER NOT NULL DEFAULT 0,
+            emoji       TEXT NOT NULL,
+            author      TEXT NOT NULL,
+            timestamp   TEXT NOT NULL,
+            PRIMARY KEY (project_key, task_id, comment_id, reply_id, emoji, author),
+            FOREIGN KEY (project_key, task_id, comment_id)
+                REFERENCES review_comments(project_key, task_id, id) ON DELETE CASCADE
+        );
+
+        -- Submitted review batches: pending drafts published together with a verdict.
+        CREATE TABLE IF NOT EXISTS review_submissions (
+            id          INTEGER NOT NULL,
+            project_key TEXT NOT NULL,
+            task_id     TEXT NOT NULL,
+            reviewer    TEXT NOT NULL,
+            verdict     TEXT NOT NULL,
+            summary     TEXT NOT NULL DEFAULT '',
+            comment_ids TEXT NOT NULL DEFAULT '[]',
+            timestamp   TEXT NOT NULL,
+            PRIMARY KEY (project_key, task_id, id)
+        );
+
         -- Per-turn token usage (Layer A). One row per agent prompt response;
         -- written from acp::handle_session_notification when Complete is emitted.
         -- Per-turn delta values (not session totals) — confirmed empirically.
@@ -513,7 +628,9 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
             start_ts           INTEGER NOT NULL,
             end_ts             INTEGER NOT NULL,
             cost_amount        REAL,
-            cost_currency      TEXT
+            cost_currency      TEXT,
+            cached_write_tokens INTEGER,
+            thought_tokens     INTEGER
         );
 
         -- Stats hot path: GROUP BY date(end_ts, 'unixepoch') WHERE project_key = ?
@@ -599,6 +716,9 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
     let _ = conn.execute_batch("ALTER TABLE hook_notifications ADD COLUMN chat_id TEXT;");
     let _ = conn.execute_batch("ALTER TABLE chat_token_usage ADD COLUMN cost_amount REAL;");
     let _ = conn.execute_batch("ALTER TABLE chat_token_usage ADD COLUMN cost_currency TEXT;");
+    let _ =
+        conn.execute_batch("ALTER TABLE chat_token_usage ADD COLUMN cached_write_tokens INTEGER;");
+    let _ = conn.execute_batch("ALTER TABLE chat_token_usage ADD COLUMN thought_tokens INTEGER;");
     // Streaming transcription mode + OS-wide global voice mode (added later).
     let _ = conn.execute_batch(
         "ALTER TABLE audio_config ADD COLUMN transcribe_mode TEXT NOT NULL DEFAULT 'batch';",
@@ -619,6 +739,9 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
     let _ = conn.execute_batch(
         "ALTER TABLE agent_pending_message ADD COLUMN project TEXT NOT NULL DEFAULT '';",
     );
+    let _ = conn.execute_batch(
+        "ALTER TABLE task_autolinks ADD COLUMN mode TEXT NOT NULL DEFAULT 'symlink';",
+    );
     conn.execute_batch(
         "UPDATE agent_edge
          SET project = COALESCE((SELECT s.project FROM session s WHERE s.session_id = agent_edge.from_session), project)
@@ -667,10 +790,11 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
             )
             .unwrap_or(0);
         if orphan_edges > 0 || orphan_pending > 0 {
-            eprintln!(
+            tracing::warn!(
                 "[fk_migrate] dropping {} orphan edges and {} orphan pending messages \
                  (referenced sessions no longer exist)",
-                orphan_edges, orphan_pending
+                orphan_edges,
+                orphan_pending
             );
         }
         conn.execute_batch(
@@ -764,6 +888,29 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
         "INTEGER NOT NULL DEFAULT 0",
     )?;
     add_column_if_missing(conn, "tasks", "files_changed", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_missing(conn, "tasks", "assignee", "TEXT")?;
+
+    // Per-project default target branch for new tasks (NULL = current branch)
+    add_column_if_missing(conn, "projects", "default_target", "TEXT")?;
+
+    // Pinned projects / tasks sort ahead of the rest in every list
+    add_column_if_missing(conn, "projects", "pinned", "INTEGER NOT NULL DEFAULT 0")?;
+    add_column_if_missing(conn, "tasks", "pinned", "INTEGER NOT NULL DEFAULT 0")?;
+
+    // Per-project commit signing override (NULL = follow [signing].enabled)
+    add_column_if_missing(conn, "projects", "commit_signing", "INTEGER")?;
+
+    // Grove-Task / Grove-Chat trailers on commits Grove makes (on by default)
+    add_column_if_missing(
+        conn,
+        "projects",
+        "commit_trailers",
+        "INTEGER NOT NULL DEFAULT 1",
+    )?;
+
+    // Pending review drafts: NULL = published, otherwise the reviewer who owns
+    // the draft. Drafts stay invisible to agents until the review is submitted.
+    add_column_if_missing(conn, "review_comments", "draft_owner", "TEXT")?;
 
     // Chat session launch mode: "acp" (default, JSON-RPC over stdio) or
     // "terminal" (spawn agent CLI under a PTY, no protocol). Old chats stay
@@ -775,6 +922,7 @@ pub(crate) fn create_schema(conn: &Connection) -> Result<()> {
         "launch_mode",
         "TEXT NOT NULL DEFAULT 'acp'",
     )?;
+    add_column_if_missing(conn, "session", "work_subdir", "TEXT")?;
 
     Ok(())
 }
@@ -831,7 +979,7 @@ pub fn run_agent_graph_startup_maintenance() {
     {
         let conn = connection();
         if let Err(e) = super::migrate_chats::migrate_chats_toml_to_sqlite(&conn) {
-            eprintln!("[warning] chats.toml migration failed: {}", e);
+            tracing::warn!("chats.toml migration failed: {}", e);
         }
     }
 
@@ -843,13 +991,15 @@ pub fn run_agent_graph_startup_maintenance() {
                     || stats.edges_deleted > 0
                     || stats.pending_messages_deleted > 0 =>
             {
-                eprintln!(
-                    "[warning] agent graph GC removed {} session(s), {} edge(s), {} pending message(s)",
-                    stats.sessions_deleted, stats.edges_deleted, stats.pending_messages_deleted
+                tracing::warn!(
+                    "agent graph GC removed {} session(s), {} edge(s), {} pending message(s)",
+                    stats.sessions_deleted,
+                    stats.edges_deleted,
+                    stats.pending_messages_deleted
                 );
             }
             Ok(_) => {}
-            Err(e) => eprintln!("[warning] agent graph GC failed: {}", e),
+            Err(e) => tracing::warn!("agent graph GC failed: {}", e),
         }
     }
 }
@@ -919,7 +1069,7 @@ fn migrate_projects(conn: &Connection) {
             params![hash, p.name, p.path, p.is_git_repo, added_at],
         );
     }
-    eprintln!("  [migrate] projects → SQLite done");
+    tracing::info!("projects → SQLite done");
 }
 
 fn migrate_taskgroups(conn: &Connection) {
@@ -946,7 +1096,7 @@ fn migrate_taskgroups(conn: &Connection) {
     let tx = match conn.unchecked_transaction() {
         Ok(tx) => tx,
         Err(e) => {
-            eprintln!("  [migrate] failed to begin transaction: {}", e);
+            tracing::warn!("failed to begin transaction: {}", e);
             return;
         }
     };
@@ -963,7 +1113,7 @@ fn migrate_taskgroups(conn: &Connection) {
         }
     }
     let _ = tx.commit();
-    eprintln!("  [migrate] taskgroups → SQLite done");
+    tracing::info!("taskgroups → SQLite done");
 }
 
 fn migrate_providers(conn: &Connection) {
@@ -984,7 +1134,7 @@ fn migrate_providers(conn: &Connection) {
     let tx = match conn.unchecked_transaction() {
         Ok(tx) => tx,
         Err(e) => {
-            eprintln!("  [migrate] failed to begin transaction: {}", e);
+            tracing::warn!("failed to begin transaction: {}", e);
             return;
         }
     };
@@ -995,7 +1145,7 @@ fn migrate_providers(conn: &Connection) {
         );
     }
     let _ = tx.commit();
-    eprintln!("  [migrate] ai/providers → SQLite done");
+    tracing::info!("ai/providers → SQLite done");
 }
 
 fn migrate_audio_global() {
@@ -1025,7 +1175,7 @@ fn migrate_audio_global() {
     };
 
     let _ = super::ai::save_audio_global(&data);
-    eprintln!("  [migrate] ai/audio (global) → SQLite done");
+    tracing::info!("ai/audio (global) → SQLite done");
 }
 
 fn migrate_audio_projects() {
@@ -1060,7 +1210,7 @@ fn migrate_audio_projects() {
 
         let _ = super::ai::save_audio_project(&hash, &data);
     }
-    eprintln!("  [migrate] ai/audio (project) → SQLite done");
+    tracing::info!("ai/audio (project) → SQLite done");
 }
 
 fn migrate_skills_all() {
@@ -1072,7 +1222,7 @@ fn migrate_skills_all() {
         .unwrap_or(0)
         > 0;
     if already {
-        eprintln!("  [migrate] skills → already in SQLite, skipping");
+        tracing::info!("skills → already in SQLite, skipping");
         return;
     }
 
@@ -1134,12 +1284,12 @@ fn migrate_skills_all() {
     }
 
     if !key_remap.is_empty() {
-        eprintln!(
-            "  [migrate] skills: remapped {} repo_key(s) to stable FNV hash",
+        tracing::info!(
+            "skills: remapped {} repo_key(s) to stable FNV hash",
             key_remap.len()
         );
     }
-    eprintln!("  [migrate] skills → SQLite done");
+    tracing::info!("skills → SQLite done");
 }
 
 fn default_true_for_migration() -> bool {
@@ -1183,9 +1333,11 @@ pub fn migrate_tasks_toml_to_sqlite() {
             let content = match std::fs::read_to_string(&toml_path) {
                 Ok(c) => c,
                 Err(e) => {
-                    eprintln!(
-                        "  [migrate] tasks.toml read failed for {}/{}: {}",
-                        project_key, filename, e
+                    tracing::warn!(
+                        "tasks.toml read failed for {}/{}: {}",
+                        project_key,
+                        filename,
+                        e
                     );
                     continue;
                 }
@@ -1193,9 +1345,11 @@ pub fn migrate_tasks_toml_to_sqlite() {
             let data: toml::Value = match toml::from_str(&content) {
                 Ok(d) => d,
                 Err(e) => {
-                    eprintln!(
-                        "  [migrate] tasks.toml parse failed for {}/{}: {}",
-                        project_key, filename, e
+                    tracing::warn!(
+                        "tasks.toml parse failed for {}/{}: {}",
+                        project_key,
+                        filename,
+                        e
                     );
                     continue;
                 }
@@ -1290,17 +1444,19 @@ pub fn migrate_tasks_toml_to_sqlite() {
                         files_changed,
                     ],
                 ) {
-                    eprintln!(
-                        "  [migrate] tasks.toml insert failed for {}/{}: {}",
+                    tracing::warn!(
+                        "tasks.toml insert failed for {}/{}: {}",
                         project_key, id, e
                     );
                 }
             }
 
             if let Err(e) = tx.commit() {
-                eprintln!(
-                    "  [migrate] tasks.toml commit failed for {}/{}: {}",
-                    project_key, filename, e
+                tracing::warn!(
+                    "tasks.toml commit failed for {}/{}: {}",
+                    project_key,
+                    filename,
+                    e
                 );
             } else {
                 total_migrated += tasks.len() as u32;
@@ -1309,8 +1465,8 @@ pub fn migrate_tasks_toml_to_sqlite() {
     }
 
     if total_migrated > 0 {
-        eprintln!(
-            "  [migrate] tasks.toml/archived.toml → SQLite done ({} tasks)",
+        tracing::info!(
+            "tasks.toml/archived.toml → SQLite done ({} tasks)",
             total_migrated
         );
     }
@@ -1388,7 +1544,7 @@ pub fn migrate_installed_agents_id_remap() -> (u32, u32, u32) {
         let mut stmt = match conn.prepare(select_sql) {
             Ok(s) => s,
             Err(e) => {
-                eprintln!("  [migrate] {}: prepare failed: {}", table_label, e);
+                tracing::warn!("{}: prepare failed: {}", table_label, e);
                 return HashSet::new();
             }
         };
@@ -1423,7 +1579,7 @@ pub fn migrate_installed_agents_id_remap() -> (u32, u32, u32) {
     let mut stmt = match conn.prepare("SELECT id FROM installed_agents") {
         Ok(s) => s,
         Err(e) => {
-            eprintln!("  [migrate] installed_agents.id: prepare failed: {}", e);
+            tracing::warn!("installed_agents.id: prepare failed: {}", e);
             return (0, 0, 0);
         }
     };
@@ -1509,8 +1665,8 @@ pub fn migrate_installed_agents_id_remap() -> (u32, u32, u32) {
                     || legacy_hidden;
 
                 if legacy_customized {
-                    eprintln!(
-                        "  [migrate] installed_agents.id: merging legacy {source} prefs \
+                    tracing::info!(
+                        "installed_agents.id: merging legacy {source} prefs \
                          (launch_mode={launch}, hidden={hidden}, args={nargs}, env={nenv}) into {target}",
                         source = source_id,
                         target = target,
@@ -1570,18 +1726,22 @@ pub fn migrate_installed_agents_id_remap() -> (u32, u32, u32) {
             }
             (Some(tx), Err(e)) => {
                 let _ = tx.rollback();
-                eprintln!(
-                    "  [migrate] installed_agents.id: {} → {} failed: {}",
-                    source_id, target, e
+                tracing::warn!(
+                    "installed_agents.id: {} → {} failed: {}",
+                    source_id,
+                    target,
+                    e
                 );
             }
             (None, Ok(())) => {
                 installed_touched += 1;
             }
             (None, Err(e)) => {
-                eprintln!(
-                    "  [migrate] installed_agents.id: {} → {} failed (no tx): {}",
-                    source_id, target, e
+                tracing::warn!(
+                    "installed_agents.id: {} → {} failed (no tx): {}",
+                    source_id,
+                    target,
+                    e
                 );
             }
         }
@@ -1610,9 +1770,11 @@ pub fn migrate_installed_agents_id_remap() -> (u32, u32, u32) {
                     params![&target, &source],
                 )
                 .unwrap_or_else(|e| {
-                    eprintln!(
-                        "  [migrate] session.agent: bulk UPDATE {} → {} failed: {}",
-                        source, target, e
+                    tracing::warn!(
+                        "session.agent: bulk UPDATE {} → {} failed: {}",
+                        source,
+                        target,
+                        e
                     );
                     0
                 });
@@ -1644,9 +1806,11 @@ pub fn migrate_installed_agents_id_remap() -> (u32, u32, u32) {
                     params![&target, &now, &source],
                 )
                 .unwrap_or_else(|e| {
-                    eprintln!(
-                        "  [migrate] custom_agent.base_agent: bulk UPDATE {} → {} failed: {}",
-                        source, target, e
+                    tracing::warn!(
+                        "custom_agent.base_agent: bulk UPDATE {} → {} failed: {}",
+                        source,
+                        target,
+                        e
                     );
                     0
                 });
@@ -1663,9 +1827,11 @@ pub fn migrate_installed_agents_id_remap() -> (u32, u32, u32) {
     }
 
     if installed_touched + sessions_touched + base_agents_touched > 0 {
-        eprintln!(
-            "  [migrate] agent id remap: installed_agents={} session.agent={} custom_agent.base_agent={}",
-            installed_touched, sessions_touched, base_agents_touched
+        tracing::info!(
+            "agent id remap: installed_agents={} session.agent={} custom_agent.base_agent={}",
+            installed_touched,
+            sessions_touched,
+            base_agents_touched
         );
     }
 
@@ -1700,9 +1866,9 @@ pub fn migrate_config_agent_command_ids() {
     }
     if changed {
         if let Err(e) = crate::storage::config::save_config(&config) {
-            eprintln!("[migrate] config agent_command canonicalize failed: {}", e);
+            tracing::warn!("config agent_command canonicalize failed: {}", e);
         } else {
-            eprintln!("  [migrate] config.toml: agent_command(s) canonicalized");
+            tracing::info!("config.toml: agent_command(s) canonicalized");
         }
     }
 }
@@ -1870,8 +2036,8 @@ pub fn migrate_review_to_sqlite() {
             // breakage.
             let target = review_path.with_extension("json.migrated");
             if let Err(e) = std::fs::rename(&review_path, &target) {
-                eprintln!(
-                    "  [migrate] failed to rename {} → {}: {}",
+                tracing::warn!(
+                    "failed to rename {} → {}: {}",
                     review_path.display(),
                     target.display(),
                     e
@@ -2267,10 +2433,7 @@ pub fn migrate_installed_agents_to_v26() -> Result<()> {
 
     tx.execute_batch("DROP TABLE installed_agents_v25;")?;
     tx.commit()?;
-    eprintln!(
-        "  [migrate] installed_agents v2.5→v2.6: migrated {} row(s)",
-        migrated
-    );
+    tracing::info!("installed_agents v2.5→v2.6: migrated {} row(s)", migrated);
     Ok(())
 }
 
@@ -2280,14 +2443,117 @@ pub fn migrate_v20_fix_empty_slots() -> bool {
     }
     eprintln!("Fixing empty task_group_slots (v2.0 bug)...");
     if let Err(e) = super::taskgroups::ensure_system_groups() {
-        eprintln!(
-            "[warning] migrate_v20_fix_empty_slots: ensure_system_groups failed: {}",
+        tracing::warn!(
+            "migrate_v20_fix_empty_slots: ensure_system_groups failed: {}",
             e
         );
     }
     true
 }
 
+pub fn migrate_audio_to_config_toml() {
+    let conn = connection();
+    // Check if audio_config table exists
+    let table_exists: bool = conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='audio_config'",
+            [],
+            |r| r.get::<_, i32>(0),
+        )
+        .unwrap_or(0)
+        > 0;
+    if !table_exists {
+        return;
+    }
+
+    // Read audio_config values if row exists
+    let db_audio = conn.query_row(
+        "SELECT enabled, transcribe_provider, toggle_shortcut, push_to_talk_key, \
+         max_duration, min_duration, revise_enabled, revise_provider, revise_prompt, \
+         preferred_languages, transcribe_mode, global_mode_enabled, ptt_activation_delay_ms \
+         FROM audio_config WHERE id = 1",
+        [],
+        |row| {
+            let enabled: i32 = row.get(0)?;
+            let transcribe_provider: String = row.get(1)?;
+            let toggle_shortcut: String = row.get(2)?;
+            let push_to_talk_key: String = row.get(3)?;
+            let max_duration: u32 = row.get(4)?;
+            let min_duration: u32 = row.get(5)?;
+            let revise_enabled: i32 = row.get(6)?;
+            let revise_provider: String = row.get(7)?;
+            let revise_prompt: String = row.get(8)?;
+            let preferred_languages_json: String = row.get(9)?;
+            let transcribe_mode: String = row.get(10)?;
+            let global_mode_enabled: i32 = row.get(11)?;
+            let ptt_activation_delay_ms: u32 = row.get(12)?;
+
+            let preferred_languages: Vec<String> =
+                serde_json::from_str(&preferred_languages_json).unwrap_or_default();
+
+            Ok((
+                enabled != 0,
+                transcribe_provider,
+                toggle_shortcut,
+                push_to_talk_key,
+                max_duration,
+                min_duration,
+                revise_enabled != 0,
+                revise_provider,
+                revise_prompt,
+                preferred_languages,
+                transcribe_mode,
+                global_mode_enabled != 0,
+                ptt_activation_delay_ms,
+            ))
+        },
+    );
+
+    if let Ok((
+        enabled,
+        transcribe_provider,
+        toggle_shortcut,
+        push_to_talk_key,
+        max_duration,
+        min_duration,
+        revise_enabled,
+        revise_provider,
+        revise_prompt_global,
+        preferred_languages,
+        transcribe_mode,
+        global_mode_enabled,
+        ptt_activation_delay_ms,
+    )) = db_audio
+    {
+        let mut config = super::config::load_config();
+        config.audio.enabled = enabled;
+        config.audio.transcribe_provider = transcribe_provider;
+        config.audio.toggle_shortcut = toggle_shortcut;
+        config.audio.push_to_talk_key = push_to_talk_key;
+        config.audio.max_duration = max_duration;
+        config.audio.min_duration = min_duration;
+        config.audio.revise_enabled = revise_enabled;
+        config.audio.revise_provider = revise_provider;
+        config.audio.revise_prompt_global = revise_prompt_global;
+        config.audio.preferred_languages = preferred_languages;
+        config.audio.transcribe_mode = transcribe_mode;
+        config.audio.global_mode_enabled = global_mode_enabled;
+        config.audio.ptt_activation_delay_ms = ptt_activation_delay_ms;
+
+        if let Err(e) = super::config::save_config(&config) {
+            tracing::warn!(
+                "failed to save migrated audio settings to config.toml: {}",
+                e
+            );
+        } else {
+            tracing::info!("audio settings migrated to config.toml successfully");
+        }
+    }
+
+    // Drop the table to prevent running it again
+    let _ = conn.execute("DROP TABLE IF EXISTS audio_config", []);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2594,106 +2860,3 @@ mod tests {
         }
     }
 }
-
-pub fn migrate_audio_to_config_toml() {
-    let conn = connection();
-    // Check if audio_config table exists
-    let table_exists: bool = conn
-        .query_row(
-            "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='audio_config'",
-            [],
-            |r| r.get::<_, i32>(0),
-        )
-        .unwrap_or(0)
-        > 0;
-    if !table_exists {
-        return;
-    }
-
-    // Read audio_config values if row exists
-    let db_audio = conn.query_row(
-        "SELECT enabled, transcribe_provider, toggle_shortcut, push_to_talk_key, \
-         max_duration, min_duration, revise_enabled, revise_provider, revise_prompt, \
-         preferred_languages, transcribe_mode, global_mode_enabled, ptt_activation_delay_ms \
-         FROM audio_config WHERE id = 1",
-        [],
-        |row| {
-            let enabled: i32 = row.get(0)?;
-            let transcribe_provider: String = row.get(1)?;
-            let toggle_shortcut: String = row.get(2)?;
-            let push_to_talk_key: String = row.get(3)?;
-            let max_duration: u32 = row.get(4)?;
-            let min_duration: u32 = row.get(5)?;
-            let revise_enabled: i32 = row.get(6)?;
-            let revise_provider: String = row.get(7)?;
-            let revise_prompt: String = row.get(8)?;
-            let preferred_languages_json: String = row.get(9)?;
-            let transcribe_mode: String = row.get(10)?;
-            let global_mode_enabled: i32 = row.get(11)?;
-            let ptt_activation_delay_ms: u32 = row.get(12)?;
-
-            let preferred_languages: Vec<String> =
-                serde_json::from_str(&preferred_languages_json).unwrap_or_default();
-
-            Ok((
-                enabled != 0,
-                transcribe_provider,
-                toggle_shortcut,
-                push_to_talk_key,
-                max_duration,
-                min_duration,
-                revise_enabled != 0,
-                revise_provider,
-                revise_prompt,
-                preferred_languages,
-                transcribe_mode,
-                global_mode_enabled != 0,
-                ptt_activation_delay_ms,
-            ))
-        },
-    );
-
-    if let Ok((
-        enabled,
-        transcribe_provider,
-        toggle_shortcut,
-        push_to_talk_key,
-        max_duration,
-        min_duration,
-        revise_enabled,
-        revise_provider,
-        revise_prompt_global,
-        preferred_languages,
-        transcribe_mode,
-        global_mode_enabled,
-        ptt_activation_delay_ms,
-    )) = db_audio
-    {
-        let mut config = super::config::load_config();
-        config.audio.enabled = enabled;
-        config.audio.transcribe_provider = transcribe_provider;
-        config.audio.toggle_shortcut = toggle_shortcut;
-        config.audio.push_to_talk_key = push_to_talk_key;
-        config.audio.max_duration = max_duration;
-        config.audio.min_duration = min_duration;
-        config.audio.revise_enabled = revise_enabled;
-        config.audio.revise_provider = revise_provider;
-        config.audio.revise_prompt_global = revise_prompt_global;
-        config.audio.preferred_languages = preferred_languages;
-        config.audio.transcribe_mode = transcribe_mode;
-        config.audio.global_mode_enabled = global_mode_enabled;
-        config.audio.ptt_activation_delay_ms = ptt_activation_delay_ms;
-
-        if let Err(e) = super::config::save_config(&config) {
-            eprintln!(
-                "[migrate] failed to save migrated audio settings to config.toml: {}",
-                e
-            );
-        } else {
-            eprintln!("[migrate] audio settings migrated to config.toml successfully");
-        }
-    }
-
-    // Drop the table to prevent running it again
-    let _ = conn.execute("DROP TABLE IF EXISTS audio_config", []);
-}
@@ -0,0 +1,31 @@
+//! 项目级 diff 过滤设置: ~/.grove/projects/{project}/diff_filters.toml
+
+use serde::{Deserialize, Serialize};
+
+use super::project_settings;
+use crate::error::Result;
+
+/// 项目级 diff 过滤设置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiffFilterSettings {
+    /// 在 review diff 中默认折叠的文件 glob（补充 .gitattributes 的
+    /// `linguist-generated` / `linguist-vendored` 标记）
+    #[serde(default)]
+    pub collapsed_patterns: Vec<String>,
+    /// 识别测试文件的 glob（为空时使用 `[risk] test_paths` 的默认值），
+    /// 用于「改了源码却没改测试」提示
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub test_patterns: Vec<String>,
+}
+
+const SETTINGS_FILE: &str = "diff_filters.toml";
+
+/// 读取 diff 过滤设置（文件不存在返回默认值）
+pub fn load_settings(project: &str) -> DiffFilterSettings {
+    project_settings::load(project, SETTINGS_FILE)
+}
+
+/// 保存 diff 过滤设置
+pub fn save_settings(project: &str, settings: &DiffFilterSettings) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, settings)
+}
@@ -0,0 +1,422 @@
+//! 新增文件守卫: ~/.grove/projects/{project}/file_guard.toml
+//!
+//! 检查任务相对 target 新增的文件：超过大小阈值、命中禁止的路径 / 扩展名、
+//! 或带有禁止的许可证（SPDX 标识或 GPL 系列许可证正文），防止 agent 误提交
+//! node_modules、大文件或 GPL 代码。开启阻止时经 merge 门禁拦截，否则只给出警告。
+
+use std::io::Read;
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+
+use super::{project_settings, tasks};
+use crate::error::Result;
+
+/// 读取文件开头这么多字节用于识别许可证
+const LICENSE_SCAN_BYTES: u64 = 32 * 1024;
+
+/// 警告 / 阻止原因里列出的文件数
+const MAX_LISTED_FILES: usize = 3;
+
+/// 项目级新增文件守卫设置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileGuardSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 命中时阻止 merge（false 只警告）
+    #[serde(default = "default_true")]
+    pub block: bool,
+    /// 单个新增文件的大小上限（KB，0 表示不限制）
+    #[serde(default = "default_max_file_kb")]
+    pub max_file_kb: u64,
+    /// 禁止新增的路径 glob
+    #[serde(default = "default_blocked_paths")]
+    pub blocked_paths: Vec<String>,
+    /// 禁止新增的扩展名（不带点，忽略大小写）
+    #[serde(default = "default_blocked_extensions")]
+    pub blocked_extensions: Vec<String>,
+    /// 禁止的许可证（SPDX 前缀匹配，如 `GPL` 匹配 `GPL-3.0-only`，但不匹配 `LGPL-2.1`）
+    #[serde(default = "default_disallowed_licenses")]
+    pub disallowed_licenses: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_file_kb() -> u64 {
+    1024
+}
+
+fn default_blocked_paths() -> Vec<String> {
+    vec!["**/node_modules/**".to_string()]
+}
+
+fn default_blocked_extensions() -> Vec<String> {
+    ["exe", "dll", "so", "dylib", "jar", "zip", "tar", "gz", "7z"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn default_disallowed_licenses() -> Vec<String> {
+    vec!["GPL".to_string(), "AGPL".to_string()]
+}
+
+impl Default for FileGuardSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            block: default_true(),
+            max_file_kb: default_max_file_kb(),
+            blocked_paths: default_blocked_paths(),
+            blocked_extensions: default_blocked_extensions(),
+            disallowed_licenses: default_disallowed_licenses(),
+        }
+    }
+}
+
+const SETTINGS_FILE: &str = "file_guard.toml";
+
+/// 读取守卫设置（文件不存在返回默认值，即未开启；文件写错时返回错误）
+pub fn load_settings(project: &str) -> Result<FileGuardSettings> {
+    project_settings::try_load(project, SETTINGS_FILE)
+}
+
+/// 保存守卫设置
+pub fn save_settings(project: &str, settings: &FileGuardSettings) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, settings)
+}
+
+/// 新增文件违反守卫的原因
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GuardViolation {
+    BlockedPath { path: String, pattern: String },
+    BlockedExtension { path: String, extension: String },
+    TooLarge { path: String, size: u64, limit: u64 },
+    License { path: String, license: String },
+}
+
+impl std::fmt::Display for GuardViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuardViolation::BlockedPath { path, pattern } => {
+                write!(f, "{} (matches {})", path, pattern)
+            }
+            GuardViolation::BlockedExtension { path, extension } => {
+                write!(f, "{} (.{} files not allowed)", path, extension)
+            }
+            GuardViolation::TooLarge { path, size, limit } => write!(
+                f,
+                "{} ({} KB > {} KB)",
+                path,
+                size.div_ceil(1024),
+                limit / 1024
+            ),
+            GuardViolation::License { path, license } => write!(f, "{} ({})", path, license),
+        }
+    }
+}
+
+/// 从文件内容识别许可证：优先 SPDX 标识，其次 GNU 许可证正文
+pub fn detect_license(content: &str) -> Option<String> {
+    if let Some(pos) = content.find("SPDX-License-Identifier:") {
+        let expr = content[pos + "SPDX-License-Identifier:".len()..]
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_end_matches("*/")
+            .trim()
+            .to_string();
+        if !expr.is_empty() {
+            return Some(expr);
+        }
+    }
+
+    let lower = content.to_ascii_lowercase();
+    let family = if lower.contains("gnu affero general public license") {
+        "AGPL"
+    } else if lower.contains("gnu lesser general public license")
+        || lower.contains("gnu library general public license")
+    {
+        "LGPL"
+    } else if lower.contains("gnu general public license") {
+        "GPL"
+    } else {
+        return None;
+    };
+    let version = if lower.contains("version 3") {
+        "-3.0"
+    } else if lower.contains("version 2") {
+        "-2.0"
+    } else {
+        ""
+    };
+    Some(format!("{}{}", family, version))
+}
+
+/// 许可证表达式中命中的禁止许可证（`MIT OR GPL-3.0` 中的 `GPL-3.0`）
+pub fn disallowed_license(expr: &str, disallowed: &[String]) -> Option<String> {
+    expr.split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .filter(|id| !id.is_empty() && !matches!(*id, "OR" | "AND" | "WITH"))
+        .find(|id| {
+            disallowed.iter().any(|d| {
+                !d.trim().is_empty() && id.to_ascii_uppercase().starts_with(&d.to_ascii_uppercase())
+            })
+        })
+        .map(str::to_string)
+}
+
+/// 编译好的守卫规则
+pub struct Guard<'a> {
+    settings: &'a FileGuardSettings,
+    paths: Vec<(String, GlobSet)>,
+}
+
+impl<'a> Guard<'a> {
+    pub fn new(settings: &'a FileGuardSettings) -> Self {
+        let paths = settings
+            .blocked_paths
+            .iter()
+            .filter_map(|pattern| {
+                let glob = Glob::new(pattern).ok()?;
+                let set = GlobSetBuilder::new().add(glob).build().ok()?;
+                Some((pattern.clone(), set))
+            })
+            .collect();
+        Self { settings, paths }
+    }
+
+    /// 只看路径的规则（路径 glob、扩展名）
+    pub fn check_path(&self, path: &str) -> Option<GuardViolation> {
+        if let Some((pattern, _)) = self.paths.iter().find(|(_, set)| set.is_match(path)) {
+            return Some(GuardViolation::BlockedPath {
+                path: path.to_string(),
+                pattern: pattern.clone(),
+            });
+        }
+        let extension = std::path::Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())?;
+        self.settings
+            .blocked_extensions
+            .iter()
+            .any(|e| e.trim_start_matches('.').eq_ignore_ascii_case(extension))
+            .then(|| GuardViolation::BlockedExtension {
+                path: path.to_string(),
+                extension: extension.to_ascii_lowercase(),
+            })
+    }
+
+    /// 路径、大小和许可证规则（head 为文件开头的内容）
+    pub fn check(&self, path: &str, size: u64, head: &[u8]) -> Option<GuardViolation> {
+        if let Some(violation) = self.check_path(path) {
+            return Some(violation);
+        }
+        let limit = self.settings.max_file_kb * 1024;
+        if limit > 0 && size > limit {
+            return Some(GuardViolation::TooLarge {
+                path: path.to_string(),
+                size,
+                limit,
+            });
+        }
+        // 二进制文件不识别许可证
+        if self.settings.disallowed_licenses.is_empty() || head.contains(&0) {
+            return None;
+        }
+        let expr = detect_license(&String::from_utf8_lossy(head))?;
+        let license = disallowed_license(&expr, &self.settings.disallowed_licenses)?;
+        Some(GuardViolation::License {
+            path: path.to_string(),
+            license,
+        })
+    }
+}
+
+/// 读取文件大小和开头内容
+fn read_head(path: &std::path::Path) -> Option<(u64, Vec<u8>)> {
+    let file = std::fs::File::open(path).ok()?;
+    let size = file.metadata().ok()?.len();
+    let mut head = Vec::new();
+    file.take(LICENSE_SCAN_BYTES).read_to_end(&mut head).ok()?;
+    Some((size, head))
+}
+
+/// 任务相对 target 新增（含未跟踪）的文件中违反守卫的文件（未开启时返回空）
+pub fn task_violations(
+    project: &str,
+    task_id: &str,
+    settings: &FileGuardSettings,
+) -> Vec<GuardViolation> {
+    if !settings.enabled {
+        return Vec::new();
+    }
+    let Some(task) = tasks::get_task(project, task_id).ok().flatten() else {
+        return Vec::new();
+    };
+    if task.is_local {
+        return Vec::new();
+    }
+    let Ok(entries) = crate::git::diff_stat(&task.worktree_path, &task.target) else {
+        return Vec::new();
+    };
+    let guard = Guard::new(settings);
+    let root = std::path::Path::new(&task.worktree_path);
+    entries
+        .into_iter()
+        .filter(|e| matches!(e.status, 'A' | 'U'))
+        .filter_map(|e| match read_head(&root.join(&e.path)) {
+            Some((size, head)) => guard.check(&e.path, size, &head),
+            None => guard.check_path(&e.path),
+        })
+        .collect()
+}
+
+/// 面向用户的一行说明，如 `2 added files violate the file guard: a.zip (…), …`
+pub fn describe(violations: &[GuardViolation]) -> String {
+    let mut listed: Vec<String> = violations
+        .iter()
+        .take(MAX_LISTED_FILES)
+        .map(|v| v.to_string())
+        .collect();
+    if violations.len() > MAX_LISTED_FILES {
+        listed.push("…".to_string());
+    }
+    format!(
+        "{} added file{} violate{} the file guard: {}",
+        violations.len(),
+        if violations.len() == 1 { "" } else { "s" },
+        if violations.len() == 1 { "s" } else { "" },
+        listed.join(", ")
+    )
+}
+
+/// 只警告模式下的警告（阻止模式由 merge 门禁处理，返回 None；
+/// 设置文件写错时 merge 门禁同样会拒绝，这里只返回错误说明）
+pub fn warning(project: &str, task_id: &str) -> Option<String> {
+    let settings = match load_settings(project) {
+        Ok(settings) => settings,
+        Err(e) => return Some(e.to_string()),
+    };
+    if !settings.enabled || settings.block {
+        return None;
+    }
+    let violations = task_violations(project, task_id, &settings);
+    (!violations.is_empty()).then(|| describe(&violations))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_license() {
+        assert_eq!(
+            detect_license("// SPDX-License-Identifier: GPL-3.0-or-later\nfn main() {}").unwrap(),
+            "GPL-3.0-or-later"
+        );
+        assert_eq!(
+            detect_license("/* SPDX-License-Identifier: MIT OR Apache-2.0 */").unwrap(),
+            "MIT OR Apache-2.0"
+        );
+        assert_eq!(
+            detect_license(
+                "This program is free software ... GNU General Public License\nversion 2"
+            )
+            .unwrap(),
+            "GPL-2.0"
+        );
+        assert_eq!(
+            detect_license("GNU LESSER GENERAL PUBLIC LICENSE\nVersion 3").unwrap(),
+            "LGPL-3.0"
+        );
+        assert_eq!(
+            detect_license("MIT License\nPermission is hereby granted"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_disallowed_license() {
+        let disallowed = default_disallowed_licenses();
+        assert_eq!(
+            disallowed_license("MIT OR GPL-3.0-only", &disallowed).as_deref(),
+            Some("GPL-3.0-only")
+        );
+        assert_eq!(disallowed_license("LGPL-2.1", &disallowed), None);
+        assert_eq!(
+            disallowed_license("(MIT AND Apache-2.0)", &disallowed),
+            None
+        );
+        assert_eq!(
+            disallowed_license("AGPL-3.0", &disallowed).as_deref(),
+            Some("AGPL-3.0")
+        );
+    }
+
+    #[test]
+    fn test_guard_check() {
+        let settings = FileGuardSettings {
+            enabled: true,
+            max_file_kb: 1,
+            ..Default::default()
+        };
+        let guard = Guard::new(&settings);
+
+        assert!(matches!(
+            guard.check("node_modules/lodash/index.js", 10, b""),
+            Some(GuardViolation::BlockedPath { .. })
+        ));
+        assert!(matches!(
+            guard.check("web/node_modules/x.js", 10, b""),
+            Some(GuardViolation::BlockedPath { .. })
+        ));
+        assert!(matches!(
+            guard.check("dist/App.ZIP", 10, b""),
+            Some(GuardViolation::BlockedExtension { .. })
+        ));
+        assert_eq!(
+            guard
+                .check("data/big.csv", 4096, b"a,b")
+                .unwrap()
+                .to_string(),
+            "data/big.csv (4 KB > 1 KB)"
+        );
+        assert_eq!(
+            guard.check(
+                "vendor/lib.c",
+                100,
+                b"/* SPDX-License-Identifier: GPL-2.0 */"
+            ),
+            Some(GuardViolation::License {
+                path: "vendor/lib.c".to_string(),
+                license: "GPL-2.0".to_string(),
+            })
+        );
+        // 二进制内容不识别许可证
+        assert_eq!(
+            guard.check("img.png", 100, b"\0GNU General Public License"),
+            None
+        );
+        assert_eq!(guard.check("src/main.rs", 100, b"fn main() {}"), None);
+    }
+
+    #[test]
+    fn test_describe() {
+        let violations: Vec<GuardViolation> = ["a.zip", "b.zip", "c.zip", "d.zip"]
+            .iter()
+            .map(|p| GuardViolation::BlockedExtension {
+                path: p.to_string(),
+                extension: "zip".to_string(),
+            })
+            .collect();
+        assert_eq!(
+            describe(&violations[..1]),
+            "1 added file violates the file guard: a.zip (.zip files not allowed)"
+        );
+        assert!(describe(&violations).ends_with("c.zip (.zip files not allowed), …"));
+    }
+}
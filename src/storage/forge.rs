@@ -0,0 +1,82 @@
+//! 项目级代码托管平台（forge）设置: ~/.grove/projects/{project}/forge.toml
+//!
+//! 默认根据 origin URL 的 host 识别平台；自建实例（host 里看不出是 GitLab /
+//! Gitea）可以在这里指定平台类型和 API 地址。
+
+use serde::{Deserialize, Serialize};
+
+use super::project_settings;
+use crate::error::{GroveError, Result};
+
+/// 代码托管平台
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ForgeKind {
+    Github,
+    Gitlab,
+    Gitea,
+}
+
+impl ForgeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ForgeKind::Github => "github",
+            ForgeKind::Gitlab => "gitlab",
+            ForgeKind::Gitea => "gitea",
+        }
+    }
+
+    /// 展示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            ForgeKind::Github => "GitHub",
+            ForgeKind::Gitlab => "GitLab",
+            ForgeKind::Gitea => "Gitea",
+        }
+    }
+}
+
+/// 项目级 forge 设置
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForgeSettings {
+    /// 平台类型；None 表示按 origin host 自动识别
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ForgeKind>,
+    /// API 根地址（如 `https://git.example.com/api/v4`）；None 表示按 host 推导
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+}
+
+impl ForgeSettings {
+    /// 去掉 api_url 首尾空白和末尾 `/`，校验协议
+    pub fn normalized(self) -> Result<Self> {
+        let api_url = self
+            .api_url
+            .map(|url| url.trim().trim_end_matches('/').to_string())
+            .filter(|url| !url.is_empty());
+        if let Some(url) = &api_url {
+            if !url.starts_with("https://") && !url.starts_with("http://") {
+                return Err(GroveError::invalid_data(format!(
+                    "API URL must start with http:// or https://: {}",
+                    url
+                )));
+            }
+        }
+        Ok(Self {
+            kind: self.kind,
+            api_url,
+        })
+    }
+}
+
+const SETTINGS_FILE: &str = "forge.toml";
+
+/// 读取项目的 forge 设置（文件不存在返回默认值）
+pub fn load_settings(project: &str) -> ForgeSettings {
+    project_settings::load(project, SETTINGS_FILE)
+}
+
+/// 保存项目的 forge 设置
+pub fn save_settings(project: &str, settings: &ForgeSettings) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, settings)
+}
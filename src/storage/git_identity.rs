@@ -0,0 +1,252 @@
+//! Git 身份覆盖（author / committer / trailer）
+//!
+//! 项目级: ~/.grove/projects/{project}/git_identity.toml
+//! 任务级: ~/.grove/projects/{project}/tasks/{task_id}/git_identity.toml
+//!
+//! 任务级设置按字段覆盖项目级设置；未设置的字段沿用 git 自身配置。
+
+use serde::{Deserialize, Serialize};
+
+use super::{ensure_task_data_dir, grove_dir, load_toml, save_toml};
+use crate::error::{GroveError, Result};
+use crate::git;
+
+const FILE_NAME: &str = "git_identity.toml";
+
+/// Grove 发起的 commit / merge 使用的身份
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GitIdentity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer_email: Option<String>,
+    /// 追加到提交信息末尾的 trailer，如 `Co-authored-by: Me <me@example.com>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trailer: Option<String>,
+}
+
+impl GitIdentity {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 去掉首尾空白，空字符串视为未设置
+    pub fn normalized(self) -> Self {
+        fn clean(value: Option<String>) -> Option<String> {
+            value
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        }
+        Self {
+            author_name: clean(self.author_name),
+            author_email: clean(self.author_email),
+            committer_name: clean(self.committer_name),
+            committer_email: clean(self.committer_email),
+            trailer: clean(self.trailer),
+        }
+    }
+
+    /// 校验字段：不允许换行，邮箱不能带尖括号，trailer 必须是 `Key: value`
+    pub fn validate(&self) -> Result<()> {
+        let fields = [
+            ("author name", &self.author_name),
+            ("author email", &self.author_email),
+            ("committer name", &self.committer_name),
+            ("committer email", &self.committer_email),
+            ("trailer", &self.trailer),
+        ];
+        for (label, value) in fields {
+            if value.as_deref().is_some_and(|v| v.contains(['\n', '\r'])) {
+                return Err(GroveError::invalid_data(format!(
+                    "Git {} must be a single line",
+                    label
+                )));
+            }
+        }
+        for email in [&self.author_email, &self.committer_email] {
+            if email.as_deref().is_some_and(|e| e.contains(['<', '>'])) {
+                return Err(GroveError::invalid_data(
+                    "Git email must not contain '<' or '>'",
+                ));
+            }
+        }
+        if let Some(trailer) = &self.trailer {
+            if !git::is_trailer_line(trailer) {
+                return Err(GroveError::invalid_data(
+                    "Trailer must look like 'Key: value'",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// 按字段覆盖：`self` 中已设置的字段优先
+    pub fn over(self, base: Self) -> Self {
+        Self {
+            author_name: self.author_name.or(base.author_name),
+            author_email: self.author_email.or(base.author_email),
+            committer_name: self.committer_name.or(base.committer_name),
+            committer_email: self.committer_email.or(base.committer_email),
+            trailer: self.trailer.or(base.trailer),
+        }
+    }
+
+    /// 放在子命令前的 `-c author.* / committer.*` 选项
+    pub fn config_args(&self) -> Vec<String> {
+        let fields = [
+            ("author.name", &self.author_name),
+            ("author.email", &self.author_email),
+            ("committer.name", &self.committer_name),
+            ("committer.email", &self.committer_email),
+        ];
+        fields
+            .into_iter()
+            .filter_map(|(key, value)| value.as_ref().map(|v| format!("{}={}", key, v)))
+            .flat_map(|arg| ["-c".to_string(), arg])
+            .collect()
+    }
+
+    /// 在提交信息末尾追加 trailer（已存在则不重复追加）
+    pub fn apply_trailer(&self, message: &str) -> String {
+        match &self.trailer {
+            Some(trailer) => git::append_trailer(message, trailer),
+            None => message.to_string(),
+        }
+    }
+}
+
+fn project_path(project: &str) -> Result<std::path::PathBuf> {
+    let dir = grove_dir().join("projects").join(project);
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir.join(FILE_NAME))
+}
+
+fn load(path: Result<std::path::PathBuf>) -> GitIdentity {
+    path.ok()
+        .filter(|path| path.exists())
+        .and_then(|path| load_toml(&path).ok())
+        .unwrap_or_default()
+}
+
+/// 保存；全部为空时删除文件
+fn save(path: std::path::PathBuf, identity: &GitIdentity) -> Result<()> {
+    if identity.is_empty() {
+        if path.exists() {
+            std::fs::remove_file(&path)?;
+        }
+        return Ok(());
+    }
+    save_toml(&path, identity)
+}
+
+/// 读取项目级身份覆盖（文件不存在返回空）
+pub fn load_project(project: &str) -> GitIdentity {
+    load(project_path(project))
+}
+
+/// 保存项目级身份覆盖
+pub fn save_project(project: &str, identity: &GitIdentity) -> Result<()> {
+    save(project_path(project)?, identity)
+}
+
+/// 读取任务级身份覆盖（文件不存在返回空）
+pub fn load_task(project: &str, task_id: &str) -> GitIdentity {
+    load(ensure_task_data_dir(project, task_id).map(|dir| dir.join(FILE_NAME)))
+}
+
+/// 保存任务级身份覆盖
+pub fn save_task(project: &str, task_id: &str, identity: &GitIdentity) -> Result<()> {
+    save(
+        ensure_task_data_dir(project, task_id)?.join(FILE_NAME),
+        identity,
+    )
+}
+
+/// 实际生效的身份：任务级覆盖项目级
+pub fn resolve(project: &str, task_id: Option<&str>) -> GitIdentity {
+    let project_identity = load_project(project);
+    match task_id {
+        Some(task_id) => load_task(project, task_id).over(project_identity),
+        None => project_identity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bot() -> GitIdentity {
+        GitIdentity {
+            author_name: Some("Agent via Grove".to_string()),
+            author_email: Some("bot@example.com".to_string()),
+            trailer: Some("Co-authored-by: Me <me@example.com>".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_config_args_and_override() {
+        assert!(GitIdentity::default().config_args().is_empty());
+
+        let task = GitIdentity {
+            author_name: Some("Task bot".to_string()),
+            committer_email: Some("ci@example.com".to_string()),
+            ..Default::default()
+        };
+        let merged = task.over(bot());
+        assert_eq!(
+            merged.config_args(),
+            vec![
+                "-c",
+                "author.name=Task bot",
+                "-c",
+                "author.email=bot@example.com",
+                "-c",
+                "committer.email=ci@example.com",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_trailer() {
+        let id = bot();
+        assert_eq!(
+            id.apply_trailer("Fix bug\n"),
+            "Fix bug\n\nCo-authored-by: Me <me@example.com>"
+        );
+        // 已有 trailer 块时接在后面
+        assert_eq!(
+            id.apply_trailer("Fix bug\n\nSigned-off-by: A <a@b>"),
+            "Fix bug\n\nSigned-off-by: A <a@b>\nCo-authored-by: Me <me@example.com>"
+        );
+        // 不重复追加
+        let once = id.apply_trailer("Fix bug");
+        assert_eq!(id.apply_trailer(&once), once);
+        assert_eq!(GitIdentity::default().apply_trailer("Msg"), "Msg");
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(bot().validate().is_ok());
+        let bad_email = GitIdentity {
+            author_email: Some("<bot@example.com>".to_string()),
+            ..Default::default()
+        };
+        assert!(bad_email.validate().is_err());
+        let bad_trailer = GitIdentity {
+            trailer: Some("not a trailer".to_string()),
+            ..Default::default()
+        };
+        assert!(bad_trailer.validate().is_err());
+        let cleared = GitIdentity {
+            author_name: Some("  ".to_string()),
+            ..Default::default()
+        }
+        .normalized();
+        assert!(cleared.is_empty());
+    }
+}
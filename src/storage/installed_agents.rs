@@ -457,9 +457,10 @@ pub fn spawn_for(
         InstallMethod::Binary => {
             let path = install.install_path.as_ref()?;
             if !std::path::Path::new(path).exists() {
-                eprintln!(
-                    "[installed_agents] install_path missing for {} — falling back: {}",
-                    rec.id, path
+                tracing::warn!(
+                    "install_path missing for {} — falling back: {}",
+                    rec.id,
+                    path
                 );
                 return None;
             }
@@ -801,11 +802,13 @@ fn warn_selection_drift_once(id: &str, selected: InstallMethod, fallback: Instal
             return;
         }
     }
-    eprintln!(
-        "[installed_agents] selection drift for {}: selected_install_method={:?} \
+    tracing::warn!(
+        "selection drift for {}: selected_install_method={:?} \
          has no matching installation; falling back to {:?}. This indicates a bug \
          in the writer path — installation list was mutated without updating the selection.",
-        id, selected, fallback,
+        id,
+        selected,
+        fallback,
     );
 }
 
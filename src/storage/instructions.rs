@@ -0,0 +1,164 @@
+//! 指令前言（instruction preamble）
+//!
+//! 全局: ~/.grove/instructions.toml
+//! 项目: ~/.grove/projects/{project}/instructions.toml
+//!
+//! 每个作用域有一段通用前言和按 agent id 区分的前言。新 ACP session 的首个
+//! prompt 会附带按 全局 → 全局 agent → 项目 → 项目 agent 顺序拼接的前言
+//! （作为隐藏的 context block，不显示在聊天记录中）。
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{grove_dir, load_toml, project_settings, save_toml};
+use crate::error::Result;
+
+/// 一个作用域（全局或项目）的前言设置
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Instructions {
+    /// 对所有 agent 生效
+    #[serde(default)]
+    pub preamble: String,
+    /// agent id → 只对该 agent 生效的前言
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub agents: BTreeMap<String, String>,
+}
+
+impl Instructions {
+    /// 去掉首尾空白，丢弃空的 agent 前言
+    pub fn normalize(&mut self) {
+        self.preamble = self.preamble.trim().to_string();
+        self.agents = std::mem::take(&mut self.agents)
+            .into_iter()
+            .map(|(agent, text)| (agent.trim().to_string(), text.trim().to_string()))
+            .filter(|(agent, text)| !agent.is_empty() && !text.is_empty())
+            .collect();
+    }
+}
+
+/// 生效的一段前言及其来源
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Preamble {
+    /// `global` / `global:{agent}` / `project` / `project:{agent}`
+    pub scope: String,
+    pub text: String,
+}
+
+fn global_path() -> std::path::PathBuf {
+    grove_dir().join("instructions.toml")
+}
+
+const PROJECT_FILE: &str = "instructions.toml";
+
+/// 读取全局前言（文件不存在返回空）
+pub fn load_global() -> Instructions {
+    let path = global_path();
+    path.exists()
+        .then(|| load_toml(&path).ok())
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// 保存全局前言
+pub fn save_global(instructions: &Instructions) -> Result<()> {
+    std::fs::create_dir_all(grove_dir())?;
+    save_toml(&global_path(), instructions)
+}
+
+/// 读取项目前言（文件不存在返回空）
+pub fn load_project(project: &str) -> Instructions {
+    project_settings::load(project, PROJECT_FILE)
+}
+
+/// 保存项目前言
+pub fn save_project(project: &str, instructions: &Instructions) -> Result<()> {
+    project_settings::save(project, PROJECT_FILE, instructions)
+}
+
+/// 按 全局 → 全局 agent → 项目 → 项目 agent 顺序收集对 agent 生效的前言
+pub fn resolve(global: &Instructions, project: &Instructions, agent: &str) -> Vec<Preamble> {
+    let mut out = Vec::new();
+    for (scope, instructions) in [("global", global), ("project", project)] {
+        let text = instructions.preamble.trim();
+        if !text.is_empty() {
+            out.push(Preamble {
+                scope: scope.to_string(),
+                text: text.to_string(),
+            });
+        }
+        if let Some(text) = instructions.agents.get(agent).map(|t| t.trim()) {
+            if !text.is_empty() {
+                out.push(Preamble {
+                    scope: format!("{}:{}", scope, agent),
+                    text: text.to_string(),
+                });
+            }
+        }
+    }
+    out
+}
+
+/// 对 agent 生效的前言（读取全局和项目设置）
+pub fn effective(project: &str, agent: &str) -> Vec<Preamble> {
+    resolve(&load_global(), &load_project(project), agent)
+}
+
+/// 把前言拼成一段文本；没有前言时返回 None
+pub fn render(preambles: &[Preamble]) -> Option<String> {
+    if preambles.is_empty() {
+        return None;
+    }
+    Some(
+        preambles
+            .iter()
+            .map(|p| p.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_orders_scopes_and_filters_agents() {
+        let global = Instructions {
+            preamble: "Be concise.".to_string(),
+            agents: BTreeMap::from([
+                ("claude".to_string(), "Use plan mode first.".to_string()),
+                ("codex".to_string(), "Codex only.".to_string()),
+            ]),
+        };
+        let project = Instructions {
+            preamble: "  ".to_string(),
+            agents: BTreeMap::from([("claude".to_string(), "Run cargo test.".to_string())]),
+        };
+        let resolved = resolve(&global, &project, "claude");
+        let scopes: Vec<&str> = resolved.iter().map(|p| p.scope.as_str()).collect();
+        assert_eq!(scopes, vec!["global", "global:claude", "project:claude"]);
+        assert_eq!(
+            render(&resolved).as_deref(),
+            Some("Be concise.\n\nUse plan mode first.\n\nRun cargo test.")
+        );
+        assert!(render(&resolve(&Instructions::default(), &project, "codex")).is_none());
+    }
+
+    #[test]
+    fn normalize_drops_blank_agent_entries() {
+        let mut instructions = Instructions {
+            preamble: "  hi \n".to_string(),
+            agents: BTreeMap::from([
+                (" claude ".to_string(), " x ".to_string()),
+                ("codex".to_string(), "   ".to_string()),
+            ]),
+        };
+        instructions.normalize();
+        assert_eq!(instructions.preamble, "hi");
+        assert_eq!(
+            instructions.agents,
+            BTreeMap::from([("claude".to_string(), "x".to_string())])
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! 任务生命周期 hook（post-create / pre-merge / post-merge）
+//!
+//! 项目设置: ~/.grove/projects/{project}/lifecycle_hooks.toml（命令、是否阻塞、超时）；
+//! 仓库内脚本 `.grove/hooks/{post-create,pre-merge,post-merge}[.sh]` 需项目显式开启
+//! `run_repo_scripts` 才会执行（克隆下来的仓库不应自动跑其中的代码），
+//! 由 operations::lifecycle_hooks 在任务 worktree 中执行。
+
+use serde::{Deserialize, Serialize};
+
+use super::project_settings;
+use crate::error::{GroveError, Result};
+
+/// 触发时机
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    /// worktree 创建之后（如 `npm install`）
+    PostCreate,
+    /// merge 之前（如跑测试）
+    PreMerge,
+    /// merge 成功之后（如清理）
+    PostMerge,
+}
+
+impl HookEvent {
+    /// `.grove/hooks/` 下的脚本名（不含 `.sh` 后缀）
+    pub fn script_name(&self) -> &'static str {
+        match self {
+            HookEvent::PostCreate => "post-create",
+            HookEvent::PreMerge => "pre-merge",
+            HookEvent::PostMerge => "post-merge",
+        }
+    }
+
+    /// 未显式配置时是否阻塞：只有 pre-merge 默认阻止 merge
+    pub fn default_blocking(&self) -> bool {
+        matches!(self, HookEvent::PreMerge)
+    }
+}
+
+/// 一个配置的 hook 命令
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LifecycleHook {
+    pub event: HookEvent,
+    /// 在任务 worktree 根目录通过 shell 执行
+    pub command: String,
+    /// 失败时是否阻塞（post-create: 取消创建；pre-merge: 阻止 merge）；
+    /// None 按事件默认值。post-merge 已无法撤销，失败只作为警告
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocking: Option<bool>,
+}
+
+impl LifecycleHook {
+    pub fn is_blocking(&self) -> bool {
+        self.blocking.unwrap_or(self.event.default_blocking())
+    }
+}
+
+/// 项目级生命周期 hook 设置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LifecycleHookSettings {
+    #[serde(default)]
+    pub hooks: Vec<LifecycleHook>,
+    /// 是否执行仓库内 `.grove/hooks/` 脚本（默认关闭）
+    #[serde(default)]
+    pub run_repo_scripts: bool,
+    /// 单个 hook 的超时时间（秒）
+    #[serde(default = "default_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_timeout() -> u64 {
+    600
+}
+
+impl Default for LifecycleHookSettings {
+    fn default() -> Self {
+        Self {
+            hooks: Vec::new(),
+            run_repo_scripts: false,
+            timeout_secs: default_timeout(),
+        }
+    }
+}
+
+impl LifecycleHookSettings {
+    /// 去掉空命令的 hook，超时至少 1 秒
+    pub fn normalized(mut self) -> Result<Self> {
+        self.hooks = self
+            .hooks
+            .into_iter()
+            .map(|h| LifecycleHook {
+                command: h.command.trim().to_string(),
+                ..h
+            })
+            .filter(|h| !h.command.is_empty())
+            .collect();
+        if self.timeout_secs == 0 {
+            return Err(GroveError::invalid_data(
+                "Hook timeout must be at least 1 second",
+            ));
+        }
+        Ok(self)
+    }
+
+    /// 某个事件的配置命令（按配置顺序）
+    pub fn for_event(&self, event: HookEvent) -> impl Iterator<Item = &LifecycleHook> {
+        self.hooks.iter().filter(move |h| h.event == event)
+    }
+}
+
+const SETTINGS_FILE: &str = "lifecycle_hooks.toml";
+
+/// 读取 hook 设置（文件不存在返回默认值：无配置命令，不执行仓库脚本）
+pub fn load_settings(project: &str) -> LifecycleHookSettings {
+    project_settings::load(project, SETTINGS_FILE)
+}
+
+/// 保存 hook 设置
+pub fn save_settings(project: &str, settings: &LifecycleHookSettings) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocking_defaults() {
+        let hook = |event, blocking| LifecycleHook {
+            event,
+            command: "true".into(),
+            blocking,
+        };
+        assert!(!hook(HookEvent::PostCreate, None).is_blocking());
+        assert!(hook(HookEvent::PreMerge, None).is_blocking());
+        assert!(!hook(HookEvent::PreMerge, Some(false)).is_blocking());
+        assert!(hook(HookEvent::PostCreate, Some(true)).is_blocking());
+    }
+
+    #[test]
+    fn test_settings_toml() {
+        let settings: LifecycleHookSettings = toml::from_str(
+            r#"
+            [[hooks]]
+            event = "post_create"
+            command = " npm install "
+
+            [[hooks]]
+            event = "pre_merge"
+            command = "  "
+            "#,
+        )
+        .unwrap();
+        assert!(!settings.run_repo_scripts);
+        assert_eq!(settings.timeout_secs, 600);
+
+        let settings = settings.normalized().unwrap();
+        assert_eq!(settings.hooks.len(), 1);
+        assert_eq!(settings.hooks[0].command, "npm install");
+        assert_eq!(settings.for_event(HookEvent::PostCreate).count(), 1);
+        assert_eq!(settings.for_event(HookEvent::PreMerge).count(), 0);
+    }
+}
@@ -0,0 +1,282 @@
+//! 项目级 merge 门禁: ~/.grove/projects/{project}/merge_gate.toml
+//!
+//! 开启后，存在未解决的 review 评论或指定 reviewer 尚未 approve 时禁止 merge。
+//! 安全扫描（security_scan.toml）开启阻止时，达到阈值的扫描问题同样会阻止 merge；
+//! 新增文件守卫（file_guard.toml）开启阻止时，违规的新增文件也会阻止 merge。
+//! TUI / Web / MCP `grove_complete_task` 共用同一份检查。
+
+use serde::{Deserialize, Serialize};
+
+use super::comments::{self, CommentStatus, CommentsData, ReviewSubmission, ReviewVerdict};
+use super::file_guard::{self, GuardViolation};
+use super::project_settings;
+use super::security_scan::{self, Severity};
+use crate::error::{GroveError, Result};
+
+/// merge 被门禁拦下时的 storage tag
+pub const MERGE_BLOCKED_TAG: &str = "merge_blocked";
+
+/// 项目级 merge 门禁设置
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MergeGate {
+    /// 所有 review 评论都必须 resolved（outdated 不算未解决）
+    #[serde(default)]
+    pub require_resolved: bool,
+    /// 必须 approve 的 reviewer（按 review 提交里的 reviewer 名匹配，忽略大小写）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub required_reviewers: Vec<String>,
+}
+
+impl MergeGate {
+    pub fn is_enabled(&self) -> bool {
+        self.require_resolved || !self.required_reviewers.is_empty()
+    }
+
+    /// 去掉空白和重复的 reviewer
+    pub fn normalized(self) -> Self {
+        let mut reviewers: Vec<String> = Vec::new();
+        for name in self.required_reviewers {
+            let name = name.trim().to_string();
+            if !name.is_empty() && !reviewers.iter().any(|r| r.eq_ignore_ascii_case(&name)) {
+                reviewers.push(name);
+            }
+        }
+        Self {
+            require_resolved: self.require_resolved,
+            required_reviewers: reviewers,
+        }
+    }
+}
+
+/// 阻止 merge 的原因
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MergeBlocker {
+    /// 仍有未解决的评论
+    OpenComments { count: usize, comment_ids: Vec<u32> },
+    /// 指定 reviewer 还没 approve（verdict 为其最后一次非 comment 结论）
+    ReviewerNotApproved {
+        reviewer: String,
+        verdict: Option<ReviewVerdict>,
+    },
+    /// 最近一次安全扫描存在 >= severity 的问题
+    SecurityFindings { count: usize, severity: Severity },
+    /// 新增文件违反守卫（过大、禁止的路径 / 扩展名 / 许可证）
+    GuardedFiles { violations: Vec<GuardViolation> },
+}
+
+impl std::fmt::Display for MergeBlocker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MergeBlocker::OpenComments { count, .. } => write!(
+                f,
+                "{} unresolved review comment{}",
+                count,
+                if *count == 1 { "" } else { "s" }
+            ),
+            MergeBlocker::ReviewerNotApproved {
+                reviewer,
+                verdict: Some(ReviewVerdict::RequestChanges),
+            } => write!(f, "{} requested changes", reviewer),
+            MergeBlocker::ReviewerNotApproved { reviewer, .. } => {
+                write!(f, "waiting for approval from {}", reviewer)
+            }
+            MergeBlocker::SecurityFindings { count, severity } => write!(
+                f,
+                "{} security finding{} of {} severity or above",
+                count,
+                if *count == 1 { "" } else { "s" },
+                severity.as_str()
+            ),
+            MergeBlocker::GuardedFiles { violations } => {
+                write!(f, "{}", file_guard::describe(violations))
+            }
+        }
+    }
+}
+
+const SETTINGS_FILE: &str = "merge_gate.toml";
+
+/// 读取 merge 门禁设置（文件不存在返回默认值，即不拦截；文件写错时返回错误，
+/// 由调用方拒绝 merge）
+pub fn load_settings(project: &str) -> Result<MergeGate> {
+    project_settings::try_load(project, SETTINGS_FILE)
+}
+
+/// 保存 merge 门禁设置
+pub fn save_settings(project: &str, gate: &MergeGate) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, gate)
+}
+
+/// 根据评论和 review 提交计算阻止 merge 的原因
+pub fn evaluate(
+    gate: &MergeGate,
+    data: &CommentsData,
+    submissions: &[ReviewSubmission],
+) -> Vec<MergeBlocker> {
+    let mut blockers = Vec::new();
+
+    if gate.require_resolved {
+        let comment_ids: Vec<u32> = data
+            .comments
+            .iter()
+            .filter(|c| c.status == CommentStatus::Open)
+            .map(|c| c.id)
+            .collect();
+        if !comment_ids.is_empty() {
+            blockers.push(MergeBlocker::OpenComments {
+                count: comment_ids.len(),
+                comment_ids,
+            });
+        }
+    }
+
+    for reviewer in &gate.required_reviewers {
+        let verdict = submissions
+            .iter()
+            .rev()
+            .filter(|s| s.reviewer.eq_ignore_ascii_case(reviewer))
+            .map(|s| s.verdict)
+            .find(|v| *v != ReviewVerdict::Comment);
+        if verdict != Some(ReviewVerdict::Approve) {
+            blockers.push(MergeBlocker::ReviewerNotApproved {
+                reviewer: reviewer.clone(),
+                verdict,
+            });
+        }
+    }
+
+    blockers
+}
+
+/// 最近一次安全扫描中达到阻止级别的问题（未配置扫描或未开启阻止时返回 None）
+fn security_blocker(project: &str, task_id: &str) -> Result<Option<MergeBlocker>> {
+    let settings = security_scan::load_settings(project)?;
+    if !settings.is_configured() || !settings.block {
+        return Ok(None);
+    }
+    let Some(report) = security_scan::load_report(project, task_id) else {
+        return Ok(None);
+    };
+    let count = report.at_least(settings.block_severity).count();
+    Ok((count > 0).then_some(MergeBlocker::SecurityFindings {
+        count,
+        severity: settings.block_severity,
+    }))
+}
+
+/// 违反守卫的新增文件（未开启守卫或只警告时返回 None）
+fn guard_blocker(project: &str, task_id: &str) -> Result<Option<MergeBlocker>> {
+    let settings = file_guard::load_settings(project)?;
+    if !settings.block {
+        return Ok(None);
+    }
+    let violations = file_guard::task_violations(project, task_id, &settings);
+    Ok((!violations.is_empty()).then_some(MergeBlocker::GuardedFiles { violations }))
+}
+
+/// 检查某个 task 当前是否可以 merge（门禁未开启且无安全问题 / 违规文件时返回空）。
+/// 任一门禁设置文件无法解析时返回错误，merge 被拒绝。
+pub fn check(project: &str, task_id: &str) -> Result<Vec<MergeBlocker>> {
+    let gate = load_settings(project)?;
+    let mut blockers = if gate.is_enabled() {
+        let data = comments::load_comments(project, task_id)?;
+        let submissions = comments::load_review_submissions(project, task_id)?;
+        evaluate(&gate, &data, &submissions)
+    } else {
+        Vec::new()
+    };
+    blockers.extend(security_blocker(project, task_id)?);
+    blockers.extend(guard_blocker(project, task_id)?);
+    Ok(blockers)
+}
+
+/// 面向用户的一行说明，如 `Merge blocked: 2 unresolved review comments; Bob requested changes`
+pub fn describe(blockers: &[MergeBlocker]) -> String {
+    let reasons: Vec<String> = blockers.iter().map(|b| b.to_string()).collect();
+    format!("Merge blocked: {}", reasons.join("; "))
+}
+
+/// 有阻止原因时返回带 `MERGE_BLOCKED_TAG` 的错误
+pub fn ensure_mergeable(project: &str, task_id: &str) -> Result<()> {
+    let blockers = check(project, task_id)?;
+    if blockers.is_empty() {
+        return Ok(());
+    }
+    Err(GroveError::storage_tagged(
+        MERGE_BLOCKED_TAG,
+        describe(&blockers),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(reviewer: &str, verdict: ReviewVerdict) -> ReviewSubmission {
+        ReviewSubmission {
+            id: 0,
+            reviewer: reviewer.to_string(),
+            verdict,
+            summary: String::new(),
+            comment_ids: Vec::new(),
+            timestamp: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_reviewers() {
+        let gate = MergeGate {
+            require_resolved: true,
+            required_reviewers: vec!["Alice".to_string(), "Bob".to_string()],
+        };
+        let submissions = vec![
+            submission("alice", ReviewVerdict::RequestChanges),
+            submission("alice", ReviewVerdict::Approve),
+            // 之后的 comment 不覆盖 approve
+            submission("alice", ReviewVerdict::Comment),
+            submission("bob", ReviewVerdict::RequestChanges),
+        ];
+        let blockers = evaluate(&gate, &CommentsData::default(), &submissions);
+        assert_eq!(
+            blockers,
+            vec![MergeBlocker::ReviewerNotApproved {
+                reviewer: "Bob".to_string(),
+                verdict: Some(ReviewVerdict::RequestChanges),
+            }]
+        );
+        assert_eq!(blockers[0].to_string(), "Bob requested changes");
+
+        assert!(evaluate(&MergeGate::default(), &CommentsData::default(), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_settings_block_merge() {
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+        let dir = temp.path().join("projects").join("proj");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // 任一门禁设置写错都应拒绝 merge，而不是退回默认值放行
+        for file in ["merge_gate.toml", "security_scan.toml", "file_guard.toml"] {
+            std::fs::write(dir.join(file), "require_resolved = tru\n").unwrap();
+            let err = check("proj", "task").unwrap_err();
+            assert!(err.to_string().contains(file), "{err}");
+            assert!(ensure_mergeable("proj", "task").is_err());
+            std::fs::remove_file(dir.join(file)).unwrap();
+        }
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_normalized() {
+        let gate = MergeGate {
+            require_resolved: false,
+            required_reviewers: vec![" Alice ".to_string(), "alice".to_string(), "".to_string()],
+        }
+        .normalized();
+        assert_eq!(gate.required_reviewers, vec!["Alice"]);
+        assert!(gate.is_enabled());
+    }
+}
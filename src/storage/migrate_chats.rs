@@ -22,8 +22,8 @@ pub fn migrate_chats_toml_to_sqlite(conn: &Connection) -> Result<()> {
     let projects = match std::fs::read_dir(&projects_dir) {
         Ok(entries) => entries,
         Err(e) => {
-            eprintln!(
-                "[warning] chats.toml migration: cannot read {}: {}",
+            tracing::warn!(
+                "chats.toml migration: cannot read {}: {}",
                 projects_dir.display(),
                 e
             );
@@ -63,9 +63,11 @@ pub fn migrate_chats_toml_to_sqlite(conn: &Connection) -> Result<()> {
             }
 
             match migrate_one_task(conn, &project_id, &task_id, &chats_path) {
-                Ok(count) => eprintln!(
-                    "  [migrate] chats.toml → session: {}/{} ({} chats)",
-                    project_id, task_id, count
+                Ok(count) => tracing::info!(
+                    "chats.toml → session: {}/{} ({} chats)",
+                    project_id,
+                    task_id,
+                    count
                 ),
                 Err(crate::error::GroveError::TomlParse(e)) => {
                     // Pick a non-clobbering target: if `chats.toml.broken`
@@ -82,8 +84,8 @@ pub fn migrate_chats_toml_to_sqlite(conn: &Connection) -> Result<()> {
                         primary
                     };
                     let renamed = std::fs::rename(&chats_path, &target).is_ok();
-                    eprintln!(
-                        "[warning] chats.toml migration: structural error for {}/{}: {}{}",
+                    tracing::warn!(
+                        "chats.toml migration: structural error for {}/{}: {}{}",
                         project_id,
                         task_id,
                         e,
@@ -103,16 +105,19 @@ pub fn migrate_chats_toml_to_sqlite(conn: &Connection) -> Result<()> {
                     // was removed before load. Treat as "nothing to
                     // migrate here" rather than a transient failure
                     // that would retry forever.
-                    eprintln!(
-                        "[info] chats.toml migration: {}/{} disappeared between scan and read; skipping",
-                        project_id, task_id
+                    tracing::info!(
+                        "chats.toml migration: {}/{} disappeared between scan and read; skipping",
+                        project_id,
+                        task_id
                     );
                 }
                 Err(e) => {
                     had_transient_failure = true;
-                    eprintln!(
-                        "[warning] chats.toml migration failed for {}/{}: {} (will retry next launch)",
-                        project_id, task_id, e
+                    tracing::warn!(
+                        "chats.toml migration failed for {}/{}: {} (will retry next launch)",
+                        project_id,
+                        task_id,
+                        e
                     );
                 }
             }
@@ -125,8 +130,8 @@ pub fn migrate_chats_toml_to_sqlite(conn: &Connection) -> Result<()> {
     if !had_transient_failure {
         mark_done(conn)?;
     } else {
-        eprintln!(
-            "[warning] chats.toml migration left unfinished due to transient errors; \
+        tracing::warn!(
+            "chats.toml migration left unfinished due to transient errors; \
              will retry on next launch"
         );
     }
@@ -194,8 +199,8 @@ fn migrate_one_task(
             ],
         )?;
         if rows == 0 {
-            eprintln!(
-                "[warning] chats.toml migration: chat id '{}' already exists in session table, skipping {}/{}",
+            tracing::warn!(
+                "chats.toml migration: chat id '{}' already exists in session table, skipping {}/{}",
                 chat.id, project_id, task_id
             );
         } else {
@@ -246,6 +251,7 @@ mod tests {
                 .to_utc(),
             duty: duty.map(str::to_string),
             launch_mode: "acp".to_string(),
+            work_subdir: None,
         }
     }
 
@@ -271,6 +277,8 @@ mod tests {
             code_deletions: 0,
             files_changed: 0,
             is_local: false,
+            assignee: None,
+            pinned: false,
         }
     }
 
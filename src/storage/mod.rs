@@ -3,26 +3,50 @@ pub mod agent_install;
 pub mod agent_registry;
 pub mod ai;
 pub mod automations;
+pub mod backend;
+pub mod bench;
 pub mod chat_attachments;
+pub mod chat_defaults;
 pub mod chat_history;
+pub mod chat_pins;
+pub mod checkpoints;
 pub mod comments;
 pub mod config;
+pub mod coverage;
 pub mod curated_agents;
 pub mod custom_agent;
 pub mod database;
+pub mod diff_filters;
+pub mod file_guard;
+pub mod forge;
+pub mod git_identity;
 pub mod installed_agents;
+pub mod instructions;
 pub mod keymap;
 pub mod libraries;
+pub mod lifecycle_hooks;
+pub mod merge_gate;
 pub mod migrate_chats;
 pub mod notes;
+pub mod org;
 pub mod plugin_data;
 pub mod plugins;
+pub mod project_notes;
+pub mod project_settings;
+pub mod security_scan;
+pub mod shares;
 pub mod sketch_checkpoints;
 pub mod sketches;
 pub mod skills;
+pub mod task_scope;
+pub mod task_summary;
+pub mod task_views;
 pub mod taskgroups;
 pub mod tasks;
+pub mod templates;
 pub mod token_usage;
+pub mod turn_manifests;
+pub mod turn_snapshots;
 pub mod workspace;
 
 use std::cell::RefCell;
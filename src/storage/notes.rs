@@ -1,15 +1,184 @@
+//! 任务笔记（notes.md）及其修订历史
+//!
+//! 每次写入都会记录一个修订，保留最近 `MAX_REVISIONS` 个，防止笔记被
+//! （例如 MCP `grove_edit_note`）整体覆盖后无法找回。
+//!
+//! 布局：
+//!   tasks/<task-id>/notes.md
+//!   tasks/<task-id>/notes_history/
+//!     ├── index.json     # { revisions: [{id, ts, source, size, hash}] }（旧 → 新）
+//!     └── <rev-id>.md    # 该修订的完整内容
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
 use super::ensure_task_data_dir;
-use crate::error::Result;
+use crate::error::{GroveError, Result};
+
+/// 每个任务保留的修订数
+pub const MAX_REVISIONS: usize = 50;
+
+/// 串行化同一进程内的笔记写入，避免并发写坏 index.json
+static NOTES_LOCK: Mutex<()> = Mutex::new(());
+
+/// 修订的写入来源
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NoteSource {
+    Web,
+    Mcp,
+    Tui,
+    Sync,
+    Automation,
+    Restore,
+    /// 自动生成的任务总结（`task_summary`）
+    Summary,
+    /// Grove 之外的修改（直接编辑文件），在下一次写入前补记
+    External,
+}
+
+impl NoteSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NoteSource::Web => "web",
+            NoteSource::Mcp => "mcp",
+            NoteSource::Tui => "tui",
+            NoteSource::Sync => "sync",
+            NoteSource::Automation => "automation",
+            NoteSource::Restore => "restore",
+            NoteSource::Summary => "summary",
+            NoteSource::External => "external",
+        }
+    }
+}
+
+/// 一个笔记修订
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NoteRevision {
+    pub id: String,
+    /// RFC 3339
+    pub ts: String,
+    pub source: NoteSource,
+    /// 内容字节数
+    pub size: usize,
+    /// 内容的 sha256（hex），用于跳过重复修订
+    pub hash: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RevisionIndex {
+    #[serde(default)]
+    revisions: Vec<NoteRevision>,
+}
+
+fn notes_path(project: &str, task_id: &str) -> Result<PathBuf> {
+    Ok(ensure_task_data_dir(project, task_id)?.join("notes.md"))
+}
+
+fn history_dir(project: &str, task_id: &str) -> Result<PathBuf> {
+    Ok(ensure_task_data_dir(project, task_id)?.join("notes_history"))
+}
+
+fn content_hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+/// 拒绝可能逃出历史目录的 id
+fn validate_revision_id(id: &str) -> Result<()> {
+    let ok = id.starts_with("rev-")
+        && id.len() <= 64
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !ok {
+        return Err(GroveError::invalid_data(format!(
+            "Invalid revision id '{}'",
+            id
+        )));
+    }
+    Ok(())
+}
+
+fn load_index(project: &str, task_id: &str) -> Result<RevisionIndex> {
+    let path = history_dir(project, task_id)?.join("index.json");
+    if !path.exists() {
+        return Ok(RevisionIndex::default());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    Ok(serde_json::from_str(&content).unwrap_or_else(|e| {
+        tracing::warn!("notes history index is corrupt ({e}); starting a new one");
+        RevisionIndex::default()
+    }))
+}
+
+fn save_index(project: &str, task_id: &str, index: &RevisionIndex) -> Result<()> {
+    let dir = history_dir(project, task_id)?;
+    std::fs::create_dir_all(&dir)?;
+    std::fs::write(dir.join("index.json"), serde_json::to_string_pretty(index)?)?;
+    Ok(())
+}
+
+/// 追加修订（与最新修订内容相同时跳过），并裁剪到 `MAX_REVISIONS`
+fn append_revision(
+    project: &str,
+    task_id: &str,
+    index: &mut RevisionIndex,
+    content: &str,
+    source: NoteSource,
+) -> Result<()> {
+    let hash = content_hash(content);
+    if index.revisions.last().is_some_and(|r| r.hash == hash) {
+        return Ok(());
+    }
+    let dir = history_dir(project, task_id)?;
+    std::fs::create_dir_all(&dir)?;
+    let id = format!("rev-{}", uuid::Uuid::new_v4().simple());
+    std::fs::write(dir.join(format!("{id}.md")), content)?;
+    index.revisions.push(NoteRevision {
+        id,
+        ts: chrono::Utc::now().to_rfc3339(),
+        source,
+        size: content.len(),
+        hash,
+    });
+    if index.revisions.len() > MAX_REVISIONS {
+        let excess = index.revisions.len() - MAX_REVISIONS;
+        for old in index.revisions.drain(..excess) {
+            let _ = std::fs::remove_file(dir.join(format!("{}.md", old.id)));
+        }
+    }
+    Ok(())
+}
+
+/// 若当前文件内容尚未记录（例如在 Grove 外被直接修改），补记为一个修订
+fn record_current(
+    project: &str,
+    task_id: &str,
+    index: &mut RevisionIndex,
+    source: NoteSource,
+) -> Result<()> {
+    let path = notes_path(project, task_id)?;
+    if !path.exists() {
+        return Ok(());
+    }
+    let current = std::fs::read_to_string(&path)?;
+    if current.is_empty() && index.revisions.is_empty() {
+        return Ok(());
+    }
+    append_revision(project, task_id, index, &current, source)
+}
 
 /// 获取 notes 文件完整路径（字符串）
 pub fn notes_file_path(project: &str, task_id: &str) -> Result<String> {
-    let path = ensure_task_data_dir(project, task_id)?.join("notes.md");
-    Ok(path.to_string_lossy().to_string())
+    Ok(notes_path(project, task_id)?.to_string_lossy().to_string())
 }
 
 /// 如果 notes 文件不存在则创建空文件
 pub fn save_notes_if_not_exists(project: &str, task_id: &str) -> Result<()> {
-    let path = ensure_task_data_dir(project, task_id)?.join("notes.md");
+    let path = notes_path(project, task_id)?;
     if !path.exists() {
         std::fs::write(&path, "")?;
     }
@@ -18,7 +187,7 @@ pub fn save_notes_if_not_exists(project: &str, task_id: &str) -> Result<()> {
 
 /// 读取用户笔记
 pub fn load_notes(project: &str, task_id: &str) -> Result<String> {
-    let path = ensure_task_data_dir(project, task_id)?.join("notes.md");
+    let path = notes_path(project, task_id)?;
     if path.exists() {
         let content = std::fs::read_to_string(&path)?;
         Ok(content)
@@ -27,9 +196,132 @@ pub fn load_notes(project: &str, task_id: &str) -> Result<String> {
     }
 }
 
-/// 保存用户笔记
-pub fn save_notes(project: &str, task_id: &str, content: &str) -> Result<()> {
-    let path = ensure_task_data_dir(project, task_id)?.join("notes.md");
-    std::fs::write(&path, content)?;
-    Ok(())
+/// 保存用户笔记，并记录一个修订
+pub fn save_notes(project: &str, task_id: &str, content: &str, source: NoteSource) -> Result<()> {
+    let _guard = NOTES_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut index = load_index(project, task_id)?;
+    record_current(project, task_id, &mut index, NoteSource::External)?;
+    std::fs::write(notes_path(project, task_id)?, content)?;
+    append_revision(project, task_id, &mut index, content, source)?;
+    save_index(project, task_id, &index)
+}
+
+/// 记录当前文件内容（用于外部编辑器直接改写 notes.md 之后）
+pub fn record_notes_revision(project: &str, task_id: &str, source: NoteSource) -> Result<()> {
+    let _guard = NOTES_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut index = load_index(project, task_id)?;
+    record_current(project, task_id, &mut index, source)?;
+    save_index(project, task_id, &index)
+}
+
+/// 列出修订（新 → 旧）
+pub fn list_revisions(project: &str, task_id: &str) -> Result<Vec<NoteRevision>> {
+    let mut revisions = load_index(project, task_id)?.revisions;
+    revisions.reverse();
+    Ok(revisions)
+}
+
+/// 读取修订元数据与内容
+pub fn load_revision(project: &str, task_id: &str, id: &str) -> Result<(NoteRevision, String)> {
+    validate_revision_id(id)?;
+    let revision = load_index(project, task_id)?
+        .revisions
+        .into_iter()
+        .find(|r| r.id == id)
+        .ok_or_else(|| GroveError::not_found(format!("Revision '{}' not found", id)))?;
+    let content = std::fs::read_to_string(history_dir(project, task_id)?.join(format!("{id}.md")))
+        .map_err(|_| GroveError::not_found(format!("Revision '{}' not found", id)))?;
+    Ok((revision, content))
+}
+
+/// 恢复到指定修订（恢复本身也会成为一个新修订，可再次撤销）
+pub fn restore_revision(project: &str, task_id: &str, id: &str) -> Result<String> {
+    let (_, content) = load_revision(project, task_id, id)?;
+    save_notes(project, task_id, &content, NoteSource::Restore)?;
+    Ok(content)
+}
+
+/// 修订与另一修订（`against` 为 None 时为当前笔记）之间的 unified diff
+pub fn diff_revision(
+    project: &str,
+    task_id: &str,
+    id: &str,
+    against: Option<&str>,
+) -> Result<String> {
+    let (_, old) = load_revision(project, task_id, id)?;
+    let (new, new_label) = match against {
+        Some(other) => (load_revision(project, task_id, other)?.1, other.to_string()),
+        None => (load_notes(project, task_id)?, "current".to_string()),
+    };
+    Ok(unified_diff(&old, &new, id, &new_label))
+}
+
+fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    similar::TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(old_label, new_label)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notes_revisions_record_restore_and_diff() {
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        save_notes("p", "t", "# Spec\n\n- keep tests\n", NoteSource::Web).unwrap();
+        // 相同内容不产生新修订
+        save_notes("p", "t", "# Spec\n\n- keep tests\n", NoteSource::Web).unwrap();
+        // 外部直接改写文件，随后被 MCP 覆盖
+        std::fs::write(notes_file_path("p", "t").unwrap(), "# Spec\n\n- edited\n").unwrap();
+        save_notes("p", "t", "wiped\n", NoteSource::Mcp).unwrap();
+
+        let revisions = list_revisions("p", "t").unwrap();
+        let sources: Vec<NoteSource> = revisions.iter().map(|r| r.source).collect();
+        assert_eq!(
+            sources,
+            vec![NoteSource::Mcp, NoteSource::External, NoteSource::Web]
+        );
+
+        let diff = diff_revision("p", "t", &revisions[2].id, None).unwrap();
+        assert!(diff.contains("-- keep tests"));
+        assert!(diff.contains("+wiped"));
+
+        let restored = restore_revision("p", "t", &revisions[2].id).unwrap();
+        assert_eq!(restored, "# Spec\n\n- keep tests\n");
+        assert_eq!(load_notes("p", "t").unwrap(), restored);
+        assert_eq!(
+            list_revisions("p", "t").unwrap()[0].source,
+            NoteSource::Restore
+        );
+
+        assert!(load_revision("p", "t", "../notes").is_err());
+        assert!(load_revision("p", "t", "rev-missing").is_err());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_notes_revisions_are_bounded() {
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        for i in 0..MAX_REVISIONS + 5 {
+            save_notes("p", "t", &format!("v{i}"), NoteSource::Tui).unwrap();
+        }
+        let revisions = list_revisions("p", "t").unwrap();
+        assert_eq!(revisions.len(), MAX_REVISIONS);
+        let (_, newest) = load_revision("p", "t", &revisions[0].id).unwrap();
+        assert_eq!(newest, format!("v{}", MAX_REVISIONS + 4));
+        let files = std::fs::read_dir(temp.path().join("projects/p/tasks/t/notes_history"))
+            .unwrap()
+            .count();
+        assert_eq!(files, MAX_REVISIONS + 1);
+
+        crate::storage::set_grove_dir_override(None);
+    }
 }
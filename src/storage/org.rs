@@ -0,0 +1,230 @@
+//! Organization 模式服务端存储：团队成员发布的共享任务
+//!
+//! 每位成员的数据由 [`MemberStore`] 隔开存放，它实现与本机相同的
+//! [`backend`](super::backend) 接口。任务以 [`Task`] 的 JSON 保存在 org_tasks
+//! 表（笔记在同一行）；review comments 复用 review_comments 表，project_key 用
+//! [`comments_key`] 与本机任务隔开。
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+use rusqlite::{params, OptionalExtension};
+
+use super::backend::{CommentStore, NoteStore, TaskStore};
+use super::comments::{self, Comment};
+use super::tasks::Task;
+use crate::error::{GroveError, Result};
+
+/// 一条共享任务
+#[derive(Debug, Clone)]
+pub struct SharedTaskRow {
+    pub owner: String,
+    pub task: Task,
+    pub notes: String,
+}
+
+/// 共享任务的 review comments 所用的 project_key
+pub fn comments_key(project: &str, owner: &str) -> String {
+    format!("org:{project}:{owner}")
+}
+
+/// 加载项目下所有成员的共享任务（最近发布的在前）
+pub fn load_shared_tasks(project: &str) -> Result<Vec<SharedTaskRow>> {
+    let conn = crate::storage::database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT owner, record, notes FROM org_tasks
+         WHERE project = ?1 ORDER BY updated_at DESC",
+    )?;
+    let rows = stmt
+        .query_map(params![project], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|(owner, record, notes)| {
+            Some(SharedTaskRow {
+                task: serde_json::from_str(&record).ok()?,
+                owner,
+                notes,
+            })
+        })
+        .collect())
+}
+
+/// 团队服务器上某位成员发布的数据
+pub struct MemberStore {
+    owner: String,
+}
+
+impl MemberStore {
+    pub fn new(owner: &str) -> Self {
+        Self {
+            owner: owner.to_string(),
+        }
+    }
+
+    fn ensure_task(&self, project: &str, task_id: &str) -> Result<()> {
+        let conn = crate::storage::database::connection();
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM org_tasks WHERE project = ?1 AND owner = ?2 AND task_id = ?3",
+            params![project, self.owner, task_id],
+            |row| row.get(0),
+        )?;
+        if count == 0 {
+            return Err(GroveError::not_found(format!(
+                "Shared task '{task_id}' of {} not found",
+                self.owner
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl TaskStore for MemberStore {
+    fn load_tasks(&self, project: &str) -> Result<Vec<Task>> {
+        Ok(load_shared_tasks(project)?
+            .into_iter()
+            .filter(|row| row.owner == self.owner)
+            .map(|row| row.task)
+            .collect())
+    }
+
+    /// 已发布任务的笔记保留；worktree、session 等本机字段不上传
+    fn replace_tasks(&self, project: &str, tasks: &[Task]) -> Result<()> {
+        let conn = crate::storage::database::connection();
+        let tx = conn.unchecked_transaction()?;
+        let existing: Vec<String> = {
+            let mut stmt =
+                tx.prepare("SELECT task_id FROM org_tasks WHERE project = ?1 AND owner = ?2")?;
+            let ids = stmt
+                .query_map(params![project, self.owner], |row| row.get(0))?
+                .collect::<rusqlite::Result<Vec<_>>>()?;
+            ids
+        };
+        let keep: HashSet<&str> = tasks.iter().map(|t| t.id.as_str()).collect();
+        for task_id in existing.iter().filter(|id| !keep.contains(id.as_str())) {
+            tx.execute(
+                "DELETE FROM org_tasks WHERE project = ?1 AND owner = ?2 AND task_id = ?3",
+                params![project, self.owner, task_id],
+            )?;
+        }
+        let now = Utc::now().to_rfc3339();
+        for task in tasks {
+            let shared = Task {
+                worktree_path: String::new(),
+                multiplexer: String::new(),
+                session_name: String::new(),
+                pinned: false,
+                ..task.clone()
+            };
+            tx.execute(
+                "INSERT INTO org_tasks (project, owner, task_id, record, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT (project, owner, task_id)
+                 DO UPDATE SET record = excluded.record, updated_at = excluded.updated_at",
+                params![
+                    project,
+                    self.owner,
+                    task.id,
+                    serde_json::to_string(&shared)?,
+                    now
+                ],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+}
+
+impl NoteStore for MemberStore {
+    fn load_notes(&self, project: &str, task_id: &str) -> Result<String> {
+        let conn = crate::storage::database::connection();
+        let notes: Option<String> = conn
+            .query_row(
+                "SELECT notes FROM org_tasks WHERE project = ?1 AND owner = ?2 AND task_id = ?3",
+                params![project, self.owner, task_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(notes.unwrap_or_default())
+    }
+
+    fn save_notes(&self, project: &str, task_id: &str, content: &str) -> Result<()> {
+        self.ensure_task(project, task_id)?;
+        let conn = crate::storage::database::connection();
+        conn.execute(
+            "UPDATE org_tasks SET notes = ?1 WHERE project = ?2 AND owner = ?3 AND task_id = ?4",
+            params![content, project, self.owner, task_id],
+        )?;
+        Ok(())
+    }
+}
+
+impl CommentStore for MemberStore {
+    fn load_comments(&self, project: &str, task_id: &str) -> Result<Vec<Comment>> {
+        self.ensure_task(project, task_id)?;
+        Ok(comments::load_comments(&comments_key(project, &self.owner), task_id)?.comments)
+    }
+
+    fn replace_comments(&self, project: &str, task_id: &str, list: &[Comment]) -> Result<()> {
+        self.ensure_task(project, task_id)?;
+        comments::replace_comments(&comments_key(project, &self.owner), task_id, list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str) -> Task {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "name": id,
+            "branch": format!("grove/{id}"),
+            "target": "main",
+            "worktree_path": "",
+            "created_at": "2026-01-01T00:00:00Z",
+            "updated_at": "2026-01-01T00:00:00Z",
+            "status": "active",
+            "multiplexer": "",
+            "session_name": "",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_member_stores_keep_members_apart() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        let alice = MemberStore::new("alice");
+        let bob = MemberStore::new("bob");
+        alice.replace_tasks("p", &[task("a1"), task("a2")]).unwrap();
+        bob.replace_tasks("p", &[task("b1")]).unwrap();
+        alice.save_notes("p", "a2", "WIP").unwrap();
+        alice.replace_tasks("p", &[task("a2")]).unwrap();
+
+        let mut ids: Vec<String> = load_shared_tasks("p")
+            .unwrap()
+            .into_iter()
+            .map(|r| format!("{}/{}", r.owner, r.task.id))
+            .collect();
+        ids.sort();
+        assert_eq!(ids, ["alice/a2", "bob/b1"]);
+        // Republishing keeps the notes
+        assert_eq!(alice.load_notes("p", "a2").unwrap(), "WIP");
+        assert!(alice.save_notes("p", "a1", "gone").is_err());
+        // Another member's task is out of reach under their own name
+        assert!(bob.save_notes("p", "a2", "mine").is_err());
+        assert!(bob.load_comments("p", "a2").is_err());
+        assert!(load_shared_tasks("other").unwrap().is_empty());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+}
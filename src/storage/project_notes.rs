@@ -0,0 +1,366 @@
+//! 项目级笔记 / 知识库（架构决策、约定等），所有任务共享
+//!
+//! 布局：
+//!   projects/<project>/notes/
+//!     ├── index.toml      # [[notes]] slug / title / include_in_chat / updated_at
+//!     └── <slug>.md       # 笔记内容
+//!
+//! 直接放进目录的 `.md` 文件同样会被识别（标题取首个 `#` 标题或文件名）。
+//! `include_in_chat` 的笔记会在新建 chat 时作为上下文附带给 agent。
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use super::{grove_dir, load_toml, save_toml};
+use crate::error::{GroveError, Result};
+
+/// 新 chat 附带的项目笔记总字节上限，超出的笔记只以链接形式提供
+pub const MAX_CHAT_CONTEXT_BYTES: usize = 64 * 1024;
+
+/// 项目笔记元数据
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ProjectNote {
+    pub slug: String,
+    pub title: String,
+    /// 新建 chat 时是否作为上下文附带
+    #[serde(default = "default_true")]
+    pub include_in_chat: bool,
+    /// RFC 3339
+    #[serde(default)]
+    pub updated_at: String,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct NotesIndex {
+    #[serde(default)]
+    notes: Vec<ProjectNote>,
+}
+
+/// 附带给 chat 的一篇笔记
+#[derive(Debug, Clone)]
+pub struct ChatContextNote {
+    pub note: ProjectNote,
+    pub path: PathBuf,
+    /// 超出 `MAX_CHAT_CONTEXT_BYTES` 时为 None（只提供链接）
+    pub content: Option<String>,
+}
+
+fn notes_dir(project: &str) -> Result<PathBuf> {
+    let dir = grove_dir().join("projects").join(project).join("notes");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// slug 只允许小写字母、数字和 `-`
+fn validate_slug(slug: &str) -> Result<()> {
+    let ok = !slug.is_empty()
+        && slug.len() <= 64
+        && !slug.starts_with('-')
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !ok {
+        return Err(GroveError::invalid_data(format!(
+            "Invalid note slug '{}'",
+            slug
+        )));
+    }
+    Ok(())
+}
+
+/// 由标题生成 slug（非 ASCII 字符丢弃，结果为空时用 `note`）
+pub fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug: String = slug.trim_end_matches('-').chars().take(48).collect();
+    let slug = slug.trim_end_matches('-').to_string();
+    if slug.is_empty() {
+        "note".to_string()
+    } else {
+        slug
+    }
+}
+
+/// 取首个 `# ` 标题作为笔记标题
+fn heading_title(content: &str) -> Option<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .find_map(|l| l.strip_prefix("# "))
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+fn load_index(project: &str) -> Result<NotesIndex> {
+    let path = notes_dir(project)?.join("index.toml");
+    if !path.exists() {
+        return Ok(NotesIndex::default());
+    }
+    Ok(load_toml(&path).unwrap_or_else(|e| {
+        tracing::warn!("project notes index is corrupt ({e}); rebuilding from files");
+        NotesIndex::default()
+    }))
+}
+
+fn save_index(project: &str, index: &NotesIndex) -> Result<()> {
+    save_toml(&notes_dir(project)?.join("index.toml"), index)
+}
+
+fn modified_rfc3339(path: &std::path::Path) -> String {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339())
+        .unwrap_or_default()
+}
+
+/// 列出项目笔记（按标题排序），包括直接放进目录、尚未登记的 `.md` 文件
+pub fn list_notes(project: &str) -> Result<Vec<ProjectNote>> {
+    let dir = notes_dir(project)?;
+    let index = load_index(project)?;
+    let mut notes = Vec::new();
+    for entry in std::fs::read_dir(&dir)?.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("md") {
+            continue;
+        }
+        let Some(slug) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if validate_slug(slug).is_err() {
+            continue;
+        }
+        let note = match index.notes.iter().find(|n| n.slug == slug) {
+            Some(note) => note.clone(),
+            None => ProjectNote {
+                slug: slug.to_string(),
+                title: std::fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|c| heading_title(&c))
+                    .unwrap_or_else(|| slug.to_string()),
+                include_in_chat: true,
+                updated_at: modified_rfc3339(&path),
+            },
+        };
+        notes.push(note);
+    }
+    notes.sort_by(|a, b| {
+        a.title
+            .to_lowercase()
+            .cmp(&b.title.to_lowercase())
+            .then_with(|| a.slug.cmp(&b.slug))
+    });
+    Ok(notes)
+}
+
+/// 笔记文件完整路径
+pub fn note_file_path(project: &str, slug: &str) -> Result<PathBuf> {
+    validate_slug(slug)?;
+    Ok(notes_dir(project)?.join(format!("{slug}.md")))
+}
+
+/// 读取笔记元数据与内容
+pub fn load_note(project: &str, slug: &str) -> Result<(ProjectNote, String)> {
+    let path = note_file_path(project, slug)?;
+    let content = std::fs::read_to_string(&path)
+        .map_err(|_| GroveError::not_found(format!("Project note '{}' not found", slug)))?;
+    let note = list_notes(project)?
+        .into_iter()
+        .find(|n| n.slug == slug)
+        .ok_or_else(|| GroveError::not_found(format!("Project note '{}' not found", slug)))?;
+    Ok((note, content))
+}
+
+/// 新建笔记（slug 由标题生成，冲突时追加序号）
+pub fn create_note(
+    project: &str,
+    title: &str,
+    content: &str,
+    include_in_chat: bool,
+) -> Result<ProjectNote> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(GroveError::invalid_data("Note title cannot be empty"));
+    }
+    let base = slugify(title);
+    let mut slug = base.clone();
+    let mut n = 2;
+    while note_file_path(project, &slug)?.exists() {
+        slug = format!("{base}-{n}");
+        n += 1;
+    }
+    std::fs::write(note_file_path(project, &slug)?, content)?;
+
+    let note = ProjectNote {
+        slug,
+        title: title.to_string(),
+        include_in_chat,
+        updated_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let mut index = load_index(project)?;
+    index.notes.retain(|n| n.slug != note.slug);
+    index.notes.push(note.clone());
+    save_index(project, &index)?;
+    Ok(note)
+}
+
+/// 更新笔记；`None` 的字段保持不变
+pub fn update_note(
+    project: &str,
+    slug: &str,
+    title: Option<&str>,
+    content: Option<&str>,
+    include_in_chat: Option<bool>,
+) -> Result<ProjectNote> {
+    let (mut note, _) = load_note(project, slug)?;
+    if let Some(title) = title {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(GroveError::invalid_data("Note title cannot be empty"));
+        }
+        note.title = title.to_string();
+    }
+    if let Some(content) = content {
+        std::fs::write(note_file_path(project, slug)?, content)?;
+    }
+    if let Some(include) = include_in_chat {
+        note.include_in_chat = include;
+    }
+    note.updated_at = chrono::Utc::now().to_rfc3339();
+
+    let mut index = load_index(project)?;
+    index.notes.retain(|n| n.slug != slug);
+    index.notes.push(note.clone());
+    save_index(project, &index)?;
+    Ok(note)
+}
+
+/// 外部编辑器改写文件后同步元数据（标题跟随首个 `#` 标题，刷新更新时间）
+pub fn touch_note(project: &str, slug: &str) -> Result<ProjectNote> {
+    let (_, content) = load_note(project, slug)?;
+    let title = heading_title(&content);
+    update_note(project, slug, title.as_deref(), None, None)
+}
+
+/// 删除笔记
+pub fn delete_note(project: &str, slug: &str) -> Result<()> {
+    let path = note_file_path(project, slug)?;
+    if !path.exists() {
+        return Err(GroveError::not_found(format!(
+            "Project note '{}' not found",
+            slug
+        )));
+    }
+    std::fs::remove_file(&path)?;
+    let mut index = load_index(project)?;
+    index.notes.retain(|n| n.slug != slug);
+    save_index(project, &index)
+}
+
+/// 新建 chat 时附带的笔记：`include_in_chat` 且非空，按标题顺序填满
+/// `MAX_CHAT_CONTEXT_BYTES`，放不下的只给路径
+pub fn chat_context_notes(project: &str) -> Result<Vec<ChatContextNote>> {
+    let mut budget = MAX_CHAT_CONTEXT_BYTES;
+    let mut out = Vec::new();
+    for note in list_notes(project)?
+        .into_iter()
+        .filter(|n| n.include_in_chat)
+    {
+        let path = note_file_path(project, &note.slug)?;
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if content.trim().is_empty() {
+            continue;
+        }
+        let content = if content.len() <= budget {
+            budget -= content.len();
+            Some(content)
+        } else {
+            None
+        };
+        out.push(ChatContextNote {
+            note,
+            path,
+            content,
+        });
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_notes_crud() {
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        let adr = create_note("p", "ADR 1: Use SQLite!", "# ADR 1\n\nYes.\n", true).unwrap();
+        assert_eq!(adr.slug, "adr-1-use-sqlite");
+        let dup = create_note("p", "ADR 1: use sqlite", "", false).unwrap();
+        assert_eq!(dup.slug, "adr-1-use-sqlite-2");
+
+        // 直接放进目录的文件
+        std::fs::write(
+            temp.path().join("projects/p/notes/conventions.md"),
+            "# Code Conventions\n\n- tabs\n",
+        )
+        .unwrap();
+
+        let notes = list_notes("p").unwrap();
+        let titles: Vec<&str> = notes.iter().map(|n| n.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec![
+                "ADR 1: use sqlite",
+                "ADR 1: Use SQLite!",
+                "Code Conventions"
+            ]
+        );
+
+        let updated = update_note("p", &dup.slug, None, Some("# Renamed\n"), Some(true)).unwrap();
+        assert!(updated.include_in_chat);
+        let touched = touch_note("p", &dup.slug).unwrap();
+        assert_eq!(touched.title, "Renamed");
+        assert_eq!(load_note("p", &dup.slug).unwrap().1, "# Renamed\n");
+
+        delete_note("p", &adr.slug).unwrap();
+        assert!(load_note("p", &adr.slug).is_err());
+        assert!(load_note("p", "../index").is_err());
+        assert!(create_note("p", "  ", "", true).is_err());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_chat_context_notes_respects_flag_and_budget() {
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        create_note("p", "A small", "small", true).unwrap();
+        create_note("p", "B hidden", "hidden", false).unwrap();
+        create_note("p", "C empty", "  \n", true).unwrap();
+        create_note("p", "D huge", &"x".repeat(MAX_CHAT_CONTEXT_BYTES), true).unwrap();
+
+        let context = chat_context_notes("p").unwrap();
+        let got: Vec<(&str, bool)> = context
+            .iter()
+            .map(|c| (c.note.slug.as_str(), c.content.is_some()))
+            .collect();
+        assert_eq!(got, vec![("a-small", true), ("d-huge", false)]);
+
+        crate::storage::set_grove_dir_override(None);
+    }
+}
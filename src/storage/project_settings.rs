@@ -0,0 +1,71 @@
+//! 项目级 TOML 设置文件的通用读写: ~/.grove/projects/{project}/{file_name}
+//!
+//! diff 过滤、merge 门禁、安全扫描等各自的设置文件都走这里，
+//! 各模块只提供文件名和设置类型。
+
+use std::path::PathBuf;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{grove_dir, load_toml, save_toml};
+use crate::error::{GroveError, Result};
+
+/// 设置文件路径（只拼路径，不创建目录）
+pub fn path(project: &str, file_name: &str) -> PathBuf {
+    grove_dir().join("projects").join(project).join(file_name)
+}
+
+/// 读取设置（文件不存在返回默认值；解析失败记日志后返回默认值）
+pub fn load<T: DeserializeOwned + Default>(project: &str, file_name: &str) -> T {
+    try_load(project, file_name).unwrap_or_else(|e| {
+        tracing::warn!("{}; using defaults", e);
+        T::default()
+    })
+}
+
+/// 读取设置（文件不存在返回默认值；读取或解析失败返回错误）。
+/// 门禁类设置用它，配置写错时拒绝放行而不是悄悄退回默认值。
+pub fn try_load<T: DeserializeOwned + Default>(project: &str, file_name: &str) -> Result<T> {
+    let path = path(project, file_name);
+    if !path.exists() {
+        return Ok(T::default());
+    }
+    load_toml(&path).map_err(|e| GroveError::config(format!("Invalid {}: {}", path.display(), e)))
+}
+
+/// 保存设置，必要时创建项目目录
+pub fn save<T: Serialize>(project: &str, file_name: &str, settings: &T) -> Result<()> {
+    let path = path(project, file_name);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    save_toml(&path, settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct Sample {
+        #[serde(default)]
+        enabled: bool,
+    }
+
+    #[test]
+    fn load_does_not_create_project_dir() {
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        let loaded: Sample = load("proj", "sample.toml");
+        assert_eq!(loaded, Sample::default());
+        assert!(!temp.path().join("projects").join("proj").exists());
+
+        save("proj", "sample.toml", &Sample { enabled: true }).unwrap();
+        let loaded: Sample = load("proj", "sample.toml");
+        assert!(loaded.enabled);
+
+        crate::storage::set_grove_dir_override(None);
+    }
+}
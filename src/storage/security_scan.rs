@@ -0,0 +1,234 @@
+//! 安全扫描（cargo audit / npm audit 等）
+//!
+//! 项目设置: ~/.grove/projects/{project}/security_scan.toml（扫描命令、阻止 / 警告的严重级别）
+//! 任务结果: ~/.grove/projects/{project}/tasks/{task_id}/security_scan.json（最近一次扫描）
+
+use serde::{Deserialize, Serialize};
+
+use super::{ensure_task_data_dir, project_settings};
+use crate::error::{GroveError, Result};
+
+const RESULT_FILE: &str = "security_scan.json";
+
+/// 漏洞严重级别（按从低到高排序）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Low,
+    Moderate,
+    High,
+    Critical,
+}
+
+impl Severity {
+    /// 解析扫描器输出中的级别（medium 视为 moderate）
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "info" | "none" => Some(Severity::Info),
+            "low" => Some(Severity::Low),
+            "moderate" | "medium" => Some(Severity::Moderate),
+            "high" => Some(Severity::High),
+            "critical" => Some(Severity::Critical),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Low => "low",
+            Severity::Moderate => "moderate",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+/// 扫描命令的输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScanFormat {
+    /// 根据 JSON 结构识别
+    #[default]
+    Auto,
+    CargoAudit,
+    NpmAudit,
+}
+
+/// 一个扫描命令，如 `cargo audit --json`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Scanner {
+    pub name: String,
+    /// 在 worktree 根目录通过 shell 执行，输出 JSON 到 stdout
+    pub command: String,
+    #[serde(default)]
+    pub format: ScanFormat,
+}
+
+/// 项目级安全扫描设置
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScanSettings {
+    #[serde(default)]
+    pub scanners: Vec<Scanner>,
+    /// merge 前自动扫描（任务 HEAD 已扫描过则复用结果）
+    #[serde(default = "default_true")]
+    pub run_before_merge: bool,
+    /// 存在 >= block_severity 的问题时阻止 merge
+    #[serde(default = "default_true")]
+    pub block: bool,
+    #[serde(default = "default_block_severity")]
+    pub block_severity: Severity,
+    /// 存在 >= warn_severity 的问题时给出警告
+    #[serde(default = "default_warn_severity")]
+    pub warn_severity: Severity,
+    /// 单个扫描命令的超时时间（秒）
+    #[serde(default = "default_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_block_severity() -> Severity {
+    Severity::High
+}
+
+fn default_warn_severity() -> Severity {
+    Severity::Low
+}
+
+fn default_timeout() -> u64 {
+    300
+}
+
+impl Default for ScanSettings {
+    fn default() -> Self {
+        Self {
+            scanners: Vec::new(),
+            run_before_merge: default_true(),
+            block: default_true(),
+            block_severity: default_block_severity(),
+            warn_severity: default_warn_severity(),
+            timeout_secs: default_timeout(),
+        }
+    }
+}
+
+impl ScanSettings {
+    pub fn is_configured(&self) -> bool {
+        self.scanners.iter().any(|s| !s.command.trim().is_empty())
+    }
+}
+
+const SETTINGS_FILE: &str = "security_scan.toml";
+
+/// 读取扫描设置（文件不存在返回默认值，即不扫描；文件写错时返回错误）
+pub fn load_settings(project: &str) -> Result<ScanSettings> {
+    project_settings::try_load(project, SETTINGS_FILE)
+}
+
+/// 保存扫描设置
+pub fn save_settings(project: &str, settings: &ScanSettings) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, settings)
+}
+
+/// 扫描出的一个问题（某个依赖包命中某条 advisory）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScanFinding {
+    /// 产出该问题的扫描命令名
+    pub scanner: String,
+    /// advisory ID，如 RUSTSEC-2023-0001 / GHSA-xxxx
+    pub id: String,
+    pub package: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    pub severity: Severity,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// 一次扫描的结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScanReport {
+    /// 扫描时间（RFC 3339）
+    pub scanned_at: String,
+    /// 扫描时的任务 HEAD commit
+    #[serde(default)]
+    pub head_commit: String,
+    /// 按严重级别从高到低排序
+    #[serde(default)]
+    pub findings: Vec<ScanFinding>,
+    /// 运行失败或输出无法解析的扫描命令
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+impl ScanReport {
+    /// 严重级别 >= severity 的问题
+    pub fn at_least(&self, severity: Severity) -> impl Iterator<Item = &ScanFinding> {
+        self.findings.iter().filter(move |f| f.severity >= severity)
+    }
+
+    /// 警告摘要，如 `3 security findings (1 high, 2 low): RUSTSEC-2023-0001 in time, …`；
+    /// 没有 >= warn 的问题且没有失败的扫描时返回 None
+    pub fn warning(&self, warn: Severity) -> Option<String> {
+        let findings: Vec<&ScanFinding> = self.at_least(warn).collect();
+        let mut parts = Vec::new();
+        if !findings.is_empty() {
+            let mut counts: Vec<(Severity, usize)> = Vec::new();
+            for finding in &findings {
+                match counts.iter_mut().find(|(s, _)| *s == finding.severity) {
+                    Some((_, n)) => *n += 1,
+                    None => counts.push((finding.severity, 1)),
+                }
+            }
+            let counts: Vec<String> = counts
+                .iter()
+                .map(|(s, n)| format!("{} {}", n, s.as_str()))
+                .collect();
+            let mut listed: Vec<String> = findings
+                .iter()
+                .take(3)
+                .map(|f| format!("{} in {}", f.id, f.package))
+                .collect();
+            if findings.len() > 3 {
+                listed.push("…".to_string());
+            }
+            parts.push(format!(
+                "{} security finding{} ({}): {}",
+                findings.len(),
+                if findings.len() == 1 { "" } else { "s" },
+                counts.join(", "),
+                listed.join(", ")
+            ));
+        }
+        if !self.errors.is_empty() {
+            parts.push(format!(
+                "{} security scanner{} failed",
+                self.errors.len(),
+                if self.errors.len() == 1 { "" } else { "s" }
+            ));
+        }
+        (!parts.is_empty()).then(|| parts.join("; "))
+    }
+}
+
+/// 读取任务最近一次扫描结果
+pub fn load_report(project: &str, task_id: &str) -> Option<ScanReport> {
+    ensure_task_data_dir(project, task_id)
+        .ok()
+        .and_then(|dir| std::fs::read_to_string(dir.join(RESULT_FILE)).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+}
+
+/// 保存扫描结果（覆盖旧结果）
+pub fn save_report(project: &str, task_id: &str, report: &ScanReport) -> Result<()> {
+    let path = ensure_task_data_dir(project, task_id)?.join(RESULT_FILE);
+    let content =
+        serde_json::to_string_pretty(report).map_err(|e| GroveError::storage(e.to_string()))?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
@@ -0,0 +1,266 @@
+//! 任务的公开只读分享链接
+//!
+//! 每个链接对应一个任务，持有者可查看该任务的 diff、commits 与 review 讨论。
+//! 可选密码以 PBKDF2-HMAC-SHA256（加盐、多轮）保存，明文不落盘；旧链接的
+//! 单轮加盐 SHA-256 仍可校验。
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rusqlite::{params, OptionalExtension};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+use super::tasks::parse_dt;
+use crate::error::Result;
+
+/// 一条分享链接
+#[derive(Debug, Clone)]
+pub struct ShareLink {
+    pub token: String,
+    pub project: String,
+    pub task_id: String,
+    /// `pbkdf2-sha256$轮数$salt$hex`（旧格式 `salt$sha256hex`），None 表示无密码
+    pub password_hash: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl ShareLink {
+    pub fn has_password(&self) -> bool {
+        self.password_hash.is_some()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|t| t <= Utc::now())
+    }
+
+    /// 校验访问密码；无密码的链接始终通过
+    pub fn verify_password(&self, password: Option<&str>) -> bool {
+        let Some(stored) = &self.password_hash else {
+            return true;
+        };
+        let password = password.unwrap_or("");
+        let computed = match stored.strip_prefix(PBKDF2_PREFIX) {
+            Some(rest) => {
+                let mut parts = rest.splitn(3, '$');
+                let (Some(rounds), Some(salt)) =
+                    (parts.next().and_then(|r| r.parse().ok()), parts.next())
+                else {
+                    return false;
+                };
+                hash_password_with(rounds, salt, password)
+            }
+            None => {
+                let Some((salt, _)) = stored.split_once('$') else {
+                    return false;
+                };
+                legacy_hash_password(salt, password)
+            }
+        };
+        // 定长比较，避免按字节提前返回泄露时序
+        computed.as_bytes().ct_eq(stored.as_bytes()).into()
+    }
+}
+
+const PBKDF2_PREFIX: &str = "pbkdf2-sha256$";
+
+/// PBKDF2 轮数：单次校验在 release 下约百毫秒，离线暴力破解成本随之放大
+/// （轮数随哈希保存，测试用较少轮数）
+#[cfg(not(test))]
+const PBKDF2_ROUNDS: u32 = 210_000;
+#[cfg(test)]
+const PBKDF2_ROUNDS: u32 = 1_000;
+
+/// PBKDF2-HMAC-SHA256，输出一个 32 字节块
+fn pbkdf2_sha256(password: &[u8], salt: &[u8], rounds: u32) -> [u8; 32] {
+    let prf = Hmac::<Sha256>::new_from_slice(password).expect("HMAC accepts any key length");
+    let mut mac = prf.clone();
+    mac.update(salt);
+    mac.update(&1u32.to_be_bytes());
+    let mut u = mac.finalize().into_bytes();
+    let mut out: [u8; 32] = u.into();
+    for _ in 1..rounds {
+        let mut mac = prf.clone();
+        mac.update(&u);
+        u = mac.finalize().into_bytes();
+        for (o, b) in out.iter_mut().zip(u.iter()) {
+            *o ^= b;
+        }
+    }
+    out
+}
+
+fn hash_password_with(rounds: u32, salt: &str, password: &str) -> String {
+    let key = pbkdf2_sha256(password.as_bytes(), salt.as_bytes(), rounds);
+    format!("{PBKDF2_PREFIX}{rounds}${salt}${}", hex::encode(key))
+}
+
+fn hash_password(salt: &str, password: &str) -> String {
+    hash_password_with(PBKDF2_ROUNDS, salt, password)
+}
+
+/// 旧格式：单轮加盐 SHA-256
+fn legacy_hash_password(salt: &str, password: &str) -> String {
+    let mut h = Sha256::new();
+    h.update(salt.as_bytes());
+    h.update(password.as_bytes());
+    format!("{salt}${}", hex::encode(h.finalize()))
+}
+
+/// 随机 token（两个 v4 UUID，共 244 bit 随机）
+fn new_token() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+fn row_to_link(row: &rusqlite::Row) -> rusqlite::Result<ShareLink> {
+    Ok(ShareLink {
+        token: row.get(0)?,
+        project: row.get(1)?,
+        task_id: row.get(2)?,
+        password_hash: row.get(3)?,
+        created_at: parse_dt(&row.get::<_, String>(4)?),
+        expires_at: row.get::<_, Option<String>>(5)?.map(|s| parse_dt(&s)),
+    })
+}
+
+/// 创建分享链接
+pub fn create_share(
+    project: &str,
+    task_id: &str,
+    password: Option<&str>,
+    expires_at: Option<DateTime<Utc>>,
+) -> Result<ShareLink> {
+    let link = ShareLink {
+        token: new_token(),
+        project: project.to_string(),
+        task_id: task_id.to_string(),
+        password_hash: password
+            .filter(|p| !p.is_empty())
+            .map(|p| hash_password(&uuid::Uuid::new_v4().simple().to_string(), p)),
+        created_at: Utc::now(),
+        expires_at,
+    };
+    let conn = crate::storage::database::connection();
+    conn.execute(
+        "INSERT INTO share_links (token, project, task_id, password_hash, created_at, expires_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            link.token,
+            link.project,
+            link.task_id,
+            link.password_hash,
+            link.created_at.to_rfc3339(),
+            link.expires_at.map(|t| t.to_rfc3339())
+        ],
+    )?;
+    Ok(link)
+}
+
+/// 按 token 查找链接（含已过期的）
+pub fn get_share(token: &str) -> Result<Option<ShareLink>> {
+    let conn = crate::storage::database::connection();
+    Ok(conn
+        .query_row(
+            "SELECT token, project, task_id, password_hash, created_at, expires_at
+             FROM share_links WHERE token = ?1",
+            params![token],
+            row_to_link,
+        )
+        .optional()?)
+}
+
+/// 任务的所有分享链接（新的在前）
+pub fn list_shares(project: &str, task_id: &str) -> Result<Vec<ShareLink>> {
+    let conn = crate::storage::database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT token, project, task_id, password_hash, created_at, expires_at
+         FROM share_links WHERE project = ?1 AND task_id = ?2 ORDER BY created_at DESC",
+    )?;
+    let rows = stmt.query_map(params![project, task_id], row_to_link)?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// 撤销链接，返回是否存在
+pub fn delete_share(project: &str, task_id: &str, token: &str) -> Result<bool> {
+    let conn = crate::storage::database::connection();
+    let n = conn.execute(
+        "DELETE FROM share_links WHERE project = ?1 AND task_id = ?2 AND token = ?3",
+        params![project, task_id, token],
+    )?;
+    Ok(n > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pbkdf2_sha256_vector() {
+        // RFC 7914 §11, first block of PBKDF2-HMAC-SHA256("passwd", "salt", 1)
+        assert_eq!(
+            hex::encode(pbkdf2_sha256(b"passwd", b"salt", 1)),
+            "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc"
+        );
+    }
+
+    #[test]
+    fn test_legacy_hash_still_verifies() {
+        let link = ShareLink {
+            token: "t".into(),
+            project: "p".into(),
+            task_id: "t1".into(),
+            password_hash: Some(legacy_hash_password("salt", "hunter2")),
+            created_at: Utc::now(),
+            expires_at: None,
+        };
+        assert!(link.verify_password(Some("hunter2")));
+        assert!(!link.verify_password(Some("hunter3")));
+    }
+
+    #[test]
+    fn test_share_link_lifecycle() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        let open = create_share("p", "t1", None, None).unwrap();
+        let locked = create_share("p", "t1", Some("hunter2"), None).unwrap();
+        assert_eq!(open.token.len(), 64);
+        assert_ne!(open.token, locked.token);
+
+        let open = get_share(&open.token).unwrap().unwrap();
+        assert!(!open.has_password());
+        assert!(open.verify_password(None));
+
+        let locked = get_share(&locked.token).unwrap().unwrap();
+        assert!(locked.verify_password(Some("hunter2")));
+        assert!(!locked.verify_password(Some("hunter3")));
+        assert!(!locked.verify_password(None));
+        assert!(!locked.password_hash.as_deref().unwrap().contains("hunter2"));
+        assert!(locked
+            .password_hash
+            .as_deref()
+            .unwrap()
+            .starts_with(PBKDF2_PREFIX));
+
+        let expired = create_share(
+            "p",
+            "t1",
+            None,
+            Some(Utc::now() - chrono::Duration::hours(1)),
+        )
+        .unwrap();
+        assert!(expired.is_expired());
+        assert_eq!(list_shares("p", "t1").unwrap().len(), 3);
+
+        assert!(!delete_share("p", "other", &open.token).unwrap());
+        assert!(delete_share("p", "t1", &open.token).unwrap());
+        assert!(get_share(&open.token).unwrap().is_none());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+}
@@ -85,7 +85,7 @@ fn load_index(project: &str, task_id: &str, sketch_id: &str) -> Result<Checkpoin
             // A corrupt index would silently lose LRU bookkeeping and leak
             // orphaned `cp-*.json` files forever. Log and rebuild from the
             // directory listing so the cap keeps working.
-            eprintln!("[sketch-checkpoints] index is corrupt ({e}); rebuilding from directory");
+            tracing::warn!("index is corrupt ({e}); rebuilding from directory");
             Ok(rebuild_index_from_dir(project, task_id, sketch_id).unwrap_or_default())
         }
     }
@@ -293,6 +293,8 @@ mod tests {
                 code_deletions: 0,
                 files_changed: 0,
                 is_local: false,
+                assignee: None,
+                pinned: false,
             };
             crate::storage::tasks::add_task(&project, task).unwrap();
             let sketch_id = format!("sketch-{}", Uuid::new_v4());
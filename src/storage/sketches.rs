@@ -917,6 +917,8 @@ mod tests {
                 code_deletions: 0,
                 files_changed: 0,
                 is_local: false,
+                assignee: None,
+                pinned: false,
             };
             crate::storage::tasks::add_task(&project, task).unwrap();
             Self {
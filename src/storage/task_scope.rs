@@ -0,0 +1,105 @@
+//! Task 路径范围: ~/.grove/projects/{project}/tasks/{task_id}/scope.toml
+//!
+//! monorepo 中的任务可以声明一个子目录（如 `services/api`）；diff、文件列表、
+//! review 和 watcher 默认只看这个目录，需要时仍可查看整个仓库。
+
+use serde::{Deserialize, Serialize};
+
+use super::{ensure_task_data_dir, load_toml, save_toml};
+use crate::error::{GroveError, Result};
+
+const FILE_NAME: &str = "scope.toml";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ScopeFile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+}
+
+/// 规范化用户输入的范围：相对 worktree 根目录、`/` 分隔、无首尾斜杠。
+/// 空字符串或 `.` 表示不限制（None）；拒绝绝对路径和 `..`。
+pub fn normalize_scope(raw: &str) -> Result<Option<String>> {
+    let raw = raw.trim().replace('\\', "/");
+    if raw.starts_with('/') || raw.chars().nth(1) == Some(':') {
+        return Err(GroveError::invalid_data(
+            "Scope must be relative to the repository root",
+        ));
+    }
+    let mut parts = Vec::new();
+    for part in raw.split('/') {
+        match part {
+            "" | "." => continue,
+            ".." => return Err(GroveError::invalid_data("Scope must not contain '..'")),
+            _ => parts.push(part),
+        }
+    }
+    Ok((!parts.is_empty()).then(|| parts.join("/")))
+}
+
+/// `path`（相对 worktree，`/` 分隔）是否在范围内
+pub fn in_scope(path: &str, scope: &str) -> bool {
+    path.strip_prefix(scope)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// 读取任务的路径范围（未设置返回 None）
+pub fn load_scope(project: &str, task_id: &str) -> Option<String> {
+    ensure_task_data_dir(project, task_id)
+        .map(|dir| dir.join(FILE_NAME))
+        .ok()
+        .filter(|path| path.exists())
+        .and_then(|path| load_toml::<ScopeFile>(&path).ok())
+        .and_then(|file| file.path)
+}
+
+/// 保存任务的路径范围（None 清除）
+pub fn save_scope(project: &str, task_id: &str, scope: Option<&str>) -> Result<()> {
+    let path = ensure_task_data_dir(project, task_id)?.join(FILE_NAME);
+    match scope {
+        Some(scope) => save_toml(
+            &path,
+            &ScopeFile {
+                path: Some(scope.to_string()),
+            },
+        ),
+        None => {
+            if path.exists() {
+                std::fs::remove_file(&path)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_scope() {
+        assert_eq!(
+            normalize_scope(" services/api/ ").unwrap().as_deref(),
+            Some("services/api")
+        );
+        assert_eq!(
+            normalize_scope("./libs//core").unwrap().as_deref(),
+            Some("libs/core")
+        );
+        assert_eq!(
+            normalize_scope("services\\web").unwrap().as_deref(),
+            Some("services/web")
+        );
+        assert_eq!(normalize_scope("").unwrap(), None);
+        assert_eq!(normalize_scope(".").unwrap(), None);
+        assert!(normalize_scope("/etc").is_err());
+        assert!(normalize_scope("services/../..").is_err());
+    }
+
+    #[test]
+    fn test_in_scope() {
+        assert!(in_scope("services/api/main.rs", "services/api"));
+        assert!(in_scope("services/api", "services/api"));
+        assert!(!in_scope("services/api-gateway/main.rs", "services/api"));
+        assert!(!in_scope("README.md", "services/api"));
+    }
+}
@@ -0,0 +1,184 @@
+//! 项目级自动任务总结设置: ~/.grove/projects/{project}/task_summary.toml
+//!
+//! 开启后，chat turn 以指定的 stop reason 结束、或 task merge 时，生成一段
+//! "What was done" 总结追加到 task notes。总结块用 HTML 注释标记包裹（Markdown
+//! 渲染时不可见），merge commit 是否带上由 `include_in_merge_commit` 控制。
+
+use serde::{Deserialize, Serialize};
+
+use super::project_settings;
+use crate::error::Result;
+
+/// 总结块起止标记
+pub const SUMMARY_START: &str = "<!-- grove:summary -->";
+pub const SUMMARY_END: &str = "<!-- /grove:summary -->";
+
+/// 项目级自动总结设置
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SummarySettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// 触发总结的 turn stop reason（snake_case，如 `end_turn` / `max_tokens`），空表示 turn 结束时不触发
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop_reasons: Vec<String>,
+    /// merge 前为整个 task 生成总结
+    #[serde(default)]
+    pub on_merge: bool,
+    /// 生成总结的 AI provider（id 或名称）；空表示使用模板
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub provider: String,
+    /// merge commit body 是否保留 notes 里的总结块
+    #[serde(default = "default_true")]
+    pub include_in_merge_commit: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for SummarySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            stop_reasons: Vec::new(),
+            on_merge: false,
+            provider: String::new(),
+            include_in_merge_commit: true,
+        }
+    }
+}
+
+impl SummarySettings {
+    /// 规范化 stop reason、去重，去掉 provider 首尾空白
+    pub fn normalized(self) -> Self {
+        let mut reasons: Vec<String> = Vec::new();
+        for reason in &self.stop_reasons {
+            let reason = normalize_stop_reason(reason);
+            if !reason.is_empty() && !reasons.contains(&reason) {
+                reasons.push(reason);
+            }
+        }
+        Self {
+            stop_reasons: reasons,
+            provider: self.provider.trim().to_string(),
+            ..self
+        }
+    }
+
+    /// turn 以 `stop_reason` 结束时是否生成总结
+    pub fn triggers_on_turn(&self, stop_reason: &str) -> bool {
+        let reason = normalize_stop_reason(stop_reason);
+        self.enabled && self.stop_reasons.contains(&reason)
+    }
+
+    /// merge 前是否生成总结
+    pub fn triggers_on_merge(&self) -> bool {
+        self.enabled && self.on_merge
+    }
+}
+
+/// stop reason 统一为 snake_case（ACP 的 Debug 输出是 `EndTurn` 这种形式）
+pub fn normalize_stop_reason(reason: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in reason.trim().chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else if c == '-' || c == ' ' {
+            out.push('_');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// 用标记包裹总结正文
+pub fn wrap_summary(body: &str) -> String {
+    format!("{}\n{}\n{}", SUMMARY_START, body.trim(), SUMMARY_END)
+}
+
+/// 去掉 notes 里所有总结块（未闭合的块保留原样）
+pub fn strip_summaries(notes: &str) -> String {
+    let mut out = String::new();
+    let mut rest = notes;
+    while let Some(start) = rest.find(SUMMARY_START) {
+        let Some(len) = rest[start..].find(SUMMARY_END) else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        rest = &rest[start + len + SUMMARY_END.len()..];
+    }
+    out.push_str(rest);
+    // 去掉删除总结块后留下的多余空行
+    let mut cleaned = String::new();
+    let mut blank_run = 0;
+    for line in out.trim().lines() {
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+        } else {
+            blank_run = 0;
+        }
+        cleaned.push_str(line);
+        cleaned.push('\n');
+    }
+    cleaned.trim_end().to_string()
+}
+
+const SETTINGS_FILE: &str = "task_summary.toml";
+
+/// 读取项目的自动总结设置（文件不存在返回默认值）
+pub fn load_settings(project: &str) -> SummarySettings {
+    project_settings::load(project, SETTINGS_FILE)
+}
+
+/// 保存项目的自动总结设置
+pub fn save_settings(project: &str, settings: &SummarySettings) -> Result<()> {
+    project_settings::save(project, SETTINGS_FILE, settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stop_reasons_are_normalized() {
+        assert_eq!(normalize_stop_reason("EndTurn"), "end_turn");
+        assert_eq!(normalize_stop_reason("max_tokens"), "max_tokens");
+        assert_eq!(
+            normalize_stop_reason(" Max-Turn-Requests "),
+            "max_turn_requests"
+        );
+
+        let settings = SummarySettings {
+            enabled: true,
+            stop_reasons: vec!["EndTurn".into(), "end_turn".into(), " ".into()],
+            ..Default::default()
+        }
+        .normalized();
+        assert_eq!(settings.stop_reasons, vec!["end_turn".to_string()]);
+        assert!(settings.triggers_on_turn("EndTurn"));
+        assert!(!settings.triggers_on_turn("Cancelled"));
+        assert!(!settings.triggers_on_merge());
+    }
+
+    #[test]
+    fn strip_summaries_keeps_user_notes() {
+        let notes = format!(
+            "Plan\n\n{}\n\nMore\n\n{}\n\n{}\ndangling",
+            wrap_summary("### What was done\n- a"),
+            wrap_summary("- b"),
+            SUMMARY_START
+        );
+        assert_eq!(
+            strip_summaries(&notes),
+            format!("Plan\n\nMore\n\n{}\ndangling", SUMMARY_START)
+        );
+        assert_eq!(strip_summaries("just notes\n"), "just notes");
+    }
+}
@@ -0,0 +1,197 @@
+//! 任务列表的排序方式与保存的视图（按项目）
+//!
+//! 视图 = 命名的过滤条件 + 排序方式，TUI 与 Web 共用；过滤与排序的具体
+//! 应用见 `model::views`。
+
+use chrono::Utc;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GroveError, Result};
+
+/// 任务列表排序方式（置顶任务始终在前）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskSort {
+    /// 最近活动在前
+    #[default]
+    Activity,
+    /// diff 行数（增 + 删）多的在前
+    DiffSize,
+    /// 按状态：需要处理的（冲突 / 损坏）在前，已合并在后
+    Status,
+}
+
+impl TaskSort {
+    pub const ALL: [TaskSort; 3] = [TaskSort::Activity, TaskSort::DiffSize, TaskSort::Status];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskSort::Activity => "activity",
+            TaskSort::DiffSize => "diff_size",
+            TaskSort::Status => "status",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TaskSort::Activity => "Last activity",
+            TaskSort::DiffSize => "Diff size",
+            TaskSort::Status => "Status",
+        }
+    }
+
+    /// 下一个排序方式（循环）
+    pub fn next(&self) -> Self {
+        let i = Self::ALL.iter().position(|s| s == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+}
+
+impl std::str::FromStr for TaskSort {
+    type Err = GroveError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|sort| sort.as_str() == s)
+            .ok_or_else(|| GroveError::invalid_data(format!("unknown sort '{s}'")))
+    }
+}
+
+/// 视图的过滤条件；空字段表示不限制
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskViewFilter {
+    /// 任务状态："live" | "idle" | "merged" | "conflict" | "broken"，任一匹配即可
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<String>,
+    /// 终端 agent 状态："busy" | "waiting" | "idle"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_state: Option<String>,
+    /// 负责人："human" | "agent"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<String>,
+    /// 任务名 / 分支名包含的关键词（不区分大小写）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// 只显示置顶任务
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub pinned_only: bool,
+}
+
+/// 保存的视图
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskView {
+    pub name: String,
+    #[serde(default)]
+    pub sort: TaskSort,
+    #[serde(default)]
+    pub filter: TaskViewFilter,
+}
+
+/// 加载项目的所有视图（按创建顺序）
+pub fn load_views(project: &str) -> Result<Vec<TaskView>> {
+    let conn = crate::storage::database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT name, sort, filter FROM task_views WHERE project = ?1 ORDER BY created_at, name",
+    )?;
+    let rows = stmt.query_map(params![project], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, String>(1)?,
+            row.get::<_, String>(2)?,
+        ))
+    })?;
+    let mut views = Vec::new();
+    for row in rows {
+        let (name, sort, filter) = row?;
+        views.push(TaskView {
+            name,
+            sort: sort.parse().unwrap_or_default(),
+            filter: serde_json::from_str(&filter).unwrap_or_default(),
+        });
+    }
+    Ok(views)
+}
+
+/// 保存视图（同名覆盖，保留原创建顺序）
+pub fn save_view(project: &str, view: &TaskView) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    conn.execute(
+        "INSERT INTO task_views (project, name, sort, filter, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)
+         ON CONFLICT(project, name) DO UPDATE SET sort = excluded.sort, filter = excluded.filter",
+        params![
+            project,
+            view.name,
+            view.sort.as_str(),
+            serde_json::to_string(&view.filter)?,
+            Utc::now().to_rfc3339()
+        ],
+    )?;
+    Ok(())
+}
+
+/// 删除视图，返回是否存在
+pub fn delete_view(project: &str, name: &str) -> Result<bool> {
+    let conn = crate::storage::database::connection();
+    let n = conn.execute(
+        "DELETE FROM task_views WHERE project = ?1 AND name = ?2",
+        params![project, name],
+    )?;
+    Ok(n > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_views_roundtrip() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        let review = TaskView {
+            name: "Needs review".to_string(),
+            sort: TaskSort::DiffSize,
+            filter: TaskViewFilter {
+                assignee: Some("human".to_string()),
+                ..Default::default()
+            },
+        };
+        let busy = TaskView {
+            name: "Agent busy".to_string(),
+            sort: TaskSort::Activity,
+            filter: TaskViewFilter {
+                agent_state: Some("busy".to_string()),
+                ..Default::default()
+            },
+        };
+        save_view("p", &review).unwrap();
+        save_view("p", &busy).unwrap();
+
+        let updated = TaskView {
+            sort: TaskSort::Status,
+            ..review.clone()
+        };
+        save_view("p", &updated).unwrap();
+
+        assert_eq!(load_views("p").unwrap(), vec![updated, busy]);
+        assert!(load_views("other").unwrap().is_empty());
+
+        assert!(delete_view("p", "Agent busy").unwrap());
+        assert!(!delete_view("p", "Agent busy").unwrap());
+        assert_eq!(load_views("p").unwrap().len(), 1);
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_task_sort_cycle_and_parse() {
+        assert_eq!(TaskSort::Activity.next(), TaskSort::DiffSize);
+        assert_eq!(TaskSort::Status.next(), TaskSort::Activity);
+        assert_eq!("diff_size".parse::<TaskSort>().unwrap(), TaskSort::DiffSize);
+        assert!("due_date".parse::<TaskSort>().is_err());
+    }
+}
@@ -5,6 +5,7 @@ use rusqlite::{params, OptionalExtension};
 use serde::{Deserialize, Serialize};
 
 use crate::error::{GroveError, Result};
+use crate::storage::config::LinkMode;
 
 /// Local Task 的固定 ID
 pub const LOCAL_TASK_ID: &str = "_local";
@@ -33,6 +34,66 @@ pub struct ChatSession {
     /// 创建时由全局 config.agent_launch_modes 快照决定,之后不可改。
     #[serde(default = "default_launch_mode")]
     pub launch_mode: String,
+    /// agent 工作目录相对 worktree 的子目录（如 `frontend`），None 表示 worktree 根目录。
+    /// 创建时设定，用于让 agent 专注 monorepo 中的某个 package。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub work_subdir: Option<String>,
+}
+
+impl ChatSession {
+    /// agent 的工作目录：worktree 根目录或其下的 `work_subdir`
+    pub fn working_dir(&self, worktree: &str) -> PathBuf {
+        let root = PathBuf::from(worktree);
+        match self.work_subdir.as_deref() {
+            Some(subdir) => root.join(subdir),
+            None => root,
+        }
+    }
+}
+
+/// 校验并规范化 chat 的工作子目录（相对 worktree）
+///
+/// 空串 / `.` 返回 None；拒绝绝对路径和 `..`，目录必须存在且（解析符号链接后）
+/// 仍位于 worktree 内。
+pub fn normalize_work_subdir(worktree: &str, subdir: &str) -> Result<Option<String>> {
+    use std::path::{Component, Path};
+
+    let mut parts: Vec<String> = Vec::new();
+    for component in Path::new(subdir.trim()).components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::CurDir => {}
+            _ => {
+                return Err(GroveError::invalid_data(format!(
+                    "Working directory must be a subdirectory of the worktree: {}",
+                    subdir
+                )))
+            }
+        }
+    }
+    if parts.is_empty() {
+        return Ok(None);
+    }
+    let rel = parts.join("/");
+    let root = Path::new(worktree);
+    let dir = root.join(&rel);
+    if !dir.is_dir() {
+        return Err(GroveError::invalid_data(format!(
+            "Directory does not exist in the worktree: {}",
+            rel
+        )));
+    }
+    let inside = match (dir.canonicalize(), root.canonicalize()) {
+        (Ok(dir), Ok(root)) => dir.starts_with(root),
+        _ => false,
+    };
+    if !inside {
+        return Err(GroveError::invalid_data(format!(
+            "Working directory escapes the worktree: {}",
+            rel
+        )));
+    }
+    Ok(Some(rel))
 }
 
 fn default_launch_mode() -> String {
@@ -47,6 +108,35 @@ pub enum TaskStatus {
     Archived,
 }
 
+/// 任务当前由谁推进（handback 工作流）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum TaskAssignee {
+    /// 等待人类处理
+    Human,
+    /// 交给某个 agent chat
+    Agent { chat_id: String },
+}
+
+impl TaskAssignee {
+    /// 数据库存储格式："human" / "agent:<chat_id>"
+    fn to_db(&self) -> String {
+        match self {
+            TaskAssignee::Human => "human".to_string(),
+            TaskAssignee::Agent { chat_id } => format!("agent:{}", chat_id),
+        }
+    }
+
+    fn from_db(s: &str) -> Option<Self> {
+        match s {
+            "human" => Some(TaskAssignee::Human),
+            _ => s.strip_prefix("agent:").map(|chat_id| TaskAssignee::Agent {
+                chat_id: chat_id.to_string(),
+            }),
+        }
+    }
+}
+
 /// 任务数据
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
@@ -79,6 +169,12 @@ pub struct Task {
     pub files_changed: u32,
     #[serde(default)]
     pub is_local: bool,
+    /// 当前负责人；None 表示未指定
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<TaskAssignee>,
+    /// 置顶（列表中排在最前）
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 fn default_multiplexer() -> String {
@@ -89,7 +185,7 @@ fn default_updated_at() -> DateTime<Utc> {
     Utc::now()
 }
 
-fn parse_dt(s: &str) -> DateTime<Utc> {
+pub(crate) fn parse_dt(s: &str) -> DateTime<Utc> {
     DateTime::parse_from_rfc3339(s)
         .map(|dt| dt.with_timezone(&Utc))
         .unwrap_or_else(|_| Utc::now())
@@ -105,6 +201,8 @@ fn row_to_task(row: &rusqlite::Row<'_>) -> rusqlite::Result<Task> {
     let code_additions: i64 = row.get(15)?;
     let code_deletions: i64 = row.get(16)?;
     let files_changed: i64 = row.get(17)?;
+    let assignee: Option<String> = row.get(18)?;
+    let pinned: i64 = row.get(19)?;
 
     Ok(Task {
         id: row.get(1)?,
@@ -127,16 +225,18 @@ fn row_to_task(row: &rusqlite::Row<'_>) -> rusqlite::Result<Task> {
         code_additions: code_additions as u32,
         code_deletions: code_deletions as u32,
         files_changed: files_changed as u32,
+        assignee: assignee.as_deref().and_then(TaskAssignee::from_db),
+        pinned: pinned != 0,
     })
 }
 
-const TASK_COLUMNS: &str = "project, id, name, branch, target, worktree_path, initial_commit, created_at, updated_at, status, multiplexer, session_name, created_by, archived_at, is_local, code_additions, code_deletions, files_changed";
+const TASK_COLUMNS: &str = "project, id, name, branch, target, worktree_path, initial_commit, created_at, updated_at, status, multiplexer, session_name, created_by, archived_at, is_local, code_additions, code_deletions, files_changed, assignee, pinned";
 
 /// 加载活跃任务列表
 pub fn load_tasks(project: &str) -> Result<Vec<Task>> {
     let conn = crate::storage::database::connection();
     let mut stmt = conn.prepare(&format!(
-        "SELECT {} FROM tasks WHERE project = ?1 AND status = 'active' ORDER BY updated_at DESC",
+        "SELECT {} FROM tasks WHERE project = ?1 AND status = 'active' ORDER BY pinned DESC, updated_at DESC",
         TASK_COLUMNS
     ))?;
     let tasks = stmt
@@ -149,7 +249,7 @@ pub fn load_tasks(project: &str) -> Result<Vec<Task>> {
 pub fn load_archived_tasks(project: &str) -> Result<Vec<Task>> {
     let conn = crate::storage::database::connection();
     let mut stmt = conn.prepare(&format!(
-        "SELECT {} FROM tasks WHERE project = ?1 AND status = 'archived' ORDER BY updated_at DESC",
+        "SELECT {} FROM tasks WHERE project = ?1 AND status = 'archived' ORDER BY pinned DESC, updated_at DESC",
         TASK_COLUMNS
     ))?;
     let tasks = stmt
@@ -162,7 +262,7 @@ pub fn load_archived_tasks(project: &str) -> Result<Vec<Task>> {
 pub fn add_task(project: &str, task: Task) -> Result<()> {
     let conn = crate::storage::database::connection();
     conn.execute(
-        &format!("INSERT INTO tasks ({}) VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13,?14,?15,?16,?17,?18)", TASK_COLUMNS),
+        &format!("INSERT INTO tasks ({}) VALUES (?1,?2,?3,?4,?5,?6,?7,?8,?9,?10,?11,?12,?13,?14,?15,?16,?17,?18,?19,?20)", TASK_COLUMNS),
         params![
             project,
             task.id,
@@ -182,6 +282,8 @@ pub fn add_task(project: &str, task: Task) -> Result<()> {
             task.code_additions as i64,
             task.code_deletions as i64,
             task.files_changed as i64,
+            task.assignee.as_ref().map(TaskAssignee::to_db),
+            task.pinned as i64,
         ],
     )?;
     Ok(())
@@ -209,6 +311,40 @@ pub fn update_local_task(project: &str, task: &Task) -> Result<()> {
     Ok(())
 }
 
+/// 用同步来的元数据覆盖任务；worktree_path / session_name / multiplexer
+/// 属于本机，保持不变
+pub fn update_task_metadata(project: &str, task: &Task) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    conn.execute(
+        "UPDATE tasks SET name = ?1, branch = ?2, target = ?3, initial_commit = ?4,
+                created_at = ?5, updated_at = ?6, status = ?7, created_by = ?8,
+                archived_at = ?9, code_additions = ?10, code_deletions = ?11,
+                files_changed = ?12, assignee = ?13
+         WHERE project = ?14 AND id = ?15",
+        params![
+            task.name,
+            task.branch,
+            task.target,
+            task.initial_commit,
+            task.created_at.to_rfc3339(),
+            task.updated_at.to_rfc3339(),
+            match task.status {
+                TaskStatus::Active => "active",
+                TaskStatus::Archived => "archived",
+            },
+            task.created_by,
+            task.archived_at.map(|t| t.to_rfc3339()),
+            task.code_additions as i64,
+            task.code_deletions as i64,
+            task.files_changed as i64,
+            task.assignee.as_ref().map(TaskAssignee::to_db),
+            project,
+            task.id,
+        ],
+    )?;
+    Ok(())
+}
+
 /// Snapshot git diff stats onto an archived task. Worktree no longer exists
 /// after archival, so this is the only chance to record per-task line counts
 /// for stats. Restored after the v2.4 migration accidentally dropped it.
@@ -274,6 +410,18 @@ pub fn remove_task(project: &str, task_id: &str) -> Result<()> {
         "DELETE FROM tasks WHERE project = ?1 AND id = ?2",
         params![project, task_id],
     )?;
+    tx.execute(
+        "DELETE FROM task_merge_failures WHERE project = ?1 AND task_id = ?2",
+        params![project, task_id],
+    )?;
+    tx.execute(
+        "DELETE FROM task_target_history WHERE project = ?1 AND task_id = ?2",
+        params![project, task_id],
+    )?;
+    tx.execute(
+        "DELETE FROM task_autolinks WHERE project = ?1 AND task_id = ?2",
+        params![project, task_id],
+    )?;
     tx.commit()?;
     Ok(())
 }
@@ -287,6 +435,14 @@ pub fn remove_archived_task(project: &str, task_id: &str) -> Result<()> {
         "DELETE FROM tasks WHERE project = ?1 AND id = ?2 AND status = 'archived'",
         params![project, task_id],
     )?;
+    tx.execute(
+        "DELETE FROM task_target_history WHERE project = ?1 AND task_id = ?2",
+        params![project, task_id],
+    )?;
+    tx.execute(
+        "DELETE FROM task_autolinks WHERE project = ?1 AND task_id = ?2",
+        params![project, task_id],
+    )?;
     tx.commit()?;
     Ok(())
 }
@@ -306,6 +462,218 @@ pub fn update_task_name(project: &str, task_id: &str, new_name: &str) -> Result<
     Ok(())
 }
 
+/// 更新任务负责人（None 清除）
+pub fn update_task_assignee(
+    project: &str,
+    task_id: &str,
+    assignee: Option<&TaskAssignee>,
+) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    let affected = conn.execute(
+        "UPDATE tasks SET assignee = ?1, updated_at = ?2 WHERE project = ?3 AND id = ?4",
+        params![
+            assignee.map(TaskAssignee::to_db),
+            Utc::now().to_rfc3339(),
+            project,
+            task_id
+        ],
+    )?;
+    if affected == 0 {
+        return Err(GroveError::not_found(format!(
+            "task {task_id} not found in project {project}"
+        )));
+    }
+    Ok(())
+}
+
+/// 设置任务置顶（不改动 updated_at，置顶不算任务活动）
+pub fn update_task_pinned(project: &str, task_id: &str, pinned: bool) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    let affected = conn.execute(
+        "UPDATE tasks SET pinned = ?1 WHERE project = ?2 AND id = ?3",
+        params![pinned as i64, project, task_id],
+    )?;
+    if affected == 0 {
+        return Err(GroveError::not_found(format!(
+            "task {task_id} not found in project {project}"
+        )));
+    }
+    Ok(())
+}
+
+/// 一次 target branch 变更
+#[derive(Debug, Clone, Serialize)]
+pub struct TargetChange {
+    pub from_target: String,
+    pub to_target: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// 记录 target branch 变更
+pub fn record_target_change(project: &str, task_id: &str, from: &str, to: &str) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    conn.execute(
+        "INSERT INTO task_target_history (project, task_id, from_target, to_target, changed_at)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![project, task_id, from, to, Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
+}
+
+/// 加载任务的 target branch 变更历史（按时间先后）
+pub fn load_target_history(project: &str, task_id: &str) -> Result<Vec<TargetChange>> {
+    let conn = crate::storage::database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT from_target, to_target, changed_at FROM task_target_history
+         WHERE project = ?1 AND task_id = ?2 ORDER BY id",
+    )?;
+    let rows = stmt.query_map(params![project, task_id], |row| {
+        Ok(TargetChange {
+            from_target: row.get(0)?,
+            to_target: row.get(1)?,
+            changed_at: parse_dt(&row.get::<_, String>(2)?),
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// 记录 AutoLink 在任务 worktree 中创建的链接（相对路径 + 创建方式，重复时更新方式）
+pub fn record_autolinks(project: &str, task_id: &str, links: &[(String, LinkMode)]) -> Result<()> {
+    if links.is_empty() {
+        return Ok(());
+    }
+    let conn = crate::storage::database::connection();
+    let tx = conn.unchecked_transaction()?;
+    let now = Utc::now().to_rfc3339();
+    for (path, mode) in links {
+        tx.execute(
+            "INSERT INTO task_autolinks (project, task_id, path, mode, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT (project, task_id, path) DO UPDATE SET mode = excluded.mode",
+            params![project, task_id, path, mode.as_str(), now],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// 加载任务记录过的 AutoLink 链接（按路径排序）
+pub fn load_autolinks(project: &str, task_id: &str) -> Result<Vec<(String, LinkMode)>> {
+    let conn = crate::storage::database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT path, mode FROM task_autolinks WHERE project = ?1 AND task_id = ?2 ORDER BY path",
+    )?;
+    let rows = stmt.query_map(params![project, task_id], |row| {
+        let mode: String = row.get(1)?;
+        Ok((row.get(0)?, mode.parse().unwrap_or_default()))
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// 删除一条 AutoLink 记录
+pub fn remove_autolink(project: &str, task_id: &str, path: &str) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    conn.execute(
+        "DELETE FROM task_autolinks WHERE project = ?1 AND task_id = ?2 AND path = ?3",
+        params![project, task_id, path],
+    )?;
+    Ok(())
+}
+
+/// 最近一次失败的 merge
+#[derive(Debug, Clone)]
+pub struct MergeFailure {
+    pub task_id: String,
+    pub error: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 记录 merge 失败（覆盖同一任务之前的记录）
+pub fn record_merge_failure(project: &str, task_id: &str, error: &str) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    conn.execute(
+        "INSERT OR REPLACE INTO task_merge_failures (project, task_id, error, timestamp)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![project, task_id, error, Utc::now().to_rfc3339()],
+    )?;
+    Ok(())
+}
+
+/// 清除任务的 merge 失败记录
+pub fn clear_merge_failure(project: &str, task_id: &str) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    conn.execute(
+        "DELETE FROM task_merge_failures WHERE project = ?1 AND task_id = ?2",
+        params![project, task_id],
+    )?;
+    Ok(())
+}
+
+/// 加载项目下所有 merge 失败记录
+pub fn load_merge_failures(project: &str) -> Result<Vec<MergeFailure>> {
+    let conn = crate::storage::database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT task_id, error, timestamp FROM task_merge_failures
+         WHERE project = ?1 ORDER BY timestamp DESC",
+    )?;
+    let rows = stmt.query_map(params![project], |row| {
+        Ok(MergeFailure {
+            task_id: row.get(0)?,
+            error: row.get(1)?,
+            timestamp: parse_dt(&row.get::<_, String>(2)?),
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// 任务在 GitHub 上打开的 Pull Request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequest {
+    pub number: u64,
+    pub url: String,
+    /// "open" | "closed" | "merged"
+    pub state: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 保存任务的 Pull Request（覆盖之前的记录）
+pub fn save_pull_request(project: &str, task_id: &str, pr: &PullRequest) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    conn.execute(
+        "INSERT OR REPLACE INTO task_pull_requests (project, task_id, number, url, state, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            project,
+            task_id,
+            pr.number as i64,
+            pr.url,
+            pr.state,
+            pr.created_at.to_rfc3339()
+        ],
+    )?;
+    Ok(())
+}
+
+/// 获取任务的 Pull Request
+pub fn get_pull_request(project: &str, task_id: &str) -> Result<Option<PullRequest>> {
+    let conn = crate::storage::database::connection();
+    Ok(conn
+        .query_row(
+            "SELECT number, url, state, created_at FROM task_pull_requests
+             WHERE project = ?1 AND task_id = ?2",
+            params![project, task_id],
+            |row| {
+                Ok(PullRequest {
+                    number: row.get::<_, i64>(0)? as u64,
+                    url: row.get(1)?,
+                    state: row.get(2)?,
+                    created_at: parse_dt(&row.get::<_, String>(3)?),
+                })
+            },
+        )
+        .optional()?)
+}
+
 /// 更新任务的 target branch
 pub fn update_task_target(project: &str, task_id: &str, new_target: &str) -> Result<()> {
     let conn = crate::storage::database::connection();
@@ -437,6 +805,8 @@ fn build_local_task(
         code_deletions: 0,
         files_changed: 0,
         is_local: true,
+        assignee: None,
+        pinned: false,
     }
 }
 
@@ -553,6 +923,7 @@ fn row_to_chat_session(row: &rusqlite::Row<'_>) -> rusqlite::Result<ChatSession>
         created_at,
         duty: row.get(5)?,
         launch_mode: row.get(6)?,
+        work_subdir: row.get(7)?,
     })
 }
 
@@ -560,7 +931,7 @@ fn row_to_chat_session(row: &rusqlite::Row<'_>) -> rusqlite::Result<ChatSession>
 pub fn load_chat_sessions(project: &str, task_id: &str) -> Result<Vec<ChatSession>> {
     let conn = crate::storage::database::connection();
     let mut stmt = conn.prepare(
-        "SELECT session_id, title, agent, acp_session_id, created_at, duty, launch_mode
+        "SELECT session_id, title, agent, acp_session_id, created_at, duty, launch_mode, work_subdir
          FROM session
          WHERE project = ?1 AND task_id = ?2
          ORDER BY created_at ASC",
@@ -588,8 +959,8 @@ pub fn add_chat_session(project: &str, task_id: &str, chat: ChatSession) -> Resu
     }
     conn.execute(
         "INSERT INTO session
-         (session_id, project, task_id, title, agent, acp_session_id, duty, created_at, launch_mode)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+         (session_id, project, task_id, title, agent, acp_session_id, duty, created_at, launch_mode, work_subdir)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
          ON CONFLICT(session_id) DO UPDATE SET
             title = excluded.title,
             agent = excluded.agent,
@@ -606,6 +977,7 @@ pub fn add_chat_session(project: &str, task_id: &str, chat: ChatSession) -> Resu
             chat.duty,
             chat.created_at.to_rfc3339(),
             chat.launch_mode,
+            chat.work_subdir,
         ],
     )?;
     Ok(())
@@ -714,7 +1086,7 @@ pub fn get_chat_session(
     let conn = crate::storage::database::connection();
     let chat = conn
         .query_row(
-            "SELECT session_id, title, agent, acp_session_id, created_at, duty, launch_mode
+            "SELECT session_id, title, agent, acp_session_id, created_at, duty, launch_mode, work_subdir
              FROM session
              WHERE project = ?1 AND task_id = ?2 AND session_id = ?3",
             params![project, task_id, chat_id],
@@ -731,7 +1103,7 @@ pub fn find_chat_session(chat_id: &str) -> Result<Option<(String, String, ChatSe
     let conn = crate::storage::database::connection();
     let row = conn
         .query_row(
-            "SELECT project, task_id, session_id, title, agent, acp_session_id, created_at, duty, launch_mode
+            "SELECT project, task_id, session_id, title, agent, acp_session_id, created_at, duty, launch_mode, work_subdir
              FROM session
              WHERE session_id = ?1",
             params![chat_id],
@@ -753,6 +1125,7 @@ pub fn find_chat_session(chat_id: &str) -> Result<Option<(String, String, ChatSe
                         created_at,
                         duty: row.get(7)?,
                         launch_mode: row.get(8)?,
+                        work_subdir: row.get(9)?,
                     },
                 ))
             },
@@ -853,6 +1226,139 @@ pub fn preview_branch_name(task_name: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_task_assignee_db_roundtrip() {
+        let agent = TaskAssignee::Agent {
+            chat_id: "chat-abc".to_string(),
+        };
+        assert_eq!(agent.to_db(), "agent:chat-abc");
+        assert_eq!(TaskAssignee::from_db("agent:chat-abc"), Some(agent));
+        assert_eq!(TaskAssignee::from_db("human"), Some(TaskAssignee::Human));
+        assert_eq!(TaskAssignee::from_db("robot"), None);
+    }
+
+    #[test]
+    fn test_merge_failure_record_and_clear() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        record_merge_failure("p", "t1", "conflict in a.rs").unwrap();
+        record_merge_failure("p", "t1", "conflict in b.rs").unwrap();
+        record_merge_failure("other", "t2", "boom").unwrap();
+
+        let failures = load_merge_failures("p").unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].task_id, "t1");
+        assert_eq!(failures[0].error, "conflict in b.rs");
+
+        clear_merge_failure("p", "t1").unwrap();
+        assert!(load_merge_failures("p").unwrap().is_empty());
+        assert_eq!(load_merge_failures("other").unwrap().len(), 1);
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_pull_request_roundtrip() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        assert_eq!(get_pull_request("p", "t1").unwrap(), None);
+        let mut pr = PullRequest {
+            number: 12,
+            url: "https://github.com/acme/app/pull/12".to_string(),
+            state: "open".to_string(),
+            created_at: parse_dt("2026-01-02T03:04:05+00:00"),
+        };
+        save_pull_request("p", "t1", &pr).unwrap();
+        pr.state = "merged".to_string();
+        save_pull_request("p", "t1", &pr).unwrap();
+        assert_eq!(get_pull_request("p", "t1").unwrap(), Some(pr));
+        assert_eq!(get_pull_request("p", "t2").unwrap(), None);
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_target_history_in_order() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        record_target_change("p", "t1", "main", "develop").unwrap();
+        record_target_change("p", "t1", "develop", "release").unwrap();
+        record_target_change("p", "t2", "main", "next").unwrap();
+
+        let history = load_target_history("p", "t1").unwrap();
+        let pairs: Vec<_> = history
+            .iter()
+            .map(|c| (c.from_target.as_str(), c.to_target.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("main", "develop"), ("develop", "release")]);
+        assert!(load_target_history("p", "t3").unwrap().is_empty());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_autolinks_record_and_remove() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        record_autolinks(
+            "p",
+            "t1",
+            &[
+                ("node_modules".into(), LinkMode::Symlink),
+                (".env".into(), LinkMode::Symlink),
+            ],
+        )
+        .unwrap();
+        record_autolinks("p", "t1", &[(".env".into(), LinkMode::Copy)]).unwrap();
+        assert_eq!(
+            load_autolinks("p", "t1").unwrap(),
+            vec![
+                (".env".to_string(), LinkMode::Copy),
+                ("node_modules".to_string(), LinkMode::Symlink)
+            ]
+        );
+
+        remove_autolink("p", "t1", ".env").unwrap();
+        assert_eq!(
+            load_autolinks("p", "t1").unwrap(),
+            vec![("node_modules".to_string(), LinkMode::Symlink)]
+        );
+        assert!(load_autolinks("p", "t2").unwrap().is_empty());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
+    #[test]
+    fn test_pinned_tasks_load_first() {
+        let _lock = crate::storage::database::test_lock().blocking_lock();
+        let temp = tempfile::tempdir().unwrap();
+        crate::storage::set_grove_dir_override(Some(temp.path().to_path_buf()));
+
+        for (i, id) in ["old", "mid", "new"].into_iter().enumerate() {
+            let mut task = build_local_task("/repo", "main", "main", id);
+            task.id = id.to_string();
+            task.is_local = false;
+            task.updated_at = Utc::now() + chrono::Duration::seconds(i as i64);
+            add_task("p", task).unwrap();
+        }
+        update_task_pinned("p", "old", true).unwrap();
+
+        let ids: Vec<_> = load_tasks("p").unwrap().into_iter().map(|t| t.id).collect();
+        assert_eq!(ids, ["old", "new", "mid"]);
+        assert!(get_task("p", "old").unwrap().unwrap().pinned);
+        assert!(update_task_pinned("p", "missing", true).is_err());
+
+        crate::storage::set_grove_dir_override(None);
+    }
+
     #[test]
     fn test_to_slug() {
         assert_eq!(to_slug("Add OAuth login"), "add-oauth-login");
@@ -975,6 +1481,7 @@ created_at = "2025-01-01T00:00:00Z"
                 .to_utc(),
             duty: None,
             launch_mode: "acp".to_string(),
+            work_subdir: None,
         };
         let toml_str = toml::to_string(&session).unwrap();
         assert!(
@@ -996,6 +1503,7 @@ created_at = "2025-01-01T00:00:00Z"
                 .to_utc(),
             duty: Some("code review".to_string()),
             launch_mode: "acp".to_string(),
+            work_subdir: None,
         };
         let toml_str = toml::to_string(&session).unwrap();
         let restored: ChatSession = toml::from_str(&toml_str).unwrap();
@@ -1005,4 +1513,33 @@ created_at = "2025-01-01T00:00:00Z"
         let toml_str2 = toml::to_string(&session).unwrap();
         assert!(!toml_str2.contains("duty"));
     }
+
+    #[test]
+    fn normalize_work_subdir_validates_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("packages/web")).unwrap();
+        let wt = dir.path().to_str().unwrap();
+
+        assert_eq!(normalize_work_subdir(wt, "").unwrap(), None);
+        assert_eq!(normalize_work_subdir(wt, " ./ ").unwrap(), None);
+        assert_eq!(
+            normalize_work_subdir(wt, "./packages/web/").unwrap(),
+            Some("packages/web".to_string())
+        );
+        assert!(normalize_work_subdir(wt, "../other").is_err());
+        assert!(normalize_work_subdir(wt, "/etc").is_err());
+        assert!(normalize_work_subdir(wt, "missing").is_err());
+
+        let chat = ChatSession {
+            id: "chat-1".to_string(),
+            title: "t".to_string(),
+            agent: "claude".to_string(),
+            acp_session_id: None,
+            created_at: Utc::now(),
+            duty: None,
+            launch_mode: "acp".to_string(),
+            work_subdir: Some("packages/web".to_string()),
+        };
+        assert_eq!(chat.working_dir(wt), dir.path().join("packages/web"));
+    }
 }
@@ -0,0 +1,233 @@
+//! 任务模板: ~/.grove/projects/{project}/templates.toml
+//!
+//! 模板预设 target 分支、notes 骨架、默认 agent、环境变量和额外的 AutoLink
+//! 模式；从模板创建任务时写入 notes，并把 agent / 环境变量记录到
+//! ~/.grove/projects/{project}/tasks/{task_id}/template.toml，供之后创建的
+//! 终端 session 和 chat 使用。
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::config::AutoLinkConfig;
+use super::{ensure_task_data_dir, load_toml, project_settings, save_toml};
+use crate::error::{GroveError, Result};
+
+const TASK_FILE_NAME: &str = "template.toml";
+
+/// 任务模板
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTemplate {
+    /// 模板名（项目内唯一）
+    pub name: String,
+    /// 默认 target 分支；None 表示使用项目默认分支
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// notes 骨架（支持 `{branch}` / `{task_id}` / `{target}` 变量）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+    /// 默认 agent（终端布局的 agent 命令，同时作为新 chat 的默认 agent）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
+    /// 注入任务 session / chat 的环境变量
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// 追加在全局 AutoLink 模式之后的模式
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub autolink_patterns: Vec<String>,
+}
+
+impl TaskTemplate {
+    /// 模板的 AutoLink 配置：全局配置 + 模板模式（去重）
+    pub fn auto_link(&self, base: &AutoLinkConfig) -> AutoLinkConfig {
+        let mut auto_link = base.clone();
+        for pattern in &self.autolink_patterns {
+            if !auto_link.patterns.contains(pattern) {
+                auto_link.patterns.push(pattern.clone());
+            }
+        }
+        auto_link
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TemplatesFile {
+    #[serde(default)]
+    templates: Vec<TaskTemplate>,
+}
+
+/// 从模板创建的任务记录的默认值
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskTemplateDefaults {
+    /// 来源模板名
+    pub template: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+/// 环境变量名：字母或下划线开头，只含字母、数字和下划线
+fn is_valid_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// 规范化并校验模板列表：去掉首尾空白，空字段归为 None，
+/// 名称非空且唯一，环境变量名合法且不能覆盖 `GROVE_*`
+pub fn normalize_templates(templates: Vec<TaskTemplate>) -> Result<Vec<TaskTemplate>> {
+    let mut names = std::collections::HashSet::new();
+    templates
+        .into_iter()
+        .map(|t| {
+            let name = t.name.trim().to_string();
+            if name.is_empty() {
+                return Err(GroveError::invalid_data("Template name must not be empty"));
+            }
+            if !names.insert(name.clone()) {
+                return Err(GroveError::invalid_data(format!(
+                    "Duplicate template name: {}",
+                    name
+                )));
+            }
+            let mut env = BTreeMap::new();
+            for (key, value) in t.env {
+                let key = key.trim().to_string();
+                if !is_valid_env_name(&key) || key.starts_with("GROVE_") {
+                    return Err(GroveError::invalid_data(format!(
+                        "Invalid environment variable name in template {}: {}",
+                        name, key
+                    )));
+                }
+                env.insert(key, value);
+            }
+            let non_empty =
+                |s: Option<String>| s.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+            Ok(TaskTemplate {
+                name,
+                target: non_empty(t.target),
+                notes: t.notes,
+                agent: non_empty(t.agent),
+                env,
+                autolink_patterns: t
+                    .autolink_patterns
+                    .into_iter()
+                    .map(|p| p.trim().to_string())
+                    .filter(|p| !p.is_empty())
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+const TEMPLATES_FILE: &str = "templates.toml";
+
+/// 读取项目的任务模板（文件不存在返回空列表）
+pub fn load_templates(project: &str) -> Vec<TaskTemplate> {
+    project_settings::load::<TemplatesFile>(project, TEMPLATES_FILE).templates
+}
+
+/// 保存项目的任务模板（按给定顺序）
+pub fn save_templates(project: &str, templates: &[TaskTemplate]) -> Result<()> {
+    project_settings::save(
+        project,
+        TEMPLATES_FILE,
+        &TemplatesFile {
+            templates: templates.to_vec(),
+        },
+    )
+}
+
+/// 按名称查找模板
+pub fn find_template(project: &str, name: &str) -> Option<TaskTemplate> {
+    load_templates(project).into_iter().find(|t| t.name == name)
+}
+
+/// 读取任务记录的模板默认值（不是从模板创建的返回 None）
+pub fn load_task_defaults(project: &str, task_id: &str) -> Option<TaskTemplateDefaults> {
+    ensure_task_data_dir(project, task_id)
+        .map(|dir| dir.join(TASK_FILE_NAME))
+        .ok()
+        .filter(|path| path.exists())
+        .and_then(|path| load_toml(&path).ok())
+}
+
+/// 记录任务的模板默认值
+pub fn save_task_defaults(
+    project: &str,
+    task_id: &str,
+    defaults: &TaskTemplateDefaults,
+) -> Result<()> {
+    save_toml(
+        &ensure_task_data_dir(project, task_id)?.join(TASK_FILE_NAME),
+        defaults,
+    )
+}
+
+/// 任务的模板环境变量（没有则为空）
+pub fn task_env(project: &str, task_id: &str) -> BTreeMap<String, String> {
+    load_task_defaults(project, task_id)
+        .map(|d| d.env)
+        .unwrap_or_default()
+}
+
+/// 任务的模板默认 agent
+pub fn task_agent(project: &str, task_id: &str) -> Option<String> {
+    load_task_defaults(project, task_id).and_then(|d| d.agent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_templates() {
+        let templates = normalize_templates(vec![TaskTemplate {
+            name: " bugfix ".into(),
+            target: Some("  ".into()),
+            agent: Some(" codex ".into()),
+            env: BTreeMap::from([(" RUST_LOG ".into(), "debug".into())]),
+            autolink_patterns: vec![".env".into(), " ".into()],
+            ..Default::default()
+        }])
+        .unwrap();
+        assert_eq!(templates[0].name, "bugfix");
+        assert_eq!(templates[0].target, None);
+        assert_eq!(templates[0].agent.as_deref(), Some("codex"));
+        assert_eq!(templates[0].env["RUST_LOG"], "debug");
+        assert_eq!(templates[0].autolink_patterns, vec![".env".to_string()]);
+
+        let named = |name: &str| TaskTemplate {
+            name: name.into(),
+            ..Default::default()
+        };
+        assert!(normalize_templates(vec![named("")]).is_err());
+        assert!(normalize_templates(vec![named("a"), named(" a")]).is_err());
+        for bad in ["1ABC", "A-B", "GROVE_TASK_ID"] {
+            let t = TaskTemplate {
+                env: BTreeMap::from([(bad.to_string(), String::new())]),
+                ..named("a")
+            };
+            assert!(normalize_templates(vec![t]).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_template_auto_link() {
+        let base = AutoLinkConfig {
+            patterns: vec!["node_modules".into()],
+            ..Default::default()
+        };
+        let template = TaskTemplate {
+            autolink_patterns: vec!["node_modules".into(), ".env.local".into()],
+            ..Default::default()
+        };
+        assert_eq!(
+            template.auto_link(&base).patterns,
+            vec!["node_modules".to_string(), ".env.local".to_string()]
+        );
+    }
+}
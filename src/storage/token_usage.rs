@@ -3,7 +3,16 @@
 //! One row per agent prompt response — written from
 //! `acp::handle_session_notification` at `Complete` emit time. Backed by the
 //! `chat_token_usage` table (see `database::create_schema`). Append-only;
-//! the only consumer today is the future Statistics aggregation.
+//! read back by the Statistics aggregation (`crate::stats`) and the per-chat /
+//! per-task / per-project usage endpoints.
+//!
+//! Cost is what ACP `usage_update` reports: a *cumulative* amount for the
+//! agent session, stored on every turn as of that turn. A chat's cost is
+//! therefore its highest recorded amount, never the sum of its rows.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
 
 use super::database;
 use crate::error::Result;
@@ -20,6 +29,8 @@ pub struct TokenUsageRecord<'a> {
     pub model: Option<&'a str>,
     pub input_tokens: u64,
     pub cached_read_tokens: Option<u64>,
+    pub cached_write_tokens: Option<u64>,
+    pub thought_tokens: Option<u64>,
     pub output_tokens: u64,
     pub total_tokens: u64,
     pub start_ts: i64,
@@ -36,8 +47,9 @@ pub fn insert(rec: &TokenUsageRecord<'_>) -> Result<()> {
         "INSERT INTO chat_token_usage (
             project_key, task_id, chat_id, agent, model,
             input_tokens, cached_read_tokens, output_tokens, total_tokens,
-            start_ts, end_ts, cost_amount, cost_currency
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+            start_ts, end_ts, cost_amount, cost_currency,
+            cached_write_tokens, thought_tokens
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
         rusqlite::params![
             rec.project_key,
             rec.task_id,
@@ -52,7 +64,248 @@ pub fn insert(rec: &TokenUsageRecord<'_>) -> Result<()> {
             rec.end_ts,
             rec.cost_amount,
             rec.cost_currency,
+            rec.cached_write_tokens.map(|v| v as i64),
+            rec.thought_tokens.map(|v| v as i64),
         ],
     )?;
     Ok(())
 }
+
+/// Accumulated usage of a chat, task or project.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct UsageTotals {
+    pub turns: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cached_read_tokens: u64,
+    pub cached_write_tokens: u64,
+    pub thought_tokens: u64,
+    pub total_tokens: u64,
+    /// `None` when no agent in scope reported cost
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_currency: Option<String>,
+    /// End of the latest turn (Unix seconds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_turn_at: Option<i64>,
+}
+
+impl UsageTotals {
+    /// Fold another scope's totals into this one.
+    pub fn add(&mut self, other: &UsageTotals) {
+        self.turns += other.turns;
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cached_read_tokens += other.cached_read_tokens;
+        self.cached_write_tokens += other.cached_write_tokens;
+        self.thought_tokens += other.thought_tokens;
+        self.total_tokens += other.total_tokens;
+        if let Some(amount) = other.cost_amount {
+            *self.cost_amount.get_or_insert(0.0) += amount;
+            if self.cost_currency.is_none() {
+                self.cost_currency = other.cost_currency.clone();
+            }
+        }
+        self.last_turn_at = self.last_turn_at.max(other.last_turn_at);
+    }
+}
+
+/// Totals of one chat.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatUsage {
+    pub task_id: String,
+    pub chat_id: String,
+    pub agent: String,
+    /// Model of the latest turn that reported one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    pub totals: UsageTotals,
+}
+
+/// One recorded turn.
+#[derive(Debug, Clone, Serialize)]
+pub struct TurnUsageRow {
+    pub agent: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_read_tokens: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_write_tokens: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thought_tokens: Option<u64>,
+    pub total_tokens: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    /// Cumulative session cost as of this turn
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_amount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost_currency: Option<String>,
+}
+
+/// Per-chat totals of a project, optionally narrowed to one task.
+pub fn usage_by_chat(project_key: &str, task_id: Option<&str>) -> Result<Vec<ChatUsage>> {
+    let conn = database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT task_id, chat_id, MAX(agent),
+                (SELECT model FROM chat_token_usage m
+                  WHERE m.project_key = u.project_key AND m.task_id = u.task_id
+                    AND m.chat_id = u.chat_id AND m.model IS NOT NULL
+                  ORDER BY m.end_ts DESC, m.id DESC LIMIT 1),
+                COUNT(*),
+                COALESCE(SUM(input_tokens), 0),
+                COALESCE(SUM(output_tokens), 0),
+                COALESCE(SUM(cached_read_tokens), 0),
+                COALESCE(SUM(cached_write_tokens), 0),
+                COALESCE(SUM(thought_tokens), 0),
+                COALESCE(SUM(total_tokens), 0),
+                MAX(cost_amount),
+                MAX(cost_currency),
+                MAX(end_ts)
+           FROM chat_token_usage u
+          WHERE project_key = ?1 AND (?2 IS NULL OR task_id = ?2)
+          GROUP BY task_id, chat_id
+          ORDER BY MAX(end_ts) DESC",
+    )?;
+    let rows = stmt.query_map(rusqlite::params![project_key, task_id], |r| {
+        Ok(ChatUsage {
+            task_id: r.get(0)?,
+            chat_id: r.get(1)?,
+            agent: r.get(2)?,
+            model: r.get(3)?,
+            totals: UsageTotals {
+                turns: r.get::<_, i64>(4)? as u64,
+                input_tokens: r.get::<_, i64>(5)? as u64,
+                output_tokens: r.get::<_, i64>(6)? as u64,
+                cached_read_tokens: r.get::<_, i64>(7)? as u64,
+                cached_write_tokens: r.get::<_, i64>(8)? as u64,
+                thought_tokens: r.get::<_, i64>(9)? as u64,
+                total_tokens: r.get::<_, i64>(10)? as u64,
+                cost_amount: r.get(11)?,
+                cost_currency: r.get(12)?,
+                last_turn_at: r.get(13)?,
+            },
+        })
+    })?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// Latest `limit` turns of a chat, newest first.
+pub fn chat_turns(
+    project_key: &str,
+    task_id: &str,
+    chat_id: &str,
+    limit: usize,
+) -> Result<Vec<TurnUsageRow>> {
+    let conn = database::connection();
+    let mut stmt = conn.prepare(
+        "SELECT agent, model, input_tokens, output_tokens, cached_read_tokens,
+                cached_write_tokens, thought_tokens, total_tokens, start_ts, end_ts,
+                cost_amount, cost_currency
+           FROM chat_token_usage
+          WHERE project_key = ?1 AND task_id = ?2 AND chat_id = ?3
+          ORDER BY end_ts DESC, id DESC
+          LIMIT ?4",
+    )?;
+    let optional = |v: Option<i64>| v.map(|v| v as u64);
+    let rows = stmt.query_map(
+        rusqlite::params![project_key, task_id, chat_id, limit as i64],
+        |r| {
+            Ok(TurnUsageRow {
+                agent: r.get(0)?,
+                model: r.get(1)?,
+                input_tokens: r.get::<_, i64>(2)? as u64,
+                output_tokens: r.get::<_, i64>(3)? as u64,
+                cached_read_tokens: optional(r.get(4)?),
+                cached_write_tokens: optional(r.get(5)?),
+                thought_tokens: optional(r.get(6)?),
+                total_tokens: r.get::<_, i64>(7)? as u64,
+                start_ts: r.get(8)?,
+                end_ts: r.get(9)?,
+                cost_amount: r.get(10)?,
+                cost_currency: r.get(11)?,
+            })
+        },
+    )?;
+    Ok(rows.collect::<rusqlite::Result<Vec<_>>>()?)
+}
+
+/// Sum chat totals per task, largest token count first.
+pub fn totals_by_task(chats: &[ChatUsage]) -> Vec<(String, UsageTotals)> {
+    let mut by_task: BTreeMap<&str, UsageTotals> = BTreeMap::new();
+    for chat in chats {
+        by_task
+            .entry(chat.task_id.as_str())
+            .or_default()
+            .add(&chat.totals);
+    }
+    let mut tasks: Vec<(String, UsageTotals)> = by_task
+        .into_iter()
+        .map(|(task_id, totals)| (task_id.to_string(), totals))
+        .collect();
+    tasks.sort_by_key(|(_, totals)| std::cmp::Reverse(totals.total_tokens));
+    tasks
+}
+
+/// Sum of all chat totals.
+pub fn grand_total(chats: &[ChatUsage]) -> UsageTotals {
+    let mut total = UsageTotals::default();
+    for chat in chats {
+        total.add(&chat.totals);
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chat(task_id: &str, tokens: u64, cost: Option<f64>, last: i64) -> ChatUsage {
+        ChatUsage {
+            task_id: task_id.to_string(),
+            chat_id: format!("chat-{}-{}", task_id, last),
+            agent: "claude".to_string(),
+            model: None,
+            totals: UsageTotals {
+                turns: 2,
+                input_tokens: tokens / 2,
+                output_tokens: tokens / 2,
+                total_tokens: tokens,
+                cost_amount: cost,
+                cost_currency: cost.map(|_| "USD".to_string()),
+                last_turn_at: Some(last),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_totals_by_task() {
+        let chats = vec![
+            chat("a", 100, Some(0.5), 10),
+            chat("b", 1000, None, 20),
+            chat("a", 300, Some(0.25), 30),
+        ];
+
+        let tasks = totals_by_task(&chats);
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].0, "b");
+        assert_eq!(tasks[0].1.cost_amount, None);
+        let (task_id, a) = &tasks[1];
+        assert_eq!(task_id, "a");
+        assert_eq!((a.turns, a.total_tokens), (4, 400));
+        assert_eq!(a.cost_amount, Some(0.75));
+        assert_eq!(a.cost_currency.as_deref(), Some("USD"));
+        assert_eq!(a.last_turn_at, Some(30));
+
+        let total = grand_total(&chats);
+        assert_eq!((total.turns, total.total_tokens), (6, 1400));
+        assert_eq!(total.cost_amount, Some(0.75));
+        assert_eq!(total.last_turn_at, Some(30));
+        assert_eq!(grand_total(&[]), UsageTotals::default());
+    }
+}
@@ -0,0 +1,121 @@
+//! Chat turn 复现清单: ~/.grove/projects/{project}/tasks/{task_id}/chats/{chat_id}/manifests.json
+//!
+//! 与 history.jsonl 放在同一目录，记录每个 turn 由哪个 agent / model / mode、
+//! 哪段 prompt（只存哈希）、哪些 skills 和指令前言产生，以及 turn 前后的 git HEAD，
+//! 供导出 transcript 时审计改动的来源。
+
+use serde::{Deserialize, Serialize};
+
+use super::instructions::Preamble;
+use crate::error::{GroveError, Result};
+
+/// 每个 chat 最多保留的 turn 清单数
+pub const MAX_TURN_MANIFESTS: usize = 500;
+
+/// 单个 turn 的复现清单
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TurnManifest {
+    /// turn 开始时间（unix 秒），与 `TurnSnapshot.turn_ts` 对应
+    pub turn_ts: i64,
+    /// agent 名称和版本（来自 ACP initialize 的 agent_info）
+    pub agent_name: String,
+    #[serde(default)]
+    pub agent_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    /// 用户 prompt 文本的 SHA-256（hex）
+    pub prompt_sha256: String,
+    /// 对该 agent 生效的 skills（全局安装 + 当前项目安装）
+    #[serde(default)]
+    pub skills: Vec<String>,
+    /// 本 turn 随 prompt 注入的指令前言（只有新 session 的首个 turn 有）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instructions: Vec<Preamble>,
+    /// turn 前后的 HEAD commit（非 git 目录或无 commit 时为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_before: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_after: Option<String>,
+    /// turn 结束时间（unix 秒，turn 未结束时为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_ts: Option<i64>,
+}
+
+fn manifests_path(project: &str, task_id: &str, chat_id: &str) -> std::path::PathBuf {
+    super::chat_history::history_file_path(project, task_id, chat_id)
+        .with_file_name("manifests.json")
+}
+
+/// 读取 chat 的所有 turn 清单（按时间升序）
+pub fn load_manifests(project: &str, task_id: &str, chat_id: &str) -> Vec<TurnManifest> {
+    std::fs::read_to_string(manifests_path(project, task_id, chat_id))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// 保存 chat 的 turn 清单列表
+pub fn save_manifests(
+    project: &str,
+    task_id: &str,
+    chat_id: &str,
+    manifests: &[TurnManifest],
+) -> Result<()> {
+    let path = manifests_path(project, task_id, chat_id);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let content =
+        serde_json::to_string_pretty(manifests).map_err(|e| GroveError::storage(e.to_string()))?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// 记录新 turn 的清单（同一 turn_ts 覆盖旧记录，超出上限时丢弃最旧的）
+pub fn record(project: &str, task_id: &str, chat_id: &str, manifest: TurnManifest) -> Result<()> {
+    let mut list = load_manifests(project, task_id, chat_id);
+    list.retain(|m| m.turn_ts != manifest.turn_ts);
+    list.push(manifest);
+    let excess = list.len().saturating_sub(MAX_TURN_MANIFESTS);
+    list.drain(..excess);
+    save_manifests(project, task_id, chat_id, &list)
+}
+
+/// turn 结束时补上 HEAD 和结束时间
+pub fn finish(
+    project: &str,
+    task_id: &str,
+    chat_id: &str,
+    turn_ts: i64,
+    head_after: Option<String>,
+    finished_ts: i64,
+) -> Result<()> {
+    let mut list = load_manifests(project, task_id, chat_id);
+    let Some(entry) = list.iter_mut().find(|m| m.turn_ts == turn_ts) else {
+        return Ok(());
+    };
+    entry.head_after = head_after;
+    entry.finished_ts = Some(finished_ts);
+    save_manifests(project, task_id, chat_id, &list)
+}
+
+/// 清空 chat 的清单（与 `chat_history::clear_history` 一起调用）
+pub fn clear_manifests(project: &str, task_id: &str, chat_id: &str) {
+    let _ = std::fs::remove_file(manifests_path(project, task_id, chat_id));
+}
+
+/// 把一个 chat 的清单复制到另一个 chat（fork 场景，源不存在时静默成功）
+pub fn copy_manifests(
+    project: &str,
+    task_id: &str,
+    src_chat_id: &str,
+    dst_chat_id: &str,
+) -> Result<()> {
+    let list = load_manifests(project, task_id, src_chat_id);
+    if list.is_empty() {
+        return Ok(());
+    }
+    save_manifests(project, task_id, dst_chat_id, &list)
+}
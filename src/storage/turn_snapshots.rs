@@ -0,0 +1,71 @@
+//! Chat turn 快照记录: ~/.grove/projects/{project}/tasks/{task_id}/snapshots/{chat_id}.json
+//!
+//! 快照本身是 git commit（由 `operations::snapshots` 创建并用 ref 保活），
+//! 这里只记录每个 turn 前后对应的 commit hash。
+
+use serde::{Deserialize, Serialize};
+
+use super::ensure_task_data_dir;
+use crate::error::Result;
+
+/// 每个 chat 最多保留的 turn 快照数
+pub const MAX_TURN_SNAPSHOTS: usize = 50;
+
+/// 单个 turn 的前后快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnSnapshot {
+    /// turn 开始时间（unix 秒），与 `AcpUpdate::Complete.start_ts` 对应
+    pub turn_ts: i64,
+    /// turn 开始前的工作区快照
+    pub before: String,
+    /// turn 结束后的工作区快照（turn 未结束时为 None）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// turn 开始时 agent 使用的 model id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// turn 开始时 agent 所处的 mode id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+fn snapshots_path(project: &str, task_id: &str, chat_id: &str) -> Result<std::path::PathBuf> {
+    let dir = ensure_task_data_dir(project, task_id)?.join("snapshots");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir.join(format!("{}.json", chat_id)))
+}
+
+/// 读取 chat 的所有 turn 快照（按时间升序）
+pub fn load_snapshots(project: &str, task_id: &str, chat_id: &str) -> Vec<TurnSnapshot> {
+    snapshots_path(project, task_id, chat_id)
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// 保存 chat 的 turn 快照列表
+pub fn save_snapshots(
+    project: &str,
+    task_id: &str,
+    chat_id: &str,
+    snapshots: &[TurnSnapshot],
+) -> Result<()> {
+    let path = snapshots_path(project, task_id, chat_id)?;
+    let content = serde_json::to_string_pretty(snapshots)
+        .map_err(|e| crate::error::GroveError::storage(e.to_string()))?;
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
+/// 查找指定 turn 的快照
+pub fn find_snapshot(
+    project: &str,
+    task_id: &str,
+    chat_id: &str,
+    turn_ts: i64,
+) -> Option<TurnSnapshot> {
+    load_snapshots(project, task_id, chat_id)
+        .into_iter()
+        .find(|s| s.turn_ts == turn_ts)
+}
@@ -2,6 +2,7 @@
 //! 管理 projects 表中的项目元数据（SQLite）
 
 use chrono::{DateTime, Utc};
+use rusqlite::OptionalExtension;
 use serde::{Deserialize, Serialize};
 
 use super::grove_dir;
@@ -54,6 +55,9 @@ pub struct RegisteredProject {
     /// 项目类型
     #[serde(default)]
     pub project_type: ProjectType,
+    /// 是否置顶
+    #[serde(default)]
+    pub pinned: bool,
 }
 
 fn default_is_git_repo() -> bool {
@@ -113,7 +117,7 @@ fn resolve_project_path(path: &str) -> Result<String> {
 pub fn load_projects() -> Result<Vec<RegisteredProject>> {
     let conn = crate::storage::database::connection();
     let mut stmt = conn.prepare(
-        "SELECT hash, name, path, is_git_repo, added_at, project_type FROM projects ORDER BY added_at DESC",
+        "SELECT hash, name, path, is_git_repo, added_at, project_type, pinned FROM projects ORDER BY pinned DESC, added_at DESC",
     )?;
     let projects = stmt
         .query_map(rusqlite::params![], |row| {
@@ -122,6 +126,7 @@ pub fn load_projects() -> Result<Vec<RegisteredProject>> {
             let is_git: bool = row.get(3)?;
             let added_at_str: String = row.get(4)?;
             let project_type_str: String = row.get(5)?;
+            let pinned: bool = row.get(6)?;
             let added_at = DateTime::parse_from_rfc3339(&added_at_str)
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or_else(|_| Utc::now());
@@ -131,6 +136,7 @@ pub fn load_projects() -> Result<Vec<RegisteredProject>> {
                 added_at,
                 is_git_repo: is_git,
                 project_type: project_type_str.parse().unwrap_or_default(),
+                pinned,
             })
         })?
         .filter_map(|r| r.ok())
@@ -142,7 +148,7 @@ pub fn load_projects() -> Result<Vec<RegisteredProject>> {
 pub fn load_project_by_hash(hash: &str) -> Result<Option<RegisteredProject>> {
     let conn = crate::storage::database::connection();
     let result = conn.query_row(
-        "SELECT name, path, is_git_repo, added_at, project_type FROM projects WHERE hash = ?1",
+        "SELECT name, path, is_git_repo, added_at, project_type, pinned FROM projects WHERE hash = ?1",
         rusqlite::params![hash],
         |row| {
             let name: String = row.get(0)?;
@@ -150,6 +156,7 @@ pub fn load_project_by_hash(hash: &str) -> Result<Option<RegisteredProject>> {
             let is_git: bool = row.get(2)?;
             let added_at_str: String = row.get(3)?;
             let project_type_str: String = row.get(4)?;
+            let pinned: bool = row.get(5)?;
             let added_at = DateTime::parse_from_rfc3339(&added_at_str)
                 .map(|dt| dt.with_timezone(&Utc))
                 .unwrap_or_else(|_| Utc::now());
@@ -159,6 +166,7 @@ pub fn load_project_by_hash(hash: &str) -> Result<Option<RegisteredProject>> {
                 added_at,
                 is_git_repo: is_git,
                 project_type: project_type_str.parse().unwrap_or_default(),
+                pinned,
             })
         },
     );
@@ -477,6 +485,106 @@ pub fn rename_project(hash: &str, new_name: &str) -> Result<()> {
     Ok(())
 }
 
+/// 读取项目的默认 target branch（未设置返回 None）
+pub fn load_default_target(hash: &str) -> Result<Option<String>> {
+    let conn = crate::storage::database::connection();
+    let target = conn
+        .query_row(
+            "SELECT default_target FROM projects WHERE hash = ?1",
+            rusqlite::params![hash],
+            |row| row.get::<_, Option<String>>(0),
+        )
+        .optional()?
+        .flatten();
+    Ok(target)
+}
+
+/// 设置项目的默认 target branch（None 清除，回退到当前分支）
+pub fn set_default_target(hash: &str, target: Option<&str>) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    let changes = conn.execute(
+        "UPDATE projects SET default_target = ?1 WHERE hash = ?2",
+        rusqlite::params![target, hash],
+    )?;
+    if changes == 0 {
+        return Err(crate::error::GroveError::storage("Project not found"));
+    }
+    Ok(())
+}
+
+/// 读取项目的提交签名覆盖（None = 跟随全局 `[signing].enabled`）
+pub fn load_commit_signing(hash: &str) -> Result<Option<bool>> {
+    let conn = crate::storage::database::connection();
+    let value = conn
+        .query_row(
+            "SELECT commit_signing FROM projects WHERE hash = ?1",
+            rusqlite::params![hash],
+            |row| row.get::<_, Option<bool>>(0),
+        )
+        .optional()?
+        .flatten();
+    Ok(value)
+}
+
+/// 设置项目的提交签名覆盖（None 清除，回退到全局设置）
+pub fn set_commit_signing(hash: &str, enabled: Option<bool>) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    let changes = conn.execute(
+        "UPDATE projects SET commit_signing = ?1 WHERE hash = ?2",
+        rusqlite::params![enabled, hash],
+    )?;
+    if changes == 0 {
+        return Err(crate::error::GroveError::storage("Project not found"));
+    }
+    Ok(())
+}
+
+/// 项目生效的签名设置：启用时返回配置，否则 None
+pub fn commit_signing(hash: &str) -> Option<crate::storage::config::SigningConfig> {
+    let signing = crate::storage::config::load_config().signing;
+    let project_override = load_commit_signing(hash).ok().flatten();
+    signing.enabled_for(project_override).then_some(signing)
+}
+
+/// 读取项目是否给提交追加 Grove-Task / Grove-Chat trailer（默认开启）
+pub fn load_commit_trailers(hash: &str) -> Result<bool> {
+    let conn = crate::storage::database::connection();
+    let value = conn
+        .query_row(
+            "SELECT commit_trailers FROM projects WHERE hash = ?1",
+            rusqlite::params![hash],
+            |row| row.get::<_, bool>(0),
+        )
+        .optional()?;
+    Ok(value.unwrap_or(true))
+}
+
+/// 设置项目是否追加 Grove trailer
+pub fn set_commit_trailers(hash: &str, enabled: bool) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    let changes = conn.execute(
+        "UPDATE projects SET commit_trailers = ?1 WHERE hash = ?2",
+        rusqlite::params![enabled, hash],
+    )?;
+    if changes == 0 {
+        return Err(crate::error::GroveError::storage("Project not found"));
+    }
+    Ok(())
+}
+
+/// 设置项目置顶
+pub fn set_project_pinned(hash: &str, pinned: bool) -> Result<()> {
+    let conn = crate::storage::database::connection();
+    let changes = conn.execute(
+        "UPDATE projects SET pinned = ?1 WHERE hash = ?2",
+        rusqlite::params![pinned, hash],
+    )?;
+    if changes == 0 {
+        return Err(crate::error::GroveError::storage("Project not found"));
+    }
+    Ok(())
+}
+
 /// 设置项目的 is_git_repo 标志
 pub fn set_is_git_repo(path: &str, is_git: bool) -> Result<()> {
     let resolved = resolve_project_path(path).unwrap_or_else(|_| path.to_string());
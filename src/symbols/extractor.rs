@@ -3,22 +3,30 @@
 //! Owns the tree-sitter `Parser` and pre-compiled `Query` for each
 //! supported language, lazily initialized on first use.
 
+#[cfg(feature = "symbols")]
 use once_cell::sync::Lazy;
+#[cfg(feature = "symbols")]
 use std::cell::RefCell;
 
+#[cfg(feature = "symbols")]
 use tree_sitter::{Parser, Query, QueryCursor, StreamingIterator};
 
-use super::types::{Language, SymbolDef, SymbolKind};
+#[cfg(feature = "symbols")]
+use super::types::SymbolKind;
+use super::types::{Language, SymbolDef};
 
+#[cfg(feature = "symbols")]
 const GO_QUERY_SRC: &str = include_str!("queries/go.scm");
 
 /// Pre-compiled query for the Go grammar. `Query` is `Sync`, so a single
 /// global instance is fine.
+#[cfg(feature = "symbols")]
 static GO_QUERY: Lazy<Query> = Lazy::new(|| {
     Query::new(&tree_sitter_go::LANGUAGE.into(), GO_QUERY_SRC)
         .expect("invalid Go tags query (queries/go.scm)")
 });
 
+#[cfg(feature = "symbols")]
 thread_local! {
     /// Tree-sitter `Parser` is not `Sync`; a global `Mutex<Parser>` would
     /// serialize extraction across worker threads. Per-thread parsers
@@ -38,6 +46,7 @@ thread_local! {
 /// `file_path` is stored verbatim into each returned `SymbolDef` —
 /// callers are expected to have already normalized it (relative to
 /// worktree root, forward slashes).
+#[cfg(feature = "symbols")]
 pub fn extract(language: Language, file_path: &str, source: &[u8]) -> Vec<SymbolDef> {
     match language {
         Language::Go => GO_PARSER.with(|cell| {
@@ -52,6 +61,14 @@ pub fn extract(language: Language, file_path: &str, source: &[u8]) -> Vec<Symbol
     }
 }
 
+/// Built without the `symbols` feature: no grammar is compiled in, so
+/// nothing is extracted.
+#[cfg(not(feature = "symbols"))]
+pub fn extract(_language: Language, _file_path: &str, _source: &[u8]) -> Vec<SymbolDef> {
+    Vec::new()
+}
+
+#[cfg(feature = "symbols")]
 fn extract_with(
     parser: &mut Parser,
     query: &Query,
@@ -117,6 +134,7 @@ fn extract_with(
     out
 }
 
+#[cfg(feature = "symbols")]
 fn parse_kind(s: &str) -> Option<SymbolKind> {
     match s {
         "function" => Some(SymbolKind::Function),
@@ -131,7 +149,7 @@ fn parse_kind(s: &str) -> Option<SymbolKind> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "symbols"))]
 mod tests {
     use super::*;
 
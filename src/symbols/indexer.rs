@@ -36,7 +36,7 @@ use std::time::{Duration, Instant};
 
 use once_cell::sync::Lazy;
 
-use crate::error::Result;
+use crate::error::{GroveError, Result};
 
 use super::extractor;
 use super::store::SymbolStore;
@@ -225,6 +225,49 @@ pub fn lookup(project_hash: &str, task_id: &str, name: &str) -> Result<Vec<Symbo
     store.lookup(task_id, name)
 }
 
+/// Files larger than this are not parsed for an outline.
+const MAX_OUTLINE_BYTES: u64 = 2 * 1024 * 1024;
+
+/// Symbols declared in a single file, sorted by position.
+///
+/// Parses the file on demand instead of reading the index, so the
+/// outline reflects unsaved-to-index edits and works even when indexing
+/// is disabled. `rel_path` is relative to the worktree root; paths that
+/// escape the worktree are rejected. Unsupported languages and oversized
+/// files yield an empty list.
+pub fn outline(worktree: &Path, rel_path: &str) -> Result<Vec<SymbolDef>> {
+    let rel_path = rel_path.replace('\\', "/");
+    let rel = Path::new(&rel_path);
+    if rel.is_absolute()
+        || rel
+            .components()
+            .any(|c| !matches!(c, std::path::Component::Normal(_)))
+    {
+        return Err(GroveError::invalid_data(format!(
+            "Invalid path '{}'",
+            rel_path
+        )));
+    }
+    let Some(language) = rel
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(Language::from_extension)
+    else {
+        return Ok(Vec::new());
+    };
+
+    let abs = worktree.join(rel);
+    let meta = std::fs::metadata(&abs)
+        .map_err(|_| GroveError::not_found(format!("File not found: {}", rel_path)))?;
+    if !meta.is_file() || meta.len() > MAX_OUTLINE_BYTES {
+        return Ok(Vec::new());
+    }
+    let bytes = std::fs::read(&abs)?;
+    let mut symbols = extractor::extract(language, &rel_path, &bytes);
+    symbols.sort_by_key(|s| (s.line, s.col));
+    Ok(symbols)
+}
+
 // ============================================================================
 // Slot / scheduler internals
 // ============================================================================
@@ -306,10 +349,7 @@ fn worker_loop(slot: Arc<BuildSlot>, project_hash: String, task_id: String, work
             Action::Sleep(d) => std::thread::sleep(d),
             Action::Build => {
                 if let Err(e) = run_build(&slot, &project_hash, &task_id, &worktree) {
-                    eprintln!(
-                        "[symbols] build failed for ({}, {}): {}",
-                        project_hash, task_id, e
-                    );
+                    tracing::warn!("build failed for ({}, {}): {}", project_hash, task_id, e);
                 }
             }
         }
@@ -466,10 +506,12 @@ mod tests {
     use crate::storage::set_grove_dir_override;
     use crate::symbols::types::SymbolKind;
 
+    #[cfg(feature = "symbols")]
     fn write(p: &Path, s: &str) {
         std::fs::write(p, s).unwrap();
     }
 
+    #[cfg(feature = "symbols")]
     fn init_repo(dir: &Path) {
         let run = |args: &[&str]| {
             Command::new("git")
@@ -486,6 +528,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "symbols")]
     fn run_build_indexes_go_files_and_skips_others() {
         let root = std::env::temp_dir().join("grove-symidx-build");
         let _ = std::fs::remove_dir_all(&root);
@@ -525,6 +568,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "symbols")]
     fn slot_coalesces_rapid_requests() {
         let root = std::env::temp_dir().join("grove-symidx-coalesce");
         let _ = std::fs::remove_dir_all(&root);
@@ -626,4 +670,27 @@ mod tests {
         on_task_deleted("proj-empty", "task-x");
         assert!(!root.join("projects/proj-empty/index.db").exists());
     }
+
+    #[test]
+    #[cfg(feature = "symbols")]
+    fn outline_parses_file_on_demand_sorted_by_line() {
+        let root = std::env::temp_dir().join("grove-symidx-outline");
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("pkg")).unwrap();
+        write(
+            &root.join("pkg/a.go"),
+            "package pkg\n\ntype Server struct {\n\tAddr string\n}\n\nfunc (s *Server) Run() {}\n\nfunc New() *Server { return nil }\n",
+        );
+        write(&root.join("README.md"), "# hi\n");
+
+        let symbols = outline(&root, "pkg/a.go").unwrap();
+        let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Server", "Addr", "Run", "New"]);
+        assert_eq!(symbols[2].kind, SymbolKind::Method);
+        assert_eq!(symbols[2].container.as_deref(), Some("Server"));
+
+        assert!(outline(&root, "README.md").unwrap().is_empty());
+        assert!(outline(&root, "../escape.go").is_err());
+        assert!(outline(&root, "pkg/missing.go").is_err());
+    }
 }
@@ -8,17 +8,20 @@
 //! 3. `indexer` — orchestrates first-build and incremental updates
 //!    driven by `FileWatcher` subscriptions. Public entry points are
 //!    `on_watch_started`, `lookup`, `trigger_reindex`, `on_task_deleted`.
+//!    `outline` bypasses the index and parses a single file on demand.
 //!
 //! Currently supports Go only. Other languages plug in by adding a
-//! `tree-sitter-<lang>` dep, a `queries/<lang>.scm`, and registering
-//! the language in `types::Language`.
+//! `tree-sitter-<lang>` dep (optional, enabled by the `symbols` feature),
+//! a `queries/<lang>.scm`, and registering the language in
+//! `types::Language`. Built without `symbols`, no language is supported
+//! and every entry point returns empty results.
 
 mod extractor;
 mod indexer;
 mod store;
 mod types;
 
-pub use indexer::{lookup, on_task_deleted, on_watch_started, trigger_reindex};
+pub use indexer::{lookup, on_task_deleted, on_watch_started, outline, trigger_reindex};
 pub use types::{Language, SymbolDef, SymbolKind};
 
 // Lower-level pieces are kept available behind the module wall but not
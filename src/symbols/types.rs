@@ -67,10 +67,10 @@ pub enum Language {
 impl Language {
     /// Map a file extension (without the leading dot) to a supported language.
     pub fn from_extension(ext: &str) -> Option<Self> {
-        match ext {
-            "go" => Some(Language::Go),
-            _ => None,
-        }
+        Self::all()
+            .iter()
+            .copied()
+            .find(|lang| lang.extensions().contains(&ext))
     }
 
     pub fn as_str(self) -> &'static str {
@@ -94,8 +94,13 @@ impl Language {
     }
 
     /// All supported languages, in deterministic order. Used by the
-    /// config response to surface the full list to the frontend.
+    /// config response to surface the full list to the frontend. Empty
+    /// when built without the `symbols` feature.
     pub fn all() -> &'static [Language] {
-        &[Language::Go]
+        if cfg!(feature = "symbols") {
+            &[Language::Go]
+        } else {
+            &[]
+        }
     }
 }
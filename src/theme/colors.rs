@@ -3,6 +3,7 @@
 use ratatui::style::Color;
 
 use super::ThemeColors;
+use crate::storage::config::StatusPalette;
 
 /// 深色主题（默认）
 pub fn dark_colors() -> ThemeColors {
@@ -498,3 +499,80 @@ pub fn catppuccin_latte_colors() -> ThemeColors {
         ],
     }
 }
+
+/// 高对比度主题 (Dark)：纯黑背景、纯白文字、饱和的状态色，便于低视力用户
+pub fn high_contrast_colors() -> ThemeColors {
+    ThemeColors {
+        bg: Color::Black,
+        bg_secondary: Color::Rgb(0, 55, 110), // 深蓝选中行，白字对比度 > 10:1
+        logo: Color::Rgb(255, 255, 0),
+        highlight: Color::Rgb(255, 255, 0), // 纯黄
+        text: Color::White,
+        muted: Color::Rgb(210, 210, 210), // 次要文字仍保持高亮度
+        border: Color::White,
+        status_live: Color::Rgb(0, 255, 0),
+        status_idle: Color::Rgb(210, 210, 210),
+        status_merged: Color::Rgb(215, 160, 255),
+        status_conflict: Color::Rgb(255, 175, 0),
+        status_error: Color::Rgb(255, 110, 110),
+        tab_active_fg: Color::Black,
+        tab_active_bg: Color::Rgb(255, 255, 0),
+        info: Color::Rgb(120, 200, 255),
+        warning: Color::Rgb(255, 255, 0),
+        error: Color::Rgb(255, 110, 110),
+        card_backgrounds: [Color::Black; 8],
+        accent_palette: [
+            Color::Rgb(255, 110, 110), // red
+            Color::Rgb(255, 175, 0),   // orange
+            Color::Rgb(255, 255, 0),   // yellow
+            Color::Rgb(0, 255, 0),     // green
+            Color::Rgb(0, 255, 255),   // cyan
+            Color::Rgb(120, 200, 255), // blue
+            Color::Rgb(215, 160, 255), // purple
+            Color::Rgb(255, 128, 255), // magenta
+            Color::White,
+            Color::Rgb(210, 210, 210), // gray
+        ],
+    }
+}
+
+/// 色盲友好的状态色（基于 Okabe–Ito 配色，靠蓝 / 黄轴和明度区分，不依赖红绿）
+#[derive(Debug, Clone, Copy)]
+pub struct StatusColors {
+    pub live: Color,
+    pub merged: Color,
+    pub conflict: Color,
+    pub error: Color,
+}
+
+/// 状态色方案对应的颜色；`Default` 返回 None（沿用主题自带颜色）
+pub fn status_palette_colors(palette: StatusPalette, light: bool) -> Option<StatusColors> {
+    match (palette, light) {
+        (StatusPalette::Default, _) => None,
+        (StatusPalette::Deuteranopia, false) => Some(StatusColors {
+            live: Color::Rgb(86, 180, 233),     // sky blue
+            merged: Color::Rgb(204, 121, 167),  // reddish purple
+            conflict: Color::Rgb(240, 228, 66), // yellow
+            error: Color::Rgb(213, 94, 0),      // vermillion
+        }),
+        (StatusPalette::Deuteranopia, true) => Some(StatusColors {
+            live: Color::Rgb(0, 114, 178),     // blue
+            merged: Color::Rgb(170, 68, 153),  // purple
+            conflict: Color::Rgb(230, 159, 0), // orange
+            error: Color::Rgb(170, 60, 0),     // dark vermillion
+        }),
+        // 红色盲对红光不敏感，红色会显得暗淡：错误色改用更亮的橙红
+        (StatusPalette::Protanopia, false) => Some(StatusColors {
+            live: Color::Rgb(86, 180, 233),     // sky blue
+            merged: Color::Rgb(204, 121, 167),  // reddish purple
+            conflict: Color::Rgb(240, 228, 66), // yellow
+            error: Color::Rgb(255, 140, 66),    // bright orange-red
+        }),
+        (StatusPalette::Protanopia, true) => Some(StatusColors {
+            live: Color::Rgb(0, 114, 178),     // blue
+            merged: Color::Rgb(170, 68, 153),  // purple
+            conflict: Color::Rgb(180, 150, 0), // dark yellow
+            error: Color::Rgb(220, 90, 20),    // orange-red
+        }),
+    }
+}
@@ -1,10 +1,16 @@
 mod colors;
 mod detect;
+mod web;
+
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 
 use ratatui::style::Color;
 
+use crate::storage::config::StatusPalette;
+
 pub use colors::*;
 pub use detect::detect_system_theme;
+pub use web::*;
 
 /// 主题类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -24,6 +30,7 @@ pub enum Theme {
     Gruvbox,
     TokyoNight,
     Catppuccin,
+    HighContrast,
 }
 
 impl Theme {
@@ -44,6 +51,7 @@ impl Theme {
             Theme::Gruvbox => "Gruvbox",
             Theme::TokyoNight => "Tokyo Night",
             Theme::Catppuccin => "Catppuccin",
+            Theme::HighContrast => "High Contrast",
         }
     }
 
@@ -64,6 +72,7 @@ impl Theme {
             Theme::Gruvbox,
             Theme::TokyoNight,
             Theme::Catppuccin,
+            Theme::HighContrast,
         ]
     }
 
@@ -95,6 +104,7 @@ impl Theme {
             Theme::Gruvbox => "gruvbox",
             Theme::TokyoNight => "tokyo-night",
             Theme::Catppuccin => "catppuccin",
+            Theme::HighContrast => "high-contrast",
         })
     }
 
@@ -118,6 +128,7 @@ impl Theme {
             "gruvbox" => Theme::Gruvbox,
             "tokyo night" | "tokyo-night" | "tokyonight" => Theme::TokyoNight,
             "catppuccin" => Theme::Catppuccin,
+            "high contrast" | "high-contrast" | "highcontrast" => Theme::HighContrast,
             _ => Theme::Auto, // 默认 Auto
         }
     }
@@ -167,8 +178,12 @@ pub struct ThemeColors {
     pub accent_palette: [Color; 10],
 }
 
-/// 获取指定主题的颜色方案
+/// 获取指定主题的颜色方案（已套用当前状态色方案）
 pub fn get_theme_colors(theme: Theme) -> ThemeColors {
+    apply_status_palette(base_theme_colors(theme), status_palette())
+}
+
+fn base_theme_colors(theme: Theme) -> ThemeColors {
     match theme {
         Theme::Auto => {
             if detect_system_theme() {
@@ -190,5 +205,108 @@ pub fn get_theme_colors(theme: Theme) -> ThemeColors {
         Theme::Gruvbox => gruvbox_colors(),
         Theme::TokyoNight => tokyo_night_colors(),
         Theme::Catppuccin => catppuccin_colors(),
+        Theme::HighContrast => high_contrast_colors(),
+    }
+}
+
+/// 减少装饰模式（`[accessibility] reduced_decoration`）：只用 ASCII 符号、
+/// 不绘制渐变色块和字符画 Logo，便于屏幕阅读器朗读
+static REDUCED_DECORATION: AtomicBool = AtomicBool::new(false);
+
+/// 更新减少装饰模式开关
+pub fn set_reduced_decoration(enabled: bool) {
+    REDUCED_DECORATION.store(enabled, Ordering::Relaxed);
+}
+
+/// 是否处于减少装饰模式
+pub fn reduced_decoration() -> bool {
+    REDUCED_DECORATION.load(Ordering::Relaxed)
+}
+
+/// 按当前模式选择符号：默认用 `fancy`，减少装饰模式用纯文本 `plain`
+pub fn glyph(fancy: &'static str, plain: &'static str) -> &'static str {
+    if reduced_decoration() {
+        plain
+    } else {
+        fancy
+    }
+}
+
+/// 状态色方案（`[theme] status_palette`），存为 `StatusPalette::all()` 下标
+static STATUS_PALETTE: AtomicU8 = AtomicU8::new(0);
+
+/// 更新状态色方案（之后 `get_theme_colors` 返回的颜色生效）
+pub fn set_status_palette(palette: StatusPalette) {
+    let index = StatusPalette::all()
+        .iter()
+        .position(|p| *p == palette)
+        .unwrap_or(0);
+    STATUS_PALETTE.store(index as u8, Ordering::Relaxed);
+}
+
+/// 当前状态色方案
+pub fn status_palette() -> StatusPalette {
+    StatusPalette::all()
+        .get(STATUS_PALETTE.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// 用色盲友好的状态色替换主题的 live / merged / conflict / error（及 error 通知色），
+/// 按背景明暗选择深浅两套
+pub fn apply_status_palette(mut colors: ThemeColors, palette: StatusPalette) -> ThemeColors {
+    let Some(status) = status_palette_colors(palette, is_light_color(colors.bg)) else {
+        return colors;
+    };
+    colors.status_live = status.live;
+    colors.status_merged = status.merged;
+    colors.status_conflict = status.conflict;
+    colors.status_error = status.error;
+    colors.error = status.error;
+    colors
+}
+
+/// 背景色是否为浅色（按感知亮度）
+fn is_light_color(color: Color) -> bool {
+    match color {
+        Color::Rgb(r, g, b) => (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000 > 128,
+        Color::White | Color::Gray => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_status_palette() {
+        let dark = dark_colors();
+        let unchanged = apply_status_palette(dark, StatusPalette::Default);
+        assert_eq!(unchanged.status_live, dark.status_live);
+        assert_eq!(unchanged.status_error, dark.status_error);
+
+        for palette in [StatusPalette::Deuteranopia, StatusPalette::Protanopia] {
+            for base in [dark_colors(), light_colors(), high_contrast_colors()] {
+                let colors = apply_status_palette(base, palette);
+                assert_ne!(colors.status_live, colors.status_error);
+                assert_ne!(colors.status_conflict, colors.status_error);
+                assert_eq!(colors.error, colors.status_error);
+                // 非状态色保持不变
+                assert_eq!(colors.bg, base.bg);
+                assert_eq!(colors.status_idle, base.status_idle);
+            }
+            assert_ne!(
+                apply_status_palette(dark_colors(), palette).status_live,
+                apply_status_palette(light_colors(), palette).status_live
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_light_color() {
+        assert!(is_light_color(light_colors().bg));
+        assert!(!is_light_color(dark_colors().bg));
+        assert!(!is_light_color(Color::Black));
     }
 }
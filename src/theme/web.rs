@@ -0,0 +1,342 @@
+//! 供 Web 端使用的主题颜色：把配置中选中的主题（内置或自定义）解析为完整的
+//! ThemeColors，并导出为 `#rrggbb` 和 CSS 变量，使 Web 与终端配色一致
+
+use std::collections::BTreeMap;
+
+use ratatui::style::Color;
+
+use super::{apply_status_palette, base_theme_colors, is_light_color, Theme, ThemeColors};
+use crate::storage::config::{CustomThemeColors, CustomThemeConfig, ThemeConfig};
+
+/// 配置解析出的当前主题
+#[derive(Debug, Clone)]
+pub struct ResolvedTheme {
+    /// Web theme id（内置主题为 kebab-case，自定义主题为其 id）
+    pub id: String,
+    pub name: String,
+    pub is_light: bool,
+    pub is_custom: bool,
+    /// 已套用状态色方案
+    pub colors: ThemeColors,
+}
+
+/// 颜色转为 `#rrggbb`（命名色按 xterm 默认值换算）
+pub fn color_hex(color: Color) -> String {
+    let (r, g, b) = match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Black | Color::Reset => (0, 0, 0),
+        Color::Red => (205, 0, 0),
+        Color::Green => (0, 205, 0),
+        Color::Yellow => (205, 205, 0),
+        Color::Blue => (0, 0, 238),
+        Color::Magenta => (205, 0, 205),
+        Color::Cyan => (0, 205, 205),
+        Color::Gray => (229, 229, 229),
+        Color::DarkGray => (127, 127, 127),
+        Color::LightRed => (255, 0, 0),
+        Color::LightGreen => (0, 255, 0),
+        Color::LightYellow => (255, 255, 0),
+        Color::LightBlue => (92, 92, 255),
+        Color::LightMagenta => (255, 0, 255),
+        Color::LightCyan => (0, 255, 255),
+        Color::White => (255, 255, 255),
+        Color::Indexed(i) => indexed_rgb(i),
+    };
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+/// xterm 256 色中 16 以上的颜色（色立方 + 灰阶）；0-15 取近似灰色
+fn indexed_rgb(i: u8) -> (u8, u8, u8) {
+    const STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    match i {
+        16..=231 => {
+            let i = i - 16;
+            (
+                STEPS[(i / 36) as usize],
+                STEPS[(i / 6 % 6) as usize],
+                STEPS[(i % 6) as usize],
+            )
+        }
+        232..=255 => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+        _ => (128, 128, 128),
+    }
+}
+
+/// 解析 `#rrggbb` / `#rgb`
+fn parse_hex(s: &str) -> Option<Color> {
+    let hex = s.trim().strip_prefix('#')?;
+    let channel = |h: &str| u8::from_str_radix(h, 16).ok();
+    match hex.len() {
+        6 => Some(Color::Rgb(
+            channel(&hex[0..2])?,
+            channel(&hex[2..4])?,
+            channel(&hex[4..6])?,
+        )),
+        3 => {
+            let mut rgb = hex.chars().map(|c| channel(&c.to_string()).map(|v| v * 17));
+            Some(Color::Rgb(rgb.next()??, rgb.next()??, rgb.next()??))
+        }
+        _ => None,
+    }
+}
+
+/// 两色的中间色（Web 的 bg_tertiary 在终端主题中没有对应色）
+fn mix(a: Color, b: Color) -> Color {
+    match (a, b) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => Color::Rgb(
+            ((r1 as u16 + r2 as u16) / 2) as u8,
+            ((g1 as u16 + g2 as u16) / 2) as u8,
+            ((b1 as u16 + b2 as u16) / 2) as u8,
+        ),
+        _ => a,
+    }
+}
+
+/// 自定义主题（Web 端定义的色槽）转为终端 ThemeColors；缺失或非法的颜色沿用
+/// 同明暗的默认主题
+fn custom_theme_colors(custom: &CustomThemeConfig) -> ThemeColors {
+    let base = base_theme_colors(if custom.is_light {
+        Theme::Light
+    } else {
+        Theme::Dark
+    });
+    let c = &custom.colors;
+    let pick = |value: &str, fallback: Color| parse_hex(value).unwrap_or(fallback);
+
+    let bg = pick(&c.bg, base.bg);
+    let highlight = pick(&c.highlight, base.highlight);
+    let muted = pick(&c.text_muted, base.muted);
+    let error = pick(&c.error, base.error);
+    let warning = pick(&c.warning, base.warning);
+    let palette: Vec<Color> = custom
+        .accent_palette
+        .iter()
+        .filter_map(|h| parse_hex(h))
+        .collect();
+    let mut accent_palette = base.accent_palette;
+    if !palette.is_empty() {
+        for (i, slot) in accent_palette.iter_mut().enumerate() {
+            *slot = palette[i % palette.len()];
+        }
+    }
+
+    ThemeColors {
+        bg,
+        bg_secondary: pick(&c.bg_secondary, base.bg_secondary),
+        logo: highlight,
+        highlight,
+        text: pick(&c.text, base.text),
+        muted,
+        border: pick(&c.border, base.border),
+        status_live: pick(&c.success, base.status_live),
+        status_idle: muted,
+        status_merged: pick(&c.accent, base.status_merged),
+        status_conflict: warning,
+        status_error: error,
+        tab_active_fg: bg,
+        tab_active_bg: highlight,
+        info: pick(&c.info, base.info),
+        warning,
+        error,
+        card_backgrounds: base.card_backgrounds,
+        accent_palette,
+    }
+}
+
+/// 按 mode / light_theme / dark_theme 解析当前主题（自定义主题优先按 id 匹配）。
+/// `prefer_dark` 决定 auto 模式下用哪个槽位（Web 传浏览器的明暗偏好）
+pub fn resolve_configured_theme(config: &ThemeConfig, prefer_dark: bool) -> ResolvedTheme {
+    let dark_slot = match config.mode.as_str() {
+        "light" => false,
+        "dark" => true,
+        _ => prefer_dark,
+    };
+    let id = if dark_slot {
+        &config.dark_theme
+    } else {
+        &config.light_theme
+    };
+
+    if let Some(custom) = config.custom_themes.iter().find(|t| &t.id == id) {
+        return ResolvedTheme {
+            id: custom.id.clone(),
+            name: custom.name.clone(),
+            is_light: custom.is_light,
+            is_custom: true,
+            colors: apply_status_palette(custom_theme_colors(custom), config.status_palette),
+        };
+    }
+
+    // 仅 Web 端有的主题（如 one-dark）按槽位回退到默认明暗主题
+    let theme = match Theme::from_name(id) {
+        Theme::Auto if dark_slot => Theme::Dark,
+        Theme::Auto => Theme::Light,
+        theme => theme,
+    };
+    let colors = apply_status_palette(base_theme_colors(theme), config.status_palette);
+    ResolvedTheme {
+        id: theme.web_id().unwrap_or_default().to_string(),
+        name: theme.label().to_string(),
+        is_light: is_light_color(colors.bg),
+        is_custom: false,
+        colors,
+    }
+}
+
+/// 终端配色映射到 Web 主题的色槽
+pub fn web_colors(colors: &ThemeColors) -> CustomThemeColors {
+    CustomThemeColors {
+        bg: color_hex(colors.bg),
+        bg_secondary: color_hex(colors.bg_secondary),
+        bg_tertiary: color_hex(mix(colors.bg_secondary, colors.border)),
+        border: color_hex(colors.border),
+        text: color_hex(colors.text),
+        text_muted: color_hex(colors.muted),
+        highlight: color_hex(colors.highlight),
+        accent: color_hex(colors.status_merged),
+        success: color_hex(colors.status_live),
+        warning: color_hex(colors.warning),
+        error: color_hex(colors.error),
+        info: color_hex(colors.info),
+    }
+}
+
+/// ThemeColors 的全部字段（名称, 颜色），不含卡片底色
+pub fn named_colors(colors: &ThemeColors) -> Vec<(&'static str, Color)> {
+    vec![
+        ("bg", colors.bg),
+        ("bg_secondary", colors.bg_secondary),
+        ("logo", colors.logo),
+        ("highlight", colors.highlight),
+        ("text", colors.text),
+        ("muted", colors.muted),
+        ("border", colors.border),
+        ("status_live", colors.status_live),
+        ("status_idle", colors.status_idle),
+        ("status_merged", colors.status_merged),
+        ("status_conflict", colors.status_conflict),
+        ("status_error", colors.status_error),
+        ("tab_active_fg", colors.tab_active_fg),
+        ("tab_active_bg", colors.tab_active_bg),
+        ("info", colors.info),
+        ("warning", colors.warning),
+        ("error", colors.error),
+    ]
+}
+
+/// Web 端 CSS 变量：Web 主题使用的 `--color-*`，以及完整终端配色 `--tui-*`
+/// （`--tui-status-live`、`--tui-accent-0` … `--tui-accent-9` 等）
+pub fn css_variables(colors: &ThemeColors) -> BTreeMap<String, String> {
+    let web = web_colors(colors);
+    let mut vars: BTreeMap<String, String> = [
+        ("--color-bg", web.bg),
+        ("--color-bg-secondary", web.bg_secondary),
+        ("--color-bg-tertiary", web.bg_tertiary),
+        ("--color-border", web.border),
+        ("--color-text", web.text),
+        ("--color-text-muted", web.text_muted),
+        ("--color-highlight", web.highlight),
+        ("--color-accent", web.accent),
+        ("--color-success", web.success),
+        ("--color-warning", web.warning),
+        ("--color-error", web.error),
+        ("--color-info", web.info),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v))
+    .collect();
+    for (name, color) in named_colors(colors) {
+        vars.insert(
+            format!("--tui-{}", name.replace('_', "-")),
+            color_hex(color),
+        );
+    }
+    for (i, color) in colors.accent_palette.iter().enumerate() {
+        vars.insert(format!("--tui-accent-{}", i), color_hex(*color));
+    }
+    vars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::config::StatusPalette;
+
+    fn config(mode: &str, light: &str, dark: &str) -> ThemeConfig {
+        ThemeConfig {
+            mode: mode.to_string(),
+            light_theme: light.to_string(),
+            dark_theme: dark.to_string(),
+            ..ThemeConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_color_hex() {
+        assert_eq!(color_hex(Color::Rgb(0, 255, 136)), "#00ff88");
+        assert_eq!(color_hex(Color::White), "#ffffff");
+        assert_eq!(color_hex(Color::Indexed(196)), "#ff0000");
+        assert_eq!(parse_hex("#0f8"), Some(Color::Rgb(0, 255, 136)));
+        assert_eq!(parse_hex("00ff88"), None);
+    }
+
+    #[test]
+    fn test_resolve_builtin_theme() {
+        let resolved = resolve_configured_theme(&config("auto", "solarized-light", "nord"), true);
+        assert_eq!(resolved.id, "nord");
+        assert_eq!(resolved.name, "Nord");
+        assert!(!resolved.is_light && !resolved.is_custom);
+        assert_eq!(
+            color_hex(resolved.colors.bg),
+            color_hex(base_theme_colors(Theme::Nord).bg)
+        );
+
+        let resolved = resolve_configured_theme(&config("light", "solarized-light", "nord"), true);
+        assert_eq!(resolved.id, "solarized-light");
+        assert!(resolved.is_light);
+
+        // Web-only themes fall back to the default of their slot
+        let resolved = resolve_configured_theme(&config("dark", "light", "one-dark"), false);
+        assert_eq!(resolved.id, "dark");
+    }
+
+    #[test]
+    fn test_resolve_custom_theme() {
+        let mut cfg = config("dark", "light", "mine");
+        cfg.status_palette = StatusPalette::Deuteranopia;
+        cfg.custom_themes.push(CustomThemeConfig {
+            id: "mine".into(),
+            name: "Mine".into(),
+            colors: CustomThemeColors {
+                bg: "#101010".into(),
+                highlight: "#ff00aa".into(),
+                success: "#00ff00".into(),
+                ..Default::default()
+            },
+            accent_palette: vec!["#111111".into(), "#222222".into()],
+            is_light: false,
+        });
+
+        let resolved = resolve_configured_theme(&cfg, false);
+        assert!(resolved.is_custom);
+        assert_eq!(resolved.name, "Mine");
+        assert_eq!(color_hex(resolved.colors.bg), "#101010");
+        assert_eq!(color_hex(resolved.colors.tab_active_bg), "#ff00aa");
+        // Missing slots fall back to the dark theme, status palette applies
+        assert_eq!(
+            color_hex(resolved.colors.border),
+            color_hex(base_theme_colors(Theme::Dark).border)
+        );
+        assert_ne!(color_hex(resolved.colors.status_live), "#00ff00");
+        assert_eq!(color_hex(resolved.colors.accent_palette[3]), "#222222");
+
+        let vars = css_variables(&resolved.colors);
+        assert_eq!(vars["--color-bg"], "#101010");
+        assert_eq!(vars["--tui-tab-active-bg"], "#ff00aa");
+        assert_eq!(vars["--tui-accent-2"], "#111111");
+        assert_eq!(vars["--color-success"], vars["--tui-status-live"]);
+    }
+}
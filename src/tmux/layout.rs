@@ -192,7 +192,7 @@ impl TaskLayout {
 // ── apply layout functions ───────────────────────────────────────────
 
 /// 查询 session 中所有 pane 的 ID（%N 格式，不受 base-index 影响）
-fn list_pane_ids(session: &str) -> Result<Vec<String>> {
+pub fn list_pane_ids(session: &str) -> Result<Vec<String>> {
     let output = Command::new("tmux")
         .args(["list-panes", "-t", session, "-F", "#{pane_id}"])
         .output()
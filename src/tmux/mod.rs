@@ -1,5 +1,6 @@
 pub mod layout;
 
+use std::collections::BTreeMap;
 use std::process::Command;
 
 use crate::error::{GroveError, Result};
@@ -21,6 +22,8 @@ pub struct SessionEnv {
     pub project_name: String,
     /// 主仓库路径
     pub project_path: String,
+    /// 任务模板定义的额外环境变量
+    pub extra: BTreeMap<String, String>,
 }
 
 impl SessionEnv {
@@ -33,6 +36,7 @@ impl SessionEnv {
         cmd.env("GROVE_WORKTREE", &self.worktree);
         cmd.env("GROVE_PROJECT_NAME", &self.project_name);
         cmd.env("GROVE_PROJECT", &self.project_path);
+        cmd.envs(&self.extra);
     }
 
     /// 生成 shell export 前缀，用于在 zellij KDL layout 中注入环境变量
@@ -49,7 +53,8 @@ impl SessionEnv {
             ("GROVE_PROJECT", &self.project_path),
         ];
         let parts: Vec<String> = vars
-            .iter()
+            .into_iter()
+            .chain(self.extra.iter().map(|(k, v)| (k.as_str(), v)))
             .map(|(k, v)| format!("{}='{}'", k, v.replace('\'', "'\\''")))
             .collect();
         format!("export {}; ", parts.join(" "))
@@ -84,6 +89,10 @@ pub fn create_session(name: &str, working_dir: &str, env: Option<&SessionEnv>) -
         args.push(format!("GROVE_PROJECT_NAME={}", env.project_name));
         args.push("-e".to_string());
         args.push(format!("GROVE_PROJECT={}", env.project_path));
+        for (key, value) in &env.extra {
+            args.push("-e".to_string());
+            args.push(format!("{}={}", key, value));
+        }
     }
 
     let output = Command::new("tmux")
@@ -132,6 +141,102 @@ pub fn session_exists(name: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// 抓取 pane 最近 `lines` 行内容（target 可以是 pane id 或 session 名）
+/// 执行: tmux capture-pane -p [-e] -t {target} -S -{lines}
+/// `ansi` 为 true 时保留颜色等转义序列
+pub fn capture_pane(target: &str, lines: usize, ansi: bool) -> Result<String> {
+    let start = format!("-{}", lines);
+    let mut cmd = Command::new("tmux");
+    cmd.args(["capture-pane", "-p"]);
+    if ansi {
+        cmd.arg("-e");
+    }
+    let output = cmd
+        .args(["-t", target, "-S", &start])
+        .output()
+        .map_err(|e| GroveError::session(format!("Pane capture failed: {}", e)))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(GroveError::session(format!(
+            "Pane capture failed: {}",
+            stderr.trim()
+        )))
+    }
+}
+
+/// session 中是否有窗口触发了 bell（agent 常用 bell 提示需要输入）
+/// 执行: tmux list-windows -t {session} -F "#{window_bell_flag}"
+pub fn window_bell(session: &str) -> bool {
+    Command::new("tmux")
+        .args(["list-windows", "-t", session, "-F", "#{window_bell_flag}"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .is_some_and(|o| {
+            String::from_utf8_lossy(&o.stdout)
+                .lines()
+                .any(|l| l.trim() == "1")
+        })
+}
+
+/// 按字面发送文本（不解析键名）
+/// 执行: tmux send-keys -t {target} -l -- {text}
+/// `--` 防止以 `-` 开头的文本被 tmux 当成选项
+pub fn send_literal(target: &str, text: &str) -> Result<()> {
+    send_keys_cmd(&["send-keys", "-t", target, "-l", "--", text])
+}
+
+/// 发送具名按键（`Enter` / `Escape` / `C-c` / `Up` …）
+/// 执行: tmux send-keys -t {target} -- {key}
+/// 只接受 `is_key_name` 认可的键名
+pub fn send_key(target: &str, key: &str) -> Result<()> {
+    if !is_key_name(key) {
+        return Err(GroveError::session(format!("Unsupported key: {}", key)));
+    }
+    send_keys_cmd(&["send-keys", "-t", target, "--", key])
+}
+
+/// tmux 认识的具名按键（不含修饰前缀）
+const KEY_NAMES: &[&str] = &[
+    "Enter", "Escape", "Tab", "BTab", "BSpace", "Space", "Up", "Down", "Left", "Right", "Home",
+    "End", "PageUp", "PageDown", "PPage", "NPage", "IC", "DC", "Insert", "Delete", "F1", "F2",
+    "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12",
+];
+
+/// 是否是合法的 tmux 键名：`KEY_NAMES` 中的名字或单个可见 ASCII 字符，
+/// 可带任意个 `C-` / `M-` / `S-` 修饰前缀
+pub fn is_key_name(key: &str) -> bool {
+    let mut base = key;
+    while let Some(rest) = ["C-", "M-", "S-"]
+        .iter()
+        .find_map(|m| base.strip_prefix(m))
+        .filter(|rest| !rest.is_empty())
+    {
+        base = rest;
+    }
+    KEY_NAMES.contains(&base) || (base.len() == 1 && base.bytes().all(|b| b.is_ascii_graphic()))
+}
+
+fn send_keys_cmd(args: &[&str]) -> Result<()> {
+    let output = Command::new("tmux")
+        .args(args)
+        .output()
+        .map_err(|e| GroveError::session(format!("Send keys failed: {}", e)))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(GroveError::session(format!(
+            "Send keys failed: {}",
+            stderr.trim()
+        )))
+    }
+}
+
 /// 关闭 session
 /// 执行: tmux kill-session -t {name}
 pub fn kill_session(name: &str) -> Result<()> {
@@ -155,3 +260,94 @@ pub fn kill_session(name: &str) -> Result<()> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_key_name_accepts_tmux_keys_only() {
+        for key in ["Enter", "C-c", "M-Left", "C-M-x", "F5", "y", "-"] {
+            assert!(is_key_name(key), "{key}");
+        }
+        for key in ["", "-tother", "-l", "Enterr", "C-", "foo bar"] {
+            assert!(!is_key_name(key), "{key}");
+        }
+    }
+
+    #[test]
+    fn send_literal_types_text_starting_with_dash() {
+        // 没装 tmux 的环境跳过
+        if Command::new("tmux").arg("-V").output().is_err() {
+            return;
+        }
+        let name = format!("grove-send-test-{}", std::process::id());
+        let started = Command::new("tmux")
+            .args([
+                "new-session",
+                "-d",
+                "-s",
+                &name,
+                "-x",
+                "80",
+                "-y",
+                "10",
+                "cat",
+            ])
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false);
+        if !started {
+            return;
+        }
+
+        let sent = send_literal(&name, "-rf foo");
+        let bad_key = send_key(&name, "-tother");
+        let mut screen = String::new();
+        for _ in 0..50 {
+            screen = capture_pane(&name, 10, false).unwrap_or_default();
+            if screen.contains("-rf foo") {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        let _ = kill_session(&name);
+
+        assert!(sent.is_ok(), "{sent:?}");
+        assert!(screen.contains("-rf foo"));
+        assert!(bad_key.is_err());
+    }
+
+    #[test]
+    fn capture_pane_keeps_ansi_only_when_asked() {
+        // 没装 tmux 的环境跳过
+        if Command::new("tmux").arg("-V").output().is_err() {
+            return;
+        }
+        let name = format!("grove-capture-test-{}", std::process::id());
+        let started = Command::new("tmux")
+            .args(["new-session", "-d", "-s", &name, "-x", "80", "-y", "10"])
+            .arg("printf '\\033[31mred-marker\\033[0m\\n'; sleep 30")
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false);
+        if !started {
+            return;
+        }
+
+        let mut plain = String::new();
+        for _ in 0..50 {
+            plain = capture_pane(&name, 10, false).unwrap_or_default();
+            if plain.contains("red-marker") {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        let ansi = capture_pane(&name, 10, true).unwrap_or_default();
+        let _ = kill_session(&name);
+
+        assert!(plain.contains("red-marker"));
+        assert!(!plain.contains('\u{1b}'));
+        assert!(ansi.contains("\u{1b}[31m"));
+    }
+}
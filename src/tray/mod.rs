@@ -164,9 +164,10 @@ pub fn tray_take_pending_navigate() -> Option<NavigatePayload> {
             // Expired entry dropped silently — log a breadcrumb so users
             // who report "I clicked Open and nothing happened" have a
             // hint in the GUI log.
-            eprintln!(
-                "[tray] dropping expired pending navigate ({}ms old, TTL {}ms)",
-                age, PENDING_NAVIGATE_TTL_MS
+            tracing::info!(
+                "dropping expired pending navigate ({}ms old, TTL {}ms)",
+                age,
+                PENDING_NAVIGATE_TTL_MS
             );
             None
         }
@@ -562,7 +563,7 @@ pub fn init(app: &AppHandle, port: u16) -> tauri::Result<()> {
         .build(app)?;
 
     if let Err(e) = ensure_popover(app, port) {
-        eprintln!("[tray] failed to create popover window: {}", e);
+        tracing::warn!("failed to create popover window: {}", e);
     }
 
     // Set the tray icon for the current theme at startup.
@@ -0,0 +1,80 @@
+//! TUI 复制到剪贴板
+//!
+//! 先写系统剪贴板（arboard），再按配置写出 OSC 52 转义序列 —— 后者由终端
+//! 处理，SSH 远程或 tmux 内也能复制到本地剪贴板。
+
+use std::io::Write;
+
+use base64::Engine;
+
+/// 复制结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyOutcome {
+    /// 已写入系统剪贴板
+    System,
+    /// 系统剪贴板不可用，仅通过 OSC 52 交给终端
+    Osc52,
+    /// 两者都不可用
+    Failed,
+}
+
+impl CopyOutcome {
+    pub fn copied(self) -> bool {
+        self != CopyOutcome::Failed
+    }
+}
+
+/// 复制文本（OSC 52 是否写出由 `clipboard.osc52` 配置决定）
+pub fn copy(text: &str) -> CopyOutcome {
+    let osc52 = crate::storage::config::load_config().clipboard.osc52;
+    let system = arboard::Clipboard::new()
+        .and_then(|mut c| c.set_text(text))
+        .is_ok();
+    let terminal = osc52 && write_osc52(text).is_ok();
+    match (system, terminal) {
+        (true, _) => CopyOutcome::System,
+        (false, true) => CopyOutcome::Osc52,
+        (false, false) => CopyOutcome::Failed,
+    }
+}
+
+/// 复制并返回 toast 文案
+pub fn copy_with_message(label: &str, text: &str) -> String {
+    if copy(text).copied() {
+        format!("Copied {}: {}", label, text)
+    } else {
+        "Clipboard unavailable".to_string()
+    }
+}
+
+fn write_osc52(text: &str) -> std::io::Result<()> {
+    let in_tmux = std::env::var_os("TMUX").is_some();
+    let mut out = std::io::stdout();
+    out.write_all(osc52_sequence(text, in_tmux).as_bytes())?;
+    out.flush()
+}
+
+/// OSC 52 序列；tmux 内需要用 DCS passthrough 包一层（内部 ESC 需要双写）
+fn osc52_sequence(text: &str, in_tmux: bool) -> String {
+    let payload = base64::engine::general_purpose::STANDARD.encode(text.as_bytes());
+    let osc = format!("\x1b]52;c;{}\x07", payload);
+    if in_tmux {
+        format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b"))
+    } else {
+        osc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52_sequence("hi", false), "\x1b]52;c;aGk=\x07");
+        assert_eq!(
+            osc52_sequence("hi", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\"
+        );
+    }
+}
@@ -18,6 +18,8 @@ use crate::ui::click_areas::{ClickAreas, DialogAction};
 pub enum ActionGroup {
     /// 编辑类操作（安全）
     Edit,
+    /// 复制类操作（无副作用）
+    Copy,
     /// 分支操作（普通）
     Branch,
     /// 会话/生命周期操作（危险）
@@ -35,6 +37,16 @@ pub enum ActionType {
     Recover,
     Commit,
     Review,
+    Links,
+    Search,
+    NotesHistory,
+    ProjectNotes,
+    CopyBranch,
+    CopyPath,
+    CopyAttach,
+    CopyUrl,
+    OpenWith,
+    Stashes,
     Reset,
 }
 
@@ -50,6 +62,16 @@ impl ActionType {
             ActionType::Recover => "Recover",
             ActionType::Commit => "Commit",
             ActionType::Review => "Review",
+            ActionType::Links => "AutoLinks",
+            ActionType::Search => "Search",
+            ActionType::NotesHistory => "Notes History",
+            ActionType::ProjectNotes => "Project Notes",
+            ActionType::CopyBranch => "Copy Branch",
+            ActionType::CopyPath => "Copy Path",
+            ActionType::CopyAttach => "Copy Attach Cmd",
+            ActionType::CopyUrl => "Copy Task URL",
+            ActionType::OpenWith => "Open With",
+            ActionType::Stashes => "Stashes",
             ActionType::Reset => "Reset",
         }
     }
@@ -65,6 +87,16 @@ impl ActionType {
             ActionType::Recover => "Restore worktree from archive",
             ActionType::Commit => "Add all and commit changes",
             ActionType::Review => "Open diff review in browser",
+            ActionType::Links => "Manage linked files",
+            ActionType::Search => "Search file contents",
+            ActionType::NotesHistory => "Previous versions of notes",
+            ActionType::ProjectNotes => "Notes shared by all tasks",
+            ActionType::CopyBranch => "Branch name to clipboard",
+            ActionType::CopyPath => "Worktree path to clipboard",
+            ActionType::CopyAttach => "tmux / zellij attach command",
+            ActionType::CopyUrl => "Review page URL to clipboard",
+            ActionType::OpenWith => "Open worktree in a custom app",
+            ActionType::Stashes => "Inspect, apply or drop stashes",
             ActionType::Reset => "Rebuild branch and worktree",
         }
     }
@@ -72,8 +104,20 @@ impl ActionType {
     /// Action 所属分组
     pub fn group(&self) -> ActionGroup {
         match self {
-            ActionType::Commit | ActionType::Review => ActionGroup::Edit,
-            ActionType::RebaseTo | ActionType::Sync | ActionType::Merge => ActionGroup::Branch,
+            ActionType::Commit
+            | ActionType::Review
+            | ActionType::Links
+            | ActionType::Search
+            | ActionType::NotesHistory
+            | ActionType::ProjectNotes
+            | ActionType::OpenWith => ActionGroup::Edit,
+            ActionType::CopyBranch
+            | ActionType::CopyPath
+            | ActionType::CopyAttach
+            | ActionType::CopyUrl => ActionGroup::Copy,
+            ActionType::RebaseTo | ActionType::Sync | ActionType::Merge | ActionType::Stashes => {
+                ActionGroup::Branch
+            }
             ActionType::Archive | ActionType::Clean | ActionType::Recover | ActionType::Reset => {
                 ActionGroup::Session
             }
@@ -84,6 +128,7 @@ impl ActionType {
     fn selected_color(&self, colors: &ThemeColors) -> Color {
         match self.group() {
             ActionGroup::Edit => colors.highlight,
+            ActionGroup::Copy => colors.text,
             ActionGroup::Branch => colors.info,
             ActionGroup::Session => colors.error,
         }
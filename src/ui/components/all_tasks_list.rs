@@ -0,0 +1,160 @@
+//! Workspace 的 All Tasks tab：跨项目任务列表
+
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::model::loader::ProjectWorktree;
+use crate::model::{format_time, WorktreeStatus};
+use crate::session::agent_state::AgentState;
+use crate::theme::{glyph, ThemeColors};
+
+/// 渲染 Projects / All Tasks 切换栏
+pub fn render_mode_tabs(frame: &mut Frame, area: Rect, all_tasks_mode: bool, colors: &ThemeColors) {
+    let tab = |label: &'static str, active: bool| {
+        if active {
+            Span::styled(
+                format!("  {}  ", label),
+                Style::default()
+                    .fg(colors.tab_active_fg)
+                    .bg(colors.tab_active_bg)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            Span::styled(format!("  {}  ", label), Style::default().fg(colors.muted))
+        }
+    };
+
+    let line = Line::from(vec![
+        tab("Projects", !all_tasks_mode),
+        Span::raw("  "),
+        tab("All Tasks", all_tasks_mode),
+        Span::styled("   Tab to switch", Style::default().fg(colors.muted)),
+    ]);
+    frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), area);
+}
+
+/// 渲染跨项目任务列表
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    tasks: &[ProjectWorktree],
+    selected_index: Option<usize>,
+    colors: &ThemeColors,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border));
+
+    if tasks.is_empty() {
+        let paragraph = Paragraph::new(Line::from(Span::styled(
+            "No active tasks across projects",
+            Style::default().fg(colors.muted),
+        )))
+        .alignment(Alignment::Center)
+        .block(block);
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let header = Row::new(vec![
+        Cell::from(""), // 选择指示器
+        Cell::from(""), // 状态图标
+        Cell::from("TASK"),
+        Cell::from("PROJECT"),
+        Cell::from("STATUS"),
+        Cell::from("BRANCH"),
+        Cell::from("UPDATED"),
+    ])
+    .style(Style::default().fg(colors.muted))
+    .height(1)
+    .bottom_margin(1);
+
+    let rows: Vec<Row> = tasks
+        .iter()
+        .enumerate()
+        .map(|(i, t)| {
+            let wt = &t.worktree;
+            let is_selected = selected_index == Some(i);
+            let selector = if is_selected { glyph("❯", ">") } else { " " };
+
+            let icon_style = match wt.status {
+                WorktreeStatus::Live => Style::default().fg(colors.status_live),
+                WorktreeStatus::Idle => Style::default().fg(colors.status_idle),
+                WorktreeStatus::Merged => Style::default().fg(colors.status_merged),
+                WorktreeStatus::Conflict => Style::default().fg(colors.status_conflict),
+                WorktreeStatus::Broken | WorktreeStatus::Error => {
+                    Style::default().fg(colors.status_error)
+                }
+                WorktreeStatus::Archived => Style::default().fg(colors.muted),
+            };
+
+            let mut name = Vec::new();
+            if wt.is_local {
+                name.push(Span::styled(
+                    glyph("◈ ", "[local] "),
+                    Style::default().fg(colors.accent_palette[0]),
+                ));
+            } else if wt.pinned {
+                name.push(Span::styled(
+                    glyph("★ ", "[pin] "),
+                    Style::default().fg(colors.warning),
+                ));
+            }
+            name.push(Span::raw(wt.task_name.as_str()));
+
+            let row_style = if is_selected {
+                Style::default()
+                    .fg(colors.text)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(colors.text)
+            };
+
+            Row::new(vec![
+                Cell::from(selector).style(Style::default().fg(colors.highlight)),
+                Cell::from(wt.status.display_icon()).style(icon_style),
+                Cell::from(Line::from(name)),
+                Cell::from(t.project_name.as_str()).style(Style::default().fg(colors.info)),
+                match wt.agent_state {
+                    Some(state) => Cell::from(state.label()).style(match state {
+                        AgentState::Waiting => Style::default().fg(colors.warning),
+                        AgentState::Busy => Style::default().fg(colors.info),
+                        AgentState::Idle => icon_style,
+                    }),
+                    None => Cell::from(wt.status.label()).style(icon_style),
+                },
+                Cell::from(wt.branch.as_str()).style(Style::default().fg(colors.muted)),
+                Cell::from(format_time(wt.updated_at)).style(Style::default().fg(colors.muted)),
+            ])
+            .style(row_style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(2),  // 选择器
+        Constraint::Length(2),  // 状态图标
+        Constraint::Fill(3),    // TASK
+        Constraint::Fill(2),    // PROJECT
+        Constraint::Length(8),  // STATUS
+        Constraint::Fill(2),    // BRANCH
+        Constraint::Length(16), // UPDATED
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(
+            Style::default()
+                .bg(colors.bg_secondary)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    let mut table_state = TableState::default();
+    table_state.select(selected_index);
+    frame.render_stateful_widget(table, area, &mut table_state);
+}
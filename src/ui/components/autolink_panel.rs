@@ -0,0 +1,160 @@
+//! AutoLink 面板（任务 worktree 中的链接：查看、重新同步、刷新副本、清理）
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::operations::autolink::{self, LinkState, TaskLink};
+use crate::theme::ThemeColors;
+
+/// AutoLink 面板数据
+#[derive(Debug, Clone, Default)]
+pub struct AutoLinkPanelData {
+    pub project_path: String,
+    pub project_key: String,
+    pub task_id: String,
+    pub task_name: String,
+    pub links: Vec<TaskLink>,
+    pub selected: usize,
+}
+
+impl AutoLinkPanelData {
+    /// 加载任务的链接列表
+    pub fn load(
+        project_path: &str,
+        project_key: &str,
+        task_id: &str,
+        task_name: &str,
+    ) -> crate::error::Result<Self> {
+        let mut data = Self {
+            project_path: project_path.to_string(),
+            project_key: project_key.to_string(),
+            task_id: task_id.to_string(),
+            task_name: task_name.to_string(),
+            ..Default::default()
+        };
+        data.reload()?;
+        Ok(data)
+    }
+
+    /// 重新读取链接状态（保持选中位置）
+    pub fn reload(&mut self) -> crate::error::Result<()> {
+        self.links =
+            autolink::list_task_links(&self.project_path, &self.project_key, &self.task_id)?;
+        self.selected = self.selected.min(self.links.len().saturating_sub(1));
+        Ok(())
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.links.is_empty() {
+            self.selected = (self.selected + 1).min(self.links.len() - 1);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_link(&self) -> Option<&TaskLink> {
+        self.links.get(self.selected)
+    }
+
+    /// 坏链接 + 已消失的记录数量
+    pub fn broken_count(&self) -> usize {
+        self.links
+            .iter()
+            .filter(|l| matches!(l.state, LinkState::Broken | LinkState::Missing))
+            .count()
+    }
+}
+
+fn state_label(state: LinkState, colors: &ThemeColors) -> Span<'static> {
+    let (text, color) = match state {
+        LinkState::Ok => ("ok", colors.status_live),
+        LinkState::Broken => ("broken", colors.error),
+        LinkState::Missing => ("missing", colors.warning),
+        LinkState::NotLink => ("replaced", colors.muted),
+        LinkState::Stale => ("stale", colors.warning),
+    };
+    Span::styled(format!("{:<9}", text), Style::default().fg(color))
+}
+
+/// 渲染 AutoLink 面板
+pub fn render(frame: &mut Frame, data: &AutoLinkPanelData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = (area.width * 3 / 5).max(40).min(area.width);
+    let height = (area.height * 3 / 5).max(10).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let broken = data.broken_count();
+    let title = if broken > 0 {
+        format!(" AutoLinks · {} ({} broken) ", data.task_name, broken)
+    } else {
+        format!(" AutoLinks · {} ", data.task_name)
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            " j/k select · r re-sync · f refresh copies · d remove · c clean broken · Esc close ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(panel_area);
+    frame.render_widget(block, panel_area);
+
+    if data.links.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "No AutoLinks in this task. Press r to apply the configured patterns.",
+                Style::default().fg(colors.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let path_width = (inner.width as usize).saturating_sub(21).max(10);
+    let items: Vec<ListItem> = data
+        .links
+        .iter()
+        .map(|link| {
+            ListItem::new(Line::from(vec![
+                state_label(link.state, colors),
+                Span::styled(
+                    format!("{:<9}", link.mode.as_str()),
+                    Style::default().fg(colors.muted),
+                ),
+                Span::styled(
+                    super::truncate(&link.path, path_width),
+                    Style::default().fg(colors.text),
+                ),
+            ]))
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(data.selected.min(data.links.len() - 1)));
+    frame.render_stateful_widget(
+        List::new(items).highlight_style(Style::default().bg(colors.bg_secondary)),
+        inner,
+        &mut state,
+    );
+}
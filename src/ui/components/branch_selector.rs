@@ -1,5 +1,8 @@
 //! 分支选择器组件（带搜索）
 
+use std::collections::HashMap;
+
+use chrono::{TimeZone, Utc};
 use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Modifier, Style},
@@ -8,6 +11,8 @@ use ratatui::{
     Frame,
 };
 
+use crate::git::{self, BranchDetail};
+use crate::model::format_time;
 use crate::theme::ThemeColors;
 use crate::ui::click_areas::{ClickAreas, DialogAction};
 
@@ -40,6 +45,8 @@ pub struct BranchSelectorData {
     pub current_target: String,
     /// 选择器模式
     pub mode: BranchSelectorMode,
+    /// 分支详情（ahead/behind 相对 current_target、最后提交时间、worktree）
+    pub details: HashMap<String, BranchDetail>,
 }
 
 impl BranchSelectorData {
@@ -54,6 +61,7 @@ impl BranchSelectorData {
             task_name,
             current_target,
             mode: BranchSelectorMode::RebaseTo,
+            details: HashMap::new(),
         }
     }
 
@@ -68,6 +76,7 @@ impl BranchSelectorData {
             task_name: String::new(),
             current_target: current_branch,
             mode: BranchSelectorMode::NewTaskTarget,
+            details: HashMap::new(),
         }
     }
 
@@ -82,9 +91,18 @@ impl BranchSelectorData {
             task_name: String::new(),
             current_target: current_branch,
             mode: BranchSelectorMode::Checkout,
+            details: HashMap::new(),
         }
     }
 
+    /// 批量加载分支详情（相对 current_target），失败时保持无详情
+    pub fn with_details(mut self, repo_path: &str) -> Self {
+        if let Ok(details) = git::branch_details(repo_path, "refs/heads", &self.current_target) {
+            self.details = details.into_iter().map(|d| (d.name.clone(), d)).collect();
+        }
+        self
+    }
+
     /// 更新搜索过滤
     pub fn update_filter(&mut self) {
         let search_lower = self.search.to_lowercase();
@@ -141,6 +159,37 @@ impl BranchSelectorData {
     }
 }
 
+/// 列表右侧的摘要：`↑2 ↓5  3h ago  ⎇`（worktree 已检出时带 ⎇）
+fn detail_summary(detail: &BranchDetail, is_base: bool) -> String {
+    let mut parts = Vec::new();
+    if !is_base {
+        if let (Some(ahead), Some(behind)) = (detail.ahead, detail.behind) {
+            parts.push(format!("↑{} ↓{}", ahead, behind));
+        }
+    }
+    if let Some(time) = detail
+        .last_commit_at
+        .and_then(|t| Utc.timestamp_opt(t, 0).single())
+    {
+        parts.push(format_time(time));
+    }
+    if detail.worktree.is_some() {
+        parts.push("⎇".to_string());
+    }
+    parts.join("  ")
+}
+
+/// 按字符截断，超长时以 … 结尾
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        let mut out: String = s.chars().take(max.saturating_sub(1)).collect();
+        out.push('…');
+        out
+    }
+}
+
 /// 渲染分支选择器
 pub fn render(
     frame: &mut Frame,
@@ -151,7 +200,7 @@ pub fn render(
     let area = frame.area();
 
     // 计算弹窗尺寸
-    let popup_width = 50u16;
+    let popup_width = 72u16.min(area.width);
     let max_visible = 8usize;
     let visible_count = data.filtered_indices.len().min(max_visible).max(1); // 至少1行显示空状态
     let popup_height = (visible_count as u16) + 8; // 标题 + 信息 + 搜索框 + 列表 + 提示
@@ -263,20 +312,28 @@ pub fn render(
             let branch = &data.branches[branch_idx];
             let is_selected = i == data.selected_index;
             let prefix = if is_selected { "❯ " } else { "  " };
-
-            if is_selected {
-                Line::from(Span::styled(
-                    format!("{}{}", prefix, branch),
-                    Style::default()
-                        .fg(colors.highlight)
-                        .add_modifier(Modifier::BOLD),
-                ))
+            let name_style = if is_selected {
+                Style::default()
+                    .fg(colors.highlight)
+                    .add_modifier(Modifier::BOLD)
             } else {
-                Line::from(Span::styled(
-                    format!("{}{}", prefix, branch),
-                    Style::default().fg(colors.text),
-                ))
-            }
+                Style::default().fg(colors.text)
+            };
+
+            let meta = data
+                .details
+                .get(branch)
+                .map(|d| detail_summary(d, branch == &data.current_target))
+                .unwrap_or_default();
+            let name_width = (list_area.width as usize)
+                .saturating_sub(2 + meta.chars().count() + 1)
+                .max(8);
+            let name = truncate(branch, name_width);
+            Line::from(vec![
+                Span::styled(format!("{}{:<name_width$}", prefix, name), name_style),
+                Span::raw(" "),
+                Span::styled(meta, Style::default().fg(colors.muted)),
+            ])
         })
         .collect();
 
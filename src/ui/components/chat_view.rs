@@ -0,0 +1,991 @@
+//! ACP Chat 面板（查看 / 接续任意前端发起的 chat）
+//!
+//! 读：跟随 history.jsonl（`HistoryTail`），所以 Web / notifyd / MCP 持有的
+//! session 在这里实时可见；busy / 队列这类不落盘的状态每秒向 owner 查询一次。
+//! 写：通过 `ChatClient` 发到 session owner，没有 owner 时在本进程启动。
+
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::acp::client::{self, ChatClient, ChatHost, ChatStatus, HistoryTail};
+use crate::acp::{
+    AcpUpdate, PermOptionData, PlanEntryData, QueuedConfig, QueuedMessage, SessionMetadata,
+    SocketCommand,
+};
+use crate::storage::tasks::{self, ChatSession, Task};
+use crate::theme::ThemeColors;
+
+/// history.jsonl 轮询间隔
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 向 owner 同步 busy / 队列 / 元数据的间隔
+const SYNC_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Transcript 条目角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatRole {
+    User,
+    Agent,
+    Thought,
+    Tool,
+    Permission,
+    System,
+    Error,
+}
+
+/// Transcript 中的一条
+#[derive(Debug, Clone)]
+pub struct ChatEntry {
+    pub role: ChatRole,
+    pub text: String,
+}
+
+/// 等待用户回应的权限请求
+#[derive(Debug, Clone)]
+pub struct PendingPermission {
+    pub description: String,
+    pub options: Vec<PermOptionData>,
+}
+
+/// 后台同步结果
+#[derive(Debug)]
+struct ChatSync {
+    host: ChatHost,
+    status: Result<ChatStatus, String>,
+    metadata: Option<SessionMetadata>,
+}
+
+/// 正在查看的 chat
+#[derive(Debug)]
+pub struct OpenChat {
+    pub chat_id: String,
+    pub title: String,
+    pub agent: String,
+    pub host: ChatHost,
+    pub entries: Vec<ChatEntry>,
+    pub permission: Option<PendingPermission>,
+    pub plan: Vec<PlanEntryData>,
+    pub queue: Vec<QueuedMessage>,
+    pub busy: bool,
+    /// agent 声明的 (id, name) 列表，来自 session.json
+    pub modes: Vec<(String, String)>,
+    pub models: Vec<(String, String)>,
+    pub current_mode: Option<String>,
+    pub current_model: Option<String>,
+    /// 用户在 TUI 里选了但还没随 prompt 发出的 mode / model
+    pub pending_mode: Option<String>,
+    pub pending_model: Option<String>,
+    /// 距底部的行数（0 = 跟随最新）
+    pub scroll_from_bottom: usize,
+    tail: HistoryTail,
+    last_poll: Instant,
+    last_sync: Option<Instant>,
+    sync_rx: Option<mpsc::Receiver<ChatSync>>,
+}
+
+impl OpenChat {
+    fn new(project_key: &str, task_id: &str, chat: &ChatSession) -> Self {
+        let client = ChatClient::discover(project_key, task_id, &chat.id);
+        let mut open = Self {
+            chat_id: chat.id.clone(),
+            title: chat.title.clone(),
+            agent: chat.agent.clone(),
+            host: client.host(),
+            entries: Vec::new(),
+            permission: None,
+            plan: Vec::new(),
+            queue: Vec::new(),
+            busy: false,
+            modes: Vec::new(),
+            models: Vec::new(),
+            current_mode: None,
+            current_model: None,
+            pending_mode: None,
+            pending_model: None,
+            scroll_from_bottom: 0,
+            tail: client.tail(),
+            last_poll: Instant::now(),
+            last_sync: None,
+            sync_rx: None,
+        };
+        if let Some(meta) = client.metadata() {
+            open.apply_metadata(meta);
+        }
+        let batch = open.tail.poll();
+        for event in batch.events {
+            open.apply(event);
+        }
+        open
+    }
+
+    /// 把一条 AcpUpdate 折叠进 transcript
+    pub fn apply(&mut self, event: AcpUpdate) {
+        match event {
+            AcpUpdate::UserMessage { text, sender, .. } => {
+                let text = match sender {
+                    Some(s) => format!("[{}] {}", s, text),
+                    None => text,
+                };
+                self.push(ChatRole::User, text);
+                self.busy = true;
+            }
+            AcpUpdate::MessageChunk { text } => self.append(ChatRole::Agent, &text),
+            AcpUpdate::ThoughtChunk { text } => self.append(ChatRole::Thought, &text),
+            AcpUpdate::ToolCall { title, .. } => self.push(ChatRole::Tool, title),
+            AcpUpdate::ToolCallUpdate { status, .. } if status == "failed" => {
+                if let Some(last) = self
+                    .entries
+                    .iter_mut()
+                    .rev()
+                    .find(|e| e.role == ChatRole::Tool)
+                {
+                    last.text.push_str(" (failed)");
+                }
+            }
+            AcpUpdate::PermissionRequest {
+                description,
+                options,
+                ..
+            } => {
+                self.push(ChatRole::Permission, description.clone());
+                self.permission = Some(PendingPermission {
+                    description,
+                    options,
+                });
+            }
+            AcpUpdate::PermissionResponse { option_id, .. } => {
+                if let Some(p) = self.permission.take() {
+                    let name = p
+                        .options
+                        .iter()
+                        .find(|o| o.option_id == option_id)
+                        .map(|o| o.name.clone())
+                        .unwrap_or(option_id);
+                    self.push(ChatRole::System, format!("→ {}", name));
+                }
+            }
+            AcpUpdate::PlanUpdate { entries } => self.plan = entries,
+            AcpUpdate::ModeChanged { mode_id } => self.current_mode = Some(mode_id),
+            AcpUpdate::ModelChanged { model_id } => self.current_model = Some(model_id),
+            AcpUpdate::Complete { stop_reason, .. } => {
+                self.busy = false;
+                self.permission = None;
+                if stop_reason != "end_turn" {
+                    self.push(ChatRole::System, format!("turn ended: {}", stop_reason));
+                }
+            }
+            AcpUpdate::Error { message } => self.push(ChatRole::Error, message),
+            AcpUpdate::SessionEnded => {
+                self.busy = false;
+                self.push(ChatRole::System, "session ended".to_string());
+            }
+            _ => {}
+        }
+    }
+
+    fn push(&mut self, role: ChatRole, text: String) {
+        self.entries.push(ChatEntry { role, text });
+    }
+
+    /// 连续的同角色 chunk 拼进同一条
+    fn append(&mut self, role: ChatRole, text: &str) {
+        match self.entries.last_mut() {
+            Some(last) if last.role == role => last.text.push_str(text),
+            _ => self.push(role, text.to_string()),
+        }
+    }
+
+    fn apply_metadata(&mut self, meta: SessionMetadata) {
+        self.modes = meta.available_modes;
+        self.models = meta.available_models;
+        self.current_mode = meta.current_mode_id;
+        self.current_model = meta.current_model_id;
+    }
+
+    /// 读取新 append 的事件（节流）
+    fn poll_history(&mut self) {
+        if self.last_poll.elapsed() < POLL_INTERVAL {
+            return;
+        }
+        self.last_poll = Instant::now();
+        let batch = self.tail.poll();
+        if batch.reset {
+            self.entries.clear();
+            self.permission = None;
+            self.plan.clear();
+        }
+        for event in batch.events {
+            self.apply(event);
+        }
+    }
+
+    /// 后台向 owner 查询 busy / 队列，顺带刷新 host 与 session.json
+    fn poll_sync(&mut self, project_key: &str, task_id: &str) {
+        let result = self.sync_rx.as_ref().and_then(|rx| rx.try_recv().ok());
+        if let Some(sync) = result {
+            self.sync_rx = None;
+            self.host = sync.host;
+            match sync.status {
+                Ok(status) => {
+                    self.busy = status.busy;
+                    self.queue = status.queue;
+                }
+                Err(e) => tracing::debug!("chat status sync failed: {}", e),
+            }
+            if let Some(meta) = sync.metadata {
+                self.apply_metadata(meta);
+            }
+        }
+
+        if self.sync_rx.is_some() || self.last_sync.is_some_and(|t| t.elapsed() < SYNC_INTERVAL) {
+            return;
+        }
+        self.last_sync = Some(Instant::now());
+
+        let (tx, rx) = mpsc::channel();
+        self.sync_rx = Some(rx);
+        let project_key = project_key.to_string();
+        let task_id = task_id.to_string();
+        let chat_id = self.chat_id.clone();
+        std::thread::spawn(move || {
+            let client = ChatClient::discover(&project_key, &task_id, &chat_id);
+            let status = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| e.to_string())
+                .and_then(|rt| rt.block_on(client.status()).map_err(|e| e.to_string()));
+            let _ = tx.send(ChatSync {
+                host: client.host(),
+                status,
+                metadata: client.metadata(),
+            });
+        });
+    }
+
+    /// 下一条 prompt 要带上的 config（只有用户在 TUI 里切换过才非空）
+    fn pending_config(&self) -> Option<QueuedConfig> {
+        if self.pending_mode.is_none() && self.pending_model.is_none() {
+            return None;
+        }
+        Some(QueuedConfig {
+            mode: self.pending_mode.clone(),
+            model: self.pending_model.clone(),
+            thought_level: None,
+            thought_level_config_id: None,
+        })
+    }
+
+    /// 循环切换 mode（随下一条 prompt 生效）
+    pub fn cycle_mode(&mut self) {
+        let current = self.pending_mode.clone().or(self.current_mode.clone());
+        self.pending_mode = next_option(&self.modes, current.as_deref());
+    }
+
+    /// 循环切换 model（随下一条 prompt 生效）
+    pub fn cycle_model(&mut self) {
+        let current = self.pending_model.clone().or(self.current_model.clone());
+        self.pending_model = next_option(&self.models, current.as_deref());
+    }
+}
+
+/// `options` 中 `current` 之后的那个 id（到尾回绕）
+fn next_option(options: &[(String, String)], current: Option<&str>) -> Option<String> {
+    if options.is_empty() {
+        return None;
+    }
+    let next = current
+        .and_then(|c| options.iter().position(|(id, _)| id == c))
+        .map(|i| (i + 1) % options.len())
+        .unwrap_or(0);
+    Some(options[next].0.clone())
+}
+
+/// id → 显示名
+fn option_name<'a>(options: &'a [(String, String)], id: &'a str) -> &'a str {
+    options
+        .iter()
+        .find(|(oid, _)| oid == id)
+        .map(|(_, name)| name.as_str())
+        .unwrap_or(id)
+}
+
+/// Chat 面板数据
+#[derive(Debug)]
+pub struct ChatViewData {
+    pub project_key: String,
+    pub project_path: String,
+    pub project_name: String,
+    pub task: Task,
+    pub chats: Vec<ChatSession>,
+    /// 打开面板时探测的各 chat 持有者（与 `chats` 一一对应）
+    pub hosts: Vec<ChatHost>,
+    pub list_state: ListState,
+    pub open: Option<OpenChat>,
+    pub input: String,
+    /// 最近一次命令的错误
+    pub status: Option<String>,
+    /// 后台命令结果：Ok(发送后的 host) / Err(错误信息)
+    result_rx: Option<mpsc::Receiver<Result<ChatHost, String>>>,
+}
+
+impl ChatViewData {
+    pub fn new(project_key: &str, project_path: &str, project_name: &str, task: Task) -> Self {
+        let mut data = Self {
+            project_key: project_key.to_string(),
+            project_path: project_path.to_string(),
+            project_name: project_name.to_string(),
+            task,
+            chats: Vec::new(),
+            hosts: Vec::new(),
+            list_state: ListState::default(),
+            open: None,
+            input: String::new(),
+            status: None,
+            result_rx: None,
+        };
+        data.reload_chats();
+        data
+    }
+
+    fn reload_chats(&mut self) {
+        let mut chats: Vec<ChatSession> =
+            tasks::load_chat_sessions(&self.project_key, &self.task.id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|c| c.launch_mode != "terminal")
+                .collect();
+        chats.sort_by_key(|c| std::cmp::Reverse(c.created_at));
+        self.hosts = chats
+            .iter()
+            .map(|c| ChatClient::discover(&self.project_key, &self.task.id, &c.id).host())
+            .collect();
+        self.chats = chats;
+        self.list_state
+            .select((!self.chats.is_empty()).then_some(0));
+    }
+
+    pub fn select_next(&mut self) {
+        if self.chats.is_empty() {
+            return;
+        }
+        let i = self.list_state.selected().unwrap_or(0);
+        self.list_state
+            .select(Some((i + 1).min(self.chats.len() - 1)));
+    }
+
+    pub fn select_previous(&mut self) {
+        let i = self.list_state.selected().unwrap_or(0);
+        self.list_state.select(Some(i.saturating_sub(1)));
+    }
+
+    /// 打开选中的 chat
+    pub fn open_selected(&mut self) {
+        let Some(chat) = self.list_state.selected().and_then(|i| self.chats.get(i)) else {
+            return;
+        };
+        self.open = Some(OpenChat::new(&self.project_key, &self.task.id, chat));
+        self.input.clear();
+        self.status = None;
+    }
+
+    /// 用默认 agent 新建 chat 并打开（agent 在第一条 prompt 时启动）
+    pub fn create_chat(&mut self) {
+        let Some(agent) = client::default_agent() else {
+            self.status = Some("No ACP agent installed".to_string());
+            return;
+        };
+        match client::create_chat(&self.project_key, &self.task.id, &agent, None, None) {
+            Ok(chat) => {
+                let chat_id = chat.id.clone();
+                self.reload_chats();
+                if let Some(i) = self.chats.iter().position(|c| c.id == chat_id) {
+                    self.list_state.select(Some(i));
+                }
+                self.open_selected();
+            }
+            Err(e) => self.status = Some(format!("Failed to create chat: {}", e)),
+        }
+    }
+
+    /// 回到 chat 列表
+    pub fn close_chat(&mut self) {
+        self.open = None;
+        self.input.clear();
+        self.status = None;
+        self.reload_chats();
+    }
+
+    /// 主循环调用：刷新 transcript / owner 状态 + 收集后台命令结果
+    pub fn poll(&mut self) {
+        if let Some(ref mut open) = self.open {
+            open.poll_history();
+            open.poll_sync(&self.project_key, &self.task.id);
+        }
+        let result = self.result_rx.as_ref().and_then(|rx| rx.try_recv().ok());
+        if let Some(result) = result {
+            self.result_rx = None;
+            match result {
+                Ok(host) => {
+                    if let Some(ref mut open) = self.open {
+                        open.host = host;
+                    }
+                }
+                Err(e) => self.status = Some(e),
+            }
+        }
+    }
+
+    /// 发送输入框内容：agent 忙时进入队列，否则直接发送
+    pub fn submit_input(&mut self) {
+        let Some(ref mut open) = self.open else {
+            return;
+        };
+        let text = self.input.trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        self.input.clear();
+        let config = open.pending_config();
+        open.pending_mode = None;
+        open.pending_model = None;
+        let cmd = if open.busy {
+            SocketCommand::QueueMessage { text, config }
+        } else {
+            SocketCommand::Prompt {
+                text,
+                attachments: vec![],
+                sender: None,
+                config,
+            }
+        };
+        self.send(cmd);
+    }
+
+    /// 取消当前 turn
+    pub fn cancel_turn(&mut self) {
+        self.send(SocketCommand::Cancel);
+    }
+
+    /// 移除最后一条排队消息
+    pub fn drop_last_queued(&mut self) {
+        let Some(id) = self
+            .open
+            .as_ref()
+            .and_then(|o| o.queue.last())
+            .map(|m| m.id.clone())
+        else {
+            return;
+        };
+        self.send(SocketCommand::DequeueMessage { id });
+    }
+
+    /// 清空队列
+    pub fn clear_queue(&mut self) {
+        if self.open.as_ref().is_some_and(|o| !o.queue.is_empty()) {
+            self.send(SocketCommand::ClearQueue);
+        }
+    }
+
+    /// 选择第 `index` 个权限选项（0-based）
+    pub fn respond_permission(&mut self, index: usize) {
+        let Some(option_id) = self
+            .open
+            .as_ref()
+            .and_then(|o| o.permission.as_ref())
+            .and_then(|p| p.options.get(index))
+            .map(|o| o.option_id.clone())
+        else {
+            return;
+        };
+        self.send(SocketCommand::RespondPermission { option_id });
+    }
+
+    /// 后台线程：连接（必要时启动）session 并发送命令
+    fn send(&mut self, cmd: SocketCommand) {
+        let Some(ref mut open) = self.open else {
+            return;
+        };
+        if self.result_rx.is_some() {
+            self.status = Some("Previous command still in flight".to_string());
+            return;
+        }
+        self.status = None;
+        // 命令发出后尽快同步一次队列 / busy
+        open.last_sync = None;
+
+        let (tx, rx) = mpsc::channel();
+        self.result_rx = Some(rx);
+        let project_key = self.project_key.clone();
+        let project_path = self.project_path.clone();
+        let project_name = self.project_name.clone();
+        let task = self.task.clone();
+        let chat_id = open.chat_id.clone();
+
+        std::thread::spawn(move || {
+            let result = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| e.to_string())
+                .and_then(|rt| {
+                    rt.block_on(async {
+                        let client = ChatClient::connect(
+                            &project_key,
+                            &project_path,
+                            &project_name,
+                            &task,
+                            &chat_id,
+                        )
+                        .await?;
+                        client.send(cmd).await?;
+                        Ok::<_, crate::error::GroveError>(client.host())
+                    })
+                    .map_err(|e| e.to_string())
+                });
+            let _ = tx.send(result);
+        });
+    }
+
+    pub fn scroll_up(&mut self, lines: usize) {
+        if let Some(ref mut open) = self.open {
+            open.scroll_from_bottom += lines;
+        }
+    }
+
+    pub fn scroll_down(&mut self, lines: usize) {
+        if let Some(ref mut open) = self.open {
+            open.scroll_from_bottom = open.scroll_from_bottom.saturating_sub(lines);
+        }
+    }
+}
+
+/// 渲染 Chat 面板：列表为居中弹窗，打开 chat 后占满整个终端
+pub fn render(frame: &mut Frame, data: &mut ChatViewData, colors: &ThemeColors) {
+    let area = frame.area();
+    let panel_area = if data.open.is_some() {
+        area
+    } else {
+        let width = (area.width * 9 / 10).max(40).min(area.width);
+        let height = (area.height * 4 / 5).max(12).min(area.height);
+        Rect::new(
+            (area.width.saturating_sub(width)) / 2,
+            (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        )
+    };
+
+    frame.render_widget(Clear, panel_area);
+
+    match data.open {
+        Some(ref open) => render_chat(frame, panel_area, data, open, colors),
+        None => render_list(frame, panel_area, data, colors),
+    }
+}
+
+fn panel_block<'a>(title: String, hint: &'a str, colors: &ThemeColors) -> Block<'a> {
+    Block::default()
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            hint,
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg))
+}
+
+fn render_list(frame: &mut Frame, area: Rect, data: &mut ChatViewData, colors: &ThemeColors) {
+    let block = panel_block(
+        format!(" Chats · {} ", data.task.name),
+        " j/k select · Enter open · n new chat · Esc close ",
+        colors,
+    );
+
+    if data.chats.is_empty() {
+        let mut lines = vec![Line::from(Span::styled(
+            "No agent chats for this task yet. Press n to start one.",
+            Style::default().fg(colors.muted),
+        ))];
+        if let Some(ref status) = data.status {
+            lines.push(Line::from(Span::styled(
+                status.as_str(),
+                Style::default().fg(colors.error),
+            )));
+        }
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+        return;
+    }
+
+    let items: Vec<ListItem> = data
+        .chats
+        .iter()
+        .zip(&data.hosts)
+        .map(|(chat, host)| {
+            let (marker, marker_color) = match host {
+                ChatHost::Detached => ("○", colors.muted),
+                _ => ("●", colors.status_live),
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(format!(" {} ", marker), Style::default().fg(marker_color)),
+                Span::styled(chat.title.clone(), Style::default().fg(colors.text)),
+                Span::styled(
+                    format!(
+                        "  {} · {}",
+                        chat.agent,
+                        chat.created_at.format("%Y-%m-%d %H:%M")
+                    ),
+                    Style::default().fg(colors.muted),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(colors.bg_secondary));
+    frame.render_stateful_widget(list, area, &mut data.list_state);
+}
+
+fn render_chat(
+    frame: &mut Frame,
+    area: Rect,
+    data: &ChatViewData,
+    open: &OpenChat,
+    colors: &ThemeColors,
+) {
+    let host_label = match open.host {
+        ChatHost::Local => "this process",
+        ChatHost::Remote => "attached",
+        ChatHost::Detached => "not running",
+    };
+    let hint = if open.permission.is_some() && data.input.is_empty() {
+        " 1-9 answer permission · Ctrl-C cancel turn · Esc back "
+    } else {
+        " Enter send/queue · Tab mode · Shift-Tab model · Ctrl-D/Ctrl-X unqueue/clear · Ctrl-C cancel · Esc back "
+    };
+    let block = panel_block(
+        format!(" {} · {} · {} ", open.title, open.agent, host_label),
+        hint,
+        colors,
+    );
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let prompt_height = open
+        .permission
+        .as_ref()
+        .map(|p| p.options.len() as u16 + 1)
+        .unwrap_or(0);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),
+            Constraint::Length(prompt_height),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    // Transcript | Plan + Queue
+    let has_side = !open.plan.is_empty() || !open.queue.is_empty();
+    let cols = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(if has_side {
+            [Constraint::Percentage(70), Constraint::Percentage(30)]
+        } else {
+            [Constraint::Percentage(100), Constraint::Percentage(0)]
+        })
+        .split(rows[0]);
+    render_transcript(frame, cols[0], open, colors);
+    if has_side {
+        render_side(frame, cols[1], open, colors);
+    }
+
+    let width = inner.width.max(1) as usize;
+
+    // Permission options
+    if let Some(ref permission) = open.permission {
+        let mut lines = vec![Line::from(Span::styled(
+            super::truncate(&permission.description, width),
+            Style::default()
+                .fg(colors.warning)
+                .add_modifier(Modifier::BOLD),
+        ))];
+        for (i, option) in permission.options.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!(" {} ", i + 1),
+                    Style::default().fg(colors.highlight),
+                ),
+                Span::styled(option.name.clone(), Style::default().fg(colors.text)),
+            ]));
+        }
+        frame.render_widget(Paragraph::new(lines), rows[1]);
+    }
+
+    // Mode / model
+    let mut settings = Vec::new();
+    for (label, options, current, pending) in [
+        ("mode", &open.modes, &open.current_mode, &open.pending_mode),
+        (
+            "model",
+            &open.models,
+            &open.current_model,
+            &open.pending_model,
+        ),
+    ] {
+        let Some(id) = pending.as_ref().or(current.as_ref()) else {
+            continue;
+        };
+        settings.push(Span::styled(
+            format!("{}: ", label),
+            Style::default().fg(colors.muted),
+        ));
+        let mut name = option_name(options, id).to_string();
+        if pending.is_some() {
+            name.push('*');
+        }
+        settings.push(Span::styled(
+            format!("{}  ", name),
+            Style::default().fg(colors.text),
+        ));
+    }
+    if open.busy {
+        settings.push(Span::styled(
+            "working…",
+            Style::default().fg(colors.status_live),
+        ));
+    }
+    frame.render_widget(Paragraph::new(Line::from(settings)), rows[2]);
+
+    // Status
+    if let Some(ref status) = data.status {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                super::truncate(status, width),
+                Style::default().fg(colors.error),
+            ))),
+            rows[3],
+        );
+    }
+
+    // Input
+    let prefix = if open.busy { "queue> " } else { "> " };
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled(prefix, Style::default().fg(colors.muted)),
+            Span::styled(data.input.as_str(), Style::default().fg(colors.text)),
+            Span::styled("█", Style::default().fg(colors.highlight)),
+        ])),
+        rows[4],
+    );
+}
+
+fn render_transcript(frame: &mut Frame, area: Rect, open: &OpenChat, colors: &ThemeColors) {
+    let width = area.width.max(1) as usize;
+    let mut lines: Vec<Line> = Vec::new();
+    for entry in &open.entries {
+        let (label, color) = match entry.role {
+            ChatRole::User => ("you", colors.highlight),
+            ChatRole::Agent => ("agent", colors.text),
+            ChatRole::Thought => ("thinking", colors.muted),
+            ChatRole::Tool => ("tool", colors.info),
+            ChatRole::Permission => ("permission", colors.warning),
+            ChatRole::System => ("", colors.muted),
+            ChatRole::Error => ("error", colors.error),
+        };
+        if !label.is_empty() {
+            lines.push(Line::from(Span::styled(
+                label,
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )));
+        }
+        let text_color = match entry.role {
+            ChatRole::Agent | ChatRole::User => colors.text,
+            _ => color,
+        };
+        for row in wrap(&entry.text, width) {
+            lines.push(Line::from(Span::styled(
+                row,
+                Style::default().fg(text_color),
+            )));
+        }
+    }
+    let visible = area.height as usize;
+    let max_scroll = lines.len().saturating_sub(visible);
+    let end = lines.len() - open.scroll_from_bottom.min(max_scroll);
+    let start = end.saturating_sub(visible);
+    frame.render_widget(Paragraph::new(lines[start..end].to_vec()), area);
+}
+
+fn render_side(frame: &mut Frame, area: Rect, open: &OpenChat, colors: &ThemeColors) {
+    let width = area.width.saturating_sub(4) as usize;
+    let mut lines = Vec::new();
+    if !open.plan.is_empty() {
+        let done = open.plan.iter().filter(|e| e.status == "completed").count();
+        lines.push(Line::from(Span::styled(
+            format!("Plan {}/{}", done, open.plan.len()),
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for entry in &open.plan {
+            let (marker, color) = match entry.status.as_str() {
+                "completed" => ("✓", colors.status_merged),
+                "in_progress" => ("▸", colors.status_live),
+                _ => ("·", colors.muted),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", marker), Style::default().fg(color)),
+                Span::styled(
+                    super::truncate(&entry.content, width.max(2)),
+                    Style::default().fg(colors.text),
+                ),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+    if !open.queue.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Queue ({})", open.queue.len()),
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for (i, msg) in open.queue.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{} ", i + 1), Style::default().fg(colors.muted)),
+                Span::styled(
+                    super::truncate(msg.text.lines().next().unwrap_or(""), width.max(2)),
+                    Style::default().fg(colors.text),
+                ),
+            ]));
+        }
+    }
+    let block = Block::default()
+        .borders(Borders::LEFT)
+        .border_style(Style::default().fg(colors.border));
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// 按字符数硬换行（保留原有换行）
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut rows = Vec::new();
+    for line in text.lines() {
+        let chars: Vec<char> = line.chars().collect();
+        if chars.is_empty() {
+            rows.push(String::new());
+            continue;
+        }
+        for chunk in chars.chunks(width.max(1)) {
+            rows.push(chunk.iter().collect());
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn open_chat() -> OpenChat {
+        OpenChat {
+            chat_id: "chat-1".into(),
+            title: "t".into(),
+            agent: "claude".into(),
+            host: ChatHost::Detached,
+            entries: Vec::new(),
+            permission: None,
+            plan: Vec::new(),
+            queue: Vec::new(),
+            busy: false,
+            modes: Vec::new(),
+            models: Vec::new(),
+            current_mode: None,
+            current_model: None,
+            pending_mode: None,
+            pending_model: None,
+            scroll_from_bottom: 0,
+            tail: HistoryTail::new(std::path::PathBuf::from("/nonexistent")),
+            last_poll: Instant::now(),
+            last_sync: None,
+            sync_rx: None,
+        }
+    }
+
+    #[test]
+    fn message_chunks_merge_into_one_agent_entry() {
+        let mut chat = open_chat();
+        chat.apply(AcpUpdate::UserMessage {
+            text: "hi".into(),
+            attachments: vec![],
+            sender: None,
+            terminal: false,
+        });
+        chat.apply(AcpUpdate::ThoughtChunk { text: "hmm".into() });
+        chat.apply(AcpUpdate::MessageChunk { text: "Hel".into() });
+        chat.apply(AcpUpdate::MessageChunk { text: "lo".into() });
+        assert_eq!(chat.entries.len(), 3);
+        assert_eq!(chat.entries[1].role, ChatRole::Thought);
+        assert_eq!(chat.entries[2].text, "Hello");
+        assert!(chat.busy);
+    }
+
+    #[test]
+    fn permission_is_pending_until_response() {
+        let mut chat = open_chat();
+        chat.apply(AcpUpdate::PermissionRequest {
+            id: "tc-1".into(),
+            description: "Run ls".into(),
+            options: vec![PermOptionData {
+                option_id: "allow".into(),
+                name: "Allow".into(),
+                kind: "allow_once".into(),
+            }],
+        });
+        assert!(chat.permission.is_some());
+        chat.apply(AcpUpdate::PermissionResponse {
+            id: "tc-1".into(),
+            option_id: "allow".into(),
+        });
+        assert!(chat.permission.is_none());
+        assert_eq!(chat.entries.last().unwrap().text, "→ Allow");
+    }
+
+    #[test]
+    fn mode_cycles_and_rides_on_next_prompt() {
+        let mut chat = open_chat();
+        assert!(chat.pending_config().is_none());
+        chat.modes = vec![
+            ("default".into(), "Default".into()),
+            ("plan".into(), "Plan".into()),
+        ];
+        chat.current_mode = Some("default".into());
+        chat.cycle_mode();
+        assert_eq!(chat.pending_mode.as_deref(), Some("plan"));
+        chat.cycle_mode();
+        assert_eq!(chat.pending_mode.as_deref(), Some("default"));
+        let config = chat.pending_config().unwrap();
+        assert_eq!(config.mode.as_deref(), Some("default"));
+        assert!(config.model.is_none());
+    }
+
+    #[test]
+    fn wrap_splits_long_lines() {
+        assert_eq!(wrap("abcde\nf", 2), vec!["ab", "cd", "e", "f"]);
+    }
+}
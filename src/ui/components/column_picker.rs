@@ -0,0 +1,161 @@
+//! 任务列表列选择器（选择显示哪些列及其顺序）
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::storage::config::TaskColumn;
+use crate::theme::ThemeColors;
+
+/// 选择器数据
+#[derive(Debug, Clone, Default)]
+pub struct ColumnPickerData {
+    /// 全部可选列（已启用的按配置顺序在前）及是否启用
+    pub items: Vec<(TaskColumn, bool)>,
+    pub selected: usize,
+}
+
+impl ColumnPickerData {
+    pub fn new(columns: &[TaskColumn]) -> Self {
+        let mut items: Vec<(TaskColumn, bool)> = columns.iter().map(|&c| (c, true)).collect();
+        items.extend(
+            TaskColumn::ALL
+                .iter()
+                .filter(|c| !columns.contains(c))
+                .map(|&c| (c, false)),
+        );
+        Self { items, selected: 0 }
+    }
+
+    /// 启用的列（按当前顺序）
+    pub fn columns(&self) -> Vec<TaskColumn> {
+        self.items
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(c, _)| *c)
+            .collect()
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.items.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn toggle(&mut self) {
+        if let Some(item) = self.items.get_mut(self.selected) {
+            item.1 = !item.1;
+        }
+    }
+
+    /// 选中列下移一位
+    pub fn move_down(&mut self) {
+        if self.selected + 1 < self.items.len() {
+            self.items.swap(self.selected, self.selected + 1);
+            self.selected += 1;
+        }
+    }
+
+    /// 选中列上移一位
+    pub fn move_up(&mut self) {
+        if self.selected > 0 {
+            self.items.swap(self.selected, self.selected - 1);
+            self.selected -= 1;
+        }
+    }
+}
+
+/// 渲染列选择器弹窗
+pub fn render(frame: &mut Frame, data: &ColumnPickerData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = 52u16.min(area.width);
+    let height = (data.items.len() as u16 + 4).min(area.height);
+    let popup_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Task Columns ")
+        .title_alignment(Alignment::Center)
+        .title_bottom(Line::from(Span::styled(
+            " Space toggle · J/K move · Enter save · Esc ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.highlight))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let lines: Vec<Line> = data
+        .items
+        .iter()
+        .enumerate()
+        .map(|(i, (column, enabled))| {
+            let is_selected = i == data.selected;
+            let prefix = if is_selected { "❯ " } else { "  " };
+            let check = if *enabled { "[x] " } else { "[ ] " };
+            let name_style = if is_selected {
+                Style::default()
+                    .fg(colors.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else if *enabled {
+                Style::default().fg(colors.text)
+            } else {
+                Style::default().fg(colors.muted)
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{}{}{:<22}", prefix, check, column.label()),
+                    name_style,
+                ),
+                Span::styled(column.header(), Style::default().fg(colors.muted)),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_and_reorder_columns() {
+        let mut data = ColumnPickerData::new(&[TaskColumn::Status, TaskColumn::Updated]);
+        assert_eq!(data.items.len(), TaskColumn::ALL.len());
+        assert_eq!(
+            data.columns(),
+            vec![TaskColumn::Status, TaskColumn::Updated]
+        );
+
+        data.move_down();
+        assert_eq!(data.selected, 1);
+        assert_eq!(
+            data.columns(),
+            vec![TaskColumn::Updated, TaskColumn::Status]
+        );
+
+        data.select_next();
+        data.toggle();
+        data.move_up();
+        assert_eq!(
+            data.columns(),
+            vec![TaskColumn::Updated, TaskColumn::Target, TaskColumn::Status]
+        );
+        assert_eq!(data.selected, 1);
+    }
+}
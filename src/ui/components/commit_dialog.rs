@@ -16,6 +16,8 @@ use crate::ui::click_areas::{ClickAreas, DialogAction};
 pub struct CommitDialogData {
     /// commit message 输入
     pub message: String,
+    /// 任务 ID（用于解析任务级 git 身份）
+    pub task_id: String,
     /// 任务名称（用于显示）
     pub task_name: String,
     /// worktree 路径
@@ -23,9 +25,10 @@ pub struct CommitDialogData {
 }
 
 impl CommitDialogData {
-    pub fn new(task_name: String, worktree_path: String) -> Self {
+    pub fn new(task_id: String, task_name: String, worktree_path: String) -> Self {
         Self {
             message: String::new(),
+            task_id,
             task_name,
             worktree_path,
         }
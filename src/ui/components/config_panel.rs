@@ -1,5 +1,7 @@
 //! Config 配置面板组件（包含 Coding Agent / Task Layout / Hook Config 子页面）
 
+use std::collections::HashMap;
+
 use ratatui::{
     layout::{Alignment, Constraint, Layout, Rect},
     style::{Modifier, Style},
@@ -8,7 +10,9 @@ use ratatui::{
     Frame,
 };
 
-use crate::storage::config::{AutoLinkConfig, LayoutConfig, TerminalMultiplexer};
+use crate::storage::config::{
+    AutoLinkConfig, LayoutConfig, LinkMode, StatusPalette, TerminalMultiplexer, TimeFormat,
+};
 use crate::theme::ThemeColors;
 use crate::tmux::layout::{LayoutNode, PathSegment, SplitDirection, TaskLayout};
 use crate::ui::click_areas::{ClickAreas, DialogAction};
@@ -18,7 +22,7 @@ use super::hook_panel::HookConfigData;
 /// 配置面板步骤
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConfigStep {
-    /// 主菜单 (0=Coding Agent, 1=Task Layout, 2=Multiplexer, 3=AutoLink, 4=Hook Config, 5=MCP Config)
+    /// 主菜单 (0=Coding Agent, 1=Task Layout, 2=Multiplexer, 3=AutoLink, 4=Hook Config, 5=MCP Config, 6=Time Display, 7=Accessibility, 8=Status Colors)
     Main,
     /// 编辑 agent 命令（文本输入）
     EditAgentCommand,
@@ -44,7 +48,7 @@ pub enum ConfigStep {
 #[derive(Debug, Clone)]
 pub struct ConfigPanelData {
     pub step: ConfigStep,
-    /// 主菜单选中项 (0=Coding Agent, 1=Task Layout, 2=Multiplexer, 3=AutoLink, 4=Hook Config, 5=MCP Config)
+    /// 主菜单选中项 (0=Coding Agent, 1=Task Layout, 2=Multiplexer, 3=AutoLink, 4=Hook Config, 5=MCP Config, 6=Time Display, 7=Accessibility, 8=Status Colors)
     pub main_selected: usize,
     /// Multiplexer 选中项 (0=tmux, 1=zellij)
     pub multiplexer_selected: usize,
@@ -58,6 +62,8 @@ pub struct ConfigPanelData {
     pub hook_data: HookConfigData,
     /// AutoLink patterns (glob patterns list)
     pub autolink_patterns: Vec<String>,
+    /// AutoLink: per-pattern link mode (patterns not listed use symlink)
+    pub autolink_modes: HashMap<String, LinkMode>,
     /// AutoLink patterns selected index
     pub autolink_selected: usize,
     /// AutoLink pattern input buffer (for adding/editing)
@@ -76,6 +82,12 @@ pub struct ConfigPanelData {
     pub custom_cmd_input: String,
     /// Custom layout: 自定义命令光标
     pub custom_cmd_cursor: usize,
+    /// 时间显示方式（Enter 直接切换）
+    pub time_format: TimeFormat,
+    /// 减少装饰模式（Enter 直接切换）
+    pub reduced_decoration: bool,
+    /// 状态色方案（Enter 循环切换）
+    pub status_palette: StatusPalette,
 }
 
 impl ConfigPanelData {
@@ -113,6 +125,7 @@ impl ConfigPanelData {
             agent_cursor: agent_input.len(),
             hook_data: HookConfigData::new(),
             autolink_patterns: autolink.patterns.clone(),
+            autolink_modes: autolink.modes.clone(),
             autolink_selected: 0,
             autolink_input: String::new(),
             autolink_cursor: 0,
@@ -122,13 +135,16 @@ impl ConfigPanelData {
             custom_choose_selected: 3, // default to Agent
             custom_cmd_input: String::new(),
             custom_cmd_cursor: 0,
+            time_format: TimeFormat::default(),
+            reduced_decoration: false,
+            status_palette: StatusPalette::default(),
         }
     }
 }
 
 /// 弹窗尺寸
 const DIALOG_WIDTH: u16 = 50;
-const DIALOG_HEIGHT_MAIN: u16 = 12;
+const DIALOG_HEIGHT_MAIN: u16 = 15;
 const DIALOG_HEIGHT_AGENT_CMD: u16 = 11;
 const DIALOG_HEIGHT_LAYOUT: u16 = 15;
 const DIALOG_HEIGHT_MCP: u16 = 15;
@@ -201,7 +217,7 @@ fn render_main(
     ])
     .areas(inner_area);
 
-    // 菜单项（6 项）
+    // 菜单项（9 项）
     let agent_value = config
         .agent_command
         .as_deref()
@@ -220,6 +236,10 @@ fn render_main(
     } else {
         &format!("{} patterns", data.autolink_patterns.len())
     };
+    let time_value = match data.time_format {
+        TimeFormat::Relative => "Relative",
+        TimeFormat::Absolute => "Absolute",
+    };
 
     let items: Vec<(&str, &str)> = vec![
         ("Coding Agent", agent_value),
@@ -228,6 +248,16 @@ fn render_main(
         ("AutoLink", autolink_value),
         ("Hook Config", ""),
         ("MCP Server", ""),
+        ("Time Display", time_value),
+        (
+            "Accessibility",
+            if data.reduced_decoration {
+                "Reduced decoration on"
+            } else {
+                "Reduced decoration off"
+            },
+        ),
+        ("Status Colors", data.status_palette.label()),
     ];
 
     let mut lines: Vec<Line> = Vec::new();
@@ -681,10 +711,18 @@ fn render_autolink_config(
                 Style::default().fg(colors.text)
             };
 
-            lines.push(Line::from(vec![
-                Span::styled(prefix, style),
-                Span::styled(pattern, style),
-            ]));
+            let mut spans = vec![Span::styled(prefix, style), Span::styled(pattern, style)];
+            if let Some(mode) = data
+                .autolink_modes
+                .get(pattern)
+                .filter(|m| **m != LinkMode::Symlink)
+            {
+                spans.push(Span::styled(
+                    format!("  [{}]", mode.as_str()),
+                    Style::default().fg(colors.muted),
+                ));
+            }
+            lines.push(Line::from(spans));
         }
     }
 
@@ -693,7 +731,7 @@ fn render_autolink_config(
     render_hint(
         frame,
         hint_area,
-        "\u{2191}\u{2193} select   a add   e edit   d delete   Esc back",
+        "\u{2191}\u{2193} select   a add   e edit   m mode   d delete   Esc back",
         colors,
     );
 
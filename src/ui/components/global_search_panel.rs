@@ -0,0 +1,210 @@
+//! 全局搜索面板（任务名、notes、chat 历史、review 评论）
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::search::{self, HitKind, SearchHit, SearchQuery, SearchResults};
+use crate::theme::ThemeColors;
+
+/// 全局搜索面板数据
+#[derive(Debug, Clone, Default)]
+pub struct GlobalSearchData {
+    /// 当前输入
+    pub query: String,
+    /// 上次执行的查询（与 query 不同时 Enter 重新搜索）
+    pub searched: String,
+    /// 搜索结果（尚未搜索时为 None）
+    pub results: Option<SearchResults>,
+    pub selected: usize,
+}
+
+impl GlobalSearchData {
+    /// 执行搜索
+    pub fn run(&mut self) {
+        let text = self.query.trim().to_string();
+        self.searched = self.query.clone();
+        self.selected = 0;
+        if text.is_empty() {
+            self.results = None;
+            return;
+        }
+        self.results = Some(search::search(&SearchQuery {
+            text,
+            ..Default::default()
+        }));
+    }
+
+    /// 输入已变化，需要重新搜索
+    pub fn is_stale(&self) -> bool {
+        self.results.is_none() || self.query != self.searched
+    }
+
+    pub fn hits(&self) -> &[SearchHit] {
+        self.results
+            .as_ref()
+            .map(|r| r.hits.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.hits().len();
+        if len > 0 {
+            self.selected = (self.selected + 1).min(len - 1);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_hit(&self) -> Option<&SearchHit> {
+        self.hits().get(self.selected)
+    }
+}
+
+fn kind_style(kind: HitKind, colors: &ThemeColors) -> Style {
+    match kind {
+        HitKind::Task => Style::default().fg(colors.highlight),
+        HitKind::Note | HitKind::ProjectNote => Style::default().fg(colors.info),
+        HitKind::Chat => Style::default().fg(colors.text),
+        HitKind::Comment => Style::default().fg(colors.warning),
+    }
+}
+
+/// 命中位置：项目 / 任务
+fn location(hit: &SearchHit) -> String {
+    match hit.task_name {
+        Some(ref task) if hit.archived => format!("{} / {} (archived)", hit.project_name, task),
+        Some(ref task) => format!("{} / {}", hit.project_name, task),
+        None => hit.project_name.clone(),
+    }
+}
+
+/// 渲染全局搜索：输入行、结果列表、选中项摘录
+pub fn render(frame: &mut Frame, data: &GlobalSearchData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = (area.width * 4 / 5).max(40).min(area.width);
+    let height = (area.height * 4 / 5).max(12).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let title = match data.results {
+        Some(ref r) if r.truncated => format!(" Search ({}+) ", r.hits.len()),
+        Some(ref r) => format!(" Search ({}) ", r.hits.len()),
+        None => " Search ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            " type to search · Enter search/jump · ↑/↓ select · Esc close ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(panel_area);
+    frame.render_widget(block, panel_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),
+            Constraint::Min(3),
+            Constraint::Length(5),
+        ])
+        .split(inner);
+
+    let input = Paragraph::new(Line::from(vec![
+        Span::styled("/ ", Style::default().fg(colors.highlight)),
+        Span::styled(data.query.clone(), Style::default().fg(colors.text)),
+        Span::styled("▏", Style::default().fg(colors.highlight)),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::BOTTOM)
+            .border_style(Style::default().fg(colors.border)),
+    );
+    frame.render_widget(input, chunks[0]);
+
+    let hits = data.hits();
+    if hits.is_empty() {
+        let message = if data.results.is_some() {
+            "No matches."
+        } else {
+            "Search task names, notes, chat history and review comments."
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                message,
+                Style::default().fg(colors.muted),
+            ))),
+            chunks[1],
+        );
+        return;
+    }
+
+    let title_width = (chunks[1].width as usize).saturating_sub(50).max(10);
+    let items: Vec<ListItem> = hits
+        .iter()
+        .map(|hit| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<13} ", hit.kind.label()),
+                    kind_style(hit.kind, colors).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!("{:<34} ", super::truncate(&location(hit), 34)),
+                    Style::default().fg(colors.muted),
+                ),
+                Span::styled(
+                    super::truncate(&hit.title, title_width),
+                    Style::default().fg(colors.text),
+                ),
+            ]))
+        })
+        .collect();
+
+    let selected = data.selected.min(hits.len() - 1);
+    let mut state = ListState::default();
+    state.select(Some(selected));
+    frame.render_stateful_widget(
+        List::new(items).highlight_style(Style::default().bg(colors.bg_secondary)),
+        chunks[1],
+        &mut state,
+    );
+
+    // 选中项摘录
+    let hit = &hits[selected];
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{} · {}", location(hit), hit.title),
+        Style::default().fg(colors.muted),
+    ))];
+    if !hit.snippet.is_empty() {
+        lines.push(Line::from(Span::styled(
+            hit.snippet.clone(),
+            Style::default().fg(colors.text),
+        )));
+    }
+    let detail = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(colors.border)),
+    );
+    frame.render_widget(detail, chunks[2]);
+}
@@ -14,7 +14,7 @@ use crate::update::UpdateInfo;
 /// 帮助面板宽度
 const PANEL_WIDTH: u16 = 38;
 /// 帮助面板高度（增加版本信息区域）
-const PANEL_HEIGHT: u16 = 42;
+const PANEL_HEIGHT: u16 = 50;
 
 /// 渲染帮助面板
 pub fn render(frame: &mut Frame, colors: &ThemeColors, update_info: Option<&UpdateInfo>) {
@@ -59,7 +59,7 @@ fn build_help_lines(colors: &ThemeColors, update_info: Option<&UpdateInfo>) -> V
         section_header("Navigation", colors),
         key_line("j / ↓", "Move down", colors),
         key_line("k / ↑", "Move up", colors),
-        key_line("Tab", "Toggle info panel", colors),
+        key_line("Tab", "Info panel / All Tasks (home)", colors),
         key_line("← / →", "Switch tab", colors),
         key_line("1 / 2 / 3", "Tab / sub-tab", colors),
         key_line("Enter", "Enter worktree", colors),
@@ -78,6 +78,11 @@ fn build_help_lines(colors: &ThemeColors, update_info: Option<&UpdateInfo>) -> V
         section_header("Actions", colors),
         key_line("n", "New task", colors),
         key_line("Space", "Action palette", colors),
+        key_line("a", "Agent chats (ACP)", colors),
+        key_line("p", "Pin / unpin task or project", colors),
+        key_line("s", "Cycle sort (activity/diff/status)", colors),
+        key_line("v", "Cycle saved views", colors),
+        key_line("V", "Task list columns", colors),
         Line::from(""),
         // Archived Tasks 分组
         section_header("Archived Tasks", colors),
@@ -89,11 +94,22 @@ fn build_help_lines(colors: &ThemeColors, update_info: Option<&UpdateInfo>) -> V
         key_line("/", "Start search", colors),
         key_line("Enter", "Confirm search", colors),
         key_line("Esc", "Clear search", colors),
+        key_line("/", "Global search (All Tasks tab)", colors),
+        Line::from(""),
+        // Monitor 分组
+        section_header("Monitor", colors),
+        key_line("f", "Follow most active task", colors),
+        key_line("p", "Pin current task", colors),
+        key_line("g", "Multi-task preview grid", colors),
         Line::from(""),
         // Other 分组
         section_header("Other", colors),
         key_line("c", "Config panel", colors),
         key_line("t", "Theme selector", colors),
+        key_line("L", "Recent logs", colors),
+        key_line("m", "Notifications", colors),
+        key_line("M", "Do Not Disturb", colors),
+        key_line("I", "Inbox", colors),
         key_line("?", "This help", colors),
         key_line("q", "Quit", colors),
     ];
@@ -0,0 +1,152 @@
+//! Inbox 面板（所有等待用户处理的事项）
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::operations::inbox::{self, InboxItem, InboxKind};
+use crate::theme::ThemeColors;
+
+/// Inbox 面板数据
+#[derive(Debug, Clone, Default)]
+pub struct InboxPanelData {
+    pub items: Vec<InboxItem>,
+    pub selected: usize,
+}
+
+impl InboxPanelData {
+    /// 收集所有项目的待办事项
+    pub fn load() -> Self {
+        Self {
+            items: inbox::collect_inbox(),
+            selected: 0,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if !self.items.is_empty() {
+            self.selected = (self.selected + 1).min(self.items.len() - 1);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_item(&self) -> Option<&InboxItem> {
+        self.items.get(self.selected)
+    }
+}
+
+fn kind_style(kind: &InboxKind, colors: &ThemeColors) -> Style {
+    match kind {
+        InboxKind::Permission { .. } | InboxKind::Handback => Style::default().fg(colors.warning),
+        InboxKind::ReviewReply { .. } => Style::default().fg(colors.info),
+        InboxKind::Conflict | InboxKind::MergeFailed { .. } => Style::default().fg(colors.error),
+    }
+}
+
+/// 渲染 Inbox：上方列表，下方选中项的完整内容
+pub fn render(frame: &mut Frame, data: &InboxPanelData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = (area.width * 4 / 5).max(40).min(area.width);
+    let height = (area.height * 4 / 5).max(12).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let block = Block::default()
+        .title(format!(" Inbox ({}) ", data.items.len()))
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            " j/k select · Enter jump · r refresh · Esc close ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(panel_area);
+    frame.render_widget(block, panel_area);
+
+    if data.items.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "Nothing is waiting on you.",
+                Style::default().fg(colors.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(6)])
+        .split(inner);
+
+    let summary_width = (chunks[0].width as usize).saturating_sub(45).max(10);
+    let items: Vec<ListItem> = data
+        .items
+        .iter()
+        .map(|item| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<12} ", item.kind_label()),
+                    kind_style(&item.kind, colors).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(
+                        "{:<30} ",
+                        super::truncate(&format!("{} / {}", item.project_name, item.task_name), 30)
+                    ),
+                    Style::default().fg(colors.muted),
+                ),
+                Span::styled(
+                    super::truncate(item.summary().lines().next().unwrap_or(""), summary_width),
+                    Style::default().fg(colors.text),
+                ),
+            ]))
+        })
+        .collect();
+
+    let selected = data.selected.min(data.items.len() - 1);
+    let mut state = ListState::default();
+    state.select(Some(selected));
+    frame.render_stateful_widget(
+        List::new(items).highlight_style(Style::default().bg(colors.bg_secondary)),
+        chunks[0],
+        &mut state,
+    );
+
+    // 选中项详情
+    let item = &data.items[selected];
+    let mut lines = vec![Line::from(Span::styled(
+        format!("{} · {}", item.project_name, item.task_name),
+        Style::default().fg(colors.muted),
+    ))];
+    lines.extend(item.summary().lines().map(|l| {
+        Line::from(Span::styled(
+            l.to_string(),
+            Style::default().fg(colors.text),
+        ))
+    }));
+    let detail = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(colors.border)),
+    );
+    frame.render_widget(detail, chunks[1]);
+}
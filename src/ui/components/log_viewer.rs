@@ -0,0 +1,124 @@
+//! 日志查看器（最近的 tracing 日志条目）
+
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::logging::{self, LogEntry};
+use crate::theme::ThemeColors;
+
+/// 打开时加载的最大条目数
+const MAX_ENTRIES: usize = 500;
+
+/// 日志查看器数据（打开时快照，`r` 刷新）
+#[derive(Debug, Clone)]
+pub struct LogViewerData {
+    pub entries: Vec<LogEntry>,
+    /// 距底部的行数（0 = 最新一条在底部）
+    pub scroll_from_bottom: usize,
+}
+
+impl LogViewerData {
+    pub fn load() -> Self {
+        Self {
+            entries: logging::recent(MAX_ENTRIES, None),
+            scroll_from_bottom: 0,
+        }
+    }
+
+    pub fn refresh(&mut self) {
+        self.entries = logging::recent(MAX_ENTRIES, None);
+        self.scroll_from_bottom = 0;
+    }
+
+    /// 向上滚动（看更早的日志）
+    pub fn scroll_up(&mut self, lines: usize) {
+        let max = self.entries.len().saturating_sub(1);
+        self.scroll_from_bottom = (self.scroll_from_bottom + lines).min(max);
+    }
+
+    /// 向下滚动（看更新的日志）
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll_from_bottom = self.scroll_from_bottom.saturating_sub(lines);
+    }
+}
+
+/// 渲染日志查看器
+pub fn render(frame: &mut Frame, data: &LogViewerData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = (area.width * 9 / 10).max(40).min(area.width);
+    let height = (area.height * 4 / 5).max(10).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let block = Block::default()
+        .title(format!(" Logs ({}) ", data.entries.len()))
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            " j/k scroll · r refresh · Esc close ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+
+    let visible = panel_area.height.saturating_sub(2) as usize;
+    let end = data.entries.len().saturating_sub(data.scroll_from_bottom);
+    let start = end.saturating_sub(visible);
+
+    let lines: Vec<Line> = if data.entries.is_empty() {
+        vec![Line::from(Span::styled(
+            format!(
+                "No log entries yet. Files: {}",
+                logging::logs_dir().display()
+            ),
+            Style::default().fg(colors.muted),
+        ))]
+    } else {
+        data.entries[start..end]
+            .iter()
+            .map(|e| entry_line(e, colors))
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines).block(block), panel_area);
+}
+
+fn entry_line<'a>(entry: &'a LogEntry, colors: &ThemeColors) -> Line<'a> {
+    let level_color = match entry.level.as_str() {
+        "ERROR" => colors.error,
+        "WARN" => colors.warning,
+        "INFO" => colors.info,
+        _ => colors.muted,
+    };
+    // RFC 3339 → HH:MM:SS
+    let time = entry.timestamp.get(11..19).unwrap_or(&entry.timestamp);
+    Line::from(vec![
+        Span::styled(format!("{} ", time), Style::default().fg(colors.muted)),
+        Span::styled(
+            format!("{:<5} ", entry.level),
+            Style::default()
+                .fg(level_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{} ", entry.target),
+            Style::default().fg(colors.muted),
+        ),
+        Span::styled(entry.message.as_str(), Style::default().fg(colors.text)),
+    ])
+}
@@ -6,7 +6,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::theme::ThemeColors;
+use crate::theme::{reduced_decoration, ThemeColors};
 
 /// ASCII Art Logo - 6 行高
 const LOGO: &[&str] = &[
@@ -35,6 +35,19 @@ pub fn render_with_padding(frame: &mut Frame, area: Rect, colors: &ThemeColors,
         lines.push(Line::from(""));
     }
 
+    // 减少装饰模式：字符画对屏幕阅读器是噪音，只显示纯文本名称（垂直居中）
+    if reduced_decoration() {
+        for _ in 0..LOGO_HEIGHT / 2 {
+            lines.push(Line::from(""));
+        }
+        lines.push(Line::from(Span::styled(
+            "GROVE",
+            Style::default().fg(colors.logo),
+        )));
+        frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), area);
+        return;
+    }
+
     // Logo 行
     for line in LOGO {
         lines.push(Line::from(Span::styled(
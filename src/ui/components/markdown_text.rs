@@ -0,0 +1,345 @@
+//! Markdown 的终端渲染（复用 `crate::markdown` 的解析结果）
+
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::markdown::{self, Align, Block, Inline};
+use crate::theme::ThemeColors;
+
+/// 把 Markdown 渲染为带样式的行，每行带 `indent` 个空格的左边距
+pub fn render_lines(source: &str, indent: usize, colors: &ThemeColors) -> Vec<Line<'static>> {
+    let mut r = Renderer {
+        colors,
+        lines: Vec::new(),
+    };
+    let prefix = vec![Span::raw(" ".repeat(indent))];
+    r.blocks(&markdown::parse(source), &prefix);
+    // 去掉末尾的空行
+    while r.lines.last().is_some_and(|l| l.width() <= indent) {
+        r.lines.pop();
+    }
+    r.lines
+}
+
+struct Renderer<'a> {
+    colors: &'a ThemeColors,
+    lines: Vec<Line<'static>>,
+}
+
+impl Renderer<'_> {
+    fn blank(&mut self, prefix: &[Span<'static>]) {
+        self.lines.push(Line::from(prefix.to_vec()));
+    }
+
+    fn blocks(&mut self, blocks: &[Block], prefix: &[Span<'static>]) {
+        for (i, block) in blocks.iter().enumerate() {
+            if i > 0 {
+                self.blank(prefix);
+            }
+            self.block(block, prefix);
+        }
+    }
+
+    fn block(&mut self, block: &Block, prefix: &[Span<'static>]) {
+        let colors = self.colors;
+        match block {
+            Block::Heading { level, content } => {
+                let mut style = Style::default()
+                    .fg(colors.highlight)
+                    .add_modifier(Modifier::BOLD);
+                if *level == 1 {
+                    style = style.add_modifier(Modifier::UNDERLINED);
+                }
+                let marker = Span::styled(
+                    format!("{} ", "#".repeat(*level as usize)),
+                    Style::default().fg(colors.muted),
+                );
+                let mut first = prefix.to_vec();
+                first.push(marker);
+                self.inline_lines(content, style, first, prefix);
+            }
+            Block::Paragraph(content) => {
+                let style = Style::default().fg(colors.text);
+                self.inline_lines(content, style, prefix.to_vec(), prefix);
+            }
+            Block::Code { lang, text } => {
+                let bar = Style::default().fg(colors.border);
+                if let Some(lang) = lang {
+                    let mut spans = prefix.to_vec();
+                    spans.push(Span::styled("┌ ", bar));
+                    spans.push(Span::styled(
+                        lang.clone(),
+                        Style::default().fg(colors.muted),
+                    ));
+                    self.lines.push(Line::from(spans));
+                }
+                for line in text.lines() {
+                    let mut spans = prefix.to_vec();
+                    spans.push(Span::styled("│ ", bar));
+                    spans.push(Span::styled(
+                        line.to_string(),
+                        Style::default().fg(colors.info),
+                    ));
+                    self.lines.push(Line::from(spans));
+                }
+            }
+            Block::Quote(inner) => {
+                let mut nested = prefix.to_vec();
+                nested.push(Span::styled("▎ ", Style::default().fg(colors.muted)));
+                let start = self.lines.len();
+                self.blocks(inner, &nested);
+                // 引用内容整体弱化
+                for line in &mut self.lines[start..] {
+                    for span in line.spans.iter_mut().skip(nested.len()) {
+                        span.style = span.style.fg(colors.muted);
+                    }
+                }
+            }
+            Block::List {
+                ordered,
+                start,
+                items,
+            } => {
+                for (n, item) in items.iter().enumerate() {
+                    let bullet = match (ordered, item.checked) {
+                        (_, Some(true)) => "☑ ".to_string(),
+                        (_, Some(false)) => "☐ ".to_string(),
+                        (true, None) => format!("{}. ", start + n as u64),
+                        (false, None) => "• ".to_string(),
+                    };
+                    let width = bullet.chars().count();
+                    let mut first = prefix.to_vec();
+                    first.push(Span::styled(bullet, Style::default().fg(colors.highlight)));
+                    let mut rest = prefix.to_vec();
+                    rest.push(Span::raw(" ".repeat(width)));
+                    self.list_item(&item.blocks, first, &rest, item.checked == Some(true));
+                }
+            }
+            Block::Table {
+                align,
+                header,
+                rows,
+            } => self.table(align, header, rows, prefix),
+            Block::Rule => {
+                let mut spans = prefix.to_vec();
+                spans.push(Span::styled(
+                    "─".repeat(40),
+                    Style::default().fg(colors.border),
+                ));
+                self.lines.push(Line::from(spans));
+            }
+        }
+    }
+
+    /// 列表项：首个块接在项目符号后，其余块使用续行缩进
+    fn list_item(
+        &mut self,
+        blocks: &[Block],
+        first: Vec<Span<'static>>,
+        rest: &[Span<'static>],
+        done: bool,
+    ) {
+        let start = self.lines.len();
+        match blocks.split_first() {
+            Some((Block::Paragraph(content), tail)) => {
+                self.inline_lines(content, Style::default().fg(self.colors.text), first, rest);
+                for block in tail {
+                    self.block(block, rest);
+                }
+            }
+            Some(_) => {
+                self.lines.push(Line::from(first));
+                for block in blocks {
+                    self.block(block, rest);
+                }
+            }
+            None => self.lines.push(Line::from(first)),
+        }
+        // 已完成的任务项划掉
+        if done {
+            for line in &mut self.lines[start..] {
+                for span in line.spans.iter_mut().skip(rest.len()) {
+                    span.style = span
+                        .style
+                        .fg(self.colors.muted)
+                        .add_modifier(Modifier::CROSSED_OUT);
+                }
+            }
+        }
+    }
+
+    fn table(
+        &mut self,
+        align: &[Align],
+        header: &[Vec<Inline>],
+        rows: &[Vec<Vec<Inline>>],
+        prefix: &[Span<'static>],
+    ) {
+        let colors = self.colors;
+        let text_of = |cell: &Vec<Inline>| markdown::plain_text(cell);
+        let mut widths: Vec<usize> = header
+            .iter()
+            .map(|c| Span::raw(text_of(c)).width())
+            .collect();
+        for row in rows {
+            for (i, cell) in row.iter().enumerate().take(widths.len()) {
+                widths[i] = widths[i].max(Span::raw(text_of(cell)).width());
+            }
+        }
+        let sep = Style::default().fg(colors.border);
+        let row_line = |cells: &[Vec<Inline>], style: Style| {
+            let mut spans = prefix.to_vec();
+            for (i, width) in widths.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::styled(" │ ", sep));
+                }
+                let text = cells.get(i).map(text_of).unwrap_or_default();
+                let pad = width.saturating_sub(Span::raw(text.clone()).width());
+                let (left, right) = match align.get(i) {
+                    Some(Align::Right) => (pad, 0),
+                    Some(Align::Center) => (pad / 2, pad - pad / 2),
+                    _ => (0, pad),
+                };
+                spans.push(Span::styled(
+                    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right)),
+                    style,
+                ));
+            }
+            Line::from(spans)
+        };
+        self.lines.push(row_line(
+            header,
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        ));
+        let mut rule = prefix.to_vec();
+        rule.push(Span::styled(
+            widths
+                .iter()
+                .map(|w| "─".repeat(*w))
+                .collect::<Vec<_>>()
+                .join("─┼─"),
+            sep,
+        ));
+        self.lines.push(Line::from(rule));
+        for row in rows {
+            self.lines
+                .push(row_line(row, Style::default().fg(colors.text)));
+        }
+    }
+
+    /// 渲染行内元素；段落内的换行另起一行，续行使用 `prefix`
+    fn inline_lines(
+        &mut self,
+        inlines: &[Inline],
+        base: Style,
+        first: Vec<Span<'static>>,
+        prefix: &[Span<'static>],
+    ) {
+        let mut current = first;
+        self.inline_spans(inlines, base, prefix, &mut current);
+        self.lines.push(Line::from(current));
+    }
+
+    fn inline_spans(
+        &mut self,
+        inlines: &[Inline],
+        style: Style,
+        prefix: &[Span<'static>],
+        current: &mut Vec<Span<'static>>,
+    ) {
+        let colors = self.colors;
+        for inline in inlines {
+            match inline {
+                Inline::Text(t) => {
+                    for (i, part) in t.split('\n').enumerate() {
+                        if i > 0 {
+                            let done = std::mem::replace(current, prefix.to_vec());
+                            self.lines.push(Line::from(done));
+                        }
+                        if !part.is_empty() {
+                            current.push(Span::styled(part.to_string(), style));
+                        }
+                    }
+                }
+                Inline::Code(t) => {
+                    current.push(Span::styled(t.clone(), style.fg(colors.info)));
+                }
+                Inline::Emphasis(c) => {
+                    self.inline_spans(c, style.add_modifier(Modifier::ITALIC), prefix, current)
+                }
+                Inline::Strong(c) => {
+                    self.inline_spans(c, style.add_modifier(Modifier::BOLD), prefix, current)
+                }
+                Inline::Strike(c) => self.inline_spans(
+                    c,
+                    style.add_modifier(Modifier::CROSSED_OUT),
+                    prefix,
+                    current,
+                ),
+                Inline::Link { href, content } => {
+                    let link_style = style.fg(colors.info).add_modifier(Modifier::UNDERLINED);
+                    self.inline_spans(content, link_style, prefix, current);
+                    if markdown::plain_text(content) != *href {
+                        current.push(Span::styled(
+                            format!(" ({})", href),
+                            Style::default().fg(colors.muted),
+                        ));
+                    }
+                }
+                Inline::Image { src, alt } => {
+                    current.push(Span::styled(
+                        format!("[image: {}] ({})", alt, src),
+                        Style::default().fg(colors.muted),
+                    ));
+                }
+                Inline::Break => {
+                    let done = std::mem::replace(current, prefix.to_vec());
+                    self.lines.push(Line::from(done));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::dark_colors;
+
+    fn plain(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_render_lines() {
+        let colors = dark_colors();
+        let lines = render_lines(
+            "# Plan\n\n- [x] ship **it**\n- read [docs](https://d.dev)\n\n| a | bb |\n|---|---:|\n| xyz | 1 |\n\n```sh\nmake\n```\n",
+            1,
+            &colors,
+        );
+        assert_eq!(
+            plain(&lines),
+            vec![
+                " # Plan",
+                " ",
+                " ☑ ship it",
+                " • read docs (https://d.dev)",
+                " ",
+                " a   │ bb",
+                " ────┼───",
+                " xyz │  1",
+                " ",
+                " ┌ sh",
+                " │ make",
+            ]
+        );
+    }
+}
@@ -9,6 +9,9 @@ use ratatui::{
 };
 
 use super::dialog_utils::{center_dialog, render_dialog_frame, render_hint, render_option};
+use crate::diff::test_changes::TestChanges;
+use crate::operations::risk::{RiskAssessment, RiskLevel};
+use crate::storage::tasks::PullRequest;
 use crate::theme::ThemeColors;
 use crate::ui::click_areas::{ClickAreas, DialogAction};
 
@@ -38,6 +41,18 @@ pub struct MergeDialogData {
     pub branch: String,
     pub target: String,
     pub selected: MergeMethod,
+    /// 是否在 commit message 中附加 review 摘要
+    pub include_review: bool,
+    /// 任务风险评分（未开启评分时为 None）
+    pub risk: Option<RiskAssessment>,
+    /// 改了源码却没改测试时的提示
+    pub tests_warning: Option<String>,
+    /// 已创建的 PR / MR
+    pub pull_request: Option<PullRequest>,
+    /// 是否识别出 forge 且配置了 token（可创建 PR）
+    pub can_create_pr: bool,
+    /// PR 正在后台创建
+    pub pr_pending: bool,
 }
 
 impl MergeDialogData {
@@ -48,17 +63,50 @@ impl MergeDialogData {
             branch,
             target,
             selected: MergeMethod::Squash,
+            include_review: false,
+            risk: None,
+            tests_warning: None,
+            pull_request: None,
+            can_create_pr: false,
+            pr_pending: false,
         }
     }
 
+    pub fn with_pull_request(mut self, pr: Option<PullRequest>, can_create_pr: bool) -> Self {
+        self.pull_request = pr;
+        self.can_create_pr = can_create_pr;
+        self
+    }
+
+    pub fn with_risk(mut self, risk: Option<RiskAssessment>) -> Self {
+        self.risk = risk;
+        self
+    }
+
+    pub fn with_test_changes(mut self, tests: Option<TestChanges>) -> Self {
+        self.tests_warning = tests.filter(|t| t.tests_missing).map(|t| {
+            let count = t.source_files.len();
+            format!(
+                "No test changes ({} source file{})",
+                count,
+                if count == 1 { "" } else { "s" }
+            )
+        });
+        self
+    }
+
     pub fn toggle(&mut self) {
         self.selected = self.selected.toggle();
     }
+
+    pub fn toggle_include_review(&mut self) {
+        self.include_review = !self.include_review;
+    }
 }
 
 /// 弹窗尺寸
-const DIALOG_WIDTH: u16 = 42;
-const DIALOG_HEIGHT: u16 = 12;
+const DIALOG_WIDTH: u16 = 56;
+const DIALOG_HEIGHT: u16 = 18;
 
 /// 渲染 Merge 弹窗
 pub fn render(
@@ -71,14 +119,20 @@ pub fn render(
     let inner_area = render_dialog_frame(frame, dialog_area, " Merge ", colors.highlight, colors);
 
     // 内部布局
-    let [info_area, _spacer1, options_area, _spacer2, hint_area] = Layout::vertical([
-        Constraint::Length(2),
-        Constraint::Length(1),
-        Constraint::Length(2),
-        Constraint::Min(1),
-        Constraint::Length(1),
-    ])
-    .areas(inner_area);
+    let [info_area, risk_area, tests_area, pr_area, _spacer1, options_area, _spacer2, review_area, _spacer3, hint_area] =
+        Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(2),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(2),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .areas(inner_area);
 
     // 渲染任务信息
     let info = Paragraph::new(vec![
@@ -100,6 +154,58 @@ pub fn render(
     .alignment(Alignment::Center);
     frame.render_widget(info, info_area);
 
+    // 渲染风险评分
+    if let Some(risk) = &data.risk {
+        let color = match risk.level {
+            RiskLevel::High => colors.error,
+            RiskLevel::Medium => colors.warning,
+            RiskLevel::Low => colors.muted,
+        };
+        let risk_line = Paragraph::new(Line::from(Span::styled(
+            format!("Risk: {}", risk.summary()),
+            Style::default().fg(color),
+        )))
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+        frame.render_widget(risk_line, risk_area);
+    }
+
+    // 渲染测试缺失提示
+    if let Some(warning) = &data.tests_warning {
+        let tests_line = Paragraph::new(Line::from(Span::styled(
+            format!("⚠ {}", warning),
+            Style::default().fg(colors.warning),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(tests_line, tests_area);
+    }
+
+    // 渲染 PR 状态
+    let pr_line = if data.pr_pending {
+        Some(Line::from(Span::styled(
+            "Creating pull request…",
+            Style::default().fg(colors.info),
+        )))
+    } else {
+        data.pull_request.as_ref().map(|pr| {
+            let color = match pr.state.as_str() {
+                "open" => colors.status_live,
+                "merged" => colors.status_merged,
+                _ => colors.muted,
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("PR #{} ", pr.number),
+                    Style::default().fg(colors.text),
+                ),
+                Span::styled(pr.state.clone(), Style::default().fg(color)),
+            ])
+        })
+    };
+    if let Some(line) = pr_line {
+        frame.render_widget(Paragraph::new(line).alignment(Alignment::Center), pr_area);
+    }
+
     // 渲染选项
     let squash_selected = data.selected == MergeMethod::Squash;
     let options = Paragraph::new(vec![
@@ -109,13 +215,26 @@ pub fn render(
     .alignment(Alignment::Center);
     frame.render_widget(options, options_area);
 
+    // 渲染 review 摘要开关
+    let (mark, style) = if data.include_review {
+        ("[x]", Style::default().fg(colors.highlight))
+    } else {
+        ("[ ]", Style::default().fg(colors.muted))
+    };
+    let review = Paragraph::new(Line::from(vec![
+        Span::styled(format!("{} ", mark), style),
+        Span::styled("Include review summary", style),
+    ]))
+    .alignment(Alignment::Center);
+    frame.render_widget(review, review_area);
+
     // 渲染底部提示
-    render_hint(
-        frame,
-        hint_area,
-        &[("j/k", "switch"), ("Enter", "confirm"), ("Esc", "cancel")],
-        colors,
-    );
+    let mut hints = vec![("j/k", "switch"), ("r", "review")];
+    if data.can_create_pr && data.pull_request.is_none() {
+        hints.push(("p", "PR"));
+    }
+    hints.extend([("Enter", "confirm"), ("Esc", "cancel")]);
+    render_hint(frame, hint_area, &hints, colors);
 
     // 注册点击区域
     click_areas.dialog_area = Some(dialog_area);
@@ -9,7 +9,11 @@ pub fn truncate(s: &str, max_len: usize) -> String {
 
 pub mod action_palette;
 pub mod add_project_dialog;
+pub mod all_tasks_list;
+pub mod autolink_panel;
 pub mod branch_selector;
+pub mod chat_view;
+pub mod column_picker;
 pub mod commit_dialog;
 pub mod config_panel;
 pub mod confirm_dialog;
@@ -17,17 +21,27 @@ pub mod delete_project_dialog;
 pub mod dialog_utils;
 pub mod empty_state;
 pub mod footer;
+pub mod global_search_panel;
 pub mod header;
 pub mod help_panel;
 pub mod hook_panel;
+pub mod inbox_panel;
 pub mod input_confirm_dialog;
+pub mod log_viewer;
 pub mod logo;
+pub mod markdown_text;
 pub mod merge_dialog;
 pub mod new_project_dialog;
 pub mod new_task_dialog;
+pub mod notes_history_panel;
+pub mod notification_center;
+pub mod open_with_picker;
 pub mod preview_panel;
 pub mod project_info;
+pub mod project_notes_panel;
 pub mod search_bar;
+pub mod search_panel;
+pub mod stash_panel;
 pub mod tabs;
 pub mod theme_selector;
 pub mod toast;
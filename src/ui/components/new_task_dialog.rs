@@ -17,6 +17,8 @@ pub fn render(
     frame: &mut Frame,
     input: &str,
     target_branch: &str,
+    template: Option<&str>,
+    has_templates: bool,
     colors: &ThemeColors,
     click_areas: &mut ClickAreas,
 ) {
@@ -24,7 +26,7 @@ pub fn render(
 
     // 计算弹窗尺寸
     let popup_width = 60u16.min(area.width.saturating_sub(4));
-    let popup_height = 9u16;
+    let popup_height = if has_templates { 10u16 } else { 9u16 };
 
     // 居中显示
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
@@ -46,14 +48,15 @@ pub fn render(
     let inner_area = block.inner(popup_area);
     frame.render_widget(block, popup_area);
 
-    // 内部布局: 空行 + 输入行 + 空行 + 预览行 + 空行 + 提示行
-    let [_, input_area, _, preview_area, _, hint_area] = Layout::vertical([
-        Constraint::Length(1), // 顶部空行
-        Constraint::Length(1), // 输入行
-        Constraint::Length(1), // 空行
-        Constraint::Length(1), // 预览行
-        Constraint::Length(1), // 空行
-        Constraint::Length(1), // 提示行
+    // 内部布局: 空行 + 输入行 + 模板行（有模板时） + 空行 + 预览行 + 空行 + 提示行
+    let [_, input_area, template_area, _, preview_area, _, hint_area] = Layout::vertical([
+        Constraint::Length(1),                                 // 顶部空行
+        Constraint::Length(1),                                 // 输入行
+        Constraint::Length(if has_templates { 1 } else { 0 }), // 模板行
+        Constraint::Length(1),                                 // 空行
+        Constraint::Length(1),                                 // 预览行
+        Constraint::Length(1),                                 // 空行
+        Constraint::Length(1),                                 // 提示行
     ])
     .areas(inner_area);
 
@@ -65,6 +68,18 @@ pub fn render(
     ]);
     frame.render_widget(Paragraph::new(input_line), input_area);
 
+    // 渲染模板行: "Template: {name}"
+    if has_templates {
+        let template_line = Line::from(vec![
+            Span::styled("  Template: ", Style::default().fg(colors.muted)),
+            match template {
+                Some(name) => Span::styled(name, Style::default().fg(colors.text)),
+                None => Span::styled("(none)", Style::default().fg(colors.muted)),
+            },
+        ]);
+        frame.render_widget(Paragraph::new(template_line), template_area);
+    }
+
     // 渲染预览行: "→ {branch} from {target}"
     let preview_line = if input.trim().is_empty() {
         Line::from(Span::styled(
@@ -93,6 +108,8 @@ pub fn render(
         Span::styled(" create  ", Style::default().fg(colors.muted)),
         Span::styled("Tab", Style::default().fg(colors.highlight)),
         Span::styled(" branch  ", Style::default().fg(colors.muted)),
+        Span::styled("^T", Style::default().fg(colors.highlight)),
+        Span::styled(" template  ", Style::default().fg(colors.muted)),
         Span::styled("Esc", Style::default().fg(colors.highlight)),
         Span::styled(" cancel", Style::default().fg(colors.muted)),
     ]))
@@ -0,0 +1,283 @@
+//! Notes 历史版本面板（查看修订 diff 并恢复）
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::error::Result;
+use crate::model::format_time;
+use crate::storage::notes::{self, NoteRevision};
+use crate::theme::ThemeColors;
+
+/// diff 的比较对象
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompareMode {
+    /// 与当前笔记比较（恢复后会发生的变化）
+    #[default]
+    Current,
+    /// 与上一个修订比较（该修订引入的变化）
+    Previous,
+}
+
+/// Notes 历史面板数据
+#[derive(Debug, Clone, Default)]
+pub struct NotesHistoryData {
+    pub project_key: String,
+    pub task_id: String,
+    pub task_name: String,
+    /// 修订列表（新 → 旧）
+    pub revisions: Vec<NoteRevision>,
+    pub selected: usize,
+    pub compare: CompareMode,
+    pub diff: String,
+    pub error: Option<String>,
+    pub diff_scroll: u16,
+}
+
+impl NotesHistoryData {
+    pub fn load(project_key: &str, task_id: &str, task_name: &str) -> Self {
+        let mut data = Self {
+            project_key: project_key.to_string(),
+            task_id: task_id.to_string(),
+            task_name: task_name.to_string(),
+            ..Default::default()
+        };
+        data.reload();
+        data
+    }
+
+    /// 重新读取修订列表和当前 diff
+    pub fn reload(&mut self) {
+        match notes::list_revisions(&self.project_key, &self.task_id) {
+            Ok(revisions) => {
+                self.revisions = revisions;
+                self.error = None;
+            }
+            Err(e) => {
+                self.revisions.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+        self.selected = self.selected.min(self.revisions.len().saturating_sub(1));
+        self.load_diff();
+    }
+
+    fn load_diff(&mut self) {
+        self.diff_scroll = 0;
+        let Some(rev) = self.revisions.get(self.selected) else {
+            self.diff.clear();
+            return;
+        };
+        // Previous 模式：从上一个（更旧的）修订到所选修订
+        let result = match self.compare {
+            CompareMode::Current => {
+                notes::diff_revision(&self.project_key, &self.task_id, &rev.id, None)
+            }
+            CompareMode::Previous => match self.revisions.get(self.selected + 1) {
+                Some(prev) => {
+                    notes::diff_revision(&self.project_key, &self.task_id, &prev.id, Some(&rev.id))
+                }
+                None => notes::load_revision(&self.project_key, &self.task_id, &rev.id).map(
+                    |(_, content)| {
+                        content
+                            .lines()
+                            .map(|l| format!("+{}\n", l))
+                            .collect::<String>()
+                    },
+                ),
+            },
+        };
+        match result {
+            Ok(diff) => {
+                self.diff = diff;
+                self.error = None;
+            }
+            Err(e) => {
+                self.diff.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.revisions.len() {
+            self.selected += 1;
+            self.load_diff();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.load_diff();
+        }
+    }
+
+    pub fn toggle_compare(&mut self) {
+        self.compare = match self.compare {
+            CompareMode::Current => CompareMode::Previous,
+            CompareMode::Previous => CompareMode::Current,
+        };
+        self.load_diff();
+    }
+
+    pub fn scroll_diff_down(&mut self, lines: u16) {
+        let max = self.diff.lines().count().saturating_sub(1) as u16;
+        self.diff_scroll = (self.diff_scroll + lines).min(max);
+    }
+
+    pub fn scroll_diff_up(&mut self, lines: u16) {
+        self.diff_scroll = self.diff_scroll.saturating_sub(lines);
+    }
+
+    /// 恢复所选修订，返回恢复的修订 id
+    pub fn restore_selected(&mut self) -> Result<Option<String>> {
+        let Some(rev) = self.revisions.get(self.selected).cloned() else {
+            return Ok(None);
+        };
+        notes::restore_revision(&self.project_key, &self.task_id, &rev.id)?;
+        self.selected = 0;
+        self.reload();
+        Ok(Some(rev.id))
+    }
+}
+
+fn revision_time(rev: &NoteRevision) -> String {
+    chrono::DateTime::parse_from_rfc3339(&rev.ts)
+        .map(|t| format_time(t.with_timezone(&chrono::Utc)))
+        .unwrap_or_else(|_| rev.ts.clone())
+}
+
+/// 渲染 Notes 历史面板
+pub fn render(frame: &mut Frame, data: &NotesHistoryData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = (area.width * 4 / 5).max(60).min(area.width);
+    let height = (area.height * 4 / 5).max(14).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let compare = match data.compare {
+        CompareMode::Current => "vs current",
+        CompareMode::Previous => "vs previous",
+    };
+    let block = Block::default()
+        .title(format!(
+            " Notes History · {} · {} version(s) ",
+            data.task_name,
+            data.revisions.len()
+        ))
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            format!(
+                " ↑/↓ select · Tab {} · J/K scroll diff · r restore · Esc close ",
+                compare
+            ),
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(panel_area);
+    frame.render_widget(block, panel_area);
+
+    if data.revisions.is_empty() {
+        let message = match &data.error {
+            Some(e) => Span::styled(e.clone(), Style::default().fg(colors.error)),
+            None => Span::styled(
+                "No versions yet — one is recorded every time the notes are saved.",
+                Style::default().fg(colors.muted),
+            ),
+        };
+        frame.render_widget(Paragraph::new(Line::from(message)), inner);
+        return;
+    }
+
+    let [list_area, diff_area] =
+        Layout::horizontal([Constraint::Length(34), Constraint::Fill(1)]).areas(inner);
+
+    // 修订列表
+    let items: Vec<ListItem> = data
+        .revisions
+        .iter()
+        .enumerate()
+        .map(|(i, rev)| {
+            let label = if i == 0 { " (latest)" } else { "" };
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<10}", revision_time(rev)),
+                    Style::default().fg(colors.text),
+                ),
+                Span::styled(
+                    format!(" {:<10}", rev.source.as_str()),
+                    Style::default().fg(colors.info),
+                ),
+                Span::styled(
+                    format!("{}B{}", rev.size, label),
+                    Style::default().fg(colors.muted),
+                ),
+            ]))
+        })
+        .collect();
+    let mut state = ListState::default();
+    state.select(Some(data.selected.min(data.revisions.len() - 1)));
+    frame.render_stateful_widget(
+        List::new(items)
+            .highlight_style(Style::default().bg(colors.bg_secondary))
+            .block(
+                Block::default()
+                    .borders(Borders::RIGHT)
+                    .border_style(Style::default().fg(colors.border)),
+            ),
+        list_area,
+        &mut state,
+    );
+
+    // diff
+    let lines: Vec<Line> = if let Some(e) = &data.error {
+        vec![Line::from(Span::styled(
+            format!(" {}", e),
+            Style::default().fg(colors.error),
+        ))]
+    } else if data.diff.is_empty() {
+        vec![Line::from(Span::styled(
+            " No differences",
+            Style::default().fg(colors.muted),
+        ))]
+    } else {
+        data.diff
+            .lines()
+            .map(|line| {
+                let style = if line.starts_with("+++") || line.starts_with("---") {
+                    Style::default().fg(colors.muted)
+                } else if line.starts_with('+') {
+                    Style::default().fg(colors.status_live)
+                } else if line.starts_with('-') {
+                    Style::default().fg(colors.error)
+                } else if line.starts_with("@@") {
+                    Style::default().fg(colors.info)
+                } else {
+                    Style::default().fg(colors.text)
+                };
+                Line::from(Span::styled(format!(" {}", line), style))
+            })
+            .collect()
+    };
+    frame.render_widget(
+        Paragraph::new(lines).scroll((data.diff_scroll, 0)),
+        diff_area,
+    );
+}
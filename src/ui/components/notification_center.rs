@@ -0,0 +1,151 @@
+//! 通知中心（Toast + Hook 通知历史）
+
+use std::collections::VecDeque;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::hooks::NotificationLevel;
+use crate::notification_state::NotificationRecord;
+use crate::theme::ThemeColors;
+
+/// 通知中心数据
+#[derive(Debug, Clone, Default)]
+pub struct NotificationCenterData {
+    /// 选中的记录（`history` 下标，0 = 最新）
+    pub selected: usize,
+}
+
+impl NotificationCenterData {
+    pub fn select_next(&mut self, len: usize) {
+        if len > 0 {
+            self.selected = (self.selected + 1).min(len - 1);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+fn level_style(level: NotificationLevel, colors: &ThemeColors) -> (&'static str, Style) {
+    match level {
+        NotificationLevel::Notice => ("INFO", Style::default().fg(colors.info)),
+        NotificationLevel::Warn => ("WARN", Style::default().fg(colors.warning)),
+        NotificationLevel::Critical => ("ERROR", Style::default().fg(colors.error)),
+    }
+}
+
+/// 渲染通知中心：上方列表，下方选中项的完整内容
+pub fn render(
+    frame: &mut Frame,
+    data: &NotificationCenterData,
+    history: &VecDeque<NotificationRecord>,
+    colors: &ThemeColors,
+) {
+    let area = frame.area();
+    let width = (area.width * 4 / 5).max(40).min(area.width);
+    let height = (area.height * 4 / 5).max(12).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let records: Vec<&NotificationRecord> = history.iter().collect();
+    let block = Block::default()
+        .title(format!(" Notifications ({}) ", records.len()))
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            " j/k select · y copy · D clear · Esc close ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(panel_area);
+    frame.render_widget(block, panel_area);
+
+    if records.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "No notifications yet.",
+                Style::default().fg(colors.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(6)])
+        .split(inner);
+
+    let message_width = (chunks[0].width as usize).saturating_sub(30).max(10);
+    let items: Vec<ListItem> = records
+        .iter()
+        .map(|r| {
+            let (label, style) = level_style(r.level, colors);
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{} ", r.timestamp.format("%H:%M:%S")),
+                    Style::default().fg(colors.muted),
+                ),
+                Span::styled(format!("{:<5} ", label), style.add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    format!("{} ", super::truncate(&r.source, 14)),
+                    Style::default().fg(colors.muted),
+                ),
+                Span::styled(
+                    super::truncate(r.message.lines().next().unwrap_or(""), message_width),
+                    Style::default().fg(colors.text),
+                ),
+            ]))
+        })
+        .collect();
+
+    let selected = data.selected.min(records.len() - 1);
+    let mut state = ListState::default();
+    state.select(Some(selected));
+    frame.render_stateful_widget(
+        List::new(items).highlight_style(Style::default().bg(colors.bg_secondary)),
+        chunks[0],
+        &mut state,
+    );
+
+    // 选中项详情
+    let record = records[selected];
+    let mut lines = vec![Line::from(Span::styled(
+        format!(
+            "{} · {}",
+            record.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            record.source
+        ),
+        Style::default().fg(colors.muted),
+    ))];
+    lines.extend(
+        record
+            .message
+            .lines()
+            .map(|l| Line::from(Span::styled(l, Style::default().fg(colors.text)))),
+    );
+    let detail = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .borders(Borders::TOP)
+            .border_style(Style::default().fg(colors.border)),
+    );
+    frame.render_widget(detail, chunks[1]);
+}
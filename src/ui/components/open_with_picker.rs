@@ -0,0 +1,109 @@
+//! "Open With" 应用选择器（用自定义命令打开任务 worktree）
+
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::storage::config::{self, OpenWithApp};
+use crate::theme::ThemeColors;
+
+/// 选择器数据
+#[derive(Debug, Clone, Default)]
+pub struct OpenWithData {
+    pub task_name: String,
+    pub path: String,
+    pub branch: String,
+    pub apps: Vec<OpenWithApp>,
+    pub selected: usize,
+}
+
+impl OpenWithData {
+    pub fn load(task_name: &str, path: &str, branch: &str) -> Self {
+        Self {
+            task_name: task_name.to_string(),
+            path: path.to_string(),
+            branch: branch.to_string(),
+            apps: config::load_config().open_with,
+            selected: 0,
+        }
+    }
+
+    pub fn selected_app(&self) -> Option<&OpenWithApp> {
+        self.apps.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.apps.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+/// 渲染选择器弹窗
+pub fn render(frame: &mut Frame, data: &OpenWithData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = 60u16.min(area.width);
+    let height = (data.apps.len().max(1) as u16 + 4).min(area.height);
+    let popup_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Open {} With ", data.task_name))
+        .title_alignment(Alignment::Center)
+        .title_bottom(Line::from(Span::styled(
+            " ↑/↓ select · Enter open · Esc close ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.highlight))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    if data.apps.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                " No applications — add [[open_with]] entries to config.toml",
+                Style::default().fg(colors.muted),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let lines: Vec<Line> = data
+        .apps
+        .iter()
+        .enumerate()
+        .map(|(i, app)| {
+            let is_selected = i == data.selected;
+            let prefix = if is_selected { "❯ " } else { "  " };
+            let name_style = if is_selected {
+                Style::default()
+                    .fg(colors.highlight)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(colors.text)
+            };
+            Line::from(vec![
+                Span::styled(format!("{}{:<16}", prefix, app.name), name_style),
+                Span::styled(app.command.clone(), Style::default().fg(colors.muted)),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
@@ -7,12 +7,15 @@ use ratatui::{
 };
 
 use crate::app::{PanelData, PreviewSubTab};
-use crate::model::{Worktree, WorktreeStatus};
+use crate::model::{format_time, Worktree, WorktreeStatus};
+use crate::operations::time_tracking::{self, TaskTime};
 use crate::storage::comments::{CommentStatus, CommentType};
 use crate::theme::ThemeColors;
 use crate::ui::click_areas::ClickAreas;
 use crate::watcher::TaskEditHistory;
 
+use super::markdown_text;
+
 /// 渲染预览面板
 #[allow(clippy::too_many_arguments)]
 pub fn render(
@@ -31,7 +34,7 @@ pub fn render(
     click_areas: &mut ClickAreas,
 ) {
     let (title, border_color) = if let Some(wt) = worktree {
-        let icon = wt.status.icon();
+        let icon = wt.status.display_icon();
         let title = format!(" {} {} ", icon, wt.task_name);
         let color = match wt.status {
             WorktreeStatus::Live => colors.status_live,
@@ -88,9 +91,14 @@ pub fn render(
 
     // Render content (Tab order: Stats, Git, Notes, Review)
     match sub_tab {
-        PreviewSubTab::Stats => {
-            render_stats_tab(frame, content_area, stats_history, stats_scroll, colors)
-        }
+        PreviewSubTab::Stats => render_stats_tab(
+            frame,
+            content_area,
+            stats_history,
+            panel_data.time_spent.as_ref(),
+            stats_scroll,
+            colors,
+        ),
         PreviewSubTab::Git => {
             if is_git_usable {
                 render_git_tab(frame, content_area, panel_data, git_scroll, colors)
@@ -249,7 +257,10 @@ pub fn render_git_tab(
     } else {
         for entry in &data.git_log {
             let time_width = 12;
-            let time = format!(" {:>width$}", entry.time_ago, width = time_width);
+            let when = chrono::DateTime::parse_from_rfc3339(&entry.time)
+                .map(|t| format_time(t.with_timezone(&chrono::Utc)))
+                .unwrap_or_else(|_| entry.time_ago.clone());
+            let time = format!(" {:>width$}", when, width = time_width);
             lines.push(Line::from(vec![
                 Span::styled(time, Style::default().fg(colors.muted)),
                 Span::raw("  "),
@@ -369,13 +380,7 @@ pub fn render_notes_tab(
         ];
         frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), center);
     } else {
-        let mut lines: Vec<Line> = Vec::new();
-        for line in content.lines() {
-            lines.push(Line::from(Span::styled(
-                format!(" {}", line),
-                Style::default().fg(colors.text),
-            )));
-        }
+        let lines = markdown_text::render_lines(content, 1, colors);
         let paragraph = Paragraph::new(lines)
             .wrap(Wrap { trim: false })
             .scroll((scroll, 0));
@@ -704,6 +709,7 @@ pub fn render_stats_tab(
     frame: &mut Frame,
     area: Rect,
     history: Option<&TaskEditHistory>,
+    time_spent: Option<&TaskTime>,
     scroll: u16,
     colors: &ThemeColors,
 ) {
@@ -871,6 +877,24 @@ pub fn render_stats_tab(
         Span::styled(format!("{}", file_count), Style::default().fg(colors.text)),
     ]));
 
+    if let Some(time) = time_spent.filter(|t| t.total_secs > 0) {
+        lines.push(Line::from(vec![
+            Span::styled("   Time spent: ", Style::default().fg(colors.muted)),
+            Span::styled(
+                time_tracking::format_duration(time.total_secs),
+                Style::default().fg(colors.text),
+            ),
+            Span::styled(
+                format!(
+                    "  (today {} · this week {})",
+                    time_tracking::format_duration(time.today_secs),
+                    time_tracking::format_duration(time.week_secs)
+                ),
+                Style::default().fg(colors.muted),
+            ),
+        ]));
+    }
+
     if let Some(last) = history.last_activity {
         let elapsed = chrono::Utc::now().signed_duration_since(last);
         let elapsed_str = if elapsed.num_hours() > 0 {
@@ -0,0 +1,217 @@
+//! 项目笔记面板（所有任务共享的知识库）
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::markdown_text;
+use crate::error::Result;
+use crate::storage::project_notes::{self, ProjectNote};
+use crate::theme::ThemeColors;
+
+/// 项目笔记面板数据
+#[derive(Debug, Clone, Default)]
+pub struct ProjectNotesData {
+    pub project_key: String,
+    pub project_name: String,
+    pub notes: Vec<ProjectNote>,
+    pub selected: usize,
+    /// 所选笔记的内容
+    pub content: String,
+    pub error: Option<String>,
+    pub scroll: u16,
+}
+
+impl ProjectNotesData {
+    pub fn load(project_key: &str, project_name: &str) -> Self {
+        let mut data = Self {
+            project_key: project_key.to_string(),
+            project_name: project_name.to_string(),
+            ..Default::default()
+        };
+        data.reload();
+        data
+    }
+
+    /// 重新读取笔记列表和所选内容
+    pub fn reload(&mut self) {
+        match project_notes::list_notes(&self.project_key) {
+            Ok(notes) => {
+                self.notes = notes;
+                self.error = None;
+            }
+            Err(e) => {
+                self.notes.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+        self.selected = self.selected.min(self.notes.len().saturating_sub(1));
+        self.load_content();
+    }
+
+    fn load_content(&mut self) {
+        self.scroll = 0;
+        self.content = match self.notes.get(self.selected) {
+            Some(note) => project_notes::load_note(&self.project_key, &note.slug)
+                .map(|(_, content)| content)
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+    }
+
+    /// 选中指定 slug 的笔记
+    pub fn select_slug(&mut self, slug: &str) {
+        if let Some(i) = self.notes.iter().position(|n| n.slug == slug) {
+            self.selected = i;
+            self.load_content();
+        }
+    }
+
+    pub fn selected_note(&self) -> Option<&ProjectNote> {
+        self.notes.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.notes.len() {
+            self.selected += 1;
+            self.load_content();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.load_content();
+        }
+    }
+
+    pub fn scroll_down(&mut self, lines: u16) {
+        let max = self.content.lines().count().saturating_sub(1) as u16;
+        self.scroll = (self.scroll + lines).min(max);
+    }
+
+    pub fn scroll_up(&mut self, lines: u16) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// 新建一篇空笔记并选中，返回其文件路径（供外部编辑器打开）
+    pub fn create(&mut self) -> Result<(String, String)> {
+        let note =
+            project_notes::create_note(&self.project_key, "Untitled", "# Untitled\n\n", true)?;
+        self.reload();
+        self.select_slug(&note.slug);
+        let path = project_notes::note_file_path(&self.project_key, &note.slug)?;
+        Ok((note.slug, path.to_string_lossy().to_string()))
+    }
+
+    /// 切换所选笔记是否附带到新 chat，返回新状态
+    pub fn toggle_include_in_chat(&mut self) -> Result<Option<bool>> {
+        let Some(note) = self.selected_note().cloned() else {
+            return Ok(None);
+        };
+        let updated = project_notes::update_note(
+            &self.project_key,
+            &note.slug,
+            None,
+            None,
+            Some(!note.include_in_chat),
+        )?;
+        self.reload();
+        Ok(Some(updated.include_in_chat))
+    }
+}
+
+/// 渲染项目笔记面板
+pub fn render(frame: &mut Frame, data: &ProjectNotesData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = (area.width * 4 / 5).max(60).min(area.width);
+    let height = (area.height * 4 / 5).max(14).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let block = Block::default()
+        .title(format!(
+            " Project Notes · {} · {} note(s) ",
+            data.project_name,
+            data.notes.len()
+        ))
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            " ↑/↓ select · e edit · n new · c toggle chat context · J/K scroll · Esc close ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(panel_area);
+    frame.render_widget(block, panel_area);
+
+    if data.notes.is_empty() {
+        let message = match &data.error {
+            Some(e) => Span::styled(e.clone(), Style::default().fg(colors.error)),
+            None => Span::styled(
+                "No project notes yet — press n to write architecture decisions or conventions shared by every task.",
+                Style::default().fg(colors.muted),
+            ),
+        };
+        frame.render_widget(Paragraph::new(Line::from(message)), inner);
+        return;
+    }
+
+    let [list_area, content_area] =
+        Layout::horizontal([Constraint::Length(32), Constraint::Fill(1)]).areas(inner);
+
+    // 笔记列表（● = 附带到新 chat）
+    let items: Vec<ListItem> = data
+        .notes
+        .iter()
+        .map(|note| {
+            let (marker, marker_color) = if note.include_in_chat {
+                ("● ", colors.status_live)
+            } else {
+                ("○ ", colors.muted)
+            };
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, Style::default().fg(marker_color)),
+                Span::styled(note.title.clone(), Style::default().fg(colors.text)),
+            ]))
+        })
+        .collect();
+    let mut state = ListState::default();
+    state.select(Some(data.selected.min(data.notes.len() - 1)));
+    frame.render_stateful_widget(
+        List::new(items)
+            .highlight_style(Style::default().bg(colors.bg_secondary))
+            .block(
+                Block::default()
+                    .borders(Borders::RIGHT)
+                    .border_style(Style::default().fg(colors.border)),
+            ),
+        list_area,
+        &mut state,
+    );
+
+    let lines = if data.content.trim().is_empty() {
+        vec![Line::from(Span::styled(
+            " (empty — press e to edit)",
+            Style::default().fg(colors.muted),
+        ))]
+    } else {
+        markdown_text::render_lines(&data.content, 1, colors)
+    };
+    frame.render_widget(Paragraph::new(lines).scroll((data.scroll, 0)), content_area);
+}
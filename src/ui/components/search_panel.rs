@@ -0,0 +1,245 @@
+//! 搜索面板（在任务 worktree 中搜索文件内容）
+
+use std::path::PathBuf;
+
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::operations::search::{self, SearchMatch, SearchOptions, SearchResult};
+use crate::theme::ThemeColors;
+
+/// 搜索面板数据
+#[derive(Debug, Clone, Default)]
+pub struct SearchPanelData {
+    pub worktree: PathBuf,
+    pub task_name: String,
+    /// 输入中的查询
+    pub query: String,
+    /// 按正则表达式匹配
+    pub regex: bool,
+    /// 区分大小写
+    pub case_sensitive: bool,
+    /// 最近一次搜索结果
+    pub result: Option<SearchResult>,
+    /// 最近一次搜索的错误（如正则无效）
+    pub error: Option<String>,
+    pub selected: usize,
+}
+
+impl SearchPanelData {
+    pub fn new(worktree: &str, task_name: &str) -> Self {
+        Self {
+            worktree: PathBuf::from(worktree),
+            task_name: task_name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 执行搜索（同步，结果数与文件大小均有上限）
+    pub fn run(&mut self) {
+        if self.query.is_empty() {
+            return;
+        }
+        let opts = SearchOptions {
+            query: self.query.clone(),
+            regex: self.regex,
+            case_sensitive: self.case_sensitive,
+            ..Default::default()
+        };
+        match search::search_worktree(&self.worktree, &opts) {
+            Ok(result) => {
+                self.result = Some(result);
+                self.error = None;
+            }
+            Err(e) => {
+                self.result = None;
+                self.error = Some(e.to_string());
+            }
+        }
+        self.selected = 0;
+    }
+
+    pub fn matches(&self) -> &[SearchMatch] {
+        self.result.as_ref().map_or(&[], |r| r.matches.as_slice())
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.matches().len();
+        if len > 0 {
+            self.selected = (self.selected + 1).min(len - 1);
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn selected_match(&self) -> Option<&SearchMatch> {
+        self.matches().get(self.selected)
+    }
+}
+
+fn flag(label: &str, on: bool, colors: &ThemeColors) -> Span<'static> {
+    let style = if on {
+        Style::default()
+            .fg(colors.highlight)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(colors.muted)
+    };
+    Span::styled(format!(" [{}]", label), style)
+}
+
+/// 渲染搜索面板
+pub fn render(frame: &mut Frame, data: &SearchPanelData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = (area.width * 4 / 5).max(50).min(area.width);
+    let height = (area.height * 4 / 5).max(14).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let title = match &data.result {
+        Some(r) => format!(
+            " Search · {} · {}{} match(es) in {} file(s) ",
+            data.task_name,
+            r.matches.len(),
+            if r.truncated { "+" } else { "" },
+            r.files_searched
+        ),
+        None => format!(" Search · {} ", data.task_name),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(Span::styled(
+            " Enter search · ↑/↓ select · Ctrl+R regex · Ctrl+T case · Esc close ",
+            Style::default().fg(colors.muted),
+        )))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(panel_area);
+    frame.render_widget(block, panel_area);
+
+    let [input_area, list_area, preview_area] = Layout::vertical([
+        Constraint::Length(2),
+        Constraint::Fill(1),
+        Constraint::Length(9),
+    ])
+    .areas(inner);
+
+    // 查询输入
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("/ ", Style::default().fg(colors.highlight)),
+            Span::styled(data.query.clone(), Style::default().fg(colors.text)),
+            Span::styled("▏", Style::default().fg(colors.highlight)),
+            flag("regex", data.regex, colors),
+            flag("case", data.case_sensitive, colors),
+        ]))
+        .block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Style::default().fg(colors.border)),
+        ),
+        input_area,
+    );
+
+    // 结果列表
+    let matches = data.matches();
+    if matches.is_empty() {
+        let message = match (&data.error, &data.result) {
+            (Some(e), _) => Span::styled(e.clone(), Style::default().fg(colors.error)),
+            (None, Some(_)) => Span::styled("No matches", Style::default().fg(colors.muted)),
+            (None, None) => Span::styled(
+                "Type a query and press Enter to search tracked files",
+                Style::default().fg(colors.muted),
+            ),
+        };
+        frame.render_widget(Paragraph::new(Line::from(message)), list_area);
+    } else {
+        let location_width = (list_area.width as usize / 3).max(12);
+        let text_width = (list_area.width as usize)
+            .saturating_sub(location_width + 1)
+            .max(10);
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|m| {
+                let location = format!("{}:{}", m.path, m.line);
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!(
+                            "{:<width$} ",
+                            super::truncate(&location, location_width),
+                            width = location_width
+                        ),
+                        Style::default().fg(colors.info),
+                    ),
+                    Span::styled(
+                        super::truncate(m.text.trim(), text_width),
+                        Style::default().fg(colors.text),
+                    ),
+                ]))
+            })
+            .collect();
+        let mut state = ListState::default();
+        state.select(Some(data.selected.min(matches.len() - 1)));
+        frame.render_stateful_widget(
+            List::new(items).highlight_style(Style::default().bg(colors.bg_secondary)),
+            list_area,
+            &mut state,
+        );
+    }
+
+    // 选中匹配的上下文预览
+    let Some(m) = data.selected_match() else {
+        return;
+    };
+    let first_line = m.line - m.before.len();
+    let mut lines: Vec<Line> = Vec::new();
+    let context_line = |n: usize, text: &str, is_match: bool| {
+        let style = if is_match {
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(colors.muted)
+        };
+        Line::from(vec![
+            Span::styled(format!("{:>5} │ ", n), Style::default().fg(colors.muted)),
+            Span::styled(text.to_string(), style),
+        ])
+    };
+    for (i, text) in m.before.iter().enumerate() {
+        lines.push(context_line(first_line + i, text, false));
+    }
+    lines.push(context_line(m.line, &m.text, true));
+    for (i, text) in m.after.iter().enumerate() {
+        lines.push(context_line(m.line + 1 + i, text, false));
+    }
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(format!(" {} ", m.path))
+                .title_style(Style::default().fg(colors.info))
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(colors.border)),
+        ),
+        preview_area,
+    );
+}
@@ -0,0 +1,303 @@
+//! Stash 管理面板（查看、应用、删除 stash）
+
+use chrono::{TimeZone, Utc};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::error::Result;
+use crate::git::{self, StashEntry};
+use crate::model::format_time;
+use crate::theme::ThemeColors;
+
+/// Stash 面板数据
+///
+/// refs/stash 在主仓库与所有 worktree 间共享，所以列表总是完整的；
+/// 应用位置可以在当前任务 worktree 和主仓库之间切换。
+#[derive(Debug, Clone, Default)]
+pub struct StashPanelData {
+    /// 主仓库路径
+    pub repo_path: String,
+    /// 当前任务（名称、分支、worktree 路径）
+    pub task: Option<(String, String, String)>,
+    /// true 时 apply/pop 在任务 worktree 中执行
+    pub apply_in_task: bool,
+    pub stashes: Vec<StashEntry>,
+    pub selected: usize,
+    pub patch: String,
+    pub patch_scroll: u16,
+    pub error: Option<String>,
+    /// 等待确认 drop
+    pub confirm_drop: bool,
+}
+
+impl StashPanelData {
+    pub fn load(repo_path: &str, task: Option<(String, String, String)>) -> Self {
+        let mut data = Self {
+            repo_path: repo_path.to_string(),
+            apply_in_task: task.is_some(),
+            task,
+            ..Default::default()
+        };
+        data.reload();
+        // 优先选中当前任务分支上的 stash
+        if let Some((_, branch, _)) = &data.task {
+            if let Some(i) = data
+                .stashes
+                .iter()
+                .position(|s| s.branch.as_deref() == Some(branch.as_str()))
+            {
+                data.selected = i;
+                data.load_patch();
+            }
+        }
+        data
+    }
+
+    /// 重新读取 stash 列表和当前 patch
+    pub fn reload(&mut self) {
+        match git::list_stashes(&self.repo_path) {
+            Ok(stashes) => {
+                self.stashes = stashes;
+                self.error = None;
+            }
+            Err(e) => {
+                self.stashes.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+        self.selected = self.selected.min(self.stashes.len().saturating_sub(1));
+        self.load_patch();
+    }
+
+    fn load_patch(&mut self) {
+        self.patch_scroll = 0;
+        self.confirm_drop = false;
+        let Some(entry) = self.stashes.get(self.selected) else {
+            self.patch.clear();
+            return;
+        };
+        match git::stash_show(&self.repo_path, entry.index) {
+            Ok(patch) => self.patch = patch,
+            Err(e) => {
+                self.patch.clear();
+                self.error = Some(e.to_string());
+            }
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.stashes.len() {
+            self.selected += 1;
+            self.load_patch();
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.load_patch();
+        }
+    }
+
+    pub fn scroll_patch_down(&mut self, lines: u16) {
+        let max = self.patch.lines().count().saturating_sub(1) as u16;
+        self.patch_scroll = (self.patch_scroll + lines).min(max);
+    }
+
+    pub fn scroll_patch_up(&mut self, lines: u16) {
+        self.patch_scroll = self.patch_scroll.saturating_sub(lines);
+    }
+
+    /// 切换应用位置（任务 worktree ↔ 主仓库）
+    pub fn toggle_target(&mut self) {
+        if self.task.is_some() {
+            self.apply_in_task = !self.apply_in_task;
+        }
+    }
+
+    /// 应用位置的显示名和路径
+    fn target(&self) -> (&str, &str) {
+        match &self.task {
+            Some((name, _, path)) if self.apply_in_task => (name, path),
+            _ => ("main repo", &self.repo_path),
+        }
+    }
+
+    /// apply / pop 所选 stash，返回提示文字
+    pub fn apply_selected(&mut self, pop: bool) -> Result<Option<String>> {
+        let Some(entry) = self.stashes.get(self.selected).cloned() else {
+            return Ok(None);
+        };
+        let (label, path) = self.target();
+        let label = label.to_string();
+        git::stash_apply(path, entry.index, pop)?;
+        self.reload();
+        let verb = if pop { "Popped" } else { "Applied" };
+        Ok(Some(format!("{} {} in {}", verb, entry.reference(), label)))
+    }
+
+    /// 删除所选 stash（第一次调用只进入确认状态）
+    pub fn drop_selected(&mut self) -> Result<Option<String>> {
+        let Some(entry) = self.stashes.get(self.selected).cloned() else {
+            return Ok(None);
+        };
+        if !self.confirm_drop {
+            self.confirm_drop = true;
+            return Ok(None);
+        }
+        git::stash_drop(&self.repo_path, entry.index)?;
+        self.reload();
+        Ok(Some(format!("Dropped {}", entry.reference())))
+    }
+}
+
+fn stash_time(entry: &StashEntry) -> String {
+    Utc.timestamp_opt(entry.created_at, 0)
+        .single()
+        .map(format_time)
+        .unwrap_or_default()
+}
+
+/// 渲染 Stash 面板
+pub fn render(frame: &mut Frame, data: &StashPanelData, colors: &ThemeColors) {
+    let area = frame.area();
+    let width = (area.width * 4 / 5).max(60).min(area.width);
+    let height = (area.height * 4 / 5).max(14).min(area.height);
+    let panel_area = Rect::new(
+        (area.width.saturating_sub(width)) / 2,
+        (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    );
+
+    frame.render_widget(Clear, panel_area);
+
+    let (target, _) = data.target();
+    let hint = if data.confirm_drop {
+        Span::styled(
+            " Press d again to drop — any other key cancels ",
+            Style::default().fg(colors.error),
+        )
+    } else {
+        let toggle = if data.task.is_some() {
+            "Tab target · "
+        } else {
+            ""
+        };
+        Span::styled(
+            format!(
+                " ↑/↓ select · J/K scroll · a apply · p pop · d drop · {}Esc close ",
+                toggle
+            ),
+            Style::default().fg(colors.muted),
+        )
+    };
+    let block = Block::default()
+        .title(format!(
+            " Stashes · {} · apply in {} ",
+            data.stashes.len(),
+            target
+        ))
+        .title_style(
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .title_bottom(Line::from(hint))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors.border))
+        .style(Style::default().bg(colors.bg));
+    let inner = block.inner(panel_area);
+    frame.render_widget(block, panel_area);
+
+    if data.stashes.is_empty() {
+        let message = match &data.error {
+            Some(e) => Span::styled(e.clone(), Style::default().fg(colors.error)),
+            None => Span::styled("No stashes", Style::default().fg(colors.muted)),
+        };
+        frame.render_widget(Paragraph::new(Line::from(message)), inner);
+        return;
+    }
+
+    let [list_area, patch_area] =
+        Layout::horizontal([Constraint::Length(40), Constraint::Fill(1)]).areas(inner);
+
+    // stash 列表：当前任务分支上的 stash 高亮
+    let task_branch = data.task.as_ref().map(|(_, b, _)| b.as_str());
+    let items: Vec<ListItem> = data
+        .stashes
+        .iter()
+        .map(|entry| {
+            let branch = entry.branch.as_deref().unwrap_or("(detached)");
+            let branch_style = if entry.branch.as_deref() == task_branch {
+                Style::default().fg(colors.highlight)
+            } else {
+                Style::default().fg(colors.info)
+            };
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<11}", entry.reference()),
+                        Style::default().fg(colors.text),
+                    ),
+                    Span::styled(branch.to_string(), branch_style),
+                ]),
+                Line::from(vec![
+                    Span::styled(
+                        format!("  {:<10} ", stash_time(entry)),
+                        Style::default().fg(colors.muted),
+                    ),
+                    Span::styled(entry.message.clone(), Style::default().fg(colors.muted)),
+                ]),
+            ])
+        })
+        .collect();
+    let mut state = ListState::default();
+    state.select(Some(data.selected.min(data.stashes.len() - 1)));
+    frame.render_stateful_widget(
+        List::new(items)
+            .highlight_style(Style::default().bg(colors.bg_secondary))
+            .block(
+                Block::default()
+                    .borders(Borders::RIGHT)
+                    .border_style(Style::default().fg(colors.border)),
+            ),
+        list_area,
+        &mut state,
+    );
+
+    // patch
+    let lines: Vec<Line> = if let Some(e) = &data.error {
+        vec![Line::from(Span::styled(
+            format!(" {}", e),
+            Style::default().fg(colors.error),
+        ))]
+    } else {
+        data.patch
+            .lines()
+            .map(|line| {
+                let style = if line.starts_with("+++") || line.starts_with("---") {
+                    Style::default().fg(colors.muted)
+                } else if line.starts_with('+') {
+                    Style::default().fg(colors.status_live)
+                } else if line.starts_with('-') {
+                    Style::default().fg(colors.error)
+                } else if line.starts_with("@@") {
+                    Style::default().fg(colors.info)
+                } else {
+                    Style::default().fg(colors.text)
+                };
+                Line::from(Span::styled(format!(" {}", line), style))
+            })
+            .collect()
+    };
+    frame.render_widget(
+        Paragraph::new(lines).scroll((data.patch_scroll, 0)),
+        patch_area,
+    );
+}
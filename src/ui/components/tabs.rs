@@ -10,11 +10,12 @@ use crate::model::ProjectTab;
 use crate::theme::ThemeColors;
 use crate::ui::click_areas::ClickAreas;
 
-/// 渲染 Tab 栏
+/// 渲染 Tab 栏（`view_label` 为右侧的排序/视图提示）
 pub fn render(
     frame: &mut Frame,
     area: Rect,
     current_tab: ProjectTab,
+    view_label: &str,
     colors: &ThemeColors,
     click_areas: &mut ClickAreas,
 ) {
@@ -48,6 +49,14 @@ pub fn render(
         }
     }
 
+    if !view_label.is_empty() {
+        spans.push(Span::raw("    "));
+        spans.push(Span::styled(
+            view_label.to_string(),
+            Style::default().fg(colors.muted),
+        ));
+    }
+
     let line = Line::from(spans);
 
     // 记录 tab 点击区域（block 有 LEFT border，内容从 area.x + 1 开始）
@@ -11,8 +11,26 @@ use ratatui::{
 use crate::theme::ThemeColors;
 
 /// 渲染 Workspace Footer
-pub fn render(frame: &mut Frame, area: Rect, has_items: bool, colors: &ThemeColors) {
-    let shortcuts = get_shortcuts(has_items);
+pub fn render(
+    frame: &mut Frame,
+    area: Rect,
+    has_items: bool,
+    all_tasks_mode: bool,
+    colors: &ThemeColors,
+) {
+    let shortcuts = if all_tasks_mode {
+        vec![
+            ("Enter", "open task"),
+            ("j/k", "move"),
+            ("/", "search"),
+            ("Tab", "projects"),
+            ("r", "refresh"),
+            ("?", "help"),
+            ("q", "quit"),
+        ]
+    } else {
+        get_shortcuts(has_items)
+    };
 
     let mut spans = Vec::new();
     spans.push(Span::raw("  "));
@@ -51,7 +69,9 @@ fn get_shortcuts(has_items: bool) -> Vec<(&'static str, &'static str)> {
             ("a", "add"),
             ("n", "new"),
             ("x", "delete"),
+            ("p", "pin"),
             ("/", "search"),
+            ("Tab", "all tasks"),
             ("c", "config"),
             ("t", "theme"),
             ("?", "help"),
@@ -14,7 +14,7 @@ use ratatui::{
 use crate::hooks::{HookEntry, NotificationLevel};
 use crate::model::workspace::WorkspaceState;
 use crate::model::ProjectInfo;
-use crate::theme::ThemeColors;
+use crate::theme::{glyph, reduced_decoration, ThemeColors};
 use crate::ui::click_areas::ClickAreas;
 
 use super::truncate;
@@ -61,7 +61,11 @@ fn lerp_color(a: Color, b: Color, t: f32) -> Color {
 /// 生成方块某一行的渐变色 span（左下→右上对角渐变）
 ///
 /// `block_row`: 0=顶, 1=中, 2=底（3 行方块）
+/// 减少装饰模式下不绘制方块，只保留等宽留白
 fn gradient_block_spans(block_row: usize, color_a: Color, color_b: Color) -> Vec<Span<'static>> {
+    if reduced_decoration() {
+        return vec![Span::raw(" ".repeat(BLOCK_WIDTH))];
+    }
     let max_t = (BLOCK_WIDTH - 1 + 2) as f32; // width + rows - 1
     (0..BLOCK_WIDTH)
         .map(|col| {
@@ -237,9 +241,11 @@ fn render_card(
     // 内容可用宽度：左边距 1 + 方块 6 + 间距 2 = 9，右边留 1
     let content_w = (area.width as usize).saturating_sub(10);
 
-    // 状态指示器
-    let (status_str, status_color) = if project.task_count > 0 {
-        ("○", colors.muted)
+    // 状态指示器（置顶优先显示 ★）
+    let (status_str, status_color) = if project.pinned {
+        (glyph("★", "*"), colors.warning)
+    } else if project.task_count > 0 {
+        (glyph("○", "o"), colors.muted)
     } else {
         (" ", colors.muted)
     };
@@ -325,9 +331,9 @@ fn render_card(
 
     // ── Row 3: [方块底]  N tasks / Git not init / missing ──
     let (task_text, task_color) = if !project.exists {
-        ("⚠ missing".to_string(), colors.error)
+        (format!("{} missing", glyph("⚠", "!")), colors.error)
     } else if !project.is_git_repo {
-        ("⚠ Git not init".to_string(), colors.warning)
+        (format!("{} Git not init", glyph("⚠", "!")), colors.warning)
     } else if project.task_count == 1 {
         ("1 task".to_string(), colors.muted)
     } else {
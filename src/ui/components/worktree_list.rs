@@ -3,15 +3,71 @@ use std::collections::HashMap;
 use ratatui::{
     layout::{Constraint, Rect},
     style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
 };
 
 use crate::hooks::{HookEntry, NotificationLevel};
-use crate::model::{format_relative_time, Worktree, WorktreeStatus};
-use crate::theme::ThemeColors;
+use crate::model::{format_time, Worktree, WorktreeStatus};
+use crate::session::agent_state::AgentState;
+use crate::storage::config::TaskColumn;
+use crate::theme::{glyph, reduced_decoration, ThemeColors};
 use crate::ui::click_areas::ClickAreas;
 
+/// 选择器 + 状态图标 + 通知标记 + 左右边框
+const FIXED_WIDTH: u16 = 2 + 2 + 4 + 2;
+/// TASK 列的最小宽度
+const TASK_MIN_WIDTH: u16 = 16;
+
+/// 列宽约束
+fn column_constraint(column: TaskColumn) -> Constraint {
+    match column {
+        TaskColumn::Target => Constraint::Fill(2),
+        _ => Constraint::Length(column_min_width(column)),
+    }
+}
+
+/// 列所需的最小宽度
+fn column_min_width(column: TaskColumn) -> u16 {
+    match column {
+        TaskColumn::Status => 8,
+        TaskColumn::Target => 10,
+        TaskColumn::Diff => 12,
+        TaskColumn::Behind => 4,
+        TaskColumn::Updated => 16, // 绝对时间 "2026-01-31 09:30"
+        TaskColumn::Session => 7,
+    }
+}
+
+/// 减少装饰模式下状态不能只靠图标颜色区分，保证 STATUS 文字列在最前
+fn labelled_columns(columns: &[TaskColumn], reduced: bool) -> Vec<TaskColumn> {
+    if reduced && !columns.contains(&TaskColumn::Status) {
+        std::iter::once(TaskColumn::Status)
+            .chain(columns.iter().copied())
+            .collect()
+    } else {
+        columns.to_vec()
+    }
+}
+
+/// 在 `width` 内能放下的列：保证 TASK 列最小宽度，放不下时从末尾开始隐藏
+pub fn visible_columns(columns: &[TaskColumn], width: u16) -> Vec<TaskColumn> {
+    let available = width.saturating_sub(FIXED_WIDTH + TASK_MIN_WIDTH);
+    let mut used = 0u16;
+    let mut visible = Vec::new();
+    for &column in columns {
+        // 每列前有 1 格列间距
+        let need = column_min_width(column) + 1;
+        if used + need > available {
+            break;
+        }
+        used += need;
+        visible.push(column);
+    }
+    visible
+}
+
 /// 渲染 Worktree 列表
 #[allow(clippy::too_many_arguments)]
 pub fn render(
@@ -21,22 +77,23 @@ pub fn render(
     selected_index: Option<usize>,
     colors: &ThemeColors,
     notifications: &HashMap<String, HookEntry>,
+    columns: &[TaskColumn],
     click_areas: &mut ClickAreas,
 ) {
+    let columns = visible_columns(&labelled_columns(columns, reduced_decoration()), area.width);
+
     // 表头
-    let header = Row::new(vec![
+    let mut header_cells = vec![
         Cell::from(""), // 选择指示器
         Cell::from(""), // 状态图标
         Cell::from(""), // 通知标记
         Cell::from("TASK"),
-        Cell::from("STATUS"),
-        Cell::from("TARGET"),
-        Cell::from("↓"), // commits behind
-        Cell::from("UPDATED"),
-    ])
-    .style(Style::default().fg(colors.muted))
-    .height(1)
-    .bottom_margin(1);
+    ];
+    header_cells.extend(columns.iter().map(|c| Cell::from(c.header())));
+    let header = Row::new(header_cells)
+        .style(Style::default().fg(colors.muted))
+        .height(1)
+        .bottom_margin(1);
 
     // 数据行
     let rows: Vec<Row> = worktrees
@@ -44,7 +101,7 @@ pub fn render(
         .enumerate()
         .map(|(i, wt)| {
             let is_selected = selected_index == Some(i);
-            let selector = if is_selected { "❯" } else { " " };
+            let selector = if is_selected { glyph("❯", ">") } else { " " };
 
             // 状态图标样式
             let icon_style = match wt.status {
@@ -57,11 +114,6 @@ pub fn render(
                 WorktreeStatus::Archived => Style::default().fg(colors.muted),
             };
 
-            let commits = wt
-                .commits_behind
-                .map(|n| n.to_string())
-                .unwrap_or_else(|| "—".to_string());
-
             let row_style = if is_selected {
                 Style::default()
                     .fg(colors.text)
@@ -70,8 +122,6 @@ pub fn render(
                 Style::default().fg(colors.text)
             };
 
-            let updated = format_relative_time(wt.updated_at);
-
             // 获取通知标记
             let (notif_marker, notif_style) = match notifications.get(&wt.id).map(|e| e.level) {
                 Some(NotificationLevel::Notice) => ("[i]", Style::default().fg(colors.info)),
@@ -80,47 +130,52 @@ pub fn render(
                 None => ("", Style::default()),
             };
 
-            Row::new(vec![
+            let mut cells = vec![
                 Cell::from(selector).style(Style::default().fg(colors.highlight)),
-                Cell::from(wt.status.icon()).style(icon_style),
+                Cell::from(wt.status.display_icon()).style(icon_style),
                 Cell::from(notif_marker).style(notif_style),
                 Cell::from(if wt.is_local {
-                    ratatui::text::Line::from(vec![
-                        ratatui::text::Span::styled(
-                            "◈ ",
+                    Line::from(vec![
+                        Span::styled(
+                            glyph("◈ ", "[local] "),
                             Style::default().fg(colors.accent_palette[0]),
                         ),
-                        ratatui::text::Span::raw(&wt.task_name),
-                    ])
-                } else if wt.created_by == "agent" {
-                    ratatui::text::Line::from(vec![
-                        ratatui::text::Span::styled("⚡", Style::default().fg(colors.info)),
-                        ratatui::text::Span::raw(&wt.task_name),
+                        Span::raw(&wt.task_name),
                     ])
                 } else {
-                    ratatui::text::Line::from(wt.task_name.clone())
+                    let mut spans = Vec::new();
+                    if wt.pinned {
+                        spans.push(Span::styled(
+                            glyph("★ ", "[pin] "),
+                            Style::default().fg(colors.warning),
+                        ));
+                    }
+                    if wt.created_by == "agent" {
+                        spans.push(Span::styled(
+                            glyph("⚡", "[agent] "),
+                            Style::default().fg(colors.info),
+                        ));
+                    }
+                    spans.push(Span::raw(&wt.task_name));
+                    Line::from(spans)
                 }),
-                Cell::from(wt.status.label()).style(icon_style),
-                Cell::from(ratatui::text::Line::from(vec![
-                    ratatui::text::Span::styled(&wt.target, Style::default().fg(colors.text)),
-                ])),
-                Cell::from(commits),
-                Cell::from(updated).style(Style::default().fg(colors.muted)),
-            ])
-            .style(row_style)
+            ];
+            cells.extend(
+                columns
+                    .iter()
+                    .map(|&column| column_cell(column, wt, icon_style, colors)),
+            );
+            Row::new(cells).style(row_style)
         })
         .collect();
 
-    let widths = [
-        Constraint::Length(2),  // 选择器
-        Constraint::Length(2),  // 状态图标
-        Constraint::Length(4),  // 通知标记
-        Constraint::Fill(2),    // TASK (flex)
-        Constraint::Length(8),  // STATUS
-        Constraint::Fill(2),    // TARGET (flex)
-        Constraint::Length(4),  // commits behind
-        Constraint::Length(14), // UPDATED
+    let mut widths = vec![
+        Constraint::Length(2), // 选择器
+        Constraint::Length(2), // 状态图标
+        Constraint::Length(4), // 通知标记
+        Constraint::Fill(2),   // TASK (flex)
     ];
+    widths.extend(columns.iter().map(|&c| column_constraint(c)));
 
     let table = Table::new(rows, widths)
         .header(header)
@@ -151,3 +206,100 @@ pub fn render(
 
     frame.render_stateful_widget(table, area, &mut table_state);
 }
+
+/// 可选列的单元格
+fn column_cell<'a>(
+    column: TaskColumn,
+    wt: &'a Worktree,
+    icon_style: Style,
+    colors: &ThemeColors,
+) -> Cell<'a> {
+    match column {
+        TaskColumn::Status => match wt.agent_state {
+            // Live tmux task: 显示推断出的 agent 状态
+            Some(state) => Cell::from(state.label()).style(match state {
+                AgentState::Waiting => Style::default().fg(colors.warning),
+                AgentState::Busy => Style::default().fg(colors.info),
+                AgentState::Idle => icon_style,
+            }),
+            None => Cell::from(wt.status.label()).style(icon_style),
+        },
+        TaskColumn::Target => Cell::from(Line::from(vec![Span::styled(
+            &wt.target,
+            Style::default().fg(colors.text),
+        )])),
+        TaskColumn::Diff => {
+            let changes = &wt.file_changes;
+            if changes.additions == 0 && changes.deletions == 0 {
+                Cell::from("—").style(Style::default().fg(colors.muted))
+            } else {
+                Cell::from(Line::from(vec![
+                    Span::styled(
+                        format!("+{}", changes.additions),
+                        Style::default().fg(colors.status_live),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        format!("-{}", changes.deletions),
+                        Style::default().fg(colors.error),
+                    ),
+                ]))
+            }
+        }
+        TaskColumn::Behind => Cell::from(
+            wt.commits_behind
+                .map(|n| n.to_string())
+                .unwrap_or_else(|| "—".to_string()),
+        ),
+        TaskColumn::Updated => {
+            Cell::from(format_time(wt.updated_at)).style(Style::default().fg(colors.muted))
+        }
+        TaskColumn::Session => {
+            let session = if wt.is_local || wt.multiplexer.is_empty() {
+                "—"
+            } else {
+                wt.multiplexer.as_str()
+            };
+            Cell::from(session).style(Style::default().fg(colors.muted))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn narrow_widths_drop_trailing_columns() {
+        let columns = [
+            TaskColumn::Status,
+            TaskColumn::Target,
+            TaskColumn::Behind,
+            TaskColumn::Updated,
+        ];
+        assert_eq!(visible_columns(&columns, 120), columns.to_vec());
+        // 26 固定 + 9 STATUS + 11 TARGET + 5 ↓ = 51，UPDATED 放不下
+        assert_eq!(
+            visible_columns(&columns, 55),
+            vec![TaskColumn::Status, TaskColumn::Target, TaskColumn::Behind]
+        );
+        assert!(visible_columns(&columns, 20).is_empty());
+        // 顺序由配置决定
+        assert_eq!(
+            visible_columns(&[TaskColumn::Updated, TaskColumn::Status], 45),
+            vec![TaskColumn::Updated]
+        );
+    }
+
+    #[test]
+    fn reduced_decoration_keeps_status_label() {
+        let columns = [TaskColumn::Target, TaskColumn::Updated];
+        assert_eq!(labelled_columns(&columns, false), columns.to_vec());
+        assert_eq!(
+            labelled_columns(&columns, true),
+            vec![TaskColumn::Status, TaskColumn::Target, TaskColumn::Updated]
+        );
+        let with_status = [TaskColumn::Updated, TaskColumn::Status];
+        assert_eq!(labelled_columns(&with_status, true), with_status.to_vec());
+    }
+}
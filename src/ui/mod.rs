@@ -1,4 +1,5 @@
 pub mod click_areas;
+pub mod clipboard;
 pub mod components;
 pub mod monitor;
 pub mod project;
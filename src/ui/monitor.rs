@@ -9,12 +9,13 @@ use ratatui::{
 };
 
 use crate::app::{App, MonitorAction, MonitorFocus, PreviewSubTab};
+use crate::model::format_time;
 use crate::theme::ThemeColors;
 use crate::ui::click_areas::ClickAreas;
 
 use super::components::{
-    commit_dialog, confirm_dialog, help_panel, input_confirm_dialog, merge_dialog, preview_panel,
-    theme_selector, toast,
+    commit_dialog, confirm_dialog, help_panel, inbox_panel, input_confirm_dialog, log_viewer,
+    merge_dialog, notification_center, preview_panel, theme_selector, toast,
 };
 
 /// 展开 sidebar 宽度
@@ -74,6 +75,21 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     .areas(content_area);
 
     render_monitor_header(frame, header_area, &app.monitor, colors);
+
+    // Grid 视图占用 tab bar + 内容区域
+    if app.monitor.grid_view {
+        let grid_area = Rect::new(
+            tab_area.x,
+            tab_area.y,
+            tab_area.width,
+            footer_area.y.saturating_sub(tab_area.y),
+        );
+        render_grid(frame, grid_area, &app.monitor, colors);
+        render_monitor_footer(frame, footer_area, &app.monitor, colors);
+        render_overlays(frame, app);
+        return;
+    }
+
     render_tab_bar(
         frame,
         tab_area,
@@ -103,6 +119,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
                 frame,
                 main_area,
                 stats_history.as_ref(),
+                app.monitor.panel_data.time_spent.as_ref(),
                 app.monitor.stats_scroll,
                 colors,
             );
@@ -131,6 +148,12 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     }
 
     render_monitor_footer(frame, footer_area, &app.monitor, colors);
+    render_overlays(frame, app);
+}
+
+/// 渲染 Toast 和覆盖弹窗
+fn render_overlays(frame: &mut Frame, app: &mut App) {
+    let colors = &app.ui.colors;
 
     // 渲染 Toast
     if let Some(ref msg) = app.async_ops.loading_message {
@@ -162,11 +185,106 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     if let Some(ref data) = app.dialogs.commit_dialog {
         commit_dialog::render(frame, data, colors, &mut app.ui.click_areas);
     }
+    if let Some(ref data) = app.dialogs.log_viewer {
+        log_viewer::render(frame, data, colors);
+    }
+
+    // 通知中心
+    if let Some(ref data) = app.dialogs.notification_center {
+        notification_center::render(frame, data, &app.notification.history, colors);
+    }
+    if let Some(ref data) = app.dialogs.inbox {
+        inbox_panel::render(frame, data, colors);
+    }
     if app.dialogs.show_help {
         help_panel::render(frame, colors, app.update_info.as_ref());
     }
 }
 
+/// 渲染多 task 预览 grid（最多 6 格，当前 task 标记 ●）
+fn render_grid(
+    frame: &mut Frame,
+    area: Rect,
+    monitor: &crate::app::MonitorState,
+    colors: &ThemeColors,
+) {
+    if monitor.grid.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                "No active tasks",
+                Style::default().fg(colors.muted),
+            )))
+            .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    let count = monitor.grid.len();
+    let cols = match count {
+        1 => 1,
+        2..=4 => 2,
+        _ => 3,
+    };
+    let rows = count.div_ceil(cols);
+    let row_areas = Layout::vertical(vec![Constraint::Fill(1); rows]).split(area);
+
+    for (i, cell) in monitor.grid.iter().enumerate() {
+        let col_areas =
+            Layout::horizontal(vec![Constraint::Fill(1); cols]).split(row_areas[i / cols]);
+        let cell_area = col_areas[i % cols];
+
+        let border_color = if i == monitor.grid_selected {
+            colors.highlight
+        } else {
+            colors.border
+        };
+        let mut title = vec![Span::styled(
+            format!(" {} ", cell.task_name),
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )];
+        if cell.task_id == monitor.task_id {
+            title.push(Span::styled(
+                "\u{25cf} ",
+                Style::default().fg(colors.status_live),
+            ));
+        }
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border_color))
+            .title(Line::from(title));
+        if !cell.source.is_empty() {
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" {} ", cell.source),
+                    Style::default().fg(colors.muted),
+                ))
+                .right_aligned(),
+            );
+        }
+        let inner = block.inner(cell_area);
+        frame.render_widget(block, cell_area);
+
+        // 只显示最后能放下的几行
+        let height = inner.height as usize;
+        let lines: Vec<Line> = if cell.lines.is_empty() {
+            vec![Line::from(Span::styled(
+                "No preview",
+                Style::default().fg(colors.muted),
+            ))]
+        } else {
+            cell.lines
+                .iter()
+                .skip(cell.lines.len().saturating_sub(height))
+                .map(|l| Line::from(Span::styled(l.as_str(), Style::default().fg(colors.text))))
+                .collect()
+        };
+        frame.render_widget(Paragraph::new(lines), inner);
+    }
+}
+
 /// 渲染折叠状态的 sidebar（窄条）
 fn render_sidebar_collapsed(frame: &mut Frame, area: Rect, colors: &ThemeColors) {
     let block = Block::default()
@@ -418,6 +536,51 @@ fn render_monitor_header(
 
     let paragraph = Paragraph::new(lines);
     frame.render_widget(paragraph, inner);
+
+    // 右侧：跟随 / 锁定状态 + 项目内最活跃的 task
+    let mut badge: Vec<Span> = Vec::new();
+    if monitor.pinned {
+        badge.push(Span::styled(
+            "\u{25c6} pinned ",
+            Style::default()
+                .fg(colors.warning)
+                .add_modifier(Modifier::BOLD),
+        ));
+    } else if monitor.follow_activity {
+        badge.push(Span::styled(
+            "\u{25c9} follow ",
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if let Some(hot) = monitor.most_active() {
+        let ago = format_time(hot.last_activity);
+        if hot.task_id == monitor.task_id {
+            badge.push(Span::styled(
+                format!("active {} ", ago),
+                Style::default().fg(colors.status_live),
+            ));
+        } else {
+            badge.push(Span::styled("hot: ", Style::default().fg(colors.muted)));
+            badge.push(Span::styled(
+                format!("{} ", hot.task_name),
+                Style::default()
+                    .fg(colors.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            badge.push(Span::styled(
+                format!("{} ", ago),
+                Style::default().fg(colors.muted),
+            ));
+        }
+    }
+    if !badge.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(badge)).alignment(Alignment::Right),
+            inner,
+        );
+    }
 }
 
 /// 渲染 tab bar
@@ -475,13 +638,23 @@ fn render_monitor_footer(
     monitor: &crate::app::MonitorState,
     colors: &ThemeColors,
 ) {
-    let hints = if monitor.sidebar_collapsed {
+    let hints = if monitor.grid_view {
+        vec![
+            ("g/Esc", "close grid"),
+            ("h/j/k/l", "select"),
+            ("Enter", "focus"),
+            ("f", "follow"),
+            ("q", "quit"),
+        ]
+    } else if monitor.sidebar_collapsed {
         // 折叠时只显示展开提示 + 内容操作
         let mut h = vec![
             ("Tab", "unfold"),
             ("1/2/3", "tab"),
             ("j/k", "scroll"),
             ("r", "refresh"),
+            ("f", "follow"),
+            ("p", "pin"),
         ];
         if monitor.content_tab == PreviewSubTab::Notes {
             h.push(("i", "edit"));
@@ -497,6 +670,9 @@ fn render_monitor_footer(
                     ("j/k", "select"),
                     ("Enter", "run"),
                     ("r", "refresh"),
+                    ("g", "grid"),
+                    ("f", "follow"),
+                    ("p", "pin"),
                     ("q", "quit"),
                 ]
             }
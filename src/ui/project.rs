@@ -9,9 +9,11 @@ use ratatui::{
 use crate::app::App;
 
 use super::components::{
-    action_palette, branch_selector, commit_dialog, config_panel, confirm_dialog, empty_state,
-    footer, header, help_panel, input_confirm_dialog, merge_dialog, new_task_dialog, preview_panel,
-    project_info, search_bar, tabs, theme_selector, toast, worktree_list,
+    action_palette, autolink_panel, branch_selector, chat_view, column_picker, commit_dialog,
+    config_panel, confirm_dialog, empty_state, footer, header, help_panel, inbox_panel,
+    input_confirm_dialog, log_viewer, merge_dialog, new_task_dialog, notes_history_panel,
+    notification_center, open_with_picker, preview_panel, project_info, project_notes_panel,
+    search_bar, search_panel, stash_panel, tabs, theme_selector, toast, worktree_list,
 };
 
 /// 渲染 Project 页面
@@ -135,10 +137,12 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     project_info::render(frame, project_info_area, &project_info_data, colors);
 
     // 渲染 Tabs
+    let view_label = app.project.view_label();
     tabs::render(
         frame,
         tabs_area,
         app.project.current_tab,
+        &view_label,
         colors,
         &mut app.ui.click_areas,
     );
@@ -178,6 +182,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
                 selected,
                 colors,
                 &app.notification.notifications,
+                &app.project.columns,
                 &mut app.ui.click_areas,
             );
         }
@@ -216,6 +221,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             selected,
             colors,
             &app.notification.notifications,
+            &app.project.columns,
             &mut app.ui.click_areas,
         );
     }
@@ -254,6 +260,11 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             frame,
             &app.dialogs.new_task_input,
             &app.async_ops.target_branch,
+            app.dialogs
+                .new_task_template
+                .and_then(|i| app.dialogs.new_task_templates.get(i))
+                .map(|t| t.name.as_str()),
+            !app.dialogs.new_task_templates.is_empty(),
             colors,
             &mut app.ui.click_areas,
         );
@@ -295,6 +306,53 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         config_panel::render(frame, data, &config.layout, colors, &mut app.ui.click_areas);
     }
 
+    // 渲染日志查看器
+    if let Some(ref data) = app.dialogs.log_viewer {
+        log_viewer::render(frame, data, colors);
+    }
+
+    // 通知中心
+    if let Some(ref data) = app.dialogs.notification_center {
+        notification_center::render(frame, data, &app.notification.history, colors);
+    }
+
+    if let Some(ref data) = app.dialogs.inbox {
+        inbox_panel::render(frame, data, colors);
+    }
+
+    if let Some(ref data) = app.dialogs.autolink_panel {
+        autolink_panel::render(frame, data, colors);
+    }
+
+    if let Some(ref data) = app.dialogs.search_panel {
+        search_panel::render(frame, data, colors);
+    }
+
+    if let Some(ref data) = app.dialogs.notes_history {
+        notes_history_panel::render(frame, data, colors);
+    }
+
+    if let Some(ref data) = app.dialogs.project_notes {
+        project_notes_panel::render(frame, data, colors);
+    }
+
+    if let Some(ref data) = app.dialogs.open_with {
+        open_with_picker::render(frame, data, colors);
+    }
+
+    if let Some(ref data) = app.dialogs.stash_panel {
+        stash_panel::render(frame, data, colors);
+    }
+
+    if let Some(ref data) = app.dialogs.column_picker {
+        column_picker::render(frame, data, colors);
+    }
+
+    // 渲染 Chat 面板
+    if let Some(ref mut data) = app.dialogs.chat_view {
+        chat_view::render(frame, data, colors);
+    }
+
     // 渲染帮助面板
     if app.dialogs.show_help {
         help_panel::render(frame, colors, app.update_info.as_ref());
@@ -10,8 +10,9 @@ use ratatui::{
 use crate::app::App;
 
 use super::components::{
-    add_project_dialog, config_panel, delete_project_dialog, help_panel, logo, new_project_dialog,
-    search_bar, theme_selector, toast, workspace_empty, workspace_footer, workspace_list,
+    add_project_dialog, all_tasks_list, config_panel, delete_project_dialog, global_search_panel,
+    help_panel, inbox_panel, log_viewer, logo, new_project_dialog, notification_center, search_bar,
+    theme_selector, toast, workspace_empty, workspace_footer, workspace_list,
 };
 
 /// 渲染 Workspace 页面
@@ -46,6 +47,24 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         );
     }
 
+    // 渲染日志查看器
+    if let Some(ref data) = app.dialogs.log_viewer {
+        log_viewer::render(frame, data, &app.ui.colors);
+    }
+
+    // 通知中心
+    if let Some(ref data) = app.dialogs.notification_center {
+        notification_center::render(frame, data, &app.notification.history, &app.ui.colors);
+    }
+
+    if let Some(ref data) = app.dialogs.inbox {
+        inbox_panel::render(frame, data, &app.ui.colors);
+    }
+
+    if let Some(ref data) = app.dialogs.global_search {
+        global_search_panel::render(frame, data, &app.ui.colors);
+    }
+
     // 渲染帮助面板
     if app.dialogs.show_help {
         help_panel::render(frame, &app.ui.colors, app.update_info.as_ref());
@@ -84,29 +103,52 @@ fn render_grid(frame: &mut Frame, app: &mut App, show_search: bool) {
     let area = frame.area();
     let colors = &app.ui.colors;
 
-    // 布局
-    let (logo_area, search_area, content_area, footer_area) = if show_search {
-        let [logo_area, search_area, content_area, footer_area] = Layout::vertical([
+    // 布局（All Tasks tab 不显示搜索框）
+    let show_search = show_search && !app.workspace.all_tasks_mode;
+    let (logo_area, tabs_area, search_area, content_area, footer_area) = if show_search {
+        let [logo_area, tabs_area, search_area, content_area, footer_area] = Layout::vertical([
             Constraint::Length(9), // Logo
+            Constraint::Length(1), // Projects / All Tasks
             Constraint::Length(1), // 搜索框
             Constraint::Fill(1),   // 内容
             Constraint::Length(3), // Footer
         ])
         .areas(area);
-        (logo_area, Some(search_area), content_area, footer_area)
+        (
+            logo_area,
+            tabs_area,
+            Some(search_area),
+            content_area,
+            footer_area,
+        )
     } else {
-        let [logo_area, content_area, footer_area] = Layout::vertical([
+        let [logo_area, tabs_area, content_area, footer_area] = Layout::vertical([
             Constraint::Length(9), // Logo
+            Constraint::Length(1), // Projects / All Tasks
             Constraint::Fill(1),   // 内容
             Constraint::Length(3), // Footer
         ])
         .areas(area);
-        (logo_area, None, content_area, footer_area)
+        (logo_area, tabs_area, None, content_area, footer_area)
     };
 
     // 渲染 Logo（带顶部间距）
     logo::render_with_padding(frame, logo_area, colors, 2);
 
+    all_tasks_list::render_mode_tabs(frame, tabs_area, app.workspace.all_tasks_mode, colors);
+
+    if app.workspace.all_tasks_mode {
+        all_tasks_list::render(
+            frame,
+            content_area,
+            &app.workspace.all_tasks,
+            app.workspace.all_tasks_selected,
+            colors,
+        );
+        workspace_footer::render(frame, footer_area, true, true, colors);
+        return;
+    }
+
     // 渲染搜索框
     if let Some(search_area) = search_area {
         search_bar::render(
@@ -136,5 +178,5 @@ fn render_grid(frame: &mut Frame, app: &mut App, show_search: bool) {
 
     // 渲染 Footer
     let has_items = !app.workspace.projects.is_empty();
-    workspace_footer::render(frame, footer_area, has_items, colors);
+    workspace_footer::render(frame, footer_area, has_items, false, colors);
 }
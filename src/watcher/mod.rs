@@ -9,7 +9,6 @@ mod storage;
 
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::sync::{Arc, RwLock};
 use std::thread;
@@ -20,7 +19,7 @@ use notify::{
     Config, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher,
 };
 
-pub use storage::{load_edit_history, save_edit_history, EditEvent};
+pub use storage::{last_edit_at, load_edit_history, save_edit_history, EditEvent};
 
 /// Debounce window in seconds - ignore duplicate events for same file within this window
 const DEBOUNCE_SECS: i64 = 2;
@@ -82,23 +81,12 @@ fn is_noise_file(path: &Path) -> bool {
     false
 }
 
-/// Get list of git-tracked files in a directory
+/// Get list of git-tracked files in a directory (sparse worktrees: only the
+/// checked-out ones)
 fn get_git_tracked_files(worktree_path: &Path) -> HashSet<PathBuf> {
-    let output = Command::new("git")
-        .args(["ls-files"])
-        .current_dir(worktree_path)
-        .output();
-
-    match output {
-        Ok(output) if output.status.success() => {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            stdout
-                .lines()
-                .map(|line| PathBuf::from(crate::git::git_unquote(line.trim())))
-                .collect()
-        }
-        _ => HashSet::new(),
-    }
+    crate::git::sparse_checkout::checked_out_files(&worktree_path.to_string_lossy())
+        .map(|files| files.into_iter().map(PathBuf::from).collect())
+        .unwrap_or_default()
 }
 
 /// Maximum events to keep in memory per task (older events are dropped from memory but kept on disk)
@@ -196,6 +184,35 @@ impl TaskEditHistory {
     }
 }
 
+/// Edits closer together than this belong to the same active-work interval
+const ACTIVE_GAP_SECS: i64 = 5 * 60;
+
+/// Work credited after the last edit of an interval, so a lone edit counts
+const EDIT_CREDIT_SECS: i64 = 60;
+
+/// Derive active-work intervals from edit events.
+///
+/// Consecutive edits less than `ACTIVE_GAP_SECS` apart form one interval,
+/// which runs from its first edit to `EDIT_CREDIT_SECS` past its last one.
+/// Events may be in any order; the result is sorted and non-overlapping.
+pub fn edit_intervals(events: &[EditEvent]) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut times: Vec<DateTime<Utc>> = events.iter().map(|e| e.timestamp).collect();
+    times.sort();
+
+    let gap = chrono::Duration::seconds(ACTIVE_GAP_SECS);
+    let credit = chrono::Duration::seconds(EDIT_CREDIT_SECS);
+    let mut intervals: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+    let mut last_edit: Option<DateTime<Utc>> = None;
+    for time in times {
+        match (intervals.last_mut(), last_edit) {
+            (Some(current), Some(last)) if time - last < gap => current.1 = time + credit,
+            _ => intervals.push((time, time + credit)),
+        }
+        last_edit = Some(time);
+    }
+    intervals
+}
+
 /// Per-task event subscriber. Called from the watcher thread for every
 /// debounced edit event matching the registered `task_id`. Implementors
 /// must be lightweight — heavy work should be moved off-thread.
@@ -221,6 +238,8 @@ enum WatcherCommand {
     Watch {
         task_id: String,
         path: PathBuf,
+        /// Task path scope (relative); only this subdirectory is watched
+        scope: Option<PathBuf>,
     },
     #[allow(dead_code)]
     Unwatch {
@@ -291,7 +310,7 @@ impl FileWatcher {
                 } else {
                     "unknown panic".to_string()
                 };
-                eprintln!("[Grove] File watcher thread panicked: {}", msg);
+                tracing::error!("File watcher thread panicked: {}", msg);
             }
         });
 
@@ -322,9 +341,11 @@ impl FileWatcher {
         }
 
         if let Some(tx) = &self.control_tx {
+            let scope = crate::storage::task_scope::load_scope(&self.project_key, task_id);
             let _ = tx.send(WatcherCommand::Watch {
                 task_id: task_id.to_string(),
                 path: path.to_path_buf(),
+                scope: scope.map(PathBuf::from),
             });
         }
     }
@@ -423,6 +444,10 @@ fn run_watcher_thread(
     // Map of path -> task_id for event routing
     let mut path_to_task: HashMap<PathBuf, String> = HashMap::new();
 
+    // Directory actually registered with notify per worktree (the scope
+    // subdirectory for path-scoped tasks, otherwise the worktree itself)
+    let mut watched_dirs: HashMap<PathBuf, PathBuf> = HashMap::new();
+
     // Cache of git-tracked files per worktree (path -> set of relative file paths)
     let mut git_tracked_cache: HashMap<PathBuf, HashSet<PathBuf>> = HashMap::new();
     let mut last_cache_refresh = std::time::Instant::now();
@@ -435,8 +460,22 @@ fn run_watcher_thread(
         // Check for control commands (non-blocking)
         while let Ok(cmd) = control_rx.try_recv() {
             match cmd {
-                WatcherCommand::Watch { task_id, path } => {
-                    if watcher.watch(&path, RecursiveMode::Recursive).is_ok() {
+                WatcherCommand::Watch {
+                    task_id,
+                    path,
+                    scope,
+                } => {
+                    let dir = scope
+                        .map(|s| path.join(s))
+                        .filter(|dir| dir.is_dir())
+                        .unwrap_or_else(|| path.clone());
+                    // Scope changed: drop the previously watched directory
+                    if let Some(previous) = watched_dirs.get(&path).filter(|p| **p != dir) {
+                        let _ = watcher.unwatch(previous);
+                        watched_dirs.remove(&path);
+                    }
+                    if watcher.watch(&dir, RecursiveMode::Recursive).is_ok() {
+                        watched_dirs.insert(path.clone(), dir);
                         // Initialize git tracked files cache for this worktree
                         let tracked = get_git_tracked_files(&path);
                         git_tracked_cache.insert(path.clone(), tracked);
@@ -451,7 +490,8 @@ fn run_watcher_thread(
                         .map(|(p, _)| p.clone())
                         .collect();
                     for path in path_to_remove {
-                        let _ = watcher.unwatch(&path);
+                        let dir = watched_dirs.remove(&path).unwrap_or_else(|| path.clone());
+                        let _ = watcher.unwatch(&dir);
                         path_to_task.remove(&path);
                         git_tracked_cache.remove(&path);
                     }
@@ -490,6 +530,7 @@ fn run_watcher_thread(
 
             match event_rx.recv_timeout(timeout) {
                 Ok(event) => {
+                    crate::metrics::record_watcher_fs_events(1);
                     // Process file content changes:
                     // - Modify(Data): direct file edits
                     // - Modify(Any): platform doesn't distinguish modification types
@@ -559,6 +600,7 @@ fn run_watcher_thread(
 
         // Process batch with single lock acquisition
         if !batch.is_empty() {
+            crate::metrics::record_watcher_edit_events(batch.len() as u64);
             if let Ok(mut histories) = histories.write() {
                 for (task_id, event) in &batch {
                     let history = histories
@@ -590,6 +632,11 @@ fn run_watcher_thread(
                 }
             }
 
+            // Periodic auto-commit checkpoints for tasks that opted in
+            for task_id in batch.iter().map(|(tid, _)| tid).collect::<HashSet<_>>() {
+                crate::operations::checkpoints::on_activity(&project_key, task_id);
+            }
+
             if let Ok(mut pending) = pending_events.write() {
                 for (task_id, event) in batch {
                     pending.entry(task_id).or_insert_with(Vec::new).push(event);
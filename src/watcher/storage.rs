@@ -28,6 +28,17 @@ fn edits_file_path(project_key: &str, task_id: &str) -> Result<PathBuf> {
     Ok(ensure_task_data_dir(project_key, task_id)?.join("activity.jsonl"))
 }
 
+/// activity.jsonl 最近一次写入时间（文件不存在返回 None）
+pub fn last_edit_at(project_key: &str, task_id: &str) -> Option<DateTime<Utc>> {
+    let modified = edits_file_path(project_key, task_id)
+        .ok()?
+        .metadata()
+        .ok()?
+        .modified()
+        .ok()?;
+    Some(modified.into())
+}
+
 /// Load all edit events for a task.
 ///
 /// Filters out historical noise (tmp files, OS cruft) on the way in so the
@@ -100,6 +100,72 @@ pub fn session_exists(name: &str) -> bool {
     })
 }
 
+/// 向 session 当前聚焦的 pane 写入文本
+/// 执行: zellij --session {name} action write-chars -- {text}
+pub fn write_chars(name: &str, text: &str) -> Result<()> {
+    run_action(name, &write_chars_action(text))
+}
+
+/// write-chars 的参数；`--` 防止以 `-` 开头的文本被当成选项
+fn write_chars_action(text: &str) -> [&str; 3] {
+    ["write-chars", "--", text]
+}
+
+/// 向 session 当前聚焦的 pane 发送一个具名按键（tmux 键名风格）
+/// 执行: zellij --session {name} action write {bytes...}
+pub fn write_key(name: &str, key: &str) -> Result<()> {
+    let bytes = key_bytes(key)
+        .ok_or_else(|| GroveError::session(format!("Unsupported key for zellij: {}", key)))?;
+    let args: Vec<String> = bytes.iter().map(|b| b.to_string()).collect();
+    let mut action = vec!["write"];
+    action.extend(args.iter().map(String::as_str));
+    run_action(name, &action)
+}
+
+fn run_action(name: &str, action: &[&str]) -> Result<()> {
+    let output = zellij_cmd()
+        .args(["--session", name, "action"])
+        .args(action)
+        .output()
+        .map_err(|e| GroveError::session(format!("Zellij action failed: {}", e)))?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(GroveError::session(format!(
+            "Zellij action failed: {}",
+            stderr.trim()
+        )))
+    }
+}
+
+/// tmux 风格键名 → 终端字节序列
+fn key_bytes(key: &str) -> Option<Vec<u8>> {
+    let bytes = match key {
+        "Enter" => vec![13],
+        "Escape" => vec![27],
+        "Tab" => vec![9],
+        "BSpace" => vec![127],
+        "Space" => vec![32],
+        "Up" => vec![27, 91, 65],
+        "Down" => vec![27, 91, 66],
+        "Right" => vec![27, 91, 67],
+        "Left" => vec![27, 91, 68],
+        _ => {
+            // C-a … C-z
+            let letter = key.strip_prefix("C-")?;
+            let mut chars = letter.chars();
+            let c = chars.next()?.to_ascii_lowercase();
+            if chars.next().is_some() || !c.is_ascii_lowercase() {
+                return None;
+            }
+            vec![c as u8 & 0x1f]
+        }
+    };
+    Some(bytes)
+}
+
 /// 关闭活跃 session
 pub fn kill_session(name: &str) -> Result<()> {
     let output = zellij_cmd()
@@ -124,3 +190,26 @@ pub fn kill_session(name: &str) -> Result<()> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_chars_passes_dash_text_as_positional() {
+        assert_eq!(
+            write_chars_action("-rf foo"),
+            ["write-chars", "--", "-rf foo"]
+        );
+    }
+
+    #[test]
+    fn key_bytes_maps_named_and_ctrl_keys() {
+        assert_eq!(key_bytes("Enter"), Some(vec![13]));
+        assert_eq!(key_bytes("Up"), Some(vec![27, 91, 65]));
+        assert_eq!(key_bytes("C-c"), Some(vec![3]));
+        assert_eq!(key_bytes("C-C"), Some(vec![3]));
+        assert_eq!(key_bytes("C-cc"), None);
+        assert_eq!(key_bytes("F13"), None);
+    }
+}